@@ -0,0 +1,26 @@
+-- Migration: Saved Material Library
+
+-- Custom materials with user-specific prices and properties.
+-- Calculators reference these by id so negotiated prices flow into estimates.
+CREATE TABLE IF NOT EXISTS custom_materials (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    material_type VARCHAR(50) NOT NULL,
+    unit VARCHAR(20) NOT NULL,
+    unit_price DOUBLE PRECISION,
+    currency CHAR(3) NOT NULL DEFAULT 'USD',
+    waste_factor DOUBLE PRECISION,
+    density DOUBLE PRECISION,
+    -- Free-form numeric properties (yield_strength, elastic_modulus, ...)
+    properties JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT material_name_not_empty CHECK (name != ''),
+    CONSTRAINT material_price_non_negative CHECK (unit_price IS NULL OR unit_price >= 0),
+    CONSTRAINT material_waste_range CHECK (waste_factor IS NULL OR (waste_factor >= 0 AND waste_factor <= 1)),
+    CONSTRAINT material_name_unique UNIQUE (user_id, name)
+);
+
+CREATE INDEX idx_custom_materials_user ON custom_materials(user_id);
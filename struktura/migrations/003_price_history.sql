@@ -0,0 +1,18 @@
+-- Migration: Price History
+
+-- Every price the engine observes, kept for trend and escalation analysis.
+CREATE TABLE IF NOT EXISTS price_history (
+    id BIGSERIAL PRIMARY KEY,
+    material_code VARCHAR(100) NOT NULL,
+    country_code CHAR(2) NOT NULL,
+    region VARCHAR(100),
+    city VARCHAR(100),
+    store_name VARCHAR(255) NOT NULL,
+    price DOUBLE PRECISION NOT NULL,
+    currency CHAR(3) NOT NULL,
+    observed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT price_history_non_negative CHECK (price >= 0)
+);
+
+CREATE INDEX idx_price_history_lookup ON price_history(material_code, country_code, observed_at);
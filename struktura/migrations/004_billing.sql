@@ -0,0 +1,23 @@
+-- Migration: Subscriptions & Billing
+
+-- One Stripe subscription per user. Rows are written by the webhook handler;
+-- users without a row are on the free plan.
+CREATE TABLE IF NOT EXISTS subscriptions (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    stripe_customer_id VARCHAR(255) NOT NULL UNIQUE,
+    stripe_subscription_id VARCHAR(255) UNIQUE,
+    plan VARCHAR(20) NOT NULL DEFAULT 'free',
+    status VARCHAR(30) NOT NULL DEFAULT 'incomplete',
+    current_period_start TIMESTAMP WITH TIME ZONE,
+    current_period_end TIMESTAMP WITH TIME ZONE,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT subscription_plan_valid CHECK (plan IN ('free', 'pro', 'enterprise'))
+);
+
+-- Processed webhook events, so Stripe retries are applied once
+CREATE TABLE IF NOT EXISTS stripe_events (
+    id VARCHAR(255) PRIMARY KEY,
+    event_type VARCHAR(100) NOT NULL,
+    received_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,21 @@
+-- Migration: Feature Flags & Administrators
+
+-- Operators allowed to toggle flags and other admin-only settings
+ALTER TABLE users ADD COLUMN IF NOT EXISTS is_admin BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- A flag is off unless enabled; when enabled it applies to listed users and
+-- orgs plus a stable percentage bucket of everyone else. Calculators are
+-- gated by flags named `calculator.<tier>.<id>`.
+CREATE TABLE IF NOT EXISTS feature_flags (
+    key VARCHAR(100) PRIMARY KEY,
+    description TEXT NOT NULL DEFAULT '',
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    rollout_percentage SMALLINT NOT NULL DEFAULT 0,
+    allowed_users UUID[] NOT NULL DEFAULT '{}',
+    allowed_orgs TEXT[] NOT NULL DEFAULT '{}',
+    updated_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT feature_flag_key_format CHECK (key ~ '^[a-z0-9_.-]+$'),
+    CONSTRAINT feature_flag_rollout_range CHECK (rollout_percentage BETWEEN 0 AND 100)
+);
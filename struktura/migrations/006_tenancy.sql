@@ -0,0 +1,85 @@
+-- Migration: Organizations & Tenant Isolation
+
+-- Every user belongs to exactly one organization. Individuals get a personal
+-- organization sharing their user id; firms are created by an administrator
+-- and their members moved into it. Limits left NULL fall back to the
+-- platform defaults.
+CREATE TABLE IF NOT EXISTS organizations (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(100) NOT NULL,
+    personal BOOLEAN NOT NULL DEFAULT FALSE,
+    rate_limit_per_minute INTEGER,
+    monthly_calculation_quota INTEGER,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT organization_name_not_empty CHECK (name != ''),
+    CONSTRAINT organization_rate_limit_positive CHECK (rate_limit_per_minute IS NULL OR rate_limit_per_minute > 0),
+    CONSTRAINT organization_quota_non_negative CHECK (monthly_calculation_quota IS NULL OR monthly_calculation_quota >= 0)
+);
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS org_id UUID REFERENCES organizations(id);
+
+INSERT INTO organizations (id, name, personal)
+SELECT id, username, TRUE FROM users WHERE org_id IS NULL
+ON CONFLICT (id) DO NOTHING;
+
+UPDATE users SET org_id = id WHERE org_id IS NULL;
+ALTER TABLE users ALTER COLUMN org_id SET NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_users_org ON users(org_id);
+
+-- Signups without an organization get a personal one
+CREATE OR REPLACE FUNCTION assign_personal_org() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.org_id IS NULL THEN
+        INSERT INTO organizations (id, name, personal) VALUES (NEW.id, NEW.username, TRUE)
+        ON CONFLICT (id) DO NOTHING;
+        NEW.org_id := NEW.id;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS users_assign_personal_org ON users;
+CREATE TRIGGER users_assign_personal_org
+    BEFORE INSERT ON users
+    FOR EACH ROW EXECUTE FUNCTION assign_personal_org();
+
+-- User data rows carry the owning organization. Writers that predate
+-- tenancy only supply user_id, so the org is inherited on insert.
+CREATE OR REPLACE FUNCTION inherit_user_org() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.org_id IS NULL THEN
+        SELECT org_id INTO NEW.org_id FROM users WHERE id = NEW.user_id;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE usage_metrics ADD COLUMN IF NOT EXISTS org_id UUID REFERENCES organizations(id);
+UPDATE usage_metrics m SET org_id = u.org_id FROM users u WHERE m.user_id = u.id AND m.org_id IS NULL;
+ALTER TABLE usage_metrics ALTER COLUMN org_id SET NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_usage_metrics_org_accessed ON usage_metrics(org_id, accessed_at DESC);
+
+DROP TRIGGER IF EXISTS usage_metrics_inherit_org ON usage_metrics;
+CREATE TRIGGER usage_metrics_inherit_org
+    BEFORE INSERT ON usage_metrics
+    FOR EACH ROW EXECUTE FUNCTION inherit_user_org();
+
+ALTER TABLE custom_materials ADD COLUMN IF NOT EXISTS org_id UUID REFERENCES organizations(id);
+UPDATE custom_materials m SET org_id = u.org_id FROM users u WHERE m.user_id = u.id AND m.org_id IS NULL;
+ALTER TABLE custom_materials ALTER COLUMN org_id SET NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_custom_materials_org ON custom_materials(org_id);
+
+DROP TRIGGER IF EXISTS custom_materials_inherit_org ON custom_materials;
+CREATE TRIGGER custom_materials_inherit_org
+    BEFORE INSERT ON custom_materials
+    FOR EACH ROW EXECUTE FUNCTION inherit_user_org();
+
+ALTER TABLE subscriptions ADD COLUMN IF NOT EXISTS org_id UUID REFERENCES organizations(id);
+UPDATE subscriptions s SET org_id = u.org_id FROM users u WHERE s.user_id = u.id AND s.org_id IS NULL;
+ALTER TABLE subscriptions ALTER COLUMN org_id SET NOT NULL;
+
+DROP TRIGGER IF EXISTS subscriptions_inherit_org ON subscriptions;
+CREATE TRIGGER subscriptions_inherit_org
+    BEFORE INSERT ON subscriptions
+    FOR EACH ROW EXECUTE FUNCTION inherit_user_org();
@@ -0,0 +1,29 @@
+-- Migration: OEE Threshold Profiles
+
+-- Classification thresholds saved per machine or per line. OEE analyses
+-- that leave thresholds out use the machine's profile, else its line's,
+-- else the built-in defaults. Percentages are stored as fractions.
+CREATE TABLE IF NOT EXISTS threshold_profiles (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    machine_id VARCHAR(100),
+    line_id VARCHAR(100),
+    micro_stoppage_seconds DOUBLE PRECISION NOT NULL,
+    speed_loss_fraction DOUBLE PRECISION NOT NULL,
+    high_scrap_rate_fraction DOUBLE PRECISION NOT NULL,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT threshold_profile_name_not_empty CHECK (name != ''),
+    CONSTRAINT threshold_profile_single_target CHECK ((machine_id IS NULL) != (line_id IS NULL)),
+    CONSTRAINT threshold_profile_micro_stoppage_range CHECK (micro_stoppage_seconds BETWEEN 0 AND 3600),
+    CONSTRAINT threshold_profile_speed_loss_range CHECK (speed_loss_fraction BETWEEN 0 AND 1),
+    CONSTRAINT threshold_profile_scrap_rate_range CHECK (high_scrap_rate_fraction BETWEEN 0 AND 1)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_threshold_profiles_machine
+    ON threshold_profiles(org_id, machine_id) WHERE machine_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_threshold_profiles_line
+    ON threshold_profiles(org_id, line_id) WHERE line_id IS NOT NULL;
@@ -0,0 +1,36 @@
+-- Migration: Saved Schedules and Milestone Alerts
+
+-- Activity schedules kept per organization. A background sweep alerts the
+-- configured email address and/or webhook when a milestone comes within one
+-- of the lead times or slips past its baseline.
+CREATE TABLE IF NOT EXISTS saved_schedules (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    -- Activities as accepted by the scheduling calculators
+    schedule JSONB NOT NULL,
+    -- Days before a milestone falls due to alert; empty sends slip alerts only
+    alert_lead_days INTEGER[] NOT NULL DEFAULT '{7,1}',
+    alert_email VARCHAR(255),
+    alert_webhook_url TEXT,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT saved_schedule_name_not_empty CHECK (name != ''),
+    CONSTRAINT saved_schedule_name_unique UNIQUE (org_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_schedules_alerting
+    ON saved_schedules(id) WHERE alert_email IS NOT NULL OR alert_webhook_url IS NOT NULL;
+
+-- Alerts already delivered, so each fires once
+CREATE TABLE IF NOT EXISTS milestone_alerts_sent (
+    schedule_id UUID NOT NULL REFERENCES saved_schedules(id) ON DELETE CASCADE,
+    milestone_id VARCHAR(100) NOT NULL,
+    -- `lead:<days>` or `slipped:<forecast date>`
+    alert_key VARCHAR(50) NOT NULL,
+    sent_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (schedule_id, milestone_id, alert_key)
+);
@@ -0,0 +1,37 @@
+-- Migration: Punch Lists
+
+-- Closeout punch lists kept per organization. Items are updated one at a
+-- time as work is done; the closeout calculator counts them live.
+CREATE TABLE IF NOT EXISTS punch_lists (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    project_name VARCHAR(200),
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT punch_list_name_not_empty CHECK (name != ''),
+    CONSTRAINT punch_list_name_unique UNIQUE (org_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS punch_items (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    punch_list_id UUID NOT NULL REFERENCES punch_lists(id) ON DELETE CASCADE,
+    description VARCHAR(500) NOT NULL,
+    location VARCHAR(200),
+    responsible_party VARCHAR(100) NOT NULL,
+    status VARCHAR(30) NOT NULL DEFAULT 'open',
+    due_date DATE,
+    -- Photo metadata (url, caption, taken_at); images live in file storage
+    photos JSONB NOT NULL DEFAULT '[]',
+    closed_at TIMESTAMP WITH TIME ZONE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT punch_item_status_valid
+        CHECK (status IN ('open', 'in_progress', 'ready_for_inspection', 'closed'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_punch_items_list ON punch_items(punch_list_id);
@@ -0,0 +1,31 @@
+-- Migration: Field Reports
+
+-- Daily reports from site, one per project per day. Crews, work entries,
+-- delays and photo metadata are stored as JSON arrays; the progress and
+-- delay calculators read a project's reports as its field log.
+CREATE TABLE IF NOT EXISTS field_reports (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    project_name VARCHAR(200) NOT NULL,
+    report_date DATE NOT NULL,
+    weather JSONB NOT NULL DEFAULT '{}',
+    crews JSONB NOT NULL DEFAULT '[]',
+    work_performed JSONB NOT NULL DEFAULT '[]',
+    -- Each delay: cause (weather, owner, design, contractor, subcontractor,
+    -- third_party), description and hours lost
+    delays JSONB NOT NULL DEFAULT '[]',
+    percent_complete DOUBLE PRECISION,
+    photos JSONB NOT NULL DEFAULT '[]',
+    notes TEXT,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT field_report_project_not_empty CHECK (project_name != ''),
+    CONSTRAINT field_report_percent_range
+        CHECK (percent_complete IS NULL OR percent_complete BETWEEN 0 AND 100),
+    CONSTRAINT field_report_one_per_day UNIQUE (org_id, project_name, report_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_field_reports_project
+    ON field_reports(org_id, project_name, report_date);
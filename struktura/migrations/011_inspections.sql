@@ -0,0 +1,27 @@
+-- Migration: Inspections
+
+-- Trade inspection checklists generated from project scope, with the
+-- outcome of each item. Checklist items and results are stored as JSON
+-- arrays; quality statistics are computed from the results.
+CREATE TABLE IF NOT EXISTS inspections (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    project_name VARCHAR(200) NOT NULL,
+    trade VARCHAR(30) NOT NULL,
+    inspection_date DATE NOT NULL DEFAULT CURRENT_DATE,
+    inspector VARCHAR(200),
+    items JSONB NOT NULL DEFAULT '[]',
+    -- Each result: item_id, outcome (pass, fail, not_applicable),
+    -- reinspections and notes
+    results JSONB NOT NULL DEFAULT '[]',
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT inspection_project_not_empty CHECK (project_name != ''),
+    CONSTRAINT inspection_trade_valid
+        CHECK (trade IN ('concrete_pour', 'steel_erection', 'roofing'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_inspections_project
+    ON inspections(org_id, project_name, inspection_date);
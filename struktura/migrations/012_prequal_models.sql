@@ -0,0 +1,35 @@
+-- Migration: Prequalification Scoring Models
+
+-- Weights and minimums for ranking subcontractors, saved per organization.
+-- Requests that name no model use the organization's default, else the
+-- built-in weights. Unset minimums are not checked.
+CREATE TABLE IF NOT EXISTS prequal_models (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    is_default BOOLEAN NOT NULL DEFAULT FALSE,
+    weight_financials DOUBLE PRECISION NOT NULL,
+    weight_safety DOUBLE PRECISION NOT NULL,
+    weight_capacity DOUBLE PRECISION NOT NULL,
+    weight_past_performance DOUBLE PRECISION NOT NULL,
+    min_current_ratio DOUBLE PRECISION,
+    max_debt_to_equity DOUBLE PRECISION,
+    max_emr DOUBLE PRECISION,
+    min_capacity_ratio DOUBLE PRECISION,
+    min_past_performance DOUBLE PRECISION,
+    min_score DOUBLE PRECISION,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT prequal_model_name_not_empty CHECK (name != ''),
+    CONSTRAINT prequal_model_weights_positive CHECK (
+        weight_financials >= 0 AND weight_safety >= 0 AND weight_capacity >= 0
+        AND weight_past_performance >= 0
+        AND weight_financials + weight_safety + weight_capacity + weight_past_performance > 0
+    ),
+    CONSTRAINT prequal_model_name_unique UNIQUE (org_id, name)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_prequal_models_default
+    ON prequal_models(org_id) WHERE is_default;
@@ -0,0 +1,27 @@
+-- Migration: Default Assumptions
+
+-- Values merged into calculation requests that leave them out. A row with
+-- no user is the organization's default; a row with a user overrides it
+-- for that member. Unset values fall through to the next layer, and
+-- finally to the platform defaults.
+CREATE TABLE IF NOT EXISTS assumption_defaults (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    labor_rate DOUBLE PRECISION,
+    waste_factor DOUBLE PRECISION,
+    markup_percentage DOUBLE PRECISION,
+    design_code VARCHAR(50),
+    currency CHAR(3),
+    updated_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT assumption_defaults_labor_rate_range CHECK (labor_rate IS NULL OR labor_rate BETWEEN 10 AND 200),
+    CONSTRAINT assumption_defaults_waste_range CHECK (waste_factor IS NULL OR waste_factor BETWEEN 1 AND 2),
+    CONSTRAINT assumption_defaults_markup_range CHECK (markup_percentage IS NULL OR markup_percentage BETWEEN 5 AND 50)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_assumption_defaults_org
+    ON assumption_defaults(org_id) WHERE user_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_assumption_defaults_user
+    ON assumption_defaults(org_id, user_id) WHERE user_id IS NOT NULL;
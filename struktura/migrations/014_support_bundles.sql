@@ -0,0 +1,21 @@
+-- Migration: Support Bundles
+
+-- Failed calculations captured at the caller's request, for support to
+-- reproduce. Inputs are stored with personal data and credentials already
+-- redacted; the id is what users quote in bug reports.
+CREATE TABLE IF NOT EXISTS support_bundles (
+    id UUID PRIMARY KEY,
+    request_id VARCHAR(100),
+    tier VARCHAR(20) NOT NULL,
+    path VARCHAR(500) NOT NULL,
+    calculator_id VARCHAR(100),
+    calculator_version VARCHAR(20),
+    app_version VARCHAR(20) NOT NULL,
+    input JSONB NOT NULL,
+    status SMALLINT NOT NULL,
+    response JSONB NOT NULL,
+    error_chain TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_support_bundles_created ON support_bundles(created_at);
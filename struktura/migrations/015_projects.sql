@@ -0,0 +1,37 @@
+-- Migration: Project Workspaces
+
+-- Named projects belonging to a user within their organization, and the
+-- calculations saved into them. Each calculation keeps the parameters it
+-- was run with and the response of its latest run, tagged with the
+-- calculator's formula version so re-runs can show what changed.
+CREATE TABLE IF NOT EXISTS projects (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(200) NOT NULL,
+    description TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT project_name_not_empty CHECK (name != ''),
+    CONSTRAINT project_name_unique UNIQUE (user_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_projects_user ON projects(org_id, user_id);
+
+CREATE TABLE IF NOT EXISTS project_calculations (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    tier VARCHAR(20) NOT NULL,
+    calculation_type VARCHAR(100) NOT NULL,
+    label VARCHAR(200),
+    parameters JSONB NOT NULL,
+    response JSONB NOT NULL,
+    calculator_version VARCHAR(20) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_run_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT project_calculation_tier CHECK (tier IN ('beginner', 'engineer', 'contractor'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_calculations_project ON project_calculations(project_id);
@@ -0,0 +1,12 @@
+-- Migration: Password Rotation
+
+-- When each password was last set, so organizations can require rotation.
+-- Existing passwords start their clock now rather than expiring at once.
+ALTER TABLE users ADD COLUMN IF NOT EXISTS password_changed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP;
+
+-- Maximum password age for the organization's members; NULL never expires
+ALTER TABLE organizations ADD COLUMN IF NOT EXISTS password_max_age_days INTEGER;
+
+ALTER TABLE organizations DROP CONSTRAINT IF EXISTS organization_password_max_age_positive;
+ALTER TABLE organizations ADD CONSTRAINT organization_password_max_age_positive
+    CHECK (password_max_age_days IS NULL OR password_max_age_days > 0);
@@ -0,0 +1,31 @@
+-- Migration: Calculation History
+
+-- One row per calculation a signed-in user ran, whatever its outcome, so
+-- firms can show what was computed and when. Inputs are kept only as a
+-- SHA-256 of their canonical JSON. Rows are never purged by the cleanup
+-- sweep: they are the audit trail.
+CREATE TABLE IF NOT EXISTS calculation_history (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    org_id UUID NOT NULL REFERENCES organizations(id),
+    tier VARCHAR(20) NOT NULL,
+    path VARCHAR(255) NOT NULL,
+    calculator_id VARCHAR(100),
+    category VARCHAR(100),
+    parameters_hash CHAR(64) NOT NULL,
+    duration_ms INTEGER NOT NULL,
+    status SMALLINT NOT NULL,
+    outcome VARCHAR(10) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT calculation_history_outcome CHECK (outcome IN ('success', 'rejected', 'failed')),
+    CONSTRAINT calculation_history_duration_non_negative CHECK (duration_ms >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_calculation_history_user_created ON calculation_history(user_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_calculation_history_org_created ON calculation_history(org_id, created_at DESC);
+
+DROP TRIGGER IF EXISTS calculation_history_inherit_org ON calculation_history;
+CREATE TRIGGER calculation_history_inherit_org
+    BEFORE INSERT ON calculation_history
+    FOR EACH ROW EXECUTE FUNCTION inherit_user_org();
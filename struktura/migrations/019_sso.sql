@@ -0,0 +1,33 @@
+-- Migration: Enterprise Single Sign-On
+
+-- OpenID Connect identity providers, each tied to the organization its
+-- users are provisioned into. The slug names the connection in sign-in URLs.
+CREATE TABLE IF NOT EXISTS sso_connections (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    slug VARCHAR(50) NOT NULL UNIQUE,
+    issuer VARCHAR(255) NOT NULL,
+    client_id VARCHAR(255) NOT NULL,
+    client_secret TEXT NOT NULL,
+    redirect_uri VARCHAR(500) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT sso_connection_slug_format CHECK (slug ~ '^[a-z0-9-]+$')
+);
+
+CREATE INDEX IF NOT EXISTS idx_sso_connections_org ON sso_connections(org_id);
+
+-- Which account an IdP subject signs in as; created on first sign-in
+CREATE TABLE IF NOT EXISTS sso_identities (
+    connection_id UUID NOT NULL REFERENCES sso_connections(id) ON DELETE CASCADE,
+    subject VARCHAR(255) NOT NULL,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    email VARCHAR(255),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_login_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (connection_id, subject)
+);
+
+CREATE INDEX IF NOT EXISTS idx_sso_identities_user ON sso_identities(user_id);
@@ -0,0 +1,53 @@
+-- Migration: SCIM Provisioning
+
+-- Identity provider attributes for accounts an organization provisions
+-- over SCIM. `scim_user_name` is the IdP's userName (usually an email);
+-- the platform username is derived from it. Deactivated accounts keep
+-- their data but cannot sign in; deprovisioned ones (deleted by the IdP)
+-- are also hidden from SCIM.
+ALTER TABLE users ADD COLUMN IF NOT EXISTS active BOOLEAN NOT NULL DEFAULT TRUE;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS deprovisioned_at TIMESTAMP WITH TIME ZONE;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS email VARCHAR(255);
+ALTER TABLE users ADD COLUMN IF NOT EXISTS display_name VARCHAR(255);
+ALTER TABLE users ADD COLUMN IF NOT EXISTS scim_user_name VARCHAR(255);
+ALTER TABLE users ADD COLUMN IF NOT EXISTS external_id VARCHAR(255);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_users_org_scim_user_name ON users(org_id, lower(scim_user_name))
+    WHERE scim_user_name IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_users_org_email ON users(org_id, lower(email)) WHERE email IS NOT NULL;
+
+-- Bearer tokens an organization's IdP provisions with. Only hashes are kept.
+CREATE TABLE IF NOT EXISTS scim_tokens (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    token_hash CHAR(64) NOT NULL UNIQUE,
+    description VARCHAR(100),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_used_at TIMESTAMP WITH TIME ZONE,
+    revoked_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX IF NOT EXISTS idx_scim_tokens_org ON scim_tokens(org_id);
+
+-- Groups pushed by the IdP, scoped to the organization
+CREATE TABLE IF NOT EXISTS org_groups (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    display_name VARCHAR(255) NOT NULL,
+    external_id VARCHAR(255),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT org_group_name_not_empty CHECK (display_name != '')
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_org_groups_name ON org_groups(org_id, lower(display_name));
+
+CREATE TABLE IF NOT EXISTS org_group_members (
+    group_id UUID NOT NULL REFERENCES org_groups(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+
+    PRIMARY KEY (group_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_org_group_members_user ON org_group_members(user_id);
@@ -0,0 +1,34 @@
+-- Migration: Calculation Jobs
+
+-- Calculations queued with `"async": true`, worked by every instance's
+-- job workers (claimed with FOR UPDATE SKIP LOCKED). `result` holds the
+-- response of a succeeded job; `error` and `http_status` what the request
+-- would have received had it failed synchronously. Finished jobs are
+-- deleted by the cleanup sweep after JOB_RETENTION_DAYS.
+CREATE TABLE IF NOT EXISTS calculation_jobs (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    org_id UUID NOT NULL REFERENCES organizations(id),
+    tier VARCHAR(20) NOT NULL,
+    calculation_type VARCHAR(100) NOT NULL,
+    parameters JSONB NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'queued',
+    result JSONB,
+    error JSONB,
+    http_status SMALLINT,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    started_at TIMESTAMP WITH TIME ZONE,
+    finished_at TIMESTAMP WITH TIME ZONE,
+
+    CONSTRAINT calculation_jobs_status CHECK (status IN ('queued', 'running', 'succeeded', 'failed'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_calculation_jobs_queued ON calculation_jobs(created_at) WHERE status = 'queued';
+CREATE INDEX IF NOT EXISTS idx_calculation_jobs_user_created ON calculation_jobs(user_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_calculation_jobs_finished ON calculation_jobs(finished_at) WHERE finished_at IS NOT NULL;
+
+DROP TRIGGER IF EXISTS calculation_jobs_inherit_org ON calculation_jobs;
+CREATE TRIGGER calculation_jobs_inherit_org
+    BEFORE INSERT ON calculation_jobs
+    FOR EACH ROW EXECUTE FUNCTION inherit_user_org();
@@ -0,0 +1,48 @@
+-- Migration: Public Status
+
+-- Periodic component probes, one row per component per probe, summarised
+-- into daily uptime on the status page. Kept for 90 days.
+CREATE TABLE IF NOT EXISTS status_checks (
+    id BIGSERIAL PRIMARY KEY,
+    component VARCHAR(50) NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    latency_ms INTEGER,
+    checked_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT status_checks_status CHECK (status IN ('operational', 'degraded', 'outage', 'maintenance'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_status_checks_component_checked ON status_checks(component, checked_at DESC);
+
+-- Incidents posted by admins
+CREATE TABLE IF NOT EXISTS status_incidents (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    title VARCHAR(200) NOT NULL,
+    message TEXT NOT NULL,
+    impact VARCHAR(20) NOT NULL DEFAULT 'minor',
+    status VARCHAR(20) NOT NULL DEFAULT 'investigating',
+    components TEXT[] NOT NULL DEFAULT '{}',
+    started_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    resolved_at TIMESTAMP WITH TIME ZONE,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT status_incidents_impact CHECK (impact IN ('minor', 'major', 'critical')),
+    CONSTRAINT status_incidents_status CHECK (status IN ('investigating', 'identified', 'monitoring', 'resolved'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_status_incidents_started ON status_incidents(started_at DESC);
+
+-- Planned maintenance announced ahead of time
+CREATE TABLE IF NOT EXISTS status_maintenance_windows (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    title VARCHAR(200) NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    components TEXT[] NOT NULL DEFAULT '{}',
+    starts_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    ends_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT status_maintenance_window_order CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_status_maintenance_windows_ends ON status_maintenance_windows(ends_at);
@@ -0,0 +1,26 @@
+-- Migration: Email Verification and Password Reset
+
+-- When the account's email address was confirmed. Password resets are only
+-- mailed to confirmed addresses; changing the address clears it.
+ALTER TABLE users ADD COLUMN IF NOT EXISTS email_verified_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX IF NOT EXISTS idx_users_verified_email ON users(lower(email)) WHERE email_verified_at IS NOT NULL;
+
+-- One-time tokens mailed to users. Only hashes are kept; a token is spent
+-- by setting `used_at`. Expired tokens are deleted by the cleanup sweep.
+CREATE TABLE IF NOT EXISTS email_tokens (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    purpose VARCHAR(20) NOT NULL,
+    -- Address the token was mailed to; verification confirms this one
+    email VARCHAR(255) NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    used_at TIMESTAMP WITH TIME ZONE,
+
+    CONSTRAINT email_tokens_purpose CHECK (purpose IN ('verify_email', 'reset_password'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_email_tokens_user ON email_tokens(user_id, purpose);
+CREATE INDEX IF NOT EXISTS idx_email_tokens_expires ON email_tokens(expires_at);
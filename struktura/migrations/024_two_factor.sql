@@ -0,0 +1,23 @@
+-- Migration: Two-Factor Authentication (TOTP)
+
+-- The confirmed authenticator secret (base32) and one still awaiting its
+-- first code. `totp_last_step` is the newest 30-second step a code was
+-- accepted for; older and equal steps are refused so codes can't be replayed.
+ALTER TABLE users ADD COLUMN IF NOT EXISTS totp_secret VARCHAR(64);
+ALTER TABLE users ADD COLUMN IF NOT EXISTS totp_pending_secret VARCHAR(64);
+ALTER TABLE users ADD COLUMN IF NOT EXISTS totp_enabled_at TIMESTAMP WITH TIME ZONE;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS totp_last_step BIGINT;
+-- Whether password logins must also send a code; set in the profile
+ALTER TABLE users ADD COLUMN IF NOT EXISTS totp_required BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Single-use codes for a lost authenticator. Only SHA-256 hashes (salted
+-- with the user id) are kept; a code is spent by setting `used_at`.
+CREATE TABLE IF NOT EXISTS recovery_codes (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    code_hash VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    used_at TIMESTAMP WITH TIME ZONE,
+
+    UNIQUE (user_id, code_hash)
+);
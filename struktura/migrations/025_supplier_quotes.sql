@@ -0,0 +1,25 @@
+-- Migration: Supplier Quotes
+
+-- Prices a supplier has committed to for a limited window, kept per
+-- organization. While open they replace scouted prices for that
+-- organization's calculations only.
+CREATE TABLE IF NOT EXISTS supplier_quotes (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    material_code VARCHAR(100) NOT NULL,
+    -- Full material and supplier records as accepted by the pricing API
+    material JSONB NOT NULL,
+    supplier JSONB NOT NULL,
+    price DOUBLE PRECISION NOT NULL,
+    currency CHAR(3) NOT NULL,
+    valid_from TIMESTAMP WITH TIME ZONE NOT NULL,
+    valid_until TIMESTAMP WITH TIME ZONE NOT NULL,
+    notes TEXT,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT supplier_quote_non_negative CHECK (price >= 0),
+    CONSTRAINT supplier_quote_window CHECK (valid_until > valid_from)
+);
+
+CREATE INDEX IF NOT EXISTS idx_supplier_quotes_lookup ON supplier_quotes(org_id, material_code, valid_until);
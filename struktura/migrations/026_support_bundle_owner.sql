@@ -0,0 +1,10 @@
+-- Migration: Support Bundle Owners
+
+-- Bundles belong to the user (and organization) whose request failed, so
+-- only they and admins can download them. Bundles captured before this, or
+-- for anonymous trial requests, have no owner and are admin-only.
+ALTER TABLE support_bundles
+    ADD COLUMN IF NOT EXISTS user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    ADD COLUMN IF NOT EXISTS org_id UUID REFERENCES organizations(id) ON DELETE CASCADE;
+
+CREATE INDEX IF NOT EXISTS idx_support_bundles_owner ON support_bundles(org_id, user_id);
@@ -0,0 +1,6 @@
+-- Migration: Calculation Job Request URI
+
+-- The path and query of the request that queued a job, so options such as
+-- `?trace=true` and `?audience=` apply when a worker runs it. Jobs queued
+-- before this column existed run with the defaults.
+ALTER TABLE calculation_jobs ADD COLUMN IF NOT EXISTS request_uri TEXT;
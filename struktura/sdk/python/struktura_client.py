@@ -0,0 +1,1502 @@
+"""Struktura API client.
+
+Generated by `cargo run --bin generate_sdk` - do not edit by hand.
+Source: calculator registries and serde output of the Rust API models.
+"""
+
+import json
+import urllib.error
+import urllib.request
+from typing import Any, Dict, List, Literal, NotRequired, Optional, TypedDict
+
+Tier = Literal["beginner", "engineer", "contractor"]
+
+BeginnerCalculationType = Literal[
+    "acoustic_treatment",
+    "baseboard",
+    "compost_bin",
+    "concrete_slab",
+    "crown_molding",
+    "deck",
+    "drain_line",
+    "drip_irrigation",
+    "driveway",
+    "drop_ceiling",
+    "drywall_ceiling",
+    "drywall_count",
+    "electrical_service_load",
+    "fence",
+    "gravel_path",
+    "hardwood_flooring",
+    "hvac_sizing",
+    "insulation",
+    "laminate_flooring",
+    "lawn_seed",
+    "mulch_bed",
+    "paint_coverage",
+    "patio",
+    "pergola",
+    "pipe_run",
+    "planter_box",
+    "raised_garden_bed",
+    "recessed_lighting",
+    "retaining_wall",
+    "shed_foundation",
+    "sod",
+    "sprinkler_coverage",
+    "stepping_stone",
+    "tile_count",
+    "track_lighting",
+    "wall_framing",
+    "wallpaper",
+]
+
+EngineerCalculationType = Literal[
+    "beam_design",
+    "capacity_planning",
+    "column_design",
+    "compressor_sizing",
+    "condensation_risk",
+    "connection_design",
+    "conveyor_belt",
+    "culvert_sizing",
+    "elevator_traffic",
+    "facility_layout",
+    "foundation_design",
+    "generator_ups_sizing",
+    "grounding_lightning_protection",
+    "heat_exchanger",
+    "hvac_load_calculation",
+    "inventory_optimization",
+    "lateral_load_analysis",
+    "moment_frame_design",
+    "pavement_design",
+    "pile_foundation",
+    "piping_pressure_drop",
+    "process_capability",
+    "production_line_balancing",
+    "pump_sizing",
+    "rational_method_runoff",
+    "refrigeration_cycle",
+    "retaining_wall",
+    "seismic_load",
+    "settlement_analysis",
+    "slab_design",
+    "slope_stability",
+    "soil_bearing_capacity",
+    "soil_compaction",
+    "sprinkler_hydraulic",
+    "storm_sewer_sizing",
+    "thermal_expansion",
+    "tower_crane",
+    "truss_analysis",
+    "valve_sizing",
+    "water_hammer",
+    "wind_load",
+    "work_sampling",
+]
+
+ContractorCalculationType = Literal[
+    "bid_bond",
+    "bid_pricing",
+    "budget_forecast",
+    "cash_flow_analysis",
+    "change_order",
+    "contingency_planning",
+    "contract_estimation",
+    "cost_breakdown",
+    "critical_path",
+    "delay_analysis",
+    "equipment_cost",
+    "fuel_emissions",
+    "gantt_chart",
+    "labor_cost",
+    "material_cost",
+    "milestone_tracking",
+    "overhead",
+    "profit_margin",
+    "progress_tracking",
+    "project_closeout",
+    "quality_control",
+    "quantity_takeoff",
+    "resource_allocation",
+    "resource_leveling",
+    "risk_assessment",
+    "safety_planning",
+    "schedule_optimization",
+    "subcontractor_evaluation",
+    "time_cost_tradeoff",
+    "travel_cost",
+    "value_engineering",
+]
+
+
+class BeginnerCalculationRequestParameters(TypedDict):
+    additional: NotRequired[Dict[str, float]]
+    height: float
+    length: float
+    width: float
+
+
+class BeginnerCalculationRequest(TypedDict):
+    calculation_type: BeginnerCalculationType
+    parameters: BeginnerCalculationRequestParameters
+
+
+class BeginnerCalculationResponseResult(TypedDict):
+    label: str
+    unit: str
+    value: float
+
+
+class BeginnerCalculationResponse(TypedDict):
+    calculation_type: str
+    results: List[BeginnerCalculationResponseResult]
+    warnings: List[str]
+
+
+class BeginnerCatalogueCalculatorExampleRequestParameters(TypedDict):
+    additional: NotRequired[Dict[str, float]]
+    height: float
+    length: float
+    width: float
+
+
+class BeginnerCatalogueCalculatorExampleRequest(TypedDict):
+    calculation_type: str
+    parameters: BeginnerCatalogueCalculatorExampleRequestParameters
+
+
+class BeginnerCatalogueCalculatorExample(TypedDict):
+    expected_results: Dict[str, float]
+    name: str
+    request: BeginnerCatalogueCalculatorExampleRequest
+
+
+class BeginnerCatalogueCalculatorParameter(TypedDict):
+    data_type: str
+    description: str
+    max_value: float
+    min_value: float
+    name: str
+    path: str
+    required: bool
+    typical_range: List[float]
+    unit: str
+
+
+class BeginnerCatalogueCalculator(TypedDict):
+    category: str
+    description: str
+    examples: List[BeginnerCatalogueCalculatorExample]
+    id: str
+    name: str
+    optional_parameters: List[str]
+    parameters: List[BeginnerCatalogueCalculatorParameter]
+    required_parameters: List[str]
+
+
+class BeginnerCatalogueCategory(TypedDict):
+    description: str
+    icon: str
+    id: str
+    name: str
+
+
+class BeginnerCatalogueChangelogRecessedLightingItem(TypedDict):
+    date: str
+    impact: str
+    note: str
+    summary: str
+    version: str
+
+
+class BeginnerCatalogueChangelog(TypedDict):
+    recessed_lighting: List[BeginnerCatalogueChangelogRecessedLightingItem]
+
+
+class BeginnerCatalogue(TypedDict):
+    calculators: List[BeginnerCatalogueCalculator]
+    categories: List[BeginnerCatalogueCategory]
+    changelog: BeginnerCatalogueChangelog
+    disclaimer: str
+    version: str
+
+
+class EngineerCalculationRequestParametersLoads(TypedDict):
+    dead_load: float
+    live_load: float
+    load_combination: str
+    seismic_load: NotRequired[float]
+    shear_load: NotRequired[float]
+    tension_load: NotRequired[float]
+    wind_load: NotRequired[float]
+
+
+class EngineerCalculationRequestParametersMaterial(TypedDict):
+    compressive_strength: NotRequired[float]
+    density: NotRequired[float]
+    elastic_modulus: NotRequired[float]
+    material_type: str
+    thermal_expansion: NotRequired[float]
+    yield_strength: NotRequired[float]
+
+
+class EngineerCalculationRequestParametersSafetyFactors(TypedDict):
+    bearing: NotRequired[float]
+    dead_load_factor: float
+    importance_factor: float
+    live_load_factor: float
+    material_reduction_factor: float
+    overturning: NotRequired[float]
+
+
+class EngineerCalculationRequestParameters(TypedDict):
+    additional: NotRequired[Dict[str, float]]
+    dimensions: Dict[str, float]
+    exposure_class: NotRequired[str]
+    loads: NotRequired[EngineerCalculationRequestParametersLoads]
+    material: NotRequired[EngineerCalculationRequestParametersMaterial]
+    safety_factors: NotRequired[EngineerCalculationRequestParametersSafetyFactors]
+
+
+class EngineerCalculationRequest(TypedDict):
+    calculation_type: EngineerCalculationType
+    parameters: EngineerCalculationRequestParameters
+
+
+class EngineerCalculationResponseAnalysisStoryForce(TypedDict):
+    elevation: float
+    force: float
+    level: float
+    shear: float
+    weight: float
+
+
+class EngineerCalculationResponseAnalysisZonePressure(TypedDict):
+    elevation: NotRequired[float]
+    external_coefficient: float
+    pressure: float
+    zone: str
+
+
+class EngineerCalculationResponseAnalysis(TypedDict):
+    governing_limit_state: str
+    max_deflection: float
+    max_moment: float
+    max_shear: float
+    story_forces: NotRequired[List[EngineerCalculationResponseAnalysisStoryForce]]
+    utilization_ratio: float
+    zone_pressures: NotRequired[List[EngineerCalculationResponseAnalysisZonePressure]]
+
+
+class EngineerCalculationResponseCalculationMetadata(TypedDict):
+    calculator_version: str
+    design_code_used: str
+    requires_pe_review: bool
+    timestamp: str
+
+
+class EngineerCalculationResponseDataQualityFactor(TypedDict):
+    code: str
+    points: float
+    subject: str
+
+
+class EngineerCalculationResponseDataQuality(TypedDict):
+    factors: List[EngineerCalculationResponseDataQualityFactor]
+    score: float
+
+
+class EngineerCalculationResponseResult(TypedDict):
+    formatted_value: str
+    is_critical: bool
+    label: str
+    tolerance: NotRequired[float]
+    unit: str
+    value: float
+
+
+class EngineerCalculationResponseStructuredWarning(TypedDict):
+    affected_parameter: NotRequired[str]
+    code: str
+    message: str
+    severity: str
+
+
+class EngineerCalculationResponse(TypedDict):
+    analysis: NotRequired[EngineerCalculationResponseAnalysis]
+    calculation_metadata: EngineerCalculationResponseCalculationMetadata
+    calculation_type: str
+    compliance_notes: List[str]
+    data_quality: NotRequired[EngineerCalculationResponseDataQuality]
+    recommendations: List[str]
+    results: List[EngineerCalculationResponseResult]
+    structured_warnings: List[EngineerCalculationResponseStructuredWarning]
+    warnings: List[str]
+
+
+class EngineerCatalogueCalculatorExampleRequestParametersLoads(TypedDict):
+    dead_load: float
+    live_load: float
+    load_combination: str
+    seismic_load: NotRequired[float]
+    shear_load: NotRequired[float]
+    tension_load: NotRequired[float]
+    wind_load: NotRequired[float]
+
+
+class EngineerCatalogueCalculatorExampleRequestParametersMaterial(TypedDict):
+    compressive_strength: NotRequired[float]
+    density: NotRequired[float]
+    elastic_modulus: NotRequired[float]
+    material_type: str
+    thermal_expansion: NotRequired[float]
+    yield_strength: NotRequired[float]
+
+
+class EngineerCatalogueCalculatorExampleRequestParametersSafetyFactors(TypedDict):
+    bearing: NotRequired[float]
+    dead_load_factor: float
+    importance_factor: float
+    live_load_factor: float
+    material_reduction_factor: float
+    overturning: NotRequired[float]
+
+
+class EngineerCatalogueCalculatorExampleRequestParameters(TypedDict):
+    additional: NotRequired[Dict[str, float]]
+    dimensions: Dict[str, float]
+    exposure_class: NotRequired[str]
+    loads: NotRequired[EngineerCatalogueCalculatorExampleRequestParametersLoads]
+    material: NotRequired[EngineerCatalogueCalculatorExampleRequestParametersMaterial]
+    safety_factors: NotRequired[EngineerCatalogueCalculatorExampleRequestParametersSafetyFactors]
+
+
+class EngineerCatalogueCalculatorExampleRequest(TypedDict):
+    calculation_type: str
+    parameters: EngineerCatalogueCalculatorExampleRequestParameters
+
+
+class EngineerCatalogueCalculatorExample(TypedDict):
+    expected_results: Dict[str, float]
+    name: str
+    request: EngineerCatalogueCalculatorExampleRequest
+
+
+class EngineerCatalogueCalculatorParameter(TypedDict):
+    data_type: Any
+    default_value: NotRequired[float]
+    description: str
+    max_value: NotRequired[float]
+    min_value: NotRequired[float]
+    name: str
+    path: str
+    required: bool
+    typical_range: NotRequired[List[float]]
+    unit: str
+    validation_rules: NotRequired[List[str]]
+
+
+class EngineerCatalogueCalculatorWizardStepField(TypedDict):
+    path: str
+
+
+class EngineerCatalogueCalculatorWizardStep(TypedDict):
+    description: NotRequired[str]
+    fields: List[EngineerCatalogueCalculatorWizardStepField]
+    id: str
+    title: str
+
+
+class EngineerCatalogueCalculatorWizard(TypedDict):
+    steps: List[EngineerCatalogueCalculatorWizardStep]
+
+
+class EngineerCatalogueCalculator(TypedDict):
+    category: str
+    complexity_level: str
+    description: str
+    design_codes: List[str]
+    examples: NotRequired[List[EngineerCatalogueCalculatorExample]]
+    id: str
+    name: str
+    optional_parameters: List[str]
+    parameters: List[EngineerCatalogueCalculatorParameter]
+    required_parameters: List[str]
+    requires_pe_review: bool
+    typical_applications: List[Any]
+    wizard: NotRequired[EngineerCatalogueCalculatorWizard]
+
+
+class EngineerCatalogueCategory(TypedDict):
+    description: str
+    icon: str
+    id: str
+    name: str
+    requires_pe: bool
+
+
+class EngineerCatalogueChangelogBeamDesignItem(TypedDict):
+    date: str
+    impact: str
+    note: str
+    summary: str
+    version: str
+
+
+class EngineerCatalogueChangelogColumnDesignItem(TypedDict):
+    date: str
+    impact: str
+    note: str
+    summary: str
+    version: str
+
+
+class EngineerCatalogueChangelog(TypedDict):
+    beam_design: List[EngineerCatalogueChangelogBeamDesignItem]
+    column_design: List[EngineerCatalogueChangelogColumnDesignItem]
+
+
+class EngineerCatalogueSearchIndex(TypedDict):
+    keywords: Dict[str, List[str]]
+    tags: Dict[str, List[str]]
+
+
+class EngineerCatalogue(TypedDict):
+    calculators: List[EngineerCatalogueCalculator]
+    categories: List[EngineerCatalogueCategory]
+    changelog: EngineerCatalogueChangelog
+    disclaimer: str
+    search_index: EngineerCatalogueSearchIndex
+    version: str
+
+
+class ContractorCalculationRequestParametersLocation(TypedDict):
+    city: Optional[Any]
+    country_code: str
+    latitude: float
+    longitude: float
+    postal_code: Optional[Any]
+    region: Optional[Any]
+
+
+class ContractorCalculationRequestParametersMaterial(TypedDict):
+    material_type: str
+    unit_cost: NotRequired[float]
+    waste_factor: NotRequired[float]
+
+
+class ContractorCalculationRequestParametersOrigin(TypedDict):
+    city: Optional[Any]
+    country_code: str
+    latitude: float
+    longitude: float
+    postal_code: Optional[Any]
+    region: Optional[Any]
+
+
+class ContractorCalculationRequestParametersResources(TypedDict):
+    equipment_hours: float
+    labor_hours: float
+    material_quantity: NotRequired[float]
+    overhead: NotRequired[float]
+
+
+class ContractorCalculationRequestParametersSafetyFactors(TypedDict):
+    cost_factor: float
+    importance_factor: float
+    risk_reduction_factor: float
+    time_factor: float
+
+
+class ContractorCalculationRequestParameters(TypedDict):
+    additional: NotRequired[Dict[str, float]]
+    dimensions: Dict[str, float]
+    location: NotRequired[ContractorCalculationRequestParametersLocation]
+    material: NotRequired[ContractorCalculationRequestParametersMaterial]
+    origin: NotRequired[ContractorCalculationRequestParametersOrigin]
+    resources: NotRequired[ContractorCalculationRequestParametersResources]
+    safety_factors: NotRequired[ContractorCalculationRequestParametersSafetyFactors]
+
+
+class ContractorCalculationRequest(TypedDict):
+    calculation_type: ContractorCalculationType
+    parameters: ContractorCalculationRequestParameters
+
+
+class ContractorCalculationResponseAnalysis(TypedDict):
+    compliance_score: float
+    risk_level: float
+    total_cost: float
+    total_duration: float
+
+
+class ContractorCalculationResponseCalculationMetadata(TypedDict):
+    calculator_version: str
+    regulation_code_used: str
+    requires_certification_review: bool
+    timestamp: str
+
+
+class ContractorCalculationResponseResult(TypedDict):
+    formatted_value: str
+    is_critical: bool
+    label: str
+    tolerance: NotRequired[float]
+    unit: str
+    value: float
+
+
+class ContractorCalculationResponseStructuredWarning(TypedDict):
+    affected_parameter: str
+    code: str
+    message: str
+    severity: str
+
+
+class ContractorCalculationResponse(TypedDict):
+    analysis: NotRequired[ContractorCalculationResponseAnalysis]
+    calculation_metadata: ContractorCalculationResponseCalculationMetadata
+    calculation_type: str
+    compliance_notes: List[str]
+    recommendations: List[str]
+    results: List[ContractorCalculationResponseResult]
+    structured_warnings: NotRequired[List[ContractorCalculationResponseStructuredWarning]]
+    warnings: List[str]
+
+
+class ContractorCatalogueCalculatorExampleRequestParametersLocation(TypedDict):
+    city: Optional[Any]
+    country_code: str
+    latitude: float
+    longitude: float
+    postal_code: Optional[Any]
+    region: Optional[Any]
+
+
+class ContractorCatalogueCalculatorExampleRequestParametersMaterial(TypedDict):
+    material_type: str
+    unit_cost: NotRequired[float]
+    waste_factor: NotRequired[float]
+
+
+class ContractorCatalogueCalculatorExampleRequestParametersOrigin(TypedDict):
+    city: Optional[Any]
+    country_code: str
+    latitude: float
+    longitude: float
+    postal_code: Optional[Any]
+    region: Optional[Any]
+
+
+class ContractorCatalogueCalculatorExampleRequestParametersResources(TypedDict):
+    equipment_hours: float
+    labor_hours: float
+    material_quantity: NotRequired[float]
+    overhead: NotRequired[float]
+
+
+class ContractorCatalogueCalculatorExampleRequestParametersSafetyFactors(TypedDict):
+    cost_factor: float
+    importance_factor: float
+    risk_reduction_factor: float
+    time_factor: float
+
+
+class ContractorCatalogueCalculatorExampleRequestParameters(TypedDict):
+    additional: NotRequired[Dict[str, float]]
+    dimensions: Dict[str, float]
+    location: NotRequired[ContractorCatalogueCalculatorExampleRequestParametersLocation]
+    material: NotRequired[ContractorCatalogueCalculatorExampleRequestParametersMaterial]
+    origin: NotRequired[ContractorCatalogueCalculatorExampleRequestParametersOrigin]
+    resources: NotRequired[ContractorCatalogueCalculatorExampleRequestParametersResources]
+    safety_factors: NotRequired[ContractorCatalogueCalculatorExampleRequestParametersSafetyFactors]
+
+
+class ContractorCatalogueCalculatorExampleRequest(TypedDict):
+    calculation_type: str
+    parameters: ContractorCatalogueCalculatorExampleRequestParameters
+
+
+class ContractorCatalogueCalculatorExample(TypedDict):
+    expected_results: Dict[str, float]
+    name: str
+    request: ContractorCatalogueCalculatorExampleRequest
+
+
+class ContractorCatalogueCalculatorParameter(TypedDict):
+    data_type: str
+    default_value: NotRequired[float]
+    description: str
+    max_value: NotRequired[float]
+    min_value: NotRequired[float]
+    name: str
+    path: str
+    required: bool
+    typical_range: NotRequired[List[float]]
+    unit: str
+    validation_rules: NotRequired[List[str]]
+
+
+class ContractorCatalogueCalculator(TypedDict):
+    category: str
+    complexity_level: str
+    description: str
+    examples: List[ContractorCatalogueCalculatorExample]
+    id: str
+    name: str
+    optional_parameters: List[str]
+    parameters: List[ContractorCatalogueCalculatorParameter]
+    regulation_codes: List[str]
+    required_parameters: List[str]
+    requires_certification_review: bool
+    typical_applications: List[Any]
+
+
+class ContractorCatalogueCategory(TypedDict):
+    description: str
+    icon: str
+    id: str
+    name: str
+    requires_certification: bool
+
+
+class ContractorCatalogueSearchIndex(TypedDict):
+    keywords: Dict[str, List[str]]
+    tags: Dict[str, List[str]]
+
+
+class ContractorCatalogue(TypedDict):
+    calculators: List[ContractorCatalogueCalculator]
+    categories: List[ContractorCatalogueCategory]
+    disclaimer: str
+    search_index: ContractorCatalogueSearchIndex
+    version: str
+
+
+CALCULATORS: List[Dict[str, Any]] = [
+    {"tier": "beginner", "id": "acoustic_treatment", "name": "Acoustic Treatment & STC Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+        {"path": "additional.target_rt", "unit": "s", "required": False},
+        {"path": "additional.carpet_fraction", "unit": "", "required": False},
+        {"path": "additional.ceiling_tile_fraction", "unit": "", "required": False},
+        {"path": "additional.window_area", "unit": "m²", "required": False},
+        {"path": "additional.target_stc", "unit": "STC", "required": False},
+    ]},
+    {"tier": "beginner", "id": "baseboard", "name": "Baseboard Trim Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "compost_bin", "name": "Compost Bin Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "concrete_slab", "name": "Concrete Slab Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "crown_molding", "name": "Crown Molding Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "deck", "name": "Deck Builder", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "drain_line", "name": "Drain Line Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "mm", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "drip_irrigation", "name": "Drip Irrigation Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "driveway", "name": "Driveway Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "surface_type", "required": True},
+    ]},
+    {"tier": "beginner", "id": "drop_ceiling", "name": "Drop Ceiling Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "drywall_ceiling", "name": "Drywall Ceiling Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "drywall_count", "name": "Drywall Material Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "electrical_service_load", "name": "Electrical Service Load & Panel Schedule", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "floors", "required": True},
+        {"path": "additional.range_kw", "unit": "kW", "required": False},
+        {"path": "additional.dryer_kw", "unit": "kW", "required": False},
+        {"path": "additional.water_heater_kw", "unit": "kW", "required": False},
+        {"path": "additional.heating_kw", "unit": "kW", "required": False},
+        {"path": "additional.cooling_kw", "unit": "kW", "required": False},
+        {"path": "additional.ev_charger_kw", "unit": "kW", "required": False},
+    ]},
+    {"tier": "beginner", "id": "fence", "name": "Fence Builder", "parameters": [
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+        {"path": "width", "unit": "gates", "required": True},
+    ]},
+    {"tier": "beginner", "id": "gravel_path", "name": "Gravel Path Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "hardwood_flooring", "name": "Hardwood Flooring Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "hvac_sizing", "name": "HVAC Sizing & Ductwork Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "insulation", "name": "Wall/Ceiling Insulation Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "laminate_flooring", "name": "Laminate Flooring Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "lawn_seed", "name": "Lawn Seed Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "mulch_bed", "name": "Mulch Bed Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "paint_coverage", "name": "Paint Coverage Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "patio", "name": "Patio Paver Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "pergola", "name": "Pergola Builder", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "pipe_run", "name": "Water Supply Pipe Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "planter_box", "name": "Planter Box Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "raised_garden_bed", "name": "Raised Garden Bed Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "recessed_lighting", "name": "Recessed Lighting Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+        {"path": "additional.room_type", "unit": "", "required": False},
+        {"path": "additional.target_lux", "unit": "lux", "required": False},
+        {"path": "additional.fixture_lumens", "unit": "lumens", "required": False},
+        {"path": "additional.maintenance_factor", "unit": "", "required": False},
+    ]},
+    {"tier": "beginner", "id": "retaining_wall", "name": "Small Retaining Wall Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "shed_foundation", "name": "Shed Foundation Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "foundation_type", "required": True},
+    ]},
+    {"tier": "beginner", "id": "sod", "name": "Sod Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "sprinkler_coverage", "name": "Sprinkler Coverage Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "stepping_stone", "name": "Stepping Stone Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "tile_count", "name": "Tile Installation Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "track_lighting", "name": "Track Lighting Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+        {"path": "additional.room_type", "unit": "", "required": False},
+        {"path": "additional.target_lux", "unit": "lux", "required": False},
+        {"path": "additional.fixture_lumens", "unit": "lumens", "required": False},
+        {"path": "additional.maintenance_factor", "unit": "", "required": False},
+    ]},
+    {"tier": "beginner", "id": "wall_framing", "name": "Wall Framing Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "beginner", "id": "wallpaper", "name": "Wallpaper Calculator", "parameters": [
+        {"path": "width", "unit": "m", "required": True},
+        {"path": "length", "unit": "m", "required": True},
+        {"path": "height", "unit": "m", "required": True},
+    ]},
+    {"tier": "engineer", "id": "beam_design", "name": "Beam Design", "parameters": [
+        {"path": "dimensions.length", "unit": "m", "required": True},
+        {"path": "loads.dead_load", "unit": "kN/m", "required": True},
+        {"path": "loads.live_load", "unit": "kN/m", "required": True},
+        {"path": "material.yield_strength", "unit": "MPa", "required": False},
+        {"path": "material.compressive_strength", "unit": "MPa", "required": False},
+        {"path": "dimensions.width", "unit": "m", "required": False},
+        {"path": "dimensions.depth", "unit": "m", "required": False},
+        {"path": "additional.support_condition", "unit": "", "required": False},
+    ]},
+    {"tier": "engineer", "id": "capacity_planning", "name": "Capacity Planning Analysis", "parameters": [
+        {"path": "additional.demand_units", "unit": "units", "required": True},
+        {"path": "additional.period_days", "unit": "days", "required": True},
+        {"path": "additional.shifts_per_day", "unit": "shifts/day", "required": True},
+        {"path": "additional.hours_per_shift", "unit": "hours/shift", "required": True},
+        {"path": "additional.cycle_time", "unit": "minutes/cycle", "required": True},
+        {"path": "additional.output_per_cycle", "unit": "units/cycle", "required": True},
+        {"path": "additional.quality_yield", "unit": "%", "required": False},
+        {"path": "additional.target_utilization", "unit": "%", "required": False},
+    ]},
+    {"tier": "engineer", "id": "column_design", "name": "Column Design", "parameters": [
+        {"path": "dimensions.height", "unit": "m", "required": True},
+        {"path": "loads.dead_load", "unit": "kN", "required": True},
+        {"path": "loads.live_load", "unit": "kN", "required": True},
+        {"path": "material.yield_strength", "unit": "MPa", "required": False},
+        {"path": "additional.k_factor", "unit": "dimensionless", "required": False},
+        {"path": "additional.radius_of_gyration", "unit": "mm", "required": False},
+        {"path": "material.compressive_strength", "unit": "MPa", "required": False},
+        {"path": "additional.reinforcement_ratio", "unit": "dimensionless", "required": False},
+    ]},
+    {"tier": "engineer", "id": "compressor_sizing", "name": "Compressor Sizing", "parameters": [
+        {"path": "additional.p_in", "unit": "kPa", "required": True},
+        {"path": "additional.p_out", "unit": "kPa", "required": True},
+        {"path": "additional.flow_rate", "unit": "m³/min", "required": True},
+        {"path": "additional.gas_constant", "unit": "J/(kg·K)", "required": False},
+        {"path": "additional.k", "unit": "dimensionless", "required": False},
+        {"path": "additional.efficiency", "unit": "%", "required": False},
+    ]},
+    {"tier": "engineer", "id": "condensation_risk", "name": "Condensation Risk (Glaser Method)", "parameters": [
+        {"path": "extended_parameters.layers", "unit": "", "required": False},
+        {"path": "additional.insulation_thickness", "unit": "mm", "required": False},
+        {"path": "additional.insulation_conductivity", "unit": "W/(m·K)", "required": False},
+        {"path": "additional.vapour_control_sd", "unit": "m", "required": False},
+        {"path": "additional.indoor_temp", "unit": "°C", "required": True},
+        {"path": "additional.indoor_rh", "unit": "%", "required": True},
+        {"path": "additional.outdoor_temp", "unit": "°C", "required": True},
+        {"path": "additional.outdoor_rh", "unit": "%", "required": True},
+        {"path": "additional.cold_days", "unit": "days", "required": False},
+        {"path": "additional.accumulation_limit", "unit": "g/m²", "required": False},
+    ]},
+    {"tier": "engineer", "id": "connection_design", "name": "Bolted Connection Design", "parameters": [
+        {"path": "loads.shear_load", "unit": "kN", "required": True},
+        {"path": "loads.tension_load", "unit": "kN", "required": False},
+        {"path": "additional.bolt_grade", "unit": "", "required": False},
+        {"path": "dimensions.diameter", "unit": "mm", "required": False},
+        {"path": "additional.eccentricity", "unit": "mm", "required": False},
+        {"path": "additional.bolt_rows", "unit": "", "required": False},
+        {"path": "additional.bolt_columns", "unit": "", "required": False},
+        {"path": "additional.bolt_pitch", "unit": "mm", "required": False},
+        {"path": "additional.bolt_gauge", "unit": "mm", "required": False},
+    ]},
+    {"tier": "engineer", "id": "conveyor_belt", "name": "Belt Conveyor Design and Sizing", "parameters": [
+        {"path": "dimensions.length", "unit": "m", "required": True},
+        {"path": "dimensions.width", "unit": "m", "required": True},
+        {"path": "additional.belt_speed", "unit": "m/s", "required": True},
+        {"path": "material.density", "unit": "kg/m³", "required": False},
+        {"path": "additional.inclination_angle", "unit": "degrees", "required": False},
+        {"path": "additional.surcharge_angle", "unit": "degrees", "required": False},
+    ]},
+    {"tier": "engineer", "id": "culvert_sizing", "name": "Culvert Sizing (Inlet/Outlet Control)", "parameters": [
+        {"path": "additional.design_flow", "unit": "m³/s", "required": True},
+        {"path": "additional.allowable_headwater", "unit": "m", "required": True},
+        {"path": "dimensions.length", "unit": "m", "required": False},
+        {"path": "dimensions.diameter", "unit": "m", "required": False},
+        {"path": "additional.slope", "unit": "m/m", "required": False},
+        {"path": "additional.tailwater", "unit": "m", "required": False},
+        {"path": "additional.inlet_type", "unit": "", "required": False},
+        {"path": "additional.manning_n", "unit": "", "required": False},
+    ]},
+    {"tier": "engineer", "id": "elevator_traffic", "name": "Elevator Traffic Analysis", "parameters": [
+        {"path": "additional.floors", "unit": "floors", "required": True},
+        {"path": "additional.population", "unit": "persons", "required": True},
+        {"path": "dimensions.floor_height", "unit": "m", "required": True},
+        {"path": "additional.rated_load", "unit": "kg", "required": False},
+        {"path": "additional.car_speed", "unit": "m/s", "required": False},
+        {"path": "additional.acceleration", "unit": "m/s²", "required": False},
+        {"path": "additional.cars", "unit": "cars", "required": False},
+        {"path": "additional.door_open_time", "unit": "s", "required": False},
+        {"path": "additional.door_close_time", "unit": "s", "required": False},
+        {"path": "additional.transfer_time", "unit": "s", "required": False},
+        {"path": "additional.start_delay", "unit": "s", "required": False},
+        {"path": "additional.target_interval", "unit": "s", "required": False},
+        {"path": "additional.target_handling", "unit": "%", "required": False},
+    ]},
+    {"tier": "engineer", "id": "facility_layout", "name": "Facility Layout Optimization", "parameters": [
+        {"path": "additional.total_flow_distance", "unit": "m", "required": True},
+        {"path": "additional.num_departments", "unit": "departments", "required": True},
+        {"path": "additional.facility_area", "unit": "m²", "required": True},
+        {"path": "additional.target_efficiency", "unit": "%", "required": False},
+    ]},
+    {"tier": "engineer", "id": "foundation_design", "name": "Shallow Foundation Design", "parameters": [
+        {"path": "loads.dead_load", "unit": "kN", "required": True},
+        {"path": "additional.bearing_capacity", "unit": "kPa", "required": True},
+        {"path": "dimensions.depth", "unit": "m", "required": False},
+        {"path": "safety_factors.bearing", "unit": "dimensionless", "required": False},
+    ]},
+    {"tier": "engineer", "id": "generator_ups_sizing", "name": "Standby Generator and UPS Runtime", "parameters": [
+        {"path": "extended_parameters.loads", "unit": "", "required": False},
+        {"path": "additional.running_load", "unit": "kW", "required": False},
+        {"path": "additional.largest_motor", "unit": "kW", "required": False},
+        {"path": "additional.ups_load", "unit": "kW", "required": False},
+        {"path": "additional.generator_rating", "unit": "kW", "required": False},
+        {"path": "additional.loading_limit", "unit": "fraction", "required": False},
+        {"path": "additional.max_voltage_dip", "unit": "fraction", "required": False},
+        {"path": "additional.transient_reactance", "unit": "per unit", "required": False},
+        {"path": "additional.battery_capacity", "unit": "Ah", "required": False},
+        {"path": "additional.battery_voltage", "unit": "V", "required": False},
+        {"path": "additional.inverter_efficiency", "unit": "fraction", "required": False},
+        {"path": "additional.required_runtime", "unit": "min", "required": False},
+    ]},
+    {"tier": "engineer", "id": "grounding_lightning_protection", "name": "Grounding and Lightning Protection", "parameters": [
+        {"path": "additional.soil_resistivity", "unit": "Ω·m", "required": True},
+        {"path": "additional.rod_length", "unit": "m", "required": False},
+        {"path": "additional.rod_diameter", "unit": "m", "required": False},
+        {"path": "additional.rod_count", "unit": "rods", "required": False},
+        {"path": "additional.target_resistance", "unit": "Ω", "required": False},
+        {"path": "dimensions.grid_length", "unit": "m", "required": False},
+        {"path": "dimensions.grid_width", "unit": "m", "required": False},
+        {"path": "additional.grid_spacing", "unit": "m", "required": False},
+        {"path": "additional.burial_depth", "unit": "m", "required": False},
+        {"path": "dimensions.length", "unit": "m", "required": True},
+        {"path": "dimensions.width", "unit": "m", "required": True},
+        {"path": "dimensions.height", "unit": "m", "required": True},
+        {"path": "additional.air_terminal_height", "unit": "m", "required": False},
+    ]},
+    {"tier": "engineer", "id": "heat_exchanger", "name": "Heat Exchanger Design and Sizing", "parameters": [
+        {"path": "additional.t_hot_in", "unit": "°C", "required": True},
+        {"path": "additional.t_hot_out", "unit": "°C", "required": True},
+        {"path": "additional.t_cold_in", "unit": "°C", "required": True},
+        {"path": "additional.t_cold_out", "unit": "°C", "required": True},
+        {"path": "additional.mass_flow_hot", "unit": "kg/s", "required": True},
+        {"path": "additional.mass_flow_cold", "unit": "kg/s", "required": True},
+        {"path": "additional.u_value", "unit": "W/(m²·K)", "required": False},
+    ]},
+    {"tier": "engineer", "id": "hvac_load_calculation", "name": "HVAC Cooling/Heating Load Calculation", "parameters": [
+        {"path": "dimensions.area", "unit": "m²", "required": True},
+        {"path": "additional.outdoor_temp", "unit": "°C", "required": True},
+        {"path": "additional.indoor_temp", "unit": "°C", "required": True},
+        {"path": "additional.wall_u", "unit": "W/(m²·K)", "required": False},
+        {"path": "additional.window_ratio", "unit": "%", "required": False},
+        {"path": "additional.occupancy", "unit": "persons/m²", "required": False},
+        {"path": "additional.outdoor_rh", "unit": "%", "required": False},
+        {"path": "additional.indoor_rh", "unit": "%", "required": False},
+        {"path": "additional.altitude", "unit": "m", "required": False},
+        {"path": "additional.outdoor_airflow", "unit": "m³/h", "required": False},
+        {"path": "additional.return_airflow", "unit": "m³/h", "required": False},
+    ]},
+    {"tier": "engineer", "id": "inventory_optimization", "name": "Inventory Optimization (EOQ, ROP, Safety Stock)", "parameters": [
+        {"path": "additional.annual_demand", "unit": "units/year", "required": True},
+        {"path": "additional.ordering_cost", "unit": "USD/order", "required": True},
+        {"path": "additional.holding_cost_per_unit", "unit": "USD/unit/year", "required": True},
+        {"path": "additional.daily_demand", "unit": "units/day", "required": True},
+        {"path": "additional.lead_time_days", "unit": "days", "required": True},
+        {"path": "additional.safety_stock", "unit": "units", "required": False},
+    ]},
+    {"tier": "engineer", "id": "lateral_load_analysis", "name": "Lateral Load Analysis", "parameters": [
+        {"path": "dimensions.height", "unit": "m", "required": True},
+        {"path": "dimensions.width", "unit": "m", "required": True},
+        {"path": "additional.load_type", "unit": "", "required": True},
+        {"path": "loads.wind_load", "unit": "kPa", "required": True},
+        {"path": "additional.num_stories", "unit": "", "required": True},
+    ]},
+    {"tier": "engineer", "id": "moment_frame_design", "name": "Moment Frame Design", "parameters": [
+        {"path": "dimensions.height", "unit": "m", "required": True},
+        {"path": "additional.num_stories", "unit": "", "required": True},
+        {"path": "loads.seismic_load", "unit": "kN", "required": True},
+        {"path": "dimensions.width", "unit": "m", "required": True},
+    ]},
+    {"tier": "engineer", "id": "pavement_design", "name": "Flexible Pavement Design", "parameters": [
+        {"path": "additional.esal", "unit": "loadings", "required": True},
+        {"path": "additional.cbr", "unit": "%", "required": True},
+        {"path": "additional.reliability", "unit": "%", "required": False},
+        {"path": "additional.drainage_coeff", "unit": "dimensionless", "required": False},
+    ]},
+    {"tier": "engineer", "id": "pile_foundation", "name": "Pile Foundation Design", "parameters": [
+        {"path": "loads.dead_load", "unit": "kN", "required": True},
+        {"path": "loads.live_load", "unit": "kN", "required": False},
+        {"path": "dimensions.diameter", "unit": "m", "required": False},
+        {"path": "dimensions.length", "unit": "m", "required": False},
+        {"path": "extended_parameters.layers", "unit": "", "required": False},
+        {"path": "additional.undrained_shear_strength", "unit": "kPa", "required": False},
+        {"path": "additional.friction_angle", "unit": "degrees", "required": False},
+        {"path": "additional.unit_weight", "unit": "kN/m³", "required": False},
+        {"path": "additional.water_table_depth", "unit": "m", "required": False},
+        {"path": "additional.earth_pressure_coefficient", "unit": "dimensionless", "required": False},
+        {"path": "additional.pile_spacing", "unit": "m", "required": False},
+        {"path": "additional.pile_rows", "unit": "", "required": False},
+        {"path": "additional.pile_columns", "unit": "", "required": False},
+        {"path": "additional.allowable_settlement", "unit": "mm", "required": False},
+        {"path": "safety_factors.bearing", "unit": "dimensionless", "required": False},
+    ]},
+    {"tier": "engineer", "id": "piping_pressure_drop", "name": "Piping Pressure Drop Calculation", "parameters": [
+        {"path": "dimensions.length", "unit": "m", "required": True},
+        {"path": "dimensions.diameter", "unit": "m", "required": True},
+        {"path": "additional.flow_rate", "unit": "m³/s", "required": True},
+        {"path": "material.density", "unit": "kg/m³", "required": False},
+        {"path": "additional.viscosity", "unit": "Pa·s", "required": False},
+        {"path": "additional.roughness", "unit": "m", "required": False},
+    ]},
+    {"tier": "engineer", "id": "process_capability", "name": "Process Capability Analysis (Cp, Cpk)", "parameters": [
+        {"path": "additional.mean", "unit": "", "required": True},
+        {"path": "additional.std_dev", "unit": "", "required": True},
+        {"path": "additional.lower_spec", "unit": "", "required": True},
+        {"path": "additional.upper_spec", "unit": "", "required": True},
+    ]},
+    {"tier": "engineer", "id": "production_line_balancing", "name": "Production Line Balancing Analysis", "parameters": [
+        {"path": "additional.num_workstations", "unit": "stations", "required": False},
+        {"path": "additional.desired_output_per_day", "unit": "units/day", "required": True},
+        {"path": "additional.available_time_per_day", "unit": "minutes", "required": True},
+        {"path": "additional.total_task_time", "unit": "minutes", "required": True},
+        {"path": "additional.longest_task_time", "unit": "minutes", "required": False},
+    ]},
+    {"tier": "engineer", "id": "pump_sizing", "name": "Pump Sizing and Selection", "parameters": [
+        {"path": "additional.flow_rate", "unit": "m³/h", "required": True},
+        {"path": "additional.total_head", "unit": "m", "required": True},
+        {"path": "material.density", "unit": "kg/m³", "required": False},
+        {"path": "additional.viscosity", "unit": "cP", "required": False},
+        {"path": "additional.pump_efficiency", "unit": "%", "required": False},
+        {"path": "additional.npsh_available", "unit": "m", "required": False},
+    ]},
+    {"tier": "engineer", "id": "rational_method_runoff", "name": "Rational Method Peak Runoff", "parameters": [
+        {"path": "additional.drainage_area", "unit": "ha", "required": True},
+        {"path": "additional.runoff_coefficient", "unit": "dimensionless", "required": False},
+        {"path": "extended_parameters.subareas", "unit": "", "required": False},
+        {"path": "additional.time_of_concentration", "unit": "min", "required": False},
+        {"path": "additional.flow_length", "unit": "m", "required": False},
+        {"path": "additional.watershed_slope", "unit": "m/m", "required": False},
+        {"path": "additional.rainfall_intensity", "unit": "mm/h", "required": False},
+        {"path": "additional.idf_a", "unit": "mm/h·min^n", "required": False},
+        {"path": "additional.idf_b", "unit": "min", "required": False},
+        {"path": "additional.idf_n", "unit": "dimensionless", "required": False},
+        {"path": "additional.return_period", "unit": "years", "required": False},
+    ]},
+    {"tier": "engineer", "id": "refrigeration_cycle", "name": "Refrigeration Cycle Analysis", "parameters": [
+        {"path": "additional.t_evap", "unit": "°C", "required": True},
+        {"path": "additional.t_cond", "unit": "°C", "required": True},
+        {"path": "material.material_type", "unit": "", "required": False},
+        {"path": "additional.cooling_capacity", "unit": "kW", "required": True},
+        {"path": "additional.isentropic_eff", "unit": "%", "required": False},
+    ]},
+    {"tier": "engineer", "id": "retaining_wall", "name": "Retaining Wall Design", "parameters": [
+        {"path": "dimensions.height", "unit": "m", "required": True},
+        {"path": "additional.friction_angle", "unit": "degrees", "required": True},
+        {"path": "additional.soil_unit_weight", "unit": "kN/m³", "required": False},
+        {"path": "loads.live_load", "unit": "kPa", "required": False},
+        {"path": "material.compressive_strength", "unit": "MPa", "required": False},
+        {"path": "safety_factors.overturning", "unit": "dimensionless", "required": False},
+    ]},
+    {"tier": "engineer", "id": "seismic_load", "name": "Seismic Base Shear (ASCE 7 ELF)", "parameters": [
+        {"path": "dimensions.height", "unit": "m", "required": True},
+        {"path": "additional.ss", "unit": "g", "required": True},
+        {"path": "additional.s1", "unit": "g", "required": True},
+        {"path": "extended_parameters.site_class", "unit": "", "required": False},
+        {"path": "additional.response_modification", "unit": "dimensionless", "required": True},
+        {"path": "additional.importance_factor", "unit": "dimensionless", "required": False},
+        {"path": "additional.seismic_weight", "unit": "kN", "required": True},
+        {"path": "additional.period", "unit": "s", "required": False},
+        {"path": "additional.long_period_transition", "unit": "s", "required": False},
+        {"path": "additional.num_stories", "unit": "", "required": False},
+    ]},
+    {"tier": "engineer", "id": "settlement_analysis", "name": "Consolidation Settlement Analysis", "parameters": [
+        {"path": "loads.dead_load", "unit": "kPa", "required": True},
+        {"path": "dimensions.thickness", "unit": "m", "required": True},
+        {"path": "additional.cc", "unit": "dimensionless", "required": True},
+        {"path": "additional.e0", "unit": "dimensionless", "required": True},
+        {"path": "additional.pc", "unit": "kPa", "required": False},
+    ]},
+    {"tier": "engineer", "id": "slab_design", "name": "Concrete Slab Design", "parameters": [
+        {"path": "dimensions.length", "unit": "m", "required": True},
+        {"path": "loads.dead_load", "unit": "kPa", "required": True},
+        {"path": "loads.live_load", "unit": "kPa", "required": True},
+        {"path": "material.compressive_strength", "unit": "MPa", "required": False},
+        {"path": "material.yield_strength", "unit": "MPa", "required": False},
+    ]},
+    {"tier": "engineer", "id": "slope_stability", "name": "Slope Stability Analysis", "parameters": [
+        {"path": "additional.slope_angle", "unit": "degrees", "required": True},
+        {"path": "additional.friction_angle", "unit": "degrees", "required": True},
+        {"path": "additional.cohesion", "unit": "kPa", "required": False},
+        {"path": "additional.unit_weight", "unit": "kN/m³", "required": False},
+        {"path": "dimensions.height", "unit": "m", "required": False},
+    ]},
+    {"tier": "engineer", "id": "soil_bearing_capacity", "name": "Soil Bearing Capacity", "parameters": [
+        {"path": "additional.cohesion", "unit": "kPa", "required": False},
+        {"path": "additional.friction_angle", "unit": "degrees", "required": True},
+        {"path": "additional.unit_weight", "unit": "kN/m³", "required": False},
+        {"path": "dimensions.width", "unit": "m", "required": True},
+        {"path": "dimensions.depth", "unit": "m", "required": False},
+    ]},
+    {"tier": "engineer", "id": "soil_compaction", "name": "Soil Compaction Control", "parameters": [
+        {"path": "extended_parameters.tests", "unit": "", "required": False},
+        {"path": "additional.wet_density", "unit": "kg/m³", "required": False},
+        {"path": "additional.field_moisture", "unit": "%", "required": False},
+        {"path": "additional.max_dry_density", "unit": "kg/m³", "required": True},
+        {"path": "additional.optimum_moisture", "unit": "%", "required": True},
+        {"path": "additional.required_compaction", "unit": "%", "required": False},
+        {"path": "additional.moisture_below", "unit": "%", "required": False},
+        {"path": "additional.moisture_above", "unit": "%", "required": False},
+        {"path": "additional.moisture_correction", "unit": "%", "required": False},
+        {"path": "additional.specific_gravity", "unit": "dimensionless", "required": False},
+    ]},
+    {"tier": "engineer", "id": "sprinkler_hydraulic", "name": "Fire Sprinkler Hydraulic Calculation", "parameters": [
+        {"path": "extended_parameters.hazard_class", "unit": "", "required": False},
+        {"path": "additional.density", "unit": "mm/min", "required": False},
+        {"path": "additional.design_area", "unit": "m²", "required": False},
+        {"path": "dimensions.sprinkler_spacing", "unit": "m", "required": False},
+        {"path": "dimensions.branch_spacing", "unit": "m", "required": False},
+        {"path": "dimensions.branch_diameter", "unit": "mm", "required": False},
+        {"path": "dimensions.main_diameter", "unit": "mm", "required": False},
+        {"path": "dimensions.feed_length", "unit": "m", "required": False},
+        {"path": "additional.elevation", "unit": "m", "required": False},
+        {"path": "additional.k_factor", "unit": "L/min/bar^0.5", "required": False},
+        {"path": "additional.min_pressure", "unit": "kPa", "required": False},
+        {"path": "additional.hazen_williams_c", "unit": "", "required": False},
+        {"path": "additional.supply_static_pressure", "unit": "kPa", "required": False},
+        {"path": "additional.supply_residual_pressure", "unit": "kPa", "required": False},
+        {"path": "additional.supply_flow", "unit": "L/min", "required": False},
+    ]},
+    {"tier": "engineer", "id": "storm_sewer_sizing", "name": "Storm Sewer Sizing", "parameters": [
+        {"path": "additional.design_flow", "unit": "m³/s", "required": False},
+        {"path": "additional.slope", "unit": "m/m", "required": False},
+        {"path": "extended_parameters.reaches", "unit": "", "required": False},
+        {"path": "additional.inlet_time", "unit": "min", "required": False},
+        {"path": "additional.idf_a", "unit": "mm/h·min^n", "required": False},
+        {"path": "additional.idf_b", "unit": "min", "required": False},
+        {"path": "additional.idf_n", "unit": "dimensionless", "required": False},
+        {"path": "additional.manning_n", "unit": "", "required": False},
+    ]},
+    {"tier": "engineer", "id": "thermal_expansion", "name": "Thermal Expansion Calculation", "parameters": [
+        {"path": "dimensions.length", "unit": "m", "required": True},
+        {"path": "additional.delta_t", "unit": "°C", "required": True},
+        {"path": "material.thermal_expansion", "unit": "1/°C", "required": True},
+        {"path": "additional.expansion_type", "unit": "", "required": False},
+    ]},
+    {"tier": "engineer", "id": "tower_crane", "name": "Tower Crane Selection", "parameters": [
+        {"path": "extended_parameters.candidates", "unit": "", "required": False},
+        {"path": "extended_parameters.picks", "unit": "", "required": False},
+        {"path": "additional.max_load", "unit": "t", "required": False},
+        {"path": "additional.max_radius", "unit": "m", "required": False},
+        {"path": "additional.crane_x", "unit": "m", "required": False},
+        {"path": "additional.crane_y", "unit": "m", "required": False},
+        {"path": "additional.oversail_limit", "unit": "m", "required": False},
+        {"path": "additional.mast_height", "unit": "m", "required": True},
+        {"path": "additional.wind_pressure", "unit": "kPa", "required": False},
+        {"path": "additional.footing_width", "unit": "m", "required": False},
+        {"path": "additional.footing_depth", "unit": "m", "required": False},
+        {"path": "additional.allowable_bearing", "unit": "kPa", "required": False},
+    ]},
+    {"tier": "engineer", "id": "truss_analysis", "name": "Truss Analysis", "parameters": [
+        {"path": "additional.nodes", "unit": "(x,y)", "required": True},
+        {"path": "additional.members", "unit": "[node_i,node_j]", "required": True},
+        {"path": "additional.supports", "unit": "[node, type]", "required": True},
+        {"path": "loads", "unit": "kN", "required": True},
+    ]},
+    {"tier": "engineer", "id": "valve_sizing", "name": "Valve Sizing (Cv Calculation)", "parameters": [
+        {"path": "additional.flow_rate", "unit": "gpm", "required": True},
+        {"path": "additional.dp", "unit": "psi", "required": True},
+        {"path": "additional.sg", "unit": "dimensionless", "required": False},
+        {"path": "additional.fluid_type", "unit": "", "required": False},
+    ]},
+    {"tier": "engineer", "id": "water_hammer", "name": "Water Hammer / Surge Analysis", "parameters": [
+        {"path": "dimensions.length", "unit": "m", "required": True},
+        {"path": "dimensions.diameter", "unit": "m", "required": True},
+        {"path": "dimensions.wall_thickness", "unit": "m", "required": True},
+        {"path": "material.material_type", "unit": "", "required": False},
+        {"path": "material.elastic_modulus", "unit": "GPa", "required": False},
+        {"path": "additional.velocity", "unit": "m/s", "required": True},
+        {"path": "additional.closure_time", "unit": "s", "required": False},
+        {"path": "additional.operating_pressure", "unit": "kPa", "required": False},
+        {"path": "additional.pressure_rating", "unit": "kPa", "required": False},
+        {"path": "additional.fluid_density", "unit": "kg/m³", "required": False},
+        {"path": "additional.bulk_modulus", "unit": "GPa", "required": False},
+    ]},
+    {"tier": "engineer", "id": "wind_load", "name": "Wind Load (ASCE 7 Directional)", "parameters": [
+        {"path": "dimensions.height", "unit": "m", "required": True},
+        {"path": "dimensions.width", "unit": "m", "required": True},
+        {"path": "dimensions.length", "unit": "m", "required": True},
+        {"path": "additional.wind_speed", "unit": "m/s", "required": True},
+        {"path": "exposure_class", "unit": "", "required": False},
+        {"path": "additional.topographic_factor", "unit": "dimensionless", "required": False},
+        {"path": "additional.ground_elevation", "unit": "m", "required": False},
+        {"path": "additional.internal_pressure_coefficient", "unit": "dimensionless", "required": False},
+        {"path": "additional.num_stories", "unit": "", "required": False},
+    ]},
+    {"tier": "engineer", "id": "work_sampling", "name": "Work Sampling Analysis", "parameters": [
+        {"path": "additional.total_observations", "unit": "counts", "required": True},
+        {"path": "additional.productive_observations", "unit": "counts", "required": True},
+        {"path": "additional.confidence_level", "unit": "%", "required": False},
+    ]},
+    {"tier": "contractor", "id": "bid_bond", "name": "Bid Bond", "parameters": [
+        {"path": "additional.bid_price", "unit": "USD", "required": True},
+        {"path": "additional.bond_percentage", "unit": "%", "required": True},
+    ]},
+    {"tier": "contractor", "id": "bid_pricing", "name": "Bid Pricing", "parameters": [
+        {"path": "resources.labor_hours", "unit": "hours", "required": True},
+        {"path": "resources.equipment_hours", "unit": "hours", "required": True},
+        {"path": "resources.material_quantity", "unit": "units", "required": True},
+        {"path": "material.unit_cost", "unit": "USD/unit", "required": True},
+        {"path": "additional.labor_rate", "unit": "USD/hour", "required": True},
+        {"path": "additional.equipment_rate", "unit": "USD/hour", "required": True},
+        {"path": "additional.markup_percentage", "unit": "%", "required": True},
+    ]},
+    {"tier": "contractor", "id": "budget_forecast", "name": "Budget Forecast", "parameters": [
+        {"path": "additional.total_cost", "unit": "USD", "required": True},
+        {"path": "additional.duration_months", "unit": "months", "required": True},
+        {"path": "additional.inflation_rate", "unit": "%", "required": False},
+        {"path": "additional.material_share", "unit": "%", "required": False},
+        {"path": "additional.material_escalation_rate", "unit": "%", "required": False},
+    ]},
+    {"tier": "contractor", "id": "cash_flow_analysis", "name": "Cash Flow Analysis", "parameters": [
+        {"path": "additional.inflows", "unit": "USD", "required": True},
+        {"path": "additional.outflows", "unit": "USD", "required": True},
+    ]},
+    {"tier": "contractor", "id": "change_order", "name": "Change Order", "parameters": [
+        {"path": "additional.original_cost", "unit": "USD", "required": True},
+        {"path": "additional.change_cost", "unit": "USD", "required": True},
+        {"path": "additional.change_duration", "unit": "days", "required": False},
+    ]},
+    {"tier": "contractor", "id": "contingency_planning", "name": "Contingency Planning", "parameters": [
+        {"path": "additional.total_cost", "unit": "USD", "required": True},
+        {"path": "additional.risk_factor", "unit": "", "required": True},
+    ]},
+    {"tier": "contractor", "id": "contract_estimation", "name": "Contract Estimation", "parameters": [
+        {"path": "additional.total_cost", "unit": "USD", "required": True},
+        {"path": "additional.contingency_percentage", "unit": "%", "required": True},
+    ]},
+    {"tier": "contractor", "id": "cost_breakdown", "name": "Cost Breakdown", "parameters": [
+        {"path": "additional.material_cost", "unit": "USD", "required": True},
+        {"path": "additional.labor_cost", "unit": "USD", "required": True},
+        {"path": "additional.equipment_cost", "unit": "USD", "required": True},
+        {"path": "resources.overhead", "unit": "USD", "required": False},
+    ]},
+    {"tier": "contractor", "id": "critical_path", "name": "Critical Path", "parameters": [
+        {"path": "additional.total_tasks", "unit": "", "required": True},
+        {"path": "additional.avg_duration", "unit": "days", "required": True},
+        {"path": "additional.parallel_factor", "unit": "", "required": False},
+    ]},
+    {"tier": "contractor", "id": "delay_analysis", "name": "Delay Analysis", "parameters": [
+        {"path": "additional.planned_duration", "unit": "days", "required": True},
+        {"path": "additional.actual_duration", "unit": "days", "required": True},
+        {"path": "additional.delay_cause_factor", "unit": "", "required": False},
+        {"path": "additional.window_days", "unit": "days", "required": False},
+    ]},
+    {"tier": "contractor", "id": "equipment_cost", "name": "Equipment Cost Estimator", "parameters": [
+        {"path": "resources.equipment_hours", "unit": "hours", "required": True},
+        {"path": "additional.equipment_rate", "unit": "USD/hour", "required": True},
+        {"path": "additional.maintenance_factor", "unit": "", "required": False},
+    ]},
+    {"tier": "contractor", "id": "fuel_emissions", "name": "Fuel Consumption and Fleet Emissions", "parameters": [
+        {"path": "resources.equipment_hours", "unit": "hours", "required": False},
+        {"path": "additional.fuel_price", "unit": "USD/L", "required": False},
+        {"path": "additional.fuel_rate", "unit": "L/h", "required": False},
+        {"path": "additional.hours_per_day", "unit": "hours", "required": False},
+        {"path": "additional.emission_factor", "unit": "kg CO2e/L", "required": False},
+        {"path": "additional.idle_fraction", "unit": "", "required": False},
+        {"path": "additional.target_idle_fraction", "unit": "", "required": False},
+    ]},
+    {"tier": "contractor", "id": "gantt_chart", "name": "Gantt Chart Generator", "parameters": [
+        {"path": "additional.start_date", "unit": "unix timestamp", "required": True},
+        {"path": "additional.duration", "unit": "days", "required": True},
+        {"path": "additional.milestones", "unit": "", "required": False},
+    ]},
+    {"tier": "contractor", "id": "labor_cost", "name": "Labor Cost Estimator", "parameters": [
+        {"path": "resources.labor_hours", "unit": "hours", "required": True},
+        {"path": "additional.labor_rate", "unit": "USD/hour", "required": True},
+        {"path": "additional.productivity_factor", "unit": "", "required": False},
+    ]},
+    {"tier": "contractor", "id": "material_cost", "name": "Material Cost Estimator", "parameters": [
+        {"path": "resources.material_quantity", "unit": "units", "required": True},
+        {"path": "material.unit_cost", "unit": "USD/unit", "required": True},
+        {"path": "material.waste_factor", "unit": "", "required": False},
+    ]},
+    {"tier": "contractor", "id": "milestone_tracking", "name": "Milestone Tracking", "parameters": [
+        {"path": "additional.total_milestones", "unit": "", "required": True},
+        {"path": "additional.completed_milestones", "unit": "", "required": True},
+    ]},
+    {"tier": "contractor", "id": "overhead", "name": "Overhead Calculator", "parameters": [
+        {"path": "additional.direct_cost", "unit": "USD", "required": True},
+        {"path": "additional.overhead_percentage", "unit": "%", "required": True},
+    ]},
+    {"tier": "contractor", "id": "profit_margin", "name": "Profit Margin", "parameters": [
+        {"path": "additional.total_cost", "unit": "USD", "required": True},
+        {"path": "additional.bid_price", "unit": "USD", "required": True},
+    ]},
+    {"tier": "contractor", "id": "progress_tracking", "name": "Progress Tracking", "parameters": [
+        {"path": "additional.planned_progress", "unit": "%", "required": True},
+        {"path": "additional.actual_progress", "unit": "%", "required": True},
+        {"path": "additional.budget_at_completion", "unit": "USD", "required": False},
+    ]},
+    {"tier": "contractor", "id": "project_closeout", "name": "Project Closeout", "parameters": [
+        {"path": "additional.completion_percentage", "unit": "%", "required": True},
+        {"path": "additional.outstanding_issues", "unit": "", "required": True},
+    ]},
+    {"tier": "contractor", "id": "quality_control", "name": "Quality Control", "parameters": [
+        {"path": "additional.total_items", "unit": "", "required": True},
+        {"path": "additional.defective_items", "unit": "", "required": True},
+    ]},
+    {"tier": "contractor", "id": "quantity_takeoff", "name": "Quantity Takeoff", "parameters": [
+        {"path": "dimensions.length", "unit": "m", "required": True},
+        {"path": "dimensions.width", "unit": "m", "required": True},
+        {"path": "dimensions.height", "unit": "m", "required": False},
+        {"path": "material.waste_factor", "unit": "", "required": False},
+    ]},
+    {"tier": "contractor", "id": "resource_allocation", "name": "Resource Allocation", "parameters": [
+        {"path": "resources.labor_hours", "unit": "hours", "required": True},
+        {"path": "resources.equipment_hours", "unit": "hours", "required": True},
+        {"path": "additional.available_labor", "unit": "hours", "required": True},
+        {"path": "additional.available_equipment", "unit": "hours", "required": True},
+    ]},
+    {"tier": "contractor", "id": "resource_leveling", "name": "Resource Leveling", "parameters": [
+        {"path": "additional.peak_demand", "unit": "units", "required": True},
+        {"path": "additional.available_resources", "unit": "units", "required": True},
+        {"path": "additional.project_duration", "unit": "days", "required": True},
+    ]},
+    {"tier": "contractor", "id": "risk_assessment", "name": "Risk Assessment", "parameters": [
+        {"path": "safety_factors.risk_reduction_factor", "unit": "", "required": True},
+        {"path": "safety_factors.importance_factor", "unit": "", "required": True},
+        {"path": "additional.project_complexity", "unit": "", "required": True},
+    ]},
+    {"tier": "contractor", "id": "safety_planning", "name": "Safety Planning", "parameters": [
+        {"path": "safety_factors.risk_reduction_factor", "unit": "", "required": True},
+        {"path": "safety_factors.importance_factor", "unit": "", "required": True},
+        {"path": "additional.hazard_level", "unit": "", "required": True},
+    ]},
+    {"tier": "contractor", "id": "schedule_optimization", "name": "Schedule Optimization", "parameters": [
+        {"path": "additional.original_duration", "unit": "days", "required": True},
+        {"path": "additional.optimization_factor", "unit": "", "required": True},
+    ]},
+    {"tier": "contractor", "id": "subcontractor_evaluation", "name": "Subcontractor Evaluation", "parameters": [
+        {"path": "additional.performance_score", "unit": "", "required": True},
+        {"path": "additional.reliability_score", "unit": "", "required": True},
+        {"path": "additional.cost_score", "unit": "", "required": True},
+    ]},
+    {"tier": "contractor", "id": "time_cost_tradeoff", "name": "Time-Cost Tradeoff", "parameters": [
+        {"path": "additional.normal_duration", "unit": "days", "required": True},
+        {"path": "additional.normal_cost", "unit": "USD", "required": True},
+        {"path": "additional.crash_duration", "unit": "days", "required": True},
+        {"path": "additional.crash_cost", "unit": "USD", "required": True},
+    ]},
+    {"tier": "contractor", "id": "travel_cost", "name": "Jobsite Travel Cost Estimator", "parameters": [
+        {"path": "origin.latitude", "unit": "deg", "required": True},
+        {"path": "origin.longitude", "unit": "deg", "required": True},
+        {"path": "location.latitude", "unit": "deg", "required": True},
+        {"path": "location.longitude", "unit": "deg", "required": True},
+        {"path": "additional.project_days", "unit": "days", "required": True},
+        {"path": "additional.equipment_loads", "unit": "loads", "required": False},
+        {"path": "additional.transport_rate", "unit": "USD/mile", "required": False},
+        {"path": "additional.crew_vehicles", "unit": "vehicles", "required": False},
+        {"path": "additional.vehicle_rate", "unit": "USD/mile", "required": False},
+        {"path": "additional.crew_size", "unit": "workers", "required": False},
+        {"path": "additional.travel_labor_rate", "unit": "USD/hour", "required": False},
+        {"path": "additional.average_speed", "unit": "mph", "required": False},
+        {"path": "additional.circuity_factor", "unit": "", "required": False},
+    ]},
+    {"tier": "contractor", "id": "value_engineering", "name": "Value Engineering", "parameters": [
+        {"path": "additional.original_cost", "unit": "USD", "required": True},
+        {"path": "additional.alternative_cost", "unit": "USD", "required": True},
+        {"path": "additional.performance_factor", "unit": "", "required": False},
+    ]},
+]
+
+
+class StrukturaError(Exception):
+    def __init__(self, status: int, body: Any) -> None:
+        super().__init__(f"HTTP {status}: {body}")
+        self.status = status
+        self.body = body
+
+
+class StrukturaClient:
+    def __init__(
+        self,
+        base_url: str = "http://localhost:8000/api/v1",
+        token: Optional[str] = None,
+        timeout: float = 30.0,
+    ) -> None:
+        self.base_url = base_url.rstrip("/")
+        self.token = token
+        self.timeout = timeout
+
+    def _request(self, method: str, path: str, body: Any = None) -> Any:
+        data = None if body is None else json.dumps(body).encode("utf-8")
+        request = urllib.request.Request(self.base_url + path, data=data, method=method)
+        request.add_header("Accept", "application/json")
+        if data is not None:
+            request.add_header("Content-Type", "application/json")
+        if self.token:
+            request.add_header("Authorization", f"Bearer {self.token}")
+        try:
+            with urllib.request.urlopen(request, timeout=self.timeout) as response:
+                return json.loads(response.read() or b"null")
+        except urllib.error.HTTPError as e:
+            raise StrukturaError(e.code, e.read().decode("utf-8", "replace")) from None
+
+    def calculate_beginner(self, request: BeginnerCalculationRequest) -> BeginnerCalculationResponse:
+        return self._request("POST", "/calculus/beginner/calculate", request)
+
+    def beginner_catalogue(self) -> BeginnerCatalogue:
+        return self._request("GET", "/calculus/beginner/catalogue")
+
+    def calculate_engineer(self, request: EngineerCalculationRequest) -> EngineerCalculationResponse:
+        return self._request("POST", "/calculus/engineer/calculate", request)
+
+    def engineer_catalogue(self) -> EngineerCatalogue:
+        return self._request("GET", "/calculus/engineer/catalogue")
+
+    def calculate_contractor(self, request: ContractorCalculationRequest) -> ContractorCalculationResponse:
+        return self._request("POST", "/calculus/contractor/calculate", request)
+
+    def contractor_catalogue(self) -> ContractorCatalogue:
+        return self._request("GET", "/calculus/contractor/catalogue")
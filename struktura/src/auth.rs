@@ -3,14 +3,21 @@ use axum::{
     http::{StatusCode, HeaderMap, HeaderValue},
     response::{Json, IntoResponse},
 };
+use base64::{engine::general_purpose, Engine as _};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use sqlx::types::time::OffsetDateTime;
 use std::sync::Arc;
-use validator::Validate;
+use time::Duration;
+use validator::{Validate, ValidationError, ValidationErrors};
 use uuid::Uuid;
 
+use crate::notifications::{self, Email};
+use crate::password;
 use crate::sec::{self, AppError, Claims, PasswordManager};
 use crate::state::AppState;
+use crate::totp;
 
 #[derive(Deserialize, Serialize, Debug, Validate)]
 pub struct AuthPayload {
@@ -18,12 +25,30 @@ pub struct AuthPayload {
     pub username: String,
     #[validate(length(min = 12))]
     pub password: String,
+    /// Login only: replaces a password its organization considers expired
+    #[serde(default, skip_serializing)]
+    pub new_password: Option<String>,
+    /// Login only: authenticator or recovery code, when two-factor is on
+    #[serde(default, skip_serializing)]
+    pub two_factor_code: Option<String>,
+    /// Signup only: address to confirm, which password resets are mailed to
+    #[validate(email)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Validate)]
 pub struct UpdateProfilePayload {
     #[validate(length(max = 100))]
     pub fav_experience_level: Option<String>,
+    /// Required alongside `new_password`, and to stop requiring two-factor
+    #[serde(default, skip_serializing)]
+    pub current_password: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub new_password: Option<String>,
+    /// Ask for an authenticator code at login; needs a confirmed authenticator
+    #[serde(default, skip_serializing)]
+    pub require_two_factor: Option<bool>,
 }
 
 #[derive(Serialize)]
@@ -31,6 +56,9 @@ pub struct AuthResponse {
     pub message: String,
     pub token: Option<String>,
     pub csrf_token: Option<String>,
+    /// Issued on signup, login and refresh; exchange it at `/auth/refresh`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
     pub user_profile: Option<UserProfile>,
 }
 
@@ -71,6 +99,11 @@ pub async fn signup_handler(
         return Err(AppError::InvalidCredentials);
     }
 
+    if let Err(e) = app_state.password_policy.enforce(&payload.password, &payload.username).await {
+        sec::log_security_event("SIGNUP_WEAK_PASSWORD", Some(&payload.username), ip_str, "Policy violation");
+        return Err(e);
+    }
+
     let hashed_password = PasswordManager::hash_password(&payload.password)?;
 
     let profile = sqlx::query!(
@@ -89,21 +122,27 @@ pub async fn signup_handler(
         AppError::DbError(e)
     })?;
 
+    if let Some(email) = payload.email.as_deref() {
+        sqlx::query("UPDATE users SET email = $1 WHERE id = $2")
+            .bind(email)
+            .bind(profile.id)
+            .execute(&app_state.pool)
+            .await?;
+        let token = issue_email_token(&app_state.pool, profile.id, VERIFY_EMAIL, email, VERIFY_EMAIL_TTL).await?;
+        let link = notifications::link("/verify-email", &token);
+        send_email(&app_state, Email::verification(email, &profile.username, &link));
+    }
+
     let session_fp = sec::compute_session_fingerprint(ip.as_deref(), ua_hash.as_deref());
-    let (token, claims) = sec::generate_jwt(
-        profile.id.to_string(),
-        profile.username.clone(),
-        session_fp,
-        &app_state.jwt_secret
-    )?;
-    let csrf_token = app_state.csrf_store.generate_and_store(&claims.sub)?;
+    let session = start_session(&app_state, profile.id, &profile.username, session_fp).await?;
 
     sec::log_security_event("USER_SIGNUP", Some(&payload.username), ip_str, "Success");
 
     Ok(Json(AuthResponse {
         message: "Registered successfully".to_string(),
-        token: Some(token),
-        csrf_token: Some(csrf_token),
+        token: Some(session.token),
+        csrf_token: Some(session.csrf_token),
+        refresh_token: Some(session.refresh_token),
         user_profile: Some(UserProfile {
             username: profile.username,
             is_pro: profile.is_pro,
@@ -152,21 +191,43 @@ pub async fn login_handler(
         return Err(AppError::InvalidCredentials);
     }
 
+    if !is_active(&app_state.pool, user_record.id).await? {
+        sec::log_security_event("LOGIN_FAIL", Some(&payload.username), ip_str, "Deactivated account");
+        sec::traffic_jitter(200).await;
+        return Err(AppError::InvalidCredentials);
+    }
+
+    if totp::is_required(&app_state.pool, user_record.id).await? {
+        let Some(code) = payload.two_factor_code.as_deref() else {
+            sec::log_security_event("LOGIN_TWO_FACTOR", Some(&payload.username), ip_str, "Code required");
+            return Err(AppError::TwoFactorRequired);
+        };
+        if !totp::verify_login(&app_state.pool, user_record.id, code).await? {
+            sec::log_security_event("LOGIN_FAIL", Some(&payload.username), ip_str, "Bad two-factor code");
+            sec::traffic_jitter(200).await;
+            return Err(AppError::InvalidCredentials);
+        }
+    }
+
+    if password::must_rotate(&app_state.pool, user_record.id).await? {
+        let Some(new_password) = payload.new_password.as_deref() else {
+            sec::log_security_event("LOGIN_PASSWORD_EXPIRED", Some(&payload.username), ip_str, "Rotation required");
+            return Err(AppError::PasswordExpired);
+        };
+        change_password(&app_state, user_record.id, &user_record.username, &user_record.hash, new_password).await?;
+        sec::log_security_event("PASSWORD_ROTATED", Some(&payload.username), ip_str, "Success");
+    }
+
     let session_fp = sec::compute_session_fingerprint(ip.as_deref(), ua_hash.as_deref());
-    let (token, claims) = sec::generate_jwt(
-        user_record.id.to_string(),
-        user_record.username.clone(),
-        session_fp,
-        &app_state.jwt_secret
-    )?;
-    let csrf_token = app_state.csrf_store.generate_and_store(&claims.sub)?;
+    let session = start_session(&app_state, user_record.id, &user_record.username, session_fp).await?;
 
-    sec::log_security_event("USER_LOGIN", Some(&claims.username), ip_str, "Success");
+    sec::log_security_event("USER_LOGIN", Some(&user_record.username), ip_str, "Success");
 
     Ok(Json(AuthResponse {
         message: "Login successful".to_string(),
-        token: Some(token),
-        csrf_token: Some(csrf_token),
+        token: Some(session.token),
+        csrf_token: Some(session.csrf_token),
+        refresh_token: Some(session.refresh_token),
         user_profile: Some(UserProfile {
             username: user_record.username,
             is_pro: user_record.is_pro,
@@ -184,6 +245,7 @@ pub async fn logout_handler(
     
     app_state.token_blacklist.revoke(&claims.jti);
     app_state.csrf_store.invalidate_token(&claims.sub);
+    revoke_session(&app_state, &claims.jti).await?;
     
     let (ip, _) = sec::extract_ip_and_ua(&headers)?;
     sec::log_security_event("USER_LOGOUT", Some(&claims.username), ip.as_deref(), "Success");
@@ -191,6 +253,97 @@ pub async fn logout_handler(
     Ok(StatusCode::OK)
 }
 
+#[derive(Deserialize, Debug)]
+pub struct RefreshPayload {
+    pub refresh_token: String,
+}
+
+/// Exchange a refresh token for a new access token, rotating the refresh
+/// token. A token that was already used revokes its whole family: either
+/// the client retried a stale token or someone else holds a copy.
+pub async fn refresh_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<RefreshPayload>,
+) -> Result<Json<AuthResponse>, AppError> {
+    let (ip, ua_hash) = sec::extract_ip_and_ua(&headers)?;
+    let ip_str = ip.as_deref();
+
+    let stored = sqlx::query_as::<_, StoredRefreshToken>(
+        r#"
+        SELECT r.id, r.user_id, r.family_id, r.session_fp, r.expires_at, r.revoked_at, u.username
+        FROM refresh_tokens r
+        JOIN users u ON u.id = r.user_id AND u.active
+        WHERE r.token_hash = $1
+        "#,
+    )
+    .bind(hash_token(&payload.refresh_token))
+    .fetch_optional(&app_state.pool)
+    .await?;
+
+    let Some(stored) = stored else {
+        sec::log_security_event("REFRESH_FAIL", None, ip_str, "Unknown refresh token");
+        sec::traffic_jitter(100).await;
+        return Err(AppError::InvalidToken);
+    };
+
+    if stored.revoked_at.is_some() {
+        sec::log_security_event("REFRESH_REUSE", Some(&stored.username), ip_str, "Revoked refresh token presented");
+        revoke_family(&app_state, stored.family_id).await?;
+        return Err(AppError::InvalidToken);
+    }
+    if stored.expires_at <= OffsetDateTime::now_utc() {
+        return Err(AppError::ExpiredToken);
+    }
+
+    let session_fp = sec::compute_session_fingerprint(ip.as_deref(), ua_hash.as_deref());
+    if stored.session_fp != session_fp {
+        sec::log_security_event("SESSION_HIJACK", Some(&stored.username), ip_str, "Refresh fingerprint mismatch");
+        revoke_family(&app_state, stored.family_id).await?;
+        return Err(AppError::InvalidToken);
+    }
+
+    let (token, claims) = sec::generate_jwt(
+        stored.user_id.to_string(),
+        stored.username.clone(),
+        session_fp,
+        &app_state.jwt_secret,
+    )?;
+
+    // Only the first of two concurrent uses may rotate the token
+    let mut tx = app_state.pool.begin().await?;
+    let rotated = sqlx::query("UPDATE refresh_tokens SET revoked_at = NOW() WHERE id = $1 AND revoked_at IS NULL")
+        .bind(stored.id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    if rotated == 0 {
+        tx.rollback().await?;
+        sec::log_security_event("REFRESH_REUSE", Some(&stored.username), ip_str, "Concurrent refresh");
+        revoke_family(&app_state, stored.family_id).await?;
+        return Err(AppError::InvalidToken);
+    }
+    let (replacement, refresh_token) = insert_refresh_token(&mut *tx, stored.user_id, stored.family_id, &claims).await?;
+    sqlx::query("UPDATE refresh_tokens SET replaced_by = $1 WHERE id = $2")
+        .bind(replacement)
+        .bind(stored.id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    let csrf_token = app_state.csrf_store.generate_and_store(&claims.sub)?;
+
+    sec::log_security_event("TOKEN_REFRESH", Some(&claims.username), ip_str, "Success");
+
+    Ok(Json(AuthResponse {
+        message: "Token refreshed".to_string(),
+        token: Some(token),
+        csrf_token: Some(csrf_token),
+        refresh_token: Some(refresh_token),
+        user_profile: None,
+    }))
+}
+
 pub async fn get_my_profile_handler(
     State(app_state): State<Arc<AppState>>,
     claims: Claims,
@@ -217,6 +370,7 @@ pub async fn get_my_profile_handler(
         message: "Profile retrieved".to_string(),
         token: None,
         csrf_token: None,
+        refresh_token: None,
         user_profile: Some(profile),
     }))
 }
@@ -233,6 +387,40 @@ pub async fn update_profile_handler(
     let (ip, _) = sec::extract_ip_and_ua(&headers)?;
     let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
 
+    // Loosening sign-in needs the password, not just a (possibly stolen) token
+    let disabling_two_factor = payload.require_two_factor == Some(false);
+    if payload.new_password.is_some() || disabling_two_factor {
+        let current_hash = sqlx::query_scalar::<_, String>("SELECT hash FROM users WHERE id = $1")
+            .bind(user_id)
+            .fetch_optional(&app_state.pool)
+            .await?
+            .ok_or(AppError::UserNotFound)?;
+
+        let current_password = payload.current_password.as_deref().unwrap_or_default();
+        if !PasswordManager::verify_password(current_password, &current_hash)? {
+            sec::log_security_event("PASSWORD_CHANGE_FAIL", Some(&claims.username), ip.as_deref(), "Bad current password");
+            sec::traffic_jitter(200).await;
+            return Err(AppError::InvalidCredentials);
+        }
+
+        if let Some(new_password) = payload.new_password.as_deref() {
+            change_password(&app_state, user_id, &claims.username, &current_hash, new_password).await?;
+            sec::log_security_event("PASSWORD_CHANGE", Some(&claims.username), ip.as_deref(), "Success");
+        }
+    }
+
+    if let Some(required) = payload.require_two_factor {
+        if !totp::set_required(&app_state.pool, user_id, required).await? {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "require_two_factor",
+                ValidationError::new("not_enrolled").with_message("Set up an authenticator first".into()),
+            );
+            return Err(AppError::ValidationError(errors));
+        }
+        sec::log_security_event("TWO_FACTOR_REQUIREMENT", Some(&claims.username), ip.as_deref(), if required { "On" } else { "Off" });
+    }
+
     let updated_profile = sqlx::query_as!(
         UserProfile,
         r#"
@@ -254,6 +442,7 @@ pub async fn update_profile_handler(
         message: "Profile updated".to_string(),
         token: None,
         csrf_token: None,
+        refresh_token: None,
         user_profile: Some(updated_profile),
     }))
 }
@@ -280,4 +469,511 @@ pub async fn get_csrf_token_handler(
         headers,
         Json(serde_json::json!({ "csrf_token": token }))
     ))
-}
\ No newline at end of file
+}
+
+// =============================================================================
+// EMAIL VERIFICATION AND PASSWORD RESET
+// =============================================================================
+
+/// Token purposes, as stored in `email_tokens.purpose`
+const VERIFY_EMAIL: &str = "verify_email";
+const RESET_PASSWORD: &str = "reset_password";
+
+const VERIFY_EMAIL_TTL: Duration = Duration::hours(48);
+const RESET_PASSWORD_TTL: Duration = Duration::hours(1);
+
+#[derive(Deserialize, Debug)]
+pub struct VerifyEmailPayload {
+    pub token: String,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct ForgotPasswordPayload {
+    #[validate(email)]
+    pub email: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ResetPasswordPayload {
+    pub token: String,
+    pub new_password: String,
+}
+
+/// Store a one-time token for `user_id`, mailed to `email`
+async fn issue_email_token(
+    pool: &sqlx::PgPool,
+    user_id: Uuid,
+    purpose: &str,
+    email: &str,
+    ttl: Duration,
+) -> Result<String, AppError> {
+    let token = new_secret_token();
+    sqlx::query(
+        r#"
+        INSERT INTO email_tokens (user_id, purpose, email, token_hash, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+    )
+    .bind(user_id)
+    .bind(purpose)
+    .bind(email)
+    .bind(hash_token(&token))
+    .bind(OffsetDateTime::now_utc() + ttl)
+    .execute(pool)
+    .await?;
+    Ok(token)
+}
+
+/// Send in the background: a slow relay shouldn't hold up the response, and
+/// forgot-password must answer in the same time whether or not it mails
+fn send_email(app_state: &AppState, email: Email) {
+    let mailer = app_state.mailer.clone();
+    tokio::spawn(async move {
+        if let Err(e) = mailer.send(&email).await {
+            tracing::error!(error = %e, mailer = mailer.name(), subject = %email.subject, "account email failed");
+        }
+    });
+}
+
+/// POST /auth/verify: confirm the address a verification token was mailed to
+pub async fn verify_email_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<VerifyEmailPayload>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let (ip, _) = sec::extract_ip_and_ua(&headers)?;
+
+    let spent: Option<(Uuid, String)> = sqlx::query_as(
+        r#"
+        UPDATE email_tokens SET used_at = NOW()
+        WHERE token_hash = $1 AND purpose = $2 AND used_at IS NULL AND expires_at > NOW()
+        RETURNING user_id, email
+        "#,
+    )
+    .bind(hash_token(&payload.token))
+    .bind(VERIFY_EMAIL)
+    .fetch_optional(&app_state.pool)
+    .await?;
+
+    let Some((user_id, email)) = spent else {
+        sec::log_security_event("EMAIL_VERIFY_FAIL", None, ip.as_deref(), "Unknown, used or expired token");
+        sec::traffic_jitter(100).await;
+        return Err(AppError::InvalidToken);
+    };
+
+    // The address may have changed since the token was mailed
+    let username: Option<String> = sqlx::query_scalar(
+        r#"
+        UPDATE users SET email_verified_at = COALESCE(email_verified_at, NOW())
+        WHERE id = $1 AND lower(email) = lower($2)
+        RETURNING username
+        "#,
+    )
+    .bind(user_id)
+    .bind(&email)
+    .fetch_optional(&app_state.pool)
+    .await?;
+    let Some(username) = username else {
+        return Err(AppError::InvalidToken);
+    };
+
+    sec::log_security_event("EMAIL_VERIFIED", Some(&username), ip.as_deref(), "Success");
+    Ok(Json(serde_json::json!({ "message": "Email address confirmed", "email": email })))
+}
+
+/// POST /auth/forgot-password: mail a reset link to every active account
+/// with this confirmed address
+///
+/// The answer is the same whether or not an account matched, so the
+/// endpoint can't be used to find out who has an account.
+pub async fn forgot_password_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<ForgotPasswordPayload>,
+) -> Result<(StatusCode, Json<serde_json::Value>), AppError> {
+    payload.validate()?;
+    let (ip, _) = sec::extract_ip_and_ua(&headers)?;
+
+    let accounts: Vec<(Uuid, String, String)> = sqlx::query_as(
+        r#"
+        SELECT id, username, email FROM users
+        WHERE lower(email) = lower($1) AND email_verified_at IS NOT NULL
+          AND active AND deprovisioned_at IS NULL
+        "#,
+    )
+    .bind(payload.email.trim())
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    for (user_id, username, email) in &accounts {
+        // Only the newest link works
+        sqlx::query("UPDATE email_tokens SET used_at = NOW() WHERE user_id = $1 AND purpose = $2 AND used_at IS NULL")
+            .bind(user_id)
+            .bind(RESET_PASSWORD)
+            .execute(&app_state.pool)
+            .await?;
+        let token = issue_email_token(&app_state.pool, *user_id, RESET_PASSWORD, email, RESET_PASSWORD_TTL).await?;
+        let link = notifications::link("/reset-password", &token);
+        send_email(&app_state, Email::password_reset(email, username, &link));
+        sec::log_security_event("PASSWORD_RESET_REQUEST", Some(username), ip.as_deref(), "Reset link sent");
+    }
+    sec::traffic_jitter(100).await;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(serde_json::json!({
+            "message": "If an account has this confirmed address, a reset link is on its way"
+        })),
+    ))
+}
+
+/// POST /auth/reset-password: set a new password with a reset token, then
+/// sign out every session of the account
+pub async fn reset_password_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<ResetPasswordPayload>,
+) -> Result<Json<AuthResponse>, AppError> {
+    let (ip, _) = sec::extract_ip_and_ua(&headers)?;
+    let ip_str = ip.as_deref();
+
+    // The row lock makes a second request with the same token wait, then
+    // find it spent
+    let mut tx = app_state.pool.begin().await?;
+    let stored: Option<(Uuid, Uuid, String, String)> = sqlx::query_as(
+        r#"
+        SELECT t.id, u.id, u.username, u.hash
+        FROM email_tokens t
+        JOIN users u ON u.id = t.user_id AND u.active
+        WHERE t.token_hash = $1 AND t.purpose = $2 AND t.used_at IS NULL AND t.expires_at > NOW()
+        FOR UPDATE OF t
+        "#,
+    )
+    .bind(hash_token(&payload.token))
+    .bind(RESET_PASSWORD)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some((token_id, user_id, username, current_hash)) = stored else {
+        sec::log_security_event("PASSWORD_RESET_FAIL", None, ip_str, "Unknown, used or expired token");
+        sec::traffic_jitter(100).await;
+        return Err(AppError::InvalidToken);
+    };
+
+    // A rejected password leaves the token usable for another try
+    change_password(&app_state, user_id, &username, &current_hash, &payload.new_password).await?;
+
+    sqlx::query("UPDATE email_tokens SET used_at = NOW() WHERE id = $1")
+        .bind(token_id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    revoke_user_sessions(&app_state, user_id).await?;
+    sec::log_security_event("PASSWORD_RESET", Some(&username), ip_str, "Success");
+
+    Ok(Json(AuthResponse {
+        message: "Password reset; sign in with the new password".to_string(),
+        token: None,
+        csrf_token: None,
+        refresh_token: None,
+        user_profile: None,
+    }))
+}
+
+// =============================================================================
+// TWO-FACTOR AUTHENTICATION
+// =============================================================================
+
+#[derive(Serialize)]
+pub struct TwoFactorSetupResponse {
+    /// Base32 secret, for apps that can't scan
+    pub secret: String,
+    /// Render as a QR code for the authenticator app
+    pub otpauth_uri: String,
+}
+
+/// Proof of the current factor, needed only to replace an active authenticator
+#[derive(Deserialize, Debug, Default)]
+pub struct TwoFactorSetupPayload {
+    /// Authenticator or recovery code
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TwoFactorConfirmPayload {
+    pub code: String,
+}
+
+#[derive(Serialize)]
+pub struct TwoFactorConfirmResponse {
+    pub message: String,
+    /// Single-use codes for a lost authenticator; shown only this once
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TwoFactorDisablePayload {
+    pub password: String,
+}
+
+/// Whether `password` is the user's current password
+async fn verify_current_password(app_state: &AppState, user_id: Uuid, password: &str) -> Result<bool, AppError> {
+    let current_hash = sqlx::query_scalar::<_, String>("SELECT hash FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(&app_state.pool)
+        .await?
+        .ok_or(AppError::UserNotFound)?;
+    Ok(PasswordManager::verify_password(password, &current_hash)?)
+}
+
+/// POST /user/2fa/setup: start enrolling an authenticator; replacing an
+/// active one needs a current code, a recovery code or the password
+pub async fn two_factor_setup_handler(
+    State(app_state): State<Arc<AppState>>,
+    claims: Claims,
+    headers: HeaderMap,
+    payload: Option<Json<TwoFactorSetupPayload>>,
+) -> Result<Json<TwoFactorSetupResponse>, AppError> {
+    let (ip, _) = sec::extract_ip_and_ua(&headers)?;
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+    let Json(payload) = payload.unwrap_or_default();
+
+    let proven = match (payload.code.as_deref(), payload.password.as_deref()) {
+        (Some(code), _) => totp::verify_login(&app_state.pool, user_id, code).await?,
+        (None, Some(password)) => verify_current_password(&app_state, user_id, password).await?,
+        (None, None) => false,
+    };
+    let Some(secret) = totp::begin_enrollment(&app_state.pool, user_id, proven).await? else {
+        sec::log_security_event("TWO_FACTOR_SETUP_FAIL", Some(&claims.username), ip.as_deref(), "Missing proof");
+        sec::traffic_jitter(200).await;
+        return Err(AppError::InvalidCredentials);
+    };
+
+    sec::log_security_event("TWO_FACTOR_SETUP", Some(&claims.username), ip.as_deref(), "Pending");
+
+    Ok(Json(TwoFactorSetupResponse {
+        otpauth_uri: totp::provisioning_uri(&secret, &claims.username),
+        secret,
+    }))
+}
+
+/// POST /user/2fa/confirm: activate the pending authenticator with its first
+/// code; login asks for codes from then on
+pub async fn two_factor_confirm_handler(
+    State(app_state): State<Arc<AppState>>,
+    claims: Claims,
+    headers: HeaderMap,
+    Json(payload): Json<TwoFactorConfirmPayload>,
+) -> Result<Json<TwoFactorConfirmResponse>, AppError> {
+    let (ip, _) = sec::extract_ip_and_ua(&headers)?;
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+
+    let Some(recovery_codes) = totp::confirm_enrollment(&app_state.pool, user_id, &payload.code).await? else {
+        sec::log_security_event("TWO_FACTOR_SETUP_FAIL", Some(&claims.username), ip.as_deref(), "Bad code");
+        sec::traffic_jitter(200).await;
+        return Err(AppError::InvalidCredentials);
+    };
+
+    sec::log_security_event("TWO_FACTOR_ENABLED", Some(&claims.username), ip.as_deref(), "Success");
+
+    Ok(Json(TwoFactorConfirmResponse {
+        message: "Two-factor authentication enabled".to_string(),
+        recovery_codes,
+    }))
+}
+
+/// POST /user/2fa/disable: remove the authenticator and recovery codes
+pub async fn two_factor_disable_handler(
+    State(app_state): State<Arc<AppState>>,
+    claims: Claims,
+    headers: HeaderMap,
+    Json(payload): Json<TwoFactorDisablePayload>,
+) -> Result<StatusCode, AppError> {
+    let (ip, _) = sec::extract_ip_and_ua(&headers)?;
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+
+    if !verify_current_password(&app_state, user_id, &payload.password).await? {
+        sec::log_security_event("TWO_FACTOR_DISABLE_FAIL", Some(&claims.username), ip.as_deref(), "Bad password");
+        sec::traffic_jitter(200).await;
+        return Err(AppError::InvalidCredentials);
+    }
+
+    totp::disable(&app_state.pool, user_id).await?;
+    sec::log_security_event("TWO_FACTOR_DISABLED", Some(&claims.username), ip.as_deref(), "Success");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// =============================================================================
+// PASSWORD CHANGES
+// =============================================================================
+
+/// Replace a user's password after the policy accepts the new one
+async fn change_password(
+    app_state: &AppState,
+    user_id: Uuid,
+    username: &str,
+    current_hash: &str,
+    new_password: &str,
+) -> Result<(), AppError> {
+    if PasswordManager::verify_password(new_password, current_hash)? {
+        return Err(AppError::PasswordPolicy(vec![password::PolicyViolation::reused()]));
+    }
+    app_state.password_policy.enforce(new_password, username).await?;
+
+    let hashed_password = PasswordManager::hash_password(new_password)?;
+    password::set_password(&app_state.pool, user_id, &hashed_password).await
+}
+
+// =============================================================================
+// REFRESH TOKENS
+// =============================================================================
+
+/// How long a refresh token can wait to be used
+const REFRESH_TOKEN_TTL: Duration = Duration::days(30);
+
+#[derive(sqlx::FromRow)]
+struct StoredRefreshToken {
+    id: Uuid,
+    user_id: Uuid,
+    family_id: Uuid,
+    session_fp: String,
+    expires_at: OffsetDateTime,
+    revoked_at: Option<OffsetDateTime>,
+    username: String,
+}
+
+/// 256 random bits, URL-safe; used for refresh and emailed tokens
+fn new_secret_token() -> String {
+    let bytes: [u8; 32] = rand::rng().random();
+    general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Only hashes are stored, so a leaked table cannot be replayed
+fn hash_token(token: &str) -> String {
+    general_purpose::STANDARD.encode(Sha256::digest(token.as_bytes()))
+}
+
+async fn insert_refresh_token<'e, E>(executor: E, user_id: Uuid, family_id: Uuid, claims: &Claims) -> Result<(Uuid, String), AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let token = new_secret_token();
+    let id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO refresh_tokens (user_id, family_id, token_hash, session_fp, access_jti, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(family_id)
+    .bind(hash_token(&token))
+    .bind(&claims.session_fp)
+    .bind(&claims.jti)
+    .bind(OffsetDateTime::now_utc() + REFRESH_TOKEN_TTL)
+    .fetch_one(executor)
+    .await?;
+    Ok((id, token))
+}
+
+/// Start a refresh token family for a fresh login
+async fn issue_refresh_token(pool: &sqlx::PgPool, user_id: Uuid, family_id: Uuid, claims: &Claims) -> Result<String, AppError> {
+    insert_refresh_token(pool, user_id, family_id, claims).await.map(|(_, token)| token)
+}
+
+/// Tokens handed out when a user signs in
+pub(crate) struct Session {
+    pub token: String,
+    pub csrf_token: String,
+    pub refresh_token: String,
+}
+
+/// Sign a user in: access token, CSRF token and a new refresh token family
+pub(crate) async fn start_session(
+    app_state: &AppState,
+    user_id: Uuid,
+    username: &str,
+    session_fp: String,
+) -> Result<Session, AppError> {
+    let (token, claims) = sec::generate_jwt(user_id.to_string(), username.to_string(), session_fp, &app_state.jwt_secret)?;
+    let csrf_token = app_state.csrf_store.generate_and_store(&claims.sub)?;
+    let refresh_token = issue_refresh_token(&app_state.pool, user_id, Uuid::new_v4(), &claims).await?;
+    Ok(Session { token, csrf_token, refresh_token })
+}
+
+/// Revoke every refresh token of a family and blacklist the access tokens
+/// issued with them
+async fn revoke_family(app_state: &AppState, family_id: Uuid) -> Result<(), AppError> {
+    let access_jtis: Vec<String> = sqlx::query_scalar(
+        r#"
+        UPDATE refresh_tokens SET revoked_at = COALESCE(revoked_at, NOW())
+        WHERE family_id = $1
+        RETURNING access_jti
+        "#,
+    )
+    .bind(family_id)
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    for jti in &access_jtis {
+        app_state.token_blacklist.revoke(jti);
+    }
+    Ok(())
+}
+
+/// End every session of a user: all refresh tokens revoked, the access
+/// tokens issued with them blacklisted
+pub(crate) async fn revoke_user_sessions(app_state: &AppState, user_id: Uuid) -> Result<(), AppError> {
+    let access_jtis: Vec<String> = sqlx::query_scalar(
+        "UPDATE refresh_tokens SET revoked_at = COALESCE(revoked_at, NOW()) WHERE user_id = $1 RETURNING access_jti",
+    )
+    .bind(user_id)
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    for jti in &access_jtis {
+        app_state.token_blacklist.revoke(jti);
+    }
+    app_state.csrf_store.invalidate_token(&user_id.to_string());
+    Ok(())
+}
+
+async fn is_active(pool: &sqlx::PgPool, user_id: Uuid) -> Result<bool, AppError> {
+    Ok(sqlx::query_scalar::<_, bool>("SELECT active FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?
+        .unwrap_or(false))
+}
+
+/// Revoke the refresh token family an access token was issued with
+async fn revoke_session(app_state: &AppState, access_jti: &str) -> Result<(), AppError> {
+    let family: Option<Uuid> = sqlx::query_scalar("SELECT family_id FROM refresh_tokens WHERE access_jti = $1")
+        .bind(access_jti)
+        .fetch_optional(&app_state.pool)
+        .await?;
+    match family {
+        Some(family_id) => revoke_family(app_state, family_id).await,
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refresh_tokens_are_random_and_hashed() {
+        let (a, b) = (new_secret_token(), new_secret_token());
+        assert_ne!(a, b);
+        assert_eq!(a.len(), 43);
+        assert_eq!(hash_token(&a), hash_token(&a));
+        assert_ne!(hash_token(&a), a);
+        assert!(REFRESH_TOKEN_TTL > sec::ACCESS_TOKEN_TTL);
+    }
+}
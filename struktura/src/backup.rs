@@ -0,0 +1,564 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Json},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::types::{time::OffsetDateTime, Json as SqlJson};
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+use validator::{ValidationError, ValidationErrors};
+
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+
+/// Identifies a tenant archive regardless of version
+pub const ARCHIVE_FORMAT: &str = "struktura.tenant-archive";
+/// Newest archive version this build writes and reads
+pub const ARCHIVE_VERSION: u32 = 2;
+
+// =============================================================================
+// ARCHIVE
+// =============================================================================
+
+/// Everything an organization keeps on the server, keyed by usernames
+/// rather than ids so it can be restored into another environment
+///
+/// Version 1 carries the organization settings, the saved material library
+/// (calculation presets) and the metered calculation history. Version 2 adds
+/// project workspaces with their saved calculations and the per-user
+/// calculation history audit trail; version 1 archives still import,
+/// without either.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TenantArchive {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: OffsetDateTime,
+    pub source_org: Uuid,
+    pub organization: ArchivedOrganization,
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub materials: Vec<ArchivedMaterial>,
+    #[serde(default)]
+    pub history: Vec<ArchivedUsage>,
+    #[serde(default)]
+    pub projects: Vec<ArchivedProject>,
+    #[serde(default)]
+    pub calculation_history: Vec<ArchivedCalculation>,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArchivedOrganization {
+    pub name: String,
+    pub rate_limit_per_minute: Option<i32>,
+    pub monthly_calculation_quota: Option<i32>,
+    #[serde(default)]
+    pub password_max_age_days: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArchivedMaterial {
+    pub owner: String,
+    pub name: String,
+    pub material_type: String,
+    pub unit: String,
+    pub unit_price: Option<f64>,
+    pub currency: String,
+    pub waste_factor: Option<f64>,
+    pub density: Option<f64>,
+    pub properties: SqlJson<HashMap<String, f64>>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArchivedUsage {
+    pub username: String,
+    pub feature_name: String,
+    pub experience_level: String,
+    pub accessed_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArchivedProject {
+    /// Source id, only used to attach calculations while exporting
+    #[serde(skip)]
+    pub id: Uuid,
+    pub owner: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+    #[serde(default)]
+    #[sqlx(skip)]
+    pub calculations: Vec<ArchivedProjectCalculation>,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArchivedProjectCalculation {
+    #[serde(skip)]
+    pub project_id: Uuid,
+    pub tier: String,
+    pub calculation_type: String,
+    pub label: Option<String>,
+    pub parameters: SqlJson<Value>,
+    pub response: SqlJson<Value>,
+    pub calculator_version: String,
+    pub created_at: OffsetDateTime,
+    pub last_run_at: OffsetDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArchivedCalculation {
+    pub username: String,
+    pub tier: String,
+    pub path: String,
+    pub calculator_id: Option<String>,
+    pub category: Option<String>,
+    pub parameters_hash: String,
+    pub duration_ms: i32,
+    pub status: i16,
+    pub outcome: String,
+    pub created_at: OffsetDateTime,
+}
+
+impl TenantArchive {
+    /// Refuse archives from another tool or a newer release
+    pub fn check_compatible(&self) -> Result<(), AppError> {
+        let field = if self.format != ARCHIVE_FORMAT {
+            "format"
+        } else if self.version == 0 || self.version > ARCHIVE_VERSION {
+            "version"
+        } else {
+            return Ok(());
+        };
+
+        let mut errors = ValidationErrors::new();
+        errors.add(field, ValidationError::new("unsupported_archive"));
+        Err(AppError::ValidationError(errors))
+    }
+}
+
+/// What an import wrote and what it had to leave out
+#[derive(Debug, Default, Serialize)]
+pub struct ImportReport {
+    pub archive_version: u32,
+    pub materials_restored: u64,
+    pub history_restored: u64,
+    pub history_already_present: u64,
+    pub projects_restored: u64,
+    pub project_calculations_restored: u64,
+    pub calculation_history_restored: u64,
+    pub calculation_history_already_present: u64,
+    /// Archived members with no account in the target organization; their
+    /// rows are skipped until they are added and the import is re-run
+    pub unknown_members: Vec<String>,
+}
+
+// =============================================================================
+// HANDLERS
+// =============================================================================
+
+/// Download an organization's data as a versioned archive (admin only)
+pub async fn export_org_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(org_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    let pool = &state.pool;
+
+    let organization = sqlx::query_as::<_, ArchivedOrganization>(
+        "SELECT name, rate_limit_per_minute, monthly_calculation_quota, password_max_age_days \
+         FROM organizations WHERE id = $1",
+    )
+    .bind(org_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    let members = sqlx::query_scalar::<_, String>("SELECT username FROM users WHERE org_id = $1 ORDER BY username")
+        .bind(org_id)
+        .fetch_all(pool)
+        .await?;
+
+    let materials = sqlx::query_as::<_, ArchivedMaterial>(
+        "SELECT u.username AS owner, m.name, m.material_type, m.unit, m.unit_price, m.currency, \
+                m.waste_factor, m.density, m.properties, m.created_at, m.updated_at \
+         FROM custom_materials m JOIN users u ON u.id = m.user_id \
+         WHERE m.org_id = $1 ORDER BY u.username, m.name",
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    let history = sqlx::query_as::<_, ArchivedUsage>(
+        "SELECT u.username, m.feature_name, m.experience_level_used AS experience_level, m.accessed_at \
+         FROM usage_metrics m JOIN users u ON u.id = m.user_id \
+         WHERE m.org_id = $1 ORDER BY m.accessed_at, m.id",
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    let mut projects = sqlx::query_as::<_, ArchivedProject>(
+        "SELECT p.id, u.username AS owner, p.name, p.description, p.created_at, p.updated_at \
+         FROM projects p JOIN users u ON u.id = p.user_id \
+         WHERE p.org_id = $1 ORDER BY u.username, p.name",
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    let calculations = sqlx::query_as::<_, ArchivedProjectCalculation>(
+        "SELECT c.project_id, c.tier, c.calculation_type, c.label, c.parameters, c.response, \
+                c.calculator_version, c.created_at, c.last_run_at \
+         FROM project_calculations c JOIN projects p ON p.id = c.project_id \
+         WHERE p.org_id = $1 ORDER BY c.created_at, c.id",
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    let positions: HashMap<Uuid, usize> = projects.iter().enumerate().map(|(i, p)| (p.id, i)).collect();
+    for calculation in calculations {
+        if let Some(&i) = positions.get(&calculation.project_id) {
+            projects[i].calculations.push(calculation);
+        }
+    }
+
+    let calculation_history = sqlx::query_as::<_, ArchivedCalculation>(
+        "SELECT u.username, h.tier, h.path, h.calculator_id, h.category, h.parameters_hash, \
+                h.duration_ms, h.status, h.outcome, h.created_at \
+         FROM calculation_history h JOIN users u ON u.id = h.user_id \
+         WHERE h.org_id = $1 ORDER BY h.created_at, h.id",
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    let archive = TenantArchive {
+        format: ARCHIVE_FORMAT.to_string(),
+        version: ARCHIVE_VERSION,
+        exported_at: OffsetDateTime::now_utc(),
+        source_org: org_id,
+        organization,
+        members,
+        materials,
+        history,
+        projects,
+        calculation_history,
+    };
+
+    sec::log_security_event("ORG_EXPORT", Some(&claims.username), None, &org_id.to_string());
+
+    let filename = format!("attachment; filename=\"struktura-org-{}-v{}.json\"", org_id, ARCHIVE_VERSION);
+    Ok(([(header::CONTENT_DISPOSITION, filename)], Json(archive)))
+}
+
+/// Restore an archive into an organization (admin only)
+///
+/// Runs in one transaction and is safe to repeat: materials are matched by
+/// owner and name with the archive winning, projects likewise, and history
+/// rows, project calculations and calculation history already present are
+/// not duplicated. Members must already belong to the target
+/// organization.
+pub async fn import_org_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(org_id): Path<Uuid>,
+    Json(archive): Json<TenantArchive>,
+) -> Result<Json<ImportReport>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    archive.check_compatible()?;
+
+    let mut tx = state.pool.begin().await?;
+
+    sqlx::query(
+        "UPDATE organizations SET name = $2, rate_limit_per_minute = $3, monthly_calculation_quota = $4, \
+         password_max_age_days = $5 WHERE id = $1 RETURNING id",
+    )
+    .bind(org_id)
+    .bind(&archive.organization.name)
+    .bind(archive.organization.rate_limit_per_minute)
+    .bind(archive.organization.monthly_calculation_quota)
+    .bind(archive.organization.password_max_age_days)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    let accounts: HashMap<String, Uuid> = sqlx::query_as::<_, (String, Uuid)>(
+        "SELECT username, id FROM users WHERE org_id = $1",
+    )
+    .bind(org_id)
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .collect();
+
+    let mut report = ImportReport {
+        archive_version: archive.version,
+        unknown_members: archive
+            .members
+            .iter()
+            .filter(|name| !accounts.contains_key(*name))
+            .cloned()
+            .collect(),
+        ..Default::default()
+    };
+
+    for material in &archive.materials {
+        let Some(&user_id) = accounts.get(&material.owner) else { continue };
+        sqlx::query(
+            r#"
+            INSERT INTO custom_materials
+                (user_id, org_id, name, material_type, unit, unit_price, currency,
+                 waste_factor, density, properties, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (user_id, name) DO UPDATE SET
+                org_id = EXCLUDED.org_id, material_type = EXCLUDED.material_type,
+                unit = EXCLUDED.unit, unit_price = EXCLUDED.unit_price,
+                currency = EXCLUDED.currency, waste_factor = EXCLUDED.waste_factor,
+                density = EXCLUDED.density, properties = EXCLUDED.properties,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(org_id)
+        .bind(&material.name)
+        .bind(&material.material_type)
+        .bind(&material.unit)
+        .bind(material.unit_price)
+        .bind(&material.currency)
+        .bind(material.waste_factor)
+        .bind(material.density)
+        .bind(&material.properties)
+        .bind(material.created_at)
+        .bind(material.updated_at)
+        .execute(&mut *tx)
+        .await?;
+        report.materials_restored += 1;
+    }
+
+    for usage in &archive.history {
+        let Some(&user_id) = accounts.get(&usage.username) else { continue };
+        let inserted = sqlx::query(
+            "INSERT INTO usage_metrics (user_id, org_id, feature_name, experience_level_used, accessed_at) \
+             SELECT $1, $2, $3, $4, $5 WHERE NOT EXISTS ( \
+                 SELECT 1 FROM usage_metrics WHERE user_id = $1 AND feature_name = $3 \
+                 AND accessed_at IS NOT DISTINCT FROM $5)",
+        )
+        .bind(user_id)
+        .bind(org_id)
+        .bind(&usage.feature_name)
+        .bind(&usage.experience_level)
+        .bind(usage.accessed_at)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if inserted > 0 {
+            report.history_restored += 1;
+        } else {
+            report.history_already_present += 1;
+        }
+    }
+
+    for project in &archive.projects {
+        let Some(&user_id) = accounts.get(&project.owner) else { continue };
+        let project_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO projects (user_id, org_id, name, description, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (user_id, name) DO UPDATE SET
+                org_id = EXCLUDED.org_id, description = EXCLUDED.description,
+                updated_at = EXCLUDED.updated_at
+            RETURNING id
+            "#,
+        )
+        .bind(user_id)
+        .bind(org_id)
+        .bind(&project.name)
+        .bind(&project.description)
+        .bind(project.created_at)
+        .bind(project.updated_at)
+        .fetch_one(&mut *tx)
+        .await?;
+        report.projects_restored += 1;
+
+        for calculation in &project.calculations {
+            let inserted = sqlx::query(
+                "INSERT INTO project_calculations \
+                     (project_id, tier, calculation_type, label, parameters, response, calculator_version, created_at, last_run_at) \
+                 SELECT $1, $2, $3, $4, $5, $6, $7, $8, $9 WHERE NOT EXISTS ( \
+                     SELECT 1 FROM project_calculations WHERE project_id = $1 AND calculation_type = $3 \
+                     AND label IS NOT DISTINCT FROM $4 AND created_at = $8)",
+            )
+            .bind(project_id)
+            .bind(&calculation.tier)
+            .bind(&calculation.calculation_type)
+            .bind(&calculation.label)
+            .bind(&calculation.parameters)
+            .bind(&calculation.response)
+            .bind(&calculation.calculator_version)
+            .bind(calculation.created_at)
+            .bind(calculation.last_run_at)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+            report.project_calculations_restored += inserted;
+        }
+    }
+
+    for calculation in &archive.calculation_history {
+        let Some(&user_id) = accounts.get(&calculation.username) else { continue };
+        let inserted = sqlx::query(
+            "INSERT INTO calculation_history \
+                 (user_id, org_id, tier, path, calculator_id, category, parameters_hash, duration_ms, status, outcome, created_at) \
+             SELECT $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11 WHERE NOT EXISTS ( \
+                 SELECT 1 FROM calculation_history WHERE user_id = $1 AND path = $4 \
+                 AND parameters_hash = $7 AND created_at = $11)",
+        )
+        .bind(user_id)
+        .bind(org_id)
+        .bind(&calculation.tier)
+        .bind(&calculation.path)
+        .bind(&calculation.calculator_id)
+        .bind(&calculation.category)
+        .bind(&calculation.parameters_hash)
+        .bind(calculation.duration_ms)
+        .bind(calculation.status)
+        .bind(&calculation.outcome)
+        .bind(calculation.created_at)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if inserted > 0 {
+            report.calculation_history_restored += 1;
+        } else {
+            report.calculation_history_already_present += 1;
+        }
+    }
+
+    tx.commit().await?;
+    state.tenants.invalidate();
+
+    sec::log_security_event(
+        "ORG_IMPORT",
+        Some(&claims.username),
+        None,
+        &format!("{} -> {}", archive.source_org, org_id),
+    );
+    Ok(Json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive(version: u32) -> TenantArchive {
+        TenantArchive {
+            format: ARCHIVE_FORMAT.to_string(),
+            version,
+            exported_at: OffsetDateTime::now_utc(),
+            source_org: Uuid::new_v4(),
+            organization: ArchivedOrganization {
+                name: "Acme Builders".to_string(),
+                rate_limit_per_minute: Some(600),
+                monthly_calculation_quota: None,
+                password_max_age_days: None,
+            },
+            members: vec!["alice".to_string()],
+            materials: vec![ArchivedMaterial {
+                owner: "alice".to_string(),
+                name: "Ready-mix 30 MPa".to_string(),
+                material_type: "Concrete".to_string(),
+                unit: "m3".to_string(),
+                unit_price: Some(165.0),
+                currency: "CAD".to_string(),
+                waste_factor: Some(0.05),
+                density: Some(2400.0),
+                properties: SqlJson(HashMap::from([("compressive_strength".to_string(), 30.0)])),
+                created_at: OffsetDateTime::now_utc(),
+                updated_at: OffsetDateTime::now_utc(),
+            }],
+            history: vec![ArchivedUsage {
+                username: "alice".to_string(),
+                feature_name: "calculus.engineer".to_string(),
+                experience_level: "advanced".to_string(),
+                accessed_at: Some(OffsetDateTime::now_utc()),
+            }],
+            projects: vec![ArchivedProject {
+                id: Uuid::new_v4(),
+                owner: "alice".to_string(),
+                name: "Warehouse".to_string(),
+                description: None,
+                created_at: OffsetDateTime::now_utc(),
+                updated_at: OffsetDateTime::now_utc(),
+                calculations: vec![ArchivedProjectCalculation {
+                    project_id: Uuid::new_v4(),
+                    tier: "engineer".to_string(),
+                    calculation_type: "beam_design".to_string(),
+                    label: Some("Roof beam".to_string()),
+                    parameters: SqlJson(serde_json::json!({ "dimensions": { "span": 8.0 } })),
+                    response: SqlJson(serde_json::json!({ "results": [] })),
+                    calculator_version: "2.1.0".to_string(),
+                    created_at: OffsetDateTime::now_utc(),
+                    last_run_at: OffsetDateTime::now_utc(),
+                }],
+            }],
+            calculation_history: vec![ArchivedCalculation {
+                username: "alice".to_string(),
+                tier: "engineer".to_string(),
+                path: "/api/v1/calculus/engineer/calculate".to_string(),
+                calculator_id: Some("beam_design".to_string()),
+                category: Some("structural".to_string()),
+                parameters_hash: "0".repeat(64),
+                duration_ms: 12,
+                status: 200,
+                outcome: "success".to_string(),
+                created_at: OffsetDateTime::now_utc(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_archive_round_trips_through_json() {
+        let json = serde_json::to_string(&archive(ARCHIVE_VERSION)).unwrap();
+        let restored: TenantArchive = serde_json::from_str(&json).unwrap();
+
+        assert!(restored.check_compatible().is_ok());
+        assert_eq!(restored.members, vec!["alice"]);
+        assert_eq!(restored.materials[0].properties.get("compressive_strength"), Some(&30.0));
+        assert_eq!(restored.history[0].feature_name, "calculus.engineer");
+        assert_eq!(restored.projects[0].name, "Warehouse");
+        assert_eq!(restored.projects[0].calculations[0].parameters.0["dimensions"]["span"], 8.0);
+        assert_eq!(restored.calculation_history[0].calculator_id.as_deref(), Some("beam_design"));
+    }
+
+    #[test]
+    fn test_version_one_archives_still_import() {
+        let mut v1 = serde_json::to_value(archive(1)).unwrap();
+        v1.as_object_mut().unwrap().remove("projects");
+        v1.as_object_mut().unwrap().remove("calculation_history");
+
+        let restored: TenantArchive = serde_json::from_value(v1).unwrap();
+        assert!(restored.check_compatible().is_ok());
+        assert_eq!(restored.version, 1);
+        assert!(restored.projects.is_empty());
+        assert!(restored.calculation_history.is_empty());
+        assert_eq!(restored.materials.len(), 1);
+    }
+
+    #[test]
+    fn test_rejects_foreign_and_newer_archives() {
+        assert!(archive(ARCHIVE_VERSION + 1).check_compatible().is_err());
+        assert!(archive(0).check_compatible().is_err());
+
+        let mut foreign = archive(ARCHIVE_VERSION);
+        foreign.format = "other.backup".to_string();
+        assert!(foreign.check_compatible().is_err());
+    }
+}
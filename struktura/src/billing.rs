@@ -0,0 +1,752 @@
+use axum::{
+    body::Bytes,
+    extract::{OptionalFromRequestParts, Request, State},
+    http::{HeaderMap, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use sqlx::types::time::OffsetDateTime;
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+
+use crate::calculus::recommendation::Tier;
+use crate::resilience::{Guard, ResiliencePolicy};
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+use crate::tenancy::{self, TenantScope};
+
+const STRIPE_API: &str = "https://api.stripe.com/v1";
+
+/// Webhook timestamps older than this are rejected as replays
+const SIGNATURE_TOLERANCE_SECS: i64 = 300;
+
+// =============================================================================
+// PLANS & ENTITLEMENTS
+// =============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Plan {
+    Free,
+    Pro,
+    Enterprise,
+}
+
+/// What a plan unlocks
+#[derive(Debug, Clone, Serialize)]
+pub struct Entitlements {
+    pub plan: Plan,
+    pub calculator_tiers: Vec<Tier>,
+    /// Calculations per billing period; `None` = unlimited
+    pub monthly_calculations: Option<u32>,
+    /// PDF/CSV/DXF exports
+    pub exports: bool,
+    /// Programmatic access with API keys
+    pub api_access: bool,
+}
+
+impl Entitlements {
+    pub fn allows(&self, tier: Tier) -> bool {
+        self.calculator_tiers.contains(&tier)
+    }
+}
+
+impl Plan {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Plan::Free => "free",
+            Plan::Pro => "pro",
+            Plan::Enterprise => "enterprise",
+        }
+    }
+
+    pub fn parse(value: &str) -> Plan {
+        match value {
+            "pro" => Plan::Pro,
+            "enterprise" => Plan::Enterprise,
+            _ => Plan::Free,
+        }
+    }
+
+    pub fn entitlements(&self) -> Entitlements {
+        match self {
+            Plan::Free => Entitlements {
+                plan: *self,
+                calculator_tiers: vec![Tier::Beginner],
+                monthly_calculations: Some(50),
+                exports: false,
+                api_access: false,
+            },
+            Plan::Pro => Entitlements {
+                plan: *self,
+                calculator_tiers: vec![Tier::Beginner, Tier::Contractor, Tier::Engineer],
+                monthly_calculations: Some(2000),
+                exports: true,
+                api_access: false,
+            },
+            Plan::Enterprise => Entitlements {
+                plan: *self,
+                calculator_tiers: vec![Tier::Beginner, Tier::Contractor, Tier::Engineer],
+                monthly_calculations: None,
+                exports: true,
+                api_access: true,
+            },
+        }
+    }
+
+    /// Cheapest plan that unlocks a calculator tier
+    pub fn required_for(tier: Tier) -> Plan {
+        [Plan::Free, Plan::Pro, Plan::Enterprise]
+            .into_iter()
+            .find(|p| p.entitlements().allows(tier))
+            .unwrap_or(Plan::Enterprise)
+    }
+
+    /// Cheapest plan that unlocks exports
+    pub fn required_for_exports() -> Plan {
+        [Plan::Free, Plan::Pro, Plan::Enterprise]
+            .into_iter()
+            .find(|p| p.entitlements().exports)
+            .unwrap_or(Plan::Enterprise)
+    }
+}
+
+fn tier_name(tier: Tier) -> &'static str {
+    match tier {
+        Tier::Beginner => "beginner",
+        Tier::Engineer => "engineer",
+        Tier::Contractor => "contractor",
+    }
+}
+
+/// `usage_metrics.experience_level_used` for a calculator tier
+pub(crate) fn experience_level(tier: Tier) -> &'static str {
+    match tier {
+        Tier::Beginner => "beginner",
+        Tier::Contractor => "intermediate",
+        Tier::Engineer => "advanced",
+    }
+}
+
+// =============================================================================
+// CONFIG
+// =============================================================================
+
+/// Stripe settings; billing (and entitlement enforcement) is off without them
+#[derive(Debug, Clone)]
+pub struct BillingConfig {
+    pub secret_key: String,
+    pub webhook_secret: String,
+    /// Stripe price id -> plan
+    pub prices: HashMap<String, Plan>,
+    pub success_url: String,
+    pub cancel_url: String,
+}
+
+impl BillingConfig {
+    /// Reads `STRIPE_SECRET_KEY`, `STRIPE_WEBHOOK_SECRET`, `STRIPE_PRICE_PRO`,
+    /// `STRIPE_PRICE_ENTERPRISE` and `BILLING_RETURN_URL`
+    pub fn from_env() -> Option<Self> {
+        let secret_key = std::env::var("STRIPE_SECRET_KEY").ok()?;
+        let webhook_secret = std::env::var("STRIPE_WEBHOOK_SECRET").ok()?;
+
+        let mut prices = HashMap::new();
+        for (var, plan) in [("STRIPE_PRICE_PRO", Plan::Pro), ("STRIPE_PRICE_ENTERPRISE", Plan::Enterprise)] {
+            if let Ok(price) = std::env::var(var) {
+                prices.insert(price, plan);
+            }
+        }
+
+        let return_url = std::env::var("BILLING_RETURN_URL")
+            .unwrap_or_else(|_| "http://localhost:3000/account/billing".to_string());
+
+        Some(Self {
+            secret_key,
+            webhook_secret,
+            prices,
+            success_url: format!("{}?checkout=success", return_url),
+            cancel_url: format!("{}?checkout=cancelled", return_url),
+        })
+    }
+
+    fn price_for(&self, plan: Plan) -> Option<&str> {
+        self.prices.iter().find(|(_, p)| **p == plan).map(|(id, _)| id.as_str())
+    }
+
+    fn plan_for(&self, price_id: &str) -> Plan {
+        self.prices.get(price_id).copied().unwrap_or(Plan::Free)
+    }
+
+    /// POST to Stripe; never retried, since sessions must not be created twice
+    async fn post(&self, path: &str, form: &[(&str, String)]) -> Result<serde_json::Value, BillingError> {
+        let body = Guard::named("stripe", ResiliencePolicy::no_retry())
+            .call(|_| false, || async {
+                reqwest::Client::new()
+                    .post(format!("{}{}", STRIPE_API, path))
+                    .basic_auth(&self.secret_key, None::<&str>)
+                    .form(form)
+                    .send()
+                    .await?
+                    .text()
+                    .await
+            })
+            .await
+            .map_err(|e| BillingError::Stripe(e.to_string()))?;
+
+        let value: serde_json::Value = serde_json::from_str(&body)
+            .map_err(|e| BillingError::Stripe(e.to_string()))?;
+        if let Some(message) = value.pointer("/error/message").and_then(|m| m.as_str()) {
+            return Err(BillingError::Stripe(message.to_string()));
+        }
+        Ok(value)
+    }
+}
+
+// =============================================================================
+// SUBSCRIPTIONS
+// =============================================================================
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct Subscription {
+    #[serde(skip)]
+    pub stripe_customer_id: String,
+    pub plan: String,
+    pub status: String,
+    pub current_period_start: Option<OffsetDateTime>,
+    pub current_period_end: Option<OffsetDateTime>,
+}
+
+impl Subscription {
+    /// Plan in force; lapsed subscriptions fall back to free
+    pub fn effective_plan(&self) -> Plan {
+        match self.status.as_str() {
+            "active" | "trialing" | "past_due" => Plan::parse(&self.plan),
+            _ => Plan::Free,
+        }
+    }
+}
+
+pub async fn subscription_for(pool: &PgPool, user_id: Uuid) -> Result<Option<Subscription>, sqlx::Error> {
+    sqlx::query_as::<_, Subscription>(
+        "SELECT stripe_customer_id, plan, status, current_period_start, current_period_end \
+         FROM subscriptions WHERE user_id = $1",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Start of the user's billing period (calendar month for free users)
+pub fn period_start(subscription: Option<&Subscription>) -> OffsetDateTime {
+    subscription
+        .and_then(|s| s.current_period_start)
+        .unwrap_or_else(|| {
+            let now = OffsetDateTime::now_utc();
+            now.replace_day(1)
+                .map(|d| d.replace_time(time::Time::MIDNIGHT))
+                .unwrap_or(now)
+        })
+}
+
+pub async fn calculations_since(pool: &PgPool, user_id: Uuid, since: OffsetDateTime) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM usage_metrics \
+         WHERE user_id = $1 AND feature_name LIKE 'calculus.%' AND accessed_at >= $2",
+    )
+    .bind(user_id)
+    .bind(since)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn record_calculation(pool: &PgPool, user_id: Uuid, tier: Tier) -> Result<(), sqlx::Error> {
+    crate::stats::record_usage(pool, user_id, &format!("calculus.{}", tier_name(tier)), experience_level(tier)).await
+}
+
+/// Record `count` calculations in a single insert
+pub async fn record_calculations(pool: &PgPool, user_id: Uuid, tier: Tier, count: usize) -> Result<(), sqlx::Error> {
+    if count == 0 {
+        return Ok(());
+    }
+    sqlx::query(
+        "INSERT INTO usage_metrics (user_id, feature_name, experience_level_used) \
+         SELECT $1, $2, $3 FROM generate_series(1, $4)",
+    )
+    .bind(user_id)
+    .bind(format!("calculus.{}", tier_name(tier)))
+    .bind(experience_level(tier))
+    .bind(count as i32)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+// =============================================================================
+// ENTITLEMENT MIDDLEWARE
+// =============================================================================
+
+/// Response extension for endpoints that run several calculations per
+/// request: how many of them to meter instead of one
+#[derive(Debug, Clone, Copy)]
+pub struct CalculationCount(pub usize);
+
+/// Middleware state: which calculator tier a router serves
+#[derive(Clone)]
+pub struct EntitlementGate {
+    state: Arc<AppState>,
+    tier: Tier,
+}
+
+impl EntitlementGate {
+    pub fn new(state: Arc<AppState>, tier: Tier) -> Self {
+        Self { state, tier }
+    }
+}
+
+fn upgrade_response(message: String, current: Plan, required: Plan) -> Response {
+    let body = Json(serde_json::json!({
+        "error": message,
+        "code": "upgrade_required",
+        "current_plan": current,
+        "required_plan": required,
+        "upgrade": {
+            "checkout": "/api/v1/user/billing/checkout",
+            "login": "/api/v1/auth/login",
+        },
+    }));
+    (StatusCode::PAYMENT_REQUIRED, body).into_response()
+}
+
+/// Refuse `calculations` more calculations in `tier` that the caller's plan
+/// does not cover or that would overrun its monthly quota
+///
+/// Only enforced when Stripe is configured. Anonymous beginner
+/// calculations are left to the trial quota.
+pub async fn check_plan(state: &AppState, user_id: Option<Uuid>, tier: Tier, calculations: usize) -> Result<(), Response> {
+    if state.billing.is_none() {
+        return Ok(());
+    }
+    let Some(user_id) = user_id else {
+        if tier == Tier::Beginner {
+            return Ok(());
+        }
+        return Err(upgrade_response(
+            format!("Sign in with a plan that includes {} calculators", tier_name(tier)),
+            Plan::Free,
+            Plan::required_for(tier),
+        ));
+    };
+
+    let pool = &state.pool;
+    let subscription = subscription_for(pool, user_id).await.map_err(|e| AppError::from(e).into_response())?;
+    let plan = subscription.as_ref().map_or(Plan::Free, |s| s.effective_plan());
+    let entitlements = plan.entitlements();
+
+    if !entitlements.allows(tier) {
+        return Err(upgrade_response(
+            format!("Your plan does not include {} calculators", tier_name(tier)),
+            plan,
+            Plan::required_for(tier),
+        ));
+    }
+
+    if let Some(limit) = entitlements.monthly_calculations {
+        let since = period_start(subscription.as_ref());
+        let used = calculations_since(pool, user_id, since)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+        if let Some(message) = tenancy::quota_shortfall("Monthly calculation quota", used, calculations, limit) {
+            return Err(upgrade_response(
+                message,
+                plan,
+                if plan == Plan::Free { Plan::Pro } else { Plan::Enterprise },
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 402 for a plan without exports
+fn exports_refusal(plan: Plan) -> Option<Response> {
+    (!plan.entitlements().exports).then(|| {
+        upgrade_response("Your plan does not include exports".to_string(), plan, Plan::required_for_exports())
+    })
+}
+
+/// Refuse a PDF/CSV/DXF export the caller's plan does not include
+///
+/// Only enforced when Stripe is configured, like `check_plan`.
+pub async fn check_exports(state: &AppState, user_id: Option<Uuid>) -> Result<(), Response> {
+    if state.billing.is_none() {
+        return Ok(());
+    }
+    let plan = match user_id {
+        Some(user_id) => subscription_for(&state.pool, user_id)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?
+            .map_or(Plan::Free, |s| s.effective_plan()),
+        None => Plan::Free,
+    };
+    exports_refusal(plan).map_or(Ok(()), Err)
+}
+
+/// Enforce plan entitlements on calculation requests and meter usage
+///
+/// Browsing (GET) is always open. Anonymous beginner calculations are left
+/// to the trial quota. Without Stripe configured only organization quotas
+/// are enforced, but signed-in usage is still recorded.
+pub async fn entitlement_middleware(
+    State(gate): State<EntitlementGate>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method() != Method::POST {
+        return next.run(request).await;
+    }
+
+    let (mut parts, body) = request.into_parts();
+    let claims = match <Claims as OptionalFromRequestParts<Arc<AppState>>>::from_request_parts(&mut parts, &gate.state).await {
+        Ok(claims) => claims,
+        Err(e) => return e.into_response(),
+    };
+    let scope = match <TenantScope as OptionalFromRequestParts<Arc<AppState>>>::from_request_parts(&mut parts, &gate.state).await {
+        Ok(scope) => scope,
+        Err(e) => return e.into_response(),
+    };
+    let request = Request::from_parts(parts, body);
+    let pool = &gate.state.pool;
+
+    // Organization quotas apply whether or not Stripe is configured
+    if let Some(scope) = &scope
+        && let Err(response) = tenancy::check_monthly_quota(pool, scope, 1).await
+    {
+        return response;
+    }
+
+    let user_id = claims.as_ref().and_then(|c| Uuid::parse_str(&c.sub).ok());
+    if let Err(response) = check_plan(&gate.state, user_id, gate.tier, 1).await {
+        return response;
+    }
+
+    let response = next.run(request).await;
+
+    // Usage is not metered while the database is read-only
+    let metered = response.status().is_success() && !gate.state.maintenance.is_active();
+    if let Some(user_id) = user_id.filter(|_| metered) {
+        let count = response.extensions().get::<CalculationCount>().map_or(1, |c| c.0);
+        if let Err(e) = record_calculations(pool, user_id, gate.tier, count).await {
+            tracing::error!(error = %e, %user_id, count, "failed to record calculations");
+        }
+    }
+
+    response
+}
+
+// =============================================================================
+// STRIPE WEBHOOKS
+// =============================================================================
+
+/// Check a `Stripe-Signature` header (`t=<ts>,v1=<hex>`) against the raw body
+pub fn verify_signature(payload: &[u8], header: &str, secret: &str, now: i64) -> bool {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", t)) => timestamp = t.parse::<i64>().ok(),
+            Some(("v1", sig)) => signatures.push(sig),
+            _ => {}
+        }
+    }
+
+    let Some(timestamp) = timestamp else { return false };
+    if (now - timestamp).abs() > SIGNATURE_TOLERANCE_SECS {
+        return false;
+    }
+
+    let mut signed = format!("{}.", timestamp).into_bytes();
+    signed.extend_from_slice(payload);
+    signatures.iter().any(|sig| sec::verify_hmac_sha256(secret.as_bytes(), &signed, sig))
+}
+
+#[derive(Deserialize)]
+struct StripeEvent {
+    id: String,
+    #[serde(rename = "type")]
+    event_type: String,
+    data: StripeEventData,
+}
+
+#[derive(Deserialize)]
+struct StripeEventData {
+    object: serde_json::Value,
+}
+
+fn unix_time(value: &serde_json::Value) -> Option<OffsetDateTime> {
+    value.as_i64().and_then(|t| OffsetDateTime::from_unix_timestamp(t).ok())
+}
+
+pub async fn stripe_webhook_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<StatusCode, BillingError> {
+    let config = app_state.billing.as_ref().ok_or(BillingError::NotConfigured)?;
+
+    let signature = headers
+        .get("stripe-signature")
+        .and_then(|h| h.to_str().ok())
+        .ok_or(BillingError::InvalidSignature)?;
+    if !verify_signature(&body, signature, &config.webhook_secret, OffsetDateTime::now_utc().unix_timestamp()) {
+        sec::log_security_event("STRIPE_WEBHOOK", None, None, "Invalid signature");
+        return Err(BillingError::InvalidSignature);
+    }
+
+    let event: StripeEvent = serde_json::from_slice(&body)
+        .map_err(|e| BillingError::Stripe(format!("Malformed event: {}", e)))?;
+
+    // Stripe retries deliveries; apply each event once
+    let fresh = sqlx::query("INSERT INTO stripe_events (id, event_type) VALUES ($1, $2) ON CONFLICT (id) DO NOTHING")
+        .bind(&event.id)
+        .bind(&event.event_type)
+        .execute(&app_state.pool)
+        .await?
+        .rows_affected() > 0;
+    if !fresh {
+        return Ok(StatusCode::OK);
+    }
+
+    let object = &event.data.object;
+    let customer = object["customer"].as_str().unwrap_or_default();
+
+    match event.event_type.as_str() {
+        "checkout.session.completed" => {
+            let user_id = object["client_reference_id"]
+                .as_str()
+                .and_then(|id| Uuid::parse_str(id).ok())
+                .ok_or_else(|| BillingError::Stripe("Checkout session without user reference".to_string()))?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO subscriptions (user_id, stripe_customer_id, stripe_subscription_id)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (user_id) DO UPDATE
+                SET stripe_customer_id = EXCLUDED.stripe_customer_id,
+                    stripe_subscription_id = EXCLUDED.stripe_subscription_id,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(user_id)
+            .bind(customer)
+            .bind(object["subscription"].as_str())
+            .execute(&app_state.pool)
+            .await?;
+        }
+        "customer.subscription.created" | "customer.subscription.updated" => {
+            let price = object.pointer("/items/data/0/price/id").and_then(|p| p.as_str()).unwrap_or_default();
+
+            sqlx::query(
+                r#"
+                UPDATE subscriptions
+                SET stripe_subscription_id = $2, plan = $3, status = $4,
+                    current_period_start = $5, current_period_end = $6,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE stripe_customer_id = $1
+                "#,
+            )
+            .bind(customer)
+            .bind(object["id"].as_str())
+            .bind(config.plan_for(price).as_str())
+            .bind(object["status"].as_str().unwrap_or("incomplete"))
+            .bind(unix_time(&object["current_period_start"]))
+            .bind(unix_time(&object["current_period_end"]))
+            .execute(&app_state.pool)
+            .await?;
+        }
+        "customer.subscription.deleted" => {
+            sqlx::query(
+                "UPDATE subscriptions SET plan = 'free', status = 'canceled', updated_at = CURRENT_TIMESTAMP \
+                 WHERE stripe_customer_id = $1",
+            )
+            .bind(customer)
+            .execute(&app_state.pool)
+            .await?;
+        }
+        _ => {}
+    }
+
+    sec::log_security_event("STRIPE_WEBHOOK", None, None, &event.event_type);
+    Ok(StatusCode::OK)
+}
+
+// =============================================================================
+// USER HANDLERS
+// =============================================================================
+
+#[derive(Serialize)]
+pub struct BillingOverview {
+    pub billing_enabled: bool,
+    pub subscription: Option<Subscription>,
+    pub entitlements: Entitlements,
+    pub calculations_this_period: i64,
+}
+
+pub async fn billing_overview_handler(
+    State(app_state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<BillingOverview>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+    let subscription = subscription_for(&app_state.pool, user_id).await?;
+    let plan = subscription.as_ref().map_or(Plan::Free, |s| s.effective_plan());
+    let used = calculations_since(&app_state.pool, user_id, period_start(subscription.as_ref())).await?;
+
+    Ok(Json(BillingOverview {
+        billing_enabled: app_state.billing.is_some(),
+        subscription,
+        entitlements: plan.entitlements(),
+        calculations_this_period: used,
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct CheckoutRequest {
+    pub plan: Plan,
+}
+
+#[derive(Serialize)]
+pub struct RedirectResponse {
+    pub url: String,
+}
+
+/// Start a Stripe Checkout session for a paid plan
+pub async fn create_checkout_handler(
+    State(app_state): State<Arc<AppState>>,
+    claims: Claims,
+    Json(payload): Json<CheckoutRequest>,
+) -> Result<Json<RedirectResponse>, BillingError> {
+    let config = app_state.billing.as_ref().ok_or(BillingError::NotConfigured)?;
+    let price = config
+        .price_for(payload.plan)
+        .ok_or_else(|| BillingError::Stripe(format!("No price configured for the {} plan", payload.plan.as_str())))?;
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+
+    let mut form = vec![
+        ("mode", "subscription".to_string()),
+        ("line_items[0][price]", price.to_string()),
+        ("line_items[0][quantity]", "1".to_string()),
+        ("client_reference_id", user_id.to_string()),
+        ("success_url", config.success_url.clone()),
+        ("cancel_url", config.cancel_url.clone()),
+    ];
+    if let Some(existing) = subscription_for(&app_state.pool, user_id).await? {
+        form.push(("customer", existing.stripe_customer_id));
+    }
+
+    let session = config.post("/checkout/sessions", &form).await?;
+    let url = session["url"].as_str().unwrap_or_default().to_string();
+
+    sec::log_security_event("BILLING_CHECKOUT", Some(&claims.username), None, payload.plan.as_str());
+    Ok(Json(RedirectResponse { url }))
+}
+
+/// Open the Stripe customer portal to change or cancel a subscription
+pub async fn create_portal_handler(
+    State(app_state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<RedirectResponse>, BillingError> {
+    let config = app_state.billing.as_ref().ok_or(BillingError::NotConfigured)?;
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+    let subscription = subscription_for(&app_state.pool, user_id)
+        .await?
+        .ok_or(BillingError::NoSubscription)?;
+
+    let session = config
+        .post(
+            "/billing_portal/sessions",
+            &[("customer", subscription.stripe_customer_id), ("return_url", config.success_url.clone())],
+        )
+        .await?;
+
+    Ok(Json(RedirectResponse {
+        url: session["url"].as_str().unwrap_or_default().to_string(),
+    }))
+}
+
+// =============================================================================
+// ERRORS
+// =============================================================================
+
+#[derive(Debug)]
+pub enum BillingError {
+    NotConfigured,
+    NoSubscription,
+    InvalidSignature,
+    Stripe(String),
+    App(AppError),
+}
+
+impl From<AppError> for BillingError {
+    fn from(e: AppError) -> Self {
+        BillingError::App(e)
+    }
+}
+
+impl From<sqlx::Error> for BillingError {
+    fn from(e: sqlx::Error) -> Self {
+        BillingError::App(AppError::DbError(e))
+    }
+}
+
+impl IntoResponse for BillingError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match self {
+            BillingError::NotConfigured => (StatusCode::SERVICE_UNAVAILABLE, "Billing is not enabled".to_string()),
+            BillingError::NoSubscription => (StatusCode::NOT_FOUND, "No subscription on file".to_string()),
+            BillingError::InvalidSignature => (StatusCode::BAD_REQUEST, "Invalid webhook signature".to_string()),
+            BillingError::Stripe(msg) => {
+                tracing::error!(error = %msg, "stripe request failed");
+                (StatusCode::BAD_GATEWAY, "Payment provider error".to_string())
+            }
+            BillingError::App(e) => return e.into_response(),
+        };
+        (status, Json(serde_json::json!({ "error": msg }))).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_entitlements() {
+        assert!(!Plan::Free.entitlements().allows(Tier::Engineer));
+        assert!(Plan::Pro.entitlements().allows(Tier::Engineer));
+        assert_eq!(Plan::required_for(Tier::Contractor), Plan::Pro);
+        assert_eq!(Plan::required_for(Tier::Beginner), Plan::Free);
+        assert_eq!(Plan::Enterprise.entitlements().monthly_calculations, None);
+    }
+
+    #[test]
+    fn test_exports_need_a_paid_plan() {
+        let refused = exports_refusal(Plan::Free).expect("free plans have no exports");
+        assert_eq!(refused.status(), StatusCode::PAYMENT_REQUIRED);
+        assert_eq!(Plan::required_for_exports(), Plan::Pro);
+        assert!(exports_refusal(Plan::Pro).is_none());
+        assert!(exports_refusal(Plan::Enterprise).is_none());
+    }
+
+    #[test]
+    fn test_webhook_signature() {
+        let payload = br#"{"id":"evt_1"}"#;
+        let mut signed = b"1700000000.".to_vec();
+        signed.extend_from_slice(payload);
+        let sig = sec::sign_hmac_sha256(b"whsec_test", &signed);
+        let header = format!("t=1700000000,v1={}", sig);
+
+        assert!(verify_signature(payload, &header, "whsec_test", 1700000100));
+        assert!(!verify_signature(payload, &header, "whsec_other", 1700000100));
+        // Replayed long after signing
+        assert!(!verify_signature(payload, &header, "whsec_test", 1700009999));
+    }
+}
@@ -0,0 +1,25 @@
+use std::{fs, io, path::Path};
+
+#[tokio::main]
+async fn main() -> Result<(), io::Error> {
+    use struktura::calculus::{beginner, contractor, engineer, selftest};
+
+    // Paths are relative to the crate root, wherever cargo was invoked from
+    let target = Path::new(env!("CARGO_MANIFEST_DIR")).join(selftest::GOLDEN_PATH);
+
+    let fixtures = selftest::record(
+        &beginner::create_default_registry(),
+        &engineer::create_default_registry(),
+        &contractor::create_default_registry(),
+    )
+    .await;
+
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(&fixtures).map_err(io::Error::other)?;
+    fs::write(&target, json + "\n")?;
+    println!("Wrote {} fixtures to {}", fixtures.len(), target.display());
+
+    Ok(())
+}
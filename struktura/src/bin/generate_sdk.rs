@@ -0,0 +1,18 @@
+use std::{fs, io, path::Path};
+
+#[tokio::main]
+async fn main() -> Result<(), io::Error> {
+    // Paths are relative to the crate root, wherever cargo was invoked from
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    for (path, contents) in struktura::sdk::generate().await {
+        let target = root.join(path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, contents)?;
+        println!("Wrote {}", target.display());
+    }
+
+    Ok(())
+}
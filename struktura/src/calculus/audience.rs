@@ -0,0 +1,143 @@
+// ============================================================================
+// Audience Shaping
+//
+// One calculation, several readers. `audience` trims a finished response to
+// what its reader acts on: operators get results and actions, managers get
+// money and how far to trust it, engineers get everything. Calculators never
+// see the audience; shaping only removes, it never recomputes. Engineer is
+// the default, so responses are unchanged unless a narrower view is asked for.
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::calculus::engineer::models::{EngineeringCalculationResponse, WarningSeverity};
+use crate::utils::format::UnitKind;
+
+/// Who a response is being shaped for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Audience {
+    /// Shop floor: what to do next
+    Operator,
+    /// Full detail, including analysis, compliance and traces
+    #[default]
+    Engineer,
+    /// Costs, recommendations and data quality
+    Manager,
+}
+
+impl Audience {
+    pub fn is_full(self) -> bool {
+        self == Audience::Engineer
+    }
+
+    /// Trim an engineering response in place
+    pub fn shape_engineering(self, response: &mut EngineeringCalculationResponse) {
+        match self {
+            Audience::Engineer => return,
+            Audience::Operator => {
+                retain_or_keep_all(&mut response.results, |r| r.is_critical);
+                retain_warnings(response, |s| s != WarningSeverity::Low);
+                response.calculation_metadata = None;
+                response.data_quality = None;
+            }
+            Audience::Manager => {
+                retain_or_keep_all(&mut response.results, |r| {
+                    r.is_critical || UnitKind::of(&r.unit) == UnitKind::Currency
+                });
+                retain_warnings(response, |s| {
+                    matches!(s, WarningSeverity::Critical | WarningSeverity::High)
+                });
+            }
+        }
+        response.analysis = None;
+        response.compliance_notes.clear();
+        response.calculation_trace = None;
+    }
+}
+
+/// Keep matching items, or everything when nothing matches
+fn retain_or_keep_all<T>(items: &mut Vec<T>, keep: impl Fn(&T) -> bool) {
+    if items.iter().any(&keep) {
+        items.retain(keep);
+    }
+}
+
+/// Filter structured warnings and rebuild the legacy list from what remains
+fn retain_warnings(response: &mut EngineeringCalculationResponse, keep: impl Fn(WarningSeverity) -> bool) {
+    if let Some(structured) = response.structured_warnings.as_mut() {
+        structured.retain(|w| keep(w.severity));
+        response.warnings = structured.iter().map(|w| w.message.clone()).collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::models::{
+        CalculationStep, EngineeringResultItem, EngineeringWarning,
+    };
+
+    fn response() -> EngineeringCalculationResponse {
+        let structured = vec![
+            EngineeringWarning {
+                code: "W1".to_string(),
+                severity: WarningSeverity::High,
+                message: "high".to_string(),
+                affected_parameter: None,
+            },
+            EngineeringWarning {
+                code: "W2".to_string(),
+                severity: WarningSeverity::Low,
+                message: "low".to_string(),
+                affected_parameter: None,
+            },
+        ];
+        EngineeringCalculationResponse {
+            calculation_type: "test".to_string(),
+            results: vec![
+                EngineeringResultItem::new("Utilization", 0.8, "ratio").critical(),
+                EngineeringResultItem::new("Depth", 450.0, "mm"),
+                EngineeringResultItem::new("Cost", 1200.0, "USD"),
+            ],
+            analysis: None,
+            warnings: EngineeringWarning::messages(&structured),
+            structured_warnings: Some(structured),
+            recommendations: vec!["Increase depth".to_string()],
+            compliance_notes: vec!["ACI 318".to_string()],
+            calculation_metadata: None,
+            calculation_trace: Some(Vec::<CalculationStep>::new()),
+            data_quality: Some(Default::default()),
+        }
+    }
+
+    #[test]
+    fn test_engineer_view_is_unchanged() {
+        let mut shaped = response();
+        Audience::Engineer.shape_engineering(&mut shaped);
+        assert_eq!(shaped.results.len(), 3);
+        assert_eq!(shaped.warnings.len(), 2);
+        assert!(shaped.calculation_trace.is_some());
+        assert_eq!(shaped.compliance_notes.len(), 1);
+    }
+
+    #[test]
+    fn test_operator_and_manager_views() {
+        let mut operator = response();
+        Audience::Operator.shape_engineering(&mut operator);
+        let labels: Vec<_> = operator.results.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, ["Utilization"]);
+        assert_eq!(operator.warnings, ["high"]);
+        assert_eq!(operator.recommendations.len(), 1);
+        assert!(operator.compliance_notes.is_empty());
+        assert!(operator.calculation_trace.is_none());
+        assert!(operator.data_quality.is_none());
+
+        let mut manager = response();
+        Audience::Manager.shape_engineering(&mut manager);
+        let labels: Vec<_> = manager.results.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, ["Utilization", "Cost"]);
+        assert!(manager.data_quality.is_some());
+        assert!(manager.calculation_trace.is_none());
+    }
+}
@@ -67,6 +67,7 @@ impl BeginnerCalculator for CompostBinCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -191,6 +192,7 @@ mod tests {
             length: 1.0,
             height: 1.0,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
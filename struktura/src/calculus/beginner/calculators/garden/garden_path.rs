@@ -67,6 +67,7 @@ impl BeginnerCalculator for GravelPathCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -212,6 +213,7 @@ impl BeginnerCalculator for SteppingStoneCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -286,6 +288,7 @@ mod tests {
             length: 10.0,
             height: 0.1,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -300,6 +303,7 @@ mod tests {
             length: 10.0,
             height: 0.05,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
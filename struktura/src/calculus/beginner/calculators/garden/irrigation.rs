@@ -67,6 +67,7 @@ impl BeginnerCalculator for DripIrrigationCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -201,6 +202,7 @@ impl BeginnerCalculator for SprinklerCoverageCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -276,6 +278,7 @@ mod tests {
             length: 10.0,
             height: 0.3,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -290,6 +293,7 @@ mod tests {
             length: 30.0,
             height: 4.0,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -67,6 +67,7 @@ impl BeginnerCalculator for LawnSeedCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -207,6 +208,7 @@ impl BeginnerCalculator for SodCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -298,6 +300,7 @@ mod tests {
             length: 20.0,
             height: 0.05,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -312,6 +315,7 @@ mod tests {
             length: 20.0,
             height: 0.05,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
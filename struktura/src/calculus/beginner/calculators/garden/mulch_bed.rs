@@ -67,6 +67,7 @@ impl BeginnerCalculator for MulchBedCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -214,6 +215,7 @@ mod tests {
             length: 5.0,
             height: 0.08,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -228,6 +230,7 @@ mod tests {
             length: 3.0,
             height: 0.03,  // Too thin
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await.unwrap();
@@ -67,6 +67,7 @@ impl BeginnerCalculator for PlanterBoxCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -241,6 +242,7 @@ mod tests {
             length: 2.4,
             height: 0.40,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -255,6 +257,7 @@ mod tests {
             length: 2.0,
             height: 0.15,  // Shallow
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await.unwrap();
@@ -67,6 +67,7 @@ impl BeginnerCalculator for SmallRetainingWallCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -179,6 +180,7 @@ mod tests {
             length: 0.4,
             height: 0.8,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -0,0 +1,380 @@
+use crate::calculus::beginner::{
+    errors::{BeginnerError, BeginnerResult},
+    models::*,
+    traits::{BeginnerCalculator, ParameterValidator},
+};
+use async_trait::async_trait;
+
+/// Sound absorption coefficients at 500 Hz
+const ABSORPTION_DRYWALL: f64 = 0.05;
+const ABSORPTION_HARD_FLOOR: f64 = 0.03;     // Concrete, tile, sealed wood
+const ABSORPTION_CARPET: f64 = 0.30;         // Carpet on underlay
+const ABSORPTION_GLASS: f64 = 0.18;
+const ABSORPTION_CEILING_TILE: f64 = 0.60;   // Mineral fibre lay-in tiles
+const ABSORPTION_PANEL: f64 = 0.90;          // 50 mm fabric-wrapped mineral wool
+
+/// Sabine constant (s/m)
+const SABINE: f64 = 0.161;
+
+/// Absorption panel size and costs (USD)
+const PANEL_AREA: f64 = 0.72;                // 0.6 × 1.2 m
+const PANEL_COST: f64 = 65.00;
+const PANEL_INSTALL_HOURS: f64 = 0.25;
+
+/// Typical laboratory STC ratings, cheapest first
+const WALL_ASSEMBLIES: [(&str, f64); 6] = [
+    ("Single stud, 1 layer drywall each side, empty cavity", 33.0),
+    ("Single stud, 1 layer drywall each side, insulated", 37.0),
+    ("200 mm concrete block, painted both sides", 45.0),
+    ("Single stud on resilient channel, insulated", 47.0),
+    ("Staggered stud, 1 layer drywall each side, insulated", 50.0),
+    ("Double stud, 2 layers drywall one side, insulated", 57.0),
+];
+
+pub struct AcousticTreatmentCalculator;
+
+impl AcousticTreatmentCalculator {
+    /// Optional `additional` value, range-checked when supplied
+    fn optional(&self, params: &BeginnerParameters, name: &str, default: f64, min: f64, max: f64) -> BeginnerResult<f64> {
+        match params.additional.as_ref().and_then(|a| a.get(name)) {
+            Some(&value) => self.validate_dimension(name, value, min, max),
+            None => Ok(default),
+        }
+    }
+}
+
+#[async_trait]
+impl BeginnerCalculator for AcousticTreatmentCalculator {
+    fn id(&self) -> &str {
+        "acoustic_treatment"
+    }
+
+    fn name(&self) -> &str {
+        "Acoustic Treatment & STC Calculator"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Interiors
+    }
+
+    fn metadata(&self) -> BeginnerCalculatorMetadata {
+        let parameters = vec![
+            ParameterMetadata {
+                name: "width".to_string(),
+                path: "width".to_string(),
+                data_type: "number".to_string(),
+                unit: "m".to_string(),
+                description: "Room width".to_string(),
+                required: true,
+                min_value: Some(2.0),
+                max_value: Some(20.0),
+                typical_range: Some((3.0, 8.0)),
+            },
+            ParameterMetadata {
+                name: "length".to_string(),
+                path: "length".to_string(),
+                data_type: "number".to_string(),
+                unit: "m".to_string(),
+                description: "Room length".to_string(),
+                required: true,
+                min_value: Some(2.0),
+                max_value: Some(30.0),
+                typical_range: Some((3.0, 10.0)),
+            },
+            ParameterMetadata {
+                name: "height".to_string(),
+                path: "height".to_string(),
+                data_type: "number".to_string(),
+                unit: "m".to_string(),
+                description: "Ceiling height".to_string(),
+                required: true,
+                min_value: Some(2.0),
+                max_value: Some(6.0),
+                typical_range: Some((2.4, 3.0)),
+            },
+            ParameterMetadata {
+                name: "target_rt".to_string(),
+                path: "additional.target_rt".to_string(),
+                data_type: "number".to_string(),
+                unit: "s".to_string(),
+                description: "Target reverberation time (0.3 s home studio, 0.6 s office); 0.6 s when omitted".to_string(),
+                required: false,
+                min_value: Some(0.2),
+                max_value: Some(2.0),
+                typical_range: Some((0.3, 0.8)),
+            },
+            ParameterMetadata {
+                name: "carpet_fraction".to_string(),
+                path: "additional.carpet_fraction".to_string(),
+                data_type: "number".to_string(),
+                unit: "".to_string(),
+                description: "Share of the floor that is carpeted (0 to 1); hard floor when omitted".to_string(),
+                required: false,
+                min_value: Some(0.0),
+                max_value: Some(1.0),
+                typical_range: Some((0.0, 1.0)),
+            },
+            ParameterMetadata {
+                name: "ceiling_tile_fraction".to_string(),
+                path: "additional.ceiling_tile_fraction".to_string(),
+                data_type: "number".to_string(),
+                unit: "".to_string(),
+                description: "Share of the ceiling with acoustic tiles (0 to 1); drywall when omitted".to_string(),
+                required: false,
+                min_value: Some(0.0),
+                max_value: Some(1.0),
+                typical_range: Some((0.0, 1.0)),
+            },
+            ParameterMetadata {
+                name: "window_area".to_string(),
+                path: "additional.window_area".to_string(),
+                data_type: "number".to_string(),
+                unit: "m²".to_string(),
+                description: "Total glazed area in the walls".to_string(),
+                required: false,
+                min_value: Some(0.0),
+                max_value: Some(100.0),
+                typical_range: Some((0.0, 6.0)),
+            },
+            ParameterMetadata {
+                name: "target_stc".to_string(),
+                path: "additional.target_stc".to_string(),
+                data_type: "number".to_string(),
+                unit: "STC".to_string(),
+                description: "Sound isolation wanted from the partition walls; STC 50 (dwelling separation) when omitted".to_string(),
+                required: false,
+                min_value: Some(25.0),
+                max_value: Some(65.0),
+                typical_range: Some((40.0, 55.0)),
+            },
+        ];
+
+        BeginnerCalculatorMetadata {
+            id: self.id().to_string(),
+            name: self.name().to_string(),
+            category: self.category().as_str().to_string(),
+            description: "Estimate room reverberation time from the surface finishes, the absorption panels needed to reach a target, and typical STC ratings of common wall assemblies.".to_string(),
+            parameters,
+            required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
+            optional_parameters: vec![
+                "target_rt".to_string(),
+                "carpet_fraction".to_string(),
+                "ceiling_tile_fraction".to_string(),
+                "window_area".to_string(),
+                "target_stc".to_string(),
+            ],
+            examples: Vec::new(),
+        }
+    }
+
+    fn validate(&self, params: &BeginnerParameters) -> BeginnerResult<()> {
+        self.validate_dimension("width", params.width, 2.0, 20.0)?;
+        self.validate_dimension("length", params.length, 2.0, 30.0)?;
+        self.validate_dimension("height", params.height, 2.0, 6.0)?;
+        self.optional(params, "target_rt", 0.6, 0.2, 2.0)?;
+        self.optional(params, "carpet_fraction", 0.0, 0.0, 1.0)?;
+        self.optional(params, "ceiling_tile_fraction", 0.0, 0.0, 1.0)?;
+        self.optional(params, "target_stc", 50.0, 25.0, 65.0)?;
+        let windows = self.optional(params, "window_area", 0.0, 0.0, 100.0)?;
+
+        let wall_area = 2.0 * (params.width + params.length) * params.height;
+        if windows > 0.8 * wall_area {
+            return Err(BeginnerError::DomainError {
+                field: "window_area".to_string(),
+                message: format!("{:.1} m² of glazing leaves too little of the {:.1} m² of wall", windows, wall_area),
+            });
+        }
+        Ok(())
+    }
+
+    async fn calculate(&self, params: BeginnerParameters) -> BeginnerResult<BeginnerCalculationResponse> {
+        let mut warnings = Vec::new();
+
+        let target_rt = self.optional(&params, "target_rt", 0.6, 0.2, 2.0)?;
+        let carpet = self.optional(&params, "carpet_fraction", 0.0, 0.0, 1.0)?;
+        let tiles = self.optional(&params, "ceiling_tile_fraction", 0.0, 0.0, 1.0)?;
+        let windows = self.optional(&params, "window_area", 0.0, 0.0, 100.0)?;
+        let target_stc = self.optional(&params, "target_stc", 50.0, 25.0, 65.0)?;
+
+        let floor_area = params.width * params.length;
+        let wall_area = 2.0 * (params.width + params.length) * params.height - windows;
+        let volume = floor_area * params.height;
+
+        // Total absorption A = Σ S·α in m² (metric sabins)
+        let absorption = floor_area * (carpet * ABSORPTION_CARPET + (1.0 - carpet) * ABSORPTION_HARD_FLOOR)
+            + floor_area * (tiles * ABSORPTION_CEILING_TILE + (1.0 - tiles) * ABSORPTION_DRYWALL)
+            + wall_area * ABSORPTION_DRYWALL
+            + windows * ABSORPTION_GLASS;
+
+        // Sabine: RT60 = 0.161·V / A
+        let reverb_time = SABINE * volume / absorption;
+
+        // Wall-mounted panels replace drywall, so each m² adds the difference in absorption
+        let absorption_needed = (SABINE * volume / target_rt - absorption).max(0.0);
+        let panel_area = absorption_needed / (ABSORPTION_PANEL - ABSORPTION_DRYWALL);
+        let panel_count = (panel_area / PANEL_AREA).ceil();
+        let treated_absorption = absorption + panel_count * PANEL_AREA * (ABSORPTION_PANEL - ABSORPTION_DRYWALL);
+        let treated_reverb_time = SABINE * volume / treated_absorption;
+        let panel_cost = panel_count * PANEL_COST;
+        let labor_hours = panel_count * PANEL_INSTALL_HOURS;
+
+        if reverb_time > 1.0 {
+            warnings.push(format!("A {:.1} s reverberation time will sound echoey; speech and recordings lose clarity above about 0.8 s.", reverb_time));
+        }
+        if panel_count * PANEL_AREA > 0.5 * wall_area {
+            warnings.push("Panels would cover more than half the walls. Add a rug, ceiling clouds or acoustic ceiling tiles to share the absorption.".to_string());
+        }
+        if target_rt <= 0.4 {
+            warnings.push("Studio targets need low-frequency control too: add corner bass traps, since thin panels mostly absorb mid and high frequencies.".to_string());
+        }
+        if treated_absorption / (2.0 * floor_area + wall_area + windows) > 0.3 {
+            warnings.push("Sabine's formula overestimates reverberation in heavily treated rooms; the actual time will be somewhat shorter.".to_string());
+        }
+
+        let recommended = WALL_ASSEMBLIES.iter().find(|(_, stc)| *stc >= target_stc);
+        match recommended {
+            Some(_) if target_stc >= 50.0 => warnings.push(
+                "STC ratings assume a sealed wall; back-to-back outlets, gaps and hollow doors can cut isolation by 10 points or more.".to_string(),
+            ),
+            Some(_) => {}
+            None => warnings.push(format!(
+                "No common stud or block wall reaches STC {:.0}; consult an acoustic consultant about decoupled room-within-a-room construction.",
+                target_stc
+            )),
+        }
+
+        let mut results = vec![
+            BeginnerResultItem {
+                label: "Room Volume".to_string(),
+                value: volume,
+                unit: "m³".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Existing Absorption".to_string(),
+                value: absorption,
+                unit: "m²".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Reverberation Time".to_string(),
+                value: reverb_time,
+                unit: "s".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Target Reverberation Time".to_string(),
+                value: target_rt,
+                unit: "s".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Absorption Panel Area".to_string(),
+                value: panel_area,
+                unit: "m²".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Absorption Panels (0.6 × 1.2 m)".to_string(),
+                value: panel_count,
+                unit: "panels".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Treated Reverberation Time".to_string(),
+                value: treated_reverb_time,
+                unit: "s".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Panel Cost".to_string(),
+                value: panel_cost,
+                unit: "USD".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Installation Labor Hours".to_string(),
+                value: labor_hours,
+                unit: "hours".to_string(),
+            },
+        ];
+        results.extend(WALL_ASSEMBLIES.iter().map(|(assembly, stc)| BeginnerResultItem {
+            label: format!("STC: {}", assembly),
+            value: *stc,
+            unit: "STC".to_string(),
+        }));
+        if let Some((assembly, stc)) = recommended {
+            results.push(BeginnerResultItem {
+                label: "Recommended Wall Assembly".to_string(),
+                value: *stc,
+                unit: assembly.to_string(),
+            });
+        }
+
+        Ok(BeginnerCalculationResponse {
+            calculation_type: self.id().to_string(),
+            results,
+            warnings,
+        })
+    }
+}
+
+impl ParameterValidator for AcousticTreatmentCalculator {
+    fn calculator_id(&self) -> &str {
+        self.id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(response: &BeginnerCalculationResponse, label: &str) -> f64 {
+        response.results.iter().find(|r| r.label == label).unwrap().value
+    }
+
+    #[tokio::test]
+    async fn test_bare_room_needs_panels() {
+        let calc = AcousticTreatmentCalculator;
+        let params = BeginnerParameters {
+            width: 4.0,
+            length: 5.0,
+            height: 2.5,
+            ..Default::default()
+        };
+
+        // A = 20·0.03 + 20·0.05 + 45·0.05 = 3.85 m², RT = 0.161·50 / 3.85 ≈ 2.09 s
+        let result = calc.calculate(params).await.unwrap();
+        assert!((value(&result, "Reverberation Time") - 0.161 * 50.0 / 3.85).abs() < 1e-9);
+        let panel_area = (0.161 * 50.0 / 0.6 - 3.85) / 0.85;
+        assert!((value(&result, "Absorption Panel Area") - panel_area).abs() < 1e-9);
+        assert_eq!(value(&result, "Absorption Panels (0.6 × 1.2 m)"), 16.0);
+        assert!(value(&result, "Treated Reverberation Time") <= 0.6);
+        assert_eq!(value(&result, "Recommended Wall Assembly"), 50.0);
+    }
+
+    #[tokio::test]
+    async fn test_furnished_office_needs_no_panels() {
+        let calc = AcousticTreatmentCalculator;
+        let params = BeginnerParameters {
+            width: 4.0,
+            length: 5.0,
+            height: 2.5,
+            additional: Some(
+                [("carpet_fraction".to_string(), 1.0), ("ceiling_tile_fraction".to_string(), 1.0), ("target_rt".to_string(), 0.8)]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        let result = calc.calculate(params).await.unwrap();
+        assert!(value(&result, "Reverberation Time") < 0.8);
+        assert_eq!(value(&result, "Absorption Panels (0.6 × 1.2 m)"), 0.0);
+    }
+
+    #[test]
+    fn test_window_area_limited_by_walls() {
+        let calc = AcousticTreatmentCalculator;
+        let params = BeginnerParameters {
+            width: 3.0,
+            length: 3.0,
+            height: 2.5,
+            additional: Some([("window_area".to_string(), 40.0)].into_iter().collect()),
+            ..Default::default()
+        };
+        assert!(calc.validate(&params).is_err());
+    }
+}
@@ -79,6 +79,7 @@ impl BeginnerCalculator for DropCeilingCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -268,6 +269,7 @@ impl BeginnerCalculator for DrywallCeilingCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
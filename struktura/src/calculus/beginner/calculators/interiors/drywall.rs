@@ -76,6 +76,7 @@ impl BeginnerCalculator for DrywallCountCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -214,6 +215,7 @@ mod tests {
             length: 4.0,
             height: 1.0,  // Not used
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -228,6 +230,7 @@ mod tests {
             length: 10.0,
             height: 1.0,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await.unwrap();
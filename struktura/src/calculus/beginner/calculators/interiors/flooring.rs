@@ -82,6 +82,7 @@ impl BeginnerCalculator for HardwoodFlooringCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -266,6 +267,7 @@ impl BeginnerCalculator for LaminateFlooringCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
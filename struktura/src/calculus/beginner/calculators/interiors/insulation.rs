@@ -74,6 +74,7 @@ impl BeginnerCalculator for InsulationCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -217,6 +218,7 @@ mod tests {
             length: 3.0,
             height: 0.089,  // 2x4 cavity
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -231,6 +233,7 @@ mod tests {
             length: 3.0,
             height: 0.140,  // 2x6 cavity
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -11,6 +11,7 @@
 // - insulation.rs:      Thermal insulation materials and R-values
 // - ceiling.rs:         Drop ceiling, drywall ceiling, material counts
 // - trim.rs:            Baseboards, crown molding, door/window casing
+// - acoustic.rs:        Reverberation time, absorption panels, wall STC ratings
 // ============================================================================
 
 mod wall_framing;
@@ -19,6 +20,7 @@ mod flooring;
 mod insulation;
 mod ceiling;
 mod trim;
+mod acoustic;
 
 // Strategic re-exports for external access
 pub use wall_framing::WallFramingCalculator;
@@ -27,6 +29,7 @@ pub use flooring::{HardwoodFlooringCalculator, LaminateFlooringCalculator};
 pub use insulation::InsulationCalculator;
 pub use ceiling::{DropCeilingCalculator, DrywallCeilingCalculator};
 pub use trim::{BaseboardCalculator, CrownMoldingCalculator};
+pub use acoustic::AcousticTreatmentCalculator;
 
 // Material constants shared across calculators
 pub mod constants {
@@ -72,5 +75,6 @@ mod tests {
         let _ = DrywallCeilingCalculator;
         let _ = BaseboardCalculator;
         let _ = CrownMoldingCalculator;
+        let _ = AcousticTreatmentCalculator;
     }
 }
\ No newline at end of file
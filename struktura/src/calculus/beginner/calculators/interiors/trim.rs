@@ -80,6 +80,7 @@ impl BeginnerCalculator for BaseboardCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -279,6 +280,7 @@ impl BeginnerCalculator for CrownMoldingCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -417,6 +419,7 @@ mod tests {
             length: 5.0,
             height: 1.0,  // One doorway
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -431,6 +434,7 @@ mod tests {
             length: 5.0,
             height: 2.44,  // Standard ceiling
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -74,6 +74,7 @@ impl BeginnerCalculator for WallFramingCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -212,6 +213,7 @@ mod tests {
             length: 4.0,    // 4m wall
             height: 2.44,   // 8ft ceiling
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -229,6 +231,7 @@ mod tests {
             length: 10.0,   // Long wall
             height: 2.44,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await.unwrap();
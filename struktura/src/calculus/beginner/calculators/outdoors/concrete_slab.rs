@@ -3,9 +3,15 @@ use crate::calculus::beginner::{
     models::*,
     traits::{BeginnerCalculator, ParameterValidator},
 };
+use crate::utils::drawing::{spaced, Drawing, Layer, Point};
 use async_trait::async_trait;
 use super::constants::*;
 
+/// Nominal bar spacing each way, for the reinforcement sketch
+const REBAR_SPACING: f64 = 0.3;
+/// Edge cover to the outermost bars
+const REBAR_COVER: f64 = 0.075;
+
 pub struct ConcreteSlabCalculator;
 
 #[async_trait]
@@ -67,6 +73,7 @@ impl BeginnerCalculator for ConcreteSlabCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -172,6 +179,40 @@ impl BeginnerCalculator for ConcreteSlabCalculator {
             warnings,
         })
     }
+
+    /// Plan view: outline with a reinforcement grid inside the cover
+    fn drawing(&self, params: &BeginnerParameters) -> Option<Drawing> {
+        let (w, l) = (params.width, params.length);
+        let (x0, x1, y0, y1) = (REBAR_COVER, w - REBAR_COVER, REBAR_COVER, l - REBAR_COVER);
+        let mut drawing = Drawing::new("Slab reinforcement layout");
+
+        if x1 > x0 && y1 > y0 {
+            let bars_x = spaced(x0, x1, ((x1 - x0) / REBAR_SPACING).ceil() as usize + 1);
+            let bars_y = spaced(y0, y1, ((y1 - y0) / REBAR_SPACING).ceil() as usize + 1);
+            for x in &bars_x {
+                drawing = drawing.line(Point::new(*x, y0), Point::new(*x, y1), Layer::Reinforcement);
+            }
+            for y in &bars_y {
+                drawing = drawing.line(Point::new(x0, *y), Point::new(x1, *y), Layer::Reinforcement);
+            }
+            if let Some(second) = bars_x.get(1) {
+                drawing = drawing.dimension(
+                    Point::new(x0, y1),
+                    Point::new(*second, y1),
+                    -(REBAR_COVER + 0.2),
+                    Some(&format!("bars {:.0} mm e.w.", (second - x0) * 1000.0)),
+                );
+            }
+        }
+
+        Some(
+            drawing
+                .rect(Point::new(0.0, 0.0), w, l, Layer::Outline)
+                .dimension(Point::new(0.0, 0.0), Point::new(w, 0.0), -0.4, None)
+                .dimension(Point::new(0.0, 0.0), Point::new(0.0, l), 0.4, None)
+                .label(Point::new(w / 2.0, l / 2.0), format!("{:.0} mm thick", params.height * 1000.0)),
+        )
+    }
 }
 
 impl ParameterValidator for ConcreteSlabCalculator {
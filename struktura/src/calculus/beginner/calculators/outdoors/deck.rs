@@ -3,6 +3,8 @@ use crate::calculus::beginner::{
     models::*,
     traits::{BeginnerCalculator, ParameterValidator},
 };
+use crate::pricing::{MaterialCategory, MaterialId};
+use crate::utils::drawing::{spaced, Drawing, Layer, Point};
 use async_trait::async_trait;
 use super::constants::*;
 
@@ -10,6 +12,12 @@ use super::constants::*;
 const DECK_BOARD_COVERAGE_M2: f64 = 0.18; // Standard 140mm x 1.2m board
 const DECK_JOIST_SPACING: f64 = 0.4; // 40cm joist spacing
 const DECK_BOARD_COST: f64 = 12.50;
+const DECK_POST_COST: f64 = 15.0;
+
+// Live pricing codes
+const DECK_BOARD_CODE: &str = "deck_board_140mm_1200";
+const JOIST_CODE: &str = "lumber_2x6_treated";
+const POST_CODE: &str = "post_4x4_treated";
 
 pub struct DeckCalculator;
 
@@ -72,6 +80,7 @@ impl BeginnerCalculator for DeckCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -97,11 +106,12 @@ impl BeginnerCalculator for DeckCalculator {
         }
         
         let boards_needed = (area / DECK_BOARD_COVERAGE_M2).ceil() * 1.10;
-        let board_cost = boards_needed * DECK_BOARD_COST;
+        let prices = &params.live_prices;
+        let board_cost = boards_needed * prices.price_or(DECK_BOARD_CODE, DECK_BOARD_COST);
         
         let num_joists = (params.length / DECK_JOIST_SPACING).ceil() + 2.0;
         let total_joist_length = num_joists * params.width;
-        let joist_cost = total_joist_length * TREATED_2X6_COST_PER_M;
+        let joist_cost = total_joist_length * prices.price_or(JOIST_CODE, TREATED_2X6_COST_PER_M);
         
         let hardware_cost = area * HARDWARE_COST_PER_M2;
         
@@ -110,7 +120,7 @@ impl BeginnerCalculator for DeckCalculator {
         } else {
             0.0
         };
-        let post_cost = posts_needed * 15.0;
+        let post_cost = posts_needed * prices.price_or(POST_CODE, DECK_POST_COST);
         
         let total_cost = board_cost + joist_cost + hardware_cost + post_cost;
         
@@ -168,6 +178,51 @@ impl BeginnerCalculator for DeckCalculator {
             warnings,
         })
     }
+
+    fn priced_materials(&self, _params: &BeginnerParameters) -> Vec<MaterialId> {
+        vec![
+            MaterialId::new(MaterialCategory::Decking, DECK_BOARD_CODE, "unit", "Deck board 140mm x 1.2m"),
+            MaterialId::new(MaterialCategory::Lumber, JOIST_CODE, "m", "Treated 2x6 joist lumber"),
+            MaterialId::new(MaterialCategory::Lumber, POST_CODE, "unit", "Treated 4x4 post"),
+        ]
+    }
+
+    /// Plan view: outline, joists across the width, and posts for raised decks
+    fn drawing(&self, params: &BeginnerParameters) -> Option<Drawing> {
+        let (w, l) = (params.width, params.length);
+        let joists = spaced(0.0, l, (l / DECK_JOIST_SPACING).ceil() as usize + 1);
+        let joist_spacing = joists.get(1).copied().unwrap_or(l);
+
+        let mut drawing = Drawing::new("Deck framing plan");
+        for y in &joists {
+            drawing = drawing.line(Point::new(0.0, *y), Point::new(w, *y), Layer::Framing);
+        }
+        drawing = drawing.rect(Point::new(0.0, 0.0), w, l, Layer::Outline);
+
+        if params.height > 0.5 {
+            let (mut nx, mut ny) = ((w / 2.0).ceil() as usize, (l / 2.0).ceil() as usize);
+            if nx * ny < 4 {
+                (nx, ny) = (2, 2);
+            }
+            for x in spaced(0.0, w, nx) {
+                for y in spaced(0.0, l, ny) {
+                    drawing = drawing.circle(Point::new(x, y), 0.045, Layer::Posts);
+                }
+            }
+        }
+
+        Some(
+            drawing
+                .dimension(Point::new(0.0, 0.0), Point::new(w, 0.0), -0.4, None)
+                .dimension(Point::new(0.0, 0.0), Point::new(0.0, l), 0.4, None)
+                .dimension(
+                    Point::new(w, 0.0),
+                    Point::new(w, joist_spacing),
+                    -0.3,
+                    Some(&format!("joists {:.0} mm o.c.", joist_spacing * 1000.0)),
+                ),
+        )
+    }
 }
 
 impl ParameterValidator for DeckCalculator {
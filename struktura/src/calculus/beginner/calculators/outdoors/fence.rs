@@ -3,6 +3,7 @@ use crate::calculus::beginner::{
     models::*,
     traits::{BeginnerCalculator, ParameterValidator},
 };
+use crate::utils::drawing::{spaced, Drawing, Layer, Point};
 use async_trait::async_trait;
 use super::constants::*;
 
@@ -12,6 +13,8 @@ const FENCE_POST_SPACING: f64 = 2.4;
 const FENCE_POST_COST: f64 = 18.0;
 const CONCRETE_PER_POST_M3: f64 = 0.035; // ~35 liters per post
 const GATE_COST: f64 = 120.0;
+/// Opening left for each gate
+const GATE_WIDTH: f64 = 1.2;
 
 pub struct FenceCalculator;
 
@@ -74,6 +77,7 @@ impl BeginnerCalculator for FenceCalculator {
             parameters,
             required_parameters: vec!["length".to_string(), "height".to_string()],
             optional_parameters: vec!["width".to_string()],
+            examples: Vec::new(),
         }
     }
 
@@ -105,7 +109,7 @@ impl BeginnerCalculator for FenceCalculator {
         }
         
         // Account for gate openings (typically 1.2m each)
-        let gate_length = num_gates * 1.2;
+        let gate_length = num_gates * GATE_WIDTH;
         let fence_panel_length = params.length - gate_length;
         
         // Fence panels and posts
@@ -201,6 +205,44 @@ impl BeginnerCalculator for FenceCalculator {
             warnings,
         })
     }
+
+    /// Plan view of the run: gates first, then panels, with every post
+    fn drawing(&self, params: &BeginnerParameters) -> Option<Drawing> {
+        let num_gates = params.width.round().max(0.0) as usize;
+        let gate_run = (num_gates as f64 * GATE_WIDTH).min(params.length);
+        let panels = ((params.length - gate_run) / FENCE_PANEL_WIDTH).ceil() as usize;
+
+        let mut drawing = Drawing::new("Fence post layout");
+        let mut posts = Vec::new();
+        for gate in 0..num_gates {
+            let (a, b) = (gate as f64 * GATE_WIDTH, (gate + 1) as f64 * GATE_WIDTH);
+            if b > params.length {
+                break;
+            }
+            drawing = drawing
+                .line(Point::new(a, 0.0), Point::new(b, GATE_WIDTH * 0.5), Layer::Framing)
+                .dimension(Point::new(a, 0.0), Point::new(b, 0.0), 0.4, Some("gate"));
+            posts.extend([a, b]);
+        }
+        posts.extend(spaced(gate_run, params.length, panels + 1));
+        posts.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+
+        drawing = drawing.line(Point::new(gate_run, 0.0), Point::new(params.length, 0.0), Layer::Outline);
+        for x in &posts {
+            drawing = drawing.circle(Point::new(*x, 0.0), 0.05, Layer::Posts);
+        }
+        if panels > 0 {
+            let panel = (params.length - gate_run) / panels as f64;
+            drawing = drawing.dimension(
+                Point::new(gate_run, 0.0),
+                Point::new(gate_run + panel, 0.0),
+                0.4,
+                Some(&format!("posts {:.2} m o.c.", panel)),
+            );
+        }
+
+        Some(drawing.dimension(Point::new(0.0, 0.0), Point::new(params.length, 0.0), -0.6, None))
+    }
 }
 
 impl ParameterValidator for FenceCalculator {
@@ -72,6 +72,7 @@ impl BeginnerCalculator for RetainingWallCalculator {
             parameters,
             required_parameters: vec!["length".to_string(), "height".to_string(), "width".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -71,6 +71,7 @@ impl BeginnerCalculator for ShedFoundationCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
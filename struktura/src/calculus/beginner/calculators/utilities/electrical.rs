@@ -0,0 +1,445 @@
+use crate::calculus::beginner::{
+    errors::{BeginnerError, BeginnerResult},
+    models::*,
+    traits::{BeginnerCalculator, ParameterValidator},
+};
+use async_trait::async_trait;
+
+/// NEC Article 220 dwelling loads (VA)
+const GENERAL_LIGHTING_VA_PER_M2: f64 = 32.3;  // 3 VA/ft², 220.41
+const SMALL_APPLIANCE_CIRCUIT_VA: f64 = 1500.0; // Two kitchen circuits, 220.52(A)
+const LAUNDRY_CIRCUIT_VA: f64 = 1500.0;         // 220.52(B)
+const MIN_DRYER_VA: f64 = 5000.0;               // 220.54
+const MIN_EV_CHARGER_VA: f64 = 7200.0;          // 220.57
+const RANGE_DEMAND_VA: f64 = 8000.0;            // Table 220.55, column C, one range up to 12 kW
+
+/// Fastened-in-place appliances assumed in every kitchen (name, VA)
+const FIXED_APPLIANCES: [(&str, f64); 3] = [
+    ("Dishwasher", 1200.0),
+    ("Disposal", 900.0),
+    ("Built-in Microwave", 1500.0),
+];
+
+/// Table 220.42 lighting demand: first 3000 VA at 100%, up to 120 kVA at 35%, remainder at 25%
+const LIGHTING_DEMAND_FIRST: f64 = 3000.0;
+const LIGHTING_DEMAND_SECOND: f64 = 120_000.0;
+
+const SERVICE_VOLTAGE: f64 = 240.0;
+const BRANCH_VOLTAGE: f64 = 120.0;
+const GENERAL_CIRCUIT_VA: f64 = 15.0 * BRANCH_VOLTAGE;
+
+/// Standard service ratings (A); 100 A is the dwelling minimum, 230.79(C)
+const SERVICE_SIZES: [f64; 7] = [100.0, 125.0, 150.0, 200.0, 225.0, 320.0, 400.0];
+
+/// Standard breaker ratings (A), 240.6(A)
+const BREAKER_SIZES: [f64; 14] = [15.0, 20.0, 25.0, 30.0, 35.0, 40.0, 45.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0, 125.0];
+
+/// Common load centre sizes (breaker spaces)
+const PANEL_SPACES: [f64; 5] = [20.0, 24.0, 30.0, 40.0, 42.0];
+
+/// Smallest standard breaker carrying 125% of a continuous or motor load
+fn breaker_for(va: f64, voltage: f64) -> f64 {
+    let amps = 1.25 * va / voltage;
+    BREAKER_SIZES.iter().copied().find(|&b| b >= amps).unwrap_or(BREAKER_SIZES[BREAKER_SIZES.len() - 1])
+}
+
+/// Lighting and small-appliance load after the Table 220.42 demand factors
+fn lighting_demand(va: f64) -> f64 {
+    va.min(LIGHTING_DEMAND_FIRST)
+        + 0.35 * (va.min(LIGHTING_DEMAND_SECOND) - LIGHTING_DEMAND_FIRST).max(0.0)
+        + 0.25 * (va - LIGHTING_DEMAND_SECOND).max(0.0)
+}
+
+pub struct ServiceLoadCalculator;
+
+impl ServiceLoadCalculator {
+    /// Optional `additional` value, range-checked when supplied
+    fn optional(&self, params: &BeginnerParameters, name: &str, default: f64, min: f64, max: f64) -> BeginnerResult<f64> {
+        match params.additional.as_ref().and_then(|a| a.get(name)) {
+            Some(&value) => self.validate_dimension(name, value, min, max),
+            None => Ok(default),
+        }
+    }
+}
+
+#[async_trait]
+impl BeginnerCalculator for ServiceLoadCalculator {
+    fn id(&self) -> &str {
+        "electrical_service_load"
+    }
+
+    fn name(&self) -> &str {
+        "Electrical Service Load & Panel Schedule"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Utilities
+    }
+
+    fn metadata(&self) -> BeginnerCalculatorMetadata {
+        let appliance = |name: &str, description: &str, max: f64, typical: (f64, f64)| ParameterMetadata {
+            name: name.to_string(),
+            path: format!("additional.{}", name),
+            data_type: "number".to_string(),
+            unit: "kW".to_string(),
+            description: description.to_string(),
+            required: false,
+            min_value: Some(0.0),
+            max_value: Some(max),
+            typical_range: Some(typical),
+        };
+        let parameters = vec![
+            ParameterMetadata {
+                name: "width".to_string(),
+                path: "width".to_string(),
+                data_type: "number".to_string(),
+                unit: "m".to_string(),
+                description: "House width".to_string(),
+                required: true,
+                min_value: Some(4.0),
+                max_value: Some(40.0),
+                typical_range: Some((7.0, 14.0)),
+            },
+            ParameterMetadata {
+                name: "length".to_string(),
+                path: "length".to_string(),
+                data_type: "number".to_string(),
+                unit: "m".to_string(),
+                description: "House length".to_string(),
+                required: true,
+                min_value: Some(4.0),
+                max_value: Some(40.0),
+                typical_range: Some((9.0, 18.0)),
+            },
+            ParameterMetadata {
+                name: "height".to_string(),
+                path: "height".to_string(),
+                data_type: "number".to_string(),
+                unit: "floors".to_string(),
+                description: "Number of finished floors with this footprint".to_string(),
+                required: true,
+                min_value: Some(1.0),
+                max_value: Some(4.0),
+                typical_range: Some((1.0, 2.0)),
+            },
+            appliance("range_kw", "Electric range or cooktop nameplate rating; 12 kW when omitted, 0 for gas", 27.0, (8.0, 14.0)),
+            appliance("dryer_kw", "Electric clothes dryer nameplate rating; 5 kW when omitted, 0 for gas", 10.0, (4.5, 6.0)),
+            appliance("water_heater_kw", "Electric water heater rating; 4.5 kW when omitted, 0 for gas", 12.0, (3.8, 5.5)),
+            appliance("heating_kw", "Electric space heating (furnace, baseboard or heat pump strips); none when omitted", 40.0, (5.0, 20.0)),
+            appliance("cooling_kw", "Air conditioner or heat pump electrical input; 3.5 kW when omitted", 15.0, (2.5, 6.0)),
+            appliance("ev_charger_kw", "Electric vehicle charger rating; none when omitted", 19.2, (7.2, 11.5)),
+        ];
+
+        BeginnerCalculatorMetadata {
+            id: self.id().to_string(),
+            name: self.name().to_string(),
+            category: self.category().as_str().to_string(),
+            description: "Estimate a home's electrical service size with the NEC Article 220 standard method and draft a panel schedule. Planning aid only; a licensed electrician must size and install the service.".to_string(),
+            parameters,
+            required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
+            optional_parameters: vec![
+                "range_kw".to_string(),
+                "dryer_kw".to_string(),
+                "water_heater_kw".to_string(),
+                "heating_kw".to_string(),
+                "cooling_kw".to_string(),
+                "ev_charger_kw".to_string(),
+            ],
+            examples: Vec::new(),
+        }
+    }
+
+    fn validate(&self, params: &BeginnerParameters) -> BeginnerResult<()> {
+        self.validate_dimension("width", params.width, 4.0, 40.0)?;
+        self.validate_dimension("length", params.length, 4.0, 40.0)?;
+        self.validate_dimension("height", params.height, 1.0, 4.0)?;
+        self.optional(params, "range_kw", 12.0, 0.0, 27.0)?;
+        self.optional(params, "dryer_kw", 5.0, 0.0, 10.0)?;
+        self.optional(params, "water_heater_kw", 4.5, 0.0, 12.0)?;
+        self.optional(params, "heating_kw", 0.0, 0.0, 40.0)?;
+        self.optional(params, "cooling_kw", 3.5, 0.0, 15.0)?;
+        self.optional(params, "ev_charger_kw", 0.0, 0.0, 19.2)?;
+
+        if params.width * params.length * params.height.round() > 1000.0 {
+            return Err(BeginnerError::DomainError {
+                field: "height".to_string(),
+                message: "Homes over 1000 m² need an engineered service design".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    async fn calculate(&self, params: BeginnerParameters) -> BeginnerResult<BeginnerCalculationResponse> {
+        let mut warnings = Vec::new();
+
+        let floors = params.height.round();
+        let floor_area = params.width * params.length * floors;
+        let range_kw = self.optional(&params, "range_kw", 12.0, 0.0, 27.0)?;
+        let dryer_kw = self.optional(&params, "dryer_kw", 5.0, 0.0, 10.0)?;
+        let water_heater_kw = self.optional(&params, "water_heater_kw", 4.5, 0.0, 12.0)?;
+        let heating_kw = self.optional(&params, "heating_kw", 0.0, 0.0, 40.0)?;
+        let cooling_kw = self.optional(&params, "cooling_kw", 3.5, 0.0, 15.0)?;
+        let ev_kw = self.optional(&params, "ev_charger_kw", 0.0, 0.0, 19.2)?;
+
+        // General lighting plus small-appliance and laundry circuits, then Table 220.42
+        let lighting_va = floor_area * GENERAL_LIGHTING_VA_PER_M2;
+        let connected_lighting = lighting_va + 2.0 * SMALL_APPLIANCE_CIRCUIT_VA + LAUNDRY_CIRCUIT_VA;
+        let lighting_load = lighting_demand(connected_lighting);
+
+        // Fastened-in-place appliances at 75% when there are four or more, 220.53
+        let mut fixed: Vec<(&str, f64)> = FIXED_APPLIANCES.to_vec();
+        if water_heater_kw > 0.0 {
+            fixed.push(("Water Heater", water_heater_kw * 1000.0));
+        }
+        let fixed_connected: f64 = fixed.iter().map(|(_, va)| va).sum();
+        let fixed_factor = if fixed.len() >= 4 { 0.75 } else { 1.0 };
+        let fixed_load = fixed_connected * fixed_factor;
+
+        // Range per Table 220.55 column C: 8 kW up to 12 kW, plus 5% per kW above
+        let range_load = if range_kw > 0.0 {
+            RANGE_DEMAND_VA * (1.0 + 0.05 * (range_kw - 12.0).max(0.0).ceil())
+        } else {
+            0.0
+        };
+        let dryer_load = if dryer_kw > 0.0 { (dryer_kw * 1000.0).max(MIN_DRYER_VA) } else { 0.0 };
+
+        // Heating and cooling are noncoincident; only the larger counts, 220.60
+        let hvac_load = heating_kw.max(cooling_kw) * 1000.0;
+        let ev_load = if ev_kw > 0.0 { (ev_kw * 1000.0).max(MIN_EV_CHARGER_VA) } else { 0.0 };
+
+        let total_load = lighting_load + fixed_load + range_load + dryer_load + hvac_load + ev_load;
+        let service_amps = total_load / SERVICE_VOLTAGE;
+        let service_size = SERVICE_SIZES.iter().copied().find(|&s| s >= service_amps);
+
+        // Panel schedule draft: (circuit, breaker A, poles, number of circuits)
+        let lighting_circuits = (lighting_va / GENERAL_CIRCUIT_VA).ceil().max(1.0);
+        let mut schedule: Vec<(String, f64, f64, f64)> = vec![
+            (format!("General Lighting & Receptacles ({:.0} circuits)", lighting_circuits), 15.0, 1.0, lighting_circuits),
+            ("Kitchen Small Appliance (2 circuits)".to_string(), 20.0, 1.0, 2.0),
+            ("Laundry".to_string(), 20.0, 1.0, 1.0),
+            ("Bathroom Receptacles".to_string(), 20.0, 1.0, 1.0),
+        ];
+        for (name, va) in FIXED_APPLIANCES {
+            schedule.push((name.to_string(), breaker_for(va, BRANCH_VOLTAGE).max(20.0), 1.0, 1.0));
+        }
+        if water_heater_kw > 0.0 {
+            schedule.push(("Water Heater".to_string(), breaker_for(water_heater_kw * 1000.0, SERVICE_VOLTAGE), 2.0, 1.0));
+        }
+        if range_kw > 0.0 {
+            let range_amps = range_load / SERVICE_VOLTAGE;
+            let breaker = BREAKER_SIZES.iter().copied().find(|&b| b >= range_amps).unwrap_or(60.0).max(40.0);
+            schedule.push(("Range".to_string(), breaker, 2.0, 1.0));
+        }
+        if dryer_kw > 0.0 {
+            schedule.push(("Dryer".to_string(), breaker_for(dryer_load, SERVICE_VOLTAGE).max(30.0), 2.0, 1.0));
+        }
+        if heating_kw > 0.0 {
+            schedule.push(("Electric Heat".to_string(), breaker_for(heating_kw * 1000.0, SERVICE_VOLTAGE), 2.0, 1.0));
+        }
+        if cooling_kw > 0.0 {
+            schedule.push(("Air Conditioner".to_string(), breaker_for(cooling_kw * 1000.0, SERVICE_VOLTAGE), 2.0, 1.0));
+        }
+        if ev_kw > 0.0 {
+            schedule.push(("EV Charger".to_string(), breaker_for(ev_load, SERVICE_VOLTAGE), 2.0, 1.0));
+        }
+
+        let spaces: f64 = schedule.iter().map(|(_, _, poles, count)| poles * count).sum();
+        let spare_spaces = (spaces * 0.2).ceil().max(4.0);
+        let panel_spaces = PANEL_SPACES
+            .iter()
+            .copied()
+            .find(|&p| p >= spaces + spare_spaces)
+            .unwrap_or(PANEL_SPACES[PANEL_SPACES.len() - 1]);
+
+        warnings.push("Planning estimate only. Service upgrades and panel work must be designed and installed by a licensed electrician under permit and inspection.".to_string());
+        warnings.push("The utility and local authority have the final say on service size; local amendments to the NEC may apply.".to_string());
+
+        let service_value = match service_size {
+            Some(size) => size,
+            None => {
+                warnings.push(format!(
+                    "Calculated load of {:.0} A exceeds a 400 A residential service. An engineered or multi-meter service is needed.",
+                    service_amps
+                ));
+                service_amps.ceil()
+            }
+        };
+        if service_amps > 0.8 * service_value {
+            warnings.push("Calculated load is above 80% of the service rating. Leave headroom for future loads such as an EV charger or heat pump.".to_string());
+        }
+        if spaces + spare_spaces > PANEL_SPACES[PANEL_SPACES.len() - 1] {
+            warnings.push("More circuits than a single 42-space panel holds. Plan a subpanel.".to_string());
+        }
+
+        let mut results = vec![
+            BeginnerResultItem {
+                label: "Floor Area".to_string(),
+                value: floor_area,
+                unit: "m²".to_string(),
+            },
+            BeginnerResultItem {
+                label: "General Lighting Load".to_string(),
+                value: lighting_va,
+                unit: "VA".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Lighting & Small Appliance Demand".to_string(),
+                value: lighting_load,
+                unit: "VA".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Fixed Appliance Demand".to_string(),
+                value: fixed_load,
+                unit: format!("VA ({:.0}% of {:.0} VA)", fixed_factor * 100.0, fixed_connected),
+            },
+            BeginnerResultItem {
+                label: "Range Demand".to_string(),
+                value: range_load,
+                unit: "VA".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Dryer Demand".to_string(),
+                value: dryer_load,
+                unit: "VA".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Heating/Cooling Demand".to_string(),
+                value: hvac_load,
+                unit: "VA".to_string(),
+            },
+            BeginnerResultItem {
+                label: "EV Charger Demand".to_string(),
+                value: ev_load,
+                unit: "VA".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Total Calculated Load".to_string(),
+                value: total_load,
+                unit: "VA".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Calculated Service Current".to_string(),
+                value: service_amps,
+                unit: "A at 240 V".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Recommended Service Size".to_string(),
+                value: service_value,
+                unit: "A".to_string(),
+            },
+        ];
+        results.extend(schedule.iter().map(|(name, breaker, poles, _)| BeginnerResultItem {
+            label: format!("Circuit: {}", name),
+            value: *breaker,
+            unit: format!("A {:.0}-pole", poles),
+        }));
+        results.extend([
+            BeginnerResultItem {
+                label: "Breaker Spaces Used".to_string(),
+                value: spaces,
+                unit: "spaces".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Recommended Panel Size".to_string(),
+                value: panel_spaces,
+                unit: "spaces".to_string(),
+            },
+        ]);
+
+        Ok(BeginnerCalculationResponse {
+            calculation_type: self.id().to_string(),
+            results,
+            warnings,
+        })
+    }
+}
+
+impl ParameterValidator for ServiceLoadCalculator {
+    fn calculator_id(&self) -> &str {
+        self.id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(result: &BeginnerCalculationResponse, label: &str) -> f64 {
+        result.results.iter().find(|r| r.label == label).unwrap().value
+    }
+
+    #[tokio::test]
+    async fn test_all_electric_house() {
+        let calc = ServiceLoadCalculator;
+        let params = BeginnerParameters {
+            width: 10.0,
+            length: 10.0,
+            height: 2.0,
+            ..Default::default()
+        };
+
+        // 200 m² → 6460 VA lighting + 4500 VA small appliance/laundry = 10960 VA connected
+        let result = calc.calculate(params).await.unwrap();
+        let lighting = 3000.0 + 0.35 * (10960.0 - 3000.0);
+        assert!((value(&result, "Lighting & Small Appliance Demand") - lighting).abs() < 1e-9);
+        // Four fixed appliances with the water heater: 8100 VA at 75%
+        assert!((value(&result, "Fixed Appliance Demand") - 6075.0).abs() < 1e-9);
+        assert_eq!(value(&result, "Range Demand"), 8000.0);
+        let total = lighting + 6075.0 + 8000.0 + 5000.0 + 3500.0;
+        assert!((value(&result, "Total Calculated Load") - total).abs() < 1e-9);
+        assert_eq!(value(&result, "Recommended Service Size"), 125.0);
+        assert_eq!(value(&result, "Circuit: Range"), 40.0);
+        assert_eq!(value(&result, "Circuit: Water Heater"), 25.0);
+        assert!(result.warnings.iter().any(|w| w.contains("licensed electrician")));
+    }
+
+    #[tokio::test]
+    async fn test_heat_pump_and_ev_upsize_service() {
+        let calc = ServiceLoadCalculator;
+        let params = BeginnerParameters {
+            width: 10.0,
+            length: 10.0,
+            height: 2.0,
+            additional: Some(
+                [
+                    ("heating_kw".to_string(), 15.0),
+                    ("ev_charger_kw".to_string(), 11.5),
+                    ("range_kw".to_string(), 14.0),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            ..Default::default()
+        };
+
+        // Heating outweighs cooling (noncoincident), and a 14 kW range adds 10%
+        let result = calc.calculate(params).await.unwrap();
+        assert_eq!(value(&result, "Heating/Cooling Demand"), 15000.0);
+        assert!((value(&result, "Range Demand") - 8800.0).abs() < 1e-9);
+        assert_eq!(value(&result, "EV Charger Demand"), 11500.0);
+        assert_eq!(value(&result, "Recommended Service Size"), 225.0);
+        assert_eq!(value(&result, "Circuit: EV Charger"), 60.0);
+    }
+
+    #[tokio::test]
+    async fn test_gas_appliances_keep_full_demand() {
+        let calc = ServiceLoadCalculator;
+        let params = BeginnerParameters {
+            width: 8.0,
+            length: 10.0,
+            height: 1.0,
+            additional: Some(
+                [("water_heater_kw".to_string(), 0.0), ("range_kw".to_string(), 0.0)]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        let result = calc.calculate(params).await.unwrap();
+        assert_eq!(value(&result, "Fixed Appliance Demand"), 3600.0);
+        assert_eq!(value(&result, "Recommended Service Size"), 100.0);
+        assert!(!result.results.iter().any(|r| r.label == "Circuit: Water Heater"));
+        assert!(!result.results.iter().any(|r| r.label == "Circuit: Range"));
+    }
+}
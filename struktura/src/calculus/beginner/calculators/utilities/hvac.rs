@@ -3,6 +3,7 @@ use crate::calculus::beginner::{
     models::*,
     traits::{BeginnerCalculator, ParameterValidator},
 };
+use crate::utils::conversions::essential::power::{btu_h_to_w, w_to_ton_refrigeration};
 use async_trait::async_trait;
 
 /// HVAC sizing constants
@@ -82,6 +83,7 @@ impl BeginnerCalculator for HVACSizingCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -104,8 +106,8 @@ impl BeginnerCalculator for HVACSizingCalculator {
         // For this basic calculator, assume average conditions
         let adjusted_btu = base_btu;
         
-        // Convert BTU to tons (12,000 BTU = 1 ton)
-        let tons_cooling = adjusted_btu / 12000.0;
+        // Convert BTU/h to tons of refrigeration (12,000 BTU/h = 1 ton)
+        let tons_cooling = w_to_ton_refrigeration(btu_h_to_w(adjusted_btu).await).await;
         
         // Strategic warnings
         warnings.push("This is a simplified estimate. Professional Manual J calculation required for accurate sizing.".to_string());
@@ -18,11 +18,124 @@ const TRACK_SECTION_COST_PER_M: f64 = 28.00;  // 4-foot section
 const TRACK_HEAD_COST: f64 = 22.50;
 const TRACK_CONNECTOR_COST: f64 = 8.50;
 
-/// Lighting design standards
-const RECESSED_SPACING_GENERAL: f64 = 1.8;     // 6 feet spacing
-const RECESSED_SPACING_TASK: f64 = 1.2;        // 4 feet for task lighting
-const LUMENS_PER_M2_LIVING: f64 = 100.0;       // Living spaces
-const LUMENS_PER_M2_KITCHEN: f64 = 300.0;      // Task areas
+/// Lumen-method design values
+const WORKPLANE_HEIGHT: f64 = 0.85;            // Task plane above floor
+const MAX_SPACING_TO_HEIGHT: f64 = 1.5;        // Downlight spacing limit for even light
+const DEFAULT_FIXTURE_LUMENS: f64 = 800.0;     // Typical 6" LED downlight
+const DEFAULT_MAINTENANCE_FACTOR: f64 = 0.8;   // LED depreciation + dirt, clean room
+const TRACK_HEAD_LUMENS: f64 = 450.0;          // Typical LED track head
+const TRACK_CEILING_HEIGHT: f64 = 2.4;         // Assumed ceiling for track runs
+
+/// Target maintained illuminance by room type (lux)
+const ROOM_TYPES: [(&str, f64); 6] = [
+    ("Living room", 150.0),
+    ("Bedroom", 100.0),
+    ("Kitchen", 300.0),
+    ("Bathroom", 200.0),
+    ("Home office", 500.0),
+    ("Hallway", 100.0),
+];
+
+/// Utilization factor of a wide-beam downlight by room index
+/// (ceiling/wall/floor reflectance 0.7/0.5/0.2)
+const UTILIZATION_FACTORS: [(f64, f64); 10] = [
+    (0.6, 0.36),
+    (0.8, 0.43),
+    (1.0, 0.48),
+    (1.25, 0.53),
+    (1.5, 0.57),
+    (2.0, 0.62),
+    (2.5, 0.66),
+    (3.0, 0.69),
+    (4.0, 0.72),
+    (5.0, 0.74),
+];
+
+/// Optional `additional` value, range-checked when supplied
+fn optional<C: ParameterValidator>(calc: &C, params: &BeginnerParameters, name: &str, default: f64, min: f64, max: f64) -> BeginnerResult<f64> {
+    match params.additional.as_ref().and_then(|a| a.get(name)) {
+        Some(&value) => calc.validate_dimension(name, value, min, max),
+        None => Ok(default),
+    }
+}
+
+/// Room type name and target illuminance, honouring an explicit `target_lux`
+fn target_illuminance<C: ParameterValidator>(calc: &C, params: &BeginnerParameters) -> BeginnerResult<(&'static str, f64)> {
+    let room_type = optional(calc, params, "room_type", 1.0, 1.0, ROOM_TYPES.len() as f64)?.round() as usize;
+    let (name, lux) = ROOM_TYPES[room_type - 1];
+    Ok((name, optional(calc, params, "target_lux", lux, 50.0, 1000.0)?))
+}
+
+/// Room index k = L·W / (Hm·(L + W))
+fn room_index(width: f64, length: f64, mounting_height: f64) -> f64 {
+    width * length / (mounting_height * (width + length))
+}
+
+/// Utilization factor interpolated from the downlight table, clamped at its ends
+fn utilization_factor(room_index: f64) -> f64 {
+    let (first, last) = (UTILIZATION_FACTORS[0], UTILIZATION_FACTORS[UTILIZATION_FACTORS.len() - 1]);
+    if room_index <= first.0 {
+        return first.1;
+    }
+    if room_index >= last.0 {
+        return last.1;
+    }
+    UTILIZATION_FACTORS
+        .windows(2)
+        .find(|w| room_index <= w[1].0)
+        .map(|w| w[0].1 + (w[1].1 - w[0].1) * (room_index - w[0].0) / (w[1].0 - w[0].0))
+        .unwrap_or(last.1)
+}
+
+/// Lumen-method parameters shared by both lighting calculators
+fn lumen_parameters(fixture_lumens: f64) -> Vec<ParameterMetadata> {
+    vec![
+        ParameterMetadata {
+            name: "room_type".to_string(),
+            path: "additional.room_type".to_string(),
+            data_type: "number".to_string(),
+            unit: "".to_string(),
+            description: "Room type setting the target light level: 1 living room (150 lux), 2 bedroom (100), 3 kitchen (300), 4 bathroom (200), 5 home office (500), 6 hallway (100); living room when omitted".to_string(),
+            required: false,
+            min_value: Some(1.0),
+            max_value: Some(ROOM_TYPES.len() as f64),
+            typical_range: Some((1.0, 6.0)),
+        },
+        ParameterMetadata {
+            name: "target_lux".to_string(),
+            path: "additional.target_lux".to_string(),
+            data_type: "number".to_string(),
+            unit: "lux".to_string(),
+            description: "Target average light level; overrides the room type value".to_string(),
+            required: false,
+            min_value: Some(50.0),
+            max_value: Some(1000.0),
+            typical_range: Some((100.0, 500.0)),
+        },
+        ParameterMetadata {
+            name: "fixture_lumens".to_string(),
+            path: "additional.fixture_lumens".to_string(),
+            data_type: "number".to_string(),
+            unit: "lumens".to_string(),
+            description: format!("Light output of one fixture (see the package label); {} lm when omitted", fixture_lumens),
+            required: false,
+            min_value: Some(200.0),
+            max_value: Some(3000.0),
+            typical_range: Some((450.0, 1200.0)),
+        },
+        ParameterMetadata {
+            name: "maintenance_factor".to_string(),
+            path: "additional.maintenance_factor".to_string(),
+            data_type: "number".to_string(),
+            unit: "".to_string(),
+            description: "Share of light left after lamp ageing and dirt (0.9 clean, 0.7 dusty); 0.8 when omitted".to_string(),
+            required: false,
+            min_value: Some(0.5),
+            max_value: Some(1.0),
+            typical_range: Some((0.7, 0.9)),
+        },
+    ]
+}
 
 pub struct RecessedLightingCalculator;
 
@@ -41,7 +154,7 @@ impl BeginnerCalculator for RecessedLightingCalculator {
     }
 
     fn metadata(&self) -> BeginnerCalculatorMetadata {
-        let parameters = vec![
+        let mut parameters = vec![
             ParameterMetadata {
                 name: "width".to_string(),
                 path: "width".to_string(),
@@ -76,15 +189,22 @@ impl BeginnerCalculator for RecessedLightingCalculator {
                 typical_range: Some((2.4, 3.0)),
             },
         ];
+        parameters.extend(lumen_parameters(DEFAULT_FIXTURE_LUMENS));
 
         BeginnerCalculatorMetadata {
             id: self.id().to_string(),
             name: self.name().to_string(),
             category: self.category().as_str().to_string(),
-            description: "Calculate recessed lighting layout, materials, and electrical costs for residential spaces.".to_string(),
+            description: "Calculate recessed lighting layout from target light levels (lumen method), plus materials and electrical costs for residential spaces.".to_string(),
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
-            optional_parameters: vec![],
+            optional_parameters: vec![
+                "room_type".to_string(),
+                "target_lux".to_string(),
+                "fixture_lumens".to_string(),
+                "maintenance_factor".to_string(),
+            ],
+            examples: Vec::new(),
         }
     }
 
@@ -92,6 +212,9 @@ impl BeginnerCalculator for RecessedLightingCalculator {
         self.validate_dimension("width", params.width, 2.0, 12.0)?;
         self.validate_dimension("length", params.length, 2.0, 15.0)?;
         self.validate_dimension("height", params.height, 2.0, 4.0)?;
+        target_illuminance(self, params)?;
+        optional(self, params, "fixture_lumens", DEFAULT_FIXTURE_LUMENS, 200.0, 3000.0)?;
+        optional(self, params, "maintenance_factor", DEFAULT_MAINTENANCE_FACTOR, 0.5, 1.0)?;
         Ok(())
     }
 
@@ -99,17 +222,37 @@ impl BeginnerCalculator for RecessedLightingCalculator {
         let mut warnings = Vec::new();
         
         let area = params.width * params.length;
+        let (room_name, target_lux) = target_illuminance(self, &params)?;
+        let fixture_lumens = optional(self, &params, "fixture_lumens", DEFAULT_FIXTURE_LUMENS, 200.0, 3000.0)?;
+        let maintenance_factor = optional(self, &params, "maintenance_factor", DEFAULT_MAINTENANCE_FACTOR, 0.5, 1.0)?;
         
-        // Calculate optimal light count using spacing
-        let lights_across_width = (params.width / RECESSED_SPACING_GENERAL).ceil().max(2.0);
-        let lights_across_length = (params.length / RECESSED_SPACING_GENERAL).ceil().max(2.0);
+        // Lumen method: N = E·A / (Φ·UF·MF), with UF from the room index
+        let mounting_height = params.height - WORKPLANE_HEIGHT;
+        let index = room_index(params.width, params.length, mounting_height);
+        let uf = utilization_factor(index);
+        let lumen_count = (target_lux * area / (fixture_lumens * uf * maintenance_factor)).ceil().max(1.0);
+        
+        // Grid following the room's proportions, tightened where spacing would exceed 1.5 × mounting height
+        let max_spacing = MAX_SPACING_TO_HEIGHT * mounting_height;
+        let grid_length = (lumen_count * params.length / params.width).sqrt().round().max(1.0);
+        let grid_width = (lumen_count / grid_length).ceil();
+        let lights_across_length = grid_length.max((params.length / max_spacing).ceil());
+        let lights_across_width = grid_width.max((params.width / max_spacing).ceil());
         let num_lights = lights_across_width * lights_across_length;
+        let achieved_lux = num_lights * fixture_lumens * uf * maintenance_factor / area;
         
         // Strategic assessment
         warnings.push("Recessed lighting requires ceiling cavity depth. Verify minimum 6 inches clearance above ceiling.".to_string());
         
-        if params.height > 3.0 {
-            warnings.push("High ceilings (>3m) may require higher-lumen bulbs or additional fixtures for adequate brightness.".to_string());
+        if index < 1.0 {
+            warnings.push(format!("Room index {:.2}: narrow or tall rooms lose much of the light to the walls. Lighter wall colours help.", index));
+        }
+        
+        if num_lights > grid_width * grid_length {
+            warnings.push(format!(
+                "Even spacing sets the count here ({:.0} fixtures vs {:.0} for light level). Lower-lumen or dimmable fixtures avoid over-lighting.",
+                num_lights, lumen_count
+            ));
         }
         
         if num_lights > 12.0 {
@@ -135,10 +278,6 @@ impl BeginnerCalculator for RecessedLightingCalculator {
         
         let total_project = total_material + labor_cost;
         
-        // Lighting calculations
-        let recommended_lumens = area * LUMENS_PER_M2_LIVING;
-        let lumens_per_bulb = recommended_lumens / num_lights;
-        
         warnings.push("All electrical work must be performed by licensed electrician per local code requirements.".to_string());
         
         let results = vec![
@@ -147,6 +286,21 @@ impl BeginnerCalculator for RecessedLightingCalculator {
                 value: area,
                 unit: "m²".to_string(),
             },
+            BeginnerResultItem {
+                label: "Target Illuminance".to_string(),
+                value: target_lux,
+                unit: format!("lux ({})", room_name),
+            },
+            BeginnerResultItem {
+                label: "Room Index".to_string(),
+                value: index,
+                unit: "".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Utilization Factor".to_string(),
+                value: uf,
+                unit: "".to_string(),
+            },
             BeginnerResultItem {
                 label: "Recommended Light Count".to_string(),
                 value: num_lights,
@@ -159,9 +313,14 @@ impl BeginnerCalculator for RecessedLightingCalculator {
             },
             BeginnerResultItem {
                 label: "Recommended Lumens per Bulb".to_string(),
-                value: lumens_per_bulb,
+                value: fixture_lumens,
                 unit: "lumens".to_string(),
             },
+            BeginnerResultItem {
+                label: "Average Illuminance".to_string(),
+                value: achieved_lux,
+                unit: "lux".to_string(),
+            },
             BeginnerResultItem {
                 label: "Total Wire Required".to_string(),
                 value: total_wire_length,
@@ -240,7 +399,7 @@ impl BeginnerCalculator for TrackLightingCalculator {
     }
 
     fn metadata(&self) -> BeginnerCalculatorMetadata {
-        let parameters = vec![
+        let mut parameters = vec![
             ParameterMetadata {
                 name: "width".to_string(),
                 path: "width".to_string(),
@@ -275,6 +434,7 @@ impl BeginnerCalculator for TrackLightingCalculator {
                 typical_range: Some((3.0, 8.0)),
             },
         ];
+        parameters.extend(lumen_parameters(TRACK_HEAD_LUMENS));
 
         BeginnerCalculatorMetadata {
             id: self.id().to_string(),
@@ -283,7 +443,13 @@ impl BeginnerCalculator for TrackLightingCalculator {
             description: "Calculate track lighting materials for adjustable accent and task lighting.".to_string(),
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
-            optional_parameters: vec![],
+            optional_parameters: vec![
+                "room_type".to_string(),
+                "target_lux".to_string(),
+                "fixture_lumens".to_string(),
+                "maintenance_factor".to_string(),
+            ],
+            examples: Vec::new(),
         }
     }
 
@@ -291,6 +457,9 @@ impl BeginnerCalculator for TrackLightingCalculator {
         self.validate_dimension("width", params.width, 1.5, 8.0)?;
         self.validate_dimension("length", params.length, 1.2, 10.0)?;
         self.validate_dimension("height", params.height, 2.0, 12.0)?;
+        target_illuminance(self, params)?;
+        optional(self, params, "fixture_lumens", TRACK_HEAD_LUMENS, 200.0, 3000.0)?;
+        optional(self, params, "maintenance_factor", DEFAULT_MAINTENANCE_FACTOR, 0.5, 1.0)?;
         Ok(())
     }
 
@@ -299,9 +468,25 @@ impl BeginnerCalculator for TrackLightingCalculator {
         
         let track_length = params.length;
         let num_heads = params.height.floor();
+        let (room_name, target_lux) = target_illuminance(self, &params)?;
+        let head_lumens = optional(self, &params, "fixture_lumens", TRACK_HEAD_LUMENS, 200.0, 3000.0)?;
+        let maintenance_factor = optional(self, &params, "maintenance_factor", DEFAULT_MAINTENANCE_FACTOR, 0.5, 1.0)?;
+        
+        // Lumen method over the lit area, heads on a standard-height ceiling
+        let area = params.width * track_length;
+        let uf = utilization_factor(room_index(params.width, track_length, TRACK_CEILING_HEIGHT - WORKPLANE_HEIGHT));
+        let achieved_lux = num_heads * head_lumens * uf * maintenance_factor / area;
+        let heads_for_target = (target_lux * area / (head_lumens * uf * maintenance_factor)).ceil();
         
         warnings.push("Track lighting provides flexible, adjustable illumination. Ideal for galleries, kitchens, and display areas.".to_string());
         
+        if achieved_lux < target_lux {
+            warnings.push(format!(
+                "{:.0} lux average is below the {:.0} lux target for a {}. Add ambient lighting or use {:.0} heads.",
+                achieved_lux, target_lux, room_name.to_lowercase(), heads_for_target
+            ));
+        }
+        
         if num_heads > 8.0 {
             warnings.push("More than 8 track heads may overload single circuit. Verify electrical capacity.".to_string());
         }
@@ -341,6 +526,16 @@ impl BeginnerCalculator for TrackLightingCalculator {
                 value: num_heads,
                 unit: "heads".to_string(),
             },
+            BeginnerResultItem {
+                label: "Average Illuminance".to_string(),
+                value: achieved_lux,
+                unit: "lux".to_string(),
+            },
+            BeginnerResultItem {
+                label: "Heads for Target Illuminance".to_string(),
+                value: heads_for_target,
+                unit: format!("heads ({:.0} lux)", target_lux),
+            },
             BeginnerResultItem {
                 label: "Track Sections Required".to_string(),
                 value: track_sections,
@@ -405,4 +600,66 @@ impl ParameterValidator for TrackLightingCalculator {
     fn calculator_id(&self) -> &str {
         self.id()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(result: &BeginnerCalculationResponse, label: &str) -> f64 {
+        result.results.iter().find(|r| r.label == label).unwrap().value
+    }
+
+    #[tokio::test]
+    async fn test_recessed_lumen_method() {
+        let calc = RecessedLightingCalculator;
+        let params = BeginnerParameters {
+            width: 4.0,
+            length: 5.0,
+            height: 2.7,
+            ..Default::default()
+        };
+
+        // k = 20 / (1.85 · 9) ≈ 1.20, UF ≈ 0.520, N = 150·20 / (800·0.520·0.8) ≈ 9.0 → 10, laid out 3 × 4
+        let result = calc.calculate(params).await.unwrap();
+        let uf = 0.48 + 0.05 * (20.0 / (1.85 * 9.0) - 1.0) / 0.25;
+        assert!((value(&result, "Utilization Factor") - uf).abs() < 1e-9);
+        assert_eq!(value(&result, "Target Illuminance"), 150.0);
+        assert_eq!(value(&result, "Recommended Light Count"), 12.0);
+        assert!(value(&result, "Average Illuminance") >= 150.0);
+    }
+
+    #[tokio::test]
+    async fn test_recessed_count_follows_light_level() {
+        let calc = RecessedLightingCalculator;
+        let params = |room_type: f64| BeginnerParameters {
+            width: 4.0,
+            length: 5.0,
+            height: 2.7,
+            additional: Some([("room_type".to_string(), room_type)].into_iter().collect()),
+            ..Default::default()
+        };
+
+        let bedroom = calc.calculate(params(2.0)).await.unwrap();
+        let office = calc.calculate(params(5.0)).await.unwrap();
+        assert!(value(&office, "Recommended Light Count") > 3.0 * value(&bedroom, "Recommended Light Count"));
+        assert!(value(&office, "Average Illuminance") >= 500.0);
+    }
+
+    #[tokio::test]
+    async fn test_track_heads_below_target() {
+        let calc = TrackLightingCalculator;
+        let params = BeginnerParameters {
+            width: 3.0,
+            length: 4.0,
+            height: 4.0,
+            additional: Some([("room_type".to_string(), 3.0)].into_iter().collect()),
+            ..Default::default()
+        };
+
+        let result = calc.calculate(params).await.unwrap();
+        assert!(value(&result, "Average Illuminance") < 300.0);
+        assert!(value(&result, "Heads for Target Illuminance") > 4.0);
+        assert!(result.warnings.iter().any(|w| w.contains("300 lux target for a kitchen")));
+    }
+}
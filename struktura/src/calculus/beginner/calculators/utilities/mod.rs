@@ -9,6 +9,7 @@
 // - tile.rs:        Floor/wall tile materials and installation
 // - wallpaper.rs:   Wallpaper rolls, adhesive, and application
 // - lighting.rs:    Recessed lighting layout and electrical
+// - electrical.rs:  NEC Article 220 service load and panel schedule
 // - hvac.rs:        Basic HVAC sizing and duct material estimates
 // - plumbing.rs:    Pipe materials for basic installations
 // ============================================================================
@@ -17,6 +18,7 @@ mod paint;
 mod tile;
 mod wallpaper;
 mod lighting;
+mod electrical;
 mod hvac;
 mod plumbing;
 
@@ -25,6 +27,7 @@ pub use paint::PaintCoverageCalculator;
 pub use tile::TileCountCalculator;
 pub use wallpaper::WallpaperCalculator;
 pub use lighting::{RecessedLightingCalculator, TrackLightingCalculator};
+pub use electrical::ServiceLoadCalculator;
 pub use hvac::HVACSizingCalculator;
 pub use plumbing::{PipeRunCalculator, DrainLineCalculator};
 
@@ -61,6 +64,7 @@ mod tests {
         let _ = WallpaperCalculator;
         let _ = RecessedLightingCalculator;
         let _ = TrackLightingCalculator;
+        let _ = ServiceLoadCalculator;
         let _ = HVACSizingCalculator;
         let _ = PipeRunCalculator;
         let _ = DrainLineCalculator;
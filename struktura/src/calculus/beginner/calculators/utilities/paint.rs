@@ -84,6 +84,7 @@ impl BeginnerCalculator for PaintCoverageCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string(), "height".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -243,6 +244,7 @@ mod tests {
             length: 5.0,
             height: 2.44,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -257,6 +259,7 @@ mod tests {
             length: 10.0,
             height: 3.0,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await.unwrap();
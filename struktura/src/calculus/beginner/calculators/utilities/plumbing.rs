@@ -85,6 +85,7 @@ impl BeginnerCalculator for PipeRunCalculator {
             parameters,
             required_parameters: vec!["width".to_string()],
             optional_parameters: vec!["length".to_string(), "height".to_string()],
+            examples: Vec::new(),
         }
     }
 
@@ -304,6 +305,7 @@ impl BeginnerCalculator for DrainLineCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string()],
             optional_parameters: vec!["height".to_string()],
+            examples: Vec::new(),
         }
     }
 
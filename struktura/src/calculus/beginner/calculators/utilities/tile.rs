@@ -80,6 +80,7 @@ impl BeginnerCalculator for TileCountCalculator {
             parameters,
             required_parameters: vec!["width".to_string(), "length".to_string()],
             optional_parameters: vec![],
+            examples: Vec::new(),
         }
     }
 
@@ -246,6 +247,7 @@ mod tests {
             length: 3.0,
             height: 1.0,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await;
@@ -260,6 +262,7 @@ mod tests {
             length: 8.0,
             height: 1.0,
             additional: None,
+            ..Default::default()
         };
         
         let result = calc.calculate(params).await.unwrap();
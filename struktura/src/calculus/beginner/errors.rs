@@ -6,6 +6,8 @@ use axum::{
 use serde::Serialize;
 use std::fmt;
 
+use crate::support::ErrorChain;
+
 /// Beginner calculation error types with surgical precision
 #[derive(Debug, Clone)]
 pub enum BeginnerError {
@@ -180,7 +182,9 @@ impl BeginnerError {
 impl IntoResponse for BeginnerError {
     fn into_response(self) -> Response {
         let (status, error_response) = self.to_response();
-        (status, Json(error_response)).into_response()
+        let mut response = (status, Json(error_response)).into_response();
+        response.extensions_mut().insert(ErrorChain::of(&self));
+        response
     }
 }
 
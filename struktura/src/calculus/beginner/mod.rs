@@ -10,6 +10,9 @@
 // - models.rs:    Data structures for inputs, outputs, and metadata
 // - registry.rs:  Thread-safe calculator registry
 // - router.rs:    Axum HTTP router with API endpoints
+// - proposal.rs:  Homeowner proposal export
+// - sketch.rs:    Dimension extraction from annotated sketches
+// - units.rs:     Per-request preferred output units
 // - calculators/: Individual calculator implementations by category
 // ============================================================================
 
@@ -18,7 +21,10 @@ pub mod errors;
 pub mod traits;
 pub mod models;
 pub mod registry;
+pub mod proposal;
 pub mod router;
+pub mod sketch;
+pub mod units;
 
 // Calculator implementations organized by category
 pub mod calculators {
@@ -88,6 +94,7 @@ pub mod test_utils {
             length: 1.0,
             height: 0.1,
             additional: None,
+            ..Default::default()
         }
     }
 
@@ -98,6 +105,7 @@ pub mod test_utils {
             length: l,
             height: h,
             additional: None,
+            ..Default::default()
         }
     }
 }
@@ -130,4 +138,17 @@ mod tests {
         assert!(!catalogue.version.is_empty());
         assert!(!catalogue.categories.is_empty());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_geometric_calculators_draw() {
+        let registry = create_default_registry();
+        let params = test_utils::parameters_with_dimensions(3.0, 4.0, 0.8);
+
+        for id in ["deck", "concrete_slab", "fence"] {
+            let drawing = registry.find(id).unwrap().drawing(&params).unwrap_or_else(|| panic!("{} has no drawing", id));
+            assert!(drawing.entity_count() > 4, "{}", id);
+            assert!(drawing.to_svg().contains("4.00 m"), "{}", id);
+        }
+        assert!(registry.find("paint_coverage").unwrap().drawing(&params).is_none());
+    }
+}
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use crate::calculus::changelog::FormulaChange;
+use crate::calculus::examples::CalculatorExample;
+use crate::pricing::{LivePrices, Location};
+use crate::utils::conversions::UnitSystem;
 
 // ============================================================================
 // ENUMS AND CONSTANTS
@@ -57,6 +61,22 @@ pub struct BeginnerParameters {
     pub height: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional: Option<HashMap<String, f64>>,
+
+    /// Units the request is written in; results come back in the same system
+    #[serde(default, skip_serializing_if = "UnitSystem::is_metric")]
+    pub unit_system: UnitSystem,
+
+    /// Price materials at `location` instead of the bundled USD costs
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub use_live_pricing: bool,
+
+    /// Where materials are bought; required with `use_live_pricing`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+
+    /// Prices resolved for this request by the router
+    #[serde(skip)]
+    pub live_prices: LivePrices,
 }
 
 // Default impl if needed
@@ -67,6 +87,10 @@ impl Default for BeginnerParameters {
             length: 0.0,
             height: 0.0,
             additional: None,
+            unit_system: UnitSystem::Metric,
+            use_live_pricing: false,
+            location: None,
+            live_prices: LivePrices::default(),
         }
     }
 }
@@ -98,6 +122,13 @@ pub struct BeginnerCalculationResponse {
     pub warnings: Vec<String>,
 }
 
+impl BeginnerCalculationResponse {
+    /// Re-express metric results in the request's unit system
+    pub fn express_in(&mut self, system: UnitSystem) {
+        system.results_from_metric(self.results.iter_mut().map(|r| (&mut r.value, &mut r.unit)));
+    }
+}
+
 // ============================================================================
 // METADATA MODELS
 // ============================================================================
@@ -129,6 +160,9 @@ pub struct BeginnerCalculatorMetadata {
     pub parameters: Vec<ParameterMetadata>,
     pub required_parameters: Vec<String>,
     pub optional_parameters: Vec<String>,
+    /// Worked requests with their results; see [`crate::calculus::examples`]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<CalculatorExample>,
 }
 
 /// Category info
@@ -147,6 +181,10 @@ pub struct BeginnerCalculatorCatalogue {
     pub categories: Vec<BeginnerCategoryInfo>,
     pub calculators: Vec<BeginnerCalculatorMetadata>,
     pub disclaimer: String,
+
+    /// Formula changes per calculator id; unchanged calculators are omitted
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub changelog: BTreeMap<String, Vec<FormulaChange>>,
 }
 
 #[cfg(test)]
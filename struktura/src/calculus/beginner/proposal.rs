@@ -0,0 +1,230 @@
+// ============================================================================
+// Homeowner Proposals
+//
+// Turns a beginner calculation into a document a contractor can hand
+// straight to a customer: what will be built, the materials with a price
+// range, the labor estimate and the fine print. Deliberately plain - no
+// formulas, tolerances or design codes; those belong in engineering reports.
+//
+// Prices come from the calculator's own cost results. Each is shown as a
+// range either side of that figure, since a homeowner reading a single
+// number takes it as a quote.
+// ============================================================================
+
+use crate::calculus::beginner::models::{BeginnerCalculationResponse, BeginnerParameters, BeginnerResultItem};
+use crate::utils::format::{self, UnitKind};
+use crate::utils::pdf::PdfDocument;
+use serde::{Deserialize, Serialize};
+
+/// Spread either side of each price when the request does not set one
+pub const DEFAULT_PRICE_SPREAD: f64 = 0.15;
+/// Widest spread accepted; beyond this a range says nothing
+pub const MAX_PRICE_SPREAD: f64 = 0.5;
+
+const DISCLAIMERS: &[&str] = &[
+    "This proposal is an estimate based on the dimensions provided. Final quantities and prices are confirmed after an on-site measurement.",
+    "Material prices vary by supplier, region and season. The ranges shown are typical, not guaranteed.",
+    "Labor estimates assume normal site access and conditions. Hidden damage, permits and disposal are not included unless listed.",
+    "Structural, electrical and plumbing work may require a permit or a licensed professional under local codes.",
+];
+
+/// Request body for a proposal export
+#[derive(Debug, Deserialize)]
+pub struct ProposalRequest {
+    pub calculation_type: String,
+    pub parameters: BeginnerParameters,
+    #[serde(default)]
+    pub customer_name: Option<String>,
+    #[serde(default)]
+    pub project_address: Option<String>,
+    /// Contractor or company named on the proposal
+    #[serde(default)]
+    pub prepared_by: Option<String>,
+    /// Fraction either side of each price, e.g. 0.15 for ±15%
+    #[serde(default)]
+    pub price_spread: Option<f64>,
+}
+
+/// One line of the scope summary
+#[derive(Debug, Clone, Serialize)]
+pub struct ScopeItem {
+    pub label: String,
+    pub value: String,
+}
+
+/// A cost shown as a low-high range
+#[derive(Debug, Clone, Serialize)]
+pub struct PricedLine {
+    pub label: String,
+    pub low: f64,
+    pub high: f64,
+}
+
+impl PricedLine {
+    fn new(item: &BeginnerResultItem, spread: f64) -> Self {
+        Self {
+            label: item.label.clone(),
+            low: (item.value * (1.0 - spread)).max(0.0),
+            high: item.value * (1.0 + spread),
+        }
+    }
+
+    fn range(&self) -> String {
+        format!("${:.0} to ${:.0}", self.low, self.high)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Proposal {
+    pub title: String,
+    pub date: String,
+    pub customer_name: Option<String>,
+    pub project_address: Option<String>,
+    pub prepared_by: Option<String>,
+    pub scope: Vec<ScopeItem>,
+    pub materials: Vec<PricedLine>,
+    pub labor: Vec<PricedLine>,
+    /// Totals as the calculator reports them, alternatives included
+    pub totals: Vec<PricedLine>,
+    /// Calculator warnings, in plain words
+    pub notes: Vec<String>,
+    pub disclaimers: Vec<String>,
+}
+
+fn is_labor(label: &str) -> bool {
+    let label = label.to_lowercase();
+    label.contains("labor") || label.contains("install")
+}
+
+impl Proposal {
+    pub fn build(
+        title: &str,
+        request: &ProposalRequest,
+        response: &BeginnerCalculationResponse,
+    ) -> Self {
+        let params = &request.parameters;
+        let spread = request.price_spread.unwrap_or(DEFAULT_PRICE_SPREAD).clamp(0.0, MAX_PRICE_SPREAD);
+        let policy = format::policy();
+
+        let mut scope = vec![ScopeItem {
+            label: "Area".to_string(),
+            value: format!("{} x {}", policy.format(params.width, "m"), policy.format(params.length, "m")),
+        }];
+        let (mut materials, mut labor, mut totals) = (Vec::new(), Vec::new(), Vec::new());
+
+        for item in &response.results {
+            if UnitKind::of(&item.unit) != UnitKind::Currency {
+                scope.push(ScopeItem { label: item.label.clone(), value: policy.format(item.value, &item.unit) });
+            } else if item.label.starts_with("Total") {
+                totals.push(PricedLine::new(item, spread));
+            } else if is_labor(&item.label) {
+                labor.push(PricedLine::new(item, spread));
+            } else {
+                materials.push(PricedLine::new(item, spread));
+            }
+        }
+
+        Self {
+            title: title.to_string(),
+            date: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+            customer_name: request.customer_name.clone(),
+            project_address: request.project_address.clone(),
+            prepared_by: request.prepared_by.clone(),
+            scope,
+            materials,
+            labor,
+            totals,
+            notes: response.warnings.clone(),
+            disclaimers: DISCLAIMERS.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let mut doc = PdfDocument::new()
+            .title(&format!("Struktura Project Proposal: {}", self.title))
+            .text(&format!("Date: {}", self.date));
+        for (label, value) in [
+            ("Prepared for", &self.customer_name),
+            ("Project address", &self.project_address),
+            ("Prepared by", &self.prepared_by),
+        ] {
+            if let Some(value) = value {
+                doc = doc.text(&format!("{}: {}", label, value));
+            }
+        }
+
+        doc = doc.spacer().heading("Scope of work");
+        for item in &self.scope {
+            doc = doc.row(&[(0.0, &item.label), (300.0, &item.value)]);
+        }
+
+        for (heading, lines) in [("Materials", &self.materials), ("Labor", &self.labor), ("Estimated total", &self.totals)] {
+            if lines.is_empty() {
+                continue;
+            }
+            doc = doc.spacer().heading(heading).bold_row(&[(0.0, "Item"), (300.0, "Price range")]);
+            for line in lines {
+                doc = doc.row(&[(0.0, &line.label), (300.0, &line.range())]);
+            }
+        }
+
+        if !self.notes.is_empty() {
+            doc = doc.spacer().heading("Things to know");
+            for note in &self.notes {
+                doc = doc.paragraph(&format!("- {}", note));
+            }
+        }
+
+        doc = doc.spacer().heading("Terms");
+        for disclaimer in &self.disclaimers {
+            doc = doc.paragraph(disclaimer);
+        }
+        doc.spacer().text("Prepared with Struktura").to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::beginner::test_utils::parameters_with_dimensions;
+
+    fn item(label: &str, value: f64, unit: &str) -> BeginnerResultItem {
+        BeginnerResultItem { label: label.to_string(), value, unit: unit.to_string() }
+    }
+
+    #[test]
+    fn test_results_are_sorted_into_sections() {
+        let request = ProposalRequest {
+            calculation_type: "concrete_slab".to_string(),
+            parameters: parameters_with_dimensions(4.0, 5.0, 0.1),
+            customer_name: Some("J. Doe".to_string()),
+            project_address: None,
+            prepared_by: None,
+            price_spread: Some(0.9),
+        };
+        let response = BeginnerCalculationResponse {
+            calculation_type: "concrete_slab".to_string(),
+            results: vec![
+                item("Concrete Volume", 2.16, "m³"),
+                item("Concrete Cost", 300.0, "USD"),
+                item("Estimated Labor Cost", 200.0, "USD"),
+                item("Total Project Cost", 500.0, "USD"),
+            ],
+            warnings: vec!["Large slab".to_string()],
+        };
+
+        let proposal = Proposal::build("Concrete Slab", &request, &response);
+        assert_eq!(proposal.scope.len(), 2);
+        assert_eq!(proposal.materials[0].label, "Concrete Cost");
+        assert_eq!(proposal.labor[0].label, "Estimated Labor Cost");
+        assert_eq!(proposal.totals.len(), 1);
+
+        // Spread is capped at ±50%
+        assert_eq!((proposal.totals[0].low, proposal.totals[0].high), (250.0, 750.0));
+
+        let pdf = String::from_utf8_lossy(&proposal.to_pdf()).to_string();
+        assert!(pdf.starts_with("%PDF"));
+        assert!(pdf.contains("$250 to $750"));
+        assert!(pdf.contains("Prepared for: J. Doe"));
+    }
+}
@@ -3,8 +3,13 @@ use crate::calculus::beginner::{
     models::*,
     traits::BeginnerCalculator,
 };
+use crate::calculus::changelog;
+use crate::calculus::examples;
+use crate::calculus::recommendation::Tier;
+use crate::calculus::sanitize;
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::Instrument;
 
 /// Thread-safe calculator registry
 #[derive(Clone)]
@@ -51,6 +56,32 @@ impl BeginnerRegistry {
             .ok_or_else(|| BeginnerError::CalculatorNotFound(id.to_string()))
     }
 
+    /// Validate and run a calculation, then sanitize its results
+    ///
+    /// Non-finite result values fail the calculation; implausibly large ones
+    /// are served with a warning. Either is logged with the inputs.
+    #[tracing::instrument(name = "calculator", skip_all, fields(tier = Tier::Beginner.as_str(), calculator = calculator.id()))]
+    pub async fn execute(
+        &self,
+        calculator: &Arc<dyn BeginnerCalculator>,
+        params: BeginnerParameters,
+    ) -> BeginnerResult<BeginnerCalculationResponse> {
+        tracing::info_span!("validate").in_scope(|| calculator.validate(&params))?;
+        let inputs = params.clone();
+        let mut response = calculator.calculate(params).instrument(tracing::info_span!("calculate")).await?;
+
+        let findings = sanitize::Findings::inspect(response.results.iter().map(|r| (r.label.as_str(), r.value)));
+        findings.log(Tier::Beginner, calculator.id(), &inputs);
+        if let Some(message) = findings.error() {
+            return Err(BeginnerError::CalculationError(message));
+        }
+        for message in findings.warnings() {
+            response.warnings.push(message);
+        }
+
+        Ok(response)
+    }
+
     /// Get all calculators
     pub fn all(&self) -> Vec<Arc<dyn BeginnerCalculator>> {
         self.calculators.values().cloned().collect()
@@ -116,7 +147,11 @@ impl BeginnerRegistry {
         let calculators: Vec<BeginnerCalculatorMetadata> = self
             .all()
             .iter()
-            .map(|calc| calc.metadata())
+            .map(|calc| {
+                let mut metadata = calc.metadata();
+                metadata.examples = examples::for_calculator(Tier::Beginner, calc.id());
+                metadata
+            })
             .collect();
 
         BeginnerCalculatorCatalogue {
@@ -124,6 +159,7 @@ impl BeginnerRegistry {
             categories,
             calculators,
             disclaimer: "Calculations are estimates only. Consult professionals for accurate assessments.".to_string(),
+            changelog: changelog::for_tier(Tier::Beginner),
         }
     }
 
@@ -216,6 +252,7 @@ pub fn create_default_registry() -> BeginnerRegistry {
         .with_calculator(Arc::new(calculators::interiors::DrywallCeilingCalculator))
         .with_calculator(Arc::new(calculators::interiors::BaseboardCalculator))
         .with_calculator(Arc::new(calculators::interiors::CrownMoldingCalculator))
+        .with_calculator(Arc::new(calculators::interiors::AcousticTreatmentCalculator))
 
         // Utilities registry
         .with_calculator(Arc::new(calculators::utilities::PaintCoverageCalculator))
@@ -223,6 +260,7 @@ pub fn create_default_registry() -> BeginnerRegistry {
         .with_calculator(Arc::new(calculators::utilities::WallpaperCalculator))
         .with_calculator(Arc::new(calculators::utilities::RecessedLightingCalculator))
         .with_calculator(Arc::new(calculators::utilities::TrackLightingCalculator))
+        .with_calculator(Arc::new(calculators::utilities::ServiceLoadCalculator))
         .with_calculator(Arc::new(calculators::utilities::HVACSizingCalculator))
         .with_calculator(Arc::new(calculators::utilities::PipeRunCalculator))
         .with_calculator(Arc::new(calculators::utilities::DrainLineCalculator))
@@ -264,6 +302,7 @@ mod tests {
                 parameters: vec![],
                 required_parameters: vec![],
                 optional_parameters: vec![],
+                examples: Vec::new(),
             }
         }
 
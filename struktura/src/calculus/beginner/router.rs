@@ -2,17 +2,29 @@ use crate::calculus::beginner::{
     errors::BeginnerError,
     models::*,
     registry::BeginnerRegistry,
+    proposal::{Proposal, ProposalRequest},
+    sketch::{self, SketchDimensionRequest, SketchDimensionResponse},
 };
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, HeaderMap},
     response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use uuid::Uuid;
+use crate::billing;
+use crate::calculus::examples;
+use crate::calculus::links::{self, Hal};
+use crate::calculus::recommendation::Tier;
+use crate::flags::FlagContext;
+use crate::pricing;
+use crate::sec::Claims;
 use crate::state::AppState;
+use crate::trial;
+use crate::utils::negotiate::{Negotiated, ResponseFormat};
 
 /// Application state
 #[derive(Clone)]
@@ -47,26 +59,190 @@ pub struct HealthResponse {
 
 async fn calculate_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    headers: HeaderMap,
     Json(payload): Json<BeginnerCalculationRequest>,
-) -> Result<Json<BeginnerCalculationResponse>, BeginnerError> {
+) -> Response {
+    // Anonymous callers spend one trial calculation
+    let grant = match trial::admit(&state, claims.as_ref(), &headers) {
+        Ok(grant) => grant,
+        Err(e) => return e.into_response(),
+    };
+
+    let format = ResponseFormat::from_headers(&headers);
+    let calculation_type = payload.calculation_type.clone();
+    match run_calculation(&state, claims.as_ref(), payload).await {
+        Ok(response) => {
+            let related = related_calculators(&state, claims.as_ref(), &calculation_type).await;
+            let body = Hal::new(response, links::calculation(Tier::Beginner, &calculation_type, related));
+            match grant {
+                Some(grant) => (grant.headers(), Negotiated(format, body)).into_response(),
+                None => Negotiated(format, body).into_response(),
+            }
+        }
+        Err(e) => {
+            if let Some(grant) = grant {
+                state.trial_quota.refund(&grant.claims, &grant.device_fp);
+            }
+            e.into_response()
+        }
+    }
+}
+
+async fn run_calculation(
+    state: &AppState,
+    claims: Option<&Claims>,
+    mut payload: BeginnerCalculationRequest,
+) -> Result<BeginnerCalculationResponse, BeginnerError> {
     // Find calculator in registry
     let calculator = state.calculators_beginner.find(&payload.calculation_type)?;
 
-    // Validate parameters
-    calculator.validate(&payload.parameters)?;
+    // Calculators behind a rollout flag look missing to callers outside it
+    let ctx = FlagContext::for_caller(&state.tenants, claims);
+    if !state.flags.calculator_enabled(&state.pool, Tier::Beginner, calculator.id(), &ctx).await {
+        return Err(BeginnerError::CalculatorNotFound(payload.calculation_type));
+    }
+
+    // Imperial requests run in the metric units calculators work in
+    let system = payload.parameters.unit_system;
+    let declared = calculator.metadata().parameters;
+    payload.parameters = system
+        .parameters_to_metric(payload.parameters, declared.iter().map(|p| (p.path.as_str(), p.unit.as_str())))
+        .map_err(|e| BeginnerError::InvalidParameter {
+            parameter: "unit_system".to_string(),
+            value: system.as_str().to_string(),
+            reason: e.to_string(),
+        })?;
+
+    // Swap bundled costs for regional prices when the caller asks
+    if payload.parameters.use_live_pricing {
+        let Some(location) = payload.parameters.location.as_ref() else {
+            return Err(BeginnerError::MissingParameter {
+                parameter: "location".to_string(),
+                calculator: calculator.id().to_string(),
+            });
+        };
+        let materials = calculator.priced_materials(&payload.parameters);
+        // Supplier quotes of the caller's organization outrank scouted prices
+        let org_id = match claims {
+            Some(claims) => state.tenants.resolve(&state.pool, claims).await.ok().map(|scope| scope.org_id),
+            None => None,
+        };
+        payload.parameters.live_prices = pricing::live::resolve(&state.pricing, location, org_id, materials).await;
+    }
+    let pricing_warnings = payload.parameters.live_prices.warnings.clone();
 
-    // Execute calculation
-    let response = calculator.calculate(payload.parameters).await?;
+    // Validate, execute and sanitize the results
+    let mut response = state.calculators_beginner.execute(&calculator, payload.parameters).await?;
+    response.warnings.extend(pricing_warnings);
+    response.express_in(system);
+    Ok(response)
+}
 
+async fn sketch_dimensions_handler(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<SketchDimensionRequest>,
+) -> Result<Json<SketchDimensionResponse>, BeginnerError> {
+    let response = sketch::extract_dimensions(payload, state.sketch_vision.as_deref()).await?;
     Ok(Json(response))
 }
 
+/// Query parameters for the drawing endpoint
+#[derive(Debug, Default, Deserialize)]
+pub struct DrawingQuery {
+    /// `svg` (default) or `dxf`
+    format: Option<String>,
+}
+
+/// Dimensioned sketch for calculators with simple geometry
+async fn drawing_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Query(query): Query<DrawingQuery>,
+    Json(payload): Json<BeginnerCalculationRequest>,
+) -> Result<Response, BeginnerError> {
+    let calculator = state.calculators_beginner.find(&payload.calculation_type)?;
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Beginner, calculator.id(), &ctx).await {
+        return Err(BeginnerError::CalculatorNotFound(payload.calculation_type));
+    }
+    calculator.validate(&payload.parameters)?;
+
+    let drawing = calculator.drawing(&payload.parameters).ok_or_else(|| BeginnerError::DomainError {
+        field: "calculation_type".to_string(),
+        message: format!("No drawing is available for '{}'", calculator.id()),
+    })?;
+
+    let (content_type, extension, body) = match query.format.as_deref().unwrap_or("svg") {
+        "svg" => ("image/svg+xml", "svg", drawing.to_svg()),
+        "dxf" => ("application/dxf", "dxf", drawing.to_dxf()),
+        other => {
+            return Err(BeginnerError::InvalidParameter {
+                parameter: "format".to_string(),
+                value: other.to_string(),
+                reason: "Must be 'svg' or 'dxf'".to_string(),
+            });
+        }
+    };
+    // SVG previews stay open; DXF is an export
+    if extension == "dxf" {
+        let user_id = claims.as_ref().and_then(|c| Uuid::parse_str(&c.sub).ok());
+        if let Err(response) = billing::check_exports(&state, user_id).await {
+            return Ok(response);
+        }
+    }
+    let filename = format!("attachment; filename=\"{}.{}\"", calculator.id(), extension);
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CONTENT_DISPOSITION, filename),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// Homeowner proposal PDF for a beginner calculation
+async fn proposal_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Json(payload): Json<ProposalRequest>,
+) -> Result<Response, BeginnerError> {
+    if let Err(response) = billing::check_exports(&state, Uuid::parse_str(&claims.sub).ok()).await {
+        return Ok(response);
+    }
+    let calculator = state.calculators_beginner.find(&payload.calculation_type)?;
+    let request = BeginnerCalculationRequest {
+        calculation_type: payload.calculation_type.clone(),
+        parameters: payload.parameters.clone(),
+    };
+    let response = run_calculation(&state, Some(&claims), request).await?;
+    let proposal = Proposal::build(calculator.name(), &payload, &response);
+    crate::sec::log_security_event("PROPOSAL_PDF", Some(&claims.username), None, "Success");
+
+    let filename = format!("attachment; filename=\"proposal-{}.pdf\"", calculator.id());
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (header::CONTENT_DISPOSITION, filename),
+        ],
+        proposal.to_pdf(),
+    )
+        .into_response())
+}
+
 async fn catalogue_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Query(query): Query<CatalogueQuery>,
-) -> Json<BeginnerCalculatorCatalogue> {
+) -> Json<Hal<BeginnerCalculatorCatalogue>> {
     let mut catalogue = state.calculators_beginner.catalogue();
 
+    // Hide calculators the caller is not rolled out to
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Beginner, &ctx).await;
+    catalogue.calculators.retain(|calc| !hidden.contains(&calc.id));
+
     if let Some(category_filter) = query.category {
         catalogue.calculators.retain(|calc| calc.category == category_filter);
     }
@@ -78,15 +254,49 @@ async fn catalogue_handler(
         catalogue.calculators.retain(|calc| result_ids.contains(&calc.id));
     }
 
-    Json(catalogue)
+    let listed = catalogue.calculators.iter().map(|c| (c.id.clone(), c.name.clone()));
+    let links = links::catalogue(Tier::Beginner, listed);
+    Json(Hal::new(catalogue, links))
 }
 
 async fn calculator_metadata_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Path(id): Path<String>,
-) -> Result<Json<BeginnerCalculatorMetadata>, BeginnerError> {
+) -> Result<Json<Hal<BeginnerCalculatorMetadata>>, BeginnerError> {
     let calculator = state.calculators_beginner.find(&id)?;
-    Ok(Json(calculator.metadata()))
+
+    // Calculators behind a rollout flag look missing to callers outside it
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Beginner, calculator.id(), &ctx).await {
+        return Err(BeginnerError::CalculatorNotFound(id));
+    }
+
+    let related = related_calculators(&state, claims.as_ref(), calculator.id()).await;
+    let links = links::calculator(Tier::Beginner, calculator.id(), related);
+    let mut metadata = calculator.metadata();
+    metadata.examples = examples::for_calculator(Tier::Beginner, calculator.id());
+    Ok(Json(Hal::new(metadata, links)))
+}
+
+/// Calculators sharing a category with `calculator_id` that the caller can
+/// see, as (id, name) pairs for `related` links
+async fn related_calculators(state: &AppState, claims: Option<&Claims>, calculator_id: &str) -> Vec<(String, String)> {
+    let Ok(calculator) = state.calculators_beginner.find(calculator_id) else {
+        return Vec::new();
+    };
+    let ctx = FlagContext::for_caller(&state.tenants, claims);
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Beginner, &ctx).await;
+
+    let mut related: Vec<(String, String)> = state
+        .calculators_beginner
+        .by_category(calculator.category())
+        .into_iter()
+        .filter(|c| c.id() != calculator_id && !hidden.contains(c.id()))
+        .map(|c| (c.id().to_string(), c.name().to_string()))
+        .collect();
+    related.sort();
+    related
 }
 
 async fn categories_handler(
@@ -98,6 +308,7 @@ async fn categories_handler(
 
 async fn category_calculators_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Path(category_str): Path<String>,
 ) -> Result<Json<Vec<BeginnerCalculatorMetadata>>, BeginnerError> {
     let category = match category_str.as_str() {
@@ -114,9 +325,14 @@ async fn category_calculators_handler(
         }
     };
 
+    // Hide calculators the caller is not rolled out to
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Beginner, &ctx).await;
+
     let calculators = state.calculators_beginner.by_category(category);
     let metadata: Vec<BeginnerCalculatorMetadata> = calculators
         .iter()
+        .filter(|calc| !hidden.contains(calc.id()))
         .map(|calc| calc.metadata())
         .collect();
 
@@ -130,11 +346,17 @@ struct SearchQuery {
 
 async fn search_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Query(query): Query<SearchQuery>,
 ) -> Json<Vec<BeginnerCalculatorMetadata>> {
+    // Hide calculators the caller is not rolled out to
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Beginner, &ctx).await;
+
     let results = state.calculators_beginner.search(&query.q);
     let metadata: Vec<BeginnerCalculatorMetadata> = results
         .iter()
+        .filter(|calc| !hidden.contains(calc.id()))
         .map(|calc| calc.metadata())
         .collect();
 
@@ -177,12 +399,15 @@ async fn stats_handler(
 pub fn create_router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/calculate", post(calculate_handler))
+        .route("/sketch/dimensions", post(sketch_dimensions_handler))
+        .route("/proposal", post(proposal_handler))
+        .route("/drawing", post(drawing_handler))
         
         .route("/catalogue", get(catalogue_handler))
-        .route("/catalogue/meta", get(calculator_metadata_handler))
+        .route("/catalogue/{id}", get(calculator_metadata_handler))
         
         .route("/categories", get(categories_handler))
-        .route("/categories/handler", get(category_calculators_handler))
+        .route("/categories/{category}", get(category_calculators_handler))
         
         .route("/search", get(search_handler))
         
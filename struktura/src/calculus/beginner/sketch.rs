@@ -0,0 +1,317 @@
+// ============================================================================
+// Sketch Dimension Extraction
+//
+// Turns an annotated sketch or floor plan into `BeginnerParameters`.
+// Users draw (or a vision provider detects) labelled lines on the image and
+// give one reference length; every line is then scaled into meters so
+// homeowners can work in feet, inches or pixels instead of a tape in meters.
+// ============================================================================
+
+use crate::calculus::beginner::{
+    errors::{BeginnerError, BeginnerResult},
+    models::BeginnerParameters,
+};
+use crate::utils::{convert, units::find_unit, Dimension};
+use async_trait::async_trait;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+/// Largest decoded image accepted (5 MB)
+pub const MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+
+/// Relative spread between repeated measurements that triggers a warning
+const DISAGREEMENT_TOLERANCE: f64 = 0.05;
+
+/// Optional OCR / vision backend that detects dimension lines on an image
+#[async_trait]
+pub trait VisionProvider: Send + Sync {
+    /// Provider name reported back to the client
+    fn name(&self) -> &str;
+
+    /// Detect labelled dimension lines in pixel coordinates
+    async fn detect(&self, image: &[u8]) -> Result<Vec<SketchAnnotation>, String>;
+}
+
+/// Which room dimension a line measures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DimensionLabel {
+    Width,
+    Length,
+    Height,
+}
+
+/// Point in image pixel coordinates
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SketchPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl SketchPoint {
+    fn distance_to(&self, other: &SketchPoint) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+/// Labelled line drawn on the sketch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SketchAnnotation {
+    pub label: DimensionLabel,
+    pub start: SketchPoint,
+    pub end: SketchPoint,
+}
+
+/// Reference line with a known real-world length
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SketchScale {
+    pub start: SketchPoint,
+    pub end: SketchPoint,
+    pub length: f64,
+    /// Length unit symbol, e.g. "ft", "in", "m"
+    pub unit: String,
+}
+
+/// Request body for sketch dimension extraction
+#[derive(Debug, Deserialize)]
+pub struct SketchDimensionRequest {
+    /// Base64-encoded image, only needed when no annotations are supplied
+    #[serde(default)]
+    pub image: Option<String>,
+    pub scale: SketchScale,
+    #[serde(default)]
+    pub annotations: Vec<SketchAnnotation>,
+    /// Height measured separately, in the scale unit
+    #[serde(default)]
+    pub height: Option<f64>,
+}
+
+/// One dimension resolved from the sketch
+#[derive(Debug, Clone, Serialize)]
+pub struct MeasuredDimension {
+    pub label: DimensionLabel,
+    pub samples: usize,
+    pub pixel_length: f64,
+    pub meters: f64,
+}
+
+/// Extracted parameters ready for a beginner calculation
+#[derive(Debug, Serialize)]
+pub struct SketchDimensionResponse {
+    pub parameters: BeginnerParameters,
+    pub meters_per_pixel: f64,
+    pub measurements: Vec<MeasuredDimension>,
+    /// "annotations" or the vision provider name
+    pub source: String,
+    pub warnings: Vec<String>,
+}
+
+fn invalid(parameter: &str, value: impl ToString, reason: &str) -> BeginnerError {
+    BeginnerError::InvalidParameter {
+        parameter: parameter.to_string(),
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Convert a length in the scale unit to meters
+async fn to_meters(value: f64, unit: &str) -> BeginnerResult<f64> {
+    let info = find_unit(unit).map_err(|e| invalid("scale.unit", unit, &e.to_string()))?;
+    if info.dimension != Dimension::Length {
+        return Err(invalid("scale.unit", unit, "Scale unit must be a length unit"));
+    }
+    convert(value, unit, "m")
+        .await
+        .map_err(|e| invalid("scale.length", value, &e.to_string()))
+}
+
+/// Resolve room dimensions from annotations, falling back to the vision provider
+pub async fn extract_dimensions(
+    request: SketchDimensionRequest,
+    provider: Option<&dyn VisionProvider>,
+) -> BeginnerResult<SketchDimensionResponse> {
+    let SketchDimensionRequest { image, scale, annotations, height } = request;
+
+    let reference_pixels = scale.start.distance_to(&scale.end);
+    if !reference_pixels.is_finite() || reference_pixels <= 0.0 {
+        return Err(invalid("scale", reference_pixels, "Reference line must have non-zero length"));
+    }
+    if !scale.length.is_finite() || scale.length <= 0.0 {
+        return Err(invalid("scale.length", scale.length, "Reference length must be positive"));
+    }
+    let meters_per_pixel = to_meters(scale.length, &scale.unit).await? / reference_pixels;
+
+    let (annotations, source) = if !annotations.is_empty() {
+        (annotations, "annotations".to_string())
+    } else {
+        let encoded = image.ok_or_else(|| BeginnerError::MissingParameter {
+            parameter: "annotations".to_string(),
+            calculator: "sketch_dimensions".to_string(),
+        })?;
+        let provider = provider.ok_or_else(|| {
+            BeginnerError::CalculationError(
+                "No vision provider configured; annotate the sketch instead".to_string(),
+            )
+        })?;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded.trim())
+            .map_err(|_| invalid("image", "<base64>", "Image is not valid base64"))?;
+        if bytes.len() > MAX_IMAGE_BYTES {
+            return Err(invalid("image", bytes.len(), "Image exceeds 5 MB"));
+        }
+        let detected = provider
+            .detect(&bytes)
+            .await
+            .map_err(BeginnerError::CalculationError)?;
+        (detected, provider.name().to_string())
+    };
+
+    let mut warnings = Vec::new();
+    let mut measurements = Vec::new();
+    for label in [DimensionLabel::Width, DimensionLabel::Length, DimensionLabel::Height] {
+        let lengths: Vec<f64> = annotations
+            .iter()
+            .filter(|a| a.label == label)
+            .map(|a| a.start.distance_to(&a.end))
+            .filter(|l| l.is_finite() && *l > 0.0)
+            .collect();
+        if lengths.is_empty() {
+            continue;
+        }
+
+        let mean = lengths.iter().sum::<f64>() / lengths.len() as f64;
+        let (min, max) = lengths
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(lo, hi), l| (lo.min(*l), hi.max(*l)));
+        if (max - min) / mean > DISAGREEMENT_TOLERANCE {
+            warnings.push(format!(
+                "{:?} lines disagree by {:.0}%; using the average",
+                label,
+                (max - min) / mean * 100.0
+            ));
+        }
+
+        measurements.push(MeasuredDimension {
+            label,
+            samples: lengths.len(),
+            pixel_length: mean,
+            meters: mean * meters_per_pixel,
+        });
+    }
+
+    let find = |label| measurements.iter().find(|m| m.label == label).map(|m| m.meters);
+    let width = find(DimensionLabel::Width).ok_or_else(|| BeginnerError::MissingParameter {
+        parameter: "width".to_string(),
+        calculator: "sketch_dimensions".to_string(),
+    })?;
+    let length = find(DimensionLabel::Length).ok_or_else(|| BeginnerError::MissingParameter {
+        parameter: "length".to_string(),
+        calculator: "sketch_dimensions".to_string(),
+    })?;
+    let height = match height {
+        Some(h) => to_meters(h, &scale.unit).await?,
+        None => find(DimensionLabel::Height).unwrap_or(0.0),
+    };
+
+    Ok(SketchDimensionResponse {
+        parameters: BeginnerParameters {
+            width,
+            length,
+            height,
+            additional: None,
+            ..Default::default()
+        },
+        meters_per_pixel,
+        measurements,
+        source,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(label: DimensionLabel, x: f64, y: f64) -> SketchAnnotation {
+        SketchAnnotation {
+            label,
+            start: SketchPoint { x: 0.0, y: 0.0 },
+            end: SketchPoint { x, y },
+        }
+    }
+
+    fn feet_scale() -> SketchScale {
+        // 100 px = 10 ft
+        SketchScale {
+            start: SketchPoint { x: 0.0, y: 0.0 },
+            end: SketchPoint { x: 100.0, y: 0.0 },
+            length: 10.0,
+            unit: "ft".to_string(),
+        }
+    }
+
+    struct FixedProvider;
+
+    #[async_trait]
+    impl VisionProvider for FixedProvider {
+        fn name(&self) -> &str {
+            "fixed"
+        }
+
+        async fn detect(&self, _image: &[u8]) -> Result<Vec<SketchAnnotation>, String> {
+            Ok(vec![line(DimensionLabel::Width, 100.0, 0.0), line(DimensionLabel::Length, 0.0, 200.0)])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_annotations_scaled_from_feet() {
+        let request = SketchDimensionRequest {
+            image: None,
+            scale: feet_scale(),
+            annotations: vec![
+                line(DimensionLabel::Width, 120.0, 0.0),
+                line(DimensionLabel::Length, 0.0, 150.0),
+                line(DimensionLabel::Length, 0.0, 170.0),
+            ],
+            height: Some(8.0),
+        };
+
+        let response = extract_dimensions(request, None).await.unwrap();
+        assert!((response.parameters.width - 12.0 * 0.3048).abs() < 1e-9);
+        assert!((response.parameters.length - 16.0 * 0.3048).abs() < 1e-9);
+        assert!((response.parameters.height - 8.0 * 0.3048).abs() < 1e-9);
+        assert_eq!(response.source, "annotations");
+        assert_eq!(response.warnings.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_vision_provider_fallback() {
+        let request = || SketchDimensionRequest {
+            image: Some(base64::engine::general_purpose::STANDARD.encode(b"sketch")),
+            scale: feet_scale(),
+            annotations: vec![],
+            height: None,
+        };
+
+        assert!(extract_dimensions(request(), None).await.is_err());
+
+        let response = extract_dimensions(request(), Some(&FixedProvider)).await.unwrap();
+        assert_eq!(response.source, "fixed");
+        assert!((response.parameters.length - 20.0 * 0.3048).abs() < 1e-9);
+        assert_eq!(response.parameters.height, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_non_length_scale_unit() {
+        let mut scale = feet_scale();
+        scale.unit = "kg".to_string();
+        let request = SketchDimensionRequest {
+            image: None,
+            scale,
+            annotations: vec![line(DimensionLabel::Width, 10.0, 0.0)],
+            height: None,
+        };
+
+        assert!(extract_dimensions(request, None).await.is_err());
+    }
+}
@@ -2,6 +2,8 @@ use crate::calculus::beginner::{
     errors::{BeginnerError, BeginnerResult},
     models::*,
 };
+use crate::pricing::MaterialId;
+use crate::utils::drawing::Drawing;
 use async_trait::async_trait;
 
 /// Core trait for all beginner calculators
@@ -25,6 +27,16 @@ pub trait BeginnerCalculator: Send + Sync {
     /// Perform the calculation
     async fn calculate(&self, params: BeginnerParameters) 
         -> BeginnerResult<BeginnerCalculationResponse>;
+
+    /// Dimensioned plan sketch, for calculators with simple geometry
+    fn drawing(&self, _params: &BeginnerParameters) -> Option<Drawing> {
+        None
+    }
+
+    /// Materials whose costs can come from live regional prices
+    fn priced_materials(&self, _params: &BeginnerParameters) -> Vec<MaterialId> {
+        Vec::new()
+    }
 }
 
 /// Parameter validator trait for reusable validation logic
@@ -0,0 +1,83 @@
+// ============================================================================
+// Preferred Output Units
+//
+// `parameters.output_units` names the unit each kind of quantity should be
+// reported in (`{"length": "ft", "area": "ft²", "volume": "gal"}`), for
+// homeowners who think in feet and gallons. The calculator never sees it:
+// this layer lifts it out of the request and converts the response's results
+// afterwards, keeping each converted item's metric value as `si_value` /
+// `si_unit`. Calculation history and queued jobs keep the metric response.
+// ============================================================================
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request,
+    http::{header, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::Value;
+
+use crate::calculus::beginner::errors::BeginnerError;
+use crate::utils::conversions::OutputUnits;
+
+/// Largest body read; matches axum's default body limit
+const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Lift `parameters.output_units` out of a calculate request and apply it to
+/// the results
+pub async fn output_units_middleware(request: Request, next: Next) -> Response {
+    if request.method() != Method::POST || !request.uri().path().ends_with("/calculate") {
+        return next.run(request).await;
+    }
+
+    let (mut parts, body) = request.into_parts();
+    let Ok(bytes) = to_bytes(body, MAX_BODY_BYTES).await else {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+
+    // Bodies that aren't JSON, or carry no preference, go through unchanged
+    let mut json = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(json) => json,
+        Err(_) => return next.run(Request::from_parts(parts, Body::from(bytes))).await,
+    };
+    let Some(requested) = json
+        .get_mut("parameters")
+        .and_then(Value::as_object_mut)
+        .and_then(|parameters| parameters.remove("output_units"))
+    else {
+        return next.run(Request::from_parts(parts, Body::from(bytes))).await;
+    };
+
+    let units = match OutputUnits::parse(&requested) {
+        Ok(units) => units,
+        Err(e) => {
+            let parameter = match e.kind.as_str() {
+                "" => "output_units".to_string(),
+                kind => format!("output_units.{}", kind),
+            };
+            return BeginnerError::InvalidParameter { parameter, value: e.unit, reason: e.reason }.into_response();
+        }
+    };
+
+    let body = serde_json::to_vec(&json).unwrap_or_else(|_| bytes.to_vec());
+    parts.headers.insert(header::CONTENT_LENGTH, body.len().into());
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if units.is_empty() || !response.status().is_success() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = to_bytes(body, usize::MAX).await else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    let body = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(mut json) => {
+            units.apply(&mut json);
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Body::from(serde_json::to_vec(&json).unwrap_or_else(|_| bytes.to_vec()))
+        }
+        Err(_) => Body::from(bytes),
+    };
+    Response::from_parts(parts, body)
+}
@@ -0,0 +1,271 @@
+// ============================================================================
+// Formula Changelog
+//
+// Machine-readable record of every change to a calculator's formulas or
+// constants that can move its numbers. Each calculator's formula version is
+// the newest version listed for it here (1.0.0 when it has never changed);
+// engineer and contractor responses carry it in `calculator_version`, and a
+// client re-opening a saved result asks what changed since that version or
+// date.
+// ============================================================================
+
+use crate::calculus::recommendation::Tier;
+use crate::state::AppState;
+use axum::{
+    extract::{Path, Query},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Formula version of a calculator with no recorded changes
+pub const BASELINE_VERSION: &str = "1.0.0";
+
+/// How much a change can move previously computed results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeImpact {
+    /// Rounding, labels or warnings only; numbers are unchanged
+    Cosmetic,
+    /// Results can shift slightly; earlier conclusions still hold
+    Minor,
+    /// Results can change materially; earlier results should be re-run
+    Significant,
+}
+
+/// One formula or constant change to a calculator
+#[derive(Debug, Clone, Serialize)]
+pub struct FormulaChange {
+    #[serde(skip)]
+    pub tier: Tier,
+    #[serde(skip)]
+    pub calculator_id: &'static str,
+    pub version: &'static str,
+    /// ISO date (YYYY-MM-DD) the change shipped
+    pub date: &'static str,
+    pub summary: &'static str,
+    pub impact: ChangeImpact,
+    pub note: &'static str,
+}
+
+/// Every recorded change, oldest first within each calculator
+///
+/// Add an entry whenever a formula, constant or default that feeds a
+/// result changes, bumping the calculator's version.
+pub static CHANGES: &[FormulaChange] = &[
+    FormulaChange {
+        tier: Tier::Engineer,
+        calculator_id: "beam_design",
+        version: "1.1.0",
+        date: "2026-10-17",
+        summary: "Design code selects AISC 360, Eurocode 3, ACI 318 or Eurocode 2; US codes check 1.4D as well as 1.2D + 1.6L",
+        impact: ChangeImpact::Minor,
+        note: "AISC 360 results only change where dead load dominates",
+    },
+    FormulaChange {
+        tier: Tier::Engineer,
+        calculator_id: "column_design",
+        version: "1.1.0",
+        date: "2026-10-17",
+        summary: "Required area from the AISC 360 E3 critical stress with a section radius of gyration; adds Eurocode 3, ACI 318 and Eurocode 2",
+        impact: ChangeImpact::Significant,
+        note: "Earlier required areas and slenderness ratios were not meaningful; re-run saved results",
+    },
+    FormulaChange {
+        tier: Tier::Beginner,
+        calculator_id: "recessed_lighting",
+        version: "1.1.0",
+        date: "2026-10-17",
+        summary: "Fixture count from the lumen method (target lux by room type, room index utilization factor, maintenance factor) instead of 1.8 m spacing",
+        impact: ChangeImpact::Significant,
+        note: "Counts and costs change for most rooms; lumens per bulb is now the fixture's rated output",
+    },
+];
+
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.trim_start_matches('v').split('.').map(|p| p.parse::<u32>().ok());
+    Some((parts.next()??, parts.next().flatten().unwrap_or(0), parts.next().flatten().unwrap_or(0)))
+}
+
+fn entries_in(changes: &'static [FormulaChange], tier: Tier, calculator_id: &str) -> Vec<&'static FormulaChange> {
+    changes
+        .iter()
+        .filter(|c| c.tier == tier && c.calculator_id == calculator_id)
+        .collect()
+}
+
+fn version_in(changes: &'static [FormulaChange], tier: Tier, calculator_id: &str) -> &'static str {
+    entries_in(changes, tier, calculator_id)
+        .into_iter()
+        .filter_map(|c| parse_version(c.version).map(|v| (v, c.version)))
+        .max_by_key(|(v, _)| *v)
+        .map_or(BASELINE_VERSION, |(_, version)| version)
+}
+
+/// Changes newer than a saved result, identified by its formula version or,
+/// failing that, the date it was computed (any ISO date or timestamp)
+fn since_in(
+    changes: &'static [FormulaChange],
+    tier: Tier,
+    calculator_id: &str,
+    since_version: Option<&str>,
+    since_date: Option<&str>,
+) -> Vec<&'static FormulaChange> {
+    let version = since_version.and_then(parse_version);
+    let date = since_date.map(|d| d.get(..10).unwrap_or(d));
+
+    entries_in(changes, tier, calculator_id)
+        .into_iter()
+        .filter(|c| match (version, date) {
+            (Some(v), _) => parse_version(c.version).is_some_and(|cv| cv > v),
+            (None, Some(d)) => c.date > d,
+            (None, None) => true,
+        })
+        .collect()
+}
+
+/// Current formula version of a calculator
+pub fn formula_version(tier: Tier, calculator_id: &str) -> &'static str {
+    version_in(CHANGES, tier, calculator_id)
+}
+
+/// Changelogs of every changed calculator in a tier, for the catalogue
+pub fn for_tier(tier: Tier) -> BTreeMap<String, Vec<FormulaChange>> {
+    let mut map: BTreeMap<String, Vec<FormulaChange>> = BTreeMap::new();
+    for change in CHANGES.iter().filter(|c| c.tier == tier) {
+        map.entry(change.calculator_id.to_string()).or_default().push(change.clone());
+    }
+    map
+}
+
+// ============================================================================
+// HTTP
+// ============================================================================
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ChangelogQuery {
+    /// Formula version recorded with a saved result
+    pub since_version: Option<String>,
+    /// When the saved result was computed, if its version is unknown
+    pub since: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CalculatorChangelog {
+    pub tier: Tier,
+    pub calculator_id: String,
+    pub formula_version: &'static str,
+    /// Whether a saved result predates any listed change
+    pub changed: bool,
+    pub changes: Vec<FormulaChange>,
+}
+
+pub fn create_router() -> Router<Arc<AppState>> {
+    Router::new().route("/{tier}/{calculator_id}", get(changelog_handler))
+}
+
+async fn changelog_handler(
+    Path((tier, calculator_id)): Path<(Tier, String)>,
+    Query(query): Query<ChangelogQuery>,
+) -> Json<CalculatorChangelog> {
+    let filtered = query.since_version.is_some() || query.since.is_some();
+    let changes: Vec<FormulaChange> = since_in(
+        CHANGES,
+        tier,
+        &calculator_id,
+        query.since_version.as_deref(),
+        query.since.as_deref(),
+    )
+    .into_iter()
+    .cloned()
+    .collect();
+
+    Json(CalculatorChangelog {
+        tier,
+        formula_version: formula_version(tier, &calculator_id),
+        changed: filtered && !changes.is_empty(),
+        calculator_id,
+        changes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static FIXTURE: &[FormulaChange] = &[
+        FormulaChange {
+            tier: Tier::Engineer,
+            calculator_id: "beam_design",
+            version: "1.1.0",
+            date: "2026-03-02",
+            summary: "Deflection limit uses L/360 for live load",
+            impact: ChangeImpact::Significant,
+            note: "Serviceability checks may now fail for long spans",
+        },
+        FormulaChange {
+            tier: Tier::Engineer,
+            calculator_id: "beam_design",
+            version: "1.10.0",
+            date: "2026-09-14",
+            summary: "Self-weight included in dead load",
+            impact: ChangeImpact::Minor,
+            note: "",
+        },
+        FormulaChange {
+            tier: Tier::Contractor,
+            calculator_id: "beam_design",
+            version: "2.0.0",
+            date: "2026-01-01",
+            summary: "Unrelated tier",
+            impact: ChangeImpact::Cosmetic,
+            note: "",
+        },
+    ];
+
+    #[test]
+    fn test_versions_compare_numerically() {
+        assert_eq!(version_in(FIXTURE, Tier::Engineer, "beam_design"), "1.10.0");
+        assert_eq!(version_in(FIXTURE, Tier::Engineer, "slab_design"), BASELINE_VERSION);
+        assert_eq!(parse_version("v2.1"), Some((2, 1, 0)));
+        assert_eq!(parse_version("draft"), None);
+    }
+
+    #[test]
+    fn test_changes_since_saved_result() {
+        let since = |v: Option<&str>, d: Option<&str>| since_in(FIXTURE, Tier::Engineer, "beam_design", v, d).len();
+
+        assert_eq!(since(None, None), 2);
+        assert_eq!(since(Some("1.0.0"), None), 2);
+        assert_eq!(since(Some("1.2.0"), None), 1);
+        assert_eq!(since(Some("1.10.0"), None), 0);
+        assert_eq!(since(None, Some("2026-05-01T10:00:00Z")), 1);
+        assert_eq!(since(None, Some("2026-09-14")), 0);
+    }
+
+    #[test]
+    fn test_shipped_changelog_is_well_formed() {
+        let beginner = crate::calculus::beginner::create_default_registry();
+        let engineer = crate::calculus::engineer::create_default_registry();
+        let contractor = crate::calculus::contractor::create_default_registry();
+
+        for change in CHANGES {
+            assert!(parse_version(change.version).is_some(), "{}: bad version", change.calculator_id);
+            assert!(
+                chrono::NaiveDate::parse_from_str(change.date, "%Y-%m-%d").is_ok(),
+                "{}: bad date",
+                change.calculator_id
+            );
+            assert!(!change.summary.is_empty());
+
+            let known = match change.tier {
+                Tier::Beginner => beginner.find(change.calculator_id).is_ok(),
+                Tier::Engineer => engineer.find(change.calculator_id).is_ok(),
+                Tier::Contractor => contractor.find(change.calculator_id).is_ok(),
+            };
+            assert!(known, "{}: unknown calculator", change.calculator_id);
+        }
+    }
+}
@@ -0,0 +1,404 @@
+// ============================================================================
+// Calculator Comparison
+//
+// Runs one parameter set through several calculators of a tier - or the same
+// calculator under different design codes - and lines their results up in a
+// single table. Each run may patch the shared parameters (a JSON merge patch,
+// so `{"design_code": "EN 1992"}` is all a code comparison needs), and every
+// value is reported next to its difference from the first successful run,
+// the baseline. Only critical results are tabled unless all are asked for.
+//
+// Runs go through the same registry path as `/calculate` (validation,
+// execution, sanitizing) and are metered like a batch: one calculation per
+// successful run.
+// ============================================================================
+
+use crate::billing;
+use crate::calculus::recommendation::Tier;
+use crate::calculus::selftest;
+use crate::flags::FlagContext;
+use crate::sec::{AppError, Claims};
+use crate::state::AppState;
+use crate::tenancy::{self, TenantScope};
+use axum::{
+    extract::State,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::{ValidationError, ValidationErrors};
+
+/// Most runs one comparison may hold
+pub const MAX_RUNS: usize = 8;
+
+/// One calculator to run, with its changes to the shared parameters
+#[derive(Debug, Clone, Deserialize)]
+pub struct ComparisonRun {
+    pub calculation_type: String,
+    /// Column heading; defaults to the calculator id
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Merged over the shared parameters; `null` removes a field
+    #[serde(default)]
+    pub parameters: Option<Value>,
+}
+
+/// POST /api/v1/calculus/compare body
+#[derive(Debug, Clone, Deserialize)]
+pub struct ComparisonRequest {
+    pub tier: Tier,
+    /// Parameters every run starts from
+    pub parameters: Value,
+    pub runs: Vec<ComparisonRun>,
+    /// Table every result, not only the critical ones
+    #[serde(default)]
+    pub all_results: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStatus {
+    Ok,
+    Error,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RunSummary {
+    pub label: String,
+    pub calculation_type: String,
+    pub status: RunStatus,
+    /// Design or regulation code the calculator reports having applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_used: Option<String>,
+    pub warnings: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// One run's value in a row, against the baseline
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComparedValue {
+    pub value: f64,
+    /// `value` minus the baseline value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta: Option<f64>,
+    /// `delta` relative to the baseline; absent when the baseline is zero
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_percent: Option<f64>,
+}
+
+/// A result compared across runs; `values` follow the order of `runs`, with
+/// `null` where a run didn't produce it
+#[derive(Debug, Serialize)]
+pub struct ComparisonRow {
+    pub label: String,
+    pub unit: String,
+    pub critical: bool,
+    pub values: Vec<Option<ComparedValue>>,
+    /// (max - min) / |baseline|, over the runs that produced the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spread_percent: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComparisonReport {
+    pub tier: Tier,
+    /// Label of the run the others are measured against
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<String>,
+    pub runs: Vec<RunSummary>,
+    pub rows: Vec<ComparisonRow>,
+}
+
+impl ComparisonRequest {
+    fn check(&self) -> Result<(), AppError> {
+        let mut errors = ValidationErrors::new();
+        if self.runs.len() < 2 || self.runs.len() > MAX_RUNS {
+            errors.add(
+                "runs",
+                ValidationError::new("count")
+                    .with_message(format!("a comparison needs between 2 and {} runs", MAX_RUNS).into()),
+            );
+        }
+        if !self.parameters.is_object() {
+            errors.add("parameters", ValidationError::new("type").with_message("must be an object".into()));
+        }
+        if errors.is_empty() { Ok(()) } else { Err(AppError::ValidationError(errors)) }
+    }
+}
+
+/// RFC 7396 merge patch: objects merge recursively, `null` deletes
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let target = target.as_object_mut().expect("just made an object");
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+/// A result item as the comparison reads it
+struct ResultCell {
+    key: (String, String),
+    value: f64,
+    critical: bool,
+}
+
+/// Result items keyed by label and unit; repeated labels get a `#n` suffix
+fn result_cells(response: &Value) -> Vec<ResultCell> {
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+    let items = response.get("results").and_then(Value::as_array).into_iter().flatten();
+    items
+        .filter_map(|item| {
+            let label = item.get("label")?.as_str()?;
+            let value = item.get("value")?.as_f64()?;
+            let unit = item.get("unit").and_then(Value::as_str).unwrap_or_default().to_string();
+
+            let mut key = (label.to_string(), unit.clone());
+            let mut n = 1;
+            while seen.contains(&key) {
+                n += 1;
+                key = (format!("{label}#{n}"), unit.clone());
+            }
+            seen.insert(key.clone());
+            Some(ResultCell {
+                key,
+                value,
+                critical: item.get("is_critical").and_then(Value::as_bool).unwrap_or(false),
+            })
+        })
+        .collect()
+}
+
+fn code_used(response: &Value) -> Option<String> {
+    let metadata = response.get("calculation_metadata")?;
+    ["design_code_used", "regulation_code_used"]
+        .iter()
+        .find_map(|field| metadata.get(*field)?.as_str())
+        .map(str::to_string)
+}
+
+/// Table the runs' results against the first successful run
+fn tabulate(outcomes: &[Result<Value, String>], all_results: bool) -> (Option<usize>, Vec<ComparisonRow>) {
+    let cells: Vec<Vec<ResultCell>> = outcomes
+        .iter()
+        .map(|outcome| outcome.as_ref().map(result_cells).unwrap_or_default())
+        .collect();
+    let baseline = outcomes.iter().position(Result::is_ok);
+
+    // Rows in the order results first appear
+    let mut keys: Vec<(String, String)> = Vec::new();
+    for cell in cells.iter().flatten() {
+        if !keys.contains(&cell.key) {
+            keys.push(cell.key.clone());
+        }
+    }
+
+    let rows = keys
+        .into_iter()
+        .filter_map(|key| {
+            let found: Vec<Option<&ResultCell>> = cells.iter().map(|run| run.iter().find(|c| c.key == key)).collect();
+            let critical = found.iter().flatten().any(|c| c.critical);
+            if !critical && !all_results {
+                return None;
+            }
+
+            let base = baseline.and_then(|b| found[b]).map(|c| c.value);
+            let values: Vec<Option<ComparedValue>> = found
+                .iter()
+                .map(|cell| {
+                    cell.map(|c| {
+                        let delta = base.map(|b| c.value - b);
+                        ComparedValue {
+                            value: c.value,
+                            delta,
+                            delta_percent: base.filter(|b| *b != 0.0).zip(delta).map(|(b, d)| d / b.abs() * 100.0),
+                        }
+                    })
+                })
+                .collect();
+
+            let present: Vec<f64> = values.iter().flatten().map(|v| v.value).collect();
+            let spread_percent = base.filter(|b| *b != 0.0 && present.len() > 1).map(|b| {
+                let max = present.iter().cloned().fold(f64::MIN, f64::max);
+                let min = present.iter().cloned().fold(f64::MAX, f64::min);
+                (max - min) / b.abs() * 100.0
+            });
+
+            let (label, unit) = key;
+            Some(ComparisonRow { label, unit, critical, values, spread_percent })
+        })
+        .collect();
+
+    (baseline, rows)
+}
+
+/// Run every comparison run and table the results; calculators in `hidden`
+/// are reported as unknown
+pub async fn compare(
+    beginner: &crate::calculus::beginner::BeginnerRegistry,
+    engineer: &crate::calculus::engineer::EngineeringRegistry,
+    contractor: &crate::calculus::contractor::ContractingRegistry,
+    request: &ComparisonRequest,
+    hidden: &HashSet<String>,
+) -> ComparisonReport {
+    let mut outcomes = Vec::with_capacity(request.runs.len());
+    for run in &request.runs {
+        let outcome = if hidden.contains(&run.calculation_type) {
+            Ok(None)
+        } else {
+            let mut parameters = request.parameters.clone();
+            if let Some(patch) = &run.parameters {
+                merge_patch(&mut parameters, patch);
+            }
+            selftest::execute(beginner, engineer, contractor, request.tier, &run.calculation_type, parameters).await
+        };
+        outcomes.push(match outcome {
+            Ok(Some(response)) => Ok(response),
+            Ok(None) => Err(format!("Unknown {} calculator: {}", request.tier.as_str(), run.calculation_type)),
+            Err(e) => Err(e),
+        });
+    }
+
+    let (baseline, rows) = tabulate(&outcomes, request.all_results);
+    let runs: Vec<RunSummary> = request
+        .runs
+        .iter()
+        .zip(&outcomes)
+        .map(|(run, outcome)| RunSummary {
+            label: run.label.clone().unwrap_or_else(|| run.calculation_type.clone()),
+            calculation_type: run.calculation_type.clone(),
+            status: if outcome.is_ok() { RunStatus::Ok } else { RunStatus::Error },
+            code_used: outcome.as_ref().ok().and_then(code_used),
+            warnings: outcome
+                .as_ref()
+                .ok()
+                .and_then(|r| r.get("warnings")?.as_array().map(Vec::len))
+                .unwrap_or(0),
+            error: outcome.as_ref().err().cloned(),
+        })
+        .collect();
+
+    ComparisonReport {
+        tier: request.tier,
+        baseline: baseline.map(|b| runs[b].label.clone()),
+        runs,
+        rows,
+    }
+}
+
+/// POST /api/v1/calculus/compare
+/// Run one parameter set through several calculators and diff the results
+pub async fn compare_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    scope: Option<TenantScope>,
+    Json(request): Json<ComparisonRequest>,
+) -> Response {
+    if let Err(e) = request.check() {
+        return e.into_response();
+    }
+
+    let user_id = claims.as_ref().and_then(|c| Uuid::parse_str(&c.sub).ok());
+    if !state.sandbox {
+        if let Some(scope) = &scope
+            && let Err(response) = tenancy::check_monthly_quota(&state.pool, scope, request.runs.len()).await
+        {
+            return response;
+        }
+        if let Err(response) = billing::check_plan(&state, user_id, request.tier, request.runs.len()).await {
+            return response;
+        }
+    }
+
+    // Calculators behind a rollout flag look missing to callers outside it
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let mut hidden = HashSet::new();
+    for run in &request.runs {
+        if !state.flags.calculator_enabled(&state.pool, request.tier, &run.calculation_type, &ctx).await {
+            hidden.insert(run.calculation_type.clone());
+        }
+    }
+
+    let report = compare(
+        &state.calculators_beginner,
+        &state.calculators_engineer,
+        &state.calculators_contractor,
+        &request,
+        &hidden,
+    )
+    .await;
+
+    // Each successful run counts towards the caller's usage, as in a batch
+    let succeeded = report.runs.iter().filter(|r| r.status == RunStatus::Ok).count();
+    if let Some(user_id) = user_id.filter(|_| !state.sandbox && !state.maintenance.is_active())
+        && let Err(e) = billing::record_calculations(&state.pool, user_id, request.tier, succeeded).await
+    {
+        tracing::error!(error = %e, %user_id, count = succeeded, "failed to record calculations");
+    }
+
+    Json(report).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_patch() {
+        let mut target = json!({ "design_code": "ACI 318", "additional": { "a": 1.0, "b": 2.0 } });
+        merge_patch(&mut target, &json!({ "design_code": "EN 1992", "additional": { "b": null, "c": 3.0 } }));
+        assert_eq!(target, json!({ "design_code": "EN 1992", "additional": { "a": 1.0, "c": 3.0 } }));
+    }
+
+    #[tokio::test]
+    async fn test_runs_are_diffed_against_the_baseline() {
+        let request: ComparisonRequest = serde_json::from_value(json!({
+            "tier": "contractor",
+            "parameters": {
+                "dimensions": {},
+                "resources": { "labor_hours": 10.0, "equipment_hours": 100.0 },
+                "additional": { "equipment_rate": 100.0, "maintenance_factor": 1.0 }
+            },
+            "runs": [
+                { "calculation_type": "equipment_cost", "label": "Owned" },
+                { "calculation_type": "equipment_cost", "label": "Rented", "parameters": { "additional": { "equipment_rate": 150.0 } } },
+                { "calculation_type": "no_such_calculator" }
+            ]
+        }))
+        .unwrap();
+
+        let report = compare(
+            &crate::calculus::beginner::create_default_registry(),
+            &crate::calculus::engineer::create_default_registry(),
+            &crate::calculus::contractor::create_default_registry(),
+            &request,
+            &HashSet::new(),
+        )
+        .await;
+
+        assert_eq!(report.baseline.as_deref(), Some("Owned"));
+        assert_eq!(report.runs[2].status, RunStatus::Error);
+
+        let row = report.rows.iter().find(|r| r.label == "Total Equipment Cost").unwrap();
+        assert_eq!(row.unit, "USD");
+        assert_eq!(row.values[0].as_ref().unwrap().value, 10_000.0);
+        assert_eq!(row.values[1].as_ref().unwrap().delta_percent, Some(50.0));
+        assert_eq!(row.values[2], None);
+        assert_eq!(row.spread_percent, Some(50.0));
+    }
+}
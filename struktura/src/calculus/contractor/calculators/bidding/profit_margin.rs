@@ -2,6 +2,7 @@ use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::PROFIT_LOW_MARGIN,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -104,7 +105,7 @@ impl ContractorCalculator for ProfitMarginCalculator {
         ];
 
         let warnings = if margin < 10.0 {
-            vec!["Low profit margin".to_string()]
+            vec![PROFIT_LOW_MARGIN.warn("Low profit margin")]
         } else {
             vec![]
         };
@@ -118,8 +119,8 @@ impl ContractorCalculator for ProfitMarginCalculator {
                 risk_level: 100.0 - margin,
                 compliance_score: 1.0,
             }),
-            warnings,
-            structured_warnings: None,
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations: vec!["Aim for margins above 15% for sustainability".to_string()],
             compliance_notes: vec!["Compliant with PMP profit guidelines".to_string()],
             calculation_metadata: Some(CalculationMetadata {
@@ -2,6 +2,7 @@ use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::RISK_HIGH_LEVEL,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -117,7 +118,7 @@ impl ContractorCalculator for RiskAssessmentCalculator {
         ];
 
         let warnings = if risk_level > 50.0 {
-            vec!["High risk level detected".to_string()]
+            vec![RISK_HIGH_LEVEL.warn("High risk level detected")]
         } else {
             vec![]
         };
@@ -131,8 +132,8 @@ impl ContractorCalculator for RiskAssessmentCalculator {
                 risk_level,
                 compliance_score: 1.0 - adjusted_risk,
             }),
-            warnings,
-            structured_warnings: None,
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations: vec!["Implement additional risk mitigation if level > 30%".to_string()],
             compliance_notes: vec!["Compliant with OSHA risk assessment".to_string()],
             calculation_metadata: Some(CalculationMetadata {
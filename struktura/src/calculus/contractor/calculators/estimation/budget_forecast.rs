@@ -73,6 +73,32 @@ impl ContractorCalculator for BudgetForecastCalculator {
                 validation_rules: None,
                 default_value: Some(3.0),
             })
+            .parameter(ParameterMetadata {
+                name: "material_share".to_string(),
+                path: "additional.material_share".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Share of total cost spent on materials".to_string(),
+                required: false,
+                min_value: Some(0.0),
+                max_value: Some(100.0),
+                typical_range: Some((30.0, 60.0)),
+                validation_rules: None,
+                default_value: Some(0.0),
+            })
+            .parameter(ParameterMetadata {
+                name: "material_escalation_rate".to_string(),
+                path: "additional.material_escalation_rate".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Annual material price escalation (annualized_escalation_pct from /pricing/history)".to_string(),
+                required: false,
+                min_value: Some(-50.0),
+                max_value: Some(100.0),
+                typical_range: Some((0.0, 10.0)),
+                validation_rules: None,
+                default_value: None,
+            })
             .complexity(ComplexityLevel::Intermediate)
             .build()
     }
@@ -80,6 +106,13 @@ impl ContractorCalculator for BudgetForecastCalculator {
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         self.get_additional_param(params, "total_cost", Some(0.0), None)?;
         self.get_additional_param(params, "duration_months", Some(1.0), Some(120.0))?;
+        let additional = params.additional.as_ref();
+        if additional.is_some_and(|a| a.contains_key("material_share")) {
+            self.get_additional_param(params, "material_share", Some(0.0), Some(100.0))?;
+        }
+        if additional.is_some_and(|a| a.contains_key("material_escalation_rate")) {
+            self.get_additional_param(params, "material_escalation_rate", Some(-50.0), Some(100.0))?;
+        }
         Ok(())
     }
 
@@ -88,8 +121,19 @@ impl ContractorCalculator for BudgetForecastCalculator {
         let duration = self.get_additional_param(&params, "duration_months", None, None)?;
         let inflation = self.get_additional_param(&params, "inflation_rate", None, None).unwrap_or(3.0) / 100.0;
 
+        let material_share = self.get_additional_param(&params, "material_share", None, None).unwrap_or(0.0) / 100.0;
+        let material_escalation = self.get_additional_param(&params, "material_escalation_rate", None, None)
+            .ok()
+            .map(|rate| rate / 100.0);
+
+        // Materials escalate at their observed market rate when one is supplied,
+        // everything else at general inflation
+        let escalation = |rate: f64| (1.0 + rate / 12.0).powf(duration);
+        let material_rate = material_escalation.unwrap_or(inflation);
+
         let monthly_cost = total_cost / duration;
-        let adjusted_monthly = monthly_cost * (1.0 + inflation / 12.0).powf(duration);
+        let adjusted_monthly = monthly_cost
+            * ((1.0 - material_share) * escalation(inflation) + material_share * escalation(material_rate));
         let forecast_total = adjusted_monthly * duration;
 
         let mut results = vec![
@@ -111,6 +155,27 @@ impl ContractorCalculator for BudgetForecastCalculator {
             },
         ];
 
+        let mut recommendations = vec!["Monitor inflation trends".to_string()];
+        if let Some(rate) = material_escalation {
+            let material_cost = total_cost * material_share;
+            let material_increase = material_cost * (escalation(rate) - 1.0);
+            results.push(ContractingResultItem {
+                label: "Material Escalation".to_string(),
+                value: material_increase,
+                unit: "USD".to_string(),
+                tolerance: Some(0.15),
+                formatted_value: Some(format!("${:.2}", material_increase)),
+                is_critical: false,
+            });
+            if rate > inflation {
+                recommendations.push(format!(
+                    "Material prices are rising {:.1}%/yr vs {:.1}% inflation; consider locking supplier quotes early",
+                    rate * 100.0,
+                    inflation * 100.0
+                ));
+            }
+        }
+
         Ok(ContractingCalculationResponse {
             calculation_type: self.id().to_string(),
             results,
@@ -122,7 +187,7 @@ impl ContractorCalculator for BudgetForecastCalculator {
             }),
             warnings: vec![],
             structured_warnings: None,
-            recommendations: vec!["Monitor inflation trends".to_string()],
+            recommendations,
             compliance_notes: vec!["Compliant with PMP forecasting".to_string()],
             calculation_metadata: Some(CalculationMetadata {
                 timestamp: chrono::Utc::now().to_rfc3339(),
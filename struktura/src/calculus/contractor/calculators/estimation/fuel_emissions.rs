@@ -0,0 +1,374 @@
+use crate::calculus::contractor::{
+    errors::{ContractingError, ContractingResult},
+    models::*,
+    schedule::Schedule,
+    traits::{ContractorCalculator, ParameterValidator},
+};
+use async_trait::async_trait;
+
+/// Fuel burned idling relative to working at load
+const IDLE_BURN_RATIO: f64 = 0.25;
+
+/// Phase used for activities without one, and for bare equipment hours
+const DEFAULT_PHASE: &str = "Project";
+
+/// Typical diesel burn at load, L/h, by equipment class
+const FUEL_RATES: &[(&str, f64)] = &[
+    ("excavator", 18.0),
+    ("wheel_loader", 16.0),
+    ("dozer", 25.0),
+    ("backhoe", 9.0),
+    ("skid_steer", 6.0),
+    ("grader", 17.0),
+    ("roller", 9.0),
+    ("dump_truck", 20.0),
+    ("crane", 14.0),
+    ("telehandler", 8.0),
+    ("concrete_pump", 14.0),
+    ("compressor", 8.0),
+    ("generator", 7.0),
+];
+
+/// Engine hours and load-rated fuel burn rolled up for one phase
+struct PhaseUsage {
+    phase: String,
+    hours: f64,
+    /// Sum of hours × L/h, before idle adjustment
+    rated_fuel: f64,
+}
+
+/// Estimator for fleet fuel use, fuel cost and Scope 1 emissions
+pub struct FuelEmissionsCalculator;
+
+impl FuelEmissionsCalculator {
+    /// Equipment hours per phase from the schedule, or from `resources.equipment_hours`
+    fn usage(&self, params: &ContractingParameters, warnings: &mut Vec<String>) -> ContractingResult<Vec<PhaseUsage>> {
+        let hours_per_day = self.optional_param(params, "hours_per_day", 8.0, 1.0, 24.0)?;
+        let default_rate = self.optional_param(params, "fuel_rate", 15.0, 1.0, 200.0)?;
+
+        let Some(schedule) = params.schedule.as_ref().filter(|s| has_equipment(s)) else {
+            let resources = params.resources.as_ref().ok_or_else(|| ContractingError::MissingParameter {
+                parameter: "resources.equipment_hours".to_string(),
+                calculator: self.calculator_id().to_string(),
+            })?;
+            if !resources.equipment_hours.is_finite() || resources.equipment_hours < 0.0 {
+                return Err(ContractingError::InvalidParameter {
+                    parameter: "resources.equipment_hours".to_string(),
+                    value: resources.equipment_hours.to_string(),
+                    reason: "Equipment hours must be zero or more".to_string(),
+                });
+            }
+            return Ok(vec![PhaseUsage {
+                phase: DEFAULT_PHASE.to_string(),
+                hours: resources.equipment_hours,
+                rated_fuel: resources.equipment_hours * default_rate,
+            }]);
+        };
+
+        let mut phases: Vec<PhaseUsage> = Vec::new();
+        let mut unknown: Vec<&str> = Vec::new();
+        for activity in &schedule.activities {
+            let phase = activity.phase.as_deref().unwrap_or(DEFAULT_PHASE);
+            for equipment in &activity.equipment {
+                let field = format!("schedule.{}.equipment.{}", activity.id, equipment.kind);
+                let hours = equipment.hours.unwrap_or(equipment.count * activity.duration_days * hours_per_day);
+                let rate = match equipment.fuel_rate.or_else(|| class_rate(&equipment.kind)) {
+                    Some(rate) => rate,
+                    None => {
+                        if !unknown.contains(&equipment.kind.as_str()) {
+                            unknown.push(&equipment.kind);
+                        }
+                        default_rate
+                    }
+                };
+                if !hours.is_finite() || hours < 0.0 || equipment.count < 0.0 {
+                    return Err(ContractingError::InvalidParameter {
+                        parameter: field,
+                        value: hours.to_string(),
+                        reason: "Equipment count and hours must be zero or more".to_string(),
+                    });
+                }
+                if !rate.is_finite() || rate <= 0.0 {
+                    return Err(ContractingError::InvalidParameter {
+                        parameter: field,
+                        value: rate.to_string(),
+                        reason: "Fuel rate must be positive".to_string(),
+                    });
+                }
+
+                match phases.iter_mut().find(|p| p.phase == phase) {
+                    Some(usage) => {
+                        usage.hours += hours;
+                        usage.rated_fuel += hours * rate;
+                    }
+                    None => phases.push(PhaseUsage {
+                        phase: phase.to_string(),
+                        hours,
+                        rated_fuel: hours * rate,
+                    }),
+                }
+            }
+        }
+        if !unknown.is_empty() {
+            warnings.push(format!(
+                "No default fuel rate for {}; used {:.1} L/h. Set fuel_rate on the equipment for a better estimate",
+                unknown.join(", "),
+                default_rate
+            ));
+        }
+        Ok(phases)
+    }
+}
+
+fn has_equipment(schedule: &Schedule) -> bool {
+    schedule.activities.iter().any(|a| !a.equipment.is_empty())
+}
+
+fn class_rate(kind: &str) -> Option<f64> {
+    let kind = kind.trim().to_lowercase().replace([' ', '-'], "_");
+    FUEL_RATES.iter().find(|(k, _)| *k == kind).map(|(_, rate)| *rate)
+}
+
+/// Fuel per rated litre when a fraction `idle` of engine hours is spent idling
+fn idle_factor(idle: f64) -> f64 {
+    (1.0 - idle) + idle * IDLE_BURN_RATIO
+}
+
+/// Fuel after cutting idle from `idle` to `reduced`, as a share of today's burn.
+/// Productive hours stay the same, so total engine hours shrink with the idle time.
+fn reduction_ratio(idle: f64, reduced: f64) -> f64 {
+    (1.0 - idle) / (1.0 - reduced) * idle_factor(reduced) / idle_factor(idle)
+}
+
+impl ParameterValidator for FuelEmissionsCalculator {
+    fn calculator_id(&self) -> &str {
+        "fuel_emissions"
+    }
+}
+
+fn number(name: &str, path: &str, unit: &str, description: &str, range: (f64, f64), typical: Option<(f64, f64)>, default: Option<f64>) -> ParameterMetadata {
+    ParameterMetadata {
+        name: name.to_string(),
+        path: path.to_string(),
+        data_type: ParameterType::Number,
+        unit: unit.to_string(),
+        description: description.to_string(),
+        required: default.is_none(),
+        min_value: Some(range.0),
+        max_value: Some(range.1),
+        typical_range: typical,
+        validation_rules: None,
+        default_value: default,
+    }
+}
+
+#[async_trait]
+impl ContractorCalculator for FuelEmissionsCalculator {
+    fn id(&self) -> &str {
+        "fuel_emissions"
+    }
+
+    fn name(&self) -> &str {
+        "Fuel Consumption and Fleet Emissions"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Estimation
+    }
+
+    fn metadata(&self) -> ContractingCalculatorMetadata {
+        ContractingCalculatorMetadata::builder("fuel_emissions", "Fuel Consumption and Fleet Emissions")
+            .category("estimation")
+            .description("Rolls equipment hours from the schedule into fuel use, fuel cost and CO2e per phase, with idle reduction scenarios")
+            .regulation_code("GHG Protocol")
+            .parameter(ParameterMetadata {
+                name: "equipment_hours".to_string(),
+                path: "resources.equipment_hours".to_string(),
+                data_type: ParameterType::Number,
+                unit: "hours".to_string(),
+                description: "Fleet engine hours; used when the schedule lists no equipment".to_string(),
+                required: false,
+                min_value: Some(0.0),
+                max_value: None,
+                typical_range: Some((100.0, 5000.0)),
+                validation_rules: Some(vec!["positive".to_string()]),
+                default_value: None,
+            })
+            .parameter(number("fuel_price", "additional.fuel_price", "USD/L", "Delivered diesel price", (0.0, 10.0), Some((0.9, 1.6)), Some(1.10)))
+            .parameter(number("fuel_rate", "additional.fuel_rate", "L/h", "Burn rate for equipment without a class default", (1.0, 200.0), Some((6.0, 25.0)), Some(15.0)))
+            .parameter(number("hours_per_day", "additional.hours_per_day", "hours", "Engine hours per unit per working day", (1.0, 24.0), Some((8.0, 10.0)), Some(8.0)))
+            .parameter(number("emission_factor", "additional.emission_factor", "kg CO2e/L", "Well-to-exhaust factor for the fuel", (0.0, 5.0), Some((2.6, 3.2)), Some(2.70)))
+            .parameter(number("idle_fraction", "additional.idle_fraction", "", "Share of engine hours spent idling today", (0.0, 0.9), Some((0.2, 0.5)), Some(0.35)))
+            .parameter(number("target_idle_fraction", "additional.target_idle_fraction", "", "Idle share an idle-reduction policy aims for", (0.0, 0.9), Some((0.1, 0.2)), Some(0.15)))
+            .complexity(ComplexityLevel::Intermediate)
+            .build()
+    }
+
+    fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
+        self.usage(params, &mut Vec::new())?;
+        self.optional_param(params, "fuel_price", 1.10, 0.0, 10.0)?;
+        self.optional_param(params, "emission_factor", 2.70, 0.0, 5.0)?;
+        self.optional_param(params, "idle_fraction", 0.35, 0.0, 0.9)?;
+        self.optional_param(params, "target_idle_fraction", 0.15, 0.0, 0.9)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: ContractingParameters) -> ContractingResult<ContractingCalculationResponse> {
+        let mut warnings = Vec::new();
+        let phases = self.usage(&params, &mut warnings)?;
+        let fuel_price = self.optional_param(&params, "fuel_price", 1.10, 0.0, 10.0)?;
+        let emission_factor = self.optional_param(&params, "emission_factor", 2.70, 0.0, 5.0)?;
+        let idle = self.optional_param(&params, "idle_fraction", 0.35, 0.0, 0.9)?;
+        let target = self.optional_param(&params, "target_idle_fraction", 0.15, 0.0, 0.9)?;
+
+        let mut results = Vec::new();
+        let mut total_hours = 0.0;
+        let mut total_fuel = 0.0;
+        for usage in &phases {
+            let fuel = usage.rated_fuel * idle_factor(idle);
+            total_hours += usage.hours;
+            total_fuel += fuel;
+            if phases.len() > 1 {
+                results.push(ContractingResultItem {
+                    label: format!("{} Fuel", usage.phase),
+                    value: fuel,
+                    unit: "L".to_string(),
+                    tolerance: Some(0.2),
+                    formatted_value: Some(format!("{:.0} L over {:.0} h", fuel, usage.hours)),
+                    is_critical: false,
+                });
+                results.push(ContractingResultItem {
+                    label: format!("{} Fuel Cost", usage.phase),
+                    value: fuel * fuel_price,
+                    unit: "USD".to_string(),
+                    tolerance: Some(0.2),
+                    formatted_value: Some(format!("${:.2}", fuel * fuel_price)),
+                    is_critical: false,
+                });
+                results.push(ContractingResultItem {
+                    label: format!("{} CO2e", usage.phase),
+                    value: fuel * emission_factor / 1000.0,
+                    unit: "t CO2e".to_string(),
+                    tolerance: Some(0.2),
+                    formatted_value: Some(format!("{:.2} t", fuel * emission_factor / 1000.0)),
+                    is_critical: false,
+                });
+            }
+        }
+        let total_cost = total_fuel * fuel_price;
+        let total_co2e = total_fuel * emission_factor / 1000.0;
+
+        results.push(ContractingResultItem {
+            label: "Engine Hours".to_string(),
+            value: total_hours,
+            unit: "hours".to_string(),
+            tolerance: None,
+            formatted_value: Some(format!("{:.0} h", total_hours)),
+            is_critical: false,
+        });
+        results.push(ContractingResultItem {
+            label: "Total Fuel".to_string(),
+            value: total_fuel,
+            unit: "L".to_string(),
+            tolerance: Some(0.2),
+            formatted_value: Some(format!("{:.0} L", total_fuel)),
+            is_critical: true,
+        });
+        results.push(ContractingResultItem {
+            label: "Total Fuel Cost".to_string(),
+            value: total_cost,
+            unit: "USD".to_string(),
+            tolerance: Some(0.2),
+            formatted_value: Some(format!("${:.2}", total_cost)),
+            is_critical: true,
+        });
+        results.push(ContractingResultItem {
+            label: "Scope 1 CO2e".to_string(),
+            value: total_co2e,
+            unit: "t CO2e".to_string(),
+            tolerance: Some(0.2),
+            formatted_value: Some(format!("{:.2} t", total_co2e)),
+            is_critical: true,
+        });
+
+        // Idle cuts relative to today's share, then the policy target
+        let mut scenarios = Vec::new();
+        if idle > 0.0 {
+            scenarios.push(("Idle -25%".to_string(), idle * 0.75));
+            scenarios.push(("Idle -50%".to_string(), idle * 0.5));
+        }
+        if target < idle {
+            scenarios.push((format!("Idle at {:.0}%", target * 100.0), target));
+        }
+        let mut best_saving = 0.0;
+        for (name, reduced) in scenarios {
+            let saved = total_fuel * (1.0 - reduction_ratio(idle, reduced));
+            best_saving = f64::max(best_saving, saved);
+            results.push(ContractingResultItem {
+                label: format!("{}: Fuel Saved", name),
+                value: saved,
+                unit: "L".to_string(),
+                tolerance: Some(0.25),
+                formatted_value: Some(format!("{:.0} L", saved)),
+                is_critical: false,
+            });
+            results.push(ContractingResultItem {
+                label: format!("{}: Cost Saved", name),
+                value: saved * fuel_price,
+                unit: "USD".to_string(),
+                tolerance: Some(0.25),
+                formatted_value: Some(format!("${:.2}", saved * fuel_price)),
+                is_critical: false,
+            });
+            results.push(ContractingResultItem {
+                label: format!("{}: CO2e Avoided", name),
+                value: saved * emission_factor / 1000.0,
+                unit: "t CO2e".to_string(),
+                tolerance: Some(0.25),
+                formatted_value: Some(format!("{:.2} t", saved * emission_factor / 1000.0)),
+                is_critical: false,
+            });
+        }
+
+        let mut recommendations = Vec::new();
+        if idle > 0.3 {
+            recommendations.push(format!(
+                "Idle share of {:.0}% is high; auto-shutdown and telematics idle reports typically bring it under 20%",
+                idle * 100.0
+            ));
+        }
+        if best_saving > 0.0 {
+            recommendations.push(format!(
+                "Best idle scenario saves {:.0} L (${:.2}); carry it as an alternate in the estimate",
+                best_saving,
+                best_saving * fuel_price
+            ));
+        }
+        if total_hours == 0.0 {
+            warnings.push("No engine hours found; fuel and emissions are zero".to_string());
+        }
+
+        Ok(ContractingCalculationResponse {
+            calculation_type: self.id().to_string(),
+            results,
+            analysis: Some(ProjectAnalysisResult {
+                total_cost,
+                total_duration: 0.0,
+                risk_level: 0.1,
+                compliance_score: 1.0,
+            }),
+            warnings,
+            structured_warnings: None,
+            recommendations,
+            compliance_notes: vec![
+                "Scope 1 mobile combustion per the GHG Protocol; report with the emission factor used".to_string(),
+            ],
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: "1.0".to_string(),
+                regulation_code_used: "GHG Protocol".to_string(),
+                requires_certification_review: false,
+            }),
+        })
+    }
+}
@@ -3,9 +3,25 @@ use crate::calculus::contractor::{
     models::*,
     traits::{ContractorCalculator, ParameterValidator},
 };
+use crate::pricing::{MaterialCategory, MaterialId};
 use async_trait::async_trait;
 use std::collections::HashMap;
 
+/// Live pricing identity of the estimated material, keyed by its type
+fn priced_material(material: &MaterialProperties) -> MaterialId {
+    let code = material.material_type.trim().to_lowercase().replace([' ', '-'], "_");
+    let category = match code.as_str() {
+        "concrete" => MaterialCategory::Concrete,
+        "rebar" => MaterialCategory::Rebar,
+        "steel" => MaterialCategory::Steel,
+        "lumber" | "timber" => MaterialCategory::Lumber,
+        "gravel" => MaterialCategory::Gravel,
+        "sand" => MaterialCategory::Sand,
+        _ => MaterialCategory::Custom(code.clone()),
+    };
+    MaterialId::new(category, code, "unit", material.material_type.clone())
+}
+
 /// Estimator for material costs
 pub struct MaterialCostEstimator;
 
@@ -77,6 +93,10 @@ impl ContractorCalculator for MaterialCostEstimator {
             .build()
     }
 
+    fn priced_materials(&self, params: &ContractingParameters) -> Vec<MaterialId> {
+        params.material.iter().map(priced_material).collect()
+    }
+
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         self.validate_resources(&params.resources)?;
         self.validate_material(&params.material)?;
@@ -87,7 +107,9 @@ impl ContractorCalculator for MaterialCostEstimator {
         let resources = params.resources.as_ref().unwrap();
         let material = params.material.as_ref().unwrap();
         let quantity = resources.material_quantity.unwrap_or(0.0);
-        let unit_cost = material.unit_cost.unwrap_or(0.0);
+        // A live regional price beats the one sent with the request
+        let live_cost = params.live_prices.get(&priced_material(material).code);
+        let unit_cost = live_cost.or(material.unit_cost).unwrap_or(0.0);
         let waste_factor = material.waste_factor.unwrap_or(1.1);
 
         let adjusted_quantity = quantity * waste_factor;
@@ -123,7 +145,11 @@ impl ContractorCalculator for MaterialCostEstimator {
             }),
             warnings: vec![],
             structured_warnings: None,
-            recommendations: vec!["Check current market prices".to_string()],
+            recommendations: if live_cost.is_some() {
+                vec![format!("Unit cost of ${:.2} is a live regional price; confirm with the supplier before ordering", unit_cost)]
+            } else {
+                vec!["Check current market prices".to_string()]
+            },
             compliance_notes: vec!["Compliant with ASTM material standards".to_string()],
             calculation_metadata: Some(CalculationMetadata {
                 timestamp: chrono::Utc::now().to_rfc3339(),
@@ -1,17 +1,21 @@
 pub mod budget_forecast;
 pub mod cost_breakdown;
 pub mod equipment_cost;
+pub mod fuel_emissions;
 pub mod labor_cost;
 pub mod material_cost;
 pub mod overhead;
 pub mod quantity_takeoff;
+pub mod travel_cost;
 pub mod value_engineering;
 
 pub use budget_forecast::BudgetForecastCalculator;
 pub use cost_breakdown::CostBreakdownCalculator;
 pub use equipment_cost::EquipmentCostEstimator;
+pub use fuel_emissions::FuelEmissionsCalculator;
 pub use labor_cost::LaborCostEstimator;
 pub use material_cost::MaterialCostEstimator;
 pub use overhead::OverheadCalculator;
 pub use quantity_takeoff::QuantityTakeoffCalculator;
+pub use travel_cost::TravelCostEstimator;
 pub use value_engineering::ValueEngineeringCalculator;
@@ -0,0 +1,229 @@
+use crate::calculus::contractor::{
+    errors::{ContractingError, ContractingResult},
+    models::*,
+    traits::{ContractorCalculator, ParameterValidator},
+};
+use crate::pricing::geocoding::{haversine_km, validate_coordinates};
+use crate::pricing::Location;
+use async_trait::async_trait;
+
+const KM_PER_MILE: f64 = 1.609344;
+
+/// Daily driving limit for a property-carrying driver (FMCSA 49 CFR 395.3)
+const MAX_DRIVING_HOURS: f64 = 11.0;
+
+/// One-way drive beyond which lodging near the site usually beats commuting
+const COMMUTE_LIMIT_HOURS: f64 = 1.5;
+
+/// Estimator for mobilization and daily travel between the yard and the jobsite
+pub struct TravelCostEstimator;
+
+impl TravelCostEstimator {
+    /// Coordinates of a placed location, or the parameter that is missing
+    fn coordinates(&self, location: &Option<Location>, name: &str) -> ContractingResult<(f64, f64)> {
+        let (lat, lon) = location
+            .as_ref()
+            .and_then(Location::coordinates)
+            .ok_or_else(|| ContractingError::MissingParameter {
+                parameter: format!("{}.latitude/longitude", name),
+                calculator: self.calculator_id().to_string(),
+            })?;
+        validate_coordinates(lat, lon).map_err(|e| ContractingError::InvalidParameter {
+            parameter: name.to_string(),
+            value: format!("{}, {}", lat, lon),
+            reason: e.to_string(),
+        })?;
+        Ok((lat, lon))
+    }
+}
+
+impl ParameterValidator for TravelCostEstimator {
+    fn calculator_id(&self) -> &str {
+        "travel_cost"
+    }
+}
+
+fn number(name: &str, path: &str, unit: &str, description: &str, range: (f64, f64), typical: Option<(f64, f64)>, default: Option<f64>) -> ParameterMetadata {
+    ParameterMetadata {
+        name: name.to_string(),
+        path: path.to_string(),
+        data_type: ParameterType::Number,
+        unit: unit.to_string(),
+        description: description.to_string(),
+        required: default.is_none(),
+        min_value: Some(range.0),
+        max_value: Some(range.1),
+        typical_range: typical,
+        validation_rules: None,
+        default_value: default,
+    }
+}
+
+#[async_trait]
+impl ContractorCalculator for TravelCostEstimator {
+    fn id(&self) -> &str {
+        "travel_cost"
+    }
+
+    fn name(&self) -> &str {
+        "Jobsite Travel Cost Estimator"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Estimation
+    }
+
+    fn metadata(&self) -> ContractingCalculatorMetadata {
+        ContractingCalculatorMetadata::builder("travel_cost", "Jobsite Travel Cost Estimator")
+            .category("estimation")
+            .description("Estimates equipment mobilization and daily crew travel from the office or yard to the jobsite; addresses without coordinates are geocoded")
+            .regulation_code("FMCSA")
+            .parameter(number("origin_latitude", "origin.latitude", "deg", "Office or yard latitude", (-90.0, 90.0), None, None))
+            .parameter(number("origin_longitude", "origin.longitude", "deg", "Office or yard longitude", (-180.0, 180.0), None, None))
+            .parameter(number("site_latitude", "location.latitude", "deg", "Jobsite latitude", (-90.0, 90.0), None, None))
+            .parameter(number("site_longitude", "location.longitude", "deg", "Jobsite longitude", (-180.0, 180.0), None, None))
+            .parameter(number("project_days", "additional.project_days", "days", "Working days the crew travels to site", (1.0, 2000.0), Some((5.0, 250.0)), None))
+            .parameter(number("equipment_loads", "additional.equipment_loads", "loads", "Heavy-haul loads to mobilize", (0.0, 100.0), Some((1.0, 10.0)), Some(1.0)))
+            .parameter(number("transport_rate", "additional.transport_rate", "USD/mile", "Equipment transport rate per loaded mile", (0.0, 50.0), Some((3.0, 10.0)), Some(5.0)))
+            .parameter(number("crew_vehicles", "additional.crew_vehicles", "vehicles", "Vehicles driven to site each day", (0.0, 50.0), Some((1.0, 6.0)), Some(2.0)))
+            .parameter(number("vehicle_rate", "additional.vehicle_rate", "USD/mile", "Crew vehicle cost per mile", (0.0, 5.0), Some((0.6, 1.2)), Some(0.70)))
+            .parameter(number("crew_size", "additional.crew_size", "workers", "Workers paid for travel time", (0.0, 200.0), Some((2.0, 12.0)), Some(4.0)))
+            .parameter(number("travel_labor_rate", "additional.travel_labor_rate", "USD/hour", "Wage paid for drive time; 0 when travel is unpaid", (0.0, 200.0), Some((0.0, 60.0)), Some(0.0)))
+            .parameter(number("average_speed", "additional.average_speed", "mph", "Average road speed", (5.0, 75.0), Some((30.0, 55.0)), Some(45.0)))
+            .parameter(number("circuity_factor", "additional.circuity_factor", "", "Road distance over straight-line distance", (1.0, 2.0), Some((1.2, 1.4)), Some(1.3)))
+            .complexity(ComplexityLevel::Basic)
+            .build()
+    }
+
+    fn needs_coordinates(&self) -> bool {
+        true
+    }
+
+    fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
+        self.coordinates(&params.origin, "origin")?;
+        self.coordinates(&params.location, "location")?;
+        self.get_additional_param(params, "project_days", Some(1.0), Some(2000.0))?;
+        self.optional_param(params, "equipment_loads", 1.0, 0.0, 100.0)?;
+        self.optional_param(params, "transport_rate", 5.0, 0.0, 50.0)?;
+        self.optional_param(params, "crew_vehicles", 2.0, 0.0, 50.0)?;
+        self.optional_param(params, "vehicle_rate", 0.70, 0.0, 5.0)?;
+        self.optional_param(params, "crew_size", 4.0, 0.0, 200.0)?;
+        self.optional_param(params, "travel_labor_rate", 0.0, 0.0, 200.0)?;
+        self.optional_param(params, "average_speed", 45.0, 5.0, 75.0)?;
+        self.optional_param(params, "circuity_factor", 1.3, 1.0, 2.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: ContractingParameters) -> ContractingResult<ContractingCalculationResponse> {
+        let origin = self.coordinates(&params.origin, "origin")?;
+        let site = self.coordinates(&params.location, "location")?;
+        let project_days = self.get_additional_param(&params, "project_days", None, None)?;
+        let equipment_loads = self.optional_param(&params, "equipment_loads", 1.0, 0.0, 100.0)?;
+        let transport_rate = self.optional_param(&params, "transport_rate", 5.0, 0.0, 50.0)?;
+        let crew_vehicles = self.optional_param(&params, "crew_vehicles", 2.0, 0.0, 50.0)?;
+        let vehicle_rate = self.optional_param(&params, "vehicle_rate", 0.70, 0.0, 5.0)?;
+        let crew_size = self.optional_param(&params, "crew_size", 4.0, 0.0, 200.0)?;
+        let travel_labor_rate = self.optional_param(&params, "travel_labor_rate", 0.0, 0.0, 200.0)?;
+        let average_speed = self.optional_param(&params, "average_speed", 45.0, 5.0, 75.0)?;
+        let circuity_factor = self.optional_param(&params, "circuity_factor", 1.3, 1.0, 2.0)?;
+
+        // Roads wander; scale the great-circle distance to a driving distance
+        let straight_km = haversine_km(origin, site);
+        let road_miles = straight_km * circuity_factor / KM_PER_MILE;
+        let drive_hours = road_miles / average_speed;
+
+        // Each load is hauled out at mobilization and back at demobilization
+        let mobilization = equipment_loads * road_miles * transport_rate;
+        let mob_demob = 2.0 * mobilization;
+
+        // Crews drive out and back every working day
+        let daily_mileage = crew_vehicles * 2.0 * road_miles * vehicle_rate;
+        let daily_travel_time = crew_size * 2.0 * drive_hours * travel_labor_rate;
+        let daily_travel = daily_mileage + daily_travel_time;
+        let total_travel = daily_travel * project_days;
+        let total_cost = mob_demob + total_travel;
+
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        if drive_hours > MAX_DRIVING_HOURS {
+            warnings.push(format!(
+                "One-way drive of {:.1} h exceeds the {:.0} h FMCSA driving limit; equipment haul needs a layover or team drivers",
+                drive_hours, MAX_DRIVING_HOURS
+            ));
+        }
+        if drive_hours > COMMUTE_LIMIT_HOURS && project_days > 1.0 {
+            recommendations.push(format!(
+                "{:.1} h each way: compare daily travel of ${:.2} with per diem and lodging near the site",
+                drive_hours, daily_travel
+            ));
+        }
+        if straight_km < 1.0 {
+            recommendations.push("Yard and jobsite are under 1 km apart; check that both locations were placed correctly".to_string());
+        }
+        recommendations.push("Confirm haul rates, permits and escort requirements for oversize loads with the carrier".to_string());
+
+        let money = |label: &str, value: f64, is_critical: bool| ContractingResultItem {
+            label: label.to_string(),
+            value,
+            unit: "USD".to_string(),
+            tolerance: Some(0.15),
+            formatted_value: Some(format!("${:.2}", value)),
+            is_critical,
+        };
+
+        let results = vec![
+            ContractingResultItem {
+                label: "Straight-line Distance".to_string(),
+                value: straight_km,
+                unit: "km".to_string(),
+                tolerance: None,
+                formatted_value: Some(format!("{:.1} km", straight_km)),
+                is_critical: false,
+            },
+            ContractingResultItem {
+                label: "One-way Road Distance".to_string(),
+                value: road_miles,
+                unit: "mi".to_string(),
+                tolerance: Some(0.15),
+                formatted_value: Some(format!("{:.1} mi", road_miles)),
+                is_critical: false,
+            },
+            ContractingResultItem {
+                label: "One-way Drive Time".to_string(),
+                value: drive_hours,
+                unit: "hours".to_string(),
+                tolerance: Some(0.2),
+                formatted_value: Some(format!("{:.2} h", drive_hours)),
+                is_critical: false,
+            },
+            money("Mobilization (One Way)", mobilization, false),
+            money("Mobilization and Demobilization", mob_demob, true),
+            money("Daily Vehicle Mileage", daily_mileage, false),
+            money("Daily Paid Travel Time", daily_travel_time, false),
+            money("Daily Travel Cost", daily_travel, false),
+            money("Total Crew Travel", total_travel, false),
+            money("Total Travel Cost", total_cost, true),
+        ];
+
+        Ok(ContractingCalculationResponse {
+            calculation_type: self.id().to_string(),
+            results,
+            analysis: Some(ProjectAnalysisResult {
+                total_cost,
+                total_duration: project_days,
+                risk_level: if drive_hours > MAX_DRIVING_HOURS { 0.5 } else { 0.1 },
+                compliance_score: if drive_hours > MAX_DRIVING_HOURS { 0.7 } else { 1.0 },
+            }),
+            warnings,
+            structured_warnings: None,
+            recommendations,
+            compliance_notes: vec!["Drive time checked against FMCSA hours-of-service driving limits".to_string()],
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: "1.0".to_string(),
+                regulation_code_used: "FMCSA".to_string(),
+                requires_certification_review: false,
+            }),
+        })
+    }
+}
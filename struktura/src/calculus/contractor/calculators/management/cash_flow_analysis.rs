@@ -2,6 +2,7 @@ use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::CASH_FLOW_NEGATIVE,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -97,7 +98,7 @@ impl ContractorCalculator for CashFlowAnalysisCalculator {
         ];
 
         let warnings = if net_flow < 0.0 {
-            vec!["Negative cash flow".to_string()]
+            vec![CASH_FLOW_NEGATIVE.warn("Negative cash flow")]
         } else {
             vec![]
         };
@@ -111,8 +112,8 @@ impl ContractorCalculator for CashFlowAnalysisCalculator {
                 risk_level: if net_flow < 0.0 { -net_flow / inflows * 100.0 } else { 0.0 },
                 compliance_score: 1.0,
             }),
-            warnings,
-            structured_warnings: None,
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations: vec!["Monitor cash flow monthly".to_string()],
             compliance_notes: vec!["Compliant with PMP financial management".to_string()],
             calculation_metadata: Some(CalculationMetadata {
@@ -1,7 +1,9 @@
 use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
+    field_log,
     models::*,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::PROGRESS_SIGNIFICANT_DELAY,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -32,7 +34,7 @@ impl ContractorCalculator for ProgressTrackingCalculator {
     fn metadata(&self) -> ContractingCalculatorMetadata {
         ContractingCalculatorMetadata::builder("progress_tracking", "Progress Tracking")
             .category("management")
-            .description("Tracks project progress and variance")
+            .description("Tracks project progress and variance, with earned value when a budget is given")
             .regulation_code("PMP")
             .parameter(ParameterMetadata {
                 name: "planned_progress".to_string(),
@@ -60,10 +62,29 @@ impl ContractorCalculator for ProgressTrackingCalculator {
                 validation_rules: None,
                 default_value: None,
             })
+            .parameter(ParameterMetadata {
+                name: "budget_at_completion".to_string(),
+                path: "additional.budget_at_completion".to_string(),
+                data_type: ParameterType::Number,
+                unit: "USD".to_string(),
+                description: "Total budget, for earned value figures".to_string(),
+                required: false,
+                min_value: Some(0.0),
+                max_value: None,
+                typical_range: None,
+                validation_rules: None,
+                default_value: None,
+            })
             .complexity(ComplexityLevel::Basic)
             .build()
     }
 
+    /// Measure planned and actual progress from an attached field log
+    fn preprocess(&self, params: &mut ContractingParameters) -> ContractingResult<()> {
+        field_log::fill_parameters(params, self.id());
+        Ok(())
+    }
+
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         self.get_additional_param(params, "planned_progress", Some(0.0), Some(100.0))?;
         self.get_additional_param(params, "actual_progress", Some(0.0), Some(100.0))?;
@@ -96,8 +117,53 @@ impl ContractorCalculator for ProgressTrackingCalculator {
             },
         ];
 
+        // Earned value: the budget share of work done and of work planned
+        if let Some(budget) = params.additional.as_ref().and_then(|a| a.get("budget_at_completion")).copied() {
+            let earned = budget * actual / 100.0;
+            let planned_value = budget * planned / 100.0;
+            results.push(ContractingResultItem {
+                label: "Earned Value".to_string(),
+                value: earned,
+                unit: "USD".to_string(),
+                tolerance: Some(0.01),
+                formatted_value: Some(format!("${:.2}", earned)),
+                is_critical: false,
+            });
+            results.push(ContractingResultItem {
+                label: "Planned Value".to_string(),
+                value: planned_value,
+                unit: "USD".to_string(),
+                tolerance: Some(0.01),
+                formatted_value: Some(format!("${:.2}", planned_value)),
+                is_critical: false,
+            });
+            if planned_value > 0.0 {
+                let spi = earned / planned_value;
+                results.push(ContractingResultItem {
+                    label: "Schedule Performance Index".to_string(),
+                    value: spi,
+                    unit: "".to_string(),
+                    tolerance: Some(0.01),
+                    formatted_value: Some(format!("{:.2}", spi)),
+                    is_critical: spi < 0.9,
+                });
+            }
+        }
+
+        if let Some(log) = params.field_log.as_ref() {
+            let summary = log.summary();
+            results.push(ContractingResultItem {
+                label: "Labor Hours Logged".to_string(),
+                value: summary.labor_hours,
+                unit: "hours".to_string(),
+                tolerance: None,
+                formatted_value: Some(format!("{:.1} hours over {} reports", summary.labor_hours, summary.reports)),
+                is_critical: false,
+            });
+        }
+
         let warnings = if variance < -10.0 {
-            vec!["Significant delay detected".to_string()]
+            vec![PROGRESS_SIGNIFICANT_DELAY.warn("Significant delay detected")]
         } else {
             vec![]
         };
@@ -111,8 +177,8 @@ impl ContractorCalculator for ProgressTrackingCalculator {
                 risk_level: if variance < 0.0 { -variance } else { 0.0 },
                 compliance_score: actual / 100.0,
             }),
-            warnings,
-            structured_warnings: None,
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations: vec!["Adjust resources if behind schedule".to_string()],
             compliance_notes: vec!["Compliant with PMP progress tracking".to_string()],
             calculation_metadata: Some(CalculationMetadata {
@@ -1,7 +1,9 @@
 use crate::calculus::contractor::{
+    closeout,
     errors::{ContractingError, ContractingResult},
     models::*,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::CLOSEOUT_OVERDUE_ITEMS,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -32,7 +34,7 @@ impl ContractorCalculator for ProjectCloseoutCalculator {
     fn metadata(&self) -> ContractingCalculatorMetadata {
         ContractingCalculatorMetadata::builder("project_closeout", "Project Closeout")
             .category("management")
-            .description("Assesses project closeout readiness")
+            .description("Assesses project closeout readiness, from summary figures or a live punch list")
             .regulation_code("PMP")
             .parameter(ParameterMetadata {
                 name: "completion_percentage".to_string(),
@@ -64,6 +66,12 @@ impl ContractorCalculator for ProjectCloseoutCalculator {
             .build()
     }
 
+    /// Count completion and outstanding items from an attached punch list
+    fn preprocess(&self, params: &mut ContractingParameters) -> ContractingResult<()> {
+        closeout::fill_parameters(params);
+        Ok(())
+    }
+
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         self.get_additional_param(params, "completion_percentage", Some(0.0), Some(100.0))?;
         self.get_additional_param(params, "outstanding_issues", Some(0.0), None)?;
@@ -96,6 +104,46 @@ impl ContractorCalculator for ProjectCloseoutCalculator {
             },
         ];
 
+        // Per-party breakdown of an attached punch list
+        let mut warnings = Vec::new();
+        let mut recommendations = vec!["Resolve all issues before closeout".to_string()];
+        if let Some(list) = params.punch_list.as_ref() {
+            let report = list.report(chrono::Utc::now().date_naive());
+            results.push(ContractingResultItem {
+                label: "Punch Items Closed".to_string(),
+                value: report.closed as f64,
+                unit: "items".to_string(),
+                tolerance: None,
+                formatted_value: Some(format!("{} of {}", report.closed, report.total)),
+                is_critical: false,
+            });
+            results.push(ContractingResultItem {
+                label: "Overdue Items".to_string(),
+                value: report.overdue as f64,
+                unit: "items".to_string(),
+                tolerance: None,
+                formatted_value: Some(report.overdue.to_string()),
+                is_critical: report.overdue > 0,
+            });
+            if report.overdue > 0 {
+                warnings.push(CLOSEOUT_OVERDUE_ITEMS.warn(format!(
+                    "{} open punch items are past their due date",
+                    report.overdue
+                )));
+            }
+            for party in report.by_party.iter().filter(|p| p.closed < p.total) {
+                recommendations.push(format!(
+                    "{}: {} open items ({:.0}% complete)",
+                    party.responsible_party,
+                    party.total - party.closed,
+                    party.completion_percentage
+                ));
+            }
+            if report.undocumented > 0 {
+                recommendations.push(format!("Photograph {} open items before final walkthrough", report.undocumented));
+            }
+        }
+
         Ok(ContractingCalculationResponse {
             calculation_type: self.id().to_string(),
             results,
@@ -105,9 +153,9 @@ impl ContractorCalculator for ProjectCloseoutCalculator {
                 risk_level: issues as f64 * 10.0,
                 compliance_score: readiness_score / 100.0,
             }),
-            warnings: vec![],
-            structured_warnings: None,
-            recommendations: vec!["Resolve all issues before closeout".to_string()],
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
             compliance_notes: vec!["Compliant with PMP closeout procedures".to_string()],
             calculation_metadata: Some(CalculationMetadata {
                 timestamp: chrono::Utc::now().to_rfc3339(),
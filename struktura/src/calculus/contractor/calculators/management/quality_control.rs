@@ -1,7 +1,9 @@
 use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
+    inspections,
     models::*,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::{QUALITY_HIGH_DEFECT_RATE, QUALITY_PENDING_HOLD_POINTS},
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -64,6 +66,11 @@ impl ContractorCalculator for QualityControlCalculator {
             .build()
     }
 
+    fn preprocess(&self, params: &mut ContractingParameters) -> ContractingResult<()> {
+        inspections::fill_parameters(params);
+        Ok(())
+    }
+
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         let total = self.get_additional_param(params, "total_items", Some(1.0), None)?;
         let defective = self.get_additional_param(params, "defective_items", Some(0.0), Some(total))?;
@@ -103,11 +110,44 @@ impl ContractorCalculator for QualityControlCalculator {
             },
         ];
 
-        let warnings = if defect_rate > 5.0 {
-            vec!["High defect rate detected".to_string()]
+        let mut warnings = if defect_rate > 5.0 {
+            vec![QUALITY_HIGH_DEFECT_RATE.warn("High defect rate detected")]
         } else {
             vec![]
         };
+        let mut recommendations = vec!["Implement quality checks if rate > 2%".to_string()];
+
+        // First-time quality by trade from attached inspections
+        if let Some(attached) = params.inspections.as_ref() {
+            let trades = inspections::first_time_quality(attached);
+            for trade in &trades {
+                results.push(ContractingResultItem {
+                    label: format!("First-Time Quality: {}", trade.trade.as_str()),
+                    value: trade.first_time_quality,
+                    unit: "%".to_string(),
+                    tolerance: Some(0.01),
+                    formatted_value: Some(format!(
+                        "{:.1}% ({} of {})",
+                        trade.first_time_quality, trade.first_time_passes, trade.inspected
+                    )),
+                    is_critical: false,
+                });
+                if trade.open_failures > 0 {
+                    recommendations.push(format!(
+                        "{}: reinspect {} failed items",
+                        trade.trade.as_str(),
+                        trade.open_failures
+                    ));
+                }
+            }
+            let pending: usize = trades.iter().map(|t| t.pending_hold_points).sum();
+            if pending > 0 {
+                warnings.push(QUALITY_PENDING_HOLD_POINTS.warn(format!(
+                    "{} hold points have not been inspected",
+                    pending
+                )));
+            }
+        }
 
         Ok(ContractingCalculationResponse {
             calculation_type: self.id().to_string(),
@@ -118,9 +158,9 @@ impl ContractorCalculator for QualityControlCalculator {
                 risk_level: defect_rate,
                 compliance_score: quality_score / 100.0,
             }),
-            warnings,
-            structured_warnings: None,
-            recommendations: vec!["Implement quality checks if rate > 2%".to_string()],
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
             compliance_notes: vec!["Compliant with ISO quality standards".to_string()],
             calculation_metadata: Some(CalculationMetadata {
                 timestamp: chrono::Utc::now().to_rfc3339(),
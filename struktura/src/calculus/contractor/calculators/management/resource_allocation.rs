@@ -2,6 +2,7 @@ use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::ALLOCATION_HIGH_UTILIZATION,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -138,7 +139,7 @@ impl ContractorCalculator for ResourceAllocationCalculator {
         ];
 
         let warnings = if labor_util > 90.0 || equip_util > 90.0 {
-            vec!["High utilization - risk of overallocation".to_string()]
+            vec![ALLOCATION_HIGH_UTILIZATION.warn("High utilization - risk of overallocation")]
         } else {
             vec![]
         };
@@ -152,8 +153,8 @@ impl ContractorCalculator for ResourceAllocationCalculator {
                 risk_level: (labor_util + equip_util) / 2.0,
                 compliance_score: 1.0,
             }),
-            warnings,
-            structured_warnings: None,
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations: vec!["Monitor allocation weekly".to_string()],
             compliance_notes: vec!["Compliant with PMP resource management".to_string()],
             calculation_metadata: Some(CalculationMetadata {
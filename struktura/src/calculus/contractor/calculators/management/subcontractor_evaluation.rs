@@ -1,7 +1,9 @@
 use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
+    prequal::Prequalification,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::PREQUAL_THRESHOLD_FAILURE,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -78,6 +80,9 @@ impl ContractorCalculator for SubcontractorEvaluationCalculator {
     }
 
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
+        if let Some(prequal) = params.prequalification.as_ref() {
+            return validate_prequalification(prequal);
+        }
         self.get_additional_param(params, "performance_score", Some(1.0), Some(10.0))?;
         self.get_additional_param(params, "reliability_score", Some(1.0), Some(10.0))?;
         self.get_additional_param(params, "cost_score", Some(1.0), Some(10.0))?;
@@ -85,6 +90,9 @@ impl ContractorCalculator for SubcontractorEvaluationCalculator {
     }
 
     async fn calculate(&self, params: ContractingParameters) -> ContractingResult<ContractingCalculationResponse> {
+        if let Some(prequal) = params.prequalification.as_ref() {
+            return Ok(self.rank(prequal));
+        }
         let perf = self.get_additional_param(&params, "performance_score", None, None)?;
         let reli = self.get_additional_param(&params, "reliability_score", None, None)?;
         let cost = self.get_additional_param(&params, "cost_score", None, None)?;
@@ -129,4 +137,114 @@ impl ContractorCalculator for SubcontractorEvaluationCalculator {
             }),
         })
     }
-}
\ No newline at end of file
+}
+
+fn validate_prequalification(prequal: &Prequalification) -> ContractingResult<()> {
+    let invalid = |parameter: &str, value: String, reason: &str| {
+        Err(ContractingError::InvalidParameter {
+            parameter: parameter.to_string(),
+            value,
+            reason: reason.to_string(),
+        })
+    };
+    if !prequal.project_value.is_finite() || prequal.project_value <= 0.0 {
+        return invalid("prequalification.project_value", prequal.project_value.to_string(), "Must be positive");
+    }
+    if prequal.candidates.is_empty() {
+        return invalid("prequalification.candidates", "[]".to_string(), "At least one candidate is required");
+    }
+    if let Some(problem) = prequal.model.problem() {
+        return invalid("prequalification.model", format!("{:?}", prequal.model.weights), problem);
+    }
+    for candidate in &prequal.candidates {
+        let figures = [candidate.current_ratio, candidate.debt_to_equity, candidate.emr, candidate.bonding_capacity, candidate.backlog];
+        if figures.iter().any(|v| !v.is_finite() || *v < 0.0) {
+            return invalid("prequalification.candidates", candidate.name.clone(), "Financial, safety and capacity figures must be non-negative");
+        }
+        if !(1.0..=10.0).contains(&candidate.past_performance) {
+            return invalid("prequalification.candidates", candidate.name.clone(), "Past performance must be between 1 and 10");
+        }
+    }
+    Ok(())
+}
+
+impl SubcontractorEvaluationCalculator {
+    /// Weighted prequalification: one score per candidate, best qualified first
+    fn rank(&self, prequal: &Prequalification) -> ContractingCalculationResponse {
+        let report = prequal.report();
+        let top = report.ranked.first().map_or(0.0, |c| c.weighted_score);
+
+        let mut results = vec![
+            ContractingResultItem {
+                label: "Overall Score".to_string(),
+                value: top,
+                unit: "/10".to_string(),
+                tolerance: Some(0.5),
+                formatted_value: Some(format!("{:.1}/10", top)),
+                is_critical: true,
+            },
+            ContractingResultItem {
+                label: "Qualified Candidates".to_string(),
+                value: report.qualified as f64,
+                unit: "".to_string(),
+                tolerance: None,
+                formatted_value: Some(format!("{} of {}", report.qualified, report.ranked.len())),
+                is_critical: report.qualified == 0,
+            },
+        ];
+        for candidate in &report.ranked {
+            results.push(ContractingResultItem {
+                label: format!("#{} {}", candidate.rank, candidate.name),
+                value: candidate.weighted_score,
+                unit: "/10".to_string(),
+                tolerance: Some(0.5),
+                formatted_value: Some(format!(
+                    "{:.1}/10{}",
+                    candidate.weighted_score,
+                    if candidate.qualified { "" } else { " (not qualified)" }
+                )),
+                is_critical: false,
+            });
+        }
+
+        let warnings: Vec<_> = report
+            .ranked
+            .iter()
+            .filter(|c| !c.qualified)
+            .map(|c| {
+                let failed: Vec<String> = c
+                    .failures
+                    .iter()
+                    .map(|f| format!("{} {:.2} (limit {:.2})", f.criterion, f.value, f.limit))
+                    .collect();
+                PREQUAL_THRESHOLD_FAILURE.warn(format!("{}: {}", c.name, failed.join(", ")))
+            })
+            .collect();
+
+        let recommendations = match report.ranked.iter().find(|c| c.qualified) {
+            Some(best) => vec![format!("Award to {} ({:.1}/10)", best.name, best.weighted_score)],
+            None => vec!["No candidate meets the minimums; widen the bid list or review the thresholds".to_string()],
+        };
+
+        ContractingCalculationResponse {
+            calculation_type: self.id().to_string(),
+            results,
+            analysis: Some(ProjectAnalysisResult {
+                total_cost: 0.0,
+                total_duration: 0.0,
+                risk_level: (10.0 - top) * 10.0,
+                compliance_score: report.qualified as f64 / report.ranked.len().max(1) as f64,
+            }),
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes: vec!["Compliant with PMP procurement management".to_string()],
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: "1.0".to_string(),
+                regulation_code_used: "PMP".to_string(),
+                requires_certification_review: false,
+            }),
+        }
+    }
+}
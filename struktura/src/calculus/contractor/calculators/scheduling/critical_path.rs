@@ -1,6 +1,7 @@
 use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
+    schedule,
     traits::{ContractorCalculator, ParameterValidator},
 };
 use async_trait::async_trait;
@@ -78,6 +79,11 @@ impl ContractorCalculator for CriticalPathCalculator {
             .build()
     }
 
+    /// Measure unset inputs from an attached schedule
+    fn preprocess(&self, params: &mut ContractingParameters) -> ContractingResult<()> {
+        schedule::fill_parameters(params, self.id())
+    }
+
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         self.get_additional_param(params, "total_tasks", Some(1.0), None)?;
         self.get_additional_param(params, "avg_duration", Some(1.0), Some(365.0))?;
@@ -1,7 +1,10 @@
 use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
+    field_log,
+    schedule::windows,
     models::*,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::DELAY_UNATTRIBUTED,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -32,7 +35,7 @@ impl ContractorCalculator for DelayAnalysisCalculator {
     fn metadata(&self) -> ContractingCalculatorMetadata {
         ContractingCalculatorMetadata::builder("delay_analysis", "Delay Analysis")
             .category("scheduling")
-            .description("Analyzes schedule delays")
+            .description("Analyzes schedule delays, from summary figures, daily field reports or as-planned vs as-built windows")
             .regulation_code("PMP")
             .parameter(ParameterMetadata {
                 name: "planned_duration".to_string(),
@@ -73,11 +76,32 @@ impl ContractorCalculator for DelayAnalysisCalculator {
                 validation_rules: None,
                 default_value: Some(0.2),
             })
+            .parameter(ParameterMetadata {
+                name: "window_days".to_string(),
+                path: "additional.window_days".to_string(),
+                data_type: ParameterType::Number,
+                unit: "days".to_string(),
+                description: "Window length for a schedule windows analysis".to_string(),
+                required: false,
+                min_value: Some(1.0),
+                max_value: Some(365.0),
+                typical_range: Some((7.0, 31.0)),
+                validation_rules: None,
+                default_value: Some(windows::DEFAULT_WINDOW_DAYS as f64),
+            })
             .requires_certification()
             .complexity(ComplexityLevel::Intermediate)
             .build()
     }
 
+    /// Measure durations and the compensable share from an attached
+    /// schedule's windows, else from an attached field log
+    fn preprocess(&self, params: &mut ContractingParameters) -> ContractingResult<()> {
+        windows::fill_parameters(params)?;
+        field_log::fill_parameters(params, self.id());
+        Ok(())
+    }
+
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         let planned = self.get_additional_param(params, "planned_duration", Some(1.0), None)?;
         let actual = self.get_additional_param(params, "actual_duration", Some(1.0), None)?;
@@ -126,6 +150,60 @@ impl ContractorCalculator for DelayAnalysisCalculator {
             },
         ];
 
+        // As-planned vs as-built windows of an attached schedule
+        let mut warnings = Vec::new();
+        let mut recommendations = vec!["Analyze causes for compensable delays".to_string()];
+        if let Some(analysis) = windows::analyze_parameters(&params)? {
+            let summary = &analysis.summary;
+            for (label, days, critical) in [
+                ("Extension of Time", summary.extension_of_time_days, true),
+                ("Concurrent Delay", summary.attributed.concurrent, false),
+                ("Non-Excusable Delay", summary.attributed.non_excusable, false),
+                ("Unattributed Delay", summary.attributed.unattributed, false),
+            ] {
+                results.push(ContractingResultItem {
+                    label: label.to_string(),
+                    value: days,
+                    unit: "days".to_string(),
+                    tolerance: Some(0.05),
+                    formatted_value: Some(format!("{:.1} days", days)),
+                    is_critical: critical,
+                });
+            }
+            if summary.attributed.unattributed > 0.0 {
+                warnings.push(DELAY_UNATTRIBUTED.warn(format!(
+                    "{:.1} days of critical delay have no cause logged; add field reports for those windows",
+                    summary.attributed.unattributed
+                )));
+            }
+            recommendations.extend(summary.narrative.iter().map(|line| format!("Window {}", line)));
+        }
+
+        // Days lost per cause, with the report dates that back a claim
+        if let Some(log) = params.field_log.as_ref() {
+            for delay in log.summary().delays {
+                let cause = delay.cause.as_str().replace('_', " ");
+                results.push(ContractingResultItem {
+                    label: format!("Days Lost: {}", cause),
+                    value: delay.days,
+                    unit: "days".to_string(),
+                    tolerance: Some(0.05),
+                    formatted_value: Some(format!("{:.1} days", delay.days)),
+                    is_critical: delay.compensable,
+                });
+                if delay.excusable {
+                    let dates: Vec<String> = delay.dates.iter().map(|d| d.to_string()).collect();
+                    recommendations.push(format!(
+                        "Claim {:.1} {} days{}: logged on {}",
+                        delay.days,
+                        cause,
+                        if delay.compensable { " with costs" } else { " (time only)" },
+                        dates.join(", ")
+                    ));
+                }
+            }
+        }
+
         Ok(ContractingCalculationResponse {
             calculation_type: self.id().to_string(),
             results,
@@ -135,9 +213,9 @@ impl ContractorCalculator for DelayAnalysisCalculator {
                 risk_level: (delay / planned) * 100.0,
                 compliance_score: planned / actual,
             }),
-            warnings: vec![],
-            structured_warnings: None,
-            recommendations: vec!["Analyze causes for compensable delays".to_string()],
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
             compliance_notes: vec!["Compliant with PMP delay analysis".to_string()],
             calculation_metadata: Some(CalculationMetadata {
                 timestamp: chrono::Utc::now().to_rfc3339(),
@@ -1,7 +1,9 @@
 use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
+    schedule::{self, gantt::GanttChart},
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::GANTT_BASELINE_SLIP,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -15,6 +17,24 @@ impl ParameterValidator for GanttChartGenerator {
     }
 }
 
+impl GanttChartGenerator {
+    /// Bars from the attached schedule, or a single project bar with evenly
+    /// spaced milestones when only aggregate inputs were given
+    pub fn chart(&self, params: &ContractingParameters) -> ContractingResult<GanttChart> {
+        if let Some(schedule) = params.schedule.as_ref() {
+            return GanttChart::from_schedule(schedule).map_err(|message| ContractingError::DomainError {
+                field: "schedule".to_string(),
+                message,
+            });
+        }
+        let start = self.get_additional_param(params, "start_date", None, None)?;
+        let duration = self.get_additional_param(params, "duration", Some(1.0), None)?;
+        let milestones = self.get_additional_param(params, "milestones", Some(0.0), None).unwrap_or(5.0);
+        let start_date = chrono::DateTime::from_timestamp(start as i64, 0).map(|d| d.date_naive());
+        Ok(GanttChart::aggregate(start_date, duration, milestones as usize))
+    }
+}
+
 #[async_trait]
 impl ContractorCalculator for GanttChartGenerator {
     fn id(&self) -> &str {
@@ -32,7 +52,7 @@ impl ContractorCalculator for GanttChartGenerator {
     fn metadata(&self) -> ContractingCalculatorMetadata {
         ContractingCalculatorMetadata::builder("gantt_chart", "Gantt Chart Generator")
             .category("scheduling")
-            .description("Generates parameters for Gantt chart")
+            .description("Generates Gantt chart parameters and bars, with baseline comparison for attached schedules")
             .regulation_code("PMP")
             .parameter(ParameterMetadata {
                 name: "start_date".to_string(),
@@ -78,6 +98,11 @@ impl ContractorCalculator for GanttChartGenerator {
             .build()
     }
 
+    /// Measure unset inputs from an attached schedule
+    fn preprocess(&self, params: &mut ContractingParameters) -> ContractingResult<()> {
+        schedule::fill_parameters(params, self.id())
+    }
+
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         self.get_additional_param(params, "start_date", None, None)?;
         self.get_additional_param(params, "duration", Some(1.0), None)?;
@@ -111,6 +136,34 @@ impl ContractorCalculator for GanttChartGenerator {
             },
         ];
 
+        // With a schedule attached, report its critical path and baseline slip
+        let mut warnings = Vec::new();
+        if params.schedule.is_some() {
+            let chart = self.chart(&params)?;
+            let critical = chart.bars.iter().filter(|b| b.critical).count() as f64;
+            results.push(ContractingResultItem {
+                label: "Critical Activities".to_string(),
+                value: critical,
+                unit: "activities".to_string(),
+                tolerance: None,
+                formatted_value: Some(format!("{:.0}", critical)),
+                is_critical: false,
+            });
+            if let Some(slip) = chart.max_slip() {
+                results.push(ContractingResultItem {
+                    label: "Baseline Slip".to_string(),
+                    value: slip,
+                    unit: "days".to_string(),
+                    tolerance: None,
+                    formatted_value: Some(format!("{:.1} days", slip)),
+                    is_critical: slip > 0.0,
+                });
+                if slip > 0.0 {
+                    warnings.push(GANTT_BASELINE_SLIP.warn(format!("Latest activity finishes {:.1} days behind baseline", slip)));
+                }
+            }
+        }
+
         Ok(ContractingCalculationResponse {
             calculation_type: self.id().to_string(),
             results,
@@ -120,8 +173,8 @@ impl ContractorCalculator for GanttChartGenerator {
                 risk_level: 0.0,
                 compliance_score: 1.0,
             }),
-            warnings: vec![],
-            structured_warnings: None,
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations: vec!["Use for visual scheduling".to_string()],
             compliance_notes: vec!["Compliant with PMP visualization".to_string()],
             calculation_metadata: Some(CalculationMetadata {
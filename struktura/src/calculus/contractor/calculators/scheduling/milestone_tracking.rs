@@ -1,7 +1,12 @@
 use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
+    schedule::{
+        self,
+        alerts::{self, AlertKind, DEFAULT_LEAD_DAYS},
+    },
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::{MILESTONE_APPROACHING, MILESTONE_SLIPPED},
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -65,6 +70,11 @@ impl ContractorCalculator for MilestoneTrackingCalculator {
             .build()
     }
 
+    /// Count milestones, and those already passed, from an attached schedule
+    fn preprocess(&self, params: &mut ContractingParameters) -> ContractingResult<()> {
+        schedule::fill_parameters(params, self.id())
+    }
+
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         let total = self.get_additional_param(params, "total_milestones", Some(1.0), None)?;
         let completed = self.get_additional_param(params, "completed_milestones", Some(0.0), Some(total))?;
@@ -97,6 +107,20 @@ impl ContractorCalculator for MilestoneTrackingCalculator {
             },
         ];
 
+        // Milestones of an attached schedule that are due soon or have slipped
+        let mut warnings = Vec::new();
+        if let Some(schedule) = params.schedule.as_ref() {
+            let due = alerts::milestone_alerts(schedule, chrono::Utc::now().date_naive(), DEFAULT_LEAD_DAYS)
+                .map_err(|message| ContractingError::DomainError { field: "schedule".to_string(), message })?;
+            for alert in &due {
+                let code = match alert.kind {
+                    AlertKind::Approaching { .. } => &MILESTONE_APPROACHING,
+                    AlertKind::Slipped { .. } => &MILESTONE_SLIPPED,
+                };
+                warnings.push(code.warn(alert.message()));
+            }
+        }
+
         Ok(ContractingCalculationResponse {
             calculation_type: self.id().to_string(),
             results,
@@ -106,8 +130,8 @@ impl ContractorCalculator for MilestoneTrackingCalculator {
                 risk_level: (remaining / total) * 100.0,
                 compliance_score: progress / 100.0,
             }),
-            warnings: vec![],
-            structured_warnings: None,
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations: vec!["Track milestones regularly".to_string()],
             compliance_notes: vec!["Compliant with PMP milestone management".to_string()],
             calculation_metadata: Some(CalculationMetadata {
@@ -1,7 +1,9 @@
 use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
+    schedule,
     traits::{ContractorCalculator, ParameterValidator},
+    warnings::LEVELING_SCHEDULE_EXTENSION,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -78,6 +80,11 @@ impl ContractorCalculator for ResourceLevelingCalculator {
             .build()
     }
 
+    /// Measure unset inputs from an attached schedule
+    fn preprocess(&self, params: &mut ContractingParameters) -> ContractingResult<()> {
+        schedule::fill_parameters(params, self.id())
+    }
+
     fn validate(&self, params: &ContractingParameters) -> ContractingResult<()> {
         let peak = self.get_additional_param(params, "peak_demand", Some(1.0), None)?;
         let avail = self.get_additional_param(params, "available_resources", Some(1.0), None)?;
@@ -119,7 +126,7 @@ impl ContractorCalculator for ResourceLevelingCalculator {
         ];
 
         let warnings = if leveling_factor > 1.5 {
-            vec!["Significant schedule extension due to leveling".to_string()]
+            vec![LEVELING_SCHEDULE_EXTENSION.warn("Significant schedule extension due to leveling")]
         } else {
             vec![]
         };
@@ -133,8 +140,8 @@ impl ContractorCalculator for ResourceLevelingCalculator {
                 risk_level: (leveling_factor - 1.0) * 100.0,
                 compliance_score: 1.0 / leveling_factor,
             }),
-            warnings,
-            structured_warnings: None,
+            warnings: ContractingWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations: vec!["Add resources if possible to reduce duration".to_string()],
             compliance_notes: vec!["Compliant with PMP resource management".to_string()],
             calculation_metadata: Some(CalculationMetadata {
@@ -0,0 +1,249 @@
+// ============================================================================
+// Punch Lists
+//
+// `project_closeout` scores readiness from a completion percentage and a
+// count of outstanding issues. On site those numbers come from the punch
+// list, so a list can be attached to the parameters instead: its items are
+// counted and the figures filled in. Values sent explicitly in `additional`
+// always win over counted ones.
+//
+// - saved.rs: per-organization punch lists with live item status
+// ============================================================================
+
+pub mod saved;
+
+use crate::calculus::contractor::{models::ContractingParameters, records::PhotoMetadata};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Where a punch item stands
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PunchStatus {
+    #[default]
+    Open,
+    InProgress,
+    /// Work done, waiting for the owner or inspector to accept it
+    ReadyForInspection,
+    Closed,
+}
+
+impl PunchStatus {
+    pub const ALL: [PunchStatus; 4] = [
+        PunchStatus::Open,
+        PunchStatus::InProgress,
+        PunchStatus::ReadyForInspection,
+        PunchStatus::Closed,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PunchStatus::Open => "open",
+            PunchStatus::InProgress => "in_progress",
+            PunchStatus::ReadyForInspection => "ready_for_inspection",
+            PunchStatus::Closed => "closed",
+        }
+    }
+
+    /// Unknown values read as open, so nothing is counted done by mistake
+    pub fn parse(value: &str) -> Self {
+        Self::ALL.into_iter().find(|s| s.as_str() == value).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PunchItem {
+    pub id: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Trade or company that must resolve the item
+    pub responsible_party: String,
+    #[serde(default)]
+    pub status: PunchStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub photos: Vec<PhotoMetadata>,
+}
+
+impl PunchItem {
+    pub fn is_closed(&self) -> bool {
+        self.status == PunchStatus::Closed
+    }
+
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        !self.is_closed() && self.due_date.is_some_and(|due| due < today)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PunchList {
+    pub items: Vec<PunchItem>,
+}
+
+/// Progress of one responsible party
+#[derive(Debug, Clone, Serialize)]
+pub struct PartyProgress {
+    pub responsible_party: String,
+    pub total: usize,
+    pub closed: usize,
+    pub overdue: usize,
+    pub completion_percentage: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletionReport {
+    pub total: usize,
+    pub closed: usize,
+    pub outstanding: usize,
+    pub overdue: usize,
+    /// Open items with no photo on record
+    pub undocumented: usize,
+    pub completion_percentage: f64,
+    pub by_status: HashMap<PunchStatus, usize>,
+    /// Parties with the most outstanding items first
+    pub by_party: Vec<PartyProgress>,
+}
+
+/// Share of `closed` in `total`; an empty list is complete
+fn percentage(closed: usize, total: usize) -> f64 {
+    if total == 0 { 100.0 } else { closed as f64 / total as f64 * 100.0 }
+}
+
+impl PunchList {
+    pub fn report(&self, today: NaiveDate) -> CompletionReport {
+        let closed = self.items.iter().filter(|i| i.is_closed()).count();
+        let overdue = self.items.iter().filter(|i| i.is_overdue(today)).count();
+        let undocumented = self.items.iter().filter(|i| !i.is_closed() && i.photos.is_empty()).count();
+
+        let mut by_status = HashMap::new();
+        for item in &self.items {
+            *by_status.entry(item.status).or_insert(0) += 1;
+        }
+
+        let mut parties: Vec<PartyProgress> = Vec::new();
+        for item in &self.items {
+            let index = match parties.iter().position(|p| p.responsible_party == item.responsible_party) {
+                Some(index) => index,
+                None => {
+                    parties.push(PartyProgress {
+                        responsible_party: item.responsible_party.clone(),
+                        total: 0,
+                        closed: 0,
+                        overdue: 0,
+                        completion_percentage: 0.0,
+                    });
+                    parties.len() - 1
+                }
+            };
+            let party = &mut parties[index];
+            party.total += 1;
+            party.closed += usize::from(item.is_closed());
+            party.overdue += usize::from(item.is_overdue(today));
+        }
+        for party in &mut parties {
+            party.completion_percentage = percentage(party.closed, party.total);
+        }
+        parties.sort_by(|a, b| {
+            (b.total - b.closed)
+                .cmp(&(a.total - a.closed))
+                .then_with(|| a.responsible_party.cmp(&b.responsible_party))
+        });
+
+        CompletionReport {
+            total: self.items.len(),
+            closed,
+            outstanding: self.items.len() - closed,
+            overdue,
+            undocumented,
+            completion_percentage: percentage(closed, self.items.len()),
+            by_status,
+            by_party: parties,
+        }
+    }
+}
+
+/// Fill `project_closeout` inputs from an attached punch list
+pub fn fill_parameters(params: &mut ContractingParameters) {
+    let Some(list) = params.punch_list.as_ref() else {
+        return;
+    };
+    let report = list.report(chrono::Utc::now().date_naive());
+
+    let additional = params.additional.get_or_insert_with(HashMap::new);
+    additional
+        .entry("completion_percentage".to_string())
+        .or_insert(report.completion_percentage);
+    additional
+        .entry("outstanding_issues".to_string())
+        .or_insert(report.outstanding as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, party: &str, status: PunchStatus, due: Option<NaiveDate>) -> PunchItem {
+        PunchItem {
+            id: id.to_string(),
+            description: format!("Item {}", id),
+            location: None,
+            responsible_party: party.to_string(),
+            status,
+            due_date: due,
+            photos: vec![],
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 6, day).unwrap()
+    }
+
+    #[test]
+    fn test_report_by_party() {
+        let list = PunchList {
+            items: vec![
+                item("1", "Electrical", PunchStatus::Closed, None),
+                item("2", "Electrical", PunchStatus::Open, Some(date(1))),
+                item("3", "Drywall", PunchStatus::ReadyForInspection, Some(date(20))),
+                item("4", "Drywall", PunchStatus::InProgress, None),
+            ],
+        };
+        let report = list.report(date(10));
+        assert_eq!(report.closed, 1);
+        assert_eq!(report.outstanding, 3);
+        assert_eq!(report.overdue, 1);
+        assert_eq!(report.undocumented, 3);
+        assert!((report.completion_percentage - 25.0).abs() < 1e-9);
+        assert_eq!(report.by_status[&PunchStatus::Open], 1);
+        assert_eq!(report.by_party[0].responsible_party, "Drywall");
+        assert!((report.by_party[1].completion_percentage - 50.0).abs() < 1e-9);
+
+        assert!((PunchList::default().report(date(10)).completion_percentage - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fill_keeps_explicit_values() {
+        let mut params = ContractingParameters {
+            punch_list: Some(PunchList {
+                items: vec![item("1", "Roofing", PunchStatus::Open, None), item("2", "Roofing", PunchStatus::Closed, None)],
+            }),
+            additional: Some(HashMap::from([("outstanding_issues".to_string(), 0.0)])),
+            ..Default::default()
+        };
+        fill_parameters(&mut params);
+        let additional = params.additional.unwrap();
+        assert!((additional["completion_percentage"] - 50.0).abs() < 1e-9);
+        assert_eq!(additional["outstanding_issues"], 0.0);
+    }
+
+    #[test]
+    fn test_status_round_trip() {
+        for status in PunchStatus::ALL {
+            assert_eq!(PunchStatus::parse(status.as_str()), status);
+        }
+        assert_eq!(PunchStatus::parse("bogus"), PunchStatus::Open);
+    }
+}
@@ -0,0 +1,409 @@
+//! Saved punch lists
+//!
+//! Each organization keeps punch lists whose items are updated as work is
+//! done on site. Item status, responsible party and photo metadata live in
+//! their own rows so crews can close items one at a time; the closeout
+//! calculator reads the list as it stands.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use sqlx::types::time::{Date, OffsetDateTime};
+use sqlx::types::Json as SqlJson;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+use super::{CompletionReport, PunchItem, PunchList, PunchStatus};
+use crate::calculus::contractor::records::{to_date, to_naive, validate_photos, PhotoMetadata};
+use crate::sec::{self, AppError};
+use crate::state::AppState;
+use crate::tenancy::{TenantOwned, TenantScope};
+
+// ============================================================================
+// Models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct SavedPunchList {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub name: String,
+    pub project_name: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl TenantOwned for SavedPunchList {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct PunchItemRow {
+    id: Uuid,
+    description: String,
+    location: Option<String>,
+    responsible_party: String,
+    status: String,
+    due_date: Option<Date>,
+    photos: SqlJson<Vec<PhotoMetadata>>,
+    closed_at: Option<OffsetDateTime>,
+    updated_at: OffsetDateTime,
+}
+
+/// A stored item with its audit timestamps
+#[derive(Debug, Clone, Serialize)]
+pub struct PunchItemRecord {
+    #[serde(flatten)]
+    pub item: PunchItem,
+    pub closed_at: Option<OffsetDateTime>,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<PunchItemRow> for PunchItemRecord {
+    fn from(row: PunchItemRow) -> Self {
+        Self {
+            item: PunchItem {
+                id: row.id.to_string(),
+                description: row.description,
+                location: row.location,
+                responsible_party: row.responsible_party,
+                status: PunchStatus::parse(&row.status),
+                due_date: row.due_date.and_then(to_naive),
+                photos: row.photos.0,
+            },
+            closed_at: row.closed_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// A list with its items and completion figures
+#[derive(Debug, Serialize)]
+pub struct PunchListDetail {
+    #[serde(flatten)]
+    pub list: SavedPunchList,
+    pub items: Vec<PunchItemRecord>,
+    pub report: CompletionReport,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct PunchListPayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(max = 200))]
+    pub project_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct PunchItemPayload {
+    #[validate(length(min = 1, max = 500))]
+    pub description: String,
+    #[validate(length(max = 200))]
+    pub location: Option<String>,
+    #[validate(length(min = 1, max = 100))]
+    pub responsible_party: String,
+    #[serde(default)]
+    pub status: PunchStatus,
+    pub due_date: Option<NaiveDate>,
+    #[serde(default)]
+    #[validate(custom(function = "validate_photos"))]
+    pub photos: Vec<PhotoMetadata>,
+}
+
+const LIST_COLUMNS: &str = "id, org_id, name, project_name, created_at, updated_at";
+const ITEM_COLUMNS: &str = "id, description, location, responsible_party, status, due_date, photos, \
+    closed_at, updated_at";
+
+// ============================================================================
+// Queries
+// ============================================================================
+
+async fn fetch_list(pool: &PgPool, scope: &TenantScope, id: Uuid) -> Result<SavedPunchList, AppError> {
+    let list = sqlx::query_as::<_, SavedPunchList>(&format!(
+        "SELECT {LIST_COLUMNS} FROM punch_lists WHERE id = $1 AND org_id = $2"
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    scope.guard(list)
+}
+
+async fn fetch_items(pool: &PgPool, list_id: Uuid) -> Result<Vec<PunchItemRecord>, AppError> {
+    let rows = sqlx::query_as::<_, PunchItemRow>(&format!(
+        "SELECT {ITEM_COLUMNS} FROM punch_items WHERE punch_list_id = $1 ORDER BY created_at, id"
+    ))
+    .bind(list_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(PunchItemRecord::from).collect())
+}
+
+/// The organization's punch list as it stands, for the closeout calculator
+pub async fn load_punch_list(pool: &PgPool, scope: &TenantScope, id: Uuid) -> Result<PunchList, AppError> {
+    fetch_list(pool, scope, id).await?;
+    let items = fetch_items(pool, id).await?;
+    Ok(PunchList { items: items.into_iter().map(|record| record.item).collect() })
+}
+
+/// Bump the list so clients polling `updated_at` see item changes
+async fn touch_list(pool: &PgPool, id: Uuid) -> Result<(), AppError> {
+    sqlx::query("UPDATE punch_lists SET updated_at = CURRENT_TIMESTAMP WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+// ============================================================================
+// List handlers
+// ============================================================================
+
+pub async fn list_punch_lists_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+) -> Result<Json<Vec<SavedPunchList>>, AppError> {
+    let lists = sqlx::query_as::<_, SavedPunchList>(&format!(
+        "SELECT {LIST_COLUMNS} FROM punch_lists WHERE org_id = $1 ORDER BY name"
+    ))
+    .bind(scope.org_id)
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    Ok(Json(scope.guard_all(lists)?))
+}
+
+pub async fn get_punch_list_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PunchListDetail>, AppError> {
+    let list = fetch_list(&app_state.pool, &scope, id).await?;
+    let items = fetch_items(&app_state.pool, id).await?;
+    let report = PunchList { items: items.iter().map(|record| record.item.clone()).collect() }
+        .report(chrono::Utc::now().date_naive());
+
+    Ok(Json(PunchListDetail { list, items, report }))
+}
+
+pub async fn create_punch_list_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<PunchListPayload>,
+) -> Result<(StatusCode, Json<SavedPunchList>), AppError> {
+    payload.validate()?;
+
+    let list = sqlx::query_as::<_, SavedPunchList>(&format!(
+        r#"
+        INSERT INTO punch_lists (org_id, name, project_name, created_by)
+        VALUES ($1, $2, $3, $4)
+        RETURNING {LIST_COLUMNS}
+        "#
+    ))
+    .bind(scope.org_id)
+    .bind(&payload.name)
+    .bind(&payload.project_name)
+    .bind(scope.user_id)
+    .fetch_one(&app_state.pool)
+    .await?;
+
+    sec::log_security_event("PUNCH_LIST_CREATE", Some(&scope.username), None, "Success");
+
+    Ok((StatusCode::CREATED, Json(list)))
+}
+
+pub async fn update_punch_list_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<PunchListPayload>,
+) -> Result<Json<SavedPunchList>, AppError> {
+    payload.validate()?;
+
+    let list = sqlx::query_as::<_, SavedPunchList>(&format!(
+        r#"
+        UPDATE punch_lists
+        SET name = $3, project_name = $4, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1 AND org_id = $2
+        RETURNING {LIST_COLUMNS}
+        "#
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .bind(&payload.name)
+    .bind(&payload.project_name)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("PUNCH_LIST_UPDATE", Some(&scope.username), None, "Success");
+
+    Ok(Json(list))
+}
+
+pub async fn delete_punch_list_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let result = sqlx::query("DELETE FROM punch_lists WHERE id = $1 AND org_id = $2")
+        .bind(id)
+        .bind(scope.org_id)
+        .execute(&app_state.pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("PUNCH_LIST_DELETE", Some(&scope.username), None, "Success");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Item handlers
+// ============================================================================
+
+pub async fn create_punch_item_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(list_id): Path<Uuid>,
+    Json(payload): Json<PunchItemPayload>,
+) -> Result<(StatusCode, Json<PunchItemRecord>), AppError> {
+    payload.validate()?;
+    fetch_list(&app_state.pool, &scope, list_id).await?;
+
+    let row = sqlx::query_as::<_, PunchItemRow>(&format!(
+        r#"
+        INSERT INTO punch_items
+            (punch_list_id, description, location, responsible_party, status, due_date, photos,
+             closed_at, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7,
+                CASE WHEN $5 = 'closed' THEN CURRENT_TIMESTAMP END, $8)
+        RETURNING {ITEM_COLUMNS}
+        "#
+    ))
+    .bind(list_id)
+    .bind(&payload.description)
+    .bind(&payload.location)
+    .bind(&payload.responsible_party)
+    .bind(payload.status.as_str())
+    .bind(payload.due_date.and_then(to_date))
+    .bind(SqlJson(&payload.photos))
+    .bind(scope.user_id)
+    .fetch_one(&app_state.pool)
+    .await?;
+    touch_list(&app_state.pool, list_id).await?;
+
+    sec::log_security_event("PUNCH_ITEM_CREATE", Some(&scope.username), None, "Success");
+
+    Ok((StatusCode::CREATED, Json(row.into())))
+}
+
+/// Replace an item; closing it stamps `closed_at`, reopening clears it
+pub async fn update_punch_item_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path((list_id, item_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<PunchItemPayload>,
+) -> Result<Json<PunchItemRecord>, AppError> {
+    payload.validate()?;
+    fetch_list(&app_state.pool, &scope, list_id).await?;
+
+    let row = sqlx::query_as::<_, PunchItemRow>(&format!(
+        r#"
+        UPDATE punch_items
+        SET description = $3, location = $4, responsible_party = $5, status = $6,
+            due_date = $7, photos = $8,
+            closed_at = CASE WHEN $6 = 'closed' THEN COALESCE(closed_at, CURRENT_TIMESTAMP) END,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1 AND punch_list_id = $2
+        RETURNING {ITEM_COLUMNS}
+        "#
+    ))
+    .bind(item_id)
+    .bind(list_id)
+    .bind(&payload.description)
+    .bind(&payload.location)
+    .bind(&payload.responsible_party)
+    .bind(payload.status.as_str())
+    .bind(payload.due_date.and_then(to_date))
+    .bind(SqlJson(&payload.photos))
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+    touch_list(&app_state.pool, list_id).await?;
+
+    sec::log_security_event("PUNCH_ITEM_UPDATE", Some(&scope.username), None, "Success");
+
+    Ok(Json(row.into()))
+}
+
+pub async fn delete_punch_item_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path((list_id, item_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    fetch_list(&app_state.pool, &scope, list_id).await?;
+
+    let result = sqlx::query("DELETE FROM punch_items WHERE id = $1 AND punch_list_id = $2")
+        .bind(item_id)
+        .bind(list_id)
+        .execute(&app_state.pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+    touch_list(&app_state.pool, list_id).await?;
+
+    sec::log_security_event("PUNCH_ITEM_DELETE", Some(&scope.username), None, "Success");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> PunchItemPayload {
+        PunchItemPayload {
+            description: "Touch up paint at stair 2".to_string(),
+            location: Some("Level 3".to_string()),
+            responsible_party: "Painting".to_string(),
+            status: PunchStatus::Open,
+            due_date: NaiveDate::from_ymd_opt(2025, 6, 30),
+            photos: vec![PhotoMetadata {
+                url: "https://files.example.com/punch/1.jpg".to_string(),
+                caption: None,
+                taken_at: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_item_payload_validation() {
+        assert!(payload().validate().is_ok());
+
+        let mut no_party = payload();
+        no_party.responsible_party.clear();
+        assert!(no_party.validate().is_err());
+
+        let mut bad_photo = payload();
+        bad_photo.photos[0].url = "not a url".to_string();
+        assert!(bad_photo.validate().is_err());
+    }
+}
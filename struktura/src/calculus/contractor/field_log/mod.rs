@@ -0,0 +1,350 @@
+// ============================================================================
+// Field Reports
+//
+// Superintendents log each working day: weather, crews on site, work done,
+// delays and photos. `progress_tracking` and `delay_analysis` take summary
+// figures; a field log can be attached to the parameters instead and the
+// figures are measured from it. Values sent explicitly in `additional`
+// always win over measured ones.
+//
+// - saved.rs: per-organization daily reports, grouped by project
+// ============================================================================
+
+pub mod saved;
+
+use crate::calculus::contractor::{models::ContractingParameters, records::PhotoMetadata};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Hours in a standard working day; delay hours beyond this in one day are
+/// concurrent and count once
+pub const WORKDAY_HOURS: f64 = 8.0;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Weather {
+    /// Free text, e.g. "Overcast, light rain after 14:00"
+    #[serde(default)]
+    pub conditions: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature_high_c: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature_low_c: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precipitation_mm: Option<f64>,
+}
+
+/// Workers of one trade on site
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrewCount {
+    pub trade: String,
+    pub workers: u32,
+    /// Hours worked by the whole crew
+    pub hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkEntry {
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+/// Who a delay is attributed to, which decides how a claim treats it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DelayCause {
+    Weather,
+    /// Owner decisions, access or late owner-furnished items
+    Owner,
+    /// Design errors, RFIs and changes
+    Design,
+    Contractor,
+    Subcontractor,
+    /// Utilities, authorities and other outside parties
+    ThirdParty,
+}
+
+impl DelayCause {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DelayCause::Weather => "weather",
+            DelayCause::Owner => "owner",
+            DelayCause::Design => "design",
+            DelayCause::Contractor => "contractor",
+            DelayCause::Subcontractor => "subcontractor",
+            DelayCause::ThirdParty => "third_party",
+        }
+    }
+
+    /// Time and money are recoverable from the owner
+    pub fn is_compensable(&self) -> bool {
+        matches!(self, DelayCause::Owner | DelayCause::Design)
+    }
+
+    /// Time is recoverable but money is not
+    pub fn is_excusable(&self) -> bool {
+        self.is_compensable() || matches!(self, DelayCause::Weather | DelayCause::ThirdParty)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelayEntry {
+    pub cause: DelayCause,
+    pub description: String,
+    pub hours_lost: f64,
+}
+
+/// One day's report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldReport {
+    pub report_date: NaiveDate,
+    #[serde(default)]
+    pub weather: Weather,
+    #[serde(default)]
+    pub crews: Vec<CrewCount>,
+    #[serde(default)]
+    pub work_performed: Vec<WorkEntry>,
+    #[serde(default)]
+    pub delays: Vec<DelayEntry>,
+    /// Overall project completion as assessed on site, in percent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percent_complete: Option<f64>,
+    #[serde(default)]
+    pub photos: Vec<PhotoMetadata>,
+}
+
+impl FieldReport {
+    pub fn workers(&self) -> u32 {
+        self.crews.iter().map(|c| c.workers).sum()
+    }
+
+    pub fn labor_hours(&self) -> f64 {
+        self.crews.iter().map(|c| c.hours).sum()
+    }
+
+    /// Delay hours by cause, scaled down when they exceed a working day
+    pub fn delay_hours(&self) -> HashMap<DelayCause, f64> {
+        let total: f64 = self.delays.iter().map(|d| d.hours_lost.max(0.0)).sum();
+        let scale = if total > WORKDAY_HOURS { WORKDAY_HOURS / total } else { 1.0 };
+        let mut hours = HashMap::new();
+        for delay in &self.delays {
+            *hours.entry(delay.cause).or_insert(0.0) += delay.hours_lost.max(0.0) * scale;
+        }
+        hours
+    }
+}
+
+/// Reports for one project, with the planned window they are measured against
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FieldLog {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub planned_start: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub planned_finish: Option<NaiveDate>,
+    pub reports: Vec<FieldReport>,
+}
+
+/// Days lost to one kind of cause
+#[derive(Debug, Clone, Serialize)]
+pub struct DelaySummary {
+    pub cause: DelayCause,
+    pub days: f64,
+    pub compensable: bool,
+    pub excusable: bool,
+    /// Report dates with this cause logged, as evidence for a claim
+    pub dates: Vec<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldLogSummary {
+    pub reports: usize,
+    pub first_date: Option<NaiveDate>,
+    pub last_date: Option<NaiveDate>,
+    pub labor_hours: f64,
+    pub peak_workers: u32,
+    /// Most recent completion logged on site
+    pub percent_complete: Option<f64>,
+    /// Completion a straight-line plan expects by `last_date`
+    pub planned_percent_complete: Option<f64>,
+    pub planned_duration_days: Option<f64>,
+    pub days_lost: f64,
+    pub delays: Vec<DelaySummary>,
+}
+
+impl FieldLog {
+    fn sorted_reports(&self) -> Vec<&FieldReport> {
+        let mut reports: Vec<&FieldReport> = self.reports.iter().collect();
+        reports.sort_by_key(|r| r.report_date);
+        reports
+    }
+
+    /// Calendar days from planned start to planned finish, inclusive
+    pub fn planned_duration(&self) -> Option<f64> {
+        match (self.planned_start, self.planned_finish) {
+            (Some(start), Some(finish)) if finish >= start => Some(((finish - start).num_days() + 1) as f64),
+            _ => None,
+        }
+    }
+
+    /// Straight-line planned completion at the end of `date`
+    pub fn planned_percent_at(&self, date: NaiveDate) -> Option<f64> {
+        let start = self.planned_start?;
+        let duration = self.planned_duration()?;
+        let elapsed = ((date - start).num_days() + 1) as f64;
+        Some((elapsed / duration * 100.0).clamp(0.0, 100.0))
+    }
+
+    pub fn summary(&self) -> FieldLogSummary {
+        let reports = self.sorted_reports();
+        let last_date = reports.last().map(|r| r.report_date);
+
+        let mut delays: Vec<DelaySummary> = Vec::new();
+        for report in &reports {
+            let mut causes: Vec<(DelayCause, f64)> = report.delay_hours().into_iter().collect();
+            causes.sort_by_key(|(cause, _)| cause.as_str());
+            for (cause, hours) in causes {
+                let index = match delays.iter().position(|d| d.cause == cause) {
+                    Some(index) => index,
+                    None => {
+                        delays.push(DelaySummary {
+                            cause,
+                            days: 0.0,
+                            compensable: cause.is_compensable(),
+                            excusable: cause.is_excusable(),
+                            dates: Vec::new(),
+                        });
+                        delays.len() - 1
+                    }
+                };
+                delays[index].days += hours / WORKDAY_HOURS;
+                delays[index].dates.push(report.report_date);
+            }
+        }
+        delays.sort_by(|a, b| b.days.total_cmp(&a.days));
+
+        FieldLogSummary {
+            reports: reports.len(),
+            first_date: reports.first().map(|r| r.report_date),
+            last_date,
+            labor_hours: reports.iter().map(|r| r.labor_hours()).sum(),
+            peak_workers: reports.iter().map(|r| r.workers()).max().unwrap_or(0),
+            percent_complete: reports.iter().rev().find_map(|r| r.percent_complete),
+            planned_percent_complete: last_date.and_then(|date| self.planned_percent_at(date)),
+            planned_duration_days: self.planned_duration(),
+            days_lost: delays.iter().map(|d| d.days).sum(),
+            delays,
+        }
+    }
+
+    /// Inputs for `calculator` measured from the reports
+    pub fn derived_parameters(&self, calculator: &str) -> Vec<(&'static str, f64)> {
+        let summary = self.summary();
+        let mut derived = Vec::new();
+        match calculator {
+            "progress_tracking" => {
+                if let Some(actual) = summary.percent_complete {
+                    derived.push(("actual_progress", actual));
+                }
+                if let Some(planned) = summary.planned_percent_complete {
+                    derived.push(("planned_progress", planned));
+                }
+            }
+            "delay_analysis" => {
+                if let Some(planned) = summary.planned_duration_days {
+                    derived.push(("planned_duration", planned));
+                    derived.push(("actual_duration", planned + summary.days_lost));
+                }
+                if summary.days_lost > 0.0 {
+                    let compensable: f64 = summary.delays.iter().filter(|d| d.compensable).map(|d| d.days).sum();
+                    derived.push(("delay_cause_factor", compensable / summary.days_lost));
+                }
+            }
+            _ => {}
+        }
+        derived
+    }
+}
+
+/// Fill `calculator` inputs from an attached field log
+pub fn fill_parameters(params: &mut ContractingParameters, calculator: &str) {
+    let Some(log) = params.field_log.as_ref() else {
+        return;
+    };
+    let derived = log.derived_parameters(calculator);
+
+    let additional = params.additional.get_or_insert_with(HashMap::new);
+    for (key, value) in derived {
+        additional.entry(key.to_string()).or_insert(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 4, day).unwrap()
+    }
+
+    fn report(day: u32, delays: Vec<(DelayCause, f64)>, percent: Option<f64>) -> FieldReport {
+        FieldReport {
+            report_date: date(day),
+            weather: Weather::default(),
+            crews: vec![CrewCount { trade: "Concrete".to_string(), workers: 6, hours: 48.0 }],
+            work_performed: vec![],
+            delays: delays
+                .into_iter()
+                .map(|(cause, hours_lost)| DelayEntry { cause, description: String::new(), hours_lost })
+                .collect(),
+            percent_complete: percent,
+            photos: vec![],
+        }
+    }
+
+    fn log() -> FieldLog {
+        FieldLog {
+            planned_start: Some(date(1)),
+            planned_finish: Some(date(10)),
+            reports: vec![
+                report(2, vec![(DelayCause::Weather, 8.0)], Some(5.0)),
+                report(1, vec![], Some(2.0)),
+                report(4, vec![(DelayCause::Owner, 8.0), (DelayCause::Contractor, 8.0)], None),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let summary = log().summary();
+        assert_eq!(summary.reports, 3);
+        assert_eq!(summary.last_date, Some(date(4)));
+        assert_eq!(summary.percent_complete, Some(5.0));
+        assert_eq!(summary.planned_percent_complete, Some(40.0));
+        assert!((summary.labor_hours - 144.0).abs() < 1e-9);
+        // Owner and contractor overlapped on day 4: one day between them
+        assert!((summary.days_lost - 2.0).abs() < 1e-9);
+        assert_eq!(summary.delays[0].cause, DelayCause::Weather);
+        assert_eq!(summary.delays[0].dates, vec![date(2)]);
+    }
+
+    #[test]
+    fn test_fill_parameters() {
+        let mut params = ContractingParameters { field_log: Some(log()), ..Default::default() };
+        fill_parameters(&mut params, "delay_analysis");
+        let additional = params.additional.as_ref().unwrap();
+        assert_eq!(additional["planned_duration"], 10.0);
+        assert!((additional["actual_duration"] - 12.0).abs() < 1e-9);
+        assert!((additional["delay_cause_factor"] - 0.25).abs() < 1e-9);
+
+        let mut params = ContractingParameters {
+            field_log: Some(log()),
+            additional: Some(HashMap::from([("actual_progress".to_string(), 7.0)])),
+            ..Default::default()
+        };
+        fill_parameters(&mut params, "progress_tracking");
+        let additional = params.additional.unwrap();
+        assert_eq!(additional["actual_progress"], 7.0);
+        assert_eq!(additional["planned_progress"], 40.0);
+    }
+}
@@ -0,0 +1,347 @@
+//! Saved field reports
+//!
+//! One report per project per day, kept per organization. The project is
+//! identified by name, as on punch lists. Listing by project and date range
+//! gives the field log the progress and delay calculators read.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use sqlx::types::time::{Date, OffsetDateTime};
+use sqlx::types::Json as SqlJson;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use super::{CrewCount, DelayEntry, FieldReport, Weather, WorkEntry};
+use crate::calculus::contractor::records::{to_date, to_naive, validate_photos, PhotoMetadata};
+use crate::sec::{self, AppError};
+use crate::state::AppState;
+use crate::tenancy::{TenantOwned, TenantScope};
+
+/// Longest list of crews, work entries or delays on one report
+const MAX_ENTRIES: usize = 100;
+
+// ============================================================================
+// Models
+// ============================================================================
+
+#[derive(Debug, sqlx::FromRow)]
+struct FieldReportRow {
+    id: Uuid,
+    org_id: Uuid,
+    project_name: String,
+    report_date: Date,
+    weather: SqlJson<Weather>,
+    crews: SqlJson<Vec<CrewCount>>,
+    work_performed: SqlJson<Vec<WorkEntry>>,
+    delays: SqlJson<Vec<DelayEntry>>,
+    percent_complete: Option<f64>,
+    photos: SqlJson<Vec<PhotoMetadata>>,
+    notes: Option<String>,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SavedFieldReport {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub project_name: String,
+    #[serde(flatten)]
+    pub report: FieldReport,
+    pub notes: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl TenantOwned for SavedFieldReport {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+impl TryFrom<FieldReportRow> for SavedFieldReport {
+    type Error = AppError;
+
+    fn try_from(row: FieldReportRow) -> Result<Self, AppError> {
+        let report_date = to_naive(row.report_date)
+            .ok_or_else(|| AppError::Internal(format!("Report {} has an invalid date", row.id)))?;
+        Ok(Self {
+            id: row.id,
+            org_id: row.org_id,
+            project_name: row.project_name,
+            report: FieldReport {
+                report_date,
+                weather: row.weather.0,
+                crews: row.crews.0,
+                work_performed: row.work_performed.0,
+                delays: row.delays.0,
+                percent_complete: row.percent_complete,
+                photos: row.photos.0,
+            },
+            notes: row.notes,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[validate(schema(function = "validate_report"))]
+pub struct FieldReportPayload {
+    #[validate(length(min = 1, max = 200))]
+    pub project_name: String,
+    #[serde(flatten)]
+    pub report: FieldReport,
+    #[validate(length(max = 5000))]
+    pub notes: Option<String>,
+}
+
+fn validate_report(payload: &FieldReportPayload) -> Result<(), ValidationError> {
+    let report = &payload.report;
+    let invalid = |code: &'static str| Err(ValidationError::new(code));
+
+    if report.crews.len() > MAX_ENTRIES || report.work_performed.len() > MAX_ENTRIES || report.delays.len() > MAX_ENTRIES {
+        return invalid("too many entries");
+    }
+    if report.crews.iter().any(|c| c.trade.trim().is_empty() || !(0.0..=24.0 * c.workers as f64).contains(&c.hours)) {
+        return invalid("crew hours between 0 and 24 per worker");
+    }
+    if report.delays.iter().any(|d| !(0.0..=24.0).contains(&d.hours_lost)) {
+        return invalid("delay hours between 0 and 24");
+    }
+    if report.percent_complete.is_some_and(|p| !(0.0..=100.0).contains(&p)) {
+        return invalid("percent complete between 0 and 100");
+    }
+    validate_photos(&report.photos)
+}
+
+/// Filters for listing reports
+#[derive(Debug, Deserialize)]
+pub struct FieldReportQuery {
+    pub project: Option<String>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+const REPORT_COLUMNS: &str = "id, org_id, project_name, report_date, weather, crews, work_performed, \
+    delays, percent_complete, photos, notes, created_at, updated_at";
+
+// ============================================================================
+// Queries
+// ============================================================================
+
+async fn query_reports(
+    pool: &PgPool,
+    scope: &TenantScope,
+    project: Option<&str>,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<Vec<SavedFieldReport>, AppError> {
+    let rows = sqlx::query_as::<_, FieldReportRow>(&format!(
+        "SELECT {REPORT_COLUMNS} FROM field_reports \
+         WHERE org_id = $1 \
+           AND ($2::VARCHAR IS NULL OR project_name = $2) \
+           AND ($3::DATE IS NULL OR report_date >= $3) \
+           AND ($4::DATE IS NULL OR report_date <= $4) \
+         ORDER BY project_name, report_date"
+    ))
+    .bind(scope.org_id)
+    .bind(project)
+    .bind(from.and_then(to_date))
+    .bind(to.and_then(to_date))
+    .fetch_all(pool)
+    .await?;
+
+    let reports = rows.into_iter().map(SavedFieldReport::try_from).collect::<Result<Vec<_>, _>>()?;
+    scope.guard_all(reports)
+}
+
+/// A project's reports in date order, for the progress and delay calculators
+pub async fn load_reports(
+    pool: &PgPool,
+    scope: &TenantScope,
+    project: &str,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<Vec<FieldReport>, AppError> {
+    let reports = query_reports(pool, scope, Some(project), from, to).await?;
+    Ok(reports.into_iter().map(|saved| saved.report).collect())
+}
+
+async fn fetch(app_state: &AppState, scope: &TenantScope, id: Uuid) -> Result<SavedFieldReport, AppError> {
+    let row = sqlx::query_as::<_, FieldReportRow>(&format!(
+        "SELECT {REPORT_COLUMNS} FROM field_reports WHERE id = $1 AND org_id = $2"
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    scope.guard(row.try_into()?)
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+pub async fn list_reports_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Query(query): Query<FieldReportQuery>,
+) -> Result<Json<Vec<SavedFieldReport>>, AppError> {
+    let reports = query_reports(&app_state.pool, &scope, query.project.as_deref(), query.from, query.to).await?;
+    Ok(Json(reports))
+}
+
+pub async fn get_report_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<SavedFieldReport>, AppError> {
+    Ok(Json(fetch(&app_state, &scope, id).await?))
+}
+
+pub async fn create_report_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<FieldReportPayload>,
+) -> Result<(StatusCode, Json<SavedFieldReport>), AppError> {
+    payload.validate()?;
+    let report = &payload.report;
+
+    let row = sqlx::query_as::<_, FieldReportRow>(&format!(
+        r#"
+        INSERT INTO field_reports
+            (org_id, project_name, report_date, weather, crews, work_performed, delays,
+             percent_complete, photos, notes, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        RETURNING {REPORT_COLUMNS}
+        "#
+    ))
+    .bind(scope.org_id)
+    .bind(&payload.project_name)
+    .bind(to_date(report.report_date))
+    .bind(SqlJson(&report.weather))
+    .bind(SqlJson(&report.crews))
+    .bind(SqlJson(&report.work_performed))
+    .bind(SqlJson(&report.delays))
+    .bind(report.percent_complete)
+    .bind(SqlJson(&report.photos))
+    .bind(&payload.notes)
+    .bind(scope.user_id)
+    .fetch_one(&app_state.pool)
+    .await?;
+
+    sec::log_security_event("FIELD_REPORT_CREATE", Some(&scope.username), None, "Success");
+
+    Ok((StatusCode::CREATED, Json(row.try_into()?)))
+}
+
+pub async fn update_report_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<FieldReportPayload>,
+) -> Result<Json<SavedFieldReport>, AppError> {
+    payload.validate()?;
+    let report = &payload.report;
+
+    let row = sqlx::query_as::<_, FieldReportRow>(&format!(
+        r#"
+        UPDATE field_reports
+        SET project_name = $3, report_date = $4, weather = $5, crews = $6, work_performed = $7,
+            delays = $8, percent_complete = $9, photos = $10, notes = $11,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1 AND org_id = $2
+        RETURNING {REPORT_COLUMNS}
+        "#
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .bind(&payload.project_name)
+    .bind(to_date(report.report_date))
+    .bind(SqlJson(&report.weather))
+    .bind(SqlJson(&report.crews))
+    .bind(SqlJson(&report.work_performed))
+    .bind(SqlJson(&report.delays))
+    .bind(report.percent_complete)
+    .bind(SqlJson(&report.photos))
+    .bind(&payload.notes)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("FIELD_REPORT_UPDATE", Some(&scope.username), None, "Success");
+
+    Ok(Json(row.try_into()?))
+}
+
+pub async fn delete_report_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let result = sqlx::query("DELETE FROM field_reports WHERE id = $1 AND org_id = $2")
+        .bind(id)
+        .bind(scope.org_id)
+        .execute(&app_state.pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("FIELD_REPORT_DELETE", Some(&scope.username), None, "Success");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::contractor::field_log::DelayCause;
+
+    fn payload() -> FieldReportPayload {
+        serde_json::from_value(serde_json::json!({
+            "project_name": "Riverside Clinic",
+            "report_date": "2025-04-02",
+            "weather": { "conditions": "Rain", "precipitation_mm": 12.0 },
+            "crews": [{ "trade": "Concrete", "workers": 6, "hours": 48.0 }],
+            "work_performed": [{ "description": "Formed level 2 slab edge" }],
+            "delays": [{ "cause": "weather", "description": "Pour postponed", "hours_lost": 4.0 }],
+            "percent_complete": 18.5,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_payload_flattens_report() {
+        let payload = payload();
+        assert_eq!(payload.report.delays[0].cause, DelayCause::Weather);
+        assert!(payload.validate().is_ok());
+    }
+
+    #[test]
+    fn test_payload_validation() {
+        let mut overworked = payload();
+        overworked.report.crews[0].hours = 200.0;
+        assert!(overworked.validate().is_err());
+
+        let mut over_complete = payload();
+        over_complete.report.percent_complete = Some(120.0);
+        assert!(over_complete.validate().is_err());
+
+        let mut unnamed = payload();
+        unnamed.project_name.clear();
+        assert!(unnamed.validate().is_err());
+    }
+}
@@ -0,0 +1,420 @@
+// ============================================================================
+// Inspection Checklists
+//
+// Trade checklists are generated from the project scope: each trade has a
+// fixed core of hold points plus items that only apply to some scopes (post-
+// tensioning, welded connections, membrane seams). Sample counts follow the
+// usual frequencies: one concrete strength test per 115 m³ and per pour
+// (ACI 301), every weld visually (AWS D1.1), 10% of pretensioned bolted
+// connections (RCSC).
+//
+// Results record each item's outcome and how often it was reinspected.
+// Items passed at the first inspection give the first-time-quality rate;
+// `quality_control` counts inspected and defective items from them.
+//
+// - saved.rs: per-organization inspections with their results
+// ============================================================================
+
+pub mod saved;
+
+use crate::calculus::contractor::models::ContractingParameters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Concrete placed per strength test, in m³ (150 yd³)
+const CONCRETE_TEST_VOLUME_M3: f64 = 115.0;
+/// Share of pretensioned bolted connections checked
+const BOLT_SAMPLE_RATE: f64 = 0.10;
+/// Roof area per adhesion / fastener pull test, in m²
+const ROOF_TEST_AREA_M2: f64 = 1000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trade {
+    ConcretePour,
+    SteelErection,
+    Roofing,
+}
+
+impl Trade {
+    pub const ALL: [Trade; 3] = [Trade::ConcretePour, Trade::SteelErection, Trade::Roofing];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Trade::ConcretePour => "concrete_pour",
+            Trade::SteelErection => "steel_erection",
+            Trade::Roofing => "roofing",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.as_str() == value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Before,
+    During,
+    After,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    /// Stable id, `<trade>.<check>`
+    pub id: String,
+    pub phase: Phase,
+    pub description: String,
+    /// Standard the check comes from
+    pub reference: String,
+    /// Work may not proceed until this item passes
+    pub hold_point: bool,
+    /// Number of samples or locations to check, where it varies with scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConcreteScope {
+    pub pours: u32,
+    pub volume_m3: f64,
+    #[serde(default)]
+    pub post_tensioned: bool,
+    /// Placements expected below 5 °C
+    #[serde(default)]
+    pub cold_weather: bool,
+    /// Placements expected above 30 °C
+    #[serde(default)]
+    pub hot_weather: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SteelScope {
+    #[serde(default)]
+    pub bolted_connections: u32,
+    #[serde(default)]
+    pub welded_connections: u32,
+    #[serde(default)]
+    pub metal_deck: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoofSystem {
+    #[default]
+    Membrane,
+    Shingle,
+    Metal,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RoofingScope {
+    #[serde(default)]
+    pub system: RoofSystem,
+    pub area_m2: f64,
+    #[serde(default)]
+    pub penetrations: u32,
+}
+
+/// What the project includes, trade by trade
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectScope {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concrete: Option<ConcreteScope>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub steel: Option<SteelScope>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roofing: Option<RoofingScope>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checklist {
+    pub trade: Trade,
+    pub items: Vec<ChecklistItem>,
+}
+
+fn item(trade: Trade, check: &str, phase: Phase, description: &str, reference: &str, hold_point: bool) -> ChecklistItem {
+    ChecklistItem {
+        id: format!("{}.{}", trade.as_str(), check),
+        phase,
+        description: description.to_string(),
+        reference: reference.to_string(),
+        hold_point,
+        quantity: None,
+    }
+}
+
+fn counted(mut item: ChecklistItem, quantity: u32) -> ChecklistItem {
+    item.quantity = Some(quantity.max(1));
+    item
+}
+
+fn concrete_checklist(scope: &ConcreteScope) -> Checklist {
+    use Phase::*;
+    let trade = Trade::ConcretePour;
+    let tests = ((scope.volume_m3 / CONCRETE_TEST_VOLUME_M3).ceil() as u32).max(scope.pours);
+    let mut items = vec![
+        counted(item(trade, "formwork", Before, "Formwork line, level, bracing and cleanliness", "ACI 347", true), scope.pours),
+        counted(item(trade, "reinforcement", Before, "Bar size, spacing, laps, cover and chairs per drawings", "ACI 318 §26.6", true), scope.pours),
+        item(trade, "embeds", Before, "Embedded items, sleeves and anchor bolts located and secured", "ACI 301 §5.3", true),
+        item(trade, "mix_ticket", During, "Delivery tickets match the approved mix; time since batching within limit", "ASTM C94", false),
+        counted(item(trade, "slump_air_temp", During, "Slump, air content and concrete temperature tested", "ASTM C143 / C231 / C1064", false), tests),
+        counted(item(trade, "cylinders", During, "Strength specimens cast and labelled", "ACI 301 §1.6 / ASTM C31", false), tests),
+        item(trade, "consolidation", During, "Vibration and placement without cold joints or segregation", "ACI 309", false),
+        item(trade, "finish", After, "Surface finish and flatness to specification", "ACI 117", false),
+        item(trade, "curing", After, "Curing started and maintained for the specified period", "ACI 308", false),
+    ];
+    if scope.post_tensioned {
+        items.push(item(trade, "pt_profile", Before, "Tendon profile, anchorages and supports per shop drawings", "PTI M10.2", true));
+        items.push(item(trade, "pt_stressing", After, "Stressing records: elongations within 7% of calculated", "ACI 318 §26.10", true));
+    }
+    if scope.cold_weather {
+        items.push(item(trade, "cold_weather", After, "Enclosure or blankets keep concrete above 10 °C", "ACI 306", false));
+    }
+    if scope.hot_weather {
+        items.push(item(trade, "hot_weather", During, "Concrete temperature below limit; evaporation control in place", "ACI 305", false));
+    }
+    Checklist { trade, items }
+}
+
+fn steel_checklist(scope: &SteelScope) -> Checklist {
+    use Phase::*;
+    let trade = Trade::SteelErection;
+    let mut items = vec![
+        item(trade, "anchor_bolts", Before, "Anchor bolt positions and projections surveyed", "AISC 303 §7.5", true),
+        item(trade, "materials", Before, "Mill certificates and member marks match the approved shop drawings", "AISC 360 §A3", false),
+        item(trade, "plumbness", During, "Columns plumb within 1:500 before final connection", "AISC 303 §7.13", true),
+        item(trade, "bracing", During, "Temporary bracing in place until the frame is self-supporting", "AISC 303 §7.10", true),
+        item(trade, "coatings", After, "Shop primer touched up at field connections and damage", "SSPC-PA 1", false),
+    ];
+    if scope.bolted_connections > 0 {
+        let sample = (scope.bolted_connections as f64 * BOLT_SAMPLE_RATE).ceil() as u32;
+        items.push(counted(item(trade, "bolt_pretension", After, "Pretension verified on sampled bolted connections", "RCSC §9", true), sample));
+    }
+    if scope.welded_connections > 0 {
+        items.push(item(trade, "welder_quals", Before, "Welder qualifications and WPS on file", "AWS D1.1 §6", true));
+        items.push(counted(item(trade, "weld_visual", After, "Visual inspection of every completed weld", "AWS D1.1 §8.9", true), scope.welded_connections));
+    }
+    if scope.metal_deck {
+        items.push(item(trade, "deck_fastening", After, "Deck welds or fasteners at specified pattern and side laps", "SDI QA/QC", false));
+    }
+    Checklist { trade, items }
+}
+
+fn roofing_checklist(scope: &RoofingScope) -> Checklist {
+    use Phase::*;
+    let trade = Trade::Roofing;
+    let tests = (scope.area_m2 / ROOF_TEST_AREA_M2).ceil() as u32;
+    let mut items = vec![
+        item(trade, "substrate", Before, "Deck dry, clean, sound and sloped to drains", "NRCA", true),
+        item(trade, "insulation", During, "Insulation boards staggered and fastened to the wind uplift pattern", "FM 1-29", false),
+        counted(item(trade, "flashings", After, "Perimeter, curb and penetration flashings complete", "NRCA", false), scope.penetrations),
+        item(trade, "drainage", After, "Drains and scuppers clear; no ponding after 48 h", "IBC §1502", false),
+    ];
+    match scope.system {
+        RoofSystem::Membrane => {
+            items.push(item(trade, "seams", After, "Seams probed along their full length", "NRCA / manufacturer", true));
+            items.push(counted(item(trade, "adhesion", After, "Adhesion or fastener pull tests", "ANSI/SPRI FX-1", false), tests));
+        }
+        RoofSystem::Shingle => {
+            items.push(item(trade, "underlayment", Before, "Underlayment and ice barrier laps to specification", "IRC R905.1", true));
+            items.push(item(trade, "nailing", During, "Nail count and placement per shingle", "ASTM D7158 / manufacturer", false));
+        }
+        RoofSystem::Metal => {
+            items.push(item(trade, "clips", During, "Clip spacing to the uplift design", "ANSI/SPRI / manufacturer", true));
+            items.push(item(trade, "seaming", After, "Seams fully formed; sealant at end laps", "MBMA", false));
+        }
+    }
+    Checklist { trade, items }
+}
+
+impl ProjectScope {
+    /// One checklist per trade in scope
+    pub fn checklists(&self) -> Vec<Checklist> {
+        let mut checklists = Vec::new();
+        if let Some(concrete) = &self.concrete {
+            checklists.push(concrete_checklist(concrete));
+        }
+        if let Some(steel) = &self.steel {
+            checklists.push(steel_checklist(steel));
+        }
+        if let Some(roofing) = &self.roofing {
+            checklists.push(roofing_checklist(roofing));
+        }
+        checklists
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Pass,
+    Fail,
+    NotApplicable,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemResult {
+    pub item_id: String,
+    pub outcome: Outcome,
+    /// Inspections after the first; zero means the outcome is first-time
+    #[serde(default)]
+    pub reinspections: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+impl ItemResult {
+    pub fn first_time_pass(&self) -> bool {
+        self.outcome == Outcome::Pass && self.reinspections == 0
+    }
+}
+
+/// A checklist and what has been inspected on it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Inspection {
+    pub trade: Trade,
+    pub items: Vec<ChecklistItem>,
+    #[serde(default)]
+    pub results: Vec<ItemResult>,
+}
+
+/// First-time quality for one trade
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeQuality {
+    pub trade: Trade,
+    /// Items with a pass or fail recorded
+    pub inspected: usize,
+    pub first_time_passes: usize,
+    /// Items currently failed
+    pub open_failures: usize,
+    /// Hold points with no result yet
+    pub pending_hold_points: usize,
+    pub first_time_quality: f64,
+}
+
+/// Inspected items are those with a pass or fail; not-applicable ones and
+/// results for items not on the checklist are ignored
+pub fn first_time_quality(inspections: &[Inspection]) -> Vec<TradeQuality> {
+    let mut by_trade: Vec<TradeQuality> = Vec::new();
+    for inspection in inspections {
+        let results: HashMap<&str, &ItemResult> =
+            inspection.results.iter().map(|r| (r.item_id.as_str(), r)).collect();
+        let index = match by_trade.iter().position(|t| t.trade == inspection.trade) {
+            Some(index) => index,
+            None => {
+                by_trade.push(TradeQuality {
+                    trade: inspection.trade,
+                    inspected: 0,
+                    first_time_passes: 0,
+                    open_failures: 0,
+                    pending_hold_points: 0,
+                    first_time_quality: 0.0,
+                });
+                by_trade.len() - 1
+            }
+        };
+        let quality = &mut by_trade[index];
+        for item in &inspection.items {
+            match results.get(item.id.as_str()) {
+                Some(result) if result.outcome != Outcome::NotApplicable => {
+                    quality.inspected += 1;
+                    quality.first_time_passes += usize::from(result.first_time_pass());
+                    quality.open_failures += usize::from(result.outcome == Outcome::Fail);
+                }
+                Some(_) => {}
+                None => quality.pending_hold_points += usize::from(item.hold_point),
+            }
+        }
+    }
+    for quality in &mut by_trade {
+        quality.first_time_quality = if quality.inspected == 0 {
+            0.0
+        } else {
+            quality.first_time_passes as f64 / quality.inspected as f64 * 100.0
+        };
+    }
+    by_trade
+}
+
+/// Fill `quality_control` inputs from attached inspections: every inspected
+/// item counts, and those not passed first time are the defects
+pub fn fill_parameters(params: &mut ContractingParameters) {
+    let Some(inspections) = params.inspections.as_ref() else {
+        return;
+    };
+    let trades = first_time_quality(inspections);
+    let inspected: usize = trades.iter().map(|t| t.inspected).sum();
+    if inspected == 0 {
+        return;
+    }
+    let passed: usize = trades.iter().map(|t| t.first_time_passes).sum();
+
+    let additional = params.additional.get_or_insert_with(HashMap::new);
+    additional.entry("total_items".to_string()).or_insert(inspected as f64);
+    additional.entry("defective_items".to_string()).or_insert((inspected - passed) as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope() -> ProjectScope {
+        ProjectScope {
+            concrete: Some(ConcreteScope { pours: 2, volume_m3: 400.0, post_tensioned: true, ..Default::default() }),
+            steel: Some(SteelScope { bolted_connections: 45, welded_connections: 0, metal_deck: false }),
+            roofing: None,
+        }
+    }
+
+    #[test]
+    fn test_checklists_follow_scope() {
+        let checklists = scope().checklists();
+        assert_eq!(checklists.len(), 2);
+
+        let concrete = &checklists[0];
+        let cylinders = concrete.items.iter().find(|i| i.id == "concrete_pour.cylinders").unwrap();
+        assert_eq!(cylinders.quantity, Some(4));
+        assert!(concrete.items.iter().any(|i| i.id == "concrete_pour.pt_stressing"));
+        assert!(!concrete.items.iter().any(|i| i.id == "concrete_pour.cold_weather"));
+
+        let steel = &checklists[1];
+        let bolts = steel.items.iter().find(|i| i.id == "steel_erection.bolt_pretension").unwrap();
+        assert_eq!(bolts.quantity, Some(5));
+        assert!(!steel.items.iter().any(|i| i.id == "steel_erection.weld_visual"));
+    }
+
+    #[test]
+    fn test_first_time_quality() {
+        let checklist = scope().checklists().remove(1);
+        let result = |id: &str, outcome, reinspections| ItemResult {
+            item_id: format!("steel_erection.{}", id),
+            outcome,
+            reinspections,
+            notes: None,
+        };
+        let inspection = Inspection {
+            trade: checklist.trade,
+            items: checklist.items,
+            results: vec![
+                result("anchor_bolts", Outcome::Pass, 0),
+                result("materials", Outcome::Pass, 1),
+                result("plumbness", Outcome::Fail, 0),
+                result("coatings", Outcome::NotApplicable, 0),
+            ],
+        };
+        let quality = first_time_quality(std::slice::from_ref(&inspection));
+        assert_eq!(quality[0].inspected, 3);
+        assert_eq!(quality[0].first_time_passes, 1);
+        assert_eq!(quality[0].open_failures, 1);
+        // bracing and bolt pretension are hold points with no result
+        assert_eq!(quality[0].pending_hold_points, 2);
+
+        let mut params = ContractingParameters { inspections: Some(vec![inspection]), ..Default::default() };
+        fill_parameters(&mut params);
+        let additional = params.additional.unwrap();
+        assert_eq!(additional["total_items"], 3.0);
+        assert_eq!(additional["defective_items"], 2.0);
+    }
+}
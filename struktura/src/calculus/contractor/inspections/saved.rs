@@ -0,0 +1,354 @@
+//! Saved inspections
+//!
+//! An inspection is one trade's checklist on a project, generated from the
+//! scope when it is created, with results recorded as items are inspected.
+//! The project is identified by name, as on punch lists and field reports.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use sqlx::types::time::{Date, OffsetDateTime};
+use sqlx::types::Json as SqlJson;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use super::{ChecklistItem, Inspection, ItemResult, ProjectScope, Trade};
+use crate::calculus::contractor::records::{to_date, to_naive};
+use crate::sec::{self, AppError};
+use crate::state::AppState;
+use crate::tenancy::{TenantOwned, TenantScope};
+
+/// Longest note on a single result
+const MAX_NOTE_LENGTH: usize = 2000;
+
+// ============================================================================
+// Models
+// ============================================================================
+
+#[derive(Debug, sqlx::FromRow)]
+struct InspectionRow {
+    id: Uuid,
+    org_id: Uuid,
+    project_name: String,
+    trade: String,
+    inspection_date: Date,
+    inspector: Option<String>,
+    items: SqlJson<Vec<ChecklistItem>>,
+    results: SqlJson<Vec<ItemResult>>,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SavedInspection {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub project_name: String,
+    pub inspection_date: NaiveDate,
+    pub inspector: Option<String>,
+    #[serde(flatten)]
+    pub inspection: Inspection,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl TenantOwned for SavedInspection {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+impl TryFrom<InspectionRow> for SavedInspection {
+    type Error = AppError;
+
+    fn try_from(row: InspectionRow) -> Result<Self, AppError> {
+        let trade = Trade::parse(&row.trade)
+            .ok_or_else(|| AppError::Internal(format!("Inspection {} has an unknown trade", row.id)))?;
+        let inspection_date = to_naive(row.inspection_date)
+            .ok_or_else(|| AppError::Internal(format!("Inspection {} has an invalid date", row.id)))?;
+        Ok(Self {
+            id: row.id,
+            org_id: row.org_id,
+            project_name: row.project_name,
+            inspection_date,
+            inspector: row.inspector,
+            inspection: Inspection { trade, items: row.items.0, results: row.results.0 },
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// Create an inspection: the checklist for `trade` is generated from `scope`
+#[derive(Debug, Deserialize, Validate)]
+#[validate(schema(function = "validate_inspection"))]
+pub struct InspectionPayload {
+    #[validate(length(min = 1, max = 200))]
+    pub project_name: String,
+    pub trade: Trade,
+    pub scope: ProjectScope,
+    /// Defaults to today
+    pub inspection_date: Option<NaiveDate>,
+    #[validate(length(max = 200))]
+    pub inspector: Option<String>,
+}
+
+fn validate_inspection(payload: &InspectionPayload) -> Result<(), ValidationError> {
+    let scope = &payload.scope;
+    let invalid = |code: &'static str| Err(ValidationError::new(code));
+
+    let in_scope = match payload.trade {
+        Trade::ConcretePour => scope.concrete.is_some(),
+        Trade::SteelErection => scope.steel.is_some(),
+        Trade::Roofing => scope.roofing.is_some(),
+    };
+    if !in_scope {
+        return invalid("trade not in scope");
+    }
+    if scope.concrete.as_ref().is_some_and(|c| !c.volume_m3.is_finite() || c.volume_m3 < 0.0) {
+        return invalid("concrete volume must be positive");
+    }
+    if scope.roofing.as_ref().is_some_and(|r| !r.area_m2.is_finite() || r.area_m2 < 0.0) {
+        return invalid("roof area must be positive");
+    }
+    Ok(())
+}
+
+/// Check recorded results against the inspection's checklist
+fn validate_results(items: &[ChecklistItem], results: &[ItemResult]) -> Result<(), AppError> {
+    let code = if results.iter().any(|r| !items.iter().any(|i| i.id == r.item_id)) {
+        "unknown checklist item"
+    } else if results.iter().any(|r| r.notes.as_ref().is_some_and(|n| n.len() > MAX_NOTE_LENGTH)) {
+        "note too long"
+    } else {
+        return Ok(());
+    };
+    let mut errors = ValidationErrors::new();
+    errors.add("results", ValidationError::new(code));
+    Err(AppError::ValidationError(errors))
+}
+
+/// Replace the results for the given items, keeping the others
+fn merge_results(existing: &mut Vec<ItemResult>, updates: Vec<ItemResult>) {
+    for update in updates {
+        match existing.iter_mut().find(|r| r.item_id == update.item_id) {
+            Some(result) => *result = update,
+            None => existing.push(update),
+        }
+    }
+}
+
+/// Filters for listing inspections
+#[derive(Debug, Deserialize)]
+pub struct InspectionQuery {
+    pub project: Option<String>,
+    pub trade: Option<Trade>,
+}
+
+const INSPECTION_COLUMNS: &str = "id, org_id, project_name, trade, inspection_date, inspector, items, results, \
+    created_at, updated_at";
+
+// ============================================================================
+// Queries
+// ============================================================================
+
+/// An organization's inspections in date order, optionally for one project or trade
+pub async fn load_inspections(
+    pool: &PgPool,
+    scope: &TenantScope,
+    project: Option<&str>,
+    trade: Option<Trade>,
+) -> Result<Vec<SavedInspection>, AppError> {
+    let rows = sqlx::query_as::<_, InspectionRow>(&format!(
+        "SELECT {INSPECTION_COLUMNS} FROM inspections \
+         WHERE org_id = $1 \
+           AND ($2::VARCHAR IS NULL OR project_name = $2) \
+           AND ($3::VARCHAR IS NULL OR trade = $3) \
+         ORDER BY inspection_date, created_at"
+    ))
+    .bind(scope.org_id)
+    .bind(project)
+    .bind(trade.map(|t| t.as_str()))
+    .fetch_all(pool)
+    .await?;
+
+    let inspections = rows.into_iter().map(SavedInspection::try_from).collect::<Result<Vec<_>, _>>()?;
+    scope.guard_all(inspections)
+}
+
+async fn fetch(app_state: &AppState, scope: &TenantScope, id: Uuid) -> Result<SavedInspection, AppError> {
+    let row = sqlx::query_as::<_, InspectionRow>(&format!(
+        "SELECT {INSPECTION_COLUMNS} FROM inspections WHERE id = $1 AND org_id = $2"
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    scope.guard(row.try_into()?)
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+pub async fn list_inspections_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Query(query): Query<InspectionQuery>,
+) -> Result<Json<Vec<SavedInspection>>, AppError> {
+    let inspections = load_inspections(&app_state.pool, &scope, query.project.as_deref(), query.trade).await?;
+    Ok(Json(inspections))
+}
+
+pub async fn get_inspection_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<SavedInspection>, AppError> {
+    Ok(Json(fetch(&app_state, &scope, id).await?))
+}
+
+pub async fn create_inspection_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<InspectionPayload>,
+) -> Result<(StatusCode, Json<SavedInspection>), AppError> {
+    payload.validate()?;
+    let items = payload
+        .scope
+        .checklists()
+        .into_iter()
+        .find(|c| c.trade == payload.trade)
+        .map(|c| c.items)
+        .unwrap_or_default();
+    let inspection_date = payload.inspection_date.unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+    let row = sqlx::query_as::<_, InspectionRow>(&format!(
+        r#"
+        INSERT INTO inspections (org_id, project_name, trade, inspection_date, inspector, items, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING {INSPECTION_COLUMNS}
+        "#
+    ))
+    .bind(scope.org_id)
+    .bind(&payload.project_name)
+    .bind(payload.trade.as_str())
+    .bind(to_date(inspection_date))
+    .bind(&payload.inspector)
+    .bind(SqlJson(&items))
+    .bind(scope.user_id)
+    .fetch_one(&app_state.pool)
+    .await?;
+
+    sec::log_security_event("INSPECTION_CREATE", Some(&scope.username), None, "Success");
+
+    Ok((StatusCode::CREATED, Json(row.try_into()?)))
+}
+
+/// PUT /inspections/{id}/results
+/// Record outcomes; items not in the body keep their previous result
+pub async fn record_results_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(results): Json<Vec<ItemResult>>,
+) -> Result<Json<SavedInspection>, AppError> {
+    let mut saved = fetch(&app_state, &scope, id).await?;
+    validate_results(&saved.inspection.items, &results)?;
+    merge_results(&mut saved.inspection.results, results);
+
+    let row = sqlx::query_as::<_, InspectionRow>(&format!(
+        r#"
+        UPDATE inspections
+        SET results = $3, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1 AND org_id = $2
+        RETURNING {INSPECTION_COLUMNS}
+        "#
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .bind(SqlJson(&saved.inspection.results))
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("INSPECTION_RESULTS", Some(&scope.username), None, "Success");
+
+    Ok(Json(row.try_into()?))
+}
+
+pub async fn delete_inspection_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let result = sqlx::query("DELETE FROM inspections WHERE id = $1 AND org_id = $2")
+        .bind(id)
+        .bind(scope.org_id)
+        .execute(&app_state.pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("INSPECTION_DELETE", Some(&scope.username), None, "Success");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::contractor::inspections::Outcome;
+
+    fn payload() -> InspectionPayload {
+        serde_json::from_value(serde_json::json!({
+            "project_name": "Riverside Clinic",
+            "trade": "roofing",
+            "scope": { "roofing": { "system": "membrane", "area_m2": 2400.0, "penetrations": 6 } },
+        }))
+        .unwrap()
+    }
+
+    fn result(item_id: &str, outcome: Outcome) -> ItemResult {
+        ItemResult { item_id: item_id.to_string(), outcome, reinspections: 0, notes: None }
+    }
+
+    #[test]
+    fn test_payload_validation() {
+        assert!(payload().validate().is_ok());
+
+        let mut out_of_scope = payload();
+        out_of_scope.trade = Trade::SteelErection;
+        assert!(out_of_scope.validate().is_err());
+
+        let mut negative = payload();
+        negative.scope.roofing.as_mut().unwrap().area_m2 = -5.0;
+        assert!(negative.validate().is_err());
+    }
+
+    #[test]
+    fn test_results_merge_by_item() {
+        let items = payload().scope.checklists().remove(0).items;
+        assert!(validate_results(&items, &[result("roofing.seams", Outcome::Fail)]).is_ok());
+        assert!(validate_results(&items, &[result("roofing.nailing", Outcome::Pass)]).is_err());
+
+        let mut results = vec![result("roofing.seams", Outcome::Fail), result("roofing.substrate", Outcome::Pass)];
+        let mut retest = result("roofing.seams", Outcome::Pass);
+        retest.reinspections = 1;
+        merge_results(&mut results, vec![retest, result("roofing.drainage", Outcome::Pass)]);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].outcome, Outcome::Pass);
+        assert!(!results[0].first_time_pass());
+    }
+}
@@ -10,6 +10,13 @@
 // - models.rs:    Data structures for inputs, outputs, and metadata
 // - registry.rs:  Thread-safe calculator registry
 // - router.rs:    Axum HTTP router with API endpoints
+// - warnings.rs:  Catalogue of stable warning codes
+// - schedule/:    Activity schedules, CPM and MS Project / P6 import
+// - closeout/:    Punch lists feeding project closeout
+// - field_log/:   Daily field reports feeding progress and delay analysis
+// - inspections/: Trade inspection checklists feeding quality control
+// - prequal/:     Weighted subcontractor prequalification
+// - records.rs:   Photo metadata and dates shared by saved site records
 // - calculators/: Individual calculator implementations by discipline
 // ============================================================================
 
@@ -19,6 +26,13 @@ pub mod traits;
 pub mod models;
 pub mod registry;
 pub mod router;
+pub mod warnings;
+pub mod schedule;
+pub mod closeout;
+pub mod field_log;
+pub mod inspections;
+pub mod prequal;
+pub mod records;
 
 // Calculator implementations organized by discipline
 pub mod calculators {
@@ -105,6 +119,16 @@ pub mod test_utils {
             humidity: None,
             additional: None,
             project_metadata: None,
+            schedule: None,
+            punch_list: None,
+            field_log: None,
+            inspections: None,
+            prequalification: None,
+            unit_system: Default::default(),
+            use_live_pricing: false,
+            location: None,
+            origin: None,
+            live_prices: Default::default(),
         }
     }
 
@@ -140,6 +164,16 @@ pub mod test_utils {
             humidity: None,
             additional: None,
             project_metadata: None,
+            schedule: None,
+            punch_list: None,
+            field_log: None,
+            inspections: None,
+            prequalification: None,
+            unit_system: Default::default(),
+            use_live_pricing: false,
+            location: None,
+            origin: None,
+            live_prices: Default::default(),
         }
     }
 }
@@ -166,6 +200,65 @@ mod tests {
         assert!(stats.by_category.len() >= 0);
     }
 
+    #[tokio::test]
+    async fn test_travel_cost_from_yard_to_site() {
+        use crate::pricing::Location;
+
+        let registry = create_default_registry();
+        let calculator = registry.find("travel_cost").unwrap();
+        let mut params = ContractingParameters {
+            origin: Some(Location::new("US").with_coordinates(32.7767, -96.797)),
+            location: Some(Location::new("US").with_coordinates(32.7555, -97.3308)),
+            additional: Some([("project_days".to_string(), 20.0)].into_iter().collect()),
+            ..test_utils::minimal_parameters()
+        };
+
+        let response = registry.execute(&calculator, params.clone()).await.unwrap();
+        let value = |label: &str| response.results.iter().find(|r| r.label == label).unwrap().value;
+        // Dallas to Fort Worth: ~50 km straight, ~40 road miles at 1.3 circuity
+        assert!((value("Straight-line Distance") - 50.0).abs() < 1.5);
+        let miles = value("One-way Road Distance");
+        assert!((value("Mobilization and Demobilization") - 2.0 * miles * 5.0).abs() < 1e-9);
+        assert!((value("Total Crew Travel") - 20.0 * 2.0 * 2.0 * miles * 0.70).abs() < 1e-9);
+
+        params.origin = Some(Location::new("US").with_city("Dallas"));
+        assert!(registry.execute(&calculator, params).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fuel_emissions_by_phase() {
+        let registry = create_default_registry();
+        let calculator = registry.find("fuel_emissions").unwrap();
+        let schedule = serde_json::from_value(serde_json::json!({
+            "activities": [
+                { "id": "EX", "duration_days": 5, "phase": "Earthwork",
+                  "equipment": [{ "kind": "excavator", "count": 2 }] },
+                { "id": "PV", "duration_days": 3, "phase": "Paving", "predecessors": [{ "id": "EX" }],
+                  "equipment": [{ "kind": "roller", "hours": 40, "fuel_rate": 10 }, { "kind": "trencher", "hours": 10 }] }
+            ]
+        }))
+        .unwrap();
+        let params = ContractingParameters {
+            schedule: Some(schedule),
+            ..test_utils::minimal_parameters()
+        };
+
+        let response = registry.execute(&calculator, params).await.unwrap();
+        let value = |label: &str| response.results.iter().find(|r| r.label == label).unwrap().value;
+        // 35% idle burning at a quarter of the load rate
+        let factor = 0.65 + 0.35 * 0.25;
+        assert!((value("Earthwork Fuel") - 2.0 * 5.0 * 8.0 * 18.0 * factor).abs() < 1e-9);
+        assert!((value("Paving Fuel") - (40.0 * 10.0 + 10.0 * 15.0) * factor).abs() < 1e-9);
+        assert!((value("Total Fuel Cost") - value("Total Fuel") * 1.10).abs() < 1e-9);
+        assert!((value("Scope 1 CO2e") - value("Total Fuel") * 2.70 / 1000.0).abs() < 1e-9);
+        assert!(response.warnings.iter().any(|w| w.contains("trencher")));
+
+        let quarter = value("Idle -25%: Fuel Saved");
+        let half = value("Idle -50%: Fuel Saved");
+        let target = value("Idle at 15%: Fuel Saved");
+        assert!(0.0 < quarter && quarter < half && half < target);
+    }
+
     #[test]
     fn test_catalogue_generation() {
         let registry = create_default_registry();
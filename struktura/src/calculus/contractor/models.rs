@@ -1,5 +1,14 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use crate::calculus::changelog::FormulaChange;
+use crate::calculus::examples::CalculatorExample;
+use crate::calculus::contractor::closeout::PunchList;
+use crate::calculus::contractor::field_log::FieldLog;
+use crate::calculus::contractor::inspections::Inspection;
+use crate::calculus::contractor::prequal::Prequalification;
+use crate::calculus::contractor::schedule::Schedule;
+use crate::pricing::{LivePrices, Location};
+use crate::utils::conversions::UnitSystem;
 
 // ============================================================================
 // ENUMS AND CONSTANTS
@@ -77,6 +86,10 @@ impl RegulationCode {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MaterialProperties {
     pub material_type: String,
+
+    /// Saved material library entry to fill unset values from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub material_id: Option<String>,
     
     // Cost properties
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -97,6 +110,7 @@ impl Default for MaterialProperties {
     fn default() -> Self {
         Self {
             material_type: "Concrete".to_string(),
+            material_id: None,
             unit_cost: None,
             waste_factor: None,
             density: None,
@@ -152,7 +166,7 @@ impl Default for SafetyFactors {
 }
 
 /// Comprehensive contracting parameters
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ContractingParameters {
     /// Geometric dimensions (meters, etc.)
     pub dimensions: HashMap<String, f64>,
@@ -188,6 +202,47 @@ pub struct ContractingParameters {
     /// Optional project metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project_metadata: Option<ProjectMetadata>,
+
+    /// Activity schedule; scheduling calculators measure their inputs from it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<Schedule>,
+
+    /// Punch list; `project_closeout` counts its inputs from it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub punch_list: Option<PunchList>,
+
+    /// Daily field reports; progress and delay calculators measure their inputs from them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_log: Option<FieldLog>,
+
+    /// Inspection checklists with results; `quality_control` counts its inputs from them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inspections: Option<Vec<Inspection>>,
+
+    /// Candidates and scoring model; `subcontractor_evaluation` ranks them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prequalification: Option<Prequalification>,
+
+    /// Units the request is written in; results come back in the same system
+    #[serde(default, skip_serializing_if = "UnitSystem::is_metric")]
+    pub unit_system: UnitSystem,
+
+    /// Price materials at `location` instead of the bundled USD costs
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub use_live_pricing: bool,
+
+    /// Where materials are bought; required with `use_live_pricing`. Also
+    /// the jobsite `travel_cost` measures to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+
+    /// Office or yard crews and equipment leave from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<Location>,
+
+    /// Prices resolved for this request by the router
+    #[serde(skip)]
+    pub live_prices: LivePrices,
 }
 
 /// Project metadata for tracking and documentation
@@ -218,7 +273,7 @@ pub struct ContractingResultItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tolerance: Option<f64>,
     
-    /// Formatted value string
+    /// Display string, rounded by `utils::format` when served
     #[serde(skip_serializing_if = "Option::is_none")]
     pub formatted_value: Option<String>,
     
@@ -245,15 +300,23 @@ pub enum WarningSeverity {
     Critical,
 }
 
-/// Structured warning with severity and details
+/// Structured warning with a stable code from the warning catalogue
 #[derive(Debug, Clone, Serialize)]
 pub struct ContractingWarning {
+    pub code: String,
     pub severity: WarningSeverity,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub affected_parameter: Option<String>,
 }
 
+impl ContractingWarning {
+    /// Flatten structured warnings into the legacy `warnings` field
+    pub fn messages(warnings: &[ContractingWarning]) -> Vec<String> {
+        warnings.iter().map(|w| w.message.clone()).collect()
+    }
+}
+
 /// Contracting calculation response
 #[derive(Debug, Serialize)]
 pub struct ContractingCalculationResponse {
@@ -277,6 +340,20 @@ pub struct ContractingCalculationResponse {
     pub calculation_metadata: Option<CalculationMetadata>,
 }
 
+impl ContractingCalculationResponse {
+    /// Re-express metric results in the request's unit system, refreshing
+    /// their display strings
+    pub fn express_in(&mut self, system: UnitSystem) {
+        let items = self.results.iter_mut().map(|r| (&mut r.value, &mut r.unit));
+        if system.results_from_metric(items) > 0 {
+            let policy = crate::utils::format::policy();
+            for item in self.results.iter_mut() {
+                item.formatted_value = Some(policy.format(item.value, &item.unit));
+            }
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct CalculationMetadata {
     pub timestamp: String,
@@ -346,6 +423,10 @@ pub struct ContractingCalculatorMetadata {
     pub complexity_level: Option<ComplexityLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub calculation_time: Option<String>, // e.g., "< 1s", "1-5s"
+
+    /// Worked requests with their results; see [`crate::calculus::examples`]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<CalculatorExample>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize)]
@@ -376,6 +457,10 @@ pub struct ContractingCalculatorCatalogue {
     
     #[serde(skip_serializing_if = "Option::is_none")]
     pub search_index: Option<SearchIndex>,
+
+    /// Formula changes per calculator id; unchanged calculators are omitted
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub changelog: BTreeMap<String, Vec<FormulaChange>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -499,6 +584,7 @@ impl MetadataBuilder {
             requires_certification_review: self.requires_certification_review,
             complexity_level: self.complexity_level,
             calculation_time: None,
+            examples: Vec::new(),
         }
     }
 }
@@ -0,0 +1,338 @@
+// ============================================================================
+// Subcontractor Prequalification
+//
+// Candidates are scored 0-10 on four criteria and combined with the
+// organization's weights:
+//
+// - financials:       current ratio (2.0 scores 10) and debt-to-equity
+//                     (0 scores 10, 3.0 or more scores 0), averaged
+// - safety:           EMR, 0.6 scores 10, 1.0 scores 5, 1.4 scores 0
+// - capacity:         bonding capacity left after backlog, as a multiple of
+//                     the project value; twice the value scores 10
+// - past performance: the 1-10 rating as given
+//
+// Minimums are pass/fail on the raw figures, not on the scores: a candidate
+// with an EMR over the limit fails however well it scores elsewhere. The
+// report ranks qualified candidates first, each group by weighted score.
+//
+// - saved.rs: per-organization scoring models
+// ============================================================================
+
+pub mod saved;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Weights {
+    pub financials: f64,
+    pub safety: f64,
+    pub capacity: f64,
+    pub past_performance: f64,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self { financials: 0.3, safety: 0.3, capacity: 0.2, past_performance: 0.2 }
+    }
+}
+
+impl Weights {
+    pub fn total(&self) -> f64 {
+        self.financials + self.safety + self.capacity + self.past_performance
+    }
+
+    fn is_valid(&self) -> bool {
+        let all = [self.financials, self.safety, self.capacity, self.past_performance];
+        all.iter().all(|w| w.is_finite() && *w >= 0.0) && self.total() > 0.0
+    }
+}
+
+/// Limits a candidate must meet to qualify; unset limits are not checked
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Minimums {
+    #[serde(default)]
+    pub min_current_ratio: Option<f64>,
+    #[serde(default)]
+    pub max_debt_to_equity: Option<f64>,
+    #[serde(default)]
+    pub max_emr: Option<f64>,
+    /// Bonding capacity left after backlog, as a multiple of the project value
+    #[serde(default)]
+    pub min_capacity_ratio: Option<f64>,
+    #[serde(default)]
+    pub min_past_performance: Option<f64>,
+    /// Weighted score out of 10
+    #[serde(default)]
+    pub min_score: Option<f64>,
+}
+
+impl Default for Minimums {
+    fn default() -> Self {
+        Self {
+            min_current_ratio: Some(1.2),
+            max_debt_to_equity: None,
+            max_emr: Some(1.0),
+            min_capacity_ratio: Some(1.0),
+            min_past_performance: None,
+            min_score: Some(6.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoringModel {
+    #[serde(default)]
+    pub weights: Weights,
+    #[serde(default)]
+    pub minimums: Minimums,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Candidate {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade: Option<String>,
+    pub current_ratio: f64,
+    pub debt_to_equity: f64,
+    /// Experience modification rate; 1.0 is the industry average
+    pub emr: f64,
+    pub bonding_capacity: f64,
+    /// Uncompleted work already under contract
+    #[serde(default)]
+    pub backlog: f64,
+    /// Rating on past work, 1-10
+    pub past_performance: f64,
+}
+
+/// Candidates to rank for one project
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Prequalification {
+    #[serde(default)]
+    pub model: ScoringModel,
+    pub project_value: f64,
+    pub candidates: Vec<Candidate>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct CriterionScores {
+    pub financials: f64,
+    pub safety: f64,
+    pub capacity: f64,
+    pub past_performance: f64,
+}
+
+/// A minimum the candidate did not meet
+#[derive(Debug, Clone, Serialize)]
+pub struct ThresholdFailure {
+    /// `current_ratio`, `debt_to_equity`, `emr`, `capacity_ratio`,
+    /// `past_performance` or `weighted_score`
+    pub criterion: &'static str,
+    pub value: f64,
+    pub limit: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedCandidate {
+    pub rank: usize,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade: Option<String>,
+    pub scores: CriterionScores,
+    pub weighted_score: f64,
+    pub capacity_ratio: f64,
+    pub qualified: bool,
+    pub failures: Vec<ThresholdFailure>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrequalReport {
+    pub project_value: f64,
+    pub model: ScoringModel,
+    pub qualified: usize,
+    pub ranked: Vec<RankedCandidate>,
+}
+
+fn clamp_score(score: f64) -> f64 {
+    score.clamp(0.0, 10.0)
+}
+
+impl Candidate {
+    fn capacity_ratio(&self, project_value: f64) -> f64 {
+        if project_value <= 0.0 {
+            return 0.0;
+        }
+        (self.bonding_capacity - self.backlog).max(0.0) / project_value
+    }
+
+    pub fn scores(&self, project_value: f64) -> CriterionScores {
+        let liquidity = clamp_score(self.current_ratio / 2.0 * 10.0);
+        let leverage = clamp_score((3.0 - self.debt_to_equity) / 3.0 * 10.0);
+        CriterionScores {
+            financials: (liquidity + leverage) / 2.0,
+            safety: clamp_score(10.0 - (self.emr - 0.6) * 12.5),
+            capacity: clamp_score(self.capacity_ratio(project_value) * 5.0),
+            past_performance: clamp_score(self.past_performance),
+        }
+    }
+}
+
+impl ScoringModel {
+    pub fn weighted_score(&self, scores: &CriterionScores) -> f64 {
+        let w = &self.weights;
+        let total = w.total();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        (scores.financials * w.financials
+            + scores.safety * w.safety
+            + scores.capacity * w.capacity
+            + scores.past_performance * w.past_performance)
+            / total
+    }
+
+    /// Reason the model cannot be used, if any
+    pub fn problem(&self) -> Option<&'static str> {
+        if !self.weights.is_valid() {
+            return Some("Weights must be non-negative with a positive total");
+        }
+        let m = &self.minimums;
+        let limits = [m.min_current_ratio, m.max_debt_to_equity, m.max_emr, m.min_capacity_ratio];
+        if limits.into_iter().flatten().any(|v| !v.is_finite() || v < 0.0) {
+            return Some("Minimums must be non-negative");
+        }
+        let ratings = [m.min_past_performance, m.min_score];
+        if ratings.into_iter().flatten().any(|v| !(0.0..=10.0).contains(&v)) {
+            return Some("Score minimums must be between 0 and 10");
+        }
+        None
+    }
+
+    fn failures(&self, candidate: &Candidate, capacity_ratio: f64, weighted_score: f64) -> Vec<ThresholdFailure> {
+        let m = &self.minimums;
+        let checks = [
+            ("current_ratio", candidate.current_ratio, m.min_current_ratio, false),
+            ("debt_to_equity", candidate.debt_to_equity, m.max_debt_to_equity, true),
+            ("emr", candidate.emr, m.max_emr, true),
+            ("capacity_ratio", capacity_ratio, m.min_capacity_ratio, false),
+            ("past_performance", candidate.past_performance, m.min_past_performance, false),
+            ("weighted_score", weighted_score, m.min_score, false),
+        ];
+        checks
+            .into_iter()
+            .filter_map(|(criterion, value, limit, is_max)| {
+                let limit = limit?;
+                let fails = if is_max { value > limit } else { value < limit };
+                fails.then_some(ThresholdFailure { criterion, value, limit })
+            })
+            .collect()
+    }
+}
+
+impl Prequalification {
+    pub fn report(&self) -> PrequalReport {
+        let mut ranked: Vec<RankedCandidate> = self
+            .candidates
+            .iter()
+            .map(|candidate| {
+                let scores = candidate.scores(self.project_value);
+                let weighted_score = self.model.weighted_score(&scores);
+                let capacity_ratio = candidate.capacity_ratio(self.project_value);
+                let failures = self.model.failures(candidate, capacity_ratio, weighted_score);
+                RankedCandidate {
+                    rank: 0,
+                    name: candidate.name.clone(),
+                    trade: candidate.trade.clone(),
+                    scores,
+                    weighted_score,
+                    capacity_ratio,
+                    qualified: failures.is_empty(),
+                    failures,
+                }
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.qualified
+                .cmp(&a.qualified)
+                .then_with(|| b.weighted_score.total_cmp(&a.weighted_score))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        for (index, candidate) in ranked.iter_mut().enumerate() {
+            candidate.rank = index + 1;
+        }
+
+        PrequalReport {
+            project_value: self.project_value,
+            model: self.model,
+            qualified: ranked.iter().filter(|c| c.qualified).count(),
+            ranked,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(name: &str, emr: f64, bonding: f64, past: f64) -> Candidate {
+        Candidate {
+            name: name.to_string(),
+            trade: None,
+            current_ratio: 2.0,
+            debt_to_equity: 0.0,
+            emr,
+            bonding_capacity: bonding,
+            backlog: 0.0,
+            past_performance: past,
+        }
+    }
+
+    #[test]
+    fn test_scores() {
+        let scores = candidate("A", 1.0, 2_000_000.0, 7.0).scores(1_000_000.0);
+        assert!((scores.financials - 10.0).abs() < 1e-9);
+        assert!((scores.safety - 5.0).abs() < 1e-9);
+        assert!((scores.capacity - 10.0).abs() < 1e-9);
+
+        let model = ScoringModel::default();
+        // 0.3 * 10 + 0.3 * 5 + 0.2 * 10 + 0.2 * 7
+        assert!((model.weighted_score(&scores) - 7.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_failures_rank_below_qualified() {
+        let prequal = Prequalification {
+            model: ScoringModel::default(),
+            project_value: 1_000_000.0,
+            candidates: vec![
+                candidate("Unsafe", 1.3, 5_000_000.0, 10.0),
+                candidate("Steady", 0.9, 1_500_000.0, 7.0),
+                candidate("Best", 0.7, 2_000_000.0, 9.0),
+            ],
+        };
+        let report = prequal.report();
+        assert_eq!(report.qualified, 2);
+        assert_eq!(report.ranked[0].name, "Best");
+        assert_eq!(report.ranked[1].name, "Steady");
+
+        let unsafe_ = &report.ranked[2];
+        assert_eq!(unsafe_.rank, 3);
+        assert!(!unsafe_.qualified);
+        assert_eq!(unsafe_.failures.len(), 1);
+        assert_eq!(unsafe_.failures[0].criterion, "emr");
+    }
+
+    #[test]
+    fn test_model_problems() {
+        assert!(ScoringModel::default().problem().is_none());
+
+        let zero = ScoringModel {
+            weights: Weights { financials: 0.0, safety: 0.0, capacity: 0.0, past_performance: 0.0 },
+            ..Default::default()
+        };
+        assert!(zero.problem().is_some());
+
+        let mut bad_minimum = ScoringModel::default();
+        bad_minimum.minimums.min_score = Some(12.0);
+        assert!(bad_minimum.problem().is_some());
+    }
+}
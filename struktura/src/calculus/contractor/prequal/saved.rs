@@ -0,0 +1,322 @@
+//! Saved scoring models
+//!
+//! Each organization keeps its own prequalification weights and minimums.
+//! At most one model per organization is the default; marking another one
+//! default clears the flag on the rest.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::{PgPool, Postgres};
+use sqlx::types::time::OffsetDateTime;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use super::{Minimums, ScoringModel, Weights};
+use crate::sec::{self, AppError};
+use crate::state::AppState;
+use crate::tenancy::{TenantOwned, TenantScope};
+
+// ============================================================================
+// Models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SavedScoringModel {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub name: String,
+    pub is_default: bool,
+    #[serde(flatten)]
+    pub model: ScoringModel,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(sqlx::FromRow)]
+struct ModelRow {
+    id: Uuid,
+    org_id: Uuid,
+    name: String,
+    is_default: bool,
+    weight_financials: f64,
+    weight_safety: f64,
+    weight_capacity: f64,
+    weight_past_performance: f64,
+    min_current_ratio: Option<f64>,
+    max_debt_to_equity: Option<f64>,
+    max_emr: Option<f64>,
+    min_capacity_ratio: Option<f64>,
+    min_past_performance: Option<f64>,
+    min_score: Option<f64>,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+}
+
+impl From<ModelRow> for SavedScoringModel {
+    fn from(row: ModelRow) -> Self {
+        Self {
+            id: row.id,
+            org_id: row.org_id,
+            name: row.name,
+            is_default: row.is_default,
+            model: ScoringModel {
+                weights: Weights {
+                    financials: row.weight_financials,
+                    safety: row.weight_safety,
+                    capacity: row.weight_capacity,
+                    past_performance: row.weight_past_performance,
+                },
+                minimums: Minimums {
+                    min_current_ratio: row.min_current_ratio,
+                    max_debt_to_equity: row.max_debt_to_equity,
+                    max_emr: row.max_emr,
+                    min_capacity_ratio: row.min_capacity_ratio,
+                    min_past_performance: row.min_past_performance,
+                    min_score: row.min_score,
+                },
+            },
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+impl TenantOwned for SavedScoringModel {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[validate(schema(function = "validate_model"))]
+pub struct ScoringModelPayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[serde(default)]
+    pub is_default: bool,
+    #[serde(flatten)]
+    pub model: ScoringModel,
+}
+
+fn validate_model(payload: &ScoringModelPayload) -> Result<(), ValidationError> {
+    match payload.model.problem() {
+        Some(problem) => Err(ValidationError::new("invalid_model").with_message(problem.into())),
+        None => Ok(()),
+    }
+}
+
+const MODEL_COLUMNS: &str = "id, org_id, name, is_default, weight_financials, weight_safety, weight_capacity, \
+    weight_past_performance, min_current_ratio, max_debt_to_equity, max_emr, min_capacity_ratio, \
+    min_past_performance, min_score, created_at, updated_at";
+
+// ============================================================================
+// Engine lookup
+// ============================================================================
+
+/// The named model, else the organization's default, else the built-in one
+pub async fn load_model(pool: &PgPool, scope: &TenantScope, id: Option<Uuid>) -> Result<ScoringModel, AppError> {
+    let row = sqlx::query_as::<_, ModelRow>(&format!(
+        "SELECT {MODEL_COLUMNS} FROM prequal_models \
+         WHERE org_id = $1 AND (id = $2 OR ($2 IS NULL AND is_default))"
+    ))
+    .bind(scope.org_id)
+    .bind(id)
+    .fetch_optional(pool)
+    .await?;
+
+    match (row, id) {
+        (Some(row), _) => Ok(scope.guard(SavedScoringModel::from(row))?.model),
+        (None, Some(_)) => Err(AppError::UserNotFound),
+        (None, None) => Ok(ScoringModel::default()),
+    }
+}
+
+/// Clear the default flag on the organization's other models
+async fn clear_default<'c, E>(executor: E, scope: &TenantScope, keep: Option<Uuid>) -> Result<(), AppError>
+where
+    E: sqlx::Executor<'c, Database = Postgres>,
+{
+    sqlx::query("UPDATE prequal_models SET is_default = FALSE WHERE org_id = $1 AND is_default AND id IS DISTINCT FROM $2")
+        .bind(scope.org_id)
+        .bind(keep)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+pub async fn list_models_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+) -> Result<Json<Vec<SavedScoringModel>>, AppError> {
+    let rows = sqlx::query_as::<_, ModelRow>(&format!(
+        "SELECT {MODEL_COLUMNS} FROM prequal_models WHERE org_id = $1 ORDER BY is_default DESC, name"
+    ))
+    .bind(scope.org_id)
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    Ok(Json(scope.guard_all(rows.into_iter().map(SavedScoringModel::from).collect())?))
+}
+
+pub async fn get_model_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<SavedScoringModel>, AppError> {
+    let row = sqlx::query_as::<_, ModelRow>(&format!(
+        "SELECT {MODEL_COLUMNS} FROM prequal_models WHERE id = $1 AND org_id = $2"
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    Ok(Json(scope.guard(SavedScoringModel::from(row))?))
+}
+
+pub async fn create_model_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<ScoringModelPayload>,
+) -> Result<(StatusCode, Json<SavedScoringModel>), AppError> {
+    payload.validate()?;
+    let (w, m) = (&payload.model.weights, &payload.model.minimums);
+
+    let mut tx = app_state.pool.begin().await?;
+    if payload.is_default {
+        clear_default(&mut *tx, &scope, None).await?;
+    }
+    let row = sqlx::query_as::<_, ModelRow>(&format!(
+        r#"
+        INSERT INTO prequal_models
+            (org_id, name, is_default, weight_financials, weight_safety, weight_capacity,
+             weight_past_performance, min_current_ratio, max_debt_to_equity, max_emr,
+             min_capacity_ratio, min_past_performance, min_score, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+        RETURNING {MODEL_COLUMNS}
+        "#
+    ))
+    .bind(scope.org_id)
+    .bind(&payload.name)
+    .bind(payload.is_default)
+    .bind(w.financials)
+    .bind(w.safety)
+    .bind(w.capacity)
+    .bind(w.past_performance)
+    .bind(m.min_current_ratio)
+    .bind(m.max_debt_to_equity)
+    .bind(m.max_emr)
+    .bind(m.min_capacity_ratio)
+    .bind(m.min_past_performance)
+    .bind(m.min_score)
+    .bind(scope.user_id)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    sec::log_security_event("PREQUAL_MODEL_CREATE", Some(&scope.username), None, "Success");
+
+    Ok((StatusCode::CREATED, Json(row.into())))
+}
+
+pub async fn update_model_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ScoringModelPayload>,
+) -> Result<Json<SavedScoringModel>, AppError> {
+    payload.validate()?;
+    let (w, m) = (&payload.model.weights, &payload.model.minimums);
+
+    let mut tx = app_state.pool.begin().await?;
+    if payload.is_default {
+        clear_default(&mut *tx, &scope, Some(id)).await?;
+    }
+    let row = sqlx::query_as::<_, ModelRow>(&format!(
+        r#"
+        UPDATE prequal_models
+        SET name = $3, is_default = $4, weight_financials = $5, weight_safety = $6,
+            weight_capacity = $7, weight_past_performance = $8, min_current_ratio = $9,
+            max_debt_to_equity = $10, max_emr = $11, min_capacity_ratio = $12,
+            min_past_performance = $13, min_score = $14, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1 AND org_id = $2
+        RETURNING {MODEL_COLUMNS}
+        "#
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .bind(&payload.name)
+    .bind(payload.is_default)
+    .bind(w.financials)
+    .bind(w.safety)
+    .bind(w.capacity)
+    .bind(w.past_performance)
+    .bind(m.min_current_ratio)
+    .bind(m.max_debt_to_equity)
+    .bind(m.max_emr)
+    .bind(m.min_capacity_ratio)
+    .bind(m.min_past_performance)
+    .bind(m.min_score)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+    tx.commit().await?;
+
+    sec::log_security_event("PREQUAL_MODEL_UPDATE", Some(&scope.username), None, "Success");
+
+    Ok(Json(row.into()))
+}
+
+pub async fn delete_model_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let result = sqlx::query("DELETE FROM prequal_models WHERE id = $1 AND org_id = $2")
+        .bind(id)
+        .bind(scope.org_id)
+        .execute(&app_state.pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("PREQUAL_MODEL_DELETE", Some(&scope.username), None, "Success");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_flattens_model() {
+        let payload: ScoringModelPayload = serde_json::from_value(serde_json::json!({
+            "name": "Heavy civil",
+            "is_default": true,
+            "weights": { "financials": 0.2, "safety": 0.5, "capacity": 0.2, "past_performance": 0.1 },
+            "minimums": { "max_emr": 0.9, "min_score": 7.0 },
+        }))
+        .unwrap();
+        assert!(payload.validate().is_ok());
+        assert_eq!(payload.model.minimums.max_emr, Some(0.9));
+        assert_eq!(payload.model.minimums.min_current_ratio, None);
+
+        let mut negative = payload;
+        negative.model.weights.safety = -1.0;
+        assert!(negative.validate().is_err());
+    }
+}
@@ -0,0 +1,60 @@
+//! Pieces shared by the persisted site records (punch lists, field reports)
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use sqlx::types::time::Date;
+use time::Month;
+use validator::{ValidateUrl, ValidationError};
+
+/// Most photos recorded against a single record
+pub const MAX_PHOTOS: usize = 20;
+
+/// A photo documenting site conditions; the image itself lives in file storage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhotoMetadata {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taken_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub fn validate_photos(photos: &[PhotoMetadata]) -> Result<(), ValidationError> {
+    if photos.len() > MAX_PHOTOS {
+        return Err(ValidationError::new("too many photos"));
+    }
+    if photos.iter().all(|p| p.url.validate_url()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("photo url"))
+    }
+}
+
+/// Calculators work in chrono dates, the database driver in `time` dates
+pub fn to_naive(date: Date) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(date.year(), u8::from(date.month()) as u32, date.day() as u32)
+}
+
+pub fn to_date(date: NaiveDate) -> Option<Date> {
+    let month = Month::try_from(date.month() as u8).ok()?;
+    Date::from_calendar_date(date.year(), month, date.day() as u8).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_conversion_round_trip() {
+        let day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(to_date(day).and_then(to_naive), Some(day));
+    }
+
+    #[test]
+    fn test_photo_validation() {
+        let photo = |url: &str| PhotoMetadata { url: url.to_string(), caption: None, taken_at: None };
+        assert!(validate_photos(&[photo("https://files.example.com/1.jpg")]).is_ok());
+        assert!(validate_photos(&[photo("not a url")]).is_err());
+        assert!(validate_photos(&vec![photo("https://files.example.com/1.jpg"); MAX_PHOTOS + 1]).is_err());
+    }
+}
@@ -3,8 +3,15 @@ use crate::calculus::contractor::{
     models::*,
     traits::{CalculatorRegistry, ContractorCalculator},
 };
+use crate::calculus::changelog;
+use crate::calculus::examples;
+use crate::calculus::provenance::{self, AssumptionLedger, DeclaredParameter};
+use crate::calculus::recommendation::Tier;
+use crate::calculus::sanitize;
+use crate::utils::format;
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::Instrument;
 
 /// Thread-safe calculator registry with tactical precision
 #[derive(Clone)]
@@ -55,6 +62,75 @@ impl ContractingRegistry {
             .ok_or_else(|| ContractingError::CalculatorNotFound(id.to_string()))
     }
 
+    /// Preprocess, validate and run a calculation, then sanitize its results
+    ///
+    /// Non-finite result values fail the calculation; implausibly large ones
+    /// are served with a warning. Either is logged with the inputs. Every
+    /// result's `formatted_value` is rebuilt from the number format policy.
+    pub async fn execute(
+        &self,
+        calculator: &Arc<dyn ContractorCalculator>,
+        params: ContractingParameters,
+    ) -> ContractingResult<ContractingCalculationResponse> {
+        self.execute_with_provenance(calculator, params).await.map(|(response, _)| response)
+    }
+
+    /// `execute`, also returning where each declared parameter came from
+    ///
+    /// Values filled in by `preprocess` from attached project data are
+    /// recorded as inferred.
+    #[tracing::instrument(name = "calculator", skip_all, fields(tier = Tier::Contractor.as_str(), calculator = calculator.id()))]
+    pub async fn execute_with_provenance(
+        &self,
+        calculator: &Arc<dyn ContractorCalculator>,
+        mut params: ContractingParameters,
+    ) -> ContractingResult<(ContractingCalculationResponse, AssumptionLedger)> {
+        let requested = serde_json::to_value(&params).unwrap_or_default();
+        tracing::info_span!("preprocess").in_scope(|| calculator.preprocess(&mut params))?;
+        tracing::info_span!("validate").in_scope(|| calculator.validate(&params))?;
+        let inputs = params.clone();
+        let mut response = calculator.calculate(params).instrument(tracing::info_span!("calculate")).await?;
+
+        let findings = sanitize::Findings::inspect(response.results.iter().map(|r| (r.label.as_str(), r.value)));
+        findings.log(Tier::Contractor, calculator.id(), &inputs);
+        if let Some(message) = findings.error() {
+            return Err(ContractingError::NumericalError(message));
+        }
+        for message in findings.warnings() {
+            response
+                .structured_warnings
+                .get_or_insert_with(Vec::new)
+                .push(ContractingWarning {
+                    code: sanitize::IMPLAUSIBLE_MAGNITUDE_CODE.to_string(),
+                    severity: WarningSeverity::High,
+                    message: message.clone(),
+                    affected_parameter: None,
+                });
+            response.warnings.push(message);
+        }
+
+        // Display strings follow the crate-wide rounding policy
+        let policy = format::policy();
+        for item in response.results.iter_mut() {
+            item.formatted_value = Some(policy.format(item.value, &item.unit));
+        }
+
+        let metadata = calculator.metadata();
+        let resolved = serde_json::to_value(&inputs).unwrap_or_default();
+        let ledger = provenance::parameter_ledger(
+            metadata.parameters.iter().map(|p| DeclaredParameter {
+                path: &p.path,
+                name: &p.name,
+                required: p.required,
+                default_value: p.default_value,
+            }),
+            &requested,
+            &resolved,
+        );
+
+        Ok((response, ledger))
+    }
+
     /// Get all calculators (for catalogue generation)
     pub fn all(&self) -> Vec<Arc<dyn ContractorCalculator>> {
         self.calculators.values().cloned().collect()
@@ -126,7 +202,11 @@ impl ContractingRegistry {
         let calculators: Vec<ContractingCalculatorMetadata> = self
             .all()
             .iter()
-            .map(|calc| calc.metadata())
+            .map(|calc| {
+                let mut metadata = calc.metadata();
+                metadata.examples = examples::for_calculator(Tier::Contractor, calc.id());
+                metadata
+            })
             .collect();
 
         // Build search index
@@ -171,6 +251,7 @@ impl ContractingRegistry {
                         contractor before implementation."
                 .to_string(),
             search_index: Some(SearchIndex { tags, keywords }),
+            changelog: changelog::for_tier(Tier::Contractor),
         }
     }
 
@@ -289,6 +370,8 @@ pub fn create_default_registry() -> ContractingRegistry {
         .with_calculator(Arc::new(calculators::estimation::LaborCostEstimator))
         .with_calculator(Arc::new(calculators::estimation::MaterialCostEstimator))
         .with_calculator(Arc::new(calculators::estimation::EquipmentCostEstimator))
+        .with_calculator(Arc::new(calculators::estimation::TravelCostEstimator))
+        .with_calculator(Arc::new(calculators::estimation::FuelEmissionsCalculator))
         .with_calculator(Arc::new(calculators::estimation::OverheadCalculator))
         .with_calculator(Arc::new(calculators::estimation::BudgetForecastCalculator))
         .with_calculator(Arc::new(calculators::estimation::ValueEngineeringCalculator))
@@ -344,6 +427,7 @@ mod tests {
                 parameters: vec![],
                 required_parameters: vec![],
                 optional_parameters: vec![],
+                examples: Vec::new(),
                 typical_applications: vec![],
                 requires_certification_review: false,
                 complexity_level: None,
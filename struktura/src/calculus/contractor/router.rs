@@ -1,7 +1,23 @@
 use crate::calculus::contractor::{
+    calculators::{
+        management::{ProjectCloseoutCalculator, QualityControlCalculator, SubcontractorEvaluationCalculator},
+        scheduling::{DelayAnalysisCalculator, GanttChartGenerator},
+    },
+    closeout::{saved as punch_lists, CompletionReport},
+    field_log::{saved as field_reports, FieldLog, FieldLogSummary},
+    inspections::{self, saved as saved_inspections, Checklist, ProjectScope, Trade, TradeQuality},
     errors::ContractingError,
     models::*,
+    prequal::{saved as prequal_models, Candidate, PrequalReport, Prequalification},
     registry::ContractingRegistry,
+    schedule::{
+        gantt::GanttChart,
+        import::{self, ColumnMapping, ImportFormat},
+        windows::{self, WindowAnalysis},
+        CriticalPathAnalysis, Schedule,
+    },
+    traits::ContractorCalculator,
+    warnings::{self, WarningCode},
 };
 use axum::{
     extract::{Path, Query, State},
@@ -12,7 +28,18 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use crate::calculus::changelog;
+use crate::calculus::examples;
+use crate::defaults::{self, WithAssumptions};
+use crate::calculus::links::{self, Hal};
+use crate::calculus::recommendation::Tier;
+use crate::flags::FlagContext;
+use crate::pricing;
+use crate::sec::Claims;
 use crate::state::AppState;
+use crate::utils::negotiate::{Negotiated, ResponseFormat};
+use crate::tenancy::TenantScope;
+use uuid::Uuid;
 
 /// Application state containing the calculator registry
 #[derive(Clone)]
@@ -37,6 +64,130 @@ pub struct CatalogueQuery {
     certification_required: Option<bool>,
 }
 
+/// Query parameters for warning catalogue endpoint
+#[derive(Debug, Deserialize)]
+pub struct WarningCatalogueQuery {
+    /// Filter by calculator ID
+    calculator: Option<String>,
+}
+
+/// Request body for a schedule import
+#[derive(Debug, Deserialize)]
+pub struct ScheduleImportRequest {
+    pub format: ImportFormat,
+    /// The exported file, as text
+    pub content: String,
+    /// CSV header names; the usual MS Project / P6 headers by default
+    #[serde(default)]
+    pub mapping: ColumnMapping,
+    /// Scheduling calculator to run on the imported schedule
+    #[serde(default)]
+    pub calculation_type: Option<String>,
+    /// Further inputs for that calculator, e.g. `additional.available_resources`
+    #[serde(default)]
+    pub parameters: Option<ContractingParameters>,
+}
+
+/// Imported schedule with its critical path
+#[derive(Serialize)]
+pub struct ScheduleImportResponse {
+    pub schedule: Schedule,
+    pub analysis: CriticalPathAnalysis,
+    /// Rows and links skipped during import
+    pub warnings: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calculation: Option<ContractingCalculationResponse>,
+}
+
+/// Query parameters for the Gantt endpoint
+#[derive(Debug, Deserialize)]
+pub struct GanttQuery {
+    /// `svg` to include a rendered chart alongside the bars
+    render: Option<String>,
+}
+
+/// Gantt bars, optionally with the rendered chart
+#[derive(Serialize)]
+pub struct GanttResponse {
+    pub chart: GanttChart,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub svg: Option<String>,
+}
+
+/// Closeout readiness of a saved punch list
+#[derive(Serialize)]
+pub struct CloseoutResponse {
+    pub report: CompletionReport,
+    pub calculation: ContractingCalculationResponse,
+}
+
+/// Calculators that read a field log
+const FIELD_LOG_CALCULATORS: &[&str] = &["progress_tracking", "delay_analysis"];
+
+/// Request body for a calculation on a project's saved field reports
+#[derive(Debug, Deserialize)]
+pub struct FieldLogCalculationRequest {
+    pub project_name: String,
+    /// `progress_tracking` or `delay_analysis`
+    pub calculation_type: String,
+    /// Only reports from this date on
+    #[serde(default)]
+    pub from: Option<chrono::NaiveDate>,
+    /// Only reports up to this date
+    #[serde(default)]
+    pub to: Option<chrono::NaiveDate>,
+    /// Planned window the reports are measured against
+    #[serde(default)]
+    pub planned_start: Option<chrono::NaiveDate>,
+    #[serde(default)]
+    pub planned_finish: Option<chrono::NaiveDate>,
+    /// Further inputs, e.g. `additional.budget_at_completion`
+    #[serde(default)]
+    pub parameters: Option<ContractingParameters>,
+}
+
+/// Field log summary with the calculation run on it
+#[derive(Serialize)]
+pub struct FieldLogCalculationResponse {
+    pub summary: FieldLogSummary,
+    pub calculation: ContractingCalculationResponse,
+}
+
+/// Request body for `quality_control` on a project's saved inspections
+#[derive(Debug, Deserialize)]
+pub struct QualityCalculationRequest {
+    pub project_name: String,
+    /// Only inspections of this trade
+    #[serde(default)]
+    pub trade: Option<Trade>,
+    #[serde(default)]
+    pub parameters: Option<ContractingParameters>,
+}
+
+/// First-time quality by trade with the calculation run on it
+#[derive(Serialize)]
+pub struct QualityCalculationResponse {
+    pub quality: Vec<TradeQuality>,
+    pub calculation: ContractingCalculationResponse,
+}
+
+/// Request body for ranking subcontractors with a saved scoring model
+#[derive(Debug, Deserialize)]
+pub struct PrequalRequest {
+    /// Saved model to score with; the organization's default when omitted
+    #[serde(default)]
+    pub model_id: Option<Uuid>,
+    pub project_value: f64,
+    pub candidates: Vec<Candidate>,
+}
+
+/// Ranked prequalification report with the calculation run on it
+#[derive(Serialize)]
+pub struct PrequalResponse {
+    pub report: PrequalReport,
+    pub calculation: ContractingCalculationResponse,
+}
+
 /// Health check response
 #[derive(Serialize)]
 pub struct HealthResponse {
@@ -53,28 +204,360 @@ pub struct HealthResponse {
 /// Execute a contracting calculation
 async fn calculate_handler(
     State(state): State<Arc<AppState>>,
-    Json(payload): Json<ContractingCalculationRequest>,
-) -> Result<Json<ContractingCalculationResponse>, ContractingError> {
+    claims: Option<Claims>,
+    format: ResponseFormat,
+    Json(mut payload): Json<ContractingCalculationRequest>,
+) -> Result<Negotiated<Hal<WithAssumptions<ContractingCalculationResponse>>>, ContractingError> {
     // Find calculator in registry
     let calculator = state.calculators_contractor.find(&payload.calculation_type)?;
 
-    // Validate parameters
-    calculator.validate(&payload.parameters)?;
+    // Calculators behind a rollout flag look missing to callers outside it
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Contractor, calculator.id(), &ctx).await {
+        return Err(ContractingError::CalculatorNotFound(payload.calculation_type));
+    }
+
+    // Imperial requests run in the metric units calculators work in
+    let system = payload.parameters.unit_system;
+    let metadata = calculator.metadata();
+    payload.parameters = system
+        .parameters_to_metric(payload.parameters, metadata.parameters.iter().map(|p| (p.path.as_str(), p.unit.as_str())))
+        .map_err(|e| ContractingError::InvalidParameter {
+            parameter: "unit_system".to_string(),
+            value: system.as_str().to_string(),
+            reason: e.to_string(),
+        })?;
+
+    // Fill unset material values from the caller's saved library
+    if let Some(material) = payload.parameters.material.as_mut()
+        && let Some(id) = material.material_id.clone()
+    {
+        crate::materials::lookup(&state, claims.as_ref(), &id).await?
+            .apply_to_contracting(material);
+    }
+
+    // Merge the caller's default assumptions under what the request supplied
+    let accepted: Vec<&str> = metadata.parameters.iter().map(|p| p.path.as_str()).collect();
+    let assumptions = defaults::for_caller(&state, claims.as_ref())
+        .await
+        .apply_to_contracting(&mut payload.parameters, &accepted);
+
+    // Swap bundled costs for regional prices when the caller asks
+    if payload.parameters.use_live_pricing {
+        let Some(location) = payload.parameters.location.as_ref() else {
+            return Err(ContractingError::MissingParameter {
+                parameter: "location".to_string(),
+                calculator: calculator.id().to_string(),
+            });
+        };
+        let materials = calculator.priced_materials(&payload.parameters);
+        // Supplier quotes of the caller's organization outrank scouted prices
+        let org_id = match claims.as_ref() {
+            Some(claims) => state.tenants.resolve(&state.pool, claims).await.ok().map(|scope| scope.org_id),
+            None => None,
+        };
+        payload.parameters.live_prices = pricing::live::resolve(&state.pricing, location, org_id, materials).await;
+    }
+    let mut pricing_warnings = payload.parameters.live_prices.warnings.clone();
+
+    // Place the yard and jobsite on the map for calculators measuring travel
+    if calculator.needs_coordinates()
+        && let Some(geocoder) = state.pricing.geocoder()
+    {
+        let parameters = &mut payload.parameters;
+        for location in [parameters.origin.as_mut(), parameters.location.as_mut()].into_iter().flatten() {
+            if let Err(e) = pricing::geocoding::place(geocoder, location).await {
+                tracing::warn!(country = %location.country_code, error = %e, "geocoding failed");
+                pricing_warnings.push(format!("Address lookup unavailable ({}); send coordinates instead", e));
+            }
+        }
+    }
+
+    // Validate, execute and sanitize the results
+    let (mut response, provenance) = state.calculators_contractor.execute_with_provenance(&calculator, payload.parameters).await?;
+    response.warnings.extend(pricing_warnings);
+
+    // Saved results carry the formula version so later changes can be surfaced
+    if let Some(metadata) = response.calculation_metadata.as_mut() {
+        metadata.calculator_version = changelog::formula_version(Tier::Contractor, calculator.id()).to_string();
+    }
+    response.express_in(system);
+
+    let related = related_calculators(&state, claims.as_ref(), calculator.id()).await;
+    let links = links::calculation(Tier::Contractor, calculator.id(), related);
+    Ok(Negotiated(format, Hal::new(WithAssumptions::new(response, assumptions, provenance), links)))
+}
+
+/// POST /api/v1/calculus/contractor/schedule/import
+/// Parse an MS Project / Primavera export and optionally run a scheduling
+/// calculator on it
+async fn schedule_import_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Json(payload): Json<ScheduleImportRequest>,
+) -> Result<Json<ScheduleImportResponse>, ContractingError> {
+    let imported = import::import(payload.format, &payload.content, &payload.mapping)
+        .map_err(|message| ContractingError::DomainError { field: "content".to_string(), message })?;
+    let analysis = imported
+        .schedule
+        .analyze()
+        .map_err(|message| ContractingError::DomainError { field: "schedule".to_string(), message })?;
+
+    let calculation = match payload.calculation_type {
+        Some(calculation_type) => {
+            let calculator = state.calculators_contractor.find(&calculation_type)?;
+            let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+            if !state.flags.calculator_enabled(&state.pool, Tier::Contractor, calculator.id(), &ctx).await {
+                return Err(ContractingError::CalculatorNotFound(calculation_type));
+            }
+
+            let mut parameters = payload.parameters.unwrap_or_default();
+            parameters.schedule = Some(imported.schedule.clone());
+            let mut response = state.calculators_contractor.execute(&calculator, parameters).await?;
+            if let Some(metadata) = response.calculation_metadata.as_mut() {
+                metadata.calculator_version = changelog::formula_version(Tier::Contractor, calculator.id()).to_string();
+            }
+            Some(response)
+        }
+        None => None,
+    };
+
+    Ok(Json(ScheduleImportResponse {
+        schedule: imported.schedule,
+        analysis,
+        warnings: imported.warnings,
+        calculation,
+    }))
+}
+
+/// POST /api/v1/calculus/contractor/gantt
+/// Lay out Gantt bars for `gantt_chart` parameters, optionally rendered
+async fn gantt_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Query(query): Query<GanttQuery>,
+    Json(mut parameters): Json<ContractingParameters>,
+) -> Result<Json<GanttResponse>, ContractingError> {
+    let generator = GanttChartGenerator;
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Contractor, generator.id(), &ctx).await {
+        return Err(ContractingError::CalculatorNotFound(generator.id().to_string()));
+    }
+
+    let render_svg = match query.render.as_deref() {
+        None | Some("none") => false,
+        Some("svg") => true,
+        Some(other) => {
+            return Err(ContractingError::InvalidParameter {
+                parameter: "render".to_string(),
+                value: other.to_string(),
+                reason: "Supported renderings: svg".to_string(),
+            })
+        }
+    };
+
+    generator.preprocess(&mut parameters)?;
+    generator.validate(&parameters)?;
+    let chart = generator.chart(&parameters)?;
+    let svg = render_svg.then(|| chart.to_svg());
+    Ok(Json(GanttResponse { chart, svg }))
+}
+
+/// POST /api/v1/calculus/contractor/delay-analysis/windows
+/// Claim-ready windows analysis of `delay_analysis` parameters carrying a
+/// schedule with baselines and actual dates
+async fn delay_windows_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Json(parameters): Json<ContractingParameters>,
+) -> Result<Json<WindowAnalysis>, ContractingError> {
+    let calculator = DelayAnalysisCalculator;
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Contractor, calculator.id(), &ctx).await {
+        return Err(ContractingError::CalculatorNotFound(calculator.id().to_string()));
+    }
+
+    windows::analyze_parameters(&parameters)?
+        .map(Json)
+        .ok_or_else(|| ContractingError::MissingParameter {
+            parameter: "schedule".to_string(),
+            calculator: calculator.id().to_string(),
+        })
+}
+
+/// GET /api/v1/calculus/contractor/closeout/{punch_list_id}
+/// Run `project_closeout` on a saved punch list as it stands
+async fn closeout_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    claims: Option<Claims>,
+    Path(punch_list_id): Path<Uuid>,
+) -> Response {
+    let calculator = match state.calculators_contractor.find(ProjectCloseoutCalculator.id()) {
+        Ok(calculator) => calculator,
+        Err(e) => return e.into_response(),
+    };
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Contractor, calculator.id(), &ctx).await {
+        return ContractingError::CalculatorNotFound(calculator.id().to_string()).into_response();
+    }
 
-    // Execute calculation
-    let response = calculator.calculate(payload.parameters).await?;
+    let list = match punch_lists::load_punch_list(&state.pool, &scope, punch_list_id).await {
+        Ok(list) => list,
+        Err(e) => return e.into_response(),
+    };
+    let report = list.report(chrono::Utc::now().date_naive());
+    let parameters = ContractingParameters { punch_list: Some(list), ..Default::default() };
 
-    Ok(Json(response))
+    match state.calculators_contractor.execute(&calculator, parameters).await {
+        Ok(mut calculation) => {
+            if let Some(metadata) = calculation.calculation_metadata.as_mut() {
+                metadata.calculator_version = changelog::formula_version(Tier::Contractor, calculator.id()).to_string();
+            }
+            Json(CloseoutResponse { report, calculation }).into_response()
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+/// POST /api/v1/calculus/contractor/field-log/calculate
+/// Run `progress_tracking` or `delay_analysis` on a project's saved field reports
+async fn field_log_calculate_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    claims: Option<Claims>,
+    Json(payload): Json<FieldLogCalculationRequest>,
+) -> Response {
+    if !FIELD_LOG_CALCULATORS.contains(&payload.calculation_type.as_str()) {
+        return ContractingError::InvalidParameter {
+            parameter: "calculation_type".to_string(),
+            value: payload.calculation_type,
+            reason: format!("Calculators that read field reports: {}", FIELD_LOG_CALCULATORS.join(", ")),
+        }
+        .into_response();
+    }
+    let calculator = match state.calculators_contractor.find(&payload.calculation_type) {
+        Ok(calculator) => calculator,
+        Err(e) => return e.into_response(),
+    };
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Contractor, calculator.id(), &ctx).await {
+        return ContractingError::CalculatorNotFound(payload.calculation_type).into_response();
+    }
+
+    let reports = match field_reports::load_reports(&state.pool, &scope, &payload.project_name, payload.from, payload.to).await {
+        Ok(reports) => reports,
+        Err(e) => return e.into_response(),
+    };
+    let log = FieldLog { planned_start: payload.planned_start, planned_finish: payload.planned_finish, reports };
+    let summary = log.summary();
+    let mut parameters = payload.parameters.unwrap_or_default();
+    parameters.field_log = Some(log);
+
+    match state.calculators_contractor.execute(&calculator, parameters).await {
+        Ok(mut calculation) => {
+            if let Some(metadata) = calculation.calculation_metadata.as_mut() {
+                metadata.calculator_version = changelog::formula_version(Tier::Contractor, calculator.id()).to_string();
+            }
+            Json(FieldLogCalculationResponse { summary, calculation }).into_response()
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+/// POST /api/v1/calculus/contractor/inspections/checklists
+/// Generate the inspection checklist of each trade in scope
+async fn inspection_checklists_handler(Json(scope): Json<ProjectScope>) -> Json<Vec<Checklist>> {
+    Json(scope.checklists())
+}
+
+/// POST /api/v1/calculus/contractor/quality/calculate
+/// Run `quality_control` on a project's saved inspections
+async fn quality_calculate_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    claims: Option<Claims>,
+    Json(payload): Json<QualityCalculationRequest>,
+) -> Response {
+    let calculator = match state.calculators_contractor.find(QualityControlCalculator.id()) {
+        Ok(calculator) => calculator,
+        Err(e) => return e.into_response(),
+    };
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Contractor, calculator.id(), &ctx).await {
+        return ContractingError::CalculatorNotFound(calculator.id().to_string()).into_response();
+    }
+
+    let saved = match saved_inspections::load_inspections(&state.pool, &scope, Some(&payload.project_name), payload.trade).await {
+        Ok(saved) => saved,
+        Err(e) => return e.into_response(),
+    };
+    let attached: Vec<_> = saved.into_iter().map(|s| s.inspection).collect();
+    let quality = inspections::first_time_quality(&attached);
+    let mut parameters = payload.parameters.unwrap_or_default();
+    parameters.inspections = Some(attached);
+
+    match state.calculators_contractor.execute(&calculator, parameters).await {
+        Ok(mut calculation) => {
+            if let Some(metadata) = calculation.calculation_metadata.as_mut() {
+                metadata.calculator_version = changelog::formula_version(Tier::Contractor, calculator.id()).to_string();
+            }
+            Json(QualityCalculationResponse { quality, calculation }).into_response()
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+/// POST /api/v1/calculus/contractor/prequalification
+/// Rank subcontractors with the organization's scoring model
+async fn prequalification_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    claims: Option<Claims>,
+    Json(payload): Json<PrequalRequest>,
+) -> Response {
+    let calculator = match state.calculators_contractor.find(SubcontractorEvaluationCalculator.id()) {
+        Ok(calculator) => calculator,
+        Err(e) => return e.into_response(),
+    };
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Contractor, calculator.id(), &ctx).await {
+        return ContractingError::CalculatorNotFound(calculator.id().to_string()).into_response();
+    }
+
+    let model = match prequal_models::load_model(&state.pool, &scope, payload.model_id).await {
+        Ok(model) => model,
+        Err(e) => return e.into_response(),
+    };
+    let prequal = Prequalification { model, project_value: payload.project_value, candidates: payload.candidates };
+    let report = prequal.report();
+    let parameters = ContractingParameters { prequalification: Some(prequal), ..Default::default() };
+
+    match state.calculators_contractor.execute(&calculator, parameters).await {
+        Ok(mut calculation) => {
+            if let Some(metadata) = calculation.calculation_metadata.as_mut() {
+                metadata.calculator_version = changelog::formula_version(Tier::Contractor, calculator.id()).to_string();
+            }
+            Json(PrequalResponse { report, calculation }).into_response()
+        }
+        Err(e) => e.into_response(),
+    }
 }
 
 /// GET /api/v1/calculus/contractor/catalogue
 /// Get complete calculator catalogue with optional filtering
 async fn catalogue_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Query(query): Query<CatalogueQuery>,
-) -> Json<ContractingCalculatorCatalogue> {
+) -> Json<Hal<ContractingCalculatorCatalogue>> {
     let mut catalogue = state.calculators_contractor.catalogue();
 
+    // Hide calculators the caller is not rolled out to
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Contractor, &ctx).await;
+    catalogue.calculators.retain(|calc| !hidden.contains(&calc.id));
+
     // Apply filters if specified
     if let Some(category_filter) = query.category {
         catalogue.calculators.retain(|calc| calc.category == category_filter);
@@ -92,17 +575,51 @@ async fn catalogue_handler(
         catalogue.calculators.retain(|calc| result_ids.contains(&calc.id));
     }
 
-    Json(catalogue)
+    let listed = catalogue.calculators.iter().map(|c| (c.id.clone(), c.name.clone()));
+    let links = links::catalogue(Tier::Contractor, listed);
+    Json(Hal::new(catalogue, links))
 }
 
 /// GET /api/v1/calculus/contractor/catalogue/:id
 /// Get metadata for specific calculator
 async fn calculator_metadata_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Path(id): Path<String>,
-) -> Result<Json<ContractingCalculatorMetadata>, ContractingError> {
+) -> Result<Json<Hal<ContractingCalculatorMetadata>>, ContractingError> {
     let calculator = state.calculators_contractor.find(&id)?;
-    Ok(Json(calculator.metadata()))
+
+    // Calculators behind a rollout flag look missing to callers outside it
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Contractor, calculator.id(), &ctx).await {
+        return Err(ContractingError::CalculatorNotFound(id));
+    }
+
+    let related = related_calculators(&state, claims.as_ref(), calculator.id()).await;
+    let links = links::calculator(Tier::Contractor, calculator.id(), related);
+    let mut metadata = calculator.metadata();
+    metadata.examples = examples::for_calculator(Tier::Contractor, calculator.id());
+    Ok(Json(Hal::new(metadata, links)))
+}
+
+/// Calculators sharing a category with `calculator_id` that the caller can
+/// see, as (id, name) pairs for `related` links
+async fn related_calculators(state: &AppState, claims: Option<&Claims>, calculator_id: &str) -> Vec<(String, String)> {
+    let Ok(calculator) = state.calculators_contractor.find(calculator_id) else {
+        return Vec::new();
+    };
+    let ctx = FlagContext::for_caller(&state.tenants, claims);
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Contractor, &ctx).await;
+
+    let mut related: Vec<(String, String)> = state
+        .calculators_contractor
+        .by_category(calculator.category())
+        .into_iter()
+        .filter(|c| c.id() != calculator_id && !hidden.contains(c.id()))
+        .map(|c| (c.id().to_string(), c.name().to_string()))
+        .collect();
+    related.sort();
+    related
 }
 
 /// GET /api/v1/calculus/contractor/categories
@@ -118,6 +635,7 @@ async fn categories_handler(
 /// Get calculators in specific category
 async fn category_calculators_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Path(category_str): Path<String>,
 ) -> Result<Json<Vec<ContractingCalculatorMetadata>>, ContractingError> {
     // Parse category
@@ -135,9 +653,14 @@ async fn category_calculators_handler(
         }
     };
 
+    // Hide calculators the caller is not rolled out to
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Contractor, &ctx).await;
+
     let calculators = state.calculators_contractor.by_category(category);
     let metadata: Vec<ContractingCalculatorMetadata> = calculators
         .iter()
+        .filter(|calc| !hidden.contains(calc.id()))
         .map(|calc| calc.metadata())
         .collect();
 
@@ -153,11 +676,17 @@ struct SearchQuery {
 
 async fn search_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Query(query): Query<SearchQuery>,
 ) -> Json<Vec<ContractingCalculatorMetadata>> {
+    // Hide calculators the caller is not rolled out to
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Contractor, &ctx).await;
+
     let results = state.calculators_contractor.search(&query.q);
     let metadata: Vec<ContractingCalculatorMetadata> = results
         .iter()
+        .filter(|calc| !hidden.contains(calc.id()))
         .map(|calc| calc.metadata())
         .collect();
 
@@ -201,6 +730,20 @@ async fn stats_handler(
     })
 }
 
+/// GET /api/v1/calculus/contractor/warnings/catalogue
+/// List every warning code calculators can emit
+async fn warning_catalogue_handler(
+    Query(query): Query<WarningCatalogueQuery>,
+) -> Json<Vec<WarningCode>> {
+    let codes = warnings::WARNING_CATALOGUE
+        .iter()
+        .filter(|w| query.calculator.as_deref().is_none_or(|id| w.calculator == id))
+        .copied()
+        .collect();
+
+    Json(codes)
+}
+
 // ============================================================================
 // ROUTER CONSTRUCTION
 // ============================================================================
@@ -212,17 +755,38 @@ pub fn create_router() -> Router<Arc<AppState>> {
     Router::new()
         // Main calculation endpoint
         .route("/calculate", post(calculate_handler))
+
+        // MS Project / Primavera schedule import
+        .route("/schedule/import", post(schedule_import_handler))
+        .route("/gantt", post(gantt_handler))
+        .route("/delay-analysis/windows", post(delay_windows_handler))
+
+        // Closeout readiness from a saved punch list
+        .route("/closeout/{punch_list_id}", get(closeout_handler))
+
+        // Progress and delay analysis from saved field reports
+        .route("/field-log/calculate", post(field_log_calculate_handler))
+
+        // Inspection checklists and first-time quality from saved inspections
+        .route("/inspections/checklists", post(inspection_checklists_handler))
+        .route("/quality/calculate", post(quality_calculate_handler))
+
+        // Subcontractor ranking with a saved scoring model
+        .route("/prequalification", post(prequalification_handler))
         
         // Catalogue and discovery endpoints
         .route("/catalogue", get(catalogue_handler))
-        .route("/catalogue/meta", get(calculator_metadata_handler))
+        .route("/catalogue/{id}", get(calculator_metadata_handler))
         
         // Category endpoints
         .route("/categories", get(categories_handler))
-        .route("/categories/handler", get(category_calculators_handler))
+        .route("/categories/{category}", get(category_calculators_handler))
         
         // Search endpoint
         .route("/search", get(search_handler))
+
+        // Warning catalogue
+        .route("/warnings/catalogue", get(warning_catalogue_handler))
         
         // System endpoints
         .route("/health", get(health_handler))
@@ -0,0 +1,184 @@
+//! Milestone alerts: which milestones of a schedule are coming due or have
+//! slipped past their baseline.
+//!
+//! A milestone is due on its baseline date when it has one, else on the
+//! date the schedule currently forecasts. Alerts carry a stable `key` so a
+//! sender can remember what it already sent: an approaching alert fires
+//! once per lead time crossed, a slip alert once per forecast date.
+
+use super::{gantt::GanttChart, Schedule};
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+
+/// Lead times used when a schedule does not set its own, in days
+pub const DEFAULT_LEAD_DAYS: &[i64] = &[7, 1];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AlertKind {
+    /// Due within `lead_days`
+    Approaching { days_left: i64, lead_days: i64 },
+    /// Forecast later than the baseline date
+    Slipped { days_late: i64 },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MilestoneAlert {
+    pub milestone_id: String,
+    pub name: String,
+    pub due: NaiveDate,
+    pub forecast: NaiveDate,
+    #[serde(flatten)]
+    pub kind: AlertKind,
+}
+
+impl MilestoneAlert {
+    /// Identifies what was alerted, for sending each alert once
+    pub fn key(&self) -> String {
+        match &self.kind {
+            AlertKind::Approaching { lead_days, .. } => format!("lead:{}", lead_days),
+            AlertKind::Slipped { .. } => format!("slipped:{}", self.forecast),
+        }
+    }
+
+    pub fn subject(&self, schedule_name: &str) -> String {
+        match &self.kind {
+            AlertKind::Approaching { days_left: 0, .. } => format!("[{}] {} is due today", schedule_name, self.name),
+            AlertKind::Approaching { days_left, .. } => {
+                format!("[{}] {} is due in {} days", schedule_name, self.name, days_left)
+            }
+            AlertKind::Slipped { days_late } => {
+                format!("[{}] {} has slipped {} days", schedule_name, self.name, days_late)
+            }
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match &self.kind {
+            AlertKind::Approaching { .. } => format!(
+                "Milestone '{}' ({}) is due on {}. The schedule forecasts {}.",
+                self.name, self.milestone_id, self.due, self.forecast
+            ),
+            AlertKind::Slipped { days_late } => format!(
+                "Milestone '{}' ({}) was baselined for {} and is now forecast for {}, {} days late.",
+                self.name, self.milestone_id, self.due, self.forecast, days_late
+            ),
+        }
+    }
+}
+
+/// Alerts for the schedule's milestones as of `today`
+///
+/// Lead times are in days; each approaching milestone reports the tightest
+/// lead it falls within. Schedules without a start date cannot be placed
+/// on the calendar and raise no alerts.
+pub fn milestone_alerts(schedule: &Schedule, today: NaiveDate, lead_days: &[i64]) -> Result<Vec<MilestoneAlert>, String> {
+    let Some(origin) = schedule.project_start() else {
+        return Ok(Vec::new());
+    };
+    let chart = GanttChart::from_schedule(schedule)?;
+
+    let mut alerts = Vec::new();
+    for (activity, bar) in schedule.activities.iter().zip(&chart.bars) {
+        if !activity.is_milestone() {
+            continue;
+        }
+        let forecast = origin + Duration::days(bar.start.floor() as i64);
+        let baseline = activity.baseline_finish.or(activity.baseline_start);
+        let due = baseline.unwrap_or(forecast);
+        let alert = |kind| MilestoneAlert {
+            milestone_id: activity.id.clone(),
+            name: activity.name.clone(),
+            due,
+            forecast,
+            kind,
+        };
+
+        if forecast > due {
+            alerts.push(alert(AlertKind::Slipped { days_late: (forecast - due).num_days() }));
+        }
+        let days_left = (due - today).num_days();
+        if days_left >= 0
+            && let Some(lead) = lead_days.iter().copied().filter(|lead| days_left <= *lead).min()
+        {
+            alerts.push(alert(AlertKind::Approaching { days_left, lead_days: lead }));
+        }
+    }
+    Ok(alerts)
+}
+
+/// Milestones whose forecast date is before `today`
+pub fn passed_milestones(schedule: &Schedule, today: NaiveDate) -> Result<usize, String> {
+    let Some(origin) = schedule.project_start() else {
+        return Ok(0);
+    };
+    let chart = GanttChart::from_schedule(schedule)?;
+    Ok(chart
+        .bars
+        .iter()
+        .filter(|bar| bar.milestone && origin + Duration::days(bar.start.floor() as i64) < today)
+        .count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::contractor::schedule::{Activity, Dependency, DependencyType};
+
+    fn activity(id: &str, duration: f64, after: Option<&str>, baseline: Option<NaiveDate>) -> Activity {
+        Activity {
+            id: id.to_string(),
+            name: format!("Step {}", id),
+            duration_days: duration,
+            predecessors: after
+                .map(|p| vec![Dependency { id: p.to_string(), kind: DependencyType::FS, lag_days: 0.0 }])
+                .unwrap_or_default(),
+            resource_units: None,
+            phase: None,
+            equipment: Vec::new(),
+            start: None,
+            finish: None,
+            baseline_start: baseline,
+            baseline_finish: None,
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    // Monday 3 March; A runs Mon-Fri so milestones after it fall on Monday 10
+    fn schedule() -> Schedule {
+        Schedule {
+            start_date: Some(date(3)),
+            activities: vec![
+                activity("A", 5.0, None, None),
+                activity("M1", 0.0, Some("A"), None),
+                activity("M2", 0.0, Some("A"), Some(date(7))),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_approaching_uses_tightest_lead() {
+        let alerts = milestone_alerts(&schedule(), date(4), &[7, 1]).unwrap();
+        let m1: Vec<_> = alerts.iter().filter(|a| a.milestone_id == "M1").collect();
+        assert_eq!(m1.len(), 1);
+        assert_eq!(m1[0].kind, AlertKind::Approaching { days_left: 6, lead_days: 7 });
+        assert_eq!(m1[0].key(), "lead:7");
+
+        let alerts = milestone_alerts(&schedule(), date(9), &[7, 1]).unwrap();
+        assert_eq!(alerts.iter().find(|a| a.milestone_id == "M1").unwrap().key(), "lead:1");
+    }
+
+    #[test]
+    fn test_slip_against_baseline() {
+        let alerts = milestone_alerts(&schedule(), date(1), &[]).unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AlertKind::Slipped { days_late: 3 });
+        assert_eq!(alerts[0].key(), "slipped:2025-03-10");
+        assert!(alerts[0].subject("Tower B").contains("slipped 3 days"));
+
+        assert_eq!(passed_milestones(&schedule(), date(11)).unwrap(), 2);
+    }
+}
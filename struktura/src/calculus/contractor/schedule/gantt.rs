@@ -0,0 +1,334 @@
+//! Gantt bars laid out from a schedule, and their SVG rendering.
+//!
+//! Bars are positioned in days from the project start. When the schedule
+//! has a start date the axis is in calendar days, with the critical-path
+//! pass's working days spread over Monday to Friday; otherwise it is in
+//! working days. Activities that carry their own start and finish dates
+//! are drawn where those dates put them, so a progressed schedule compares
+//! against its baseline.
+
+use super::Schedule;
+use crate::utils::drawing::escape_xml;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// Width of the activity name column, in pixels
+const LABEL_WIDTH: f64 = 220.0;
+/// Width of the timeline, in pixels
+const TIMELINE_WIDTH: f64 = 760.0;
+const ROW_HEIGHT: f64 = 26.0;
+const HEADER_HEIGHT: f64 = 40.0;
+const LEGEND_HEIGHT: f64 = 36.0;
+/// Most axis ticks drawn across the timeline
+const MAX_TICKS: f64 = 12.0;
+
+const CRITICAL_FILL: &str = "#c0392b";
+const NORMAL_FILL: &str = "#2e86c1";
+const BASELINE_FILL: &str = "#b0b0b0";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GanttBar {
+    pub id: String,
+    pub name: String,
+    /// Days from the chart start
+    pub start: f64,
+    pub finish: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_start: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_finish: Option<f64>,
+    /// Finish against baseline finish; positive is late
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variance_days: Option<f64>,
+    pub total_float: f64,
+    pub critical: bool,
+    pub milestone: bool,
+    pub predecessors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GanttChart {
+    /// Day zero of the axis, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDate>,
+    /// Axis length in days, baselines included
+    pub span_days: f64,
+    pub bars: Vec<GanttBar>,
+    pub critical_path: Vec<String>,
+}
+
+fn is_workday(date: NaiveDate) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Calendar days from `origin` covering `working` working days. Starts
+/// are moved past weekends; finishes end on the last working day used.
+fn calendar_offset(origin: NaiveDate, working: f64, is_start: bool) -> f64 {
+    let (mut remaining, mut calendar, mut date) = (working, 0.0, origin);
+    while remaining > 1e-9 {
+        if is_workday(date) {
+            let step = remaining.min(1.0);
+            remaining -= step;
+            calendar += step;
+            if step < 1.0 {
+                break;
+            }
+        } else {
+            calendar += 1.0;
+        }
+        date += Duration::days(1);
+    }
+    if is_start {
+        while !is_workday(origin + Duration::days(calendar.floor() as i64)) {
+            calendar += 1.0;
+        }
+    }
+    calendar
+}
+
+impl GanttChart {
+    pub fn from_schedule(schedule: &Schedule) -> Result<Self, String> {
+        let analysis = schedule.analyze()?;
+        let origin = schedule.project_start();
+        let offset = |working: f64, is_start: bool| match origin {
+            Some(origin) => calendar_offset(origin, working, is_start),
+            None => working,
+        };
+        let day = |date: Option<NaiveDate>| Some((date? - origin?).num_days() as f64);
+
+        let bars: Vec<GanttBar> = schedule
+            .activities
+            .iter()
+            .zip(&analysis.activities)
+            .map(|(activity, cpm)| {
+                let milestone = activity.is_milestone();
+                // Dates are inclusive, so a finish date ends at the close of that day
+                let close = |finish: f64, start: f64| if milestone { start } else { finish + 1.0 };
+                let (start, finish) = match (day(activity.start), day(activity.finish)) {
+                    (Some(s), Some(f)) if f >= s => (s, close(f, s)),
+                    _ => (offset(cpm.early_start, true), offset(cpm.early_finish, milestone)),
+                };
+                let baseline_start = day(activity.baseline_start);
+                let baseline_finish = match (baseline_start, day(activity.baseline_finish)) {
+                    (Some(s), Some(f)) => Some(close(f, s)),
+                    (_, finish) => finish.map(|f| f + 1.0),
+                };
+                GanttBar {
+                    id: activity.id.clone(),
+                    name: activity.name.clone(),
+                    start,
+                    finish,
+                    baseline_start,
+                    baseline_finish,
+                    variance_days: baseline_finish.map(|b| finish - b),
+                    total_float: cpm.total_float,
+                    critical: cpm.critical,
+                    milestone,
+                    predecessors: activity.predecessors.iter().map(|d| d.id.clone()).collect(),
+                }
+            })
+            .collect();
+
+        let span_days = bars
+            .iter()
+            .flat_map(|b| [Some(b.finish), b.baseline_finish])
+            .flatten()
+            .fold(0.0, f64::max);
+        Ok(Self { start_date: origin, span_days, bars, critical_path: analysis.critical_path })
+    }
+
+    /// A single project bar with evenly spaced milestones, for calculations
+    /// made from aggregate figures rather than a schedule
+    pub fn aggregate(start_date: Option<NaiveDate>, duration: f64, milestones: usize) -> Self {
+        let project = GanttBar {
+            id: "project".to_string(),
+            name: "Project".to_string(),
+            start: 0.0,
+            finish: duration,
+            baseline_start: None,
+            baseline_finish: None,
+            variance_days: None,
+            total_float: 0.0,
+            critical: true,
+            milestone: false,
+            predecessors: Vec::new(),
+        };
+        let markers = (1..=milestones).map(|i| {
+            let at = duration * i as f64 / milestones as f64;
+            GanttBar {
+                id: format!("M{}", i),
+                name: format!("Milestone {}", i),
+                start: at,
+                finish: at,
+                milestone: true,
+                critical: false,
+                predecessors: vec!["project".to_string()],
+                ..project.clone()
+            }
+        });
+        let bars: Vec<GanttBar> = std::iter::once(project.clone()).chain(markers).collect();
+        Self { start_date, span_days: duration, bars, critical_path: vec![project.id] }
+    }
+
+    /// Latest finish against baseline, in days; `None` without baselines
+    pub fn max_slip(&self) -> Option<f64> {
+        self.bars.iter().filter_map(|b| b.variance_days).reduce(f64::max)
+    }
+
+    pub fn to_svg(&self) -> String {
+        let span = self.span_days.max(1.0);
+        let scale = TIMELINE_WIDTH / span;
+        let x = |day: f64| LABEL_WIDTH + day * scale;
+        let width = LABEL_WIDTH + TIMELINE_WIDTH + 20.0;
+        let height = HEADER_HEIGHT + self.bars.len() as f64 * ROW_HEIGHT + LEGEND_HEIGHT;
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.0}" height="{h:.0}" viewBox="0 0 {w:.0} {h:.0}" font-family="Helvetica, Arial, sans-serif" font-size="12">"#,
+            w = width,
+            h = height
+        );
+        let _ = writeln!(out, "<title>Gantt chart</title>");
+        let _ = writeln!(out, r##"<rect width="100%" height="100%" fill="#ffffff"/>"##);
+
+        // Axis ticks on a 1/7/14/30/... day step that keeps the count readable
+        let step = [1.0, 7.0, 14.0, 30.0, 60.0, 90.0, 180.0, 365.0]
+            .into_iter()
+            .find(|s| span / s <= MAX_TICKS)
+            .unwrap_or((span / MAX_TICKS).ceil());
+        let mut tick = 0.0;
+        while tick <= span {
+            let label = match self.start_date {
+                Some(start) => (start + Duration::days(tick as i64)).format("%d %b").to_string(),
+                None => format!("day {}", tick),
+            };
+            let _ = writeln!(
+                out,
+                r##"<line x1="{x:.1}" y1="{top:.1}" x2="{x:.1}" y2="{bottom:.1}" stroke="#e4e4e4"/><text x="{x:.1}" y="24" text-anchor="middle" fill="#555555">{label}</text>"##,
+                x = x(tick),
+                top = HEADER_HEIGHT - 8.0,
+                bottom = height - LEGEND_HEIGHT,
+                label = label
+            );
+            tick += step;
+        }
+
+        for (row, bar) in self.bars.iter().enumerate() {
+            let top = HEADER_HEIGHT + row as f64 * ROW_HEIGHT;
+            let fill = if bar.critical { CRITICAL_FILL } else { NORMAL_FILL };
+            let _ = writeln!(
+                out,
+                r##"<text x="8" y="{:.1}" dominant-baseline="middle" fill="#222222">{}</text>"##,
+                top + ROW_HEIGHT / 2.0,
+                escape_xml(&bar.name)
+            );
+            if let (Some(start), Some(finish)) = (bar.baseline_start, bar.baseline_finish) {
+                let _ = writeln!(
+                    out,
+                    r#"<rect class="baseline" x="{:.1}" y="{:.1}" width="{:.1}" height="5" fill="{}"/>"#,
+                    x(start), top + 18.0, ((finish - start) * scale).max(2.0), BASELINE_FILL
+                );
+            }
+            if bar.milestone {
+                let (cx, cy) = (x(bar.start), top + 11.0);
+                let _ = writeln!(
+                    out,
+                    r#"<polygon class="milestone" points="{:.1},{:.1} {:.1},{:.1} {:.1},{:.1} {:.1},{:.1}" fill="{}"/>"#,
+                    cx, cy - 7.0, cx + 7.0, cy, cx, cy + 7.0, cx - 7.0, cy, fill
+                );
+            } else {
+                let _ = writeln!(
+                    out,
+                    r#"<rect class="{}" x="{:.1}" y="{:.1}" width="{:.1}" height="12" rx="2" fill="{}"><title>{}: {:.1} days float</title></rect>"#,
+                    if bar.critical { "critical" } else { "activity" },
+                    x(bar.start), top + 5.0, ((bar.finish - bar.start) * scale).max(2.0), fill,
+                    escape_xml(&bar.id), bar.total_float
+                );
+            }
+        }
+
+        let legend_y = height - LEGEND_HEIGHT / 2.0;
+        for (i, (fill, label)) in [(CRITICAL_FILL, "Critical"), (NORMAL_FILL, "Activity"), (BASELINE_FILL, "Baseline")]
+            .into_iter()
+            .enumerate()
+        {
+            let lx = 8.0 + i as f64 * 110.0;
+            let _ = writeln!(
+                out,
+                r##"<rect x="{:.1}" y="{:.1}" width="14" height="10" fill="{}"/><text x="{:.1}" y="{:.1}" dominant-baseline="middle" fill="#222222">{}</text>"##,
+                lx, legend_y - 5.0, fill, lx + 20.0, legend_y, label
+            );
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::contractor::schedule::{Activity, Dependency, DependencyType};
+
+    fn activity(id: &str, duration: f64, after: Option<&str>) -> Activity {
+        Activity {
+            id: id.to_string(),
+            name: format!("Task {}", id),
+            duration_days: duration,
+            predecessors: after
+                .map(|p| vec![Dependency { id: p.to_string(), kind: DependencyType::FS, lag_days: 0.0 }])
+                .unwrap_or_default(),
+            resource_units: None,
+            phase: None,
+            equipment: Vec::new(),
+            start: None,
+            finish: None,
+            baseline_start: None,
+            baseline_finish: None,
+        }
+    }
+
+    #[test]
+    fn test_working_days_span_weekends() {
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+        assert_eq!(calendar_offset(monday, 5.0, false), 5.0);
+        assert_eq!(calendar_offset(monday, 5.0, true), 7.0);
+        assert_eq!(calendar_offset(monday, 6.0, false), 8.0);
+    }
+
+    #[test]
+    fn test_bars_follow_critical_path_and_baseline() {
+        let mut walls = activity("B", 5.0, Some("A"));
+        walls.baseline_start = NaiveDate::from_ymd_opt(2025, 3, 10);
+        walls.baseline_finish = NaiveDate::from_ymd_opt(2025, 3, 12);
+        let schedule = Schedule {
+            start_date: NaiveDate::from_ymd_opt(2025, 3, 3),
+            activities: vec![activity("A", 5.0, None), walls, activity("C", 2.0, Some("A")), activity("M", 0.0, Some("B"))],
+        };
+
+        let chart = GanttChart::from_schedule(&schedule).unwrap();
+        let b = &chart.bars[1];
+        assert_eq!((b.start, b.finish), (7.0, 12.0));
+        assert_eq!(b.variance_days, Some(2.0));
+        assert!(b.critical && !chart.bars[2].critical);
+        assert!(chart.bars[3].milestone);
+        assert_eq!(chart.critical_path, ["A", "B", "M"]);
+        assert_eq!(chart.max_slip(), Some(2.0));
+
+        let svg = chart.to_svg();
+        assert_eq!(svg.matches(r#"class="critical""#).count(), 2);
+        assert_eq!(svg.matches(r#"class="baseline""#).count(), 1);
+        assert_eq!(svg.matches("<polygon").count(), 1);
+        assert!(svg.contains(">03 Mar</text>"));
+    }
+
+    #[test]
+    fn test_aggregate_chart() {
+        let chart = GanttChart::aggregate(None, 90.0, 3);
+        assert_eq!(chart.bars.len(), 4);
+        assert_eq!(chart.bars[3].start, 90.0);
+        assert!(chart.max_slip().is_none());
+        assert!(chart.to_svg().contains(">day 0</text>"));
+    }
+}
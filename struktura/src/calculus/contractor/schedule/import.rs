@@ -0,0 +1,628 @@
+//! Schedule exports from MS Project and Primavera P6, mapped onto `Activity`.
+//!
+//! CSV exports differ by tool, version and whoever set up the table, so
+//! columns are located by header name: common MS Project and P6 headers are
+//! recognised out of the box and a `ColumnMapping` names any others. XML
+//! exports have fixed schemas (MS Project's `Project` and P6's
+//! `APIBusinessObjects`) and need no mapping. Both parsers are hand-written
+//! and read only what a schedule needs.
+//!
+//! Rows that cannot be read are skipped with a warning rather than failing
+//! the import: a 300-activity schedule with one bad row is still useful.
+
+use super::{Activity, Dependency, DependencyType, Schedule};
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Working hours in a day, for hour-based durations and lags
+pub const HOURS_PER_DAY: f64 = 8.0;
+/// Working days in a week
+pub const DAYS_PER_WEEK: f64 = 5.0;
+/// Working days in a month
+pub const DAYS_PER_MONTH: f64 = 20.0;
+
+/// Date formats tried in order; month-first wins for ambiguous dates, as in
+/// MS Project's default US export
+const DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d", "%m/%d/%y", "%m/%d/%Y", "%d-%b-%y", "%d-%b-%Y", "%d %b %Y", "%b %d %Y", "%d.%m.%Y", "%Y/%m/%d",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportFormat {
+    Csv,
+    MsProjectXml,
+    PrimaveraXml,
+}
+
+/// CSV header names for each activity field; unset fields fall back to
+/// the usual MS Project / P6 headers. Matching ignores case.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColumnMapping {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predecessors: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finish: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_finish: Option<String>,
+    /// Yes/no column marking summary rows, which are skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+/// Headers recognised when the mapping leaves a field unset
+const DEFAULT_HEADERS: &[(&str, &[&str])] = &[
+    ("id", &["ID", "Activity ID", "Task ID", "Unique ID", "task_code"]),
+    ("name", &["Name", "Task Name", "Activity Name", "task_name"]),
+    ("duration", &["Duration", "Original Duration", "Planned Duration", "target_drtn_hr_cnt"]),
+    ("predecessors", &["Predecessors", "Predecessor", "Predecessor Details"]),
+    ("start", &["Start", "Start Date", "Planned Start", "Early Start"]),
+    ("finish", &["Finish", "Finish Date", "Planned Finish", "Early Finish"]),
+    ("resources", &["Resource Units", "Units", "Budgeted Units", "Crew Size"]),
+    ("baseline_start", &["Baseline Start", "BL Project Start", "BL1 Start"]),
+    ("baseline_finish", &["Baseline Finish", "BL Project Finish", "BL1 Finish"]),
+    ("summary", &["Summary"]),
+];
+
+impl ColumnMapping {
+    fn requested(&self, field: &str) -> Option<&str> {
+        match field {
+            "id" => self.id.as_deref(),
+            "name" => self.name.as_deref(),
+            "duration" => self.duration.as_deref(),
+            "predecessors" => self.predecessors.as_deref(),
+            "start" => self.start.as_deref(),
+            "finish" => self.finish.as_deref(),
+            "resources" => self.resources.as_deref(),
+            "baseline_start" => self.baseline_start.as_deref(),
+            "baseline_finish" => self.baseline_finish.as_deref(),
+            "summary" => self.summary.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Column index per field, in `DEFAULT_HEADERS` order
+    fn resolve(&self, headers: &[String]) -> Result<Vec<Option<usize>>, String> {
+        let find = |name: &str| headers.iter().position(|h| h.trim().eq_ignore_ascii_case(name.trim()));
+        DEFAULT_HEADERS
+            .iter()
+            .map(|(field, defaults)| match self.requested(field) {
+                Some(name) => find(name)
+                    .map(Some)
+                    .ok_or_else(|| format!("mapped {} column '{}' is not in the file", field, name)),
+                None => Ok(defaults.iter().find_map(|name| find(name))),
+            })
+            .collect()
+    }
+}
+
+/// A parsed schedule and what was skipped on the way
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportResult {
+    pub schedule: Schedule,
+    pub warnings: Vec<String>,
+}
+
+/// Parse an export; fails only when nothing usable can be read
+pub fn import(format: ImportFormat, content: &str, mapping: &ColumnMapping) -> Result<ImportResult, String> {
+    let content = content.trim_start_matches('\u{feff}');
+    let mut result = match format {
+        ImportFormat::Csv => import_csv(content, mapping)?,
+        ImportFormat::MsProjectXml => import_ms_project(content),
+        ImportFormat::PrimaveraXml => import_primavera(content),
+    };
+    drop_dangling_links(&mut result);
+    if result.schedule.activities.is_empty() {
+        return Err("no activities could be read from the file".to_string());
+    }
+    Ok(result)
+}
+
+/// Links to skipped or missing activities would fail the analysis
+fn drop_dangling_links(result: &mut ImportResult) {
+    let ids: HashSet<String> = result.schedule.activities.iter().map(|a| a.id.clone()).collect();
+    for activity in result.schedule.activities.iter_mut() {
+        activity.predecessors.retain(|dep| {
+            let known = ids.contains(&dep.id);
+            if !known {
+                result.warnings.push(format!(
+                    "activity '{}': dropped link to unknown predecessor '{}'",
+                    activity.id, dep.id
+                ));
+            }
+            known
+        });
+    }
+}
+
+fn push_activity(result: &mut ImportResult, seen: &mut HashSet<String>, activity: Activity) {
+    if seen.insert(activity.id.clone()) {
+        result.schedule.activities.push(activity);
+    } else {
+        result.warnings.push(format!("activity '{}' appears more than once; kept the first", activity.id));
+    }
+}
+
+// ----------------------------------------------------------------------------
+// CSV
+// ----------------------------------------------------------------------------
+
+fn import_csv(content: &str, mapping: &ColumnMapping) -> Result<ImportResult, String> {
+    let mut rows = parse_csv(content).into_iter().filter(|r| r.iter().any(|c| !c.trim().is_empty()));
+    let headers = rows.next().ok_or("the file is empty")?;
+    let columns = mapping.resolve(&headers)?;
+    let [id, name, duration, predecessors, start, finish, resources, baseline_start, baseline_finish, summary] =
+        columns[..]
+    else {
+        unreachable!("one column per default header");
+    };
+    let id = id.ok_or_else(|| format!("no activity id column; found {}", headers.join(", ")))?;
+    if duration.is_none() && (start.is_none() || finish.is_none()) {
+        return Err("no duration column, and no start and finish columns to derive it from".to_string());
+    }
+
+    let mut result = ImportResult { schedule: Schedule::default(), warnings: Vec::new() };
+    let mut seen = HashSet::new();
+    let mut summaries = 0;
+    for (line, row) in rows.enumerate() {
+        let cell = |column: Option<usize>| column.and_then(|c| row.get(c)).map(|v| v.trim()).filter(|v| !v.is_empty());
+        let Some(activity_id) = cell(Some(id)) else {
+            continue;
+        };
+        if cell(summary).is_some_and(is_yes) {
+            summaries += 1;
+            continue;
+        }
+
+        let start_date = cell(start).and_then(parse_date);
+        let finish_date = cell(finish).and_then(parse_date);
+        let duration_days = match cell(duration).and_then(parse_duration) {
+            Some(days) => days,
+            None => match (start_date, finish_date) {
+                (Some(s), Some(f)) if f >= s => working_days(s, f),
+                _ => {
+                    result.warnings.push(format!(
+                        "row {} ('{}'): unreadable duration '{}'; skipped",
+                        line + 2,
+                        activity_id,
+                        cell(duration).unwrap_or("")
+                    ));
+                    continue;
+                }
+            },
+        };
+
+        let activity = Activity {
+            id: activity_id.to_string(),
+            name: cell(name).unwrap_or(activity_id).to_string(),
+            duration_days,
+            predecessors: cell(predecessors).map(parse_predecessors).unwrap_or_default(),
+            resource_units: cell(resources).and_then(|v| v.parse().ok()),
+            phase: None,
+            equipment: Vec::new(),
+            start: start_date,
+            finish: finish_date,
+            baseline_start: cell(baseline_start).and_then(parse_date),
+            baseline_finish: cell(baseline_finish).and_then(parse_date),
+        };
+        push_activity(&mut result, &mut seen, activity);
+    }
+    if summaries > 0 {
+        result.warnings.push(format!("{} summary rows skipped", summaries));
+    }
+    Ok(result)
+}
+
+fn is_yes(value: &str) -> bool {
+    matches!(value.to_ascii_lowercase().as_str(), "yes" | "y" | "true" | "1")
+}
+
+/// Rows of fields; handles quoted fields, doubled quotes and the delimiter
+/// (`,`, `;` or tab) most common in the header line
+pub fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let header = content.lines().next().unwrap_or("");
+    let delimiter = [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|d| header.matches(*d).count())
+        .unwrap_or(',');
+
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+/// Working days from a duration such as `5 days`, `5d?`, `16h`, `2 wks`,
+/// `1 mo` or ISO 8601 `PT40H0M0S`; a bare number is days
+pub fn parse_duration(text: &str) -> Option<f64> {
+    let text = text.trim().trim_end_matches('?').trim().to_ascii_lowercase();
+    if let Some(iso) = text.strip_prefix("pt") {
+        return parse_iso_hours(iso).map(|hours| hours / HOURS_PER_DAY);
+    }
+    let split = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(text.len());
+    let value: f64 = text[..split].trim().parse().ok()?;
+    let per_unit = match text[split..].trim().trim_start_matches('e') {
+        "" | "d" | "dy" | "dys" | "day" | "days" => 1.0,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 1.0 / HOURS_PER_DAY,
+        "m" | "min" | "mins" | "minute" | "minutes" => 1.0 / (HOURS_PER_DAY * 60.0),
+        "w" | "wk" | "wks" | "week" | "weeks" => DAYS_PER_WEEK,
+        "mo" | "mon" | "mons" | "month" | "months" => DAYS_PER_MONTH,
+        _ => return None,
+    };
+    Some(value * per_unit).filter(|d| d.is_finite())
+}
+
+/// Hours in the time part of an ISO 8601 duration, e.g. `40H30M0S`
+fn parse_iso_hours(text: &str) -> Option<f64> {
+    let mut hours = 0.0;
+    let mut number = String::new();
+    for c in text.chars() {
+        match c {
+            '0'..='9' | '.' | '-' => number.push(c),
+            'h' | 'm' | 's' => {
+                let value: f64 = std::mem::take(&mut number).parse().ok()?;
+                hours += match c {
+                    'h' => value,
+                    'm' => value / 60.0,
+                    _ => value / 3600.0,
+                };
+            }
+            _ => return None,
+        }
+    }
+    number.is_empty().then_some(hours)
+}
+
+/// Predecessor list such as `3FS+2 days, 5SS-1d; 7` or `A1010: FF`
+pub fn parse_predecessors(text: &str) -> Vec<Dependency> {
+    text.split([',', ';']).filter_map(parse_predecessor).collect()
+}
+
+fn parse_predecessor(token: &str) -> Option<Dependency> {
+    let token = token.trim();
+    let upper = token.to_ascii_uppercase();
+    // The link type is the last two-letter code followed by nothing or a lag
+    let link = (1..upper.len().saturating_sub(1)).rev().find_map(|i| {
+        let kind = upper.get(i..i + 2).and_then(DependencyType::parse)?;
+        let rest = upper[i + 2..].trim();
+        (rest.is_empty() || rest.starts_with(['+', '-'])).then_some((i, kind))
+    });
+
+    let (id, kind, lag_days) = match link {
+        Some((i, kind)) => {
+            let lag = token[i + 2..].trim().replace(' ', "");
+            let lag_days = if lag.is_empty() { 0.0 } else { parse_duration(&lag)? };
+            (token[..i].trim_end_matches([' ', ':']), kind, lag_days)
+        }
+        None => (token, DependencyType::FS, 0.0),
+    };
+    (!id.is_empty()).then(|| Dependency { id: id.to_string(), kind, lag_days })
+}
+
+/// A date in any of `DATE_FORMATS`, ignoring a leading weekday and any time
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    let text = text.trim().replace(',', "");
+    let text = match text.split_once(' ') {
+        Some((first, rest)) if first.chars().all(|c| c.is_ascii_alphabetic()) && first.parse::<Weekday>().is_ok() => {
+            rest.trim().to_string()
+        }
+        _ => text,
+    };
+    // ISO date-times such as `2025-03-03T08:00:00`
+    let date_part = if text.get(10..11) == Some("T") { &text[..10] } else { &text };
+    let mut candidates = vec![date_part.to_string()];
+    // Drop a trailing time such as `08:00` or `8:00 AM`
+    if let Some(idx) = text.find(':').and_then(|colon| text[..colon].rfind(' ')) {
+        candidates.push(text[..idx].trim().to_string());
+    }
+    candidates
+        .iter()
+        .find_map(|candidate| DATE_FORMATS.iter().find_map(|f| NaiveDate::parse_from_str(candidate, f).ok()))
+}
+
+/// Monday-to-Friday days from `start` to `finish`, both included
+fn working_days(start: NaiveDate, finish: NaiveDate) -> f64 {
+    start
+        .iter_days()
+        .take_while(|d| *d <= finish)
+        .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+        .count() as f64
+}
+
+// ----------------------------------------------------------------------------
+// XML
+// ----------------------------------------------------------------------------
+
+/// Bodies of every `<tag ...>...</tag>` in `xml`; self-closing tags give an
+/// empty body. Same-name nesting is not supported, and neither schema uses it.
+fn elements<'a>(xml: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut found = Vec::new();
+    let mut rest = xml;
+    while let Some(at) = rest.find(&open) {
+        let after = &rest[at + open.len()..];
+        // `<Task` must not match `<Tasks` or `<TaskName`
+        if !after.starts_with(['>', ' ', '\t', '\r', '\n', '/']) {
+            rest = after;
+            continue;
+        }
+        let Some(head_end) = after.find('>') else { break };
+        if after[..head_end].ends_with('/') {
+            found.push("");
+            rest = &after[head_end + 1..];
+            continue;
+        }
+        let body = &after[head_end + 1..];
+        let Some(end) = body.find(&close) else { break };
+        found.push(&body[..end]);
+        rest = &body[end + close.len()..];
+    }
+    found
+}
+
+/// Trimmed, unescaped text of the first `<tag>` child
+fn child_text(xml: &str, tag: &str) -> Option<String> {
+    let text = elements(xml, tag).into_iter().next()?.trim();
+    (!text.is_empty()).then(|| {
+        text.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&")
+    })
+}
+
+fn child_number(xml: &str, tag: &str) -> Option<f64> {
+    child_text(xml, tag)?.parse().ok()
+}
+
+/// MS Project XML: `Project/Tasks/Task` with `PredecessorLink` children
+fn import_ms_project(xml: &str) -> ImportResult {
+    let mut result = ImportResult { schedule: Schedule::default(), warnings: Vec::new() };
+    let mut seen = HashSet::new();
+    let mut summaries = 0;
+    for task in elements(xml, "Task") {
+        let Some(uid) = child_text(task, "UID") else {
+            continue;
+        };
+        if child_text(task, "Summary").as_deref() == Some("1") {
+            summaries += 1;
+            continue;
+        }
+        let Some(duration_days) = child_text(task, "Duration").as_deref().and_then(parse_duration) else {
+            result.warnings.push(format!("task UID {}: unreadable duration; skipped", uid));
+            continue;
+        };
+
+        let predecessors = elements(task, "PredecessorLink")
+            .into_iter()
+            .filter_map(|link| {
+                Some(Dependency {
+                    id: child_text(link, "PredecessorUID")?,
+                    kind: child_text(link, "Type").as_deref().and_then(DependencyType::parse).unwrap_or_default(),
+                    // LinkLag is in tenths of a minute
+                    lag_days: child_number(link, "LinkLag").unwrap_or(0.0) / 600.0 / HOURS_PER_DAY,
+                })
+            })
+            .collect();
+        let baseline = elements(task, "Baseline")
+            .into_iter()
+            .find(|b| child_text(b, "Number").as_deref().is_none_or(|n| n == "0"));
+
+        let activity = Activity {
+            name: child_text(task, "Name").unwrap_or_else(|| uid.clone()),
+            id: uid,
+            duration_days,
+            predecessors,
+            resource_units: None,
+            phase: None,
+            equipment: Vec::new(),
+            start: child_text(task, "Start").as_deref().and_then(parse_date),
+            finish: child_text(task, "Finish").as_deref().and_then(parse_date),
+            baseline_start: baseline.and_then(|b| child_text(b, "Start")).as_deref().and_then(parse_date),
+            baseline_finish: baseline.and_then(|b| child_text(b, "Finish")).as_deref().and_then(parse_date),
+        };
+        push_activity(&mut result, &mut seen, activity);
+    }
+    if summaries > 0 {
+        result.warnings.push(format!("{} summary tasks skipped", summaries));
+    }
+    result
+}
+
+/// Primavera P6 XML: `Activity` elements plus project-level `Relationship`s,
+/// joined on `ObjectId`; durations and lags are in hours
+fn import_primavera(xml: &str) -> ImportResult {
+    let mut result = ImportResult { schedule: Schedule::default(), warnings: Vec::new() };
+    let mut seen = HashSet::new();
+    // ObjectId -> activity id
+    let mut object_ids = std::collections::HashMap::new();
+    for activity in elements(xml, "Activity") {
+        let Some(id) = child_text(activity, "Id") else {
+            continue;
+        };
+        let Some(hours) = child_number(activity, "PlannedDuration") else {
+            result.warnings.push(format!("activity '{}': unreadable duration; skipped", id));
+            continue;
+        };
+        if let Some(object_id) = child_text(activity, "ObjectId") {
+            object_ids.insert(object_id, id.clone());
+        }
+        let date = |tags: &[&str]| tags.iter().find_map(|t| child_text(activity, t).as_deref().and_then(parse_date));
+        push_activity(
+            &mut result,
+            &mut seen,
+            Activity {
+                name: child_text(activity, "Name").unwrap_or_else(|| id.clone()),
+                id,
+                duration_days: hours / HOURS_PER_DAY,
+                predecessors: Vec::new(),
+                resource_units: None,
+                phase: None,
+                equipment: Vec::new(),
+                start: date(&["StartDate", "PlannedStartDate"]),
+                finish: date(&["FinishDate", "PlannedFinishDate"]),
+                baseline_start: date(&["BaselineStartDate"]),
+                baseline_finish: date(&["BaselineFinishDate"]),
+            },
+        );
+    }
+
+    for relationship in elements(xml, "Relationship") {
+        let lookup = |tag: &str| child_text(relationship, tag).and_then(|o| object_ids.get(&o).cloned());
+        let (Some(predecessor), Some(successor)) =
+            (lookup("PredecessorActivityObjectId"), lookup("SuccessorActivityObjectId"))
+        else {
+            result.warnings.push("relationship between unknown activities; skipped".to_string());
+            continue;
+        };
+        let dependency = Dependency {
+            id: predecessor,
+            kind: child_text(relationship, "Type").as_deref().and_then(DependencyType::parse).unwrap_or_default(),
+            lag_days: child_number(relationship, "Lag").unwrap_or(0.0) / HOURS_PER_DAY,
+        };
+        if let Some(activity) = result.schedule.activities.iter_mut().find(|a| a.id == successor) {
+            activity.predecessors.push(dependency);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ms_project_csv_with_quoted_fields() {
+        let csv = "\u{feff}ID,Name,Duration,Predecessors,Start,Summary\r\n\
+                   1,Build,15 days,,\"Mon 3/3/25\",Yes\r\n\
+                   2,\"Site prep, clearing\",5 days,,Mon 3/3/25,No\r\n\
+                   3,Footings,1w,\"2FS+2 days\",3/12/25,No\r\n\
+                   4,Framing,80h,\"3SS+1d;2\",,No\r\n\
+                   5,Roof,??,4,,No\r\n\
+                   6,Handover,0 days,\"5,4FF\",,No\r\n";
+        let result = import(ImportFormat::Csv, csv, &ColumnMapping::default()).unwrap();
+        let activities = &result.schedule.activities;
+
+        assert_eq!(activities.len(), 4);
+        assert_eq!(activities[0].name, "Site prep, clearing");
+        assert_eq!(activities[0].start, NaiveDate::from_ymd_opt(2025, 3, 3));
+        assert_eq!(activities[1].duration_days, 5.0);
+        assert_eq!(activities[1].predecessors[0].lag_days, 2.0);
+        assert_eq!(activities[2].duration_days, 10.0);
+        assert_eq!(activities[2].predecessors[0].kind, DependencyType::SS);
+        assert_eq!(activities[3].predecessors, vec![Dependency { id: "4".to_string(), kind: DependencyType::FF, lag_days: 0.0 }]);
+
+        // Summary row, unreadable duration and the link to it
+        assert_eq!(result.warnings.len(), 3);
+        assert!(result.schedule.analyze().is_ok());
+    }
+
+    #[test]
+    fn test_custom_mapping() {
+        let csv = "Code;Descr;Dur (h);After\nA1010;Excavate;16;\nA1020;Pour;8;A1010: FS\n";
+        let mapping = ColumnMapping {
+            id: Some("code".to_string()),
+            duration: Some("Dur (h)".to_string()),
+            predecessors: Some("After".to_string()),
+            ..Default::default()
+        };
+        let result = import(ImportFormat::Csv, csv, &mapping).unwrap();
+        assert_eq!(result.schedule.activities[1].predecessors[0].id, "A1010");
+        // Bare numbers are days, whatever the header says
+        assert_eq!(result.schedule.activities[0].duration_days, 16.0);
+
+        let missing = ColumnMapping { id: Some("Activity".to_string()), ..Default::default() };
+        assert!(import(ImportFormat::Csv, csv, &missing).unwrap_err().contains("'Activity'"));
+    }
+
+    #[test]
+    fn test_durations_and_dates() {
+        assert_eq!(parse_duration("5 days?"), Some(5.0));
+        assert_eq!(parse_duration("2 wks"), Some(10.0));
+        assert_eq!(parse_duration("PT40H0M0S"), Some(5.0));
+        assert_eq!(parse_duration("-4h"), Some(-0.5));
+        assert_eq!(parse_duration("soon"), None);
+
+        let date = NaiveDate::from_ymd_opt(2025, 3, 14);
+        assert_eq!(parse_date("2025-03-14T08:00:00"), date);
+        assert_eq!(parse_date("14-Mar-25 08:00"), date);
+        assert_eq!(parse_date("Fri 3/14/25 8:00 AM"), date);
+        assert_eq!(working_days(date.unwrap(), NaiveDate::from_ymd_opt(2025, 3, 18).unwrap()), 3.0);
+    }
+
+    #[test]
+    fn test_ms_project_xml() {
+        let xml = r#"<?xml version="1.0"?>
+            <Project xmlns="http://schemas.microsoft.com/project">
+              <Tasks>
+                <Task><UID>0</UID><Name>Project</Name><Summary>1</Summary><Duration>PT80H0M0S</Duration></Task>
+                <Task><UID>1</UID><Name>Dig &amp; pour</Name><Duration>PT24H0M0S</Duration>
+                  <Start>2025-03-03T08:00:00</Start>
+                  <Baseline><Number>0</Number><Start>2025-02-24T08:00:00</Start></Baseline></Task>
+                <Task><UID>2</UID><Name>Walls</Name><Duration>PT40H0M0S</Duration><Milestone>0</Milestone>
+                  <PredecessorLink><PredecessorUID>1</PredecessorUID><Type>1</Type><LinkLag>4800</LinkLag></PredecessorLink>
+                </Task>
+              </Tasks>
+            </Project>"#;
+        let result = import(ImportFormat::MsProjectXml, xml, &ColumnMapping::default()).unwrap();
+        let activities = &result.schedule.activities;
+        assert_eq!(activities.len(), 2);
+        assert_eq!(activities[0].name, "Dig & pour");
+        assert_eq!(activities[0].baseline_start, NaiveDate::from_ymd_opt(2025, 2, 24));
+        assert_eq!(activities[1].predecessors[0].lag_days, 1.0);
+        assert_eq!(result.schedule.analyze().unwrap().duration_days, 9.0);
+    }
+
+    #[test]
+    fn test_primavera_xml() {
+        let xml = r#"<APIBusinessObjects><Project>
+              <Activity><Id>A100</Id><ObjectId>11</ObjectId><Name>Excavate</Name><PlannedDuration>40</PlannedDuration></Activity>
+              <Activity><Id>A110</Id><ObjectId>12</ObjectId><Name>Pour</Name><PlannedDuration>16</PlannedDuration></Activity>
+              <Relationship><PredecessorActivityObjectId>11</PredecessorActivityObjectId>
+                <SuccessorActivityObjectId>12</SuccessorActivityObjectId><Type>Start to Start</Type><Lag>8</Lag></Relationship>
+            </Project></APIBusinessObjects>"#;
+        let result = import(ImportFormat::PrimaveraXml, xml, &ColumnMapping::default()).unwrap();
+        let pour = &result.schedule.activities[1];
+        assert_eq!(pour.duration_days, 2.0);
+        assert_eq!(pour.predecessors[0], Dependency { id: "A100".to_string(), kind: DependencyType::SS, lag_days: 1.0 });
+    }
+}
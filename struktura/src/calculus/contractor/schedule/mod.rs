@@ -0,0 +1,442 @@
+// ============================================================================
+// Activity Schedules
+//
+// The scheduling calculators take aggregate figures (task count, average
+// duration, peak demand). Real projects arrive as activity lists exported
+// from MS Project or Primavera, so a schedule can be attached to the
+// parameters instead: a critical-path pass measures it and fills in the
+// figures each calculator needs. Values sent explicitly in `additional`
+// always win over measured ones.
+//
+// - import.rs:  CSV / XML exports mapped onto `Activity`
+// - gantt.rs:   bar layout with baselines, rendered to SVG
+// - alerts.rs:  approaching and slipped milestones
+// - saved.rs:   per-organization schedules and the milestone alert sweep
+// - windows.rs: as-planned vs as-built windows delay analysis
+// ============================================================================
+
+pub mod alerts;
+pub mod gantt;
+pub mod import;
+pub mod saved;
+pub mod windows;
+
+use crate::calculus::contractor::{
+    errors::{ContractingError, ContractingResult},
+    models::ContractingParameters,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Floats below this (in days) count as zero
+const FLOAT_TOLERANCE: f64 = 1e-6;
+
+/// How a successor is tied to its predecessor
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DependencyType {
+    /// Finish-to-start
+    #[default]
+    FS,
+    /// Start-to-start
+    SS,
+    /// Finish-to-finish
+    FF,
+    /// Start-to-finish
+    SF,
+}
+
+impl DependencyType {
+    pub fn parse(code: &str) -> Option<Self> {
+        match code.trim().to_ascii_uppercase().as_str() {
+            "FS" | "FINISH TO START" | "PR_FS" | "1" => Some(DependencyType::FS),
+            "SS" | "START TO START" | "PR_SS" | "3" => Some(DependencyType::SS),
+            "FF" | "FINISH TO FINISH" | "PR_FF" | "0" => Some(DependencyType::FF),
+            "SF" | "START TO FINISH" | "PR_SF" | "2" => Some(DependencyType::SF),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dependency {
+    /// Predecessor activity id
+    pub id: String,
+    #[serde(default)]
+    pub kind: DependencyType,
+    /// Working days; negative for leads
+    #[serde(default)]
+    pub lag_days: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Activity {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// Working days; zero for milestones
+    pub duration_days: f64,
+    #[serde(default)]
+    pub predecessors: Vec<Dependency>,
+    /// Crew or resource units the activity needs while it runs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_units: Option<f64>,
+    /// Phase or WBS heading the activity rolls up to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    /// Equipment working on the activity
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub equipment: Vec<EquipmentUse>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finish: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_start: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_finish: Option<NaiveDate>,
+}
+
+/// Equipment assigned to an activity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquipmentUse {
+    /// Equipment class, e.g. `excavator` or `dump_truck`
+    pub kind: String,
+    #[serde(default = "default_count")]
+    pub count: f64,
+    /// Engine hours for all units; count × duration × shift length when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<f64>,
+    /// Fuel burn in L/h at load; the class default when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuel_rate: Option<f64>,
+}
+
+fn default_count() -> f64 {
+    1.0
+}
+
+impl Activity {
+    pub fn is_milestone(&self) -> bool {
+        self.duration_days == 0.0
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Schedule {
+    /// Project start; the earliest activity start when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDate>,
+    pub activities: Vec<Activity>,
+}
+
+/// Early/late dates of one activity, in days from project start
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledActivity {
+    pub id: String,
+    pub early_start: f64,
+    pub early_finish: f64,
+    pub late_start: f64,
+    pub late_finish: f64,
+    pub total_float: f64,
+    pub critical: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CriticalPathAnalysis {
+    pub duration_days: f64,
+    /// Critical activity ids in start order
+    pub critical_path: Vec<String>,
+    /// In schedule order
+    pub activities: Vec<ScheduledActivity>,
+}
+
+impl CriticalPathAnalysis {
+    /// Highest total resource demand on any working day of the early schedule
+    pub fn peak_resource_demand(&self, schedule: &Schedule) -> Option<f64> {
+        if schedule.activities.iter().all(|a| a.resource_units.is_none()) {
+            return None;
+        }
+        let days = self.duration_days.ceil() as usize;
+        let peak = (0..days)
+            .map(|day| {
+                let midday = day as f64 + 0.5;
+                schedule
+                    .activities
+                    .iter()
+                    .zip(&self.activities)
+                    .filter(|(_, s)| s.early_start <= midday && midday < s.early_finish)
+                    .filter_map(|(a, _)| a.resource_units)
+                    .sum::<f64>()
+            })
+            .fold(0.0, f64::max);
+        Some(peak)
+    }
+}
+
+impl Schedule {
+    pub fn project_start(&self) -> Option<NaiveDate> {
+        self.start_date.or_else(|| self.activities.iter().filter_map(|a| a.start).min())
+    }
+
+    /// Forward and backward pass over the activity network
+    pub fn analyze(&self) -> Result<CriticalPathAnalysis, String> {
+        if self.activities.is_empty() {
+            return Err("schedule has no activities".to_string());
+        }
+        let index: HashMap<&str, usize> =
+            self.activities.iter().enumerate().map(|(i, a)| (a.id.as_str(), i)).collect();
+        if index.len() != self.activities.len() {
+            return Err("activity ids must be unique".to_string());
+        }
+
+        // (predecessor, successor, dependency) edges
+        let mut edges = Vec::new();
+        for (j, activity) in self.activities.iter().enumerate() {
+            if activity.duration_days < 0.0 || !activity.duration_days.is_finite() {
+                return Err(format!("activity '{}' has an invalid duration", activity.id));
+            }
+            for dep in &activity.predecessors {
+                let i = *index
+                    .get(dep.id.as_str())
+                    .ok_or_else(|| format!("activity '{}' depends on unknown activity '{}'", activity.id, dep.id))?;
+                edges.push((i, j, dep));
+            }
+        }
+
+        let order = topological_order(self.activities.len(), &edges)
+            .ok_or_else(|| "activity dependencies form a loop".to_string())?;
+        let duration = |i: usize| self.activities[i].duration_days;
+
+        let mut early_start = vec![0.0_f64; self.activities.len()];
+        for &j in &order {
+            for &(i, _, dep) in edges.iter().filter(|(_, s, _)| *s == j) {
+                let es_i = early_start[i];
+                let ef_i = es_i + duration(i);
+                let bound = match dep.kind {
+                    DependencyType::FS => ef_i + dep.lag_days,
+                    DependencyType::SS => es_i + dep.lag_days,
+                    DependencyType::FF => ef_i + dep.lag_days - duration(j),
+                    DependencyType::SF => es_i + dep.lag_days - duration(j),
+                };
+                early_start[j] = early_start[j].max(bound);
+            }
+        }
+        let project = (0..self.activities.len()).map(|i| early_start[i] + duration(i)).fold(0.0, f64::max);
+
+        let mut late_finish = vec![project; self.activities.len()];
+        for &i in order.iter().rev() {
+            for &(_, j, dep) in edges.iter().filter(|(p, _, _)| *p == i) {
+                let lf_j = late_finish[j];
+                let ls_j = lf_j - duration(j);
+                let bound = match dep.kind {
+                    DependencyType::FS => ls_j - dep.lag_days,
+                    DependencyType::SS => ls_j - dep.lag_days + duration(i),
+                    DependencyType::FF => lf_j - dep.lag_days,
+                    DependencyType::SF => lf_j - dep.lag_days + duration(i),
+                };
+                late_finish[i] = late_finish[i].min(bound);
+            }
+        }
+
+        let activities: Vec<ScheduledActivity> = self
+            .activities
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                let late_start = late_finish[i] - a.duration_days;
+                let total_float = late_start - early_start[i];
+                ScheduledActivity {
+                    id: a.id.clone(),
+                    early_start: early_start[i],
+                    early_finish: early_start[i] + a.duration_days,
+                    late_start,
+                    late_finish: late_finish[i],
+                    total_float,
+                    critical: total_float.abs() < FLOAT_TOLERANCE,
+                }
+            })
+            .collect();
+
+        let mut critical: Vec<&ScheduledActivity> = activities.iter().filter(|a| a.critical).collect();
+        critical.sort_by(|a, b| a.early_start.total_cmp(&b.early_start));
+
+        Ok(CriticalPathAnalysis {
+            duration_days: project,
+            critical_path: critical.iter().map(|a| a.id.clone()).collect(),
+            activities,
+        })
+    }
+
+    /// Aggregate inputs for a scheduling calculator, measured from the schedule
+    pub fn derived_parameters(&self, calculator: &str) -> Result<Vec<(&'static str, f64)>, String> {
+        let analysis = self.analyze()?;
+        let count = self.activities.len() as f64;
+        let total: f64 = self.activities.iter().map(|a| a.duration_days).sum();
+
+        let params = match calculator {
+            "critical_path" => vec![
+                ("total_tasks", count),
+                ("avg_duration", total / count),
+                // The calculator's critical duration is tasks x (1 - factor) x average
+                ("parallel_factor", if total > 0.0 { (1.0 - analysis.duration_days / total).clamp(0.0, 1.0) } else { 0.0 }),
+            ],
+            "resource_leveling" => {
+                let mut params = vec![("project_duration", analysis.duration_days)];
+                if let Some(peak) = analysis.peak_resource_demand(self) {
+                    params.push(("peak_demand", peak));
+                }
+                params
+            }
+            "gantt_chart" => {
+                let mut params = vec![("duration", analysis.duration_days)];
+                if let Some(start) = self.project_start().and_then(|d| d.and_hms_opt(0, 0, 0)) {
+                    params.push(("start_date", start.and_utc().timestamp() as f64));
+                }
+                let milestones = self.activities.iter().filter(|a| a.is_milestone()).count();
+                if milestones > 0 {
+                    params.push(("milestones", milestones as f64));
+                }
+                params
+            }
+            "milestone_tracking" => {
+                let milestones = self.activities.iter().filter(|a| a.is_milestone()).count();
+                if milestones == 0 {
+                    Vec::new()
+                } else {
+                    let passed = alerts::passed_milestones(self, chrono::Utc::now().date_naive())?;
+                    vec![("total_milestones", milestones as f64), ("completed_milestones", passed as f64)]
+                }
+            }
+            _ => Vec::new(),
+        };
+        Ok(params)
+    }
+}
+
+/// Fill unset `additional` values from the attached schedule, if any
+pub fn fill_parameters(params: &mut ContractingParameters, calculator: &str) -> ContractingResult<()> {
+    let Some(schedule) = params.schedule.as_ref() else {
+        return Ok(());
+    };
+    let derived = schedule.derived_parameters(calculator).map_err(|message| ContractingError::DomainError {
+        field: "schedule".to_string(),
+        message,
+    })?;
+
+    let additional = params.additional.get_or_insert_with(HashMap::new);
+    for (key, value) in derived {
+        additional.entry(key.to_string()).or_insert(value);
+    }
+    Ok(())
+}
+
+/// Kahn's algorithm; `None` when the graph has a cycle
+fn topological_order(count: usize, edges: &[(usize, usize, &Dependency)]) -> Option<Vec<usize>> {
+    let mut in_degree = vec![0usize; count];
+    for &(_, j, _) in edges {
+        in_degree[j] += 1;
+    }
+    let mut queue: VecDeque<usize> = (0..count).filter(|&i| in_degree[i] == 0).collect();
+    let mut order = Vec::with_capacity(count);
+    while let Some(i) = queue.pop_front() {
+        order.push(i);
+        for &(_, j, _) in edges.iter().filter(|(p, _, _)| *p == i) {
+            in_degree[j] -= 1;
+            if in_degree[j] == 0 {
+                queue.push_back(j);
+            }
+        }
+    }
+    (order.len() == count).then_some(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(super) fn activity(id: &str, duration: f64, predecessors: &[(&str, DependencyType, f64)]) -> Activity {
+        Activity {
+            id: id.to_string(),
+            name: id.to_string(),
+            duration_days: duration,
+            predecessors: predecessors
+                .iter()
+                .map(|(p, kind, lag)| Dependency { id: p.to_string(), kind: *kind, lag_days: *lag })
+                .collect(),
+            resource_units: None,
+            phase: None,
+            equipment: Vec::new(),
+            start: None,
+            finish: None,
+            baseline_start: None,
+            baseline_finish: None,
+        }
+    }
+
+    fn sample() -> Schedule {
+        use DependencyType::*;
+        Schedule {
+            start_date: NaiveDate::from_ymd_opt(2025, 3, 3),
+            activities: vec![
+                activity("A", 5.0, &[]),
+                activity("B", 3.0, &[("A", FS, 0.0)]),
+                activity("C", 8.0, &[("A", FS, 0.0)]),
+                activity("D", 2.0, &[("B", FS, 0.0), ("C", FS, 1.0)]),
+                activity("M", 0.0, &[("D", FS, 0.0)]),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_forward_and_backward_pass() {
+        let analysis = sample().analyze().unwrap();
+        assert_eq!(analysis.duration_days, 16.0);
+        assert_eq!(analysis.critical_path, ["A", "C", "D", "M"]);
+
+        let b = &analysis.activities[1];
+        assert_eq!((b.early_start, b.late_start, b.total_float), (5.0, 11.0, 6.0));
+    }
+
+    #[test]
+    fn test_start_and_finish_links() {
+        use DependencyType::*;
+        let schedule = Schedule {
+            start_date: None,
+            activities: vec![
+                activity("A", 10.0, &[]),
+                activity("B", 4.0, &[("A", SS, 2.0)]),
+                activity("C", 3.0, &[("A", FF, 2.0)]),
+            ],
+        };
+        let analysis = schedule.analyze().unwrap();
+        assert_eq!(analysis.activities[1].early_start, 2.0);
+        assert_eq!(analysis.activities[2].early_finish, 12.0);
+        assert_eq!(analysis.duration_days, 12.0);
+    }
+
+    #[test]
+    fn test_rejects_loops_and_unknown_predecessors() {
+        use DependencyType::*;
+        let looped = Schedule {
+            start_date: None,
+            activities: vec![activity("A", 1.0, &[("B", FS, 0.0)]), activity("B", 1.0, &[("A", FS, 0.0)])],
+        };
+        assert!(looped.analyze().unwrap_err().contains("loop"));
+
+        let dangling = Schedule { start_date: None, activities: vec![activity("A", 1.0, &[("Z", FS, 0.0)])] };
+        assert!(dangling.analyze().unwrap_err().contains("'Z'"));
+    }
+
+    #[test]
+    fn test_derived_parameters_reproduce_cpm_duration() {
+        let schedule = sample();
+        let params: HashMap<_, _> = schedule.derived_parameters("critical_path").unwrap().into_iter().collect();
+        let critical = params["total_tasks"] * (1.0 - params["parallel_factor"]) * params["avg_duration"];
+        assert!((critical - 16.0).abs() < 1e-9);
+
+        let gantt: HashMap<_, _> = schedule.derived_parameters("gantt_chart").unwrap().into_iter().collect();
+        assert_eq!(gantt["milestones"], 1.0);
+        assert_eq!(gantt["start_date"], 1740960000.0);
+    }
+}
@@ -0,0 +1,418 @@
+//! Saved schedules and their milestone alerts
+//!
+//! Organizations keep schedules between sessions and choose where alerts
+//! go. A background sweep checks every schedule with an alert channel,
+//! sends what is newly due through the notification subsystem and records
+//! it, so each alert is delivered once.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::types::Json as SqlJson;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use super::alerts::{self, AlertKind, MilestoneAlert, DEFAULT_LEAD_DAYS};
+use super::Schedule;
+use crate::notify::{Channel, Notification, Notifier};
+use crate::sec::{self, AppError};
+use crate::state::AppState;
+use crate::tenancy::{TenantOwned, TenantScope};
+
+/// Minutes between alert sweeps unless MILESTONE_ALERT_INTERVAL_MINUTES is set
+const DEFAULT_SWEEP_MINUTES: u64 = 60;
+/// Longest lead time accepted, in days
+const MAX_LEAD_DAYS: i32 = 365;
+
+// ============================================================================
+// Models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct SavedSchedule {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub name: String,
+    pub schedule: SqlJson<Schedule>,
+    pub alert_lead_days: Vec<i32>,
+    pub alert_email: Option<String>,
+    pub alert_webhook_url: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl TenantOwned for SavedSchedule {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+impl SavedSchedule {
+    pub fn channels(&self) -> Vec<Channel> {
+        let email = self.alert_email.clone().map(Channel::Email);
+        let webhook = self.alert_webhook_url.clone().map(Channel::Webhook);
+        email.into_iter().chain(webhook).collect()
+    }
+
+    pub fn alerts(&self, today: chrono::NaiveDate) -> Result<Vec<MilestoneAlert>, String> {
+        let leads: Vec<i64> = self.alert_lead_days.iter().map(|d| i64::from(*d)).collect();
+        alerts::milestone_alerts(&self.schedule, today, &leads)
+    }
+
+    fn notification(&self, alert: &MilestoneAlert) -> Notification {
+        let event = match alert.kind {
+            AlertKind::Approaching { .. } => "milestone.approaching",
+            AlertKind::Slipped { .. } => "milestone.slipped",
+        };
+        Notification {
+            event: event.to_string(),
+            subject: alert.subject(&self.name),
+            text: alert.message(),
+            data: serde_json::json!({
+                "schedule_id": self.id,
+                "schedule_name": self.name,
+                "alert": alert,
+            }),
+        }
+    }
+}
+
+fn default_lead_days() -> Vec<i32> {
+    DEFAULT_LEAD_DAYS.iter().map(|d| *d as i32).collect()
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[validate(schema(function = "validate_schedule"))]
+pub struct SavedSchedulePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    pub schedule: Schedule,
+    /// Days before a milestone falls due to alert; empty sends slip alerts only
+    #[serde(default = "default_lead_days")]
+    #[validate(length(max = 10), custom(function = "validate_lead_days"))]
+    pub alert_lead_days: Vec<i32>,
+    #[validate(email)]
+    pub alert_email: Option<String>,
+    #[validate(url)]
+    pub alert_webhook_url: Option<String>,
+}
+
+fn validate_schedule(payload: &SavedSchedulePayload) -> Result<(), ValidationError> {
+    payload.schedule.analyze().map(|_| ()).map_err(|message| {
+        let mut error = ValidationError::new("schedule");
+        error.message = Some(message.into());
+        error
+    })
+}
+
+fn validate_lead_days(days: &[i32]) -> Result<(), ValidationError> {
+    if days.iter().all(|d| (0..=MAX_LEAD_DAYS).contains(d)) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("lead days between 0 and 365"))
+    }
+}
+
+const SCHEDULE_COLUMNS: &str = "id, org_id, name, schedule, alert_lead_days, alert_email, \
+    alert_webhook_url, created_at, updated_at";
+
+// ============================================================================
+// Alert sweep
+// ============================================================================
+
+/// Send every alert that is due and not yet delivered; returns how many went out
+///
+/// An alert counts as delivered once any of its schedule's channels accepts
+/// it. Failed deliveries are logged and retried on the next sweep.
+pub async fn send_due_alerts(pool: &PgPool, notifier: &Notifier) -> Result<usize, sqlx::Error> {
+    let schedules = sqlx::query_as::<_, SavedSchedule>(&format!(
+        "SELECT {SCHEDULE_COLUMNS} FROM saved_schedules \
+         WHERE alert_email IS NOT NULL OR alert_webhook_url IS NOT NULL"
+    ))
+    .fetch_all(pool)
+    .await?;
+
+    let today = chrono::Utc::now().date_naive();
+    let mut delivered = 0;
+    for saved in schedules {
+        let due = match saved.alerts(today) {
+            Ok(due) => due,
+            Err(e) => {
+                tracing::warn!(schedule_id = %saved.id, error = %e, "schedule cannot be analysed for alerts");
+                continue;
+            }
+        };
+        if due.is_empty() {
+            continue;
+        }
+
+        let sent: HashSet<(String, String)> = sqlx::query_as::<_, (String, String)>(
+            "SELECT milestone_id, alert_key FROM milestone_alerts_sent WHERE schedule_id = $1",
+        )
+        .bind(saved.id)
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .collect();
+
+        let channels: Vec<Channel> = saved.channels().into_iter().filter(|c| notifier.supports(c)).collect();
+        for alert in due.iter().filter(|a| !sent.contains(&(a.milestone_id.clone(), a.key()))) {
+            let notification = saved.notification(alert);
+            let mut accepted = false;
+            for channel in &channels {
+                match notifier.send(channel, &notification).await {
+                    Ok(()) => accepted = true,
+                    Err(e) => tracing::warn!(schedule_id = %saved.id, error = %e, "milestone alert delivery failed"),
+                }
+            }
+            if accepted {
+                sqlx::query(
+                    "INSERT INTO milestone_alerts_sent (schedule_id, milestone_id, alert_key) \
+                     VALUES ($1, $2, $3) ON CONFLICT DO NOTHING",
+                )
+                .bind(saved.id)
+                .bind(&alert.milestone_id)
+                .bind(alert.key())
+                .execute(pool)
+                .await?;
+                delivered += 1;
+            }
+        }
+    }
+    Ok(delivered)
+}
+
+/// Run `send_due_alerts` in the background every MILESTONE_ALERT_INTERVAL_MINUTES
+pub fn spawn_alert_sweep(pool: PgPool) {
+    let minutes = std::env::var("MILESTONE_ALERT_INTERVAL_MINUTES")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|m| *m > 0)
+        .unwrap_or(DEFAULT_SWEEP_MINUTES);
+    let notifier = Notifier::from_env();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(minutes * 60));
+        loop {
+            interval.tick().await;
+            match send_due_alerts(&pool, &notifier).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!(count, "sent milestone alerts"),
+                Err(e) => tracing::error!(error = %e, "milestone alert sweep failed"),
+            }
+        }
+    });
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+pub async fn list_schedules_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+) -> Result<Json<Vec<SavedSchedule>>, AppError> {
+    let schedules = sqlx::query_as::<_, SavedSchedule>(&format!(
+        "SELECT {SCHEDULE_COLUMNS} FROM saved_schedules WHERE org_id = $1 ORDER BY name"
+    ))
+    .bind(scope.org_id)
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    Ok(Json(scope.guard_all(schedules)?))
+}
+
+async fn fetch(app_state: &AppState, scope: &TenantScope, id: Uuid) -> Result<SavedSchedule, AppError> {
+    let schedule = sqlx::query_as::<_, SavedSchedule>(&format!(
+        "SELECT {SCHEDULE_COLUMNS} FROM saved_schedules WHERE id = $1 AND org_id = $2"
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    scope.guard(schedule)
+}
+
+pub async fn get_schedule_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<SavedSchedule>, AppError> {
+    Ok(Json(fetch(&app_state, &scope, id).await?))
+}
+
+/// Alerts the schedule would raise today, sent or not
+pub async fn schedule_alerts_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<MilestoneAlert>>, AppError> {
+    let saved = fetch(&app_state, &scope, id).await?;
+    saved
+        .alerts(chrono::Utc::now().date_naive())
+        .map(Json)
+        .map_err(AppError::Internal)
+}
+
+pub async fn create_schedule_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<SavedSchedulePayload>,
+) -> Result<(StatusCode, Json<SavedSchedule>), AppError> {
+    payload.validate()?;
+
+    let schedule = sqlx::query_as::<_, SavedSchedule>(&format!(
+        r#"
+        INSERT INTO saved_schedules
+            (org_id, name, schedule, alert_lead_days, alert_email, alert_webhook_url, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING {SCHEDULE_COLUMNS}
+        "#
+    ))
+    .bind(scope.org_id)
+    .bind(&payload.name)
+    .bind(SqlJson(&payload.schedule))
+    .bind(&payload.alert_lead_days)
+    .bind(&payload.alert_email)
+    .bind(&payload.alert_webhook_url)
+    .bind(scope.user_id)
+    .fetch_one(&app_state.pool)
+    .await?;
+
+    sec::log_security_event("SCHEDULE_CREATE", Some(&scope.username), None, "Success");
+
+    Ok((StatusCode::CREATED, Json(schedule)))
+}
+
+pub async fn update_schedule_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<SavedSchedulePayload>,
+) -> Result<Json<SavedSchedule>, AppError> {
+    payload.validate()?;
+
+    let schedule = sqlx::query_as::<_, SavedSchedule>(&format!(
+        r#"
+        UPDATE saved_schedules
+        SET name = $3, schedule = $4, alert_lead_days = $5, alert_email = $6,
+            alert_webhook_url = $7, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1 AND org_id = $2
+        RETURNING {SCHEDULE_COLUMNS}
+        "#
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .bind(&payload.name)
+    .bind(SqlJson(&payload.schedule))
+    .bind(&payload.alert_lead_days)
+    .bind(&payload.alert_email)
+    .bind(&payload.alert_webhook_url)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("SCHEDULE_UPDATE", Some(&scope.username), None, "Success");
+
+    Ok(Json(schedule))
+}
+
+pub async fn delete_schedule_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let result = sqlx::query("DELETE FROM saved_schedules WHERE id = $1 AND org_id = $2")
+        .bind(id)
+        .bind(scope.org_id)
+        .execute(&app_state.pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("SCHEDULE_DELETE", Some(&scope.username), None, "Success");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::contractor::schedule::{Activity, Dependency, DependencyType};
+
+    fn activity(id: &str, after: Option<&str>) -> Activity {
+        Activity {
+            id: id.to_string(),
+            name: id.to_string(),
+            duration_days: 1.0,
+            predecessors: after
+                .map(|p| vec![Dependency { id: p.to_string(), kind: DependencyType::FS, lag_days: 0.0 }])
+                .unwrap_or_default(),
+            resource_units: None,
+            phase: None,
+            equipment: Vec::new(),
+            start: None,
+            finish: None,
+            baseline_start: None,
+            baseline_finish: None,
+        }
+    }
+
+    fn payload() -> SavedSchedulePayload {
+        SavedSchedulePayload {
+            name: "Tower B".to_string(),
+            schedule: Schedule { start_date: None, activities: vec![activity("A", None), activity("B", Some("A"))] },
+            alert_lead_days: default_lead_days(),
+            alert_email: Some("pm@example.com".to_string()),
+            alert_webhook_url: None,
+        }
+    }
+
+    #[test]
+    fn test_payload_validation() {
+        assert!(payload().validate().is_ok());
+
+        let mut looped = payload();
+        looped.schedule.activities[0] = activity("A", Some("B"));
+        assert!(looped.validate().is_err());
+
+        let mut far = payload();
+        far.alert_lead_days = vec![400];
+        assert!(far.validate().is_err());
+
+        let mut bad_hook = payload();
+        bad_hook.alert_webhook_url = Some("not a url".to_string());
+        assert!(bad_hook.validate().is_err());
+    }
+
+    #[test]
+    fn test_channels() {
+        let saved = SavedSchedule {
+            id: Uuid::new_v4(),
+            org_id: Uuid::new_v4(),
+            name: "Tower B".to_string(),
+            schedule: SqlJson(payload().schedule),
+            alert_lead_days: vec![],
+            alert_email: None,
+            alert_webhook_url: Some("https://example.com/hook".to_string()),
+            created_at: OffsetDateTime::now_utc(),
+            updated_at: OffsetDateTime::now_utc(),
+        };
+        assert_eq!(saved.channels(), [Channel::Webhook("https://example.com/hook".to_string())]);
+        // No start date, so nothing can be placed on the calendar
+        assert!(saved.alerts(chrono::Utc::now().date_naive()).unwrap().is_empty());
+    }
+}
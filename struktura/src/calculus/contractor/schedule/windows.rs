@@ -0,0 +1,482 @@
+//! Windows delay analysis: as-planned against as-built
+//!
+//! The project is cut into fixed windows. At the end of each window the
+//! critical activity most recently due is compared with its baseline: how
+//! far it finished (or is running) behind is the project slip at that date,
+//! and the change across a window is the delay the window caused. A
+//! negative change is time recovered.
+//!
+//! Each window's delay is then attributed from the delay events logged in
+//! it. Owner and design delays are compensable; weather and third-party
+//! delays are excusable only; contractor and subcontractor delays are not
+//! excusable. Days with both owner-side and contractor-side delays logged
+//! are concurrent and earn time without money. Windows with delay but no
+//! logged cause are left unattributed rather than guessed.
+//!
+//! Activity `start` / `finish` are the as-built dates (current forecast for
+//! unfinished work); `baseline_start` / `baseline_finish` the as-planned.
+
+use super::Schedule;
+use crate::calculus::contractor::{
+    errors::{ContractingError, ContractingResult},
+    field_log::{DelayCause, FieldLog, WORKDAY_HOURS},
+    models::ContractingParameters,
+};
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Window length when none is given, in calendar days
+pub const DEFAULT_WINDOW_DAYS: i64 = 30;
+
+/// Days below this are treated as zero
+const DAY_TOLERANCE: f64 = 1e-6;
+
+/// A delay logged on site
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelayEvent {
+    pub date: NaiveDate,
+    pub cause: DelayCause,
+    /// Working days lost
+    pub days: f64,
+}
+
+impl DelayEvent {
+    /// One event per cause per report, concurrent hours already scaled
+    pub fn from_field_log(log: &FieldLog) -> Vec<DelayEvent> {
+        let mut events = Vec::new();
+        for report in &log.reports {
+            for (cause, hours) in report.delay_hours() {
+                events.push(DelayEvent { date: report.report_date, cause, days: hours / WORKDAY_HOURS });
+            }
+        }
+        events.sort_by_key(|e| (e.date, e.cause.as_str()));
+        events
+    }
+}
+
+/// Who bears the delay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DelayClass {
+    /// Owner-caused: time and money
+    Compensable,
+    /// Neither party's fault: time only
+    Excusable,
+    /// Owner and contractor delays on the same days: time only
+    Concurrent,
+    /// Contractor-caused: no relief
+    NonExcusable,
+    /// No cause logged
+    Unattributed,
+    /// Time recovered, or nothing lost
+    None,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DelayBreakdown {
+    pub compensable: f64,
+    pub excusable: f64,
+    pub concurrent: f64,
+    pub non_excusable: f64,
+    pub unattributed: f64,
+}
+
+impl DelayBreakdown {
+    fn total(&self) -> f64 {
+        self.compensable + self.excusable + self.concurrent + self.non_excusable + self.unattributed
+    }
+
+    fn add(&mut self, other: &DelayBreakdown) {
+        self.compensable += other.compensable;
+        self.excusable += other.excusable;
+        self.concurrent += other.concurrent;
+        self.non_excusable += other.non_excusable;
+        self.unattributed += other.unattributed;
+    }
+
+    fn scaled(&self, factor: f64) -> DelayBreakdown {
+        DelayBreakdown {
+            compensable: self.compensable * factor,
+            excusable: self.excusable * factor,
+            concurrent: self.concurrent * factor,
+            non_excusable: self.non_excusable * factor,
+            unattributed: self.unattributed * factor,
+        }
+    }
+
+    /// The largest share names the window
+    fn dominant(&self) -> DelayClass {
+        [
+            (DelayClass::Compensable, self.compensable),
+            (DelayClass::Excusable, self.excusable),
+            (DelayClass::Concurrent, self.concurrent),
+            (DelayClass::NonExcusable, self.non_excusable),
+            (DelayClass::Unattributed, self.unattributed),
+        ]
+        .into_iter()
+        .filter(|(_, days)| *days > DAY_TOLERANCE)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(DelayClass::None, |(class, _)| class)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DelayWindow {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Project slip at the window's end, in calendar days
+    pub slip_days: f64,
+    /// Slip added in the window; negative when time was recovered
+    pub delay_days: f64,
+    /// Delay days logged on site in the window, by class
+    pub logged: DelayBreakdown,
+    /// `delay_days` apportioned over the logged classes
+    pub attributed: DelayBreakdown,
+    pub classification: DelayClass,
+    /// Critical activities running late in the window
+    pub critical_activities: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DelayClaimSummary {
+    pub planned_finish: NaiveDate,
+    pub actual_finish: NaiveDate,
+    pub planned_duration_days: f64,
+    pub actual_duration_days: f64,
+    /// Net slip over all windows
+    pub total_delay_days: f64,
+    pub attributed: DelayBreakdown,
+    /// Compensable, excusable and concurrent days
+    pub extension_of_time_days: f64,
+    /// One line per window with delay, for the claim narrative
+    pub narrative: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WindowAnalysis {
+    pub window_days: i64,
+    pub windows: Vec<DelayWindow>,
+    pub summary: DelayClaimSummary,
+}
+
+/// Dates of one critical activity, as planned and as built
+struct Tracked<'a> {
+    id: &'a str,
+    planned_finish: NaiveDate,
+    actual_start: Option<NaiveDate>,
+    actual_finish: Option<NaiveDate>,
+}
+
+impl Tracked<'_> {
+    /// Days behind baseline as seen at `date`
+    fn lag_at(&self, date: NaiveDate) -> f64 {
+        match self.actual_finish {
+            Some(finish) if finish <= date => (finish - self.planned_finish).num_days() as f64,
+            _ => (date - self.planned_finish).num_days() as f64,
+        }
+    }
+}
+
+/// Project slip at `date`: lag of the critical activity most recently due
+fn slip_at(tracked: &[Tracked], date: NaiveDate) -> f64 {
+    let due = tracked.iter().filter(|t| t.planned_finish <= date);
+    let Some(latest) = due.clone().map(|t| t.planned_finish).max() else {
+        return 0.0;
+    };
+    due.filter(|t| t.planned_finish == latest)
+        .map(|t| t.lag_at(date))
+        .fold(f64::NEG_INFINITY, f64::max)
+        .max(0.0)
+}
+
+/// Logged delay days of `events` in `start..=end`, by class
+fn logged_breakdown(events: &[DelayEvent], start: NaiveDate, end: NaiveDate) -> DelayBreakdown {
+    let mut breakdown = DelayBreakdown::default();
+    let mut day = start;
+    while day <= end {
+        let (mut owner, mut neutral, mut contractor) = (0.0, 0.0, 0.0);
+        for event in events.iter().filter(|e| e.date == day) {
+            match event.cause {
+                cause if cause.is_compensable() => owner += event.days,
+                cause if cause.is_excusable() => neutral += event.days,
+                _ => contractor += event.days,
+            }
+        }
+        let concurrent = f64::min(owner, contractor);
+        breakdown.concurrent += concurrent;
+        breakdown.compensable += owner - concurrent;
+        breakdown.non_excusable += contractor - concurrent;
+        breakdown.excusable += neutral;
+        day += Duration::days(1);
+    }
+    breakdown
+}
+
+impl Schedule {
+    /// Windowed as-planned vs as-built comparison with delays attributed
+    /// from `events`
+    pub fn delay_windows(&self, events: &[DelayEvent], window_days: i64) -> Result<WindowAnalysis, String> {
+        if window_days < 1 {
+            return Err("Window length must be at least one day".to_string());
+        }
+        let analysis = self.analyze()?;
+
+        let planned_start = self.activities.iter().filter_map(|a| a.baseline_start).min();
+        let planned_finish = self.activities.iter().filter_map(|a| a.baseline_finish).max();
+        let actual_start = self.activities.iter().filter_map(|a| a.start).min();
+        let actual_finish = self.activities.iter().filter_map(|a| a.finish).max();
+        let (Some(planned_start), Some(planned_finish), Some(actual_start), Some(actual_finish)) =
+            (planned_start, planned_finish, actual_start, actual_finish)
+        else {
+            return Err("Windows analysis needs baseline and actual dates on the activities".to_string());
+        };
+
+        let tracked: Vec<Tracked> = self
+            .activities
+            .iter()
+            .zip(&analysis.activities)
+            .filter(|(_, scheduled)| scheduled.critical)
+            .filter_map(|(activity, _)| {
+                Some(Tracked {
+                    id: &activity.id,
+                    planned_finish: activity.baseline_finish?,
+                    actual_start: activity.start,
+                    actual_finish: activity.finish,
+                })
+            })
+            .collect();
+        if tracked.is_empty() {
+            return Err("No critical activity has a baseline finish".to_string());
+        }
+
+        let origin = planned_start.min(actual_start);
+        let horizon = planned_finish.max(actual_finish);
+        let mut windows = Vec::new();
+        let mut previous_slip = 0.0;
+        let mut start = origin;
+        while start <= horizon {
+            let end = (start + Duration::days(window_days - 1)).min(horizon);
+            let slip = slip_at(&tracked, end);
+            let delay = slip - previous_slip;
+            let logged = logged_breakdown(events, start, end);
+
+            let attributed = if delay <= DAY_TOLERANCE {
+                DelayBreakdown::default()
+            } else if logged.total() <= DAY_TOLERANCE {
+                DelayBreakdown { unattributed: delay, ..Default::default() }
+            } else {
+                logged.scaled(delay / logged.total())
+            };
+            let critical_activities = tracked
+                .iter()
+                .filter(|t| t.actual_start.is_none_or(|s| s <= end) && t.planned_finish <= end && t.lag_at(end) > 0.0)
+                .filter(|t| t.actual_finish.is_none_or(|f| f >= start))
+                .map(|t| t.id.to_string())
+                .collect();
+
+            windows.push(DelayWindow {
+                start,
+                end,
+                slip_days: slip,
+                delay_days: delay,
+                classification: attributed.dominant(),
+                logged,
+                attributed,
+                critical_activities,
+            });
+            previous_slip = slip;
+            start = end + Duration::days(1);
+        }
+
+        let mut attributed = DelayBreakdown::default();
+        for window in &windows {
+            attributed.add(&window.attributed);
+        }
+        let narrative = windows
+            .iter()
+            .filter(|w| w.delay_days.abs() > DAY_TOLERANCE)
+            .map(|w| {
+                if w.delay_days < 0.0 {
+                    format!("{} to {}: {:.1} days recovered", w.start, w.end, -w.delay_days)
+                } else {
+                    let activities = if w.critical_activities.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" on {}", w.critical_activities.join(", "))
+                    };
+                    format!(
+                        "{} to {}: {:.1} days of critical delay{} ({})",
+                        w.start,
+                        w.end,
+                        w.delay_days,
+                        activities,
+                        class_label(w.classification)
+                    )
+                }
+            })
+            .collect();
+
+        let span = |from: NaiveDate, to: NaiveDate| ((to - from).num_days() + 1) as f64;
+        Ok(WindowAnalysis {
+            window_days,
+            summary: DelayClaimSummary {
+                planned_finish,
+                actual_finish,
+                planned_duration_days: span(planned_start, planned_finish),
+                actual_duration_days: span(actual_start, actual_finish),
+                total_delay_days: windows.last().map_or(0.0, |w| w.slip_days),
+                extension_of_time_days: attributed.compensable + attributed.excusable + attributed.concurrent,
+                attributed,
+                narrative,
+            },
+            windows,
+        })
+    }
+}
+
+/// Windows analysis of the attached schedule, delays taken from the
+/// attached field log; `None` without a schedule
+pub fn analyze_parameters(params: &ContractingParameters) -> ContractingResult<Option<WindowAnalysis>> {
+    let Some(schedule) = params.schedule.as_ref() else {
+        return Ok(None);
+    };
+    let events = params.field_log.as_ref().map(DelayEvent::from_field_log).unwrap_or_default();
+    let window_days = params
+        .additional
+        .as_ref()
+        .and_then(|a| a.get("window_days"))
+        .map_or(DEFAULT_WINDOW_DAYS, |days| days.round() as i64);
+
+    schedule
+        .delay_windows(&events, window_days)
+        .map(Some)
+        .map_err(|message| ContractingError::DomainError { field: "schedule".to_string(), message })
+}
+
+/// Fill `delay_analysis` inputs from the as-planned and as-built spans
+pub fn fill_parameters(params: &mut ContractingParameters) -> ContractingResult<()> {
+    let Some(analysis) = analyze_parameters(params)? else {
+        return Ok(());
+    };
+    let summary = &analysis.summary;
+    let mut derived = vec![
+        ("planned_duration", summary.planned_duration_days),
+        ("actual_duration", summary.actual_duration_days),
+    ];
+    let delayed = summary.attributed.total();
+    if delayed > DAY_TOLERANCE {
+        derived.push(("delay_cause_factor", summary.attributed.compensable / delayed));
+    }
+
+    let additional = params.additional.get_or_insert_with(HashMap::new);
+    for (key, value) in derived {
+        additional.entry(key.to_string()).or_insert(value);
+    }
+    Ok(())
+}
+
+fn class_label(class: DelayClass) -> &'static str {
+    match class {
+        DelayClass::Compensable => "mainly compensable",
+        DelayClass::Excusable => "mainly excusable",
+        DelayClass::Concurrent => "mainly concurrent",
+        DelayClass::NonExcusable => "mainly non-excusable",
+        DelayClass::Unattributed => "no cause logged",
+        DelayClass::None => "no delay",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::contractor::schedule::{Activity, Dependency, DependencyType};
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn activity(id: &str, after: Option<&str>, planned: (NaiveDate, NaiveDate), built: (NaiveDate, NaiveDate)) -> Activity {
+        Activity {
+            id: id.to_string(),
+            name: id.to_string(),
+            duration_days: ((planned.1 - planned.0).num_days() + 1) as f64,
+            predecessors: after
+                .map(|p| vec![Dependency { id: p.to_string(), kind: DependencyType::FS, lag_days: 0.0 }])
+                .unwrap_or_default(),
+            resource_units: None,
+            phase: None,
+            equipment: Vec::new(),
+            start: Some(built.0),
+            finish: Some(built.1),
+            baseline_start: Some(planned.0),
+            baseline_finish: Some(planned.1),
+        }
+    }
+
+    // Foundations planned for March finish 10 days late; framing planned
+    // for April absorbs 4 of them
+    fn schedule() -> Schedule {
+        Schedule {
+            start_date: None,
+            activities: vec![
+                activity("FND", None, (date(3, 1), date(3, 31)), (date(3, 1), date(4, 10))),
+                activity("FRM", Some("FND"), (date(4, 1), date(4, 30)), (date(4, 11), date(5, 6))),
+            ],
+        }
+    }
+
+    fn event(month: u32, day: u32, cause: DelayCause) -> DelayEvent {
+        DelayEvent { date: date(month, day), cause, days: 1.0 }
+    }
+
+    #[test]
+    fn test_windows_track_slip_and_recovery() {
+        let analysis = schedule().delay_windows(&[], 31).unwrap();
+        assert_eq!(analysis.windows.len(), 3);
+        // Window 1 ends on the foundations' planned finish: nothing late yet
+        assert_eq!(analysis.windows[0].slip_days, 0.0);
+        // Window 2 ends 1 May with framing, due 30 April, still running
+        assert_eq!(analysis.windows[1].slip_days, 1.0);
+        assert_eq!(analysis.windows[2].slip_days, 6.0);
+        assert_eq!(analysis.summary.total_delay_days, 6.0);
+        assert_eq!(analysis.windows[2].classification, DelayClass::Unattributed);
+        assert_eq!(analysis.summary.planned_duration_days, 61.0);
+        assert_eq!(analysis.summary.actual_duration_days, 67.0);
+    }
+
+    #[test]
+    fn test_attribution_and_concurrency() {
+        let events = [
+            event(5, 2, DelayCause::Owner),
+            event(5, 2, DelayCause::Contractor),
+            event(5, 3, DelayCause::Design),
+            event(5, 4, DelayCause::Owner),
+            event(5, 4, DelayCause::Weather),
+        ];
+        let analysis = schedule().delay_windows(&events, 31).unwrap();
+        let window = &analysis.windows[2];
+        assert!((window.logged.concurrent - 1.0).abs() < 1e-9);
+        assert!((window.logged.compensable - 2.0).abs() < 1e-9);
+        assert!((window.logged.excusable - 1.0).abs() < 1e-9);
+        // 5 days of delay spread over four logged days
+        assert!((window.attributed.total() - 5.0).abs() < 1e-9);
+        assert!((window.attributed.compensable - 2.5).abs() < 1e-9);
+        assert_eq!(window.classification, DelayClass::Compensable);
+        let summary = &analysis.summary;
+        assert!((summary.extension_of_time_days - 5.0).abs() < 1e-9);
+        // Window 2 had no cause logged
+        assert!((summary.attributed.unattributed - 1.0).abs() < 1e-9);
+        assert!(summary.narrative.iter().any(|line| line.contains("FRM")));
+    }
+
+    #[test]
+    fn test_needs_baselines() {
+        let mut schedule = schedule();
+        for activity in &mut schedule.activities {
+            activity.baseline_finish = None;
+        }
+        assert!(schedule.delay_windows(&[], 30).is_err());
+        assert!(self::schedule().delay_windows(&[], 0).is_err());
+    }
+}
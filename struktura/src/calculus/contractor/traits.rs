@@ -2,6 +2,7 @@ use crate::calculus::contractor::{
     errors::{ContractingError, ContractingResult},
     models::*,
 };
+use crate::pricing::MaterialId;
 use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -53,6 +54,16 @@ pub trait ContractorCalculator: Send + Sync {
         // Default: no postprocessing
         Ok(())
     }
+    
+    /// Optional: Materials whose costs can come from live regional prices
+    fn priced_materials(&self, _params: &ContractingParameters) -> Vec<MaterialId> {
+        Vec::new()
+    }
+    
+    /// Optional: Whether `origin` and `location` are geocoded before calculating
+    fn needs_coordinates(&self) -> bool {
+        false
+    }
 }
 
 /// Parameter validator trait for reusable validation logic
@@ -137,6 +148,21 @@ pub trait ParameterValidator {
         
         Ok(value)
     }
+
+    /// Get an optional additional parameter, range-checked only when supplied
+    fn optional_param(
+        &self,
+        params: &ContractingParameters,
+        name: &str,
+        default: f64,
+        min: f64,
+        max: f64,
+    ) -> ContractingResult<f64> {
+        match self.get_additional_param(params, name, Some(min), Some(max)) {
+            Err(ContractingError::MissingParameter { .. }) => Ok(default),
+            other => other,
+        }
+    }
     
     /// Get calculator ID for error messages
     fn calculator_id(&self) -> &str;
@@ -0,0 +1,144 @@
+// ============================================================================
+// Contracting Warning Catalogue
+//
+// Stable warning codes for contracting calculators. Mirrors the engineering
+// catalogue so frontends can filter and translate warnings across tiers.
+// ============================================================================
+
+use crate::calculus::contractor::models::{ContractingWarning, WarningSeverity};
+use serde::Serialize;
+
+/// Catalogue entry describing one warning a calculator may emit
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct WarningCode {
+    pub code: &'static str,
+    pub calculator: &'static str,
+    pub severity: WarningSeverity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affected_parameter: Option<&'static str>,
+    pub description: &'static str,
+}
+
+impl WarningCode {
+    /// Build a warning for this code with a context-specific message
+    pub fn warn(&self, message: impl Into<String>) -> ContractingWarning {
+        ContractingWarning {
+            code: self.code.to_string(),
+            severity: self.severity,
+            message: message.into(),
+            affected_parameter: self.affected_parameter.map(str::to_string),
+        }
+    }
+}
+
+const fn code(
+    code: &'static str,
+    calculator: &'static str,
+    severity: WarningSeverity,
+    affected_parameter: Option<&'static str>,
+    description: &'static str,
+) -> WarningCode {
+    WarningCode { code, calculator, severity, affected_parameter, description }
+}
+
+use WarningSeverity::{High, Medium};
+
+pub const RISK_HIGH_LEVEL: WarningCode = code(
+    "risk_assessment.high_risk", "risk_assessment", High, Some("additional.project_complexity"),
+    "Adjusted project risk exceeds 50%",
+);
+pub const PROFIT_LOW_MARGIN: WarningCode = code(
+    "profit_margin.low_margin", "profit_margin", High, Some("additional.bid_price"),
+    "Profit margin below 10% of total cost",
+);
+pub const LEVELING_SCHEDULE_EXTENSION: WarningCode = code(
+    "resource_leveling.schedule_extension", "resource_leveling", Medium,
+    Some("additional.available_resources"),
+    "Peak demand exceeds available resources by more than 50%",
+);
+pub const QUALITY_HIGH_DEFECT_RATE: WarningCode = code(
+    "quality_control.high_defect_rate", "quality_control", High, Some("additional.defective_items"),
+    "Defect rate above 5%",
+);
+pub const PROGRESS_SIGNIFICANT_DELAY: WarningCode = code(
+    "progress_tracking.significant_delay", "progress_tracking", High, Some("additional.actual_progress"),
+    "Actual progress trails plan by more than 10 points",
+);
+pub const CASH_FLOW_NEGATIVE: WarningCode = code(
+    "cash_flow_analysis.negative_cash_flow", "cash_flow_analysis", High, Some("additional.outflows"),
+    "Outflows exceed inflows for the period",
+);
+pub const ALLOCATION_HIGH_UTILIZATION: WarningCode = code(
+    "resource_allocation.high_utilization", "resource_allocation", Medium, None,
+    "Labor or equipment utilization above 90%",
+);
+pub const GANTT_BASELINE_SLIP: WarningCode = code(
+    "gantt_chart.baseline_slip", "gantt_chart", Medium, Some("schedule"),
+    "Activities finish later than their baseline",
+);
+pub const MILESTONE_SLIPPED: WarningCode = code(
+    "milestone_tracking.slipped", "milestone_tracking", High, Some("schedule"),
+    "A milestone is forecast later than its baseline",
+);
+pub const MILESTONE_APPROACHING: WarningCode = code(
+    "milestone_tracking.approaching", "milestone_tracking", Medium, Some("schedule"),
+    "A milestone falls due within the alert lead time",
+);
+pub const DELAY_UNATTRIBUTED: WarningCode = code(
+    "delay_analysis.unattributed", "delay_analysis", Medium, Some("field_log"),
+    "Critical delay in a window has no cause logged",
+);
+pub const CLOSEOUT_OVERDUE_ITEMS: WarningCode = code(
+    "project_closeout.overdue_items", "project_closeout", Medium, Some("punch_list"),
+    "Open punch items are past their due date",
+);
+pub const QUALITY_PENDING_HOLD_POINTS: WarningCode = code(
+    "quality_control.pending_hold_points", "quality_control", Medium, Some("inspections"),
+    "Inspection hold points have no recorded result",
+);
+pub const PREQUAL_THRESHOLD_FAILURE: WarningCode = code(
+    "subcontractor_evaluation.threshold_failure", "subcontractor_evaluation", High, Some("prequalification"),
+    "Candidate misses a prequalification minimum",
+);
+
+/// All warning codes emitted by contracting calculators
+pub const WARNING_CATALOGUE: &[WarningCode] = &[
+    RISK_HIGH_LEVEL,
+    PROFIT_LOW_MARGIN,
+    LEVELING_SCHEDULE_EXTENSION,
+    QUALITY_HIGH_DEFECT_RATE,
+    PROGRESS_SIGNIFICANT_DELAY,
+    CASH_FLOW_NEGATIVE,
+    ALLOCATION_HIGH_UTILIZATION,
+    GANTT_BASELINE_SLIP,
+    MILESTONE_SLIPPED,
+    MILESTONE_APPROACHING,
+    DELAY_UNATTRIBUTED,
+    CLOSEOUT_OVERDUE_ITEMS,
+    QUALITY_PENDING_HOLD_POINTS,
+    PREQUAL_THRESHOLD_FAILURE,
+];
+
+/// Look up a catalogue entry by its stable code
+pub fn find(code: &str) -> Option<&'static WarningCode> {
+    WARNING_CATALOGUE.iter().find(|w| w.code == code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::contractor::registry::create_default_registry;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_codes_are_unique_and_registered() {
+        let registry = create_default_registry();
+        let mut seen = HashSet::new();
+        for w in WARNING_CATALOGUE {
+            assert!(seen.insert(w.code), "duplicate warning code {}", w.code);
+            assert!(w.code.starts_with(&format!("{}.", w.calculator)));
+            assert!(registry.find(w.calculator).is_ok(), "unknown calculator {}", w.calculator);
+        }
+        assert!(find("profit_margin.low_margin").is_some());
+    }
+}
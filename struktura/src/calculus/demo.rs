@@ -0,0 +1,429 @@
+// ============================================================================
+// Demo / Sandbox Responses
+//
+// Every registered calculator gets one realistic example: a request built
+// from its own parameter metadata (defaults, typical ranges) and the
+// response the calculator produced for it at startup. The demo routes serve
+// these canned pairs without touching the database, the trial quota or plan
+// entitlements, so sales demos and frontend work run against a bare binary.
+// ============================================================================
+
+use crate::calculus::beginner::{BeginnerParameters, BeginnerRegistry};
+use crate::calculus::contractor::{self, ContractingParameters, ContractingRegistry};
+use crate::calculus::engineer::{self, EngineeringParameters, EngineeringRegistry};
+use crate::calculus::recommendation::Tier;
+use crate::state::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::sync::Arc;
+
+/// One canned request/response pair
+#[derive(Debug, Clone, Serialize)]
+pub struct DemoExample {
+    pub tier: Tier,
+    pub calculator_id: String,
+    pub name: String,
+    /// Body accepted by the real `/calculate` endpoint
+    pub request: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<Value>,
+    /// Why no response could be produced from metadata alone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unavailable_reason: Option<String>,
+}
+
+/// Listing entry for the demo index
+#[derive(Debug, Serialize)]
+pub struct DemoSummary {
+    pub tier: Tier,
+    pub calculator_id: String,
+    pub name: String,
+    pub available: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DemoIndex {
+    /// True when the server itself runs without a database
+    pub sandbox: bool,
+    pub calculators: Vec<DemoSummary>,
+}
+
+/// Pre-baked examples for every calculator, built once at startup
+#[derive(Debug, Clone, Default)]
+pub struct DemoCatalogue {
+    examples: Vec<DemoExample>,
+}
+
+impl DemoCatalogue {
+    pub async fn build(
+        beginner: &BeginnerRegistry,
+        engineer: &EngineeringRegistry,
+        contractor: &ContractingRegistry,
+    ) -> Self {
+        let mut examples = Vec::new();
+
+        for calc in beginner.all() {
+            let meta = calc.metadata();
+            let params: Vec<SampleParam> = meta
+                .parameters
+                .iter()
+                .map(|p| SampleParam {
+                    path: p.path.clone(),
+                    value: (p.data_type == "number")
+                        .then(|| json!(sample_number(None, p.typical_range, p.min_value, p.max_value))),
+                })
+                .chain(overrides(Tier::Beginner, calc.id()))
+                .collect();
+            let parameters = sample_parameters(json!({ "width": 0.0, "length": 0.0, "height": 0.0 }), &params, |_| None);
+
+            let outcome = match serde_json::from_value::<BeginnerParameters>(parameters.clone()) {
+                Ok(p) => beginner.execute(&calc, p).await.map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            examples.push(DemoExample::new(Tier::Beginner, calc.id(), &meta.name, parameters, outcome));
+        }
+
+        for calc in engineer.all() {
+            let meta = calc.metadata();
+            let params: Vec<SampleParam> = meta
+                .parameters
+                .iter()
+                .map(|p| SampleParam {
+                    path: p.path.clone(),
+                    value: match &p.data_type {
+                        engineer::models::ParameterType::Number => {
+                            Some(json!(sample_number(p.default_value, p.typical_range, p.min_value, p.max_value)))
+                        }
+                        engineer::models::ParameterType::Integer => {
+                            Some(json!(sample_number(p.default_value, p.typical_range, p.min_value, p.max_value).round()))
+                        }
+                        engineer::models::ParameterType::Enum(options) if !is_numeric_map(&p.path) => {
+                            options.first().map(|o| json!(o))
+                        }
+                        _ => None,
+                    },
+                })
+                .chain(overrides(Tier::Engineer, calc.id()))
+                .collect();
+            let parameters = sample_parameters(json!({ "dimensions": {} }), &params, |section| match section {
+                "material" => serde_json::to_value(engineer::MaterialProperties::default()).ok(),
+                "loads" => serde_json::to_value(engineer::LoadCase::default()).ok(),
+                "safety_factors" => serde_json::to_value(engineer::SafetyFactors::default()).ok(),
+                _ => None,
+            });
+
+            let outcome = match serde_json::from_value::<EngineeringParameters>(parameters.clone()) {
+                Ok(p) => engineer
+                    .execute(&calc, p)
+                    .await
+                    .map(|mut r| {
+                        // The real endpoint only returns the trace on request
+                        r.calculation_trace = None;
+                        r
+                    })
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            examples.push(DemoExample::new(Tier::Engineer, calc.id(), &meta.name, parameters, outcome));
+        }
+
+        for calc in contractor.all() {
+            let meta = calc.metadata();
+            let params: Vec<SampleParam> = meta
+                .parameters
+                .iter()
+                .map(|p| SampleParam {
+                    path: p.path.clone(),
+                    value: match &p.data_type {
+                        contractor::models::ParameterType::Number => {
+                            Some(json!(sample_number(p.default_value, p.typical_range, p.min_value, p.max_value)))
+                        }
+                        contractor::models::ParameterType::Integer => {
+                            Some(json!(sample_number(p.default_value, p.typical_range, p.min_value, p.max_value).round()))
+                        }
+                        contractor::models::ParameterType::Enum(options) if !is_numeric_map(&p.path) => {
+                            options.first().map(|o| json!(o))
+                        }
+                        _ => None,
+                    },
+                })
+                .chain(overrides(Tier::Contractor, calc.id()))
+                .collect();
+            let parameters = sample_parameters(json!({ "dimensions": {} }), &params, |section| match section {
+                "material" => serde_json::to_value(contractor::MaterialProperties::default()).ok(),
+                "resources" => serde_json::to_value(contractor::ResourceRequirements::default()).ok(),
+                "safety_factors" => serde_json::to_value(contractor::SafetyFactors::default()).ok(),
+                "origin" | "location" => serde_json::to_value(crate::pricing::Location::new("US")).ok(),
+                _ => None,
+            });
+
+            let outcome = match serde_json::from_value::<ContractingParameters>(parameters.clone()) {
+                Ok(p) => contractor.execute(&calc, p).await.map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            examples.push(DemoExample::new(Tier::Contractor, calc.id(), &meta.name, parameters, outcome));
+        }
+
+        examples.sort_by(|a, b| (a.tier as u8, &a.calculator_id).cmp(&(b.tier as u8, &b.calculator_id)));
+        Self { examples }
+    }
+
+    pub fn get(&self, tier: Tier, id: &str) -> Option<&DemoExample> {
+        self.examples.iter().find(|e| e.tier == tier && e.calculator_id == id)
+    }
+
+    pub fn examples(&self) -> &[DemoExample] {
+        &self.examples
+    }
+}
+
+impl DemoExample {
+    fn new<R: Serialize, E: ToString>(
+        tier: Tier,
+        id: &str,
+        name: &str,
+        parameters: Value,
+        outcome: Result<R, E>,
+    ) -> Self {
+        let (response, unavailable_reason) = match outcome.map(|r| serde_json::to_value(r)) {
+            Ok(Ok(value)) => (Some(value), None),
+            Ok(Err(e)) => (None, Some(e.to_string())),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        Self {
+            tier,
+            calculator_id: id.to_string(),
+            name: name.to_string(),
+            request: json!({ "calculation_type": id, "parameters": parameters }),
+            response,
+            unavailable_reason,
+        }
+    }
+}
+
+// ============================================================================
+// SAMPLE INPUTS
+// ============================================================================
+
+/// Hand-picked inputs where metadata defaults alone describe an infeasible
+/// case (e.g. demand the default line cannot meet)
+const OVERRIDES: &[(Tier, &str, &str, f64)] = &[
+    (Tier::Engineer, "production_line_balancing", "additional.desired_output_per_day", 30.0),
+    (Tier::Engineer, "slope_stability", "additional.slope_angle", 25.0),
+    (Tier::Contractor, "resource_allocation", "resources.labor_hours", 1200.0),
+    (Tier::Contractor, "resource_allocation", "resources.equipment_hours", 400.0),
+    (Tier::Contractor, "resource_allocation", "additional.available_labor", 1600.0),
+    (Tier::Contractor, "resource_allocation", "additional.available_equipment", 500.0),
+    (Tier::Contractor, "travel_cost", "origin.latitude", 32.7767),
+    (Tier::Contractor, "travel_cost", "origin.longitude", -96.797),
+    (Tier::Contractor, "travel_cost", "location.latitude", 32.7555),
+    (Tier::Contractor, "travel_cost", "location.longitude", -97.3308),
+    (Tier::Contractor, "time_cost_tradeoff", "additional.normal_duration", 120.0),
+    (Tier::Contractor, "time_cost_tradeoff", "additional.normal_cost", 250_000.0),
+    (Tier::Contractor, "time_cost_tradeoff", "additional.crash_duration", 90.0),
+    (Tier::Contractor, "time_cost_tradeoff", "additional.crash_cost", 310_000.0),
+];
+
+fn overrides(tier: Tier, id: &str) -> impl Iterator<Item = SampleParam> + '_ {
+    OVERRIDES
+        .iter()
+        .filter(move |(t, calc, _, _)| *t == tier && *calc == id)
+        .map(|(_, _, path, value)| SampleParam { path: path.to_string(), value: Some(json!(value)) })
+}
+
+/// A metadata parameter and the value chosen for it (`None` = leave unset)
+struct SampleParam {
+    path: String,
+    value: Option<Value>,
+}
+
+/// Representative value: the declared default, else the middle of the
+/// typical range, else the middle of the allowed range
+fn sample_number(
+    default: Option<f64>,
+    typical: Option<(f64, f64)>,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> f64 {
+    if let Some(d) = default {
+        return d;
+    }
+    if let Some((lo, hi)) = typical {
+        return (lo + hi) / 2.0;
+    }
+    match (min, max) {
+        (Some(lo), Some(hi)) => (lo + hi) / 2.0,
+        (Some(lo), None) if lo > 0.0 => lo * 2.0,
+        (None, Some(hi)) if hi < 1.0 => hi / 2.0,
+        _ => 1.0,
+    }
+}
+
+/// `additional` and `dimensions` only hold numbers
+fn is_numeric_map(path: &str) -> bool {
+    path.starts_with("additional.") || path.starts_with("dimensions.")
+}
+
+/// Assemble a parameters object from dotted paths. `seed` supplies the
+/// defaults for a section the first time one of its fields is set, so
+/// required sibling fields are present.
+fn sample_parameters(root: Value, params: &[SampleParam], seed: impl Fn(&str) -> Option<Value>) -> Value {
+    let mut root = root;
+    for param in params {
+        let Some(value) = &param.value else { continue };
+        if let Some((section, _)) = param.path.split_once('.')
+            && root.get(section).is_none()
+            && let Some(defaults) = seed(section)
+        {
+            root[section] = defaults;
+        }
+        set_path(&mut root, &param.path, value.clone());
+    }
+    root
+}
+
+/// Set `a.b.c` in a JSON object, creating intermediate objects
+pub(crate) fn set_path(root: &mut Value, path: &str, value: Value) {
+    let mut current = root;
+    let mut segments = path.split('.').peekable();
+    while let Some(segment) = segments.next() {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+        let object = current.as_object_mut().expect("just made an object");
+        if segments.peek().is_none() {
+            object.insert(segment.to_string(), value);
+            return;
+        }
+        current = object.entry(segment).or_insert_with(|| Value::Object(Map::new()));
+    }
+}
+
+// ============================================================================
+// API
+// ============================================================================
+
+#[derive(Debug)]
+pub enum DemoError {
+    NotFound(String),
+    Unavailable(String),
+}
+
+impl IntoResponse for DemoError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            DemoError::NotFound(id) => (StatusCode::NOT_FOUND, format!("No demo for calculator '{}'", id)),
+            DemoError::Unavailable(reason) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("Calculator has no demo response: {}", reason),
+            ),
+        };
+        (status, Json(json!({ "error": message, "code": "demo_unavailable" }))).into_response()
+    }
+}
+
+/// Only the calculator is read - demo responses ignore the parameters
+#[derive(Debug, Deserialize)]
+pub struct DemoCalculateRequest {
+    pub calculation_type: String,
+}
+
+async fn index_handler(State(state): State<Arc<AppState>>) -> Json<DemoIndex> {
+    Json(DemoIndex {
+        sandbox: state.sandbox,
+        calculators: state
+            .demo
+            .examples()
+            .iter()
+            .map(|e| DemoSummary {
+                tier: e.tier,
+                calculator_id: e.calculator_id.clone(),
+                name: e.name.clone(),
+                available: e.response.is_some(),
+            })
+            .collect(),
+    })
+}
+
+async fn example_handler(
+    State(state): State<Arc<AppState>>,
+    Path((tier, id)): Path<(Tier, String)>,
+) -> Result<Json<DemoExample>, DemoError> {
+    state.demo.get(tier, &id).cloned().map(Json).ok_or(DemoError::NotFound(id))
+}
+
+/// Drop-in for `/api/v1/calculus/{tier}/calculate` returning the canned response
+async fn calculate_handler(
+    State(state): State<Arc<AppState>>,
+    Path(tier): Path<Tier>,
+    Json(payload): Json<DemoCalculateRequest>,
+) -> Result<Json<Value>, DemoError> {
+    let example = state
+        .demo
+        .get(tier, &payload.calculation_type)
+        .ok_or_else(|| DemoError::NotFound(payload.calculation_type.clone()))?;
+
+    match (&example.response, &example.unavailable_reason) {
+        (Some(response), _) => Ok(Json(response.clone())),
+        (None, reason) => Err(DemoError::Unavailable(reason.clone().unwrap_or_default())),
+    }
+}
+
+pub fn create_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(index_handler))
+        .route("/{tier}/calculate", post(calculate_handler))
+        .route("/{tier}/{id}", get(example_handler))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_path_creates_nested_objects() {
+        let mut root = json!({ "dimensions": {} });
+        set_path(&mut root, "dimensions.span", json!(6.0));
+        set_path(&mut root, "loads.dead_load", json!(12.5));
+        set_path(&mut root, "width", json!(3.0));
+        assert_eq!(root, json!({ "dimensions": { "span": 6.0 }, "loads": { "dead_load": 12.5 }, "width": 3.0 }));
+    }
+
+    #[test]
+    fn test_sample_number_preference() {
+        assert_eq!(sample_number(Some(1.5), Some((2.0, 4.0)), Some(0.0), Some(10.0)), 1.5);
+        assert_eq!(sample_number(None, Some((2.0, 4.0)), Some(0.0), Some(10.0)), 3.0);
+        assert_eq!(sample_number(None, None, Some(0.0), Some(10.0)), 5.0);
+        assert_eq!(sample_number(None, None, None, None), 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_most_calculators_have_demo_responses() {
+        let catalogue = DemoCatalogue::build(
+            &crate::calculus::beginner::create_default_registry(),
+            &crate::calculus::engineer::create_default_registry(),
+            &crate::calculus::contractor::create_default_registry(),
+        )
+        .await;
+
+        let total = catalogue.examples().len();
+        let available = catalogue.examples().iter().filter(|e| e.response.is_some()).count();
+        assert!(total > 0);
+        assert!(available * 10 >= total * 9, "only {} of {} calculators produced a demo", available, total);
+
+        // Every beginner calculator is fully described by its metadata
+        assert!(catalogue
+            .examples()
+            .iter()
+            .filter(|e| e.tier == Tier::Beginner)
+            .all(|e| e.response.is_some()));
+    }
+}
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{FOUNDATION_LARGE_FOOTING, FOUNDATION_SHALLOW_EMBEDMENT},
 };
 use async_trait::async_trait;
 
@@ -114,21 +115,36 @@ impl EngineerCalculator for FoundationDesignCalculator {
         let depth = params.dimensions.get("depth").copied().unwrap_or(1.5);
         let fos = params.safety_factors.as_ref().and_then(|s| s.bearing).unwrap_or(3.0);
 
+        let mut trace = CalculationTrace::new();
+
         let area_req = load / q_all;
+        trace.step(
+            "formulas.foundation_design.required_area",
+            &[("load", load), ("bearing_capacity", q_all)],
+            area_req,
+            "m²",
+        );
         let size = area_req.sqrt();
+        trace.step("formulas.foundation_design.footing_size", &[("area_req", area_req)], size, "m");
         let settlement_est = load / (q_all * fos) * 25.4; // mm, heuristic
+        trace.step(
+            "formulas.foundation_design.settlement_estimate",
+            &[("load", load), ("bearing_capacity", q_all), ("safety_factor", fos)],
+            settlement_est,
+            "mm",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if size > 3.0 {
-            warnings.push(format!("Large footing ({:.1}m). Consider mat foundation.", size));
+            warnings.push(FOUNDATION_LARGE_FOOTING.warn(format!("Large footing ({:.1}m). Consider mat foundation.", size)));
             recommendations.push("Verify eccentric loading if applicable".to_string());
         }
 
         if depth < 1.0 {
-            warnings.push("Shallow embedment. Frost protection may be required.".to_string());
+            warnings.push(FOUNDATION_SHALLOW_EMBEDMENT.warn("Shallow embedment. Frost protection may be required."));
         }
 
         compliance_notes.push("Bearing capacity per Terzaghi equation".to_string());
@@ -150,8 +166,8 @@ impl EngineerCalculator for FoundationDesignCalculator {
             calculation_type: "foundation_design".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -160,6 +176,8 @@ impl EngineerCalculator for FoundationDesignCalculator {
                 design_code_used: "ACI 318".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
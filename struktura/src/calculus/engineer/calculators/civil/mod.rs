@@ -12,6 +12,8 @@ pub mod foundation_design;
 pub mod slope_stability;
 pub mod settlement_analysis;
 pub mod soil_bearing_capacity;
+pub mod soil_compaction;
+pub mod pile_foundation;
 
 // Re-export calculators
 pub use retaining_wall::RetainingWallCalculator;
@@ -20,6 +22,8 @@ pub use foundation_design::FoundationDesignCalculator;
 pub use slope_stability::SlopeStabilityCalculator;
 pub use settlement_analysis::SettlementAnalysisCalculator;
 pub use soil_bearing_capacity::SoilBearingCapacityCalculator;
+pub use soil_compaction::SoilCompactionCalculator;
+pub use pile_foundation::PileFoundationCalculator;
 
 // ============================================================================
 // CIVIL ENGINEERING CONSTANTS
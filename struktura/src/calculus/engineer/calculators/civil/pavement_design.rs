@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{PAVEMENT_HIGH_ESAL, PAVEMENT_POOR_DRAINAGE},
 };
 use async_trait::async_trait;
 
@@ -113,27 +114,48 @@ impl EngineerCalculator for PavementDesignCalculator {
         let reliability = params.additional.as_ref().and_then(|a| a.get("reliability").copied()).unwrap_or(90.0);
         let drainage = params.additional.as_ref().and_then(|a| a.get("drainage_coeff").copied()).unwrap_or(1.0);
 
+        let mut trace = CalculationTrace::new();
+
         // Simplified AASHTO structural number calculation
         let zr = if reliability == 90.0 { -1.282 } else if reliability == 95.0 { -1.645 } else { -2.326 }; // 99%
         let so = 0.5; // Standard deviation
         let delta_psi = 2.5; // Serviceability loss
         let mr = cbr * 1500.0; // Resilient modulus psi
+        trace.step("formulas.pavement_design.resilient_modulus", &[("cbr", cbr)], mr, "psi");
         let sn = (2.32 * esal.log10() + 9.36 * (mr.log10() / 1000.0) + zr * so + 2.97) / 2.32; // Heuristic
+        trace.step(
+            "formulas.pavement_design.structural_number",
+            &[("esal", esal), ("resilient_modulus", mr), ("zr", zr), ("so", so)],
+            sn,
+            "dimensionless",
+        );
 
         let asphalt_thick = sn / ASPHALT_LAYER_COEFF * 25.4; // mm
+        trace.step(
+            "formulas.pavement_design.asphalt_thickness",
+            &[("sn", sn), ("layer_coefficient", ASPHALT_LAYER_COEFF)],
+            asphalt_thick,
+            "mm",
+        );
         let base_thick = sn * 0.3 / BASE_LAYER_COEFF * 25.4; // mm
+        trace.step(
+            "formulas.pavement_design.base_thickness",
+            &[("sn", sn), ("layer_coefficient", BASE_LAYER_COEFF)],
+            base_thick,
+            "mm",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if esal > ESAL_HEAVY_TRAFFIC {
-            warnings.push(format!("High ESAL ({:.0}). Verify traffic projections.", esal));
+            warnings.push(PAVEMENT_HIGH_ESAL.warn(format!("High ESAL ({:.0}). Verify traffic projections.", esal)));
             recommendations.push("Consider rigid pavement alternative".to_string());
         }
 
         if drainage < 0.8 {
-            warnings.push("Poor drainage. Risk of premature failure.".to_string());
+            warnings.push(PAVEMENT_POOR_DRAINAGE.warn("Poor drainage. Risk of premature failure."));
         }
 
         compliance_notes.push("Design per AASHTO 1993 empirical method".to_string());
@@ -153,8 +175,8 @@ impl EngineerCalculator for PavementDesignCalculator {
             calculation_type: "pavement_design".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -163,6 +185,8 @@ impl EngineerCalculator for PavementDesignCalculator {
                 design_code_used: "AASHTO 1993".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -0,0 +1,746 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{PILE_BLOCK_FAILURE, PILE_CLOSE_SPACING, PILE_EXCESSIVE_SETTLEMENT, PILE_GROUP_OVERLOADED},
+};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::f64::consts::PI;
+
+use super::concrete_properties::ELASTIC_MODULUS_C30;
+
+/// Unit weight of water (kN/m³)
+const UNIT_WEIGHT_WATER: f64 = 9.81;
+
+/// Bearing capacity factor for end bearing in clay
+const NC_PILE: f64 = 9.0;
+
+/// Slice height for integrating skin friction along the shaft (m)
+const SLICE: f64 = 0.25;
+
+/// Pile-soil friction angle below the soil friction angle for granular layers (°, API RP 2A)
+const INTERFACE_REDUCTION: f64 = 5.0;
+
+/// Granular soil design values by friction angle (API RP 2A):
+/// (φ °, Nq, limiting unit end bearing kPa, limiting unit skin friction kPa)
+const GRANULAR_LIMITS: [(f64, f64, f64, f64); 5] = [
+    (20.0, 8.0, 1900.0, 47.8),
+    (25.0, 12.0, 2900.0, 67.0),
+    (30.0, 20.0, 4800.0, 81.3),
+    (35.0, 40.0, 9600.0, 95.7),
+    (40.0, 50.0, 12000.0, 114.8),
+];
+
+/// Largest group the design search will try
+const MAX_PILES: usize = 100;
+
+/// One soil stratum, listed from the ground surface down
+#[derive(Debug, Clone, Deserialize)]
+struct SoilLayer {
+    #[serde(default)]
+    name: Option<String>,
+    /// Thickness (m); the deepest layer extends below the pile toe
+    thickness: f64,
+    /// Total unit weight (kN/m³)
+    #[serde(default = "default_unit_weight")]
+    unit_weight: f64,
+    /// Undrained shear strength (kPa) of a cohesive layer
+    #[serde(default)]
+    undrained_shear_strength: Option<f64>,
+    /// Friction angle (°) of a granular layer
+    #[serde(default)]
+    friction_angle: Option<f64>,
+}
+
+fn default_unit_weight() -> f64 {
+    18.0
+}
+
+/// Nq, limiting end bearing and limiting skin friction interpolated by friction angle
+fn granular_limits(phi: f64) -> (f64, f64, f64) {
+    let (first, last) = (GRANULAR_LIMITS[0], GRANULAR_LIMITS[GRANULAR_LIMITS.len() - 1]);
+    let row = if phi <= first.0 {
+        first
+    } else if phi >= last.0 {
+        last
+    } else {
+        GRANULAR_LIMITS
+            .windows(2)
+            .find(|w| phi <= w[1].0)
+            .map(|w| {
+                let t = (phi - w[0].0) / (w[1].0 - w[0].0);
+                let lerp = |a: f64, b: f64| a + (b - a) * t;
+                (phi, lerp(w[0].1, w[1].1), lerp(w[0].2, w[1].2), lerp(w[0].3, w[1].3))
+            })
+            .unwrap_or(last)
+    };
+    (row.1, row.2, row.3)
+}
+
+/// API RP 2A adhesion factor from the strength ratio ψ = cu/σ'v
+fn adhesion_factor(cu: f64, sigma_v: f64) -> f64 {
+    let psi = cu / sigma_v.max(1.0);
+    let alpha = if psi <= 1.0 { 0.5 * psi.powf(-0.5) } else { 0.5 * psi.powf(-0.25) };
+    alpha.min(1.0)
+}
+
+/// Soil profile with the groundwater level
+struct Profile {
+    layers: Vec<SoilLayer>,
+    water_table: f64,
+}
+
+impl Profile {
+    /// Index of the layer at depth `z`, the deepest layer continuing downwards
+    fn layer_at(&self, z: f64) -> usize {
+        let mut top = 0.0;
+        for (i, layer) in self.layers.iter().enumerate() {
+            if z < top + layer.thickness {
+                return i;
+            }
+            top += layer.thickness;
+        }
+        self.layers.len() - 1
+    }
+
+    /// Vertical effective stress at depth `z` (kPa)
+    fn effective_stress(&self, z: f64) -> f64 {
+        let mut top = 0.0;
+        let mut total = 0.0;
+        for (i, layer) in self.layers.iter().enumerate() {
+            let bottom = if i == self.layers.len() - 1 { f64::INFINITY } else { top + layer.thickness };
+            total += layer.unit_weight * (z.min(bottom) - top).max(0.0);
+            if z <= bottom {
+                break;
+            }
+            top = bottom;
+        }
+        total - UNIT_WEIGHT_WATER * (z - self.water_table).max(0.0)
+    }
+
+    /// Unit skin friction at depth `z` for the pile and for a block of soil enclosing the group (kPa)
+    ///
+    /// Cohesive layers use the alpha method on the pile and the full undrained
+    /// strength on the block perimeter; granular layers use the beta method on both.
+    fn skin_friction(&self, z: f64, k: f64) -> (f64, f64) {
+        let layer = &self.layers[self.layer_at(z)];
+        let sigma_v = self.effective_stress(z);
+        match (layer.undrained_shear_strength, layer.friction_angle) {
+            (Some(cu), _) => (adhesion_factor(cu, sigma_v) * cu, cu),
+            (None, Some(phi)) => {
+                let (_, _, fs_limit) = granular_limits(phi);
+                let fs = (k * sigma_v * (phi - INTERFACE_REDUCTION).to_radians().tan()).min(fs_limit);
+                (fs, fs)
+            }
+            (None, None) => (0.0, 0.0),
+        }
+    }
+
+    /// Unit end bearing at depth `z` (kPa)
+    fn end_bearing(&self, z: f64) -> f64 {
+        let layer = &self.layers[self.layer_at(z)];
+        match (layer.undrained_shear_strength, layer.friction_angle) {
+            (Some(cu), _) => NC_PILE * cu,
+            (None, Some(phi)) => {
+                let (nq, qb_limit, _) = granular_limits(phi);
+                (nq * self.effective_stress(z)).min(qb_limit)
+            }
+            (None, None) => 0.0,
+        }
+    }
+}
+
+/// Converse-Labarre efficiency of a rows × columns group
+fn group_efficiency(rows: usize, columns: usize, diameter: f64, spacing: f64) -> f64 {
+    let (m, n) = (rows as f64, columns as f64);
+    let theta = (diameter / spacing).atan().to_degrees();
+    1.0 - theta * ((n - 1.0) * m + (m - 1.0) * n) / (90.0 * m * n)
+}
+
+pub struct PileFoundationCalculator;
+
+impl ParameterValidator for PileFoundationCalculator {
+    fn calculator_id(&self) -> &str {
+        "pile_foundation"
+    }
+}
+
+impl PileFoundationCalculator {
+    /// Strata from `extended_parameters.layers`, or one uniform layer described in `additional`
+    fn profile(&self, params: &EngineeringParameters) -> EngineeringResult<Profile> {
+        let water_table = self.optional_param(params, "water_table_depth", f64::INFINITY, 0.0, 100.0)?;
+        let Some(value) = params.extended_parameters.as_ref().and_then(|e| e.get("layers")) else {
+            let unit_weight = self.optional_param(params, "unit_weight", default_unit_weight(), 12.0, 25.0)?;
+            let layer = match params.additional.as_ref().and_then(|a| a.get("undrained_shear_strength")) {
+                Some(_) => SoilLayer {
+                    name: Some("Clay".to_string()),
+                    thickness: 100.0,
+                    unit_weight,
+                    undrained_shear_strength: Some(self.get_additional_param(
+                        params,
+                        "undrained_shear_strength",
+                        Some(5.0),
+                        Some(500.0),
+                    )?),
+                    friction_angle: None,
+                },
+                None => SoilLayer {
+                    name: Some("Sand".to_string()),
+                    thickness: 100.0,
+                    unit_weight,
+                    undrained_shear_strength: None,
+                    friction_angle: Some(self.optional_param(params, "friction_angle", 32.0, 20.0, 45.0)?),
+                },
+            };
+            return Ok(Profile { layers: vec![layer], water_table });
+        };
+        let invalid = |reason: String| EngineeringError::InvalidParameter {
+            parameter: "layers".to_string(),
+            value: format!("{:?}", value),
+            reason,
+        };
+        let items = value.as_array().ok_or_else(|| invalid("Expected an array of soil layers".to_string()))?;
+        let layers: Vec<SoilLayer> =
+            serde_json::from_value(serde_json::Value::Array(items.clone())).map_err(|e| invalid(e.to_string()))?;
+        if layers.is_empty() || layers.len() > 20 {
+            return Err(invalid("Between 1 and 20 layers are required".to_string()));
+        }
+        for (i, layer) in layers.iter().enumerate() {
+            let strength_ok = match (layer.undrained_shear_strength, layer.friction_angle) {
+                (Some(cu), None) => (5.0..=500.0).contains(&cu),
+                (None, Some(phi)) => (20.0..=45.0).contains(&phi),
+                _ => false,
+            };
+            if !strength_ok || !(0.1..=100.0).contains(&layer.thickness) || !(12.0..=25.0).contains(&layer.unit_weight) {
+                return Err(invalid(format!(
+                    "Layer {} needs a thickness of 0.1-100 m, unit weight of 12-25 kN/m³ and either undrained_shear_strength (5-500 kPa) or friction_angle (20-45°)",
+                    layer.name.clone().unwrap_or_else(|| (i + 1).to_string())
+                )));
+            }
+        }
+        Ok(Profile { layers, water_table })
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for PileFoundationCalculator {
+    fn id(&self) -> &str {
+        "pile_foundation"
+    }
+
+    fn name(&self) -> &str {
+        "Pile Foundation Design"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Civil
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("pile_foundation", "Pile Foundation Design")
+            .category("civil")
+            .description("Single pile and pile group axial capacity by the alpha and beta methods, with group efficiency, block failure and group settlement")
+            .design_code("API RP 2A")
+            .design_code("FHWA GEC 12")
+            .parameter(ParameterMetadata {
+                name: "Dead Load".to_string(),
+                path: "loads.dead_load".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kN".to_string(),
+                description: "Service dead load on the pile cap".to_string(),
+                required: true,
+                default_value: Some(2000.0),
+                min_value: Some(50.0),
+                max_value: Some(100000.0),
+                typical_range: Some((1000.0, 10000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Live Load".to_string(),
+                path: "loads.live_load".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kN".to_string(),
+                description: "Service live load on the pile cap".to_string(),
+                required: false,
+                default_value: Some(1000.0),
+                min_value: Some(0.0),
+                max_value: Some(100000.0),
+                typical_range: Some((500.0, 5000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Pile Diameter".to_string(),
+                path: "dimensions.diameter".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Diameter of a circular concrete pile; 0.6 m when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.2),
+                max_value: Some(2.5),
+                typical_range: Some((0.3, 1.2)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Pile Length".to_string(),
+                path: "dimensions.length".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Embedded length below the pile cap; 15 m when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(3.0),
+                max_value: Some(80.0),
+                typical_range: Some((8.0, 30.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Soil Layers".to_string(),
+                path: "extended_parameters.layers".to_string(),
+                data_type: ParameterType::Array,
+                unit: "".to_string(),
+                description: "Objects with thickness (m), optional name and unit_weight (kN/m³), and either undrained_shear_strength (kPa) or friction_angle (°), listed from the surface down; the deepest layer continues below the toe".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec!["1 to 20 layers".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Undrained Shear Strength".to_string(),
+                path: "additional.undrained_shear_strength".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kPa".to_string(),
+                description: "Strength of a uniform clay profile used when no layers are given; a uniform sand is assumed when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(5.0),
+                max_value: Some(500.0),
+                typical_range: Some((25.0, 150.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Friction Angle".to_string(),
+                path: "additional.friction_angle".to_string(),
+                data_type: ParameterType::Number,
+                unit: "degrees".to_string(),
+                description: "Friction angle of the uniform sand profile used when no layers or undrained strength are given; 32° when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(20.0),
+                max_value: Some(45.0),
+                typical_range: Some((28.0, 38.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Unit Weight".to_string(),
+                path: "additional.unit_weight".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kN/m³".to_string(),
+                description: "Unit weight of the uniform profile; 18 kN/m³ when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(12.0),
+                max_value: Some(25.0),
+                typical_range: Some((16.0, 20.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Water Table Depth".to_string(),
+                path: "additional.water_table_depth".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Depth of groundwater below the pile cap; below the piles when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(100.0),
+                typical_range: Some((1.0, 10.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Lateral Earth Pressure Coefficient".to_string(),
+                path: "additional.earth_pressure_coefficient".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "K for the beta method in granular layers (0.8 bored or open-ended, 1.0 driven displacement); 0.8 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.3),
+                max_value: Some(2.0),
+                typical_range: Some((0.7, 1.2)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Pile Spacing".to_string(),
+                path: "additional.pile_spacing".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Centre-to-centre spacing in the group; three diameters when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.4),
+                max_value: Some(10.0),
+                typical_range: Some((1.5, 3.6)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Pile Rows".to_string(),
+                path: "additional.pile_rows".to_string(),
+                data_type: ParameterType::Number,
+                unit: "".to_string(),
+                description: "Rows in a fixed group layout; the smallest adequate near-square group is designed when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(10.0),
+                typical_range: Some((2.0, 4.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Pile Columns".to_string(),
+                path: "additional.pile_columns".to_string(),
+                data_type: ParameterType::Number,
+                unit: "".to_string(),
+                description: "Columns in a fixed group layout; equal to the rows when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(10.0),
+                typical_range: Some((2.0, 4.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Allowable Settlement".to_string(),
+                path: "additional.allowable_settlement".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm".to_string(),
+                description: "Settlement limit for the group".to_string(),
+                required: false,
+                default_value: Some(25.0),
+                min_value: Some(5.0),
+                max_value: Some(150.0),
+                typical_range: Some((20.0, 50.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Safety Factor Bearing".to_string(),
+                path: "safety_factors.bearing".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "FOS on ultimate axial capacity".to_string(),
+                required: false,
+                default_value: Some(2.5),
+                min_value: Some(1.5),
+                max_value: Some(4.0),
+                typical_range: Some((2.0, 3.0)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Advanced)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        if let Some(loads) = &params.loads
+            && (loads.dead_load < 50.0 || loads.live_load < 0.0)
+        {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: "dead_load".to_string(),
+                value: loads.dead_load.to_string(),
+                reason: "Dead load of at least 50 kN and a non-negative live load are required".to_string(),
+            });
+        }
+
+        self.profile(params)?;
+        let diameter = self.optional_dimension(params, "diameter", 0.6, 0.2, 2.5)?;
+        self.optional_dimension(params, "length", 15.0, 3.0, 80.0)?;
+        self.optional_param(params, "earth_pressure_coefficient", 0.8, 0.3, 2.0)?;
+        self.optional_param(params, "pile_rows", 1.0, 1.0, 10.0)?;
+        self.optional_param(params, "pile_columns", 1.0, 1.0, 10.0)?;
+        self.optional_param(params, "allowable_settlement", 25.0, 5.0, 150.0)?;
+        let spacing = self.optional_param(params, "pile_spacing", 3.0 * diameter, 0.4, 10.0)?;
+
+        if spacing < 2.0 * diameter {
+            return Err(EngineeringError::DomainError {
+                field: "pile_spacing".to_string(),
+                message: format!("Spacing of {:.2} m is under two {:.2} m diameters", spacing, diameter),
+            });
+        }
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let (dead, live) = params.loads.as_ref().map(|l| (l.dead_load, l.live_load)).unwrap_or((2000.0, 1000.0));
+        let profile = self.profile(&params)?;
+        let diameter = self.optional_dimension(&params, "diameter", 0.6, 0.2, 2.5)?;
+        let length = self.optional_dimension(&params, "length", 15.0, 3.0, 80.0)?;
+        let k = self.optional_param(&params, "earth_pressure_coefficient", 0.8, 0.3, 2.0)?;
+        let spacing = self.optional_param(&params, "pile_spacing", 3.0 * diameter, 0.4, 10.0)?;
+        let allowable_settlement = self.optional_param(&params, "allowable_settlement", 25.0, 5.0, 150.0)?;
+        let fos = params.safety_factors.as_ref().and_then(|s| s.bearing).unwrap_or(2.5);
+        let fixed_layout = match self.get_additional_param(&params, "pile_rows", Some(1.0), Some(10.0)) {
+            Ok(rows) => {
+                let columns = self.optional_param(&params, "pile_columns", rows, 1.0, 10.0)?;
+                Some((rows.round() as usize, columns.round() as usize))
+            }
+            Err(EngineeringError::MissingParameter { .. }) => None,
+            Err(e) => return Err(e),
+        };
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut results = Vec::new();
+
+        let load = trace.step("formulas.pile_foundation.service_load", &[("dead", dead), ("live", live)], dead + live, "kN");
+        let perimeter = PI * diameter;
+        let area = PI * diameter.powi(2) / 4.0;
+
+        // Integrate unit skin friction over slices, totalled per layer for the pile
+        // and overall for a block of soil around the group
+        let names: Vec<String> = profile
+            .layers
+            .iter()
+            .enumerate()
+            .map(|(i, l)| l.name.clone().unwrap_or_else(|| format!("Layer {}", i + 1)))
+            .collect();
+        let mut shaft_by_layer = vec![0.0; profile.layers.len()];
+        let mut block_friction = 0.0;
+        let slices = (length / SLICE).ceil() as usize;
+        let dz = length / slices as f64;
+        for i in 0..slices {
+            let z = (i as f64 + 0.5) * dz;
+            let (fs_pile, fs_block) = profile.skin_friction(z, k);
+            shaft_by_layer[profile.layer_at(z)] += fs_pile * perimeter * dz;
+            block_friction += fs_block * dz;
+        }
+        for (i, capacity) in shaft_by_layer.iter().enumerate() {
+            if *capacity > 0.0 {
+                results.push(
+                    EngineeringResultItem::new(format!("Skin Friction in {}", names[i]), *capacity, "kN")
+                        .with_format(format!("{:.0} kN", capacity)),
+                );
+            }
+        }
+        let shaft = trace.step(
+            "formulas.pile_foundation.shaft_capacity",
+            &[("diameter", diameter), ("length", length), ("earth_pressure_coefficient", k)],
+            shaft_by_layer.iter().sum(),
+            "kN",
+        );
+        let qb = profile.end_bearing(length);
+        let base = trace.step(
+            "formulas.pile_foundation.end_bearing",
+            &[("unit_end_bearing", qb), ("area", area)],
+            qb * area,
+            "kN",
+        );
+        let single_ultimate = shaft + base;
+        let single_allowable = trace.step(
+            "formulas.pile_foundation.single_allowable",
+            &[("ultimate", single_ultimate), ("safety_factor", fos)],
+            single_ultimate / fos,
+            "kN",
+        );
+
+        // Ultimate group capacity: the lesser of individual piles reduced by the group
+        // efficiency and the block of soil enclosing the group
+        let group = |rows: usize, columns: usize| {
+            let eta = group_efficiency(rows, columns, diameter, spacing);
+            let width = (columns - 1) as f64 * spacing + diameter;
+            let breadth = (rows - 1) as f64 * spacing + diameter;
+            let piles = eta * (rows * columns) as f64 * single_ultimate;
+            let block = 2.0 * (width + breadth) * block_friction + qb * width * breadth;
+            let ultimate = if rows * columns > 1 { piles.min(block) } else { single_ultimate };
+            (eta, block, ultimate)
+        };
+        let (rows, columns) = fixed_layout.unwrap_or_else(|| {
+            let (mut rows, mut columns) = (1, 1);
+            while group(rows, columns).2 / fos < load && rows * columns < MAX_PILES {
+                if columns == rows {
+                    columns += 1;
+                } else {
+                    rows += 1;
+                }
+            }
+            (rows, columns)
+        });
+        let count = (rows * columns) as f64;
+        let (eta, block, group_ultimate) = group(rows, columns);
+        trace.step(
+            "formulas.pile_foundation.group_efficiency",
+            &[("rows", rows as f64), ("columns", columns as f64), ("diameter", diameter), ("spacing", spacing)],
+            eta,
+            "",
+        );
+        trace.step(
+            "formulas.pile_foundation.block_capacity",
+            &[("spacing", spacing), ("unit_end_bearing", qb)],
+            block,
+            "kN",
+        );
+        let group_allowable = trace.step(
+            "formulas.pile_foundation.group_allowable",
+            &[("ultimate", group_ultimate), ("safety_factor", fos)],
+            group_ultimate / fos,
+            "kN",
+        );
+        let utilization = load / group_allowable;
+
+        if utilization > 1.0 {
+            warnings.push(PILE_GROUP_OVERLOADED.warn(format!(
+                "{:.0} kN exceeds the {:.0} kN allowable capacity of {:.0} piles",
+                load, group_allowable, count
+            )));
+            recommendations.push("Lengthen the piles to reach stronger strata, increase the diameter or add piles".to_string());
+        }
+        if count > 1.0 && block < eta * count * single_ultimate {
+            warnings.push(PILE_BLOCK_FAILURE.warn(format!(
+                "Block failure ({:.0} kN) governs over the individual piles ({:.0} kN); spacing is {:.1} diameters",
+                block,
+                eta * count * single_ultimate,
+                spacing / diameter
+            )));
+        }
+        if count > 1.0 && spacing < 2.5 * diameter {
+            warnings.push(PILE_CLOSE_SPACING.warn(format!(
+                "Spacing of {:.1} diameters; 2.5 to 3 diameters is usual",
+                spacing / diameter
+            )));
+        }
+
+        // Vesić: base movement of D/100 plus elastic shortening of the shaft at the working
+        // load, scaled to the group by the square root of its width over the pile diameter
+        let pile_load = load / count;
+        let single_settlement = trace.step(
+            "formulas.pile_foundation.single_settlement",
+            &[("diameter", diameter), ("pile_load", pile_load), ("length", length), ("area", area)],
+            (diameter / 100.0 + pile_load * length / (area * ELASTIC_MODULUS_C30 * 1.0e6)) * 1000.0,
+            "mm",
+        );
+        let group_width = (columns.min(rows) - 1) as f64 * spacing + diameter;
+        let group_settlement = trace.step(
+            "formulas.pile_foundation.group_settlement",
+            &[("single_settlement", single_settlement), ("group_width", group_width), ("diameter", diameter)],
+            single_settlement * (group_width / diameter).sqrt(),
+            "mm",
+        );
+        if group_settlement > allowable_settlement {
+            warnings.push(PILE_EXCESSIVE_SETTLEMENT.warn(format!(
+                "Group settlement of {:.0} mm exceeds the {:.0} mm limit",
+                group_settlement, allowable_settlement
+            )));
+        }
+
+        let compliance_notes = vec![
+            "Skin friction by the alpha method in clay and the beta method in sand, with API RP 2A limiting values".to_string(),
+            format!(
+                "{}×{} group of {:.2} m piles, {:.1} m long at {:.2} m centres",
+                rows, columns, diameter, length, spacing
+            ),
+            "Group efficiency per Converse-Labarre; settlement per Vesić is indicative - confirm with load tests".to_string(),
+        ];
+
+        results.extend([
+            EngineeringResultItem::new("Shaft Capacity", shaft, "kN").with_format(format!("{:.0} kN", shaft)),
+            EngineeringResultItem::new("End Bearing Capacity", base, "kN").with_format(format!("{:.0} kN", base)),
+            EngineeringResultItem::new("Single Pile Ultimate Capacity", single_ultimate, "kN")
+                .with_format(format!("{:.0} kN", single_ultimate)),
+            EngineeringResultItem::new("Single Pile Allowable Capacity", single_allowable, "kN")
+                .critical()
+                .with_format(format!("{:.0} kN", single_allowable)),
+            EngineeringResultItem::new("Pile Count", count, "piles").critical().with_format(format!("{:.0} piles", count)),
+            EngineeringResultItem::new("Pile Rows", rows as f64, "").with_format(format!("{}", rows)),
+            EngineeringResultItem::new("Pile Columns", columns as f64, "").with_format(format!("{}", columns)),
+            EngineeringResultItem::new("Group Efficiency", eta, "").with_format(format!("{:.2}", eta)),
+            EngineeringResultItem::new("Block Failure Capacity", block, "kN").with_format(format!("{:.0} kN", block)),
+            EngineeringResultItem::new("Group Allowable Capacity", group_allowable, "kN")
+                .critical()
+                .with_format(format!("{:.0} kN", group_allowable)),
+            EngineeringResultItem::new("Group Utilization", utilization, "").critical().with_format(format!("{:.2}", utilization)),
+            EngineeringResultItem::new("Single Pile Settlement", single_settlement, "mm")
+                .with_format(format!("{:.1} mm", single_settlement)),
+            EngineeringResultItem::new("Group Settlement", group_settlement, "mm")
+                .critical()
+                .with_format(format!("{:.1} mm", group_settlement)),
+        ]);
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "pile_foundation".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "API RP 2A".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_pile_foundation_group_capacity() {
+        let params = |additional: serde_json::Value, layers: Option<serde_json::Value>| -> EngineeringParameters {
+            let mut json = serde_json::json!({
+                "dimensions": { "diameter": 0.6, "length": 15.0 },
+                "loads": { "dead_load": 2000.0, "live_load": 1000.0, "load_combination": "ASD" },
+                "additional": additional,
+            });
+            if let Some(layers) = layers {
+                json["extended_parameters"] = serde_json::json!({ "layers": { "type": "Array", "value": layers } });
+            }
+            serde_json::from_value(json).unwrap()
+        };
+        let area = std::f64::consts::PI * 0.36 / 4.0;
+
+        // Uniform clay: Qu ≈ 1082 + 127 kN, so 3000 kN at FS 2.5 needs a 3 × 3 group at 72.7% efficiency
+        let clay = serde_json::json!({ "undrained_shear_strength": 50.0, "pile_spacing": 1.8 });
+        let design = execute("pile_foundation", params(clay.clone(), None)).await.unwrap();
+        assert!((value(&design, "Shaft Capacity") - 1082.458).abs() < 0.01);
+        assert!((value(&design, "End Bearing Capacity") - 450.0 * area).abs() < 1e-9);
+        assert_eq!(value(&design, "Pile Count"), 9.0);
+        let theta = (0.6f64 / 1.8).atan().to_degrees();
+        assert!((value(&design, "Group Efficiency") - (1.0 - theta * 12.0 / 810.0)).abs() < 1e-9);
+        assert!(value(&design, "Group Utilization") <= 1.0);
+        assert!(value(&design, "Group Settlement") > value(&design, "Single Pile Settlement"));
+        assert!(warning_codes(&design).is_empty());
+
+        let mut square = clay.clone();
+        square["pile_rows"] = serde_json::json!(2.0);
+        let fixed = execute("pile_foundation", params(square, None)).await.unwrap();
+        assert_eq!(value(&fixed, "Pile Count"), 4.0);
+        assert!(value(&fixed, "Group Utilization") > 1.0);
+        assert!(warning_codes(&fixed).contains(&"pile_foundation.group_overloaded".to_string()));
+
+        // Clay over sand with groundwater at 2 m: end bearing Nq·σ'v = 20 · (5·18 + 10·20 − 13·9.81)
+        let layered = serde_json::json!([
+            { "name": "Clay", "thickness": 5.0, "undrained_shear_strength": 40.0 },
+            { "name": "Sand", "thickness": 20.0, "unit_weight": 20.0, "friction_angle": 30.0 },
+        ]);
+        let sand = execute("pile_foundation", params(serde_json::json!({ "water_table_depth": 2.0, "pile_spacing": 1.4 }), Some(layered)))
+            .await
+            .unwrap();
+        assert!((value(&sand, "End Bearing Capacity") - 20.0 * (290.0 - 13.0 * 9.81) * area).abs() < 1e-6);
+        assert!(value(&sand, "Skin Friction in Clay") > 0.0);
+        assert!(value(&sand, "Skin Friction in Sand") > 0.0);
+        assert!(warning_codes(&sand).contains(&"pile_foundation.close_spacing".to_string()));
+
+        let mut tight = clay;
+        tight["pile_spacing"] = serde_json::json!(1.0);
+        assert!(execute("pile_foundation", params(tight, None)).await.is_err());
+    }
+}
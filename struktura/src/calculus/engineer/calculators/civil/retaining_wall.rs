@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::RETAINING_WALL_WIDE_BASE,
 };
 use async_trait::async_trait;
 
@@ -164,17 +165,43 @@ impl EngineerCalculator for RetainingWallCalculator {
         let fc = params.material.as_ref().and_then(|m| m.compressive_strength).unwrap_or(30.0);
         let fos_ot = params.safety_factors.as_ref().and_then(|s| s.overturning).unwrap_or(2.0);
 
+        let mut trace = CalculationTrace::new();
+
         let ka = rankine_active(phi);
+        trace.step(
+            "formulas.retaining_wall.rankine_active",
+            &[("friction_angle", phi)],
+            ka,
+            "dimensionless",
+        );
         let pa = 0.5 * ka * gamma * height.powi(2) + ka * surcharge * height;
+        trace.step(
+            "formulas.retaining_wall.active_thrust",
+            &[("ka", ka), ("gamma", gamma), ("height", height), ("surcharge", surcharge)],
+            pa,
+            "kN/m",
+        );
         let overturn_moment = pa * (height / 3.0);
+        trace.step(
+            "formulas.retaining_wall.overturning_moment",
+            &[("pa", pa), ("height", height)],
+            overturn_moment,
+            "kNm/m",
+        );
         let base_width_min = (overturn_moment * fos_ot / (0.5 * UNIT_WEIGHT_CONCRETE * height.powi(2))).sqrt();
+        trace.step(
+            "formulas.retaining_wall.minimum_base_width",
+            &[("overturn_moment", overturn_moment), ("safety_factor", fos_ot), ("height", height)],
+            base_width_min,
+            "m",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if base_width_min > height / 2.0 {
-            warnings.push(format!("Wide base required ({:.2}m). Optimize design.", base_width_min));
+            warnings.push(RETAINING_WALL_WIDE_BASE.warn(format!("Wide base required ({:.2}m). Optimize design.", base_width_min)));
             recommendations.push("Consider gravity wall or soil improvement".to_string());
         }
 
@@ -197,8 +224,8 @@ impl EngineerCalculator for RetainingWallCalculator {
             calculation_type: "retaining_wall".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -207,6 +234,8 @@ impl EngineerCalculator for RetainingWallCalculator {
                 design_code_used: "ACI 318".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
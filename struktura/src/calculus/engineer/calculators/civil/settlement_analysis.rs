@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::SETTLEMENT_SIGNIFICANT,
 };
 use async_trait::async_trait;
 
@@ -128,7 +129,15 @@ impl EngineerCalculator for SettlementAnalysisCalculator {
         let pc = params.additional.as_ref().and_then(|a| a.get("pc").copied()).unwrap_or(100.0);
         let sigma0 = 100.0; // Assume average effective stress
 
+        let mut trace = CalculationTrace::new();
+
         let ocr = pc / sigma0;
+        trace.step(
+            "formulas.settlement_analysis.overconsolidation_ratio",
+            &[("pc", pc), ("sigma0", sigma0)],
+            ocr,
+            "dimensionless",
+        );
         let settlement = if delta_sigma + sigma0 < pc {
             // Normally consolidated
             (cc * h / (1.0 + e0)) * ((sigma0 + delta_sigma) / sigma0).log10() * 1000.0 // mm
@@ -137,13 +146,19 @@ impl EngineerCalculator for SettlementAnalysisCalculator {
             let cr = cc / 5.0; // Assume recompression index
             (cr * h / (1.0 + e0)) * ((sigma0 + delta_sigma) / sigma0).log10() * 1000.0
         };
+        trace.step(
+            "formulas.settlement_analysis.consolidation_settlement",
+            &[("cc", cc), ("h", h), ("e0", e0), ("sigma0", sigma0), ("delta_sigma", delta_sigma)],
+            settlement,
+            "mm",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if settlement > 50.0 {
-            warnings.push(format!("Significant settlement ({:.1} mm). Consider preloading.", settlement));
+            warnings.push(SETTLEMENT_SIGNIFICANT.warn(format!("Significant settlement ({:.1} mm). Consider preloading.", settlement)));
             recommendations.push("Perform time-rate analysis for consolidation".to_string());
         }
 
@@ -166,8 +181,8 @@ impl EngineerCalculator for SettlementAnalysisCalculator {
             calculation_type: "settlement_analysis".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -176,6 +191,8 @@ impl EngineerCalculator for SettlementAnalysisCalculator {
                 design_code_used: "USACE EM 1110-1-1904".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::SLOPE_LOW_FOS,
 };
 use async_trait::async_trait;
 
@@ -128,15 +129,23 @@ impl EngineerCalculator for SlopeStabilityCalculator {
         let gamma = params.additional.as_ref().and_then(|a| a.get("unit_weight").copied()).unwrap_or(UNIT_WEIGHT_SANDY);
         let height = params.dimensions.get("height").copied().unwrap_or(10.0);
 
+        let mut trace = CalculationTrace::new();
+
         // Infinite slope FOS
         let fos_infinite = (phi.tan() / beta.tan()) + (2.0 * c) / (gamma * height * beta.sin() * beta.cos());
+        trace.step(
+            "formulas.slope_stability.infinite_slope_fos",
+            &[("friction_angle", phi), ("slope_angle", beta), ("cohesion", c), ("gamma", gamma), ("height", height)],
+            fos_infinite,
+            "dimensionless",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if fos_infinite < 1.5 {
-            warnings.push(format!("Low FOS ({:.2}). Slope may be unstable.", fos_infinite));
+            warnings.push(SLOPE_LOW_FOS.warn(format!("Low FOS ({:.2}). Slope may be unstable.", fos_infinite)));
             recommendations.push("Flatten slope or add reinforcement".to_string());
         }
 
@@ -157,8 +166,8 @@ impl EngineerCalculator for SlopeStabilityCalculator {
             calculation_type: "slope_stability".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -167,6 +176,8 @@ impl EngineerCalculator for SlopeStabilityCalculator {
                 design_code_used: "USACE EM 1110-2-1902".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::SOIL_LOW_FRICTION_ANGLE,
 };
 use async_trait::async_trait;
 
@@ -120,19 +121,45 @@ impl EngineerCalculator for SoilBearingCapacityCalculator {
         let b = params.dimensions.get("width").copied().unwrap_or(2.0);
         let df = params.dimensions.get("depth").copied().unwrap_or(1.0);
 
+        let mut trace = CalculationTrace::new();
+
         // Terzaghi bearing factors
         let nq = ((phi.tan() + 1.0).powi(2) / (2.0 * (45.0 - phi / 2.0).to_radians().tan().powi(2))).exp();
+        trace.step(
+            "formulas.soil_bearing_capacity.nq",
+            &[("friction_angle", phi)],
+            nq,
+            "dimensionless",
+        );
         let nc = if phi == 0.0 { 5.7 } else { (nq - 1.0) / phi.tan() };
+        trace.step(
+            "formulas.soil_bearing_capacity.nc",
+            &[("nq", nq), ("friction_angle", phi)],
+            nc,
+            "dimensionless",
+        );
         let ng = 1.5 * (nq - 1.0) * phi.tan();
+        trace.step(
+            "formulas.soil_bearing_capacity.ngamma",
+            &[("nq", nq), ("friction_angle", phi)],
+            ng,
+            "dimensionless",
+        );
 
         let q_ult = c * nc + gamma * df * nq + 0.5 * gamma * b * ng;
+        trace.step(
+            "formulas.soil_bearing_capacity.ultimate_capacity",
+            &[("cohesion", c), ("nc", nc), ("gamma", gamma), ("df", df), ("nq", nq), ("b", b), ("ng", ng)],
+            q_ult,
+            "kPa",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if phi < 25.0 {
-            warnings.push("Low friction angle. Cohesive soil dominant.".to_string());
+            warnings.push(SOIL_LOW_FRICTION_ANGLE.warn("Low friction angle. Cohesive soil dominant."));
         }
 
         if df / b < 0.5 {
@@ -159,8 +186,8 @@ impl EngineerCalculator for SoilBearingCapacityCalculator {
             calculation_type: "soil_bearing_capacity".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -169,6 +196,8 @@ impl EngineerCalculator for SoilBearingCapacityCalculator {
                 design_code_used: "USACE EM 1110-1-1905".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
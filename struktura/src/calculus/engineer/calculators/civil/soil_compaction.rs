@@ -0,0 +1,405 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{COMPACTION_BELOW_SPEC, COMPACTION_MOISTURE_OUT_OF_RANGE, COMPACTION_SUSPECT_TEST},
+};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Density of water (kg/m³)
+const WATER_DENSITY: f64 = 1000.0;
+
+/// Relative compaction above which the reference Proctor curve is suspect (%)
+const SUSPECT_COMPACTION: f64 = 103.0;
+
+/// One field density test, as read from a nuclear gauge or sand cone
+#[derive(Debug, Clone, Deserialize)]
+struct FieldTest {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default = "first_lift")]
+    lift: u32,
+    /// In-place wet density (kg/m³)
+    wet_density: f64,
+    /// Moisture content as read in the field (%)
+    moisture: f64,
+}
+
+fn first_lift() -> u32 {
+    1
+}
+
+pub struct SoilCompactionCalculator;
+
+impl ParameterValidator for SoilCompactionCalculator {
+    fn calculator_id(&self) -> &str {
+        "soil_compaction"
+    }
+}
+
+impl SoilCompactionCalculator {
+    /// Test batch from `extended_parameters.tests`, or a single test from `additional`
+    fn tests(&self, params: &EngineeringParameters) -> EngineeringResult<Vec<FieldTest>> {
+        let Some(value) = params.extended_parameters.as_ref().and_then(|e| e.get("tests")) else {
+            return Ok(vec![FieldTest {
+                id: None,
+                lift: 1,
+                wet_density: self.get_additional_param(params, "wet_density", Some(1000.0), Some(3000.0))?,
+                moisture: self.get_additional_param(params, "field_moisture", Some(0.0), Some(60.0))?,
+            }]);
+        };
+        let invalid = |reason: String| EngineeringError::InvalidParameter {
+            parameter: "tests".to_string(),
+            value: format!("{:?}", value),
+            reason,
+        };
+        let items = value.as_array().ok_or_else(|| invalid("Expected an array of field tests".to_string()))?;
+        let tests: Vec<FieldTest> =
+            serde_json::from_value(serde_json::Value::Array(items.clone())).map_err(|e| invalid(e.to_string()))?;
+        if tests.is_empty() {
+            return Err(invalid("At least one field test is required".to_string()));
+        }
+        if let Some(t) = tests.iter().find(|t| !(1000.0..=3000.0).contains(&t.wet_density) || !(0.0..=60.0).contains(&t.moisture)) {
+            return Err(invalid(format!(
+                "Test {} needs a wet density of 1000-3000 kg/m³ and moisture of 0-60%",
+                t.id.as_deref().unwrap_or("?")
+            )));
+        }
+        Ok(tests)
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for SoilCompactionCalculator {
+    fn id(&self) -> &str {
+        "soil_compaction"
+    }
+
+    fn name(&self) -> &str {
+        "Soil Compaction Control"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Civil
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("soil_compaction", "Soil Compaction Control")
+            .category("civil")
+            .description("Relative compaction of field density tests against the Proctor maximum, with moisture correction and lift acceptance")
+            .design_code("ASTM D6938")
+            .parameter(ParameterMetadata {
+                name: "Field Tests".to_string(),
+                path: "extended_parameters.tests".to_string(),
+                data_type: ParameterType::Array,
+                unit: "".to_string(),
+                description: "Objects with wet_density (kg/m³), moisture (%) and optional id and lift number".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec!["At least one test".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Field Wet Density".to_string(),
+                path: "additional.wet_density".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kg/m³".to_string(),
+                description: "Wet density of a single test, used when no test batch is given".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1000.0),
+                max_value: Some(3000.0),
+                typical_range: Some((1800.0, 2300.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Field Moisture".to_string(),
+                path: "additional.field_moisture".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Moisture content of a single test, used when no test batch is given".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(60.0),
+                typical_range: Some((5.0, 20.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Maximum Dry Density".to_string(),
+                path: "additional.max_dry_density".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kg/m³".to_string(),
+                description: "Laboratory maximum dry density from the standard or modified Proctor test".to_string(),
+                required: true,
+                default_value: Some(1900.0),
+                min_value: Some(1000.0),
+                max_value: Some(2600.0),
+                typical_range: Some((1600.0, 2300.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Optimum Moisture Content".to_string(),
+                path: "additional.optimum_moisture".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Laboratory optimum moisture content".to_string(),
+                required: true,
+                default_value: Some(12.0),
+                min_value: Some(1.0),
+                max_value: Some(50.0),
+                typical_range: Some((6.0, 20.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Required Relative Compaction".to_string(),
+                path: "additional.required_compaction".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Specified minimum percentage of maximum dry density".to_string(),
+                required: false,
+                default_value: Some(95.0),
+                min_value: Some(80.0),
+                max_value: Some(105.0),
+                typical_range: Some((90.0, 100.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Moisture Below Optimum".to_string(),
+                path: "additional.moisture_below".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Percentage points the moisture may fall below optimum".to_string(),
+                required: false,
+                default_value: Some(2.0),
+                min_value: Some(0.0),
+                max_value: Some(10.0),
+                typical_range: Some((1.0, 3.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Moisture Above Optimum".to_string(),
+                path: "additional.moisture_above".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Percentage points the moisture may exceed optimum".to_string(),
+                required: false,
+                default_value: Some(2.0),
+                min_value: Some(0.0),
+                max_value: Some(10.0),
+                typical_range: Some((1.0, 3.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Moisture Correction".to_string(),
+                path: "additional.moisture_correction".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Oven-dry minus gauge moisture from correlation tests, added to every field reading; 0 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(-10.0),
+                max_value: Some(10.0),
+                typical_range: Some((-2.0, 2.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Specific Gravity of Solids".to_string(),
+                path: "additional.specific_gravity".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "Gs for the zero-air-voids check".to_string(),
+                required: false,
+                default_value: Some(2.65),
+                min_value: Some(2.4),
+                max_value: Some(3.0),
+                typical_range: Some((2.6, 2.75)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Intermediate)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.tests(params)?;
+        self.get_additional_param(params, "max_dry_density", Some(1000.0), Some(2600.0))?;
+        self.get_additional_param(params, "optimum_moisture", Some(1.0), Some(50.0))?;
+        self.optional_param(params, "required_compaction", 95.0, 80.0, 105.0)?;
+        self.optional_param(params, "moisture_below", 2.0, 0.0, 10.0)?;
+        self.optional_param(params, "moisture_above", 2.0, 0.0, 10.0)?;
+        self.optional_param(params, "moisture_correction", 0.0, -10.0, 10.0)?;
+        self.optional_param(params, "specific_gravity", 2.65, 2.4, 3.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let tests = self.tests(&params)?;
+        let mdd = self.get_additional_param(&params, "max_dry_density", None, None)?;
+        let omc = self.get_additional_param(&params, "optimum_moisture", None, None)?;
+        let required = self.optional_param(&params, "required_compaction", 95.0, 80.0, 105.0)?;
+        let below = self.optional_param(&params, "moisture_below", 2.0, 0.0, 10.0)?;
+        let above = self.optional_param(&params, "moisture_above", 2.0, 0.0, 10.0)?;
+        let correction = self.optional_param(&params, "moisture_correction", 0.0, -10.0, 10.0)?;
+        let gs = self.optional_param(&params, "specific_gravity", 2.65, 2.4, 3.0)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut results = Vec::new();
+        let mut lifts: BTreeMap<u32, bool> = BTreeMap::new();
+        let mut compactions = Vec::new();
+        let mut passes = Vec::new();
+
+        for (i, test) in tests.iter().enumerate() {
+            let id = test.id.clone().unwrap_or_else(|| format!("T{}", i + 1));
+            let moisture = test.moisture + correction;
+            let w = moisture / 100.0;
+            // ρd = ρ / (1 + w), against the zero-air-voids line ρw·Gs / (1 + w·Gs)
+            let dry_density = trace.step(
+                "formulas.soil_compaction.dry_density",
+                &[("wet_density", test.wet_density), ("moisture", moisture)],
+                test.wet_density / (1.0 + w),
+                "kg/m³",
+            );
+            let relative = trace.step(
+                "formulas.soil_compaction.relative_compaction",
+                &[("dry_density", dry_density), ("max_dry_density", mdd)],
+                dry_density / mdd * 100.0,
+                "%",
+            );
+            let zero_air_voids = WATER_DENSITY * gs / (1.0 + w * gs);
+
+            let density_ok = relative >= required;
+            let moisture_ok = (omc - below..=omc + above).contains(&moisture);
+            if !density_ok {
+                warnings.push(COMPACTION_BELOW_SPEC.warn(format!(
+                    "{} (lift {}): {:.1}% < {:.1}% required",
+                    id, test.lift, relative, required
+                )));
+            }
+            if !moisture_ok {
+                warnings.push(COMPACTION_MOISTURE_OUT_OF_RANGE.warn(format!(
+                    "{} (lift {}): {:.1}% moisture against {:.1}% to {:.1}%",
+                    id,
+                    test.lift,
+                    moisture,
+                    omc - below,
+                    omc + above
+                )));
+            }
+            if dry_density > zero_air_voids || relative > SUSPECT_COMPACTION {
+                warnings.push(COMPACTION_SUSPECT_TEST.warn(format!(
+                    "{}: {:.0} kg/m³ dry against {:.0} kg/m³ at zero air voids; retest or run a one-point Proctor",
+                    id, dry_density, zero_air_voids
+                )));
+            }
+            let pass = density_ok && moisture_ok;
+            *lifts.entry(test.lift).or_insert(true) &= pass;
+            compactions.push(relative);
+            passes.push(pass);
+
+            results.push(
+                EngineeringResultItem::new(format!("{} Relative Compaction", id), relative, "%")
+                    .with_format(format!("{:.1}% ({})", relative, if pass { "pass" } else { "fail" })),
+            );
+        }
+
+        let passed = passes.iter().filter(|&&ok| ok).count();
+        let mean = compactions.iter().sum::<f64>() / compactions.len() as f64;
+        let minimum = compactions.iter().copied().fold(f64::INFINITY, f64::min);
+        let rejected: Vec<u32> = lifts.iter().filter(|(_, ok)| !**ok).map(|(lift, _)| *lift).collect();
+
+        let mut recommendations = Vec::new();
+        if !rejected.is_empty() {
+            recommendations.push(format!(
+                "Rework and retest lift{} {} before placing the next lift",
+                if rejected.len() > 1 { "s" } else { "" },
+                rejected.iter().map(u32::to_string).collect::<Vec<_>>().join(", ")
+            ));
+        }
+        if warnings.iter().any(|w| w.code == COMPACTION_MOISTURE_OUT_OF_RANGE.code) {
+            recommendations.push("Dry back or water and remix the fill to bring it within the moisture window".to_string());
+        }
+
+        let compliance_notes = vec![
+            format!("Acceptance: ≥ {:.0}% of {:.0} kg/m³ at {:.1}% to {:.1}% moisture", required, mdd, omc - below, omc + above),
+            format!("Field moisture corrected by {:+.1} percentage points", correction),
+            "A lift is accepted only when every test in it passes".to_string(),
+        ];
+
+        results.extend([
+            EngineeringResultItem::new("Tests Passed", passed as f64, "").with_format(format!("{} of {}", passed, tests.len())),
+            EngineeringResultItem::new("Mean Relative Compaction", mean, "%").with_format(format!("{:.1}%", mean)),
+            EngineeringResultItem::new("Minimum Relative Compaction", minimum, "%")
+                .critical()
+                .with_format(format!("{:.1}%", minimum)),
+            EngineeringResultItem::new("Lifts Accepted", (lifts.len() - rejected.len()) as f64, "")
+                .critical()
+                .with_format(format!("{} of {}", lifts.len() - rejected.len(), lifts.len())),
+        ]);
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "soil_compaction".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "ASTM D6938".to_string(),
+                requires_pe_review: false,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_soil_compaction_lift_acceptance() {
+        let params = |correction: f64, tests: serde_json::Value| -> EngineeringParameters {
+            serde_json::from_value(serde_json::json!({
+                "dimensions": {},
+                "additional": { "max_dry_density": 1900.0, "optimum_moisture": 12.0, "moisture_correction": correction },
+                "extended_parameters": { "tests": { "type": "Array", "value": tests } },
+            }))
+            .unwrap()
+        };
+        let batch = serde_json::json!([
+            { "id": "A1", "lift": 1, "wet_density": 2128.0, "moisture": 12.0 },
+            { "id": "A2", "lift": 1, "wet_density": 2016.0, "moisture": 12.0 },
+            { "id": "B1", "lift": 2, "wet_density": 2080.5, "moisture": 9.5 },
+        ]);
+
+        // A2 is 1800 / 1900 = 94.7%; B1 is at 100% but 2.5 points dry of optimum
+        let raw = execute("soil_compaction", params(0.0, batch.clone())).await.unwrap();
+        assert!((value(&raw, "A1 Relative Compaction") - 100.0).abs() < 1e-9);
+        assert!((value(&raw, "A2 Relative Compaction") - 1800.0 / 19.0).abs() < 1e-9);
+        assert_eq!(value(&raw, "Tests Passed"), 1.0);
+        assert_eq!(value(&raw, "Lifts Accepted"), 0.0);
+        assert!(warning_codes(&raw).contains(&"soil_compaction.below_spec".to_string()));
+        assert!(warning_codes(&raw).contains(&"soil_compaction.moisture_out_of_range".to_string()));
+
+        // A +1 point gauge correction brings B1 into the window and accepts lift 2
+        let corrected = execute("soil_compaction", params(1.0, batch)).await.unwrap();
+        assert!((value(&corrected, "B1 Relative Compaction") - 2080.5 / 1.105 / 19.0).abs() < 1e-9);
+        assert_eq!(value(&corrected, "Lifts Accepted"), 1.0);
+
+        let dense = execute("soil_compaction", params(0.0, serde_json::json!([{ "wet_density": 2400.0, "moisture": 12.0 }])))
+            .await
+            .unwrap();
+        assert!(warning_codes(&dense).contains(&"soil_compaction.suspect_test".to_string()));
+        assert!(execute("soil_compaction", params(0.0, serde_json::json!([]))).await.is_err());
+    }
+}
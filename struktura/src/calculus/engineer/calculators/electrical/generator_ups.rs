@@ -0,0 +1,568 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{GENERATOR_LIGHT_LOAD, GENERATOR_OVERLOADED, GENERATOR_VOLTAGE_DIP_EXCEEDED, UPS_RUNTIME_SHORT},
+};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::helpers::{kva_for_voltage_dip, peukert_capacity, peukert_runtime, voltage_dip};
+use super::standby_power::*;
+
+/// Power factor assumed for loads that do not state one
+const DEFAULT_POWER_FACTOR: f64 = 0.85;
+
+/// Running load below this fraction of the rating risks wet stacking in diesel sets
+const LIGHT_LOAD_FRACTION: f64 = 0.3;
+
+/// One load in the facility profile
+#[derive(Debug, Clone, Deserialize)]
+struct Load {
+    #[serde(default)]
+    name: Option<String>,
+    /// Running demand (kW)
+    kw: f64,
+    #[serde(default)]
+    power_factor: Option<f64>,
+    /// Motor started across the line unless `starting_kva` is given
+    #[serde(default)]
+    motor: bool,
+    #[serde(default)]
+    starting_kva: Option<f64>,
+    /// Generator load step the load is transferred in, from 1
+    #[serde(default)]
+    step: Option<u32>,
+    /// Carried by the UPS rather than connected directly
+    #[serde(default)]
+    ups: bool,
+}
+
+impl Load {
+    fn running_kva(&self) -> f64 {
+        self.kw / self.power_factor.unwrap_or(DEFAULT_POWER_FACTOR)
+    }
+
+    /// kVA drawn at the instant the load is switched on
+    fn inrush_kva(&self) -> f64 {
+        match (self.starting_kva, self.motor) {
+            (Some(kva), _) => kva,
+            (None, true) => LOCKED_ROTOR_MULTIPLE * self.running_kva(),
+            (None, false) => self.running_kva(),
+        }
+    }
+}
+
+pub struct GeneratorUpsCalculator;
+
+impl ParameterValidator for GeneratorUpsCalculator {
+    fn calculator_id(&self) -> &str {
+        "generator_ups_sizing"
+    }
+}
+
+impl GeneratorUpsCalculator {
+    /// Loads from `extended_parameters.loads`, or a running load, one motor and a UPS load
+    fn loads(&self, params: &EngineeringParameters) -> EngineeringResult<Vec<Load>> {
+        let Some(value) = params.extended_parameters.as_ref().and_then(|e| e.get("loads")) else {
+            let mut loads = vec![Load {
+                name: Some("Running Load".to_string()),
+                kw: self.optional_param(params, "running_load", 100.0, 0.0, 5000.0)?,
+                power_factor: None,
+                motor: false,
+                starting_kva: None,
+                step: Some(1),
+                ups: false,
+            }];
+            let ups_load = self.optional_param(params, "ups_load", 0.0, 0.0, 2000.0)?;
+            if ups_load > 0.0 {
+                loads.push(Load {
+                    name: Some("UPS".to_string()),
+                    kw: ups_load,
+                    power_factor: Some(0.95),
+                    motor: false,
+                    starting_kva: None,
+                    step: Some(1),
+                    ups: true,
+                });
+            }
+            let motor = self.optional_param(params, "largest_motor", 0.0, 0.0, 2000.0)?;
+            if motor > 0.0 {
+                loads.push(Load {
+                    name: Some("Largest Motor".to_string()),
+                    kw: motor,
+                    power_factor: None,
+                    motor: true,
+                    starting_kva: None,
+                    step: Some(2),
+                    ups: false,
+                });
+            }
+            loads.retain(|l| l.kw > 0.0);
+            if loads.is_empty() {
+                return Err(EngineeringError::InvalidParameter {
+                    parameter: "running_load".to_string(),
+                    value: "0".to_string(),
+                    reason: "At least one load is required".to_string(),
+                });
+            }
+            return Ok(loads);
+        };
+        let invalid = |reason: String| EngineeringError::InvalidParameter {
+            parameter: "loads".to_string(),
+            value: format!("{:?}", value),
+            reason,
+        };
+        let items = value.as_array().ok_or_else(|| invalid("Expected an array of loads".to_string()))?;
+        let loads: Vec<Load> =
+            serde_json::from_value(serde_json::Value::Array(items.clone())).map_err(|e| invalid(e.to_string()))?;
+        if loads.is_empty() || loads.len() > 100 {
+            return Err(invalid("Between 1 and 100 loads are required".to_string()));
+        }
+        for (i, load) in loads.iter().enumerate() {
+            let ok = load.kw > 0.0
+                && load.kw <= 5000.0
+                && load.power_factor.is_none_or(|pf| (0.3..=1.0).contains(&pf))
+                && load.starting_kva.is_none_or(|kva| kva > 0.0 && kva <= 50000.0)
+                && load.step.is_none_or(|s| (1..=10).contains(&s));
+            if !ok {
+                return Err(invalid(format!(
+                    "Load {} needs 0-5000 kW, a power factor of 0.3-1, positive starting kVA and a step of 1-10",
+                    load.name.clone().unwrap_or_else(|| (i + 1).to_string())
+                )));
+            }
+        }
+        Ok(loads)
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for GeneratorUpsCalculator {
+    fn id(&self) -> &str {
+        "generator_ups_sizing"
+    }
+
+    fn name(&self) -> &str {
+        "Standby Generator and UPS Runtime"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Electrical
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("generator_ups_sizing", "Standby Generator and UPS Runtime")
+            .category("electrical")
+            .description("Size a standby generator for running load and step-load voltage dip, and estimate UPS battery runtime from a facility load profile")
+            .design_code("NFPA 110")
+            .design_code("IEEE 446")
+            .design_code("IEEE 485")
+            .parameter(ParameterMetadata {
+                name: "Loads".to_string(),
+                path: "extended_parameters.loads".to_string(),
+                data_type: ParameterType::Array,
+                unit: "".to_string(),
+                description: "Load profile: kw, optional power_factor, motor, starting_kva, step (1-10) and ups flag for each load".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec!["1 to 100 loads".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Running Load".to_string(),
+                path: "additional.running_load".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kW".to_string(),
+                description: "Non-motor running load, used when no load profile is given".to_string(),
+                required: false,
+                default_value: Some(100.0),
+                min_value: Some(0.0),
+                max_value: Some(5000.0),
+                typical_range: Some((20.0, 1000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Largest Motor".to_string(),
+                path: "additional.largest_motor".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kW".to_string(),
+                description: "Largest motor, started across the line after the running load".to_string(),
+                required: false,
+                default_value: Some(0.0),
+                min_value: Some(0.0),
+                max_value: Some(2000.0),
+                typical_range: Some((5.0, 200.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "UPS Load".to_string(),
+                path: "additional.ups_load".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kW".to_string(),
+                description: "Critical load carried by the UPS".to_string(),
+                required: false,
+                default_value: Some(0.0),
+                min_value: Some(0.0),
+                max_value: Some(2000.0),
+                typical_range: Some((5.0, 500.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Generator Rating".to_string(),
+                path: "additional.generator_rating".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kW".to_string(),
+                description: "Check an existing or proposed generator instead of selecting one".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(5.0),
+                max_value: Some(5000.0),
+                typical_range: Some((20.0, 2000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Loading Limit".to_string(),
+                path: "additional.loading_limit".to_string(),
+                data_type: ParameterType::Number,
+                unit: "fraction".to_string(),
+                description: "Largest running load as a fraction of the generator rating".to_string(),
+                required: false,
+                default_value: Some(0.8),
+                min_value: Some(0.5),
+                max_value: Some(1.0),
+                typical_range: Some((0.7, 0.9)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Maximum Voltage Dip".to_string(),
+                path: "additional.max_voltage_dip".to_string(),
+                data_type: ParameterType::Number,
+                unit: "fraction".to_string(),
+                description: "Allowable transient voltage dip when a load step is applied".to_string(),
+                required: false,
+                default_value: Some(0.3),
+                min_value: Some(0.05),
+                max_value: Some(0.5),
+                typical_range: Some((0.15, 0.35)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Transient Reactance".to_string(),
+                path: "additional.transient_reactance".to_string(),
+                data_type: ParameterType::Number,
+                unit: "per unit".to_string(),
+                description: "Generator transient reactance X'd from the alternator data sheet".to_string(),
+                required: false,
+                default_value: Some(0.2),
+                min_value: Some(0.05),
+                max_value: Some(0.5),
+                typical_range: Some((0.15, 0.3)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Battery Capacity".to_string(),
+                path: "additional.battery_capacity".to_string(),
+                data_type: ParameterType::Number,
+                unit: "Ah".to_string(),
+                description: "20-hour rated capacity of the UPS battery string".to_string(),
+                required: false,
+                default_value: Some(100.0),
+                min_value: Some(1.0),
+                max_value: Some(10000.0),
+                typical_range: Some((20.0, 500.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Battery Voltage".to_string(),
+                path: "additional.battery_voltage".to_string(),
+                data_type: ParameterType::Number,
+                unit: "V".to_string(),
+                description: "Nominal DC voltage of the battery string".to_string(),
+                required: false,
+                default_value: Some(480.0),
+                min_value: Some(12.0),
+                max_value: Some(1000.0),
+                typical_range: Some((192.0, 600.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Inverter Efficiency".to_string(),
+                path: "additional.inverter_efficiency".to_string(),
+                data_type: ParameterType::Number,
+                unit: "fraction".to_string(),
+                description: "UPS inverter efficiency on battery".to_string(),
+                required: false,
+                default_value: Some(0.94),
+                min_value: Some(0.7),
+                max_value: Some(1.0),
+                typical_range: Some((0.9, 0.97)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Required Runtime".to_string(),
+                path: "additional.required_runtime".to_string(),
+                data_type: ParameterType::Number,
+                unit: "min".to_string(),
+                description: "Battery runtime needed to ride through generator start or a controlled shutdown".to_string(),
+                required: false,
+                default_value: Some(15.0),
+                min_value: Some(1.0),
+                max_value: Some(480.0),
+                typical_range: Some((5.0, 30.0)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Intermediate)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.loads(params)?;
+        self.optional_param(params, "generator_rating", 100.0, 5.0, 5000.0)?;
+        self.optional_param(params, "loading_limit", 0.8, 0.5, 1.0)?;
+        self.optional_param(params, "max_voltage_dip", 0.3, 0.05, 0.5)?;
+        self.optional_param(params, "transient_reactance", 0.2, 0.05, 0.5)?;
+        self.optional_param(params, "battery_capacity", 100.0, 1.0, 10000.0)?;
+        self.optional_param(params, "battery_voltage", 480.0, 12.0, 1000.0)?;
+        self.optional_param(params, "inverter_efficiency", 0.94, 0.7, 1.0)?;
+        self.optional_param(params, "required_runtime", 15.0, 1.0, 480.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let loads = self.loads(&params)?;
+        let loading_limit = self.optional_param(&params, "loading_limit", 0.8, 0.5, 1.0)?;
+        let max_dip = self.optional_param(&params, "max_voltage_dip", 0.3, 0.05, 0.5)?;
+        let reactance = self.optional_param(&params, "transient_reactance", 0.2, 0.05, 0.5)?;
+        let efficiency = self.optional_param(&params, "inverter_efficiency", 0.94, 0.7, 1.0)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut compliance_notes = vec![format!(
+            "Motor starting at {:.0}× running kVA unless starting kVA is given; UPS loads drawn through the rectifier at {:.0}% efficiency",
+            LOCKED_ROTOR_MULTIPLE,
+            efficiency * 100.0
+        )];
+
+        // UPS loads reach the generator through the rectifier losses
+        let input_kw = |load: &Load| if load.ups { load.kw / efficiency } else { load.kw };
+        let running_kw = trace.step(
+            "formulas.generator_ups_sizing.running_kw",
+            &[("loads", loads.len() as f64)],
+            loads.iter().map(input_kw).sum(),
+            "kW",
+        );
+        let running_kva: f64 = loads.iter().map(|l| input_kw(l) / l.power_factor.unwrap_or(DEFAULT_POWER_FACTOR)).sum();
+
+        // Step loading: every load in a step switches on together
+        let step_count = loads.iter().map(|l| l.step.unwrap_or(1)).max().unwrap_or(1);
+        let steps: Vec<(u32, f64)> = (1..=step_count)
+            .map(|step| {
+                let kva = loads.iter().filter(|l| l.step.unwrap_or(1) == step).map(|l| l.inrush_kva()).sum();
+                (step, kva)
+            })
+            .filter(|&(_, kva)| kva > 0.0)
+            .collect();
+        let largest_step = steps.iter().map(|&(_, kva)| kva).fold(0.0, f64::max);
+
+        let for_running = running_kw / loading_limit;
+        let for_dip = kva_for_voltage_dip(largest_step, reactance, max_dip) * GENERATOR_POWER_FACTOR;
+        let required_kw = trace.step(
+            "formulas.generator_ups_sizing.required_rating",
+            &[("running_kw", running_kw), ("loading_limit", loading_limit), ("largest_step_kva", largest_step), ("max_dip", max_dip)],
+            for_running.max(for_dip),
+            "kW",
+        );
+        let rating = match self.get_additional_param(&params, "generator_rating", Some(5.0), Some(5000.0)) {
+            Ok(rating) => rating,
+            Err(EngineeringError::MissingParameter { .. }) => GENERATOR_RATINGS
+                .iter()
+                .copied()
+                .find(|&kw| kw >= required_kw)
+                .unwrap_or(GENERATOR_RATINGS[GENERATOR_RATINGS.len() - 1]),
+            Err(e) => return Err(e),
+        };
+        let rating_kva = rating / GENERATOR_POWER_FACTOR;
+        compliance_notes.push(format!(
+            "Generator rated {:.0} kW / {:.0} kVA at {:.1} power factor; {} governs",
+            rating,
+            rating_kva,
+            GENERATOR_POWER_FACTOR,
+            if for_dip > for_running { "step-load voltage dip" } else { "running load" }
+        ));
+
+        let mut results = vec![
+            EngineeringResultItem::new("Running Load", running_kw, "kW").with_format(format!("{:.1} kW", running_kw)),
+            EngineeringResultItem::new("Running Load kVA", running_kva, "kVA").with_format(format!("{:.1} kVA", running_kva)),
+            EngineeringResultItem::new("Largest Load Step", largest_step, "kVA").with_format(format!("{:.1} kVA", largest_step)),
+            EngineeringResultItem::new("Required Generator Rating", required_kw, "kW").with_format(format!("{:.1} kW", required_kw)),
+            EngineeringResultItem::new("Generator Rating", rating, "kW")
+                .critical()
+                .with_format(format!("{:.0} kW / {:.0} kVA", rating, rating_kva)),
+            EngineeringResultItem::new("Generator Loading", running_kw / rating, "")
+                .with_format(format!("{:.0}%", running_kw / rating * 100.0)),
+        ];
+
+        let mut worst_dip: f64 = 0.0;
+        for &(step, kva) in &steps {
+            let dip = trace.step(
+                "formulas.generator_ups_sizing.voltage_dip",
+                &[("step", step as f64), ("step_kva", kva), ("generator_kva", rating_kva), ("transient_reactance", reactance)],
+                voltage_dip(kva, rating_kva, reactance),
+                "",
+            );
+            worst_dip = worst_dip.max(dip);
+            results.push(
+                EngineeringResultItem::new(format!("Step {} Voltage Dip", step), dip, "")
+                    .with_format(format!("{:.1}% ({:.0} kVA)", dip * 100.0, kva)),
+            );
+        }
+        results.push(
+            EngineeringResultItem::new("Maximum Voltage Dip", worst_dip, "")
+                .critical()
+                .with_format(format!("{:.1}%", worst_dip * 100.0)),
+        );
+
+        if worst_dip > max_dip {
+            warnings.push(GENERATOR_VOLTAGE_DIP_EXCEEDED.warn(format!(
+                "A {:.0} kVA step dips the voltage {:.1}%, above the {:.0}% limit",
+                largest_step,
+                worst_dip * 100.0,
+                max_dip * 100.0
+            )));
+            recommendations.push("Split the load into more steps or use soft starters or VFDs on the largest motors".to_string());
+        }
+        if running_kw > rating * loading_limit {
+            warnings.push(GENERATOR_OVERLOADED.warn(format!(
+                "Running load of {:.1} kW exceeds {:.0}% of the {:.0} kW rating",
+                running_kw,
+                loading_limit * 100.0,
+                rating
+            )));
+        }
+        if running_kw < LIGHT_LOAD_FRACTION * rating {
+            warnings.push(GENERATOR_LIGHT_LOAD.warn(format!(
+                "Running load is {:.0}% of the rating; a diesel set this lightly loaded is prone to wet stacking",
+                running_kw / rating * 100.0
+            )));
+            recommendations.push("Provide a load bank for exercising and testing".to_string());
+        }
+
+        // ====================================================================
+        // UPS battery runtime
+        // ====================================================================
+
+        let ups_kw: f64 = loads.iter().filter(|l| l.ups).map(|l| l.kw).sum();
+        if ups_kw > 0.0 {
+            let capacity = self.optional_param(&params, "battery_capacity", 100.0, 1.0, 10000.0)?;
+            let voltage = self.optional_param(&params, "battery_voltage", 480.0, 12.0, 1000.0)?;
+            let required_runtime = self.optional_param(&params, "required_runtime", 15.0, 1.0, 480.0)?;
+
+            let current = trace.step(
+                "formulas.generator_ups_sizing.battery_current",
+                &[("ups_kw", ups_kw), ("battery_voltage", voltage), ("inverter_efficiency", efficiency)],
+                ups_kw * 1000.0 / (voltage * efficiency),
+                "A",
+            );
+            let runtime = trace.step(
+                "formulas.generator_ups_sizing.battery_runtime",
+                &[("capacity", capacity), ("end_of_life", END_OF_LIFE_CAPACITY), ("current", current), ("peukert", PEUKERT_EXPONENT)],
+                peukert_runtime(capacity * END_OF_LIFE_CAPACITY, current, BATTERY_RATED_HOURS, PEUKERT_EXPONENT) * 60.0,
+                "min",
+            );
+            let required_capacity = trace.step(
+                "formulas.generator_ups_sizing.required_capacity",
+                &[("current", current), ("required_runtime", required_runtime), ("end_of_life", END_OF_LIFE_CAPACITY)],
+                peukert_capacity(current, required_runtime / 60.0, BATTERY_RATED_HOURS, PEUKERT_EXPONENT) / END_OF_LIFE_CAPACITY,
+                "Ah",
+            );
+            compliance_notes.push(format!(
+                "Battery runtime by Peukert's law (k = {:.2}) at {:.0}% of rated capacity for end of life (IEEE 485)",
+                PEUKERT_EXPONENT,
+                END_OF_LIFE_CAPACITY * 100.0
+            ));
+            if runtime < required_runtime {
+                warnings.push(UPS_RUNTIME_SHORT.warn(format!(
+                    "Battery runtime of {:.1} min is below the required {:.0} min",
+                    runtime, required_runtime
+                )));
+                recommendations.push(format!("Increase the battery string to at least {:.0} Ah", required_capacity.ceil()));
+            }
+            results.extend([
+                EngineeringResultItem::new("UPS Load", ups_kw, "kW").with_format(format!("{:.1} kW", ups_kw)),
+                EngineeringResultItem::new("Battery Current", current, "A").with_format(format!("{:.1} A", current)),
+                EngineeringResultItem::new("Battery Runtime", runtime, "min")
+                    .critical()
+                    .with_format(format!("{:.1} min", runtime)),
+                EngineeringResultItem::new("Required Battery Capacity", required_capacity, "Ah")
+                    .with_format(format!("{:.0} Ah", required_capacity)),
+            ]);
+        }
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "generator_ups_sizing".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "NFPA 110 / IEEE 446".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_generator_and_ups_sizing() {
+        let params = |additional: serde_json::Value| -> EngineeringParameters {
+            serde_json::from_value(serde_json::json!({
+                "dimensions": {},
+                "additional": additional,
+                "extended_parameters": { "loads": { "type": "Array", "value": [
+                    { "name": "Lighting", "kw": 40.0, "step": 1 },
+                    { "name": "IT", "kw": 30.0, "ups": true, "step": 1 },
+                    { "name": "Chiller", "kw": 75.0, "motor": true, "step": 2 },
+                ] } },
+            }))
+            .unwrap()
+        };
+
+        // Starting the chiller (6 × 88.2 kVA) at a 30% dip needs 197.6 kW, so 200 kW is selected over 183.6 kW for running load
+        let sized = execute("generator_ups_sizing", params(serde_json::json!({}))).await.unwrap();
+        let running = 40.0 + 30.0 / 0.94 + 75.0;
+        assert!((value(&sized, "Running Load") - running).abs() < 1e-9);
+        assert!((value(&sized, "Largest Load Step") - 6.0 * 75.0 / 0.85).abs() < 1e-9);
+        assert_eq!(value(&sized, "Generator Rating"), 200.0);
+        assert!(value(&sized, "Maximum Voltage Dip") <= 0.3);
+        assert!(warning_codes(&sized).is_empty());
+
+        // 30 kW through a 480 V string at 94%: t = 20·(0.8·100 / (20·I))^1.15 hours
+        let current = 30000.0 / (480.0 * 0.94);
+        assert!((value(&sized, "Battery Current") - current).abs() < 1e-9);
+        let runtime = 20.0 * (80.0 / (20.0 * current)).powf(1.15) * 60.0;
+        assert!((value(&sized, "Battery Runtime") - runtime).abs() < 1e-9);
+
+        let undersized = execute("generator_ups_sizing", params(serde_json::json!({ "generator_rating": 150.0, "required_runtime": 60.0 })))
+            .await
+            .unwrap();
+        let warnings = warning_codes(&undersized);
+        assert!(warnings.contains(&"generator_ups_sizing.voltage_dip_exceeded".to_string()));
+        assert!(warnings.contains(&"generator_ups_sizing.overloaded".to_string()));
+        assert!(warnings.contains(&"generator_ups_sizing.runtime_short".to_string()));
+        assert!(value(&undersized, "Required Battery Capacity") > 100.0);
+    }
+}
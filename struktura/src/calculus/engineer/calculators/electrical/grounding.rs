@@ -0,0 +1,465 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{GROUNDING_HIGH_RESISTIVITY, GROUNDING_TARGET_NOT_MET, LIGHTNING_CLASS_II},
+};
+use async_trait::async_trait;
+
+use super::helpers::{grid_resistance, rod_group_resistance, rod_resistance, MAX_RODS};
+use super::lightning::*;
+
+/// NEC 250.53(A)(2): a single rod above 25 Ω needs a supplemental electrode
+const DEFAULT_TARGET_RESISTANCE: f64 = 25.0;
+
+/// Resistivity above which soil treatment or deep electrodes are usually needed (Ω·m)
+const HIGH_RESISTIVITY: f64 = 1000.0;
+
+pub struct GroundingLightningProtectionCalculator;
+
+impl ParameterValidator for GroundingLightningProtectionCalculator {
+    fn calculator_id(&self) -> &str {
+        "grounding_lightning_protection"
+    }
+}
+
+impl GroundingLightningProtectionCalculator {
+    /// Grid length and width, when both are given
+    fn grid(&self, params: &EngineeringParameters) -> EngineeringResult<Option<(f64, f64)>> {
+        match (params.dimensions.get("grid_length"), params.dimensions.get("grid_width")) {
+            (Some(&length), Some(&width)) => Ok(Some((
+                self.validate_dimension("grid_length", Some(length), 2.0, 500.0)?,
+                self.validate_dimension("grid_width", Some(width), 2.0, 500.0)?,
+            ))),
+            (None, None) => Ok(None),
+            _ => Err(EngineeringError::InvalidParameter {
+                parameter: "grid_width".to_string(),
+                value: format!("{:?}", params.dimensions.get("grid_width")),
+                reason: "Grid length and width must be given together".to_string(),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for GroundingLightningProtectionCalculator {
+    fn id(&self) -> &str {
+        "grounding_lightning_protection"
+    }
+
+    fn name(&self) -> &str {
+        "Grounding and Lightning Protection"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Electrical
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("grounding_lightning_protection", "Grounding and Lightning Protection")
+            .category("electrical")
+            .description("Estimate ground rod and grid resistance in a given soil resistivity and lay out NFPA 780 air terminals, down conductors and ground terminals for a simple rectangular building")
+            .design_code("IEEE 142")
+            .design_code("IEEE 80")
+            .design_code("NFPA 780")
+            .parameter(ParameterMetadata {
+                name: "Soil Resistivity".to_string(),
+                path: "additional.soil_resistivity".to_string(),
+                data_type: ParameterType::Number,
+                unit: "Ω·m".to_string(),
+                description: "Measured (Wenner) or assumed soil resistivity".to_string(),
+                required: true,
+                default_value: Some(100.0),
+                min_value: Some(1.0),
+                max_value: Some(20000.0),
+                typical_range: Some((10.0, 1000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Rod Length".to_string(),
+                path: "additional.rod_length".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Driven length of each ground rod".to_string(),
+                required: false,
+                default_value: Some(3.0),
+                min_value: Some(1.5),
+                max_value: Some(30.0),
+                typical_range: Some((2.4, 6.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Rod Diameter".to_string(),
+                path: "additional.rod_diameter".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Ground rod diameter".to_string(),
+                required: false,
+                default_value: Some(0.016),
+                min_value: Some(0.01),
+                max_value: Some(0.05),
+                typical_range: Some((0.0127, 0.019)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Rod Count".to_string(),
+                path: "additional.rod_count".to_string(),
+                data_type: ParameterType::Integer,
+                unit: "rods".to_string(),
+                description: "Rods in parallel, spaced at least one rod length apart".to_string(),
+                required: false,
+                default_value: Some(1.0),
+                min_value: Some(1.0),
+                max_value: Some(MAX_RODS as f64),
+                typical_range: Some((1.0, 4.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Target Resistance".to_string(),
+                path: "additional.target_resistance".to_string(),
+                data_type: ParameterType::Number,
+                unit: "Ω".to_string(),
+                description: "Required resistance to remote earth (25 Ω per NEC 250.53, 10 Ω or less for lightning and telecom)".to_string(),
+                required: false,
+                default_value: Some(DEFAULT_TARGET_RESISTANCE),
+                min_value: Some(0.1),
+                max_value: Some(100.0),
+                typical_range: Some((1.0, 25.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Grid Length".to_string(),
+                path: "dimensions.grid_length".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Length of a buried ground grid; give with grid width to size a grid instead of rods".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(2.0),
+                max_value: Some(500.0),
+                typical_range: Some((10.0, 100.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Grid Width".to_string(),
+                path: "dimensions.grid_width".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Width of the buried ground grid".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(2.0),
+                max_value: Some(500.0),
+                typical_range: Some((10.0, 100.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Grid Conductor Spacing".to_string(),
+                path: "additional.grid_spacing".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Spacing of parallel grid conductors in both directions".to_string(),
+                required: false,
+                default_value: Some(5.0),
+                min_value: Some(1.0),
+                max_value: Some(50.0),
+                typical_range: Some((3.0, 10.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Grid Burial Depth".to_string(),
+                path: "additional.burial_depth".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Depth of the grid conductors".to_string(),
+                required: false,
+                default_value: Some(0.5),
+                min_value: Some(0.25),
+                max_value: Some(2.5),
+                typical_range: Some((0.3, 1.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Building Length".to_string(),
+                path: "dimensions.length".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Plan length of the protected building".to_string(),
+                required: true,
+                default_value: Some(30.0),
+                min_value: Some(2.0),
+                max_value: Some(500.0),
+                typical_range: Some((10.0, 100.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Building Width".to_string(),
+                path: "dimensions.width".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Plan width of the protected building".to_string(),
+                required: true,
+                default_value: Some(20.0),
+                min_value: Some(2.0),
+                max_value: Some(500.0),
+                typical_range: Some((10.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Building Height".to_string(),
+                path: "dimensions.height".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Height of the roof edge above grade".to_string(),
+                required: true,
+                default_value: Some(10.0),
+                min_value: Some(2.0),
+                max_value: Some(300.0),
+                typical_range: Some((4.0, 40.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Air Terminal Height".to_string(),
+                path: "additional.air_terminal_height".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Height of each air terminal; 600 mm or taller allows 7.6 m edge spacing instead of 6.1 m".to_string(),
+                required: false,
+                default_value: Some(0.6),
+                min_value: Some(0.25),
+                max_value: Some(3.0),
+                typical_range: Some((0.3, 0.9)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Intermediate)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.get_additional_param(params, "soil_resistivity", Some(1.0), Some(20000.0))?;
+        self.validate_dimension("length", params.dimensions.get("length").copied(), 2.0, 500.0)?;
+        self.validate_dimension("width", params.dimensions.get("width").copied(), 2.0, 500.0)?;
+        self.validate_dimension("height", params.dimensions.get("height").copied(), 2.0, 300.0)?;
+        self.grid(params)?;
+        self.optional_param(params, "rod_length", 3.0, 1.5, 30.0)?;
+        self.optional_param(params, "rod_diameter", 0.016, 0.01, 0.05)?;
+        self.optional_param(params, "rod_count", 1.0, 1.0, MAX_RODS as f64)?;
+        self.optional_param(params, "target_resistance", DEFAULT_TARGET_RESISTANCE, 0.1, 100.0)?;
+        self.optional_param(params, "grid_spacing", 5.0, 1.0, 50.0)?;
+        self.optional_param(params, "burial_depth", 0.5, 0.25, 2.5)?;
+        self.optional_param(params, "air_terminal_height", 0.6, 0.25, 3.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let resistivity = self.get_additional_param(&params, "soil_resistivity", None, None)?;
+        let length = self.validate_dimension("length", params.dimensions.get("length").copied(), 2.0, 500.0)?;
+        let width = self.validate_dimension("width", params.dimensions.get("width").copied(), 2.0, 500.0)?;
+        let height = self.validate_dimension("height", params.dimensions.get("height").copied(), 2.0, 300.0)?;
+        let rod_length = self.optional_param(&params, "rod_length", 3.0, 1.5, 30.0)?;
+        let rod_diameter = self.optional_param(&params, "rod_diameter", 0.016, 0.01, 0.05)?;
+        let rods = self.optional_param(&params, "rod_count", 1.0, 1.0, MAX_RODS as f64)?.round() as usize;
+        let target = self.optional_param(&params, "target_resistance", DEFAULT_TARGET_RESISTANCE, 0.1, 100.0)?;
+        let terminal_height = self.optional_param(&params, "air_terminal_height", 0.6, 0.25, 3.0)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut compliance_notes = vec![
+            "Rod resistance by Dwight's formula; multiple rods per IEEE 142, spaced at least one rod length apart".to_string(),
+        ];
+
+        // ====================================================================
+        // Ground electrodes
+        // ====================================================================
+
+        let single = trace.step(
+            "formulas.grounding_lightning_protection.rod_resistance",
+            &[("resistivity", resistivity), ("rod_length", rod_length), ("rod_diameter", rod_diameter)],
+            rod_resistance(resistivity, rod_length, rod_diameter),
+            "Ω",
+        );
+        let rod_group = trace.step(
+            "formulas.grounding_lightning_protection.rod_group_resistance",
+            &[("single_rod", single), ("rods", rods as f64)],
+            rod_group_resistance(single, rods),
+            "Ω",
+        );
+        let rods_for_target = (1..=MAX_RODS).find(|&n| rod_group_resistance(single, n) <= target);
+
+        let mut results = vec![
+            EngineeringResultItem::new("Single Rod Resistance", single, "Ω").with_format(format!("{:.1} Ω", single)),
+            EngineeringResultItem::new("Rod Group Resistance", rod_group, "Ω")
+                .critical()
+                .with_format(format!("{:.1} Ω ({} rod{})", rod_group, rods, if rods == 1 { "" } else { "s" })),
+        ];
+        if let Some(n) = rods_for_target {
+            results.push(EngineeringResultItem::new("Rods for Target Resistance", n as f64, "rods").with_format(format!("{} rods", n)));
+        }
+
+        let system_resistance = match self.grid(&params)? {
+            Some((grid_length, grid_width)) => {
+                let spacing = self.optional_param(&params, "grid_spacing", 5.0, 1.0, 50.0)?;
+                let depth = self.optional_param(&params, "burial_depth", 0.5, 0.25, 2.5)?;
+                let along_length = (grid_width / spacing).ceil() + 1.0;
+                let along_width = (grid_length / spacing).ceil() + 1.0;
+                let conductor = along_length * grid_length + along_width * grid_width;
+                let area = grid_length * grid_width;
+                let grid = trace.step(
+                    "formulas.grounding_lightning_protection.grid_resistance",
+                    &[("resistivity", resistivity), ("conductor_length", conductor), ("area", area), ("depth", depth)],
+                    grid_resistance(resistivity, conductor, area, depth),
+                    "Ω",
+                );
+                compliance_notes.push(format!(
+                    "Grid resistance by Sverak's equation (IEEE 80) for {:.0} × {:.0} m at {:.1} m spacing, {:.2} m deep",
+                    grid_length, grid_width, spacing, depth
+                ));
+                results.push(EngineeringResultItem::new("Grid Conductor Length", conductor, "m").with_format(format!("{:.0} m", conductor)));
+                results.push(EngineeringResultItem::new("Grid Resistance", grid, "Ω").critical().with_format(format!("{:.2} Ω", grid)));
+                grid
+            }
+            None => rod_group,
+        };
+
+        if system_resistance > target {
+            warnings.push(GROUNDING_TARGET_NOT_MET.warn(format!(
+                "Ground resistance of {:.1} Ω exceeds the {:.1} Ω target",
+                system_resistance, target
+            )));
+            match rods_for_target {
+                Some(n) => recommendations.push(format!("Drive {} rods spaced at least {:.1} m apart to reach {:.1} Ω", n, rod_length, target)),
+                None => recommendations.push("Use longer rods, a ground grid, a ring electrode or soil enhancement".to_string()),
+            }
+        }
+        if resistivity > HIGH_RESISTIVITY {
+            warnings.push(GROUNDING_HIGH_RESISTIVITY.warn(format!(
+                "Soil resistivity of {:.0} Ω·m is high; verify with Wenner tests and consider chemical rods or deep-driven electrodes",
+                resistivity
+            )));
+        }
+
+        // ====================================================================
+        // Lightning protection (NFPA 780)
+        // ====================================================================
+
+        let edge_spacing = if terminal_height >= TALL_TERMINAL_HEIGHT { EDGE_SPACING_TALL } else { EDGE_SPACING_SHORT };
+        let edge_run = |side: f64| ((side - 2.0 * CORNER_OFFSET).max(0.0) / edge_spacing).ceil().max(1.0);
+        let perimeter_terminals = 2.0 * (edge_run(length) + edge_run(width));
+        let interior_rows = (width / INTERIOR_SPACING).ceil() - 1.0;
+        let interior_columns = (length / INTERIOR_SPACING).ceil() - 1.0;
+        let interior_terminals = interior_rows * interior_columns;
+        let air_terminals = trace.step(
+            "formulas.grounding_lightning_protection.air_terminals",
+            &[("length", length), ("width", width), ("edge_spacing", edge_spacing), ("interior_spacing", INTERIOR_SPACING)],
+            perimeter_terminals + interior_terminals,
+            "terminals",
+        );
+
+        let perimeter = 2.0 * (length + width);
+        let down_conductors = trace.step(
+            "formulas.grounding_lightning_protection.down_conductors",
+            &[("perimeter", perimeter), ("max_spacing", DOWN_CONDUCTOR_SPACING)],
+            (perimeter / DOWN_CONDUCTOR_SPACING).ceil().max(2.0),
+            "conductors",
+        );
+        let roof_conductor = perimeter + interior_rows * length + interior_columns * width;
+        let conductor_length = trace.step(
+            "formulas.grounding_lightning_protection.conductor_length",
+            &[("roof_conductor", roof_conductor), ("down_conductors", down_conductors), ("height", height)],
+            roof_conductor + down_conductors * height,
+            "m",
+        );
+
+        let class = if height > CLASS_I_MAX_HEIGHT { 2.0 } else { 1.0 };
+        if class > 1.0 {
+            warnings.push(LIGHTNING_CLASS_II.warn(format!(
+                "At {:.1} m the structure exceeds {:.0} m and needs Class II air terminals and conductors",
+                height, CLASS_I_MAX_HEIGHT
+            )));
+        }
+        compliance_notes.push(format!(
+            "NFPA 780 layout: air terminals within {:.1} m of corners at {:.1} m along edges, intermediate terminals at {:.0} m on flat roofs, down conductors at {:.0} m average spacing",
+            CORNER_OFFSET, edge_spacing, INTERIOR_SPACING, DOWN_CONDUCTOR_SPACING
+        ));
+        compliance_notes.push("Quantities are for a flat roof; add terminals for ridges, dormers, chimneys and rooftop equipment".to_string());
+        recommendations.push("Bond the lightning ground terminals to the building grounding electrode system".to_string());
+
+        results.extend([
+            EngineeringResultItem::new("Protection Class", class, "").with_format(format!("Class {}", if class > 1.0 { "II" } else { "I" })),
+            EngineeringResultItem::new("Perimeter Air Terminals", perimeter_terminals, "terminals")
+                .with_format(format!("{:.0} at {:.1} m max", perimeter_terminals, edge_spacing)),
+            EngineeringResultItem::new("Interior Air Terminals", interior_terminals, "terminals").with_format(format!("{:.0}", interior_terminals)),
+            EngineeringResultItem::new("Total Air Terminals", air_terminals, "terminals")
+                .critical()
+                .with_format(format!("{:.0}", air_terminals)),
+            EngineeringResultItem::new("Down Conductors", down_conductors, "conductors")
+                .critical()
+                .with_format(format!("{:.0}", down_conductors)),
+            EngineeringResultItem::new("Ground Terminals", down_conductors, "rods").with_format(format!("{:.0}", down_conductors)),
+            EngineeringResultItem::new("Conductor Length", conductor_length, "m").with_format(format!("{:.0} m", conductor_length)),
+        ]);
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "grounding_lightning_protection".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "IEEE 142 / NFPA 780".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_grounding_and_lightning_protection() {
+        let params = |dimensions: serde_json::Value, additional: serde_json::Value| -> EngineeringParameters {
+            serde_json::from_value(serde_json::json!({ "dimensions": dimensions, "additional": additional })).unwrap()
+        };
+        let building = serde_json::json!({ "length": 30.0, "width": 20.0, "height": 10.0 });
+
+        // One 3 m rod in 100 Ω·m soil is ≈ 33.5 Ω; two rods at 1.16/2 reach the 25 Ω target
+        let single = execute("grounding_lightning_protection", params(building.clone(), serde_json::json!({ "soil_resistivity": 100.0 })))
+            .await
+            .unwrap();
+        assert!((value(&single, "Single Rod Resistance") - 33.49).abs() < 0.01);
+        assert_eq!(value(&single, "Rods for Target Resistance"), 2.0);
+        assert!(warning_codes(&single).contains(&"grounding_lightning_protection.target_not_met".to_string()));
+
+        // 30 × 20 m flat roof: 14 edge terminals at 7.6 m, one intermediate, four down conductors
+        assert_eq!(value(&single, "Perimeter Air Terminals"), 14.0);
+        assert_eq!(value(&single, "Total Air Terminals"), 15.0);
+        assert_eq!(value(&single, "Down Conductors"), 4.0);
+        assert_eq!(value(&single, "Conductor Length"), 100.0 + 30.0 + 20.0 + 4.0 * 10.0);
+        assert_eq!(value(&single, "Protection Class"), 1.0);
+
+        // Short terminals tighten edge spacing; a grid replaces the rods as the governing electrode
+        let mut tall = building;
+        tall["height"] = serde_json::json!(30.0);
+        tall["grid_length"] = serde_json::json!(40.0);
+        tall["grid_width"] = serde_json::json!(40.0);
+        let grid = execute("grounding_lightning_protection", params(tall, serde_json::json!({ "soil_resistivity": 100.0, "air_terminal_height": 0.3 })))
+            .await
+            .unwrap();
+        assert_eq!(value(&grid, "Perimeter Air Terminals"), 2.0 * (5.0 + 4.0));
+        assert!(value(&grid, "Grid Resistance") < 2.0);
+        assert_eq!(value(&grid, "Protection Class"), 2.0);
+        let grid_codes = warning_codes(&grid);
+        assert!(!grid_codes.contains(&"grounding_lightning_protection.target_not_met".to_string()));
+        assert!(grid_codes.contains(&"grounding_lightning_protection.class_ii".to_string()));
+    }
+}
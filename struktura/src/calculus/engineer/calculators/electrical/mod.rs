@@ -0,0 +1,175 @@
+// ============================================================================
+// Electrical Engineering Calculators
+//
+// Grounding, lightning protection and power system calculators.
+// All calculators in this module require PE (Professional Engineer) review.
+// ============================================================================
+
+// Individual calculator modules
+pub mod grounding;
+pub mod generator_ups;
+
+// Re-export calculators
+pub use grounding::GroundingLightningProtectionCalculator;
+pub use generator_ups::GeneratorUpsCalculator;
+
+// ============================================================================
+// ELECTRICAL ENGINEERING CONSTANTS
+// ============================================================================
+
+/// Typical soil resistivities (Ω·m)
+pub mod soil_resistivity {
+    pub const WET_ORGANIC: f64 = 10.0;
+    pub const MOIST_CLAY: f64 = 100.0;
+    pub const DRY_SAND: f64 = 1000.0;
+    pub const ROCK: f64 = 10000.0;
+}
+
+/// NFPA 780 air terminal and conductor layout limits (m)
+pub mod lightning {
+    /// Maximum terminal spacing along edges for terminals under 600 mm tall
+    pub const EDGE_SPACING_SHORT: f64 = 6.1;
+    /// Maximum terminal spacing along edges for terminals 600 mm or taller
+    pub const EDGE_SPACING_TALL: f64 = 7.6;
+    /// Terminal height from which the wider edge spacing applies
+    pub const TALL_TERMINAL_HEIGHT: f64 = 0.6;
+    /// Maximum distance of the end terminals from corners and ridge ends
+    pub const CORNER_OFFSET: f64 = 0.6;
+    /// Maximum spacing of intermediate terminals on flat roofs
+    pub const INTERIOR_SPACING: f64 = 15.0;
+    /// Maximum average spacing of down conductors around the perimeter
+    pub const DOWN_CONDUCTOR_SPACING: f64 = 30.0;
+    /// Structures taller than this need Class II materials
+    pub const CLASS_I_MAX_HEIGHT: f64 = 23.0;
+}
+
+/// Standby generator and UPS battery defaults
+pub mod standby_power {
+    /// Standby generator ratings (kW) at 0.8 power factor
+    pub const GENERATOR_RATINGS: [f64; 24] = [
+        20.0, 30.0, 40.0, 50.0, 60.0, 80.0, 100.0, 125.0, 150.0, 175.0, 200.0, 250.0, 300.0, 350.0, 400.0, 500.0,
+        600.0, 750.0, 800.0, 1000.0, 1250.0, 1500.0, 2000.0, 2500.0,
+    ];
+    /// Rated generator power factor
+    pub const GENERATOR_POWER_FACTOR: f64 = 0.8;
+    /// Locked-rotor kVA as a multiple of running kVA for across-the-line motor starting
+    pub const LOCKED_ROTOR_MULTIPLE: f64 = 6.0;
+    /// Discharge time at which battery amp-hour ratings are published (h)
+    pub const BATTERY_RATED_HOURS: f64 = 20.0;
+    /// Peukert exponent of VRLA batteries
+    pub const PEUKERT_EXPONENT: f64 = 1.15;
+    /// Capacity left at end of battery life (IEEE 485 aging factor 1.25)
+    pub const END_OF_LIFE_CAPACITY: f64 = 0.8;
+}
+
+/// Helper functions for ground electrode resistance and standby power
+pub mod helpers {
+    use std::f64::consts::PI;
+
+    /// Multiplying factors for rods spaced at least one rod length apart (IEEE 142 Table 4-5)
+    const MULTIPLE_ROD_FACTORS: [(f64, f64); 9] = [
+        (1.0, 1.0),
+        (2.0, 1.16),
+        (3.0, 1.29),
+        (4.0, 1.36),
+        (8.0, 1.68),
+        (12.0, 1.80),
+        (16.0, 1.92),
+        (20.0, 2.00),
+        (24.0, 2.16),
+    ];
+
+    /// Most rods covered by the multiplying factor table
+    pub const MAX_RODS: usize = 24;
+
+    /// Resistance (Ω) of one driven rod from Dwight's formula R = ρ/(2πL)·(ln(8L/d) − 1)
+    pub fn rod_resistance(resistivity: f64, length: f64, diameter: f64) -> f64 {
+        resistivity / (2.0 * PI * length) * ((8.0 * length / diameter).ln() - 1.0)
+    }
+
+    /// IEEE 142 multiplying factor for `rods` rods, interpolated between table rows
+    pub fn multiple_rod_factor(rods: usize) -> f64 {
+        let n = rods.clamp(1, MAX_RODS) as f64;
+        MULTIPLE_ROD_FACTORS
+            .windows(2)
+            .find(|w| n <= w[1].0)
+            .map(|w| w[0].1 + (n - w[0].0) / (w[1].0 - w[0].0) * (w[1].1 - w[0].1))
+            .unwrap_or(1.0)
+    }
+
+    /// Resistance (Ω) of `rods` identical rods in parallel
+    pub fn rod_group_resistance(single: f64, rods: usize) -> f64 {
+        single * multiple_rod_factor(rods) / rods as f64
+    }
+
+    /// Sverak's grid resistance (Ω) for a grid of `area` m² with `conductor_length` m buried `depth` m (IEEE 80 Eq. 52)
+    pub fn grid_resistance(resistivity: f64, conductor_length: f64, area: f64, depth: f64) -> f64 {
+        let depth_term = 1.0 + 1.0 / (1.0 + depth * (20.0 / area).sqrt());
+        resistivity * (1.0 / conductor_length + depth_term / (20.0 * area).sqrt())
+    }
+
+    /// Transient voltage dip (fraction) when `step_kva` is applied to a generator of `generator_kva`
+    /// with transient reactance `reactance` (per unit)
+    pub fn voltage_dip(step_kva: f64, generator_kva: f64, reactance: f64) -> f64 {
+        let x = reactance * step_kva / generator_kva;
+        x / (1.0 + x)
+    }
+
+    /// Smallest generator kVA that keeps the dip for `step_kva` within `max_dip`
+    pub fn kva_for_voltage_dip(step_kva: f64, reactance: f64, max_dip: f64) -> f64 {
+        reactance * step_kva * (1.0 - max_dip) / max_dip
+    }
+
+    /// Battery runtime (h) at a constant `current` from Peukert's law t = H·(C / (I·H))^k
+    pub fn peukert_runtime(capacity: f64, current: f64, rated_hours: f64, exponent: f64) -> f64 {
+        rated_hours * (capacity / (current * rated_hours)).powf(exponent)
+    }
+
+    /// Rated capacity (Ah) that delivers `current` for `hours`, the inverse of [`peukert_runtime`]
+    pub fn peukert_capacity(current: f64, hours: f64, rated_hours: f64, exponent: f64) -> f64 {
+        current * rated_hours * (hours / rated_hours).powf(1.0 / exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rod_resistance() {
+        use helpers::*;
+
+        // 3 m × 16 mm rod in 100 Ω·m soil: 100/(6π)·(ln 1500 − 1) ≈ 33.5 Ω
+        let single = rod_resistance(soil_resistivity::MOIST_CLAY, 3.0, 0.016);
+        assert!((single - 33.49).abs() < 0.01);
+        assert_eq!(multiple_rod_factor(1), 1.0);
+        assert!((multiple_rod_factor(6) - 1.52).abs() < 1e-9);
+        assert!((rod_group_resistance(single, 2) - single * 0.58).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_grid_resistance() {
+        use helpers::*;
+
+        // Deeper burial and more conductor both lower the resistance
+        let shallow = grid_resistance(100.0, 200.0, 400.0, 0.25);
+        assert!(grid_resistance(100.0, 200.0, 400.0, 1.0) < shallow);
+        assert!(grid_resistance(100.0, 400.0, 400.0, 0.25) < shallow);
+        // Never below the area term ρ/√(20A)
+        assert!(shallow > 100.0 / (20.0f64 * 400.0).sqrt());
+    }
+
+    #[test]
+    fn test_voltage_dip_and_battery_runtime() {
+        use helpers::*;
+
+        // Sizing for a 30% dip gives exactly a 30% dip
+        let kva = kva_for_voltage_dip(300.0, 0.2, 0.3);
+        assert!((voltage_dip(300.0, kva, 0.2) - 0.3).abs() < 1e-12);
+
+        // At the 20-hour current a battery runs 20 hours; capacity and runtime are inverses
+        assert!((peukert_runtime(100.0, 5.0, 20.0, 1.15) - 20.0).abs() < 1e-9);
+        let capacity = peukert_capacity(60.0, 0.25, 20.0, 1.15);
+        assert!((peukert_runtime(capacity, 60.0, 20.0, 1.15) - 0.25).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,404 @@
+use crate::calculus::engineer::{
+    errors::EngineeringResult,
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{CULVERT_HEADWATER_EXCEEDED, CULVERT_HIGH_OUTLET_VELOCITY},
+};
+use async_trait::async_trait;
+use std::f64::consts::PI;
+
+use super::helpers::{circular_section, critical_depth, normal_depth};
+use super::manning::CONCRETE_PIPE;
+use super::STANDARD_DIAMETERS;
+use crate::calculus::engineer::calculators::mechanical::constants::GRAVITY;
+
+/// SI unit conversion factor of the HDS-5 inlet control equations
+const KU: f64 = 1.811;
+
+/// Slope correction of the unsubmerged and submerged inlet equations
+const SLOPE_CORRECTION: f64 = -0.5;
+
+/// Discharge intensity Ku·Q / (A·D^0.5) bounding the unsubmerged and submerged forms
+const UNSUBMERGED_LIMIT: f64 = 3.5;
+const SUBMERGED_LIMIT: f64 = 4.0;
+
+/// Outlet velocity above which scour protection is needed (m/s)
+const MAX_OUTLET_VELOCITY: f64 = 4.5;
+
+/// Concrete pipe inlets (FHWA HDS-5 Table C.1, form 1): name, K, M, c, Y, entrance loss ke
+const INLETS: [(&str, f64, f64, f64, f64, f64); 3] = [
+    ("square edge with headwall", 0.0098, 2.0, 0.0398, 0.67, 0.5),
+    ("groove end with headwall", 0.0018, 2.0, 0.0292, 0.74, 0.2),
+    ("groove end projecting", 0.0045, 2.0, 0.0317, 0.69, 0.2),
+];
+
+/// Headwater of one barrel under inlet and outlet control
+struct Headwater {
+    inlet: f64,
+    outlet: f64,
+    critical_depth: f64,
+}
+
+impl Headwater {
+    fn design(&self) -> f64 {
+        self.inlet.max(self.outlet)
+    }
+}
+
+/// Inlet and outlet control headwater of a circular culvert
+struct Culvert {
+    flow: f64,
+    length: f64,
+    slope: f64,
+    n: f64,
+    tailwater: f64,
+    inlet: (f64, f64, f64, f64),
+    ke: f64,
+}
+
+impl Culvert {
+    fn headwater(&self, diameter: f64) -> Headwater {
+        let (k, m, c, y) = self.inlet;
+        let area = PI * diameter.powi(2) / 4.0;
+        let dc = critical_depth(self.flow, diameter);
+        let (area_c, _, _) = circular_section(diameter, dc);
+        let hc = dc + (self.flow / area_c).powi(2) / (2.0 * GRAVITY);
+
+        // Inlet control, blending the unsubmerged and submerged forms through the transition
+        let intensity = KU * self.flow / (area * diameter.sqrt());
+        let unsubmerged = hc / diameter + k * intensity.powf(m) + SLOPE_CORRECTION * self.slope;
+        let submerged = c * intensity.powi(2) + y + SLOPE_CORRECTION * self.slope;
+        let ratio = if intensity <= UNSUBMERGED_LIMIT {
+            unsubmerged
+        } else if intensity >= SUBMERGED_LIMIT {
+            submerged
+        } else {
+            let t = (intensity - UNSUBMERGED_LIMIT) / (SUBMERGED_LIMIT - UNSUBMERGED_LIMIT);
+            unsubmerged + t * (submerged - unsubmerged)
+        };
+
+        // Outlet control with the barrel flowing full: H = (1 + ke + 2g·n²·L / R^(4/3))·V²/2g
+        let velocity = self.flow / area;
+        let friction = 2.0 * GRAVITY * self.n.powi(2) * self.length / (diameter / 4.0).powf(4.0 / 3.0);
+        let losses = (1.0 + self.ke + friction) * velocity.powi(2) / (2.0 * GRAVITY);
+        let outlet_depth = self.tailwater.max((dc + diameter) / 2.0);
+
+        Headwater {
+            inlet: ratio * diameter,
+            outlet: losses + outlet_depth - self.length * self.slope,
+            critical_depth: dc,
+        }
+    }
+}
+
+pub struct CulvertSizingCalculator;
+
+impl ParameterValidator for CulvertSizingCalculator {
+    fn calculator_id(&self) -> &str {
+        "culvert_sizing"
+    }
+}
+
+impl CulvertSizingCalculator {
+    /// Barrel diameter when the caller fixes one, range-checked only when supplied
+    fn fixed_diameter(&self, params: &EngineeringParameters) -> EngineeringResult<Option<f64>> {
+        params
+            .dimensions
+            .get("diameter")
+            .map(|&value| self.validate_dimension("diameter", Some(value), 0.3, 3.0))
+            .transpose()
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for CulvertSizingCalculator {
+    fn id(&self) -> &str {
+        "culvert_sizing"
+    }
+
+    fn name(&self) -> &str {
+        "Culvert Sizing (Inlet/Outlet Control)"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Hydraulic
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("culvert_sizing", "Culvert Sizing (Inlet/Outlet Control)")
+            .category("hydraulic")
+            .description("Size a circular concrete culvert for a design flow and allowable headwater, checking inlet control (FHWA HDS-5) and full-flow outlet control")
+            .design_code("FHWA HDS-5")
+            .parameter(ParameterMetadata {
+                name: "Design Flow".to_string(),
+                path: "additional.design_flow".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m³/s".to_string(),
+                description: "Peak flow the culvert must pass".to_string(),
+                required: true,
+                default_value: Some(2.0),
+                min_value: Some(0.01),
+                max_value: Some(50.0),
+                typical_range: Some((0.5, 10.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Allowable Headwater".to_string(),
+                path: "additional.allowable_headwater".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Highest permitted water level above the inlet invert".to_string(),
+                required: true,
+                default_value: Some(2.0),
+                min_value: Some(0.3),
+                max_value: Some(10.0),
+                typical_range: Some((1.0, 4.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Culvert Length".to_string(),
+                path: "dimensions.length".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Barrel length".to_string(),
+                required: false,
+                default_value: Some(20.0),
+                min_value: Some(2.0),
+                max_value: Some(300.0),
+                typical_range: Some((10.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Culvert Diameter".to_string(),
+                path: "dimensions.diameter".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Diameter to check; the smallest standard pipe meeting the allowable headwater is chosen when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.3),
+                max_value: Some(3.0),
+                typical_range: Some((0.6, 1.8)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Barrel Slope".to_string(),
+                path: "additional.slope".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m/m".to_string(),
+                description: "Invert slope of the barrel".to_string(),
+                required: false,
+                default_value: Some(0.01),
+                min_value: Some(0.0),
+                max_value: Some(0.2),
+                typical_range: Some((0.005, 0.03)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Tailwater Depth".to_string(),
+                path: "additional.tailwater".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Water depth above the outlet invert".to_string(),
+                required: false,
+                default_value: Some(0.0),
+                min_value: Some(0.0),
+                max_value: Some(10.0),
+                typical_range: Some((0.0, 1.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Inlet Type".to_string(),
+                path: "additional.inlet_type".to_string(),
+                data_type: ParameterType::Integer,
+                unit: "".to_string(),
+                description: "1 square edge with headwall, 2 groove end with headwall, 3 groove end projecting".to_string(),
+                required: false,
+                default_value: Some(1.0),
+                min_value: Some(1.0),
+                max_value: Some(3.0),
+                typical_range: Some((1.0, 3.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Manning n".to_string(),
+                path: "additional.manning_n".to_string(),
+                data_type: ParameterType::Number,
+                unit: "".to_string(),
+                description: "Barrel roughness for outlet control".to_string(),
+                required: false,
+                default_value: Some(CONCRETE_PIPE),
+                min_value: Some(0.009),
+                max_value: Some(0.035),
+                typical_range: Some((0.012, 0.024)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Intermediate)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.get_additional_param(params, "design_flow", Some(0.01), Some(50.0))?;
+        self.get_additional_param(params, "allowable_headwater", Some(0.3), Some(10.0))?;
+        self.fixed_diameter(params)?;
+        if let Some(&length) = params.dimensions.get("length") {
+            self.validate_dimension("length", Some(length), 2.0, 300.0)?;
+        }
+        self.optional_param(params, "slope", 0.01, 0.0, 0.2)?;
+        self.optional_param(params, "tailwater", 0.0, 0.0, 10.0)?;
+        self.optional_param(params, "inlet_type", 1.0, 1.0, 3.0)?;
+        self.optional_param(params, "manning_n", CONCRETE_PIPE, 0.009, 0.035)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let flow = self.get_additional_param(&params, "design_flow", None, None)?;
+        let allowable = self.get_additional_param(&params, "allowable_headwater", None, None)?;
+        let fixed_diameter = self.fixed_diameter(&params)?;
+        let (name, k, m, c, y, ke) = INLETS[self.optional_param(&params, "inlet_type", 1.0, 1.0, 3.0)?.round() as usize - 1];
+        let culvert = Culvert {
+            flow,
+            length: params.dimensions.get("length").copied().unwrap_or(20.0),
+            slope: self.optional_param(&params, "slope", 0.01, 0.0, 0.2)?,
+            n: self.optional_param(&params, "manning_n", CONCRETE_PIPE, 0.009, 0.035)?,
+            tailwater: self.optional_param(&params, "tailwater", 0.0, 0.0, 10.0)?,
+            inlet: (k, m, c, y),
+            ke,
+        };
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+
+        let diameter = fixed_diameter.unwrap_or_else(|| {
+            STANDARD_DIAMETERS
+                .iter()
+                .copied()
+                .find(|&d| culvert.headwater(d).design() <= allowable)
+                .unwrap_or(STANDARD_DIAMETERS[STANDARD_DIAMETERS.len() - 1])
+        });
+        let hw = culvert.headwater(diameter);
+        trace.step("formulas.culvert_sizing.critical_depth", &[("flow", flow), ("diameter", diameter)], hw.critical_depth, "m");
+        trace.step(
+            "formulas.culvert_sizing.inlet_control",
+            &[("flow", flow), ("diameter", diameter), ("k", k), ("m", m), ("c", c), ("y", y), ("slope", culvert.slope)],
+            hw.inlet,
+            "m",
+        );
+        trace.step(
+            "formulas.culvert_sizing.outlet_control",
+            &[("flow", flow), ("diameter", diameter), ("length", culvert.length), ("manning_n", culvert.n), ("ke", ke), ("tailwater", culvert.tailwater)],
+            hw.outlet,
+            "m",
+        );
+        let design_hw = hw.design();
+        let control = if hw.inlet >= hw.outlet { "Inlet" } else { "Outlet" };
+
+        // Barrel velocity at the outlet: normal depth when the pipe runs part full, otherwise full flow
+        let area = PI * diameter.powi(2) / 4.0;
+        let yn = normal_depth(flow, culvert.n, diameter, culvert.slope.max(1.0e-4));
+        let outlet_velocity = match yn {
+            Some(depth) if control == "Inlet" => flow / circular_section(diameter, depth).0,
+            _ => flow / area,
+        };
+        trace.step(
+            "formulas.culvert_sizing.outlet_velocity",
+            &[("flow", flow), ("diameter", diameter), ("normal_depth", yn.unwrap_or(diameter))],
+            outlet_velocity,
+            "m/s",
+        );
+
+        if design_hw > allowable {
+            warnings.push(CULVERT_HEADWATER_EXCEEDED.warn(format!(
+                "{} control headwater of {:.2} m exceeds the allowable {:.2} m for a {:.0} mm barrel",
+                control,
+                design_hw,
+                allowable,
+                diameter * 1000.0
+            )));
+            recommendations.push("Use a larger barrel, multiple barrels or an improved inlet".to_string());
+        }
+        if outlet_velocity > MAX_OUTLET_VELOCITY {
+            warnings.push(CULVERT_HIGH_OUTLET_VELOCITY.warn(format!(
+                "Outlet velocity of {:.1} m/s exceeds {:.1} m/s",
+                outlet_velocity, MAX_OUTLET_VELOCITY
+            )));
+            recommendations.push("Provide a riprap apron or energy dissipator at the outlet".to_string());
+        }
+
+        let compliance_notes = vec![
+            format!("Inlet control per FHWA HDS-5 for a concrete pipe, {}", name),
+            format!("{} control governs at {:.2} m headwater", control, design_hw),
+            "Outlet control assumes the barrel flows full; check partly full outlet control for low tailwater".to_string(),
+        ];
+
+        let mut results = vec![
+            EngineeringResultItem::new("Culvert Diameter", diameter, "m").critical().with_format(format!("{:.0} mm", diameter * 1000.0)),
+            EngineeringResultItem::new("Inlet Control Headwater", hw.inlet, "m").with_format(format!("{:.2} m", hw.inlet)),
+            EngineeringResultItem::new("Outlet Control Headwater", hw.outlet, "m").with_format(format!("{:.2} m", hw.outlet)),
+            EngineeringResultItem::new("Design Headwater", design_hw, "m").critical().with_format(format!("{:.2} m", design_hw)),
+            EngineeringResultItem::new("Headwater Ratio HW/D", design_hw / diameter, "").with_format(format!("{:.2}", design_hw / diameter)),
+            EngineeringResultItem::new("Critical Depth", hw.critical_depth, "m").with_format(format!("{:.2} m", hw.critical_depth)),
+        ];
+        if let Some(depth) = yn {
+            results.push(EngineeringResultItem::new("Normal Depth", depth, "m").with_format(format!("{:.2} m", depth)));
+        }
+        results.push(
+            EngineeringResultItem::new("Outlet Velocity", outlet_velocity, "m/s")
+                .critical()
+                .with_format(format!("{:.2} m/s", outlet_velocity)),
+        );
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "culvert_sizing".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "FHWA HDS-5".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_culvert_sizing_headwater() {
+        let params = |diameter: Option<f64>| -> EngineeringParameters {
+            let mut json = serde_json::json!({
+                "dimensions": { "length": 30.0 },
+                "additional": { "design_flow": 2.0, "allowable_headwater": 2.0, "slope": 0.01 },
+            });
+            if let Some(d) = diameter {
+                json["dimensions"]["diameter"] = serde_json::json!(d);
+            }
+            serde_json::from_value(json).unwrap()
+        };
+
+        let sized = execute("culvert_sizing", params(None)).await.unwrap();
+        let diameter = value(&sized, "Culvert Diameter");
+        let design = value(&sized, "Design Headwater");
+        assert!(design <= 2.0);
+        assert_eq!(design, value(&sized, "Inlet Control Headwater").max(value(&sized, "Outlet Control Headwater")));
+        assert!(!warning_codes(&sized).contains(&"culvert_sizing.headwater_exceeded".to_string()));
+
+        // The next smaller barrel does not pass
+        let sizes = STANDARD_DIAMETERS;
+        let smaller = sizes[sizes.iter().position(|&d| d == diameter).unwrap() - 1];
+        let undersized = execute("culvert_sizing", params(Some(smaller))).await.unwrap();
+        assert!(value(&undersized, "Design Headwater") > 2.0);
+        assert!(warning_codes(&undersized).contains(&"culvert_sizing.headwater_exceeded".to_string()));
+    }
+}
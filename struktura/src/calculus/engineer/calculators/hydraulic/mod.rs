@@ -0,0 +1,150 @@
+// ============================================================================
+// Hydraulic Engineering Calculators
+//
+// Storm drainage runoff, culvert and gravity sewer calculators.
+// All calculators in this module require PE (Professional Engineer) review.
+// ============================================================================
+
+// Individual calculator modules
+pub mod rational_method;
+pub mod culvert_sizing;
+pub mod storm_sewer;
+
+// Re-export calculators
+pub use rational_method::RationalMethodRunoffCalculator;
+pub use culvert_sizing::CulvertSizingCalculator;
+pub use storm_sewer::StormSewerSizingCalculator;
+
+// ============================================================================
+// HYDRAULIC ENGINEERING CONSTANTS
+// ============================================================================
+
+/// Manning roughness coefficients
+pub mod manning {
+    pub const CONCRETE_PIPE: f64 = 0.013;
+    pub const PVC_HDPE_SMOOTH: f64 = 0.011;
+    pub const CORRUGATED_METAL: f64 = 0.024;
+    pub const GRASS_CHANNEL: f64 = 0.035;
+}
+
+/// Nominal diameters of precast concrete pipe (m)
+pub const STANDARD_DIAMETERS: [f64; 20] = [
+    0.3, 0.375, 0.45, 0.525, 0.6, 0.675, 0.75, 0.825, 0.9, 1.05, 1.2, 1.35, 1.5, 1.65, 1.8, 1.95, 2.1, 2.4, 2.7, 3.0,
+];
+
+/// Default intensity-duration-frequency curve i = a / (t + b)^n (i mm/h, t min), a 10-year storm
+pub mod idf {
+    pub const DEFAULT_A: f64 = 1500.0;
+    pub const DEFAULT_B: f64 = 10.0;
+    pub const DEFAULT_N: f64 = 0.8;
+}
+
+/// Helper functions for open channel and pipe hydraulics
+pub mod helpers {
+    use crate::calculus::engineer::calculators::mechanical::constants::GRAVITY;
+
+    /// Flow area, wetted perimeter and top width of a circular pipe flowing at depth `y`
+    pub fn circular_section(diameter: f64, y: f64) -> (f64, f64, f64) {
+        let y = y.clamp(0.0, diameter);
+        let theta = 2.0 * (1.0 - 2.0 * y / diameter).acos();
+        let area = diameter.powi(2) / 8.0 * (theta - theta.sin());
+        let perimeter = theta * diameter / 2.0;
+        let top_width = diameter * (theta / 2.0).sin();
+        (area, perimeter, top_width)
+    }
+
+    /// Manning discharge (m³/s) for a flow area, hydraulic radius and slope
+    pub fn manning_flow(n: f64, area: f64, hydraulic_radius: f64, slope: f64) -> f64 {
+        area * hydraulic_radius.powf(2.0 / 3.0) * slope.sqrt() / n
+    }
+
+    /// Full-flow discharge (m³/s) and velocity (m/s) of a circular pipe
+    pub fn full_flow(n: f64, diameter: f64, slope: f64) -> (f64, f64) {
+        let area = std::f64::consts::PI * diameter.powi(2) / 4.0;
+        let q = manning_flow(n, area, diameter / 4.0, slope);
+        (q, q / area)
+    }
+
+    /// Discharge at depth `y` in a circular pipe
+    fn partial_flow(n: f64, diameter: f64, slope: f64, y: f64) -> f64 {
+        let (area, perimeter, _) = circular_section(diameter, y);
+        if perimeter <= 0.0 {
+            return 0.0;
+        }
+        manning_flow(n, area, area / perimeter, slope)
+    }
+
+    /// Normal depth (m) of `q` in a circular pipe, or None when it exceeds the
+    /// pipe's peak capacity (reached at 93.8% of the diameter)
+    pub fn normal_depth(q: f64, n: f64, diameter: f64, slope: f64) -> Option<f64> {
+        let (mut low, mut high) = (0.0, 0.938 * diameter);
+        if q > partial_flow(n, diameter, slope, high) {
+            return None;
+        }
+        for _ in 0..60 {
+            let mid = 0.5 * (low + high);
+            if partial_flow(n, diameter, slope, mid) < q {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Some(0.5 * (low + high))
+    }
+
+    /// Critical depth (m) of `q` in a circular pipe, where Q²·T / (g·A³) = 1
+    pub fn critical_depth(q: f64, diameter: f64) -> f64 {
+        let (mut low, mut high) = (1.0e-6 * diameter, 0.999 * diameter);
+        for _ in 0..60 {
+            let mid = 0.5 * (low + high);
+            let (area, _, top_width) = circular_section(diameter, mid);
+            if q.powi(2) * top_width / (GRAVITY * area.powi(3)) > 1.0 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        0.5 * (low + high)
+    }
+
+    /// Rainfall intensity (mm/h) for a duration in minutes from i = a / (t + b)^n
+    pub fn idf_intensity(a: f64, b: f64, n: f64, duration: f64) -> f64 {
+        a / (duration + b).powf(n)
+    }
+
+    /// Kirpich time of concentration (min) for a flow length (m) and slope (m/m)
+    pub fn kirpich(length: f64, slope: f64) -> f64 {
+        0.0195 * length.powf(0.77) * slope.powf(-0.385)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_and_partial_flow() {
+        use helpers::*;
+
+        // 600 mm concrete pipe at 1%: Q = (1/0.013)·0.2827·0.15^(2/3)·0.1 ≈ 0.614 m³/s
+        let (q, v) = full_flow(manning::CONCRETE_PIPE, 0.6, 0.01);
+        assert!((q - 0.614).abs() < 0.005);
+        assert!((v - q / (std::f64::consts::PI * 0.09)).abs() < 1e-12);
+
+        // Half full carries half the full flow
+        let y = normal_depth(q / 2.0, manning::CONCRETE_PIPE, 0.6, 0.01).unwrap();
+        assert!((y / 0.6 - 0.5).abs() < 0.01);
+        assert!(normal_depth(1.1 * q, manning::CONCRETE_PIPE, 0.6, 0.01).is_none());
+    }
+
+    #[test]
+    fn test_critical_depth() {
+        use helpers::*;
+
+        let d = 1.2;
+        let dc = critical_depth(1.0, d);
+        let (area, _, top) = circular_section(d, dc);
+        assert!((1.0f64 * top / (9.81 * area.powi(3)) - 1.0).abs() < 1e-6);
+        assert!(dc > 0.0 && dc < d);
+    }
+}
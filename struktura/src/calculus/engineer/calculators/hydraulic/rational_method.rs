@@ -0,0 +1,424 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{RATIONAL_LARGE_AREA, RATIONAL_SHORT_TC},
+};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::helpers::{idf_intensity, kirpich};
+use super::idf::{DEFAULT_A, DEFAULT_B, DEFAULT_N};
+
+/// Q (m³/s) = C·i (mm/h)·A (ha) / 360
+const UNIT_CONVERSION: f64 = 360.0;
+
+/// Shortest time of concentration used for intensity (min)
+const MIN_TC: f64 = 5.0;
+
+/// Largest catchment the rational method is normally applied to (ha)
+const MAX_AREA: f64 = 80.0;
+
+/// Antecedent precipitation factor by return period (years), capped so C·Cf ≤ 1
+const FREQUENCY_FACTORS: [(f64, f64); 4] = [(10.0, 1.0), (25.0, 1.1), (50.0, 1.2), (100.0, 1.25)];
+
+/// One land use within the catchment
+#[derive(Debug, Clone, Deserialize)]
+struct SubArea {
+    #[serde(default)]
+    name: Option<String>,
+    /// Area (ha)
+    area: f64,
+    /// Runoff coefficient C
+    c: f64,
+}
+
+/// Frequency factor for the longest tabulated return period not exceeding `years`
+fn frequency_factor(years: f64) -> f64 {
+    FREQUENCY_FACTORS.iter().rev().find(|(t, _)| years >= *t).map(|(_, cf)| *cf).unwrap_or(1.0)
+}
+
+pub struct RationalMethodRunoffCalculator;
+
+impl ParameterValidator for RationalMethodRunoffCalculator {
+    fn calculator_id(&self) -> &str {
+        "rational_method_runoff"
+    }
+}
+
+impl RationalMethodRunoffCalculator {
+    /// Land uses from `extended_parameters.subareas`, or one area with a single coefficient
+    fn subareas(&self, params: &EngineeringParameters) -> EngineeringResult<Vec<SubArea>> {
+        let Some(value) = params.extended_parameters.as_ref().and_then(|e| e.get("subareas")) else {
+            return Ok(vec![SubArea {
+                name: None,
+                area: self.get_additional_param(params, "drainage_area", Some(0.01), Some(1000.0))?,
+                c: self.optional_param(params, "runoff_coefficient", 0.5, 0.05, 1.0)?,
+            }]);
+        };
+        let invalid = |reason: String| EngineeringError::InvalidParameter {
+            parameter: "subareas".to_string(),
+            value: format!("{:?}", value),
+            reason,
+        };
+        let items = value.as_array().ok_or_else(|| invalid("Expected an array of subareas".to_string()))?;
+        let subareas: Vec<SubArea> =
+            serde_json::from_value(serde_json::Value::Array(items.clone())).map_err(|e| invalid(e.to_string()))?;
+        if subareas.is_empty() || subareas.len() > 50 {
+            return Err(invalid("Between 1 and 50 subareas are required".to_string()));
+        }
+        for (i, s) in subareas.iter().enumerate() {
+            if !(0.001..=1000.0).contains(&s.area) || !(0.05..=1.0).contains(&s.c) {
+                return Err(invalid(format!(
+                    "Subarea {} needs an area of 0.001-1000 ha and a runoff coefficient of 0.05-1.0",
+                    s.name.clone().unwrap_or_else(|| (i + 1).to_string())
+                )));
+            }
+        }
+        Ok(subareas)
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for RationalMethodRunoffCalculator {
+    fn id(&self) -> &str {
+        "rational_method_runoff"
+    }
+
+    fn name(&self) -> &str {
+        "Rational Method Peak Runoff"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Hydraulic
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("rational_method_runoff", "Rational Method Peak Runoff")
+            .category("hydraulic")
+            .description("Peak storm runoff Q = C·i·A from a composite runoff coefficient, Kirpich time of concentration and an IDF curve")
+            .design_code("ASCE MOP 77")
+            .parameter(ParameterMetadata {
+                name: "Drainage Area".to_string(),
+                path: "additional.drainage_area".to_string(),
+                data_type: ParameterType::Number,
+                unit: "ha".to_string(),
+                description: "Catchment area, used when no subareas are given".to_string(),
+                required: true,
+                default_value: Some(10.0),
+                min_value: Some(0.01),
+                max_value: Some(1000.0),
+                typical_range: Some((1.0, 50.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Runoff Coefficient".to_string(),
+                path: "additional.runoff_coefficient".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "C for the whole catchment (0.9 paved, 0.5 residential, 0.2 lawns); 0.5 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.05),
+                max_value: Some(1.0),
+                typical_range: Some((0.3, 0.9)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Subareas".to_string(),
+                path: "extended_parameters.subareas".to_string(),
+                data_type: ParameterType::Array,
+                unit: "".to_string(),
+                description: "Objects with area (ha), runoff coefficient c and optional name, combined into an area-weighted C".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec!["1 to 50 subareas".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Time of Concentration".to_string(),
+                path: "additional.time_of_concentration".to_string(),
+                data_type: ParameterType::Number,
+                unit: "min".to_string(),
+                description: "Known time of concentration; Kirpich from the flow length and slope when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(600.0),
+                typical_range: Some((5.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Flow Length".to_string(),
+                path: "additional.flow_length".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Longest flow path to the design point".to_string(),
+                required: false,
+                default_value: Some(300.0),
+                min_value: Some(10.0),
+                max_value: Some(20000.0),
+                typical_range: Some((100.0, 1500.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Watershed Slope".to_string(),
+                path: "additional.watershed_slope".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m/m".to_string(),
+                description: "Average slope along the flow path".to_string(),
+                required: false,
+                default_value: Some(0.02),
+                min_value: Some(0.001),
+                max_value: Some(0.5),
+                typical_range: Some((0.005, 0.05)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Rainfall Intensity".to_string(),
+                path: "additional.rainfall_intensity".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm/h".to_string(),
+                description: "Design intensity for the time of concentration; taken from the IDF curve when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(500.0),
+                typical_range: Some((25.0, 200.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "IDF Coefficient a".to_string(),
+                path: "additional.idf_a".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm/h·min^n".to_string(),
+                description: "a in i = a / (t + b)^n from the local IDF curve; 1500 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(10.0),
+                max_value: Some(20000.0),
+                typical_range: Some((500.0, 5000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "IDF Coefficient b".to_string(),
+                path: "additional.idf_b".to_string(),
+                data_type: ParameterType::Number,
+                unit: "min".to_string(),
+                description: "b in i = a / (t + b)^n; 10 min when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(60.0),
+                typical_range: Some((5.0, 20.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "IDF Exponent n".to_string(),
+                path: "additional.idf_n".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "n in i = a / (t + b)^n; 0.8 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.3),
+                max_value: Some(1.2),
+                typical_range: Some((0.6, 0.9)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Return Period".to_string(),
+                path: "additional.return_period".to_string(),
+                data_type: ParameterType::Number,
+                unit: "years".to_string(),
+                description: "Design storm return period; sets the frequency factor applied to C".to_string(),
+                required: false,
+                default_value: Some(10.0),
+                min_value: Some(1.0),
+                max_value: Some(500.0),
+                typical_range: Some((2.0, 100.0)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Basic)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.subareas(params)?;
+        self.optional_param(params, "time_of_concentration", MIN_TC, 1.0, 600.0)?;
+        self.optional_param(params, "flow_length", 300.0, 10.0, 20000.0)?;
+        self.optional_param(params, "watershed_slope", 0.02, 0.001, 0.5)?;
+        self.optional_param(params, "rainfall_intensity", 50.0, 1.0, 500.0)?;
+        self.optional_param(params, "idf_a", DEFAULT_A, 10.0, 20000.0)?;
+        self.optional_param(params, "idf_b", DEFAULT_B, 0.0, 60.0)?;
+        self.optional_param(params, "idf_n", DEFAULT_N, 0.3, 1.2)?;
+        self.optional_param(params, "return_period", 10.0, 1.0, 500.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let subareas = self.subareas(&params)?;
+        let return_period = self.optional_param(&params, "return_period", 10.0, 1.0, 500.0)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut compliance_notes = vec!["Rational method Q = C·Cf·i·A / 360 (Q m³/s, i mm/h, A ha)".to_string()];
+
+        let area: f64 = subareas.iter().map(|s| s.area).sum();
+        let composite_c = trace.step(
+            "formulas.rational_method_runoff.composite_coefficient",
+            &[("sum_ca", subareas.iter().map(|s| s.c * s.area).sum()), ("area", area)],
+            subareas.iter().map(|s| s.c * s.area).sum::<f64>() / area,
+            "",
+        );
+        let cf = frequency_factor(return_period);
+        let effective_c = trace.step(
+            "formulas.rational_method_runoff.effective_coefficient",
+            &[("composite_c", composite_c), ("frequency_factor", cf)],
+            (composite_c * cf).min(1.0),
+            "",
+        );
+
+        let tc_input = match self.get_additional_param(&params, "time_of_concentration", Some(1.0), Some(600.0)) {
+            Ok(tc) => tc,
+            Err(EngineeringError::MissingParameter { .. }) => {
+                let length = self.optional_param(&params, "flow_length", 300.0, 10.0, 20000.0)?;
+                let slope = self.optional_param(&params, "watershed_slope", 0.02, 0.001, 0.5)?;
+                compliance_notes.push(format!("Kirpich time of concentration for {:.0} m at {:.3} m/m", length, slope));
+                trace.step(
+                    "formulas.rational_method_runoff.kirpich",
+                    &[("flow_length", length), ("slope", slope)],
+                    kirpich(length, slope),
+                    "min",
+                )
+            }
+            Err(e) => return Err(e),
+        };
+        if tc_input < MIN_TC {
+            warnings.push(RATIONAL_SHORT_TC.warn(format!(
+                "Time of concentration of {:.1} min raised to {:.0} min for the design intensity",
+                tc_input, MIN_TC
+            )));
+        }
+        let tc = tc_input.max(MIN_TC);
+
+        let intensity = match self.get_additional_param(&params, "rainfall_intensity", Some(1.0), Some(500.0)) {
+            Ok(i) => i,
+            Err(EngineeringError::MissingParameter { .. }) => {
+                let a = self.optional_param(&params, "idf_a", DEFAULT_A, 10.0, 20000.0)?;
+                let b = self.optional_param(&params, "idf_b", DEFAULT_B, 0.0, 60.0)?;
+                let n = self.optional_param(&params, "idf_n", DEFAULT_N, 0.3, 1.2)?;
+                trace.step(
+                    "formulas.rational_method_runoff.idf_intensity",
+                    &[("a", a), ("b", b), ("n", n), ("duration", tc)],
+                    idf_intensity(a, b, n, tc),
+                    "mm/h",
+                )
+            }
+            Err(e) => return Err(e),
+        };
+
+        let peak = trace.step(
+            "formulas.rational_method_runoff.peak_runoff",
+            &[("c", effective_c), ("intensity", intensity), ("area", area)],
+            effective_c * intensity * area / UNIT_CONVERSION,
+            "m³/s",
+        );
+        // Triangular hydrograph rising over tc and receding over tc
+        let volume = trace.step(
+            "formulas.rational_method_runoff.runoff_volume",
+            &[("peak", peak), ("tc", tc)],
+            peak * tc * 60.0,
+            "m³",
+        );
+
+        if area > MAX_AREA {
+            warnings.push(RATIONAL_LARGE_AREA.warn(format!(
+                "{:.0} ha catchment exceeds the {:.0} ha usually accepted for the rational method",
+                area, MAX_AREA
+            )));
+            recommendations.push("Check the peak with a unit hydrograph or SCS/NRCS method".to_string());
+        }
+        compliance_notes.push(format!("{:.0}-year storm, frequency factor {:.2}", return_period, cf));
+
+        let mut results = vec![
+            EngineeringResultItem::new("Drainage Area", area, "ha").with_format(format!("{:.2} ha", area)),
+            EngineeringResultItem::new("Composite Runoff Coefficient", composite_c, "").with_format(format!("{:.2}", composite_c)),
+            EngineeringResultItem::new("Frequency Factor", cf, "").with_format(format!("{:.2}", cf)),
+            EngineeringResultItem::new("Time of Concentration", tc, "min").with_format(format!("{:.1} min", tc)),
+            EngineeringResultItem::new("Rainfall Intensity", intensity, "mm/h").with_format(format!("{:.1} mm/h", intensity)),
+            EngineeringResultItem::new("Peak Runoff", peak, "m³/s").critical().with_format(format!("{:.3} m³/s", peak)),
+            EngineeringResultItem::new("Runoff Volume", volume, "m³").with_format(format!("{:.0} m³", volume)),
+        ];
+        if subareas.len() > 1 {
+            for (i, s) in subareas.iter().enumerate() {
+                let share = s.c * s.area / (composite_c * area) * peak;
+                let name = s.name.clone().unwrap_or_else(|| format!("Subarea {}", i + 1));
+                results.push(
+                    EngineeringResultItem::new(format!("{} Contribution", name), share, "m³/s")
+                        .with_format(format!("{:.3} m³/s", share)),
+                );
+            }
+        }
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "rational_method_runoff".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "ASCE MOP 77".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_rational_method_peak_runoff() {
+        let params = |json: serde_json::Value| -> EngineeringParameters { serde_json::from_value(json).unwrap() };
+
+        // 10 ha at C = 0.5 with tc = 15 min on the default IDF curve: i = 1500 / 25^0.8
+        let single = execute("rational_method_runoff", params(serde_json::json!({
+                "dimensions": {},
+                "additional": { "drainage_area": 10.0, "runoff_coefficient": 0.5, "time_of_concentration": 15.0 },
+            })))
+            .await
+            .unwrap();
+        let intensity = 1500.0 / 25f64.powf(0.8);
+        assert!((value(&single, "Rainfall Intensity") - intensity).abs() < 1e-9);
+        assert!((value(&single, "Peak Runoff") - 0.5 * intensity * 10.0 / 360.0).abs() < 1e-9);
+        assert!(warning_codes(&single).is_empty());
+
+        // Composite C by area and the 25-year frequency factor
+        let composite = execute("rational_method_runoff", params(serde_json::json!({
+                "dimensions": {},
+                "additional": { "rainfall_intensity": 100.0, "return_period": 25.0, "time_of_concentration": 3.0 },
+                "extended_parameters": { "subareas": { "type": "Array", "value": [
+                    { "name": "Roofs", "area": 2.0, "c": 0.9 },
+                    { "name": "Lawns", "area": 6.0, "c": 0.2 },
+                ] } },
+            })))
+            .await
+            .unwrap();
+        assert!((value(&composite, "Composite Runoff Coefficient") - 0.375).abs() < 1e-9);
+        assert!((value(&composite, "Peak Runoff") - 0.375 * 1.1 * 100.0 * 8.0 / 360.0).abs() < 1e-9);
+        assert_eq!(value(&composite, "Time of Concentration"), 5.0);
+        assert!(warning_codes(&composite).contains(&"rational_method_runoff.short_time_of_concentration".to_string()));
+    }
+}
@@ -0,0 +1,426 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{SEWER_CAPACITY_EXCEEDED, SEWER_HIGH_VELOCITY, SEWER_LOW_VELOCITY},
+};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::helpers::{circular_section, full_flow, idf_intensity, normal_depth};
+use super::idf::{DEFAULT_A, DEFAULT_B, DEFAULT_N};
+use super::manning::CONCRETE_PIPE;
+use super::STANDARD_DIAMETERS;
+
+/// Self-cleansing velocity at the design flow (m/s)
+const MIN_VELOCITY: f64 = 0.9;
+
+/// Velocity above which abrasion and surcharge at junctions become a concern (m/s)
+const MAX_VELOCITY: f64 = 4.5;
+
+/// One pipe of the run, listed from the upstream end
+#[derive(Debug, Clone, Deserialize)]
+struct Reach {
+    #[serde(default)]
+    name: Option<String>,
+    /// Area draining into the upstream manhole of this reach (ha)
+    #[serde(default)]
+    area: f64,
+    /// Runoff coefficient of that area
+    #[serde(default)]
+    c: f64,
+    /// Pipe length (m)
+    length: f64,
+    /// Invert slope (m/m)
+    slope: f64,
+}
+
+/// Sized pipe for one reach
+struct PipeDesign {
+    name: String,
+    flow: f64,
+    tc: f64,
+    diameter: f64,
+    capacity: f64,
+    velocity: f64,
+    depth_ratio: f64,
+}
+
+pub struct StormSewerSizingCalculator;
+
+impl ParameterValidator for StormSewerSizingCalculator {
+    fn calculator_id(&self) -> &str {
+        "storm_sewer_sizing"
+    }
+}
+
+impl StormSewerSizingCalculator {
+    /// Reaches from `extended_parameters.reaches`, validated
+    fn reaches(&self, params: &EngineeringParameters) -> EngineeringResult<Option<Vec<Reach>>> {
+        let Some(value) = params.extended_parameters.as_ref().and_then(|e| e.get("reaches")) else {
+            return Ok(None);
+        };
+        let invalid = |reason: String| EngineeringError::InvalidParameter {
+            parameter: "reaches".to_string(),
+            value: format!("{:?}", value),
+            reason,
+        };
+        let items = value.as_array().ok_or_else(|| invalid("Expected an array of reaches".to_string()))?;
+        let reaches: Vec<Reach> =
+            serde_json::from_value(serde_json::Value::Array(items.clone())).map_err(|e| invalid(e.to_string()))?;
+        if reaches.is_empty() || reaches.len() > 50 {
+            return Err(invalid("Between 1 and 50 reaches are required".to_string()));
+        }
+        for (i, r) in reaches.iter().enumerate() {
+            let ok = (0.0..=500.0).contains(&r.area)
+                && (0.0..=1.0).contains(&r.c)
+                && (1.0..=1000.0).contains(&r.length)
+                && (0.0001..=0.2).contains(&r.slope);
+            if !ok {
+                return Err(invalid(format!(
+                    "Reach {} needs an area of 0-500 ha, c of 0-1, length of 1-1000 m and slope of 0.0001-0.2",
+                    r.name.clone().unwrap_or_else(|| (i + 1).to_string())
+                )));
+            }
+        }
+        if reaches[0].area * reaches[0].c <= 0.0 {
+            return Err(invalid("The first reach must have a contributing area".to_string()));
+        }
+        Ok(Some(reaches))
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for StormSewerSizingCalculator {
+    fn id(&self) -> &str {
+        "storm_sewer_sizing"
+    }
+
+    fn name(&self) -> &str {
+        "Storm Sewer Sizing"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Hydraulic
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("storm_sewer_sizing", "Storm Sewer Sizing")
+            .category("hydraulic")
+            .description("Size gravity storm sewer pipes with Manning's equation, either for one design flow or down a run of reaches with rational method flows and accumulated travel time")
+            .design_code("ASCE MOP 77")
+            .design_code("EN 752")
+            .parameter(ParameterMetadata {
+                name: "Design Flow".to_string(),
+                path: "additional.design_flow".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m³/s".to_string(),
+                description: "Flow for a single pipe, used when no reaches are given".to_string(),
+                required: false,
+                default_value: Some(0.5),
+                min_value: Some(0.001),
+                max_value: Some(30.0),
+                typical_range: Some((0.1, 3.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Pipe Slope".to_string(),
+                path: "additional.slope".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m/m".to_string(),
+                description: "Invert slope of the single pipe".to_string(),
+                required: false,
+                default_value: Some(0.005),
+                min_value: Some(0.0001),
+                max_value: Some(0.2),
+                typical_range: Some((0.002, 0.02)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Reaches".to_string(),
+                path: "extended_parameters.reaches".to_string(),
+                data_type: ParameterType::Array,
+                unit: "".to_string(),
+                description: "Pipes from upstream to outfall, each with length (m), slope (m/m) and the area (ha) and runoff coefficient c entering at its upstream manhole".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec!["1 to 50 reaches".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Inlet Time".to_string(),
+                path: "additional.inlet_time".to_string(),
+                data_type: ParameterType::Number,
+                unit: "min".to_string(),
+                description: "Overland flow time to the first inlet of a run".to_string(),
+                required: false,
+                default_value: Some(10.0),
+                min_value: Some(5.0),
+                max_value: Some(60.0),
+                typical_range: Some((5.0, 20.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "IDF Coefficient a".to_string(),
+                path: "additional.idf_a".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm/h·min^n".to_string(),
+                description: "a in i = a / (t + b)^n from the local IDF curve; 1500 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(10.0),
+                max_value: Some(20000.0),
+                typical_range: Some((500.0, 5000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "IDF Coefficient b".to_string(),
+                path: "additional.idf_b".to_string(),
+                data_type: ParameterType::Number,
+                unit: "min".to_string(),
+                description: "b in i = a / (t + b)^n; 10 min when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(60.0),
+                typical_range: Some((5.0, 20.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "IDF Exponent n".to_string(),
+                path: "additional.idf_n".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "n in i = a / (t + b)^n; 0.8 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.3),
+                max_value: Some(1.2),
+                typical_range: Some((0.6, 0.9)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Manning n".to_string(),
+                path: "additional.manning_n".to_string(),
+                data_type: ParameterType::Number,
+                unit: "".to_string(),
+                description: "Pipe roughness".to_string(),
+                required: false,
+                default_value: Some(CONCRETE_PIPE),
+                min_value: Some(0.009),
+                max_value: Some(0.035),
+                typical_range: Some((0.011, 0.015)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Intermediate)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.reaches(params)?;
+        self.optional_param(params, "design_flow", 0.5, 0.001, 30.0)?;
+        self.optional_param(params, "slope", 0.005, 0.0001, 0.2)?;
+        self.optional_param(params, "inlet_time", 10.0, 5.0, 60.0)?;
+        self.optional_param(params, "idf_a", DEFAULT_A, 10.0, 20000.0)?;
+        self.optional_param(params, "idf_b", DEFAULT_B, 0.0, 60.0)?;
+        self.optional_param(params, "idf_n", DEFAULT_N, 0.3, 1.2)?;
+        self.optional_param(params, "manning_n", CONCRETE_PIPE, 0.009, 0.035)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let reaches = self.reaches(&params)?;
+        let n = self.optional_param(&params, "manning_n", CONCRETE_PIPE, 0.009, 0.035)?;
+        let inlet_time = self.optional_param(&params, "inlet_time", 10.0, 5.0, 60.0)?;
+        let a = self.optional_param(&params, "idf_a", DEFAULT_A, 10.0, 20000.0)?;
+        let b = self.optional_param(&params, "idf_b", DEFAULT_B, 0.0, 60.0)?;
+        let exponent = self.optional_param(&params, "idf_n", DEFAULT_N, 0.3, 1.2)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut compliance_notes = vec![format!("Manning's equation for circular pipes, n = {:.3}", n)];
+
+        // Smallest standard pipe, no smaller than the one upstream, whose full-flow capacity carries the flow
+        let size = |flow: f64, slope: f64, minimum: f64| {
+            STANDARD_DIAMETERS
+                .iter()
+                .copied()
+                .filter(|&d| d >= minimum)
+                .find(|&d| full_flow(n, d, slope).0 >= flow)
+        };
+        let mut design = |name: String, flow: f64, tc: f64, slope: f64, minimum: f64| {
+            let fits = size(flow, slope, minimum);
+            let diameter = fits.unwrap_or(STANDARD_DIAMETERS[STANDARD_DIAMETERS.len() - 1]);
+            let (capacity, full_velocity) = full_flow(n, diameter, slope);
+            let depth = normal_depth(flow, n, diameter, slope);
+            let velocity = depth.map(|y| flow / circular_section(diameter, y).0).unwrap_or(full_velocity);
+            if fits.is_none() {
+                warnings.push(SEWER_CAPACITY_EXCEEDED.warn(format!(
+                    "{}: {:.2} m³/s exceeds the {:.2} m³/s capacity of a {:.0} mm pipe",
+                    name,
+                    flow,
+                    capacity,
+                    diameter * 1000.0
+                )));
+            }
+            if velocity < MIN_VELOCITY {
+                warnings.push(SEWER_LOW_VELOCITY.warn(format!(
+                    "{}: {:.2} m/s at the design flow is below the {:.1} m/s self-cleansing velocity",
+                    name, velocity, MIN_VELOCITY
+                )));
+            }
+            if full_velocity > MAX_VELOCITY {
+                warnings.push(SEWER_HIGH_VELOCITY.warn(format!(
+                    "{}: {:.1} m/s full-flow velocity exceeds {:.1} m/s",
+                    name, full_velocity, MAX_VELOCITY
+                )));
+            }
+            PipeDesign {
+                name,
+                flow,
+                tc,
+                diameter,
+                capacity,
+                velocity,
+                depth_ratio: depth.map(|y| y / diameter).unwrap_or(1.0),
+            }
+        };
+
+        let pipes = match reaches {
+            None => {
+                let flow = self.optional_param(&params, "design_flow", 0.5, 0.001, 30.0)?;
+                let slope = self.optional_param(&params, "slope", 0.005, 0.0001, 0.2)?;
+                vec![design("Pipe".to_string(), flow, 0.0, slope, 0.0)]
+            }
+            Some(reaches) => {
+                // Rational method down the run: CA accumulates and tc grows with the travel time in each pipe
+                compliance_notes.push(format!(
+                    "Rational method flows with {:.0} min inlet time and i = {:.0} / (t + {:.0})^{:.2}",
+                    inlet_time, a, b, exponent
+                ));
+                let mut pipes: Vec<PipeDesign> = Vec::new();
+                let mut sum_ca = 0.0;
+                let mut tc = inlet_time;
+                for (i, reach) in reaches.iter().enumerate() {
+                    sum_ca += reach.c * reach.area;
+                    let intensity = idf_intensity(a, b, exponent, tc);
+                    let flow = sum_ca * intensity / 360.0;
+                    let minimum = pipes.last().map(|p| p.diameter).unwrap_or(0.0);
+                    let name = reach.name.clone().unwrap_or_else(|| format!("Reach {}", i + 1));
+                    let pipe = design(name, flow, tc, reach.slope, minimum);
+                    tc += reach.length / pipe.velocity / 60.0;
+                    pipes.push(pipe);
+                }
+                trace.step(
+                    "formulas.storm_sewer_sizing.outfall_tc",
+                    &[("inlet_time", inlet_time), ("reaches", reaches.len() as f64)],
+                    tc,
+                    "min",
+                );
+                pipes
+            }
+        };
+
+        let mut results = Vec::new();
+        for pipe in &pipes {
+            trace.step(
+                "formulas.storm_sewer_sizing.pipe_capacity",
+                &[("flow", pipe.flow), ("diameter", pipe.diameter), ("manning_n", n)],
+                pipe.capacity,
+                "m³/s",
+            );
+            if pipes.len() > 1 {
+                results.push(
+                    EngineeringResultItem::new(format!("{} Diameter", pipe.name), pipe.diameter, "m")
+                        .with_format(format!("{:.0} mm ({:.3} m³/s, tc {:.1} min)", pipe.diameter * 1000.0, pipe.flow, pipe.tc)),
+                );
+                results.push(
+                    EngineeringResultItem::new(format!("{} Velocity", pipe.name), pipe.velocity, "m/s")
+                        .with_format(format!("{:.2} m/s", pipe.velocity)),
+                );
+            }
+        }
+
+        let outfall = &pipes[pipes.len() - 1];
+        let min_velocity = pipes.iter().map(|p| p.velocity).fold(f64::INFINITY, f64::min);
+        if min_velocity < MIN_VELOCITY {
+            recommendations.push("Steepen flat reaches or reduce the pipe size where capacity allows".to_string());
+        }
+        results.extend([
+            EngineeringResultItem::new("Design Flow", outfall.flow, "m³/s").with_format(format!("{:.3} m³/s", outfall.flow)),
+            EngineeringResultItem::new("Pipe Diameter", outfall.diameter, "m")
+                .critical()
+                .with_format(format!("{:.0} mm", outfall.diameter * 1000.0)),
+            EngineeringResultItem::new("Full-Flow Capacity", outfall.capacity, "m³/s")
+                .with_format(format!("{:.3} m³/s", outfall.capacity)),
+            EngineeringResultItem::new("Capacity Utilization", outfall.flow / outfall.capacity, "")
+                .critical()
+                .with_format(format!("{:.2}", outfall.flow / outfall.capacity)),
+            EngineeringResultItem::new("Depth Ratio y/D", outfall.depth_ratio, "").with_format(format!("{:.2}", outfall.depth_ratio)),
+            EngineeringResultItem::new("Velocity at Design Flow", outfall.velocity, "m/s")
+                .with_format(format!("{:.2} m/s", outfall.velocity)),
+            EngineeringResultItem::new("Minimum Velocity", min_velocity, "m/s")
+                .critical()
+                .with_format(format!("{:.2} m/s", min_velocity)),
+        ]);
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "storm_sewer_sizing".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "ASCE MOP 77".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_storm_sewer_sizing() {
+        let params = |json: serde_json::Value| -> EngineeringParameters { serde_json::from_value(json).unwrap() };
+
+        // 0.5 m³/s at 0.5%: a 600 mm pipe carries 0.434 m³/s full, so 675 mm is needed
+        let single = execute("storm_sewer_sizing", params(serde_json::json!({ "dimensions": {}, "additional": {} })))
+            .await
+            .unwrap();
+        assert_eq!(value(&single, "Pipe Diameter"), 0.675);
+        assert!(value(&single, "Capacity Utilization") <= 1.0);
+        assert!(value(&single, "Depth Ratio y/D") < 0.938);
+
+        // Flows and pipe sizes never decrease down the run
+        let run = execute("storm_sewer_sizing", params(serde_json::json!({
+                "dimensions": {},
+                "additional": {},
+                "extended_parameters": { "reaches": { "type": "Array", "value": [
+                    { "name": "MH1-MH2", "area": 2.0, "c": 0.7, "length": 80.0, "slope": 0.01 },
+                    { "name": "MH2-MH3", "area": 1.5, "c": 0.6, "length": 100.0, "slope": 0.004 },
+                    { "name": "MH3-Outfall", "length": 60.0, "slope": 0.02 },
+                ] } },
+            })))
+            .await
+            .unwrap();
+        let first = value(&run, "MH1-MH2 Diameter");
+        let second = value(&run, "MH2-MH3 Diameter");
+        let outfall = value(&run, "MH3-Outfall Diameter");
+        assert!(first <= second && second <= outfall);
+        assert_eq!(outfall, value(&run, "Pipe Diameter"));
+        assert!(value(&run, "Design Flow") < (1.4 + 0.9) * 1500.0 / 20f64.powf(0.8) / 360.0);
+    }
+}
@@ -2,7 +2,9 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::COMPRESSOR_HIGH_PRESSURE_RATIO,
 };
+use crate::utils::conversions::essential::{flow::m3min_to_m3s, pressure::kpa_to_pa};
 use async_trait::async_trait;
 
 use super::constants::*;
@@ -143,18 +145,50 @@ impl EngineerCalculator for CompressorSizingCalculator {
         let k = params.additional.as_ref().and_then(|a| a.get("k").copied()).unwrap_or(1.4);
         let eff = params.additional.as_ref().and_then(|a| a.get("efficiency").copied()).unwrap_or(75.0) / 100.0;
 
+        let mut trace = CalculationTrace::new();
+
         let pressure_ratio = p_out / p_in;
+        trace.step(
+            "formulas.compressor_sizing.pressure_ratio",
+            &[("p_out", p_out), ("p_in", p_in)],
+            pressure_ratio,
+            "dimensionless",
+        );
         let isentropic_work = (k / (k - 1.0)) * r * 293.0 * (pressure_ratio.powf((k - 1.0)/k) - 1.0) / 1000.0; // kJ/kg
+        trace.step(
+            "formulas.compressor_sizing.isentropic_work",
+            &[("k", k), ("gas_constant", r), ("pressure_ratio", pressure_ratio)],
+            isentropic_work,
+            "kJ/kg",
+        );
         let actual_work = isentropic_work / eff;
-        let mass_flow = (flow_rate_m3min / 60.0) * (p_in * 1000.0 / (r * 293.0)); // kg/s (assume T=20°C)
+        trace.step(
+            "formulas.compressor_sizing.actual_work",
+            &[("isentropic_work", isentropic_work), ("efficiency", eff)],
+            actual_work,
+            "kJ/kg",
+        );
+        let mass_flow = m3min_to_m3s(flow_rate_m3min).await * (kpa_to_pa(p_in).await / (r * 293.0)); // kg/s (assume T=20°C)
+        trace.step(
+            "formulas.compressor_sizing.mass_flow",
+            &[("flow_rate", flow_rate_m3min), ("p_in", p_in), ("gas_constant", r)],
+            mass_flow,
+            "kg/s",
+        );
         let power = actual_work * mass_flow;
+        trace.step(
+            "formulas.compressor_sizing.shaft_power",
+            &[("actual_work", actual_work), ("mass_flow", mass_flow)],
+            power,
+            "kW",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if pressure_ratio > 10.0 {
-            warnings.push(format!("High pressure ratio ({:.1}). Consider multi-stage compression.", pressure_ratio));
+            warnings.push(COMPRESSOR_HIGH_PRESSURE_RATIO.warn(format!("High pressure ratio ({:.1}). Consider multi-stage compression.", pressure_ratio)));
             recommendations.push("Add intercooling for ratios >5".to_string());
         }
 
@@ -177,8 +211,8 @@ impl EngineerCalculator for CompressorSizingCalculator {
             calculation_type: "compressor_sizing".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -187,6 +221,8 @@ impl EngineerCalculator for CompressorSizingCalculator {
                 design_code_used: "ASME PTC 10".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
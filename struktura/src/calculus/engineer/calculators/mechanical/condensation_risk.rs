@@ -0,0 +1,559 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{CONDENSATION_ACCUMULATION, CONDENSATION_INTERSTITIAL, CONDENSATION_SURFACE},
+};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+/// Surface resistances for heat flow through a wall (m²·K/W, ISO 6946)
+const RSI: f64 = 0.13;
+const RSE: f64 = 0.04;
+
+/// Inside surface resistance for the surface condensation check (m²·K/W, ISO 13788)
+const RSI_SURFACE: f64 = 0.25;
+
+/// Water vapour permeability of still air (kg/(m·s·Pa), ISO 13788)
+const DELTA_AIR: f64 = 2.0e-10;
+
+/// Surface relative humidity above which mould growth is assumed (ISO 13788)
+const MOULD_RH: f64 = 0.8;
+
+/// One layer of the assembly, listed from the inside face outwards
+#[derive(Debug, Clone, Deserialize)]
+struct Layer {
+    #[serde(default)]
+    name: Option<String>,
+    /// Thickness (mm)
+    thickness: f64,
+    /// Thermal conductivity (W/(m·K))
+    conductivity: f64,
+    /// Water vapour resistance factor μ
+    #[serde(default = "vapour_open")]
+    mu: f64,
+    /// Equivalent air layer thickness (m), overriding μ·d for membranes
+    #[serde(default)]
+    sd: Option<f64>,
+}
+
+fn vapour_open() -> f64 {
+    1.0
+}
+
+impl Layer {
+    fn new(name: &str, thickness: f64, conductivity: f64, mu: f64) -> Self {
+        Self { name: Some(name.to_string()), thickness, conductivity, mu, sd: None }
+    }
+
+    fn resistance(&self) -> f64 {
+        self.thickness / 1000.0 / self.conductivity
+    }
+
+    fn sd(&self) -> f64 {
+        self.sd.unwrap_or(self.mu * self.thickness / 1000.0)
+    }
+}
+
+/// Saturation vapour pressure over water or ice (Pa, ISO 13788 Annex E)
+fn saturation_pressure(theta: f64) -> f64 {
+    if theta >= 0.0 {
+        610.5 * (17.269 * theta / (237.3 + theta)).exp()
+    } else {
+        610.5 * (21.875 * theta / (265.5 + theta)).exp()
+    }
+}
+
+/// Temperature at which `pressure` is the saturation pressure (°C)
+fn dewpoint(pressure: f64) -> f64 {
+    let l = (pressure / 610.5).ln();
+    if l >= 0.0 {
+        237.3 * l / (17.269 - l)
+    } else {
+        265.5 * l / (21.875 - l)
+    }
+}
+
+/// Lower convex hull of (sd, pressure) points, returned as indices into `points`
+///
+/// The Glaser vapour pressure line is the tightest line from inside to outside
+/// that never exceeds saturation; it touches the saturation curve at the
+/// condensation planes.
+fn pressure_line(points: &[(f64, f64)]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| points[a].0.total_cmp(&points[b].0).then(points[a].1.total_cmp(&points[b].1)));
+    let cross = |o: usize, a: usize, b: usize| {
+        let (o, a, b) = (points[o], points[a], points[b]);
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    };
+    let mut hull: Vec<usize> = Vec::new();
+    for i in order {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], i) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    hull
+}
+
+pub struct CondensationRiskCalculator;
+
+impl ParameterValidator for CondensationRiskCalculator {
+    fn calculator_id(&self) -> &str {
+        "condensation_risk"
+    }
+}
+
+impl CondensationRiskCalculator {
+    /// Layers from `extended_parameters.layers`, or an insulated stud wall built from `additional`
+    fn layers(&self, params: &EngineeringParameters) -> EngineeringResult<Vec<Layer>> {
+        let Some(value) = params.extended_parameters.as_ref().and_then(|e| e.get("layers")) else {
+            let mut layers = vec![Layer::new("Plasterboard", 12.5, 0.25, 10.0)];
+            if let Some(sd) = params.additional.as_ref().and_then(|a| a.get("vapour_control_sd")).copied() {
+                layers.push(Layer { sd: Some(sd), ..Layer::new("Vapour control layer", 0.2, 0.33, 1.0) });
+            }
+            layers.push(Layer::new(
+                "Insulation",
+                self.optional_param(params, "insulation_thickness", 140.0, 20.0, 400.0)?,
+                self.optional_param(params, "insulation_conductivity", 0.035, 0.015, 0.1)?,
+                1.0,
+            ));
+            layers.push(Layer::new("OSB sheathing", 12.0, 0.13, 50.0));
+            return Ok(layers);
+        };
+        let invalid = |reason: String| EngineeringError::InvalidParameter {
+            parameter: "layers".to_string(),
+            value: format!("{:?}", value),
+            reason,
+        };
+        let items = value.as_array().ok_or_else(|| invalid("Expected an array of layers".to_string()))?;
+        let layers: Vec<Layer> =
+            serde_json::from_value(serde_json::Value::Array(items.clone())).map_err(|e| invalid(e.to_string()))?;
+        if layers.is_empty() || layers.len() > 20 {
+            return Err(invalid("Between 1 and 20 layers are required".to_string()));
+        }
+        for (i, layer) in layers.iter().enumerate() {
+            let ok = (0.1..=1000.0).contains(&layer.thickness)
+                && (0.01..=5.0).contains(&layer.conductivity)
+                && (1.0..=1.0e6).contains(&layer.mu)
+                && layer.sd.is_none_or(|sd| (0.001..=2000.0).contains(&sd));
+            if !ok {
+                return Err(invalid(format!(
+                    "Layer {} needs a thickness of 0.1-1000 mm, conductivity of 0.01-5 W/(m·K), μ ≥ 1 and sd of 0.001-2000 m",
+                    layer.name.clone().unwrap_or_else(|| (i + 1).to_string())
+                )));
+            }
+        }
+        Ok(layers)
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for CondensationRiskCalculator {
+    fn id(&self) -> &str {
+        "condensation_risk"
+    }
+
+    fn name(&self) -> &str {
+        "Condensation Risk (Glaser Method)"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Mechanical
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("condensation_risk", "Condensation Risk (Glaser Method)")
+            .category("mechanical")
+            .description("Dewpoint profile of a wall assembly with interstitial and surface condensation checks under winter design conditions")
+            .design_code("ISO 13788")
+            .parameter(ParameterMetadata {
+                name: "Layers".to_string(),
+                path: "extended_parameters.layers".to_string(),
+                data_type: ParameterType::Array,
+                unit: "".to_string(),
+                description: "Objects with thickness (mm), conductivity (W/(m·K)), optional name, mu and sd (m), listed from the inside face outwards".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec!["1 to 20 layers".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Insulation Thickness".to_string(),
+                path: "additional.insulation_thickness".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm".to_string(),
+                description: "Cavity insulation of the default stud wall (plasterboard, insulation, OSB) used when no layers are given; 140 mm when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(20.0),
+                max_value: Some(400.0),
+                typical_range: Some((90.0, 200.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Insulation Conductivity".to_string(),
+                path: "additional.insulation_conductivity".to_string(),
+                data_type: ParameterType::Number,
+                unit: "W/(m·K)".to_string(),
+                description: "Conductivity of the default wall's insulation; 0.035 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.015),
+                max_value: Some(0.1),
+                typical_range: Some((0.022, 0.045)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Vapour Control Layer sd".to_string(),
+                path: "additional.vapour_control_sd".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Equivalent air layer thickness of a vapour control layer behind the plasterboard of the default wall; none when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(2000.0),
+                typical_range: Some((2.0, 100.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Indoor Temperature".to_string(),
+                path: "additional.indoor_temp".to_string(),
+                data_type: ParameterType::Number,
+                unit: "°C".to_string(),
+                description: "Indoor design temperature".to_string(),
+                required: true,
+                default_value: Some(20.0),
+                min_value: Some(10.0),
+                max_value: Some(30.0),
+                typical_range: Some((18.0, 22.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Indoor Relative Humidity".to_string(),
+                path: "additional.indoor_rh".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Indoor design relative humidity".to_string(),
+                required: true,
+                default_value: Some(50.0),
+                min_value: Some(10.0),
+                max_value: Some(95.0),
+                typical_range: Some((40.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Outdoor Temperature".to_string(),
+                path: "additional.outdoor_temp".to_string(),
+                data_type: ParameterType::Number,
+                unit: "°C".to_string(),
+                description: "Winter design outdoor temperature".to_string(),
+                required: true,
+                default_value: Some(-5.0),
+                min_value: Some(-40.0),
+                max_value: Some(15.0),
+                typical_range: Some((-15.0, 5.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Outdoor Relative Humidity".to_string(),
+                path: "additional.outdoor_rh".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Winter design outdoor relative humidity".to_string(),
+                required: true,
+                default_value: Some(80.0),
+                min_value: Some(10.0),
+                max_value: Some(100.0),
+                typical_range: Some((70.0, 90.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Condensation Period".to_string(),
+                path: "additional.cold_days".to_string(),
+                data_type: ParameterType::Number,
+                unit: "days".to_string(),
+                description: "Duration of the design winter conditions for the seasonal accumulation".to_string(),
+                required: false,
+                default_value: Some(60.0),
+                min_value: Some(1.0),
+                max_value: Some(180.0),
+                typical_range: Some((30.0, 90.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Accumulation Limit".to_string(),
+                path: "additional.accumulation_limit".to_string(),
+                data_type: ParameterType::Number,
+                unit: "g/m²".to_string(),
+                description: "Permissible condensate accumulated over the condensation period".to_string(),
+                required: false,
+                default_value: Some(500.0),
+                min_value: Some(0.0),
+                max_value: Some(5000.0),
+                typical_range: Some((200.0, 1000.0)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Intermediate)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.layers(params)?;
+        self.optional_param(params, "vapour_control_sd", 0.0, 0.0, 2000.0)?;
+        let indoor = self.get_additional_param(params, "indoor_temp", Some(10.0), Some(30.0))?;
+        self.get_additional_param(params, "indoor_rh", Some(10.0), Some(95.0))?;
+        let outdoor = self.get_additional_param(params, "outdoor_temp", Some(-40.0), Some(15.0))?;
+        self.get_additional_param(params, "outdoor_rh", Some(10.0), Some(100.0))?;
+        self.optional_param(params, "cold_days", 60.0, 1.0, 180.0)?;
+        self.optional_param(params, "accumulation_limit", 500.0, 0.0, 5000.0)?;
+
+        if outdoor >= indoor {
+            return Err(EngineeringError::DomainError {
+                field: "outdoor_temp".to_string(),
+                message: "The Glaser method assesses winter conditions; outdoor must be colder than indoor".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let layers = self.layers(&params)?;
+        let theta_i = self.get_additional_param(&params, "indoor_temp", None, None)?;
+        let rh_i = self.get_additional_param(&params, "indoor_rh", None, None)?;
+        let theta_e = self.get_additional_param(&params, "outdoor_temp", None, None)?;
+        let rh_e = self.get_additional_param(&params, "outdoor_rh", None, None)?;
+        let days = self.optional_param(&params, "cold_days", 60.0, 1.0, 180.0)?;
+        let limit = self.optional_param(&params, "accumulation_limit", 500.0, 0.0, 5000.0)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut results = Vec::new();
+
+        let names: Vec<String> = layers
+            .iter()
+            .enumerate()
+            .map(|(i, l)| l.name.clone().unwrap_or_else(|| format!("Layer {}", i + 1)))
+            .collect();
+        let r_layers: f64 = layers.iter().map(Layer::resistance).sum();
+        let r_total = trace.step(
+            "formulas.condensation_risk.total_resistance",
+            &[("rsi", RSI), ("layers", r_layers), ("rse", RSE)],
+            RSI + r_layers + RSE,
+            "m²·K/W",
+        );
+        let u_value = 1.0 / r_total;
+        let sd_total = trace.step(
+            "formulas.condensation_risk.total_sd",
+            &[("layers", layers.len() as f64)],
+            layers.iter().map(Layer::sd).sum(),
+            "m",
+        );
+        let p_i = trace.step(
+            "formulas.condensation_risk.indoor_vapour_pressure",
+            &[("indoor_temp", theta_i), ("indoor_rh", rh_i)],
+            rh_i / 100.0 * saturation_pressure(theta_i),
+            "Pa",
+        );
+        let p_e = trace.step(
+            "formulas.condensation_risk.outdoor_vapour_pressure",
+            &[("outdoor_temp", theta_e), ("outdoor_rh", rh_e)],
+            rh_e / 100.0 * saturation_pressure(theta_e),
+            "Pa",
+        );
+
+        // Interfaces between layers: temperature from the thermal resistance profile,
+        // position from the cumulative equivalent air layer thickness
+        let mut r_cumulative = RSI;
+        let mut sd_cumulative = 0.0;
+        let mut interfaces = Vec::new();
+        for (i, layer) in layers.iter().enumerate().take(layers.len() - 1) {
+            r_cumulative += layer.resistance();
+            sd_cumulative += layer.sd();
+            let theta = theta_i - (theta_i - theta_e) * r_cumulative / r_total;
+            interfaces.push((format!("{}/{}", names[i], names[i + 1]), theta, sd_cumulative));
+        }
+
+        let mut margin = f64::INFINITY;
+        for (name, theta, sd) in &interfaces {
+            // Margin against the straight-line vapour profile, before any condensation redistributes it
+            let p = p_i - (p_i - p_e) * sd / sd_total;
+            margin = margin.min(theta - dewpoint(p));
+            results.push(
+                EngineeringResultItem::new(format!("{} Temperature", name), *theta, "°C")
+                    .with_format(format!("{:.1}°C", theta)),
+            );
+        }
+
+        let mut points = vec![(0.0, p_i)];
+        points.extend(interfaces.iter().map(|(_, theta, sd)| (*sd, saturation_pressure(*theta))));
+        points.push((sd_total, p_e));
+        let hull = pressure_line(&points);
+
+        let mut total_rate = 0.0;
+        for w in hull.windows(3) {
+            let ((x0, y0), (x1, y1), (x2, y2)) = (points[w[0]], points[w[1]], points[w[2]]);
+            // gc = δ0·(Δp_in/sd_in − Δp_out/sd_out), converted to g/(m²·day)
+            let rate = trace.step(
+                "formulas.condensation_risk.condensation_rate",
+                &[("p_before", y0), ("p_plane", y1), ("p_after", y2), ("sd_in", x1 - x0), ("sd_out", x2 - x1)],
+                DELTA_AIR * ((y0 - y1) / (x1 - x0) - (y1 - y2) / (x2 - x1)) * 86_400.0 * 1000.0,
+                "g/(m²·day)",
+            );
+            let name = &interfaces[w[1] - 1].0;
+            total_rate += rate;
+            warnings.push(CONDENSATION_INTERSTITIAL.warn(format!(
+                "Condensation at the {} interface: {:.1} g/(m²·day) at {:.1}°C",
+                name,
+                rate,
+                interfaces[w[1] - 1].1
+            )));
+            results.push(
+                EngineeringResultItem::new(format!("Condensation at {}", name), rate, "g/(m²·day)")
+                    .with_format(format!("{:.1} g/(m²·day)", rate)),
+            );
+        }
+        let accumulation = trace.step(
+            "formulas.condensation_risk.seasonal_accumulation",
+            &[("rate", total_rate), ("days", days)],
+            total_rate * days,
+            "g/m²",
+        );
+        if accumulation > limit {
+            warnings.push(CONDENSATION_ACCUMULATION.warn(format!(
+                "{:.0} g/m² accumulates over {:.0} days against a {:.0} g/m² limit",
+                accumulation, days, limit
+            )));
+        }
+        if total_rate > 0.0 {
+            recommendations.push(
+                "Add or tighten the vapour control layer on the warm side, or use vapour-open sheathing on the cold side".to_string(),
+            );
+        }
+
+        // Surface check with the higher inside surface resistance of ISO 13788
+        let f_rsi = trace.step(
+            "formulas.condensation_risk.temperature_factor",
+            &[("rsi", RSI_SURFACE), ("total_resistance", r_total - RSI + RSI_SURFACE)],
+            1.0 - RSI_SURFACE / (r_total - RSI + RSI_SURFACE),
+            "",
+        );
+        let theta_si = theta_e + f_rsi * (theta_i - theta_e);
+        let indoor_dewpoint = trace.step(
+            "formulas.condensation_risk.indoor_dewpoint",
+            &[("vapour_pressure", p_i)],
+            dewpoint(p_i),
+            "°C",
+        );
+        let f_rsi_min = (dewpoint(p_i / MOULD_RH) - theta_e) / (theta_i - theta_e);
+        if theta_si < indoor_dewpoint {
+            warnings.push(CONDENSATION_SURFACE.warn(format!(
+                "Inside surface at {:.1}°C is below the {:.1}°C indoor dewpoint",
+                theta_si, indoor_dewpoint
+            )));
+        }
+        if f_rsi < f_rsi_min {
+            recommendations.push(format!(
+                "Temperature factor {:.2} is below the {:.2} needed to keep the surface under 80% RH; improve insulation or reduce indoor humidity",
+                f_rsi, f_rsi_min
+            ));
+        }
+
+        let compliance_notes = vec![
+            "Glaser method per ISO 13788 under steady winter design conditions".to_string(),
+            format!(
+                "Indoor {:.1}°C at {:.0}% RH, outdoor {:.1}°C at {:.0}% RH over {:.0} days",
+                theta_i, rh_i, theta_e, rh_e, days
+            ),
+            "Ignores liquid transport, built-in moisture and drying in the following season".to_string(),
+        ];
+
+        results.extend([
+            EngineeringResultItem::new("U-Value", u_value, "W/(m²·K)").with_format(format!("{:.3} W/(m²·K)", u_value)),
+            EngineeringResultItem::new("Indoor Dewpoint", indoor_dewpoint, "°C").with_format(format!("{:.1}°C", indoor_dewpoint)),
+            EngineeringResultItem::new("Inside Surface Temperature", theta_si, "°C").with_format(format!("{:.1}°C", theta_si)),
+            EngineeringResultItem::new("Temperature Factor fRsi", f_rsi, "").with_format(format!("{:.2}", f_rsi)),
+            EngineeringResultItem::new("Condensation Rate", total_rate, "g/(m²·day)")
+                .with_format(format!("{:.1} g/(m²·day)", total_rate)),
+            EngineeringResultItem::new("Seasonal Accumulation", accumulation, "g/m²")
+                .critical()
+                .with_format(format!("{:.0} g/m²", accumulation)),
+        ]);
+        if margin.is_finite() {
+            results.push(
+                EngineeringResultItem::new("Minimum Dewpoint Margin", margin, "°C")
+                    .critical()
+                    .with_format(format!("{:.1}°C", margin)),
+            );
+        }
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "condensation_risk".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "ISO 13788".to_string(),
+                requires_pe_review: false,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_condensation_risk_glaser() {
+        let params = |additional: serde_json::Value, layers: Option<serde_json::Value>| -> EngineeringParameters {
+            let mut json = serde_json::json!({ "dimensions": {}, "additional": additional });
+            if let Some(layers) = layers {
+                json["extended_parameters"] = serde_json::json!({ "layers": { "type": "Array", "value": layers } });
+            }
+            serde_json::from_value(json).unwrap()
+        };
+        let winter = serde_json::json!({ "indoor_temp": 20.0, "indoor_rh": 50.0, "outdoor_temp": -5.0, "outdoor_rh": 80.0 });
+
+        // Default stud wall without a vapour control layer condenses behind the OSB
+        let open = execute("condensation_risk", params(winter.clone(), None)).await.unwrap();
+        let r_total = 0.13 + 0.0125 / 0.25 + 0.14 / 0.035 + 0.012 / 0.13 + 0.04;
+        assert!((value(&open, "U-Value") - 1.0 / r_total).abs() < 1e-9);
+        assert!(value(&open, "Condensation at Insulation/OSB sheathing") > 0.0);
+        assert!(value(&open, "Minimum Dewpoint Margin") < 0.0);
+        assert!(warning_codes(&open).contains(&"condensation_risk.interstitial".to_string()));
+        assert!(!warning_codes(&open).contains(&"condensation_risk.surface".to_string()));
+
+        let mut controlled = winter.clone();
+        controlled["vapour_control_sd"] = serde_json::json!(20.0);
+        let closed = execute("condensation_risk", params(controlled, None)).await.unwrap();
+        assert_eq!(value(&closed, "Seasonal Accumulation"), 0.0);
+        assert!(value(&closed, "Minimum Dewpoint Margin") > 0.0);
+        assert!(warning_codes(&closed).is_empty());
+
+        // An uninsulated slab of concrete runs wet on the inside face
+        let mut humid = winter.clone();
+        humid["indoor_rh"] = serde_json::json!(70.0);
+        let slab = serde_json::json!([{ "name": "Concrete", "thickness": 100.0, "conductivity": 2.0, "mu": 80.0 }]);
+        let bare = execute("condensation_risk", params(humid, Some(slab))).await.unwrap();
+        assert!(value(&bare, "Inside Surface Temperature") < value(&bare, "Indoor Dewpoint"));
+        assert!(warning_codes(&bare).contains(&"condensation_risk.surface".to_string()));
+
+        let mut summer = winter;
+        summer["outdoor_temp"] = serde_json::json!(15.0);
+        summer["indoor_temp"] = serde_json::json!(12.0);
+        assert!(execute("condensation_risk", params(summer, None)).await.is_err());
+    }
+}
@@ -0,0 +1,475 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{ELEVATOR_LONG_INTERVAL, ELEVATOR_LOW_HANDLING_CAPACITY, ELEVATOR_SLOW_FOR_RISE},
+};
+use async_trait::async_trait;
+
+use super::elevator_values::{
+    highest_reversal_floor, probable_stops, single_floor_flight_time, AVERAGE_LOAD_FACTOR, MAX_GROUP_SIZE,
+    MAX_TRAVEL_TIME, PASSENGER_MASS,
+};
+
+pub struct ElevatorTrafficCalculator;
+
+impl ParameterValidator for ElevatorTrafficCalculator {
+    fn calculator_id(&self) -> &str {
+        "elevator_traffic"
+    }
+}
+
+/// Up-peak performance of a group of identical cars
+struct GroupPerformance {
+    cars: u32,
+    interval: f64,
+    handling_capacity: f64,
+    handling_percent: f64,
+}
+
+impl ElevatorTrafficCalculator {
+    /// Whole-number `additional` value
+    fn count_param(&self, params: &EngineeringParameters, name: &str, min: f64, max: f64) -> EngineeringResult<u32> {
+        let value = self.get_additional_param(params, name, Some(min), Some(max))?;
+        if value.fract() != 0.0 {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: name.to_string(),
+                value: value.to_string(),
+                reason: "Must be a whole number".to_string(),
+            });
+        }
+        Ok(value as u32)
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for ElevatorTrafficCalculator {
+    fn id(&self) -> &str {
+        "elevator_traffic"
+    }
+
+    fn name(&self) -> &str {
+        "Elevator Traffic Analysis"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Mechanical
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("elevator_traffic", "Elevator Traffic Analysis")
+            .category("mechanical")
+            .description("Up-peak round trip time, interval and five-minute handling capacity of an elevator group from population, floors and car speed and capacity")
+            .design_code("CIBSE Guide D")
+            .parameter(ParameterMetadata {
+                name: "Floors Served".to_string(),
+                path: "additional.floors".to_string(),
+                data_type: ParameterType::Integer,
+                unit: "floors".to_string(),
+                description: "Occupied floors served above the main lobby".to_string(),
+                required: true,
+                default_value: Some(10.0),
+                min_value: Some(1.0),
+                max_value: Some(100.0),
+                typical_range: Some((4.0, 25.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Population".to_string(),
+                path: "additional.population".to_string(),
+                data_type: ParameterType::Number,
+                unit: "persons".to_string(),
+                description: "Building population above the lobby, spread evenly over the floors".to_string(),
+                required: true,
+                default_value: Some(800.0),
+                min_value: Some(1.0),
+                max_value: Some(50000.0),
+                typical_range: Some((200.0, 3000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Floor Height".to_string(),
+                path: "dimensions.floor_height".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Floor-to-floor height".to_string(),
+                required: true,
+                default_value: Some(3.5),
+                min_value: Some(2.4),
+                max_value: Some(10.0),
+                typical_range: Some((3.0, 4.5)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Rated Load".to_string(),
+                path: "additional.rated_load".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kg".to_string(),
+                description: "Car rated load; capacity in persons at 75 kg each".to_string(),
+                required: false,
+                default_value: Some(1000.0),
+                min_value: Some(320.0),
+                max_value: Some(5000.0),
+                typical_range: Some((630.0, 1600.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Car Speed".to_string(),
+                path: "additional.car_speed".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m/s".to_string(),
+                description: "Rated car speed".to_string(),
+                required: false,
+                default_value: Some(1.6),
+                min_value: Some(0.5),
+                max_value: Some(10.0),
+                typical_range: Some((1.0, 4.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Acceleration".to_string(),
+                path: "additional.acceleration".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m/s²".to_string(),
+                description: "Car acceleration and deceleration".to_string(),
+                required: false,
+                default_value: Some(1.0),
+                min_value: Some(0.4),
+                max_value: Some(1.5),
+                typical_range: Some((0.8, 1.2)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Number of Cars".to_string(),
+                path: "additional.cars".to_string(),
+                data_type: ParameterType::Integer,
+                unit: "cars".to_string(),
+                description: "Cars in the group; the smallest group meeting the targets when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(MAX_GROUP_SIZE as f64),
+                typical_range: Some((2.0, 6.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Door Opening Time".to_string(),
+                path: "additional.door_open_time".to_string(),
+                data_type: ParameterType::Number,
+                unit: "s".to_string(),
+                description: "Time for the doors to open".to_string(),
+                required: false,
+                default_value: Some(2.0),
+                min_value: Some(0.5),
+                max_value: Some(10.0),
+                typical_range: Some((1.5, 3.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Door Closing Time".to_string(),
+                path: "additional.door_close_time".to_string(),
+                data_type: ParameterType::Number,
+                unit: "s".to_string(),
+                description: "Time for the doors to close".to_string(),
+                required: false,
+                default_value: Some(3.0),
+                min_value: Some(0.5),
+                max_value: Some(10.0),
+                typical_range: Some((2.0, 4.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Passenger Transfer Time".to_string(),
+                path: "additional.transfer_time".to_string(),
+                data_type: ParameterType::Number,
+                unit: "s".to_string(),
+                description: "Time for one passenger to enter or leave the car".to_string(),
+                required: false,
+                default_value: Some(1.2),
+                min_value: Some(0.5),
+                max_value: Some(3.0),
+                typical_range: Some((1.0, 1.5)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Start Delay".to_string(),
+                path: "additional.start_delay".to_string(),
+                data_type: ParameterType::Number,
+                unit: "s".to_string(),
+                description: "Delay between doors closed and the car moving".to_string(),
+                required: false,
+                default_value: Some(0.5),
+                min_value: Some(0.0),
+                max_value: Some(3.0),
+                typical_range: Some((0.3, 1.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Target Interval".to_string(),
+                path: "additional.target_interval".to_string(),
+                data_type: ParameterType::Number,
+                unit: "s".to_string(),
+                description: "Longest acceptable up-peak interval (30 s for good office service)".to_string(),
+                required: false,
+                default_value: Some(30.0),
+                min_value: Some(10.0),
+                max_value: Some(120.0),
+                typical_range: Some((20.0, 40.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Target Handling Capacity".to_string(),
+                path: "additional.target_handling".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Share of the population to carry in the busiest five minutes (12% for single-tenant offices)".to_string(),
+                required: false,
+                default_value: Some(12.0),
+                min_value: Some(5.0),
+                max_value: Some(25.0),
+                typical_range: Some((11.0, 17.0)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Intermediate)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.count_param(params, "floors", 1.0, 100.0)?;
+        self.get_additional_param(params, "population", Some(1.0), Some(50000.0))?;
+        self.validate_dimension("floor_height", params.dimensions.get("floor_height").copied(), 2.4, 10.0)?;
+        self.optional_param(params, "rated_load", 1000.0, 320.0, 5000.0)?;
+        self.optional_param(params, "car_speed", 1.6, 0.5, 10.0)?;
+        self.optional_param(params, "acceleration", 1.0, 0.4, 1.5)?;
+        match self.count_param(params, "cars", 1.0, MAX_GROUP_SIZE as f64) {
+            Err(EngineeringError::MissingParameter { .. }) | Ok(_) => {}
+            Err(e) => return Err(e),
+        }
+        self.optional_param(params, "door_open_time", 2.0, 0.5, 10.0)?;
+        self.optional_param(params, "door_close_time", 3.0, 0.5, 10.0)?;
+        self.optional_param(params, "transfer_time", 1.2, 0.5, 3.0)?;
+        self.optional_param(params, "start_delay", 0.5, 0.0, 3.0)?;
+        self.optional_param(params, "target_interval", 30.0, 10.0, 120.0)?;
+        self.optional_param(params, "target_handling", 12.0, 5.0, 25.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let floors = self.count_param(&params, "floors", 1.0, 100.0)?;
+        let population = self.get_additional_param(&params, "population", None, None)?;
+        let floor_height = params.dimensions.get("floor_height").copied().unwrap_or(3.5);
+        let rated_load = self.optional_param(&params, "rated_load", 1000.0, 320.0, 5000.0)?;
+        let speed = self.optional_param(&params, "car_speed", 1.6, 0.5, 10.0)?;
+        let acceleration = self.optional_param(&params, "acceleration", 1.0, 0.4, 1.5)?;
+        let cars = match self.count_param(&params, "cars", 1.0, MAX_GROUP_SIZE as f64) {
+            Err(EngineeringError::MissingParameter { .. }) => None,
+            other => Some(other?),
+        };
+        let door_open = self.optional_param(&params, "door_open_time", 2.0, 0.5, 10.0)?;
+        let door_close = self.optional_param(&params, "door_close_time", 3.0, 0.5, 10.0)?;
+        let transfer = self.optional_param(&params, "transfer_time", 1.2, 0.5, 3.0)?;
+        let start_delay = self.optional_param(&params, "start_delay", 0.5, 0.0, 3.0)?;
+        let target_interval = self.optional_param(&params, "target_interval", 30.0, 10.0, 120.0)?;
+        let target_handling = self.optional_param(&params, "target_handling", 12.0, 5.0, 25.0)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut compliance_notes = Vec::new();
+
+        // Car capacity in persons and the average up-peak load
+        let capacity = (rated_load / PASSENGER_MASS).floor();
+        let passengers = trace.step(
+            "formulas.elevator_traffic.average_load",
+            &[("capacity", capacity), ("load_factor", AVERAGE_LOAD_FACTOR)],
+            AVERAGE_LOAD_FACTOR * capacity,
+            "persons",
+        );
+        let stops = trace.step(
+            "formulas.elevator_traffic.probable_stops",
+            &[("floors", floors as f64), ("passengers", passengers)],
+            probable_stops(floors as f64, passengers),
+            "stops",
+        );
+        let reversal = trace.step(
+            "formulas.elevator_traffic.highest_reversal_floor",
+            &[("floors", floors as f64), ("passengers", passengers)],
+            highest_reversal_floor(floors, passengers),
+            "floors",
+        );
+
+        // Time per floor at rated speed, and the time lost by each stop
+        let floor_time = floor_height / speed;
+        let flight_time = trace.step(
+            "formulas.elevator_traffic.single_floor_flight_time",
+            &[("floor_height", floor_height), ("speed", speed), ("acceleration", acceleration)],
+            single_floor_flight_time(floor_height, speed, acceleration),
+            "s",
+        );
+        let stop_time = flight_time - floor_time + start_delay + door_close + door_open;
+        let round_trip = trace.step(
+            "formulas.elevator_traffic.round_trip_time",
+            &[("reversal", reversal), ("floor_time", floor_time), ("stops", stops), ("stop_time", stop_time), ("passengers", passengers), ("transfer", transfer)],
+            2.0 * reversal * floor_time + (stops + 1.0) * stop_time + 2.0 * passengers * transfer,
+            "s",
+        );
+
+        let performance = |cars: u32| {
+            let interval = round_trip / cars as f64;
+            let handling_capacity = 300.0 * passengers * cars as f64 / round_trip;
+            GroupPerformance {
+                cars,
+                interval,
+                handling_capacity,
+                handling_percent: 100.0 * handling_capacity / population,
+            }
+        };
+        let meets = |g: &GroupPerformance| g.interval <= target_interval && g.handling_percent >= target_handling;
+        let group = match cars {
+            Some(cars) => performance(cars),
+            None => (1..=MAX_GROUP_SIZE)
+                .map(performance)
+                .find(|g| meets(g))
+                .unwrap_or_else(|| performance(MAX_GROUP_SIZE)),
+        };
+        trace.step(
+            "formulas.elevator_traffic.interval",
+            &[("round_trip", round_trip), ("cars", group.cars as f64)],
+            group.interval,
+            "s",
+        );
+        trace.step(
+            "formulas.elevator_traffic.handling_capacity",
+            &[("passengers", passengers), ("cars", group.cars as f64), ("round_trip", round_trip), ("population", population)],
+            group.handling_percent,
+            "%",
+        );
+
+        // Waiting time for 50-80% average car loads
+        let load_ratio = passengers / capacity;
+        let waiting_time = (0.4 + (1.8 * load_ratio - 0.77).powi(2)) * group.interval;
+        let travel_time = floors as f64 * floor_time;
+
+        if group.interval > target_interval {
+            warnings.push(ELEVATOR_LONG_INTERVAL.warn(format!(
+                "Interval of {:.1} s with {} car(s) exceeds the {:.0} s target",
+                group.interval, group.cars, target_interval
+            )));
+        }
+        if group.handling_percent < target_handling {
+            warnings.push(ELEVATOR_LOW_HANDLING_CAPACITY.warn(format!(
+                "Handling capacity of {:.1}% of the population in five minutes is below the {:.0}% target",
+                group.handling_percent, target_handling
+            )));
+        }
+        if !meets(&group) {
+            if cars.is_none() {
+                recommendations.push(format!(
+                    "No group of up to {} cars meets the targets; use larger cars, zone the building or add sky lobbies",
+                    MAX_GROUP_SIZE
+                ));
+            } else {
+                recommendations.push("Add cars or increase car size and speed".to_string());
+            }
+        }
+        if travel_time > MAX_TRAVEL_TIME {
+            warnings.push(ELEVATOR_SLOW_FOR_RISE.warn(format!(
+                "Travel to the top floor takes {:.0} s at {:.1} m/s",
+                travel_time, speed
+            )));
+            recommendations.push(format!(
+                "A rated speed of {:.1} m/s reaches the top floor in {:.0} s",
+                floors as f64 * floor_height / MAX_TRAVEL_TIME,
+                MAX_TRAVEL_TIME
+            ));
+        }
+
+        compliance_notes.push("Up-peak round trip time per CIBSE Guide D with equal floor populations and 80% average car load".to_string());
+        compliance_notes.push("Lunch-time and two-way traffic can govern in multi-tenant buildings; verify with a simulation".to_string());
+
+        let results = vec![
+            EngineeringResultItem::new("Car Capacity", capacity, "persons").with_format(format!("{:.0} persons", capacity)),
+            EngineeringResultItem::new("Average Car Load", passengers, "persons")
+                .with_format(format!("{:.1} persons", passengers)),
+            EngineeringResultItem::new("Probable Stops", stops, "stops").with_format(format!("{:.2}", stops)),
+            EngineeringResultItem::new("Highest Reversal Floor", reversal, "floors").with_format(format!("{:.2}", reversal)),
+            EngineeringResultItem::new("Single Floor Flight Time", flight_time, "s")
+                .with_format(format!("{:.2} s", flight_time)),
+            EngineeringResultItem::new("Round Trip Time", round_trip, "s")
+                .critical()
+                .with_format(format!("{:.1} s", round_trip)),
+            EngineeringResultItem::new("Number of Cars", group.cars as f64, "cars").with_format(format!("{}", group.cars)),
+            EngineeringResultItem::new("Interval", group.interval, "s")
+                .critical()
+                .with_format(format!("{:.1} s", group.interval)),
+            EngineeringResultItem::new("Handling Capacity", group.handling_capacity, "persons/5 min")
+                .with_format(format!("{:.0} persons/5 min", group.handling_capacity)),
+            EngineeringResultItem::new("Handling Capacity Percentage", group.handling_percent, "%")
+                .critical()
+                .with_format(format!("{:.1}%", group.handling_percent)),
+            EngineeringResultItem::new("Average Waiting Time", waiting_time, "s")
+                .with_format(format!("{:.1} s", waiting_time)),
+            EngineeringResultItem::new("Travel Time to Top Floor", travel_time, "s")
+                .with_format(format!("{:.1} s", travel_time)),
+        ];
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "elevator_traffic".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "CIBSE Guide D".to_string(),
+                requires_pe_review: false,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_elevator_traffic_analysis() {
+        let params = |additional: serde_json::Value| -> EngineeringParameters {
+            let mut base = serde_json::json!({ "floors": 10.0, "population": 800.0 });
+            base.as_object_mut().unwrap().extend(additional.as_object().unwrap().clone());
+            serde_json::from_value(serde_json::json!({ "dimensions": { "floor_height": 3.5 }, "additional": base })).unwrap()
+        };
+
+        // 13-person cars at 1.6 m/s: RTT = 2Htv + (S + 1)ts + 2Ptp
+        let auto = execute("elevator_traffic", params(serde_json::json!({}))).await.unwrap();
+        let (p, n) = (10.4f64, 10.0f64);
+        let stops = n * (1.0 - 0.9f64.powf(p));
+        let reversal = n - (1..10).map(|i| (i as f64 / n).powf(p)).sum::<f64>();
+        let tv = 3.5 / 1.6;
+        let rtt = 2.0 * reversal * tv + (stops + 1.0) * (1.6 + 0.5 + 3.0 + 2.0) + 2.0 * p * 1.2;
+        assert!((value(&auto, "Round Trip Time") - rtt).abs() < 1e-9);
+        // Four cars leave a 30.3 s interval; five meet the 30 s target
+        assert_eq!(value(&auto, "Number of Cars"), 5.0);
+        assert!((value(&auto, "Interval") - rtt / 5.0).abs() < 1e-9);
+        assert!((value(&auto, "Handling Capacity") - 1500.0 * p / rtt).abs() < 1e-9);
+        assert!(warning_codes(&auto).is_empty());
+
+        let three = execute("elevator_traffic", params(serde_json::json!({ "cars": 3.0 }))).await.unwrap();
+        let warnings = warning_codes(&three);
+        assert!(warnings.contains(&"elevator_traffic.long_interval".to_string()));
+        assert!(warnings.contains(&"elevator_traffic.low_handling_capacity".to_string()));
+
+        let tall = execute("elevator_traffic", params(serde_json::json!({ "floors": 30.0, "car_speed": 1.0, "cars": 8.0 })))
+            .await
+            .unwrap();
+        assert!(warning_codes(&tall).contains(&"elevator_traffic.slow_for_rise".to_string()));
+
+        assert!(execute("elevator_traffic", params(serde_json::json!({ "cars": 2.5 }))).await.is_err());
+    }
+}
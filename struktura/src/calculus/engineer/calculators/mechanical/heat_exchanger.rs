@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::HEAT_EXCHANGER_LOW_EFFECTIVENESS,
 };
 use async_trait::async_trait;
 
@@ -177,24 +178,62 @@ impl EngineerCalculator for HeatExchangerCalculator {
         let mass_flow_cold = self.get_additional_param(&params, "mass_flow_cold", None, None)?;
         let u_value = params.additional.as_ref().and_then(|a| a.get("u_value").copied()).unwrap_or(U_WATER_WATER);
 
+        let mut trace = CalculationTrace::new();
+
         // Assume water for both sides
         let c_hot = WATER_SPECIFIC_HEAT * mass_flow_hot;
         let c_cold = WATER_SPECIFIC_HEAT * mass_flow_cold;
         let c_min = c_hot.min(c_cold);
         let c_ratio = c_min / c_hot.max(c_cold);
+        trace.step(
+            "formulas.heat_exchanger.capacity_ratio",
+            &[("c_hot", c_hot), ("c_cold", c_cold)],
+            c_ratio,
+            "dimensionless",
+        );
 
         let lmtd = lmtd_counterflow(t_hot_in, t_hot_out, t_cold_in, t_cold_out);
+        trace.step(
+            "formulas.heat_exchanger.lmtd",
+            &[("t_hot_in", t_hot_in), ("t_hot_out", t_hot_out), ("t_cold_in", t_cold_in), ("t_cold_out", t_cold_out)],
+            lmtd,
+            "°C",
+        );
         let heat_rate = heat_transfer_kw(mass_flow_hot, WATER_SPECIFIC_HEAT, t_hot_in - t_hot_out);
+        trace.step(
+            "formulas.heat_exchanger.heat_duty",
+            &[("mass_flow_hot", mass_flow_hot), ("cp", WATER_SPECIFIC_HEAT), ("delta_t", t_hot_in - t_hot_out)],
+            heat_rate,
+            "kW",
+        );
         let required_area = heat_rate * 1000.0 / (u_value * lmtd);
+        trace.step(
+            "formulas.heat_exchanger.required_area",
+            &[("heat_rate", heat_rate), ("u_value", u_value), ("lmtd", lmtd)],
+            required_area,
+            "m²",
+        );
         let ntu_val = ntu(u_value * required_area, c_min);
+        trace.step(
+            "formulas.heat_exchanger.ntu",
+            &[("u_value", u_value), ("required_area", required_area), ("c_min", c_min)],
+            ntu_val,
+            "dimensionless",
+        );
         let effectiveness = effectiveness_from_ntu_counterflow(ntu_val, c_ratio);
+        trace.step(
+            "formulas.heat_exchanger.effectiveness",
+            &[("ntu", ntu_val), ("c_ratio", c_ratio)],
+            effectiveness,
+            "dimensionless",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if effectiveness < 0.5 {
-            warnings.push(format!("Low effectiveness ({:.2}). Consider different configuration.", effectiveness));
+            warnings.push(HEAT_EXCHANGER_LOW_EFFECTIVENESS.warn(format!("Low effectiveness ({:.2}). Consider different configuration.", effectiveness)));
             recommendations.push("Switch to parallel flow or multiple passes".to_string());
         }
 
@@ -224,8 +263,8 @@ impl EngineerCalculator for HeatExchangerCalculator {
             calculation_type: "heat_exchanger".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -234,6 +273,8 @@ impl EngineerCalculator for HeatExchangerCalculator {
                 design_code_used: "TEMA".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -2,11 +2,31 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{HVAC_HIGH_OCCUPANCY, HVAC_LARGE_TEMPERATURE_DIFFERENCE, HVAC_MIXED_AIR_FOG, HVAC_MIXED_AIR_FREEZING},
+};
+use super::psychrometrics::{self, AirState};
+use crate::utils::conversions::essential::{
+    flow::m3h_to_m3s,
+    power::{w_to_kw, w_to_ton_refrigeration},
 };
 use async_trait::async_trait;
 
 pub struct HVACLoadCalculationCalculator;
 
+/// Results describing one moist air state
+fn air_state_results(name: &str, air: &AirState) -> Vec<EngineeringResultItem> {
+    vec![
+        EngineeringResultItem::new(format!("{} Humidity Ratio", name), air.humidity_ratio * 1000.0, "g/kg")
+            .with_format(format!("{:.2} g/kg", air.humidity_ratio * 1000.0)),
+        EngineeringResultItem::new(format!("{} Dew Point", name), air.dew_point, "°C")
+            .with_format(format!("{:.1} °C", air.dew_point)),
+        EngineeringResultItem::new(format!("{} Wet Bulb", name), air.wet_bulb, "°C")
+            .with_format(format!("{:.1} °C", air.wet_bulb)),
+        EngineeringResultItem::new(format!("{} Enthalpy", name), air.enthalpy, "kJ/kg")
+            .with_format(format!("{:.1} kJ/kg", air.enthalpy)),
+    ]
+}
+
 impl ParameterValidator for HVACLoadCalculationCalculator {
     fn calculator_id(&self) -> &str {
         "hvac_load_calculation"
@@ -111,6 +131,71 @@ impl EngineerCalculator for HVACLoadCalculationCalculator {
                 typical_range: Some((0.05, 0.2)),
                 validation_rules: None,
             })
+            .parameter(ParameterMetadata {
+                name: "Outdoor Relative Humidity".to_string(),
+                path: "additional.outdoor_rh".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Design outdoor relative humidity; enables the psychrometric analysis".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(100.0),
+                typical_range: Some((30.0, 80.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Indoor Relative Humidity".to_string(),
+                path: "additional.indoor_rh".to_string(),
+                data_type: ParameterType::Number,
+                unit: "%".to_string(),
+                description: "Design indoor relative humidity".to_string(),
+                required: false,
+                default_value: Some(50.0),
+                min_value: Some(1.0),
+                max_value: Some(100.0),
+                typical_range: Some((30.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Site Altitude".to_string(),
+                path: "additional.altitude".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Elevation above sea level, for the barometric pressure".to_string(),
+                required: false,
+                default_value: Some(0.0),
+                min_value: Some(-500.0),
+                max_value: Some(5000.0),
+                typical_range: Some((0.0, 2000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Outdoor Airflow".to_string(),
+                path: "additional.outdoor_airflow".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m³/h".to_string(),
+                description: "Outdoor air volume mixed with return air at the air handler".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(1000000.0),
+                typical_range: Some((500.0, 20000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Return Airflow".to_string(),
+                path: "additional.return_airflow".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m³/h".to_string(),
+                description: "Return air volume at indoor conditions mixed with the outdoor air".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(1000000.0),
+                typical_range: Some((2000.0, 80000.0)),
+                validation_rules: None,
+            })
             .complexity(ComplexityLevel::Intermediate)
             .build()
     }
@@ -122,6 +207,11 @@ impl EngineerCalculator for HVACLoadCalculationCalculator {
         self.get_additional_param(params, "wall_u", Some(0.1), Some(2.0))?;
         self.get_additional_param(params, "window_ratio", Some(0.0), Some(80.0))?;
         self.get_additional_param(params, "occupancy", Some(0.0), Some(1.0))?;
+        self.optional_param(params, "outdoor_rh", 50.0, 1.0, 100.0)?;
+        self.optional_param(params, "indoor_rh", 50.0, 1.0, 100.0)?;
+        self.optional_param(params, "altitude", 0.0, -500.0, 5000.0)?;
+        self.optional_param(params, "outdoor_airflow", 0.0, 0.0, 1_000_000.0)?;
+        self.optional_param(params, "return_airflow", 0.0, 0.0, 1_000_000.0)?;
 
         if area < 100.0 {
             return Err(EngineeringError::DomainError {
@@ -141,50 +231,194 @@ impl EngineerCalculator for HVACLoadCalculationCalculator {
         let window_ratio = params.additional.as_ref().and_then(|a| a.get("window_ratio").copied()).unwrap_or(20.0) / 100.0;
         let occupancy = params.additional.as_ref().and_then(|a| a.get("occupancy").copied()).unwrap_or(0.1);
 
+        let mut trace = CalculationTrace::new();
+
         // Simplified load calculation
         let dt = (outdoor_temp - indoor_temp).abs();
         let envelope_area = area * 2.5; // Assume height 2.5m, perimeter approx
+        trace.step(
+            "formulas.hvac_load_calculation.envelope_area",
+            &[("area", area)],
+            envelope_area,
+            "m²",
+        );
         let conduction_load = wall_u * envelope_area * dt * (1.0 - window_ratio);
+        trace.step(
+            "formulas.hvac_load_calculation.conduction_load",
+            &[("wall_u", wall_u), ("envelope_area", envelope_area), ("dt", dt), ("window_ratio", window_ratio)],
+            conduction_load,
+            "W",
+        );
         let window_load = 2.0 * envelope_area * window_ratio * dt; // Higher U for windows
+        trace.step(
+            "formulas.hvac_load_calculation.window_load",
+            &[("envelope_area", envelope_area), ("window_ratio", window_ratio), ("dt", dt)],
+            window_load,
+            "W",
+        );
         let solar_load = 200.0 * area * 0.5; // Approximate solar gain
+        trace.step("formulas.hvac_load_calculation.solar_load", &[("area", area)], solar_load, "W");
         let internal_load = 100.0 * occupancy * area; // W/person
+        trace.step(
+            "formulas.hvac_load_calculation.internal_load",
+            &[("occupancy", occupancy), ("area", area)],
+            internal_load,
+            "W",
+        );
         let ventilation_load = 500.0 * occupancy * area * dt / 20.0; // Approximate
+        trace.step(
+            "formulas.hvac_load_calculation.ventilation_load",
+            &[("occupancy", occupancy), ("area", area), ("dt", dt)],
+            ventilation_load,
+            "W",
+        );
 
         let total_load = conduction_load + window_load + solar_load + internal_load + ventilation_load;
-        let load_tons = total_load / 12000.0 / 3.517; // Convert W to tons (approx)
+        trace.step(
+            "formulas.hvac_load_calculation.total_load",
+            &[("conduction_load", conduction_load), ("window_load", window_load), ("solar_load", solar_load), ("internal_load", internal_load), ("ventilation_load", ventilation_load)],
+            total_load,
+            "W",
+        );
+        let load_tons = w_to_ton_refrigeration(total_load).await;
+        trace.step(
+            "formulas.hvac_load_calculation.load_tons",
+            &[("total_load", total_load)],
+            load_tons,
+            "TR",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if dt > 20.0 {
-            warnings.push(format!("Large temperature difference ({:.1}°C). Verify insulation.", dt));
+            warnings.push(HVAC_LARGE_TEMPERATURE_DIFFERENCE.warn(format!("Large temperature difference ({:.1}°C). Verify insulation.", dt)));
             recommendations.push("Improve building envelope U-values".to_string());
         }
 
         if occupancy > 0.2 {
-            warnings.push("High occupancy density. Verify ventilation requirements".to_string());
+            warnings.push(HVAC_HIGH_OCCUPANCY.warn("High occupancy density. Verify ventilation requirements"));
         }
 
         compliance_notes.push("Simplified load calculation per ASHRAE methods".to_string());
         compliance_notes.push("For detailed analysis, use CLTD/CLF method".to_string());
 
-        let results = vec![
-            EngineeringResultItem::new("Total Load", total_load / 1000.0, "kW")
+        let total_load_kw = w_to_kw(total_load).await;
+        let mut results = vec![
+            EngineeringResultItem::new("Total Load", total_load_kw, "kW")
                 .critical()
-                .with_format(format!("{:.1} kW", total_load / 1000.0)),
+                .with_format(format!("{:.1} kW", total_load_kw)),
             EngineeringResultItem::new("Load in Tons", load_tons, "tons")
                 .with_format(format!("{:.1} tons", load_tons)),
-            EngineeringResultItem::new("Conduction Load", conduction_load / 1000.0, "kW"),
-            EngineeringResultItem::new("Internal Load", internal_load / 1000.0, "kW"),
+            EngineeringResultItem::new("Conduction Load", w_to_kw(conduction_load).await, "kW"),
+            EngineeringResultItem::new("Internal Load", w_to_kw(internal_load).await, "kW"),
         ];
 
+        // Psychrometric analysis once the outdoor humidity is known
+        if params.additional.as_ref().is_some_and(|a| a.contains_key("outdoor_rh")) {
+            let outdoor_rh = self.optional_param(&params, "outdoor_rh", 50.0, 1.0, 100.0)?;
+            let indoor_rh = self.optional_param(&params, "indoor_rh", 50.0, 1.0, 100.0)?;
+            let altitude = self.optional_param(&params, "altitude", 0.0, -500.0, 5000.0)?;
+            let outdoor_airflow = self.optional_param(&params, "outdoor_airflow", 0.0, 0.0, 1_000_000.0)?;
+            let return_airflow = self.optional_param(&params, "return_airflow", 0.0, 0.0, 1_000_000.0)?;
+
+            let pressure = psychrometrics::pressure_at_altitude(altitude);
+            trace.step(
+                "formulas.hvac_load_calculation.barometric_pressure",
+                &[("altitude", altitude)],
+                pressure,
+                "kPa",
+            );
+            let outdoor = psychrometrics::state_from_relative_humidity(outdoor_temp, outdoor_rh, pressure);
+            let indoor = psychrometrics::state_from_relative_humidity(indoor_temp, indoor_rh, pressure);
+            for (name, air, rh) in [("outdoor", &outdoor, outdoor_rh), ("indoor", &indoor, indoor_rh)] {
+                trace.step(
+                    format!("formulas.hvac_load_calculation.{}_humidity_ratio", name),
+                    &[("dry_bulb", air.dry_bulb), ("rh", rh), ("pressure", pressure)],
+                    air.humidity_ratio * 1000.0,
+                    "g/kg",
+                );
+                trace.step(
+                    format!("formulas.hvac_load_calculation.{}_enthalpy", name),
+                    &[("dry_bulb", air.dry_bulb), ("humidity_ratio", air.humidity_ratio)],
+                    air.enthalpy,
+                    "kJ/kg",
+                );
+            }
+
+            results.push(
+                EngineeringResultItem::new("Barometric Pressure", pressure, "kPa")
+                    .with_format(format!("{:.2} kPa", pressure)),
+            );
+            results.extend(air_state_results("Outdoor", &outdoor));
+            results.extend(air_state_results("Indoor", &indoor));
+
+            if outdoor_airflow > 0.0 {
+                // Outdoor air brought to indoor conditions; positive loads add cooling
+                let mass_flow = m3h_to_m3s(outdoor_airflow).await / outdoor.specific_volume;
+                let oa_total = mass_flow * (outdoor.enthalpy - indoor.enthalpy);
+                let oa_latent = mass_flow * 2501.0 * (outdoor.humidity_ratio - indoor.humidity_ratio);
+                trace.step(
+                    "formulas.hvac_load_calculation.outdoor_air_latent_load",
+                    &[("mass_flow", mass_flow), ("outdoor_w", outdoor.humidity_ratio), ("indoor_w", indoor.humidity_ratio)],
+                    oa_latent,
+                    "kW",
+                );
+                results.extend([
+                    EngineeringResultItem::new("Outdoor Air Sensible Load", oa_total - oa_latent, "kW")
+                        .with_format(format!("{:.1} kW", oa_total - oa_latent)),
+                    EngineeringResultItem::new("Outdoor Air Latent Load", oa_latent, "kW")
+                        .with_format(format!("{:.1} kW", oa_latent)),
+                ]);
+                compliance_notes.push("Outdoor air loads are positive when they add cooling and negative when they add heating".to_string());
+            }
+
+            if outdoor_airflow > 0.0 && return_airflow > 0.0 {
+                let (mixed, _, _) = psychrometrics::mix(&outdoor, outdoor_airflow, &indoor, return_airflow, pressure);
+                trace.step(
+                    "formulas.hvac_load_calculation.mixed_air_temperature",
+                    &[("outdoor_enthalpy", outdoor.enthalpy), ("indoor_enthalpy", indoor.enthalpy), ("mixed_w", mixed.humidity_ratio)],
+                    mixed.dry_bulb,
+                    "°C",
+                );
+                let outdoor_fraction = outdoor_airflow / (outdoor_airflow + return_airflow);
+
+                if mixed.relative_humidity > 100.0 {
+                    warnings.push(HVAC_MIXED_AIR_FOG.warn(format!(
+                        "Mixed air at {:.1}°C holds more moisture than saturation ({:.0}% RH). Preheat the outdoor air before mixing.",
+                        mixed.dry_bulb, mixed.relative_humidity
+                    )));
+                }
+                if mixed.dry_bulb < 2.0 {
+                    warnings.push(HVAC_MIXED_AIR_FREEZING.warn(format!(
+                        "Mixed air temperature of {:.1}°C risks freezing coils. Preheat the outdoor air or add freeze protection.",
+                        mixed.dry_bulb
+                    )));
+                    recommendations.push("Add an outdoor air preheat coil or freeze-stat controlled dampers".to_string());
+                }
+
+                results.extend([
+                    EngineeringResultItem::new("Outdoor Air Fraction", outdoor_fraction * 100.0, "%")
+                        .with_format(format!("{:.0}%", outdoor_fraction * 100.0)),
+                    EngineeringResultItem::new("Mixed Air Temperature", mixed.dry_bulb, "°C")
+                        .critical()
+                        .with_format(format!("{:.1} °C", mixed.dry_bulb)),
+                    EngineeringResultItem::new("Mixed Air Relative Humidity", mixed.relative_humidity, "%")
+                        .with_format(format!("{:.0}%", mixed.relative_humidity)),
+                ]);
+                results.extend(air_state_results("Mixed Air", &mixed));
+            }
+
+            compliance_notes.push("Psychrometric properties per ASHRAE Fundamentals Chapter 1 (Hyland-Wexler saturation pressure)".to_string());
+        }
+
         Ok(EngineeringCalculationResponse {
             calculation_type: "hvac_load_calculation".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -193,7 +427,62 @@ impl EngineerCalculator for HVACLoadCalculationCalculator {
                 design_code_used: "ASHRAE 90.1".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_hvac_psychrometrics_and_mixing() {
+        let params = |additional: serde_json::Value| -> EngineeringParameters {
+            let mut base = serde_json::json!({
+                "outdoor_temp": 35.0, "indoor_temp": 24.0, "wall_u": 0.5, "window_ratio": 20.0, "occupancy": 0.1,
+            });
+            base.as_object_mut().unwrap().extend(additional.as_object().unwrap().clone());
+            serde_json::from_value(serde_json::json!({ "dimensions": { "area": 1000.0 }, "additional": base })).unwrap()
+        };
+
+        // Without outdoor humidity the response is unchanged
+        let plain = execute("hvac_load_calculation", params(serde_json::json!({}))).await.unwrap();
+        assert!(plain.results.iter().all(|r| r.label != "Outdoor Humidity Ratio"));
+
+        // 35°C / 40% outside, 24°C / 50% inside, 25% outdoor air
+        let summer = execute("hvac_load_calculation", params(serde_json::json!({
+                "outdoor_rh": 40.0, "indoor_rh": 50.0, "outdoor_airflow": 2000.0, "return_airflow": 6000.0,
+            })))
+            .await
+            .unwrap();
+        assert_eq!(value(&summer, "Total Load"), value(&plain, "Total Load"));
+        assert!((value(&summer, "Outdoor Humidity Ratio") - 14.13).abs() < 0.05);
+        assert!((value(&summer, "Indoor Dew Point") - 12.9).abs() < 0.1);
+        assert!(value(&summer, "Outdoor Air Latent Load") > 0.0);
+        assert!((value(&summer, "Outdoor Air Fraction") - 25.0).abs() < 1e-9);
+        // Mixed air lies between the streams, close to the 26.75°C volume-weighted average
+        let mixed = value(&summer, "Mixed Air Temperature");
+        assert!((mixed - 26.75).abs() < 0.2);
+        assert!(warning_codes(&summer).is_empty());
+
+        // Less air at altitude: same RH holds more moisture per kg
+        let high = execute("hvac_load_calculation", params(serde_json::json!({ "outdoor_rh": 40.0, "altitude": 1500.0 })))
+            .await
+            .unwrap();
+        assert!(value(&high, "Outdoor Humidity Ratio") > value(&summer, "Outdoor Humidity Ratio"));
+
+        // Winter with half outdoor air: cold mixed air
+        let winter = execute("hvac_load_calculation", params(serde_json::json!({
+                "outdoor_temp": -25.0, "indoor_temp": 21.0, "outdoor_rh": 80.0, "indoor_rh": 40.0,
+                "outdoor_airflow": 6000.0, "return_airflow": 4000.0,
+            })))
+            .await
+            .unwrap();
+        assert!(value(&winter, "Mixed Air Temperature") < 2.0);
+        assert!(value(&winter, "Outdoor Air Sensible Load") < 0.0);
+        assert!(warning_codes(&winter).contains(&"hvac_load_calculation.mixed_air_freezing".to_string()));
+    }
+}
@@ -1,9 +1,10 @@
 // ============================================================================
 // Mechanical Engineering Calculators
 // 
-// Thermodynamics, fluid mechanics, HVAC, and pump system calculators.
+// Thermodynamics, fluid mechanics, HVAC, pump system, elevator traffic and
+// fire sprinkler calculators.
 // These calculators typically do not require PE review unless for critical
-// applications (pressure vessels, life safety systems).
+// applications (pressure vessels, life safety systems such as sprinklers).
 // ============================================================================
 
 // Individual calculator modules
@@ -15,6 +16,10 @@ pub mod refrigeration_cycle;
 pub mod compressor_sizing;
 pub mod valve_sizing;
 pub mod thermal_expansion;
+pub mod condensation_risk;
+pub mod water_hammer;
+pub mod elevator_traffic;
+pub mod sprinkler_hydraulic;
 
 // Re-export calculators
 pub use heat_exchanger::HeatExchangerCalculator;
@@ -25,6 +30,10 @@ pub use refrigeration_cycle::RefrigerationCycleCalculator;
 pub use compressor_sizing::CompressorSizingCalculator;
 pub use valve_sizing::ValveSizingCalculator;
 pub use thermal_expansion::ThermalExpansionCalculator;
+pub use condensation_risk::CondensationRiskCalculator;
+pub use water_hammer::WaterHammerCalculator;
+pub use elevator_traffic::ElevatorTrafficCalculator;
+pub use sprinkler_hydraulic::SprinklerHydraulicCalculator;
 
 // ============================================================================
 // MECHANICAL ENGINEERING CONSTANTS
@@ -93,6 +102,264 @@ pub mod pump_hydraulics {
     pub const FRICTION_TURBULENT_SMOOTH: f64 = 0.02;
 }
 
+/// Pipe wall materials for surge analysis
+pub mod pipe_materials {
+    /// Name, elastic modulus (GPa) and Poisson's ratio
+    pub const PIPE_MATERIALS: [(&str, f64, f64); 6] = [
+        ("steel", 207.0, 0.30),
+        ("ductile_iron", 169.0, 0.28),
+        ("copper", 117.0, 0.34),
+        ("concrete", 30.0, 0.20),
+        ("pvc", 3.3, 0.45),
+        ("hdpe", 0.8, 0.46),
+    ];
+
+    // Water at 20°C
+    pub const WATER_BULK_MODULUS: f64 = 2.2; // GPa
+    pub const WATER_VAPOR_PRESSURE: f64 = 2.3; // kPa absolute
+
+    /// Elastic modulus and Poisson's ratio of a named pipe material
+    pub fn lookup(name: &str) -> Option<(f64, f64)> {
+        let name = name.trim().to_ascii_lowercase().replace([' ', '-'], "_");
+        PIPE_MATERIALS.iter().find(|(n, _, _)| *n == name).map(|&(_, e, nu)| (e, nu))
+    }
+}
+
+/// Moist air properties (ASHRAE Fundamentals, Chapter 1)
+///
+/// Temperatures in °C, pressures in kPa, humidity ratios in kg water per kg
+/// dry air, enthalpies in kJ/kg dry air.
+pub mod psychrometrics {
+    /// Ratio of the molar masses of water vapour and dry air
+    pub const MOLAR_MASS_RATIO: f64 = 0.621945;
+    /// Gas constant of dry air (kJ/(kg·K))
+    pub const R_DRY_AIR: f64 = 0.287042;
+
+    /// State of moist air at a given barometric pressure
+    #[derive(Debug, Clone, Copy)]
+    pub struct AirState {
+        pub dry_bulb: f64,
+        pub relative_humidity: f64,
+        pub humidity_ratio: f64,
+        pub dew_point: f64,
+        pub wet_bulb: f64,
+        pub enthalpy: f64,
+        /// m³ per kg dry air
+        pub specific_volume: f64,
+    }
+
+    /// Standard atmospheric pressure (kPa) at `altitude` m (Eq. 3)
+    pub fn pressure_at_altitude(altitude: f64) -> f64 {
+        101.325 * (1.0 - 2.25577e-5 * altitude).powf(5.2559)
+    }
+
+    /// Saturation vapour pressure (kPa) over ice below 0°C and over water above (Hyland-Wexler, Eq. 5-6)
+    pub fn saturation_pressure(t: f64) -> f64 {
+        let k = t + 273.15;
+        let ln_p = if t < 0.0 {
+            -5.674_535_9e3 / k + 6.392_524_7 - 9.677_843e-3 * k + 6.221_570_1e-7 * k.powi(2)
+                + 2.074_782_5e-9 * k.powi(3)
+                - 9.484_024e-13 * k.powi(4)
+                + 4.163_501_9 * k.ln()
+        } else {
+            -5.800_220_6e3 / k + 1.391_499_3 - 4.864_023_9e-2 * k + 4.176_476_8e-5 * k.powi(2)
+                - 1.445_209_3e-8 * k.powi(3)
+                + 6.545_967_3 * k.ln()
+        };
+        ln_p.exp() / 1000.0
+    }
+
+    /// Humidity ratio for a vapour partial pressure `pw` at barometric pressure `p` (Eq. 20)
+    pub fn humidity_ratio(pw: f64, p: f64) -> f64 {
+        MOLAR_MASS_RATIO * pw / (p - pw)
+    }
+
+    /// Vapour partial pressure for humidity ratio `w` at pressure `p`
+    pub fn vapour_pressure(w: f64, p: f64) -> f64 {
+        p * w / (MOLAR_MASS_RATIO + w)
+    }
+
+    /// Moist air enthalpy (Eq. 32)
+    pub fn enthalpy(t: f64, w: f64) -> f64 {
+        1.006 * t + w * (2501.0 + 1.86 * t)
+    }
+
+    /// Dry bulb temperature with enthalpy `h` and humidity ratio `w`, the inverse of [`enthalpy`]
+    pub fn dry_bulb_from_enthalpy(h: f64, w: f64) -> f64 {
+        (h - 2501.0 * w) / (1.006 + 1.86 * w)
+    }
+
+    /// Specific volume (m³/kg dry air, Eq. 26)
+    pub fn specific_volume(t: f64, w: f64, p: f64) -> f64 {
+        R_DRY_AIR * (t + 273.15) * (1.0 + 1.607_858 * w) / p
+    }
+
+    /// Root of an increasing function `f` between `lo` and `hi` by bisection
+    fn bisect(mut lo: f64, mut hi: f64, f: impl Fn(f64) -> f64) -> f64 {
+        for _ in 0..60 {
+            let mid = 0.5 * (lo + hi);
+            if f(mid) > 0.0 {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        0.5 * (lo + hi)
+    }
+
+    /// Temperature at which `pw` is the saturation pressure
+    pub fn dew_point(pw: f64) -> f64 {
+        bisect(-100.0, 200.0, |t| saturation_pressure(t) - pw)
+    }
+
+    /// Humidity ratio implied by a wet bulb `t_star` for dry bulb `t` (Eq. 33 and 35)
+    fn humidity_ratio_at_wet_bulb(t: f64, t_star: f64, p: f64) -> f64 {
+        let ws = humidity_ratio(saturation_pressure(t_star), p);
+        if t_star >= 0.0 {
+            ((2501.0 - 2.326 * t_star) * ws - 1.006 * (t - t_star)) / (2501.0 + 1.86 * t - 4.186 * t_star)
+        } else {
+            ((2830.0 - 0.24 * t_star) * ws - 1.006 * (t - t_star)) / (2830.0 + 1.86 * t - 2.1 * t_star)
+        }
+    }
+
+    /// Thermodynamic wet bulb temperature, which lies between the dew point and the dry bulb
+    pub fn wet_bulb(t: f64, w: f64, p: f64) -> f64 {
+        let dew = dew_point(vapour_pressure(w, p));
+        bisect(dew.min(t), t, |t_star| humidity_ratio_at_wet_bulb(t, t_star, p) - w)
+    }
+
+    /// Air at dry bulb `t` with humidity ratio `w`
+    pub fn state_from_humidity_ratio(t: f64, w: f64, p: f64) -> AirState {
+        let pw = vapour_pressure(w, p);
+        AirState {
+            dry_bulb: t,
+            relative_humidity: 100.0 * pw / saturation_pressure(t),
+            humidity_ratio: w,
+            dew_point: dew_point(pw),
+            wet_bulb: wet_bulb(t, w, p),
+            enthalpy: enthalpy(t, w),
+            specific_volume: specific_volume(t, w, p),
+        }
+    }
+
+    /// Air at dry bulb `t` and relative humidity `rh` (%)
+    pub fn state_from_relative_humidity(t: f64, rh: f64, p: f64) -> AirState {
+        let w = humidity_ratio(rh / 100.0 * saturation_pressure(t), p);
+        state_from_humidity_ratio(t, w, p)
+    }
+
+    /// Adiabatic mix of two airstreams given by volume flow, weighted by dry air mass;
+    /// returns the mixed state and the dry air mass flow of each stream in the flow's time unit
+    pub fn mix(a: &AirState, flow_a: f64, b: &AirState, flow_b: f64, p: f64) -> (AirState, f64, f64) {
+        let mass_a = flow_a / a.specific_volume;
+        let mass_b = flow_b / b.specific_volume;
+        let total = mass_a + mass_b;
+        let w = (mass_a * a.humidity_ratio + mass_b * b.humidity_ratio) / total;
+        let h = (mass_a * a.enthalpy + mass_b * b.enthalpy) / total;
+        (state_from_humidity_ratio(dry_bulb_from_enthalpy(h, w), w, p), mass_a, mass_b)
+    }
+}
+
+/// Up-peak elevator traffic design values (CIBSE Guide D)
+pub mod elevator_values {
+    /// Mass per passenger for rated car capacity (kg, EN 81-20)
+    pub const PASSENGER_MASS: f64 = 75.0;
+    /// Average up-peak car load as a fraction of rated capacity
+    pub const AVERAGE_LOAD_FACTOR: f64 = 0.8;
+    /// Largest group of cars serving one lobby
+    pub const MAX_GROUP_SIZE: u32 = 8;
+    /// Longest comfortable travel time to the top floor at rated speed (s)
+    pub const MAX_TRAVEL_TIME: f64 = 25.0;
+
+    /// Expected number of stops above the main terminal with `passengers` aboard and
+    /// `floors` equally populated floors: S = N(1 − (1 − 1/N)^P)
+    pub fn probable_stops(floors: f64, passengers: f64) -> f64 {
+        floors * (1.0 - (1.0 - 1.0 / floors).powf(passengers))
+    }
+
+    /// Expected highest reversal floor: H = N − Σ(i/N)^P for i = 1..N−1
+    pub fn highest_reversal_floor(floors: u32, passengers: f64) -> f64 {
+        let n = floors as f64;
+        n - (1..floors).map(|i| (i as f64 / n).powf(passengers)).sum::<f64>()
+    }
+
+    /// Time (s) to run one floor height `distance` from rest to rest at rated `speed` and `acceleration`
+    pub fn single_floor_flight_time(distance: f64, speed: f64, acceleration: f64) -> f64 {
+        if distance > speed * speed / acceleration {
+            distance / speed + speed / acceleration
+        } else {
+            2.0 * (distance / acceleration).sqrt()
+        }
+    }
+}
+
+/// NFPA 13 sprinkler design values (SI)
+pub mod sprinkler_values {
+    /// Occupancy hazard design criteria
+    pub struct HazardClass {
+        pub name: &'static str,
+        /// Density at the design point of the density/area curve (mm/min)
+        pub density: f64,
+        /// Design area at that point (m²)
+        pub design_area: f64,
+        /// Largest area one sprinkler may protect (m²)
+        pub max_coverage: f64,
+        /// Combined inside and outside hose stream allowance (L/min)
+        pub hose_allowance: f64,
+        /// Minimum water supply duration (min)
+        pub duration: f64,
+        /// Most sprinklers each pipe size may supply under the pipe schedule method
+        pub pipe_schedule: [u32; 8],
+    }
+
+    pub const HAZARD_CLASSES: [HazardClass; 5] = [
+        HazardClass { name: "light", density: 4.1, design_area: 139.0, max_coverage: 20.9, hose_allowance: 380.0, duration: 30.0, pipe_schedule: [2, 3, 5, 10, 30, 60, 100, u32::MAX] },
+        HazardClass { name: "ordinary_1", density: 6.1, design_area: 139.0, max_coverage: 12.1, hose_allowance: 950.0, duration: 60.0, pipe_schedule: [2, 3, 5, 10, 20, 40, 65, 100] },
+        HazardClass { name: "ordinary_2", density: 8.1, design_area: 139.0, max_coverage: 12.1, hose_allowance: 950.0, duration: 60.0, pipe_schedule: [2, 3, 5, 10, 20, 40, 65, 100] },
+        HazardClass { name: "extra_1", density: 12.2, design_area: 232.0, max_coverage: 9.3, hose_allowance: 1900.0, duration: 90.0, pipe_schedule: [1, 2, 5, 8, 15, 27, 40, 55] },
+        HazardClass { name: "extra_2", density: 16.3, design_area: 232.0, max_coverage: 9.3, hose_allowance: 1900.0, duration: 90.0, pipe_schedule: [1, 2, 5, 8, 15, 27, 40, 55] },
+    ];
+
+    /// Nominal size and internal diameter (mm) of schedule 40 steel pipe, matching `pipe_schedule`
+    pub const PIPE_SIZES: [(&str, f64); 8] = [
+        ("1\"", 26.6),
+        ("1-1/4\"", 35.1),
+        ("1-1/2\"", 40.9),
+        ("2\"", 52.5),
+        ("2-1/2\"", 62.7),
+        ("3\"", 77.9),
+        ("3-1/2\"", 90.1),
+        ("4\"", 102.3),
+    ];
+
+    /// Hazen-Williams C for wet-pipe black steel
+    pub const HAZEN_WILLIAMS_STEEL: f64 = 120.0;
+    /// Velocity commonly accepted as an upper limit in sprinkler piping (m/s)
+    pub const MAX_VELOCITY: f64 = 6.0;
+    /// Pressure of a water column per metre of elevation (bar/m)
+    pub const ELEVATION_PRESSURE: f64 = 0.0981;
+
+    /// Design criteria of a named hazard class
+    pub fn lookup(name: &str) -> Option<&'static HazardClass> {
+        let name = name.trim().to_ascii_lowercase().replace([' ', '-'], "_");
+        HAZARD_CLASSES.iter().find(|h| h.name == name)
+    }
+
+    /// Index into `PIPE_SIZES` of the size nearest an internal diameter (mm)
+    pub fn nearest_pipe_size(diameter: f64) -> usize {
+        PIPE_SIZES
+            .iter()
+            .enumerate()
+            .min_by(|a, b| (a.1.1 - diameter).abs().total_cmp(&(b.1.1 - diameter).abs()))
+            .map_or(0, |(i, _)| i)
+    }
+
+    /// Hazen-Williams friction loss (bar/m) for `flow` L/min in `diameter` mm pipe (NFPA 13 SI form)
+    pub fn friction_loss(flow: f64, diameter: f64, c: f64) -> f64 {
+        6.05e5 * flow.powf(1.85) / (c.powf(1.85) * diameter.powf(4.87))
+    }
+}
+
 /// Helper functions for mechanical calculations
 pub mod helpers {
     use super::constants::GRAVITY;
@@ -128,6 +395,16 @@ pub mod helpers {
         (density * GRAVITY * flow_m3s * head_m) / 1000.0
     }
     
+    /// Pressure wave speed (m/s) in a liquid-filled elastic pipe (Korteweg), with restraint factor `c1`
+    pub fn wave_speed(bulk_modulus: f64, density: f64, diameter: f64, wall: f64, elastic_modulus: f64, c1: f64) -> f64 {
+        ((bulk_modulus / density) / (1.0 + c1 * bulk_modulus * diameter / (elastic_modulus * wall))).sqrt()
+    }
+
+    /// Joukowsky pressure rise (Pa) for an instantaneous velocity change
+    pub fn joukowsky(density: f64, wave_speed: f64, delta_v: f64) -> f64 {
+        density * wave_speed * delta_v
+    }
+
     /// Calculate LMTD (Log Mean Temperature Difference)
     pub fn lmtd_counterflow(t_hot_in: f64, t_hot_out: f64, t_cold_in: f64, t_cold_out: f64) -> f64 {
         let dt1 = t_hot_in - t_cold_out;
@@ -193,6 +470,83 @@ mod tests {
         assert!((power - 19.62).abs() < 0.1);
     }
 
+    #[test]
+    fn test_water_hammer() {
+        use helpers::*;
+        use fluid_properties::*;
+
+        // Rigid pipe: wave speed is the speed of sound in water, √(2.2e9 / 1000) ≈ 1483 m/s
+        let rigid = wave_speed(2.2e9, WATER_DENSITY, 0.3, 0.01, f64::INFINITY, 1.0);
+        assert!((rigid - 1483.2).abs() < 0.1);
+        // Plastic pipe is far softer
+        let (e, nu) = pipe_materials::lookup("PVC").unwrap();
+        assert!(wave_speed(2.2e9, WATER_DENSITY, 0.3, 0.015, e * 1e9, 1.0 - nu * nu) < 500.0);
+
+        // Stopping 2 m/s at 1000 m/s: 1000 × 1000 × 2 = 2 MPa
+        assert_eq!(joukowsky(WATER_DENSITY, 1000.0, 2.0), 2.0e6);
+    }
+
+    #[test]
+    fn test_psychrometrics() {
+        use psychrometrics::*;
+
+        // ASHRAE Fundamentals Table 2 at 20°C: pws 2.3392 kPa; Ws = 0.621945 × 2.3392 / 98.986,
+        // slightly under the tabulated 0.014758 that includes the enhancement factor
+        let p = pressure_at_altitude(0.0);
+        assert!((p - 101.325).abs() < 1e-9);
+        assert!((saturation_pressure(20.0) - 2.3392).abs() < 0.001);
+        assert!((humidity_ratio(saturation_pressure(20.0), p) - 0.014698).abs() < 1e-5);
+        // Ice side at -10°C: 0.25987 kPa
+        assert!((saturation_pressure(-10.0) - 0.25987).abs() < 0.0005);
+        // Roughly 89.9 kPa at 1000 m
+        assert!((pressure_at_altitude(1000.0) - 89.88).abs() < 0.05);
+
+        // 30°C / 50% RH: dew point ≈ 18.4°C, wet bulb ≈ 22.0°C, h ≈ 64.3 kJ/kg
+        let air = state_from_relative_humidity(30.0, 50.0, p);
+        assert!((air.dew_point - 18.4).abs() < 0.1);
+        assert!((air.wet_bulb - 22.0).abs() < 0.2);
+        assert!((air.enthalpy - 64.3).abs() < 0.3);
+        assert!((air.relative_humidity - 50.0).abs() < 1e-9);
+
+        // Saturated air: dry bulb, wet bulb and dew point coincide
+        let saturated = state_from_relative_humidity(15.0, 100.0, p);
+        assert!((saturated.wet_bulb - 15.0).abs() < 1e-6);
+        assert!((saturated.dew_point - 15.0).abs() < 1e-6);
+
+        // Equal flows of the same air mix to that air
+        let (mixed, mass_a, mass_b) = mix(&air, 1.0, &air, 1.0, p);
+        assert!((mixed.dry_bulb - 30.0).abs() < 1e-9);
+        assert!((mass_a - mass_b).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_elevator_traffic() {
+        use elevator_values::*;
+
+        // 10 floors, 10.4 passengers (13-person car at 80%): S = 10(1 − 0.9^10.4) ≈ 6.66, H ≈ 9.54
+        assert!((probable_stops(10.0, 10.4) - 6.657).abs() < 0.001);
+        assert!((highest_reversal_floor(10, 10.4) - 9.537).abs() < 0.001);
+        // One floor is always the top floor
+        assert_eq!(highest_reversal_floor(1, 8.0), 1.0);
+
+        // 3.3 m at 1.6 m/s and 1 m/s²: 3.3/1.6 + 1.6 ≈ 3.66 s; short floors never reach rated speed
+        assert!((single_floor_flight_time(3.3, 1.6, 1.0) - 3.6625).abs() < 1e-9);
+        assert!((single_floor_flight_time(3.3, 2.5, 1.0) - 2.0 * 3.3f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sprinkler_values() {
+        use sprinkler_values::*;
+
+        assert_eq!(lookup("Ordinary 2").unwrap().density, 8.1);
+        assert!(lookup("storage").is_none());
+        assert_eq!(PIPE_SIZES[nearest_pipe_size(27.0)].0, "1\"");
+        assert_eq!(PIPE_SIZES[nearest_pipe_size(200.0)].0, "4\"");
+
+        // 380 L/min (100 gpm) in 52.5 mm (2") steel pipe: about 0.0214 bar/m (0.094 psi/ft)
+        assert!((friction_loss(380.0, 52.5, 120.0) - 0.0214).abs() < 0.0002);
+    }
+
     #[test]
     fn test_lmtd() {
         use helpers::*;
@@ -2,7 +2,9 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{PIPING_HIGH_VELOCITY, PIPING_LOW_VELOCITY},
 };
+use crate::utils::conversions::essential::pressure::pa_to_kpa;
 use async_trait::async_trait;
 
 use super::helpers::*;
@@ -126,14 +128,15 @@ impl EngineerCalculator for PipingPressureDropCalculator {
         self.get_additional_param(params, "viscosity", Some(1e-6), Some(1.0))?;
         self.get_additional_param(params, "roughness", Some(1e-6), Some(0.001))?;
 
-        if let Some(material) = &params.material {
-            if material.density < Some(500.0) || material.density > Some(2000.0) {
-                return Err(EngineeringError::InvalidParameter {
-                    parameter: "density".to_string(),
-                    value: material.density.expect("No density provided, defaulting to water value").to_string(),
-                    reason: "Unusual fluid density".to_string(),
-                });
-            }
+        // No density means water, which is always in range
+        if let Some(density) = params.material.as_ref().and_then(|m| m.density)
+            && !(500.0..=2000.0).contains(&density)
+        {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: "density".to_string(),
+                value: density.to_string(),
+                reason: "Unusual fluid density".to_string(),
+            });
         }
 
         if length / diameter > 10000.0 {
@@ -150,30 +153,56 @@ impl EngineerCalculator for PipingPressureDropCalculator {
         let length = params.dimensions.get("length").copied().unwrap_or(100.0);
         let diameter = params.dimensions.get("diameter").copied().unwrap_or(0.1);
         let flow_rate = self.get_additional_param(&params, "flow_rate", None, None)?;
-        let density = params.material.as_ref().map(|m| m.density).unwrap_or(Some(WATER_DENSITY));
+        let density = params.material.as_ref().and_then(|m| m.density).unwrap_or(WATER_DENSITY);
         let viscosity = params.additional.as_ref().and_then(|a| a.get("viscosity").copied()).unwrap_or(WATER_VISCOSITY);
         let roughness = params.additional.as_ref().and_then(|a| a.get("roughness").copied()).unwrap_or(0.00015);
 
+        let mut trace = CalculationTrace::new();
+
         let velocity = flow_rate / (std::f64::consts::PI * (diameter / 2.0).powi(2));
-        let re = reynolds_number(velocity, diameter, density.expect("No density provided, defaulting to water value"), viscosity);
+        trace.step(
+            "formulas.piping_pressure_drop.velocity",
+            &[("flow_rate", flow_rate), ("diameter", diameter)],
+            velocity,
+            "m/s",
+        );
+        let re = reynolds_number(velocity, diameter, density, viscosity);
+        trace.step(
+            "formulas.piping_pressure_drop.reynolds_number",
+            &[("velocity", velocity), ("diameter", diameter), ("density", density), ("viscosity", viscosity)],
+            re,
+            "dimensionless",
+        );
 
         let friction = if re < 2300.0 {
             64.0 / re // Laminar
         } else {
             friction_factor_turbulent(re, roughness, diameter)
         };
-
-        let pressure_drop = pressure_drop_pipe(friction, length, diameter, velocity, density.expect("No density provided, defaulting to water value")) / 1000.0; // kPa
+        trace.step(
+            "formulas.piping_pressure_drop.friction_factor",
+            &[("reynolds", re), ("roughness", roughness), ("diameter", diameter)],
+            friction,
+            "dimensionless",
+        );
+
+        let pressure_drop = pa_to_kpa(pressure_drop_pipe(friction, length, diameter, velocity, density)).await;
+        trace.step(
+            "formulas.piping_pressure_drop.darcy_weisbach",
+            &[("friction", friction), ("length", length), ("diameter", diameter), ("velocity", velocity), ("density", density)],
+            pressure_drop,
+            "kPa",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if velocity > 3.0 {
-            warnings.push(format!("High velocity ({:.1} m/s). Risk of erosion.", velocity));
+            warnings.push(PIPING_HIGH_VELOCITY.warn(format!("High velocity ({:.1} m/s). Risk of erosion.", velocity)));
             recommendations.push("Increase pipe diameter to reduce velocity".to_string());
         } else if velocity < 0.5 {
-            warnings.push(format!("Low velocity ({:.1} m/s). Risk of settling.", velocity));
+            warnings.push(PIPING_LOW_VELOCITY.warn(format!("Low velocity ({:.1} m/s). Risk of settling.", velocity)));
             recommendations.push("Decrease pipe diameter or increase flow".to_string());
         }
 
@@ -200,8 +229,8 @@ impl EngineerCalculator for PipingPressureDropCalculator {
             calculation_type: "piping_pressure_drop".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -210,6 +239,8 @@ impl EngineerCalculator for PipingPressureDropCalculator {
                 design_code_used: "ASME B31.3".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -2,7 +2,9 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{PUMP_HIGH_VISCOSITY, PUMP_INSUFFICIENT_NPSH},
 };
+use crate::utils::conversions::essential::flow::m3h_to_m3s;
 use async_trait::async_trait;
 
 use super::pump_hydraulics::*;
@@ -127,14 +129,15 @@ impl EngineerCalculator for PumpSizingCalculator {
         self.get_additional_param(params, "pump_efficiency", Some(50.0), Some(90.0))?;
         self.get_additional_param(params, "npsh_available", Some(0.0), Some(50.0))?;
 
-        if let Some(material) = &params.material {
-            if material.density < Some(500.0) || material.density > Some(2000.0) {
-                return Err(EngineeringError::InvalidParameter {
-                    parameter: "density".to_string(),
-                    value: material.density.expect("No density provided, defaulting to water value").to_string(),
-                    reason: "Unusual fluid density - verify".to_string(),
-                });
-            }
+        // No density means water, which is always in range
+        if let Some(density) = params.material.as_ref().and_then(|m| m.density)
+            && !(500.0..=2000.0).contains(&density)
+        {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: "density".to_string(),
+                value: density.to_string(),
+                reason: "Unusual fluid density - verify".to_string(),
+            });
         }
 
         Ok(())
@@ -143,28 +146,54 @@ impl EngineerCalculator for PumpSizingCalculator {
     async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
         let flow_rate_m3h = self.get_additional_param(&params, "flow_rate", None, None)?;
         let total_head = self.get_additional_param(&params, "total_head", None, None)?;
-        let density = params.material.as_ref().map(|m| m.density).unwrap_or(Some(WATER_DENSITY));
+        let density = params.material.as_ref().and_then(|m| m.density).unwrap_or(WATER_DENSITY);
         let viscosity_cp = params.additional.as_ref().and_then(|a| a.get("viscosity").copied()).unwrap_or(1.0);
         let pump_eff = params.additional.as_ref().and_then(|a| a.get("pump_efficiency").copied()).unwrap_or(EFF_MEDIUM_CENTRIFUGAL) / 100.0;
         let npsha = params.additional.as_ref().and_then(|a| a.get("npsh_available").copied()).unwrap_or(5.0);
 
-        let flow_m3s = flow_rate_m3h / 3600.0;
-        let hydraulic_power = hydraulic_power_kw(flow_m3s, total_head, density.expect("No density provided, defaulting to water value"));
+        let mut trace = CalculationTrace::new();
+
+        let flow_m3s = m3h_to_m3s(flow_rate_m3h).await;
+        let hydraulic_power = hydraulic_power_kw(flow_m3s, total_head, density);
+        trace.step(
+            "formulas.pump_sizing.hydraulic_power",
+            &[("flow", flow_m3s), ("total_head", total_head), ("density", density)],
+            hydraulic_power,
+            "kW",
+        );
         let brake_power = hydraulic_power / pump_eff;
+        trace.step(
+            "formulas.pump_sizing.brake_power",
+            &[("hydraulic_power", hydraulic_power), ("efficiency", pump_eff)],
+            brake_power,
+            "kW",
+        );
         let npshr_approx = 2.0 + (flow_rate_m3h / 100.0).powf(0.5); // Rough estimate
+        trace.step(
+            "formulas.pump_sizing.npsh_required",
+            &[("flow_rate", flow_rate_m3h)],
+            npshr_approx,
+            "m",
+        );
         let npsh_margin = npsha - npshr_approx;
+        trace.step(
+            "formulas.pump_sizing.npsh_margin",
+            &[("npsha", npsha), ("npshr", npshr_approx)],
+            npsh_margin,
+            "m",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if npsh_margin < 1.0 {
-            warnings.push(format!("Insufficient NPSH margin ({:.1}m). Risk of cavitation.", npsh_margin));
+            warnings.push(PUMP_INSUFFICIENT_NPSH.warn(format!("Insufficient NPSH margin ({:.1}m). Risk of cavitation.", npsh_margin)));
             recommendations.push("Increase suction head or reduce vapor pressure".to_string());
         }
 
         if viscosity_cp > 100.0 {
-            warnings.push(format!("High viscosity ({:.1} cP). Efficiency reduced - consider viscosity correction.", viscosity_cp));
+            warnings.push(PUMP_HIGH_VISCOSITY.warn(format!("High viscosity ({:.1} cP). Efficiency reduced - consider viscosity correction.", viscosity_cp)));
             recommendations.push("Use viscosity correction charts for accurate sizing".to_string());
         }
 
@@ -188,8 +217,8 @@ impl EngineerCalculator for PumpSizingCalculator {
             calculation_type: "pump_sizing".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -198,6 +227,8 @@ impl EngineerCalculator for PumpSizingCalculator {
                 design_code_used: "API 610".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::REFRIGERATION_LOW_COP,
 };
 use async_trait::async_trait;
 
@@ -124,6 +125,8 @@ impl EngineerCalculator for RefrigerationCycleCalculator {
         let cooling_capacity = self.get_additional_param(&params, "cooling_capacity", None, None)?;
         let isentropic_eff = params.additional.as_ref().and_then(|a| a.get("isentropic_eff").copied()).unwrap_or(80.0) / 100.0;
 
+        let mut trace = CalculationTrace::new();
+
         // Simplified cycle analysis (assume R134a properties)
         let p_evap = 2.93; // bar at -10°C
         let p_cond = 10.16; // bar at 40°C
@@ -131,18 +134,48 @@ impl EngineerCalculator for RefrigerationCycleCalculator {
         let h_liquid = 250.0; // kJ/kg after condenser
         let h_isentropic = 430.0; // kJ/kg isentropic compression
         let h_actual = h_evap + (h_isentropic - h_evap) / isentropic_eff;
+        trace.step(
+            "formulas.refrigeration_cycle.actual_discharge_enthalpy",
+            &[("h_evap", h_evap), ("h_isentropic", h_isentropic), ("isentropic_eff", isentropic_eff)],
+            h_actual,
+            "kJ/kg",
+        );
 
         let cop = (h_evap - h_liquid) / (h_actual - h_evap);
+        trace.step(
+            "formulas.refrigeration_cycle.cop",
+            &[("h_evap", h_evap), ("h_liquid", h_liquid), ("h_actual", h_actual)],
+            cop,
+            "dimensionless",
+        );
         let work_input = cooling_capacity / cop;
+        trace.step(
+            "formulas.refrigeration_cycle.work_input",
+            &[("cooling_capacity", cooling_capacity), ("cop", cop)],
+            work_input,
+            "kW",
+        );
         let heat_rejection = cooling_capacity + work_input;
+        trace.step(
+            "formulas.refrigeration_cycle.heat_rejection",
+            &[("cooling_capacity", cooling_capacity), ("work_input", work_input)],
+            heat_rejection,
+            "kW",
+        );
         let mass_flow = cooling_capacity / (h_evap - h_liquid);
+        trace.step(
+            "formulas.refrigeration_cycle.mass_flow",
+            &[("cooling_capacity", cooling_capacity), ("h_evap", h_evap), ("h_liquid", h_liquid)],
+            mass_flow,
+            "kg/s",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if cop < 3.0 {
-            warnings.push(format!("Low COP ({:.2}). Optimize temperatures.", cop));
+            warnings.push(REFRIGERATION_LOW_COP.warn(format!("Low COP ({:.2}). Optimize temperatures.", cop)));
             recommendations.push("Reduce condenser temperature or increase evaporator temp".to_string());
         }
 
@@ -165,8 +198,8 @@ impl EngineerCalculator for RefrigerationCycleCalculator {
             calculation_type: "refrigeration_cycle".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -175,6 +208,8 @@ impl EngineerCalculator for RefrigerationCycleCalculator {
                 design_code_used: "ASHRAE Fundamentals".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
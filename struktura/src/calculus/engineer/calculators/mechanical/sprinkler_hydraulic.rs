@@ -0,0 +1,577 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{
+        SPRINKLER_COVERAGE_EXCEEDED, SPRINKLER_HIGH_VELOCITY, SPRINKLER_PIPE_SCHEDULE_EXCEEDED,
+        SPRINKLER_SUPPLY_INSUFFICIENT,
+    },
+};
+use async_trait::async_trait;
+use std::f64::consts::PI;
+
+use super::sprinkler_values::{
+    friction_loss, lookup, nearest_pipe_size, HazardClass, ELEVATION_PRESSURE, HAZARD_CLASSES, HAZEN_WILLIAMS_STEEL,
+    MAX_VELOCITY, PIPE_SIZES,
+};
+
+pub struct SprinklerHydraulicCalculator;
+
+impl ParameterValidator for SprinklerHydraulicCalculator {
+    fn calculator_id(&self) -> &str {
+        "sprinkler_hydraulic"
+    }
+}
+
+impl SprinklerHydraulicCalculator {
+    /// Hazard class design criteria, ordinary hazard group 1 when omitted
+    fn hazard(&self, params: &EngineeringParameters) -> EngineeringResult<&'static HazardClass> {
+        let name = params
+            .extended_parameters
+            .as_ref()
+            .and_then(|e| e.get("hazard_class"))
+            .and_then(|v| v.as_string())
+            .unwrap_or("ordinary_1");
+        lookup(name).ok_or_else(|| EngineeringError::InvalidParameter {
+            parameter: "hazard_class".to_string(),
+            value: name.to_string(),
+            reason: format!(
+                "Expected one of {}",
+                HAZARD_CLASSES.iter().map(|h| h.name).collect::<Vec<_>>().join(", ")
+            ),
+        })
+    }
+
+    /// Water supply test as (static, residual, flow), when all three are given
+    fn supply(&self, params: &EngineeringParameters) -> EngineeringResult<Option<(f64, f64, f64)>> {
+        let names = ["supply_static_pressure", "supply_residual_pressure", "supply_flow"];
+        let given = names
+            .iter()
+            .filter(|n| params.additional.as_ref().is_some_and(|a| a.contains_key(**n)))
+            .count();
+        if given == 0 {
+            return Ok(None);
+        }
+        if given < names.len() {
+            return Err(EngineeringError::MissingParameter {
+                parameter: names.join(", "),
+                calculator: self.calculator_id().to_string(),
+            });
+        }
+        let static_pressure = self.get_additional_param(params, names[0], Some(50.0), Some(2500.0))?;
+        let residual = self.get_additional_param(params, names[1], Some(0.0), Some(2500.0))?;
+        let flow = self.get_additional_param(params, names[2], Some(10.0), Some(50000.0))?;
+        if residual >= static_pressure {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: names[1].to_string(),
+                value: residual.to_string(),
+                reason: "Residual pressure must be below the static pressure".to_string(),
+            });
+        }
+        Ok(Some((static_pressure, residual, flow)))
+    }
+}
+
+fn velocity(flow_lpm: f64, diameter_mm: f64) -> f64 {
+    (flow_lpm / 60_000.0) / (PI * (diameter_mm / 1000.0).powi(2) / 4.0)
+}
+
+#[async_trait]
+impl EngineerCalculator for SprinklerHydraulicCalculator {
+    fn id(&self) -> &str {
+        "sprinkler_hydraulic"
+    }
+
+    fn name(&self) -> &str {
+        "Fire Sprinkler Hydraulic Calculation"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Mechanical
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("sprinkler_hydraulic", "Fire Sprinkler Hydraulic Calculation")
+            .category("mechanical")
+            .description("NFPA 13 density/area demand of the most remote design area on a tree system: sprinkler flows, required pressure and flow at the source, and a pipe schedule check")
+            .design_code("NFPA 13")
+            .requires_pe()
+            .parameter(ParameterMetadata {
+                name: "Hazard Class".to_string(),
+                path: "extended_parameters.hazard_class".to_string(),
+                data_type: ParameterType::String,
+                unit: "".to_string(),
+                description: "Occupancy hazard classification; ordinary_1 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec![HAZARD_CLASSES.iter().map(|h| h.name).collect::<Vec<_>>().join(", ")]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Design Density".to_string(),
+                path: "additional.density".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm/min".to_string(),
+                description: "Overrides the hazard class density".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(2.0),
+                max_value: Some(40.0),
+                typical_range: Some((4.1, 16.3)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Design Area".to_string(),
+                path: "additional.design_area".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m²".to_string(),
+                description: "Overrides the hazard class area of operation".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(50.0),
+                max_value: Some(500.0),
+                typical_range: Some((139.0, 372.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Sprinkler Spacing".to_string(),
+                path: "dimensions.sprinkler_spacing".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Distance between sprinklers along a branch line".to_string(),
+                required: false,
+                default_value: Some(3.5),
+                min_value: Some(1.8),
+                max_value: Some(4.6),
+                typical_range: Some((3.0, 4.6)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Branch Line Spacing".to_string(),
+                path: "dimensions.branch_spacing".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Distance between branch lines along the cross main".to_string(),
+                required: false,
+                default_value: Some(3.4),
+                min_value: Some(1.8),
+                max_value: Some(4.6),
+                typical_range: Some((3.0, 4.6)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Branch Line Diameter".to_string(),
+                path: "dimensions.branch_diameter".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm".to_string(),
+                description: "Internal diameter of the branch lines (26.6 mm for 1\" schedule 40)".to_string(),
+                required: false,
+                default_value: Some(40.9),
+                min_value: Some(20.0),
+                max_value: Some(110.0),
+                typical_range: Some((26.6, 52.5)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Main Diameter".to_string(),
+                path: "dimensions.main_diameter".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm".to_string(),
+                description: "Internal diameter of the cross main and feed main".to_string(),
+                required: false,
+                default_value: Some(77.9),
+                min_value: Some(25.0),
+                max_value: Some(260.0),
+                typical_range: Some((62.7, 154.1)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Feed Main Length".to_string(),
+                path: "dimensions.feed_length".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Equivalent pipe length, fittings included, from the source to the design area".to_string(),
+                required: false,
+                default_value: Some(30.0),
+                min_value: Some(0.0),
+                max_value: Some(1000.0),
+                typical_range: Some((10.0, 150.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Elevation".to_string(),
+                path: "additional.elevation".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Height of the design area above the source".to_string(),
+                required: false,
+                default_value: Some(0.0),
+                min_value: Some(-50.0),
+                max_value: Some(200.0),
+                typical_range: Some((0.0, 30.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Sprinkler K-Factor".to_string(),
+                path: "additional.k_factor".to_string(),
+                data_type: ParameterType::Number,
+                unit: "L/min/bar^0.5".to_string(),
+                description: "Sprinkler discharge coefficient (80 for K5.6)".to_string(),
+                required: false,
+                default_value: Some(80.0),
+                min_value: Some(40.0),
+                max_value: Some(400.0),
+                typical_range: Some((57.0, 115.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Minimum Sprinkler Pressure".to_string(),
+                path: "additional.min_pressure".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kPa".to_string(),
+                description: "Lowest operating pressure of any sprinkler (NFPA 13: 48 kPa)".to_string(),
+                required: false,
+                default_value: Some(48.0),
+                min_value: Some(48.0),
+                max_value: Some(700.0),
+                typical_range: Some((48.0, 100.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Hazen-Williams C".to_string(),
+                path: "additional.hazen_williams_c".to_string(),
+                data_type: ParameterType::Number,
+                unit: "".to_string(),
+                description: "Pipe friction coefficient (120 for wet steel, 150 for CPVC and copper)".to_string(),
+                required: false,
+                default_value: Some(HAZEN_WILLIAMS_STEEL),
+                min_value: Some(80.0),
+                max_value: Some(150.0),
+                typical_range: Some((100.0, 150.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Supply Static Pressure".to_string(),
+                path: "additional.supply_static_pressure".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kPa".to_string(),
+                description: "Flow test static pressure; with the residual pressure and flow, checks the supply".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(50.0),
+                max_value: Some(2500.0),
+                typical_range: Some((300.0, 800.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Supply Residual Pressure".to_string(),
+                path: "additional.supply_residual_pressure".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kPa".to_string(),
+                description: "Flow test residual pressure at the test flow".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(2500.0),
+                typical_range: Some((200.0, 600.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Supply Test Flow".to_string(),
+                path: "additional.supply_flow".to_string(),
+                data_type: ParameterType::Number,
+                unit: "L/min".to_string(),
+                description: "Flow at the residual pressure".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(10.0),
+                max_value: Some(50000.0),
+                typical_range: Some((2000.0, 8000.0)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Advanced)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.hazard(params)?;
+        self.optional_param(params, "density", 0.0, 2.0, 40.0)?;
+        self.optional_param(params, "design_area", 0.0, 50.0, 500.0)?;
+        self.optional_dimension(params, "sprinkler_spacing", 3.5, 1.8, 4.6)?;
+        self.optional_dimension(params, "branch_spacing", 3.4, 1.8, 4.6)?;
+        self.optional_dimension(params, "branch_diameter", 40.9, 20.0, 110.0)?;
+        self.optional_dimension(params, "main_diameter", 77.9, 25.0, 260.0)?;
+        self.optional_dimension(params, "feed_length", 30.0, 0.0, 1000.0)?;
+        self.optional_param(params, "elevation", 0.0, -50.0, 200.0)?;
+        self.optional_param(params, "k_factor", 80.0, 40.0, 400.0)?;
+        self.optional_param(params, "min_pressure", 48.0, 48.0, 700.0)?;
+        self.optional_param(params, "hazen_williams_c", HAZEN_WILLIAMS_STEEL, 80.0, 150.0)?;
+        self.supply(params)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let hazard = self.hazard(&params)?;
+        let density = self.optional_param(&params, "density", hazard.density, 2.0, 40.0)?;
+        let design_area = self.optional_param(&params, "design_area", hazard.design_area, 50.0, 500.0)?;
+        let spacing = self.optional_dimension(&params, "sprinkler_spacing", 3.5, 1.8, 4.6)?;
+        let branch_spacing = self.optional_dimension(&params, "branch_spacing", 3.4, 1.8, 4.6)?;
+        let branch_diameter = self.optional_dimension(&params, "branch_diameter", 40.9, 20.0, 110.0)?;
+        let main_diameter = self.optional_dimension(&params, "main_diameter", 77.9, 25.0, 260.0)?;
+        let feed_length = self.optional_dimension(&params, "feed_length", 30.0, 0.0, 1000.0)?;
+        let elevation = self.optional_param(&params, "elevation", 0.0, -50.0, 200.0)?;
+        let k = self.optional_param(&params, "k_factor", 80.0, 40.0, 400.0)?;
+        let min_pressure = self.optional_param(&params, "min_pressure", 48.0, 48.0, 700.0)?;
+        let c = self.optional_param(&params, "hazen_williams_c", HAZEN_WILLIAMS_STEEL, 80.0, 150.0)?;
+        let supply = self.supply(&params)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut compliance_notes = Vec::new();
+
+        // Design area layout: its long side, 1.2√A, runs along the branch lines
+        let coverage = spacing * branch_spacing;
+        let per_branch = (1.2 * design_area.sqrt() / spacing).ceil().max(1.0);
+        let sprinklers = (design_area / coverage).ceil();
+        let branches = (sprinklers / per_branch).ceil();
+        trace.step(
+            "formulas.sprinkler_hydraulic.sprinklers_per_branch",
+            &[("design_area", design_area), ("spacing", spacing)],
+            per_branch,
+            "sprinklers",
+        );
+
+        // Most remote sprinkler: the density over its coverage, at no less than the minimum pressure
+        let remote_flow = trace.step(
+            "formulas.sprinkler_hydraulic.remote_sprinkler_flow",
+            &[("density", density), ("coverage", coverage), ("k_factor", k), ("min_pressure", min_pressure)],
+            (density * coverage).max(k * (min_pressure / 100.0).sqrt()),
+            "L/min",
+        );
+        let remote_pressure = (remote_flow / k).powi(2);
+
+        // Walk the remote branch line towards the cross main
+        let mut pressure = remote_pressure;
+        let mut branch_flow = remote_flow;
+        for _ in 1..per_branch as u32 {
+            pressure += friction_loss(branch_flow, branch_diameter, c) * spacing;
+            branch_flow += k * pressure.sqrt();
+        }
+        let branch_pressure = pressure;
+        trace.step(
+            "formulas.sprinkler_hydraulic.branch_line_flow",
+            &[("remote_flow", remote_flow), ("per_branch", per_branch), ("branch_diameter", branch_diameter), ("c", c)],
+            branch_flow,
+            "L/min",
+        );
+
+        // Further branch lines are hydraulically alike and flow as one equivalent K-factor
+        let branch_k = branch_flow / branch_pressure.sqrt();
+        let mut main_flow = branch_flow;
+        for _ in 1..branches as u32 {
+            pressure += friction_loss(main_flow, main_diameter, c) * branch_spacing;
+            main_flow += branch_k * pressure.sqrt();
+        }
+        let cross_main_pressure = pressure;
+        let sprinkler_demand = trace.step(
+            "formulas.sprinkler_hydraulic.sprinkler_demand",
+            &[("branch_flow", branch_flow), ("branches", branches), ("branch_k", branch_k)],
+            main_flow,
+            "L/min",
+        );
+
+        let feed_loss = friction_loss(sprinkler_demand, main_diameter, c) * feed_length;
+        let source_pressure = trace.step(
+            "formulas.sprinkler_hydraulic.source_pressure",
+            &[("cross_main_pressure", cross_main_pressure * 100.0), ("feed_loss", feed_loss * 100.0), ("elevation", elevation)],
+            (cross_main_pressure + feed_loss + ELEVATION_PRESSURE * elevation) * 100.0,
+            "kPa",
+        );
+        let total_demand = sprinkler_demand + hazard.hose_allowance;
+        let volume = total_demand * hazard.duration / 1000.0;
+
+        // Coverage limit
+        if coverage > hazard.max_coverage {
+            warnings.push(SPRINKLER_COVERAGE_EXCEEDED.warn(format!(
+                "Each sprinkler covers {:.1} m², more than the {:.1} m² allowed for {} hazard",
+                coverage, hazard.max_coverage, hazard.name
+            )));
+            recommendations.push("Reduce sprinkler or branch line spacing".to_string());
+        }
+
+        // Pipe schedule check: sprinklers supplied against the schedule limit for each pipe size
+        let flowing = per_branch * branches;
+        for (pipe, diameter, supplied) in [("Branch line", branch_diameter, per_branch), ("Main", main_diameter, flowing)] {
+            let size = nearest_pipe_size(diameter);
+            let limit = hazard.pipe_schedule[size];
+            if supplied > limit as f64 {
+                warnings.push(SPRINKLER_PIPE_SCHEDULE_EXCEEDED.warn(format!(
+                    "{} ({}) supplies {:.0} sprinklers; the {} hazard pipe schedule allows {}",
+                    pipe, PIPE_SIZES[size].0, supplied, hazard.name, limit
+                )));
+            }
+        }
+        let branch_velocity = velocity(branch_flow, branch_diameter);
+        let main_velocity = velocity(sprinkler_demand, main_diameter);
+        if branch_velocity.max(main_velocity) > MAX_VELOCITY {
+            warnings.push(SPRINKLER_HIGH_VELOCITY.warn(format!(
+                "Pipe velocity reaches {:.1} m/s (branch line {:.1} m/s, main {:.1} m/s)",
+                branch_velocity.max(main_velocity),
+                branch_velocity,
+                main_velocity
+            )));
+            recommendations.push("Upsize the piping to lower velocity and friction loss".to_string());
+        }
+
+        let mut results = vec![
+            EngineeringResultItem::new("Design Density", density, "mm/min").with_format(format!("{:.1} mm/min", density)),
+            EngineeringResultItem::new("Design Area", design_area, "m²").with_format(format!("{:.0} m²", design_area)),
+            EngineeringResultItem::new("Coverage per Sprinkler", coverage, "m²").with_format(format!("{:.1} m²", coverage)),
+            EngineeringResultItem::new("Sprinklers per Branch Line", per_branch, "sprinklers")
+                .with_format(format!("{:.0}", per_branch)),
+            EngineeringResultItem::new("Branch Lines", branches, "lines").with_format(format!("{:.0}", branches)),
+            EngineeringResultItem::new("Sprinklers Flowing", flowing, "sprinklers").with_format(format!("{:.0}", flowing)),
+            EngineeringResultItem::new("Remote Sprinkler Flow", remote_flow, "L/min")
+                .with_format(format!("{:.1} L/min", remote_flow)),
+            EngineeringResultItem::new("Remote Sprinkler Pressure", remote_pressure * 100.0, "kPa")
+                .with_format(format!("{:.0} kPa", remote_pressure * 100.0)),
+            EngineeringResultItem::new("Branch Line Flow", branch_flow, "L/min")
+                .with_format(format!("{:.0} L/min", branch_flow)),
+            EngineeringResultItem::new("Branch Line Pressure", branch_pressure * 100.0, "kPa")
+                .with_format(format!("{:.0} kPa", branch_pressure * 100.0)),
+            EngineeringResultItem::new("Sprinkler Demand", sprinkler_demand, "L/min")
+                .critical()
+                .with_format(format!("{:.0} L/min", sprinkler_demand)),
+            EngineeringResultItem::new("Overdischarge Ratio", sprinkler_demand / (density * design_area), "")
+                .with_format(format!("{:.2}", sprinkler_demand / (density * design_area))),
+            EngineeringResultItem::new("Required Pressure at Source", source_pressure, "kPa")
+                .critical()
+                .with_format(format!("{:.0} kPa", source_pressure)),
+            EngineeringResultItem::new("Hose Stream Allowance", hazard.hose_allowance, "L/min")
+                .with_format(format!("{:.0} L/min", hazard.hose_allowance)),
+            EngineeringResultItem::new("Total Water Demand", total_demand, "L/min")
+                .critical()
+                .with_format(format!("{:.0} L/min", total_demand)),
+            EngineeringResultItem::new("Supply Duration", hazard.duration, "min").with_format(format!("{:.0} min", hazard.duration)),
+            EngineeringResultItem::new("Required Water Volume", volume, "m³").with_format(format!("{:.0} m³", volume)),
+            EngineeringResultItem::new("Branch Line Velocity", branch_velocity, "m/s")
+                .with_format(format!("{:.1} m/s", branch_velocity)),
+            EngineeringResultItem::new("Main Velocity", main_velocity, "m/s").with_format(format!("{:.1} m/s", main_velocity)),
+        ];
+
+        // Supply curve P = Ps − (Ps − Pr)(Q / Qr)^1.85 at the total demand
+        if let Some((static_pressure, residual, test_flow)) = supply {
+            let available = static_pressure - (static_pressure - residual) * (total_demand / test_flow).powf(1.85);
+            trace.step(
+                "formulas.sprinkler_hydraulic.available_pressure",
+                &[("static", static_pressure), ("residual", residual), ("test_flow", test_flow), ("demand", total_demand)],
+                available,
+                "kPa",
+            );
+            let margin = available - source_pressure;
+            if margin < 0.0 {
+                warnings.push(SPRINKLER_SUPPLY_INSUFFICIENT.warn(format!(
+                    "Supply delivers {:.0} kPa at {:.0} L/min against {:.0} kPa required",
+                    available, total_demand, source_pressure
+                )));
+                recommendations.push("Add a fire pump, enlarge the piping or reduce the demand".to_string());
+            } else if margin < 35.0 {
+                recommendations.push("Supply margin is under 35 kPa; many authorities expect 35-70 kPa".to_string());
+            }
+            results.extend([
+                EngineeringResultItem::new("Available Supply Pressure", available, "kPa")
+                    .with_format(format!("{:.0} kPa", available)),
+                EngineeringResultItem::new("Supply Margin", margin, "kPa")
+                    .critical()
+                    .with_format(format!("{:.0} kPa", margin)),
+            ]);
+        }
+
+        compliance_notes.push(format!(
+            "NFPA 13 density/area method, {} hazard: {:.1} mm/min over {:.0} m²",
+            hazard.name, density, design_area
+        ));
+        compliance_notes.push("Tree system with identical branch lines; fittings are only counted through the equivalent feed main length".to_string());
+        compliance_notes.push("Hose allowance and duration are the lower NFPA 13 values; confirm with the authority having jurisdiction".to_string());
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "sprinkler_hydraulic".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "NFPA 13".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_sprinkler_hydraulic_demand() {
+        let params = |additional: serde_json::Value, hazard: &str| -> EngineeringParameters {
+            serde_json::from_value(serde_json::json!({
+                "dimensions": {},
+                "additional": additional,
+                "extended_parameters": { "hazard_class": { "type": "String", "value": hazard } },
+            }))
+            .unwrap()
+        };
+
+        // Ordinary hazard 1 on a 3.5 m x 3.4 m grid: 6.1 mm/min over 139 m²
+        let ordinary = execute("sprinkler_hydraulic", params(serde_json::json!({}), "ordinary_1")).await.unwrap();
+        assert_eq!(value(&ordinary, "Sprinklers per Branch Line"), 5.0);
+        assert_eq!(value(&ordinary, "Branch Lines"), 3.0);
+        let remote = 6.1 * 3.5 * 3.4;
+        assert!((value(&ordinary, "Remote Sprinkler Flow") - remote).abs() < 1e-9);
+        assert!((value(&ordinary, "Remote Sprinkler Pressure") - (remote / 80.0).powi(2) * 100.0).abs() < 1e-9);
+        let demand = value(&ordinary, "Sprinkler Demand");
+        assert!(demand > 6.1 * 139.0);
+        assert!((value(&ordinary, "Total Water Demand") - demand - 950.0).abs() < 1e-9);
+        assert!(value(&ordinary, "Required Pressure at Source") > value(&ordinary, "Branch Line Pressure"));
+        assert!(warning_codes(&ordinary).is_empty());
+
+        // Raising the design area 10 m adds about 98 kPa of static head
+        let raised = execute("sprinkler_hydraulic", params(serde_json::json!({ "elevation": 10.0 }), "ordinary_1"))
+            .await
+            .unwrap();
+        let head = value(&raised, "Required Pressure at Source") - value(&ordinary, "Required Pressure at Source");
+        assert!((head - 98.1).abs() < 1e-9);
+
+        // Extra hazard limits coverage to 9.3 m² and 1-1/2" pipe to five sprinklers
+        let extra = execute("sprinkler_hydraulic", params(serde_json::json!({}), "extra_1")).await.unwrap();
+        let warnings = warning_codes(&extra);
+        assert!(warnings.contains(&"sprinkler_hydraulic.coverage_exceeded".to_string()));
+        assert!(warnings.contains(&"sprinkler_hydraulic.pipe_schedule_exceeded".to_string()));
+
+        let weak = execute("sprinkler_hydraulic",
+                params(
+                    serde_json::json!({ "supply_static_pressure": 300.0, "supply_residual_pressure": 150.0, "supply_flow": 1000.0 }),
+                    "ordinary_1",
+                ),
+            )
+            .await
+            .unwrap();
+        assert!(value(&weak, "Supply Margin") < 0.0);
+        assert!(warning_codes(&weak).contains(&"sprinkler_hydraulic.supply_insufficient".to_string()));
+
+        let partial = params(serde_json::json!({ "supply_static_pressure": 300.0 }), "ordinary_1");
+        assert!(execute("sprinkler_hydraulic", partial).await.is_err());
+        assert!(execute("sprinkler_hydraulic", params(serde_json::json!({}), "storage")).await.is_err());
+    }
+}
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{THERMAL_LARGE_TEMPERATURE_CHANGE, THERMAL_SIGNIFICANT_EXPANSION},
 };
 use async_trait::async_trait;
 
@@ -127,6 +128,8 @@ impl EngineerCalculator for ThermalExpansionCalculator {
         let alpha = params.material.as_ref().and_then(|m| m.thermal_expansion).unwrap_or(12e-6);
         let exp_type = params.additional.as_ref().and_then(|a| a.get("expansion_type")).map(|v| v.to_string()).unwrap_or("linear".to_string());
 
+        let mut trace = CalculationTrace::new();
+
         let factor = match exp_type.as_str() {
             "linear" => 1.0,
             "area" => 2.0,
@@ -135,18 +138,24 @@ impl EngineerCalculator for ThermalExpansionCalculator {
         };
 
         let delta_l = length * alpha * delta_t * factor;
+        trace.step(
+            "formulas.thermal_expansion.expansion",
+            &[("length", length), ("alpha", alpha), ("delta_t", delta_t), ("factor", factor)],
+            delta_l,
+            "m",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if delta_t.abs() > 200.0 {
-            warnings.push(format!("Large temperature change ({:.1}°C). Verify material limits.", delta_t));
+            warnings.push(THERMAL_LARGE_TEMPERATURE_CHANGE.warn(format!("Large temperature change ({:.1}°C). Verify material limits.", delta_t)));
             recommendations.push("Consider non-linear expansion at extreme temps".to_string());
         }
 
         if delta_l / length > 0.01 {
-            warnings.push("Significant expansion (>1%). Design for accommodation".to_string());
+            warnings.push(THERMAL_SIGNIFICANT_EXPANSION.warn("Significant expansion (>1%). Design for accommodation"));
         }
 
         compliance_notes.push("Linear thermal expansion per ASTM E228".to_string());
@@ -164,8 +173,8 @@ impl EngineerCalculator for ThermalExpansionCalculator {
             calculation_type: "thermal_expansion".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -174,6 +183,8 @@ impl EngineerCalculator for ThermalExpansionCalculator {
                 design_code_used: "ASTM E228".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::VALVE_LARGE_CV,
 };
 use async_trait::async_trait;
 
@@ -120,19 +121,27 @@ impl EngineerCalculator for ValveSizingCalculator {
         let sg = params.additional.as_ref().and_then(|a| a.get("sg").copied()).unwrap_or(1.0);
         let fluid_type = params.additional.as_ref().and_then(|a| a.get("fluid_type")).map(|v| v.to_string()).unwrap_or("liquid".to_string());
 
+        let mut trace = CalculationTrace::new();
+
         let cv = if fluid_type == "liquid" {
             flow_rate / (dp / sg).sqrt()
         } else {
             // Gas, assume scfh, psid, sg gas
             flow_rate * (sg * 520.0).sqrt() / (816.0 * dp.sqrt()) // Simplified
         };
+        trace.step(
+            "formulas.valve_sizing.flow_coefficient",
+            &[("flow_rate", flow_rate), ("dp", dp), ("sg", sg)],
+            cv,
+            "Cv",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if cv > 1000.0 {
-            warnings.push(format!("Large Cv ({:.1}). Consider multiple valves.", cv));
+            warnings.push(VALVE_LARGE_CV.warn(format!("Large Cv ({:.1}). Consider multiple valves.", cv)));
             recommendations.push("Verify piping size compatibility".to_string());
         }
 
@@ -149,8 +158,8 @@ impl EngineerCalculator for ValveSizingCalculator {
             calculation_type: "valve_sizing".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -159,6 +168,8 @@ impl EngineerCalculator for ValveSizingCalculator {
                 design_code_used: "ISA 75.01".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -0,0 +1,407 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{WATER_HAMMER_COLUMN_SEPARATION, WATER_HAMMER_EXCEEDS_RATING, WATER_HAMMER_RAPID_CLOSURE},
+};
+use crate::utils::conversions::essential::pressure::pa_to_kpa;
+use async_trait::async_trait;
+
+use super::constants::{ATMOSPHERIC_PRESSURE, GRAVITY};
+use super::fluid_properties::WATER_DENSITY;
+use super::helpers::{joukowsky, wave_speed};
+use super::pipe_materials::{lookup, PIPE_MATERIALS, WATER_BULK_MODULUS, WATER_VAPOR_PRESSURE};
+
+pub struct WaterHammerCalculator;
+
+impl ParameterValidator for WaterHammerCalculator {
+    fn calculator_id(&self) -> &str {
+        "water_hammer"
+    }
+}
+
+impl WaterHammerCalculator {
+    /// Pipe elastic modulus (GPa) and Poisson's ratio from the material type, steel when omitted;
+    /// `material.elastic_modulus` overrides the tabulated modulus
+    fn pipe_material(&self, params: &EngineeringParameters) -> EngineeringResult<(String, f64, f64)> {
+        let name = params.material.as_ref().map(|m| m.material_type.as_str()).unwrap_or("steel");
+        let (modulus, poisson) = lookup(name).ok_or_else(|| EngineeringError::InvalidParameter {
+            parameter: "material_type".to_string(),
+            value: name.to_string(),
+            reason: format!(
+                "Expected one of {}",
+                PIPE_MATERIALS.iter().map(|(n, _, _)| *n).collect::<Vec<_>>().join(", ")
+            ),
+        })?;
+        let modulus = match params.material.as_ref().and_then(|m| m.elastic_modulus) {
+            Some(e) if !(0.1..=300.0).contains(&e) => {
+                return Err(EngineeringError::InvalidParameter {
+                    parameter: "elastic_modulus".to_string(),
+                    value: e.to_string(),
+                    reason: "Pipe elastic modulus must be between 0.1 and 300 GPa".to_string(),
+                })
+            }
+            Some(e) => e,
+            None => modulus,
+        };
+        Ok((name.to_string(), modulus, poisson))
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for WaterHammerCalculator {
+    fn id(&self) -> &str {
+        "water_hammer"
+    }
+
+    fn name(&self) -> &str {
+        "Water Hammer / Surge Analysis"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Mechanical
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("water_hammer", "Water Hammer / Surge Analysis")
+            .category("mechanical")
+            .description("Pressure surge from valve closure: wave speed from pipe material and wall thickness, Joukowsky pressure rise and critical closure time")
+            .design_code("AWWA M11")
+            .parameter(ParameterMetadata {
+                name: "Pipe Length".to_string(),
+                path: "dimensions.length".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Pipe length between the valve and the reflecting boundary (reservoir or tank)".to_string(),
+                required: true,
+                default_value: Some(500.0),
+                min_value: Some(1.0),
+                max_value: Some(50000.0),
+                typical_range: Some((50.0, 5000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Internal Diameter".to_string(),
+                path: "dimensions.diameter".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Pipe internal diameter".to_string(),
+                required: true,
+                default_value: Some(0.3),
+                min_value: Some(0.01),
+                max_value: Some(5.0),
+                typical_range: Some((0.05, 1.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Wall Thickness".to_string(),
+                path: "dimensions.wall_thickness".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Pipe wall thickness".to_string(),
+                required: true,
+                default_value: Some(0.008),
+                min_value: Some(0.0005),
+                max_value: Some(0.2),
+                typical_range: Some((0.003, 0.03)),
+                validation_rules: Some(vec!["Must be < diameter / 2".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Pipe Material".to_string(),
+                path: "material.material_type".to_string(),
+                data_type: ParameterType::Enum(PIPE_MATERIALS.iter().map(|(n, _, _)| n.to_string()).collect()),
+                unit: "".to_string(),
+                description: "Pipe wall material; steel when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Pipe Elastic Modulus".to_string(),
+                path: "material.elastic_modulus".to_string(),
+                data_type: ParameterType::Number,
+                unit: "GPa".to_string(),
+                description: "Overrides the tabulated modulus of the pipe material".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.1),
+                max_value: Some(300.0),
+                typical_range: Some((1.0, 210.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Flow Velocity".to_string(),
+                path: "additional.velocity".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m/s".to_string(),
+                description: "Steady velocity stopped by the valve".to_string(),
+                required: true,
+                default_value: Some(2.0),
+                min_value: Some(0.01),
+                max_value: Some(10.0),
+                typical_range: Some((0.5, 3.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Valve Closure Time".to_string(),
+                path: "additional.closure_time".to_string(),
+                data_type: ParameterType::Number,
+                unit: "s".to_string(),
+                description: "Effective valve closure time".to_string(),
+                required: false,
+                default_value: Some(5.0),
+                min_value: Some(0.0),
+                max_value: Some(600.0),
+                typical_range: Some((1.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Operating Pressure".to_string(),
+                path: "additional.operating_pressure".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kPa".to_string(),
+                description: "Steady gauge pressure at the valve".to_string(),
+                required: false,
+                default_value: Some(500.0),
+                min_value: Some(0.0),
+                max_value: Some(10000.0),
+                typical_range: Some((200.0, 1000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Pressure Rating".to_string(),
+                path: "additional.pressure_rating".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kPa".to_string(),
+                description: "Allowable pipe pressure including surge (e.g. 1600 kPa for PN16)".to_string(),
+                required: false,
+                default_value: Some(1600.0),
+                min_value: Some(100.0),
+                max_value: Some(20000.0),
+                typical_range: Some((1000.0, 2500.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Fluid Density".to_string(),
+                path: "additional.fluid_density".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kg/m³".to_string(),
+                description: "Liquid density; water when omitted".to_string(),
+                required: false,
+                default_value: Some(WATER_DENSITY),
+                min_value: Some(500.0),
+                max_value: Some(2000.0),
+                typical_range: Some((800.0, 1100.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Fluid Bulk Modulus".to_string(),
+                path: "additional.bulk_modulus".to_string(),
+                data_type: ParameterType::Number,
+                unit: "GPa".to_string(),
+                description: "Liquid bulk modulus; water when omitted".to_string(),
+                required: false,
+                default_value: Some(WATER_BULK_MODULUS),
+                min_value: Some(0.5),
+                max_value: Some(5.0),
+                typical_range: Some((1.5, 2.5)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Intermediate)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.validate_dimension("length", params.dimensions.get("length").copied(), 1.0, 50000.0)?;
+        let diameter = self.validate_dimension("diameter", params.dimensions.get("diameter").copied(), 0.01, 5.0)?;
+        let wall = self.validate_dimension("wall_thickness", params.dimensions.get("wall_thickness").copied(), 0.0005, 0.2)?;
+        if wall >= diameter / 2.0 {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: "wall_thickness".to_string(),
+                value: wall.to_string(),
+                reason: "Wall thickness must be less than half the diameter".to_string(),
+            });
+        }
+        self.pipe_material(params)?;
+        self.get_additional_param(params, "velocity", Some(0.01), Some(10.0))?;
+        self.optional_param(params, "closure_time", 5.0, 0.0, 600.0)?;
+        let operating = self.optional_param(params, "operating_pressure", 500.0, 0.0, 10000.0)?;
+        let rating = self.optional_param(params, "pressure_rating", 1600.0, 100.0, 20000.0)?;
+        if operating >= rating {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: "operating_pressure".to_string(),
+                value: operating.to_string(),
+                reason: "Operating pressure must be below the pressure rating".to_string(),
+            });
+        }
+        self.optional_param(params, "fluid_density", WATER_DENSITY, 500.0, 2000.0)?;
+        self.optional_param(params, "bulk_modulus", WATER_BULK_MODULUS, 0.5, 5.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let length = params.dimensions.get("length").copied().unwrap_or(500.0);
+        let diameter = params.dimensions.get("diameter").copied().unwrap_or(0.3);
+        let wall = params.dimensions.get("wall_thickness").copied().unwrap_or(0.008);
+        let (material, modulus, poisson) = self.pipe_material(&params)?;
+        let velocity = self.get_additional_param(&params, "velocity", None, None)?;
+        let closure_time = self.optional_param(&params, "closure_time", 5.0, 0.0, 600.0)?;
+        let operating = self.optional_param(&params, "operating_pressure", 500.0, 0.0, 10000.0)?;
+        let rating = self.optional_param(&params, "pressure_rating", 1600.0, 100.0, 20000.0)?;
+        let density = self.optional_param(&params, "fluid_density", WATER_DENSITY, 500.0, 2000.0)?;
+        let bulk_modulus = self.optional_param(&params, "bulk_modulus", WATER_BULK_MODULUS, 0.5, 5.0)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut compliance_notes = Vec::new();
+
+        // Pipe anchored throughout its length against axial movement: c1 = 1 − ν²
+        let c1 = 1.0 - poisson.powi(2);
+        let a = trace.step(
+            "formulas.water_hammer.wave_speed",
+            &[("bulk_modulus", bulk_modulus), ("density", density), ("diameter", diameter), ("wall_thickness", wall), ("elastic_modulus", modulus), ("c1", c1)],
+            wave_speed(bulk_modulus * 1e9, density, diameter, wall, modulus * 1e9, c1),
+            "m/s",
+        );
+        let critical_time = trace.step(
+            "formulas.water_hammer.critical_closure_time",
+            &[("length", length), ("wave_speed", a)],
+            2.0 * length / a,
+            "s",
+        );
+        let instant_rise = trace.step(
+            "formulas.water_hammer.joukowsky",
+            &[("density", density), ("wave_speed", a), ("velocity", velocity)],
+            pa_to_kpa(joukowsky(density, a, velocity)).await,
+            "kPa",
+        );
+
+        // Slower closures reduce the rise in proportion to 2L/a over the closure time (Michaud)
+        let rapid = closure_time <= critical_time;
+        let surge = trace.step(
+            "formulas.water_hammer.surge_pressure",
+            &[("joukowsky", instant_rise), ("critical_time", critical_time), ("closure_time", closure_time)],
+            if rapid { instant_rise } else { instant_rise * critical_time / closure_time },
+            "kPa",
+        );
+        let max_pressure = operating + surge;
+        let min_pressure = operating - surge;
+        // Closure slow enough to keep the peak within the rating
+        let safe_closure = instant_rise * critical_time / (rating - operating);
+
+        if rapid {
+            warnings.push(WATER_HAMMER_RAPID_CLOSURE.warn(format!(
+                "Closure in {:.1} s is within the {:.2} s wave reflection time; the full Joukowsky rise develops",
+                closure_time, critical_time
+            )));
+        }
+        if max_pressure > rating {
+            warnings.push(WATER_HAMMER_EXCEEDS_RATING.warn(format!(
+                "Peak pressure of {:.0} kPa exceeds the {:.0} kPa pipe rating",
+                max_pressure, rating
+            )));
+            if safe_closure > critical_time {
+                recommendations.push(format!("Close the valve over at least {:.1} s", safe_closure));
+            }
+            recommendations.push("Consider a surge tank, air vessel or pressure relief valve".to_string());
+        }
+        if min_pressure + ATMOSPHERIC_PRESSURE < WATER_VAPOR_PRESSURE {
+            warnings.push(WATER_HAMMER_COLUMN_SEPARATION.warn(format!(
+                "Downsurge to {:.0} kPa gauge falls below vapour pressure; column separation can cause a larger rejoin surge",
+                min_pressure
+            )));
+            recommendations.push("Provide air/vacuum valves at high points".to_string());
+        }
+
+        compliance_notes.push(format!(
+            "Wave speed for {} pipe (E = {:.1} GPa, ν = {:.2}) anchored against axial movement",
+            material, modulus, poisson
+        ));
+        compliance_notes.push("Rigid column estimate; long or branched systems need a method-of-characteristics transient analysis".to_string());
+
+        let results = vec![
+            EngineeringResultItem::new("Wave Speed", a, "m/s").with_format(format!("{:.0} m/s", a)),
+            EngineeringResultItem::new("Critical Closure Time", critical_time, "s")
+                .critical()
+                .with_format(format!("{:.2} s", critical_time)),
+            EngineeringResultItem::new("Joukowsky Pressure Rise", instant_rise, "kPa")
+                .with_format(format!("{:.0} kPa", instant_rise)),
+            EngineeringResultItem::new("Surge Pressure Rise", surge, "kPa").with_format(format!("{:.0} kPa", surge)),
+            EngineeringResultItem::new("Surge Head", surge * 1000.0 / (density * GRAVITY), "m")
+                .with_format(format!("{:.1} m", surge * 1000.0 / (density * GRAVITY))),
+            EngineeringResultItem::new("Maximum Pressure", max_pressure, "kPa")
+                .critical()
+                .with_format(format!("{:.0} kPa", max_pressure)),
+            EngineeringResultItem::new("Minimum Pressure", min_pressure, "kPa").with_format(format!("{:.0} kPa", min_pressure)),
+            EngineeringResultItem::new("Rating Utilization", max_pressure / rating, "")
+                .critical()
+                .with_format(format!("{:.2}", max_pressure / rating)),
+            EngineeringResultItem::new("Minimum Safe Closure Time", safe_closure.max(critical_time), "s")
+                .with_format(format!("{:.1} s", safe_closure.max(critical_time))),
+        ];
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "water_hammer".to_string(),
+            results,
+            analysis: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "AWWA M11".to_string(),
+                requires_pe_review: false,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_water_hammer_surge() {
+        let params = |additional: serde_json::Value| -> EngineeringParameters {
+            serde_json::from_value(serde_json::json!({
+                "dimensions": { "length": 500.0, "diameter": 0.3, "wall_thickness": 0.008 },
+                "additional": additional,
+            }))
+            .unwrap()
+        };
+
+        // Steel pipe: a = √(K/ρ / (1 + (1 − ν²)·K·D / (E·e)))
+        let slow = execute("water_hammer", params(serde_json::json!({ "velocity": 2.0, "closure_time": 5.0 }))).await.unwrap();
+        let a = (2.2e6f64 / (1.0 + 0.91 * 2.2 * 0.3 / (207.0 * 0.008))).sqrt();
+        assert!((value(&slow, "Wave Speed") - a).abs() < 1e-6);
+        assert!((value(&slow, "Critical Closure Time") - 1000.0 / a).abs() < 1e-9);
+        assert!((value(&slow, "Joukowsky Pressure Rise") - 2.0 * a).abs() < 1e-6);
+        // Slow closure: 2ρLv / t = 2 · 1000 · 500 · 2 / 5 = 400 kPa
+        assert!((value(&slow, "Surge Pressure Rise") - 400.0).abs() < 1e-6);
+        assert!(warning_codes(&slow).is_empty());
+
+        let rapid = execute("water_hammer", params(serde_json::json!({ "velocity": 2.0, "closure_time": 0.5 }))).await.unwrap();
+        assert_eq!(value(&rapid, "Surge Pressure Rise"), value(&rapid, "Joukowsky Pressure Rise"));
+        assert!((value(&rapid, "Minimum Safe Closure Time") - 2.0e6 / 1.1e6).abs() < 1e-9);
+        let warnings = warning_codes(&rapid);
+        assert!(warnings.contains(&"water_hammer.rapid_closure".to_string()));
+        assert!(warnings.contains(&"water_hammer.exceeds_rating".to_string()));
+        assert!(warnings.contains(&"water_hammer.column_separation".to_string()));
+
+        // Plastic pipe has a much lower wave speed and surge
+        let mut pvc = params(serde_json::json!({ "velocity": 2.0, "closure_time": 0.5 }));
+        pvc.dimensions.insert("wall_thickness".to_string(), 0.015);
+        pvc.material = Some(serde_json::from_value(serde_json::json!({ "material_type": "PVC" })).unwrap());
+        let plastic = execute("water_hammer", pvc).await.unwrap();
+        assert!(value(&plastic, "Wave Speed") < 0.4 * a);
+    }
+}
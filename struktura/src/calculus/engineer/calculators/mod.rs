@@ -9,12 +9,16 @@ pub mod civil;
 pub mod structural;
 pub mod mechanical;
 pub mod production;
+pub mod hydraulic;
+pub mod electrical;
 
 // Re-export all calculators for convenience
 pub use civil::*;
 pub use structural::*;
 pub use mechanical::*;
 pub use production::*;
+pub use hydraulic::*;
+pub use electrical::*;
 
 // ============================================================================
 // CALCULATOR ORGANIZATION
@@ -44,6 +48,17 @@ pub use production::*;
 //   ├── line_balancing.rs              (ProductionLineBalancingCalculator)
 //   └── ... (other production calculators)
 
+// hydraulic/
+//   ├── mod.rs                          (exports all hydraulic calculators)
+//   ├── rational_method.rs             (RationalMethodRunoffCalculator)
+//   ├── culvert_sizing.rs              (CulvertSizingCalculator)
+//   └── storm_sewer.rs                 (StormSewerSizingCalculator)
+
+// electrical/
+//   ├── mod.rs                          (exports all electrical calculators)
+//   ├── grounding.rs                   (GroundingLightningProtectionCalculator)
+//   └── generator_ups.rs               (GeneratorUpsCalculator)
+
 // ============================================================================
 // ADDING NEW CALCULATORS
 // ============================================================================
@@ -2,7 +2,9 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{CAPACITY_HIGH_UTILIZATION, CAPACITY_LOW_YIELD},
 };
+use crate::calculus::rules::{self, Recommendation};
 use async_trait::async_trait;
 
 use super::lean_manufacturing::*;
@@ -193,31 +195,74 @@ impl EngineerCalculator for CapacityPlanningCalculator {
         let quality_yield = params.additional.as_ref().and_then(|a| a.get("quality_yield").copied()).unwrap_or(100.0);
         let target_utilization = params.additional.as_ref().and_then(|a| a.get("target_utilization").copied()).unwrap_or(TARGET_LINE_EFFICIENCY);
 
+        let mut trace = CalculationTrace::new();
+
         let available_time_per_machine = period_days * shifts_per_day * hours_per_shift * 60.0;
+        trace.step(
+            "formulas.capacity_planning.available_time",
+            &[("period_days", period_days), ("shifts_per_day", shifts_per_day), ("hours_per_shift", hours_per_shift)],
+            available_time_per_machine,
+            "min",
+        );
 
         let effective_cycle_time = cycle_time / output_per_cycle;
+        trace.step(
+            "formulas.capacity_planning.effective_cycle_time",
+            &[("cycle_time", cycle_time), ("output_per_cycle", output_per_cycle)],
+            effective_cycle_time,
+            "min/unit",
+        );
         let required_production = demand / (quality_yield / 100.0);
+        trace.step(
+            "formulas.capacity_planning.required_production",
+            &[("demand", demand), ("quality_yield", quality_yield)],
+            required_production,
+            "units",
+        );
         let required_time = required_production * effective_cycle_time;
+        trace.step(
+            "formulas.capacity_planning.required_time",
+            &[("required_production", required_production), ("effective_cycle_time", effective_cycle_time)],
+            required_time,
+            "min",
+        );
         let required_capacity = required_time / (available_time_per_machine * (target_utilization / 100.0));
+        trace.step(
+            "formulas.capacity_planning.required_capacity",
+            &[("required_time", required_time), ("available_time_per_machine", available_time_per_machine), ("target_utilization", target_utilization)],
+            required_capacity,
+            "machines",
+        );
         let num_machines = required_capacity.ceil();
         let actual_utilization = (required_time / (num_machines * available_time_per_machine)) * 100.0;
+        trace.step(
+            "formulas.capacity_planning.actual_utilization",
+            &[("required_time", required_time), ("num_machines", num_machines), ("available_time_per_machine", available_time_per_machine)],
+            actual_utilization,
+            "%",
+        );
 
         let mut warnings = Vec::new();
-        let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if actual_utilization > 90.0 {
-            warnings.push(format!("High utilization ({:.1}%). Risk of overload and downtime.", actual_utilization));
-            recommendations.push("Consider adding buffer capacity or overtime planning".to_string());
-        } else if actual_utilization < 70.0 {
-            recommendations.push(format!("Low utilization ({:.1}%). Optimize scheduling or reduce assets.", actual_utilization));
+            warnings.push(CAPACITY_HIGH_UTILIZATION.warn(format!("High utilization ({:.1}%). Risk of overload and downtime.", actual_utilization)));
         }
 
         if quality_yield < 95.0 {
-            warnings.push(format!("Low quality yield ({:.1}%). Consider process improvements to reduce scrap.", quality_yield));
-            recommendations.push("Implement quality control measures or Six Sigma analysis".to_string());
+            warnings.push(CAPACITY_LOW_YIELD.warn(format!("Low quality yield ({:.1}%). Consider process improvements to reduce scrap.", quality_yield)));
         }
 
+        let facts = [
+            ("actual_utilization", actual_utilization),
+            ("quality_yield", quality_yield),
+            ("required_capacity", required_capacity),
+            ("num_machines", num_machines),
+        ]
+        .into_iter()
+        .collect();
+        let recommendations = Recommendation::messages(&rules::evaluate(self.id(), &facts));
+
         compliance_notes.push("Capacity planning per lean manufacturing to avoid over/under capacity".to_string());
         compliance_notes.push("Incorporate demand forecasting accuracy and quality metrics in planning".to_string());
 
@@ -243,8 +288,8 @@ impl EngineerCalculator for CapacityPlanningCalculator {
             calculation_type: "capacity_planning".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -253,6 +298,8 @@ impl EngineerCalculator for CapacityPlanningCalculator {
                 design_code_used: "Lean Manufacturing".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -284,6 +331,10 @@ mod tests {
 
         let response = result.unwrap();
         assert_eq!(response.results.len(), 8);
+
+        let trace = response.calculation_trace.expect("trace recorded");
+        assert_eq!(trace.len(), 6);
+        assert!(trace.iter().all(|s| s.formula_key.starts_with("formulas.capacity_planning.")));
     }
 
     #[test]
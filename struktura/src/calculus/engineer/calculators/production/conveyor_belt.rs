@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{CONVEYOR_HIGH_SPEED, CONVEYOR_LOW_SPEED, CONVEYOR_STEEP_INCLINE},
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -179,15 +180,35 @@ impl EngineerCalculator for ConveyorBeltCalculator {
             .copied()
             .unwrap_or(5000.0);
 
+        let mut trace = CalculationTrace::new();
+
         // Belt loading capacity (CEMA formula for troughed belt)
         let surcharge_factor = 1.0 + (surcharge_angle / 90.0) * 0.5;
         let load_cross_section = belt_width.powi(2) * 0.08 * surcharge_factor; // m²
+        trace.step(
+            "formulas.conveyor_belt.load_cross_section",
+            &[("belt_width", belt_width), ("surcharge_factor", surcharge_factor)],
+            load_cross_section,
+            "m²",
+        );
 
         // Volumetric capacity
         let volumetric_capacity = load_cross_section * belt_speed * 3600.0; // m³/h
+        trace.step(
+            "formulas.conveyor_belt.volumetric_capacity",
+            &[("load_cross_section", load_cross_section), ("belt_speed", belt_speed)],
+            volumetric_capacity,
+            "m³/h",
+        );
 
         // Mass capacity
         let mass_capacity = volumetric_capacity * material_density / 1000.0; // tonnes/h
+        trace.step(
+            "formulas.conveyor_belt.mass_capacity",
+            &[("volumetric_capacity", volumetric_capacity), ("material_density", material_density)],
+            mass_capacity,
+            "t/h",
+        );
 
         // Belt tensions
         let belt_weight_per_meter = belt_width * 15.0; // kg/m (approximate)
@@ -199,16 +220,35 @@ impl EngineerCalculator for ConveyorBeltCalculator {
         let te = (belt_weight_per_meter + material_weight_per_meter) * 
                  gravity * belt_length * 
                  (FRICTION_BELT_IDLER + inclination_angle.to_radians().sin());
+                 trace.step(
+                     "formulas.conveyor_belt.effective_tension",
+                     &[("belt_weight_per_meter", belt_weight_per_meter), ("material_weight_per_meter", material_weight_per_meter), ("belt_length", belt_length), ("friction", FRICTION_BELT_IDLER), ("inclination_angle", inclination_angle)],
+                     te,
+                     "N",
+                 );
 
         // Slack side tension (minimum to prevent sag)
         let t2 = 50.0 * belt_width * 1000.0; // N (empirical minimum)
 
         // Tight side tension
         let t1 = te + t2;
+        trace.step("formulas.conveyor_belt.tight_side_tension", &[("te", te), ("t2", t2)], t1, "N");
 
         // Required motor power
         let power_required = (te * belt_speed) / 1000.0; // kW
+        trace.step(
+            "formulas.conveyor_belt.power_required",
+            &[("te", te), ("belt_speed", belt_speed)],
+            power_required,
+            "kW",
+        );
         let motor_power = power_required * 1.15; // 15% safety factor
+        trace.step(
+            "formulas.conveyor_belt.motor_power",
+            &[("power_required", power_required)],
+            motor_power,
+            "kW",
+        );
 
         // Annual throughput
         let annual_throughput = mass_capacity * operating_hours;
@@ -216,20 +256,26 @@ impl EngineerCalculator for ConveyorBeltCalculator {
         // Belt tension safety factor
         let belt_strength_required = t1 * 10.0; // N/mm width (10:1 safety factor)
         let belt_strength_per_mm = belt_strength_required / (belt_width * 1000.0);
+        trace.step(
+            "formulas.conveyor_belt.belt_strength",
+            &[("t1", t1), ("belt_width", belt_width)],
+            belt_strength_per_mm,
+            "N/mm",
+        );
 
         // Warnings and recommendations
         if inclination_angle > 18.0 {
-            warnings.push(format!(
+            warnings.push(CONVEYOR_STEEP_INCLINE.warn(format!(
                 "Steep inclination ({:.1}°). Material may roll back. Use cleated belt or reduce angle.",
                 inclination_angle
-            ));
+            )));
         }
 
         if belt_speed > 3.0 {
-            warnings.push(format!(
+            warnings.push(CONVEYOR_HIGH_SPEED.warn(format!(
                 "High belt speed ({:.1} m/s). Verify material spillage control and dust suppression.",
                 belt_speed
-            ));
+            )));
             recommendations.push("Install dust suppression system at transfer points".to_string());
         }
 
@@ -246,7 +292,7 @@ impl EngineerCalculator for ConveyorBeltCalculator {
         }
 
         if belt_speed < BELT_SPEED_MIN {
-            warnings.push("Belt speed below minimum recommended. Material may not discharge properly".to_string());
+            warnings.push(CONVEYOR_LOW_SPEED.warn("Belt speed below minimum recommended. Material may not discharge properly"));
         }
 
         compliance_notes.push("Design per CEMA (Conveyor Equipment Manufacturers Association) standards".to_string());
@@ -284,8 +330,8 @@ impl EngineerCalculator for ConveyorBeltCalculator {
             calculation_type: "conveyor_belt".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -294,6 +340,8 @@ impl EngineerCalculator for ConveyorBeltCalculator {
                 design_code_used: "CEMA".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{LAYOUT_HIGH_SPACE_UTILIZATION, LAYOUT_LOW_EFFICIENCY},
 };
 use async_trait::async_trait;
 
@@ -116,22 +117,42 @@ impl EngineerCalculator for FacilityLayoutCalculator {
         let facility_area = self.get_additional_param(&params, "facility_area", None, None)?;
         let target_efficiency = params.additional.as_ref().and_then(|a| a.get("target_efficiency").copied()).unwrap_or(80.0);
 
+        let mut trace = CalculationTrace::new();
+
         // Simplified layout efficiency metric (lower flow distance per area is better)
         let avg_distance_per_dept = total_flow_distance / num_departments;
+        trace.step(
+            "formulas.facility_layout.avg_distance_per_department",
+            &[("total_flow_distance", total_flow_distance), ("num_departments", num_departments)],
+            avg_distance_per_dept,
+            "m",
+        );
         let space_utilization = (num_departments * 100.0) / facility_area; // Arbitrary assumption: 100m² per dept
+        trace.step(
+            "formulas.facility_layout.space_utilization",
+            &[("num_departments", num_departments), ("facility_area", facility_area)],
+            space_utilization,
+            "%",
+        );
         let layout_efficiency = 100.0 - (total_flow_distance / facility_area * 10.0); // Heuristic formula
+        trace.step(
+            "formulas.facility_layout.layout_efficiency",
+            &[("total_flow_distance", total_flow_distance), ("facility_area", facility_area)],
+            layout_efficiency,
+            "%",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if layout_efficiency < target_efficiency {
-            warnings.push(format!("Layout efficiency ({:.1}%) below target ({:.1}%). Reoptimization recommended.", layout_efficiency, target_efficiency));
+            warnings.push(LAYOUT_LOW_EFFICIENCY.warn(format!("Layout efficiency ({:.1}%) below target ({:.1}%). Reoptimization recommended.", layout_efficiency, target_efficiency)));
             recommendations.push("Use systematic layout planning (SLP) to minimize flow distances".to_string());
         }
 
         if space_utilization > 0.1 { // Arbitrary threshold
-            warnings.push("High space utilization. Risk of congestion.".to_string());
+            warnings.push(LAYOUT_HIGH_SPACE_UTILIZATION.warn("High space utilization. Risk of congestion."));
             recommendations.push("Consider cellular manufacturing or flexible layouts".to_string());
         }
 
@@ -152,8 +173,8 @@ impl EngineerCalculator for FacilityLayoutCalculator {
             calculation_type: "facility_layout".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -162,6 +183,8 @@ impl EngineerCalculator for FacilityLayoutCalculator {
                 design_code_used: "Lean Manufacturing".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::INVENTORY_HIGH_EOQ,
 };
 use async_trait::async_trait;
 
@@ -147,15 +148,29 @@ impl EngineerCalculator for InventoryOptimizationCalculator {
         let lead_time_days = self.get_additional_param(&params, "lead_time_days", None, None)?;
         let safety_stock = params.additional.as_ref().and_then(|a| a.get("safety_stock").copied()).unwrap_or(0.0);
 
+        let mut trace = CalculationTrace::new();
+
         let eoq_value = eoq(annual_demand, ordering_cost, holding_cost_per_unit);
+        trace.step(
+            "formulas.inventory_optimization.eoq",
+            &[("annual_demand", annual_demand), ("ordering_cost", ordering_cost), ("holding_cost_per_unit", holding_cost_per_unit)],
+            eoq_value,
+            "units",
+        );
         let rop_value = reorder_point(daily_demand, lead_time_days, safety_stock);
+        trace.step(
+            "formulas.inventory_optimization.reorder_point",
+            &[("daily_demand", daily_demand), ("lead_time_days", lead_time_days), ("safety_stock", safety_stock)],
+            rop_value,
+            "units",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if eoq_value > annual_demand * 0.1 {
-            warnings.push(format!("EOQ ({:.0} units) is high relative to annual demand. Verify costs.", eoq_value));
+            warnings.push(INVENTORY_HIGH_EOQ.warn(format!("EOQ ({:.0} units) is high relative to annual demand. Verify costs.", eoq_value)));
         }
 
         if safety_stock == 0.0 {
@@ -179,8 +194,8 @@ impl EngineerCalculator for InventoryOptimizationCalculator {
             calculation_type: "inventory_optimization".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -189,6 +204,8 @@ impl EngineerCalculator for InventoryOptimizationCalculator {
                 design_code_used: "Lean Manufacturing".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
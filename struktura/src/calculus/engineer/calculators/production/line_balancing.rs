@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{LINE_BELOW_MINIMUM_EFFICIENCY, LINE_BELOW_TARGET_EFFICIENCY, LINE_CYCLE_EXCEEDS_TAKT, LINE_EXCESS_WORKSTATIONS, LINE_POOR_SMOOTHNESS},
 };
 use async_trait::async_trait;
 
@@ -192,26 +193,64 @@ impl EngineerCalculator for ProductionLineBalancingCalculator {
             .unwrap_or(3.5);
 
         // Takt time (available time / desired output)
+        let mut trace = CalculationTrace::new();
+
         let takt_time_val = takt_time(available_time_per_day, desired_output);
+        trace.step(
+            "formulas.line_balancing.takt_time",
+            &[("available_time_per_day", available_time_per_day), ("desired_output", desired_output)],
+            takt_time_val,
+            "min/unit",
+        );
 
         // Theoretical minimum number of workstations
         let theoretical_min_stations = min_workstations(total_task_time, takt_time_val);
+        trace.step(
+            "formulas.line_balancing.min_workstations",
+            &[("total_task_time", total_task_time), ("takt_time", takt_time_val)],
+            theoretical_min_stations as f64,
+            "stations",
+        );
 
         // Cycle time (determines production rate)
         let cycle_time_val = takt_time_val.max(longest_task_time);
+        trace.step(
+            "formulas.line_balancing.cycle_time",
+            &[("takt_time", takt_time_val), ("longest_task_time", longest_task_time)],
+            cycle_time_val,
+            "min/unit",
+        );
 
         // Actual production rate
         let actual_output = available_time_per_day / cycle_time_val;
 
         // Line efficiency
         let line_efficiency_val = line_efficiency(total_task_time, num_workstations, cycle_time_val);
+        trace.step(
+            "formulas.line_balancing.line_efficiency",
+            &[("total_task_time", total_task_time), ("num_workstations", num_workstations as f64), ("cycle_time", cycle_time_val)],
+            line_efficiency_val,
+            "%",
+        );
 
         // Balance delay (idle time percentage)
         let balance_delay_val = balance_delay(line_efficiency_val);
+        trace.step(
+            "formulas.line_balancing.balance_delay",
+            &[("line_efficiency", line_efficiency_val)],
+            balance_delay_val,
+            "%",
+        );
 
         // Smoothness index (estimated - would need station times for exact calculation)
         let avg_station_time = total_task_time / num_workstations as f64;
         let smoothness_index_val = ((cycle_time_val - avg_station_time).powi(2)).sqrt() * 100.0 / cycle_time_val;
+        trace.step(
+            "formulas.line_balancing.smoothness_index",
+            &[("cycle_time", cycle_time_val), ("avg_station_time", avg_station_time)],
+            smoothness_index_val,
+            "%",
+        );
 
         // Idle time per cycle
         let total_idle_time = (num_workstations as f64 * cycle_time_val) - total_task_time;
@@ -219,46 +258,52 @@ impl EngineerCalculator for ProductionLineBalancingCalculator {
         // Daily idle time cost (assume $30/hour labor rate)
         let labor_rate_per_minute = 30.0 / 60.0;
         let daily_idle_cost = total_idle_time * (available_time_per_day / cycle_time_val) * labor_rate_per_minute;
+        trace.step(
+            "formulas.line_balancing.daily_idle_cost",
+            &[("total_idle_time", total_idle_time), ("available_time_per_day", available_time_per_day), ("cycle_time", cycle_time_val), ("labor_rate_per_minute", labor_rate_per_minute)],
+            daily_idle_cost,
+            "$/day",
+        );
 
         // Warnings and recommendations
         if line_efficiency_val < MINIMUM_ACCEPTABLE_EFFICIENCY {
-            warnings.push(format!(
+            warnings.push(LINE_BELOW_MINIMUM_EFFICIENCY.warn(format!(
                 "CRITICAL: Low line efficiency ({:.1}%). Significant idle time detected. \
                  Line is operating below acceptable threshold.",
                 line_efficiency_val
-            ));
+            )));
             recommendations.push("Rebalance workstations or combine tasks to improve efficiency".to_string());
             recommendations.push("Consider time-motion study to identify improvement opportunities".to_string());
         } else if line_efficiency_val < TARGET_LINE_EFFICIENCY {
-            warnings.push(format!(
+            warnings.push(LINE_BELOW_TARGET_EFFICIENCY.warn(format!(
                 "Line efficiency ({:.1}%) below target ({:.1}%). Improvement possible.",
                 line_efficiency_val, TARGET_LINE_EFFICIENCY
-            ));
+            )));
         }
 
         if num_workstations > theoretical_min_stations * 2 {
-            warnings.push(format!(
+            warnings.push(LINE_EXCESS_WORKSTATIONS.warn(format!(
                 "Excessive workstations: {} actual vs {} theoretical minimum. \
                  Consider consolidating operations.",
                 num_workstations, theoretical_min_stations
-            ));
+            )));
         }
 
         if cycle_time_val > takt_time_val * 1.1 {
-            warnings.push(format!(
+            warnings.push(LINE_CYCLE_EXCEEDS_TAKT.warn(format!(
                 "DEMAND NOT MET: Cycle time ({:.2} min) exceeds takt time ({:.2} min) by >10%. \
                  Cannot maintain required production rate.",
                 cycle_time_val, takt_time_val
-            ));
+            )));
             recommendations.push("Increase workstations or reduce task times to meet demand".to_string());
             recommendations.push("Consider parallel workstations for bottleneck tasks".to_string());
         }
 
         if smoothness_index_val > 20.0 {
-            warnings.push(format!(
+            warnings.push(LINE_POOR_SMOOTHNESS.warn(format!(
                 "Poor line balance (smoothness index: {:.1}). Tasks are unevenly distributed.",
                 smoothness_index_val
-            ));
+            )));
             recommendations.push("Redistribute tasks among workstations for better balance".to_string());
         }
 
@@ -314,8 +359,8 @@ impl EngineerCalculator for ProductionLineBalancingCalculator {
             calculation_type: "production_line_balancing".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -324,6 +369,8 @@ impl EngineerCalculator for ProductionLineBalancingCalculator {
                 design_code_used: "Lean Manufacturing".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
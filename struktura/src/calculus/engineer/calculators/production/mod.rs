@@ -55,6 +55,8 @@ pub mod material_handling {
 
 /// Lean manufacturing and line balancing
 pub mod lean_manufacturing {
+    use super::oee::domain::Percentage;
+
     // Target efficiency levels (%)
     pub const TARGET_LINE_EFFICIENCY: f64 = 85.0;
     pub const MINIMUM_ACCEPTABLE_EFFICIENCY: f64 = 75.0;
@@ -64,10 +66,10 @@ pub mod lean_manufacturing {
     pub const ACCEPTABLE_BALANCE_DELAY: f64 = 15.0;
     pub const TARGET_BALANCE_DELAY: f64 = 10.0;
     
-    // OEE (Overall Equipment Effectiveness) benchmarks (%)
-    pub const OEE_WORLD_CLASS: f64 = 85.0;
-    pub const OEE_GOOD: f64 = 65.0;
-    pub const OEE_ACCEPTABLE: f64 = 50.0;
+    // OEE (Overall Equipment Effectiveness) benchmarks
+    pub const OEE_WORLD_CLASS: Percentage = Percentage::from_fraction(0.85);
+    pub const OEE_GOOD: Percentage = Percentage::from_fraction(0.65);
+    pub const OEE_ACCEPTABLE: Percentage = Percentage::from_fraction(0.50);
     
     // Labor cost estimates (USD/hour)
     pub const LABOR_RATE_LOW: f64 = 15.0;
@@ -91,6 +93,8 @@ pub mod process_capability_indices {
 
 /// Helper functions for production calculations
 pub mod helpers {
+    use super::oee::domain::Percentage;
+
     /// Calculate takt time (available time / demand)
     pub fn takt_time(available_time_minutes: f64, demand_units: f64) -> f64 {
         available_time_minutes / demand_units
@@ -136,11 +140,11 @@ pub mod helpers {
     
     /// Calculate OEE (Overall Equipment Effectiveness)
     pub fn oee(
-        availability: f64,  // %
-        performance: f64,   // %
-        quality: f64,       // %
-    ) -> f64 {
-        (availability / 100.0) * (performance / 100.0) * (quality / 100.0) * 100.0
+        availability: Percentage,
+        performance: Percentage,
+        quality: Percentage,
+    ) -> Percentage {
+        Percentage::from_fraction(availability.fraction() * performance.fraction() * quality.fraction())
     }
     
     /// Calculate Economic Order Quantity (EOQ)
@@ -237,10 +241,16 @@ mod tests {
     #[test]
     fn test_oee() {
         use helpers::*;
+        use super::oee::domain::Percentage;
         
         // 90% availability, 95% performance, 98% quality
-        let oee_value = oee(90.0, 95.0, 98.0);
-        assert!((oee_value - 83.79).abs() < 0.1);
+        let oee_value = oee(
+            Percentage::from_percent(90.0),
+            Percentage::from_percent(95.0),
+            Percentage::from_percent(98.0),
+        );
+        assert!((oee_value.percent() - 83.79).abs() < 0.1);
+        assert!((oee_value.fraction() - 0.8379).abs() < 0.001);
     }
 
     #[test]
@@ -1,6 +1,7 @@
 //! REST API using Axum
 //! 
-//! Nested router, no state, all translation-ready.
+//! Nested router, all translation-ready. App state is only read to apply
+//! stored threshold profiles when a request leaves `thresholds` out.
 //! Accepts JSON, returns JSON, handles errors gracefully.
 //! 
 //! Now includes:
@@ -10,17 +11,32 @@
 //! - Temporal scrap analysis
 //! - Multi-machine system analysis
 //! - Leverage analysis
+//! - Loss tree drill-down
+//! - Guided input wizard definition
 
 use axum::{
-    extract::Json,
+    extract::{Json, Query, State},
     http::StatusCode,
     response::{IntoResponse, Response},
-    routing::post,
+    routing::{get, post},
     Router,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use crate::sec::Claims;
 use crate::state::AppState;
+use crate::calculus::audience::Audience;
+use crate::calculus::engineer::calculators::production::oee::profiles;
+use crate::calculus::engineer::calculators::production::oee::{
+    domain::{economics::EconomicAnalysis, extended::ExtendedMetrics, loss_tree::{LossTree, LossTreeNode}, metrics::CoreMetrics},
+    ledger::{AssumptionLedger, WarningSeverity},
+    validation::ValidationResult,
+    OeeResult,
+};
+use crate::calculus::quality::DataQuality;
+use crate::calculus::engineer::models::{
+    VisibilityCondition, VisibilityRule, WizardDefinition, WizardStep,
+};
 
 /// Create the OEE calculator API router
 /// 
@@ -40,10 +56,14 @@ pub fn router() -> Router<Arc<AppState>> {
         .route("/sensitivity", post(sensitivity_handler))
         .route("/leverage", post(leverage_handler))
         .route("/temporal-scrap", post(temporal_scrap_handler))
+        .route("/loss-tree/subtree", post(loss_subtree_handler))
         
         // Multi-machine endpoints
         .route("/system/aggregate", post(system_aggregate_handler))
         .route("/system/compare-methods", post(system_compare_methods_handler))
+
+        // Guided input flow for the SPA
+        .route("/wizard", get(wizard_handler))
 }
 
 // ============================================================================
@@ -59,7 +79,74 @@ pub struct CalculateRequest {
 /// Response body for OEE calculation
 #[derive(Debug, Serialize)]
 pub struct CalculateResponse {
-    pub result: crate::calculus::engineer::calculators::production::oee::OeeResult,
+    pub result: AudienceView,
+}
+
+/// `?audience=` on the calculate endpoints
+#[derive(Debug, Default, Deserialize)]
+pub struct AudienceQuery {
+    pub audience: Option<Audience>,
+}
+
+/// Deepest loss-tree level shown to operators
+const OPERATOR_LOSS_TREE_DEPTH: usize = 2;
+
+/// An OEE result trimmed to one audience
+///
+/// Engineers see every section, so their view serializes like `OeeResult`.
+/// Operators get the metrics, a shallow loss tree without money and the
+/// warnings worth acting on; managers get the economics, the priced loss
+/// tree, data quality and high-severity warnings.
+#[derive(Debug, Serialize)]
+pub struct AudienceView {
+    pub core_metrics: CoreMetrics,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended_metrics: Option<ExtendedMetrics>,
+    pub loss_tree: LossTree,
+    pub economic_analysis: Option<EconomicAnalysis>,
+    pub ledger: AssumptionLedger,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<ValidationResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_quality: Option<DataQuality>,
+}
+
+impl AudienceView {
+    pub fn new(result: OeeResult, audience: Audience) -> Self {
+        let mut view = Self {
+            core_metrics: result.core_metrics,
+            extended_metrics: Some(result.extended_metrics),
+            loss_tree: result.loss_tree,
+            economic_analysis: result.economic_analysis,
+            ledger: result.ledger,
+            validation: Some(result.validation),
+            data_quality: Some(result.data_quality),
+        };
+        if audience.is_full() {
+            return view;
+        }
+
+        view.extended_metrics = None;
+        view.validation = None;
+        view.ledger.assumptions.clear();
+        view.ledger.thresholds.clear();
+
+        if audience == Audience::Operator {
+            view.economic_analysis = None;
+            view.data_quality = None;
+            view.loss_tree.root = view.loss_tree.root.pruned(OPERATOR_LOSS_TREE_DEPTH);
+            strip_economics(&mut view.loss_tree.root);
+            view.ledger.warnings.retain(|w| w.severity != WarningSeverity::Low);
+        } else {
+            view.ledger.warnings.retain(|w| w.severity == WarningSeverity::High);
+        }
+        view
+    }
+}
+
+fn strip_economics(node: &mut LossTreeNode) {
+    node.economic_impact = None;
+    node.children.iter_mut().for_each(strip_economics);
 }
 
 /// Request body for OEE calculation with economics
@@ -85,37 +172,54 @@ pub struct CalculateFullRequest {
 /// Response body for full analysis
 #[derive(Debug, Serialize)]
 pub struct CalculateFullResponse {
-    pub result: crate::calculus::engineer::calculators::production::oee::OeeResult,
+    pub result: AudienceView,
     pub sensitivity_analysis: Option<crate::calculus::engineer::calculators::production::oee::engine::sensitivity::SensitivityAnalysis>,
     pub temporal_scrap_analysis: Option<crate::calculus::engineer::calculators::production::oee::engine::temporal_scrap::TemporalScrapAnalysis>,
 }
 
 /// Calculate basic OEE
 async fn calculate_handler(
-    Json(request): Json<CalculateRequest>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Query(query): Query<AudienceQuery>,
+    Json(mut request): Json<CalculateRequest>,
 ) -> Result<Json<CalculateResponse>, ApiError> {
+    profiles::apply_stored_profile(&state, claims.as_ref(), &mut request.input).await;
+    
     let result = crate::calculus::engineer::calculators::production::oee::engine::calculate_oee(request.input)
         .map_err(ApiError::from)?;
     
+    let result = AudienceView::new(result, query.audience.unwrap_or_default());
     Ok(Json(CalculateResponse { result }))
 }
 
 /// Calculate OEE with economic analysis
 async fn calculate_with_economics_handler(
-    Json(request): Json<CalculateWithEconomicsRequest>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Query(query): Query<AudienceQuery>,
+    Json(mut request): Json<CalculateWithEconomicsRequest>,
 ) -> Result<Json<CalculateResponse>, ApiError> {
+    profiles::apply_stored_profile(&state, claims.as_ref(), &mut request.input).await;
+    
     let result = crate::calculus::engineer::calculators::production::oee::engine::calculate_oee_with_economics(
         request.input,
         request.economic_parameters,
     ).map_err(ApiError::from)?;
     
+    let result = AudienceView::new(result, query.audience.unwrap_or_default());
     Ok(Json(CalculateResponse { result }))
 }
 
 /// Calculate OEE with all optional analyses
 async fn calculate_full_handler(
-    Json(request): Json<CalculateFullRequest>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Query(query): Query<AudienceQuery>,
+    Json(mut request): Json<CalculateFullRequest>,
 ) -> Result<Json<CalculateFullResponse>, ApiError> {
+    profiles::apply_stored_profile(&state, claims.as_ref(), &mut request.input).await;
+    
     // Calculate base OEE (with or without economics)
     let mut result = if let Some(economic_params) = request.economic_parameters {
         crate::calculus::engineer::calculators::production::oee::engine::calculate_oee_with_economics(
@@ -150,12 +254,71 @@ async fn calculate_full_handler(
     };
     
     Ok(Json(CalculateFullResponse {
-        result,
+        result: AudienceView::new(result, query.audience.unwrap_or_default()),
         sensitivity_analysis,
         temporal_scrap_analysis,
     }))
 }
 
+// ============================================================================
+// Wizard Definition Endpoint
+// ============================================================================
+
+/// Guided flow for the `/calculate-full` request body
+///
+/// Field paths are relative to `CalculateFullRequest`.
+pub fn wizard_definition() -> WizardDefinition {
+    WizardDefinition::new()
+        .step(WizardStep::new("context", "Machine & Window")
+            .description("Identify the machine and the analysis period")
+            .field("input.machine.machine_id")
+            .field("input.machine.line_id")
+            .field("input.window.start")
+            .field("input.window.end"))
+        .step(WizardStep::new("time", "Time Model")
+            .description("Planned production time and how it was spent")
+            .field("input.time_model.planned_production_time")
+            .field("input.time_model.allocations")
+            .field("input.time_model.all_time"))
+        .step(WizardStep::new("production", "Production Counts")
+            .field("input.production.total_units")
+            .field("input.production.good_units")
+            .field("input.production.scrap_units")
+            .field("input.production.reworked_units"))
+        .step(WizardStep::new("cycle", "Cycle Time")
+            .field("input.cycle_time.ideal_cycle_time")
+            .field("input.cycle_time.average_cycle_time"))
+        .step(WizardStep::new("downtime", "Downtime Records")
+            .field("input.downtimes.records"))
+        .step(WizardStep::new("thresholds", "Classification Thresholds")
+            .description("Defaults are suitable for most discrete lines")
+            .field("input.thresholds.micro_stoppage_threshold")
+            .field("input.thresholds.small_stop_threshold")
+            .field("input.thresholds.speed_loss_threshold")
+            .field("input.thresholds.high_scrap_rate_threshold")
+            .field("input.thresholds.low_utilization_threshold"))
+        .step(WizardStep::new("analysis", "Analysis Options")
+            .field("include_sensitivity")
+            .conditional_field(
+                "sensitivity_variation",
+                VisibilityRule::new("include_sensitivity", VisibilityCondition::NotEquals(serde_json::Value::Bool(false))),
+            )
+            .field("include_temporal_scrap")
+            .field("economic_parameters.currency"))
+        .step(WizardStep::new("economics", "Economic Parameters")
+            .description("Low, central and high estimates for cost analysis")
+            .visible_when(VisibilityRule::new("economic_parameters.currency", VisibilityCondition::Present))
+            .field("economic_parameters.unit_price")
+            .field("economic_parameters.marginal_contribution")
+            .field("economic_parameters.material_cost")
+            .field("economic_parameters.labor_cost_per_hour"))
+}
+
+/// Serve the guided input flow
+async fn wizard_handler() -> Json<WizardDefinition> {
+    Json(wizard_definition())
+}
+
 // ============================================================================
 // Sensitivity Analysis Endpoint
 // ============================================================================
@@ -176,8 +339,12 @@ pub struct SensitivityResponse {
 
 /// Analyze parameter sensitivity
 async fn sensitivity_handler(
-    Json(request): Json<SensitivityRequest>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Json(mut request): Json<SensitivityRequest>,
 ) -> Result<Json<SensitivityResponse>, ApiError> {
+    profiles::apply_stored_profile(&state, claims.as_ref(), &mut request.input).await;
+    
     // Calculate baseline metrics first
     let result = crate::calculus::engineer::calculators::production::oee::engine::calculate_oee(request.input.clone())
         .map_err(ApiError::from)?;
@@ -212,8 +379,12 @@ pub struct LeverageResponse {
 
 /// Analyze leverage opportunities
 async fn leverage_handler(
-    Json(request): Json<LeverageRequest>,
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Json(mut request): Json<LeverageRequest>,
 ) -> Result<Json<LeverageResponse>, ApiError> {
+    profiles::apply_stored_profile(&state, claims.as_ref(), &mut request.input).await;
+    
     // Calculate baseline first
     let result = crate::calculus::engineer::calculators::production::oee::engine::calculate_oee(request.input.clone())
         .map_err(ApiError::from)?;
@@ -229,6 +400,60 @@ async fn leverage_handler(
     }))
 }
 
+// ============================================================================
+// Loss Tree Drill-Down Endpoint
+// ============================================================================
+
+/// Request body for fetching one branch of the loss tree
+#[derive(Debug, Deserialize)]
+pub struct LossSubtreeRequest {
+    pub input: crate::calculus::engineer::calculators::production::oee::OeeInput,
+    /// Prices every node when provided
+    pub economic_parameters: Option<crate::calculus::engineer::calculators::production::oee::domain::economics::EconomicParameters>,
+    /// Node ids below the root joined by `/` (default: the root)
+    #[serde(default)]
+    pub path: String,
+    /// Levels of children to include (default: 1)
+    pub depth: Option<usize>,
+}
+
+/// Response body for a loss tree branch
+#[derive(Debug, Serialize)]
+pub struct LossSubtreeResponse {
+    pub path: String,
+    pub node: crate::calculus::engineer::calculators::production::oee::domain::loss_tree::LossTreeNode,
+}
+
+/// Serve one node of the loss tree and its nearest children
+///
+/// Dashboards expand the tree lazily: children beyond `depth` are left out
+/// but `child_count` tells whether a node can be expanded further.
+async fn loss_subtree_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Json(mut request): Json<LossSubtreeRequest>,
+) -> Result<Json<LossSubtreeResponse>, ApiError> {
+    profiles::apply_stored_profile(&state, claims.as_ref(), &mut request.input).await;
+    
+    let result = match request.economic_parameters {
+        Some(economic_params) => crate::calculus::engineer::calculators::production::oee::engine::calculate_oee_with_economics(
+            request.input,
+            economic_params,
+        ),
+        None => crate::calculus::engineer::calculators::production::oee::engine::calculate_oee(request.input),
+    }.map_err(ApiError::from)?;
+    
+    let node = result.loss_tree
+        .subtree(&request.path)
+        .ok_or_else(|| ApiError::node_not_found(&request.path))?
+        .pruned(request.depth.unwrap_or(1));
+    
+    Ok(Json(LossSubtreeResponse {
+        path: request.path,
+        node,
+    }))
+}
+
 // ============================================================================
 // Temporal Scrap Analysis Endpoint
 // ============================================================================
@@ -410,6 +635,17 @@ impl ApiError {
         }
     }
     
+    pub fn node_not_found(path: &str) -> Self {
+        Self {
+            code: "NODE_NOT_FOUND".to_string(),
+            message_key: "api.error.node_not_found".to_string(),
+            params: serde_json::json!({
+                "path": path,
+            }),
+            status: StatusCode::NOT_FOUND,
+        }
+    }
+    
     pub fn invalid_input(message: &str) -> Self {
         Self {
             code: "INVALID_INPUT".to_string(),
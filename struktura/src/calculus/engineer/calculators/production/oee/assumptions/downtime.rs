@@ -10,6 +10,7 @@ pub struct DowntimeRecord {
     pub duration: InputValue<Duration>,
     pub reason: ReasonCode,
     /// When it occurred (optional for now)
+    #[serde(default, with = "timestamp::option")]
     pub timestamp: Option<DateTime<Utc>>,
     /// Additional context
     pub notes: Option<String>,
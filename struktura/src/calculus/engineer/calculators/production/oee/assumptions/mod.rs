@@ -11,82 +11,20 @@ pub mod cycle;
 pub mod downtime;
 pub mod thresholds;
 pub mod time;
+pub mod timestamp;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
-/// How a value was obtained - the foundation of trust
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum InputValue<T> {
-    /// User explicitly provided this value
-    Explicit(T),
-    /// We derived this from other inputs
-    Inferred(T),
-    /// System default fallback
-    Default(T),
-}
-
-impl<T> InputValue<T> {
-    /// Get the actual value, regardless of source
-    pub fn value(&self) -> &T {
-        match self {
-            InputValue::Explicit(v) => v,
-            InputValue::Inferred(v) => v,
-            InputValue::Default(v) => v,
-        }
-    }
-    
-    /// Get the value with ownership transfer
-    pub fn into_value(self) -> T {
-        match self {
-            InputValue::Explicit(v) => v,
-            InputValue::Inferred(v) => v,
-            InputValue::Default(v) => v,
-        }
-    }
-    
-    /// Check if this is an explicit value
-    pub fn is_explicit(&self) -> bool {
-        matches!(self, InputValue::Explicit(_))
-    }
-    
-    /// Check if this is inferred
-    pub fn is_inferred(&self) -> bool {
-        matches!(self, InputValue::Inferred(_))
-    }
-    
-    /// Check if this is a default
-    pub fn is_default(&self) -> bool {
-        matches!(self, InputValue::Default(_))
-    }
-    
-    /// Get the source type as string (for ledger)
-    pub fn source_type(&self) -> &'static str {
-        match self {
-            InputValue::Explicit(_) => "explicit",
-            InputValue::Inferred(_) => "inferred",
-            InputValue::Default(_) => "default",
-        }
-    }
-    
-    /// Map the value to a different type
-    pub fn map<U, F>(self, f: F) -> InputValue<U>
-    where
-        F: FnOnce(T) -> U,
-    {
-        match self {
-            InputValue::Explicit(v) => InputValue::Explicit(f(v)),
-            InputValue::Inferred(v) => InputValue::Inferred(f(v)),
-            InputValue::Default(v) => InputValue::Default(f(v)),
-        }
-    }
-}
+pub use crate::calculus::provenance::InputValue;
 
 /// Analysis time window
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisWindow {
+    #[serde(with = "timestamp")]
     pub start: DateTime<Utc>,
+    #[serde(with = "timestamp")]
     pub end: DateTime<Utc>,
 }
 
@@ -4,6 +4,7 @@
 //! All user-configurable, all tracked in ledger.
 
 use super::*;
+use crate::calculus::engineer::calculators::production::oee::domain::Percentage;
 
 /// Threshold definitions for loss categorization
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,14 +15,41 @@ pub struct ThresholdConfiguration {
     /// Maximum duration for "small stop" categorization
     pub small_stop_threshold: Duration,
     
-    /// Speed loss detection threshold (% below ideal)
-    pub speed_loss_threshold: f64,
+    /// Speed loss detection threshold (below ideal)
+    pub speed_loss_threshold: Percentage,
     
-    /// High scrap rate warning threshold (%)
-    pub high_scrap_rate_threshold: f64,
+    /// High scrap rate warning threshold
+    pub high_scrap_rate_threshold: Percentage,
     
-    /// Low utilization warning threshold (%)
-    pub low_utilization_threshold: f64,
+    /// Low utilization warning threshold
+    pub low_utilization_threshold: Percentage,
+
+    /// Where these values came from; thresholds sent with a request are explicit
+    #[serde(default)]
+    pub origin: ThresholdOrigin,
+}
+
+/// Provenance of a threshold configuration
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ThresholdOrigin {
+    /// Provided with the analysis request
+    #[default]
+    Request,
+    /// Loaded from a stored machine or line profile
+    Profile { profile_id: String, name: String },
+    /// Built-in defaults, nothing more specific was available
+    Default,
+}
+
+impl ThresholdOrigin {
+    /// Source label for the ledger
+    pub fn source_type(&self) -> &'static str {
+        match self {
+            ThresholdOrigin::Request | ThresholdOrigin::Profile { .. } => "explicit",
+            ThresholdOrigin::Default => "default",
+        }
+    }
 }
 
 impl ThresholdConfiguration {
@@ -30,9 +58,10 @@ impl ThresholdConfiguration {
         Self {
             micro_stoppage_threshold: Duration::from_secs(30),     // 30 seconds
             small_stop_threshold: Duration::from_secs(5 * 60),      // 5 minutes
-            speed_loss_threshold: 0.05,                             // 5% below ideal
-            high_scrap_rate_threshold: 0.20,                        // 20%
-            low_utilization_threshold: 0.30,                        // 30%
+            speed_loss_threshold: Percentage::from_fraction(0.05), // 5% below ideal
+            high_scrap_rate_threshold: Percentage::from_fraction(0.20), // 20%
+            low_utilization_threshold: Percentage::from_fraction(0.30), // 30%
+            origin: ThresholdOrigin::Default,
         }
     }
     
@@ -41,9 +70,10 @@ impl ThresholdConfiguration {
         Self {
             micro_stoppage_threshold: Duration::from_secs(15),
             small_stop_threshold: Duration::from_secs(3 * 60),
-            speed_loss_threshold: 0.02,
-            high_scrap_rate_threshold: 0.10,
-            low_utilization_threshold: 0.50,
+            speed_loss_threshold: Percentage::from_fraction(0.02),
+            high_scrap_rate_threshold: Percentage::from_fraction(0.10),
+            low_utilization_threshold: Percentage::from_fraction(0.50),
+            origin: ThresholdOrigin::Request,
         }
     }
     
@@ -52,11 +82,24 @@ impl ThresholdConfiguration {
         Self {
             micro_stoppage_threshold: Duration::from_secs(60),
             small_stop_threshold: Duration::from_secs(10 * 60),
-            speed_loss_threshold: 0.10,
-            high_scrap_rate_threshold: 0.30,
-            low_utilization_threshold: 0.20,
+            speed_loss_threshold: Percentage::from_fraction(0.10),
+            high_scrap_rate_threshold: Percentage::from_fraction(0.30),
+            low_utilization_threshold: Percentage::from_fraction(0.20),
+            origin: ThresholdOrigin::Request,
         }
     }
+
+    /// Whether a stored profile may replace these values
+    pub fn is_default(&self) -> bool {
+        self.origin == ThresholdOrigin::Default
+    }
+}
+
+/// Used when a request leaves `thresholds` out
+impl Default for ThresholdConfiguration {
+    fn default() -> Self {
+        Self::defaults()
+    }
 }
 
 /// Threshold application result
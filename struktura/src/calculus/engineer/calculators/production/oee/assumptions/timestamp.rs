@@ -0,0 +1,118 @@
+//! Timestamp inputs
+//!
+//! Shop-floor exports rarely agree on a time format, so every input
+//! timestamp accepts:
+//!
+//! - epoch seconds, integer or fractional: `1709280000`
+//! - RFC 3339 with any offset: `"2024-03-01T09:00:00+01:00"`
+//! - a local time with its offset: `{"local": "2024-03-01 09:00", "offset": "+01:00"}`
+//! - a naive time, taken as UTC: `"2024-03-01T08:00:00"`
+//!
+//! Everything is normalized to UTC and written back as RFC 3339.
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const NAIVE_FORMATS: &[&str] = &["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"];
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TimestampInput {
+    Epoch(f64),
+    Text(String),
+    Local { local: String, offset: String },
+}
+
+fn parse_naive(s: &str) -> Option<NaiveDateTime> {
+    NAIVE_FORMATS.iter().find_map(|f| NaiveDateTime::parse_from_str(s, f).ok())
+}
+
+/// Parse a UTC offset: `Z`, `UTC`, `+01:00`, `-0500` or `+2`
+pub fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case("z") || s.eq_ignore_ascii_case("utc") {
+        return FixedOffset::east_opt(0);
+    }
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (digits[..2].parse::<i32>().ok()?, digits[2..].parse::<i32>().ok()?),
+        _ => return None,
+    };
+    if minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Parse any accepted timestamp string into UTC
+pub fn parse_timestamp(s: &str) -> Result<DateTime<Utc>, String> {
+    let s = s.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    if let Some(naive) = parse_naive(s) {
+        return Ok(Utc.from_utc_datetime(&naive));
+    }
+    if let Ok(secs) = s.parse::<f64>() {
+        return from_epoch(secs);
+    }
+    Err(format!("unrecognized timestamp '{}': use RFC 3339 or epoch seconds", s))
+}
+
+fn from_epoch(secs: f64) -> Result<DateTime<Utc>, String> {
+    if !secs.is_finite() {
+        return Err("epoch seconds must be finite".to_string());
+    }
+    let whole = secs.floor();
+    let nanos = ((secs - whole) * 1e9).round().min(999_999_999.0) as u32;
+    DateTime::from_timestamp(whole as i64, nanos).ok_or_else(|| format!("epoch seconds {} out of range", secs))
+}
+
+fn from_local(local: &str, offset: &str) -> Result<DateTime<Utc>, String> {
+    let offset = parse_offset(offset).ok_or_else(|| format!("invalid UTC offset '{}'", offset))?;
+    let naive = parse_naive(local.trim()).ok_or_else(|| format!("invalid local time '{}'", local))?;
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or_else(|| format!("local time '{}' does not exist at offset {}", local, offset))
+}
+
+fn resolve(input: TimestampInput) -> Result<DateTime<Utc>, String> {
+    match input {
+        TimestampInput::Epoch(secs) => from_epoch(secs),
+        TimestampInput::Text(s) => parse_timestamp(&s),
+        TimestampInput::Local { local, offset } => from_local(&local, &offset),
+    }
+}
+
+/// `#[serde(with = "timestamp")]` for `DateTime<Utc>` fields
+pub fn serialize<S: Serializer>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+    resolve(TimestampInput::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+}
+
+/// `#[serde(default, with = "timestamp::option")]` for `Option<DateTime<Utc>>` fields
+pub mod option {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error> {
+        Option::<TimestampInput>::deserialize(deserializer)?
+            .map(resolve)
+            .transpose()
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -6,6 +6,7 @@
 //! Per Section 14.2: "Require confidence bands on every economic input"
 
 use super::*;
+use super::loss_tree::{LossTree, LossTreeNode};
 
 /// Economic parameters with uncertainty
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -229,4 +230,61 @@ pub fn analyze_economics(
         opportunity_cost,
         total_impact,
     }
-}
\ No newline at end of file
+}
+
+/// Price every node of a loss tree
+///
+/// Lost time is valued at the units the machine could have made at ideal
+/// rate times their marginal contribution. Quality losses also lose the
+/// material of the rejected units. The root carries the total.
+pub fn price_loss_tree(
+    tree: &mut LossTree,
+    theoretical_units_per_hour: f64,
+    params: &EconomicParameters,
+) {
+    for category in &mut tree.root.children {
+        let mut assumptions = vec![
+            "economics.assumptions.marginal_contribution".to_string(),
+            "economics.assumptions.theoretical_capacity".to_string(),
+        ];
+        let mut value_per_unit = params.marginal_contribution;
+        if category.id == "quality_losses" {
+            let (low, central, high) = params.material_cost;
+            value_per_unit = (value_per_unit.0 + low, value_per_unit.1 + central, value_per_unit.2 + high);
+            assumptions.push("economics.assumptions.scrap_is_total_loss".to_string());
+        }
+        price_loss_node(category, theoretical_units_per_hour, value_per_unit, &assumptions, &params.currency);
+    }
+    
+    let impacts: Vec<EconomicImpact> = tree.root.children
+        .iter()
+        .filter_map(|c| c.economic_impact.clone())
+        .collect();
+    let mut total = sum_economic_impacts(&impacts);
+    total.currency = params.currency.clone();
+    tree.root.economic_impact = Some(total);
+}
+
+fn price_loss_node(
+    node: &mut LossTreeNode,
+    theoretical_units_per_hour: f64,
+    value_per_unit: (f64, f64, f64),
+    assumptions: &[String],
+    currency: &str,
+) {
+    let lost_units = node.duration.as_secs_f64() / 3600.0 * theoretical_units_per_hour;
+    let (low, central, high) = value_per_unit;
+    
+    node.economic_impact = Some(EconomicImpact {
+        description_key: node.category_key.clone(),
+        low_estimate: lost_units * low,
+        central_estimate: lost_units * central,
+        high_estimate: lost_units * high,
+        currency: currency.to_string(),
+        assumptions: assumptions.to_vec(),
+    });
+    
+    for child in &mut node.children {
+        price_loss_node(child, theoretical_units_per_hour, value_per_unit, assumptions, currency);
+    }
+}
@@ -4,14 +4,31 @@
 //! This is attribution, NOT causality.
 //! 
 //! Think of it as a budget breakdown, not a forensic investigation.
+//!
+//! Below the six big losses, nodes follow the reason-code hierarchy of the
+//! records they were built from, so a dashboard can drill from
+//! "Availability losses" down to "Setup → Changeover A→B". Each node has an
+//! `id`, and the `/`-joined ids from the root form its path.
 
 use super::*;
 
+/// Label for time whose reason code stops above this level
+pub const UNSPECIFIED_REASON: &str = "Unspecified";
+
+/// Time lost under one reason-code path
+pub type ReasonEntry = (Vec<String>, Duration);
+
 /// A node in the loss tree (hierarchical structure)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LossTreeNode {
+    /// Path segment, unique among siblings
+    #[serde(default)]
+    pub id: String,
     /// Category name (translation key)
     pub category_key: String,
+    /// Reason text for reason-code nodes, which have no translation key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
     /// Human-readable description (translation key)
     pub description_key: String,
     /// Time attributed to this category
@@ -22,6 +39,12 @@ pub struct LossTreeNode {
     pub percentage_of_parent: Option<f64>,
     /// Child categories
     pub children: Vec<LossTreeNode>,
+    /// Number of children, kept when a subtree is served without them
+    #[serde(default)]
+    pub child_count: usize,
+    /// Value of the lost time, when economic parameters were provided
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub economic_impact: Option<EconomicImpact>,
     /// How this was determined
     pub source: ValueSource,
 }
@@ -41,18 +64,102 @@ impl LossTreeNode {
         };
         
         Self {
+            id: category_key.trim_start_matches("loss_tree.").to_string(),
             category_key: category_key.to_string(),
+            label: None,
             description_key: description_key.to_string(),
             duration,
             percentage_of_planned,
             percentage_of_parent: None,
             children: Vec::new(),
+            child_count: 0,
+            economic_impact: None,
             source,
         }
     }
     
+    /// Node for one level of a reason-code path
+    pub fn reason(label: &str, duration: Duration, planned_time: Duration, source: ValueSource) -> Self {
+        let mut node = Self::new("loss_tree.reason", "loss_tree.reason_desc", duration, planned_time, source);
+        node.id = slug(label);
+        node.label = Some(label.to_string());
+        node
+    }
+    
     pub fn add_child(&mut self, child: LossTreeNode) {
         self.children.push(child);
+        self.child_count = self.children.len();
+    }
+    
+    /// Attach reason-code children built from `(path, duration)` entries
+    ///
+    /// Entries are grouped by their first path segment, recursively, with
+    /// the largest losses first. A level where no entry carries a reason
+    /// gets no children.
+    pub fn attach_reasons(&mut self, entries: &[ReasonEntry], planned_time: Duration) {
+        if entries.iter().all(|(path, _)| path.is_empty()) {
+            return;
+        }
+        
+        let mut groups: Vec<(String, Vec<ReasonEntry>)> = Vec::new();
+        for (path, duration) in entries {
+            let (label, rest) = match path.split_first() {
+                Some((head, rest)) => (head.as_str(), rest.to_vec()),
+                None => (UNSPECIFIED_REASON, Vec::new()),
+            };
+            match groups.iter_mut().find(|(l, _)| l == label) {
+                Some((_, members)) => members.push((rest, *duration)),
+                None => groups.push((label.to_string(), vec![(rest, *duration)])),
+            }
+        }
+        
+        let mut children: Vec<LossTreeNode> = groups
+            .into_iter()
+            .map(|(label, members)| {
+                let total = members.iter().map(|(_, d)| *d).sum();
+                let mut node = Self::reason(&label, total, planned_time, self.source);
+                node.attach_reasons(&members, planned_time);
+                node
+            })
+            .collect();
+        children.sort_by_key(|c| std::cmp::Reverse(c.duration));
+        
+        for mut child in children {
+            // Distinct reasons can share a slug; keep ids unique among siblings
+            let base = child.id.clone();
+            let mut n = 2;
+            while self.children.iter().any(|c| c.id == child.id) {
+                child.id = format!("{}_{}", base, n);
+                n += 1;
+            }
+            self.add_child(child);
+        }
+    }
+    
+    /// Descendant at `path` (ids relative to this node)
+    pub fn find(&self, path: &[&str]) -> Option<&LossTreeNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((id, rest)) => self.children.iter().find(|c| c.id == *id)?.find(rest),
+        }
+    }
+    
+    pub fn find_mut(&mut self, path: &[&str]) -> Option<&mut LossTreeNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((id, rest)) => self.children.iter_mut().find(|c| c.id == *id)?.find_mut(rest),
+        }
+    }
+    
+    /// Copy limited to `depth` levels below this node
+    pub fn pruned(&self, depth: usize) -> LossTreeNode {
+        let mut node = self.clone();
+        if depth == 0 {
+            node.children.clear();
+        } else {
+            node.children = self.children.iter().map(|c| c.pruned(depth - 1)).collect();
+        }
+        node
     }
     
     /// Recalculate parent percentages after tree construction
@@ -202,6 +309,14 @@ impl LossTree {
         tree
     }
     
+    /// Node at a `/`-separated path of ids below the root
+    ///
+    /// The empty path is the root: `"availability_losses/setup_adjustments"`
+    pub fn subtree(&self, path: &str) -> Option<&LossTreeNode> {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        self.root.find(&segments)
+    }
+    
     /// Flatten tree for reporting (depth-first traversal)
     pub fn flatten(&self) -> Vec<LossTreeNode> {
         let mut result = Vec::new();
@@ -216,3 +331,17 @@ impl LossTree {
         }
     }
 }
+
+/// Path segment for free-text reasons: "Changeover A→B" becomes "changeover_a_b"
+fn slug(label: &str) -> String {
+    let mut slug = String::with_capacity(label.len());
+    for c in label.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.ends_with('_') {
+            slug.push('_');
+        }
+    }
+    let slug = slug.trim_matches('_');
+    if slug.is_empty() { "reason".to_string() } else { slug.to_string() }
+}
@@ -13,16 +13,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
 
-/// Represents how a value was derived - critical for traceability
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum ValueSource {
-    /// User provided this value explicitly
-    Explicit,
-    /// We calculated/inferred this from other inputs
-    Inferred,
-    /// System default was used
-    Default,
-}
+pub use crate::calculus::percentage::{self, Percentage};
+pub use crate::calculus::provenance::ValueSource;
 
 /// A calculated metric with full traceability
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +33,13 @@ pub struct TrackedMetric {
     pub confidence: Confidence,
 }
 
+impl TrackedMetric {
+    /// The value as a typed percentage, for metrics measured in percent
+    pub fn as_percentage(&self) -> Option<Percentage> {
+        (self.unit_key == "units.percentage").then(|| Percentage::from_fraction(self.value))
+    }
+}
+
 /// Confidence level based on input quality
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Ord, Eq, PartialOrd)]
 pub enum Confidence {
@@ -56,8 +55,8 @@ pub struct LossAllocation {
     pub category_key: String,
     /// Duration of this loss
     pub duration: Duration,
-    /// Percentage of total time
-    pub percentage: f64,
+    /// Share of total time
+    pub percentage: Percentage,
     /// Sub-categories (hierarchical)
     pub sub_allocations: Vec<LossAllocation>,
 }
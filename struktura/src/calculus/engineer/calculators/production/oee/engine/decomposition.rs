@@ -1,9 +1,10 @@
 //! Loss tree decomposition
 //! 
 //! Build hierarchical loss structures from input data.
+//! Breakdowns, setups and small stops are broken down further by reason code.
 
 use crate::calculus::engineer::calculators::production::oee::{
-    domain::{loss_tree::LossTree, ValueSource},
+    domain::loss_tree::{LossTree, ReasonEntry},
     OeeInput,
 };
 use std::time::Duration;
@@ -20,7 +21,7 @@ pub fn build_loss_tree(input: &OeeInput) -> LossTree {
     let startup_rejects = Duration::ZERO; // Would need temporal data
     let production_rejects = calculate_production_rejects(input);
     
-    LossTree::build_six_big_losses(
+    let mut tree = LossTree::build_six_big_losses(
         planned_time,
         breakdowns,
         setup_adjustments,
@@ -28,7 +29,52 @@ pub fn build_loss_tree(input: &OeeInput) -> LossTree {
         speed_losses,
         startup_rejects,
         production_rejects,
-    )
+    );
+    
+    let reasons = [
+        (["availability_losses", "breakdowns"], breakdown_reasons(input)),
+        (["availability_losses", "setup_adjustments"], setup_reasons(input)),
+        (["performance_losses", "small_stops"], small_stop_reasons(input)),
+    ];
+    for (path, entries) in reasons {
+        if let Some(node) = tree.root.find_mut(&path) {
+            node.attach_reasons(&entries, planned_time);
+        }
+    }
+    tree.root.calculate_parent_percentages();
+    
+    tree
+}
+
+/// Reason paths and durations of breakdown records
+fn breakdown_reasons(input: &OeeInput) -> Vec<ReasonEntry> {
+    input.downtimes.records
+        .iter()
+        .filter(|r| is_breakdown(&r.reason))
+        .map(|r| (r.reason.path.clone(), *r.duration.value()))
+        .collect()
+}
+
+/// Reason paths and durations of setup allocations
+fn setup_reasons(input: &OeeInput) -> Vec<ReasonEntry> {
+    use crate::calculus::engineer::calculators::production::oee::assumptions::MachineState;
+    
+    input.time_model.allocations
+        .iter()
+        .filter(|a| a.state == MachineState::Setup)
+        .map(|a| (a.reason.as_ref().map(|r| r.path.clone()).unwrap_or_default(), *a.duration.value()))
+        .collect()
+}
+
+/// Reason paths and durations of small stops
+fn small_stop_reasons(input: &OeeInput) -> Vec<ReasonEntry> {
+    let threshold = input.thresholds.small_stop_threshold;
+    
+    input.downtimes.records
+        .iter()
+        .filter(|r| *r.duration.value() < threshold && is_stoppage(&r.reason))
+        .map(|r| (r.reason.path.clone(), *r.duration.value()))
+        .collect()
 }
 
 /// Calculate breakdown time from downtime records
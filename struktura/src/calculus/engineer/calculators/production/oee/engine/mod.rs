@@ -11,11 +11,13 @@ pub mod temporal_scrap;
 pub mod multi_machine;
 
 use crate::calculus::engineer::calculators::production::oee::{
+    assumptions::thresholds::ThresholdOrigin,
     domain::{self, Confidence, ValueSource},
     ledger::{assumption_tracking::AssumptionTracker, AssumptionLedger, ImpactLevel},
     validation::{self, ValidationResult},
     OeeInput, OeeResult,
 };
+use crate::calculus::quality::DataQuality;
 
 /// Main calculation pipeline
 pub fn calculate_oee(input: OeeInput) -> Result<OeeResult, EngineError> {
@@ -40,6 +42,9 @@ pub fn calculate_oee(input: OeeInput) -> Result<OeeResult, EngineError> {
     // Step 7: Add validation warnings to ledger
     transfer_validation_to_ledger(&validation_result, &mut ledger);
     
+    // Step 8: Score data quality from the ledger
+    let data_quality = assess_data_quality(&ledger, &input, &validation_result);
+    
     Ok(OeeResult {
         core_metrics,
         extended_metrics,
@@ -47,6 +52,7 @@ pub fn calculate_oee(input: OeeInput) -> Result<OeeResult, EngineError> {
         economic_analysis: None, // Calculated separately if parameters provided
         ledger,
         validation: validation_result,
+        data_quality,
     })
 }
 
@@ -78,6 +84,7 @@ pub fn calculate_oee_with_economics(
     );
     
     result.economic_analysis = Some(economic_analysis);
+    domain::economics::price_loss_tree(&mut result.loss_tree, theoretical_units_per_hour, &economic_params);
     
     Ok(result)
 }
@@ -195,22 +202,37 @@ fn build_ledger(input: &OeeInput) -> AssumptionLedger {
     );
     
     // Track thresholds
+    let threshold_source = input.thresholds.origin.source_type();
     tracker.track_threshold(
         "micro_stoppage_threshold",
         input.thresholds.micro_stoppage_threshold.as_secs_f64(),
         "units.seconds",
         "ledger.thresholds.micro_stoppage_rationale",
+        threshold_source,
     );
     
     tracker.track_threshold(
         "speed_loss_threshold",
-        input.thresholds.speed_loss_threshold,
+        input.thresholds.speed_loss_threshold.fraction(),
         "units.percentage",
         "ledger.thresholds.speed_loss_rationale",
+        threshold_source,
+    );
+    
+    tracker.track_threshold(
+        "high_scrap_rate_threshold",
+        input.thresholds.high_scrap_rate_threshold.fraction(),
+        "units.percentage",
+        "ledger.thresholds.high_scrap_rate_rationale",
+        threshold_source,
     );
     
     // Add metadata
     let mut ledger = tracker.finish();
+    if let ThresholdOrigin::Profile { profile_id, name } = &input.thresholds.origin {
+        ledger.add_metadata("threshold_profile_id", profile_id);
+        ledger.add_metadata("threshold_profile", name);
+    }
     ledger.add_metadata("machine_id", &input.machine.machine_id);
     if let Some(line) = &input.machine.line_id {
         ledger.add_metadata("line_id", line);
@@ -285,6 +307,46 @@ fn transfer_validation_to_ledger(validation: &ValidationResult, ledger: &mut Ass
     }
 }
 
+/// Numeric data-quality score behind the per-metric `Confidence`
+///
+/// Defaulted assumptions cost points by impact, inferred ones half as much.
+/// Default thresholds and validation warnings take a few points each.
+fn assess_data_quality(
+    ledger: &AssumptionLedger,
+    input: &OeeInput,
+    validation: &ValidationResult,
+) -> DataQuality {
+    use crate::calculus::engineer::calculators::production::oee::validation::Severity;
+    
+    let mut quality = DataQuality::default();
+    
+    for assumption in &ledger.assumptions {
+        let weight = match assumption.impact {
+            ImpactLevel::Critical => 20,
+            ImpactLevel::High => 12,
+            ImpactLevel::Medium => 6,
+            ImpactLevel::Low => 3,
+            ImpactLevel::Info => 0,
+        };
+        match assumption.source.as_str() {
+            "default" => quality.deduct("quality.default_input", Some(&assumption.assumption_key), weight),
+            "inferred" => quality.deduct("quality.inferred_input", Some(&assumption.assumption_key), weight / 2),
+            _ => {}
+        }
+    }
+    
+    if input.thresholds.is_default() {
+        quality.deduct("quality.default_thresholds", None, 5);
+    }
+    
+    // At most 15 points for warnings, however many there are
+    for issue in validation.issues.iter().filter(|i| i.severity == Severity::Warning).take(5) {
+        quality.deduct("quality.validation_warning", Some(&issue.code), 3);
+    }
+    
+    quality
+}
+
 /// Calculate lost units (theoretical max - actual)
 fn calculate_lost_units(input: &OeeInput) -> u32 {
     let running_time = input.time_model.running_time();
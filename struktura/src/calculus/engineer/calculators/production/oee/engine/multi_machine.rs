@@ -7,6 +7,7 @@ use crate::calculus::engineer::calculators::production::oee::{
     domain::{metrics::CoreMetrics, Confidence},
     OeeResult,
 };
+use crate::calculus::rules;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
@@ -309,21 +310,27 @@ fn analyze_bottlenecks(machines: &[MachineOeeData], system_oee: f64) -> Bottlene
             // Estimate throughput impact
             let throughput_impact = (1.0 - oee) * 100.0;
             
-            // Determine recommended action based on worst component
+            // Recommended action for the weakest component, from the `oee.bottleneck` rules
             let metrics = &machine.result.core_metrics;
-            let recommended_action = if metrics.availability.value < metrics.performance.value.min(metrics.quality.value) {
-                "bottleneck.action.reduce_downtime"
-            } else if metrics.performance.value < metrics.quality.value {
-                "bottleneck.action.improve_speed"
-            } else {
-                "bottleneck.action.improve_quality"
-            };
+            let facts = [
+                ("oee", oee),
+                ("availability", metrics.availability.value),
+                ("performance", metrics.performance.value),
+                ("quality", metrics.quality.value),
+            ]
+            .into_iter()
+            .collect();
+            let recommended_action = rules::evaluate("oee.bottleneck", &facts)
+                .into_iter()
+                .next()
+                .map(|r| r.message_key)
+                .unwrap_or_else(|| "bottleneck.action.improve_quality".to_string());
             
             bottlenecks.push(BottleneckInfo {
                 machine_id: machine.machine_id.clone(),
                 oee,
                 throughput_impact,
-                recommended_action_key: recommended_action.to_string(),
+                recommended_action_key: recommended_action,
             });
         }
     }
@@ -3,7 +3,8 @@
 //! Distinguishes startup scrap from steady-state production scrap.
 //! Requires temporal data (timestamps on scrap events).
 
-use crate::calculus::engineer::calculators::production::oee::assumptions::AnalysisWindow;
+use crate::calculus::engineer::calculators::production::oee::assumptions::{timestamp, AnalysisWindow};
+use crate::calculus::engineer::calculators::production::oee::domain::Percentage;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -12,6 +13,7 @@ use std::time::Duration;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScrapEvent {
     /// When the scrap occurred
+    #[serde(with = "timestamp")]
     pub timestamp: DateTime<Utc>,
     /// Number of units scrapped
     pub units: u32,
@@ -78,8 +80,8 @@ pub struct TemporalScrapAnalysis {
     pub steady_state_scrap: u32,
     /// Startup window duration used
     pub startup_window_duration: Duration,
-    /// Startup scrap rate (% of total)
-    pub startup_scrap_percentage: f64,
+    /// Share of total scrap made during startup
+    pub startup_scrap_percentage: Percentage,
     /// Time equivalent of startup scrap
     pub startup_scrap_time_loss: Duration,
     /// Time equivalent of steady-state scrap
@@ -100,8 +102,8 @@ pub struct ScrapByPhase {
 pub struct StartupWindowConfig {
     /// Fixed duration from analysis start (e.g., first 30 minutes)
     pub fixed_duration: Option<Duration>,
-    /// Or percentage of total time (e.g., first 10%)
-    pub percentage_of_total: Option<f64>,
+    /// Or share of total time (e.g., first 10%)
+    pub percentage_of_total: Option<Percentage>,
     /// Or dynamic detection based on scrap rate threshold
     pub dynamic_threshold: Option<f64>,
 }
@@ -117,7 +119,7 @@ impl StartupWindowConfig {
     }
     
     /// Use a percentage of total production time
-    pub fn percentage(pct: f64) -> Self {
+    pub fn percentage(pct: Percentage) -> Self {
         Self {
             fixed_duration: None,
             percentage_of_total: Some(pct),
@@ -138,7 +140,7 @@ impl StartupWindowConfig {
     pub fn default() -> Self {
         Self {
             fixed_duration: Some(Duration::from_secs(30 * 60)), // 30 minutes
-            percentage_of_total: Some(0.10), // 10%
+            percentage_of_total: Some(Percentage::from_fraction(0.10)),
             dynamic_threshold: None,
         }
     }
@@ -179,11 +181,7 @@ pub fn analyze_temporal_scrap(
     let steady_state_scrap_time_loss = ideal_cycle_time * steady_state_scrap;
     
     // Calculate percentages
-    let startup_scrap_percentage = if total_scrap > 0 {
-        (startup_scrap as f64 / total_scrap as f64) * 100.0
-    } else {
-        0.0
-    };
+    let startup_scrap_percentage = Percentage::of(startup_scrap as f64, total_scrap as f64);
     
     TemporalScrapAnalysis {
         total_scrap,
@@ -219,7 +217,7 @@ fn determine_startup_end(
     
     // Percentage of total
     if let Some(pct) = config.percentage_of_total {
-        let duration_secs = (total_duration.as_secs_f64() * pct) as i64;
+        let duration_secs = (total_duration.as_secs_f64() * pct.fraction()) as i64;
         candidates.push(start + chrono::Duration::seconds(duration_secs));
     }
     
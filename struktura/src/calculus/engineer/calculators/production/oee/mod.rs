@@ -7,10 +7,12 @@ pub mod api;
 pub mod assumptions;
 pub mod domain;
 pub mod engine;
-pub mod ledger;
+pub mod profiles;
 pub mod validation;
 pub mod tests;
 
+pub use crate::calculus::provenance::ledger;
+
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -23,6 +25,8 @@ pub struct OeeInput {
     pub production: assumptions::counts::ProductionSummary,
     pub cycle_time: assumptions::cycle::CycleTimeModel,
     pub downtimes: assumptions::downtime::DowntimeCollection,
+    /// Omitted: the caller's profile for this machine or line, else defaults
+    #[serde(default)]
     pub thresholds: assumptions::thresholds::ThresholdConfiguration,
 }
 
@@ -46,6 +50,9 @@ pub struct OeeResult {
     
     /// Validation result
     pub validation: validation::ValidationResult,
+    
+    /// 0–100 score of how much the analysis rests on explicit inputs
+    pub data_quality: crate::calculus::quality::DataQuality,
 }
 
 /// Economic parameters for cost analysis (optional)
@@ -0,0 +1,317 @@
+//! Threshold profiles: stored per-machine and per-line thresholds
+//!
+//! A plant rarely runs every asset on the same cutoffs. Organizations save a
+//! profile for a machine or a whole line, and analyses that leave
+//! `thresholds` out pick it up automatically, a machine profile winning over
+//! its line's. The ledger then records the thresholds as explicit and names
+//! the profile they came from.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::types::time::OffsetDateTime;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::calculus::engineer::calculators::production::oee::{
+    assumptions::{
+        thresholds::{ThresholdConfiguration, ThresholdOrigin},
+        MachineContext,
+    },
+    domain::Percentage,
+    OeeInput,
+};
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+use crate::tenancy::{TenantOwned, TenantScope};
+
+/// Longest stop that may still be classed as a micro-stoppage
+const MAX_MICRO_STOPPAGE_SECONDS: f64 = 3600.0;
+
+// ============================================================================
+// Models
+// ============================================================================
+
+/// Thresholds saved for one machine or one line
+#[derive(Debug, Clone, Serialize)]
+pub struct ThresholdProfile {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub name: String,
+    pub machine_id: Option<String>,
+    pub line_id: Option<String>,
+    /// Stops shorter than this are micro-stoppages
+    pub micro_stoppage_seconds: f64,
+    pub speed_loss_threshold: Percentage,
+    /// Scrap rate that raises the high-scrap alarm
+    pub high_scrap_rate_threshold: Percentage,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(sqlx::FromRow)]
+struct ProfileRow {
+    id: Uuid,
+    org_id: Uuid,
+    name: String,
+    machine_id: Option<String>,
+    line_id: Option<String>,
+    micro_stoppage_seconds: f64,
+    speed_loss_fraction: f64,
+    high_scrap_rate_fraction: f64,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+}
+
+impl From<ProfileRow> for ThresholdProfile {
+    fn from(row: ProfileRow) -> Self {
+        Self {
+            id: row.id,
+            org_id: row.org_id,
+            name: row.name,
+            machine_id: row.machine_id,
+            line_id: row.line_id,
+            micro_stoppage_seconds: row.micro_stoppage_seconds,
+            speed_loss_threshold: Percentage::from_fraction(row.speed_loss_fraction),
+            high_scrap_rate_threshold: Percentage::from_fraction(row.high_scrap_rate_fraction),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+impl TenantOwned for ThresholdProfile {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+/// A profile targets exactly one of `machine_id` or `line_id`
+#[derive(Debug, Deserialize, Validate)]
+#[validate(schema(function = "validate_target"))]
+pub struct ThresholdProfilePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(min = 1, max = 100))]
+    pub machine_id: Option<String>,
+    #[validate(length(min = 1, max = 100))]
+    pub line_id: Option<String>,
+    #[validate(range(min = 0.0, max = 3600.0))]
+    pub micro_stoppage_seconds: f64,
+    #[validate(custom(function = "validate_ratio"))]
+    pub speed_loss_threshold: Percentage,
+    #[validate(custom(function = "validate_ratio"))]
+    pub high_scrap_rate_threshold: Percentage,
+}
+
+fn validate_target(payload: &ThresholdProfilePayload) -> Result<(), ValidationError> {
+    if payload.machine_id.is_some() == payload.line_id.is_some() {
+        return Err(ValidationError::new("machine_id or line_id, not both"));
+    }
+    Ok(())
+}
+
+fn validate_ratio(value: &Percentage) -> Result<(), ValidationError> {
+    if (0.0..=1.0).contains(&value.fraction()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("between 0% and 100%"))
+    }
+}
+
+const PROFILE_COLUMNS: &str = "id, org_id, name, machine_id, line_id, micro_stoppage_seconds, \
+    speed_loss_fraction, high_scrap_rate_fraction, created_at, updated_at";
+
+impl ThresholdProfile {
+    /// Overwrite the profiled thresholds and mark them as coming from here
+    pub fn apply(&self, thresholds: &mut ThresholdConfiguration) {
+        thresholds.micro_stoppage_threshold =
+            Duration::from_secs_f64(self.micro_stoppage_seconds.clamp(0.0, MAX_MICRO_STOPPAGE_SECONDS));
+        thresholds.speed_loss_threshold = self.speed_loss_threshold;
+        thresholds.high_scrap_rate_threshold = self.high_scrap_rate_threshold;
+        thresholds.origin = ThresholdOrigin::Profile {
+            profile_id: self.id.to_string(),
+            name: self.name.clone(),
+        };
+    }
+}
+
+// ============================================================================
+// Engine lookup
+// ============================================================================
+
+/// The organization's profile for a machine, else for its line
+pub async fn find_for_machine(
+    state: &AppState,
+    scope: &TenantScope,
+    machine: &MachineContext,
+) -> Result<Option<ThresholdProfile>, AppError> {
+    let row = sqlx::query_as::<_, ProfileRow>(&format!(
+        "SELECT {PROFILE_COLUMNS} FROM threshold_profiles \
+         WHERE org_id = $1 AND (machine_id = $2 OR line_id = $3) \
+         ORDER BY machine_id IS NULL LIMIT 1"
+    ))
+    .bind(scope.org_id)
+    .bind(&machine.machine_id)
+    .bind(&machine.line_id)
+    .fetch_optional(&state.pool)
+    .await?;
+
+    row.map(|row| scope.guard(ThresholdProfile::from(row))).transpose()
+}
+
+/// Replace default thresholds with the caller's stored profile, if any
+///
+/// Thresholds sent with the request always win. A failed lookup is logged
+/// and the analysis continues on defaults.
+pub async fn apply_stored_profile(state: &AppState, claims: Option<&Claims>, input: &mut OeeInput) {
+    if state.sandbox || !input.thresholds.is_default() {
+        return;
+    }
+    let Some(claims) = claims else {
+        return;
+    };
+
+    let profile = match state.tenants.resolve(&state.pool, claims).await {
+        Ok(scope) => find_for_machine(state, &scope, &input.machine).await,
+        Err(e) => Err(e),
+    };
+    match profile {
+        Ok(Some(profile)) => profile.apply(&mut input.thresholds),
+        Ok(None) => {}
+        Err(e) => tracing::warn!(
+            error = ?e,
+            machine_id = %input.machine.machine_id,
+            "threshold profile lookup failed, using defaults"
+        ),
+    }
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+pub async fn list_profiles_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+) -> Result<Json<Vec<ThresholdProfile>>, AppError> {
+    let rows = sqlx::query_as::<_, ProfileRow>(&format!(
+        "SELECT {PROFILE_COLUMNS} FROM threshold_profiles WHERE org_id = $1 \
+         ORDER BY line_id NULLS FIRST, machine_id NULLS FIRST, name"
+    ))
+    .bind(scope.org_id)
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    Ok(Json(scope.guard_all(rows.into_iter().map(ThresholdProfile::from).collect())?))
+}
+
+pub async fn get_profile_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ThresholdProfile>, AppError> {
+    let row = sqlx::query_as::<_, ProfileRow>(&format!(
+        "SELECT {PROFILE_COLUMNS} FROM threshold_profiles WHERE id = $1 AND org_id = $2"
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    Ok(Json(scope.guard(ThresholdProfile::from(row))?))
+}
+
+pub async fn create_profile_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<ThresholdProfilePayload>,
+) -> Result<(StatusCode, Json<ThresholdProfile>), AppError> {
+    payload.validate()?;
+
+    let row = sqlx::query_as::<_, ProfileRow>(&format!(
+        r#"
+        INSERT INTO threshold_profiles
+            (org_id, name, machine_id, line_id, micro_stoppage_seconds,
+             speed_loss_fraction, high_scrap_rate_fraction, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING {PROFILE_COLUMNS}
+        "#
+    ))
+    .bind(scope.org_id)
+    .bind(&payload.name)
+    .bind(&payload.machine_id)
+    .bind(&payload.line_id)
+    .bind(payload.micro_stoppage_seconds)
+    .bind(payload.speed_loss_threshold.fraction())
+    .bind(payload.high_scrap_rate_threshold.fraction())
+    .bind(scope.user_id)
+    .fetch_one(&app_state.pool)
+    .await?;
+
+    sec::log_security_event("THRESHOLD_PROFILE_CREATE", Some(&scope.username), None, "Success");
+
+    Ok((StatusCode::CREATED, Json(row.into())))
+}
+
+pub async fn update_profile_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ThresholdProfilePayload>,
+) -> Result<Json<ThresholdProfile>, AppError> {
+    payload.validate()?;
+
+    let row = sqlx::query_as::<_, ProfileRow>(&format!(
+        r#"
+        UPDATE threshold_profiles
+        SET name = $3, machine_id = $4, line_id = $5, micro_stoppage_seconds = $6,
+            speed_loss_fraction = $7, high_scrap_rate_fraction = $8,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1 AND org_id = $2
+        RETURNING {PROFILE_COLUMNS}
+        "#
+    ))
+    .bind(id)
+    .bind(scope.org_id)
+    .bind(&payload.name)
+    .bind(&payload.machine_id)
+    .bind(&payload.line_id)
+    .bind(payload.micro_stoppage_seconds)
+    .bind(payload.speed_loss_threshold.fraction())
+    .bind(payload.high_scrap_rate_threshold.fraction())
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("THRESHOLD_PROFILE_UPDATE", Some(&scope.username), None, "Success");
+
+    Ok(Json(row.into()))
+}
+
+pub async fn delete_profile_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let result = sqlx::query("DELETE FROM threshold_profiles WHERE id = $1 AND org_id = $2")
+        .bind(id)
+        .bind(scope.org_id)
+        .execute(&app_state.pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("THRESHOLD_PROFILE_DELETE", Some(&scope.username), None, "Success");
+
+    Ok(StatusCode::NO_CONTENT)
+}
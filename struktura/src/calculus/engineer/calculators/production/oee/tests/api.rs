@@ -344,4 +344,47 @@ fn test_api_confidence_in_response() {
             crate::calculus::engineer::calculators::production::oee::domain::Confidence::Low
         )
     );
-}
\ No newline at end of file
+}
+#[test]
+fn test_wizard_definition_covers_input() {
+    let wizard = crate::calculus::engineer::calculators::production::oee::api::wizard_definition();
+    let paths: Vec<&str> = wizard.parameter_paths().collect();
+
+    assert!(paths.contains(&"input.production.good_units"));
+    assert!(paths.contains(&"input.cycle_time.ideal_cycle_time"));
+
+    let economics = wizard.steps.iter().find(|s| s.id == "economics").unwrap();
+    assert!(economics.visible_when.is_some());
+}
+
+#[test]
+fn test_audience_views() {
+    use crate::calculus::audience::Audience;
+    use crate::calculus::engineer::calculators::production::oee::api::AudienceView;
+    use crate::calculus::engineer::calculators::production::oee::engine::calculate_oee_with_economics;
+    use crate::calculus::engineer::calculators::production::oee::domain::economics::EconomicParameters;
+
+    let run = |audience| {
+        let input = TestFixture::basic().build();
+        let economics = EconomicParameters::from_point_estimates(100.0, 40.0, 20.0, 35.0, "USD");
+        let result = calculate_oee_with_economics(input, economics).unwrap();
+        serde_json::to_value(AudienceView::new(result, audience)).unwrap()
+    };
+
+    let engineer = run(Audience::Engineer);
+    assert!(engineer["extended_metrics"].is_object());
+    assert!(engineer["validation"].is_object());
+
+    let operator = run(Audience::Operator);
+    assert!(operator["economic_analysis"].is_null());
+    assert!(operator.get("data_quality").is_none());
+    assert!(operator["loss_tree"]["root"]["economic_impact"].is_null());
+    let branches = operator["loss_tree"]["root"]["children"].as_array().unwrap();
+    assert!(branches.iter().flat_map(|b| b["children"].as_array().unwrap()).all(|n| n["children"].as_array().unwrap().is_empty()));
+
+    let manager = run(Audience::Manager);
+    assert!(manager["economic_analysis"].is_object());
+    assert!(manager["data_quality"].is_object());
+    assert!(manager.get("extended_metrics").is_none());
+    assert!(manager["ledger"]["assumptions"].as_array().unwrap().is_empty());
+}
@@ -428,4 +428,25 @@ fn test_multiple_analyses_workflow() {
     assert!(!leverage.is_empty());
     assert!(result.economic_analysis.is_some());
     assert!(result.extended_metrics.teep.is_some());
-}
\ No newline at end of file
+}
+#[test]
+fn test_data_quality_tracks_input_sources() {
+    let explicit = calculate_oee(TestFixture::basic().build()).expect("Calculation should succeed");
+    
+    let mut defaulted_input = TestFixture::basic().build();
+    defaulted_input.production.total_units = InputValue::Default(1000);
+    defaulted_input.cycle_time.ideal_cycle_time = InputValue::Inferred(Duration::from_secs(25));
+    let defaulted = calculate_oee(defaulted_input).expect("Calculation should succeed");
+    
+    assert!(explicit.data_quality.score <= 100);
+    assert!(defaulted.data_quality.score < explicit.data_quality.score);
+    
+    let subjects: Vec<_> = defaulted.data_quality.factors.iter()
+        .filter_map(|f| f.subject.as_deref())
+        .collect();
+    assert!(subjects.contains(&"total_units"));
+    assert!(subjects.contains(&"ideal_cycle_time"));
+    
+    let lost: u32 = defaulted.data_quality.factors.iter().map(|f| f.points as u32).sum();
+    assert_eq!(defaulted.data_quality.score as u32, 100 - lost);
+}
@@ -320,4 +320,74 @@ fn test_loss_tree_serializable() {
     // Loss tree should be serializable to JSON
     let json = serde_json::to_string(&result.loss_tree);
     assert!(json.is_ok(), "Loss tree should be serializable");
-}
\ No newline at end of file
+}
+#[cfg(test)]
+fn with_changeovers(mut input: OeeInput) -> OeeInput {
+    for (minutes, path) in [(20, vec!["Changeover", "A→B"]), (10, vec!["Changeover", "B→C"]), (5, vec![])] {
+        let mut allocation = TimeAllocation::new(
+            MachineState::Setup,
+            InputValue::Explicit(Duration::from_secs(minutes * 60)),
+        );
+        if !path.is_empty() {
+            allocation.reason = Some(ReasonCode::new(path.into_iter().map(String::from).collect()));
+        }
+        input.time_model.allocations.push(allocation);
+    }
+    input
+}
+
+#[test]
+fn test_setup_drills_down_by_reason() {
+    let input = with_changeovers(TestFixture::basic().with_time_allocations(7, 0).build());
+    let result = calculate_oee(input).expect("Calculation should succeed");
+    
+    let setup = result.loss_tree.subtree("availability_losses/setup_adjustments").expect("Setup node");
+    assert_eq!(setup.child_count, 2);
+    assert_eq!(setup.children[0].label.as_deref(), Some("Changeover"));
+    assert_eq!(setup.children[1].id, "unspecified");
+    
+    let changeover = result.loss_tree
+        .subtree("availability_losses/setup_adjustments/changeover/a_b")
+        .expect("Changeover A→B node");
+    assert_eq!(changeover.duration, Duration::from_secs(20 * 60));
+    assert_approx_eq(changeover.percentage_of_parent.unwrap(), 2.0 / 3.0, 1e-9, "Share of changeovers");
+    
+    assert!(result.loss_tree.subtree("availability_losses/nonexistent").is_none());
+}
+
+#[test]
+fn test_economic_impact_per_node() {
+    use crate::calculus::engineer::calculators::production::oee::domain::economics::EconomicParameters;
+    use crate::calculus::engineer::calculators::production::oee::engine::calculate_oee_with_economics;
+    
+    let input = with_changeovers(TestFixture::basic().with_time_allocations(7, 0).build());
+    assert!(calculate_oee(input.clone()).unwrap().loss_tree.root.economic_impact.is_none());
+    
+    let params = EconomicParameters::from_point_estimates(100.0, 40.0, 20.0, 35.0, "EUR");
+    let result = calculate_oee_with_economics(input, params).expect("Calculation should succeed");
+    let tree = &result.loss_tree;
+    
+    // 20 minutes at the fixture's 25 s ideal cycle, priced at marginal contribution
+    let changeover = tree.subtree("availability_losses/setup_adjustments/changeover/a_b").unwrap();
+    let impact = changeover.economic_impact.as_ref().expect("Node should be priced");
+    let units_per_hour = 3600.0 / 25.0;
+    assert_approx_eq(impact.central_estimate, (20.0 / 60.0) * units_per_hour * 40.0, 1e-6, "Changeover A→B cost");
+    assert_eq!(impact.currency, "EUR");
+    assert!(impact.low_estimate < impact.central_estimate && impact.central_estimate < impact.high_estimate);
+    
+    let categories: f64 = tree.root.children.iter()
+        .map(|c| c.economic_impact.as_ref().unwrap().central_estimate)
+        .sum();
+    assert_approx_eq(tree.root.economic_impact.as_ref().unwrap().central_estimate, categories, 1e-6, "Root total");
+}
+
+#[test]
+fn test_pruned_subtree_keeps_child_count() {
+    let input = with_changeovers(TestFixture::basic().with_time_allocations(7, 0).build());
+    let result = calculate_oee(input).expect("Calculation should succeed");
+    
+    let availability = result.loss_tree.subtree("availability_losses").unwrap().pruned(1);
+    let setup = availability.find(&["setup_adjustments"]).unwrap();
+    assert!(setup.children.is_empty());
+    assert_eq!(setup.child_count, 2);
+}
@@ -16,9 +16,15 @@ pub mod invalid_inputs;
 pub mod loss_tree;
 pub mod multi_machine;
 pub mod oee_math;
+#[cfg(test)]
+pub mod percentage;
 pub mod sensitivity;
 pub mod temporal_scrap;
 pub mod teep;
+#[cfg(test)]
+pub mod threshold_profiles;
+#[cfg(test)]
+pub mod timestamps;
 
 use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use std::time::Duration;
@@ -34,6 +40,8 @@ use crate::calculus::engineer::calculators::production::oee::{
     OeeInput,
 };
 
+pub use crate::calculus::engineer::calculators::production::oee::domain::Percentage;
+
 /// Test fixture builder for common test scenarios
 pub struct TestFixture {
     window: AnalysisWindow,
@@ -0,0 +1,68 @@
+//! Percentage normalization tests
+//!
+//! Fractions and percent values must never be confused on the way in or out
+
+use super::*;
+use crate::calculus::engineer::calculators::production::helpers;
+use crate::calculus::engineer::calculators::production::oee::engine::calculate_oee;
+use serde_json::json;
+
+#[test]
+fn test_serializes_both_scales() {
+    let value = serde_json::to_value(Percentage::from_fraction(0.25)).unwrap();
+    assert_eq!(value, json!({ "fraction": 0.25, "percent": 25.0 }));
+}
+
+#[test]
+fn test_deserializes_explicit_scales() {
+    let from_percent: Percentage = serde_json::from_value(json!({ "percent": 20.0 })).unwrap();
+    let from_fraction: Percentage = serde_json::from_value(json!({ "fraction": 0.2 })).unwrap();
+    let bare: Percentage = serde_json::from_value(json!(0.2)).unwrap();
+
+    assert_approx_eq(from_percent.fraction(), 0.2, 1e-12, "percent key");
+    assert_eq!(from_fraction, bare);
+    assert_approx_eq(bare.percent(), 20.0, 1e-9, "bare number is a fraction");
+}
+
+#[test]
+fn test_rejects_contradictory_or_empty_values() {
+    assert!(serde_json::from_value::<Percentage>(json!({ "fraction": 0.2, "percent": 2.0 })).is_err());
+    assert!(serde_json::from_value::<Percentage>(json!({})).is_err());
+    assert!(serde_json::from_value::<Percentage>(json!("20%")).is_err());
+}
+
+#[test]
+fn test_round_trip_is_exact() {
+    let original = Percentage::from_percent(83.79);
+    let json = serde_json::to_string(&original).unwrap();
+    let restored: Percentage = serde_json::from_str(&json).unwrap();
+    assert_eq!(original, restored);
+}
+
+#[test]
+fn test_legacy_helper_matches_core_metrics() {
+    let input = TestFixture::basic().build();
+    let result = calculate_oee(input).expect("Calculation should succeed");
+    let core = &result.core_metrics;
+
+    let legacy = helpers::oee(
+        core.availability.as_percentage().unwrap(),
+        core.performance.as_percentage().unwrap(),
+        core.quality.as_percentage().unwrap(),
+    );
+
+    assert_eq!(core.oee.as_percentage(), Some(legacy));
+    assert_valid_percentage(legacy.fraction(), "Legacy OEE");
+}
+
+#[test]
+fn test_ledger_source_statistics_are_typed() {
+    let input = TestFixture::basic().build();
+    let result = calculate_oee(input).expect("Calculation should succeed");
+    let stats = &result.ledger.source_statistics;
+
+    let total = stats.explicit_percentage.fraction()
+        + stats.inferred_percentage.fraction()
+        + stats.default_percentage.fraction();
+    assert_approx_eq(total, 1.0, 1e-9, "Source shares");
+}
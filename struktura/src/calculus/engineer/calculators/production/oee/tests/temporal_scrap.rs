@@ -66,7 +66,7 @@ fn test_percentage_startup_window() {
     let ideal_cycle = Duration::from_secs(25);
     
     // First 20% of production time
-    let config = StartupWindowConfig::percentage(0.20);
+    let config = StartupWindowConfig::percentage(Percentage::from_fraction(0.20));
     let analysis = analyze_temporal_scrap(&scrap_data, ideal_cycle, &config);
     
     // Startup window should be ~2 hours (20% of 10)
@@ -132,7 +132,7 @@ fn test_startup_percentage_calculation() {
     
     // Should be 80% startup scrap
     assert_approx_eq(
-        analysis.startup_scrap_percentage,
+        analysis.startup_scrap_percentage.percent(),
         80.0,
         1.0,
         "Startup scrap percentage"
@@ -158,7 +158,7 @@ fn test_all_scrap_in_startup() {
     
     assert_eq!(analysis.steady_state_scrap, 0, "No steady-state scrap");
     assert_eq!(analysis.startup_scrap, 50, "All scrap in startup");
-    assert_approx_eq(analysis.startup_scrap_percentage, 100.0, 0.1, "100% startup");
+    assert_approx_eq(analysis.startup_scrap_percentage.percent(), 100.0, 0.1, "100% startup");
 }
 
 #[test]
@@ -180,7 +180,7 @@ fn test_no_scrap_in_startup() {
     
     assert_eq!(analysis.startup_scrap, 0, "No startup scrap");
     assert!(analysis.steady_state_scrap > 0, "Has steady-state scrap");
-    assert_approx_eq(analysis.startup_scrap_percentage, 0.0, 0.1, "0% startup");
+    assert_approx_eq(analysis.startup_scrap_percentage.percent(), 0.0, 0.1, "0% startup");
 }
 
 #[test]
@@ -269,7 +269,7 @@ fn test_multiple_startup_methods_conservative() {
     // Should use the shorter (more conservative) window
     let config = StartupWindowConfig {
         fixed_duration: Some(Duration::from_secs(3600)), // 1 hour
-        percentage_of_total: Some(0.20), // 20% = 1.6 hours
+        percentage_of_total: Some(Percentage::from_fraction(0.20)), // 20% = 1.6 hours
         dynamic_threshold: None,
     };
     
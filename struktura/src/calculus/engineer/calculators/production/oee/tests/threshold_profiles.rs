@@ -0,0 +1,91 @@
+//! Threshold profile tests
+//!
+//! Profiled thresholds must reach the ledger as explicit, defaults as default
+
+use super::*;
+use crate::calculus::engineer::calculators::production::oee::assumptions::thresholds::{ThresholdConfiguration, ThresholdOrigin};
+use crate::calculus::engineer::calculators::production::oee::engine::calculate_oee;
+use crate::calculus::engineer::calculators::production::oee::profiles::{ThresholdProfile, ThresholdProfilePayload};
+use serde_json::json;
+use sqlx::types::time::OffsetDateTime;
+use uuid::Uuid;
+use validator::Validate;
+
+fn profile() -> ThresholdProfile {
+    ThresholdProfile {
+        id: Uuid::nil(),
+        org_id: Uuid::nil(),
+        name: "Press line".to_string(),
+        machine_id: None,
+        line_id: Some("LINE-A".to_string()),
+        micro_stoppage_seconds: 90.0,
+        speed_loss_threshold: Percentage::from_percent(8.0),
+        high_scrap_rate_threshold: Percentage::from_percent(12.0),
+        created_at: OffsetDateTime::UNIX_EPOCH,
+        updated_at: OffsetDateTime::UNIX_EPOCH,
+    }
+}
+
+fn threshold_sources(input: OeeInput) -> Vec<(String, String)> {
+    let result = calculate_oee(input).expect("Calculation should succeed");
+    result.ledger.thresholds.iter().map(|t| (t.threshold_key.clone(), t.source.clone())).collect()
+}
+
+#[test]
+fn test_omitted_thresholds_are_defaults() {
+    let mut value = serde_json::to_value(TestFixture::basic().build()).unwrap();
+    value.as_object_mut().unwrap().remove("thresholds");
+    let input: OeeInput = serde_json::from_value(value).unwrap();
+
+    assert!(input.thresholds.is_default());
+    assert!(threshold_sources(input).iter().all(|(_, source)| source == "default"));
+}
+
+#[test]
+fn test_request_thresholds_are_explicit() {
+    let mut value = serde_json::to_value(TestFixture::basic().build()).unwrap();
+    value["thresholds"] = serde_json::to_value(ThresholdConfiguration::strict()).unwrap();
+    value["thresholds"].as_object_mut().unwrap().remove("origin");
+    let input: OeeInput = serde_json::from_value(value).unwrap();
+
+    assert_eq!(input.thresholds.origin, ThresholdOrigin::Request);
+    assert!(threshold_sources(input).iter().all(|(_, source)| source == "explicit"));
+}
+
+#[test]
+fn test_profile_applies_and_is_recorded() {
+    let mut input = TestFixture::basic().build();
+    profile().apply(&mut input.thresholds);
+
+    assert_eq!(input.thresholds.micro_stoppage_threshold, Duration::from_secs(90));
+    assert_eq!(input.thresholds.high_scrap_rate_threshold, Percentage::from_percent(12.0));
+
+    let result = calculate_oee(input).expect("Calculation should succeed");
+    let ledger = &result.ledger;
+    assert_eq!(ledger.metadata.get("threshold_profile").map(String::as_str), Some("Press line"));
+
+    let scrap = ledger.thresholds.iter()
+        .find(|t| t.threshold_key == "high_scrap_rate_threshold")
+        .expect("Scrap alarm threshold should be tracked");
+    assert_eq!(scrap.source, "explicit");
+    assert_approx_eq(scrap.value, 0.12, 1e-12, "Scrap alarm");
+}
+
+#[test]
+fn test_payload_needs_one_target_and_valid_ratios() {
+    let payload = |target: serde_json::Value, speed_loss: serde_json::Value| -> ThresholdProfilePayload {
+        let mut body = json!({
+            "name": "Filler 3",
+            "micro_stoppage_seconds": 45.0,
+            "speed_loss_threshold": speed_loss,
+            "high_scrap_rate_threshold": { "percent": 15.0 },
+        });
+        body.as_object_mut().unwrap().extend(target.as_object().unwrap().clone());
+        serde_json::from_value(body).unwrap()
+    };
+
+    assert!(payload(json!({ "machine_id": "F-3" }), json!({ "percent": 5.0 })).validate().is_ok());
+    assert!(payload(json!({ "machine_id": "F-3", "line_id": "L1" }), json!(0.05)).validate().is_err());
+    assert!(payload(json!({}), json!(0.05)).validate().is_err());
+    assert!(payload(json!({ "line_id": "L1" }), json!({ "percent": 150.0 })).validate().is_err());
+}
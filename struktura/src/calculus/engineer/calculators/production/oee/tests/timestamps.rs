@@ -0,0 +1,73 @@
+//! Timestamp input tests
+//!
+//! Every accepted format must land on the same UTC instant
+
+use super::*;
+use crate::calculus::engineer::calculators::production::oee::assumptions::timestamp::{parse_offset, parse_timestamp};
+use crate::calculus::engineer::calculators::production::oee::engine::temporal_scrap::ScrapEvent;
+use serde_json::json;
+
+fn window(start: serde_json::Value, end: serde_json::Value) -> Result<AnalysisWindow, serde_json::Error> {
+    serde_json::from_value(json!({ "start": start, "end": end }))
+}
+
+#[test]
+fn test_all_formats_agree() {
+    let expected = window(json!("2024-03-01T08:00:00Z"), json!("2024-03-01T16:00:00Z")).unwrap();
+
+    let inputs = [
+        (json!(1709280000), json!(1709308800)),
+        (json!(1709280000.0), json!("1709308800")),
+        (json!("2024-03-01T09:00:00+01:00"), json!("2024-03-01T11:00:00-05:00")),
+        (json!("2024-03-01T08:00:00"), json!("2024-03-01 16:00")),
+        (
+            json!({ "local": "2024-03-01 09:00", "offset": "+01:00" }),
+            json!({ "local": "2024-03-01T10:00:00", "offset": "-0600" }),
+        ),
+    ];
+
+    for (start, end) in inputs {
+        let parsed = window(start.clone(), end.clone()).unwrap_or_else(|e| panic!("{} / {}: {}", start, end, e));
+        assert_eq!(parsed.start, expected.start, "start {}", start);
+        assert_eq!(parsed.end, expected.end, "end {}", end);
+        assert_eq!(parsed.duration(), Duration::from_secs(8 * 3600));
+    }
+}
+
+#[test]
+fn test_fractional_epoch_keeps_subseconds() {
+    let event: ScrapEvent = serde_json::from_value(json!({ "timestamp": 1709280000.25, "units": 3 })).unwrap();
+    assert_eq!(event.timestamp.timestamp_subsec_millis(), 250);
+}
+
+#[test]
+fn test_rejects_unusable_timestamps() {
+    assert!(window(json!("yesterday"), json!(1709308800)).is_err());
+    assert!(window(json!({ "local": "2024-03-01 09:00", "offset": "Europe/Paris" }), json!(0)).is_err());
+    assert!(parse_timestamp("2024-13-01T00:00:00Z").is_err());
+    assert!(parse_offset("+01:75").is_none());
+}
+
+#[test]
+fn test_output_is_rfc3339_utc() {
+    let parsed = window(json!({ "local": "2024-03-01 09:00", "offset": "+01:00" }), json!(1709308800)).unwrap();
+    let value = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(value["start"], json!("2024-03-01T08:00:00Z"));
+}
+
+#[test]
+fn test_downtime_timestamp_is_optional() {
+    let mut record = serde_json::to_value(DowntimeRecord::new(
+        Duration::from_secs(600),
+        ReasonCode::new(vec!["Mechanical".to_string()]),
+    ))
+    .unwrap();
+    record.as_object_mut().unwrap().remove("timestamp");
+    let parsed: DowntimeRecord = serde_json::from_value(record.clone()).unwrap();
+    assert!(parsed.timestamp.is_none());
+
+    let mut stamped = record;
+    stamped["timestamp"] = json!(1709280000);
+    let parsed: DowntimeRecord = serde_json::from_value(stamped).unwrap();
+    assert_eq!(parsed.timestamp.map(|t| t.timestamp()), Some(1709280000));
+}
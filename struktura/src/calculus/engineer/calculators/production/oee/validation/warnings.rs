@@ -3,18 +3,19 @@
 //! These don't block calculation but alert users to potential issues
 
 use super::*;
+use crate::calculus::engineer::calculators::production::oee::domain::Percentage;
 use serde_json::json;
 
 /// Checks for suspiciously high scrap rate
 pub fn check_high_scrap_rate(
     scrap_units: u32,
     total_units: u32,
-    threshold: f64,  // e.g., 0.2 for 20%
+    threshold: Percentage,
 ) -> ValidationResult {
     let mut result = ValidationResult::new();
     
     if total_units > 0 {
-        let scrap_rate = scrap_units as f64 / total_units as f64;
+        let scrap_rate = Percentage::of(scrap_units as f64, total_units as f64);
         
         if scrap_rate > threshold {
             result.add_issue(
@@ -24,8 +25,8 @@ pub fn check_high_scrap_rate(
                     json!({
                         "scrap_units": scrap_units,
                         "total_units": total_units,
-                        "scrap_rate": (scrap_rate * 100.0).round(),
-                        "threshold": (threshold * 100.0).round(),
+                        "scrap_rate": scrap_rate.rounded_percent(),
+                        "threshold": threshold.rounded_percent(),
                     }),
                 )
                 .with_field("production.scrap_units"),
@@ -40,12 +41,12 @@ pub fn check_high_scrap_rate(
 pub fn check_low_utilization(
     running_time: Duration,
     planned_time: Duration,
-    threshold: f64,  // e.g., 0.3 for 30%
+    threshold: Percentage,
 ) -> ValidationResult {
     let mut result = ValidationResult::new();
     
     if planned_time.as_secs() > 0 {
-        let utilization = running_time.as_secs_f64() / planned_time.as_secs_f64();
+        let utilization = Percentage::of(running_time.as_secs_f64(), planned_time.as_secs_f64());
         
         if utilization < threshold {
             result.add_issue(
@@ -55,8 +56,8 @@ pub fn check_low_utilization(
                     json!({
                         "running_seconds": running_time.as_secs(),
                         "planned_seconds": planned_time.as_secs(),
-                        "utilization": (utilization * 100.0).round(),
-                        "threshold": (threshold * 100.0).round(),
+                        "utilization": utilization.rounded_percent(),
+                        "threshold": threshold.rounded_percent(),
                     }),
                 )
                 .with_field("time_allocations"),
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{CAPABILITY_BELOW_ADEQUATE, CAPABILITY_NOT_CAPABLE},
 };
 use async_trait::async_trait;
 
@@ -133,8 +134,22 @@ impl EngineerCalculator for ProcessCapabilityCalculator {
         let lower_spec = self.get_additional_param(&params, "lower_spec", None, None)?;
         let upper_spec = self.get_additional_param(&params, "upper_spec", None, None)?;
 
+        let mut trace = CalculationTrace::new();
+
         let cpk_value = cpk(mean, std_dev, lower_spec, upper_spec);
+        trace.step(
+            "formulas.process_capability.cpk",
+            &[("mean", mean), ("std_dev", std_dev), ("lower_spec", lower_spec), ("upper_spec", upper_spec)],
+            cpk_value,
+            "dimensionless",
+        );
         let cp_value = (upper_spec - lower_spec) / (6.0 * std_dev);
+        trace.step(
+            "formulas.process_capability.cp",
+            &[("upper_spec", upper_spec), ("lower_spec", lower_spec), ("std_dev", std_dev)],
+            cp_value,
+            "dimensionless",
+        );
         let ppm = if cpk_value >= 1.33 { SIX_SIGMA_PPM } else if cpk_value >= 1.0 { FIVE_SIGMA_PPM } else { THREE_SIGMA_PPM };
 
         let mut warnings = Vec::new();
@@ -142,10 +157,10 @@ impl EngineerCalculator for ProcessCapabilityCalculator {
         let mut compliance_notes = Vec::new();
 
         if cpk_value < CPK_MINIMUM {
-            warnings.push(format!("Cpk ({:.2}) below minimum ({:.2}). Process not capable.", cpk_value, CPK_MINIMUM));
+            warnings.push(CAPABILITY_NOT_CAPABLE.warn(format!("Cpk ({:.2}) below minimum ({:.2}). Process not capable.", cpk_value, CPK_MINIMUM)));
             recommendations.push("Reduce process variation or adjust specifications".to_string());
         } else if cpk_value < CPK_ADEQUATE {
-            warnings.push(format!("Cpk ({:.2}) below adequate level ({:.2}). Improvement recommended.", cpk_value, CPK_ADEQUATE));
+            warnings.push(CAPABILITY_BELOW_ADEQUATE.warn(format!("Cpk ({:.2}) below adequate level ({:.2}). Improvement recommended.", cpk_value, CPK_ADEQUATE)));
             recommendations.push("Implement process controls to center mean and reduce std dev".to_string());
         }
 
@@ -166,8 +181,8 @@ impl EngineerCalculator for ProcessCapabilityCalculator {
             calculation_type: "process_capability".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -176,6 +191,8 @@ impl EngineerCalculator for ProcessCapabilityCalculator {
                 design_code_used: "Six Sigma".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{SAMPLING_LOW_OBSERVATIONS, SAMPLING_LOW_PRODUCTIVITY},
 };
 use async_trait::async_trait;
 
@@ -110,25 +111,45 @@ impl EngineerCalculator for WorkSamplingCalculator {
         let prod_obs = self.get_additional_param(&params, "productive_observations", None, None)?;
         let confidence_level = params.additional.as_ref().and_then(|a| a.get("confidence_level").copied()).unwrap_or(95.0);
 
+        let mut trace = CalculationTrace::new();
+
         let productive_percentage = (prod_obs / total_obs) * 100.0;
+        trace.step(
+            "formulas.work_sampling.productive_percentage",
+            &[("productive_observations", prod_obs), ("total_observations", total_obs)],
+            productive_percentage,
+            "%",
+        );
         let non_productive_percentage = 100.0 - productive_percentage;
 
         // Approximate confidence interval (using normal approximation)
         let z_score = if confidence_level == 95.0 { 1.96 } else if confidence_level == 99.0 { 2.58 } else { 1.645 }; // 90%
         let std_error = ((productive_percentage / 100.0 * (1.0 - productive_percentage / 100.0)) / total_obs).sqrt();
+        trace.step(
+            "formulas.work_sampling.standard_error",
+            &[("productive_percentage", productive_percentage), ("total_observations", total_obs)],
+            std_error,
+            "dimensionless",
+        );
         let margin_error = z_score * std_error * 100.0;
+        trace.step(
+            "formulas.work_sampling.margin_of_error",
+            &[("z_score", z_score), ("std_error", std_error)],
+            margin_error,
+            "%",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if total_obs < 200.0 {
-            warnings.push(format!("Low observations ({:.0}). Confidence interval may be wide.", total_obs));
+            warnings.push(SAMPLING_LOW_OBSERVATIONS.warn(format!("Low observations ({:.0}). Confidence interval may be wide.", total_obs)));
             recommendations.push("Increase number of observations for better accuracy".to_string());
         }
 
         if productive_percentage < 70.0 {
-            warnings.push(format!("Low productivity ({:.1}%). Investigate non-value activities.", productive_percentage));
+            warnings.push(SAMPLING_LOW_PRODUCTIVITY.warn(format!("Low productivity ({:.1}%). Investigate non-value activities.", productive_percentage)));
             recommendations.push("Conduct time-motion study on non-productive activities".to_string());
         }
 
@@ -149,8 +170,8 @@ impl EngineerCalculator for WorkSamplingCalculator {
             calculation_type: "work_sampling".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -159,6 +180,8 @@ impl EngineerCalculator for WorkSamplingCalculator {
                 design_code_used: "Industrial Engineering Standards".to_string(),
                 requires_pe_review: false,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -1,24 +1,580 @@
 use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
-    traits::{EngineerCalculator, ParameterValidator},
+    traits::{EngineerCalculator, MultiCodeCalculator, ParameterValidator},
+    warnings::{
+        BEAM_BARS_DO_NOT_FIT, BEAM_EXCESSIVE_DEFLECTION, BEAM_HIGH_SHEAR, BEAM_OVER_REINFORCED, BEAM_SHALLOW_SECTION,
+        BEAM_SHEAR_REINFORCEMENT, BEAM_SHEAR_SECTION_INADEQUATE,
+    },
 };
 use async_trait::async_trait;
 
 use super::steel_properties::*;
+use super::concrete_properties::*;
 use super::resistance_factors::*;
-use super::load_factors::*;
 use super::deflection_limits::*;
+use super::design_codes;
 use super::helpers::*;
 
+/// Distance from the tension face to the centroid of the bars (mm)
+const COVER_TO_STEEL: f64 = 60.0;
+
+/// Largest K = M/(b·d²·fck) without compression steel (EN 1992-1-1, no redistribution)
+const K_SINGLY_REINFORCED: f64 = 0.167;
+
+/// Clear cover to the stirrups (mm)
+const CLEAR_COVER: f64 = 40.0;
+
+/// Nominal maximum aggregate size (mm)
+const AGGREGATE_SIZE: f64 = 20.0;
+
+/// Distance from the support face to the first stirrup (mm)
+const FIRST_STIRRUP: f64 = 50.0;
+
+/// Quasi-permanent combination factor ψ2 for crack control (EN 1990, offices)
+const PSI_2: f64 = 0.3;
+
+/// EN 1992-1-1 Table 7.3N for wk = 0.3 mm: (steel stress MPa, maximum bar spacing mm)
+const EC2_BAR_SPACING: [(f64, f64); 6] = [(160.0, 300.0), (200.0, 250.0), (240.0, 200.0), (280.0, 150.0), (320.0, 100.0), (360.0, 50.0)];
+
 pub struct BeamDesignCalculator;
 
+/// Results and notes of one code's design
+struct Design {
+    results: Vec<EngineeringResultItem>,
+    warnings: Vec<EngineeringWarning>,
+    recommendations: Vec<String>,
+    compliance_notes: Vec<String>,
+}
+
+/// Rectangular concrete section and its materials (mm, MPa)
+#[derive(Clone, Copy)]
+struct ConcreteSection {
+    b: f64,
+    h: f64,
+    fc: f64,
+    fy: f64,
+}
+
+/// Factored beam actions shared by every code
+struct Actions {
+    wu: f64,
+    mu: f64,
+    shear: f64,
+    /// Quasi-permanent service load for crack control (kN/m)
+    quasi_permanent: f64,
+}
+
+impl BeamDesignCalculator {
+    fn actions(&self, code: &DesignCode, span: f64, dead: f64, live: f64, continuous: bool, trace: &mut CalculationTrace) -> Actions {
+        let wu = trace.step(
+            "formulas.beam_design.factored_load",
+            &[("dead_load", dead), ("live_load", live)],
+            design_codes::ultimate_load(code, dead, live),
+            "kN/m",
+        );
+        let mu = if !continuous {
+            trace.step(
+                "formulas.beam_design.moment_simple",
+                &[("wu", wu), ("span", span)],
+                wu * span.powi(2) / 8.0,
+                "kNm",
+            )
+        } else {
+            trace.step(
+                "formulas.beam_design.moment_continuous",
+                &[("wu", wu), ("span", span)],
+                wu * span.powi(2) / 12.0, // Approximate for continuous
+                "kNm",
+            )
+        };
+        let shear = trace.step(
+            "formulas.beam_design.max_shear",
+            &[("wu", wu), ("span", span)],
+            wu * span / 2.0,
+            "kN",
+        );
+        Actions { wu, mu, shear, quasi_permanent: dead + PSI_2 * live }
+    }
+
+    /// Rolled steel section per AISC 360 (elastic Sx) or EN 1993-1-1 (plastic Wpl)
+    fn design_steel(&self, code: &DesignCode, actions: &Actions, span: f64, live: f64, fy: f64, trace: &mut CalculationTrace) -> Design {
+        let Actions { mu, shear: shear_max, .. } = *actions;
+        let (label, req_section_mod) = if design_codes::is_eurocode(code) {
+            ("Required Wpl", trace.step(
+                "formulas.beam_design.required_plastic_modulus",
+                &[("mu", mu), ("gamma_m0", GAMMA_M0), ("fy", fy)],
+                mu * 1000.0 * GAMMA_M0 / fy,
+                "cm³",
+            ))
+        } else {
+            ("Required Sx", trace.step(
+                "formulas.beam_design.required_section_modulus",
+                &[("mu", mu), ("phi", PHI_FLEXURE), ("fy", fy)],
+                mu * 1000.0 / (PHI_FLEXURE * fy),
+                "cm³",
+            ))
+        };
+        let def_live = trace.step(
+            "formulas.beam_design.live_deflection",
+            &[("live_load", live), ("span", span), ("e", E_STEEL), ("sx", req_section_mod)],
+            5.0 * live * 1000.0 * (span * 100.0).powi(4) / (384.0 * E_STEEL * 1e9 * req_section_mod / 100.0), // mm, approximate
+            "mm",
+        );
+
+        let (passes_def, util_def) = check_deflection(def_live, span, L_OVER_360);
+
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        if !passes_def {
+            warnings.push(BEAM_EXCESSIVE_DEFLECTION.warn(format!("Excessive deflection. Utilization: {:.2}", util_def)));
+            recommendations.push("Increase section size or use camber".to_string());
+        }
+        if shear_max > 1000.0 {
+            warnings.push(BEAM_HIGH_SHEAR.warn("High shear - check web thickness"));
+        }
+
+        let compliance_notes = if design_codes::is_eurocode(code) {
+            vec![
+                "Design per EN 1993-1-1 6.2.5, class 1 or 2 section, γM0 = 1.0".to_string(),
+                "Verify lateral torsional buckling (EN 1993-1-1 6.3.2)".to_string(),
+                "Check serviceability for vibrations if applicable".to_string(),
+            ]
+        } else {
+            vec![
+                "Design per AISC 360 LRFD".to_string(),
+                "Verify lateral torsional buckling".to_string(),
+                "Check serviceability for vibrations if applicable".to_string(),
+            ]
+        };
+
+        Design {
+            results: vec![
+                EngineeringResultItem::new("Factored Moment", mu, "kNm")
+                    .critical()
+                    .with_format(format!("{:.1} kNm", mu)),
+                EngineeringResultItem::new(label, req_section_mod, "cm³")
+                    .critical()
+                    .with_format(format!("{:.0} cm³", req_section_mod)),
+                EngineeringResultItem::new("Max Shear", shear_max, "kN")
+                    .with_format(format!("{:.1} kN", shear_max)),
+                EngineeringResultItem::new("Live Deflection", def_live, "mm")
+                    .with_format(format!("{:.1} mm", def_live)),
+            ],
+            warnings,
+            recommendations,
+            compliance_notes,
+        }
+    }
+
+    /// Singly reinforced rectangular section per ACI 318 or EN 1992-1-1
+    fn design_concrete(
+        &self,
+        code: &DesignCode,
+        actions: &Actions,
+        span: f64,
+        continuous: bool,
+        section: ConcreteSection,
+        trace: &mut CalculationTrace,
+    ) -> EngineeringResult<Design> {
+        let Actions { mu, shear, .. } = *actions;
+        let ConcreteSection { b, h, fc, fy } = section;
+        let d = h - COVER_TO_STEEL;
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let compliance_notes;
+
+        let (as_req, shear_capacity, min_depth) = if *code == DesignCode::ACI318 {
+            // Rn = Mu/(φ·b·d²), ρ = 0.85f'c/fy·(1 - √(1 - 2Rn/0.85f'c))
+            let rn = mu * 1e6 / (PHI_FLEXURE_TENSION * b * d * d);
+            let m = 2.0 * rn / (0.85 * fc);
+            if m >= 1.0 {
+                return Err(EngineeringError::DomainError {
+                    field: "dimensions.depth".to_string(),
+                    message: format!("{:.0} mm deep section cannot resist {:.0} kNm; increase depth or width", h, mu),
+                });
+            }
+            let rho = 0.85 * fc / fy * (1.0 - (1.0 - m).sqrt());
+            let rho_min = f64::max(0.25 * fc.sqrt(), 1.4) / fy;
+            let as_req = trace.step(
+                "formulas.beam_design.tension_steel_aci",
+                &[("mu", mu), ("phi", PHI_FLEXURE_TENSION), ("b", b), ("d", d), ("fc", fc), ("fy", fy)],
+                rho.max(rho_min) * b * d,
+                "mm²",
+            );
+
+            // Tension-controlled while the neutral axis stays within 3/8 d
+            let beta1 = (0.85 - 0.05 * (fc - 28.0) / 7.0).clamp(0.65, 0.85);
+            let rho_tc = 0.85 * beta1 * fc / fy * 0.375;
+            if rho > rho_tc {
+                warnings.push(BEAM_OVER_REINFORCED.warn(format!(
+                    "ρ = {:.4} exceeds the tension-controlled limit {:.4}; φ = 0.90 does not apply",
+                    rho, rho_tc
+                )));
+                recommendations.push("Deepen the section or add compression reinforcement".to_string());
+            }
+
+            let shear_capacity = trace.step(
+                "formulas.beam_design.concrete_shear_aci",
+                &[("phi", PHI_SHEAR_CONCRETE), ("fc", fc), ("b", b), ("d", d)],
+                PHI_SHEAR_CONCRETE * 0.17 * fc.sqrt() * b * d / 1000.0,
+                "kN",
+            );
+
+            // Table 9.3.1.1, adjusted for bars other than Grade 60
+            let ratio = if continuous { 21.0 } else { 16.0 };
+            let min_depth = trace.step(
+                "formulas.beam_design.minimum_depth_aci",
+                &[("span", span), ("ratio", ratio), ("fy", fy)],
+                span * 1000.0 / ratio * (0.4 + fy / 700.0),
+                "mm",
+            );
+
+            compliance_notes = vec![
+                "Design per ACI 318 Chapter 9 and 22, tension-controlled φ = 0.90".to_string(),
+                "Shear capacity per ACI 318 22.5.5.1 simplified Vc, φ = 0.75".to_string(),
+                "Minimum depth per ACI 318 Table 9.3.1.1; compute deflections for thinner members".to_string(),
+            ];
+            (as_req, shear_capacity, min_depth)
+        } else {
+            let fcd = ALPHA_CC * fc / GAMMA_C;
+            let fyd = fy / GAMMA_S;
+            let k = mu * 1e6 / (b * d * d * fc);
+            if k > K_SINGLY_REINFORCED {
+                warnings.push(BEAM_OVER_REINFORCED.warn(format!(
+                    "K = {:.3} exceeds K' = {:.3}; compression reinforcement is required",
+                    k, K_SINGLY_REINFORCED
+                )));
+                recommendations.push("Deepen the section or add compression reinforcement".to_string());
+            }
+            let z = (d * (0.5 + (0.25 - k.min(K_SINGLY_REINFORCED) / 1.134).sqrt())).min(0.95 * d);
+            let fctm = 0.30 * fc.powf(2.0 / 3.0);
+            let as_min = f64::max(0.26 * fctm / fy, 0.0013) * b * d;
+            let as_req = trace.step(
+                "formulas.beam_design.tension_steel_ec2",
+                &[("mu", mu), ("z", z), ("fyd", fyd), ("fcd", fcd)],
+                (mu * 1e6 / (fyd * z)).max(as_min),
+                "mm²",
+            );
+
+            // VRd,c of EN 1992-1-1 6.2.2 without axial load
+            let size = (1.0 + (200.0 / d).sqrt()).min(2.0);
+            let rho_l = (as_req / (b * d)).min(0.02);
+            let v_min = 0.035 * size.powf(1.5) * fc.sqrt();
+            let v_rdc = (0.18 / GAMMA_C * size * (100.0 * rho_l * fc).cbrt()).max(v_min);
+            let shear_capacity = trace.step(
+                "formulas.beam_design.concrete_shear_ec2",
+                &[("k", size), ("rho_l", rho_l), ("fck", fc), ("b", b), ("d", d)],
+                v_rdc * b * d / 1000.0,
+                "kN",
+            );
+
+            // Span/effective depth limit of EN 1992-1-1 7.4.2 (7.16), scaled for fyk (7.17)
+            let system = if continuous { 1.3 } else { 1.0 };
+            let rho = as_req / (b * d);
+            let rho0 = fc.sqrt() * 1e-3;
+            let base = if rho <= rho0 {
+                11.0 + 1.5 * fc.sqrt() * rho0 / rho + 3.2 * fc.sqrt() * (rho0 / rho - 1.0).powf(1.5)
+            } else {
+                11.0 + 1.5 * fc.sqrt() * rho0 / rho
+            };
+            let l_over_d = system * base * 500.0 / fy;
+            let min_depth = trace.step(
+                "formulas.beam_design.minimum_depth_ec2",
+                &[("span", span), ("l_over_d", l_over_d)],
+                span * 1000.0 / l_over_d + COVER_TO_STEEL,
+                "mm",
+            );
+
+            compliance_notes = vec![
+                format!("Design per EN 1992-1-1 6.1, γc = {:.2}, γs = {:.2}, αcc = {:.2}", GAMMA_C, GAMMA_S, ALPHA_CC),
+                "Shear capacity VRd,c per EN 1992-1-1 6.2.2".to_string(),
+                "Span/depth limit per EN 1992-1-1 7.4.2; check the National Annex".to_string(),
+            ];
+            (as_req, shear_capacity, min_depth)
+        };
+
+        if shear > shear_capacity {
+            warnings.push(BEAM_SHEAR_REINFORCEMENT.warn(format!(
+                "Shear {:.0} kN exceeds concrete capacity {:.0} kN",
+                shear, shear_capacity
+            )));
+            recommendations.push("Stirrups are sized for the excess over the concrete capacity; see the bar schedule".to_string());
+        }
+        if h < min_depth {
+            warnings.push(BEAM_SHALLOW_SECTION.warn(format!(
+                "{:.0} mm deep section is below the {:.0} mm span/depth limit",
+                h, min_depth
+            )));
+            recommendations.push("Deepen the section or check deflections explicitly".to_string());
+        }
+
+        let schedule = bar_schedule(code, actions, span, section, as_req, shear_capacity, trace);
+        let mut results = vec![
+            EngineeringResultItem::new("Factored Moment", mu, "kNm")
+                    .critical()
+                    .with_format(format!("{:.1} kNm", mu)),
+                EngineeringResultItem::new("Required As", as_req, "mm²")
+                    .critical()
+                    .with_format(format!("{:.0} mm²", as_req)),
+                EngineeringResultItem::new("Max Shear", shear, "kN")
+                    .with_format(format!("{:.1} kN", shear)),
+                EngineeringResultItem::new("Concrete Shear Capacity", shear_capacity, "kN")
+                    .with_format(format!("{:.1} kN", shear_capacity)),
+                EngineeringResultItem::new("Minimum Depth", min_depth, "mm")
+                    .with_format(format!("{:.0} mm", min_depth)),
+        ];
+        results.extend(schedule.results);
+        warnings.extend(schedule.warnings);
+        recommendations.extend(schedule.recommendations);
+
+        Ok(Design {
+            results,
+            warnings,
+            recommendations,
+            compliance_notes: [compliance_notes, schedule.compliance_notes].concat(),
+        })
+    }
+}
+
+/// Bar schedule for the required steel: tension bars in one layer, development length and stirrup layout
+fn bar_schedule(
+    code: &DesignCode,
+    actions: &Actions,
+    span: f64,
+    section: ConcreteSection,
+    as_req: f64,
+    shear_capacity: f64,
+    trace: &mut CalculationTrace,
+) -> Design {
+    let Actions { wu, shear, quasi_permanent, .. } = *actions;
+    let ConcreteSection { b, h, fc, fy } = section;
+    let d = h - COVER_TO_STEEL;
+    let aci = *code == DesignCode::ACI318;
+    let (bars, stirrups) = if aci { (&ASTM_BARS[1..], &ASTM_BARS[..2]) } else { (&METRIC_BARS[2..], &METRIC_BARS[..2]) };
+    let mut warnings = Vec::new();
+    let mut recommendations = Vec::new();
+
+    // Tension bars: the lightest single layer that meets the minimum clear spacing
+    // (ACI 318 25.2.1, EN 1992-1-1 8.2) and the crack-control maximum
+    // (ACI 318 24.3.2 at fs = 2/3 fy, EN 1992-1-1 Table 7.3N)
+    let width = b - 2.0 * (CLEAR_COVER + stirrups[0].1);
+    let layouts: Vec<(&str, f64, f64, usize, f64, f64)> = bars
+        .iter()
+        .map(|&(name, db, area)| {
+            let n = ((as_req / area).ceil() as usize).max(2);
+            let clear = (width - n as f64 * db) / (n - 1) as f64;
+            let max_spacing = if aci {
+                let fs = 2.0 / 3.0 * fy;
+                (380.0 * 280.0 / fs - 2.5 * (CLEAR_COVER + stirrups[0].1)).min(300.0 * 280.0 / fs)
+            } else {
+                let sigma_s = fy / GAMMA_S * quasi_permanent / wu * as_req / (n as f64 * area);
+                interpolate(&EC2_BAR_SPACING, sigma_s)
+            };
+            (name, db, area, n, clear, max_spacing)
+        })
+        .collect();
+    let min_clear = |db: f64| if aci { db.max(25.0).max(4.0 / 3.0 * AGGREGATE_SIZE) } else { db.max(AGGREGATE_SIZE + 5.0).max(20.0) };
+    let fits = |&&(_, db, _, _, clear, max_spacing): &&(&str, f64, f64, usize, f64, f64)| {
+        clear >= min_clear(db) && clear + db <= max_spacing
+    };
+    let chosen = layouts
+        .iter()
+        .filter(fits)
+        .min_by(|a, b| (a.3 as f64 * a.2).total_cmp(&(b.3 as f64 * b.2)).then(b.1.total_cmp(&a.1)))
+        .copied();
+    let (bar, db, area, count, clear, max_spacing) = match chosen {
+        Some(layout) => layout,
+        None => {
+            let layout = *layouts.last().expect("bar table is not empty");
+            warnings.push(BEAM_BARS_DO_NOT_FIT.warn(format!(
+                "{} {} bars leave {:.0} mm clear against {:.0} mm minimum in a {:.0} mm wide beam",
+                layout.3, layout.0, layout.4, min_clear(layout.1), b
+            )));
+            recommendations.push("Widen the beam or place the tension bars in two layers and recheck the effective depth".to_string());
+            layout
+        }
+    };
+    let as_prov = trace.step(
+        "formulas.beam_design.provided_steel",
+        &[("count", count as f64), ("bar_area", area)],
+        count as f64 * area,
+        "mm²",
+    );
+
+    // Straight development of bottom bars in tension
+    let development = if aci {
+        // ACI 318 Table 25.4.2.3 with ψt = ψe = λ = 1.0
+        let divisor = if db >= 22.0 { 1.7 } else { 2.1 };
+        trace.step(
+            "formulas.beam_design.development_length_aci",
+            &[("fy", fy), ("fc", fc), ("db", db)],
+            (fy / (divisor * fc.sqrt().min(8.3)) * db).max(300.0),
+            "mm",
+        )
+    } else {
+        // EN 1992-1-1 8.4.2 and 8.4.3, good bond, α1..α5 = 1.0
+        let fbd = 2.25 * 0.7 * 0.30 * fc.powf(2.0 / 3.0) / GAMMA_C;
+        trace.step(
+            "formulas.beam_design.development_length_ec2",
+            &[("fyd", fy / GAMMA_S), ("fbd", fbd), ("db", db)],
+            (db / 4.0 * fy / GAMMA_S / fbd).max(10.0 * db).max(100.0),
+            "mm",
+        )
+    };
+
+    // Stirrups: steel area per unit length for the shear at the support and the code minimum
+    let fyt = if aci { fy.min(420.0) } else { fy / GAMMA_S };
+    // Variable strut inclination of EN 1992-1-1 6.2.3 at cot θ = 2.5, z = 0.9d
+    let (z, cot) = (0.9 * d, 2.5);
+    let link_capacity = |av_over_s: f64| {
+        if aci {
+            shear_capacity + PHI_SHEAR_CONCRETE * av_over_s * fyt * d / 1000.0
+        } else {
+            f64::max(shear_capacity, av_over_s * z * fyt * cot / 1000.0)
+        }
+    };
+    let (required, minimum, max_spacing_links, max_shear) = if aci {
+        let vs_limit = 0.33 * fc.sqrt() * b * d / 1000.0;
+        let required = (shear - shear_capacity).max(0.0) * 1000.0 / (PHI_SHEAR_CONCRETE * fyt * d);
+        let minimum = if shear > 0.5 * shear_capacity { f64::max(0.062 * fc.sqrt(), 0.35) * b / fyt } else { 0.0 };
+        let vs = (shear - shear_capacity).max(0.0) / PHI_SHEAR_CONCRETE;
+        let s_max = if vs > vs_limit { (d / 4.0).min(300.0) } else { (d / 2.0).min(600.0) };
+        let max_shear = shear_capacity + PHI_SHEAR_CONCRETE * 0.66 * fc.sqrt() * b * d / 1000.0;
+        (required, minimum, s_max, max_shear)
+    } else {
+        let nu1 = 0.6 * (1.0 - fc / 250.0);
+        let max_shear = b * z * nu1 * ALPHA_CC * fc / GAMMA_C / (cot + 1.0 / cot) / 1000.0;
+        let required = if shear > shear_capacity { shear * 1000.0 / (z * fyt * cot) } else { 0.0 };
+        let minimum = 0.08 * fc.sqrt() / fy * b;
+        (required, minimum, (0.75 * d).min(600.0), max_shear)
+    };
+    if shear > max_shear {
+        warnings.push(BEAM_SHEAR_SECTION_INADEQUATE.warn(format!(
+            "Shear {:.0} kN exceeds the {:.0} kN web crushing limit of the {:.0} × {:.0} mm section",
+            shear, max_shear, b, h
+        )));
+        recommendations.push("Enlarge the section; stirrups alone cannot carry this shear".to_string());
+    }
+
+    // Two-legged stirrups at 25 mm increments, stepping up a size below 100 mm spacing
+    let spacing_for = |legs_area: f64, av_over_s: f64| {
+        let s = if av_over_s > 0.0 { legs_area / av_over_s } else { f64::INFINITY };
+        ((s.min(max_spacing_links) / 25.0).floor() * 25.0).max(50.0)
+    };
+    let &(stirrup, stirrup_db, stirrup_area) = stirrups
+        .iter()
+        .find(|&&(_, _, a)| spacing_for(2.0 * a, required.max(minimum)) >= 100.0)
+        .unwrap_or(&stirrups[stirrups.len() - 1]);
+    let support_spacing = trace.step(
+        "formulas.beam_design.stirrup_spacing",
+        &[("av", 2.0 * stirrup_area), ("required", required), ("minimum", minimum), ("s_max", max_spacing_links)],
+        spacing_for(2.0 * stirrup_area, required.max(minimum)),
+        "mm",
+    );
+    let mid_spacing = spacing_for(2.0 * stirrup_area, minimum);
+
+    // Shear falls linearly to midspan; the support spacing runs until the mid spacing suffices
+    let half = span * 1000.0 / 2.0 - FIRST_STIRRUP;
+    let end_zone = if mid_spacing > support_spacing {
+        (half + FIRST_STIRRUP) * (1.0 - link_capacity(2.0 * stirrup_area / mid_spacing) / shear)
+    } else {
+        0.0
+    };
+    let end_count = (end_zone.clamp(0.0, half) / support_spacing).ceil();
+    let middle = (2.0 * (half - end_count * support_spacing)).max(0.0);
+    let stirrup_count = trace.step(
+        "formulas.beam_design.stirrup_count",
+        &[("span", span), ("end_zone", end_zone), ("support_spacing", support_spacing), ("mid_spacing", mid_spacing)],
+        2.0 * end_count + (middle / mid_spacing).floor() + 1.0,
+        "",
+    );
+    let layout = if end_count > 0.0 {
+        format!("{:.0} @ {:.0} mm from each support then @ {:.0} mm", end_count, support_spacing, mid_spacing)
+    } else {
+        format!("@ {:.0} mm throughout", support_spacing)
+    };
+
+    let hanger = bars[0].0;
+    let compliance_notes = vec![
+        format!(
+            "Bar schedule: {} {} bottom ({:.0} mm² provided, {:.0} mm clear), 2 {} top hangers, {} closed stirrups {}, {:.0} total",
+            count, bar, as_prov, clear, hanger, stirrup, layout, stirrup_count
+        ),
+        if aci {
+            format!("Development length per ACI 318 Table 25.4.2.3, bar spacing per 25.2.1 and 24.3.2 ({:.0} mm maximum)", max_spacing)
+        } else {
+            format!("Anchorage per EN 1992-1-1 8.4, bar spacing per 8.2 and Table 7.3N ({:.0} mm maximum)", max_spacing)
+        },
+        format!("Extend bottom bars at least {:.0} mm past the section of maximum moment", development),
+    ];
+
+    Design {
+        results: vec![
+            EngineeringResultItem::new("Tension Bar Count", count as f64, "")
+                .critical()
+                .with_format(format!("{} {}", count, bar)),
+            EngineeringResultItem::new("Tension Bar Diameter", db, "mm").with_format(format!("{:.1} mm", db)),
+            EngineeringResultItem::new("Provided As", as_prov, "mm²").with_format(format!("{:.0} mm²", as_prov)),
+            EngineeringResultItem::new("Clear Bar Spacing", clear, "mm").with_format(format!("{:.0} mm", clear)),
+            EngineeringResultItem::new("Development Length", development, "mm").with_format(format!("{:.0} mm", development)),
+            EngineeringResultItem::new("Stirrup Diameter", stirrup_db, "mm").with_format(format!("{} stirrups", stirrup)),
+            EngineeringResultItem::new("Stirrup Spacing at Supports", support_spacing, "mm")
+                .critical()
+                .with_format(format!("{:.0} mm", support_spacing)),
+            EngineeringResultItem::new("Stirrup Spacing at Midspan", mid_spacing, "mm")
+                .with_format(format!("{:.0} mm", mid_spacing)),
+            EngineeringResultItem::new("Stirrup Count", stirrup_count, "").with_format(format!("{:.0}", stirrup_count)),
+        ],
+        warnings,
+        recommendations,
+        compliance_notes,
+    }
+}
+
+/// Linear interpolation in an ascending (x, y) table, clamped at both ends
+fn interpolate(table: &[(f64, f64)], x: f64) -> f64 {
+    let (first, last) = (table[0], table[table.len() - 1]);
+    if x <= first.0 {
+        return first.1;
+    }
+    if x >= last.0 {
+        return last.1;
+    }
+    let i = table.iter().position(|&(tx, _)| tx >= x).unwrap_or(table.len() - 1);
+    let ((x0, y0), (x1, y1)) = (table[i - 1], table[i]);
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
 impl ParameterValidator for BeamDesignCalculator {
     fn calculator_id(&self) -> &str {
         "beam_design"
     }
 }
 
+impl MultiCodeCalculator for BeamDesignCalculator {
+    fn supported_codes(&self) -> Vec<DesignCode> {
+        design_codes::MEMBER_CODES.to_vec()
+    }
+
+    /// Member codes, or a code family resolved by the material type
+    fn active_code(&self, params: &EngineeringParameters) -> DesignCode {
+        design_codes::member_code(
+            params.design_code.as_deref(),
+            params.material.as_ref().map(|m| m.material_type.as_str()),
+        )
+        .unwrap_or_else(|| self.default_code())
+    }
+
+    fn parse_code(&self, code: &str) -> Option<DesignCode> {
+        design_codes::parse(code)
+    }
+
+    fn default_code(&self) -> DesignCode {
+        DesignCode::AISC360
+    }
+}
+
 #[async_trait]
 impl EngineerCalculator for BeamDesignCalculator {
     fn id(&self) -> &str {
@@ -26,7 +582,7 @@ impl EngineerCalculator for BeamDesignCalculator {
     }
 
     fn name(&self) -> &str {
-        "Steel Beam Design"
+        "Beam Design"
     }
 
     fn category(&self) -> CalculatorCategory {
@@ -34,10 +590,13 @@ impl EngineerCalculator for BeamDesignCalculator {
     }
 
     fn metadata(&self) -> EngineeringCalculatorMetadata {
-        EngineeringCalculatorMetadata::builder("beam_design", "Steel Beam Design")
+        EngineeringCalculatorMetadata::builder("beam_design", "Beam Design")
             .category("structural")
-            .description("Design steel beam for flexure, shear, and deflection per AISC 360")
+            .description("Design a steel beam for flexure, shear and deflection per AISC 360 or Eurocode 3, or a reinforced concrete beam per ACI 318 or Eurocode 2")
             .design_code("AISC 360")
+            .design_code("Eurocode 3")
+            .design_code("ACI 318")
+            .design_code("Eurocode 2")
             .design_code("ASCE 7")
             .parameter(ParameterMetadata {
                 name: "Span Length".to_string(),
@@ -83,12 +642,51 @@ impl EngineerCalculator for BeamDesignCalculator {
                 path: "material.yield_strength".to_string(),
                 data_type: ParameterType::Number,
                 unit: "MPa".to_string(),
-                description: "Steel yield strength (Fy)".to_string(),
+                description: "Steel yield strength (Fy), or rebar yield for concrete codes".to_string(),
                 required: false,
                 default_value: Some(FY_A992),
                 min_value: Some(200.0),
-                max_value: Some(500.0),
-                typical_range: Some((250.0, 345.0)),
+                max_value: Some(600.0),
+                typical_range: Some((250.0, 500.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Concrete Strength".to_string(),
+                path: "material.compressive_strength".to_string(),
+                data_type: ParameterType::Number,
+                unit: "MPa".to_string(),
+                description: "Specified concrete strength (f'c or fck); concrete codes only, 28 MPa (ACI) or 30 MPa (Eurocode) when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(15.0),
+                max_value: Some(80.0),
+                typical_range: Some((25.0, 40.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Section Width".to_string(),
+                path: "dimensions.width".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Concrete beam width; concrete codes only, 0.3 m when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.15),
+                max_value: Some(2.0),
+                typical_range: Some((0.25, 0.6)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Section Depth".to_string(),
+                path: "dimensions.depth".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Concrete beam overall depth; concrete codes only, 0.5 m when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.2),
+                max_value: Some(2.5),
+                typical_range: Some((0.35, 0.9)),
                 validation_rules: None,
             })
             .parameter(ParameterMetadata {
@@ -105,6 +703,20 @@ impl EngineerCalculator for BeamDesignCalculator {
                 validation_rules: Some(vec!["simple or continuous".to_string()]),
             })
             .complexity(ComplexityLevel::Intermediate)
+            .wizard(WizardDefinition::new()
+                .step(WizardStep::new("geometry", "Span & Supports")
+                    .field("dimensions.length")
+                    .field("additional.support_condition"))
+                .step(WizardStep::new("loads", "Service Loads")
+                    .description("Unfactored uniform loads; the design code's load factors are applied automatically")
+                    .field("loads.dead_load")
+                    .field("loads.live_load"))
+                .step(WizardStep::new("material", "Material")
+                    .description("Steel defaults to ASTM A992; concrete codes also need the section size")
+                    .field("material.yield_strength")
+                    .field("material.compressive_strength")
+                    .field("dimensions.width")
+                    .field("dimensions.depth")))
             .build()
     }
 
@@ -132,6 +744,32 @@ impl EngineerCalculator for BeamDesignCalculator {
             }
         }
 
+        let material_type = params.material.as_ref().map(|m| m.material_type.as_str());
+        let Some(code) = design_codes::member_code(params.design_code.as_deref(), material_type) else {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: "design_code".to_string(),
+                value: params.design_code.clone().unwrap_or_default(),
+                reason: "Supported codes: AISC 360, Eurocode 3, ACI 318, Eurocode 2".to_string(),
+            });
+        };
+        if design_codes::is_concrete(&code) {
+            if let Some(width) = params.dimensions.get("width").copied() {
+                self.validate_dimension("width", Some(width), 0.15, 2.0)?;
+            }
+            if let Some(depth) = params.dimensions.get("depth").copied() {
+                self.validate_dimension("depth", Some(depth), 0.2, 2.5)?;
+            }
+            if let Some(fc) = params.material.as_ref().and_then(|m| m.compressive_strength)
+                && !(15.0..=80.0).contains(&fc)
+            {
+                return Err(EngineeringError::InvalidParameter {
+                    parameter: "compressive_strength".to_string(),
+                    value: fc.to_string(),
+                    reason: "Must be between 15 and 80 MPa".to_string(),
+                });
+            }
+        }
+
         let support = params.additional.as_ref().and_then(|a| a.get("support_condition")).map(|v| v.to_string()).unwrap_or("simple".to_string());
         if support != "simple" && support != "continuous" {
             return Err(EngineeringError::InvalidParameter {
@@ -152,70 +790,134 @@ impl EngineerCalculator for BeamDesignCalculator {
     }
 
     async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let code = self.active_code(&params);
+        let concrete = design_codes::is_concrete(&code);
         let span = params.dimensions.get("length").copied().unwrap_or(6.0);
         let dead = params.loads.as_ref().map(|l| l.dead_load).unwrap_or(10.0);
         let live = params.loads.as_ref().map(|l| l.live_load).unwrap_or(15.0);
-        let fy = params.material.as_ref().and_then(|m| m.yield_strength).unwrap_or(FY_A992);
+        let default_fy = match code {
+            DesignCode::ACI318 => FY_REBAR_GR60,
+            DesignCode::EurocodeEC2 => FYK_B500,
+            _ => FY_A992,
+        };
+        let fy = params.material.as_ref().and_then(|m| m.yield_strength).unwrap_or(default_fy);
         let support = params.additional.as_ref().and_then(|a| a.get("support_condition")).map(|v| v.to_string()).unwrap_or("simple".to_string());
+        let continuous = support != "simple";
+
+        let mut trace = CalculationTrace::new();
+        let actions = self.actions(&code, span, dead, live, continuous, &mut trace);
 
-        let wu = factored_load_basic(dead, live);
-        let mu = if support == "simple" {
-            wu * span.powi(2) / 8.0
+        let mut design = if concrete {
+            let default_fc = if code == DesignCode::EurocodeEC2 { FC_C30 } else { FC_C28 };
+            let fc = params.material.as_ref().and_then(|m| m.compressive_strength).unwrap_or(default_fc);
+            let section = ConcreteSection {
+                b: params.dimensions.get("width").copied().unwrap_or(0.3) * 1000.0,
+                h: params.dimensions.get("depth").copied().unwrap_or(0.5) * 1000.0,
+                fc,
+                fy,
+            };
+            self.design_concrete(&code, &actions, span, continuous, section, &mut trace)?
         } else {
-            wu * span.powi(2) / 12.0 // Approximate for continuous
+            self.design_steel(&code, &actions, span, live, fy, &mut trace)
         };
-
-        let req_section_mod = mu * 1000.0 / (PHI_FLEXURE * fy); // cm³
-        let shear_max = wu * span / 2.0;
-        let def_live = 5.0 * live * 1000.0 * (span * 100.0).powi(4) / (384.0 * E_STEEL * 1e9 * req_section_mod / 100.0); // mm, approximate
-
-        let (passes_def, util_def) = check_deflection(def_live, span, L_OVER_360);
-
-        let mut warnings = Vec::new();
-        let mut recommendations = Vec::new();
-        let mut compliance_notes = Vec::new();
-
-        if !passes_def {
-            warnings.push(format!("Excessive deflection. Utilization: {:.2}", util_def));
-            recommendations.push("Increase section size or use camber".to_string());
-        }
-
-        if shear_max > 1000.0 {
-            warnings.push("High shear - check web thickness".to_string());
-        }
-
-        compliance_notes.push("Design per AISC 360 LRFD".to_string());
-        compliance_notes.push("Verify lateral torsional buckling".to_string());
-        compliance_notes.push("Check serviceability for vibrations if applicable".to_string());
-
-        let results = vec![
-            EngineeringResultItem::new("Factored Moment", mu, "kNm")
-                .critical()
-                .with_format(format!("{:.1} kNm", mu)),
-            EngineeringResultItem::new("Required Sx", req_section_mod, "cm³")
-                .critical()
-                .with_format(format!("{:.0} cm³", req_section_mod)),
-            EngineeringResultItem::new("Max Shear", shear_max, "kN")
-                .with_format(format!("{:.1} kN", shear_max)),
-            EngineeringResultItem::new("Live Deflection", def_live, "mm")
-                .with_format(format!("{:.1} mm", def_live)),
-        ];
+        design.compliance_notes.insert(0, format!(
+            "Factored load {:.1} kN/m from {}",
+            actions.wu,
+            design_codes::combination(&code)
+        ));
 
         Ok(EngineeringCalculationResponse {
             calculation_type: "beam_design".to_string(),
-            results,
+            results: design.results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
-            recommendations,
-            compliance_notes,
+            warnings: EngineeringWarning::messages(&design.warnings),
+            structured_warnings: Some(design.warnings),
+            recommendations: design.recommendations,
+            compliance_notes: design.compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
                 timestamp: chrono::Utc::now().to_rfc3339(),
                 calculator_version: env!("CARGO_PKG_VERSION").to_string(),
-                design_code_used: "AISC 360".to_string(),
+                design_code_used: code.as_str().to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    fn params(code: &str, material: serde_json::Value, loads: (f64, f64)) -> EngineeringParameters {
+        serde_json::from_value(serde_json::json!({
+            "dimensions": { "length": 6.0, "height": 4.0 },
+            "material": material,
+            "loads": { "dead_load": loads.0, "live_load": loads.1, "load_combination": "LRFD" },
+            "additional": { "k_factor": 1.0 },
+            "design_code": code,
+        }))
+        .unwrap()
+    }
+
+    fn code_used(response: &EngineeringCalculationResponse) -> String {
+        response.calculation_metadata.as_ref().unwrap().design_code_used.clone()
+    }
+
+    #[tokio::test]
+    async fn test_member_design_dispatches_on_design_code() {
+        // 1.2D + 1.6L = 40 kN/m against 1.35G + 1.5Q = 42 kN/m
+        let aisc = execute("beam_design", params("AISC 360-22", serde_json::Value::Null, (20.0, 10.0))).await.unwrap();
+        let ec3 = execute("beam_design", params("EN 1993-1-1", serde_json::Value::Null, (20.0, 10.0))).await.unwrap();
+        assert_eq!(code_used(&aisc), "AISC 360");
+        assert_eq!(code_used(&ec3), "Eurocode 3");
+        assert!((value(&aisc, "Factored Moment") - 180.0).abs() < 1e-9);
+        assert!((value(&ec3, "Factored Moment") - 189.0).abs() < 1e-9);
+        assert!((value(&ec3, "Required Wpl") - 189.0 * 1000.0 / 345.0).abs() < 1e-9);
+
+        // 300 × 500 mm, d = 440 mm, f'c = 28 MPa, Grade 60 bars: As ≈ 1175 mm²
+        let aci = execute("beam_design", params("ACI 318-19", serde_json::Value::Null, (20.0, 10.0))).await.unwrap();
+        assert_eq!(code_used(&aci), "ACI 318");
+        assert!((value(&aci, "Required As") - 1175.0).abs() < 2.0);
+        let ec2 = execute("beam_design", params("Eurocode", serde_json::json!({ "material_type": "Concrete" }), (20.0, 10.0)))
+            .await
+            .unwrap();
+        assert_eq!(code_used(&ec2), "Eurocode 2");
+        assert!(value(&ec2, "Required As") > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_concrete_beam_bar_schedule() {
+        let run = |code: &str, width: f64, loads: (f64, f64)| {
+            let params: EngineeringParameters = serde_json::from_value(serde_json::json!({
+                "dimensions": { "length": 6.0, "width": width, "depth": 0.5 },
+                "loads": { "dead_load": loads.0, "live_load": loads.1, "load_combination": "LRFD" },
+                "design_code": code,
+            }))
+            .unwrap();
+            async move { execute("beam_design", params).await.unwrap() }
+        };
+
+        // 1175 mm² required: #6 bars miss the 26.7 mm minimum clear spacing and 2 #9 is the lightest layout
+        let aci = run("ACI 318", 0.3, (20.0, 10.0)).await;
+        assert_eq!(value(&aci, "Tension Bar Count"), 2.0);
+        assert_eq!(value(&aci, "Tension Bar Diameter"), 28.7);
+        assert_eq!(value(&aci, "Provided As"), 1290.0);
+        assert!((value(&aci, "Clear Bar Spacing") - (201.0 - 2.0 * 28.7)).abs() < 1e-9);
+        assert!((value(&aci, "Development Length") - 420.0 / (1.7 * 28.0_f64.sqrt()) * 28.7).abs() < 1e-9);
+        // Minimum stirrups govern: #3 at d/2 = 220 mm, rounded down to 200 mm over 5.9 m
+        assert_eq!(value(&aci, "Stirrup Spacing at Supports"), 200.0);
+        assert_eq!(value(&aci, "Stirrup Count"), 30.0);
+        assert!(aci.compliance_notes.iter().any(|n| n.starts_with("Bar schedule: 2 #9 bottom")));
+
+        let heavy = run("Eurocode 2", 0.3, (40.0, 30.0)).await;
+        assert!(value(&heavy, "Provided As") >= value(&heavy, "Required As"));
+        assert!(value(&heavy, "Stirrup Spacing at Supports") < value(&heavy, "Stirrup Spacing at Midspan"));
+        assert!(warning_codes(&heavy).contains(&"beam_design.shear_reinforcement".to_string()));
+
+        let narrow = run("ACI 318", 0.15, (20.0, 10.0)).await;
+        assert!(warning_codes(&narrow).contains(&"beam_design.bars_do_not_fit".to_string()));
+    }
+}
@@ -1,23 +1,123 @@
 use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
-    traits::{EngineerCalculator, ParameterValidator},
+    traits::{EngineerCalculator, MultiCodeCalculator, ParameterValidator},
+    warnings::{COLUMN_K_FACTOR_ASSUMED, COLUMN_LARGE_SECTION, COLUMN_REINFORCEMENT_RATIO, COLUMN_SLENDER},
 };
 use async_trait::async_trait;
+use std::f64::consts::PI;
 
 use super::steel_properties::*;
+use super::concrete_properties::*;
 use super::resistance_factors::*;
-use super::load_factors::*;
-use super::helpers::*;
+use super::design_codes;
+
+/// Slenderness above which AISC 360 E2 recommends a stockier member
+const MAX_STEEL_SLENDERNESS: f64 = 200.0;
+
+/// ACI 318 6.2.5 limit for neglecting slenderness in sway columns
+const ACI_SWAY_SLENDERNESS: f64 = 22.0;
+
+/// Imperfection factor for buckling curve b (EN 1993-1-1 Table 6.1)
+const ALPHA_CURVE_B: f64 = 0.34;
 
 pub struct ColumnDesignCalculator;
 
+impl ColumnDesignCalculator {
+    /// Critical stress and required gross area of a steel column (MPa, mm²)
+    fn design_steel(&self, code: &DesignCode, pu: f64, slenderness: f64, fy: f64, trace: &mut CalculationTrace) -> (f64, f64) {
+        let e = E_STEEL * 1000.0;
+        if design_codes::is_eurocode(code) {
+            // λ̄ = (Lcr/i)/λ1, χ from buckling curve b
+            let lambda_bar = slenderness / (PI * (e / fy).sqrt());
+            let phi = 0.5 * (1.0 + ALPHA_CURVE_B * (lambda_bar - 0.2) + lambda_bar.powi(2));
+            let chi = (1.0 / (phi + (phi.powi(2) - lambda_bar.powi(2)).sqrt())).min(1.0);
+            let stress = trace.step(
+                "formulas.column_design.buckling_stress_ec3",
+                &[("lambda_bar", lambda_bar), ("alpha", ALPHA_CURVE_B), ("fy", fy)],
+                chi * fy,
+                "MPa",
+            );
+            let area = trace.step(
+                "formulas.column_design.required_area_ec3",
+                &[("n_ed", pu), ("gamma_m1", GAMMA_M1), ("chi_fy", stress)],
+                pu * 1000.0 * GAMMA_M1 / stress,
+                "mm²",
+            );
+            (stress, area)
+        } else {
+            // Fe = π²E/(KL/r)², inelastic below Fy/Fe = 2.25 (AISC 360 E3)
+            let fe = PI.powi(2) * e / slenderness.powi(2);
+            let fcr = if fy / fe <= 2.25 { 0.658f64.powf(fy / fe) * fy } else { 0.877 * fe };
+            let stress = trace.step(
+                "formulas.column_design.critical_stress",
+                &[("fy", fy), ("fe", fe)],
+                fcr,
+                "MPa",
+            );
+            let area = trace.step(
+                "formulas.column_design.required_area",
+                &[("pu", pu), ("phi", PHI_COMPRESSION), ("fcr", stress)],
+                pu * 1000.0 / (PHI_COMPRESSION * stress),
+                "mm²",
+            );
+            (stress, area)
+        }
+    }
+
+    /// Required gross area of a tied concrete column with steel ratio `rho` (mm²)
+    fn concrete_area(&self, code: &DesignCode, pu: f64, fc: f64, fy: f64, rho: f64, trace: &mut CalculationTrace) -> f64 {
+        if *code == DesignCode::ACI318 {
+            // φPn,max = 0.80·φ·[0.85f'c(Ag - Ast) + fy·Ast] (ACI 318 22.4.2)
+            trace.step(
+                "formulas.column_design.required_area_aci",
+                &[("pu", pu), ("phi", PHI_COMPRESSION_TIED), ("fc", fc), ("fy", fy), ("rho", rho)],
+                pu * 1000.0 / (AXIAL_CAP_TIED * PHI_COMPRESSION_TIED * (0.85 * fc * (1.0 - rho) + fy * rho)),
+                "mm²",
+            )
+        } else {
+            // NRd = Ac·fcd + As·fyd (EN 1992-1-1 6.1)
+            let fcd = ALPHA_CC * fc / GAMMA_C;
+            let fyd = fy / GAMMA_S;
+            trace.step(
+                "formulas.column_design.required_area_ec2",
+                &[("n_ed", pu), ("fcd", fcd), ("fyd", fyd), ("rho", rho)],
+                pu * 1000.0 / (fcd * (1.0 - rho) + fyd * rho),
+                "mm²",
+            )
+        }
+    }
+}
+
 impl ParameterValidator for ColumnDesignCalculator {
     fn calculator_id(&self) -> &str {
         "column_design"
     }
 }
 
+impl MultiCodeCalculator for ColumnDesignCalculator {
+    fn supported_codes(&self) -> Vec<DesignCode> {
+        design_codes::MEMBER_CODES.to_vec()
+    }
+
+    /// Member codes, or a code family resolved by the material type
+    fn active_code(&self, params: &EngineeringParameters) -> DesignCode {
+        design_codes::member_code(
+            params.design_code.as_deref(),
+            params.material.as_ref().map(|m| m.material_type.as_str()),
+        )
+        .unwrap_or_else(|| self.default_code())
+    }
+
+    fn parse_code(&self, code: &str) -> Option<DesignCode> {
+        design_codes::parse(code)
+    }
+
+    fn default_code(&self) -> DesignCode {
+        DesignCode::AISC360
+    }
+}
+
 #[async_trait]
 impl EngineerCalculator for ColumnDesignCalculator {
     fn id(&self) -> &str {
@@ -25,7 +125,7 @@ impl EngineerCalculator for ColumnDesignCalculator {
     }
 
     fn name(&self) -> &str {
-        "Steel Column Design"
+        "Column Design"
     }
 
     fn category(&self) -> CalculatorCategory {
@@ -33,10 +133,13 @@ impl EngineerCalculator for ColumnDesignCalculator {
     }
 
     fn metadata(&self) -> EngineeringCalculatorMetadata {
-        EngineeringCalculatorMetadata::builder("column_design", "Steel Column Design")
+        EngineeringCalculatorMetadata::builder("column_design", "Column Design")
             .category("structural")
-            .description("Design steel column for axial compression and buckling per AISC 360")
+            .description("Design a steel column for axial compression and buckling per AISC 360 or Eurocode 3, or a tied concrete column per ACI 318 or Eurocode 2")
             .design_code("AISC 360")
+            .design_code("Eurocode 3")
+            .design_code("ACI 318")
+            .design_code("Eurocode 2")
             .design_code("ASCE 7")
             .parameter(ParameterMetadata {
                 name: "Height".to_string(),
@@ -82,12 +185,12 @@ impl EngineerCalculator for ColumnDesignCalculator {
                 path: "material.yield_strength".to_string(),
                 data_type: ParameterType::Number,
                 unit: "MPa".to_string(),
-                description: "Steel yield strength (Fy)".to_string(),
+                description: "Steel yield strength (Fy), or rebar yield for concrete codes".to_string(),
                 required: false,
                 default_value: Some(FY_A992),
                 min_value: Some(200.0),
-                max_value: Some(500.0),
-                typical_range: Some((250.0, 345.0)),
+                max_value: Some(600.0),
+                typical_range: Some((250.0, 500.0)),
                 validation_rules: None,
             })
             .parameter(ParameterMetadata {
@@ -103,6 +206,45 @@ impl EngineerCalculator for ColumnDesignCalculator {
                 typical_range: Some((0.65, 1.0)),
                 validation_rules: None,
             })
+            .parameter(ParameterMetadata {
+                name: "Radius of Gyration".to_string(),
+                path: "additional.radius_of_gyration".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm".to_string(),
+                description: "Governing radius of gyration of the steel section; steel codes only, 50 mm when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(10.0),
+                max_value: Some(500.0),
+                typical_range: Some((40.0, 110.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Concrete Strength".to_string(),
+                path: "material.compressive_strength".to_string(),
+                data_type: ParameterType::Number,
+                unit: "MPa".to_string(),
+                description: "Specified concrete strength (f'c or fck); concrete codes only, 28 MPa (ACI) or 30 MPa (Eurocode) when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(15.0),
+                max_value: Some(80.0),
+                typical_range: Some((25.0, 50.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Reinforcement Ratio".to_string(),
+                path: "additional.reinforcement_ratio".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "Longitudinal steel over gross area; concrete codes only, 0.02 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.002),
+                max_value: Some(0.08),
+                typical_range: Some((0.01, 0.03)),
+                validation_rules: None,
+            })
             .complexity(ComplexityLevel::Intermediate)
             .build()
     }
@@ -121,6 +263,33 @@ impl EngineerCalculator for ColumnDesignCalculator {
 
         self.get_additional_param(params, "k_factor", Some(0.5), Some(2.0))?;
 
+        let material_type = params.material.as_ref().map(|m| m.material_type.as_str());
+        let Some(code) = design_codes::member_code(params.design_code.as_deref(), material_type) else {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: "design_code".to_string(),
+                value: params.design_code.clone().unwrap_or_default(),
+                reason: "Supported codes: AISC 360, Eurocode 3, ACI 318, Eurocode 2".to_string(),
+            });
+        };
+        let optional = |name: &str, min: f64, max: f64| match self.get_additional_param(params, name, Some(min), Some(max)) {
+            Err(EngineeringError::MissingParameter { .. }) => Ok(()),
+            other => other.map(|_| ()),
+        };
+        if design_codes::is_concrete(&code) {
+            optional("reinforcement_ratio", 0.002, 0.08)?;
+            if let Some(fc) = params.material.as_ref().and_then(|m| m.compressive_strength)
+                && !(15.0..=80.0).contains(&fc)
+            {
+                return Err(EngineeringError::InvalidParameter {
+                    parameter: "compressive_strength".to_string(),
+                    value: fc.to_string(),
+                    reason: "Must be between 15 and 80 MPa".to_string(),
+                });
+            }
+        } else {
+            optional("radius_of_gyration", 10.0, 500.0)?;
+        }
+
         if height > 10.0 {
             return Err(EngineeringError::DomainError {
                 field: "height".to_string(),
@@ -132,64 +301,194 @@ impl EngineerCalculator for ColumnDesignCalculator {
     }
 
     async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let code = self.active_code(&params);
         let height = params.dimensions.get("height").copied().unwrap_or(4.0);
         let dead = params.loads.as_ref().map(|l| l.dead_load).unwrap_or(500.0);
         let live = params.loads.as_ref().map(|l| l.live_load).unwrap_or(300.0);
-        let fy = params.material.as_ref().and_then(|m| m.yield_strength).unwrap_or(FY_A992);
-        let k = params.additional.as_ref().and_then(|a| a.get("k_factor").copied()).unwrap_or(1.0);
-
-        let pu = 1.2 * dead + 1.6 * live;
-        let lambda = k * height * 1000.0 / (fy.sqrt() * 10.0); // Approximate r from lambda
-        let phi_pn = if lambda < 1.5 {
-            PHI_COMPRESSION * 0.658f64.powf(lambda.powi(2)) * fy * (pu / fy) // Inelastic
-        } else {
-            PHI_COMPRESSION * 0.877 / lambda.powi(2) * fy * (pu / fy) // Elastic
+        let default_fy = match code {
+            DesignCode::ACI318 => FY_REBAR_GR60,
+            DesignCode::EurocodeEC2 => FYK_B500,
+            _ => FY_A992,
         };
+        let fy = params.material.as_ref().and_then(|m| m.yield_strength).unwrap_or(default_fy);
+        let additional = |name: &str| params.additional.as_ref().and_then(|a| a.get(name).copied());
+        let k = additional("k_factor").unwrap_or(1.0);
+
+        let mut trace = CalculationTrace::new();
 
-        let req_area = pu / phi_pn;
+        let pu = trace.step(
+            "formulas.column_design.factored_axial",
+            &[("dead_load", dead), ("live_load", live)],
+            design_codes::ultimate_load(&code, dead, live),
+            "kN",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
-        let mut compliance_notes = Vec::new();
+        let mut compliance_notes = vec![format!("Factored axial load from {}", design_codes::combination(&code))];
 
         if k > 1.0 {
-            warnings.push("Fixed ends assumed. Verify alignment chart K".to_string());
-        }
-
-        if req_area > 5000.0 {
-            warnings.push("Large section required. Consider HSS or built-up".to_string());
+            warnings.push(COLUMN_K_FACTOR_ASSUMED.warn("Fixed ends assumed. Verify alignment chart K"));
         }
 
-        compliance_notes.push("Design per AISC 360 LRFD Chapter E".to_string());
-        compliance_notes.push("Assume no slenderness in other axis".to_string());
-        compliance_notes.push("Check P-delta if applicable".to_string());
-
-        let results = vec![
+        let mut results = vec![
             EngineeringResultItem::new("Factored Axial", pu, "kN")
                 .critical()
                 .with_format(format!("{:.0} kN", pu)),
-            EngineeringResultItem::new("Required Area", req_area, "mm²")
-                .critical()
-                .with_format(format!("{:.0} mm²", req_area)),
-            EngineeringResultItem::new("Slenderness Ratio", lambda * 100.0, "dimensionless") // Approximate
-                .with_format(format!("{:.1}", lambda * 100.0)),
         ];
 
+        if design_codes::is_concrete(&code) {
+            let default_fc = if code == DesignCode::EurocodeEC2 { FC_C30 } else { FC_C28 };
+            let fc = params.material.as_ref().and_then(|m| m.compressive_strength).unwrap_or(default_fc);
+            let rho = additional("reinforcement_ratio").unwrap_or(0.02);
+            let area = self.concrete_area(&code, pu, fc, fy, rho, &mut trace);
+
+            // Square section of the required area; i = b/√12 (r ≈ 0.3b in ACI 318 6.2.5.2)
+            let side = area.sqrt();
+            let radius = if code == DesignCode::ACI318 { 0.3 * side } else { side / 12f64.sqrt() };
+            let slenderness = trace.step(
+                "formulas.column_design.slenderness",
+                &[("k", k), ("height", height), ("r", radius)],
+                k * height * 1000.0 / radius,
+                "dimensionless",
+            );
+            let limit = if code == DesignCode::ACI318 {
+                ACI_SWAY_SLENDERNESS
+            } else {
+                // λlim = 20·A·B·C/√n with the default A = 0.7, B = 1.1, C = 0.7 (EN 1992-1-1 5.8.3.1)
+                let n = pu * 1000.0 / (area * ALPHA_CC * fc / GAMMA_C);
+                20.0 * 0.7 * 1.1 * 0.7 / n.sqrt()
+            };
+            if slenderness > limit {
+                warnings.push(COLUMN_SLENDER.warn(format!(
+                    "Slenderness {:.0} exceeds {:.0}; include second-order moments",
+                    slenderness, limit
+                )));
+                recommendations.push("Enlarge the section or brace the column".to_string());
+            }
+
+            let (rho_min, rho_max) = if code == DesignCode::ACI318 { (0.01, 0.08) } else { (0.002, 0.04) };
+            if !(rho_min..=rho_max).contains(&rho) {
+                warnings.push(COLUMN_REINFORCEMENT_RATIO.warn(format!(
+                    "ρ = {:.3} is outside {:.3}-{:.3} for {}",
+                    rho, rho_min, rho_max, code.as_str()
+                )));
+            }
+
+            results.push(
+                EngineeringResultItem::new("Required Area", area, "mm²")
+                    .critical()
+                    .with_format(format!("{:.0} mm² ({:.0} mm square)", area, side)),
+            );
+            results.push(
+                EngineeringResultItem::new("Longitudinal Steel", rho * area, "mm²")
+                    .with_format(format!("{:.0} mm²", rho * area)),
+            );
+            results.push(
+                EngineeringResultItem::new("Slenderness Ratio", slenderness, "dimensionless")
+                    .with_format(format!("{:.1}", slenderness)),
+            );
+
+            if code == DesignCode::ACI318 {
+                compliance_notes.push("Design per ACI 318 22.4.2, tied column φ = 0.65 with the 0.80 axial cap".to_string());
+                compliance_notes.push("Slenderness per ACI 318 6.2.5 assuming a sway frame".to_string());
+            } else {
+                compliance_notes.push(format!("Design per EN 1992-1-1 6.1, γc = {:.2}, γs = {:.2}", GAMMA_C, GAMMA_S));
+                compliance_notes.push("Slenderness limit per EN 1992-1-1 5.8.3.1 with default A, B, C".to_string());
+            }
+            compliance_notes.push("Minimum eccentricity moments are not included".to_string());
+        } else {
+            let radius = additional("radius_of_gyration").unwrap_or(50.0);
+            let slenderness = trace.step(
+                "formulas.column_design.slenderness",
+                &[("k", k), ("height", height), ("r", radius)],
+                k * height * 1000.0 / radius,
+                "dimensionless",
+            );
+            let (stress, area) = self.design_steel(&code, pu, slenderness, fy, &mut trace);
+
+            if slenderness > MAX_STEEL_SLENDERNESS {
+                warnings.push(COLUMN_SLENDER.warn(format!(
+                    "KL/r = {:.0} exceeds {:.0}",
+                    slenderness, MAX_STEEL_SLENDERNESS
+                )));
+            }
+            if area > 5000.0 {
+                warnings.push(COLUMN_LARGE_SECTION.warn("Large section required. Consider HSS or built-up"));
+            }
+
+            results.push(
+                EngineeringResultItem::new("Required Area", area, "mm²")
+                    .critical()
+                    .with_format(format!("{:.0} mm²", area)),
+            );
+            results.push(
+                EngineeringResultItem::new("Buckling Stress", stress, "MPa")
+                    .with_format(format!("{:.0} MPa", stress)),
+            );
+            results.push(
+                EngineeringResultItem::new("Slenderness Ratio", slenderness, "dimensionless")
+                    .with_format(format!("{:.1}", slenderness)),
+            );
+
+            if design_codes::is_eurocode(&code) {
+                compliance_notes.push("Design per EN 1993-1-1 6.3.1, buckling curve b, γM1 = 1.0".to_string());
+            } else {
+                compliance_notes.push("Design per AISC 360 LRFD Chapter E".to_string());
+            }
+            compliance_notes.push("Radius of gyration must be confirmed for the selected section".to_string());
+            compliance_notes.push("Check P-delta if applicable".to_string());
+        }
+
         Ok(EngineeringCalculationResponse {
             calculation_type: "column_design".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
                 timestamp: chrono::Utc::now().to_rfc3339(),
                 calculator_version: env!("CARGO_PKG_VERSION").to_string(),
-                design_code_used: "AISC 360".to_string(),
+                design_code_used: code.as_str().to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_column_design_dispatches_on_design_code() {
+        let params = |code: &str| -> EngineeringParameters {
+            serde_json::from_value(serde_json::json!({
+                "dimensions": { "length": 6.0, "height": 4.0 },
+                "loads": { "dead_load": 500.0, "live_load": 300.0, "load_combination": "LRFD" },
+                "additional": { "k_factor": 1.0 },
+                "design_code": code,
+            }))
+            .unwrap()
+        };
+
+        let aisc = execute("column_design", params("AISC 360")).await.unwrap();
+        // KL/r = 80 with the default 50 mm radius of gyration
+        assert!((value(&aisc, "Slenderness Ratio") - 80.0).abs() < 1e-9);
+        let area = value(&aisc, "Required Area");
+        assert!((area - 1080.0 * 1000.0 / (0.9 * value(&aisc, "Buckling Stress"))).abs() < 1e-6);
+        let aci = execute("column_design", params("ACI 318")).await.unwrap();
+        assert!((value(&aci, "Longitudinal Steel") - 0.02 * value(&aci, "Required Area")).abs() < 1e-6);
+
+        let mut unsupported = parameters_with_loads(500.0, 300.0);
+        unsupported.dimensions.insert("height".to_string(), 4.0);
+        unsupported.additional = Some([("k_factor".to_string(), 1.0)].into_iter().collect());
+        unsupported.design_code = Some("BS 5950".to_string());
+        assert!(execute("column_design", unsupported).await.is_err());
+    }
+}
@@ -2,12 +2,101 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::{CONNECTION_BOLT_GROUP_OVERSTRESSED, CONNECTION_COMBINED_TENSION, CONNECTION_MANY_BOLTS},
 };
 use async_trait::async_trait;
 
 use super::steel_properties::*;
 use super::resistance_factors::*;
 
+/// Bolt deformation at ultimate load, 0.34 in (AISC Manual Part 7)
+const DELTA_MAX: f64 = 8.64;
+
+/// Tensile strength of E70XX weld metal (MPa)
+const FEXX: f64 = 482.0;
+
+/// Standard fillet weld leg sizes (mm)
+const WELD_SIZES: [f64; 10] = [5.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0, 18.0, 20.0, 22.0];
+
+/// Rows tried before an undersized eccentric group is reported as is
+const MAX_ROWS: usize = 20;
+
+/// Rectangular bolt pattern centred on its centroid: (x, y) in mm
+fn bolt_pattern(rows: usize, columns: usize, pitch: f64, gauge: f64) -> Vec<(f64, f64)> {
+    let mut bolts = Vec::with_capacity(rows * columns);
+    for r in 0..rows {
+        for c in 0..columns {
+            bolts.push((
+                (c as f64 - (columns - 1) as f64 / 2.0) * gauge,
+                ((rows - 1) as f64 / 2.0 - r as f64) * pitch,
+            ));
+        }
+    }
+    bolts
+}
+
+/// Bolt forces as fractions of the ultimate bolt strength for an
+/// instantaneous centre `r0` to the left of the centroid:
+/// (forces, Σ vertical components, Σ R·r)
+fn icr_state(bolts: &[(f64, f64)], r0: f64) -> (Vec<f64>, f64, f64) {
+    let radii: Vec<f64> = bolts.iter().map(|&(x, y)| (x + r0).hypot(y)).collect();
+    let r_max = radii.iter().copied().fold(0.0, f64::max);
+    let mut vertical = 0.0;
+    let mut moment = 0.0;
+    let forces = bolts
+        .iter()
+        .zip(&radii)
+        .map(|(&(x, _), &r)| {
+            if r <= f64::EPSILON {
+                return 0.0;
+            }
+            // R = Rult·(1 − e^(−10Δ))^0.55 with Δ in inches
+            let delta = DELTA_MAX * r / r_max / 25.4;
+            let force = (1.0 - (-10.0 * delta).exp()).powf(0.55);
+            vertical += force * (x + r0) / r;
+            moment += force * r;
+            force
+        })
+        .collect();
+    (forces, vertical, moment)
+}
+
+/// Instantaneous centre of rotation (AISC Manual Part 7): the coefficient C
+/// such that the group carries C·Rn, and each bolt's share of the load
+fn instantaneous_center(bolts: &[(f64, f64)], e: f64) -> (f64, Vec<f64>) {
+    if e <= f64::EPSILON {
+        return (bolts.len() as f64, vec![1.0 / bolts.len() as f64; bolts.len()]);
+    }
+    // The IC lies on the horizontal through the centroid where the load
+    // from moment equilibrium matches the sum of vertical bolt forces
+    let mismatch = |r0: f64| {
+        let (_, vertical, moment) = icr_state(bolts, r0);
+        moment / (e + r0) - vertical
+    };
+    let (mut lo, mut hi) = (1e-3_f64, 1e6_f64);
+    for _ in 0..200 {
+        let mid = (lo * hi).sqrt();
+        if mismatch(mid) > 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let (forces, vertical, _) = icr_state(bolts, (lo * hi).sqrt());
+    let shares = forces.iter().map(|f| f / vertical).collect();
+    (vertical, shares)
+}
+
+/// Elastic vector method for two vertical fillet welds of length `l` spaced
+/// `b` apart: peak resultant force per unit length for a load `p` at `e`
+fn weld_peak_force(p: f64, e: f64, l: f64, b: f64) -> f64 {
+    let ip = 2.0 * l.powi(3) / 12.0 + 2.0 * l * (b / 2.0).powi(2);
+    let torsion = p * e;
+    let horizontal = torsion * (l / 2.0) / ip;
+    let vertical = p / (2.0 * l) + torsion * (b / 2.0) / ip;
+    horizontal.hypot(vertical)
+}
+
 pub struct ConnectionDesignCalculator;
 
 impl ParameterValidator for ConnectionDesignCalculator {
@@ -87,6 +176,71 @@ impl EngineerCalculator for ConnectionDesignCalculator {
                 typical_range: Some((16.0, 24.0)),
                 validation_rules: None,
             })
+            .parameter(ParameterMetadata {
+                name: "Load Eccentricity".to_string(),
+                path: "additional.eccentricity".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm".to_string(),
+                description: "Horizontal distance from the bolt group centroid to the line of action of the shear; enables the instantaneous center of rotation analysis".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(1000.0),
+                typical_range: Some((50.0, 300.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Bolt Rows".to_string(),
+                path: "additional.bolt_rows".to_string(),
+                data_type: ParameterType::Integer,
+                unit: "".to_string(),
+                description: "Bolts per vertical line in an eccentric group; the fewest rows that work are found when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(20.0),
+                typical_range: Some((2.0, 8.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Bolt Columns".to_string(),
+                path: "additional.bolt_columns".to_string(),
+                data_type: ParameterType::Integer,
+                unit: "".to_string(),
+                description: "Vertical lines of bolts in an eccentric group; 1 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(4.0),
+                typical_range: Some((1.0, 2.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Bolt Pitch".to_string(),
+                path: "additional.bolt_pitch".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm".to_string(),
+                description: "Vertical bolt spacing; 75 mm when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(40.0),
+                max_value: Some(200.0),
+                typical_range: Some((70.0, 100.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Bolt Gauge".to_string(),
+                path: "additional.bolt_gauge".to_string(),
+                data_type: ParameterType::Number,
+                unit: "mm".to_string(),
+                description: "Horizontal spacing between bolt lines, and the weld line spacing for the weld alternative; 75 mm when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(40.0),
+                max_value: Some(300.0),
+                typical_range: Some((75.0, 140.0)),
+                validation_rules: None,
+            })
             .complexity(ComplexityLevel::Intermediate)
             .build()
     }
@@ -112,6 +266,11 @@ impl EngineerCalculator for ConnectionDesignCalculator {
         }
 
         self.validate_dimension("diameter", params.dimensions.get("diameter").copied(), 12.0, 36.0)?;
+        self.optional_param(params, "eccentricity", 0.0, 0.0, 1000.0)?;
+        self.optional_param(params, "bolt_rows", 1.0, 1.0, 20.0)?;
+        self.optional_param(params, "bolt_columns", 1.0, 1.0, 4.0)?;
+        self.optional_param(params, "bolt_pitch", 75.0, 40.0, 200.0)?;
+        self.optional_param(params, "bolt_gauge", 75.0, 40.0, 300.0)?;
 
         Ok(())
     }
@@ -122,42 +281,173 @@ impl EngineerCalculator for ConnectionDesignCalculator {
         let grade = params.additional.as_ref().and_then(|a| a.get("bolt_grade")).map(|v| v.to_string()).unwrap_or("A325".to_string());
         let d = params.dimensions.get("diameter").copied().unwrap_or(20.0) / 1000.0;
 
+        let mut trace = CalculationTrace::new();
+
         let fub = if grade == "A325" { 830.0 } else { 1130.0 }; // MPa
         let area = std::f64::consts::PI * (d / 2.0).powi(2);
+        trace.step("formulas.connection_design.bolt_area", &[("diameter", d)], area, "m²");
         let rn_shear = 0.5 * fub * area * 1000.0; // kN per bolt
+        trace.step(
+            "formulas.connection_design.bolt_shear_strength",
+            &[("fub", fub), ("area", area)],
+            rn_shear,
+            "kN",
+        );
         let num_bolts_shear = (vu / (PHI_SHEAR * rn_shear)).ceil();
+        trace.step(
+            "formulas.connection_design.bolts_required_shear",
+            &[("vu", vu), ("phi", PHI_SHEAR), ("rn_shear", rn_shear)],
+            num_bolts_shear,
+            "bolts",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
+        let mut eccentric_results = Vec::new();
+        let mut analysis = None;
+        let mut required_bolts = num_bolts_shear;
+
+        let eccentricity = self.optional_param(&params, "eccentricity", 0.0, 0.0, 1000.0)?;
+        if eccentricity > 0.0 {
+            let columns = self.optional_param(&params, "bolt_columns", 1.0, 1.0, 4.0)?.round() as usize;
+            let pitch = self.optional_param(&params, "bolt_pitch", 75.0, 40.0, 200.0)?;
+            let gauge = self.optional_param(&params, "bolt_gauge", 75.0, 40.0, 300.0)?;
+            let bolt_capacity = PHI_SHEAR * rn_shear;
+            let fixed_rows = params.additional.as_ref().and_then(|a| a.get("bolt_rows")).map(|r| r.round() as usize);
+
+            // Fewest rows whose group capacity C·φRn carries the shear, unless the layout is given
+            let first = fixed_rows.unwrap_or_else(|| ((num_bolts_shear / columns as f64).ceil() as usize).max(2));
+            let mut rows = first;
+            let (mut bolts, mut c, mut shares);
+            loop {
+                bolts = bolt_pattern(rows, columns, pitch, gauge);
+                (c, shares) = instantaneous_center(&bolts, eccentricity);
+                if fixed_rows.is_some() || vu <= c * bolt_capacity || rows >= MAX_ROWS {
+                    break;
+                }
+                rows += 1;
+            }
+            required_bolts = (rows * columns) as f64;
+            let c = trace.step(
+                "formulas.connection_design.icr_coefficient",
+                &[("bolts", required_bolts), ("eccentricity", eccentricity), ("pitch", pitch), ("gauge", gauge)],
+                c,
+                "bolts",
+            );
+            let group_capacity = trace.step(
+                "formulas.connection_design.bolt_group_capacity",
+                &[("c", c), ("phi", PHI_SHEAR), ("rn_shear", rn_shear)],
+                c * bolt_capacity,
+                "kN",
+            );
+            let utilization = vu / group_capacity;
+            let demands: Vec<f64> = shares.iter().map(|share| share * vu).collect();
+            let governing = demands.iter().copied().fold(0.0, f64::max);
+
+            if utilization > 1.0 {
+                warnings.push(CONNECTION_BOLT_GROUP_OVERSTRESSED.warn(format!(
+                    "{} bolts with C = {:.2} carry {:.1} kN against {:.1} kN",
+                    rows * columns,
+                    c,
+                    group_capacity,
+                    vu
+                )));
+                recommendations.push("Add rows or bolt lines, reduce the eccentricity, or use a larger bolt".to_string());
+            }
+
+            // Weld alternative: two vertical fillet welds spanning the bolt pattern
+            let weld_length = rows as f64 * pitch;
+            let weld_spacing = columns as f64 * gauge;
+            let peak = weld_peak_force(vu * 1000.0, eccentricity, weld_length, weld_spacing);
+            let strength_per_leg = PHI_WELD * 0.6 * FEXX * std::f64::consts::FRAC_1_SQRT_2;
+            let required_leg = trace.step(
+                "formulas.connection_design.weld_leg",
+                &[("peak_force", peak), ("length", weld_length), ("spacing", weld_spacing)],
+                peak / strength_per_leg,
+                "mm",
+            );
+            let leg = WELD_SIZES.iter().copied().find(|&w| w >= required_leg).unwrap_or(required_leg.ceil());
+            let weld_capacity = vu * leg / required_leg;
+            recommendations.push(format!(
+                "Weld alternative: two {:.0} mm E70 fillet welds, {:.0} mm long at {:.0} mm centres, carry {:.0} kN against {:.0} kN for the bolt group",
+                leg, weld_length, weld_spacing, weld_capacity, group_capacity
+            ));
+            compliance_notes.push("Eccentric bolt group by the instantaneous center of rotation method (AISC Manual Part 7)".to_string());
+            compliance_notes.push("Weld alternative by the elastic vector method; check the minimum fillet size for the connected thickness".to_string());
+
+            eccentric_results.push(
+                EngineeringResultItem::new("Bolt Group Coefficient C", c, "")
+                    .with_format(format!("{:.2} for {} × {} bolts", c, rows, columns)),
+            );
+            eccentric_results.push(
+                EngineeringResultItem::new("Bolt Group Capacity", group_capacity, "kN")
+                    .critical()
+                    .with_format(format!("{:.1} kN", group_capacity)),
+            );
+            eccentric_results.push(
+                EngineeringResultItem::new("Governing Bolt Demand", governing, "kN")
+                    .with_format(format!("{:.1} kN", governing)),
+            );
+            eccentric_results.push(
+                EngineeringResultItem::new("Governing Bolt Utilization", utilization, "")
+                    .critical()
+                    .with_format(format!("{:.2}", utilization)),
+            );
+            for (i, demand) in demands.iter().enumerate() {
+                eccentric_results.push(
+                    EngineeringResultItem::new(format!("Bolt R{}C{} Demand", i / columns + 1, i % columns + 1), *demand, "kN")
+                        .with_format(format!("{:.1} kN", demand)),
+                );
+            }
+            eccentric_results.push(
+                EngineeringResultItem::new("Weld Alternative Leg Size", leg, "mm")
+                    .with_format(format!("{:.0} mm", leg)),
+            );
+            eccentric_results.push(
+                EngineeringResultItem::new("Weld Alternative Capacity", weld_capacity, "kN")
+                    .with_format(format!("{:.1} kN", weld_capacity)),
+            );
+
+            analysis = Some(StructuralAnalysisResult {
+                max_moment: vu * eccentricity / 1000.0,
+                max_shear: vu,
+                max_deflection: 0.0,
+                utilization_ratio: utilization,
+                governing_limit_state: "Bolt shear, instantaneous center of rotation".to_string(),
+                stress_distribution: None,
+                zone_pressures: None,
+                story_forces: None,
+            });
+        }
 
         if tu > 0.0 {
-            warnings.push("Tension present - check combined interaction".to_string());
+            warnings.push(CONNECTION_COMBINED_TENSION.warn("Tension present - check combined interaction"));
             recommendations.push("Use pretensioned bolts for tension".to_string());
         }
 
-        if num_bolts_shear > 8.0 {
-            warnings.push("Many bolts required. Consider welded connection".to_string());
+        if required_bolts > 8.0 {
+            warnings.push(CONNECTION_MANY_BOLTS.warn("Many bolts required. Consider welded connection"));
         }
 
         compliance_notes.push("Design per AISC 360 Chapter J".to_string());
         compliance_notes.push("Assume threads excluded from shear plane".to_string());
         compliance_notes.push("Check bearing and tearout".to_string());
 
-        let results = vec![
+        let mut results = vec![
             EngineeringResultItem::new("Bolt Shear Capacity", rn_shear, "kN/bolt")
                 .with_format(format!("{:.1} kN/bolt", rn_shear)),
-            EngineeringResultItem::new("Required Bolts", num_bolts_shear, "")
-                .critical()
-                .with_format(format!("{:.0}", num_bolts_shear)),
+            EngineeringResultItem::new("Required Bolts", required_bolts, "")
+                .with_format(format!("{:.0}", required_bolts)),
         ];
+        results.extend(eccentric_results);
 
         Ok(EngineeringCalculationResponse {
             calculation_type: "connection_design".to_string(),
             results,
-            analysis: None,
-            warnings,
-            structured_warnings: None,
+            analysis,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -166,7 +456,63 @@ impl EngineerCalculator for ConnectionDesignCalculator {
                 design_code_used: "AISC 360".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_connection_eccentric_bolt_group() {
+        let params = |additional: serde_json::Value| -> EngineeringParameters {
+            serde_json::from_value(serde_json::json!({
+                "dimensions": { "diameter": 20.0 },
+                "loads": { "dead_load": 0.0, "live_load": 0.0, "shear_load": 200.0, "load_combination": "LRFD" },
+                "additional": additional,
+            }))
+            .unwrap()
+        };
+
+        // Single line of four bolts at 75 mm pitch: the ICR coefficient beats the elastic 1.54
+        let check = execute("connection_design", params(serde_json::json!({ "eccentricity": 150.0, "bolt_rows": 4.0 })))
+            .await
+            .unwrap();
+        let c = value(&check, "Bolt Group Coefficient C");
+        assert!(c > 1.538 && c < 4.0, "C = {}", c);
+        assert_eq!(value(&check, "Required Bolts"), 4.0);
+        assert!(check.results.iter().any(|i| i.label == "Bolt R4C1 Demand"));
+        assert!(!warning_codes(&check).contains(&"connection_design.bolt_group_overstressed".to_string()));
+
+        let short = execute("connection_design", params(serde_json::json!({ "eccentricity": 150.0, "bolt_rows": 3.0 })))
+            .await
+            .unwrap();
+        assert!(value(&short, "Governing Bolt Utilization") > 1.0);
+        assert!(warning_codes(&short).contains(&"connection_design.bolt_group_overstressed".to_string()));
+        assert!(check.analysis.is_some());
+
+        let near = execute("connection_design", params(serde_json::json!({ "eccentricity": 5.0, "bolt_rows": 4.0 })))
+            .await
+            .unwrap();
+        assert!(value(&near, "Bolt Group Coefficient C") > c);
+        assert!(value(&near, "Bolt Group Coefficient C") <= 4.0);
+
+        // Without a layout the rows grow until the group carries the shear
+        let design = execute("connection_design", params(serde_json::json!({ "eccentricity": 150.0 })))
+            .await
+            .unwrap();
+        assert_eq!(value(&design, "Required Bolts"), 4.0);
+        assert!(value(&design, "Governing Bolt Utilization") <= 1.0);
+        assert!(!warning_codes(&design).contains(&"connection_design.bolt_group_overstressed".to_string()));
+        let leg = value(&design, "Weld Alternative Leg Size");
+        assert!([5.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0, 18.0, 20.0, 22.0].contains(&leg));
+        assert!(value(&design, "Weld Alternative Capacity") >= 200.0);
+
+        let concentric = execute("connection_design", params(serde_json::json!({}))).await.unwrap();
+        assert!(concentric.analysis.is_none());
+    }
+}
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::LATERAL_HIGH_LOAD,
 };
 use async_trait::async_trait;
 
@@ -132,21 +133,41 @@ impl EngineerCalculator for LateralLoadAnalysisCalculator {
         let base_load = params.loads.as_ref().map(|l| l.wind_load.unwrap_or(1.0)).unwrap_or(1.0);
         let num_stories = params.additional.as_ref().and_then(|a| a.get("num_stories").copied()).unwrap_or(5.0);
 
+        let mut trace = CalculationTrace::new();
+
         let area = width * height / num_stories; // Per story approx
+        trace.step(
+            "formulas.lateral_load_analysis.story_area",
+            &[("width", width), ("height", height), ("num_stories", num_stories)],
+            area,
+            "m²",
+        );
         let total_force = if load_type == "wind" {
             base_load * area * num_stories
         } else {
             base_load * 1000.0 * area * num_stories // Seismic mass approx
         };
         let base_shear = total_force;
+        trace.step(
+            "formulas.lateral_load_analysis.base_shear",
+            &[("base_load", base_load), ("area", area), ("num_stories", num_stories)],
+            base_shear,
+            "kN",
+        );
         let base_moment = base_shear * height / 2.0;
+        trace.step(
+            "formulas.lateral_load_analysis.overturning_moment",
+            &[("base_shear", base_shear), ("height", height)],
+            base_moment,
+            "kNm",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if total_force > 5000.0 {
-            warnings.push("High lateral load. Verify building configuration".to_string());
+            warnings.push(LATERAL_HIGH_LOAD.warn("High lateral load. Verify building configuration"));
             recommendations.push("Consider shear walls for high-rise".to_string());
         }
 
@@ -165,8 +186,8 @@ impl EngineerCalculator for LateralLoadAnalysisCalculator {
             calculation_type: "lateral_load_analysis".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -175,6 +196,8 @@ impl EngineerCalculator for LateralLoadAnalysisCalculator {
                 design_code_used: "ASCE 7".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
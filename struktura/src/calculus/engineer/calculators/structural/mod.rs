@@ -13,6 +13,9 @@ pub mod moment_frame_design;
 pub mod connection_design;
 pub mod slab_design;
 pub mod lateral_load_analysis;
+pub mod wind_load;
+pub mod seismic_load;
+pub mod tower_crane;
 
 // Re-export calculators
 pub use beam_design::BeamDesignCalculator;
@@ -22,6 +25,9 @@ pub use moment_frame_design::MomentFrameDesignCalculator;
 pub use connection_design::ConnectionDesignCalculator;
 pub use slab_design::SlabDesignCalculator;
 pub use lateral_load_analysis::LateralLoadAnalysisCalculator;
+pub use wind_load::WindLoadCalculator;
+pub use seismic_load::SeismicLoadCalculator;
+pub use tower_crane::TowerCraneCalculator;
 
 // ============================================================================
 // STRUCTURAL ENGINEERING CONSTANTS
@@ -63,6 +69,33 @@ pub mod concrete_properties {
     pub const FC_C35: f64 = 35.0;  // 5000 psi
     pub const FC_C40: f64 = 40.0;
     
+    // Reinforcing bar yield strengths (MPa)
+    pub const FY_REBAR_GR60: f64 = 420.0; // ASTM A615 Grade 60
+    pub const FYK_B500: f64 = 500.0;      // EN 10080 B500
+
+    // Bar designations: (name, nominal diameter mm, area mm²)
+    pub const ASTM_BARS: [(&str, f64, f64); 9] = [
+        ("#3", 9.5, 71.0),
+        ("#4", 12.7, 129.0),
+        ("#5", 15.9, 199.0),
+        ("#6", 19.1, 284.0),
+        ("#7", 22.2, 387.0),
+        ("#8", 25.4, 510.0),
+        ("#9", 28.7, 645.0),
+        ("#10", 32.3, 819.0),
+        ("#11", 35.8, 1006.0),
+    ];
+    pub const METRIC_BARS: [(&str, f64, f64); 8] = [
+        ("H8", 8.0, 50.3),
+        ("H10", 10.0, 78.5),
+        ("H12", 12.0, 113.1),
+        ("H16", 16.0, 201.1),
+        ("H20", 20.0, 314.2),
+        ("H25", 25.0, 490.9),
+        ("H32", 32.0, 804.2),
+        ("H40", 40.0, 1256.6),
+    ];
+
     // Elastic modulus (GPa) - approximate
     pub const E_CONCRETE_C28: f64 = 25.0;
     pub const E_CONCRETE_C35: f64 = 28.0;
@@ -87,12 +120,22 @@ pub mod resistance_factors {
     pub const PHI_SHEAR: f64 = 0.90;
     pub const PHI_COMPRESSION: f64 = 0.90;
     pub const PHI_TENSION: f64 = 0.90;
+    pub const PHI_WELD: f64 = 0.75;
     
     // ACI 318 (Concrete)
     pub const PHI_FLEXURE_TENSION: f64 = 0.90;
     pub const PHI_COMPRESSION_TIED: f64 = 0.65;
     pub const PHI_COMPRESSION_SPIRAL: f64 = 0.75;
     pub const PHI_SHEAR_CONCRETE: f64 = 0.75;
+    /// Cap on the axial strength of tied columns (ACI 318 Table 22.4.2.1)
+    pub const AXIAL_CAP_TIED: f64 = 0.80;
+
+    // Eurocode partial factors (recommended values)
+    pub const GAMMA_M0: f64 = 1.00; // EN 1993-1-1 cross-section resistance
+    pub const GAMMA_M1: f64 = 1.00; // EN 1993-1-1 member buckling
+    pub const GAMMA_C: f64 = 1.50;  // EN 1992-1-1 concrete
+    pub const GAMMA_S: f64 = 1.15;  // EN 1992-1-1 reinforcement
+    pub const ALPHA_CC: f64 = 1.00; // EN 1992-1-1 long-term concrete strength
 }
 
 /// Load factors (LRFD)
@@ -102,6 +145,9 @@ pub mod load_factors {
     pub const DEAD_PLUS_LIVE: (f64, f64) = (1.2, 1.6);
     pub const DEAD_PLUS_WIND: (f64, f64) = (1.2, 1.0);
     pub const DEAD_PLUS_SEISMIC: (f64, f64) = (1.2, 1.0);
+
+    // EN 1990 equation 6.10 (permanent, leading variable)
+    pub const EN1990_PERMANENT_PLUS_VARIABLE: (f64, f64) = (1.35, 1.5);
     
     // Service load factors (ASD)
     pub const SERVICE_DEAD: f64 = 1.0;
@@ -143,6 +189,101 @@ pub mod helpers {
         let utilization = deflection_mm / allowable;
         (passes, utilization)
     }
+
+    /// Linear interpolation of `ys` over ascending `xs`, clamped at the ends
+    pub fn interpolate(x: f64, xs: &[f64], ys: &[f64]) -> f64 {
+        if x <= xs[0] {
+            return ys[0];
+        }
+        for i in 1..xs.len() {
+            if x <= xs[i] {
+                let t = (x - xs[i - 1]) / (xs[i] - xs[i - 1]);
+                return ys[i - 1] + t * (ys[i] - ys[i - 1]);
+            }
+        }
+        ys[ys.len() - 1]
+    }
+}
+
+/// Design code dispatch for the member design calculators
+pub mod design_codes {
+    use super::load_factors::*;
+    use crate::calculus::engineer::models::DesignCode;
+
+    /// Codes the beam and column calculators design to
+    pub const MEMBER_CODES: [DesignCode; 4] =
+        [DesignCode::AISC360, DesignCode::EurocodeEC3, DesignCode::ACI318, DesignCode::EurocodeEC2];
+
+    fn key(code: &str) -> String {
+        code.chars().filter(char::is_ascii_alphanumeric).map(|c| c.to_ascii_lowercase()).collect()
+    }
+
+    /// Parse a member code as users write it: "ACI 318-19", "EN 1993-1-1", "EC2", "Eurocode 3"
+    pub fn parse(code: &str) -> Option<DesignCode> {
+        let key = key(code);
+        if key.starts_with("aci318") {
+            Some(DesignCode::ACI318)
+        } else if key.starts_with("aisc360") {
+            Some(DesignCode::AISC360)
+        } else if ["ec2", "eurocode2", "en1992"].iter().any(|p| key.starts_with(p)) {
+            Some(DesignCode::EurocodeEC2)
+        } else if ["ec3", "eurocode3", "en1993"].iter().any(|p| key.starts_with(p)) {
+            Some(DesignCode::EurocodeEC3)
+        } else {
+            None
+        }
+    }
+
+    /// Code a member is designed to
+    ///
+    /// A member code names the material too. A general profile such as
+    /// "Eurocode" or "ASCE 7" only picks the family, and the material type
+    /// picks concrete or steel within it; no code at all means US practice.
+    pub fn member_code(requested: Option<&str>, material_type: Option<&str>) -> Option<DesignCode> {
+        let concrete = material_type.is_some_and(|m| m.trim().eq_ignore_ascii_case("concrete"));
+        let Some(requested) = requested else {
+            return Some(if concrete { DesignCode::ACI318 } else { DesignCode::AISC360 });
+        };
+        if let Some(code) = parse(requested) {
+            return Some(code);
+        }
+        let key = key(requested);
+        if key.starts_with("eurocode") || key.starts_with("en1990") || key == "ec" || key == "en" {
+            Some(if concrete { DesignCode::EurocodeEC2 } else { DesignCode::EurocodeEC3 })
+        } else if key.starts_with("asce7") || key.starts_with("ibc") || key == "lrfd" {
+            Some(if concrete { DesignCode::ACI318 } else { DesignCode::AISC360 })
+        } else {
+            None
+        }
+    }
+
+    pub fn is_concrete(code: &DesignCode) -> bool {
+        matches!(code, DesignCode::ACI318 | DesignCode::EurocodeEC2)
+    }
+
+    pub fn is_eurocode(code: &DesignCode) -> bool {
+        matches!(code, DesignCode::EurocodeEC2 | DesignCode::EurocodeEC3)
+    }
+
+    /// Governing ultimate dead + live combination
+    pub fn ultimate_load(code: &DesignCode, dead: f64, live: f64) -> f64 {
+        if is_eurocode(code) {
+            let (g, q) = EN1990_PERMANENT_PLUS_VARIABLE;
+            g * dead + q * live
+        } else {
+            let (d, l) = DEAD_PLUS_LIVE;
+            f64::max(DEAD_ONLY * dead, d * dead + l * live)
+        }
+    }
+
+    /// Combination used by `ultimate_load`, for compliance notes
+    pub fn combination(code: &DesignCode) -> &'static str {
+        if is_eurocode(code) {
+            "1.35G + 1.5Q (EN 1990 eq. 6.10)"
+        } else {
+            "max(1.4D, 1.2D + 1.6L) (ASCE 7 LRFD)"
+        }
+    }
 }
 
 #[cfg(test)]
@@ -179,6 +320,24 @@ mod tests {
         assert!((factored - expected).abs() < 0.01);
     }
 
+    #[test]
+    fn test_member_code_selection() {
+        use design_codes::*;
+        use crate::calculus::engineer::models::DesignCode;
+
+        assert_eq!(parse("ACI 318-19"), Some(DesignCode::ACI318));
+        assert_eq!(parse("EN 1993-1-1"), Some(DesignCode::EurocodeEC3));
+        assert_eq!(parse("ASCE 7"), None);
+        assert_eq!(member_code(None, Some("Concrete")), Some(DesignCode::ACI318));
+        assert_eq!(member_code(Some("Eurocode"), Some("concrete")), Some(DesignCode::EurocodeEC2));
+        assert_eq!(member_code(Some("Eurocode"), Some("Steel")), Some(DesignCode::EurocodeEC3));
+        assert_eq!(member_code(Some("EC2"), Some("Steel")), Some(DesignCode::EurocodeEC2));
+        assert_eq!(member_code(Some("BS 5950"), None), None);
+
+        assert!((ultimate_load(&DesignCode::AISC360, 10.0, 1.0) - 14.0).abs() < 1e-9);
+        assert!((ultimate_load(&DesignCode::EurocodeEC2, 10.0, 15.0) - 36.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_deflection_check() {
         use helpers::*;
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::FRAME_EXCESSIVE_DRIFT,
 };
 use async_trait::async_trait;
 use super::steel_properties::*;
@@ -113,16 +114,36 @@ impl EngineerCalculator for MomentFrameDesignCalculator {
         let v_base = params.loads.as_ref().map(|l| l.seismic_load).unwrap_or(Some(1000.0));
         let bay_w = params.dimensions.get("width").copied().unwrap_or(6.0);
 
+        let mut trace = CalculationTrace::new();
+
         let story_shear = v_base.expect("No v_base given, defaulting") / num_stories; // Approximate uniform
+        trace.step(
+            "formulas.moment_frame_design.story_shear",
+            &[("base_shear", story_shear * num_stories), ("num_stories", num_stories)],
+            story_shear,
+            "kN",
+        );
         let moment_beam = story_shear * story_h / 2.0; // Fixed ends approx
+        trace.step(
+            "formulas.moment_frame_design.beam_moment",
+            &[("story_shear", story_shear), ("story_height", story_h)],
+            moment_beam,
+            "kNm",
+        );
         let drift_est = v_base.expect("No v_base given, defaulting") * (num_stories * story_h).powi(3) / (12.0 * E_STEEL * 1e6 * (bay_w / 10.0).powi(4)); // Approximate
+        trace.step(
+            "formulas.moment_frame_design.drift_estimate",
+            &[("num_stories", num_stories), ("story_height", story_h), ("bay_width", bay_w)],
+            drift_est,
+            "m",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if drift_est > story_h / 400.0 {
-            warnings.push(format!("Excessive drift ({:.1} mm). Stiffen frame.", drift_est * 1000.0));
+            warnings.push(FRAME_EXCESSIVE_DRIFT.warn(format!("Excessive drift ({:.1} mm). Stiffen frame.", drift_est * 1000.0)));
             recommendations.push("Increase member sizes or add bracing".to_string());
         }
 
@@ -144,8 +165,8 @@ impl EngineerCalculator for MomentFrameDesignCalculator {
             calculation_type: "moment_frame_design".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -154,6 +175,8 @@ impl EngineerCalculator for MomentFrameDesignCalculator {
                 design_code_used: "AISC 341".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
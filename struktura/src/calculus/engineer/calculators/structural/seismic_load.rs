@@ -0,0 +1,462 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{SEISMIC_ELF_NOT_PERMITTED, SEISMIC_MINIMUM_CS_GOVERNS},
+};
+use async_trait::async_trait;
+
+use super::helpers::interpolate;
+
+/// Approximate period parameters for "all other structural systems",
+/// Ta = Ct·hn^x with hn in metres (ASCE 7 Table 12.8-2)
+const CT_OTHER: f64 = 0.0488;
+const X_OTHER: f64 = 0.75;
+
+/// Assumed storey height when the number of levels is not given
+const TYPICAL_STORY_HEIGHT: f64 = 3.5;
+
+/// Short-period site coefficient Fa at Ss = 0.25, 0.5, 0.75, 1.0, 1.25, 1.5
+/// (ASCE 7-16 Table 11.4-1)
+const FA_SS: [f64; 6] = [0.25, 0.5, 0.75, 1.0, 1.25, 1.5];
+
+/// Long-period site coefficient Fv at S1 = 0.1 to 0.6 (ASCE 7-16 Table 11.4-2)
+const FV_S1: [f64; 6] = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+
+/// Upper limit coefficient Cu on the computed period against SD1 (Table 12.8-1)
+const CU_SD1: [f64; 5] = [0.1, 0.15, 0.2, 0.3, 0.4];
+const CU: [f64; 5] = [1.7, 1.6, 1.5, 1.4, 1.4];
+
+/// Site coefficients (Fa, Fv rows) for site classes A to E; F needs a site
+/// response analysis
+fn site_coefficients(site_class: &str) -> Option<([f64; 6], [f64; 6])> {
+    match site_class.trim().to_ascii_uppercase().as_str() {
+        "A" => Some(([0.8; 6], [0.8; 6])),
+        "B" => Some(([0.9; 6], [0.8; 6])),
+        "C" => Some(([1.3, 1.3, 1.2, 1.2, 1.2, 1.2], [1.5, 1.5, 1.5, 1.5, 1.5, 1.4])),
+        "D" => Some(([1.6, 1.4, 1.2, 1.1, 1.0, 1.0], [2.4, 2.2, 2.0, 1.9, 1.8, 1.7])),
+        "E" => Some(([2.4, 1.7, 1.3, 1.1, 0.9, 0.8], [4.2, 3.3, 2.8, 2.4, 2.2, 2.0])),
+        _ => None,
+    }
+}
+
+/// Seismic design category from SDS and SD1 (ASCE 7 Tables 11.6-1 and 11.6-2),
+/// for risk categories I to III
+fn design_category(sds: f64, sd1: f64, s1: f64) -> &'static str {
+    if s1 >= 0.75 {
+        return "E";
+    }
+    let by_sds = match sds {
+        x if x < 0.167 => 0,
+        x if x < 0.33 => 1,
+        x if x < 0.5 => 2,
+        _ => 3,
+    };
+    let by_sd1 = match sd1 {
+        x if x < 0.067 => 0,
+        x if x < 0.133 => 1,
+        x if x < 0.2 => 2,
+        _ => 3,
+    };
+    ["A", "B", "C", "D"][by_sds.max(by_sd1)]
+}
+
+pub struct SeismicLoadCalculator;
+
+impl ParameterValidator for SeismicLoadCalculator {
+    fn calculator_id(&self) -> &str {
+        "seismic_load"
+    }
+}
+
+impl SeismicLoadCalculator {
+    fn site_class<'a>(&self, params: &'a EngineeringParameters) -> EngineeringResult<(&'a str, [f64; 6], [f64; 6])> {
+        let site_class = params
+            .extended_parameters
+            .as_ref()
+            .and_then(|e| e.get("site_class"))
+            .and_then(|v| v.as_string())
+            .unwrap_or("D");
+        let (fa, fv) = site_coefficients(site_class).ok_or_else(|| EngineeringError::InvalidParameter {
+            parameter: "site_class".to_string(),
+            value: site_class.to_string(),
+            reason: "Site class must be A to E; site class F requires a site response analysis".to_string(),
+        })?;
+        Ok((site_class, fa, fv))
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for SeismicLoadCalculator {
+    fn id(&self) -> &str {
+        "seismic_load"
+    }
+
+    fn name(&self) -> &str {
+        "Seismic Base Shear (ASCE 7 ELF)"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Structural
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("seismic_load", "Seismic Base Shear (ASCE 7 ELF)")
+            .category("structural")
+            .description("Seismic response coefficient, base shear and vertical force distribution by the equivalent lateral force procedure")
+            .design_code("ASCE 7")
+            .parameter(ParameterMetadata {
+                name: "Structural Height".to_string(),
+                path: "dimensions.height".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Height hn from the base to the highest level of the seismic force-resisting system".to_string(),
+                required: true,
+                default_value: Some(15.0),
+                min_value: Some(3.0),
+                max_value: Some(300.0),
+                typical_range: Some((6.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Short-Period Spectral Acceleration".to_string(),
+                path: "additional.ss".to_string(),
+                data_type: ParameterType::Number,
+                unit: "g".to_string(),
+                description: "Mapped MCE_R spectral acceleration Ss at 0.2 s".to_string(),
+                required: true,
+                default_value: Some(1.0),
+                min_value: Some(0.0),
+                max_value: Some(4.0),
+                typical_range: Some((0.1, 2.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "1-Second Spectral Acceleration".to_string(),
+                path: "additional.s1".to_string(),
+                data_type: ParameterType::Number,
+                unit: "g".to_string(),
+                description: "Mapped MCE_R spectral acceleration S1 at 1 s".to_string(),
+                required: true,
+                default_value: Some(0.4),
+                min_value: Some(0.0),
+                max_value: Some(2.0),
+                typical_range: Some((0.05, 0.8)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Site Class".to_string(),
+                path: "extended_parameters.site_class".to_string(),
+                data_type: ParameterType::String,
+                unit: "".to_string(),
+                description: "Site class A to E; D when the soil properties are not known".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec!["A, B, C, D or E".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Response Modification Coefficient".to_string(),
+                path: "additional.response_modification".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "R for the seismic force-resisting system (ASCE 7 Table 12.2-1)".to_string(),
+                required: true,
+                default_value: Some(8.0),
+                min_value: Some(1.0),
+                max_value: Some(8.0),
+                typical_range: Some((3.0, 8.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Importance Factor".to_string(),
+                path: "additional.importance_factor".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "Seismic importance factor Ie: 1.0 for risk category I/II, 1.25 for III, 1.5 for IV".to_string(),
+                required: false,
+                default_value: Some(1.0),
+                min_value: Some(1.0),
+                max_value: Some(1.5),
+                typical_range: Some((1.0, 1.5)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Seismic Weight".to_string(),
+                path: "additional.seismic_weight".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kN".to_string(),
+                description: "Effective seismic weight W: dead load plus applicable storage, partition and snow loads".to_string(),
+                required: true,
+                default_value: Some(20000.0),
+                min_value: Some(1.0),
+                max_value: Some(5.0e6),
+                typical_range: Some((1000.0, 200000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Fundamental Period".to_string(),
+                path: "additional.period".to_string(),
+                data_type: ParameterType::Number,
+                unit: "s".to_string(),
+                description: "Period from a structural model, capped at Cu·Ta; the approximate period Ta is used when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.01),
+                max_value: Some(10.0),
+                typical_range: Some((0.1, 4.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Long-Period Transition".to_string(),
+                path: "additional.long_period_transition".to_string(),
+                data_type: ParameterType::Number,
+                unit: "s".to_string(),
+                description: "Long-period transition period TL from ASCE 7 Chapter 22".to_string(),
+                required: false,
+                default_value: Some(8.0),
+                min_value: Some(4.0),
+                max_value: Some(16.0),
+                typical_range: Some((4.0, 16.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Number of Stories".to_string(),
+                path: "additional.num_stories".to_string(),
+                data_type: ParameterType::Integer,
+                unit: "".to_string(),
+                description: "Levels the base shear is distributed to, weight shared equally; one per 3.5 m when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(100.0),
+                typical_range: Some((1.0, 20.0)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Advanced)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.validate_dimension("height", params.dimensions.get("height").copied(), 3.0, 300.0)?;
+        self.get_additional_param(params, "ss", Some(0.0), Some(4.0))?;
+        self.get_additional_param(params, "s1", Some(0.0), Some(2.0))?;
+        self.get_additional_param(params, "response_modification", Some(1.0), Some(8.0))?;
+        self.get_additional_param(params, "seismic_weight", Some(1.0), Some(5.0e6))?;
+        self.site_class(params)?;
+        self.optional_param(params, "importance_factor", 1.0, 1.0, 1.5)?;
+        self.optional_param(params, "period", 0.0, 0.01, 10.0)?;
+        self.optional_param(params, "long_period_transition", 8.0, 4.0, 16.0)?;
+        self.optional_param(params, "num_stories", 1.0, 1.0, 100.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let hn = params.dimensions.get("height").copied().unwrap_or(15.0);
+        let ss = self.get_additional_param(&params, "ss", None, None)?;
+        let s1 = self.get_additional_param(&params, "s1", None, None)?;
+        let r = self.get_additional_param(&params, "response_modification", None, None)?;
+        let w = self.get_additional_param(&params, "seismic_weight", None, None)?;
+        let (site_class, fa_row, fv_row) = self.site_class(&params)?;
+        let ie = self.optional_param(&params, "importance_factor", 1.0, 1.0, 1.5)?;
+        let tl = self.optional_param(&params, "long_period_transition", 8.0, 4.0, 16.0)?;
+        let stories = self
+            .optional_param(&params, "num_stories", (hn / TYPICAL_STORY_HEIGHT).ceil().max(1.0), 1.0, 100.0)?
+            .round();
+
+        let mut trace = CalculationTrace::new();
+
+        // Site-adjusted and design spectral accelerations (ASCE 7 11.4.4, 11.4.5)
+        let fa = interpolate(ss, &FA_SS, &fa_row);
+        let fv = interpolate(s1, &FV_S1, &fv_row);
+        let sds = trace.step(
+            "formulas.seismic_load.sds",
+            &[("fa", fa), ("ss", ss)],
+            2.0 / 3.0 * fa * ss,
+            "g",
+        );
+        let sd1 = trace.step(
+            "formulas.seismic_load.sd1",
+            &[("fv", fv), ("s1", s1)],
+            2.0 / 3.0 * fv * s1,
+            "g",
+        );
+        let sdc = design_category(sds, sd1, s1);
+
+        // Approximate period, with any modelled period capped at Cu·Ta (12.8.2)
+        let ta = trace.step(
+            "formulas.seismic_load.approximate_period",
+            &[("ct", CT_OTHER), ("hn", hn), ("x", X_OTHER)],
+            CT_OTHER * hn.powf(X_OTHER),
+            "s",
+        );
+        let cu = interpolate(sd1, &CU_SD1, &CU);
+        let t = match params.additional.as_ref().and_then(|a| a.get("period")) {
+            Some(&computed) => computed.min(cu * ta),
+            None => ta,
+        };
+
+        // Seismic response coefficient (12.8.1.1)
+        let r_ie = r / ie;
+        let cs_computed = sds / r_ie;
+        let cs_max = if t <= tl { sd1 / (t * r_ie) } else { sd1 * tl / (t.powi(2) * r_ie) };
+        let mut cs_min = (0.044 * sds * ie).max(0.01);
+        if s1 >= 0.6 {
+            cs_min = cs_min.max(0.5 * s1 / r_ie);
+        }
+        let minimum_governs = cs_computed.min(cs_max) < cs_min;
+        let cs = trace.step(
+            "formulas.seismic_load.response_coefficient",
+            &[("sds", sds), ("sd1", sd1), ("r", r), ("ie", ie), ("t", t)],
+            cs_computed.min(cs_max).max(cs_min),
+            "dimensionless",
+        );
+        let base_shear = trace.step(
+            "formulas.seismic_load.base_shear",
+            &[("cs", cs), ("w", w)],
+            cs * w,
+            "kN",
+        );
+
+        // Vertical distribution Fx = Cvx·V with Cvx ∝ wx·hx^k (12.8.3)
+        let k = interpolate(t, &[0.5, 2.5], &[1.0, 2.0]);
+        let story_height = hn / stories;
+        let story_weight = w / stories;
+        let levels: Vec<f64> = (1..=stories as u32).map(|i| story_height * i as f64).collect();
+        let sum_whk: f64 = levels.iter().map(|h| story_weight * h.powf(k)).sum();
+        let mut story_forces = Vec::new();
+        let mut shear = 0.0;
+        let mut overturning = 0.0;
+        for (i, &hx) in levels.iter().enumerate().rev() {
+            let force = story_weight * hx.powf(k) / sum_whk * base_shear;
+            shear += force;
+            overturning += force * hx;
+            story_forces.push(StoryForce {
+                level: i as u32 + 1,
+                elevation: hx,
+                weight: story_weight,
+                force,
+                shear,
+            });
+        }
+        trace.step(
+            "formulas.seismic_load.overturning_moment",
+            &[("base_shear", base_shear), ("k", k), ("hn", hn)],
+            overturning,
+            "kNm",
+        );
+
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        // ELF is not permitted in SDC D to F once T ≥ 3.5·Ts (Table 12.6-1)
+        let ts = sd1 / sds.max(f64::EPSILON);
+        if matches!(sdc, "D" | "E" | "F") && t >= 3.5 * ts {
+            warnings.push(SEISMIC_ELF_NOT_PERMITTED.warn(format!(
+                "ELF is not permitted in SDC {} with T = {:.2} s ≥ 3.5·Ts = {:.2} s",
+                sdc,
+                t,
+                3.5 * ts
+            )));
+            recommendations.push("Use modal response spectrum analysis (ASCE 7 12.9.1)".to_string());
+        }
+        if minimum_governs {
+            warnings.push(SEISMIC_MINIMUM_CS_GOVERNS.warn(format!("Cs = {:.4} from the minimum limit", cs)));
+        }
+        if matches!(sdc, "D" | "E" | "F") {
+            recommendations.push("Check horizontal and vertical irregularities against ASCE 7 Table 12.6-1 before relying on ELF".to_string());
+        }
+        recommendations.push("Apply the redundancy factor ρ and 5% accidental torsion when distributing to the lateral system".to_string());
+
+        let compliance_notes = vec![
+            format!("Site class {}: Fa = {:.2}, Fv = {:.2} (ASCE 7-16 Tables 11.4-1 and 11.4-2)", site_class.to_ascii_uppercase(), fa, fv),
+            format!("Seismic design category {} for risk categories I to III", sdc),
+            format!("T = {:.2} s (Ta = {:.2} s, Ct = {}, x = {})", t, ta, CT_OTHER, X_OTHER),
+        ];
+
+        let results = vec![
+            EngineeringResultItem::new("SDS", sds, "g").with_format(format!("{:.3} g", sds)),
+            EngineeringResultItem::new("SD1", sd1, "g").with_format(format!("{:.3} g", sd1)),
+            EngineeringResultItem::new("Fundamental Period", t, "s").with_format(format!("{:.2} s", t)),
+            EngineeringResultItem::new("Seismic Response Coefficient Cs", cs, "dimensionless")
+                .critical()
+                .with_format(format!("{:.4}", cs)),
+            EngineeringResultItem::new("Base Shear", base_shear, "kN")
+                .critical()
+                .with_format(format!("{:.1} kN (SDC {})", base_shear, sdc)),
+            EngineeringResultItem::new("Distribution Exponent k", k, "dimensionless").with_format(format!("{:.2}", k)),
+            EngineeringResultItem::new("Roof Level Force", story_forces[0].force, "kN")
+                .with_format(format!("{:.1} kN", story_forces[0].force)),
+            EngineeringResultItem::new("Overturning Moment", overturning, "kNm").with_format(format!("{:.1} kNm", overturning)),
+        ];
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "seismic_load".to_string(),
+            results,
+            analysis: Some(StructuralAnalysisResult {
+                max_moment: overturning,
+                max_shear: base_shear,
+                max_deflection: 0.0,
+                // Loads only; member capacities are checked by the design calculators
+                utilization_ratio: 0.0,
+                governing_limit_state: format!("Seismic ELF, SDC {}", sdc),
+                stress_distribution: None,
+                zone_pressures: None,
+                story_forces: Some(story_forces),
+            }),
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "ASCE 7".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_seismic_load_base_shear_and_distribution() {
+        let params = |site_class: &str, height: f64| -> EngineeringParameters {
+            serde_json::from_value(serde_json::json!({
+                "dimensions": { "height": height },
+                "additional": { "ss": 1.0, "s1": 0.4, "response_modification": 8.0, "seismic_weight": 20000.0, "num_stories": 4.0 },
+                "extended_parameters": { "site_class": { "type": "String", "value": site_class } },
+            }))
+            .unwrap()
+        };
+
+        // Site D: Fa = 1.1, Fv = 1.9, so SDS = 0.733 g and SD1 = 0.507 g; Ta ≈ 0.37 s keeps Cs = SDS / R
+        let d = execute("seismic_load", params("D", 15.0)).await.unwrap();
+        assert!((value(&d, "SDS") - 2.0 / 3.0 * 1.1).abs() < 1e-9);
+        assert!((value(&d, "SD1") - 2.0 / 3.0 * 1.9 * 0.4).abs() < 1e-9);
+        assert!((value(&d, "Base Shear") - 20000.0 * 2.0 / 3.0 * 1.1 / 8.0).abs() < 1e-6);
+        assert_eq!(value(&d, "Distribution Exponent k"), 1.0);
+
+        // With k = 1 and equal weights the forces follow the level heights, top down
+        let analysis = d.analysis.as_ref().unwrap();
+        let forces = analysis.story_forces.as_ref().unwrap();
+        assert_eq!(forces.len(), 4);
+        assert_eq!(forces[0].level, 4);
+        assert!((forces[0].force / forces[3].force - 4.0).abs() < 1e-9);
+        assert!((forces[3].shear - analysis.max_shear).abs() < 1e-6);
+        assert!(d.warnings.is_empty());
+
+        // A 200 m tower in SDC D has T ≥ 3.5·Ts, outside the ELF procedure
+        let tall = execute("seismic_load", params("D", 200.0)).await.unwrap();
+        assert!(tall.structured_warnings.unwrap().iter().any(|w| w.code == "seismic_load.elf_not_permitted"));
+
+        assert!(execute("seismic_load", params("F", 15.0)).await.is_err());
+    }
+}
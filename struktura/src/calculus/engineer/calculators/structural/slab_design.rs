@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::SLAB_THICK,
 };
 use async_trait::async_trait;
 
@@ -141,18 +142,39 @@ impl EngineerCalculator for SlabDesignCalculator {
         let fc = params.material.as_ref().and_then(|m| m.compressive_strength).unwrap_or(FC_C30);
         let fy = params.material.as_ref().and_then(|m| m.yield_strength).unwrap_or(420.0);
 
+        let mut trace = CalculationTrace::new();
+
         let self_wt = 0.15 * DENSITY_NORMAL / 1000.0; // Assume 150mm thick
         let qu = 1.2 * (dead + self_wt) + 1.6 * live;
+        trace.step(
+            "formulas.slab_design.factored_load",
+            &[("dead_load", dead), ("self_weight", self_wt), ("live_load", live)],
+            qu,
+            "kPa",
+        );
         let mu = qu * span.powi(2) / 8.0;
+        trace.step(
+            "formulas.slab_design.design_moment",
+            &[("qu", qu), ("span", span)],
+            mu,
+            "kNm/m",
+        );
         let d_req = (mu * 1000.0 / (0.9 * 0.85 * fc * 1000.0)).sqrt(); // mm approx
+        trace.step("formulas.slab_design.required_depth", &[("mu", mu), ("fc", fc)], d_req, "mm");
         let as_req = 0.85 * fc * d_req / fy * (1.0 - (1.0 - 2.0 * mu * 1000.0 / (0.85 * fc * d_req.powi(2))).sqrt());
+        trace.step(
+            "formulas.slab_design.required_steel",
+            &[("fc", fc), ("fy", fy), ("d_req", d_req), ("mu", mu)],
+            as_req,
+            "mm²/m",
+        );
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if d_req > 300.0 {
-            warnings.push(format!("Thick slab ({:.0} mm). Consider two-way.", d_req));
+            warnings.push(SLAB_THICK.warn(format!("Thick slab ({:.0} mm). Consider two-way.", d_req)));
             recommendations.push("Check punching shear if supported on columns".to_string());
         }
 
@@ -176,8 +198,8 @@ impl EngineerCalculator for SlabDesignCalculator {
             calculation_type: "slab_design".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -186,6 +208,8 @@ impl EngineerCalculator for SlabDesignCalculator {
                 design_code_used: "ACI 318".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -0,0 +1,711 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{CRANE_BEARING_EXCEEDED, CRANE_CRITICAL_LIFT, CRANE_NO_CANDIDATE, CRANE_OVERSAIL},
+};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::helpers::interpolate;
+
+/// Standard gravity for converting tonnes to kN
+const G: f64 = 9.81;
+
+/// Unit weight of reinforced concrete (kN/m³)
+const CONCRETE_UNIT_WEIGHT: f64 = 24.0;
+
+/// Face width of a typical lattice mast section (m)
+const MAST_WIDTH: f64 = 2.0;
+
+/// Drag coefficient times solidity ratio for a lattice mast face
+const LATTICE_DRAG: f64 = 0.8;
+
+/// Chart utilisation above which a pick is planned as a critical lift
+const CRITICAL_LIFT_RATIO: f64 = 0.75;
+
+/// One tower crane model with its load chart
+#[derive(Debug, Clone, Deserialize)]
+struct Candidate {
+    name: String,
+    /// Maximum hook radius (m)
+    jib_length: f64,
+    /// Maximum capacity (t) and the radius it is available to (m)
+    max_capacity: f64,
+    max_capacity_radius: f64,
+    /// Capacity at the jib tip (t)
+    tip_capacity: f64,
+    /// Tail swing radius (m); 30% of the jib (25% for luffers) when omitted
+    #[serde(default)]
+    counter_jib: Option<f64>,
+    /// Mast, jib and machinery weight (kN)
+    self_weight: f64,
+    /// Counterweight on the counter-jib (kN)
+    counterweight: f64,
+    /// A luffing jib is raised out of service, so only the working radius oversails
+    #[serde(default)]
+    luffing: bool,
+    /// Manufacturer chart as [radius m, capacity t] points; replaces the
+    /// constant-moment interpolation when given
+    #[serde(default)]
+    chart: Vec<[f64; 2]>,
+}
+
+impl Candidate {
+    fn counter_jib(&self) -> f64 {
+        self.counter_jib.unwrap_or(self.jib_length * if self.luffing { 0.25 } else { 0.3 })
+    }
+
+    /// Chart capacity (t) at radius `r`, or None beyond the jib
+    fn capacity(&self, r: f64) -> Option<f64> {
+        if r > self.jib_length {
+            return None;
+        }
+        if !self.chart.is_empty() {
+            let (xs, ys): (Vec<f64>, Vec<f64>) = self.chart.iter().map(|p| (p[0], p[1])).unzip();
+            return Some(interpolate(r, &xs, &ys));
+        }
+        if r <= self.max_capacity_radius {
+            return Some(self.max_capacity);
+        }
+        // Load moment varies linearly from the maximum-capacity point to the tip
+        let m1 = self.max_capacity * self.max_capacity_radius;
+        let m2 = self.tip_capacity * self.jib_length;
+        let t = (r - self.max_capacity_radius) / (self.jib_length - self.max_capacity_radius);
+        Some((m1 + t * (m2 - m1)) / r)
+    }
+
+    /// Slewing radius that sweeps past the crane base
+    fn slewing_radius(&self, working_radius: f64) -> f64 {
+        let front = if self.luffing { working_radius } else { self.jib_length };
+        front.max(self.counter_jib())
+    }
+
+    /// Size index used to prefer the smallest crane that does the job (t·m)
+    fn size(&self) -> f64 {
+        self.max_capacity * self.jib_length
+    }
+}
+
+/// A crane in the generic fleet
+struct FleetCrane {
+    name: &'static str,
+    /// Jib length (m)
+    jib: f64,
+    /// Maximum capacity (t) and the radius it holds to (m)
+    max_capacity: f64,
+    max_capacity_radius: f64,
+    /// Capacity at the jib tip (t)
+    tip_capacity: f64,
+    /// Mast, jib and machinery weight and counterweight (kN)
+    self_weight: f64,
+    counterweight: f64,
+    luffing: bool,
+}
+
+/// Generic fleet evaluated when no candidates are supplied
+const DEFAULT_FLEET: [FleetCrane; 4] = [
+    FleetCrane {
+        name: "Flat-top 50 m / 6 t",
+        jib: 50.0,
+        max_capacity: 6.0,
+        max_capacity_radius: 15.0,
+        tip_capacity: 1.5,
+        self_weight: 450.0,
+        counterweight: 150.0,
+        luffing: false,
+    },
+    FleetCrane {
+        name: "Flat-top 60 m / 10 t",
+        jib: 60.0,
+        max_capacity: 10.0,
+        max_capacity_radius: 18.0,
+        tip_capacity: 2.3,
+        self_weight: 700.0,
+        counterweight: 250.0,
+        luffing: false,
+    },
+    FleetCrane {
+        name: "Hammerhead 70 m / 16 t",
+        jib: 70.0,
+        max_capacity: 16.0,
+        max_capacity_radius: 20.0,
+        tip_capacity: 3.0,
+        self_weight: 1000.0,
+        counterweight: 400.0,
+        luffing: false,
+    },
+    FleetCrane {
+        name: "Luffing 50 m / 20 t",
+        jib: 50.0,
+        max_capacity: 20.0,
+        max_capacity_radius: 15.0,
+        tip_capacity: 4.0,
+        self_weight: 1100.0,
+        counterweight: 350.0,
+        luffing: true,
+    },
+];
+
+fn default_fleet() -> Vec<Candidate> {
+    DEFAULT_FLEET
+        .iter()
+        .map(|crane| Candidate {
+            name: crane.name.to_string(),
+            jib_length: crane.jib,
+            max_capacity: crane.max_capacity,
+            max_capacity_radius: crane.max_capacity_radius,
+            tip_capacity: crane.tip_capacity,
+            counter_jib: None,
+            self_weight: crane.self_weight,
+            counterweight: crane.counterweight,
+            luffing: crane.luffing,
+            chart: Vec::new(),
+        })
+        .collect()
+}
+
+/// One lift in a pick zone, at a radius or plan position relative to the crane
+#[derive(Debug, Clone, Deserialize)]
+struct Pick {
+    zone: String,
+    /// Hook load including rigging (t)
+    load: f64,
+    #[serde(default)]
+    radius: Option<f64>,
+    #[serde(default)]
+    x: Option<f64>,
+    #[serde(default)]
+    y: Option<f64>,
+}
+
+/// Worst pick for a candidate: (utilisation, zone), or None when a pick is out of reach
+fn governing_pick(candidate: &Candidate, picks: &[(String, f64, f64)]) -> Option<(f64, String)> {
+    let mut worst = (0.0, String::new());
+    for (zone, load, radius) in picks {
+        let ratio = load / candidate.capacity(*radius)?;
+        if ratio > worst.0 {
+            worst = (ratio, zone.clone());
+        }
+    }
+    Some(worst)
+}
+
+pub struct TowerCraneCalculator;
+
+impl ParameterValidator for TowerCraneCalculator {
+    fn calculator_id(&self) -> &str {
+        "tower_crane"
+    }
+}
+
+impl TowerCraneCalculator {
+    fn extended_array<T: serde::de::DeserializeOwned>(&self, params: &EngineeringParameters, name: &str) -> EngineeringResult<Option<Vec<T>>> {
+        let Some(value) = params.extended_parameters.as_ref().and_then(|e| e.get(name)) else {
+            return Ok(None);
+        };
+        let invalid = |reason: String| EngineeringError::InvalidParameter {
+            parameter: name.to_string(),
+            value: format!("{:?}", value),
+            reason,
+        };
+        let items = value.as_array().ok_or_else(|| invalid("Expected an array".to_string()))?;
+        serde_json::from_value(serde_json::Value::Array(items.clone()))
+            .map(Some)
+            .map_err(|e| invalid(e.to_string()))
+    }
+
+    fn candidates(&self, params: &EngineeringParameters) -> EngineeringResult<Vec<Candidate>> {
+        let candidates = self.extended_array::<Candidate>(params, "candidates")?.unwrap_or_else(default_fleet);
+        if candidates.is_empty() {
+            return Err(EngineeringError::InvalidParameter {
+                parameter: "candidates".to_string(),
+                value: "[]".to_string(),
+                reason: "At least one candidate crane is required".to_string(),
+            });
+        }
+        for c in &candidates {
+            if c.jib_length <= c.max_capacity_radius || c.tip_capacity <= 0.0 || c.max_capacity < c.tip_capacity {
+                return Err(EngineeringError::InvalidParameter {
+                    parameter: "candidates".to_string(),
+                    value: c.name.clone(),
+                    reason: "Jib must extend past the maximum-capacity radius with 0 < tip capacity ≤ maximum capacity".to_string(),
+                });
+            }
+        }
+        Ok(candidates)
+    }
+
+    /// Picks as (zone, load t, radius m); a single design pick when none are given
+    fn picks(&self, params: &EngineeringParameters) -> EngineeringResult<Vec<(String, f64, f64)>> {
+        let crane_x = self.optional_param(params, "crane_x", 0.0, -1000.0, 1000.0)?;
+        let crane_y = self.optional_param(params, "crane_y", 0.0, -1000.0, 1000.0)?;
+        let Some(picks) = self.extended_array::<Pick>(params, "picks")? else {
+            let load = self.get_additional_param(params, "max_load", Some(0.1), Some(100.0))?;
+            let radius = self.get_additional_param(params, "max_radius", Some(1.0), Some(100.0))?;
+            return Ok(vec![("Design pick".to_string(), load, radius)]);
+        };
+        picks
+            .into_iter()
+            .map(|p| {
+                let radius = match (p.radius, p.x, p.y) {
+                    (Some(r), _, _) => r,
+                    (None, Some(x), Some(y)) => (x - crane_x).hypot(y - crane_y),
+                    _ => {
+                        return Err(EngineeringError::InvalidParameter {
+                            parameter: "picks".to_string(),
+                            value: p.zone.clone(),
+                            reason: "Each pick needs a radius or x and y coordinates".to_string(),
+                        })
+                    }
+                };
+                if p.load <= 0.0 {
+                    return Err(EngineeringError::InvalidParameter {
+                        parameter: "picks".to_string(),
+                        value: p.zone.clone(),
+                        reason: "Pick load must be positive".to_string(),
+                    });
+                }
+                Ok((p.zone, p.load, radius))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for TowerCraneCalculator {
+    fn id(&self) -> &str {
+        "tower_crane"
+    }
+
+    fn name(&self) -> &str {
+        "Tower Crane Selection"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Structural
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("tower_crane", "Tower Crane Selection")
+            .category("structural")
+            .description("Check tower crane candidates against the pick zones, select the smallest that covers every lift, and size its base reactions and footing bearing")
+            .design_code("ASME B30.3")
+            .parameter(ParameterMetadata {
+                name: "Candidate Cranes".to_string(),
+                path: "extended_parameters.candidates".to_string(),
+                data_type: ParameterType::Array,
+                unit: "".to_string(),
+                description: "Objects with name, jib_length, max_capacity, max_capacity_radius, tip_capacity, self_weight, counterweight and optional counter_jib, luffing and chart [[radius, t], ...]; a generic fleet when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec!["Tip capacity at most the maximum capacity".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Picks".to_string(),
+                path: "extended_parameters.picks".to_string(),
+                data_type: ParameterType::Array,
+                unit: "".to_string(),
+                description: "Objects with zone, load (t) and either radius (m) or x, y site coordinates".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Design Pick Load".to_string(),
+                path: "additional.max_load".to_string(),
+                data_type: ParameterType::Number,
+                unit: "t".to_string(),
+                description: "Heaviest hook load including rigging, used when no picks are listed".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.1),
+                max_value: Some(100.0),
+                typical_range: Some((1.0, 10.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Design Pick Radius".to_string(),
+                path: "additional.max_radius".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Radius of the design pick, used when no picks are listed".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(100.0),
+                typical_range: Some((15.0, 50.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Crane X".to_string(),
+                path: "additional.crane_x".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Crane position for picks given as coordinates; 0 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(-1000.0),
+                max_value: Some(1000.0),
+                typical_range: None,
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Crane Y".to_string(),
+                path: "additional.crane_y".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Crane position for picks given as coordinates; 0 when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(-1000.0),
+                max_value: Some(1000.0),
+                typical_range: None,
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Oversail Limit".to_string(),
+                path: "additional.oversail_limit".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Distance from the crane to the nearest boundary the jib or counter-jib may not cross".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(0.0),
+                max_value: Some(200.0),
+                typical_range: Some((10.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Mast Height".to_string(),
+                path: "additional.mast_height".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Freestanding height under the jib".to_string(),
+                required: true,
+                default_value: Some(40.0),
+                min_value: Some(10.0),
+                max_value: Some(100.0),
+                typical_range: Some((25.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Design Wind Pressure".to_string(),
+                path: "additional.wind_pressure".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kPa".to_string(),
+                description: "Wind pressure on the mast for the base moment, out of service".to_string(),
+                required: false,
+                default_value: Some(0.8),
+                min_value: Some(0.0),
+                max_value: Some(3.0),
+                typical_range: Some((0.4, 1.5)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Footing Width".to_string(),
+                path: "additional.footing_width".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Side of the square pad footing".to_string(),
+                required: false,
+                default_value: Some(6.0),
+                min_value: Some(2.0),
+                max_value: Some(20.0),
+                typical_range: Some((4.0, 10.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Footing Depth".to_string(),
+                path: "additional.footing_depth".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Pad footing thickness".to_string(),
+                required: false,
+                default_value: Some(1.5),
+                min_value: Some(0.5),
+                max_value: Some(5.0),
+                typical_range: Some((1.0, 2.5)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Allowable Bearing Pressure".to_string(),
+                path: "additional.allowable_bearing".to_string(),
+                data_type: ParameterType::Number,
+                unit: "kPa".to_string(),
+                description: "Allowable soil bearing pressure from the geotechnical report".to_string(),
+                required: false,
+                default_value: Some(200.0),
+                min_value: Some(25.0),
+                max_value: Some(2000.0),
+                typical_range: Some((100.0, 500.0)),
+                validation_rules: None,
+            })
+            .requires_pe()
+            .complexity(ComplexityLevel::Advanced)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.candidates(params)?;
+        self.picks(params)?;
+        self.get_additional_param(params, "mast_height", Some(10.0), Some(100.0))?;
+        self.optional_param(params, "oversail_limit", 0.0, 0.0, 200.0)?;
+        self.optional_param(params, "wind_pressure", 0.8, 0.0, 3.0)?;
+        self.optional_param(params, "footing_width", 6.0, 2.0, 20.0)?;
+        self.optional_param(params, "footing_depth", 1.5, 0.5, 5.0)?;
+        self.optional_param(params, "allowable_bearing", 200.0, 25.0, 2000.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let candidates = self.candidates(&params)?;
+        let picks = self.picks(&params)?;
+        let mast_height = self.get_additional_param(&params, "mast_height", None, None)?;
+        let oversail_limit = params.additional.as_ref().and_then(|a| a.get("oversail_limit").copied());
+        let wind_pressure = self.optional_param(&params, "wind_pressure", 0.8, 0.0, 3.0)?;
+        let footing_width = self.optional_param(&params, "footing_width", 6.0, 2.0, 20.0)?;
+        let footing_depth = self.optional_param(&params, "footing_depth", 1.5, 0.5, 5.0)?;
+        let allowable_bearing = self.optional_param(&params, "allowable_bearing", 200.0, 25.0, 2000.0)?;
+
+        let mut trace = CalculationTrace::new();
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        let mut compliance_notes = Vec::new();
+        let mut results = Vec::new();
+
+        let max_radius = picks.iter().map(|p| p.2).fold(0.0, f64::max);
+        let oversails = |c: &Candidate| oversail_limit.is_some_and(|limit| c.slewing_radius(max_radius) > limit);
+
+        // Rank cranes that cover every pick: no oversail first, then smallest
+        let mut feasible = Vec::new();
+        for candidate in &candidates {
+            match governing_pick(candidate, &picks) {
+                Some((ratio, zone)) => {
+                    results.push(
+                        EngineeringResultItem::new(format!("{} Utilization", candidate.name), ratio * 100.0, "%")
+                            .with_format(format!("{:.0}% at {}", ratio * 100.0, zone)),
+                    );
+                    if ratio <= 1.0 {
+                        feasible.push((candidate, ratio, zone));
+                    }
+                }
+                None => compliance_notes.push(format!(
+                    "{} cannot reach the {:.1} m pick radius (jib {:.0} m)",
+                    candidate.name, max_radius, candidate.jib_length
+                )),
+            }
+        }
+        feasible.sort_by(|a, b| oversails(a.0).cmp(&oversails(b.0)).then(a.0.size().total_cmp(&b.0.size())));
+
+        let (selected, utilization, zone) = match feasible.first() {
+            Some((c, ratio, zone)) => (*c, *ratio, zone.clone()),
+            None => {
+                // Report the closest candidate so the shortfall is visible
+                let closest = candidates
+                    .iter()
+                    .filter_map(|c| governing_pick(c, &picks).map(|(r, z)| (c, r, z)))
+                    .min_by(|a, b| a.1.total_cmp(&b.1));
+                let Some((c, ratio, zone)) = closest else {
+                    return Err(EngineeringError::DomainError {
+                        field: "picks".to_string(),
+                        message: format!("No candidate crane reaches the {:.1} m pick radius", max_radius),
+                    });
+                };
+                warnings.push(CRANE_NO_CANDIDATE.warn(format!(
+                    "Best candidate {} is at {:.0}% of chart capacity in {}",
+                    c.name,
+                    ratio * 100.0,
+                    zone
+                )));
+                recommendations.push("Consider a larger crane, a second crane, or relocating the crane closer to the heavy picks".to_string());
+                (c, ratio, zone)
+            }
+        };
+        trace.step(
+            "formulas.tower_crane.utilization",
+            &[("jib_length", selected.jib_length), ("max_radius", max_radius)],
+            utilization,
+            "dimensionless",
+        );
+
+        for (zone, load, radius) in &picks {
+            if let Some(capacity) = selected.capacity(*radius)
+                && load / capacity > CRITICAL_LIFT_RATIO
+                && load / capacity <= 1.0
+            {
+                warnings.push(CRANE_CRITICAL_LIFT.warn(format!(
+                    "{}: {:.1} t at {:.1} m is {:.0}% of the {:.1} t chart capacity",
+                    zone,
+                    load,
+                    radius,
+                    load / capacity * 100.0,
+                    capacity
+                )));
+            }
+        }
+        if let Some(limit) = oversail_limit.filter(|_| oversails(selected)) {
+            warnings.push(CRANE_OVERSAIL.warn(format!(
+                "{} sweeps {:.1} m against a {:.1} m limit",
+                selected.name,
+                selected.slewing_radius(max_radius),
+                limit
+            )));
+            recommendations.push("Fit a slewing limiter or obtain oversail consent from the adjoining owners".to_string());
+        }
+
+        // Base reactions: counterweight moment unloaded, net load moment loaded, plus wind on the mast
+        let load_moment = picks
+            .iter()
+            .filter(|p| selected.capacity(p.2).is_some())
+            .map(|(_, load, radius)| load * G * radius)
+            .fold(0.0, f64::max);
+        let backward_moment = selected.counterweight * selected.counter_jib();
+        let wind_force = trace.step(
+            "formulas.tower_crane.mast_wind_force",
+            &[("pressure", wind_pressure), ("height", mast_height)],
+            wind_pressure * LATTICE_DRAG * MAST_WIDTH * mast_height,
+            "kN",
+        );
+        let overturning = trace.step(
+            "formulas.tower_crane.base_moment",
+            &[("load_moment", load_moment), ("counterweight_moment", backward_moment), ("wind_force", wind_force)],
+            (load_moment - backward_moment).abs().max(backward_moment) + wind_force * mast_height / 2.0,
+            "kNm",
+        );
+        let max_pick = picks.iter().map(|p| p.1).fold(0.0, f64::max);
+        let vertical = trace.step(
+            "formulas.tower_crane.base_vertical",
+            &[("self_weight", selected.self_weight), ("counterweight", selected.counterweight), ("max_pick", max_pick)],
+            selected.self_weight + selected.counterweight + max_pick * G,
+            "kN",
+        );
+
+        // Square pad bearing under uniaxial moment; the linear formula is
+        // reported once e ≥ B/2 and the footing would lift off
+        let footing_weight = footing_width.powi(2) * footing_depth * CONCRETE_UNIT_WEIGHT;
+        let total_vertical = vertical + footing_weight;
+        let e = overturning / total_vertical;
+        let uplift = e >= footing_width / 2.0;
+        let bearing = trace.step(
+            "formulas.tower_crane.bearing_pressure",
+            &[("vertical", total_vertical), ("moment", overturning), ("width", footing_width)],
+            if e <= footing_width / 6.0 || uplift {
+                total_vertical / footing_width.powi(2) * (1.0 + 6.0 * e / footing_width)
+            } else {
+                2.0 * total_vertical / (3.0 * footing_width * (footing_width / 2.0 - e))
+            },
+            "kPa",
+        );
+        if uplift || bearing > allowable_bearing {
+            warnings.push(CRANE_BEARING_EXCEEDED.warn(if uplift {
+                format!("Eccentricity {:.2} m exceeds half the {:.1} m footing", e, footing_width)
+            } else {
+                format!("{:.0} kPa against {:.0} kPa allowable", bearing, allowable_bearing)
+            }));
+            recommendations.push("Enlarge the footing or use a piled base; confirm reactions with the manufacturer's data sheet".to_string());
+        }
+
+        compliance_notes.push(format!(
+            "Selected {} for a {:.1} m maximum pick radius; {} governs",
+            selected.name, max_radius, zone
+        ));
+        compliance_notes.push("Foundation design requires review by a licensed engineer using the manufacturer's base reactions".to_string());
+        compliance_notes.push("Bearing checked for moment about one axis; check diagonal slewing over the footing corner".to_string());
+        if candidates.iter().all(|c| c.chart.is_empty()) {
+            compliance_notes.push("Capacities interpolated at constant load-moment slope; verify against the manufacturer's load chart".to_string());
+        }
+
+        results.extend([
+            EngineeringResultItem::new("Selected Jib Length", selected.jib_length, "m")
+                .with_format(format!("{:.0} m ({})", selected.jib_length, selected.name)),
+            EngineeringResultItem::new("Governing Utilization", utilization * 100.0, "%")
+                .critical()
+                .with_format(format!("{:.0}% at {}", utilization * 100.0, zone)),
+            EngineeringResultItem::new("Base Vertical Reaction", vertical, "kN").with_format(format!("{:.0} kN", vertical)),
+            EngineeringResultItem::new("Base Overturning Moment", overturning, "kNm")
+                .critical()
+                .with_format(format!("{:.0} kNm", overturning)),
+            EngineeringResultItem::new("Base Shear", wind_force, "kN").with_format(format!("{:.1} kN", wind_force)),
+            EngineeringResultItem::new("Max Bearing Pressure", bearing, "kPa")
+                .critical()
+                .with_format(format!("{:.0} kPa", bearing)),
+        ]);
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "tower_crane".to_string(),
+            results,
+            analysis: Some(StructuralAnalysisResult {
+                max_moment: overturning,
+                max_shear: wind_force,
+                max_deflection: 0.0,
+                utilization_ratio: bearing / allowable_bearing,
+                governing_limit_state: "Footing bearing".to_string(),
+                stress_distribution: None,
+                zone_pressures: None,
+                story_forces: None,
+            }),
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "ASME B30.3".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_tower_crane_selects_smallest_covering_crane() {
+        let params = |oversail_limit: Option<f64>, far_radius: f64| -> EngineeringParameters {
+            let mut additional = serde_json::json!({ "mast_height": 40.0 });
+            if let Some(limit) = oversail_limit {
+                additional["oversail_limit"] = serde_json::json!(limit);
+            }
+            serde_json::from_value(serde_json::json!({
+                "dimensions": {},
+                "additional": additional,
+                "extended_parameters": { "picks": { "type": "Array", "value": [
+                    { "zone": "Core", "load": 5.0, "radius": 20.0 },
+                    { "zone": "Laydown", "load": 2.0, "x": 27.0, "y": -far_radius * 0.8 },
+                ] } },
+            }))
+            .unwrap()
+        };
+
+        // The 50 m / 6 t flat-top only has 4.4 t at 20 m; the 60 m / 10 t is the smallest that covers both picks
+        let open_site = execute("tower_crane", params(None, 45.0)).await.unwrap();
+        assert_eq!(value(&open_site, "Selected Jib Length"), 60.0);
+        assert!(value(&open_site, "Flat-top 50 m / 6 t Utilization") > 100.0);
+        // 2 t at 45 m against 153 t·m / 45 m = 3.4 t
+        assert!((value(&open_site, "Governing Utilization") - 2.0 / 3.4 * 100.0).abs() < 1e-6);
+        assert!(open_site.calculation_metadata.as_ref().unwrap().requires_pe_review);
+        assert!(value(&open_site, "Max Bearing Pressure") > 0.0);
+
+        // A 48 m boundary is only respected by the luffer, whose raised jib sweeps 45 m
+        let tight = execute("tower_crane", params(Some(48.0), 45.0)).await.unwrap();
+        assert!(tight.compliance_notes.iter().any(|n| n.starts_with("Selected Luffing 50 m")));
+        assert!(!warning_codes(&tight).contains(&"tower_crane.oversail".to_string()));
+        let tighter = execute("tower_crane", params(Some(40.0), 45.0)).await.unwrap();
+        assert!(warning_codes(&tighter).contains(&"tower_crane.oversail".to_string()));
+
+        assert!(execute("tower_crane", params(None, 90.0)).await.is_err());
+    }
+}
@@ -2,6 +2,7 @@ use crate::calculus::engineer::{
     errors::{EngineeringError, EngineeringResult},
     models::*,
     traits::{EngineerCalculator, ParameterValidator},
+    warnings::TRUSS_HIGH_MEMBER_FORCE,
 };
 use async_trait::async_trait;
 
@@ -105,15 +106,20 @@ impl EngineerCalculator for TrussAnalysisCalculator {
     }
 
     async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let mut trace = CalculationTrace::new();
+
         // Placeholder for truss analysis - in real impl, use matrix method
         let member_forces = vec![100.0, -150.0, 200.0]; // kN, example
+        for (i, &f) in member_forces.iter().enumerate() {
+            trace.step("formulas.truss_analysis.member_force", &[("member", (i + 1) as f64)], f, "kN");
+        }
 
         let mut warnings = Vec::new();
         let mut recommendations = Vec::new();
         let mut compliance_notes = Vec::new();
 
         if member_forces.iter().any(|&f| (f as f64).abs() > 500.0) {
-            warnings.push("High member forces. Verify section sizes".to_string());
+            warnings.push(TRUSS_HIGH_MEMBER_FORCE.warn("High member forces. Verify section sizes"));
             recommendations.push("Use AISC manual for member selection".to_string());
         }
 
@@ -129,8 +135,8 @@ impl EngineerCalculator for TrussAnalysisCalculator {
             calculation_type: "truss_analysis".to_string(),
             results,
             analysis: None,
-            warnings,
-            structured_warnings: None,
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
             recommendations,
             compliance_notes,
             calculation_metadata: Some(CalculationMetadata {
@@ -139,6 +145,8 @@ impl EngineerCalculator for TrussAnalysisCalculator {
                 design_code_used: "AISC 360".to_string(),
                 requires_pe_review: true,
             }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
         })
     }
 }
@@ -0,0 +1,457 @@
+use crate::calculus::engineer::{
+    errors::{EngineeringError, EngineeringResult},
+    models::*,
+    traits::{EngineerCalculator, ParameterValidator},
+    warnings::{WIND_FLEXIBLE_BUILDING, WIND_MINIMUM_LOAD_GOVERNS},
+};
+use async_trait::async_trait;
+
+use super::helpers::interpolate;
+
+/// Wind directionality factor for building MWFRS (ASCE 7-22 Table 26.6-1)
+const KD: f64 = 0.85;
+
+/// Gust effect factor for a rigid building (ASCE 7-22 26.11.1)
+const GUST_FACTOR: f64 = 0.85;
+
+/// Windward wall external pressure coefficient (ASCE 7-22 Figure 27.3-1)
+const CP_WINDWARD: f64 = 0.8;
+
+/// Side wall external pressure coefficient
+const CP_SIDE: f64 = -0.7;
+
+/// Height below which Kz is held constant (15 ft)
+const KZ_MIN_HEIGHT: f64 = 4.572;
+
+/// Minimum design wind load on the projected wall area, 16 psf (ASCE 7-22 27.1.5)
+const MIN_WIND_PRESSURE: f64 = 0.77;
+
+/// Height to least width ratio above which a building may be flexible
+const FLEXIBLE_SLENDERNESS: f64 = 4.0;
+
+/// Terrain exposure constants (ASCE 7-22 Table 26.11-1): α and zg in metres
+fn exposure_constants(exposure: &str) -> Option<(f64, f64)> {
+    match exposure.trim().to_ascii_uppercase().as_str() {
+        "B" => Some((7.5, 999.7)),
+        "C" => Some((9.8, 749.8)),
+        "D" => Some((11.5, 589.8)),
+        _ => None,
+    }
+}
+
+/// Velocity pressure exposure coefficient (ASCE 7-22 Table 26.10-1 note)
+fn kz(z: f64, alpha: f64, zg: f64) -> f64 {
+    2.41 * (z.clamp(KZ_MIN_HEIGHT, zg) / zg).powf(2.0 / alpha)
+}
+
+/// Leeward wall Cp from the plan ratio L/B
+fn cp_leeward(l_over_b: f64) -> f64 {
+    interpolate(l_over_b, &[1.0, 2.0, 4.0], &[-0.5, -0.3, -0.2])
+}
+
+/// Roof zones measured from the windward edge with their Cp, for wind normal
+/// to the ridge on a roof under 10°, interpolated between h/L = 0.5 and 1.0
+fn roof_zones(h: f64, depth: f64) -> Vec<(String, f64)> {
+    let ratio = h / depth;
+    let low = [-0.9, -0.9, -0.5, -0.3];
+    let high = [-1.3, -0.7, -0.7, -0.7];
+    let edges = [0.0, h / 2.0, h, 2.0 * h];
+    let names = ["Roof 0 to h/2", "Roof h/2 to h", "Roof h to 2h", "Roof beyond 2h"];
+    (0..4)
+        .filter(|&i| edges[i] < depth)
+        .map(|i| (names[i].to_string(), interpolate(ratio, &[0.5, 1.0], &[low[i], high[i]])))
+        .collect()
+}
+
+pub struct WindLoadCalculator;
+
+impl ParameterValidator for WindLoadCalculator {
+    fn calculator_id(&self) -> &str {
+        "wind_load"
+    }
+}
+
+impl WindLoadCalculator {
+    fn exposure(&self, params: &EngineeringParameters) -> EngineeringResult<(f64, f64)> {
+        let exposure = params.exposure_class.as_deref().unwrap_or("C");
+        exposure_constants(exposure).ok_or_else(|| EngineeringError::InvalidParameter {
+            parameter: "exposure_class".to_string(),
+            value: exposure.to_string(),
+            reason: "Exposure category must be B, C or D".to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl EngineerCalculator for WindLoadCalculator {
+    fn id(&self) -> &str {
+        "wind_load"
+    }
+
+    fn name(&self) -> &str {
+        "Wind Load (ASCE 7 Directional)"
+    }
+
+    fn category(&self) -> CalculatorCategory {
+        CalculatorCategory::Structural
+    }
+
+    fn metadata(&self) -> EngineeringCalculatorMetadata {
+        EngineeringCalculatorMetadata::builder("wind_load", "Wind Load (ASCE 7 Directional)")
+            .category("structural")
+            .description("Main wind force resisting system pressures per facade and roof zone for an enclosed rigid building, ASCE 7-22 directional procedure")
+            .design_code("ASCE 7")
+            .parameter(ParameterMetadata {
+                name: "Mean Roof Height".to_string(),
+                path: "dimensions.height".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Mean roof height h".to_string(),
+                required: true,
+                default_value: Some(10.0),
+                min_value: Some(3.0),
+                max_value: Some(150.0),
+                typical_range: Some((6.0, 40.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Building Width".to_string(),
+                path: "dimensions.width".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Horizontal dimension normal to the wind, B".to_string(),
+                required: true,
+                default_value: Some(30.0),
+                min_value: Some(3.0),
+                max_value: Some(300.0),
+                typical_range: Some((10.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Building Depth".to_string(),
+                path: "dimensions.length".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Horizontal dimension parallel to the wind, L".to_string(),
+                required: true,
+                default_value: Some(20.0),
+                min_value: Some(3.0),
+                max_value: Some(300.0),
+                typical_range: Some((10.0, 60.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Basic Wind Speed".to_string(),
+                path: "additional.wind_speed".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m/s".to_string(),
+                description: "3-second gust speed V at 10 m for the risk category, from the ASCE 7-22 maps".to_string(),
+                required: true,
+                default_value: Some(51.0),
+                min_value: Some(30.0),
+                max_value: Some(90.0),
+                typical_range: Some((40.0, 70.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Exposure Category".to_string(),
+                path: "exposure_class".to_string(),
+                data_type: ParameterType::Enum(vec!["B".to_string(), "C".to_string(), "D".to_string()]),
+                unit: "".to_string(),
+                description: "Surface roughness exposure B, C or D; C when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: None,
+                max_value: None,
+                typical_range: None,
+                validation_rules: Some(vec!["B, C or D".to_string()]),
+            })
+            .parameter(ParameterMetadata {
+                name: "Topographic Factor".to_string(),
+                path: "additional.topographic_factor".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "Kzt for hills and escarpments; 1.0 on flat terrain".to_string(),
+                required: false,
+                default_value: Some(1.0),
+                min_value: Some(1.0),
+                max_value: Some(3.0),
+                typical_range: Some((1.0, 1.5)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Ground Elevation".to_string(),
+                path: "additional.ground_elevation".to_string(),
+                data_type: ParameterType::Number,
+                unit: "m".to_string(),
+                description: "Site elevation above sea level for the ground elevation factor Ke".to_string(),
+                required: false,
+                default_value: Some(0.0),
+                min_value: Some(0.0),
+                max_value: Some(3000.0),
+                typical_range: Some((0.0, 1000.0)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Internal Pressure Coefficient".to_string(),
+                path: "additional.internal_pressure_coefficient".to_string(),
+                data_type: ParameterType::Number,
+                unit: "dimensionless".to_string(),
+                description: "GCpi magnitude: 0.18 enclosed, 0.55 partially enclosed, 0 open".to_string(),
+                required: false,
+                default_value: Some(0.18),
+                min_value: Some(0.0),
+                max_value: Some(0.55),
+                typical_range: Some((0.18, 0.55)),
+                validation_rules: None,
+            })
+            .parameter(ParameterMetadata {
+                name: "Number of Stories".to_string(),
+                path: "additional.num_stories".to_string(),
+                data_type: ParameterType::Integer,
+                unit: "".to_string(),
+                description: "Levels the windward pressure is reported at; one per 3.5 m when omitted".to_string(),
+                required: false,
+                default_value: None,
+                min_value: Some(1.0),
+                max_value: Some(60.0),
+                typical_range: Some((1.0, 12.0)),
+                validation_rules: None,
+            })
+            .complexity(ComplexityLevel::Advanced)
+            .build()
+    }
+
+    fn validate(&self, params: &EngineeringParameters) -> EngineeringResult<()> {
+        self.validate_dimension("height", params.dimensions.get("height").copied(), 3.0, 150.0)?;
+        self.validate_dimension("width", params.dimensions.get("width").copied(), 3.0, 300.0)?;
+        self.validate_dimension("length", params.dimensions.get("length").copied(), 3.0, 300.0)?;
+        self.get_additional_param(params, "wind_speed", Some(30.0), Some(90.0))?;
+        self.exposure(params)?;
+        self.optional_param(params, "topographic_factor", 1.0, 1.0, 3.0)?;
+        self.optional_param(params, "ground_elevation", 0.0, 0.0, 3000.0)?;
+        self.optional_param(params, "internal_pressure_coefficient", 0.18, 0.0, 0.55)?;
+        self.optional_param(params, "num_stories", 1.0, 1.0, 60.0)?;
+        Ok(())
+    }
+
+    async fn calculate(&self, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        let h = params.dimensions.get("height").copied().unwrap_or(10.0);
+        let width = params.dimensions.get("width").copied().unwrap_or(30.0);
+        let depth = params.dimensions.get("length").copied().unwrap_or(20.0);
+        let v = self.get_additional_param(&params, "wind_speed", None, None)?;
+        let (alpha, zg) = self.exposure(&params)?;
+        let kzt = self.optional_param(&params, "topographic_factor", 1.0, 1.0, 3.0)?;
+        let elevation = self.optional_param(&params, "ground_elevation", 0.0, 0.0, 3000.0)?;
+        let gcpi = self.optional_param(&params, "internal_pressure_coefficient", 0.18, 0.0, 0.55)?;
+        let stories = self
+            .optional_param(&params, "num_stories", (h / 3.5).ceil().max(1.0), 1.0, 60.0)?
+            .round();
+
+        let mut trace = CalculationTrace::new();
+
+        let ke = trace.step(
+            "formulas.wind_load.ground_elevation_factor",
+            &[("elevation", elevation)],
+            (-0.000119 * elevation).exp(),
+            "dimensionless",
+        );
+        // qz = 0.613·Kz·Kzt·Ke·V² in N/m² (ASCE 7-22 eq. 26.10-1), reported in kPa
+        let velocity_pressure = |z: f64| 0.613 * kz(z, alpha, zg) * kzt * ke * v.powi(2) / 1000.0;
+        let kh = trace.step(
+            "formulas.wind_load.exposure_coefficient",
+            &[("z", h), ("alpha", alpha), ("zg", zg)],
+            kz(h, alpha, zg),
+            "dimensionless",
+        );
+        let qh = trace.step(
+            "formulas.wind_load.velocity_pressure",
+            &[("kz", kh), ("kzt", kzt), ("ke", ke), ("v", v)],
+            velocity_pressure(h),
+            "kPa",
+        );
+
+        // p = q·Kd·G·Cp − qh·Kd·(±GCpi) (ASCE 7-22 eq. 27.3-1), internal sign chosen to govern
+        let internal = qh * KD * gcpi;
+        let net = |q: f64, cp: f64| q * KD * GUST_FACTOR * cp + internal.copysign(cp);
+
+        let mut zones = Vec::new();
+        let story_height = h / stories;
+        let levels: Vec<f64> = (1..=stories as usize).map(|i| story_height * i as f64).collect();
+        for &z in &levels {
+            zones.push(ZonePressure {
+                zone: "Windward Wall".to_string(),
+                elevation: Some(z),
+                external_coefficient: CP_WINDWARD,
+                pressure: net(velocity_pressure(z), CP_WINDWARD),
+            });
+        }
+        let cp_lee = cp_leeward(depth / width);
+        zones.push(ZonePressure {
+            zone: "Leeward Wall".to_string(),
+            elevation: None,
+            external_coefficient: cp_lee,
+            pressure: net(qh, cp_lee),
+        });
+        zones.push(ZonePressure {
+            zone: "Side Walls".to_string(),
+            elevation: None,
+            external_coefficient: CP_SIDE,
+            pressure: net(qh, CP_SIDE),
+        });
+        for (zone, cp) in roof_zones(h, depth) {
+            zones.push(ZonePressure { zone, elevation: None, external_coefficient: cp, pressure: net(qh, cp) });
+        }
+
+        // Internal pressure cancels across the building; walls carry external pressure only
+        let leeward_external = qh * KD * GUST_FACTOR * cp_lee.abs();
+        let mut base_shear = 0.0;
+        let mut overturning = 0.0;
+        for &z in &levels {
+            let band = story_height * width;
+            let force = (velocity_pressure(z) * KD * GUST_FACTOR * CP_WINDWARD + leeward_external) * band;
+            base_shear += force;
+            overturning += force * (z - story_height / 2.0);
+        }
+        let minimum_shear = MIN_WIND_PRESSURE * width * h;
+        let minimum_governs = minimum_shear > base_shear;
+        if minimum_governs {
+            overturning *= minimum_shear / base_shear;
+            base_shear = minimum_shear;
+        }
+        trace.step(
+            "formulas.wind_load.base_shear",
+            &[("qh", qh), ("width", width), ("height", h), ("cp_leeward", cp_lee)],
+            base_shear,
+            "kN",
+        );
+        trace.step(
+            "formulas.wind_load.overturning_moment",
+            &[("base_shear", base_shear), ("height", h)],
+            overturning,
+            "kNm",
+        );
+
+        let mut warnings = Vec::new();
+        let mut recommendations = Vec::new();
+        if h / width.min(depth) > FLEXIBLE_SLENDERNESS {
+            warnings.push(WIND_FLEXIBLE_BUILDING.warn(format!(
+                "h / least width = {:.1}; confirm the natural frequency is at least 1 Hz",
+                h / width.min(depth)
+            )));
+            recommendations.push("Compute Gf per ASCE 7-22 26.11.5 or use a wind tunnel study".to_string());
+        }
+        if minimum_governs {
+            warnings.push(WIND_MINIMUM_LOAD_GOVERNS.warn(format!(
+                "Minimum {:.2} kPa on {:.0} m² governs the base shear",
+                MIN_WIND_PRESSURE,
+                width * h
+            )));
+        }
+        recommendations.push("Check components and cladding pressures separately (ASCE 7-22 Chapter 30)".to_string());
+
+        let compliance_notes = vec![
+            format!("ASCE 7-22 Chapter 27 Part 1, Kd = {:.2}, G = {:.2}, GCpi = ±{:.2}", KD, GUST_FACTOR, gcpi),
+            "Roof coefficients for wind normal to the ridge on roofs under 10°; the -0.18 alternate applies where it governs".to_string(),
+            "Load cases 2 to 4 (ASCE 7-22 Figure 27.3-8) with partial and torsional loading are not included".to_string(),
+        ];
+
+        let windward_top = zones[levels.len() - 1].pressure;
+        let mut results = vec![
+            EngineeringResultItem::new("Velocity Pressure qh", qh, "kPa")
+                .critical()
+                .with_format(format!("{:.3} kPa", qh)),
+            EngineeringResultItem::new("Kz at Roof Height", kh, "dimensionless")
+                .with_format(format!("{:.3}", kh)),
+            EngineeringResultItem::new("Windward Wall at Roof Height", windward_top, "kPa")
+                .critical()
+                .with_format(format!("{:.3} kPa", windward_top)),
+        ];
+        for zone in zones.iter().filter(|z| z.elevation.is_none()) {
+            results.push(
+                EngineeringResultItem::new(zone.zone.clone(), zone.pressure, "kPa")
+                    .with_format(format!("{:.3} kPa (Cp {:.2})", zone.pressure, zone.external_coefficient)),
+            );
+        }
+        results.push(
+            EngineeringResultItem::new("Base Shear", base_shear, "kN")
+                .critical()
+                .with_format(format!("{:.1} kN", base_shear)),
+        );
+        results.push(
+            EngineeringResultItem::new("Overturning Moment", overturning, "kNm")
+                .with_format(format!("{:.1} kNm", overturning)),
+        );
+
+        Ok(EngineeringCalculationResponse {
+            calculation_type: "wind_load".to_string(),
+            results,
+            analysis: Some(StructuralAnalysisResult {
+                max_moment: overturning,
+                max_shear: base_shear,
+                max_deflection: 0.0,
+                // Loads only; member capacities are checked by the design calculators
+                utilization_ratio: 0.0,
+                governing_limit_state: if minimum_governs { "Minimum design wind load" } else { "MWFRS wind, load case 1" }.to_string(),
+                stress_distribution: None,
+                zone_pressures: Some(zones),
+                story_forces: None,
+            }),
+            warnings: EngineeringWarning::messages(&warnings),
+            structured_warnings: Some(warnings),
+            recommendations,
+            compliance_notes,
+            calculation_metadata: Some(CalculationMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                calculator_version: env!("CARGO_PKG_VERSION").to_string(),
+                design_code_used: "ASCE 7".to_string(),
+                requires_pe_review: true,
+            }),
+            calculation_trace: Some(trace.into_steps()),
+            data_quality: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::test_utils::*;
+
+    #[tokio::test]
+    async fn test_wind_load_zone_pressures() {
+        let params = |exposure: &str, height: f64| -> EngineeringParameters {
+            serde_json::from_value(serde_json::json!({
+                "dimensions": { "height": height, "width": 30.0, "length": 20.0 },
+                "additional": { "wind_speed": 51.0 },
+                "exposure_class": exposure,
+            }))
+            .unwrap()
+        };
+
+        // Exposure C at 10 m: Kz = 2.41 (10 / 749.8)^(2 / 9.8) ≈ 0.999, qh = 0.613 Kz V² ≈ 1.592 kPa
+        let c = execute("wind_load", params("C", 10.0)).await.unwrap();
+        assert!((value(&c, "Kz at Roof Height") - 0.9986).abs() < 1e-3);
+        assert!((value(&c, "Velocity Pressure qh") - 1.592).abs() < 5e-3);
+
+        let analysis = c.analysis.as_ref().unwrap();
+        let zones = analysis.zone_pressures.as_ref().unwrap();
+        let windward: Vec<_> = zones.iter().filter(|z| z.zone == "Windward Wall").collect();
+        assert_eq!(windward.len(), 3);
+        assert!(windward.windows(2).all(|w| w[1].pressure >= w[0].pressure));
+        // L/B < 1 puts the leeward wall at Cp = -0.5; h/L = 0.5 gives -0.9 at the windward roof edge
+        let leeward = zones.iter().find(|z| z.zone == "Leeward Wall").unwrap();
+        assert_eq!(leeward.external_coefficient, -0.5);
+        assert!(leeward.pressure < 0.0);
+        assert_eq!(zones.iter().find(|z| z.zone == "Roof 0 to h/2").unwrap().external_coefficient, -0.9);
+        assert!((analysis.max_shear - value(&c, "Base Shear")).abs() < 1e-9);
+
+        // Rougher terrain lowers the pressure; a tall narrow tower is flagged as possibly flexible
+        let b = execute("wind_load", params("B", 10.0)).await.unwrap();
+        assert!(value(&b, "Velocity Pressure qh") < value(&c, "Velocity Pressure qh"));
+        let tower = execute("wind_load", params("C", 100.0)).await.unwrap();
+        assert!(tower.warnings.iter().any(|w| w.contains("natural frequency")));
+
+        assert!(execute("wind_load", params("A", 10.0)).await.is_err());
+    }
+}
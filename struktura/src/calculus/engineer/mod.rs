@@ -2,7 +2,7 @@
 // Engineering Calculus Module
 // 
 // A comprehensive, trait-based calculator system for civil, structural,
-// mechanical, and production engineering calculations.
+// mechanical, production, hydraulic, and electrical engineering calculations.
 //
 // Architecture:
 // - errors.rs:    Surgical error handling with actionable feedback
@@ -10,6 +10,8 @@
 // - models.rs:    Data structures for inputs, outputs, and metadata
 // - registry.rs:  Thread-safe calculator registry
 // - router.rs:    Axum HTTP router with API endpoints
+// - warnings.rs:  Catalogue of stable warning codes
+// - versions.rs:  Upconverters for older request schema versions
 // - calculators/: Individual calculator implementations by discipline
 // ============================================================================
 
@@ -19,6 +21,8 @@ pub mod traits;
 pub mod models;
 pub mod registry;
 pub mod router;
+pub mod warnings;
+pub mod versions;
 
 // Calculator implementations organized by discipline
 pub mod calculators {
@@ -26,6 +30,8 @@ pub mod calculators {
     pub mod structural;
     pub mod mechanical;
     pub mod production;
+    pub mod hydraulic;
+    pub mod electrical;
 }
 
 // Re-export commonly used types for convenience
@@ -59,6 +65,8 @@ pub use models::{
     
     // Analysis results
     StructuralAnalysisResult,
+    ZonePressure,
+    StoryForce,
 };
 pub use registry::{EngineeringRegistry, RegistryBuilder, create_default_registry};
 pub use router::create_router;
@@ -80,7 +88,7 @@ pub struct ModuleInfo {
 pub const MODULE_INFO: ModuleInfo = ModuleInfo {
     version: VERSION,
     name: "Engineering Calculus",
-    description: "Comprehensive engineering calculation system with civil, structural, mechanical, and production calculators",
+    description: "Comprehensive engineering calculation system with civil, structural, mechanical, production, hydraulic, and electrical calculators",
 };
 
 // ============================================================================
@@ -91,6 +99,7 @@ pub const MODULE_INFO: ModuleInfo = ModuleInfo {
 pub mod test_utils {
     use super::*;
     use std::collections::HashMap;
+    use std::sync::OnceLock;
 
     /// Create minimal valid parameters for testing
     pub fn minimal_parameters() -> EngineeringParameters {
@@ -106,8 +115,8 @@ pub mod test_utils {
             calculation_date: None,
             extended_parameters: None,
             additional: None,
-            structured_data: None,
             project_metadata: None,
+            unit_system: Default::default(),
         }
     }
 
@@ -124,6 +133,23 @@ pub mod test_utils {
         }
     }
 
+    /// Validate and run a registered calculator, as the calculate endpoint does
+    pub async fn execute(calculator_id: &str, params: EngineeringParameters) -> EngineeringResult<EngineeringCalculationResponse> {
+        static REGISTRY: OnceLock<EngineeringRegistry> = OnceLock::new();
+        let registry = REGISTRY.get_or_init(create_default_registry);
+        registry.execute(&registry.find(calculator_id)?, params).await
+    }
+
+    /// Value of the result labelled `label`
+    pub fn value(response: &EngineeringCalculationResponse, label: &str) -> f64 {
+        response.results.iter().find(|i| i.label == label).unwrap().value
+    }
+
+    /// Codes of the response's structured warnings
+    pub fn warning_codes(response: &EngineeringCalculationResponse) -> Vec<String> {
+        response.structured_warnings.as_ref().unwrap().iter().map(|w| w.code.clone()).collect()
+    }
+
     /// Create parameters with loads
     pub fn parameters_with_loads(dead: f64, live: f64) -> EngineeringParameters {
         EngineeringParameters {
@@ -148,8 +174,8 @@ pub mod test_utils {
             calculation_date: None,
             extended_parameters: None,
             additional: None,
-            structured_data: None,
             project_metadata: None,
+            unit_system: Default::default(),
         }
     }
 }
@@ -185,4 +211,21 @@ mod tests {
         assert!(!catalogue.categories.is_empty());
         assert!(!catalogue.disclaimer.is_empty());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_catalogue_wizards_reference_declared_parameters() {
+        let catalogue = create_default_registry().catalogue();
+        let beam = catalogue.calculators.iter().find(|c| c.id == "beam_design").unwrap();
+        assert!(beam.wizard.is_some());
+
+        for calc in catalogue.calculators.iter() {
+            let Some(wizard) = &calc.wizard else { continue };
+            for path in wizard.parameter_paths() {
+                assert!(
+                    calc.parameters.iter().any(|p| p.path == path),
+                    "{} wizard references undeclared parameter {}", calc.id, path
+                );
+            }
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use crate::calculus::changelog::FormulaChange;
+use crate::calculus::examples::CalculatorExample;
+use crate::calculus::quality::DataQuality;
+use crate::utils::conversions::UnitSystem;
 use serde_json::Value as JsonValue;
 
 // ============================================================================
@@ -18,6 +22,7 @@ pub enum CalculatorCategory {
     Transportation,
     Hydraulic,
     Environmental,
+    Electrical,
 }
 
 impl CalculatorCategory {
@@ -31,6 +36,7 @@ impl CalculatorCategory {
             Self::Transportation => "transportation",
             Self::Hydraulic => "hydraulic",
             Self::Environmental => "environmental",
+            Self::Electrical => "electrical",
         }
     }
     
@@ -44,6 +50,7 @@ impl CalculatorCategory {
             Self::Transportation => "Transportation Engineering",
             Self::Hydraulic => "Hydraulic Engineering",
             Self::Environmental => "Environmental Engineering",
+            Self::Electrical => "Electrical Engineering",
         }
     }
 }
@@ -197,6 +204,10 @@ impl ParameterValue {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MaterialProperties {
     pub material_type: String,
+
+    /// Saved material library entry to fill unset values from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub material_id: Option<String>,
     
     // Strength properties (MPa)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -233,6 +244,7 @@ impl Default for MaterialProperties {
     fn default() -> Self {
         Self {
             material_type: "Steel".to_string(),
+            material_id: None,
             compressive_strength: None,
             tensile_strength: None,
             yield_strength: None,
@@ -352,7 +364,7 @@ pub struct EngineeringParameters {
     pub calculation_date: Option<String>,
     
     // ======================================================================
-    // NEW: Strongly-typed extended parameters (replaces structured_data)
+    // NEW: Strongly-typed extended parameters (replaced structured_data, API v2)
     // ======================================================================
     /// Extended parameters with full type support beyond simple f64 values.
     /// Supports: Number, Integer, String, Boolean, Array, Object, DateTime, etc.
@@ -376,14 +388,13 @@ pub struct EngineeringParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional: Option<HashMap<String, f64>>,
     
-    /// Complex JSON data (deprecated - use extended_parameters instead)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[deprecated(since = "0.2.0", note = "Use extended_parameters instead")]
-    pub structured_data: Option<HashMap<String, JsonValue>>,
-    
     /// Optional project metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project_metadata: Option<ProjectMetadata>,
+
+    /// Units the request is written in; results come back in the same system
+    #[serde(default, skip_serializing_if = "UnitSystem::is_metric")]
+    pub unit_system: UnitSystem,
 }
 
 /// Project metadata for tracking and documentation
@@ -417,7 +428,7 @@ pub struct EngineeringResultItem {
     /// Whether this result is critical for safety/design
     pub is_critical: bool,
     
-    /// Optional formatted display value
+    /// Display string, rounded by `utils::format` when served
     #[serde(skip_serializing_if = "Option::is_none")]
     pub formatted_value: Option<String>,
 }
@@ -465,6 +476,35 @@ pub struct StructuralAnalysisResult {
     
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stress_distribution: Option<Vec<StressPoint>>,
+
+    /// Design pressures per surface zone, for wind and other area loads
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone_pressures: Option<Vec<ZonePressure>>,
+
+    /// Lateral force and storey shear per level, from the roof down
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub story_forces: Option<Vec<StoryForce>>,
+}
+
+/// Net design pressure on one surface zone; positive acts toward the surface
+#[derive(Debug, Clone, Serialize)]
+pub struct ZonePressure {
+    pub zone: String,
+    /// Height of the point the pressure was evaluated at (m), for wall levels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elevation: Option<f64>,
+    pub external_coefficient: f64, // Cp
+    pub pressure: f64,             // kPa
+}
+
+/// Lateral force applied at one level and the storey shear below it
+#[derive(Debug, Clone, Serialize)]
+pub struct StoryForce {
+    pub level: u32,
+    pub elevation: f64, // m
+    pub weight: f64,    // kN
+    pub force: f64,     // kN
+    pub shear: f64,     // kN
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -484,9 +524,10 @@ pub enum WarningSeverity {
     Low,         // Informational, best practice
 }
 
-/// Structured warning with severity
+/// Structured warning with a stable code from the warning catalogue
 #[derive(Debug, Clone, Serialize)]
 pub struct EngineeringWarning {
+    pub code: String,
     pub severity: WarningSeverity,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -494,26 +535,71 @@ pub struct EngineeringWarning {
 }
 
 impl EngineeringWarning {
-    pub fn critical(message: impl Into<String>) -> Self {
-        Self {
-            severity: WarningSeverity::Critical,
-            message: message.into(),
-            affected_parameter: None,
-        }
-    }
-    
-    pub fn high(message: impl Into<String>) -> Self {
-        Self {
-            severity: WarningSeverity::High,
-            message: message.into(),
-            affected_parameter: None,
-        }
-    }
-    
     pub fn with_parameter(mut self, param: impl Into<String>) -> Self {
         self.affected_parameter = Some(param.into());
         self
     }
+
+    /// Flatten structured warnings into the legacy `warnings` field
+    pub fn messages(warnings: &[EngineeringWarning]) -> Vec<String> {
+        warnings.iter().map(|w| w.message.clone()).collect()
+    }
+}
+
+/// Single intermediate step of a calculation trace
+///
+/// Mirrors the OEE `TrackedMetric` convention: `formula_key` is a
+/// translation key ("formulas.beam_design.factored_load") and
+/// `formula_params` holds the values substituted into that formula.
+#[derive(Debug, Clone, Serialize)]
+pub struct CalculationStep {
+    pub step: usize,
+    pub formula_key: String,
+    pub formula_params: HashMap<String, f64>,
+    pub result: f64,
+    pub unit: String,
+}
+
+/// Ordered collection of intermediate steps recorded during a calculation
+#[derive(Debug, Clone, Default)]
+pub struct CalculationTrace {
+    steps: Vec<CalculationStep>,
+}
+
+impl CalculationTrace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a step and return its result so it can be used inline
+    pub fn step(
+        &mut self,
+        formula_key: impl Into<String>,
+        params: &[(&str, f64)],
+        result: f64,
+        unit: impl Into<String>,
+    ) -> f64 {
+        self.steps.push(CalculationStep {
+            step: self.steps.len() + 1,
+            formula_key: formula_key.into(),
+            formula_params: params.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            result,
+            unit: unit.into(),
+        });
+        result
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn into_steps(self) -> Vec<CalculationStep> {
+        self.steps
+    }
 }
 
 /// Engineering calculation response
@@ -537,6 +623,28 @@ pub struct EngineeringCalculationResponse {
     
     #[serde(skip_serializing_if = "Option::is_none")]
     pub calculation_metadata: Option<CalculationMetadata>,
+
+    /// Ordered intermediate steps, only returned when `trace=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calculation_trace: Option<Vec<CalculationStep>>,
+
+    /// Data-quality score, present when optional parameters fell back to defaults
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_quality: Option<DataQuality>,
+}
+
+impl EngineeringCalculationResponse {
+    /// Re-express metric results in the request's unit system, refreshing
+    /// their display strings
+    pub fn express_in(&mut self, system: UnitSystem) {
+        let items = self.results.iter_mut().map(|r| (&mut r.value, &mut r.unit));
+        if system.results_from_metric(items) > 0 {
+            let policy = crate::utils::format::policy();
+            for item in self.results.iter_mut() {
+                item.formatted_value = Some(policy.format(item.value, &item.unit));
+            }
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -609,6 +717,14 @@ pub struct EngineeringCalculatorMetadata {
     pub complexity_level: Option<ComplexityLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub calculation_time: Option<String>, // e.g., "< 1s", "1-5s"
+
+    /// Optional guided flow for complex calculators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wizard: Option<WizardDefinition>,
+
+    /// Worked requests with their results; see [`crate::calculus::examples`]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<CalculatorExample>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize)]
@@ -619,6 +735,127 @@ pub enum ComplexityLevel {
     Advanced,    // Complex algorithms, heavy computation
 }
 
+impl ComplexityLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Basic => "basic",
+            Self::Intermediate => "intermediate",
+            Self::Advanced => "advanced",
+        }
+    }
+}
+
+/// Ordered steps the SPA renders as a guided flow
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WizardDefinition {
+    pub steps: Vec<WizardStep>,
+}
+
+impl WizardDefinition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn step(mut self, step: WizardStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Every parameter path referenced by any step
+    pub fn parameter_paths(&self) -> impl Iterator<Item = &str> {
+        self.steps.iter().flat_map(|s| s.fields.iter().map(|f| f.path.as_str()))
+    }
+}
+
+/// One page of a wizard, grouping related parameters
+#[derive(Debug, Clone, Serialize)]
+pub struct WizardStep {
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub fields: Vec<WizardField>,
+    /// Step is skipped unless the rule holds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_when: Option<VisibilityRule>,
+}
+
+impl WizardStep {
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: None,
+            fields: Vec::new(),
+            visible_when: None,
+        }
+    }
+
+    pub fn description(mut self, desc: impl Into<String>) -> Self {
+        self.description = Some(desc.into());
+        self
+    }
+
+    pub fn field(mut self, path: impl Into<String>) -> Self {
+        self.fields.push(WizardField { path: path.into(), visible_when: None });
+        self
+    }
+
+    pub fn conditional_field(mut self, path: impl Into<String>, rule: VisibilityRule) -> Self {
+        self.fields.push(WizardField { path: path.into(), visible_when: Some(rule) });
+        self
+    }
+
+    pub fn visible_when(mut self, rule: VisibilityRule) -> Self {
+        self.visible_when = Some(rule);
+        self
+    }
+}
+
+/// Reference to a parameter path shown within a step
+#[derive(Debug, Clone, Serialize)]
+pub struct WizardField {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible_when: Option<VisibilityRule>,
+}
+
+/// Condition on another parameter's current value
+#[derive(Debug, Clone, Serialize)]
+pub struct VisibilityRule {
+    pub parameter: String,
+    pub condition: VisibilityCondition,
+}
+
+impl VisibilityRule {
+    pub fn new(parameter: impl Into<String>, condition: VisibilityCondition) -> Self {
+        Self { parameter: parameter.into(), condition }
+    }
+
+    /// Evaluate the rule against a value the user has entered (None when unset)
+    pub fn is_satisfied(&self, value: Option<&JsonValue>) -> bool {
+        match (&self.condition, value) {
+            (VisibilityCondition::Present, v) => v.is_some_and(|v| !v.is_null()),
+            (VisibilityCondition::Equals(expected), Some(v)) => v == expected,
+            (VisibilityCondition::NotEquals(expected), Some(v)) => v != expected,
+            (VisibilityCondition::NotEquals(_), None) => true,
+            (VisibilityCondition::GreaterThan(limit), Some(v)) => v.as_f64().is_some_and(|x| x > *limit),
+            (VisibilityCondition::LessThan(limit), Some(v)) => v.as_f64().is_some_and(|x| x < *limit),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VisibilityCondition {
+    Equals(JsonValue),
+    NotEquals(JsonValue),
+    GreaterThan(f64),
+    LessThan(f64),
+    Present,
+}
+
 /// Category information for API
 #[derive(Debug, Clone, Serialize)]
 pub struct EngineeringCategoryInfo {
@@ -639,6 +876,27 @@ pub struct EngineeringCalculatorCatalogue {
     
     #[serde(skip_serializing_if = "Option::is_none")]
     pub search_index: Option<SearchIndex>,
+
+    /// Formula changes per calculator id; unchanged calculators are omitted
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub changelog: BTreeMap<String, Vec<FormulaChange>>,
+
+    /// Calculator counts per facet value, for faceted browsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<CatalogueFacets>,
+}
+
+/// Calculator counts per facet value
+///
+/// Each facet is counted with every other active filter applied but not its
+/// own, so the counts show what picking another value would return.
+#[derive(Debug, Default, Serialize)]
+pub struct CatalogueFacets {
+    pub category: BTreeMap<String, usize>,
+    pub complexity_level: BTreeMap<String, usize>,
+    pub design_code: BTreeMap<String, usize>,
+    /// Keyed `true` / `false`
+    pub requires_pe_review: BTreeMap<String, usize>,
 }
 
 #[derive(Debug, Serialize)]
@@ -660,6 +918,17 @@ pub struct EngineeringCalculationRequest {
     /// Optional: Request specific output format
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_format: Option<OutputFormat>,
+
+    /// Optional: Return the ordered list of intermediate steps
+    #[serde(default)]
+    pub trace: bool,
+}
+
+impl EngineeringCalculationRequest {
+    /// Whether intermediate steps should be included in the response
+    pub fn wants_trace(&self) -> bool {
+        self.trace || matches!(self.output_format, Some(OutputFormat::Detailed))
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -691,6 +960,7 @@ pub struct MetadataBuilder {
     typical_applications: Vec<String>,
     requires_pe_review: bool,
     complexity_level: Option<ComplexityLevel>,
+    wizard: Option<WizardDefinition>,
 }
 
 impl MetadataBuilder {
@@ -705,6 +975,7 @@ impl MetadataBuilder {
             typical_applications: Vec::new(),
             requires_pe_review: false,
             complexity_level: None,
+            wizard: None,
         }
     }
     
@@ -737,6 +1008,11 @@ impl MetadataBuilder {
         self.complexity_level = Some(level);
         self
     }
+
+    pub fn wizard(mut self, wizard: WizardDefinition) -> Self {
+        self.wizard = Some(wizard);
+        self
+    }
     
     pub fn build(self) -> EngineeringCalculatorMetadata {
         let required_parameters: Vec<String> = self.parameters.iter()
@@ -762,6 +1038,8 @@ impl MetadataBuilder {
             requires_pe_review: self.requires_pe_review,
             complexity_level: self.complexity_level,
             calculation_time: None,
+            wizard: self.wizard,
+            examples: Vec::new(),
         }
     }
 }
@@ -800,4 +1078,41 @@ mod tests {
         let datetime = ParameterValue::DateTime("2025-12-21T10:00:00Z".to_string());
         assert_eq!(datetime.as_string(), Some("2025-12-21T10:00:00Z"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_calculation_trace_ordering() {
+        let mut trace = CalculationTrace::new();
+        let wu = trace.step("formulas.test.factored", &[("dead", 10.0), ("live", 5.0)], 20.0, "kN/m");
+        trace.step("formulas.test.moment", &[("wu", wu), ("span", 4.0)], wu * 16.0 / 8.0, "kNm");
+
+        assert_eq!(wu, 20.0);
+        let steps = trace.into_steps();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].step, 1);
+        assert_eq!(steps[1].step, 2);
+        assert_eq!(steps[1].formula_key, "formulas.test.moment");
+        assert_eq!(steps[1].formula_params.get("wu"), Some(&20.0));
+        assert_eq!(steps[1].result, 40.0);
+    }
+
+    #[test]
+    fn test_wizard_visibility_rules() {
+        let continuous = VisibilityRule::new(
+            "additional.support_condition",
+            VisibilityCondition::Equals(JsonValue::from("continuous")),
+        );
+        assert!(continuous.is_satisfied(Some(&JsonValue::from("continuous"))));
+        assert!(!continuous.is_satisfied(Some(&JsonValue::from("simple"))));
+        assert!(!continuous.is_satisfied(None));
+
+        let long_span = VisibilityRule::new("dimensions.length", VisibilityCondition::GreaterThan(12.0));
+        assert!(long_span.is_satisfied(Some(&JsonValue::from(15.0))));
+        assert!(!long_span.is_satisfied(Some(&JsonValue::from("15"))));
+
+        let present = VisibilityRule::new("economic_parameters.currency", VisibilityCondition::Present);
+        assert!(!present.is_satisfied(Some(&JsonValue::Null)));
+
+        let json = serde_json::to_value(&continuous).unwrap();
+        assert_eq!(json["condition"]["equals"], "continuous");
+    }
+}
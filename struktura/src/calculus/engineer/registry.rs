@@ -3,8 +3,16 @@ use crate::calculus::engineer::{
     models::*,
     traits::{CalculatorRegistry, EngineerCalculator},
 };
+use crate::calculus::changelog;
+use crate::calculus::examples;
+use crate::calculus::provenance::{self, AssumptionLedger, DeclaredParameter};
+use crate::calculus::quality;
+use crate::calculus::recommendation::Tier;
+use crate::calculus::sanitize;
+use crate::utils::format;
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::Instrument;
 
 /// Thread-safe calculator registry with tactical precision
 #[derive(Clone)]
@@ -55,6 +63,79 @@ impl EngineeringRegistry {
             .ok_or_else(|| EngineeringError::CalculatorNotFound(id.to_string()))
     }
 
+    /// Validate and run a calculation, then sanitize its results
+    ///
+    /// Non-finite result values fail the calculation; implausibly large ones
+    /// are served with a warning. Either is logged with the inputs. Every
+    /// result's `formatted_value` is rebuilt from the number format policy,
+    /// and a data-quality score is attached when optional parameters were
+    /// left to their defaults.
+    pub async fn execute(
+        &self,
+        calculator: &Arc<dyn EngineerCalculator>,
+        params: EngineeringParameters,
+    ) -> EngineeringResult<EngineeringCalculationResponse> {
+        self.execute_with_provenance(calculator, params).await.map(|(response, _)| response)
+    }
+
+    /// `execute`, also returning where each declared parameter came from
+    #[tracing::instrument(name = "calculator", skip_all, fields(tier = Tier::Engineer.as_str(), calculator = calculator.id()))]
+    pub async fn execute_with_provenance(
+        &self,
+        calculator: &Arc<dyn EngineerCalculator>,
+        params: EngineeringParameters,
+    ) -> EngineeringResult<(EngineeringCalculationResponse, AssumptionLedger)> {
+        tracing::info_span!("validate").in_scope(|| calculator.validate(&params))?;
+        let inputs = params.clone();
+        let mut response = calculator.calculate(params).instrument(tracing::info_span!("calculate")).await?;
+
+        let findings = sanitize::Findings::inspect(response.results.iter().map(|r| (r.label.as_str(), r.value)));
+        findings.log(Tier::Engineer, calculator.id(), &inputs);
+        if let Some(message) = findings.error() {
+            return Err(EngineeringError::NumericalError(message));
+        }
+        for message in findings.warnings() {
+            response
+                .structured_warnings
+                .get_or_insert_with(Vec::new)
+                .push(EngineeringWarning {
+                    code: sanitize::IMPLAUSIBLE_MAGNITUDE_CODE.to_string(),
+                    severity: WarningSeverity::High,
+                    message: message.clone(),
+                    affected_parameter: None,
+                });
+            response.warnings.push(message);
+        }
+
+        // Display strings follow the crate-wide rounding policy
+        let policy = format::policy();
+        for item in response.results.iter_mut() {
+            item.formatted_value = Some(policy.format(item.value, &item.unit));
+        }
+
+        // Results leaning on parameter defaults carry a data-quality score
+        let metadata = calculator.metadata();
+        let inputs = serde_json::to_value(&inputs).unwrap_or_default();
+        let defaulted = quality::defaulted_parameters(
+            metadata.parameters.iter().map(|p| (p.path.as_str(), !p.required && p.default_value.is_some())),
+            &inputs,
+        );
+        response.data_quality = quality::for_defaulted_parameters(&defaulted);
+
+        let ledger = provenance::parameter_ledger(
+            metadata.parameters.iter().map(|p| DeclaredParameter {
+                path: &p.path,
+                name: &p.name,
+                required: p.required,
+                default_value: p.default_value,
+            }),
+            &inputs,
+            &inputs,
+        );
+
+        Ok((response, ledger))
+    }
+
     /// Get all calculators (for catalogue generation)
     pub fn all(&self) -> Vec<Arc<dyn EngineerCalculator>> {
         self.calculators.values().cloned().collect()
@@ -110,7 +191,7 @@ impl EngineeringRegistry {
             EngineeringCategoryInfo {
                 id: "mechanical".to_string(),
                 name: "Mechanical Engineering".to_string(),
-                description: "Thermodynamics, fluid mechanics, HVAC, pump systems".to_string(),
+                description: "Thermodynamics, fluid mechanics, HVAC, pump systems, elevator traffic, fire sprinklers".to_string(),
                 requires_pe: false,
                 icon: Some("⚙️".to_string()),
             },
@@ -121,12 +202,30 @@ impl EngineeringRegistry {
                 requires_pe: false,
                 icon: Some("🏭".to_string()),
             },
+            EngineeringCategoryInfo {
+                id: "hydraulic".to_string(),
+                name: "Hydraulic Engineering".to_string(),
+                description: "Storm drainage runoff, culverts, and gravity sewers".to_string(),
+                requires_pe: true,
+                icon: Some("🌊".to_string()),
+            },
+            EngineeringCategoryInfo {
+                id: "electrical".to_string(),
+                name: "Electrical Engineering".to_string(),
+                description: "Grounding, lightning protection, and power systems".to_string(),
+                requires_pe: true,
+                icon: Some("⚡".to_string()),
+            },
         ];
 
         let calculators: Vec<EngineeringCalculatorMetadata> = self
             .all()
             .iter()
-            .map(|calc| calc.metadata())
+            .map(|calc| {
+                let mut metadata = calc.metadata();
+                metadata.examples = examples::for_calculator(Tier::Engineer, calc.id());
+                metadata
+            })
             .collect();
 
         // Build search index
@@ -171,6 +270,8 @@ impl EngineeringRegistry {
                         Professional Engineer before construction or implementation."
                 .to_string(),
             search_index: Some(SearchIndex { tags, keywords }),
+            changelog: changelog::for_tier(Tier::Engineer),
+            facets: None,
         }
     }
 
@@ -269,6 +370,8 @@ pub fn create_default_registry() -> EngineeringRegistry {
         .with_calculator(Arc::new(calculators::civil::SlopeStabilityCalculator))
         .with_calculator(Arc::new(calculators::civil::SettlementAnalysisCalculator))
         .with_calculator(Arc::new(calculators::civil::SoilBearingCapacityCalculator))
+        .with_calculator(Arc::new(calculators::civil::SoilCompactionCalculator))
+        .with_calculator(Arc::new(calculators::civil::PileFoundationCalculator))
         
         // ========================================================================
         // STRUCTURAL ENGINEERING (7 calculators) - All require PE review
@@ -280,6 +383,9 @@ pub fn create_default_registry() -> EngineeringRegistry {
         .with_calculator(Arc::new(calculators::structural::ConnectionDesignCalculator))
         .with_calculator(Arc::new(calculators::structural::SlabDesignCalculator))
         .with_calculator(Arc::new(calculators::structural::LateralLoadAnalysisCalculator))
+        .with_calculator(Arc::new(calculators::structural::WindLoadCalculator))
+        .with_calculator(Arc::new(calculators::structural::SeismicLoadCalculator))
+        .with_calculator(Arc::new(calculators::structural::TowerCraneCalculator))
         
         // ========================================================================
         // MECHANICAL ENGINEERING (8 calculators) - No PE review required
@@ -292,6 +398,10 @@ pub fn create_default_registry() -> EngineeringRegistry {
         .with_calculator(Arc::new(calculators::mechanical::CompressorSizingCalculator))
         .with_calculator(Arc::new(calculators::mechanical::ValveSizingCalculator))
         .with_calculator(Arc::new(calculators::mechanical::ThermalExpansionCalculator))
+        .with_calculator(Arc::new(calculators::mechanical::CondensationRiskCalculator))
+        .with_calculator(Arc::new(calculators::mechanical::WaterHammerCalculator))
+        .with_calculator(Arc::new(calculators::mechanical::ElevatorTrafficCalculator))
+        .with_calculator(Arc::new(calculators::mechanical::SprinklerHydraulicCalculator))
         
         // ========================================================================
         // PRODUCTION ENGINEERING (8 calculators) - No PE review required
@@ -305,6 +415,19 @@ pub fn create_default_registry() -> EngineeringRegistry {
         .with_calculator(Arc::new(calculators::production::WorkSamplingCalculator))
         .with_calculator(Arc::new(calculators::production::FacilityLayoutCalculator))
         
+        // ========================================================================
+        // HYDRAULIC ENGINEERING (3 calculators) - All require PE review
+        // ========================================================================
+        .with_calculator(Arc::new(calculators::hydraulic::RationalMethodRunoffCalculator))
+        .with_calculator(Arc::new(calculators::hydraulic::CulvertSizingCalculator))
+        .with_calculator(Arc::new(calculators::hydraulic::StormSewerSizingCalculator))
+        
+        // ========================================================================
+        // ELECTRICAL ENGINEERING (2 calculators) - All require PE review
+        // ========================================================================
+        .with_calculator(Arc::new(calculators::electrical::GroundingLightningProtectionCalculator))
+        .with_calculator(Arc::new(calculators::electrical::GeneratorUpsCalculator))
+        
         .build()
 }
 
@@ -344,10 +467,12 @@ mod tests {
                 parameters: vec![],
                 required_parameters: vec![],
                 optional_parameters: vec![],
+                examples: Vec::new(),
                 typical_applications: vec![],
                 requires_pe_review: false,
                 complexity_level: None,
                 calculation_time: None,
+                wizard: None,
             }
         }
 
@@ -368,6 +493,8 @@ mod tests {
                 recommendations: vec![],
                 compliance_notes: vec![],
                 calculation_metadata: None,
+                calculation_trace: None,
+                data_quality: None,
             })
         }
     }
@@ -444,4 +571,39 @@ mod tests {
         
         assert_eq!(registry.all().len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_execute_scores_defaulted_parameters() {
+        let registry = create_default_registry();
+        let calculator = registry.find("column_design").unwrap();
+        let params: EngineeringParameters = serde_json::from_value(serde_json::json!({
+            "dimensions": { "height": 4.0 },
+            "loads": { "dead_load": 500.0, "live_load": 300.0, "load_combination": "LRFD" },
+            "additional": { "k_factor": 1.0 },
+        }))
+        .unwrap();
+
+        let response = registry.execute(&calculator, params).await.unwrap();
+        let quality = response.data_quality.expect("yield strength was defaulted");
+        assert_eq!(quality.score, 100 - quality::DEFAULT_PARAMETER_POINTS);
+        assert_eq!(quality.factors[0].subject.as_deref(), Some("material.yield_strength"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_reports_parameter_provenance() {
+        let registry = create_default_registry();
+        let calculator = registry.find("column_design").unwrap();
+        let params: EngineeringParameters = serde_json::from_value(serde_json::json!({
+            "dimensions": { "height": 4.0 },
+            "loads": { "dead_load": 500.0, "live_load": 300.0, "load_combination": "LRFD" },
+            "additional": { "k_factor": 1.0 },
+        }))
+        .unwrap();
+
+        let (_, ledger) = registry.execute_with_provenance(&calculator, params).await.unwrap();
+        let source = |key: &str| ledger.assumptions.iter().find(|a| a.assumption_key == key).map(|a| a.source.as_str());
+        assert_eq!(source("dimensions.height"), Some("explicit"));
+        assert_eq!(source("material.yield_strength"), Some("default"));
+        assert_eq!(ledger.source_statistics.default_count, 1);
+    }
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use crate::calculus::engineer::{
-    errors::EngineeringError,
+    errors::{EngineeringError, ErrorResponse},
     models::*,
     registry::EngineeringRegistry,
+    warnings::{self, WarningCode},
 };
 use crate::calculus::engineer::calculators::production::oee;
 use axum::{
@@ -13,7 +14,20 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use tokio::task::JoinSet;
+use uuid::Uuid;
+use crate::billing;
+use crate::calculus::audience::Audience;
+use crate::calculus::changelog;
+use crate::defaults::{self, WithAssumptions};
+use crate::calculus::examples;
+use crate::calculus::links::{self, Hal};
+use crate::calculus::recommendation::Tier;
+use crate::flags::FlagContext;
+use crate::sec::Claims;
 use crate::state::AppState;
+use crate::tenancy::{self, TenantScope};
+use crate::utils::negotiate::{Negotiated, ResponseFormat};
 
 /// Application state containing the calculator registry
 #[derive(Clone)]
@@ -28,16 +42,135 @@ impl EngineeringState {
 }
 
 /// Query parameters for catalogue endpoint
-#[derive(Debug, Deserialize)]
+///
+/// List filters take comma-separated values and match any of them.
+#[derive(Debug, Default, Deserialize)]
 pub struct CatalogueQuery {
     /// Filter by category
     category: Option<String>,
     /// Search query
     q: Option<String>,
-    /// Only show calculators requiring PE review
+    /// `true` for calculators requiring PE review, `false` for the rest
+    #[serde(alias = "requires_pe_review")]
+    pe_required: Option<bool>,
+    /// Filter by complexity: `basic`, `intermediate`, `advanced`
+    complexity_level: Option<String>,
+    /// Filter by design code, ignoring case, spaces and punctuation
+    /// (`aci318` matches "ACI 318")
+    design_code: Option<String>,
+}
+
+/// Catalogue facets a filter can constrain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Facet {
+    Category,
+    Complexity,
+    DesignCode,
+    PeReview,
+}
+
+fn list(values: &Option<String>) -> Vec<String> {
+    values
+        .iter()
+        .flat_map(|v| v.split(','))
+        .map(|v| v.trim().to_lowercase())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+/// "ACI 318-19" -> "aci31819"
+fn code_key(code: &str) -> String {
+    code.chars().filter(char::is_ascii_alphanumeric).map(|c| c.to_ascii_lowercase()).collect()
+}
+
+/// The facet filters of a catalogue query
+struct CatalogueFilter {
+    categories: Vec<String>,
+    complexity: Vec<String>,
+    design_codes: Vec<String>,
     pe_required: Option<bool>,
 }
 
+impl CatalogueFilter {
+    fn from_query(query: &CatalogueQuery) -> Self {
+        Self {
+            categories: list(&query.category),
+            complexity: list(&query.complexity_level),
+            design_codes: list(&query.design_code).iter().map(|c| code_key(c)).collect(),
+            pe_required: query.pe_required,
+        }
+    }
+
+    /// Whether the calculator passes every filter except `skip`'s
+    fn matches(&self, calc: &EngineeringCalculatorMetadata, skip: Option<Facet>) -> bool {
+        let check = |facet| skip != Some(facet);
+
+        (!check(Facet::Category) || self.categories.is_empty() || self.categories.contains(&calc.category.to_lowercase()))
+            && (!check(Facet::Complexity)
+                || self.complexity.is_empty()
+                || calc.complexity_level.is_some_and(|c| self.complexity.iter().any(|f| f == c.as_str())))
+            && (!check(Facet::DesignCode)
+                || self.design_codes.is_empty()
+                || calc.design_codes.iter().any(|code| self.design_codes.contains(&code_key(code))))
+            && (!check(Facet::PeReview) || self.pe_required.is_none_or(|pe| calc.requires_pe_review == pe))
+    }
+
+    /// Counts per facet value, each facet ignoring its own filter
+    fn facets(&self, calculators: &[EngineeringCalculatorMetadata]) -> CatalogueFacets {
+        let mut facets = CatalogueFacets::default();
+        for calc in calculators {
+            if self.matches(calc, Some(Facet::Category)) {
+                *facets.category.entry(calc.category.clone()).or_default() += 1;
+            }
+            if self.matches(calc, Some(Facet::Complexity))
+                && let Some(level) = calc.complexity_level
+            {
+                *facets.complexity_level.entry(level.as_str().to_string()).or_default() += 1;
+            }
+            if self.matches(calc, Some(Facet::DesignCode)) {
+                for code in &calc.design_codes {
+                    *facets.design_code.entry(code.clone()).or_default() += 1;
+                }
+            }
+            if self.matches(calc, Some(Facet::PeReview)) {
+                *facets.requires_pe_review.entry(calc.requires_pe_review.to_string()).or_default() += 1;
+            }
+        }
+        facets
+    }
+}
+
+/// Largest number of requests accepted by the batch endpoint
+pub const MAX_BATCH_SIZE: usize = 100;
+
+/// Query parameters for calculate endpoint
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CalculateQuery {
+    /// Include ordered intermediate steps in the response
+    trace: Option<bool>,
+    /// Shape the response for `operator`, `manager` or `engineer` (default)
+    audience: Option<Audience>,
+}
+
+impl CalculateQuery {
+    /// Drop the trace unless it was asked for and trim the response for its
+    /// audience
+    pub fn shape(&self, response: &mut EngineeringCalculationResponse, wants_trace: bool) {
+        // Calculators always record their steps; only expose them on request
+        if !(self.trace.unwrap_or(false) || wants_trace) {
+            response.calculation_trace = None;
+        }
+        self.audience.unwrap_or_default().shape_engineering(response);
+    }
+}
+
+/// Query parameters for warning catalogue endpoint
+#[derive(Debug, Deserialize)]
+pub struct WarningCatalogueQuery {
+    /// Filter by calculator ID
+    calculator: Option<String>,
+}
+
 /// Health check response
 #[derive(Serialize)]
 pub struct HealthResponse {
@@ -54,56 +187,272 @@ pub struct HealthResponse {
 /// Execute an engineering calculation
 async fn calculate_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Query(query): Query<CalculateQuery>,
+    format: ResponseFormat,
     Json(payload): Json<EngineeringCalculationRequest>,
-) -> Result<Json<EngineeringCalculationResponse>, EngineeringError> {
+) -> Result<Negotiated<Hal<WithAssumptions<EngineeringCalculationResponse>>>, EngineeringError> {
+    let calculation_type = payload.calculation_type.clone();
+    let response = run_calculation(&state, claims.as_ref(), &query, payload).await?;
+
+    let related = related_calculators(&state, claims.as_ref(), &calculation_type).await;
+    let links = links::calculation(Tier::Engineer, &calculation_type, related);
+    Ok(Negotiated(format, Hal::new(response, links)))
+}
+
+/// Run one calculation request the way the calculate endpoint does
+async fn run_calculation(
+    state: &AppState,
+    claims: Option<&Claims>,
+    query: &CalculateQuery,
+    mut payload: EngineeringCalculationRequest,
+) -> Result<WithAssumptions<EngineeringCalculationResponse>, EngineeringError> {
     // Find calculator in registry
     let calculator = state.calculators_engineer.find(&payload.calculation_type)?;
 
-    // Validate parameters
-    calculator.validate(&payload.parameters)?;
+    // Calculators behind a rollout flag look missing to callers outside it
+    let ctx = FlagContext::for_caller(&state.tenants, claims);
+    if !state.flags.calculator_enabled(&state.pool, Tier::Engineer, calculator.id(), &ctx).await {
+        return Err(EngineeringError::CalculatorNotFound(payload.calculation_type));
+    }
+
+    // Imperial requests run in the metric units calculators work in
+    let system = payload.parameters.unit_system;
+    let declared = calculator.metadata().parameters;
+    payload.parameters = system
+        .parameters_to_metric(payload.parameters, declared.iter().map(|p| (p.path.as_str(), p.unit.as_str())))
+        .map_err(|e| EngineeringError::InvalidParameter {
+            parameter: "unit_system".to_string(),
+            value: system.as_str().to_string(),
+            reason: e.to_string(),
+        })?;
+
+    // Fill unset material values from the caller's saved library
+    if let Some(material) = payload.parameters.material.as_mut()
+        && let Some(id) = material.material_id.clone()
+    {
+        crate::materials::lookup(state, claims, &id).await?
+            .apply_to_engineering(material);
+    }
+
+    let wants_trace = payload.wants_trace();
+
+    // Merge the caller's default assumptions under what the request supplied
+    let assumptions = defaults::for_caller(state, claims)
+        .await
+        .apply_to_engineering(&mut payload.parameters);
+
+    // Validate, execute and sanitize the results
+    let (mut response, provenance) = state.calculators_engineer.execute_with_provenance(&calculator, payload.parameters).await?;
+
+    // Saved results carry the formula version so later changes can be surfaced
+    if let Some(metadata) = response.calculation_metadata.as_mut() {
+        metadata.calculator_version = changelog::formula_version(Tier::Engineer, calculator.id()).to_string();
+    }
+
+    query.shape(&mut response, wants_trace);
+    response.express_in(system);
+
+    Ok(WithAssumptions::new(response, assumptions, provenance))
+}
+
+/// Outcome of one request in a batch
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum BatchItem {
+    Ok {
+        index: usize,
+        result: Box<WithAssumptions<EngineeringCalculationResponse>>,
+    },
+    Error {
+        index: usize,
+        /// HTTP status the request would have received on its own
+        http_status: u16,
+        error: ErrorResponse,
+    },
+}
+
+/// Batch results, in request order
+#[derive(Serialize)]
+pub struct BatchResponse {
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BatchItem>,
+}
+
+impl BatchItem {
+    fn from_result(index: usize, result: Result<WithAssumptions<EngineeringCalculationResponse>, EngineeringError>) -> Self {
+        match result {
+            Ok(result) => Self::Ok { index, result: Box::new(result) },
+            Err(e) => {
+                let (status, error) = e.to_response();
+                Self::Error { index, http_status: status.as_u16(), error }
+            }
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Self::Ok { index, .. } | Self::Error { index, .. } => *index,
+        }
+    }
+}
+
+fn check_batch_size(size: usize) -> Result<(), EngineeringError> {
+    if size == 0 || size > MAX_BATCH_SIZE {
+        return Err(EngineeringError::InvalidParameter {
+            parameter: "requests".to_string(),
+            value: size.to_string(),
+            reason: format!("a batch must hold between 1 and {} requests", MAX_BATCH_SIZE),
+        });
+    }
+    Ok(())
+}
+
+/// Run every request through `run` concurrently and collect the outcomes
+/// in request order
+async fn run_batch<F, Fut>(payload: Vec<EngineeringCalculationRequest>, run: F) -> Result<BatchResponse, EngineeringError>
+where
+    F: Fn(EngineeringCalculationRequest) -> Fut,
+    Fut: Future<Output = Result<WithAssumptions<EngineeringCalculationResponse>, EngineeringError>> + Send + 'static,
+{
+    let total = payload.len();
+    let mut tasks = JoinSet::new();
+    for (index, request) in payload.into_iter().enumerate() {
+        let calculation = run(request);
+        tasks.spawn(async move { BatchItem::from_result(index, calculation.await) });
+    }
+
+    let mut results = Vec::with_capacity(total);
+    while let Some(joined) = tasks.join_next().await {
+        let item = joined.map_err(|e| EngineeringError::CalculationError(format!("batch task failed: {}", e)))?;
+        results.push(item);
+    }
+    results.sort_by_key(BatchItem::index);
+
+    let succeeded = results.iter().filter(|item| matches!(item, BatchItem::Ok { .. })).count();
+    Ok(BatchResponse { total, succeeded, failed: total - succeeded, results })
+}
+
+impl IntoResponse for BatchResponse {
+    fn into_response(self) -> Response {
+        // Each successful item counts towards the caller's usage
+        let succeeded = self.succeeded;
+        let mut response = Json(self).into_response();
+        response.extensions_mut().insert(billing::CalculationCount(succeeded));
+        response
+    }
+}
+
+/// POST /api/v1/calculus/engineer/batch
+/// Execute up to `MAX_BATCH_SIZE` calculations concurrently; one failing
+/// request does not fail the others
+async fn batch_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    scope: Option<TenantScope>,
+    Query(query): Query<CalculateQuery>,
+    Json(payload): Json<Vec<EngineeringCalculationRequest>>,
+) -> Result<Response, EngineeringError> {
+    check_batch_size(payload.len())?;
 
-    // Execute calculation
-    let response = calculator.calculate(payload.parameters).await?;
+    // The entitlement middleware only knows one request arrived; the whole
+    // batch has to fit in what is left of the caller's quotas
+    if !state.sandbox {
+        if let Some(scope) = &scope
+            && let Err(response) = tenancy::check_monthly_quota(&state.pool, scope, payload.len()).await
+        {
+            return Ok(response);
+        }
+        let user_id = claims.as_ref().and_then(|c| Uuid::parse_str(&c.sub).ok());
+        if let Err(response) = billing::check_plan(&state, user_id, Tier::Engineer, payload.len()).await {
+            return Ok(response);
+        }
+    }
 
-    Ok(Json(response))
+    let batch = run_batch(payload, |request| {
+        let state = state.clone();
+        let claims = claims.clone();
+        let query = query.clone();
+        async move { run_calculation(&state, claims.as_ref(), &query, request).await }
+    })
+    .await?;
+    Ok(batch.into_response())
 }
 
 /// GET /api/v1/calculus/engineer/catalogue
 /// Get complete calculator catalogue with optional filtering
 async fn catalogue_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Query(query): Query<CatalogueQuery>,
-) -> Json<EngineeringCalculatorCatalogue> {
+) -> Json<Hal<EngineeringCalculatorCatalogue>> {
     let mut catalogue = state.calculators_engineer.catalogue();
 
-    // Apply filters if specified
-    if let Some(category_filter) = query.category {
-        catalogue.calculators.retain(|calc| calc.category == category_filter);
-    }
-
-    if let Some(true) = query.pe_required {
-        catalogue.calculators.retain(|calc| calc.requires_pe_review);
-    }
+    // Hide calculators the caller is not rolled out to
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Engineer, &ctx).await;
+    catalogue.calculators.retain(|calc| !hidden.contains(&calc.id));
 
     // Apply search if specified
-    if let Some(search_query) = query.q {
-        let search_results = state.calculators_engineer.search(&search_query);
+    if let Some(search_query) = &query.q {
+        let search_results = state.calculators_engineer.search(search_query);
         let result_ids: Vec<String> = search_results.iter().map(|c| c.id().to_string()).collect();
         
         catalogue.calculators.retain(|calc| result_ids.contains(&calc.id));
     }
 
-    Json(catalogue)
+    // Count facets over the searchable set, then apply the filters
+    let filter = CatalogueFilter::from_query(&query);
+    catalogue.facets = Some(filter.facets(&catalogue.calculators));
+    catalogue.calculators.retain(|calc| filter.matches(calc, None));
+
+    let listed = catalogue.calculators.iter().map(|c| (c.id.clone(), c.name.clone()));
+    let links = links::catalogue(Tier::Engineer, listed);
+    Json(Hal::new(catalogue, links))
 }
 
 /// GET /api/v1/calculus/engineer/catalogue/:id
 /// Get metadata for specific calculator
 async fn calculator_metadata_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Path(id): Path<String>,
-) -> Result<Json<EngineeringCalculatorMetadata>, EngineeringError> {
+) -> Result<Json<Hal<EngineeringCalculatorMetadata>>, EngineeringError> {
     let calculator = state.calculators_engineer.find(&id)?;
-    Ok(Json(calculator.metadata()))
+
+    // Calculators behind a rollout flag look missing to callers outside it
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    if !state.flags.calculator_enabled(&state.pool, Tier::Engineer, calculator.id(), &ctx).await {
+        return Err(EngineeringError::CalculatorNotFound(id));
+    }
+
+    let related = related_calculators(&state, claims.as_ref(), calculator.id()).await;
+    let links = links::calculator(Tier::Engineer, calculator.id(), related);
+    let mut metadata = calculator.metadata();
+    metadata.examples = examples::for_calculator(Tier::Engineer, calculator.id());
+    Ok(Json(Hal::new(metadata, links)))
+}
+
+/// Calculators sharing a category with `calculator_id` that the caller can
+/// see, as (id, name) pairs for `related` links
+async fn related_calculators(state: &AppState, claims: Option<&Claims>, calculator_id: &str) -> Vec<(String, String)> {
+    let Ok(calculator) = state.calculators_engineer.find(calculator_id) else {
+        return Vec::new();
+    };
+    let ctx = FlagContext::for_caller(&state.tenants, claims);
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Engineer, &ctx).await;
+
+    let mut related: Vec<(String, String)> = state
+        .calculators_engineer
+        .by_category(calculator.category())
+        .into_iter()
+        .filter(|c| c.id() != calculator_id && !hidden.contains(c.id()))
+        .map(|c| (c.id().to_string(), c.name().to_string()))
+        .collect();
+    related.sort();
+    related
 }
 
 /// GET /api/v1/calculus/engineer/categories
@@ -119,6 +468,7 @@ async fn categories_handler(
 /// Get calculators in specific category
 async fn category_calculators_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Path(category_str): Path<String>,
 ) -> Result<Json<Vec<EngineeringCalculatorMetadata>>, EngineeringError> {
     // Parse category
@@ -131,6 +481,7 @@ async fn category_calculators_handler(
         "transportation" => CalculatorCategory::Transportation,
         "hydraulic" => CalculatorCategory::Hydraulic,
         "environmental" => CalculatorCategory::Environmental,
+        "electrical" => CalculatorCategory::Electrical,
         _ => {
             return Err(EngineeringError::InvalidParameter {
                 parameter: "category".to_string(),
@@ -140,9 +491,14 @@ async fn category_calculators_handler(
         }
     };
 
+    // Hide calculators the caller is not rolled out to
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Engineer, &ctx).await;
+
     let calculators = state.calculators_engineer.by_category(category);
     let metadata: Vec<EngineeringCalculatorMetadata> = calculators
         .iter()
+        .filter(|calc| !hidden.contains(calc.id()))
         .map(|calc| calc.metadata())
         .collect();
 
@@ -158,11 +514,17 @@ struct SearchQuery {
 
 async fn search_handler(
     State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
     Query(query): Query<SearchQuery>,
 ) -> Json<Vec<EngineeringCalculatorMetadata>> {
+    // Hide calculators the caller is not rolled out to
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Engineer, &ctx).await;
+
     let results = state.calculators_engineer.search(&query.q);
     let metadata: Vec<EngineeringCalculatorMetadata> = results
         .iter()
+        .filter(|calc| !hidden.contains(calc.id()))
         .map(|calc| calc.metadata())
         .collect();
 
@@ -206,6 +568,20 @@ async fn stats_handler(
     })
 }
 
+/// GET /api/v1/calculus/engineer/warnings/catalogue
+/// List every warning code calculators can emit
+async fn warning_catalogue_handler(
+    Query(query): Query<WarningCatalogueQuery>,
+) -> Json<Vec<WarningCode>> {
+    let codes = warnings::WARNING_CATALOGUE
+        .iter()
+        .filter(|w| query.calculator.as_deref().is_none_or(|id| w.calculator == id))
+        .copied()
+        .collect();
+
+    Json(codes)
+}
+
 // ============================================================================
 // ROUTER CONSTRUCTION
 // ============================================================================
@@ -217,17 +593,21 @@ pub fn create_router() -> Router<Arc<AppState>> {
     Router::new()
         // Main calculation endpoint
         .route("/calculate", post(calculate_handler))
+        .route("/batch", post(batch_handler))
         
         // Catalogue and discovery endpoints
         .route("/catalogue", get(catalogue_handler))
-        .route("/catalogue/meta", get(calculator_metadata_handler))
+        .route("/catalogue/{id}", get(calculator_metadata_handler))
         
         // Category endpoints
         .route("/categories", get(categories_handler))
-        .route("/categories/handler", get(category_calculators_handler))
+        .route("/categories/{category}", get(category_calculators_handler))
         
         // Search endpoint
         .route("/search", get(search_handler))
+
+        // Warning catalogue
+        .route("/warnings/catalogue", get(warning_catalogue_handler))
         
         // System endpoints
         .route("/health", get(health_handler))
@@ -241,3 +621,109 @@ pub fn create_router() -> Router<Arc<AppState>> {
 pub fn create_default_router() -> Router<Arc<AppState>> {
     create_router()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::registry::create_default_registry;
+
+    fn query(category: Option<&str>, complexity: Option<&str>, design_code: Option<&str>, pe: Option<bool>) -> CatalogueQuery {
+        CatalogueQuery {
+            category: category.map(String::from),
+            complexity_level: complexity.map(String::from),
+            design_code: design_code.map(String::from),
+            pe_required: pe,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_catalogue_filters() {
+        let calculators = create_default_registry().catalogue().calculators;
+
+        let filter = CatalogueFilter::from_query(&query(Some("structural"), None, Some("aisc360"), None));
+        let matched: Vec<_> = calculators.iter().filter(|c| filter.matches(c, None)).collect();
+        assert!(!matched.is_empty());
+        assert!(matched.iter().all(|c| c.category == "structural" && c.design_codes.iter().any(|d| d == "AISC 360")));
+
+        let filter = CatalogueFilter::from_query(&query(None, Some("basic,advanced"), None, Some(false)));
+        assert!(calculators
+            .iter()
+            .filter(|c| filter.matches(c, None))
+            .all(|c| !c.requires_pe_review && !matches!(c.complexity_level, Some(ComplexityLevel::Intermediate) | None)));
+    }
+
+    #[test]
+    fn test_facets_ignore_their_own_filter() {
+        let calculators = create_default_registry().catalogue().calculators;
+        let filter = CatalogueFilter::from_query(&query(Some("structural"), None, None, None));
+        let facets = filter.facets(&calculators);
+
+        // Every category is still counted while one is selected
+        let total: usize = facets.category.values().sum();
+        assert_eq!(total, calculators.len());
+
+        // Other facets only count structural calculators
+        let structural = calculators.iter().filter(|c| c.category == "structural").count();
+        let pe: usize = facets.requires_pe_review.values().sum();
+        assert_eq!(pe, structural);
+    }
+
+    /// The beam design worked example, sent to `calculation_type`
+    fn batch_request(calculation_type: &str) -> EngineeringCalculationRequest {
+        let mut request = examples::for_calculator(Tier::Engineer, "beam_design")[0].request.clone();
+        request["calculation_type"] = calculation_type.into();
+        serde_json::from_value(request).expect("example is a valid request")
+    }
+
+    async fn run_batch_locally(payload: Vec<EngineeringCalculationRequest>) -> BatchResponse {
+        let registry = Arc::new(create_default_registry());
+        let started = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let size = payload.len();
+        run_batch(payload, |request| {
+            let registry = registry.clone();
+            // Earlier requests finish last, so results arrive out of order
+            let delay = size - started.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async move {
+                for _ in 0..delay {
+                    tokio::task::yield_now().await;
+                }
+                let calculator = registry.find(&request.calculation_type)?;
+                let (response, provenance) = registry.execute_with_provenance(&calculator, request.parameters).await?;
+                Ok(WithAssumptions::new(response, Vec::new(), provenance))
+            }
+        })
+        .await
+        .expect("no batch task panics")
+    }
+
+    #[test]
+    fn test_batch_size_limit() {
+        assert!(check_batch_size(0).is_err());
+        assert!(check_batch_size(1).is_ok());
+        assert!(check_batch_size(MAX_BATCH_SIZE).is_ok());
+        assert!(check_batch_size(MAX_BATCH_SIZE + 1).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_batch_keeps_request_order_and_isolates_failures() {
+        let payload = vec![batch_request("beam_design"), batch_request("no_such_calculator"), batch_request("beam_design")];
+        let batch = run_batch_locally(payload).await;
+
+        assert_eq!((batch.total, batch.succeeded, batch.failed), (3, 2, 1));
+        assert_eq!(batch.results.iter().map(BatchItem::index).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert!(matches!(batch.results[0], BatchItem::Ok { .. }));
+        assert!(matches!(batch.results[1], BatchItem::Error { http_status: 404, .. }));
+        assert!(matches!(batch.results[2], BatchItem::Ok { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_batch_meters_only_successful_items() {
+        let payload = vec![batch_request("beam_design"), batch_request("no_such_calculator")];
+        let response = run_batch_locally(payload).await.into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let count = response.extensions().get::<billing::CalculationCount>().expect("batch responses are metered");
+        assert_eq!(count.0, 1);
+    }
+}
@@ -137,6 +137,36 @@ pub trait ParameterValidator {
         
         Ok(value)
     }
+
+    /// Get an optional additional parameter, range-checked only when supplied
+    fn optional_param(
+        &self,
+        params: &EngineeringParameters,
+        name: &str,
+        default: f64,
+        min: f64,
+        max: f64,
+    ) -> EngineeringResult<f64> {
+        match self.get_additional_param(params, name, Some(min), Some(max)) {
+            Err(EngineeringError::MissingParameter { .. }) => Ok(default),
+            other => other,
+        }
+    }
+
+    /// Get an optional dimension, range-checked only when supplied
+    fn optional_dimension(
+        &self,
+        params: &EngineeringParameters,
+        name: &str,
+        default: f64,
+        min: f64,
+        max: f64,
+    ) -> EngineeringResult<f64> {
+        match params.dimensions.get(name) {
+            Some(&value) => self.validate_dimension(name, Some(value), min, max),
+            None => Ok(default),
+        }
+    }
     
     /// Get calculator ID for error messages
     fn calculator_id(&self) -> &str;
@@ -0,0 +1,119 @@
+//! Request schema changes on the engineering endpoints
+//!
+//! Each entry upconverts a payload from the previous version; see
+//! [`crate::utils::versioning`] for how clients pick a version.
+//!
+//! ## Version 2
+//!
+//! `parameters.structured_data` (deprecated since 0.2.0) is gone. Version 1
+//! payloads have its entries moved into `parameters.extended_parameters` as
+//! typed values, without overwriting entries already there.
+
+use serde_json::{Map, Value};
+
+use crate::utils::versioning::{Adapter, ApiVersion};
+
+pub const ADAPTERS: &[Adapter] = &[
+    Adapter { path: "/calculate", since: ApiVersion::V2, upgrade: calculate_v2 },
+    Adapter { path: "/batch", since: ApiVersion::V2, upgrade: batch_v2 },
+];
+
+/// Typed form of an untyped `structured_data` entry; `None` for nulls
+fn typed(value: Value) -> Option<Value> {
+    let kind = match &value {
+        Value::Null => return None,
+        Value::Bool(_) => "Boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "Integer",
+        Value::Number(_) => "Number",
+        Value::String(_) => "String",
+        Value::Array(_) => "Array",
+        Value::Object(_) => "Object",
+    };
+    Some(serde_json::json!({ "type": kind, "value": value }))
+}
+
+/// `structured_data` → `extended_parameters`
+fn calculate_v2(request: &mut Value) -> Result<(), String> {
+    let Some(parameters) = request.get_mut("parameters").and_then(Value::as_object_mut) else {
+        return Ok(());
+    };
+    let Some(structured) = parameters.remove("structured_data") else {
+        return Ok(());
+    };
+    let structured = match structured {
+        Value::Null => return Ok(()),
+        Value::Object(entries) => entries,
+        _ => return Err("parameters.structured_data must be an object".to_string()),
+    };
+
+    let extended = parameters
+        .entry("extended_parameters")
+        .or_insert_with(|| Value::Object(Map::new()));
+    if extended.is_null() {
+        *extended = Value::Object(Map::new());
+    }
+    let Some(extended) = extended.as_object_mut() else {
+        return Err("parameters.extended_parameters must be an object".to_string());
+    };
+
+    for (key, value) in structured {
+        if extended.contains_key(&key) {
+            continue;
+        }
+        if let Some(value) = typed(value) {
+            extended.insert(key, value);
+        }
+    }
+    Ok(())
+}
+
+fn batch_v2(requests: &mut Value) -> Result<(), String> {
+    let Some(requests) = requests.as_array_mut() else {
+        return Ok(());
+    };
+    for (index, request) in requests.iter_mut().enumerate() {
+        calculate_v2(request).map_err(|e| format!("request {}: {}", index, e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::models::{EngineeringCalculationRequest, ParameterValue};
+    use crate::utils::versioning::upgrade;
+    use serde_json::json;
+
+    #[test]
+    fn test_structured_data_moves_to_extended_parameters() {
+        let mut body = json!({
+            "calculation_type": "oee",
+            "parameters": {
+                "dimensions": {},
+                "structured_data": { "shifts": 3, "line": "A", "downtime": [12.5, 4.0], "note": null },
+                "extended_parameters": { "line": { "type": "String", "value": "B" } }
+            }
+        });
+
+        let upgraded = upgrade(ADAPTERS, "/api/v1/calculus/engineer/calculate", ApiVersion::V1, &mut body);
+        assert_eq!(upgraded, Ok(true));
+
+        let request: EngineeringCalculationRequest = serde_json::from_value(body).unwrap();
+        let extended = request.parameters.extended_parameters.unwrap();
+        assert!(matches!(extended["shifts"], ParameterValue::Integer(3)));
+        assert!(matches!(&extended["line"], ParameterValue::String(line) if line == "B"));
+        assert!(matches!(extended["downtime"], ParameterValue::Array(_)));
+        assert!(!extended.contains_key("note"));
+    }
+
+    #[test]
+    fn test_batch_upgrades_every_request() {
+        let mut body = json!([
+            { "calculation_type": "a", "parameters": { "dimensions": {} } },
+            { "calculation_type": "b", "parameters": { "dimensions": {}, "structured_data": "oops" } }
+        ]);
+
+        let error = upgrade(ADAPTERS, "/api/v1/calculus/engineer/batch", ApiVersion::V1, &mut body).unwrap_err();
+        assert!(error.starts_with("request 1"));
+    }
+}
@@ -0,0 +1,626 @@
+// ============================================================================
+// Engineering Warning Catalogue
+//
+// Every warning an engineering calculator can raise is declared here with a
+// stable code, default severity and the parameter it relates to. Frontends
+// use the code (e.g. "beam_design.excessive_deflection") to filter and
+// translate warnings instead of matching on free-text messages.
+// ============================================================================
+
+use crate::calculus::engineer::models::{EngineeringWarning, WarningSeverity};
+use serde::Serialize;
+
+/// Catalogue entry describing one warning a calculator may emit
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct WarningCode {
+    pub code: &'static str,
+    pub calculator: &'static str,
+    pub severity: WarningSeverity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affected_parameter: Option<&'static str>,
+    pub description: &'static str,
+}
+
+impl WarningCode {
+    /// Build a warning for this code with a context-specific message
+    pub fn warn(&self, message: impl Into<String>) -> EngineeringWarning {
+        EngineeringWarning {
+            code: self.code.to_string(),
+            severity: self.severity,
+            message: message.into(),
+            affected_parameter: self.affected_parameter.map(str::to_string),
+        }
+    }
+}
+
+const fn code(
+    code: &'static str,
+    calculator: &'static str,
+    severity: WarningSeverity,
+    affected_parameter: Option<&'static str>,
+    description: &'static str,
+) -> WarningCode {
+    WarningCode { code, calculator, severity, affected_parameter, description }
+}
+
+use WarningSeverity::{Critical, High, Low, Medium};
+
+// ============================================================================
+// STRUCTURAL
+// ============================================================================
+
+pub const BEAM_EXCESSIVE_DEFLECTION: WarningCode = code(
+    "beam_design.excessive_deflection", "beam_design", High, Some("dimensions.length"),
+    "Live load deflection exceeds the L/360 serviceability limit",
+);
+pub const BEAM_HIGH_SHEAR: WarningCode = code(
+    "beam_design.high_shear", "beam_design", Medium, Some("loads"),
+    "Maximum shear is high enough to govern web thickness",
+);
+pub const BEAM_OVER_REINFORCED: WarningCode = code(
+    "beam_design.over_reinforced", "beam_design", High, Some("dimensions.depth"),
+    "Tension steel exceeds the singly reinforced limit of the concrete section",
+);
+pub const BEAM_SHEAR_REINFORCEMENT: WarningCode = code(
+    "beam_design.shear_reinforcement", "beam_design", Medium, Some("loads"),
+    "Factored shear exceeds the concrete shear capacity; stirrups must be designed",
+);
+pub const BEAM_SHALLOW_SECTION: WarningCode = code(
+    "beam_design.shallow_section", "beam_design", Medium, Some("dimensions.depth"),
+    "Concrete section is shallower than the span/depth limit for deflection",
+);
+pub const BEAM_BARS_DO_NOT_FIT: WarningCode = code(
+    "beam_design.bars_do_not_fit", "beam_design", High, Some("dimensions.width"),
+    "Tension bars do not fit in one layer within the minimum and crack-control spacing limits",
+);
+pub const BEAM_SHEAR_SECTION_INADEQUATE: WarningCode = code(
+    "beam_design.shear_section_inadequate", "beam_design", Critical, Some("dimensions.width"),
+    "Factored shear exceeds the maximum the web can resist with stirrups",
+);
+pub const COLUMN_K_FACTOR_ASSUMED: WarningCode = code(
+    "column_design.k_factor_assumed", "column_design", Low, Some("additional.k_factor"),
+    "Effective length factor above 1.0 should be confirmed with alignment charts",
+);
+pub const COLUMN_LARGE_SECTION: WarningCode = code(
+    "column_design.large_section", "column_design", Medium, Some("loads"),
+    "Required area exceeds typical rolled sections",
+);
+pub const COLUMN_SLENDER: WarningCode = code(
+    "column_design.slender", "column_design", Medium, Some("dimensions.height"),
+    "Slenderness exceeds the code limit; second-order effects must be considered",
+);
+pub const COLUMN_REINFORCEMENT_RATIO: WarningCode = code(
+    "column_design.reinforcement_ratio", "column_design", Medium, Some("additional.reinforcement_ratio"),
+    "Longitudinal reinforcement ratio is outside the code limits",
+);
+pub const TRUSS_HIGH_MEMBER_FORCE: WarningCode = code(
+    "truss_analysis.high_member_force", "truss_analysis", Medium, None,
+    "At least one member force exceeds 500 kN",
+);
+pub const FRAME_EXCESSIVE_DRIFT: WarningCode = code(
+    "moment_frame_design.excessive_drift", "moment_frame_design", High, Some("dimensions.height"),
+    "Estimated story drift exceeds H/400",
+);
+pub const CONNECTION_COMBINED_TENSION: WarningCode = code(
+    "connection_design.combined_tension", "connection_design", Medium, Some("loads.tension_load"),
+    "Tension is present and combined shear/tension interaction must be checked",
+);
+pub const CONNECTION_MANY_BOLTS: WarningCode = code(
+    "connection_design.many_bolts", "connection_design", Low, Some("dimensions.diameter"),
+    "More than eight bolts are required for shear",
+);
+pub const CONNECTION_BOLT_GROUP_OVERSTRESSED: WarningCode = code(
+    "connection_design.bolt_group_overstressed", "connection_design", High, Some("additional.eccentricity"),
+    "The eccentric bolt group capacity is below the design shear",
+);
+pub const SLAB_THICK: WarningCode = code(
+    "slab_design.thick_slab", "slab_design", Medium, Some("dimensions.length"),
+    "Required effective depth exceeds 300 mm",
+);
+pub const LATERAL_HIGH_LOAD: WarningCode = code(
+    "lateral_load_analysis.high_lateral_load", "lateral_load_analysis", High, Some("loads.wind_load"),
+    "Total lateral force exceeds 5000 kN",
+);
+pub const WIND_FLEXIBLE_BUILDING: WarningCode = code(
+    "wind_load.flexible_building", "wind_load", High, Some("dimensions.height"),
+    "Slender building may be flexible; the rigid gust effect factor G = 0.85 may be unconservative",
+);
+pub const WIND_MINIMUM_LOAD_GOVERNS: WarningCode = code(
+    "wind_load.minimum_load_governs", "wind_load", Low, Some("additional.wind_speed"),
+    "The 0.77 kPa minimum design wind load governs the base shear",
+);
+pub const SEISMIC_ELF_NOT_PERMITTED: WarningCode = code(
+    "seismic_load.elf_not_permitted", "seismic_load", Critical, Some("dimensions.height"),
+    "Equivalent lateral force procedure is not permitted; use modal response spectrum analysis",
+);
+pub const SEISMIC_MINIMUM_CS_GOVERNS: WarningCode = code(
+    "seismic_load.minimum_cs_governs", "seismic_load", Low, Some("additional.response_modification"),
+    "The minimum seismic response coefficient governs the base shear",
+);
+pub const CRANE_NO_CANDIDATE: WarningCode = code(
+    "tower_crane.no_candidate", "tower_crane", Critical, Some("extended_parameters.candidates"),
+    "No candidate crane covers every pick within its load chart",
+);
+pub const CRANE_CRITICAL_LIFT: WarningCode = code(
+    "tower_crane.critical_lift", "tower_crane", Medium, Some("extended_parameters.picks"),
+    "A pick exceeds 75% of the chart capacity at its radius",
+);
+pub const CRANE_OVERSAIL: WarningCode = code(
+    "tower_crane.oversail", "tower_crane", High, Some("additional.oversail_limit"),
+    "The slewing radius crosses the site boundary; an oversail licence or slew restriction is needed",
+);
+pub const CRANE_BEARING_EXCEEDED: WarningCode = code(
+    "tower_crane.bearing_exceeded", "tower_crane", Critical, Some("additional.footing_width"),
+    "Base reactions exceed the allowable bearing pressure or lift the footing",
+);
+
+// ============================================================================
+// MECHANICAL
+// ============================================================================
+
+pub const VALVE_LARGE_CV: WarningCode = code(
+    "valve_sizing.large_cv", "valve_sizing", Medium, Some("additional.flow_rate"),
+    "Required Cv exceeds 1000; a single valve may be impractical",
+);
+pub const COMPRESSOR_HIGH_PRESSURE_RATIO: WarningCode = code(
+    "compressor_sizing.high_pressure_ratio", "compressor_sizing", High, Some("additional.p_out"),
+    "Pressure ratio above 10 usually requires multi-stage compression",
+);
+pub const HVAC_LARGE_TEMPERATURE_DIFFERENCE: WarningCode = code(
+    "hvac_load_calculation.large_temperature_difference", "hvac_load_calculation", Medium,
+    Some("additional.outdoor_temp"),
+    "Indoor/outdoor temperature difference exceeds 20°C",
+);
+pub const HVAC_HIGH_OCCUPANCY: WarningCode = code(
+    "hvac_load_calculation.high_occupancy", "hvac_load_calculation", Medium, Some("additional.occupancy"),
+    "Occupancy density above 0.2 persons/m² drives ventilation requirements",
+);
+pub const HVAC_MIXED_AIR_FOG: WarningCode = code(
+    "hvac_load_calculation.mixed_air_fog", "hvac_load_calculation", Medium, Some("additional.outdoor_airflow"),
+    "Mixed air is supersaturated; moisture condenses as fog in the mixing box",
+);
+pub const HVAC_MIXED_AIR_FREEZING: WarningCode = code(
+    "hvac_load_calculation.mixed_air_freezing", "hvac_load_calculation", High, Some("additional.outdoor_airflow"),
+    "Mixed air below 2°C risks freezing water coils downstream",
+);
+pub const PIPING_HIGH_VELOCITY: WarningCode = code(
+    "piping_pressure_drop.high_velocity", "piping_pressure_drop", High, Some("additional.flow_rate"),
+    "Flow velocity above 3 m/s risks erosion and noise",
+);
+pub const PIPING_LOW_VELOCITY: WarningCode = code(
+    "piping_pressure_drop.low_velocity", "piping_pressure_drop", Medium, Some("additional.flow_rate"),
+    "Flow velocity below 0.5 m/s risks solids settling",
+);
+pub const PUMP_INSUFFICIENT_NPSH: WarningCode = code(
+    "pump_sizing.insufficient_npsh", "pump_sizing", Critical, Some("additional.npsh_available"),
+    "NPSH margin below 1 m; cavitation is likely",
+);
+pub const PUMP_HIGH_VISCOSITY: WarningCode = code(
+    "pump_sizing.high_viscosity", "pump_sizing", Medium, Some("additional.viscosity"),
+    "Viscosity above 100 cP requires a viscosity correction",
+);
+pub const REFRIGERATION_LOW_COP: WarningCode = code(
+    "refrigeration_cycle.low_cop", "refrigeration_cycle", Medium, Some("additional.t_cond"),
+    "Coefficient of performance below 3.0",
+);
+pub const HEAT_EXCHANGER_LOW_EFFECTIVENESS: WarningCode = code(
+    "heat_exchanger.low_effectiveness", "heat_exchanger", Medium, None,
+    "Exchanger effectiveness below 0.5",
+);
+pub const THERMAL_LARGE_TEMPERATURE_CHANGE: WarningCode = code(
+    "thermal_expansion.large_temperature_change", "thermal_expansion", High, Some("additional.delta_t"),
+    "Temperature change above 200°C may exceed material limits",
+);
+pub const THERMAL_SIGNIFICANT_EXPANSION: WarningCode = code(
+    "thermal_expansion.significant_expansion", "thermal_expansion", Medium, Some("additional.delta_t"),
+    "Expansion exceeds 1% of the original dimension",
+);
+pub const WATER_HAMMER_EXCEEDS_RATING: WarningCode = code(
+    "water_hammer.exceeds_rating", "water_hammer", Critical, Some("additional.pressure_rating"),
+    "Operating pressure plus surge exceeds the pipe pressure rating",
+);
+pub const WATER_HAMMER_RAPID_CLOSURE: WarningCode = code(
+    "water_hammer.rapid_closure", "water_hammer", Medium, Some("additional.closure_time"),
+    "Valve closes within the 2L/a reflection time, so the full Joukowsky rise develops",
+);
+pub const WATER_HAMMER_COLUMN_SEPARATION: WarningCode = code(
+    "water_hammer.column_separation", "water_hammer", High, Some("additional.operating_pressure"),
+    "Downsurge falls below vapour pressure; column separation is likely",
+);
+pub const ELEVATOR_LONG_INTERVAL: WarningCode = code(
+    "elevator_traffic.long_interval", "elevator_traffic", Medium, Some("additional.cars"),
+    "Up-peak interval exceeds the target waiting interval",
+);
+pub const ELEVATOR_LOW_HANDLING_CAPACITY: WarningCode = code(
+    "elevator_traffic.low_handling_capacity", "elevator_traffic", High, Some("additional.cars"),
+    "Five-minute handling capacity is below the target share of the population",
+);
+pub const ELEVATOR_SLOW_FOR_RISE: WarningCode = code(
+    "elevator_traffic.slow_for_rise", "elevator_traffic", Low, Some("additional.car_speed"),
+    "Travel to the top floor at rated speed takes longer than 25 s",
+);
+pub const SPRINKLER_COVERAGE_EXCEEDED: WarningCode = code(
+    "sprinkler_hydraulic.coverage_exceeded", "sprinkler_hydraulic", High, Some("dimensions.sprinkler_spacing"),
+    "Area covered by each sprinkler exceeds the NFPA 13 maximum for the hazard",
+);
+pub const SPRINKLER_PIPE_SCHEDULE_EXCEEDED: WarningCode = code(
+    "sprinkler_hydraulic.pipe_schedule_exceeded", "sprinkler_hydraulic", Low, Some("dimensions.branch_diameter"),
+    "A pipe supplies more sprinklers than the NFPA 13 pipe schedule allows for its size",
+);
+pub const SPRINKLER_HIGH_VELOCITY: WarningCode = code(
+    "sprinkler_hydraulic.high_velocity", "sprinkler_hydraulic", Medium, Some("dimensions.main_diameter"),
+    "Pipe velocity above 6 m/s",
+);
+pub const SPRINKLER_SUPPLY_INSUFFICIENT: WarningCode = code(
+    "sprinkler_hydraulic.supply_insufficient", "sprinkler_hydraulic", Critical, Some("additional.supply_residual_pressure"),
+    "Water supply cannot deliver the system demand at the required pressure",
+);
+pub const CONDENSATION_INTERSTITIAL: WarningCode = code(
+    "condensation_risk.interstitial", "condensation_risk", High, Some("extended_parameters.layers"),
+    "Vapour pressure reaches saturation at a layer interface under the design winter conditions",
+);
+pub const CONDENSATION_ACCUMULATION: WarningCode = code(
+    "condensation_risk.accumulation", "condensation_risk", High, Some("additional.accumulation_limit"),
+    "Condensate accumulated over the cold season exceeds the permissible amount",
+);
+pub const CONDENSATION_SURFACE: WarningCode = code(
+    "condensation_risk.surface", "condensation_risk", Critical, Some("additional.indoor_rh"),
+    "The inside surface falls below the indoor dewpoint; surface condensation and mould are likely",
+);
+
+// ============================================================================
+// CIVIL
+// ============================================================================
+
+pub const FOUNDATION_LARGE_FOOTING: WarningCode = code(
+    "foundation_design.large_footing", "foundation_design", Medium, Some("loads.dead_load"),
+    "Footing width exceeds 3 m; a mat foundation may be more economical",
+);
+pub const FOUNDATION_SHALLOW_EMBEDMENT: WarningCode = code(
+    "foundation_design.shallow_embedment", "foundation_design", Medium, Some("dimensions.depth"),
+    "Embedment below 1 m may not clear the frost line",
+);
+pub const PILE_GROUP_OVERLOADED: WarningCode = code(
+    "pile_foundation.group_overloaded", "pile_foundation", Critical, Some("loads.dead_load"),
+    "Service load exceeds the allowable capacity of the pile group",
+);
+pub const PILE_BLOCK_FAILURE: WarningCode = code(
+    "pile_foundation.block_failure", "pile_foundation", High, Some("additional.pile_spacing"),
+    "Block failure of the soil enclosing the group governs over the individual piles",
+);
+pub const PILE_CLOSE_SPACING: WarningCode = code(
+    "pile_foundation.close_spacing", "pile_foundation", Medium, Some("additional.pile_spacing"),
+    "Pile spacing below 2.5 diameters lowers group efficiency and risks installation damage",
+);
+pub const PILE_EXCESSIVE_SETTLEMENT: WarningCode = code(
+    "pile_foundation.excessive_settlement", "pile_foundation", High, Some("additional.allowable_settlement"),
+    "Estimated group settlement exceeds the allowable settlement",
+);
+pub const PAVEMENT_HIGH_ESAL: WarningCode = code(
+    "pavement_design.high_esal", "pavement_design", Medium, Some("additional.esal"),
+    "Design traffic is in the heavy traffic range",
+);
+pub const PAVEMENT_POOR_DRAINAGE: WarningCode = code(
+    "pavement_design.poor_drainage", "pavement_design", High, Some("additional.drainage_coeff"),
+    "Drainage coefficient below 0.8 shortens pavement life",
+);
+pub const RETAINING_WALL_WIDE_BASE: WarningCode = code(
+    "retaining_wall.wide_base", "retaining_wall", Medium, Some("dimensions.height"),
+    "Minimum base width exceeds half the wall height",
+);
+pub const SETTLEMENT_SIGNIFICANT: WarningCode = code(
+    "settlement_analysis.significant_settlement", "settlement_analysis", High, Some("loads.dead_load"),
+    "Consolidation settlement exceeds 50 mm",
+);
+pub const SLOPE_LOW_FOS: WarningCode = code(
+    "slope_stability.low_factor_of_safety", "slope_stability", Critical, Some("additional.slope_angle"),
+    "Infinite slope factor of safety below 1.5",
+);
+pub const SOIL_LOW_FRICTION_ANGLE: WarningCode = code(
+    "soil_bearing_capacity.low_friction_angle", "soil_bearing_capacity", Medium,
+    Some("additional.friction_angle"),
+    "Friction angle below 25°; cohesive behaviour dominates",
+);
+pub const COMPACTION_BELOW_SPEC: WarningCode = code(
+    "soil_compaction.below_spec", "soil_compaction", High, Some("additional.required_compaction"),
+    "Relative compaction is below the specified percentage of maximum dry density",
+);
+pub const COMPACTION_MOISTURE_OUT_OF_RANGE: WarningCode = code(
+    "soil_compaction.moisture_out_of_range", "soil_compaction", Medium, Some("additional.optimum_moisture"),
+    "Field moisture is outside the specified window around optimum",
+);
+pub const COMPACTION_SUSPECT_TEST: WarningCode = code(
+    "soil_compaction.suspect_test", "soil_compaction", Low, Some("additional.max_dry_density"),
+    "Dry density exceeds the zero-air-voids line or 103% of maximum; the Proctor curve may not match the soil",
+);
+
+// ============================================================================
+// HYDRAULIC
+// ============================================================================
+
+pub const RATIONAL_LARGE_AREA: WarningCode = code(
+    "rational_method_runoff.large_area", "rational_method_runoff", Medium, Some("additional.drainage_area"),
+    "Drainage area exceeds 80 ha; the rational method overestimates peaks on large catchments",
+);
+pub const RATIONAL_SHORT_TC: WarningCode = code(
+    "rational_method_runoff.short_time_of_concentration", "rational_method_runoff", Low,
+    Some("additional.time_of_concentration"),
+    "Time of concentration below 5 minutes was raised to the 5 minute minimum",
+);
+pub const CULVERT_HEADWATER_EXCEEDED: WarningCode = code(
+    "culvert_sizing.headwater_exceeded", "culvert_sizing", Critical, Some("additional.allowable_headwater"),
+    "Design headwater exceeds the allowable headwater",
+);
+pub const CULVERT_HIGH_OUTLET_VELOCITY: WarningCode = code(
+    "culvert_sizing.high_outlet_velocity", "culvert_sizing", Medium, Some("additional.design_flow"),
+    "Outlet velocity above 4.5 m/s; provide outlet scour protection",
+);
+pub const SEWER_CAPACITY_EXCEEDED: WarningCode = code(
+    "storm_sewer_sizing.capacity_exceeded", "storm_sewer_sizing", Critical, Some("additional.design_flow"),
+    "Design flow exceeds the full-flow capacity of the largest standard pipe",
+);
+pub const SEWER_LOW_VELOCITY: WarningCode = code(
+    "storm_sewer_sizing.low_velocity", "storm_sewer_sizing", Medium, Some("additional.slope"),
+    "Velocity at the design flow is below the 0.9 m/s self-cleansing velocity",
+);
+pub const SEWER_HIGH_VELOCITY: WarningCode = code(
+    "storm_sewer_sizing.high_velocity", "storm_sewer_sizing", Medium, Some("additional.slope"),
+    "Full-flow velocity above 4.5 m/s risks pipe abrasion",
+);
+
+// ============================================================================
+// ELECTRICAL
+// ============================================================================
+
+pub const GROUNDING_TARGET_NOT_MET: WarningCode = code(
+    "grounding_lightning_protection.target_not_met", "grounding_lightning_protection", High,
+    Some("additional.target_resistance"),
+    "Ground electrode resistance exceeds the target resistance",
+);
+pub const GROUNDING_HIGH_RESISTIVITY: WarningCode = code(
+    "grounding_lightning_protection.high_resistivity", "grounding_lightning_protection", Medium,
+    Some("additional.soil_resistivity"),
+    "Soil resistivity above 1000 Ω·m; low electrode resistance is hard to achieve",
+);
+pub const LIGHTNING_CLASS_II: WarningCode = code(
+    "grounding_lightning_protection.class_ii", "grounding_lightning_protection", Low, Some("dimensions.height"),
+    "Structure taller than 23 m requires Class II lightning protection materials",
+);
+pub const GENERATOR_VOLTAGE_DIP_EXCEEDED: WarningCode = code(
+    "generator_ups_sizing.voltage_dip_exceeded", "generator_ups_sizing", High, Some("additional.max_voltage_dip"),
+    "A load step dips the generator voltage beyond the allowable limit",
+);
+pub const GENERATOR_OVERLOADED: WarningCode = code(
+    "generator_ups_sizing.overloaded", "generator_ups_sizing", Critical, Some("additional.generator_rating"),
+    "Running load exceeds the loading limit of the generator",
+);
+pub const GENERATOR_LIGHT_LOAD: WarningCode = code(
+    "generator_ups_sizing.light_load", "generator_ups_sizing", Low, Some("additional.generator_rating"),
+    "Running load below 30% of the rating risks wet stacking",
+);
+pub const UPS_RUNTIME_SHORT: WarningCode = code(
+    "generator_ups_sizing.runtime_short", "generator_ups_sizing", High, Some("additional.required_runtime"),
+    "UPS battery runtime is below the required runtime",
+);
+
+// ============================================================================
+// PRODUCTION
+// ============================================================================
+
+pub const CONVEYOR_STEEP_INCLINE: WarningCode = code(
+    "conveyor_belt.steep_incline", "conveyor_belt", High, Some("additional.inclination_angle"),
+    "Inclination above 18°; material may roll back on a plain belt",
+);
+pub const CONVEYOR_HIGH_SPEED: WarningCode = code(
+    "conveyor_belt.high_speed", "conveyor_belt", Medium, Some("additional.belt_speed"),
+    "Belt speed above 3 m/s increases spillage and dust",
+);
+pub const CONVEYOR_LOW_SPEED: WarningCode = code(
+    "conveyor_belt.low_speed", "conveyor_belt", Medium, Some("additional.belt_speed"),
+    "Belt speed below the recommended minimum; discharge may be poor",
+);
+pub const LINE_BELOW_MINIMUM_EFFICIENCY: WarningCode = code(
+    "production_line_balancing.below_minimum_efficiency", "production_line_balancing", High,
+    Some("additional.num_workstations"),
+    "Line efficiency below the minimum acceptable level",
+);
+pub const LINE_BELOW_TARGET_EFFICIENCY: WarningCode = code(
+    "production_line_balancing.below_target_efficiency", "production_line_balancing", Medium,
+    Some("additional.num_workstations"),
+    "Line efficiency below the target level",
+);
+pub const LINE_EXCESS_WORKSTATIONS: WarningCode = code(
+    "production_line_balancing.excess_workstations", "production_line_balancing", Medium,
+    Some("additional.num_workstations"),
+    "More than twice the theoretical minimum number of workstations",
+);
+pub const LINE_CYCLE_EXCEEDS_TAKT: WarningCode = code(
+    "production_line_balancing.cycle_exceeds_takt", "production_line_balancing", High,
+    Some("additional.longest_task_time"),
+    "Cycle time exceeds takt time; demand cannot be met",
+);
+pub const LINE_POOR_SMOOTHNESS: WarningCode = code(
+    "production_line_balancing.poor_smoothness", "production_line_balancing", Low, None,
+    "Smoothness index above 20% indicates uneven station loading",
+);
+pub const CAPACITY_HIGH_UTILIZATION: WarningCode = code(
+    "capacity_planning.high_utilization", "capacity_planning", High, Some("additional.target_utilization"),
+    "Machine utilization above 90% leaves no buffer for downtime",
+);
+pub const CAPACITY_LOW_YIELD: WarningCode = code(
+    "capacity_planning.low_yield", "capacity_planning", Medium, Some("additional.quality_yield"),
+    "Quality yield below 95% inflates required production",
+);
+pub const CAPABILITY_NOT_CAPABLE: WarningCode = code(
+    "process_capability.not_capable", "process_capability", High, Some("additional.std_dev"),
+    "Cpk below the minimum; the process is not capable",
+);
+pub const CAPABILITY_BELOW_ADEQUATE: WarningCode = code(
+    "process_capability.below_adequate", "process_capability", Medium, Some("additional.std_dev"),
+    "Cpk below the adequate level",
+);
+pub const LAYOUT_LOW_EFFICIENCY: WarningCode = code(
+    "facility_layout.low_efficiency", "facility_layout", Medium, Some("additional.total_flow_distance"),
+    "Layout efficiency below the target",
+);
+pub const LAYOUT_HIGH_SPACE_UTILIZATION: WarningCode = code(
+    "facility_layout.high_space_utilization", "facility_layout", Low, Some("additional.facility_area"),
+    "Space utilization is high; congestion is likely",
+);
+pub const INVENTORY_HIGH_EOQ: WarningCode = code(
+    "inventory_optimization.high_eoq", "inventory_optimization", Low, Some("additional.ordering_cost"),
+    "EOQ exceeds 10% of annual demand",
+);
+pub const SAMPLING_LOW_OBSERVATIONS: WarningCode = code(
+    "work_sampling.low_observations", "work_sampling", Medium, Some("additional.total_observations"),
+    "Fewer than 200 observations; the confidence interval may be wide",
+);
+pub const SAMPLING_LOW_PRODUCTIVITY: WarningCode = code(
+    "work_sampling.low_productivity", "work_sampling", Medium, Some("additional.productive_observations"),
+    "Productive time below 70%",
+);
+
+// ============================================================================
+// CATALOGUE
+// ============================================================================
+
+/// All warning codes emitted by engineering calculators
+pub const WARNING_CATALOGUE: &[WarningCode] = &[
+    BEAM_EXCESSIVE_DEFLECTION,
+    BEAM_HIGH_SHEAR,
+    BEAM_OVER_REINFORCED,
+    BEAM_SHEAR_REINFORCEMENT,
+    BEAM_SHALLOW_SECTION,
+    BEAM_BARS_DO_NOT_FIT,
+    BEAM_SHEAR_SECTION_INADEQUATE,
+    COLUMN_K_FACTOR_ASSUMED,
+    COLUMN_LARGE_SECTION,
+    COLUMN_SLENDER,
+    COLUMN_REINFORCEMENT_RATIO,
+    TRUSS_HIGH_MEMBER_FORCE,
+    FRAME_EXCESSIVE_DRIFT,
+    CONNECTION_COMBINED_TENSION,
+    CONNECTION_MANY_BOLTS,
+    CONNECTION_BOLT_GROUP_OVERSTRESSED,
+    SLAB_THICK,
+    LATERAL_HIGH_LOAD,
+    WIND_FLEXIBLE_BUILDING,
+    WIND_MINIMUM_LOAD_GOVERNS,
+    SEISMIC_ELF_NOT_PERMITTED,
+    SEISMIC_MINIMUM_CS_GOVERNS,
+    CRANE_NO_CANDIDATE,
+    CRANE_CRITICAL_LIFT,
+    CRANE_OVERSAIL,
+    CRANE_BEARING_EXCEEDED,
+    VALVE_LARGE_CV,
+    COMPRESSOR_HIGH_PRESSURE_RATIO,
+    HVAC_LARGE_TEMPERATURE_DIFFERENCE,
+    HVAC_HIGH_OCCUPANCY,
+    HVAC_MIXED_AIR_FOG,
+    HVAC_MIXED_AIR_FREEZING,
+    PIPING_HIGH_VELOCITY,
+    PIPING_LOW_VELOCITY,
+    PUMP_INSUFFICIENT_NPSH,
+    PUMP_HIGH_VISCOSITY,
+    REFRIGERATION_LOW_COP,
+    HEAT_EXCHANGER_LOW_EFFECTIVENESS,
+    THERMAL_LARGE_TEMPERATURE_CHANGE,
+    THERMAL_SIGNIFICANT_EXPANSION,
+    WATER_HAMMER_EXCEEDS_RATING,
+    WATER_HAMMER_RAPID_CLOSURE,
+    WATER_HAMMER_COLUMN_SEPARATION,
+    ELEVATOR_LONG_INTERVAL,
+    ELEVATOR_LOW_HANDLING_CAPACITY,
+    ELEVATOR_SLOW_FOR_RISE,
+    SPRINKLER_COVERAGE_EXCEEDED,
+    SPRINKLER_PIPE_SCHEDULE_EXCEEDED,
+    SPRINKLER_HIGH_VELOCITY,
+    SPRINKLER_SUPPLY_INSUFFICIENT,
+    CONDENSATION_INTERSTITIAL,
+    CONDENSATION_ACCUMULATION,
+    CONDENSATION_SURFACE,
+    FOUNDATION_LARGE_FOOTING,
+    FOUNDATION_SHALLOW_EMBEDMENT,
+    PILE_GROUP_OVERLOADED,
+    PILE_BLOCK_FAILURE,
+    PILE_CLOSE_SPACING,
+    PILE_EXCESSIVE_SETTLEMENT,
+    PAVEMENT_HIGH_ESAL,
+    PAVEMENT_POOR_DRAINAGE,
+    RETAINING_WALL_WIDE_BASE,
+    SETTLEMENT_SIGNIFICANT,
+    SLOPE_LOW_FOS,
+    SOIL_LOW_FRICTION_ANGLE,
+    COMPACTION_BELOW_SPEC,
+    COMPACTION_MOISTURE_OUT_OF_RANGE,
+    COMPACTION_SUSPECT_TEST,
+    RATIONAL_LARGE_AREA,
+    RATIONAL_SHORT_TC,
+    CULVERT_HEADWATER_EXCEEDED,
+    CULVERT_HIGH_OUTLET_VELOCITY,
+    SEWER_CAPACITY_EXCEEDED,
+    SEWER_LOW_VELOCITY,
+    SEWER_HIGH_VELOCITY,
+    GROUNDING_TARGET_NOT_MET,
+    GROUNDING_HIGH_RESISTIVITY,
+    LIGHTNING_CLASS_II,
+    GENERATOR_VOLTAGE_DIP_EXCEEDED,
+    GENERATOR_OVERLOADED,
+    GENERATOR_LIGHT_LOAD,
+    UPS_RUNTIME_SHORT,
+    CONVEYOR_STEEP_INCLINE,
+    CONVEYOR_HIGH_SPEED,
+    CONVEYOR_LOW_SPEED,
+    LINE_BELOW_MINIMUM_EFFICIENCY,
+    LINE_BELOW_TARGET_EFFICIENCY,
+    LINE_EXCESS_WORKSTATIONS,
+    LINE_CYCLE_EXCEEDS_TAKT,
+    LINE_POOR_SMOOTHNESS,
+    CAPACITY_HIGH_UTILIZATION,
+    CAPACITY_LOW_YIELD,
+    CAPABILITY_NOT_CAPABLE,
+    CAPABILITY_BELOW_ADEQUATE,
+    LAYOUT_LOW_EFFICIENCY,
+    LAYOUT_HIGH_SPACE_UTILIZATION,
+    INVENTORY_HIGH_EOQ,
+    SAMPLING_LOW_OBSERVATIONS,
+    SAMPLING_LOW_PRODUCTIVITY,
+];
+
+/// Look up a catalogue entry by its stable code
+pub fn find(code: &str) -> Option<&'static WarningCode> {
+    WARNING_CATALOGUE.iter().find(|w| w.code == code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::engineer::registry::create_default_registry;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_codes_are_unique() {
+        let mut seen = HashSet::new();
+        for w in WARNING_CATALOGUE {
+            assert!(seen.insert(w.code), "duplicate warning code {}", w.code);
+        }
+    }
+
+    #[test]
+    fn test_codes_belong_to_registered_calculators() {
+        let registry = create_default_registry();
+        for w in WARNING_CATALOGUE {
+            assert!(w.code.starts_with(&format!("{}.", w.calculator)));
+            assert!(registry.find(w.calculator).is_ok(), "unknown calculator {}", w.calculator);
+        }
+    }
+
+    #[test]
+    fn test_warn_carries_catalogue_fields() {
+        let warning = PUMP_INSUFFICIENT_NPSH.warn("NPSH margin 0.4 m");
+        assert_eq!(warning.code, "pump_sizing.insufficient_npsh");
+        assert_eq!(warning.severity, WarningSeverity::Critical);
+        assert_eq!(warning.affected_parameter.as_deref(), Some("additional.npsh_available"));
+        assert!(find("pump_sizing.insufficient_npsh").is_some());
+    }
+}
@@ -0,0 +1,86 @@
+// ============================================================================
+// Calculator Examples
+//
+// Worked examples served in calculator metadata: a request body the
+// `/calculate` endpoint accepts and the result values it comes back with.
+// They are generated from the golden fixtures the self-check verifies, so
+// an example can never drift from what the calculator actually returns -
+// re-recording the fixtures refreshes the examples too. Frontends use them
+// for "try it" buttons; integrators use them to see a valid payload for
+// calculators with many inputs.
+// ============================================================================
+
+use crate::calculus::recommendation::Tier;
+use crate::calculus::selftest::{self, GoldenFixture};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+
+/// A valid request and the results it produces
+#[derive(Debug, Clone, Serialize)]
+pub struct CalculatorExample {
+    pub name: String,
+    /// Body accepted by the tier's `/calculate` endpoint
+    pub request: Value,
+    /// Result values by label in the response to `request`; repeated labels
+    /// carry a `#n` suffix
+    pub expected_results: BTreeMap<String, f64>,
+}
+
+impl CalculatorExample {
+    fn from_fixture(fixture: &GoldenFixture, index: usize) -> Self {
+        Self {
+            name: if index == 0 { "Typical values".to_string() } else { format!("Typical values #{}", index + 1) },
+            request: json!({
+                "calculation_type": fixture.calculator_id,
+                "parameters": fixture.parameters,
+            }),
+            expected_results: fixture.results.clone(),
+        }
+    }
+}
+
+fn by_calculator() -> &'static HashMap<(Tier, String), Vec<CalculatorExample>> {
+    static EXAMPLES: OnceLock<HashMap<(Tier, String), Vec<CalculatorExample>>> = OnceLock::new();
+    EXAMPLES.get_or_init(|| index(&selftest::fixtures()))
+}
+
+fn index(fixtures: &[GoldenFixture]) -> HashMap<(Tier, String), Vec<CalculatorExample>> {
+    let mut examples: HashMap<(Tier, String), Vec<CalculatorExample>> = HashMap::new();
+    for fixture in fixtures {
+        let entry = examples.entry((fixture.tier, fixture.calculator_id.clone())).or_default();
+        entry.push(CalculatorExample::from_fixture(fixture, entry.len()));
+    }
+    examples
+}
+
+/// Examples for one calculator; empty when it has no recorded fixture
+pub fn for_calculator(tier: Tier, calculator_id: &str) -> Vec<CalculatorExample> {
+    by_calculator()
+        .get(&(tier, calculator_id.to_string()))
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples_come_from_fixtures() {
+        let fixtures = selftest::fixtures();
+        let beam = fixtures
+            .iter()
+            .find(|f| f.tier == Tier::Engineer && f.calculator_id == "beam_design")
+            .expect("beam design has a golden fixture");
+
+        let examples = for_calculator(Tier::Engineer, "beam_design");
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].request["calculation_type"], "beam_design");
+        assert_eq!(examples[0].request["parameters"], beam.parameters);
+        assert_eq!(examples[0].expected_results, beam.results);
+
+        assert!(for_calculator(Tier::Beginner, "no_such_calculator").is_empty());
+    }
+}
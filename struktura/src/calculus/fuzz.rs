@@ -0,0 +1,358 @@
+// ============================================================================
+// Input Fuzzing (dev only, `--features fuzzing`)
+//
+// Starts from each calculator's demo request and replaces parameters with
+// random draws from their metadata bounds, plus edge values (zero, bounds,
+// just outside the bounds, negatives, huge and tiny magnitudes). Rejecting
+// a case is fine; panicking in `validate`/`calculate` or returning a
+// non-finite result value is a bug. Runs are seeded, so any failing case
+// can be replayed from its seed.
+// ============================================================================
+
+use crate::calculus::beginner::{BeginnerParameters, BeginnerRegistry};
+use crate::calculus::contractor::{self, ContractingParameters, ContractingRegistry};
+use crate::calculus::demo::{set_path, DemoCatalogue};
+use crate::calculus::engineer::{self, EngineeringParameters, EngineeringRegistry};
+use crate::calculus::recommendation::Tier;
+use crate::state::AppState;
+use axum::{extract::State, routing::post, Json, Router};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+/// Cases generated per calculator when the request does not say
+pub const DEFAULT_CASES: usize = 64;
+/// Failing cases kept per calculator in a report
+const MAX_EXAMPLES: usize = 3;
+/// Chance that any one parameter is replaced in a case
+const MUTATION_RATE: f64 = 0.5;
+
+/// What fuzzing knows about one input
+#[derive(Debug, Clone)]
+enum InputKind {
+    Number,
+    Integer,
+    Choice(Vec<String>),
+}
+
+#[derive(Debug, Clone)]
+struct InputSpec {
+    path: String,
+    kind: InputKind,
+    min: Option<f64>,
+    max: Option<f64>,
+    required: bool,
+}
+
+/// How a single generated case ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseOutcome {
+    Accepted,
+    Rejected,
+    Panicked,
+    NonFinite,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FailingCase {
+    pub outcome: CaseOutcome,
+    pub seed: u64,
+    pub parameters: Value,
+    /// Panic message or the labels of non-finite results
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CalculatorFuzzReport {
+    pub tier: Tier,
+    pub calculator_id: String,
+    pub cases: usize,
+    pub accepted: usize,
+    pub rejected: usize,
+    pub panicked: usize,
+    pub non_finite: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<FailingCase>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FuzzReport {
+    pub seed: u64,
+    pub cases_per_calculator: usize,
+    pub panicked: usize,
+    pub non_finite: usize,
+    pub calculators: Vec<CalculatorFuzzReport>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FuzzRequest {
+    pub seed: Option<u64>,
+    pub cases_per_calculator: Option<usize>,
+    pub tier: Option<Tier>,
+    pub calculator_id: Option<String>,
+}
+
+fn specs(
+    beginner: &BeginnerRegistry,
+    engineer: &EngineeringRegistry,
+    contractor: &ContractingRegistry,
+    tier: Tier,
+    id: &str,
+) -> Vec<InputSpec> {
+    match tier {
+        Tier::Beginner => beginner.find(id).map(|c| c.metadata().parameters).unwrap_or_default()
+            .into_iter()
+            .filter(|p| p.data_type == "number")
+            .map(|p| InputSpec { path: p.path, kind: InputKind::Number, min: p.min_value, max: p.max_value, required: p.required })
+            .collect(),
+        Tier::Engineer => engineer.find(id).map(|c| c.metadata().parameters).unwrap_or_default()
+            .into_iter()
+            .filter_map(|p| {
+                let kind = match p.data_type {
+                    engineer::models::ParameterType::Number => InputKind::Number,
+                    engineer::models::ParameterType::Integer => InputKind::Integer,
+                    engineer::models::ParameterType::Enum(options) => InputKind::Choice(options),
+                    _ => return None,
+                };
+                Some(InputSpec { path: p.path, kind, min: p.min_value, max: p.max_value, required: p.required })
+            })
+            .collect(),
+        Tier::Contractor => contractor.find(id).map(|c| c.metadata().parameters).unwrap_or_default()
+            .into_iter()
+            .filter_map(|p| {
+                let kind = match p.data_type {
+                    contractor::models::ParameterType::Number => InputKind::Number,
+                    contractor::models::ParameterType::Integer => InputKind::Integer,
+                    contractor::models::ParameterType::Enum(options) => InputKind::Choice(options),
+                    _ => return None,
+                };
+                Some(InputSpec { path: p.path, kind, min: p.min_value, max: p.max_value, required: p.required })
+            })
+            .collect(),
+    }
+}
+
+/// A random number for an input: usually inside its bounds, sometimes an
+/// edge value or deliberately out of range
+fn draw_number(rng: &mut StdRng, spec: &InputSpec) -> f64 {
+    let lo = spec.min.unwrap_or(-1e6);
+    let hi = spec.max.unwrap_or(1e6).max(lo);
+    match rng.random_range(0..10) {
+        0 => 0.0,
+        1 => lo,
+        2 => hi,
+        3 => lo - (hi - lo).abs().max(1.0) * 0.01,
+        4 => -rng.random_range(1.0..1e6),
+        5 => [1e-12, 1e12, 1e300, f64::MIN_POSITIVE][rng.random_range(0..4)],
+        _ if hi > lo => rng.random_range(lo..=hi),
+        _ => lo,
+    }
+}
+
+fn mutate(rng: &mut StdRng, base: &Value, specs: &[InputSpec]) -> Value {
+    let mut parameters = base.clone();
+    for spec in specs {
+        if !rng.random_bool(MUTATION_RATE) {
+            continue;
+        }
+        let value = match &spec.kind {
+            // Optional inputs are sometimes dropped rather than replaced
+            _ if !spec.required && rng.random_bool(0.2) => Value::Null,
+            InputKind::Number => json!(draw_number(rng, spec)),
+            InputKind::Integer => json!(draw_number(rng, spec).round()),
+            InputKind::Choice(options) if !options.is_empty() && rng.random_bool(0.9) => {
+                json!(options[rng.random_range(0..options.len())])
+            }
+            InputKind::Choice(_) => json!("not-an-option"),
+        };
+        set_path(&mut parameters, &spec.path, value);
+    }
+    parameters
+}
+
+fn panic_message(error: tokio::task::JoinError) -> String {
+    match error.try_into_panic() {
+        Ok(payload) => payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panic".to_string()),
+        Err(e) => e.to_string(),
+    }
+}
+
+fn non_finite<'a>(results: impl Iterator<Item = (&'a str, f64)>) -> Option<String> {
+    let labels: Vec<&str> = results.filter(|(_, v)| !v.is_finite()).map(|(l, _)| l).collect();
+    (!labels.is_empty()).then(|| labels.join(", "))
+}
+
+/// Run one case on its own task so a panic is caught instead of unwinding
+async fn run_case(
+    beginner: &BeginnerRegistry,
+    engineer: &EngineeringRegistry,
+    contractor: &ContractingRegistry,
+    tier: Tier,
+    id: &str,
+    parameters: Value,
+) -> (CaseOutcome, String) {
+    let task = match tier {
+        Tier::Beginner => {
+            let Ok(calc) = beginner.find(id) else { return (CaseOutcome::Rejected, String::new()) };
+            let Ok(params) = serde_json::from_value::<BeginnerParameters>(parameters) else {
+                return (CaseOutcome::Rejected, String::new());
+            };
+            tokio::spawn(async move {
+                calc.validate(&params).ok()?;
+                let response = calc.calculate(params).await.ok()?;
+                Some(non_finite(response.results.iter().map(|r| (r.label.as_str(), r.value))))
+            })
+        }
+        Tier::Engineer => {
+            let Ok(calc) = engineer.find(id) else { return (CaseOutcome::Rejected, String::new()) };
+            let Ok(params) = serde_json::from_value::<EngineeringParameters>(parameters) else {
+                return (CaseOutcome::Rejected, String::new());
+            };
+            tokio::spawn(async move {
+                calc.validate(&params).ok()?;
+                let response = calc.calculate(params).await.ok()?;
+                Some(non_finite(response.results.iter().map(|r| (r.label.as_str(), r.value))))
+            })
+        }
+        Tier::Contractor => {
+            let Ok(calc) = contractor.find(id) else { return (CaseOutcome::Rejected, String::new()) };
+            let Ok(params) = serde_json::from_value::<ContractingParameters>(parameters) else {
+                return (CaseOutcome::Rejected, String::new());
+            };
+            tokio::spawn(async move {
+                calc.validate(&params).ok()?;
+                let response = calc.calculate(params).await.ok()?;
+                Some(non_finite(response.results.iter().map(|r| (r.label.as_str(), r.value))))
+            })
+        }
+    };
+
+    match task.await {
+        Ok(None) => (CaseOutcome::Rejected, String::new()),
+        Ok(Some(None)) => (CaseOutcome::Accepted, String::new()),
+        Ok(Some(Some(labels))) => (CaseOutcome::NonFinite, labels),
+        Err(e) => (CaseOutcome::Panicked, panic_message(e)),
+    }
+}
+
+/// Fuzz every calculator (or the one selected) from a base seed
+pub async fn run(
+    beginner: &BeginnerRegistry,
+    engineer: &EngineeringRegistry,
+    contractor: &ContractingRegistry,
+    request: &FuzzRequest,
+) -> FuzzReport {
+    let seed = request.seed.unwrap_or(0x5eed);
+    let cases = request.cases_per_calculator.unwrap_or(DEFAULT_CASES);
+    let demo = DemoCatalogue::build(beginner, engineer, contractor).await;
+
+    let mut calculators = Vec::new();
+    let selected = demo.examples().iter().filter(|e| {
+        request.tier.is_none_or(|t| t == e.tier)
+            && request.calculator_id.as_ref().is_none_or(|id| *id == e.calculator_id)
+    });
+
+    for (index, example) in selected.enumerate() {
+        let base = example.request.get("parameters").cloned().unwrap_or_else(|| json!({}));
+        let specs = specs(beginner, engineer, contractor, example.tier, &example.calculator_id);
+        let mut report = CalculatorFuzzReport {
+            tier: example.tier,
+            calculator_id: example.calculator_id.clone(),
+            cases,
+            accepted: 0,
+            rejected: 0,
+            panicked: 0,
+            non_finite: 0,
+            examples: Vec::new(),
+        };
+
+        for case in 0..cases {
+            let case_seed = seed ^ ((index as u64) << 32) ^ case as u64;
+            let mut rng = StdRng::seed_from_u64(case_seed);
+            let parameters = mutate(&mut rng, &base, &specs);
+            let (outcome, detail) =
+                run_case(beginner, engineer, contractor, example.tier, &example.calculator_id, parameters.clone()).await;
+
+            match outcome {
+                CaseOutcome::Accepted => report.accepted += 1,
+                CaseOutcome::Rejected => report.rejected += 1,
+                CaseOutcome::Panicked => report.panicked += 1,
+                CaseOutcome::NonFinite => report.non_finite += 1,
+            }
+            if matches!(outcome, CaseOutcome::Panicked | CaseOutcome::NonFinite) && report.examples.len() < MAX_EXAMPLES {
+                report.examples.push(FailingCase { outcome, seed: case_seed, parameters, detail });
+            }
+        }
+        calculators.push(report);
+    }
+
+    FuzzReport {
+        seed,
+        cases_per_calculator: cases,
+        panicked: calculators.iter().map(|c| c.panicked).sum(),
+        non_finite: calculators.iter().map(|c| c.non_finite).sum(),
+        calculators,
+    }
+}
+
+/// Mounted at `/api/v1/dev/fuzz` in builds with the `fuzzing` feature only
+pub fn create_router() -> Router<Arc<AppState>> {
+    Router::new().route("/", post(fuzz_handler))
+}
+
+async fn fuzz_handler(State(state): State<Arc<AppState>>, Json(request): Json<FuzzRequest>) -> Json<FuzzReport> {
+    Json(
+        run(
+            &state.calculators_beginner,
+            &state.calculators_engineer,
+            &state.calculators_contractor,
+            &request,
+        )
+        .await,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutation_is_reproducible_from_seed() {
+        let specs = vec![InputSpec {
+            path: "dimensions.width".to_string(),
+            kind: InputKind::Number,
+            min: Some(0.1),
+            max: Some(50.0),
+            required: true,
+        }];
+        let base = json!({ "dimensions": { "width": 2.0 } });
+
+        let a = mutate(&mut StdRng::seed_from_u64(7), &base, &specs);
+        let b = mutate(&mut StdRng::seed_from_u64(7), &base, &specs);
+        assert_eq!(a, b);
+    }
+
+    #[tokio::test]
+    async fn test_no_calculator_panics_on_fuzzed_input() {
+        let beginner = crate::calculus::beginner::create_default_registry();
+        let engineer = crate::calculus::engineer::create_default_registry();
+        let contractor = crate::calculus::contractor::create_default_registry();
+
+        let report = run(&beginner, &engineer, &contractor, &FuzzRequest::default()).await;
+        let failures: Vec<String> = report
+            .calculators
+            .iter()
+            .flat_map(|c| c.examples.iter().map(move |e| (c, e)))
+            .filter(|(_, e)| e.outcome == CaseOutcome::Panicked)
+            .map(|(c, e)| format!("{}/{} (seed {}): {}", c.tier.as_str(), c.calculator_id, e.seed, e.detail))
+            .collect();
+
+        assert_eq!(report.panicked, 0, "panics:\n{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,193 @@
+// ============================================================================
+// Hypermedia Links
+//
+// Catalogue, calculator and calculation responses carry HAL-style `_links`
+// so clients follow relations instead of assembling URLs per tier. Every URL
+// the calculator routers hand out is built here; link sets are keyed by
+// relation name and each relation is one link or a list of them.
+// Calculators are passed in as (id, name) pairs.
+// ============================================================================
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::calculus::recommendation::Tier;
+
+/// Most related calculators linked from one response
+const MAX_RELATED: usize = 5;
+
+/// Where PDF reports of calculation responses are rendered
+const REPORT_PATH: &str = "/api/v1/reports/pdf";
+
+/// Where a calculation is saved into one of the caller's projects
+const PROJECT_SAVE_PATH: &str = "/api/v1/user/projects/{project_id}/calculations";
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Link {
+    pub href: String,
+    /// HTTP method when it is not GET
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<&'static str>,
+    /// The href is a URI template (RFC 6570)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub templated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl Link {
+    pub fn get(href: impl Into<String>) -> Self {
+        Self { href: href.into(), method: None, templated: false, title: None }
+    }
+
+    pub fn post(href: impl Into<String>) -> Self {
+        Self { method: Some("POST"), ..Self::get(href) }
+    }
+
+    pub fn templated(mut self) -> Self {
+        self.templated = true;
+        self
+    }
+
+    pub fn titled(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Relation {
+    One(Link),
+    Many(Vec<Link>),
+}
+
+/// Links of one resource, by relation name
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Links(BTreeMap<&'static str, Relation>);
+
+impl Links {
+    pub fn with(mut self, rel: &'static str, link: Link) -> Self {
+        self.0.insert(rel, Relation::One(link));
+        self
+    }
+
+    /// Add a list relation; empty lists are left out
+    pub fn with_many(mut self, rel: &'static str, links: Vec<Link>) -> Self {
+        if !links.is_empty() {
+            self.0.insert(rel, Relation::Many(links));
+        }
+        self
+    }
+
+    pub fn get(&self, rel: &str) -> Option<&Relation> {
+        self.0.get(rel)
+    }
+}
+
+/// A response body with its `_links`
+#[derive(Debug, Serialize)]
+pub struct Hal<T> {
+    #[serde(flatten)]
+    pub body: T,
+    #[serde(rename = "_links")]
+    pub links: Links,
+}
+
+impl<T> Hal<T> {
+    pub fn new(body: T, links: Links) -> Self {
+        Self { body, links }
+    }
+}
+
+/// Root of a tier's calculator routes
+pub fn tier_base(tier: Tier) -> String {
+    format!("/api/v1/calculus/{}", tier.as_str())
+}
+
+pub fn calculator_href(tier: Tier, calculator_id: &str) -> String {
+    format!("{}/catalogue/{}", tier_base(tier), calculator_id)
+}
+
+fn related_links(tier: Tier, related: impl IntoIterator<Item = (String, String)>) -> Vec<Link> {
+    related
+        .into_iter()
+        .take(MAX_RELATED)
+        .map(|(id, name)| Link::get(calculator_href(tier, &id)).titled(name))
+        .collect()
+}
+
+/// Links of a calculation response: the calculator that produced it, where
+/// to re-run, export or report it, and calculators in the same category
+pub fn calculation(tier: Tier, calculator_id: &str, related: impl IntoIterator<Item = (String, String)>) -> Links {
+    Links::default()
+        .with("self", Link::post(format!("{}/calculate", tier_base(tier))))
+        .with("calculator", Link::get(calculator_href(tier, calculator_id)))
+        .with("export", Link::post(PROJECT_SAVE_PATH).templated())
+        .with("report", Link::post(REPORT_PATH))
+        .with_many("related", related_links(tier, related))
+}
+
+/// Links of one calculator's metadata
+pub fn calculator(tier: Tier, calculator_id: &str, related: impl IntoIterator<Item = (String, String)>) -> Links {
+    let base = tier_base(tier);
+    Links::default()
+        .with("self", Link::get(calculator_href(tier, calculator_id)))
+        .with("calculate", Link::post(format!("{}/calculate", base)))
+        .with("catalogue", Link::get(format!("{}/catalogue", base)))
+        .with_many("related", related_links(tier, related))
+}
+
+/// Links of a tier's catalogue, including one per listed calculator
+pub fn catalogue(tier: Tier, calculators: impl IntoIterator<Item = (String, String)>) -> Links {
+    let base = tier_base(tier);
+    let calculators = calculators
+        .into_iter()
+        .map(|(id, name)| Link::get(calculator_href(tier, &id)).titled(name))
+        .collect();
+    Links::default()
+        .with("self", Link::get(format!("{}/catalogue", base)))
+        .with("calculate", Link::post(format!("{}/calculate", base)))
+        .with("search", Link::get(format!("{}/search?q={{query}}", base)).templated())
+        .with("category", Link::get(format!("{}/categories/{{category}}", base)).templated())
+        .with_many("calculators", calculators)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_calculation_links() {
+        let related = [("column_design", "Column Design"), ("footing_design", "Footing Design")]
+            .map(|(id, name)| (id.to_string(), name.to_string()));
+        let links = calculation(Tier::Engineer, "beam_design", related);
+        let value = serde_json::to_value(&links).unwrap();
+
+        assert_eq!(value["self"], json!({ "href": "/api/v1/calculus/engineer/calculate", "method": "POST" }));
+        assert_eq!(value["calculator"]["href"], "/api/v1/calculus/engineer/catalogue/beam_design");
+        assert_eq!(value["export"]["templated"], true);
+        assert_eq!(value["report"]["href"], REPORT_PATH);
+        assert_eq!(value["related"][1]["title"], "Footing Design");
+    }
+
+    #[test]
+    fn test_empty_lists_and_related_cap() {
+        assert!(calculator(Tier::Beginner, "baseboard", []).get("related").is_none());
+
+        let related = (0..10).map(|i| (format!("calc_{}", i), format!("Calculator {}", i)));
+        let Some(Relation::Many(links)) = calculator(Tier::Contractor, "bid", related).get("related").cloned() else {
+            panic!("expected related links");
+        };
+        assert_eq!(links.len(), MAX_RELATED);
+    }
+
+    #[test]
+    fn test_hal_flattens_body() {
+        let hal = Hal::new(json!({ "calculation_type": "baseboard" }), catalogue(Tier::Beginner, []));
+        let value = serde_json::to_value(&hal).unwrap();
+        assert_eq!(value["calculation_type"], "baseboard");
+        assert_eq!(value["_links"]["self"]["href"], "/api/v1/calculus/beginner/catalogue");
+    }
+}
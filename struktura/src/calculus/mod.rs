@@ -1,6 +1,22 @@
+pub mod audience;
 pub mod beginner;
+pub mod changelog;
+pub mod compare;
 pub mod contractor;
+pub mod demo;
 pub mod engineer;
+pub mod examples;
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
+pub mod links;
+pub mod percentage;
+pub mod provenance;
+pub mod quality;
+pub mod recommendation;
+pub mod rules;
+pub mod sanitize;
+pub mod selftest;
+pub mod strict;
 
 // Re-export commonly used types from beginner module for convenience
 pub use beginner::*;
@@ -0,0 +1,109 @@
+//! Typed percentages
+//!
+//! Core metrics are fractions (0.85) while reports and the legacy helpers
+//! speak in percent (85.0). `Percentage` stores the fraction and makes every
+//! conversion explicit, so a value can no longer be read on the wrong scale.
+//!
+//! Serialized as `{"fraction": 0.85, "percent": 85.0}`. Deserializes from
+//! that object, from either key alone, or from a bare number, which is
+//! always taken as a fraction.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A ratio that is displayed as a percentage
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+#[serde(try_from = "PercentageRepr", into = "PercentageRepr")]
+pub struct Percentage(f64);
+
+impl Percentage {
+    pub const ZERO: Self = Self(0.0);
+    pub const FULL: Self = Self(1.0);
+
+    /// From a fraction where 1.0 is 100%
+    pub const fn from_fraction(fraction: f64) -> Self {
+        Self(fraction)
+    }
+
+    /// From a percent value where 100.0 is 100%
+    pub fn from_percent(percent: f64) -> Self {
+        Self(percent / 100.0)
+    }
+
+    /// `part / whole`, or zero when there is no whole
+    pub fn of(part: f64, whole: f64) -> Self {
+        if whole > 0.0 { Self(part / whole) } else { Self::ZERO }
+    }
+
+    pub fn fraction(self) -> f64 {
+        self.0
+    }
+
+    pub fn percent(self) -> f64 {
+        self.0 * 100.0
+    }
+
+    /// Limited to 0–100%
+    pub fn clamped(self) -> Self {
+        Self(self.0.clamp(0.0, 1.0))
+    }
+
+    /// Whole-number percent, as used in validation message parameters
+    pub fn rounded_percent(self) -> f64 {
+        self.percent().round()
+    }
+}
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}%", p, self.percent()),
+            None => write!(f, "{}%", self.percent()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum PercentageRepr {
+    Fraction(f64),
+    Explicit {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fraction: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        percent: Option<f64>,
+    },
+}
+
+impl TryFrom<PercentageRepr> for Percentage {
+    type Error = String;
+
+    fn try_from(repr: PercentageRepr) -> Result<Self, Self::Error> {
+        let value = match repr {
+            PercentageRepr::Fraction(fraction) => Self::from_fraction(fraction),
+            PercentageRepr::Explicit { fraction: Some(fraction), percent: Some(percent) } => {
+                if (fraction * 100.0 - percent).abs() > 1e-9 * percent.abs().max(1.0) {
+                    return Err(format!("fraction {} and percent {} disagree", fraction, percent));
+                }
+                Self::from_fraction(fraction)
+            }
+            PercentageRepr::Explicit { fraction: Some(fraction), percent: None } => Self::from_fraction(fraction),
+            PercentageRepr::Explicit { fraction: None, percent: Some(percent) } => Self::from_percent(percent),
+            PercentageRepr::Explicit { fraction: None, percent: None } => {
+                return Err("percentage needs a fraction or percent".to_string());
+            }
+        };
+
+        if value.0.is_finite() {
+            Ok(value)
+        } else {
+            Err("percentage must be finite".to_string())
+        }
+    }
+}
+
+impl From<Percentage> for PercentageRepr {
+    fn from(value: Percentage) -> Self {
+        Self::Explicit { fraction: Some(value.fraction()), percent: Some(value.percent()) }
+    }
+}
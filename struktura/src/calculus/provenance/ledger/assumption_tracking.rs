@@ -3,7 +3,7 @@
 //! Helper functions to create ledger entries from input values.
 
 use super::*;
-use crate::calculus::engineer::calculators::production::oee::assumptions::InputValue;
+use crate::calculus::provenance::InputValue;
 use chrono::Utc;
 use serde_json::json;
 
@@ -25,6 +25,7 @@ pub fn track_duration(
         timestamp: Utc::now(),
         impact,
         related_assumptions: Vec::new(),
+        origin: None,
     }
 }
 
@@ -43,6 +44,7 @@ pub fn track_count(
         timestamp: Utc::now(),
         impact,
         related_assumptions: Vec::new(),
+        origin: None,
     }
 }
 
@@ -52,12 +54,14 @@ pub fn track_threshold(
     value: f64,
     unit_key: &str,
     rationale_key: &str,
+    source: &str,
 ) -> ThresholdRecord {
     ThresholdRecord {
         threshold_key: key.to_string(),
         value,
         unit_key: unit_key.to_string(),
         rationale_key: rationale_key.to_string(),
+        source: source.to_string(),
     }
 }
 
@@ -134,8 +138,9 @@ impl AssumptionTracker {
         value: f64,
         unit_key: &str,
         rationale_key: &str,
+        source: &str,
     ) -> &mut Self {
-        self.ledger.add_threshold(track_threshold(key, value, unit_key, rationale_key));
+        self.ledger.add_threshold(track_threshold(key, value, unit_key, rationale_key, source));
         self
     }
     
@@ -3,11 +3,13 @@
 //! This is the "trust builder" - every assumption, every source,
 //! every threshold, every warning, all in one auditable structure.
 //! 
-//! Per the README: "Always accessible from results."
+//! Started in the OEE calculator ("Always accessible from results") and
+//! now attached to every engineer and contractor calculation.
 
 pub mod assumption_tracking;
 
-use crate::calculus::engineer::calculators::production::oee::assumptions::InputValue;
+use crate::calculus::percentage::Percentage;
+use crate::calculus::provenance::ValueSource;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -30,6 +32,9 @@ pub struct AssumptionEntry {
     pub impact: ImpactLevel,
     /// Related assumptions (dependencies)
     pub related_assumptions: Vec<String>,
+    /// Where a default came from ("user", "organization", "system")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
 }
 
 /// Impact level of an assumption on results
@@ -75,6 +80,8 @@ pub struct ThresholdRecord {
     pub unit_key: String,
     /// Why this threshold was used
     pub rationale_key: String,
+    /// How it was obtained
+    pub source: String,  // "explicit", "default"
 }
 
 /// Complete assumption ledger
@@ -101,9 +108,9 @@ pub struct SourceStatistics {
     pub inferred_count: usize,
     pub default_count: usize,
     pub total_count: usize,
-    pub explicit_percentage: f64,
-    pub inferred_percentage: f64,
-    pub default_percentage: f64,
+    pub explicit_percentage: Percentage,
+    pub inferred_percentage: Percentage,
+    pub default_percentage: Percentage,
 }
 
 impl AssumptionLedger {
@@ -118,9 +125,9 @@ impl AssumptionLedger {
                 inferred_count: 0,
                 default_count: 0,
                 total_count: 0,
-                explicit_percentage: 0.0,
-                inferred_percentage: 0.0,
-                default_percentage: 0.0,
+                explicit_percentage: Percentage::ZERO,
+                inferred_percentage: Percentage::ZERO,
+                default_percentage: Percentage::ZERO,
             },
             metadata: HashMap::new(),
         }
@@ -143,6 +150,19 @@ impl AssumptionLedger {
         self.metadata.insert(key.to_string(), value.to_string());
     }
     
+    /// Relabel an assumption as a default taken from `origin`
+    ///
+    /// Used when defaults were merged into the request before the ledger
+    /// saw it, so the value first looked explicit.
+    pub fn mark_default(&mut self, key: &str, origin: &str) {
+        let Some(entry) = self.assumptions.iter_mut().find(|a| a.assumption_key == key) else {
+            return;
+        };
+        entry.source = ValueSource::Default.as_str().to_string();
+        entry.origin = Some(origin.to_string());
+        self.recalculate_statistics();
+    }
+    
     fn recalculate_statistics(&mut self) {
         let mut explicit = 0;
         let mut inferred = 0;
@@ -165,9 +185,9 @@ impl AssumptionLedger {
             inferred_count: inferred,
             default_count: default,
             total_count: total,
-            explicit_percentage: if total > 0 { (explicit as f64 / total_f) * 100.0 } else { 0.0 },
-            inferred_percentage: if total > 0 { (inferred as f64 / total_f) * 100.0 } else { 0.0 },
-            default_percentage: if total > 0 { (default as f64 / total_f) * 100.0 } else { 0.0 },
+            explicit_percentage: Percentage::of(explicit as f64, total_f),
+            inferred_percentage: Percentage::of(inferred as f64, total_f),
+            default_percentage: Percentage::of(default as f64, total_f),
         };
     }
     
@@ -0,0 +1,225 @@
+// ============================================================================
+// Input Provenance
+//
+// Where each input to a calculation came from: supplied by the caller,
+// inferred from other inputs (or, for contractor calculators, from attached
+// project data), or left to a default. `InputValue` carries the distinction
+// on typed inputs; the ledger records it for the whole calculation.
+//
+// - ledger: the assumption ledger and helpers to fill it
+//
+// Engineer and contractor calculators get a ledger of their declared
+// parameters from `parameter_ledger`; the OEE calculator tracks its own
+// inputs through `ledger::assumption_tracking`.
+// ============================================================================
+
+pub mod ledger;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+pub use ledger::{AssumptionEntry, AssumptionLedger, ImpactLevel};
+
+/// Represents how a value was derived - critical for traceability
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ValueSource {
+    /// User provided this value explicitly
+    Explicit,
+    /// We calculated/inferred this from other inputs
+    Inferred,
+    /// System default was used
+    Default,
+}
+
+impl ValueSource {
+    /// Ledger spelling: `explicit`, `inferred` or `default`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValueSource::Explicit => "explicit",
+            ValueSource::Inferred => "inferred",
+            ValueSource::Default => "default",
+        }
+    }
+}
+
+/// How a value was obtained - the foundation of trust
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InputValue<T> {
+    /// User explicitly provided this value
+    Explicit(T),
+    /// We derived this from other inputs
+    Inferred(T),
+    /// System default fallback
+    Default(T),
+}
+
+impl<T> InputValue<T> {
+    /// Get the actual value, regardless of source
+    pub fn value(&self) -> &T {
+        match self {
+            InputValue::Explicit(v) => v,
+            InputValue::Inferred(v) => v,
+            InputValue::Default(v) => v,
+        }
+    }
+    
+    /// Get the value with ownership transfer
+    pub fn into_value(self) -> T {
+        match self {
+            InputValue::Explicit(v) => v,
+            InputValue::Inferred(v) => v,
+            InputValue::Default(v) => v,
+        }
+    }
+    
+    /// Check if this is an explicit value
+    pub fn is_explicit(&self) -> bool {
+        matches!(self, InputValue::Explicit(_))
+    }
+    
+    /// Check if this is inferred
+    pub fn is_inferred(&self) -> bool {
+        matches!(self, InputValue::Inferred(_))
+    }
+    
+    /// Check if this is a default
+    pub fn is_default(&self) -> bool {
+        matches!(self, InputValue::Default(_))
+    }
+    
+    /// Get the source type as string (for ledger)
+    pub fn source_type(&self) -> &'static str {
+        match self {
+            InputValue::Explicit(_) => "explicit",
+            InputValue::Inferred(_) => "inferred",
+            InputValue::Default(_) => "default",
+        }
+    }
+    
+    /// Get the source without the value
+    pub fn source(&self) -> ValueSource {
+        match self {
+            InputValue::Explicit(_) => ValueSource::Explicit,
+            InputValue::Inferred(_) => ValueSource::Inferred,
+            InputValue::Default(_) => ValueSource::Default,
+        }
+    }
+    
+    /// Map the value to a different type
+    pub fn map<U, F>(self, f: F) -> InputValue<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            InputValue::Explicit(v) => InputValue::Explicit(f(v)),
+            InputValue::Inferred(v) => InputValue::Inferred(f(v)),
+            InputValue::Default(v) => InputValue::Default(f(v)),
+        }
+    }
+}
+
+/// A parameter a calculator declares in its metadata
+#[derive(Debug, Clone, Copy)]
+pub struct DeclaredParameter<'a> {
+    /// Dotted path into the parameters, e.g. `additional.k_factor`
+    pub path: &'a str,
+    pub name: &'a str,
+    pub required: bool,
+    pub default_value: Option<f64>,
+}
+
+fn lookup<'v>(inputs: &'v Value, path: &str) -> Option<&'v Value> {
+    path.split('.')
+        .try_fold(inputs, |value, segment| value.get(segment))
+        .filter(|value| !value.is_null())
+}
+
+/// Where a declared parameter's value came from
+///
+/// `requested` is the parameters as the caller sent them, `resolved` the
+/// parameters the calculator ran on; both as JSON. Parameters set in neither
+/// fall back to their declared default, or are left out when there is none.
+pub fn classify(parameter: &DeclaredParameter, requested: &Value, resolved: &Value) -> Option<InputValue<Value>> {
+    if let Some(value) = lookup(requested, parameter.path) {
+        return Some(InputValue::Explicit(value.clone()));
+    }
+    if let Some(value) = lookup(resolved, parameter.path) {
+        return Some(InputValue::Inferred(value.clone()));
+    }
+    parameter.default_value.map(|value| InputValue::Default(json!(value)))
+}
+
+/// Ledger of a calculator's declared parameters
+///
+/// Required parameters are recorded as high impact, optional ones as low.
+pub fn parameter_ledger<'a>(
+    parameters: impl IntoIterator<Item = DeclaredParameter<'a>>,
+    requested: &Value,
+    resolved: &Value,
+) -> AssumptionLedger {
+    let mut ledger = AssumptionLedger::new();
+    for parameter in parameters {
+        let Some(input) = classify(&parameter, requested, resolved) else {
+            continue;
+        };
+        ledger.add_assumption(AssumptionEntry {
+            assumption_key: parameter.path.to_string(),
+            description_key: parameter.name.to_string(),
+            source: input.source_type().to_string(),
+            value: input.into_value(),
+            timestamp: chrono::Utc::now(),
+            impact: if parameter.required { ImpactLevel::High } else { ImpactLevel::Low },
+            related_assumptions: Vec::new(),
+            origin: None,
+        });
+    }
+    ledger
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn declared(path: &'static str, default_value: Option<f64>) -> DeclaredParameter<'static> {
+        DeclaredParameter { path, name: path, required: default_value.is_none(), default_value }
+    }
+
+    #[test]
+    fn test_parameter_ledger_sources() {
+        let requested = json!({ "dimensions": { "length": 6.0 }, "additional": { "k_factor": null } });
+        let resolved = json!({ "dimensions": { "length": 6.0 }, "additional": { "defective_items": 3.0 } });
+        let parameters = [
+            declared("dimensions.length", None),
+            declared("additional.defective_items", Some(0.0)),
+            declared("additional.k_factor", Some(1.0)),
+            declared("dimensions.width", None),
+        ];
+
+        let ledger = parameter_ledger(parameters, &requested, &resolved);
+        let sources: Vec<&str> = ledger.assumptions.iter().map(|a| a.source.as_str()).collect();
+        assert_eq!(sources, ["explicit", "inferred", "default"]);
+        assert_eq!(ledger.assumptions[2].value, json!(1.0));
+        assert_eq!(ledger.source_statistics.total_count, 3);
+        assert_eq!(ledger.default_values_used().len(), 1);
+    }
+
+    #[test]
+    fn test_mark_default_records_origin() {
+        let requested = json!({ "additional": { "labor_rate": 55.0 } });
+        let mut ledger = parameter_ledger([declared("additional.labor_rate", Some(45.0))], &requested, &requested);
+        assert_eq!(ledger.source_statistics.explicit_count, 1);
+
+        ledger.mark_default("additional.labor_rate", "organization");
+        let entry = &ledger.assumptions[0];
+        assert_eq!(entry.source, "default");
+        assert_eq!(entry.origin.as_deref(), Some("organization"));
+        assert_eq!(entry.value, json!(55.0));
+        assert_eq!(ledger.source_statistics.default_count, 1);
+    }
+
+    #[test]
+    fn test_input_value_source() {
+        assert_eq!(InputValue::Inferred(2).source(), ValueSource::Inferred);
+        assert_eq!(InputValue::Default(2).source().as_str(), InputValue::Default(2).source_type());
+    }
+}
@@ -0,0 +1,133 @@
+// ============================================================================
+// Data-Quality Scoring
+//
+// A 0–100 score for how much a result rests on values the caller actually
+// supplied, together with every deduction that produced it. Saved analyses
+// can then be sorted by trustworthiness without re-reading their
+// assumptions. 100 means nothing was defaulted, inferred or flagged.
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+
+/// Points deducted for one engineering parameter left at its default
+pub const DEFAULT_PARAMETER_POINTS: u8 = 10;
+
+/// One deduction from a perfect score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QualityFactor {
+    /// Translation key, e.g. `quality.default_input`
+    pub code: String,
+    /// Input, assumption or warning the deduction is about
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Points taken off the score
+    pub points: u8,
+}
+
+/// Data-quality score with its contributing factors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataQuality {
+    pub score: u8,
+    /// Largest deductions first
+    pub factors: Vec<QualityFactor>,
+}
+
+impl Default for DataQuality {
+    fn default() -> Self {
+        Self { score: 100, factors: Vec::new() }
+    }
+}
+
+impl DataQuality {
+    /// Record a deduction; zero-point factors are ignored
+    pub fn deduct(&mut self, code: &str, subject: Option<&str>, points: u8) {
+        if points == 0 {
+            return;
+        }
+        self.factors.push(QualityFactor {
+            code: code.to_string(),
+            subject: subject.map(str::to_string),
+            points,
+        });
+        self.factors.sort_by_key(|f| std::cmp::Reverse(f.points));
+        let total: u32 = self.factors.iter().map(|f| f.points as u32).sum();
+        self.score = 100u32.saturating_sub(total) as u8;
+    }
+
+    pub fn is_perfect(&self) -> bool {
+        self.factors.is_empty()
+    }
+}
+
+/// Optional parameters the request left out, so their default was used
+///
+/// `inputs` is the request's parameters as JSON; paths are dotted
+/// (`additional.k_factor`).
+pub fn defaulted_parameters<'a>(
+    parameters: impl IntoIterator<Item = (&'a str, bool)>,
+    inputs: &serde_json::Value,
+) -> Vec<&'a str> {
+    parameters
+        .into_iter()
+        .filter(|(path, has_default)| {
+            *has_default
+                && path
+                    .split('.')
+                    .try_fold(inputs, |value, segment| value.get(segment))
+                    .is_none_or(|value| value.is_null())
+        })
+        .map(|(path, _)| path)
+        .collect()
+}
+
+/// Score for an engineering calculation, `None` when no defaults were used
+pub fn for_defaulted_parameters(defaulted: &[&str]) -> Option<DataQuality> {
+    if defaulted.is_empty() {
+        return None;
+    }
+    let mut quality = DataQuality::default();
+    for path in defaulted {
+        quality.deduct("quality.default_parameter", Some(path), DEFAULT_PARAMETER_POINTS);
+    }
+    Some(quality)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_score_is_bounded_and_sorted() {
+        let mut quality = DataQuality::default();
+        quality.deduct("quality.inferred_input", Some("a"), 5);
+        quality.deduct("quality.default_input", Some("b"), 60);
+        quality.deduct("quality.ignored", None, 0);
+        assert_eq!(quality.score, 35);
+        assert_eq!(quality.factors[0].points, 60);
+        assert_eq!(quality.factors.len(), 2);
+
+        quality.deduct("quality.default_input", Some("c"), 60);
+        assert_eq!(quality.score, 0);
+    }
+
+    #[test]
+    fn test_detects_missing_optional_parameters() {
+        let inputs = json!({
+            "dimensions": { "height": 3.0 },
+            "additional": { "k_factor": null },
+        });
+        let parameters = [
+            ("dimensions.height", true),
+            ("additional.k_factor", true),
+            ("loads.dead_load", true),
+            ("loads.live_load", false),
+        ];
+        let defaulted = defaulted_parameters(parameters, &inputs);
+        assert_eq!(defaulted, vec!["additional.k_factor", "loads.dead_load"]);
+
+        let quality = for_defaulted_parameters(&defaulted).unwrap();
+        assert_eq!(quality.score, 80);
+        assert!(for_defaulted_parameters(&[]).is_none());
+    }
+}
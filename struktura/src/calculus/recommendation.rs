@@ -0,0 +1,403 @@
+// ============================================================================
+// Calculator Recommendation
+//
+// Suggests calculators across all tiers from a free-text description
+// ("8m span, residential floor") and/or a set of known parameter names.
+// Scoring runs entirely on registry metadata - names, descriptions, typical
+// applications and parameter names - so no external service is involved.
+// ============================================================================
+
+use crate::flags::FlagContext;
+use crate::sec::Claims;
+use crate::state::AppState;
+use axum::{extract::State, routing::post, Json, Router};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Default number of recommendations returned
+const DEFAULT_LIMIT: usize = 5;
+/// Hard cap on recommendations returned
+const MAX_LIMIT: usize = 20;
+/// Recommendations below this confidence are dropped
+const MIN_CONFIDENCE: f64 = 0.1;
+
+// Field weights - a hit in the calculator name counts more than a hit in
+// its long-form description
+const WEIGHT_NAME: f64 = 3.0;
+const WEIGHT_APPLICATION: f64 = 2.0;
+const WEIGHT_CATEGORY: f64 = 1.5;
+const WEIGHT_DESCRIPTION: f64 = 1.0;
+const WEIGHT_PARAMETER: f64 = 1.0;
+const WEIGHT_KNOWN_PARAMETER: f64 = 2.0;
+
+/// Words that carry no signal for matching
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "how", "what", "much", "many", "need", "want",
+    "calculate", "calculator", "compute", "estimate", "my", "our", "new", "of",
+];
+
+/// Everyday words mapped to the vocabulary used in calculator metadata
+const SYNONYMS: &[(&str, &[&str])] = &[
+    ("span", &["beam", "length"]),
+    ("floor", &["slab", "flooring", "joist"]),
+    ("roof", &["truss", "roofing"]),
+    ("wall", &["framing", "drywall", "retaining"]),
+    ("footing", &["foundation"]),
+    ("soil", &["bearing", "geotechnical", "settlement"]),
+    ("pipe", &["piping", "plumbing"]),
+    ("bid", &["bidding", "pricing"]),
+    ("schedule", &["scheduling", "critical"]),
+    ("cost", &["estimation", "budget"]),
+    ("factory", &["production", "capacity"]),
+];
+
+/// Calculator tier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Tier {
+    Beginner,
+    Engineer,
+    Contractor,
+}
+
+impl Tier {
+    /// Lowercase name, as used in routes and serialized values
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Tier::Beginner => "beginner",
+            Tier::Engineer => "engineer",
+            Tier::Contractor => "contractor",
+        }
+    }
+}
+
+impl std::str::FromStr for Tier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "beginner" => Ok(Tier::Beginner),
+            "engineer" => Ok(Tier::Engineer),
+            "contractor" => Ok(Tier::Contractor),
+            other => Err(format!("Unknown tier '{}'", other)),
+        }
+    }
+}
+
+/// Recommendation request
+#[derive(Debug, Default, Deserialize)]
+pub struct RecommendationRequest {
+    /// Free-text description of the problem
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Parameters the user already knows, keyed by name (values are ignored)
+    #[serde(default)]
+    pub parameters: HashMap<String, serde_json::Value>,
+    /// Restrict recommendations to these tiers
+    #[serde(default)]
+    pub tiers: Option<Vec<Tier>>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Single calculator recommendation
+#[derive(Debug, Clone, Serialize)]
+pub struct CalculatorRecommendation {
+    pub tier: Tier,
+    pub calculator_id: String,
+    pub name: String,
+    pub category: String,
+    /// 0.0 - 1.0
+    pub confidence: f64,
+    pub matched_terms: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecommendationResponse {
+    pub recommendations: Vec<CalculatorRecommendation>,
+}
+
+/// Searchable view of one calculator's metadata
+#[derive(Debug, Clone)]
+pub struct CalculatorDocument {
+    pub tier: Tier,
+    pub id: String,
+    pub name: String,
+    pub category: String,
+    pub description: String,
+    pub applications: Vec<String>,
+    pub parameters: Vec<String>,
+}
+
+impl CalculatorDocument {
+    fn parameters_from<'a>(names: impl Iterator<Item = (&'a str, &'a str)>) -> Vec<String> {
+        names
+            .flat_map(|(name, path)| [name.to_string(), path.rsplit('.').next().unwrap_or(path).to_string()])
+            .collect()
+    }
+}
+
+/// Collect documents for every registered calculator the caller is rolled
+/// out to
+pub async fn documents(state: &AppState, ctx: &FlagContext) -> Vec<CalculatorDocument> {
+    let mut docs = Vec::new();
+
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Beginner, ctx).await;
+    for calc in state.calculators_beginner.all().into_iter().filter(|c| !hidden.contains(c.id())) {
+        let meta = calc.metadata();
+        docs.push(CalculatorDocument {
+            tier: Tier::Beginner,
+            parameters: CalculatorDocument::parameters_from(
+                meta.parameters.iter().map(|p| (p.name.as_str(), p.path.as_str())),
+            ),
+            id: meta.id,
+            name: meta.name,
+            category: meta.category,
+            description: meta.description,
+            applications: Vec::new(),
+        });
+    }
+
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Engineer, ctx).await;
+    for calc in state.calculators_engineer.all().into_iter().filter(|c| !hidden.contains(c.id())) {
+        let meta = calc.metadata();
+        docs.push(CalculatorDocument {
+            tier: Tier::Engineer,
+            parameters: CalculatorDocument::parameters_from(
+                meta.parameters.iter().map(|p| (p.name.as_str(), p.path.as_str())),
+            ),
+            id: meta.id,
+            name: meta.name,
+            category: meta.category,
+            description: meta.description,
+            applications: meta.typical_applications,
+        });
+    }
+
+    let hidden = state.flags.hidden_calculators(&state.pool, Tier::Contractor, ctx).await;
+    for calc in state.calculators_contractor.all().into_iter().filter(|c| !hidden.contains(c.id())) {
+        let meta = calc.metadata();
+        docs.push(CalculatorDocument {
+            tier: Tier::Contractor,
+            parameters: CalculatorDocument::parameters_from(
+                meta.parameters.iter().map(|p| (p.name.as_str(), p.path.as_str())),
+            ),
+            id: meta.id,
+            name: meta.name,
+            category: meta.category,
+            description: meta.description,
+            applications: meta.typical_applications,
+        });
+    }
+
+    docs
+}
+
+// ============================================================================
+// SCORING
+// ============================================================================
+
+/// Lowercase, split on non-alphanumerics and drop numbers, short words and
+/// stopwords. "8m span" -> ["span"]
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .map(|w| w.trim_start_matches(|c: char| c.is_ascii_digit()))
+        .filter(|w| w.len() > 2 && !STOPWORDS.contains(w))
+        .map(|w| w.strip_suffix('s').filter(|s| s.len() > 3).unwrap_or(w).to_string())
+        .collect()
+}
+
+/// Expand query terms with their synonyms, keeping the original term first
+fn expand(terms: &[String]) -> Vec<Vec<String>> {
+    terms
+        .iter()
+        .map(|term| {
+            let mut group = vec![term.clone()];
+            if let Some((_, synonyms)) = SYNONYMS.iter().find(|(word, _)| word == term) {
+                group.extend(synonyms.iter().map(|s| s.to_string()));
+            }
+            group
+        })
+        .collect()
+}
+
+fn words_match(term: &str, word: &str) -> bool {
+    term == word || (term.len() >= 4 && word.len() >= 4 && (word.starts_with(term) || term.starts_with(word)))
+}
+
+fn field_matches(term: &str, words: &HashSet<String>) -> bool {
+    words.iter().any(|w| words_match(term, w))
+}
+
+/// Score a calculator against the query. Returns confidence and the terms
+/// that contributed to it.
+pub fn score(doc: &CalculatorDocument, query_terms: &[String], known_parameters: &[String]) -> (f64, Vec<String>) {
+    let name_words: HashSet<String> = tokenize(&doc.name).into_iter().chain(tokenize(&doc.id)).collect();
+    let application_words: HashSet<String> = doc.applications.iter().flat_map(|a| tokenize(a)).collect();
+    let category_words: HashSet<String> = tokenize(&doc.category).into_iter().collect();
+    let description_words: HashSet<String> = tokenize(&doc.description).into_iter().collect();
+    let parameter_words: HashSet<String> = doc.parameters.iter().flat_map(|p| tokenize(p)).collect();
+
+    let mut total = 0.0;
+    let mut matched = Vec::new();
+
+    for group in expand(query_terms) {
+        let best = group
+            .iter()
+            .map(|term| {
+                if field_matches(term, &name_words) {
+                    WEIGHT_NAME
+                } else if field_matches(term, &application_words) {
+                    WEIGHT_APPLICATION
+                } else if field_matches(term, &category_words) {
+                    WEIGHT_CATEGORY
+                } else if field_matches(term, &description_words) {
+                    WEIGHT_DESCRIPTION
+                } else if field_matches(term, &parameter_words) {
+                    WEIGHT_PARAMETER
+                } else {
+                    0.0
+                }
+            })
+            .fold(0.0, f64::max);
+
+        if best > 0.0 {
+            total += best;
+            matched.push(group[0].clone());
+        }
+    }
+
+    for param in known_parameters {
+        let param_terms = tokenize(param);
+        if !param_terms.is_empty() && param_terms.iter().all(|t| field_matches(t, &parameter_words)) {
+            total += WEIGHT_KNOWN_PARAMETER;
+            matched.push(param.clone());
+        }
+    }
+
+    let max_score = query_terms.len() as f64 * WEIGHT_NAME + known_parameters.len() as f64 * WEIGHT_KNOWN_PARAMETER;
+    let confidence = if max_score > 0.0 { (total / max_score).min(1.0) } else { 0.0 };
+
+    (confidence, matched)
+}
+
+/// Rank documents for a request
+pub fn recommend(docs: &[CalculatorDocument], request: &RecommendationRequest) -> Vec<CalculatorRecommendation> {
+    let query_terms = request.query.as_deref().map(tokenize).unwrap_or_default();
+    let known_parameters: Vec<String> = request.parameters.keys().cloned().collect();
+    let limit = request.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let mut recommendations: Vec<CalculatorRecommendation> = docs
+        .iter()
+        .filter(|doc| request.tiers.as_ref().is_none_or(|tiers| tiers.contains(&doc.tier)))
+        .filter_map(|doc| {
+            let (confidence, matched_terms) = score(doc, &query_terms, &known_parameters);
+            (confidence >= MIN_CONFIDENCE).then(|| CalculatorRecommendation {
+                tier: doc.tier,
+                calculator_id: doc.id.clone(),
+                name: doc.name.clone(),
+                category: doc.category.clone(),
+                confidence: (confidence * 100.0).round() / 100.0,
+                matched_terms,
+            })
+        })
+        .collect();
+
+    recommendations.sort_by(|a, b| {
+        b.confidence
+            .total_cmp(&a.confidence)
+            .then_with(|| a.calculator_id.cmp(&b.calculator_id))
+    });
+    recommendations.truncate(limit);
+    recommendations
+}
+
+// ============================================================================
+// HANDLERS
+// ============================================================================
+
+/// POST /api/v1/calculus/recommend
+/// Suggest calculators for a description or a set of known parameters
+async fn recommend_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Option<Claims>,
+    Json(payload): Json<RecommendationRequest>,
+) -> Json<RecommendationResponse> {
+    let ctx = FlagContext::for_caller(&state.tenants, claims.as_ref());
+    let docs = documents(&state, &ctx).await;
+    Json(RecommendationResponse {
+        recommendations: recommend(&docs, &payload),
+    })
+}
+
+/// Create the recommendation router
+///
+/// This router should be nested under `/api/v1/calculus/recommend`
+pub fn create_router() -> Router<Arc<AppState>> {
+    Router::new().route("/", post(recommend_handler))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(tier: Tier, id: &str, name: &str, description: &str, params: &[&str]) -> CalculatorDocument {
+        CalculatorDocument {
+            tier,
+            id: id.to_string(),
+            name: name.to_string(),
+            category: "structural".to_string(),
+            description: description.to_string(),
+            applications: vec![],
+            parameters: params.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    fn sample_docs() -> Vec<CalculatorDocument> {
+        vec![
+            doc(Tier::Engineer, "beam_design", "Steel Beam Design", "Design steel beam for flexure", &["length", "dead_load"]),
+            doc(Tier::Beginner, "deck", "Deck Builder", "Calculate decking boards and joists", &["width", "length", "height"]),
+            doc(Tier::Contractor, "bid_pricing", "Bid Pricing", "Price a bid", &["total_cost"]),
+        ]
+    }
+
+    #[test]
+    fn test_tokenize_drops_numbers_and_stopwords() {
+        assert_eq!(tokenize("8m span, for the residential floors"), vec!["span", "residential", "floor"]);
+    }
+
+    #[test]
+    fn test_span_query_ranks_beam_first() {
+        let request = RecommendationRequest {
+            query: Some("8m span residential floor".to_string()),
+            ..Default::default()
+        };
+        let results = recommend(&sample_docs(), &request);
+        assert_eq!(results[0].calculator_id, "beam_design");
+        assert!(results[0].confidence > 0.0 && results[0].confidence <= 1.0);
+        assert!(results.iter().all(|r| r.calculator_id != "bid_pricing"));
+    }
+
+    #[test]
+    fn test_known_parameters_and_tier_filter() {
+        let mut parameters = HashMap::new();
+        parameters.insert("width".to_string(), serde_json::json!(4.0));
+        parameters.insert("height".to_string(), serde_json::json!(0.5));
+        let request = RecommendationRequest {
+            parameters,
+            tiers: Some(vec![Tier::Beginner]),
+            ..Default::default()
+        };
+        let results = recommend(&sample_docs(), &request);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].calculator_id, "deck");
+        assert_eq!(results[0].confidence, 1.0);
+    }
+
+    #[test]
+    fn test_empty_request_returns_nothing() {
+        assert!(recommend(&sample_docs(), &RecommendationRequest::default()).is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+// ============================================================================
+// Recommendation Rules
+//
+// Recommendations are data, not code: each rule is a set of conditions on a
+// calculation's named results ("facts") and the message key and severity to
+// emit when they all hold. Calculators publish facts under a scope and ask
+// the rule set what to recommend.
+//
+// Built-in rules live in fixtures/recommendation_rules.json. A deployment
+// can point RECOMMENDATION_RULES at a JSON file of its own rules: a rule with
+// a built-in id replaces it in place (`"enabled": false` switches it off),
+// any other rule is appended.
+//
+// Within a `group` only the first matching rule fires, which is how an
+// if / else-if chain is written as data.
+// ============================================================================
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+static BUILTIN: &str = include_str!("../../fixtures/recommendation_rules.json");
+
+/// Named values a calculator exposes to the rules
+pub type Facts = HashMap<&'static str, f64>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Comparison {
+    fn holds(self, left: f64, right: f64) -> bool {
+        match self {
+            Comparison::Lt => left < right,
+            Comparison::Le => left <= right,
+            Comparison::Gt => left > right,
+            Comparison::Ge => left >= right,
+            Comparison::Eq => left == right,
+            Comparison::Ne => left != right,
+        }
+    }
+}
+
+/// Right-hand side of a condition: a constant or another fact
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Operand {
+    #[serde(rename = "value")]
+    Value(f64),
+    #[serde(rename = "fact_value")]
+    Fact(String),
+}
+
+/// `fact op value`; false when a fact is missing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    pub fact: String,
+    pub op: Comparison,
+    #[serde(flatten)]
+    pub operand: Operand,
+}
+
+impl Condition {
+    fn holds(&self, facts: &Facts) -> bool {
+        let right = match &self.operand {
+            Operand::Value(value) => Some(*value),
+            Operand::Fact(name) => facts.get(name.as_str()).copied(),
+        };
+        match (facts.get(self.fact.as_str()), right) {
+            (Some(left), Some(right)) => self.op.holds(*left, right),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecommendationRule {
+    /// Stable id, used to override built-in rules
+    pub id: String,
+    /// Calculator or analysis publishing the facts, e.g. `capacity_planning`
+    pub scope: String,
+    /// Only the first matching rule of a group fires
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// All must hold; empty always matches
+    #[serde(default)]
+    pub when: Vec<Condition>,
+    /// Translation key
+    pub message_key: String,
+    /// English text; `{fact}` placeholders are filled to one decimal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub severity: Severity,
+    #[serde(default = "enabled")]
+    pub enabled: bool,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+impl RecommendationRule {
+    fn matches(&self, scope: &str, facts: &Facts) -> bool {
+        self.enabled && self.scope == scope && self.when.iter().all(|c| c.holds(facts))
+    }
+
+    fn render(&self, facts: &Facts) -> Recommendation {
+        let mut message = self.message.clone().unwrap_or_else(|| self.message_key.clone());
+        let mut params = HashMap::new();
+        for (name, value) in facts {
+            let placeholder = format!("{{{}}}", name);
+            if message.contains(&placeholder) {
+                message = message.replace(&placeholder, &format!("{:.1}", value));
+            }
+            if self.when.iter().any(|c| c.fact == *name || c.operand == Operand::Fact(name.to_string())) {
+                params.insert(name.to_string(), *value);
+            }
+        }
+        Recommendation {
+            rule_id: self.id.clone(),
+            message_key: self.message_key.clone(),
+            message,
+            severity: self.severity,
+            params,
+        }
+    }
+}
+
+/// A fired rule
+#[derive(Debug, Clone, Serialize)]
+pub struct Recommendation {
+    pub rule_id: String,
+    pub message_key: String,
+    pub message: String,
+    pub severity: Severity,
+    /// Facts the rule's conditions looked at
+    pub params: HashMap<String, f64>,
+}
+
+impl Recommendation {
+    /// Flatten into the legacy `recommendations` field
+    pub fn messages(recommendations: &[Recommendation]) -> Vec<String> {
+        recommendations.iter().map(|r| r.message.clone()).collect()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    rules: Vec<RecommendationRule>,
+}
+
+impl RuleSet {
+    pub fn builtin() -> Self {
+        Self { rules: serde_json::from_str(BUILTIN).expect("built-in recommendation rules are valid") }
+    }
+
+    /// Built-in rules with the deployment's file, if RECOMMENDATION_RULES is set
+    ///
+    /// An unreadable or invalid file is logged and the built-ins are kept.
+    pub fn from_env() -> Self {
+        let mut set = Self::builtin();
+        let Ok(path) = std::env::var("RECOMMENDATION_RULES") else {
+            return set;
+        };
+        let loaded = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|raw| serde_json::from_str::<Vec<RecommendationRule>>(&raw).map_err(|e| e.to_string()));
+        match loaded {
+            Ok(rules) => set.extend(rules),
+            Err(e) => tracing::warn!(%path, error = %e, "ignoring RECOMMENDATION_RULES file"),
+        }
+        set
+    }
+
+    /// Replace rules with matching ids, append the rest
+    pub fn extend(&mut self, rules: Vec<RecommendationRule>) {
+        for rule in rules {
+            match self.rules.iter_mut().find(|r| r.id == rule.id) {
+                Some(existing) => *existing = rule,
+                None => self.rules.push(rule),
+            }
+        }
+    }
+
+    pub fn rules(&self) -> &[RecommendationRule] {
+        &self.rules
+    }
+
+    /// Recommendations for `facts`, in rule order
+    pub fn evaluate(&self, scope: &str, facts: &Facts) -> Vec<Recommendation> {
+        let mut fired_groups = HashSet::new();
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(scope, facts))
+            .filter(|rule| rule.group.as_deref().is_none_or(|g| fired_groups.insert(g)))
+            .map(|rule| rule.render(facts))
+            .collect()
+    }
+}
+
+lazy_static! {
+    static ref RULES: RuleSet = RuleSet::from_env();
+}
+
+/// Evaluate the process-wide rule set
+pub fn evaluate(scope: &str, facts: &Facts) -> Vec<Recommendation> {
+    RULES.evaluate(scope, facts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn facts(pairs: &[(&'static str, f64)]) -> Facts {
+        pairs.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_groups_fire_first_match_only() {
+        let rules = RuleSet::builtin();
+        let fired = rules.evaluate("oee.bottleneck", &facts(&[("availability", 0.5), ("performance", 0.6), ("quality", 0.9)]));
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].message_key, "bottleneck.action.reduce_downtime");
+        assert_eq!(fired[0].params.len(), 3);
+
+        let fired = rules.evaluate("oee.bottleneck", &facts(&[("availability", 0.9), ("performance", 0.95), ("quality", 0.8)]));
+        assert_eq!(fired[0].message_key, "bottleneck.action.improve_quality");
+    }
+
+    #[test]
+    fn test_messages_fill_placeholders() {
+        let fired = RuleSet::builtin().evaluate("capacity_planning", &facts(&[("actual_utilization", 62.345), ("quality_yield", 99.0)]));
+        assert_eq!(Recommendation::messages(&fired), ["Low utilization (62.3%). Optimize scheduling or reduce assets."]);
+        assert!(RuleSet::builtin().evaluate("capacity_planning", &facts(&[])).is_empty());
+    }
+
+    #[test]
+    fn test_deployment_rules_override_and_extend() {
+        let mut rules = RuleSet::builtin();
+        let deployment: Vec<RecommendationRule> = serde_json::from_value(json!([
+            {
+                "id": "capacity.low_yield", "scope": "capacity_planning", "enabled": false,
+                "message_key": "recommendations.capacity.quality_program", "severity": "medium"
+            },
+            {
+                "id": "acme.capacity.night_shift", "scope": "capacity_planning",
+                "when": [{ "fact": "actual_utilization", "op": "ge", "value": 85.0 }],
+                "message_key": "acme.night_shift", "message": "Open the night shift", "severity": "low"
+            }
+        ]))
+        .unwrap();
+        let count = rules.rules().len();
+        rules.extend(deployment);
+        assert_eq!(rules.rules().len(), count + 1);
+
+        let fired = rules.evaluate("capacity_planning", &facts(&[("actual_utilization", 92.0), ("quality_yield", 80.0)]));
+        let ids: Vec<_> = fired.iter().map(|r| r.rule_id.as_str()).collect();
+        assert_eq!(ids, ["capacity.high_utilization", "acme.capacity.night_shift"]);
+    }
+}
@@ -0,0 +1,111 @@
+// ============================================================================
+// Result Sanitation
+//
+// Every registry passes calculator output through here before it is served.
+// A NaN or infinite result value (which serde_json would quietly write as
+// `null`) fails the calculation with a numerical error; a finite value
+// beyond MAX_MAGNITUDE is kept but flagged, since it nearly always means a
+// unit or domain slip. Both are logged with the calculator and its inputs
+// so the case can be replayed.
+// ============================================================================
+
+use crate::calculus::recommendation::Tier;
+use serde::Serialize;
+
+/// Largest result magnitude served without a warning
+pub const MAX_MAGNITUDE: f64 = 1e15;
+
+/// Structured warning code attached to implausibly large results
+pub const IMPLAUSIBLE_MAGNITUDE_CODE: &str = "result.implausible_magnitude";
+
+/// Problems found in one response's result values
+#[derive(Debug, Default, PartialEq)]
+pub struct Findings {
+    /// Labels of NaN or infinite values
+    pub non_finite: Vec<String>,
+    /// Finite values larger than MAX_MAGNITUDE, by label
+    pub implausible: Vec<(String, f64)>,
+}
+
+impl Findings {
+    /// Inspect labelled result values
+    pub fn inspect<'a>(values: impl IntoIterator<Item = (&'a str, f64)>) -> Self {
+        let mut findings = Self::default();
+        for (label, value) in values {
+            if !value.is_finite() {
+                findings.non_finite.push(label.to_string());
+            } else if value.abs() > MAX_MAGNITUDE {
+                findings.implausible.push((label.to_string(), value));
+            }
+        }
+        findings
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.non_finite.is_empty() && self.implausible.is_empty()
+    }
+
+    /// Error message when any value is unusable
+    pub fn error(&self) -> Option<String> {
+        (!self.non_finite.is_empty()).then(|| {
+            format!(
+                "Calculation produced non-finite values for: {}. Check inputs for zero or extreme values.",
+                self.non_finite.join(", ")
+            )
+        })
+    }
+
+    /// One warning message per implausible value
+    pub fn warnings(&self) -> Vec<String> {
+        self.implausible
+            .iter()
+            .map(|(label, value)| {
+                format!("{} = {:.3e} is implausibly large; check input units and magnitudes", label, value)
+            })
+            .collect()
+    }
+
+    /// Log the offending calculator with the inputs that triggered it
+    pub fn log(&self, tier: Tier, calculator_id: &str, inputs: &impl Serialize) {
+        if self.is_clean() {
+            return;
+        }
+        let inputs = serde_json::to_string(inputs).unwrap_or_else(|e| format!("<unserializable: {}>", e));
+        tracing::warn!(
+            tier = tier.as_str(),
+            calculator = calculator_id,
+            non_finite = %self.non_finite.join(", "),
+            implausible = %self.implausible.iter().map(|(l, v)| format!("{}={:e}", l, v)).collect::<Vec<_>>().join(", "),
+            %inputs,
+            "calculation produced suspicious values"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flags_non_finite_and_absurd_values() {
+        let findings = Findings::inspect([
+            ("Area", 12.5),
+            ("Ratio", f64::NAN),
+            ("Load", f64::NEG_INFINITY),
+            ("Capacity", -2.0e21),
+            ("Cost", MAX_MAGNITUDE),
+        ]);
+
+        assert_eq!(findings.non_finite, vec!["Ratio", "Load"]);
+        assert_eq!(findings.implausible, vec![("Capacity".to_string(), -2.0e21)]);
+        assert!(findings.error().unwrap().contains("Ratio, Load"));
+        assert_eq!(findings.warnings().len(), 1);
+    }
+
+    #[test]
+    fn test_ordinary_results_are_clean() {
+        let findings = Findings::inspect([("Volume", 3.0), ("Bags", 0.0), ("Delta", -1.0e9)]);
+        assert!(findings.is_clean());
+        assert!(findings.error().is_none());
+    }
+}
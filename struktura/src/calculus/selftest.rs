@@ -0,0 +1,339 @@
+// ============================================================================
+// Golden-Value Self-Check
+//
+// Every calculator is run against a stored input and the result values it
+// produced when the fixture was recorded. Any value that moves beyond
+// floating-point noise is reported as drift, so a deployment can prove no
+// calculator silently changed its numbers. Fixtures are recorded from the
+// demo examples with `cargo run --bin generate_golden`; re-record them (and
+// add a formula changelog entry) whenever a change is intentional.
+// ============================================================================
+
+use crate::calculus::beginner::{BeginnerParameters, BeginnerRegistry};
+use crate::calculus::contractor::{ContractingParameters, ContractingRegistry};
+use crate::calculus::demo::DemoCatalogue;
+use crate::calculus::engineer::{EngineeringParameters, EngineeringRegistry};
+use crate::calculus::recommendation::Tier;
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+/// Fixture file, relative to the crate root
+pub const GOLDEN_PATH: &str = "fixtures/golden.json";
+
+static GOLDEN: &str = include_str!("../../fixtures/golden.json");
+
+/// Relative difference tolerated before a value counts as drift
+const REL_TOLERANCE: f64 = 1e-9;
+/// Absolute difference tolerated for values near zero
+const ABS_TOLERANCE: f64 = 1e-12;
+
+/// A calculator input and the result values it must keep producing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldenFixture {
+    pub tier: Tier,
+    pub calculator_id: String,
+    pub parameters: Value,
+    /// Result value by label
+    pub results: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Pass,
+    Drift,
+    Error,
+    /// Registered calculator without a fixture, e.g. one added since recording
+    NoFixture,
+    /// Fixture for a calculator that is no longer registered
+    Unregistered,
+}
+
+/// One result value that no longer matches its fixture
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Drift {
+    pub label: String,
+    pub expected: Option<f64>,
+    pub actual: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CalculatorCheck {
+    pub tier: Tier,
+    pub calculator_id: String,
+    pub status: CheckStatus,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub drift: Vec<Drift>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SelfTestReport {
+    /// True when nothing drifted or errored
+    pub passed: bool,
+    pub version: String,
+    pub checked: usize,
+    pub drifted: usize,
+    pub errored: usize,
+    pub without_fixture: usize,
+    pub calculators: Vec<CalculatorCheck>,
+}
+
+/// The fixtures compiled into this build
+pub fn fixtures() -> Vec<GoldenFixture> {
+    serde_json::from_str(GOLDEN).unwrap_or_default()
+}
+
+/// Result values by label; repeated labels get a `#n` suffix
+fn result_values(response: &Value) -> BTreeMap<String, f64> {
+    let mut values = BTreeMap::new();
+    let items = response.get("results").and_then(Value::as_array).into_iter().flatten();
+    for item in items {
+        let (Some(label), Some(value)) = (
+            item.get("label").and_then(Value::as_str),
+            item.get("value").and_then(Value::as_f64),
+        ) else {
+            continue;
+        };
+
+        let mut key = label.to_string();
+        let mut n = 1;
+        while values.contains_key(&key) {
+            n += 1;
+            key = format!("{label}#{n}");
+        }
+        values.insert(key, value);
+    }
+    values
+}
+
+fn matches(expected: f64, actual: f64) -> bool {
+    let diff = (expected - actual).abs();
+    diff <= ABS_TOLERANCE || diff <= REL_TOLERANCE * expected.abs().max(actual.abs())
+}
+
+/// Every value that is missing, new, or moved beyond tolerance
+pub fn compare(expected: &BTreeMap<String, f64>, actual: &BTreeMap<String, f64>) -> Vec<Drift> {
+    let labels: std::collections::BTreeSet<&String> = expected.keys().chain(actual.keys()).collect();
+    labels
+        .into_iter()
+        .filter_map(|label| {
+            let (e, a) = (expected.get(label).copied(), actual.get(label).copied());
+            match (e, a) {
+                (Some(x), Some(y)) if matches(x, y) => None,
+                _ => Some(Drift { label: label.clone(), expected: e, actual: a }),
+            }
+        })
+        .collect()
+}
+
+/// Run one calculator the way its `/calculate` endpoint would
+pub(crate) async fn execute(
+    beginner: &BeginnerRegistry,
+    engineer: &EngineeringRegistry,
+    contractor: &ContractingRegistry,
+    tier: Tier,
+    calculator_id: &str,
+    parameters: Value,
+) -> Result<Option<Value>, String> {
+    fn json<T: Serialize>(response: T) -> Result<Option<Value>, String> {
+        serde_json::to_value(response).map(Some).map_err(|e| e.to_string())
+    }
+
+    match tier {
+        Tier::Beginner => {
+            let Ok(calc) = beginner.find(calculator_id) else { return Ok(None) };
+            let params: BeginnerParameters = serde_json::from_value(parameters).map_err(|e| e.to_string())?;
+            json(beginner.execute(&calc, params).await.map_err(|e| e.to_string())?)
+        }
+        Tier::Engineer => {
+            let Ok(calc) = engineer.find(calculator_id) else { return Ok(None) };
+            let params: EngineeringParameters = serde_json::from_value(parameters).map_err(|e| e.to_string())?;
+            json(engineer.execute(&calc, params).await.map_err(|e| e.to_string())?)
+        }
+        Tier::Contractor => {
+            let Ok(calc) = contractor.find(calculator_id) else { return Ok(None) };
+            let params: ContractingParameters = serde_json::from_value(parameters).map_err(|e| e.to_string())?;
+            json(contractor.execute(&calc, params).await.map_err(|e| e.to_string())?)
+        }
+    }
+}
+
+/// Check every registered calculator against the given fixtures
+pub async fn run(
+    beginner: &BeginnerRegistry,
+    engineer: &EngineeringRegistry,
+    contractor: &ContractingRegistry,
+    fixtures: &[GoldenFixture],
+) -> SelfTestReport {
+    let mut calculators = Vec::new();
+    let mut covered = HashSet::new();
+
+    for fixture in fixtures {
+        covered.insert((fixture.tier, fixture.calculator_id.clone()));
+        let outcome = execute(
+            beginner,
+            engineer,
+            contractor,
+            fixture.tier,
+            &fixture.calculator_id,
+            fixture.parameters.clone(),
+        )
+        .await;
+
+        let (status, drift, error) = match outcome {
+            Ok(None) => (CheckStatus::Unregistered, Vec::new(), None),
+            Ok(Some(response)) => {
+                let drift = compare(&fixture.results, &result_values(&response));
+                let status = if drift.is_empty() { CheckStatus::Pass } else { CheckStatus::Drift };
+                (status, drift, None)
+            }
+            Err(e) => (CheckStatus::Error, Vec::new(), Some(e)),
+        };
+        calculators.push(CalculatorCheck {
+            tier: fixture.tier,
+            calculator_id: fixture.calculator_id.clone(),
+            status,
+            drift,
+            error,
+        });
+    }
+
+    let registered: Vec<(Tier, String)> = beginner
+        .all()
+        .iter()
+        .map(|c| (Tier::Beginner, c.id().to_string()))
+        .chain(engineer.all().iter().map(|c| (Tier::Engineer, c.id().to_string())))
+        .chain(contractor.all().iter().map(|c| (Tier::Contractor, c.id().to_string())))
+        .collect();
+    for (tier, calculator_id) in registered {
+        if !covered.contains(&(tier, calculator_id.clone())) {
+            calculators.push(CalculatorCheck {
+                tier,
+                calculator_id,
+                status: CheckStatus::NoFixture,
+                drift: Vec::new(),
+                error: None,
+            });
+        }
+    }
+
+    let count = |status| calculators.iter().filter(|c| c.status == status).count();
+    let drifted = count(CheckStatus::Drift);
+    let errored = count(CheckStatus::Error) + count(CheckStatus::Unregistered);
+
+    SelfTestReport {
+        passed: drifted == 0 && errored == 0,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        checked: fixtures.len(),
+        drifted,
+        errored,
+        without_fixture: count(CheckStatus::NoFixture),
+        calculators,
+    }
+}
+
+/// Record fixtures from the demo examples that produced a response
+pub async fn record(
+    beginner: &BeginnerRegistry,
+    engineer: &EngineeringRegistry,
+    contractor: &ContractingRegistry,
+) -> Vec<GoldenFixture> {
+    DemoCatalogue::build(beginner, engineer, contractor)
+        .await
+        .examples()
+        .iter()
+        .filter_map(|example| {
+            let response = example.response.as_ref()?;
+            Some(GoldenFixture {
+                tier: example.tier,
+                calculator_id: example.calculator_id.clone(),
+                parameters: example.request.get("parameters")?.clone(),
+                results: result_values(response),
+            })
+        })
+        .collect()
+}
+
+/// GET /api/v1/admin/selftest
+/// Run every calculator against the golden fixtures (admin only)
+pub async fn selftest_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<SelfTestReport>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+
+    let report = run(
+        &state.calculators_beginner,
+        &state.calculators_engineer,
+        &state.calculators_contractor,
+        &fixtures(),
+    )
+    .await;
+
+    let outcome = if report.passed { "Passed" } else { "Drift detected" };
+    sec::log_security_event("SELFTEST", Some(&claims.username), None, outcome);
+    Ok(Json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compare_reports_moved_missing_and_new_values() {
+        let expected = BTreeMap::from([
+            ("Area".to_string(), 12.5),
+            ("Volume".to_string(), 3.0),
+            ("Cost".to_string(), 0.0),
+        ]);
+        let actual = BTreeMap::from([
+            ("Area".to_string(), 12.5 + 1e-12),
+            ("Volume".to_string(), 3.1),
+            ("Bags".to_string(), 4.0),
+        ]);
+
+        let labels: Vec<String> = compare(&expected, &actual).into_iter().map(|d| d.label).collect();
+        assert_eq!(labels, vec!["Bags", "Cost", "Volume"]);
+        assert!(compare(&expected, &expected).is_empty());
+    }
+
+    #[test]
+    fn test_repeated_labels_are_kept_apart() {
+        let response = json!({ "results": [
+            { "label": "Load", "value": 1.0 },
+            { "label": "Load", "value": 2.0 },
+        ]});
+        let values = result_values(&response);
+        assert_eq!(values.get("Load"), Some(&1.0));
+        assert_eq!(values.get("Load#2"), Some(&2.0));
+    }
+
+    /// Fails when a calculator's numbers change; if intended, re-record with
+    /// `cargo run --bin generate_golden` and add a formula changelog entry
+    #[tokio::test]
+    async fn test_calculators_match_golden_fixtures() {
+        let beginner = crate::calculus::beginner::create_default_registry();
+        let engineer = crate::calculus::engineer::create_default_registry();
+        let contractor = crate::calculus::contractor::create_default_registry();
+
+        let report = run(&beginner, &engineer, &contractor, &fixtures()).await;
+        let failures: Vec<String> = report
+            .calculators
+            .iter()
+            .filter(|c| !matches!(c.status, CheckStatus::Pass | CheckStatus::NoFixture))
+            .map(|c| format!("{}/{}: {:?} {:?} {:?}", c.tier.as_str(), c.calculator_id, c.status, c.drift, c.error))
+            .collect();
+
+        assert!(report.checked > 0, "no golden fixtures compiled in");
+        assert!(failures.is_empty(), "golden drift:\n{}", failures.join("\n"));
+        assert!(report.passed);
+    }
+}
@@ -0,0 +1,220 @@
+// ============================================================================
+// Strict Parameter Checking
+//
+// Calculators fall back to defaults for anything they don't find, so a
+// misspelled key (`dead_laod`) quietly produces a plausible but wrong result.
+// With `?strict=true` a calculate request is rejected instead when its
+// parameters hold a key the calculator doesn't read: a field the typed
+// parameter model has no slot for, or a `dimensions` / `additional` /
+// `extended_parameters` entry the calculator's metadata doesn't declare.
+// Without the flag nothing changes.
+// ============================================================================
+
+use crate::calculus::beginner::BeginnerParameters;
+use crate::calculus::contractor::ContractingParameters;
+use crate::calculus::engineer::EngineeringParameters;
+use crate::calculus::recommendation::Tier;
+use crate::state::AppState;
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Query, Request, State},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Largest body checked; matches axum's default body limit
+const MAX_CHECKED_BYTES: usize = 2 * 1024 * 1024;
+
+/// Map-valued parameter fields whose keys are calculator inputs
+const DECLARED_MAPS: &[&str] = &["dimensions", "additional", "extended_parameters"];
+
+#[derive(Debug, Default, Deserialize)]
+struct StrictQuery {
+    #[serde(default)]
+    strict: bool,
+}
+
+#[derive(Clone)]
+pub struct StrictCheck {
+    state: Arc<AppState>,
+    tier: Tier,
+}
+
+impl StrictCheck {
+    pub fn new(state: Arc<AppState>, tier: Tier) -> Self {
+        Self { state, tier }
+    }
+
+    /// Declared parameter paths; `None` for calculators this tier doesn't have
+    fn declared(&self, calculator_id: &str) -> Option<Vec<String>> {
+        let state = &self.state;
+        let paths = match self.tier {
+            Tier::Beginner => state.calculators_beginner.find(calculator_id).ok()?
+                .metadata().parameters.into_iter().map(|p| p.path).collect(),
+            Tier::Engineer => state.calculators_engineer.find(calculator_id).ok()?
+                .metadata().parameters.into_iter().map(|p| p.path).collect(),
+            Tier::Contractor => state.calculators_contractor.find(calculator_id).ok()?
+                .metadata().parameters.into_iter().map(|p| p.path).collect(),
+        };
+        Some(paths)
+    }
+
+    /// Unknown keys in one calculate request, as `parameters.…` paths
+    fn check(&self, request: &Value) -> Vec<String> {
+        let (Some(calculation_type), Some(parameters)) = (
+            request.get("calculation_type").and_then(Value::as_str),
+            request.get("parameters"),
+        ) else {
+            return Vec::new();
+        };
+        // Unknown calculators are the handler's to reject
+        let Some(declared) = self.declared(calculation_type) else {
+            return Vec::new();
+        };
+
+        let unknown = match self.tier {
+            Tier::Beginner => unknown_parameters::<BeginnerParameters>(parameters, &declared),
+            Tier::Engineer => unknown_parameters::<EngineeringParameters>(parameters, &declared),
+            Tier::Contractor => unknown_parameters::<ContractingParameters>(parameters, &declared),
+        };
+        unknown.into_iter().map(|path| format!("parameters.{}", path)).collect()
+    }
+}
+
+/// `loads.wind_laod` for a path serde_ignored renders as `loads.?.wind_laod`
+fn dotted(path: &serde_ignored::Path) -> String {
+    use serde_ignored::Path;
+    match path {
+        Path::Root => String::new(),
+        Path::Seq { parent, index } => format!("{}[{}]", dotted(parent), index),
+        Path::Map { parent, key } => match dotted(parent) {
+            parent if parent.is_empty() => key.clone(),
+            parent => format!("{}.{}", parent, key),
+        },
+        Path::Some { parent } | Path::NewtypeStruct { parent } | Path::NewtypeVariant { parent } => dotted(parent),
+    }
+}
+
+/// Keys in `parameters` that `P` has no field for, plus declared-map entries
+/// missing from `declared`
+///
+/// Parameters that don't deserialize at all report nothing; the handler
+/// rejects them with the real error.
+pub fn unknown_parameters<P: DeserializeOwned>(parameters: &Value, declared: &[String]) -> Vec<String> {
+    let mut unknown = Vec::new();
+    let parsed: Result<P, _> = serde_ignored::deserialize(parameters, |path| unknown.push(dotted(&path)));
+    if parsed.is_err() {
+        return Vec::new();
+    }
+
+    for map in DECLARED_MAPS {
+        let Some(Value::Object(entries)) = parameters.get(*map) else { continue };
+        for key in entries.keys() {
+            let path = format!("{}.{}", map, key);
+            if !declared.contains(&path) {
+                unknown.push(path);
+            }
+        }
+    }
+
+    unknown.sort();
+    unknown.dedup();
+    unknown
+}
+
+/// Reject `?strict=true` calculate and batch requests carrying parameter
+/// keys the calculator doesn't read
+pub async fn strict_middleware(State(check): State<StrictCheck>, request: Request, next: Next) -> Response {
+    let path = request.uri().path();
+    let strict = Query::<StrictQuery>::try_from_uri(request.uri()).map(|q| q.strict).unwrap_or(false);
+    if !strict || request.method() != Method::POST || !(path.ends_with("/calculate") || path.ends_with("/batch")) {
+        return next.run(request).await;
+    }
+
+    let (parts, body) = request.into_parts();
+    let Ok(bytes) = to_bytes(body, MAX_CHECKED_BYTES).await else {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+
+    // Bodies that aren't JSON go through for the handler to reject
+    let unknown: Vec<String> = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(Value::Array(requests)) => requests
+            .iter()
+            .enumerate()
+            .flat_map(|(index, request)| check.check(request).into_iter().map(move |p| format!("[{}].{}", index, p)))
+            .collect(),
+        Ok(request) => check.check(&request),
+        Err(_) => Vec::new(),
+    };
+
+    if !unknown.is_empty() {
+        return (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(serde_json::json!({
+                "error": "unknown_parameters",
+                "message": format!("Unknown parameters in strict mode: {}", unknown.join(", ")),
+                "unknown_parameters": unknown,
+            })),
+        )
+            .into_response();
+    }
+
+    next.run(Request::from_parts(parts, Body::from(bytes))).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unknown_parameters_catch_typos() {
+        let declared: Vec<String> = ["dimensions.span", "loads.dead_load", "loads.live_load", "loads.wind_load"]
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        let parameters = json!({
+            "dimensions": { "span": 6.0, "spna": 6.0 },
+            "loads": { "dead_load": 5.0, "live_load": 3.0, "wind_laod": 1.2, "load_combination": "LRFD" },
+            "temprature": 20.0
+        });
+
+        let unknown = unknown_parameters::<EngineeringParameters>(&parameters, &declared);
+        assert_eq!(unknown, ["dimensions.spna", "loads.wind_laod", "temprature"]);
+
+        let clean = json!({ "dimensions": { "span": 6.0 }, "temperature": 20.0 });
+        assert!(unknown_parameters::<EngineeringParameters>(&clean, &declared).is_empty());
+    }
+
+    #[test]
+    fn test_golden_fixtures_pass_strict_mode() {
+        let beginner = crate::calculus::beginner::create_default_registry();
+        let engineer = crate::calculus::engineer::create_default_registry();
+        let contractor = crate::calculus::contractor::create_default_registry();
+
+        for fixture in crate::calculus::selftest::fixtures() {
+            let id = fixture.calculator_id.as_str();
+            let unknown = match fixture.tier {
+                Tier::Beginner => {
+                    let declared: Vec<String> = beginner.find(id).unwrap().metadata().parameters.into_iter().map(|p| p.path).collect();
+                    unknown_parameters::<BeginnerParameters>(&fixture.parameters, &declared)
+                }
+                Tier::Engineer => {
+                    let declared: Vec<String> = engineer.find(id).unwrap().metadata().parameters.into_iter().map(|p| p.path).collect();
+                    unknown_parameters::<EngineeringParameters>(&fixture.parameters, &declared)
+                }
+                Tier::Contractor => {
+                    let declared: Vec<String> = contractor.find(id).unwrap().metadata().parameters.into_iter().map(|p| p.path).collect();
+                    unknown_parameters::<ContractingParameters>(&fixture.parameters, &declared)
+                }
+            };
+            assert!(unknown.is_empty(), "{}: {:?}", id, unknown);
+        }
+    }
+}
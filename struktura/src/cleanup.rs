@@ -0,0 +1,201 @@
+//! Background cleanup of expired and stale data
+//!
+//! One sweep runs every `CLEANUP_INTERVAL_MINUTES` (default 60) and:
+//!
+//! - forgets revoked token ids once the tokens themselves have expired
+//! - drops CSRF tokens past their lifetime
+//! - forgets anonymous trial counters idle for longer than a trial token lives
+//! - forgets SSO sign-ins that were started but never completed
+//! - deletes refresh tokens that expired more than a day ago
+//! - deletes emailed verification and reset tokens once expired
+//! - deletes support bundles older than `SUPPORT_BUNDLE_RETENTION_DAYS`
+//!   (default 90)
+//! - fails calculation jobs abandoned by their worker and deletes finished
+//!   ones older than `JOB_RETENTION_DAYS` (default 7)
+//! - deletes status probes older than the status page's 90-day history
+//!
+//! The database steps are skipped in sandbox mode and while maintenance mode
+//! is on. Admins can run a sweep on demand through `/admin/cleanup`.
+
+use axum::{extract::State, Json};
+use serde::Serialize;
+use std::sync::Arc;
+use time::Duration;
+
+use crate::jobs;
+use crate::sec::{self, AppError, Claims};
+use crate::sso;
+use crate::status;
+use crate::state::AppState;
+use crate::trial::TRIAL_TOKEN_TTL;
+
+const DEFAULT_INTERVAL_MINUTES: u64 = 60;
+const DEFAULT_SUPPORT_BUNDLE_RETENTION_DAYS: i64 = 90;
+const DEFAULT_JOB_RETENTION_DAYS: i64 = 7;
+
+/// How long an expired refresh token is kept, so a late retry is still
+/// answered as expired rather than unknown
+const REFRESH_TOKEN_GRACE: Duration = Duration::days(1);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CleanupConfig {
+    pub interval: std::time::Duration,
+    pub support_bundle_retention: Duration,
+    pub job_retention: Duration,
+}
+
+impl Default for CleanupConfig {
+    fn default() -> Self {
+        Self {
+            interval: std::time::Duration::from_secs(DEFAULT_INTERVAL_MINUTES * 60),
+            support_bundle_retention: Duration::days(DEFAULT_SUPPORT_BUNDLE_RETENTION_DAYS),
+            job_retention: Duration::days(DEFAULT_JOB_RETENTION_DAYS),
+        }
+    }
+}
+
+impl CleanupConfig {
+    pub fn from_env() -> Self {
+        Self::from_vars(
+            std::env::var("CLEANUP_INTERVAL_MINUTES").ok().as_deref(),
+            std::env::var("SUPPORT_BUNDLE_RETENTION_DAYS").ok().as_deref(),
+            std::env::var("JOB_RETENTION_DAYS").ok().as_deref(),
+        )
+    }
+
+    /// Unset, unparsable or zero values keep their defaults
+    fn from_vars(interval_minutes: Option<&str>, retention_days: Option<&str>, job_retention_days: Option<&str>) -> Self {
+        let defaults = Self::default();
+        let days = |value: Option<&str>| value.and_then(|v| v.trim().parse::<i64>().ok()).filter(|d| *d > 0).map(Duration::days);
+        Self {
+            interval: interval_minutes
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .filter(|m| *m > 0)
+                .map(|m| std::time::Duration::from_secs(m * 60))
+                .unwrap_or(defaults.interval),
+            support_bundle_retention: days(retention_days).unwrap_or(defaults.support_bundle_retention),
+            job_retention: days(job_retention_days).unwrap_or(defaults.job_retention),
+        }
+    }
+}
+
+/// What one sweep removed
+#[derive(Debug, Default, Serialize)]
+pub struct CleanupReport {
+    pub blacklist_entries: usize,
+    pub csrf_tokens: usize,
+    pub trial_counters: usize,
+    pub sso_logins: usize,
+    pub refresh_tokens: u64,
+    pub email_tokens: u64,
+    pub support_bundles: u64,
+    pub abandoned_jobs: u64,
+    pub jobs: u64,
+    pub status_checks: u64,
+}
+
+impl CleanupReport {
+    pub fn total(&self) -> u64 {
+        (self.blacklist_entries + self.csrf_tokens + self.trial_counters + self.sso_logins) as u64
+            + self.refresh_tokens
+            + self.email_tokens
+            + self.support_bundles
+            + self.abandoned_jobs
+            + self.jobs
+            + self.status_checks
+    }
+}
+
+/// Run every cleanup step once
+pub async fn run_once(state: &AppState, config: &CleanupConfig) -> Result<CleanupReport, sqlx::Error> {
+    let mut report = CleanupReport {
+        blacklist_entries: state.token_blacklist.purge_expired(),
+        csrf_tokens: state.csrf_store.purge_expired(),
+        trial_counters: state.trial_quota.purge_idle(TRIAL_TOKEN_TTL),
+        sso_logins: sso::purge_expired(),
+        ..Default::default()
+    };
+
+    if state.sandbox || state.maintenance.is_active() {
+        return Ok(report);
+    }
+
+    report.refresh_tokens = sqlx::query("DELETE FROM refresh_tokens WHERE expires_at < NOW() - $1::interval")
+        .bind(REFRESH_TOKEN_GRACE)
+        .execute(&state.pool)
+        .await?
+        .rows_affected();
+
+    report.email_tokens = sqlx::query("DELETE FROM email_tokens WHERE expires_at < NOW()")
+        .execute(&state.pool)
+        .await?
+        .rows_affected();
+
+    report.support_bundles = sqlx::query("DELETE FROM support_bundles WHERE created_at < NOW() - $1::interval")
+        .bind(config.support_bundle_retention)
+        .execute(&state.pool)
+        .await?
+        .rows_affected();
+
+    report.abandoned_jobs = jobs::fail_abandoned(&state.pool, state.jobs.config().timeout).await?;
+    report.jobs = sqlx::query("DELETE FROM calculation_jobs WHERE finished_at < NOW() - $1::interval")
+        .bind(config.job_retention)
+        .execute(&state.pool)
+        .await?
+        .rows_affected();
+
+    report.status_checks = sqlx::query("DELETE FROM status_checks WHERE checked_at < NOW() - make_interval(days => $1)")
+        .bind(status::HISTORY_RETENTION_DAYS as i32)
+        .execute(&state.pool)
+        .await?
+        .rows_affected();
+
+    Ok(report)
+}
+
+/// Sweep in the background on the configured cadence
+pub fn spawn(state: Arc<AppState>) {
+    let config = CleanupConfig::from_env();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(config.interval);
+        loop {
+            interval.tick().await;
+            match run_once(&state, &config).await {
+                Ok(report) if report.total() == 0 => {}
+                Ok(report) => tracing::info!(?report, "cleanup sweep"),
+                Err(e) => tracing::error!(error = %e, "cleanup sweep failed"),
+            }
+        }
+    });
+}
+
+/// POST /api/v1/user/admin/cleanup
+pub async fn run_cleanup_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<CleanupReport>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+
+    let report = run_once(&state, &CleanupConfig::from_env()).await?;
+    sec::log_security_event("CLEANUP_RUN", Some(&claims.username), None, "Success");
+    Ok(Json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_from_vars() {
+        assert_eq!(CleanupConfig::from_vars(None, None, None), CleanupConfig::default());
+
+        let config = CleanupConfig::from_vars(Some("15"), Some(" 30 "), Some("2"));
+        assert_eq!(config.interval, std::time::Duration::from_secs(900));
+        assert_eq!(config.support_bundle_retention, Duration::days(30));
+        assert_eq!(config.job_retention, Duration::days(2));
+
+        let config = CleanupConfig::from_vars(Some("0"), Some("forever"), Some("0"));
+        assert_eq!(config, CleanupConfig::default());
+    }
+}
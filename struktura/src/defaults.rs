@@ -0,0 +1,471 @@
+use axum::{extract::State, response::Json};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use std::sync::Arc;
+use validator::{Validate, ValidationError};
+
+use crate::calculus::contractor::models::ContractingParameters;
+use crate::calculus::engineer::models::EngineeringParameters;
+use crate::calculus::provenance::AssumptionLedger;
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+use crate::tenancy::TenantScope;
+
+// =============================================================================
+// DEFAULT ASSUMPTIONS
+// =============================================================================
+
+/// Where an assumed value came from, highest precedence first
+///
+/// Refines `provenance::ValueSource`: `Request` is explicit, `User` and
+/// `Organization` are saved choices, `System` is the platform's own default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Request,
+    User,
+    Organization,
+    System,
+}
+
+impl Source {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Source::Request => "request",
+            Source::User => "user",
+            Source::Organization => "organization",
+            Source::System => "system",
+        }
+    }
+}
+
+/// Values merged into calculation requests that leave them out
+///
+/// Saved once for the organization and optionally overridden per user.
+/// Ranges match what the calculators using them accept.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct AssumptionDefaults {
+    /// Hourly labor rate, `additional.labor_rate`
+    #[serde(default)]
+    #[validate(range(min = 10.0, max = 200.0))]
+    pub labor_rate: Option<f64>,
+    /// Material waste multiplier, `material.waste_factor`
+    #[serde(default)]
+    #[validate(range(min = 1.0, max = 2.0))]
+    pub waste_factor: Option<f64>,
+    /// Bid markup in percent, `additional.markup_percentage`
+    #[serde(default)]
+    #[validate(range(min = 5.0, max = 50.0))]
+    pub markup_percentage: Option<f64>,
+    /// Engineering design code profile, e.g. `ACI318` or `Eurocode`
+    #[serde(default)]
+    #[validate(length(min = 1, max = 50))]
+    pub design_code: Option<String>,
+    /// ISO 4217 code costs are quoted in
+    #[serde(default)]
+    #[validate(custom(function = "validate_currency"))]
+    pub currency: Option<String>,
+}
+
+fn validate_currency(code: &str) -> Result<(), ValidationError> {
+    if code.len() == 3 && code.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("three-letter ISO 4217 code"))
+    }
+}
+
+impl AssumptionDefaults {
+    /// Platform defaults, used when neither user nor organization set a value
+    pub fn system() -> Self {
+        Self {
+            waste_factor: Some(1.1),
+            currency: Some("USD".to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+/// A value together with the layer it was taken from
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Assumed<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+/// Each default resolved through the user, organization and system layers
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EffectiveDefaults {
+    pub labor_rate: Option<Assumed<f64>>,
+    pub waste_factor: Option<Assumed<f64>>,
+    pub markup_percentage: Option<Assumed<f64>>,
+    pub design_code: Option<Assumed<String>>,
+    pub currency: Option<Assumed<String>>,
+}
+
+/// One input the calculation ran with, and where it came from
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AppliedAssumption {
+    /// Dotted parameter path, e.g. `additional.labor_rate`
+    pub path: String,
+    pub value: serde_json::Value,
+    pub source: Source,
+}
+
+/// A calculation response with the defaults merged into its request and
+/// where each declared parameter came from
+#[derive(Debug, Serialize)]
+pub struct WithAssumptions<T> {
+    #[serde(flatten)]
+    pub response: T,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assumptions: Vec<AppliedAssumption>,
+    pub provenance: AssumptionLedger,
+}
+
+impl<T> WithAssumptions<T> {
+    /// The ledger saw merged defaults as part of the request; relabel them
+    pub fn new(response: T, assumptions: Vec<AppliedAssumption>, mut provenance: AssumptionLedger) -> Self {
+        for assumption in assumptions.iter().filter(|a| a.source != Source::Request) {
+            provenance.mark_default(&assumption.path, assumption.source.as_str());
+        }
+        Self { response, assumptions, provenance }
+    }
+}
+
+fn first<T: Clone>(layers: [(Source, Option<&T>); 3]) -> Option<Assumed<T>> {
+    layers
+        .into_iter()
+        .find_map(|(source, value)| value.map(|value| Assumed { value: value.clone(), source }))
+}
+
+fn applied(path: &str, value: impl Serialize, source: Source) -> AppliedAssumption {
+    AppliedAssumption {
+        path: path.to_string(),
+        value: serde_json::to_value(value).unwrap_or_default(),
+        source,
+    }
+}
+
+impl EffectiveDefaults {
+    /// Resolve with user values over organization values over system ones
+    pub fn resolve(user: &AssumptionDefaults, org: &AssumptionDefaults) -> Self {
+        let system = AssumptionDefaults::system();
+        let layers = |pick: fn(&AssumptionDefaults) -> Option<&f64>| {
+            first([
+                (Source::User, pick(user)),
+                (Source::Organization, pick(org)),
+                (Source::System, pick(&system)),
+            ])
+        };
+        let text_layers = |pick: fn(&AssumptionDefaults) -> Option<&String>| {
+            first([
+                (Source::User, pick(user)),
+                (Source::Organization, pick(org)),
+                (Source::System, pick(&system)),
+            ])
+        };
+        Self {
+            labor_rate: layers(|d| d.labor_rate.as_ref()),
+            waste_factor: layers(|d| d.waste_factor.as_ref()),
+            markup_percentage: layers(|d| d.markup_percentage.as_ref()),
+            design_code: text_layers(|d| d.design_code.as_ref()),
+            currency: text_layers(|d| d.currency.as_ref()),
+        }
+    }
+
+    /// Defaults for callers without saved settings
+    pub fn system() -> Self {
+        Self::resolve(&AssumptionDefaults::default(), &AssumptionDefaults::default())
+    }
+
+    /// Fill contracting inputs the calculator takes and the request left out
+    ///
+    /// Only parameters listed in `accepted` (the calculator's metadata
+    /// paths) are touched. Values the request supplied, directly or through
+    /// a saved material, are recorded as `request` and kept.
+    pub fn apply_to_contracting(&self, params: &mut ContractingParameters, accepted: &[&str]) -> Vec<AppliedAssumption> {
+        let mut assumptions = Vec::new();
+
+        let numeric = [
+            ("labor_rate", &self.labor_rate),
+            ("markup_percentage", &self.markup_percentage),
+        ];
+        for (name, default) in numeric {
+            let path = format!("additional.{name}");
+            if !accepted.contains(&path.as_str()) {
+                continue;
+            }
+            let additional = params.additional.get_or_insert_with(Default::default);
+            match (additional.get(name), default) {
+                (Some(value), _) => assumptions.push(applied(&path, value, Source::Request)),
+                (None, Some(default)) => {
+                    additional.insert(name.to_string(), default.value);
+                    assumptions.push(applied(&path, default.value, default.source));
+                }
+                (None, None) => {}
+            }
+        }
+
+        if accepted.contains(&"material.waste_factor")
+            && let Some(material) = params.material.as_mut()
+        {
+            match (material.waste_factor, &self.waste_factor) {
+                (Some(value), _) => assumptions.push(applied("material.waste_factor", value, Source::Request)),
+                (None, Some(default)) => {
+                    material.waste_factor = Some(default.value);
+                    assumptions.push(applied("material.waste_factor", default.value, default.source));
+                }
+                (None, None) => {}
+            }
+        }
+
+        // Costs are reported in the caller's currency
+        if let Some(currency) = &self.currency {
+            assumptions.push(applied("currency", &currency.value, currency.source));
+        }
+        assumptions
+    }
+
+    /// Fill the design code when the request names none
+    pub fn apply_to_engineering(&self, params: &mut EngineeringParameters) -> Vec<AppliedAssumption> {
+        match (&params.design_code, &self.design_code) {
+            (Some(code), _) => vec![applied("design_code", code, Source::Request)],
+            (None, Some(default)) => {
+                params.design_code = Some(default.value.clone());
+                vec![applied("design_code", &default.value, default.source)]
+            }
+            (None, None) => Vec::new(),
+        }
+    }
+}
+
+// =============================================================================
+// STORAGE
+// =============================================================================
+
+#[derive(sqlx::FromRow)]
+struct DefaultsRow {
+    is_user: bool,
+    labor_rate: Option<f64>,
+    waste_factor: Option<f64>,
+    markup_percentage: Option<f64>,
+    design_code: Option<String>,
+    currency: Option<String>,
+}
+
+impl From<DefaultsRow> for AssumptionDefaults {
+    fn from(row: DefaultsRow) -> Self {
+        Self {
+            labor_rate: row.labor_rate,
+            waste_factor: row.waste_factor,
+            markup_percentage: row.markup_percentage,
+            design_code: row.design_code,
+            currency: row.currency,
+        }
+    }
+}
+
+/// The caller's own defaults and their organization's
+async fn load_layers(pool: &PgPool, scope: &TenantScope) -> Result<(AssumptionDefaults, AssumptionDefaults), AppError> {
+    let rows = sqlx::query_as::<_, DefaultsRow>(
+        "SELECT user_id IS NOT NULL AS is_user, labor_rate, waste_factor, markup_percentage, \
+                design_code, currency \
+         FROM assumption_defaults \
+         WHERE org_id = $1 AND (user_id IS NULL OR user_id = $2)",
+    )
+    .bind(scope.org_id)
+    .bind(scope.user_id)
+    .fetch_all(pool)
+    .await?;
+
+    let (mut user, mut org) = (AssumptionDefaults::default(), AssumptionDefaults::default());
+    for row in rows {
+        if row.is_user {
+            user = row.into();
+        } else {
+            org = row.into();
+        }
+    }
+    Ok((user, org))
+}
+
+/// Defaults for a calculation request
+///
+/// Anonymous and sandbox callers get the system defaults. A failed lookup
+/// is logged and the calculation continues on system defaults.
+pub async fn for_caller(state: &AppState, claims: Option<&Claims>) -> EffectiveDefaults {
+    let Some(claims) = claims else {
+        return EffectiveDefaults::system();
+    };
+    if state.sandbox {
+        return EffectiveDefaults::system();
+    }
+    let layers = match state.tenants.resolve(&state.pool, claims).await {
+        Ok(scope) => load_layers(&state.pool, &scope).await,
+        Err(e) => Err(e),
+    };
+    match layers {
+        Ok((user, org)) => EffectiveDefaults::resolve(&user, &org),
+        Err(e) => {
+            tracing::warn!(user = %claims.username, error = ?e, "defaults lookup failed, using system defaults");
+            EffectiveDefaults::system()
+        }
+    }
+}
+
+// =============================================================================
+// HANDLERS
+// =============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct DefaultsOverview {
+    pub organization: AssumptionDefaults,
+    pub user: AssumptionDefaults,
+    /// What calculations will use, with the layer each value comes from
+    pub effective: EffectiveDefaults,
+}
+
+pub async fn get_defaults_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+) -> Result<Json<DefaultsOverview>, AppError> {
+    let (user, organization) = load_layers(&app_state.pool, &scope).await?;
+    let effective = EffectiveDefaults::resolve(&user, &organization);
+    Ok(Json(DefaultsOverview { organization, user, effective }))
+}
+
+async fn save(
+    app_state: &AppState,
+    scope: &TenantScope,
+    user_id: Option<uuid::Uuid>,
+    defaults: &AssumptionDefaults,
+) -> Result<Json<DefaultsOverview>, AppError> {
+    let conflict = if user_id.is_some() {
+        "(org_id, user_id) WHERE user_id IS NOT NULL"
+    } else {
+        "(org_id) WHERE user_id IS NULL"
+    };
+    sqlx::query(&format!(
+        r#"
+        INSERT INTO assumption_defaults
+            (org_id, user_id, labor_rate, waste_factor, markup_percentage, design_code, currency, updated_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT {conflict} DO UPDATE
+        SET labor_rate = EXCLUDED.labor_rate, waste_factor = EXCLUDED.waste_factor,
+            markup_percentage = EXCLUDED.markup_percentage, design_code = EXCLUDED.design_code,
+            currency = EXCLUDED.currency, updated_by = EXCLUDED.updated_by,
+            updated_at = CURRENT_TIMESTAMP
+        "#
+    ))
+    .bind(scope.org_id)
+    .bind(user_id)
+    .bind(defaults.labor_rate)
+    .bind(defaults.waste_factor)
+    .bind(defaults.markup_percentage)
+    .bind(&defaults.design_code)
+    .bind(&defaults.currency)
+    .bind(scope.user_id)
+    .execute(&app_state.pool)
+    .await?;
+
+    let (user, organization) = load_layers(&app_state.pool, scope).await?;
+    let effective = EffectiveDefaults::resolve(&user, &organization);
+    Ok(Json(DefaultsOverview { organization, user, effective }))
+}
+
+/// Replace the organization's defaults; unset fields fall through to system
+pub async fn update_org_defaults_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<AssumptionDefaults>,
+) -> Result<Json<DefaultsOverview>, AppError> {
+    payload.validate()?;
+    let overview = save(&app_state, &scope, None, &payload).await?;
+    sec::log_security_event("ORG_DEFAULTS_UPDATE", Some(&scope.username), None, "Success");
+    Ok(overview)
+}
+
+/// Replace the caller's own overrides; unset fields fall through to the organization
+pub async fn update_my_defaults_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<AssumptionDefaults>,
+) -> Result<Json<DefaultsOverview>, AppError> {
+    payload.validate()?;
+    let overview = save(&app_state, &scope, Some(scope.user_id), &payload).await?;
+    sec::log_security_event("USER_DEFAULTS_UPDATE", Some(&scope.username), None, "Success");
+    Ok(overview)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::contractor::models::MaterialProperties;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_precedence_user_over_org_over_system() {
+        let org = AssumptionDefaults {
+            labor_rate: Some(55.0),
+            markup_percentage: Some(12.0),
+            currency: Some("EUR".to_string()),
+            ..Default::default()
+        };
+        let user = AssumptionDefaults { labor_rate: Some(62.0), ..Default::default() };
+        let effective = EffectiveDefaults::resolve(&user, &org);
+
+        assert_eq!(effective.labor_rate, Some(Assumed { value: 62.0, source: Source::User }));
+        assert_eq!(effective.markup_percentage, Some(Assumed { value: 12.0, source: Source::Organization }));
+        assert_eq!(effective.waste_factor, Some(Assumed { value: 1.1, source: Source::System }));
+        assert_eq!(effective.currency.unwrap().value, "EUR");
+        assert!(effective.design_code.is_none());
+    }
+
+    #[test]
+    fn test_request_values_win() {
+        let org = AssumptionDefaults { labor_rate: Some(55.0), markup_percentage: Some(12.0), ..Default::default() };
+        let effective = EffectiveDefaults::resolve(&AssumptionDefaults::default(), &org);
+        let mut params = ContractingParameters {
+            additional: Some(HashMap::from([("labor_rate".to_string(), 40.0)])),
+            material: Some(MaterialProperties {
+                material_type: "concrete".to_string(),
+                material_id: None,
+                unit_cost: Some(120.0),
+                waste_factor: None,
+                density: None,
+                availability: None,
+            }),
+            ..Default::default()
+        };
+
+        let assumptions = effective.apply_to_contracting(
+            &mut params,
+            &["additional.labor_rate", "additional.markup_percentage", "material.waste_factor"],
+        );
+        let additional = params.additional.as_ref().unwrap();
+        assert_eq!(additional["labor_rate"], 40.0);
+        assert_eq!(additional["markup_percentage"], 12.0);
+        assert_eq!(params.material.unwrap().waste_factor, Some(1.1));
+
+        let sources: Vec<_> = assumptions.iter().map(|a| (a.path.as_str(), a.source)).collect();
+        assert_eq!(
+            sources,
+            vec![
+                ("additional.labor_rate", Source::Request),
+                ("additional.markup_percentage", Source::Organization),
+                ("material.waste_factor", Source::System),
+                ("currency", Source::System),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(AssumptionDefaults::system().validate().is_ok());
+        let bad_currency = AssumptionDefaults { currency: Some("euro".to_string()), ..Default::default() };
+        assert!(bad_currency.validate().is_err());
+        let bad_rate = AssumptionDefaults { labor_rate: Some(500.0), ..Default::default() };
+        assert!(bad_rate.validate().is_err());
+    }
+}
@@ -0,0 +1,375 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::{postgres::PgPool, types::time::OffsetDateTime};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::calculus::recommendation::Tier;
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+use crate::tenancy::{TenantDirectory, TenantScope};
+
+/// How long flags are served from memory before being reloaded
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+// =============================================================================
+// FLAGS
+// =============================================================================
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct FeatureFlag {
+    pub key: String,
+    pub description: String,
+    pub enabled: bool,
+    pub rollout_percentage: i16,
+    pub allowed_users: Vec<Uuid>,
+    pub allowed_orgs: Vec<String>,
+    pub updated_at: OffsetDateTime,
+}
+
+/// Who a flag is being evaluated for
+#[derive(Debug, Clone, Default)]
+pub struct FlagContext {
+    pub user_id: Option<Uuid>,
+    pub org_id: Option<String>,
+}
+
+impl FlagContext {
+    /// The organization comes from the tenant directory, already populated
+    /// by the tenant middleware for signed-in requests
+    pub fn for_caller(tenants: &TenantDirectory, claims: Option<&Claims>) -> Self {
+        let user_id = claims.and_then(|c| Uuid::parse_str(&c.sub).ok());
+        Self {
+            user_id,
+            org_id: user_id.and_then(|id| tenants.cached_org(id)).map(|id| id.to_string()),
+        }
+    }
+
+    /// A caller whose organization is already resolved
+    pub fn for_scope(scope: &TenantScope) -> Self {
+        Self {
+            user_id: Some(scope.user_id),
+            org_id: Some(scope.org_id.to_string()),
+        }
+    }
+}
+
+impl FeatureFlag {
+    /// Listed users and orgs always get an enabled flag; everyone else by
+    /// stable percentage bucket. Anonymous callers only see full rollouts.
+    pub fn evaluate(&self, ctx: &FlagContext) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if ctx.user_id.is_some_and(|id| self.allowed_users.contains(&id))
+            || ctx.org_id.as_ref().is_some_and(|org| self.allowed_orgs.contains(org))
+        {
+            return true;
+        }
+        if self.rollout_percentage >= 100 {
+            return true;
+        }
+
+        // Orgs roll out as a unit so colleagues see the same features
+        let subject = match (&ctx.org_id, ctx.user_id) {
+            (Some(org), _) => format!("org:{}", org),
+            (None, Some(user)) => format!("user:{}", user),
+            (None, None) => return false,
+        };
+        bucket(&self.key, &subject) < self.rollout_percentage.max(0) as u32
+    }
+}
+
+/// Stable 0-99 bucket for a subject under a flag
+fn bucket(key: &str, subject: &str) -> u32 {
+    let digest = Sha256::digest(format!("{}:{}", key, subject).as_bytes());
+    u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) % 100
+}
+
+/// Flag name gating a calculator
+pub fn calculator_flag_key(tier: Tier, calculator_id: &str) -> String {
+    format!("calculator.{}.{}", tier.as_str(), calculator_id)
+}
+
+// =============================================================================
+// CACHE
+// =============================================================================
+
+#[derive(Default)]
+struct FlagCache {
+    flags: HashMap<String, FeatureFlag>,
+    loaded_at: Option<Instant>,
+}
+
+/// Database-backed flags behind a short-lived in-memory cache
+#[derive(Clone)]
+pub struct FeatureFlags {
+    cache: Arc<RwLock<FlagCache>>,
+    ttl: Duration,
+    /// Never read the database (sandbox mode); every flag is off and every
+    /// calculator ungated
+    offline: bool,
+}
+
+impl FeatureFlags {
+    pub fn new(ttl: Duration) -> Self {
+        Self { cache: Arc::new(RwLock::new(FlagCache::default())), ttl, offline: false }
+    }
+
+    pub fn offline() -> Self {
+        Self { offline: true, ..Self::new(DEFAULT_CACHE_TTL) }
+    }
+
+    /// Current flags, reloading when the cache has expired. A failed reload
+    /// keeps serving the previous set until the next expiry.
+    async fn snapshot(&self, pool: &PgPool) -> HashMap<String, FeatureFlag> {
+        {
+            let cache = self.cache.read().await;
+            let fresh = cache.loaded_at.is_some_and(|at| at.elapsed() < self.ttl);
+            if fresh || self.offline {
+                return cache.flags.clone();
+            }
+        }
+
+        let mut cache = self.cache.write().await;
+        // Another request may have reloaded while we waited for the lock
+        if cache.loaded_at.is_some_and(|at| at.elapsed() < self.ttl) {
+            return cache.flags.clone();
+        }
+        match load_flags(pool).await {
+            Ok(flags) => cache.flags = flags.into_iter().map(|f| (f.key.clone(), f)).collect(),
+            Err(e) => tracing::warn!(error = %e, "flag reload failed, serving cached flags"),
+        }
+        cache.loaded_at = Some(Instant::now());
+        cache.flags.clone()
+    }
+
+    /// Drop the cache so the next check sees admin changes immediately
+    pub async fn invalidate(&self) {
+        self.cache.write().await.loaded_at = None;
+    }
+
+    /// Unknown flags are off
+    pub async fn is_enabled(&self, pool: &PgPool, key: &str, ctx: &FlagContext) -> bool {
+        self.snapshot(pool).await.get(key).is_some_and(|f| f.evaluate(ctx))
+    }
+
+    /// Calculators without a flag are generally available
+    pub async fn calculator_enabled(&self, pool: &PgPool, tier: Tier, calculator_id: &str, ctx: &FlagContext) -> bool {
+        self.snapshot(pool)
+            .await
+            .get(&calculator_flag_key(tier, calculator_id))
+            .is_none_or(|f| f.evaluate(ctx))
+    }
+
+    /// Calculators of a tier the caller may not see
+    pub async fn hidden_calculators(&self, pool: &PgPool, tier: Tier, ctx: &FlagContext) -> HashSet<String> {
+        let prefix = calculator_flag_key(tier, "");
+        self.snapshot(pool)
+            .await
+            .values()
+            .filter(|f| !f.evaluate(ctx))
+            .filter_map(|f| f.key.strip_prefix(&prefix).map(str::to_string))
+            .collect()
+    }
+
+    /// Every flag evaluated for the caller
+    pub async fn evaluate_all(&self, pool: &PgPool, ctx: &FlagContext) -> BTreeMap<String, bool> {
+        self.snapshot(pool)
+            .await
+            .values()
+            .map(|f| (f.key.clone(), f.evaluate(ctx)))
+            .collect()
+    }
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_TTL)
+    }
+}
+
+async fn load_flags(pool: &PgPool) -> Result<Vec<FeatureFlag>, sqlx::Error> {
+    sqlx::query_as::<_, FeatureFlag>(
+        "SELECT key, description, enabled, rollout_percentage, allowed_users, allowed_orgs, updated_at \
+         FROM feature_flags ORDER BY key",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+// =============================================================================
+// HANDLERS
+// =============================================================================
+
+/// Flags as they apply to the signed-in user
+pub async fn my_flags_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Json<BTreeMap<String, bool>> {
+    let ctx = FlagContext::for_caller(&state.tenants, Some(&claims));
+    Json(state.flags.evaluate_all(&state.pool, &ctx).await)
+}
+
+pub async fn list_flags_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<Vec<FeatureFlag>>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    Ok(Json(load_flags(&state.pool).await?))
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct FlagUpdate {
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+    pub enabled: bool,
+    #[validate(range(min = 0, max = 100))]
+    #[serde(default)]
+    pub rollout_percentage: i16,
+    #[serde(default)]
+    pub allowed_users: Vec<Uuid>,
+    #[serde(default)]
+    pub allowed_orgs: Vec<String>,
+}
+
+fn validate_key(key: &str) -> Result<(), AppError> {
+    let valid = !key.is_empty()
+        && key.len() <= 100
+        && key.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "_.-".contains(c));
+    if valid {
+        return Ok(());
+    }
+    let mut errors = ValidationErrors::new();
+    errors.add("key", ValidationError::new("format"));
+    Err(AppError::ValidationError(errors))
+}
+
+/// Create or replace a flag
+pub async fn upsert_flag_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(key): Path<String>,
+    Json(payload): Json<FlagUpdate>,
+) -> Result<Json<FeatureFlag>, AppError> {
+    let admin_id = sec::require_admin(&state.pool, &claims).await?;
+    validate_key(&key)?;
+    payload.validate()?;
+
+    let flag = sqlx::query_as::<_, FeatureFlag>(
+        "INSERT INTO feature_flags \
+             (key, description, enabled, rollout_percentage, allowed_users, allowed_orgs, updated_by, updated_at) \
+         VALUES ($1, COALESCE($2, ''), $3, $4, $5, $6, $7, NOW()) \
+         ON CONFLICT (key) DO UPDATE SET \
+             description = COALESCE($2, feature_flags.description), \
+             enabled = EXCLUDED.enabled, \
+             rollout_percentage = EXCLUDED.rollout_percentage, \
+             allowed_users = EXCLUDED.allowed_users, \
+             allowed_orgs = EXCLUDED.allowed_orgs, \
+             updated_by = EXCLUDED.updated_by, \
+             updated_at = NOW() \
+         RETURNING key, description, enabled, rollout_percentage, allowed_users, allowed_orgs, updated_at",
+    )
+    .bind(&key)
+    .bind(&payload.description)
+    .bind(payload.enabled)
+    .bind(payload.rollout_percentage)
+    .bind(&payload.allowed_users)
+    .bind(&payload.allowed_orgs)
+    .bind(admin_id)
+    .fetch_one(&state.pool)
+    .await?;
+
+    state.flags.invalidate().await;
+    sec::log_security_event(
+        "FLAG_UPDATED",
+        Some(&claims.username),
+        None,
+        &format!("{} enabled={} rollout={}%", flag.key, flag.enabled, flag.rollout_percentage),
+    );
+    Ok(Json(flag))
+}
+
+pub async fn delete_flag_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(key): Path<String>,
+) -> Result<StatusCode, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+
+    let result = sqlx::query("DELETE FROM feature_flags WHERE key = $1")
+        .bind(&key)
+        .execute(&state.pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    state.flags.invalidate().await;
+    sec::log_security_event("FLAG_DELETED", Some(&claims.username), None, &key);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flag(enabled: bool, rollout: i16) -> FeatureFlag {
+        FeatureFlag {
+            key: "calculator.engineer.wind_load".to_string(),
+            description: String::new(),
+            enabled,
+            rollout_percentage: rollout,
+            allowed_users: vec![],
+            allowed_orgs: vec!["acme".to_string()],
+            updated_at: OffsetDateTime::now_utc(),
+        }
+    }
+
+    fn user(id: Uuid) -> FlagContext {
+        FlagContext { user_id: Some(id), org_id: None }
+    }
+
+    #[test]
+    fn test_rollout_is_stable_and_proportional() {
+        let half = flag(true, 50);
+        let users: Vec<Uuid> = (0..2000).map(|_| Uuid::new_v4()).collect();
+
+        let enabled = users.iter().filter(|id| half.evaluate(&user(**id))).count();
+        assert!((800..1200).contains(&enabled), "{} of 2000 enabled at 50%", enabled);
+
+        // Same user, same answer
+        assert!(users.iter().all(|id| half.evaluate(&user(*id)) == half.evaluate(&user(*id))));
+
+        // Raising the percentage never drops users already in the rollout
+        let more = flag(true, 75);
+        assert!(users.iter().all(|id| !half.evaluate(&user(*id)) || more.evaluate(&user(*id))));
+    }
+
+    #[test]
+    fn test_allow_lists_and_master_switch() {
+        let mut f = flag(true, 0);
+        let id = Uuid::new_v4();
+        assert!(!f.evaluate(&user(id)));
+        assert!(f.evaluate(&FlagContext { user_id: Some(id), org_id: Some("acme".to_string()) }));
+
+        f.allowed_users.push(id);
+        assert!(f.evaluate(&user(id)));
+
+        f.enabled = false;
+        assert!(!f.evaluate(&user(id)));
+
+        // Anonymous callers only see full rollouts
+        assert!(!flag(true, 99).evaluate(&FlagContext::default()));
+        assert!(flag(true, 100).evaluate(&FlagContext::default()));
+    }
+}
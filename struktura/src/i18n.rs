@@ -0,0 +1,242 @@
+// ============================================================================
+// Localized Messages
+//
+// Calculators describe their output with translation keys (`message_key`,
+// `unit_key`, `formula_key`, ...) rather than English text. This module
+// resolves those keys against the catalogs in fixtures/locales: the
+// calculus routers run their JSON responses through `localize_middleware`,
+// which picks a locale from `Accept-Language` and adds a `<name>_text`
+// sibling next to every `<name>_key` the catalog knows, filled in from the
+// sibling `params` (or `<name>_params`) object. Keys stay in the response so
+// clients that translate for themselves are unaffected; keys missing from
+// every catalog are left alone.
+// ============================================================================
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request,
+    http::{header, HeaderValue},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static EN: &str = include_str!("../fixtures/locales/en.json");
+static PT_BR: &str = include_str!("../fixtures/locales/pt-BR.json");
+static ES: &str = include_str!("../fixtures/locales/es.json");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    PtBr,
+    Es,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::En, Locale::PtBr, Locale::Es];
+
+    /// BCP 47 tag, as sent in `Content-Language`
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::PtBr => "pt-BR",
+            Locale::Es => "es",
+        }
+    }
+
+    /// Locale for one language range; matched on the primary subtag, so
+    /// `pt-PT` reads Brazilian Portuguese and `es-MX` reads Spanish
+    fn from_range(range: &str) -> Option<Locale> {
+        let primary = range.split('-').next().unwrap_or_default().trim();
+        match primary.to_ascii_lowercase().as_str() {
+            "en" | "*" => Some(Locale::En),
+            "pt" => Some(Locale::PtBr),
+            "es" => Some(Locale::Es),
+            _ => None,
+        }
+    }
+
+    /// Best supported locale for an `Accept-Language` header, English when
+    /// nothing in it is supported
+    pub fn negotiate(accept_language: Option<&str>) -> Locale {
+        let Some(header) = accept_language else {
+            return Locale::En;
+        };
+
+        let mut ranges: Vec<(&str, f32)> = header
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';');
+                let range = parts.next()?.trim();
+                let quality = parts
+                    .find_map(|p| p.trim().strip_prefix("q="))
+                    .and_then(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (!range.is_empty() && quality > 0.0).then_some((range, quality))
+            })
+            .collect();
+        // Stable sort keeps header order between equal weights
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        ranges.into_iter().find_map(|(range, _)| Locale::from_range(range)).unwrap_or_default()
+    }
+}
+
+type Catalog = HashMap<String, String>;
+
+fn catalogs() -> &'static HashMap<Locale, Catalog> {
+    static CATALOGS: OnceLock<HashMap<Locale, Catalog>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        Locale::ALL
+            .into_iter()
+            .map(|locale| {
+                let raw = match locale {
+                    Locale::En => EN,
+                    Locale::PtBr => PT_BR,
+                    Locale::Es => ES,
+                };
+                let catalog: Catalog = serde_json::from_str(raw)
+                    .unwrap_or_else(|e| panic!("fixtures/locales/{}.json is invalid: {}", locale.tag(), e));
+                (locale, catalog)
+            })
+            .collect()
+    })
+}
+
+/// Render a parameter for interpolation; floats are cut to two decimals
+fn render(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) if n.is_f64() => {
+            let rounded = (n.as_f64().unwrap_or_default() * 100.0).round() / 100.0;
+            rounded.to_string()
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Message for `key` in `locale`, falling back to English, with `{name}`
+/// placeholders filled from `params`; `None` when no catalog has the key
+pub fn translate(locale: Locale, key: &str, params: Option<&Map<String, Value>>) -> Option<String> {
+    let catalogs = catalogs();
+    let template = catalogs[&locale].get(key).or_else(|| catalogs[&Locale::En].get(key))?;
+
+    let mut message = template.clone();
+    for (name, value) in params.into_iter().flatten() {
+        message = message.replace(&format!("{{{}}}", name), &render(value));
+    }
+    Some(message)
+}
+
+/// Add `<name>_text` next to every resolvable `<name>_key` in `value`
+pub fn localize(value: &mut Value, locale: Locale) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(|item| localize(item, locale)),
+        Value::Object(object) => {
+            let texts: Vec<(String, String)> = object
+                .iter()
+                .filter_map(|(field, key)| {
+                    let name = field.strip_suffix("_key")?;
+                    let params = object
+                        .get(&format!("{}_params", name))
+                        .or_else(|| object.get("params"))
+                        .and_then(Value::as_object);
+                    let text = translate(locale, key.as_str()?, params)?;
+                    Some((format!("{}_text", name), text))
+                })
+                .collect();
+            for (field, text) in texts {
+                object.insert(field, Value::String(text));
+            }
+            object.values_mut().for_each(|child| localize(child, locale));
+        }
+        _ => {}
+    }
+}
+
+/// Resolve translation keys in JSON responses for the caller's
+/// `Accept-Language`
+pub async fn localize_middleware(request: Request, next: Next) -> Response {
+    let locale = Locale::negotiate(request.headers().get(header::ACCEPT_LANGUAGE).and_then(|v| v.to_str().ok()));
+    let response = next.run(request).await;
+
+    // SVG, DXF and CSV exports pass through untouched
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/json"));
+    if !is_json {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    // Our own handlers produced this body, so it is buffered whole
+    let Ok(bytes) = to_bytes(body, usize::MAX).await else {
+        return axum::http::StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    let body = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(mut json) => {
+            localize(&mut json, locale);
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Body::from(serde_json::to_vec(&json).unwrap_or_else(|_| bytes.to_vec()))
+        }
+        Err(_) => Body::from(bytes),
+    };
+
+    parts.headers.insert(header::CONTENT_LANGUAGE, HeaderValue::from_static(locale.tag()));
+    parts.headers.append(header::VARY, HeaderValue::from_static("accept-language"));
+    Response::from_parts(parts, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_negotiate_accept_language() {
+        assert_eq!(Locale::negotiate(None), Locale::En);
+        assert_eq!(Locale::negotiate(Some("pt-BR,pt;q=0.9,en;q=0.8")), Locale::PtBr);
+        assert_eq!(Locale::negotiate(Some("fr-FR, es-MX;q=0.7, en;q=0.5")), Locale::Es);
+        assert_eq!(Locale::negotiate(Some("en;q=0.3, es;q=0.9")), Locale::Es);
+        assert_eq!(Locale::negotiate(Some("es;q=0, de")), Locale::En);
+    }
+
+    #[test]
+    fn test_catalogs_cover_the_same_keys() {
+        let english = &catalogs()[&Locale::En];
+        for locale in Locale::ALL {
+            let catalog = &catalogs()[&locale];
+            let missing: Vec<_> = english.keys().filter(|k| !catalog.contains_key(*k)).collect();
+            let extra: Vec<_> = catalog.keys().filter(|k| !english.contains_key(*k)).collect();
+            assert!(missing.is_empty() && extra.is_empty(), "{}: missing {:?}, extra {:?}", locale.tag(), missing, extra);
+        }
+    }
+
+    #[test]
+    fn test_localize_adds_interpolated_text() {
+        let mut response = json!({
+            "metrics": [{ "name_key": "metrics.availability", "unit_key": "units.percentage", "value": 0.87 }],
+            "validation": { "issues": [{
+                "code": "HIGH_SCRAP_RATE",
+                "message_key": "validation.warning.high_scrap_rate",
+                "params": { "scrap_rate": 12.5, "threshold": 5.0 }
+            }] },
+            "assumption_key": "planned_production_time"
+        });
+        localize(&mut response, Locale::PtBr);
+
+        assert_eq!(response["metrics"][0]["name_text"], "Disponibilidade");
+        assert_eq!(response["metrics"][0]["unit_text"], "%");
+        assert_eq!(
+            response["validation"]["issues"][0]["message_text"],
+            "A taxa de refugo de 12.5% está acima do limite de 5%"
+        );
+        // Not a catalog key, so nothing is added
+        assert!(response.get("assumption_text").is_none());
+    }
+}
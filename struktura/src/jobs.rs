@@ -0,0 +1,531 @@
+//! Background calculation jobs
+//!
+//! Heavy calculations (Monte Carlo-style sensitivity sweeps over the OEE or
+//! contractor risk models) can outlast a request. A signed-in
+//! `POST /api/v1/calculus/{tier}/calculate` whose body carries
+//! `"async": true` is queued instead of run: the response is
+//! `202 Accepted` with the job id, and `GET /api/v1/jobs/{id}` reports the
+//! job's status and, once finished, its result or the error the request
+//! would have received. Query options such as `?trace=true` and
+//! `?audience=` apply to the queued run as they would have to the request.
+//!
+//! The queue lives in Postgres and jobs are claimed with
+//! `FOR UPDATE SKIP LOCKED`, so every instance can work it. Each runs
+//! `JOB_WORKERS` workers (default 2) that wake as soon as a job is queued
+//! on the same instance and otherwise poll every `JOB_POLL_SECONDS`
+//! (default 5). A job may run for `JOB_TIMEOUT_SECONDS` (default 600); jobs
+//! left running by an instance that went away are failed by the cleanup
+//! sweep.
+//!
+//! Jobs go through the same plan, quota and rollout checks as projects when
+//! they run, count towards usage and are recorded in the caller's history.
+//! While maintenance mode is on, workers pause and async requests simply
+//! run synchronously.
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{OriginalUri, Path, Query, Request, State},
+    http::{header, Method, StatusCode, Uri},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::types::Json as SqlJson;
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use crate::calculus::engineer::router::CalculateQuery;
+use crate::calculus::recommendation::Tier;
+use crate::projects::{self, ProjectError};
+use crate::sec::AppError;
+use crate::state::AppState;
+use crate::tenancy::{TenantOwned, TenantScope};
+
+const DEFAULT_WORKERS: usize = 2;
+const DEFAULT_POLL_SECONDS: u64 = 5;
+const DEFAULT_TIMEOUT_SECONDS: u64 = 600;
+
+/// Largest request body accepted for a queued calculation; matches the
+/// default body limit of the calculator routes
+const MAX_JOB_BYTES: usize = 2 * 1024 * 1024;
+
+/// Path recorded in calculation history for queued runs
+const HISTORY_PATH: &str = "/api/v1/jobs";
+
+// ============================================================================
+// Queue
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JobConfig {
+    pub workers: usize,
+    pub poll_interval: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for JobConfig {
+    fn default() -> Self {
+        Self {
+            workers: DEFAULT_WORKERS,
+            poll_interval: Duration::from_secs(DEFAULT_POLL_SECONDS),
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECONDS),
+        }
+    }
+}
+
+impl JobConfig {
+    pub fn from_env() -> Self {
+        Self::from_vars(
+            std::env::var("JOB_WORKERS").ok().as_deref(),
+            std::env::var("JOB_POLL_SECONDS").ok().as_deref(),
+            std::env::var("JOB_TIMEOUT_SECONDS").ok().as_deref(),
+        )
+    }
+
+    /// Unset or unparsable values keep their defaults; zero workers
+    /// disables job processing on this instance
+    fn from_vars(workers: Option<&str>, poll_seconds: Option<&str>, timeout_seconds: Option<&str>) -> Self {
+        let defaults = Self::default();
+        let seconds = |value: Option<&str>| {
+            value
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .filter(|s| *s > 0)
+                .map(Duration::from_secs)
+        };
+        Self {
+            workers: workers.and_then(|v| v.trim().parse().ok()).unwrap_or(defaults.workers),
+            poll_interval: seconds(poll_seconds).unwrap_or(defaults.poll_interval),
+            timeout: seconds(timeout_seconds).unwrap_or(defaults.timeout),
+        }
+    }
+}
+
+/// Handle on the job queue; the jobs themselves are rows in `calculation_jobs`
+#[derive(Clone, Default)]
+pub struct JobQueue {
+    config: JobConfig,
+    queued: Arc<Notify>,
+}
+
+impl JobQueue {
+    pub fn new(config: JobConfig) -> Self {
+        Self { config, queued: Arc::new(Notify::new()) }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(JobConfig::from_env())
+    }
+
+    pub fn config(&self) -> &JobConfig {
+        &self.config
+    }
+
+    /// Queue a calculation for the caller; returns the job id. `uri` is the
+    /// request's, so its query options apply when the job runs.
+    pub async fn enqueue(
+        &self,
+        pool: &PgPool,
+        scope: &TenantScope,
+        tier: Tier,
+        uri: &Uri,
+        calculation_type: &str,
+        parameters: &Value,
+    ) -> Result<Uuid, AppError> {
+        let id = sqlx::query_scalar::<_, Uuid>(
+            "INSERT INTO calculation_jobs (user_id, org_id, tier, request_uri, calculation_type, parameters) \
+             VALUES ($1, $2, $3, $4, $5, $6) RETURNING id",
+        )
+        .bind(scope.user_id)
+        .bind(scope.org_id)
+        .bind(tier.as_str())
+        .bind(uri.to_string())
+        .bind(calculation_type)
+        .bind(SqlJson(parameters))
+        .fetch_one(pool)
+        .await?;
+
+        self.queued.notify_one();
+        Ok(id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Queued => "queued",
+            JobStatus::Running => "running",
+            JobStatus::Succeeded => "succeeded",
+            JobStatus::Failed => "failed",
+        }
+    }
+}
+
+// ============================================================================
+// Intake
+// ============================================================================
+
+#[derive(Clone)]
+pub struct JobIntake {
+    state: Arc<AppState>,
+    tier: Tier,
+}
+
+impl JobIntake {
+    pub fn new(state: Arc<AppState>, tier: Tier) -> Self {
+        Self { state, tier }
+    }
+}
+
+/// The calculation a request asks to queue: its body has `"async": true`
+/// and names a calculator
+fn queued_calculation(body: &[u8]) -> Option<(String, Value)> {
+    let mut request: Value = serde_json::from_slice(body).ok()?;
+    if request.get("async") != Some(&Value::Bool(true)) {
+        return None;
+    }
+    let calculation_type = request.get("calculation_type")?.as_str()?.to_string();
+    let parameters = request.get_mut("parameters")?.take();
+    Some((calculation_type, parameters))
+}
+
+#[derive(Debug, Serialize)]
+pub struct JobAccepted {
+    pub job_id: Uuid,
+    pub status: JobStatus,
+    pub status_url: String,
+}
+
+/// Queue calculate requests flagged `async`; everything else passes through
+pub async fn intake_middleware(
+    State(intake): State<JobIntake>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let state = &intake.state;
+    if request.method() != Method::POST
+        || !request.uri().path().ends_with("/calculate")
+        || state.sandbox
+        || state.maintenance.is_active()
+    {
+        return next.run(request).await;
+    }
+
+    let (parts, body) = request.into_parts();
+    let Ok(request_body) = to_bytes(body, MAX_JOB_BYTES).await else {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+    let Some((calculation_type, parameters)) = queued_calculation(&request_body) else {
+        return next.run(Request::from_parts(parts, Body::from(request_body))).await;
+    };
+
+    // Only signed-in callers can come back for the result
+    let Some(scope) = parts.extensions.get::<TenantScope>() else {
+        return AppError::MissingToken.into_response();
+    };
+
+    // Nesting strips the path prefix; keep the URI the caller sent
+    let uri = parts.extensions.get::<OriginalUri>().map_or(&parts.uri, |original| &original.0);
+    match state.jobs.enqueue(&state.pool, scope, intake.tier, uri, &calculation_type, &parameters).await {
+        Ok(job_id) => {
+            let status_url = format!("/api/v1/jobs/{}", job_id);
+            let mut response = (
+                StatusCode::ACCEPTED,
+                Json(JobAccepted { job_id, status: JobStatus::Queued, status_url: status_url.clone() }),
+            )
+                .into_response();
+            if let Ok(location) = status_url.parse() {
+                response.headers_mut().insert(header::LOCATION, location);
+            }
+            response
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+// ============================================================================
+// Workers
+// ============================================================================
+
+#[derive(sqlx::FromRow)]
+struct ClaimedJob {
+    id: Uuid,
+    user_id: Uuid,
+    tier: String,
+    request_uri: Option<String>,
+    calculation_type: String,
+    parameters: SqlJson<Value>,
+}
+
+impl ClaimedJob {
+    /// The `trace` and `audience` options of the request that queued it
+    fn query(&self) -> CalculateQuery {
+        self.request_uri
+            .as_deref()
+            .and_then(|uri| uri.parse::<Uri>().ok())
+            .and_then(|uri| Query::<CalculateQuery>::try_from_uri(&uri).ok())
+            .map(|Query(query)| query)
+            .unwrap_or_default()
+    }
+}
+
+/// Take the oldest queued job, if any
+async fn claim(pool: &PgPool) -> Result<Option<ClaimedJob>, sqlx::Error> {
+    sqlx::query_as::<_, ClaimedJob>(
+        "UPDATE calculation_jobs SET status = 'running', started_at = CURRENT_TIMESTAMP, attempts = attempts + 1 \
+         WHERE id = (SELECT id FROM calculation_jobs WHERE status = 'queued' \
+                     ORDER BY created_at FOR UPDATE SKIP LOCKED LIMIT 1) \
+         RETURNING id, user_id, tier, request_uri, calculation_type, parameters",
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+/// What a finished job stores
+#[derive(Debug)]
+struct Finished {
+    status: JobStatus,
+    result: Option<Value>,
+    error: Option<Value>,
+    http_status: u16,
+}
+
+impl Finished {
+    fn failed(http_status: StatusCode, error: Value) -> Self {
+        Self { status: JobStatus::Failed, result: None, error: Some(error), http_status: http_status.as_u16() }
+    }
+
+    /// The error response the request would have received, kept as JSON
+    async fn from_response(response: Response) -> Self {
+        let status = response.status();
+        let body = to_bytes(response.into_body(), MAX_JOB_BYTES).await.unwrap_or_default();
+        let error = serde_json::from_slice(&body)
+            .unwrap_or_else(|_| serde_json::json!({"error": String::from_utf8_lossy(&body)}));
+        Self::failed(status, error)
+    }
+}
+
+async fn execute(state: &AppState, job: ClaimedJob) -> Finished {
+    let Ok(tier) = job.tier.parse::<Tier>() else {
+        return Finished::failed(StatusCode::BAD_REQUEST, serde_json::json!({"error": "Unknown tier"}));
+    };
+    let scope = match TenantScope::for_user(&state.pool, job.user_id).await {
+        Ok(scope) => scope,
+        Err(e) => return Finished::from_response(e.into_response()).await,
+    };
+
+    let query = job.query();
+    let run = projects::run_for_caller(
+        state,
+        &scope,
+        HISTORY_PATH,
+        tier,
+        &job.calculation_type,
+        job.parameters.0,
+        Some(&query),
+    );
+    match tokio::time::timeout(state.jobs.config.timeout, run).await {
+        Ok(Ok(executed)) => Finished {
+            status: JobStatus::Succeeded,
+            result: Some(executed.response),
+            error: None,
+            http_status: StatusCode::OK.as_u16(),
+        },
+        Ok(Err(ProjectError::Rejected(response))) => Finished::from_response(*response).await,
+        Ok(Err(ProjectError::App(e))) => Finished::from_response(e.into_response()).await,
+        Err(_) => Finished::failed(
+            StatusCode::GATEWAY_TIMEOUT,
+            serde_json::json!({"error": format!("Job exceeded {} seconds", state.jobs.config.timeout.as_secs())}),
+        ),
+    }
+}
+
+async fn work_one(state: &AppState, job: ClaimedJob) -> Result<(), sqlx::Error> {
+    let id = job.id;
+    let finished = execute(state, job).await;
+
+    sqlx::query(
+        "UPDATE calculation_jobs SET status = $2, result = $3, error = $4, http_status = $5, \
+         finished_at = CURRENT_TIMESTAMP WHERE id = $1",
+    )
+    .bind(id)
+    .bind(finished.status.as_str())
+    .bind(finished.result.map(SqlJson))
+    .bind(finished.error.map(SqlJson))
+    .bind(finished.http_status as i16)
+    .execute(&state.pool)
+    .await?;
+
+    tracing::info!(job_id = %id, status = finished.status.as_str(), "calculation job finished");
+    Ok(())
+}
+
+/// Start this instance's workers
+pub fn spawn(state: Arc<AppState>) {
+    for _ in 0..state.jobs.config.workers {
+        let state = state.clone();
+        tokio::spawn(async move {
+            loop {
+                let claimed = if state.maintenance.is_active() { Ok(None) } else { claim(&state.pool).await };
+                match claimed {
+                    Ok(Some(job)) => {
+                        if let Err(e) = work_one(&state, job).await {
+                            tracing::error!(error = %e, "failed to store calculation job outcome");
+                        }
+                        continue;
+                    }
+                    Ok(None) => {}
+                    Err(e) => tracing::error!(error = %e, "failed to claim calculation job"),
+                }
+                tokio::select! {
+                    _ = state.jobs.queued.notified() => {}
+                    _ = tokio::time::sleep(state.jobs.config.poll_interval) => {}
+                }
+            }
+        });
+    }
+}
+
+/// Fail jobs still marked running well past the timeout: the instance
+/// running them went away. Returns how many.
+pub async fn fail_abandoned(pool: &PgPool, timeout: Duration) -> Result<u64, sqlx::Error> {
+    let abandoned_after = time::Duration::try_from(timeout * 2).unwrap_or(time::Duration::HOUR);
+    Ok(sqlx::query(
+        "UPDATE calculation_jobs SET status = 'failed', http_status = 500, finished_at = CURRENT_TIMESTAMP, \
+         error = '{\"error\": \"Job abandoned by its worker\"}' \
+         WHERE status = 'running' AND started_at < NOW() - $1::interval",
+    )
+    .bind(abandoned_after)
+    .execute(pool)
+    .await?
+    .rows_affected())
+}
+
+// ============================================================================
+// Status
+// ============================================================================
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct Job {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub tier: String,
+    pub calculation_type: String,
+    pub status: String,
+    pub result: Option<SqlJson<Value>>,
+    pub error: Option<SqlJson<Value>>,
+    pub http_status: Option<i16>,
+    pub created_at: OffsetDateTime,
+    pub started_at: Option<OffsetDateTime>,
+    pub finished_at: Option<OffsetDateTime>,
+}
+
+impl TenantOwned for Job {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+const JOB_COLUMNS: &str = "id, org_id, tier, calculation_type, status, result, error, http_status, \
+    created_at, started_at, finished_at";
+
+/// Summary of a job in the caller's list; results are fetched one at a time
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct JobSummary {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub tier: String,
+    pub calculation_type: String,
+    pub status: String,
+    pub created_at: OffsetDateTime,
+    pub finished_at: Option<OffsetDateTime>,
+}
+
+impl TenantOwned for JobSummary {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+/// GET /api/v1/jobs
+/// The caller's 50 most recent jobs
+pub async fn list_jobs_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+) -> Result<Json<Vec<JobSummary>>, AppError> {
+    let jobs = sqlx::query_as::<_, JobSummary>(
+        "SELECT id, org_id, tier, calculation_type, status, created_at, finished_at FROM calculation_jobs \
+         WHERE user_id = $1 AND org_id = $2 ORDER BY created_at DESC LIMIT 50",
+    )
+    .bind(scope.user_id)
+    .bind(scope.org_id)
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(scope.guard_all(jobs)?))
+}
+
+/// GET /api/v1/jobs/{id}
+pub async fn get_job_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Job>, AppError> {
+    let job = sqlx::query_as::<_, Job>(&format!(
+        "SELECT {JOB_COLUMNS} FROM calculation_jobs WHERE id = $1 AND user_id = $2 AND org_id = $3"
+    ))
+    .bind(id)
+    .bind(scope.user_id)
+    .bind(scope.org_id)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    Ok(Json(scope.guard(job)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_from_vars() {
+        assert_eq!(JobConfig::from_vars(None, None, None), JobConfig::default());
+
+        let config = JobConfig::from_vars(Some("0"), Some(" 1 "), Some("60"));
+        assert_eq!(config.workers, 0);
+        assert_eq!(config.poll_interval, Duration::from_secs(1));
+        assert_eq!(config.timeout, Duration::from_secs(60));
+
+        assert_eq!(JobConfig::from_vars(Some("many"), Some("0"), Some("-5")), JobConfig::default());
+    }
+
+    #[test]
+    fn test_only_flagged_requests_are_queued() {
+        let (calculation_type, parameters) =
+            queued_calculation(br#"{"calculation_type": "oee", "parameters": {"dimensions": {}}, "async": true}"#).unwrap();
+        assert_eq!(calculation_type, "oee");
+        assert_eq!(parameters, serde_json::json!({"dimensions": {}}));
+
+        assert!(queued_calculation(br#"{"calculation_type": "oee", "parameters": {}}"#).is_none());
+        assert!(queued_calculation(br#"{"calculation_type": "oee", "parameters": {}, "async": "yes"}"#).is_none());
+        assert!(queued_calculation(br#"{"parameters": {}, "async": true}"#).is_none());
+        assert!(queued_calculation(b"not json").is_none());
+    }
+}
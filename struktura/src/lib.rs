@@ -1,8 +1,35 @@
 // Re-export modules for testing
 pub mod auth;
+pub mod backup;
+pub mod billing;
+pub mod flags;
+pub mod i18n;
+pub mod jobs;
+pub mod load_summary;
 pub mod stats;
 pub mod sec;
 pub mod state;
+pub mod status;
 pub mod calculus;
-//pub mod pricing;
-pub mod seo;
\ No newline at end of file
+pub mod cleanup;
+pub mod defaults;
+pub mod maintenance;
+pub mod materials;
+pub mod migrate;
+pub mod notifications;
+pub mod notify;
+pub mod password;
+pub mod projects;
+pub mod reports;
+pub mod resilience;
+pub mod support;
+pub mod telemetry;
+pub mod tenancy;
+pub mod totp;
+pub mod trial;
+pub mod pricing;
+pub mod scim;
+pub mod sdk;
+pub mod seo;
+pub mod sso;
+pub mod utils;
\ No newline at end of file
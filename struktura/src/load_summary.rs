@@ -0,0 +1,543 @@
+//! Building and campus load summaries from saved project calculations
+//!
+//! A project holding one HVAC or electrical load calculation per room, unit
+//! or building can be rolled up into a single summary. Loads are read from
+//! the stored responses (nothing is recalculated), summed per service,
+//! reduced by a diversity factor for peaks that do not coincide, and turned
+//! into plant sizing inputs: chiller and boiler capacity split across duty
+//! and standby units, and the electrical service and transformer.
+//!
+//! Recognized calculations:
+//! - `hvac_load_calculation` (engineer): cooling when the outdoor design
+//!   temperature is above indoor, heating otherwise
+//! - `electrical_service_load` (beginner): total calculated load
+//! - `generator_ups_sizing` (engineer): running load
+//!
+//! Other calculations in the project are listed as skipped. Figures are
+//! reported in metric whatever unit system the calculations were saved in.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::calculus::recommendation::Tier;
+use crate::projects::{self, SavedCalculation};
+use crate::sec::AppError;
+use crate::state::AppState;
+use crate::tenancy::TenantScope;
+use crate::utils::conversions::UnitSystem;
+
+/// Watts per ton of refrigeration
+const W_PER_TON: f64 = 3_516.852_842;
+
+/// Standard three-phase service ratings (A)
+const SERVICE_RATINGS: [f64; 11] = [200.0, 400.0, 600.0, 800.0, 1000.0, 1200.0, 1600.0, 2000.0, 2500.0, 3000.0, 4000.0];
+
+/// Standard three-phase pad-mount transformer ratings (kVA)
+const TRANSFORMER_RATINGS: [f64; 14] = [
+    75.0, 112.5, 150.0, 225.0, 300.0, 500.0, 750.0, 1000.0, 1500.0, 2000.0, 2500.0, 3000.0, 3750.0, 5000.0,
+];
+
+// =============================================================================
+// MODELS
+// =============================================================================
+
+fn default_cooling_diversity() -> f64 {
+    0.85
+}
+
+fn default_heating_diversity() -> f64 {
+    1.0
+}
+
+fn default_electrical_diversity() -> f64 {
+    0.8
+}
+
+fn default_design_margin() -> f64 {
+    0.1
+}
+
+fn default_duty_units() -> u32 {
+    2
+}
+
+fn default_standby_units() -> u32 {
+    1
+}
+
+fn default_chiller_cop() -> f64 {
+    5.0
+}
+
+fn default_service_voltage() -> f64 {
+    480.0
+}
+
+fn default_include_plant_power() -> bool {
+    true
+}
+
+/// POST /api/v1/user/projects/{id}/load-summary body
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct LoadSummaryRequest {
+    /// Calculations to include; every recognized one when absent
+    #[serde(default)]
+    pub calculation_ids: Option<Vec<Uuid>>,
+    /// Share of the summed room cooling peaks seen at the plant
+    #[serde(default = "default_cooling_diversity")]
+    #[validate(range(min = 0.3, max = 1.0))]
+    pub cooling_diversity: f64,
+    /// Share of the summed heating peaks seen at the plant
+    #[serde(default = "default_heating_diversity")]
+    #[validate(range(min = 0.3, max = 1.0))]
+    pub heating_diversity: f64,
+    /// Share of the summed electrical demands seen at the service
+    #[serde(default = "default_electrical_diversity")]
+    #[validate(range(min = 0.3, max = 1.0))]
+    pub electrical_diversity: f64,
+    /// Allowance for growth and pull-down added to plant capacity
+    #[serde(default = "default_design_margin")]
+    #[validate(range(min = 0.0, max = 0.5))]
+    pub design_margin: f64,
+    /// Units sharing the design load in each plant
+    #[serde(default = "default_duty_units")]
+    #[validate(range(min = 1, max = 12))]
+    pub duty_units: u32,
+    /// Installed spares in each plant (1 for N+1)
+    #[serde(default = "default_standby_units")]
+    #[validate(range(min = 0, max = 4))]
+    pub standby_units: u32,
+    /// Chiller coefficient of performance, for the power it adds to the service
+    #[serde(default = "default_chiller_cop")]
+    #[validate(range(min = 1.0, max = 12.0))]
+    pub chiller_cop: f64,
+    /// Three-phase service voltage (V)
+    #[serde(default = "default_service_voltage")]
+    #[validate(range(min = 200.0, max = 35000.0))]
+    pub service_voltage: f64,
+    /// Add the chiller input power to the electrical demand; turn off when
+    /// the electrical calculations already carry it
+    #[serde(default = "default_include_plant_power")]
+    pub include_plant_power: bool,
+}
+
+impl Default for LoadSummaryRequest {
+    fn default() -> Self {
+        Self {
+            calculation_ids: None,
+            cooling_diversity: default_cooling_diversity(),
+            heating_diversity: default_heating_diversity(),
+            electrical_diversity: default_electrical_diversity(),
+            design_margin: default_design_margin(),
+            duty_units: default_duty_units(),
+            standby_units: default_standby_units(),
+            chiller_cop: default_chiller_cop(),
+            service_voltage: default_service_voltage(),
+            include_plant_power: default_include_plant_power(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadKind {
+    Cooling,
+    Heating,
+    Electrical,
+}
+
+/// One saved calculation's contribution
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadSource {
+    pub calculation_id: Uuid,
+    pub label: Option<String>,
+    pub calculation_type: String,
+    pub kind: LoadKind,
+    /// kW for cooling and heating, kVA for electrical
+    pub load: f64,
+}
+
+/// A calculation left out of the summary, and why
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedCalculation {
+    pub calculation_id: Uuid,
+    pub calculation_type: String,
+    pub reason: String,
+}
+
+/// Summed and diversified load of one service
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadTotal {
+    pub sources: usize,
+    pub connected: f64,
+    /// Applied only when more than one source contributes
+    pub diversity_factor: f64,
+    pub diversified: f64,
+    pub unit: &'static str,
+}
+
+/// Capacity of a chiller or boiler plant and how it is split
+#[derive(Debug, Clone, Serialize)]
+pub struct PlantUnits {
+    /// Diversified load plus the design margin (kW)
+    pub design_capacity: f64,
+    pub duty_units: u32,
+    pub standby_units: u32,
+    /// Capacity of each unit (kW)
+    pub unit_capacity: f64,
+    pub installed_capacity: f64,
+}
+
+/// Electrical service for the diversified demand
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceSizing {
+    /// Diversified electrical load plus plant power (kVA)
+    pub demand: f64,
+    /// Chiller input power included in `demand` (kVA)
+    pub plant_power: f64,
+    pub voltage: f64,
+    pub current: f64,
+    /// Next standard service rating (A); `None` above the largest
+    pub service_rating: Option<f64>,
+    /// Next standard transformer rating (kVA); `None` above the largest
+    pub transformer_rating: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PlantSizing {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chiller: Option<PlantUnits>,
+    /// Chiller capacity per unit in tons of refrigeration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chiller_unit_tons: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boiler: Option<PlantUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<ServiceSizing>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadSummary {
+    pub project_id: Uuid,
+    pub sources: Vec<LoadSource>,
+    pub skipped: Vec<SkippedCalculation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cooling: Option<LoadTotal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heating: Option<LoadTotal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub electrical: Option<LoadTotal>,
+    pub plant: PlantSizing,
+    pub warnings: Vec<String>,
+}
+
+// =============================================================================
+// READING SAVED RESPONSES
+// =============================================================================
+
+/// Value of the result labelled `label`, with its unit
+fn result(response: &Value, label: &str) -> Option<(f64, String)> {
+    response["results"].as_array()?.iter().find(|r| r["label"] == label).and_then(|r| {
+        let value = r["value"].as_f64()?;
+        let unit = r["unit"].as_str().unwrap_or_default().to_string();
+        Some((value, unit))
+    })
+}
+
+/// A power in kW (or an apparent power in kVA) from a stored result
+fn to_kilo(value: f64, unit: &str) -> Option<f64> {
+    match unit {
+        "kW" | "kVA" => Some(value),
+        "W" | "VA" => Some(value / 1000.0),
+        "hp" => Some(UnitSystem::Imperial.to_metric(value, "kW")),
+        "tons" | "TR" => Some(value * W_PER_TON / 1000.0),
+        _ => None,
+    }
+}
+
+fn additional(parameters: &Value, key: &str) -> Option<f64> {
+    parameters["additional"][key].as_f64()
+}
+
+/// What a saved calculation contributes, or why it contributes nothing
+fn read_load(calculation: &SavedCalculation) -> Result<(LoadKind, f64), String> {
+    let (kind, label) = match (calculation.tier, calculation.calculation_type.as_str()) {
+        (Tier::Engineer, "hvac_load_calculation") => {
+            // Both temperatures are in the unit system the calculation was saved in
+            let outdoor = additional(&calculation.parameters, "outdoor_temp");
+            let indoor = additional(&calculation.parameters, "indoor_temp");
+            let kind = match (outdoor, indoor) {
+                (Some(outdoor), Some(indoor)) if outdoor > indoor => LoadKind::Cooling,
+                (Some(_), Some(_)) => LoadKind::Heating,
+                _ => return Err("Design temperatures missing from the saved parameters".to_string()),
+            };
+            (kind, "Total Load")
+        }
+        (Tier::Beginner, "electrical_service_load") => (LoadKind::Electrical, "Total Calculated Load"),
+        (Tier::Engineer, "generator_ups_sizing") => (LoadKind::Electrical, "Running Load kVA"),
+        _ => return Err("Not an HVAC or electrical load calculation".to_string()),
+    };
+
+    let (value, unit) =
+        result(&calculation.response, label).ok_or_else(|| format!("Saved response has no '{}' result", label))?;
+    let load = to_kilo(value, &unit).ok_or_else(|| format!("Unrecognized unit '{}' for '{}'", unit, label))?;
+    Ok((kind, load))
+}
+
+// =============================================================================
+// AGGREGATION
+// =============================================================================
+
+fn total(sources: &[LoadSource], kind: LoadKind, diversity: f64, unit: &'static str) -> Option<LoadTotal> {
+    let loads: Vec<f64> = sources.iter().filter(|s| s.kind == kind).map(|s| s.load).collect();
+    if loads.is_empty() {
+        return None;
+    }
+    let connected: f64 = loads.iter().sum();
+    let diversity_factor = if loads.len() > 1 { diversity } else { 1.0 };
+    Some(LoadTotal {
+        sources: loads.len(),
+        connected,
+        diversity_factor,
+        diversified: connected * diversity_factor,
+        unit,
+    })
+}
+
+fn plant_units(load: &LoadTotal, request: &LoadSummaryRequest) -> PlantUnits {
+    let design_capacity = load.diversified * (1.0 + request.design_margin);
+    let unit_capacity = design_capacity / request.duty_units as f64;
+    PlantUnits {
+        design_capacity,
+        duty_units: request.duty_units,
+        standby_units: request.standby_units,
+        unit_capacity,
+        installed_capacity: unit_capacity * (request.duty_units + request.standby_units) as f64,
+    }
+}
+
+/// Smallest standard rating at or above `value`
+fn next_rating(ratings: &[f64], value: f64) -> Option<f64> {
+    ratings.iter().copied().find(|&r| r >= value)
+}
+
+/// Roll the calculations up into a load summary
+pub fn summarize(project_id: Uuid, calculations: &[SavedCalculation], request: &LoadSummaryRequest) -> LoadSummary {
+    let selected: Option<HashSet<Uuid>> = request.calculation_ids.as_ref().map(|ids| ids.iter().copied().collect());
+
+    let mut sources = Vec::new();
+    let mut skipped = Vec::new();
+    for calculation in calculations {
+        if selected.as_ref().is_some_and(|ids| !ids.contains(&calculation.id)) {
+            continue;
+        }
+        match read_load(calculation) {
+            Ok((kind, load)) => sources.push(LoadSource {
+                calculation_id: calculation.id,
+                label: calculation.label.clone(),
+                calculation_type: calculation.calculation_type.clone(),
+                kind,
+                load,
+            }),
+            Err(reason) => skipped.push(SkippedCalculation {
+                calculation_id: calculation.id,
+                calculation_type: calculation.calculation_type.clone(),
+                reason,
+            }),
+        }
+    }
+
+    let mut warnings = Vec::new();
+    if let Some(ids) = &selected {
+        let missing = ids.iter().filter(|id| !calculations.iter().any(|c| c.id == **id)).count();
+        if missing > 0 {
+            warnings.push(format!("{} requested calculation(s) are not in this project", missing));
+        }
+    }
+
+    let cooling = total(&sources, LoadKind::Cooling, request.cooling_diversity, "kW");
+    let heating = total(&sources, LoadKind::Heating, request.heating_diversity, "kW");
+    let electrical = total(&sources, LoadKind::Electrical, request.electrical_diversity, "kVA");
+
+    let mut plant = PlantSizing::default();
+    if let Some(cooling) = &cooling {
+        let chiller = plant_units(cooling, request);
+        plant.chiller_unit_tons = Some(chiller.unit_capacity * 1000.0 / W_PER_TON);
+        plant.chiller = Some(chiller);
+    }
+    plant.boiler = heating.as_ref().map(|heating| plant_units(heating, request));
+
+    let plant_power = match &plant.chiller {
+        Some(chiller) if request.include_plant_power => chiller.design_capacity / request.chiller_cop,
+        _ => 0.0,
+    };
+    if electrical.is_some() || plant_power > 0.0 {
+        let demand = electrical.as_ref().map_or(0.0, |e| e.diversified) + plant_power;
+        let current = demand * 1000.0 / (3f64.sqrt() * request.service_voltage);
+        let service_rating = next_rating(&SERVICE_RATINGS, current);
+        let transformer_rating = next_rating(&TRANSFORMER_RATINGS, demand);
+        if service_rating.is_none() {
+            warnings.push(format!(
+                "Service current of {:.0} A exceeds a {:.0} A service. Consider a higher service voltage or several services.",
+                current,
+                SERVICE_RATINGS[SERVICE_RATINGS.len() - 1]
+            ));
+        }
+        if transformer_rating.is_none() {
+            warnings.push(format!(
+                "Demand of {:.0} kVA exceeds a single {:.0} kVA transformer.",
+                demand,
+                TRANSFORMER_RATINGS[TRANSFORMER_RATINGS.len() - 1]
+            ));
+        }
+        plant.service = Some(ServiceSizing {
+            demand,
+            plant_power,
+            voltage: request.service_voltage,
+            current,
+            service_rating,
+            transformer_rating,
+        });
+    }
+
+    if sources.is_empty() {
+        warnings.push("No HVAC or electrical load calculations to summarize".to_string());
+    }
+    if request.standby_units == 0 && (plant.chiller.is_some() || plant.boiler.is_some()) {
+        warnings.push("No standby units: losing one unit leaves the plant short of the design load".to_string());
+    }
+
+    LoadSummary {
+        project_id,
+        sources,
+        skipped,
+        cooling,
+        heating,
+        electrical,
+        plant,
+        warnings,
+    }
+}
+
+// =============================================================================
+// HANDLERS
+// =============================================================================
+
+/// Summarize the project's load calculations
+pub async fn load_summary_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(request): Json<LoadSummaryRequest>,
+) -> Result<Json<LoadSummary>, AppError> {
+    request.validate()?;
+    let project = projects::load_project(&state, &scope, id).await?;
+    let calculations = projects::load_calculations(&state, project.id).await?;
+
+    Ok(Json(summarize(project.id, &calculations, &request)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use sqlx::types::time::OffsetDateTime;
+
+    fn saved(tier: Tier, calculation_type: &str, parameters: Value, results: Value) -> SavedCalculation {
+        SavedCalculation {
+            id: Uuid::new_v4(),
+            project_id: Uuid::nil(),
+            tier,
+            calculation_type: calculation_type.to_string(),
+            label: None,
+            parameters,
+            response: json!({ "calculation_type": calculation_type, "results": results }),
+            calculator_version: "1.0.0".to_string(),
+            created_at: OffsetDateTime::UNIX_EPOCH,
+            last_run_at: OffsetDateTime::UNIX_EPOCH,
+        }
+    }
+
+    fn room(outdoor: f64, indoor: f64, kw: f64) -> SavedCalculation {
+        saved(
+            Tier::Engineer,
+            "hvac_load_calculation",
+            json!({ "additional": { "outdoor_temp": outdoor, "indoor_temp": indoor } }),
+            json!([{ "label": "Total Load", "value": kw, "unit": "kW" }]),
+        )
+    }
+
+    #[test]
+    fn test_summarize_diversifies_and_sizes_plant() {
+        let calculations = vec![
+            room(35.0, 24.0, 40.0),
+            room(35.0, 24.0, 60.0),
+            room(-10.0, 21.0, 50.0),
+            saved(
+                Tier::Beginner,
+                "electrical_service_load",
+                json!({}),
+                json!([{ "label": "Total Calculated Load", "value": 100_000.0, "unit": "VA" }]),
+            ),
+            saved(
+                Tier::Engineer,
+                "generator_ups_sizing",
+                json!({}),
+                json!([{ "label": "Running Load kVA", "value": 150.0, "unit": "kVA" }]),
+            ),
+            saved(Tier::Beginner, "baseboard", json!({}), json!([])),
+        ];
+
+        let summary = summarize(Uuid::nil(), &calculations, &LoadSummaryRequest::default());
+
+        assert_eq!(summary.sources.len(), 5);
+        assert_eq!(summary.skipped.len(), 1);
+
+        let cooling = summary.cooling.unwrap();
+        assert_eq!(cooling.connected, 100.0);
+        assert!((cooling.diversified - 85.0).abs() < 1e-9);
+        // A single heating source is not diversified
+        let heating = summary.heating.unwrap();
+        assert_eq!((heating.diversity_factor, heating.diversified), (1.0, 50.0));
+        assert!((summary.electrical.unwrap().diversified - 200.0).abs() < 1e-9);
+
+        // 85 kW + 10% over two duty units, one standby
+        let chiller = summary.plant.chiller.unwrap();
+        assert!((chiller.design_capacity - 93.5).abs() < 1e-9);
+        assert!((chiller.unit_capacity - 46.75).abs() < 1e-9);
+        assert!((chiller.installed_capacity - 140.25).abs() < 1e-9);
+        assert!((summary.plant.chiller_unit_tons.unwrap() - 13.29).abs() < 0.01);
+
+        // 200 kVA + 93.5/5 kVA of chiller power at 480 V: ≈263 A
+        let service = summary.plant.service.unwrap();
+        assert!((service.demand - 218.7).abs() < 1e-9);
+        assert!((service.current - 263.1).abs() < 0.1);
+        assert_eq!(service.service_rating, Some(400.0));
+        assert_eq!(service.transformer_rating, Some(225.0));
+    }
+
+    #[test]
+    fn test_summarize_selection_and_imperial_results() {
+        let mut imperial = room(95.0, 75.0, 134.102);
+        imperial.response["results"][0]["unit"] = json!("hp");
+        let other = room(35.0, 24.0, 10.0);
+        let request = LoadSummaryRequest {
+            calculation_ids: Some(vec![imperial.id, Uuid::new_v4()]),
+            ..Default::default()
+        };
+
+        let summary = summarize(Uuid::nil(), &[imperial, other], &request);
+
+        assert_eq!(summary.sources.len(), 1);
+        assert!((summary.sources[0].load - 100.0).abs() < 0.01);
+        assert!(summary.warnings.iter().any(|w| w.contains("not in this project")));
+    }
+}
@@ -8,10 +8,10 @@
 
 use axum::{
     http::{StatusCode, Method, HeaderValue, HeaderName},
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
     Router, middleware,
     response::{Html, IntoResponse},
-    extract::Query,
+    extract::{DefaultBodyLimit, Query},
 };
 use sqlx::postgres::PgPoolOptions; // Changed from just PgPool
 use std::sync::Arc;
@@ -30,12 +30,38 @@ use std::net::SocketAddr;
 use tokio::net::TcpListener;
 
 pub mod auth; 
+pub mod backup;
+pub mod billing;
+pub mod flags;
+pub mod i18n;
+pub mod jobs;
+pub mod load_summary;
 pub mod stats;
 pub mod sec;
 pub mod state;
+pub mod status;
 pub mod calculus;
-//pub mod pricing;
+pub mod cleanup;
+pub mod defaults;
+pub mod maintenance;
+pub mod materials;
+pub mod migrate;
+pub mod notifications;
+pub mod notify;
+pub mod password;
+pub mod projects;
+pub mod reports;
+pub mod resilience;
+pub mod support;
+pub mod telemetry;
+pub mod tenancy;
+pub mod totp;
+pub mod trial;
+pub mod pricing;
+pub mod scim;
 pub mod seo;
+pub mod sso;
+pub mod utils;
 
 use sec::{
     Claims, SecurityConfig, TokenBlacklist, CsrfTokenStore, 
@@ -43,6 +69,12 @@ use sec::{
 };
 use state::{AppState, IpRateLimiter};
 use seo::{index_handler, sitemap_handler};
+use calculus::engineer::calculators::production::oee::profiles as oee_profiles;
+use calculus::contractor::closeout::saved as punch_lists;
+use calculus::contractor::field_log::saved as field_reports;
+use calculus::contractor::inspections::saved as inspections;
+use calculus::contractor::prequal::saved as prequal_models;
+use calculus::contractor::schedule::saved as saved_schedules;
 
 async fn health_check() -> axum::http::StatusCode {
     StatusCode::OK
@@ -50,21 +82,39 @@ async fn health_check() -> axum::http::StatusCode {
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    let _telemetry = telemetry::init();
+
     // Load .env file if it exists (requires dotenvy crate or similar)
     // dotenvy::dotenv().ok(); 
 
+    // Sandbox mode runs without Postgres: the pool is never connected and
+    // only DB-free routes (calculators, demo responses) are useful
+    let sandbox = std::env::var("STRUKTURA_SANDBOX")
+        .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false);
+
     // 1. Database Connection
-    let database_url = std::env::var("DATABASE_URL")
-        .context("DATABASE_URL environment variable must be set")?;
+    let pool = if sandbox {
+        let database_url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| "postgres://sandbox@localhost/sandbox".to_string());
+        PgPoolOptions::new()
+            .acquire_timeout(Duration::from_secs(1))
+            .connect_lazy(&database_url)
+            .context("Invalid DATABASE_URL")?
+    } else {
+        let database_url = std::env::var("DATABASE_URL")
+            .context("DATABASE_URL environment variable must be set")?;
 
-    let pool = PgPoolOptions::new()
-        .max_connections(50)
-        .acquire_timeout(Duration::from_secs(3))
-        .connect(&database_url)
-        .await
-        .context("Failed to connect to Postgres")?;
+        let pool = PgPoolOptions::new()
+            .max_connections(50)
+            .acquire_timeout(Duration::from_secs(3))
+            .connect(&database_url)
+            .await
+            .context("Failed to connect to Postgres")?;
 
-    sqlx::migrate!("./migrations").run(&pool).await?;
+        migrate::run(&pool, migrate::MigrationPolicy::from_env()?).await?;
+        pool
+    };
 
     // 2. Environment Configuration
     let jwt_secret = std::env::var("JWT_SECRET")
@@ -80,7 +130,9 @@ async fn main() -> anyhow::Result<()> {
     
     // 3. State Initialization
     let quota = Quota::per_minute(std::num::NonZeroU32::new(100).unwrap());
-    let rate_limiter: IpRateLimiter = Arc::new(governor::RateLimiter::dashmap(quota));
+    let rate_limiter: IpRateLimiter = Arc::new(
+        governor::RateLimiter::dashmap(quota).with_middleware::<governor::middleware::StateInformationMiddleware>(),
+    );
 
     let security_config = SecurityConfig {
         allowed_origins: allowed_origins.clone(),
@@ -92,6 +144,26 @@ async fn main() -> anyhow::Result<()> {
     let calculators_engineer = Arc::new(calculus::engineer::create_default_registry());
     let calculators_contractor = Arc::new(calculus::contractor::create_default_registry());
     
+    let trial_limit = if sandbox {
+        u32::MAX
+    } else {
+        std::env::var("TRIAL_CALCULATION_LIMIT")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(trial::DEFAULT_TRIAL_LIMIT)
+    };
+
+    // Pricing engine: live search, then bundled prices, with live FX rates
+    let pricing_engine = pricing::init_pricing_engine((!sandbox).then(|| pool.clone()))
+        .await
+        .context("Failed to initialize pricing engine")?;
+
+    let demo = calculus::demo::DemoCatalogue::build(
+        &calculators_beginner,
+        &calculators_engineer,
+        &calculators_contractor,
+    ).await;
+
     let app_state = AppState {
         pool,
         jwt_secret,
@@ -102,10 +174,32 @@ async fn main() -> anyhow::Result<()> {
         calculators_beginner,
         calculators_engineer,
         calculators_contractor,
+        sketch_vision: None,
+        trial_quota: trial::TrialQuota::new(trial_limit),
+        billing: if sandbox { None } else { billing::BillingConfig::from_env().map(Arc::new) },
+        flags: if sandbox { flags::FeatureFlags::offline() } else { flags::FeatureFlags::default() },
+        demo: Arc::new(demo),
+        tenants: tenancy::TenantDirectory::from_env(),
+        maintenance: maintenance::Maintenance::from_env(),
+        password_policy: password::PasswordPolicy::from_env(),
+        mailer: notifications::mailer_from_env(),
+        jobs: jobs::JobQueue::from_env(),
+        pricing: Arc::new(pricing_engine),
+        sandbox,
     };
 
     let shared_state = Arc::new(app_state);
 
+    // Milestone alerts, queued calculations and status probes (need the database)
+    if !sandbox {
+        saved_schedules::spawn_alert_sweep(shared_state.pool.clone());
+        jobs::spawn(shared_state.clone());
+        status::spawn(shared_state.clone());
+    }
+
+    // Expired tokens and stale data
+    cleanup::spawn(shared_state.clone());
+
     // 4. Middleware & Router Setup
     let cors_layer = tower_http::cors::CorsLayer::new()
         .allow_origin(
@@ -120,15 +214,30 @@ async fn main() -> anyhow::Result<()> {
             axum::http::header::AUTHORIZATION,
             axum::http::header::CONTENT_TYPE,
             HeaderName::from_static("x-csrf-token"),
+            HeaderName::from_static(trial::TRIAL_TOKEN_HEADER),
+            HeaderName::from_static(support::SUPPORT_BUNDLE_HEADER),
+            HeaderName::from_static(utils::versioning::VERSION_HEADER),
+        ])
+        .expose_headers([
+            HeaderName::from_static(trial::TRIAL_TOKEN_HEADER),
+            HeaderName::from_static(trial::TRIAL_REMAINING_HEADER),
+            HeaderName::from_static(support::SUPPORT_BUNDLE_ID_HEADER),
+            HeaderName::from_static(utils::versioning::VERSION_HEADER),
+            HeaderName::from_static(sec::RATE_LIMIT_LIMIT_HEADER),
+            HeaderName::from_static(sec::RATE_LIMIT_REMAINING_HEADER),
+            HeaderName::from_static(sec::RATE_LIMIT_RESET_HEADER),
+            axum::http::header::RETRY_AFTER,
         ])
         .allow_credentials(true)
         .max_age(Duration::from_secs(3600));
 
     let middleware_stack = tower::ServiceBuilder::new()
         .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
-        .layer(TraceLayer::new_for_http())
+        .layer(TraceLayer::new_for_http().make_span_with(telemetry::request_span))
         .layer(CompressionLayer::new())
         .layer(middleware::from_fn_with_state(shared_state.clone(), rate_limit_middleware))
+        .layer(middleware::from_fn_with_state(shared_state.clone(), tenancy::tenant_limit_middleware))
+        .layer(middleware::from_fn_with_state(shared_state.clone(), maintenance::maintenance_middleware))
         .layer(cors_layer)
         .layer(middleware::from_fn_with_state(shared_state.clone(), security_headers_middleware))
         .layer(TimeoutLayer::new(Duration::from_secs(30)));
@@ -136,24 +245,218 @@ async fn main() -> anyhow::Result<()> {
     let public_routes = Router::new()
         .route("/signup", post(auth::signup_handler))
         .route("/login", post(auth::login_handler))
+        .route("/refresh", post(auth::refresh_handler))
+        .route("/verify", post(auth::verify_email_handler))
+        .route("/forgot-password", post(auth::forgot_password_handler))
+        .route("/reset-password", post(auth::reset_password_handler))
+        .route("/sso/{slug}/authorize", get(sso::authorize_handler))
+        .route("/sso/{slug}/callback", post(sso::callback_handler))
         .route("/csrf", get(auth::get_csrf_token_handler))
+        .route("/trial", get(trial::trial_status_handler))
         .route("/sitemap.xml", get(sitemap_handler))
         .route("/health", get(|| async { StatusCode::OK }));
 
+    // Authenticated by per-organization SCIM tokens, not user sessions
+    let scim_routes = Router::new()
+        .route("/ServiceProviderConfig", get(scim::service_provider_config_handler))
+        .route("/Users", get(scim::list_users_handler).post(scim::create_user_handler))
+        .route(
+            "/Users/{id}",
+            get(scim::get_user_handler)
+                .put(scim::replace_user_handler)
+                .patch(scim::patch_user_handler)
+                .delete(scim::delete_user_handler),
+        )
+        .route("/Groups", get(scim::list_groups_handler).post(scim::create_group_handler))
+        .route(
+            "/Groups/{id}",
+            get(scim::get_group_handler)
+                .put(scim::replace_group_handler)
+                .patch(scim::patch_group_handler)
+                .delete(scim::delete_group_handler),
+        );
+
     let protected_routes = Router::new()
         .route("/profile/me", get(auth::get_my_profile_handler))
         .route("/profile/update", put(auth::update_profile_handler))
+        .route("/2fa/setup", post(auth::two_factor_setup_handler))
+        .route("/2fa/confirm", post(auth::two_factor_confirm_handler))
+        .route("/2fa/disable", post(auth::two_factor_disable_handler))
         .route("/stats/me", get(stats::get_my_usage_stats_handler))
+        .route("/stats/statement", get(stats::get_usage_statement_handler))
+        .route("/stats/statement/pdf", get(stats::get_usage_statement_pdf_handler))
+        .route("/stats/quality", get(stats::get_quality_stats_handler))
+        .route("/history", get(stats::get_history_handler))
         .route("/logout", post(auth::logout_handler))
+        .route("/flags", get(flags::my_flags_handler))
+        .route("/admin/flags", get(flags::list_flags_handler))
+        .route("/admin/flags/{key}", put(flags::upsert_flag_handler).delete(flags::delete_flag_handler))
+        .route("/admin/outbound", get(resilience::outbound_stats_handler))
+        .route("/admin/pricing/providers", get(pricing::health::provider_dashboard_handler))
+        .route("/admin/cleanup", post(cleanup::run_cleanup_handler))
+        .route("/admin/status/incidents", post(status::create_incident_handler))
+        .route(
+            "/admin/status/incidents/{id}",
+            put(status::update_incident_handler).delete(status::delete_incident_handler),
+        )
+        .route("/admin/status/maintenance", post(status::create_window_handler))
+        .route(
+            "/admin/status/maintenance/{id}",
+            put(status::update_window_handler).delete(status::delete_window_handler),
+        )
+        .route(
+            "/admin/maintenance",
+            get(maintenance::get_maintenance_handler).put(maintenance::update_maintenance_handler),
+        )
+        .route(
+            "/admin/log-level",
+            get(telemetry::get_log_level_handler).put(telemetry::update_log_level_handler),
+        )
+        .route("/org", get(tenancy::my_org_handler))
+        .route("/defaults", get(defaults::get_defaults_handler))
+        .route("/defaults/org", put(defaults::update_org_defaults_handler))
+        .route("/defaults/me", put(defaults::update_my_defaults_handler))
+        .route("/admin/sso", get(sso::list_connections_handler).post(sso::create_connection_handler))
+        .route("/admin/sso/{id}", put(sso::update_connection_handler).delete(sso::delete_connection_handler))
+        .route("/admin/orgs", post(tenancy::create_org_handler))
+        .route("/admin/orgs/{id}", put(tenancy::update_org_handler))
+        .route("/admin/orgs/{id}/members/{username}", put(tenancy::add_member_handler))
+        .route(
+            "/admin/orgs/{id}/scim-tokens",
+            get(scim::list_tokens_handler).post(scim::create_token_handler),
+        )
+        .route("/admin/scim-tokens/{id}", delete(scim::revoke_token_handler))
+        .route("/admin/orgs/{id}/export", get(backup::export_org_handler))
+        .route(
+            "/admin/orgs/{id}/import",
+            post(backup::import_org_handler).layer(DefaultBodyLimit::max(64 * 1024 * 1024)),
+        )
+        .route("/billing", get(billing::billing_overview_handler))
+        .route("/billing/checkout", post(billing::create_checkout_handler))
+        .route("/billing/portal", post(billing::create_portal_handler))
+        .route("/materials", get(materials::list_materials_handler).post(materials::create_material_handler))
+        .route(
+            "/materials/{id}",
+            get(materials::get_material_handler)
+                .put(materials::update_material_handler)
+                .delete(materials::delete_material_handler),
+        )
+        .nest(
+            "/pricing",
+            pricing::quotes::create_quotes_router().merge(pricing::history::create_history_router()),
+        )
+        .route("/projects", get(projects::list_projects_handler).post(projects::create_project_handler))
+        .route(
+            "/projects/{id}",
+            get(projects::get_project_handler)
+                .put(projects::update_project_handler)
+                .delete(projects::delete_project_handler),
+        )
+        .route("/projects/{id}/calculations", post(projects::save_calculation_handler))
+        .route("/projects/{id}/calculations/{calculation_id}", delete(projects::delete_calculation_handler))
+        .route("/projects/{id}/calculations/{calculation_id}/run", post(projects::rerun_calculation_handler))
+        .route("/projects/{id}/load-summary", post(load_summary::load_summary_handler))
+        .route("/oee/threshold-profiles", get(oee_profiles::list_profiles_handler).post(oee_profiles::create_profile_handler))
+        .route(
+            "/oee/threshold-profiles/{id}",
+            get(oee_profiles::get_profile_handler)
+                .put(oee_profiles::update_profile_handler)
+                .delete(oee_profiles::delete_profile_handler),
+        )
+        .route("/schedules", get(saved_schedules::list_schedules_handler).post(saved_schedules::create_schedule_handler))
+        .route(
+            "/schedules/{id}",
+            get(saved_schedules::get_schedule_handler)
+                .put(saved_schedules::update_schedule_handler)
+                .delete(saved_schedules::delete_schedule_handler),
+        )
+        .route("/schedules/{id}/alerts", get(saved_schedules::schedule_alerts_handler))
+        .route("/punch-lists", get(punch_lists::list_punch_lists_handler).post(punch_lists::create_punch_list_handler))
+        .route(
+            "/punch-lists/{id}",
+            get(punch_lists::get_punch_list_handler)
+                .put(punch_lists::update_punch_list_handler)
+                .delete(punch_lists::delete_punch_list_handler),
+        )
+        .route("/punch-lists/{id}/items", post(punch_lists::create_punch_item_handler))
+        .route(
+            "/punch-lists/{id}/items/{item_id}",
+            put(punch_lists::update_punch_item_handler).delete(punch_lists::delete_punch_item_handler),
+        )
+        .route("/field-reports", get(field_reports::list_reports_handler).post(field_reports::create_report_handler))
+        .route(
+            "/field-reports/{id}",
+            get(field_reports::get_report_handler)
+                .put(field_reports::update_report_handler)
+                .delete(field_reports::delete_report_handler),
+        )
+        .route("/inspections", get(inspections::list_inspections_handler).post(inspections::create_inspection_handler))
+        .route(
+            "/inspections/{id}",
+            get(inspections::get_inspection_handler).delete(inspections::delete_inspection_handler),
+        )
+        .route("/inspections/{id}/results", put(inspections::record_results_handler))
+        .route("/prequal-models", get(prequal_models::list_models_handler).post(prequal_models::create_model_handler))
+        .route(
+            "/prequal-models/{id}",
+            get(prequal_models::get_model_handler)
+                .put(prequal_models::update_model_handler)
+                .delete(prequal_models::delete_model_handler),
+        )
         .route_layer(middleware::from_fn_with_state(shared_state.clone(), csrf_protection_middleware))
         .layer(middleware::from_extractor_with_state::<Claims, Arc<AppState>>(shared_state.clone()));
 
-    // Create calculator routers
-    let beginner_router = calculus::beginner::create_router();
-    let engineer_router = calculus::engineer::create_router();
-    let contractor_router = calculus::contractor::create_router();
+    // Create calculator routers, each able to capture support bundles for
+    // failed calculations and, outside sandbox mode (no database), metered
+    // against plan entitlements, recorded in the caller's calculation
+    // history and able to queue `async` requests as jobs
+    let gate = |router: Router<Arc<AppState>>, tier| {
+        let router = router.layer(middleware::from_fn_with_state(
+            support::SupportCapture::new(shared_state.clone(), tier),
+            support::capture_middleware,
+        ));
+        let router = if sandbox {
+            router
+        } else {
+            router
+                .layer(middleware::from_fn_with_state(
+                    billing::EntitlementGate::new(shared_state.clone(), tier),
+                    billing::entitlement_middleware,
+                ))
+                .layer(middleware::from_fn_with_state(
+                    stats::HistoryRecorder::new(shared_state.clone(), tier),
+                    stats::history_middleware,
+                ))
+                .layer(middleware::from_fn_with_state(
+                    jobs::JobIntake::new(shared_state.clone(), tier),
+                    jobs::intake_middleware,
+                ))
+        };
+        // Translation keys in responses gain text in the caller's language;
+        // history and support bundles keep the locale-neutral body
+        let router = router.layer(middleware::from_fn(i18n::localize_middleware));
+        // `?strict=true` rejects parameter keys the calculator doesn't read,
+        // before a request can be queued or recorded
+        let router = router.layer(middleware::from_fn_with_state(
+            calculus::strict::StrictCheck::new(shared_state.clone(), tier),
+            calculus::strict::strict_middleware,
+        ));
+        // Older request schemas are upconverted before anything reads the body
+        let adapters: &'static [utils::versioning::Adapter] = match tier {
+            calculus::recommendation::Tier::Engineer => calculus::engineer::versions::ADAPTERS,
+            _ => &[],
+        };
+        router.layer(middleware::from_fn_with_state(adapters, utils::versioning::version_middleware))
+    };
+    // Beginner results can come back in the units each request prefers
+    let beginner_router = gate(calculus::beginner::create_router(), calculus::recommendation::Tier::Beginner)
+        .layer(middleware::from_fn(calculus::beginner::units::output_units_middleware));
+    let engineer_router = gate(calculus::engineer::create_router(), calculus::recommendation::Tier::Engineer);
+    let contractor_router = gate(calculus::contractor::create_router(), calculus::recommendation::Tier::Contractor);
+    let recommendation_router = calculus::recommendation::create_router();
+    let utils_router = utils::create_router();
 
-    // Get registry stats for startup banner
+    // Get registry stats for the startup log
     let engineer_stats = shared_state.calculators_engineer.stats();
     let beginner_stats = shared_state.calculators_beginner.stats();
     let contractor_stats = shared_state.calculators_contractor.stats();
@@ -165,11 +468,30 @@ async fn main() -> anyhow::Result<()> {
         .nest_service("/fonts", ServeDir::new("static/dist/fonts"))
         .nest_service("/favicon", ServeDir::new("static/dist/favicon"))
         .fallback(index_handler)
+        .route("/api/v1/billing/webhook", post(billing::stripe_webhook_handler))
+        .route("/api/v1/admin/selftest", get(calculus::selftest::selftest_handler))
+        .route("/api/v1/support/bundles/{id}", get(support::download_bundle_handler))
+        .route("/api/v1/reports/pdf", post(reports::render_pdf_handler))
         .nest("/api/v1/auth", public_routes)
+        .nest("/api/v1/scim/v2", scim_routes)
+        .route("/api/v1/status", get(status::status_handler))
+        .route("/api/v1/jobs", get(jobs::list_jobs_handler))
+        .route("/api/v1/jobs/{id}", get(jobs::get_job_handler))
         .nest("/api/v1/user", protected_routes)
         .nest("/api/v1/calculus/beginner", beginner_router)
         .nest("/api/v1/calculus/engineer", engineer_router)
         .nest("/api/v1/calculus/contractor", contractor_router)
+        .nest("/api/v1/calculus/recommend", recommendation_router)
+        .route("/api/v1/calculus/compare", post(calculus::compare::compare_handler))
+        .nest("/api/v1/calculus/changelog", calculus::changelog::create_router())
+        .nest("/api/v1/demo", calculus::demo::create_router())
+        .nest("/api/v1/utils", utils_router);
+
+    // Dev builds only: randomized input fuzzing of every calculator
+    #[cfg(feature = "fuzzing")]
+    let app = app.nest("/api/v1/dev/fuzz", calculus::fuzz::create_router());
+
+    let app = app
         .with_state(shared_state.clone())
         .layer(middleware_stack);
 
@@ -178,34 +500,21 @@ async fn main() -> anyhow::Result<()> {
     let port = port_str.parse::<u16>().context("Invalid PORT environment variable")?;
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
 
-    println!("\n╔═══════════════════════════════════════════════════╗");
-    println!("║              SECURITY MANIFEST v2.3               ║");
-    println!("╠═══════════════════════════════════════════════════╣");
-    println!("║ ✓ System               : Standalone (Tokio)      ║");
-    println!("║ ✓ Port                 : {:<24}║", port);
-    println!("║ ✓ JWT Auth (HS384) + Session Binding             ║");
-    println!("║ ✓ CSRF Protection (Token-in-Header)              ║");
-    println!("║ ✓ Rate Limiting (100 req/min per IP)             ║");
-    println!("║ ✓ Security Headers (HSTS, CSP, X-Frame, etc.)    ║");
-    println!("║ ✓ Argon2id Password Hashing                      ║");
-    println!("║ ✓ Timing Attack Prevention                       ║");
-    println!("║ ✓ SPA Routing (Client-Side Fallback)             ║");
-    println!("║ ✓ Static Asset Serving (/assets/*)               ║");
-    println!("╠═══════════════════════════════════════════════════╣");
-    println!("║              CALCULUS MODULES                     ║");
-    println!("╠═══════════════════════════════════════════════════╣");
-    println!("║ ✓ Beginner Module ({} calculators)                ║", beginner_stats.total_calculators);
-    println!("║ ✓ Contractor Module({} calculators)              ║", contractor_stats.total_calculators);
-    println!("║ ✓ Engineer Module ({} calculators)              ║", engineer_stats.total_calculators);
-    println!("║   • Civil Engineering: {}                        ║", 
-        engineer_stats.by_category.get("civil").unwrap_or(&0));
-    println!("║   • Structural Engineering: {}                   ║",
-        engineer_stats.by_category.get("structural").unwrap_or(&0));
-    println!("║   • Mechanical Engineering: {}                   ║",
-        engineer_stats.by_category.get("mechanical").unwrap_or(&0));
-    println!("║   • Production Engineering: {}                   ║",
-        engineer_stats.by_category.get("production").unwrap_or(&0));
-    println!("╚═══════════════════════════════════════════════════╝\n");
+    tracing::info!(
+        port,
+        sandbox,
+        mailer = shared_state.mailer.name(),
+        beginner_calculators = beginner_stats.total_calculators,
+        contractor_calculators = contractor_stats.total_calculators,
+        engineer_calculators = engineer_stats.total_calculators,
+        civil = engineer_stats.by_category.get("civil").unwrap_or(&0),
+        structural = engineer_stats.by_category.get("structural").unwrap_or(&0),
+        mechanical = engineer_stats.by_category.get("mechanical").unwrap_or(&0),
+        production = engineer_stats.by_category.get("production").unwrap_or(&0),
+        hydraulic = engineer_stats.by_category.get("hydraulic").unwrap_or(&0),
+        electrical = engineer_stats.by_category.get("electrical").unwrap_or(&0),
+        "struktura listening"
+    );
 
     let listener = TcpListener::bind(addr).await?;
     axum::serve(listener, app).await?;
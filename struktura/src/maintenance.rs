@@ -0,0 +1,221 @@
+//! Maintenance mode: the API goes read-only while the database is migrated
+//!
+//! While it is on, requests that write answer `503 Service Unavailable` with
+//! a structured payload (and `Retry-After` when an end time is known).
+//! Reads keep working, and so do calculations: they compute without writing,
+//! and their usage is simply not metered for the duration.
+//!
+//! Start an instance in maintenance with `MAINTENANCE_MODE=true` (message in
+//! `MAINTENANCE_MESSAGE`), or flip a running instance through
+//! `PUT /api/v1/user/admin/maintenance`. The admin switch is per instance;
+//! use the environment to take a whole fleet read-only.
+
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, RwLock};
+
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+
+const DEFAULT_MESSAGE: &str = "Struktura is undergoing scheduled maintenance. Calculators remain available; \
+    saving and account changes are paused.";
+
+/// Write requests allowed through maintenance, by path prefix: calculators
+/// and conversions compute without writing, and the switch itself must stay
+/// reachable
+const ALLOWED_WRITE_PREFIXES: &[&str] = &[
+    "/api/v1/calculus/",
+    "/api/v1/utils/",
+    "/api/v1/demo/",
+    "/api/v1/user/admin/maintenance",
+];
+
+/// An active maintenance window
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MaintenanceWindow {
+    pub message: String,
+    pub since: DateTime<Utc>,
+    /// Expected end, when announced
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// Process-wide maintenance switch
+#[derive(Debug, Clone, Default)]
+pub struct Maintenance {
+    window: Arc<RwLock<Option<MaintenanceWindow>>>,
+}
+
+impl Maintenance {
+    /// Starts on when `MAINTENANCE_MODE` is `true` or `1`
+    pub fn from_env() -> Self {
+        let maintenance = Self::default();
+        let enabled = std::env::var("MAINTENANCE_MODE")
+            .is_ok_and(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true"));
+        if enabled {
+            maintenance.enable(std::env::var("MAINTENANCE_MESSAGE").ok(), None);
+        }
+        maintenance
+    }
+
+    pub fn enable(&self, message: Option<String>, until: Option<DateTime<Utc>>) -> MaintenanceWindow {
+        let window = MaintenanceWindow {
+            message: message.filter(|m| !m.trim().is_empty()).unwrap_or_else(|| DEFAULT_MESSAGE.to_string()),
+            since: Utc::now(),
+            until,
+        };
+        *self.window.write().unwrap_or_else(|e| e.into_inner()) = Some(window.clone());
+        window
+    }
+
+    pub fn disable(&self) {
+        *self.window.write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    pub fn current(&self) -> Option<MaintenanceWindow> {
+        self.window.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.current().is_some()
+    }
+}
+
+/// Whether a request may run while the API is read-only
+pub fn allowed_during_maintenance(method: &Method, path: &str) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+        || ALLOWED_WRITE_PREFIXES.iter().any(|prefix| path.starts_with(prefix))
+}
+
+#[derive(Debug, Serialize)]
+struct MaintenanceResponse {
+    error: &'static str,
+    #[serde(flatten)]
+    window: MaintenanceWindow,
+    read_only: bool,
+}
+
+fn unavailable(window: MaintenanceWindow) -> Response {
+    let retry_after = window
+        .until
+        .map(|until| (until - Utc::now()).num_seconds().max(1));
+    let mut response = (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(MaintenanceResponse { error: "maintenance", window, read_only: true }),
+    )
+        .into_response();
+    if let Some(seconds) = retry_after {
+        response.headers_mut().insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+    }
+    response
+}
+
+/// Refuse writes while maintenance is on
+pub async fn maintenance_middleware(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(window) = state.maintenance.current()
+        && !allowed_during_maintenance(request.method(), request.uri().path())
+    {
+        return unavailable(window);
+    }
+    next.run(request).await
+}
+
+// ============================================================================
+// Admin
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct MaintenanceStatus {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<MaintenanceWindow>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MaintenanceUpdate {
+    pub enabled: bool,
+    pub message: Option<String>,
+    /// Expected end, RFC 3339
+    #[serde(default)]
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl MaintenanceStatus {
+    fn of(maintenance: &Maintenance) -> Self {
+        let window = maintenance.current();
+        Self { enabled: window.is_some(), window }
+    }
+}
+
+/// GET /api/v1/user/admin/maintenance
+pub async fn get_maintenance_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<MaintenanceStatus>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    Ok(Json(MaintenanceStatus::of(&state.maintenance)))
+}
+
+/// PUT /api/v1/user/admin/maintenance
+pub async fn update_maintenance_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Json(payload): Json<MaintenanceUpdate>,
+) -> Result<Json<MaintenanceStatus>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+
+    if payload.enabled {
+        let window = state.maintenance.enable(payload.message, payload.until);
+        tracing::warn!(message = %window.message, "maintenance mode enabled");
+        sec::log_security_event("MAINTENANCE_ENABLED", Some(&claims.username), None, "Success");
+    } else {
+        state.maintenance.disable();
+        tracing::warn!("maintenance mode disabled");
+        sec::log_security_event("MAINTENANCE_DISABLED", Some(&claims.username), None, "Success");
+    }
+    Ok(Json(MaintenanceStatus::of(&state.maintenance)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_reads_and_calculations_pass() {
+        assert!(allowed_during_maintenance(&Method::GET, "/api/v1/user/projects"));
+        assert!(allowed_during_maintenance(&Method::POST, "/api/v1/calculus/engineer/calculate"));
+        assert!(allowed_during_maintenance(&Method::PUT, "/api/v1/user/admin/maintenance"));
+        assert!(!allowed_during_maintenance(&Method::POST, "/api/v1/user/projects"));
+        assert!(!allowed_during_maintenance(&Method::POST, "/api/v1/auth/signup"));
+        assert!(!allowed_during_maintenance(&Method::DELETE, "/api/v1/user/materials/steel"));
+    }
+
+    #[test]
+    fn test_switch_and_payload() {
+        let maintenance = Maintenance::default();
+        assert!(!maintenance.is_active());
+
+        let until = Utc::now() + chrono::Duration::minutes(10);
+        let window = maintenance.enable(Some("  ".to_string()), Some(until));
+        assert_eq!(window.message, DEFAULT_MESSAGE);
+        assert!(maintenance.is_active());
+
+        let response = unavailable(window);
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let retry: i64 = response.headers()[header::RETRY_AFTER].to_str().unwrap().parse().unwrap();
+        assert!((590..=600).contains(&retry));
+
+        maintenance.disable();
+        assert!(maintenance.current().is_none());
+    }
+}
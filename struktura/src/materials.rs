@@ -0,0 +1,352 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json as SqlJson;
+use sqlx::types::time::OffsetDateTime;
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::calculus::contractor::{models::MaterialProperties as ContractingMaterial, ContractingError};
+use crate::calculus::engineer::{models::MaterialProperties as EngineeringMaterial, EngineeringError};
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+use crate::tenancy::{TenantOwned, TenantScope};
+
+// =============================================================================
+// MODELS
+// =============================================================================
+
+/// A saved material with user-specific price and properties
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct CustomMaterial {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub name: String,
+    pub material_type: String,
+    pub unit: String,
+    pub unit_price: Option<f64>,
+    pub currency: String,
+    pub waste_factor: Option<f64>,
+    pub density: Option<f64>,
+    pub properties: SqlJson<HashMap<String, f64>>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct MaterialPayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(min = 1, max = 50))]
+    pub material_type: String,
+    #[validate(length(min = 1, max = 20))]
+    pub unit: String,
+    #[validate(range(min = 0.0))]
+    pub unit_price: Option<f64>,
+    #[validate(length(equal = 3))]
+    pub currency: Option<String>,
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub waste_factor: Option<f64>,
+    #[validate(range(min = 0.0))]
+    pub density: Option<f64>,
+    #[serde(default)]
+    pub properties: HashMap<String, f64>,
+}
+
+const MATERIAL_COLUMNS: &str = "id, org_id, name, material_type, unit, unit_price, currency, \
+    waste_factor, density, properties, created_at, updated_at";
+
+impl TenantOwned for CustomMaterial {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+impl CustomMaterial {
+    fn property(&self, key: &str) -> Option<f64> {
+        self.properties.get(key).copied()
+    }
+
+    /// Fill contracting inputs the request left blank; explicit values win
+    pub fn apply_to_contracting(&self, material: &mut ContractingMaterial) {
+        material.unit_cost = material.unit_cost.or(self.unit_price);
+        material.waste_factor = material.waste_factor.or(self.waste_factor);
+        material.density = material.density.or(self.density);
+    }
+
+    /// Fill engineering inputs the request left blank; explicit values win
+    pub fn apply_to_engineering(&self, material: &mut EngineeringMaterial) {
+        material.compressive_strength = material.compressive_strength.or(self.property("compressive_strength"));
+        material.tensile_strength = material.tensile_strength.or(self.property("tensile_strength"));
+        material.yield_strength = material.yield_strength.or(self.property("yield_strength"));
+        material.ultimate_strength = material.ultimate_strength.or(self.property("ultimate_strength"));
+        material.elastic_modulus = material.elastic_modulus.or(self.property("elastic_modulus"));
+        material.shear_modulus = material.shear_modulus.or(self.property("shear_modulus"));
+        material.poisson_ratio = material.poisson_ratio.or(self.property("poisson_ratio"));
+        material.density = material.density.or(self.density);
+        material.thermal_conductivity = material.thermal_conductivity.or(self.property("thermal_conductivity"));
+        material.thermal_expansion = material.thermal_expansion.or(self.property("thermal_expansion"));
+        material.specific_heat = material.specific_heat.or(self.property("specific_heat"));
+    }
+}
+
+// =============================================================================
+// CALCULATOR LOOKUP
+// =============================================================================
+
+/// Why a `material_id` on a calculation request could not be resolved
+#[derive(Debug)]
+pub enum MaterialLookupError {
+    Unauthenticated,
+    NotFound(String),
+    Db(sqlx::Error),
+}
+
+/// Load one of the caller's saved materials by id
+pub async fn lookup(
+    state: &AppState,
+    claims: Option<&Claims>,
+    material_id: &str,
+) -> Result<CustomMaterial, MaterialLookupError> {
+    let claims = claims.ok_or(MaterialLookupError::Unauthenticated)?;
+    let scope = state.tenants.resolve(&state.pool, claims).await.map_err(|e| match e {
+        AppError::DbError(e) => MaterialLookupError::Db(e),
+        _ => MaterialLookupError::Unauthenticated,
+    })?;
+    let id = Uuid::parse_str(material_id)
+        .map_err(|_| MaterialLookupError::NotFound(material_id.to_string()))?;
+
+    let material = sqlx::query_as::<_, CustomMaterial>(&format!(
+        "SELECT {MATERIAL_COLUMNS} FROM custom_materials WHERE id = $1 AND user_id = $2 AND org_id = $3"
+    ))
+    .bind(id)
+    .bind(scope.user_id)
+    .bind(scope.org_id)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(MaterialLookupError::Db)?
+    .ok_or_else(|| MaterialLookupError::NotFound(material_id.to_string()))?;
+
+    scope.guard(material).map_err(|_| MaterialLookupError::NotFound(material_id.to_string()))
+}
+
+impl MaterialLookupError {
+    fn reason(&self) -> String {
+        match self {
+            Self::Unauthenticated => "Sign in to use saved materials".to_string(),
+            Self::NotFound(_) => "No saved material with this id".to_string(),
+            Self::Db(e) => format!("Material library unavailable: {}", e),
+        }
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::NotFound(id) => id.clone(),
+            _ => String::new(),
+        }
+    }
+}
+
+impl From<MaterialLookupError> for ContractingError {
+    fn from(err: MaterialLookupError) -> Self {
+        match err {
+            MaterialLookupError::Db(_) => ContractingError::CalculationError(err.reason()),
+            _ => ContractingError::InvalidParameter {
+                parameter: "material.material_id".to_string(),
+                value: err.value(),
+                reason: err.reason(),
+            },
+        }
+    }
+}
+
+impl From<MaterialLookupError> for EngineeringError {
+    fn from(err: MaterialLookupError) -> Self {
+        match err {
+            MaterialLookupError::Db(_) => EngineeringError::CalculationError(err.reason()),
+            _ => EngineeringError::InvalidParameter {
+                parameter: "material.material_id".to_string(),
+                value: err.value(),
+                reason: err.reason(),
+            },
+        }
+    }
+}
+
+// =============================================================================
+// HANDLERS
+// =============================================================================
+
+pub async fn list_materials_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+) -> Result<Json<Vec<CustomMaterial>>, AppError> {
+    let materials = sqlx::query_as::<_, CustomMaterial>(&format!(
+        "SELECT {MATERIAL_COLUMNS} FROM custom_materials WHERE user_id = $1 AND org_id = $2 ORDER BY name"
+    ))
+    .bind(scope.user_id)
+    .bind(scope.org_id)
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    Ok(Json(scope.guard_all(materials)?))
+}
+
+pub async fn get_material_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CustomMaterial>, AppError> {
+    let material = sqlx::query_as::<_, CustomMaterial>(&format!(
+        "SELECT {MATERIAL_COLUMNS} FROM custom_materials WHERE id = $1 AND user_id = $2 AND org_id = $3"
+    ))
+    .bind(id)
+    .bind(scope.user_id)
+    .bind(scope.org_id)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    Ok(Json(scope.guard(material)?))
+}
+
+pub async fn create_material_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<MaterialPayload>,
+) -> Result<(StatusCode, Json<CustomMaterial>), AppError> {
+    payload.validate()?;
+
+    let material = sqlx::query_as::<_, CustomMaterial>(&format!(
+        r#"
+        INSERT INTO custom_materials
+            (user_id, name, material_type, unit, unit_price, currency, waste_factor, density, properties, org_id)
+        VALUES ($1, $2, $3, $4, $5, COALESCE($6, 'USD'), $7, $8, $9, $10)
+        RETURNING {MATERIAL_COLUMNS}
+        "#
+    ))
+    .bind(scope.user_id)
+    .bind(&payload.name)
+    .bind(&payload.material_type)
+    .bind(&payload.unit)
+    .bind(payload.unit_price)
+    .bind(&payload.currency)
+    .bind(payload.waste_factor)
+    .bind(payload.density)
+    .bind(SqlJson(&payload.properties))
+    .bind(scope.org_id)
+    .fetch_one(&app_state.pool)
+    .await?;
+
+    sec::log_security_event("MATERIAL_CREATE", Some(&scope.username), None, "Success");
+
+    Ok((StatusCode::CREATED, Json(material)))
+}
+
+pub async fn update_material_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<MaterialPayload>,
+) -> Result<Json<CustomMaterial>, AppError> {
+    payload.validate()?;
+
+    let material = sqlx::query_as::<_, CustomMaterial>(&format!(
+        r#"
+        UPDATE custom_materials
+        SET name = $3, material_type = $4, unit = $5, unit_price = $6,
+            currency = COALESCE($7, currency), waste_factor = $8, density = $9,
+            properties = $10, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1 AND user_id = $2 AND org_id = $11
+        RETURNING {MATERIAL_COLUMNS}
+        "#
+    ))
+    .bind(id)
+    .bind(scope.user_id)
+    .bind(&payload.name)
+    .bind(&payload.material_type)
+    .bind(&payload.unit)
+    .bind(payload.unit_price)
+    .bind(&payload.currency)
+    .bind(payload.waste_factor)
+    .bind(payload.density)
+    .bind(SqlJson(&payload.properties))
+    .bind(scope.org_id)
+    .fetch_optional(&app_state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("MATERIAL_UPDATE", Some(&scope.username), None, "Success");
+
+    Ok(Json(material))
+}
+
+pub async fn delete_material_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let result = sqlx::query("DELETE FROM custom_materials WHERE id = $1 AND user_id = $2 AND org_id = $3")
+        .bind(id)
+        .bind(scope.user_id)
+        .bind(scope.org_id)
+        .execute(&app_state.pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("MATERIAL_DELETE", Some(&scope.username), None, "Success");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lumber() -> CustomMaterial {
+        CustomMaterial {
+            id: Uuid::new_v4(),
+            org_id: Uuid::new_v4(),
+            name: "2x4 SPF negotiated".to_string(),
+            material_type: "Lumber".to_string(),
+            unit: "piece".to_string(),
+            unit_price: Some(3.85),
+            currency: "USD".to_string(),
+            waste_factor: Some(0.08),
+            density: Some(450.0),
+            properties: SqlJson(HashMap::from([("elastic_modulus".to_string(), 9.5)])),
+            created_at: OffsetDateTime::now_utc(),
+            updated_at: OffsetDateTime::now_utc(),
+        }
+    }
+
+    #[test]
+    fn test_library_fills_missing_contracting_values() {
+        let mut material = ContractingMaterial {
+            waste_factor: Some(0.1),
+            ..Default::default()
+        };
+        lumber().apply_to_contracting(&mut material);
+
+        assert_eq!(material.unit_cost, Some(3.85));
+        assert_eq!(material.waste_factor, Some(0.1));
+        assert_eq!(material.density, Some(450.0));
+    }
+
+    #[test]
+    fn test_library_fills_engineering_properties() {
+        let mut material = EngineeringMaterial::default();
+        lumber().apply_to_engineering(&mut material);
+
+        assert_eq!(material.elastic_modulus, Some(9.5));
+        assert_eq!(material.yield_strength, None);
+    }
+}
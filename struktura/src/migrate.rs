@@ -0,0 +1,216 @@
+//! Startup migrations, safe for blue/green deploys
+//!
+//! `MIGRATION_POLICY` decides what an instance does with the schema when it
+//! boots:
+//!
+//! - `apply` (default): run pending migrations
+//! - `verify`: change nothing, refuse to start unless every migration this
+//!   build knows is applied unchanged (for instances that must not touch the
+//!   schema, e.g. the old colour during a rollout)
+//! - `skip`: no migration work at all
+//!
+//! `apply` and `verify` run under a Postgres advisory lock, so instances
+//! booting together wait for the first one's migrations instead of racing
+//! it. Under either, a build that finds the database already migrated past
+//! the newest migration it ships refuses to start: old code never serves
+//! against a schema it does not know.
+
+use anyhow::{bail, Context};
+use sqlx::migrate::{Migrate, Migrator};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// Advisory lock key held while an instance checks and migrates the schema
+/// ("strukmig" in ASCII)
+const MIGRATION_LOCK_KEY: i64 = 0x7374_7275_6b6d_6967;
+
+/// What an instance does with the schema on startup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MigrationPolicy {
+    #[default]
+    Apply,
+    Verify,
+    Skip,
+}
+
+impl MigrationPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Apply => "apply",
+            Self::Verify => "verify",
+            Self::Skip => "skip",
+        }
+    }
+
+    /// Reads `MIGRATION_POLICY`; unset means `apply`
+    pub fn from_env() -> anyhow::Result<Self> {
+        match std::env::var("MIGRATION_POLICY") {
+            Ok(value) => value.parse(),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+impl FromStr for MigrationPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "apply" => Ok(Self::Apply),
+            "verify" | "verify-only" | "verify_only" => Ok(Self::Verify),
+            "skip" => Ok(Self::Skip),
+            other => bail!("unknown MIGRATION_POLICY '{}' (expected apply, verify or skip)", other),
+        }
+    }
+}
+
+/// How the database's applied migrations compare with this build's
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaState {
+    /// Every known migration is applied and nothing newer
+    Current,
+    /// Known migrations not applied yet, by version
+    Pending(Vec<i64>),
+    /// Migrations applied by a newer build, by version
+    Ahead(Vec<i64>),
+    /// Applied migrations whose source changed since, by version
+    Modified(Vec<i64>),
+}
+
+/// Compare this build's migrations with the ones the database recorded,
+/// both as (version, checksum)
+pub fn compare(known: &[(i64, &[u8])], applied: &[(i64, Vec<u8>)]) -> SchemaState {
+    let applied_by_version: HashMap<i64, &[u8]> = applied.iter().map(|(v, c)| (*v, c.as_slice())).collect();
+    let known_versions: HashMap<i64, &[u8]> = known.iter().copied().collect();
+
+    let mut ahead: Vec<i64> = applied
+        .iter()
+        .map(|(v, _)| *v)
+        .filter(|v| !known_versions.contains_key(v))
+        .collect();
+    if !ahead.is_empty() {
+        ahead.sort_unstable();
+        return SchemaState::Ahead(ahead);
+    }
+
+    let mut modified = Vec::new();
+    let mut pending = Vec::new();
+    for (version, checksum) in known {
+        match applied_by_version.get(version) {
+            Some(recorded) if recorded != checksum => modified.push(*version),
+            Some(_) => {}
+            None => pending.push(*version),
+        }
+    }
+    if !modified.is_empty() {
+        SchemaState::Modified(modified)
+    } else if !pending.is_empty() {
+        SchemaState::Pending(pending)
+    } else {
+        SchemaState::Current
+    }
+}
+
+/// Bring the schema in line with `policy`, or explain why this build must
+/// not start against it
+pub async fn run(pool: &PgPool, policy: MigrationPolicy) -> anyhow::Result<()> {
+    if policy == MigrationPolicy::Skip {
+        tracing::warn!("MIGRATION_POLICY=skip: schema is neither migrated nor checked");
+        return Ok(());
+    }
+
+    let mut conn = pool.acquire().await.context("Failed to acquire a connection for migrations")?;
+    sqlx::query("SELECT pg_advisory_lock($1)")
+        .bind(MIGRATION_LOCK_KEY)
+        .execute(&mut *conn)
+        .await
+        .context("Failed to take the migration lock")?;
+
+    let outcome = migrate_locked(&mut conn, policy).await;
+
+    if let Err(e) = sqlx::query("SELECT pg_advisory_unlock($1)")
+        .bind(MIGRATION_LOCK_KEY)
+        .execute(&mut *conn)
+        .await
+    {
+        // The lock dies with the session; make sure the session goes too
+        tracing::error!(error = %e, "failed to release the migration lock");
+        conn.detach();
+    }
+    outcome
+}
+
+async fn migrate_locked(conn: &mut sqlx::PgConnection, policy: MigrationPolicy) -> anyhow::Result<()> {
+    conn.ensure_migrations_table().await.context("Failed to prepare the migrations table")?;
+    let applied: Vec<(i64, Vec<u8>)> = conn
+        .list_applied_migrations()
+        .await
+        .context("Failed to read applied migrations")?
+        .into_iter()
+        .map(|m| (m.version, m.checksum.into_owned()))
+        .collect();
+    let known: Vec<(i64, &[u8])> = MIGRATOR
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| (m.version, m.checksum.as_ref()))
+        .collect();
+
+    match (compare(&known, &applied), policy) {
+        (SchemaState::Current, _) => {
+            tracing::info!(policy = policy.as_str(), "database schema is current");
+            Ok(())
+        }
+        (SchemaState::Ahead(versions), _) => bail!(
+            "database schema is ahead of this build (unknown migrations {:?}); refusing to start",
+            versions
+        ),
+        (SchemaState::Modified(versions), _) => bail!(
+            "applied migrations {:?} differ from this build's copies; refusing to start",
+            versions
+        ),
+        (SchemaState::Pending(versions), MigrationPolicy::Verify) => bail!(
+            "MIGRATION_POLICY=verify but migrations {:?} are not applied; refusing to start",
+            versions
+        ),
+        (SchemaState::Pending(versions), _) => {
+            tracing::info!(?versions, "applying pending migrations");
+            MIGRATOR.run_direct(conn).await.context("Failed to apply migrations")?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_parsing() {
+        assert_eq!("APPLY".parse::<MigrationPolicy>().unwrap(), MigrationPolicy::Apply);
+        assert_eq!("verify-only".parse::<MigrationPolicy>().unwrap(), MigrationPolicy::Verify);
+        assert_eq!(" skip ".parse::<MigrationPolicy>().unwrap(), MigrationPolicy::Skip);
+        assert!("yolo".parse::<MigrationPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_schema_comparison() {
+        let known: Vec<(i64, &[u8])> = vec![(1, b"a"), (2, b"b"), (3, b"c")];
+        let recorded = |entries: &[(i64, &[u8])]| entries.iter().map(|(v, c)| (*v, c.to_vec())).collect::<Vec<_>>();
+
+        assert_eq!(compare(&known, &recorded(&known)), SchemaState::Current);
+        assert_eq!(compare(&known, &recorded(&[(1, b"a")])), SchemaState::Pending(vec![2, 3]));
+        assert_eq!(compare(&known, &recorded(&[(1, b"a"), (2, b"x")])), SchemaState::Modified(vec![2]));
+        assert_eq!(
+            compare(&known, &recorded(&[(1, b"a"), (2, b"b"), (3, b"c"), (4, b"d")])),
+            SchemaState::Ahead(vec![4])
+        );
+    }
+
+    #[test]
+    fn test_build_ships_migrations() {
+        assert!(MIGRATOR.iter().count() >= 15);
+    }
+}
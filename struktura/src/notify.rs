@@ -0,0 +1,195 @@
+// =============================================================================
+// Notifications
+//
+// Outbound alerts over email and webhooks. Email goes through an HTTP relay
+// (any transactional mail API that accepts a JSON message) so no SMTP stack
+// is needed: set NOTIFY_EMAIL_URL, and NOTIFY_EMAIL_TOKEN / NOTIFY_EMAIL_FROM
+// as the relay requires. Without a relay, email channels are skipped.
+//
+// Webhooks receive the notification as JSON. When NOTIFY_WEBHOOK_SECRET is
+// set each delivery carries `X-Struktura-Signature: t=<ts>,v1=<hex>`, an
+// HMAC-SHA256 of `<ts>.<body>` in the same scheme Stripe uses, so receivers
+// can reuse their verification code.
+//
+// Deliveries go through a resilience guard: the email relay shares one, each
+// webhook host gets its own, so a dead receiver only trips its own circuit.
+// =============================================================================
+
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::resilience::{retryable_http, Guard, ResiliencePolicy};
+use crate::sec;
+
+/// Header carrying the webhook signature
+pub const SIGNATURE_HEADER: &str = "x-struktura-signature";
+/// Give up on a slow relay or receiver after this long
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Where a notification goes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Channel {
+    Email(String),
+    Webhook(String),
+}
+
+impl Channel {
+    fn describe(&self) -> String {
+        match self {
+            Channel::Email(address) => format!("email {}", address),
+            Channel::Webhook(url) => format!("webhook {}", url),
+        }
+    }
+
+    /// Resilience guard for the relay or webhook host
+    fn guard(&self) -> Arc<Guard> {
+        let policy = ResiliencePolicy { timeout: SEND_TIMEOUT, ..ResiliencePolicy::default() };
+        match self {
+            Channel::Email(_) => Guard::named("notify_email", policy),
+            Channel::Webhook(url) => {
+                let host = reqwest::Url::parse(url)
+                    .ok()
+                    .and_then(|u| u.host_str().map(str::to_string))
+                    .unwrap_or_default();
+                Guard::named(&format!("webhook:{}", host), policy)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    /// Machine-readable event name, e.g. `milestone.slipped`
+    pub event: String,
+    pub subject: String,
+    pub text: String,
+    /// Event-specific details for webhook receivers
+    pub data: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NotifyConfig {
+    pub email_url: Option<String>,
+    pub email_token: Option<String>,
+    pub email_from: String,
+    pub webhook_secret: Option<String>,
+}
+
+impl NotifyConfig {
+    pub fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        Self {
+            email_url: var("NOTIFY_EMAIL_URL"),
+            email_token: var("NOTIFY_EMAIL_TOKEN"),
+            email_from: var("NOTIFY_EMAIL_FROM").unwrap_or_else(|| "alerts@struktura.fly.dev".to_string()),
+            webhook_secret: var("NOTIFY_WEBHOOK_SECRET"),
+        }
+    }
+}
+
+/// `t=<ts>,v1=<hex>` signature of a webhook body
+pub fn sign(body: &[u8], secret: &str, timestamp: i64) -> String {
+    let mut signed = format!("{}.", timestamp).into_bytes();
+    signed.extend_from_slice(body);
+    format!("t={},v1={}", timestamp, sec::sign_hmac_sha256(secret.as_bytes(), &signed))
+}
+
+#[derive(Clone)]
+pub struct Notifier {
+    config: NotifyConfig,
+    client: reqwest::Client,
+}
+
+impl Notifier {
+    pub fn new(config: NotifyConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(SEND_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self { config, client }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(NotifyConfig::from_env())
+    }
+
+    /// Whether `channel` can be delivered with this configuration
+    pub fn supports(&self, channel: &Channel) -> bool {
+        match channel {
+            Channel::Email(_) => self.config.email_url.is_some(),
+            Channel::Webhook(_) => true,
+        }
+    }
+
+    pub async fn send(&self, channel: &Channel, notification: &Notification) -> Result<(), String> {
+        let request = match channel {
+            Channel::Email(address) => {
+                let url = self.config.email_url.as_ref().ok_or("no email relay configured")?;
+                let message = serde_json::json!({
+                    "from": self.config.email_from,
+                    "to": address,
+                    "subject": notification.subject,
+                    "text": notification.text,
+                });
+                let request = self
+                    .client
+                    .post(url)
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(message.to_string());
+                match &self.config.email_token {
+                    Some(token) => request.bearer_auth(token),
+                    None => request,
+                }
+            }
+            Channel::Webhook(url) => {
+                let body = serde_json::to_vec(notification).map_err(|e| e.to_string())?;
+                let mut request = self
+                    .client
+                    .post(url)
+                    .header(reqwest::header::CONTENT_TYPE, "application/json");
+                if let Some(secret) = &self.config.webhook_secret {
+                    let timestamp = chrono::Utc::now().timestamp();
+                    request = request.header(SIGNATURE_HEADER, sign(&body, secret, timestamp));
+                }
+                request.body(body)
+            }
+        };
+
+        channel
+            .guard()
+            .call(retryable_http, || {
+                let attempt = request.try_clone();
+                async move {
+                    // Bodies are buffered, so the request always clones
+                    let attempt = attempt.expect("buffered request body");
+                    attempt.send().await?.error_for_status()
+                }
+            })
+            .await
+            .map_err(|e| format!("{}: {}", channel.describe(), e))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::billing::verify_signature;
+
+    #[test]
+    fn test_signature_matches_stripe_scheme() {
+        let body = br#"{"event":"milestone.slipped"}"#;
+        let header = sign(body, "whsec_test", 1_700_000_000);
+        assert!(header.starts_with("t=1700000000,v1="));
+        assert!(verify_signature(body, &header, "whsec_test", 1_700_000_010));
+        assert!(!verify_signature(body, &header, "other", 1_700_000_010));
+    }
+
+    #[test]
+    fn test_email_needs_relay() {
+        let notifier = Notifier::new(NotifyConfig::default());
+        assert!(!notifier.supports(&Channel::Email("pm@example.com".to_string())));
+        assert!(notifier.supports(&Channel::Webhook("https://example.com/hook".to_string())));
+    }
+}
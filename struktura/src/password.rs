@@ -0,0 +1,277 @@
+//! Password policy
+//!
+//! Applied whenever a password is chosen: at signup, when a signed-in user
+//! changes theirs through the profile, and when an expired one is replaced
+//! at login. The rules come from the environment:
+//!
+//! - `PASSWORD_MIN_LENGTH` (default 12) and `PASSWORD_MAX_LENGTH` (default 128)
+//! - `PASSWORD_MIN_CHARACTER_CLASSES` (default 1): how many of lowercase,
+//!   uppercase, digits and symbols must appear
+//! - `PASSWORD_BREACH_CHECK` (default on): refuse passwords found in known
+//!   breaches, asked of Have I Been Pwned by k-anonymity (only the first five
+//!   hex digits of the SHA-1 leave the server). An unreachable service does
+//!   not block signups; the check is skipped with a warning.
+//!
+//! Rotation is set per organization: enterprise tenants put a maximum age on
+//! their members' passwords with `password_max_age_days`, and a member whose
+//! password is older must replace it when logging in.
+//!
+//! Every rule that fails is reported, each with a stable code, so clients
+//! can point at all of them at once.
+
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::resilience::{retryable_http, Guard, ResiliencePolicy};
+use crate::sec::AppError;
+
+const DEFAULT_MIN_LENGTH: usize = 12;
+const DEFAULT_MAX_LENGTH: usize = 128;
+const HIBP_RANGE_URL: &str = "https://api.pwnedpasswords.com/range/";
+
+/// One rule a password failed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PolicyViolation {
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl PolicyViolation {
+    fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+
+    /// The new password is the one being replaced
+    pub fn reused() -> Self {
+        Self::new("reused", "The new password must differ from the current one")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub min_character_classes: usize,
+    pub breach_check: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: DEFAULT_MIN_LENGTH,
+            max_length: DEFAULT_MAX_LENGTH,
+            min_character_classes: 1,
+            breach_check: true,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    pub fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok().and_then(|v| v.trim().parse::<usize>().ok());
+        let defaults = Self::default();
+
+        let min_length = var("PASSWORD_MIN_LENGTH").filter(|n| *n > 0).unwrap_or(defaults.min_length);
+        Self {
+            min_length,
+            max_length: var("PASSWORD_MAX_LENGTH").filter(|n| *n >= min_length).unwrap_or(defaults.max_length.max(min_length)),
+            min_character_classes: var("PASSWORD_MIN_CHARACTER_CLASSES")
+                .map(|n| n.clamp(1, 4))
+                .unwrap_or(defaults.min_character_classes),
+            breach_check: std::env::var("PASSWORD_BREACH_CHECK")
+                .map(|v| !matches!(v.trim().to_lowercase().as_str(), "0" | "false" | "off"))
+                .unwrap_or(defaults.breach_check),
+        }
+    }
+
+    /// Rules that need no network: length, character classes and the username
+    pub fn check_local(&self, password: &str, username: &str) -> Vec<PolicyViolation> {
+        let mut violations = Vec::new();
+        let length = password.chars().count();
+
+        if length < self.min_length {
+            violations.push(PolicyViolation::new(
+                "too_short",
+                format!("Use at least {} characters", self.min_length),
+            ));
+        }
+        if length > self.max_length {
+            violations.push(PolicyViolation::new(
+                "too_long",
+                format!("Use at most {} characters", self.max_length),
+            ));
+        }
+        if character_classes(password) < self.min_character_classes {
+            violations.push(PolicyViolation::new(
+                "too_few_character_classes",
+                format!(
+                    "Mix at least {} of lowercase letters, uppercase letters, digits and symbols",
+                    self.min_character_classes
+                ),
+            ));
+        }
+        if username.chars().count() >= 3 && password.to_lowercase().contains(&username.to_lowercase()) {
+            violations.push(PolicyViolation::new("contains_username", "The password must not contain the username"));
+        }
+        violations
+    }
+
+    /// Every rule, breach list included; `PasswordPolicy` error listing what failed
+    pub async fn enforce(&self, password: &str, username: &str) -> Result<(), AppError> {
+        let mut violations = self.check_local(password, username);
+
+        // Only passwords that pass the cheap rules are worth a lookup
+        if violations.is_empty() && self.breach_check {
+            match breach_count(password).await {
+                Ok(0) => {}
+                Ok(count) => {
+                    tracing::info!(count, "password refused: found in breach corpus");
+                    violations.push(PolicyViolation::new(
+                        "breached",
+                        "This password appears in known data breaches; choose another",
+                    ));
+                }
+                Err(e) => tracing::warn!(error = %e, "breach check unavailable; skipped"),
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::PasswordPolicy(violations))
+        }
+    }
+}
+
+fn character_classes(password: &str) -> usize {
+    [
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .iter()
+    .filter(|present| **present)
+    .count()
+}
+
+// ============================================================================
+// Breach list
+// ============================================================================
+
+/// Uppercase hex SHA-1, split into the prefix sent and the suffix kept
+fn sha1_prefix_suffix(password: &str) -> (String, String) {
+    let digest = Sha1::digest(password.as_bytes());
+    let hex: String = digest.iter().map(|b| format!("{:02X}", b)).collect();
+    let (prefix, suffix) = hex.split_at(5);
+    (prefix.to_string(), suffix.to_string())
+}
+
+/// Breach count for `suffix` in a range response (`SUFFIX:COUNT` per line);
+/// padding entries carry a zero count
+fn count_in_range(body: &str, suffix: &str) -> u64 {
+    body.lines()
+        .filter_map(|line| line.trim().split_once(':'))
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(suffix))
+        .and_then(|(_, count)| count.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+/// How many times the password appears in the breach corpus
+async fn breach_count(password: &str) -> Result<u64, String> {
+    let (prefix, suffix) = sha1_prefix_suffix(password);
+    let base = std::env::var("HIBP_RANGE_URL").unwrap_or_else(|_| HIBP_RANGE_URL.to_string());
+
+    let body = Guard::named("hibp", ResiliencePolicy::default())
+        .call(retryable_http, || async {
+            reqwest::Client::new()
+                .get(format!("{}{}", base, prefix))
+                .header("Add-Padding", "true")
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(count_in_range(&body, &suffix))
+}
+
+// ============================================================================
+// Rotation
+// ============================================================================
+
+/// Whether a password set at `changed_at` is past its organization's maximum age
+pub fn is_expired(changed_at: OffsetDateTime, max_age_days: Option<i32>, now: OffsetDateTime) -> bool {
+    max_age_days.is_some_and(|days| now - changed_at > Duration::days(days.into()))
+}
+
+/// Whether the user must replace their password before signing in
+pub async fn must_rotate(pool: &PgPool, user_id: Uuid) -> Result<bool, AppError> {
+    let (changed_at, max_age_days) = sqlx::query_as::<_, (OffsetDateTime, Option<i32>)>(
+        "SELECT u.password_changed_at, o.password_max_age_days \
+         FROM users u JOIN organizations o ON o.id = u.org_id WHERE u.id = $1",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    Ok(is_expired(changed_at, max_age_days, OffsetDateTime::now_utc()))
+}
+
+/// Store a new password hash and restart its rotation clock
+pub async fn set_password(pool: &PgPool, user_id: Uuid, hash: &str) -> Result<(), AppError> {
+    sqlx::query("UPDATE users SET hash = $1, password_changed_at = CURRENT_TIMESTAMP WHERE id = $2")
+        .bind(hash)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(violations: &[PolicyViolation]) -> Vec<&'static str> {
+        violations.iter().map(|v| v.code).collect()
+    }
+
+    #[test]
+    fn test_local_rules() {
+        let policy = PasswordPolicy { min_character_classes: 3, ..PasswordPolicy::default() };
+
+        assert!(policy.check_local("Correct-horse-7", "alice").is_empty());
+        assert_eq!(codes(&policy.check_local("short", "alice")), vec!["too_short", "too_few_character_classes"]);
+        assert_eq!(codes(&policy.check_local("Alice-rocks-2024", "alice")), vec!["contains_username"]);
+        assert_eq!(codes(&policy.check_local(&"Ab1-".repeat(40), "bob")), vec!["too_long"]);
+    }
+
+    #[test]
+    fn test_range_lookup() {
+        let (prefix, suffix) = sha1_prefix_suffix("password");
+        assert_eq!(prefix, "5BAA6");
+        assert_eq!(suffix, "1E4C9B93F3F0682250B6CF8331B7EE68FD8");
+
+        let body = "003D68EB55068C33ACE09247EE4C639306B:3\r\n\
+                    1E4C9B93F3F0682250B6CF8331B7EE68FD8:9545824\r\n\
+                    01330C689E5D64F660D6947A93AD634EF8F:0\r\n";
+        assert_eq!(count_in_range(body, &suffix), 9_545_824);
+        assert_eq!(count_in_range(body, "01330C689E5D64F660D6947A93AD634EF8F"), 0);
+        assert_eq!(count_in_range(body, "FFFFF"), 0);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let now = OffsetDateTime::now_utc();
+        assert!(!is_expired(now - Duration::days(400), None, now));
+        assert!(!is_expired(now - Duration::days(30), Some(90), now));
+        assert!(is_expired(now - Duration::days(91), Some(90), now));
+    }
+}
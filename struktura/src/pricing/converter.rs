@@ -1,6 +1,10 @@
 use crate::pricing::{errors::*, models::Currency, traits::CurrencyConverter};
+use crate::resilience::Guard;
 use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::sync::RwLock;
 
 /// Simple currency converter with static rates
@@ -79,10 +83,332 @@ impl CurrencyConverter for SimpleCurrencyConverter {
     }
 }
 
+/// Where live exchange rates come from
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateSource {
+    /// European Central Bank daily reference rates - free, no key, EUR based
+    Ecb,
+    /// exchangerate.host live rates - needs an access key
+    ExchangeRateHost { access_key: String },
+}
+
+impl RateSource {
+    /// `PRICING_FX_SOURCE` = `ecb` (default) or `exchangerate.host`, the
+    /// latter with its key in `PRICING_FX_ACCESS_KEY`
+    pub fn from_env() -> Self {
+        let source = std::env::var("PRICING_FX_SOURCE").unwrap_or_default();
+        let access_key = std::env::var("PRICING_FX_ACCESS_KEY").unwrap_or_default();
+        Self::from_vars(&source, &access_key)
+    }
+
+    fn from_vars(source: &str, access_key: &str) -> Self {
+        match source.trim().to_ascii_lowercase().as_str() {
+            "exchangerate.host" | "exchangeratehost" if !access_key.trim().is_empty() => {
+                RateSource::ExchangeRateHost { access_key: access_key.trim().to_string() }
+            }
+            "exchangerate.host" | "exchangeratehost" => {
+                tracing::warn!("PRICING_FX_SOURCE=exchangerate.host without PRICING_FX_ACCESS_KEY; using ECB rates");
+                RateSource::Ecb
+            }
+            _ => RateSource::Ecb,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            RateSource::Ecb => "ecb",
+            RateSource::ExchangeRateHost { .. } => "exchangerate.host",
+        }
+    }
+
+    fn default_base_url(&self) -> &'static str {
+        match self {
+            RateSource::Ecb => "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml",
+            RateSource::ExchangeRateHost { .. } => "https://api.exchangerate.host/live",
+        }
+    }
+}
+
+/// One snapshot of rates against a single base currency
+#[derive(Debug, Clone)]
+pub struct RateTable {
+    pub base: Currency,
+    /// Units of each currency per one unit of `base`
+    pub rates: HashMap<Currency, f64>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl RateTable {
+    pub fn new(base: Currency, rates: HashMap<Currency, f64>) -> Self {
+        let mut rates: HashMap<Currency, f64> = rates
+            .into_iter()
+            .filter(|(_, rate)| rate.is_finite() && *rate > 0.0)
+            .collect();
+        rates.insert(base, 1.0);
+
+        Self { base, rates, fetched_at: Utc::now() }
+    }
+
+    /// Cross rate through the base currency
+    pub fn rate(&self, from: Currency, to: Currency) -> Option<f64> {
+        Some(self.rates.get(&to)? / self.rates.get(&from)?)
+    }
+
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        Utc::now() - self.fetched_at < ttl
+    }
+}
+
+#[derive(Deserialize)]
+struct EcbEnvelope {
+    #[serde(rename = "Cube")]
+    cube: EcbCube,
+}
+
+#[derive(Deserialize)]
+struct EcbCube {
+    #[serde(rename = "Cube")]
+    day: EcbDay,
+}
+
+#[derive(Deserialize)]
+struct EcbDay {
+    #[serde(rename = "Cube", default)]
+    rates: Vec<EcbRate>,
+}
+
+#[derive(Deserialize)]
+struct EcbRate {
+    #[serde(rename = "@currency")]
+    currency: String,
+    #[serde(rename = "@rate")]
+    rate: f64,
+}
+
+/// Parse the ECB daily reference rates (EUR based)
+fn parse_ecb(body: &str) -> PricingResult<RateTable> {
+    let envelope: EcbEnvelope = quick_xml::de::from_str(body)
+        .map_err(|e| PricingError::ApiError(format!("ECB rates: {}", e)))?;
+
+    let rates = envelope.cube.day.rates
+        .into_iter()
+        .filter_map(|r| Some((Currency::from_code(&r.currency)?, r.rate)))
+        .collect();
+
+    Ok(RateTable::new(Currency::EUR, rates))
+}
+
+#[derive(Deserialize)]
+struct ExchangeRateHostLive {
+    #[serde(default)]
+    success: bool,
+    source: Option<String>,
+    #[serde(default)]
+    quotes: HashMap<String, f64>,
+    error: Option<serde_json::Value>,
+}
+
+/// Parse an exchangerate.host `live` response; quotes are keyed `EURUSD`
+fn parse_exchangerate_host(body: &str) -> PricingResult<RateTable> {
+    let live: ExchangeRateHostLive = serde_json::from_str(body)
+        .map_err(|e| PricingError::ApiError(format!("exchangerate.host rates: {}", e)))?;
+
+    if !live.success {
+        let reason = live.error.map(|e| e.to_string()).unwrap_or_else(|| "request rejected".to_string());
+        return Err(PricingError::ApiError(format!("exchangerate.host: {}", reason)));
+    }
+
+    let base = live.source
+        .as_deref()
+        .and_then(Currency::from_code)
+        .ok_or_else(|| PricingError::ApiError("exchangerate.host: unknown source currency".to_string()))?;
+
+    let rates = live.quotes
+        .into_iter()
+        .filter_map(|(pair, rate)| {
+            let code = pair.strip_prefix(base.code())?;
+            Some((Currency::from_code(code)?, rate))
+        })
+        .collect();
+
+    Ok(RateTable::new(base, rates))
+}
+
+/// Currency converter backed by live exchange rates
+///
+/// Rates are fetched once per TTL (`PRICING_FX_TTL_HOURS`, default 12; the
+/// ECB publishes once a working day) and shared by every conversion. When a
+/// refresh fails the last table keeps serving, and before any table has been
+/// fetched the static rates stand in, so a flaky rate feed never fails a
+/// price lookup.
+pub struct LiveCurrencyConverter {
+    client: reqwest::Client,
+    guard: Arc<Guard>,
+    source: RateSource,
+    base_url: String,
+    ttl: Duration,
+    table: RwLock<Option<RateTable>>,
+    fallback: SimpleCurrencyConverter,
+}
+
+impl LiveCurrencyConverter {
+    pub fn new() -> Self {
+        let ttl_hours = std::env::var("PRICING_FX_TTL_HOURS")
+            .ok()
+            .and_then(|v| v.trim().parse::<i64>().ok())
+            .filter(|h| *h > 0)
+            .unwrap_or(12);
+
+        Self::with_source(RateSource::from_env()).with_ttl(Duration::hours(ttl_hours))
+    }
+
+    pub fn with_source(source: RateSource) -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent("struktura-pricing/1.0")
+            .build()
+            .unwrap();
+
+        Self {
+            client,
+            guard: Guard::named("fx_rates", Default::default()),
+            base_url: source.default_base_url().to_string(),
+            source,
+            ttl: Duration::hours(12),
+            table: RwLock::new(None),
+            fallback: SimpleCurrencyConverter::new(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// When the rates in use were fetched; `None` while on static rates
+    pub async fn rates_fetched_at(&self) -> Option<DateTime<Utc>> {
+        self.table.read().await.as_ref().map(|t| t.fetched_at)
+    }
+
+    /// Fetch a fresh table now, replacing the cached one
+    pub async fn refresh(&self) -> PricingResult<()> {
+        let table = self.fetch().await?;
+        *self.table.write().await = Some(table);
+        Ok(())
+    }
+
+    async fn fetch(&self) -> PricingResult<RateTable> {
+        let url = match &self.source {
+            RateSource::Ecb => self.base_url.clone(),
+            RateSource::ExchangeRateHost { access_key } => {
+                let currencies = Currency::ALL.iter().map(|c| c.code()).collect::<Vec<_>>().join(",");
+                format!(
+                    "{}?access_key={}&source=EUR&currencies={}",
+                    self.base_url,
+                    urlencoding::encode(access_key),
+                    currencies
+                )
+            }
+        };
+
+        let body = self.guard
+            .call(PricingError::is_retryable, || async {
+                let response = self.client
+                    .get(&url)
+                    .send()
+                    .await
+                    .map_err(|e| PricingError::NetworkError(e.to_string()))?;
+
+                if !response.status().is_success() {
+                    return Err(PricingError::ApiError(format!(
+                        "{} returned {}",
+                        self.source.name(),
+                        response.status()
+                    )));
+                }
+
+                response.text().await.map_err(|e| PricingError::NetworkError(e.to_string()))
+            })
+            .await?;
+
+        match self.source {
+            RateSource::Ecb => parse_ecb(&body),
+            RateSource::ExchangeRateHost { .. } => parse_exchangerate_host(&body),
+        }
+    }
+
+    /// Cached rate when fresh, otherwise refresh; a failed refresh keeps the
+    /// stale table and returns `None` only when there has never been one
+    async fn live_rate(&self, from: Currency, to: Currency) -> Option<f64> {
+        if let Some(table) = self.table.read().await.as_ref()
+            && table.is_fresh(self.ttl)
+        {
+            return table.rate(from, to);
+        }
+
+        if let Err(e) = self.refresh().await {
+            tracing::warn!(source = self.source.name(), error = %e, "exchange rates not refreshed");
+        }
+
+        self.table.read().await.as_ref().and_then(|t| t.rate(from, to))
+    }
+}
+
+impl Default for LiveCurrencyConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CurrencyConverter for LiveCurrencyConverter {
+    async fn convert(&self, amount: f64, from: Currency, to: Currency) -> PricingResult<f64> {
+        if from == to {
+            return Ok(amount);
+        }
+
+        let rate = self.get_rate(from, to).await?;
+        Ok(amount * rate)
+    }
+
+    async fn get_rate(&self, from: Currency, to: Currency) -> PricingResult<f64> {
+        if from == to {
+            return Ok(1.0);
+        }
+
+        match self.live_rate(from, to).await {
+            Some(rate) => Ok(rate),
+            None => self.fallback.get_rate(from, to).await,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_pricing_convertion {
     use super::*;
-    
+    use crate::pricing::models::{MaterialCategory, MaterialId, PriceInfo, PriceResponse, StoreInfo};
+
+    const ECB_DAILY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+	<gesmes:subject>Reference rates</gesmes:subject>
+	<gesmes:Sender>
+		<gesmes:name>European Central Bank</gesmes:name>
+	</gesmes:Sender>
+	<Cube>
+		<Cube time='2026-10-16'>
+			<Cube currency='USD' rate='1.0800'/>
+			<Cube currency='JPY' rate='161.20'/>
+			<Cube currency='GBP' rate='0.8500'/>
+			<Cube currency='BRL' rate='5.9400'/>
+			<Cube currency='CAD' rate='1.4800'/>
+		</Cube>
+	</Cube>
+</gesmes:Envelope>"#;
+
     #[tokio::test]
     async fn test_conversion() {
         let converter = SimpleCurrencyConverter::new();
@@ -93,4 +419,84 @@ mod test_pricing_convertion {
         let same = converter.convert(100.0, Currency::USD, Currency::USD).await.unwrap();
         assert_eq!(same, 100.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_ecb_cross_rates() {
+        let table = parse_ecb(ECB_DAILY).unwrap();
+
+        assert_eq!(table.base, Currency::EUR);
+        assert_eq!(table.rates.len(), 5); // JPY is not a supported currency
+        assert_eq!(table.rate(Currency::EUR, Currency::USD), Some(1.08));
+
+        let usd_brl = table.rate(Currency::USD, Currency::BRL).unwrap();
+        assert!((usd_brl - 5.94 / 1.08).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_exchangerate_host() {
+        let body = r#"{"success":true,"source":"EUR","timestamp":1792195200,
+            "quotes":{"EURUSD":1.08,"EURGBP":0.85,"EURBRL":5.94,"EURCAD":1.48,"EURJPY":161.2}}"#;
+        let table = parse_exchangerate_host(body).unwrap();
+        assert_eq!(table.rate(Currency::EUR, Currency::GBP), Some(0.85));
+
+        let rejected = r#"{"success":false,"error":{"code":101,"type":"missing_access_key"}}"#;
+        assert!(parse_exchangerate_host(rejected).is_err());
+    }
+
+    #[test]
+    fn test_rate_source_from_vars() {
+        assert_eq!(RateSource::from_vars("", ""), RateSource::Ecb);
+        assert_eq!(RateSource::from_vars("exchangerate.host", ""), RateSource::Ecb);
+        assert_eq!(
+            RateSource::from_vars("exchangerate.host", "key"),
+            RateSource::ExchangeRateHost { access_key: "key".to_string() }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_live_converter_uses_cached_table() {
+        let converter = LiveCurrencyConverter::with_source(RateSource::Ecb)
+            .with_base_url("http://127.0.0.1:9/unreachable");
+        *converter.table.write().await = Some(parse_ecb(ECB_DAILY).unwrap());
+
+        let gbp = converter.convert(100.0, Currency::EUR, Currency::GBP).await.unwrap();
+        assert!((gbp - 85.0).abs() < 1e-9);
+    }
+
+    fn concrete_price(price: f64, currency: Currency) -> PriceInfo {
+        PriceInfo {
+            material: MaterialId::new(MaterialCategory::Concrete, "concrete_30mpa", "m3", "Concrete 30MPa"),
+            price,
+            currency,
+            store: StoreInfo {
+                name: "Depot".to_string(),
+                address: String::new(),
+                distance_km: None,
+                latitude: None,
+                longitude: None,
+                phone: None,
+                website: None,
+                maps_link: None,
+            },
+            in_stock: true,
+            last_updated: Utc::now(),
+            notes: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_normalize_to_one_currency() {
+        let converter = SimpleCurrencyConverter::new();
+        let mut response = PriceResponse::new();
+        response.prices = vec![
+            concrete_price(500.0, Currency::BRL),
+            concrete_price(100.0, Currency::USD),
+        ];
+
+        response.normalize_to(Currency::USD, &converter).await;
+
+        assert!(response.prices.iter().all(|p| p.currency == Currency::USD));
+        assert!((response.prices[0].price - 101.0).abs() < 1e-9);
+        assert!(response.warnings.is_empty());
+    }
+}
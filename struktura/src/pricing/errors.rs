@@ -1,7 +1,7 @@
 use thiserror::Error;
-use std::fmt::Display;
+use crate::resilience::ResilienceError;
 
-#[derive(Error, Debug, Display)]
+#[derive(Error, Debug)]
 pub enum PricingError {
     #[error("Location not supported: {0}")]
     UnsupportedLocation(String),
@@ -24,6 +24,12 @@ pub enum PricingError {
     #[error("No stores found within {0}km radius")]
     NoStoresInRadius(f64),
     
+    #[error("Rate limited by {0}")]
+    RateLimited(String),
+    
+    #[error("Blocked by robots.txt: {0}")]
+    DisallowedByRobots(String),
+    
     #[error("Configuration error: {0}")]
     ConfigError(String),
 }
@@ -34,9 +40,21 @@ impl PricingError {
     pub fn is_retryable(&self) -> bool {
         matches!(
             self,
-            PricingError::NetworkError(_) | PricingError::ApiError(_)
+            PricingError::NetworkError(_) | PricingError::ApiError(_) | PricingError::RateLimited(_)
         )
     }
 }
 
+impl From<ResilienceError<PricingError>> for PricingError {
+    fn from(e: ResilienceError<PricingError>) -> Self {
+        match e {
+            ResilienceError::Failed(e) => e,
+            ResilienceError::Timeout(..) => PricingError::NetworkError(e.to_string()),
+            ResilienceError::CircuitOpen(ref name) | ResilienceError::BulkheadFull(ref name) => {
+                PricingError::ProviderFailed(name.clone(), e.to_string())
+            }
+        }
+    }
+}
+
 pub type PricingResult<T> = Result<T, PricingError>;
\ No newline at end of file
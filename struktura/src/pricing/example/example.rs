@@ -4,7 +4,7 @@ use pricing::*;
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Initialize with zero configuration
-    let engine = init_pricing_engine().await?;
+    let engine = init_pricing_engine(None).await?;
     
     println!("🎯 Pricing Engine initialized");
     println!("📡 Providers: {:?}", engine.list_providers().await);
@@ -0,0 +1,283 @@
+// "You can't measure the march if you don't know where the camp is."
+
+use crate::pricing::{errors::*, models::*};
+use crate::resilience::{Guard, ResiliencePolicy};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Mean Earth radius (IUGG), km
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// Great-circle distance between two (lat, lon) points in degrees
+pub fn haversine_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lon1) = (from.0.to_radians(), from.1.to_radians());
+    let (lat2, lon2) = (to.0.to_radians(), to.1.to_radians());
+
+    let dlat = lat2 - lat1;
+    let dlon = lon2 - lon1;
+    let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Reject coordinates outside the valid lat/lon ranges
+pub fn validate_coordinates(lat: f64, lon: f64) -> PricingResult<()> {
+    if !lat.is_finite() || !lon.is_finite() || lat.abs() > 90.0 || lon.abs() > 180.0 {
+        return Err(PricingError::InvalidCoordinates(format!("{}, {}", lat, lon)));
+    }
+    Ok(())
+}
+
+/// Geocoding provider trait - turns addresses into coordinates
+#[async_trait]
+pub trait Geocoder: Send + Sync {
+    /// Provider identification
+    fn name(&self) -> &str;
+
+    /// Resolve a free-form address to (lat, lon); `None` when nothing matches
+    async fn geocode(&self, query: &str) -> PricingResult<Option<(f64, f64)>>;
+
+    /// Resolve a request location, using its coordinates when already known
+    async fn locate(&self, location: &Location) -> PricingResult<Option<(f64, f64)>> {
+        if let Some(coords) = location.coordinates() {
+            return Ok(Some(coords));
+        }
+
+        let query = [location.postal_code.as_ref(), location.city.as_ref(), location.region.as_ref()]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .chain(std::iter::once(location.country_code.clone()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        self.geocode(&query).await
+    }
+}
+
+/// OpenStreetMap Nominatim geocoder
+///
+/// Same doctrine as the DuckDuckGo provider: no API keys, free to use.
+/// Results are cached because Nominatim's usage policy caps us at 1 req/s.
+pub struct NominatimGeocoder {
+    client: reqwest::Client,
+    guard: Arc<Guard>,
+    base_url: String,
+    cache: tokio::sync::RwLock<HashMap<String, Option<(f64, f64)>>>,
+}
+
+#[derive(serde::Deserialize)]
+struct NominatimPlace {
+    lat: String,
+    lon: String,
+}
+
+impl NominatimGeocoder {
+    pub fn new() -> Self {
+        Self::with_base_url("https://nominatim.openstreetmap.org")
+    }
+
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent("struktura-pricing/1.0")
+            .build()
+            .unwrap();
+        // Nominatim's policy allows one request at a time
+        let policy = ResiliencePolicy { max_concurrent: 1, ..ResiliencePolicy::default() };
+
+        Self {
+            client,
+            guard: Guard::named("nominatim", policy),
+            base_url: base_url.into(),
+            cache: tokio::sync::RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for NominatimGeocoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Geocoder for NominatimGeocoder {
+    fn name(&self) -> &str {
+        "nominatim"
+    }
+
+    async fn geocode(&self, query: &str) -> PricingResult<Option<(f64, f64)>> {
+        if let Some(cached) = self.cache.read().await.get(query) {
+            return Ok(*cached);
+        }
+
+        let url = format!(
+            "{}/search?format=json&limit=1&q={}",
+            self.base_url,
+            urlencoding::encode(query)
+        );
+
+        let body = self.guard
+            .call(PricingError::is_retryable, || async {
+                self.client
+                    .get(&url)
+                    .send()
+                    .await
+                    .map_err(|e| PricingError::NetworkError(e.to_string()))?
+                    .text()
+                    .await
+                    .map_err(|e| PricingError::NetworkError(e.to_string()))
+            })
+            .await?;
+
+        let places: Vec<NominatimPlace> = serde_json::from_str(&body)
+            .map_err(|e| PricingError::ApiError(e.to_string()))?;
+
+        let coords = places.first().and_then(|p| {
+            Some((p.lat.parse::<f64>().ok()?, p.lon.parse::<f64>().ok()?))
+        });
+
+        self.cache.write().await.insert(query.to_string(), coords);
+        Ok(coords)
+    }
+}
+
+/// Fixed lookup table - for tests and offline deployments
+#[derive(Default)]
+pub struct StaticGeocoder {
+    places: HashMap<String, (f64, f64)>,
+}
+
+impl StaticGeocoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_place(mut self, query: impl Into<String>, lat: f64, lon: f64) -> Self {
+        self.places.insert(query.into().to_lowercase(), (lat, lon));
+        self
+    }
+}
+
+#[async_trait]
+impl Geocoder for StaticGeocoder {
+    fn name(&self) -> &str {
+        "static"
+    }
+
+    async fn geocode(&self, query: &str) -> PricingResult<Option<(f64, f64)>> {
+        Ok(self.places.get(&query.to_lowercase()).copied())
+    }
+}
+
+/// Give a location coordinates from its address when it has none yet
+pub async fn place(geocoder: &dyn Geocoder, location: &mut Location) -> PricingResult<()> {
+    if location.coordinates().is_none()
+        && let Some((lat, lon)) = geocoder.locate(location).await?
+    {
+        location.latitude = Some(lat);
+        location.longitude = Some(lon);
+    }
+    Ok(())
+}
+
+/// Fill `distance_km` on every price from store coordinates
+///
+/// Stores without coordinates are geocoded by address when a geocoder is
+/// available; anything still unplaced keeps `distance_km = None`.
+pub async fn resolve_distances(
+    origin: (f64, f64),
+    geocoder: Option<&dyn Geocoder>,
+    prices: &mut [PriceInfo],
+) {
+    let mut resolved: HashMap<String, Option<(f64, f64)>> = HashMap::new();
+
+    for price in prices.iter_mut() {
+        let mut coords = price.store.coordinates();
+
+        if coords.is_none()
+            && let Some(geocoder) = geocoder
+        {
+            let address = price.store.address.clone();
+            if !resolved.contains_key(&address) {
+                let found = geocoder.geocode(&address).await.ok().flatten();
+                resolved.insert(address.clone(), found);
+            }
+            coords = resolved[&address];
+            if let Some((lat, lon)) = coords {
+                price.store.latitude = Some(lat);
+                price.store.longitude = Some(lon);
+            }
+        }
+
+        price.store.distance_km = coords.map(|c| haversine_km(origin, c));
+    }
+}
+
+#[cfg(test)]
+mod test_pricing_geocoding {
+    use super::*;
+
+    #[test]
+    fn test_haversine_known_distance() {
+        // São Paulo (Sé) to Campinas: ~84 km as the crow flies
+        let sao_paulo = (-23.5505, -46.6333);
+        let campinas = (-22.9056, -47.0608);
+        let d = haversine_km(sao_paulo, campinas);
+        assert!((d - 83.7).abs() < 2.0, "got {}", d);
+        assert_eq!(haversine_km(campinas, campinas), 0.0);
+    }
+
+    #[test]
+    fn test_validate_coordinates() {
+        assert!(validate_coordinates(-22.9, -47.06).is_ok());
+        assert!(validate_coordinates(91.0, 0.0).is_err());
+        assert!(validate_coordinates(0.0, f64::NAN).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_place_keeps_known_coordinates() {
+        let geocoder = StaticGeocoder::new().with_place("Campinas, SP, BR", -22.9056, -47.0608);
+
+        let mut site = Location::new("BR").with_region("SP").with_city("Campinas");
+        place(&geocoder, &mut site).await.unwrap();
+        assert_eq!(site.coordinates(), Some((-22.9056, -47.0608)));
+
+        let mut yard = Location::new("BR").with_city("Campinas").with_coordinates(-23.55, -46.63);
+        place(&geocoder, &mut yard).await.unwrap();
+        assert_eq!(yard.coordinates(), Some((-23.55, -46.63)));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_distances_geocodes_store_address() {
+        let geocoder = StaticGeocoder::new()
+            .with_place("Av. das Nações, Campinas - SP", -22.8936, -47.0306);
+        let material = MaterialId::new(MaterialCategory::Sand, "sand", "m3", "Sand");
+
+        let mut prices = vec![PriceInfo {
+            material,
+            price: 85.0,
+            currency: Currency::BRL,
+            store: StoreInfo {
+                name: "Leroy Merlin".to_string(),
+                address: "Av. das Nações, Campinas - SP".to_string(),
+                distance_km: Some(999.0),
+                latitude: None,
+                longitude: None,
+                phone: None,
+                website: None,
+                maps_link: None,
+            },
+            in_stock: true,
+            last_updated: chrono::Utc::now(),
+            notes: None,
+        }];
+
+        resolve_distances((-22.9056, -47.0608), Some(&geocoder), &mut prices).await;
+
+        let distance = prices[0].store.distance_km.unwrap();
+        assert!(distance > 2.0 && distance < 5.0, "got {}", distance);
+        assert!(prices[0].store.latitude.is_some());
+    }
+}
@@ -0,0 +1,195 @@
+// "Know the state of your scouts before you send the army."
+
+use crate::pricing::traits::PriceProvider;
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// What kind of source a provider is - drives failover ordering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderTier {
+    /// Structured retailer/distributor API
+    Api,
+    /// Web scraping (DuckDuckGo and friends)
+    Scraper,
+    /// Bundled reference data
+    Static,
+}
+
+impl std::str::FromStr for ProviderTier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "api" => Ok(ProviderTier::Api),
+            "scraper" => Ok(ProviderTier::Scraper),
+            "static" => Ok(ProviderTier::Static),
+            other => Err(format!("Unknown provider tier '{}'", other)),
+        }
+    }
+}
+
+/// Order in which provider tiers are consulted
+///
+/// Each tier is only asked for materials the previous tiers could not
+/// price. Tiers missing from the chain are never consulted.
+#[derive(Debug, Clone, Serialize)]
+pub struct FailoverPolicy {
+    pub chain: Vec<ProviderTier>,
+}
+
+impl FailoverPolicy {
+    pub fn new(chain: Vec<ProviderTier>) -> Self {
+        Self { chain }
+    }
+
+    /// Parse a comma-separated chain, e.g. `"api,scraper,static"`
+    pub fn parse(chain: &str) -> Result<Self, String> {
+        let chain = chain
+            .split(',')
+            .filter(|s| !s.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<ProviderTier>, _>>()?;
+        if chain.is_empty() {
+            return Err("Failover chain is empty".to_string());
+        }
+        Ok(Self { chain })
+    }
+
+    /// Read `PRICING_FAILOVER`, falling back to the default chain
+    pub fn from_env() -> Self {
+        std::env::var("PRICING_FAILOVER")
+            .ok()
+            .and_then(|chain| Self::parse(&chain).ok())
+            .unwrap_or_default()
+    }
+}
+
+impl Default for FailoverPolicy {
+    fn default() -> Self {
+        Self::new(vec![ProviderTier::Api, ProviderTier::Scraper, ProviderTier::Static])
+    }
+}
+
+/// Running counters for one provider
+#[derive(Debug, Clone, Default)]
+struct ProviderStats {
+    attempts: u64,
+    successes: u64,
+    total_latency: Duration,
+    last_error: Option<String>,
+}
+
+/// Records the outcome of every provider call made by the engine
+#[derive(Default)]
+pub struct ProviderHealthMonitor {
+    stats: RwLock<HashMap<String, ProviderStats>>,
+}
+
+impl ProviderHealthMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn record(&self, provider: &str, latency: Duration, error: Option<String>) {
+        let mut stats = self.stats.write().await;
+        let entry = stats.entry(provider.to_string()).or_default();
+        entry.attempts += 1;
+        entry.total_latency += latency;
+        match error {
+            None => entry.successes += 1,
+            Some(e) => entry.last_error = Some(e),
+        }
+    }
+
+    /// Live health report, running each provider's health check
+    pub async fn report(&self, providers: &[Arc<dyn PriceProvider>]) -> Vec<ProviderHealthReport> {
+        let stats = self.stats.read().await.clone();
+        let mut reports = Vec::with_capacity(providers.len());
+
+        for provider in providers {
+            let s = stats.get(provider.name()).cloned().unwrap_or_default();
+            let healthy = provider.health_check().await.unwrap_or(false);
+
+            reports.push(ProviderHealthReport {
+                name: provider.name().to_string(),
+                tier: provider.tier(),
+                healthy,
+                attempts: s.attempts,
+                success_rate: (s.attempts > 0).then(|| s.successes as f64 / s.attempts as f64),
+                avg_latency_ms: (s.attempts > 0)
+                    .then(|| s.total_latency.as_secs_f64() * 1000.0 / s.attempts as f64),
+                last_error: s.last_error,
+            });
+        }
+
+        reports
+    }
+}
+
+/// One row of the provider dashboard
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderHealthReport {
+    pub name: String,
+    pub tier: ProviderTier,
+    pub healthy: bool,
+    pub attempts: u64,
+    pub success_rate: Option<f64>,
+    pub avg_latency_ms: Option<f64>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProviderDashboard {
+    pub failover: FailoverPolicy,
+    pub providers: Vec<ProviderHealthReport>,
+}
+
+// ============================================================================
+// API
+// ============================================================================
+
+/// Provider health, success rates and failover order (admin only, since
+/// it triggers outbound health checks)
+pub async fn provider_dashboard_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<ProviderDashboard>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    Ok(Json(state.pricing.dashboard().await))
+}
+
+#[cfg(test)]
+mod test_pricing_health {
+    use super::*;
+
+    #[test]
+    fn test_parse_failover_chain() {
+        let policy = FailoverPolicy::parse("scraper, static").unwrap();
+        assert_eq!(policy.chain, vec![ProviderTier::Scraper, ProviderTier::Static]);
+        assert!(FailoverPolicy::parse("carrier-pigeon").is_err());
+        assert!(FailoverPolicy::parse("").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_monitor_success_rate_and_latency() {
+        let monitor = ProviderHealthMonitor::new();
+        monitor.record("static", Duration::from_millis(10), None).await;
+        monitor.record("static", Duration::from_millis(30), Some("timeout".to_string())).await;
+
+        let provider: Arc<dyn PriceProvider> = Arc::new(crate::pricing::providers::StaticProvider::new());
+        let report = monitor.report(&[provider]).await;
+
+        assert_eq!(report[0].attempts, 2);
+        assert_eq!(report[0].success_rate, Some(0.5));
+        assert!((report[0].avg_latency_ms.unwrap() - 20.0).abs() < 1e-9);
+        assert_eq!(report[0].last_error.as_deref(), Some("timeout"));
+        assert_eq!(report[0].tier, ProviderTier::Static);
+    }
+}
@@ -0,0 +1,425 @@
+// "Those who cannot remember the past are condemned to overpay for it."
+
+use crate::pricing::{errors::*, models::*};
+use crate::state::AppState;
+use async_trait::async_trait;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// One observed price, flattened for storage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PricePoint {
+    pub material_code: String,
+    pub country_code: String,
+    pub region: Option<String>,
+    pub city: Option<String>,
+    pub store_name: String,
+    pub price: f64,
+    pub currency: Currency,
+    pub observed_at: DateTime<Utc>,
+}
+
+impl PricePoint {
+    pub fn from_price(price: &PriceInfo, location: &Location) -> Self {
+        Self {
+            material_code: price.material.code.clone(),
+            country_code: location.country_code.clone(),
+            region: location.region.clone(),
+            city: location.city.clone(),
+            store_name: price.store.name.clone(),
+            price: price.price,
+            currency: price.currency,
+            observed_at: price.last_updated,
+        }
+    }
+
+    fn matches(&self, material_code: &str, location: &Location) -> bool {
+        self.material_code == material_code
+            && self.country_code == location.country_code
+            && location.region.as_ref().is_none_or(|r| self.region.as_ref() == Some(r))
+            && location.city.as_ref().is_none_or(|c| self.city.as_ref() == Some(c))
+    }
+}
+
+/// Storage for observed prices
+#[async_trait]
+pub trait PriceHistoryStore: Send + Sync {
+    async fn record(&self, points: &[PricePoint]) -> PricingResult<()>;
+
+    /// Points for a material at a location observed since `since`, oldest first
+    async fn query(
+        &self,
+        material_code: &str,
+        location: &Location,
+        since: DateTime<Utc>,
+    ) -> PricingResult<Vec<PricePoint>>;
+}
+
+/// Process-local history - for tests and deployments without a database
+#[derive(Default)]
+pub struct InMemoryPriceHistory {
+    points: RwLock<Vec<PricePoint>>,
+}
+
+impl InMemoryPriceHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PriceHistoryStore for InMemoryPriceHistory {
+    async fn record(&self, points: &[PricePoint]) -> PricingResult<()> {
+        self.points.write().await.extend_from_slice(points);
+        Ok(())
+    }
+
+    async fn query(
+        &self,
+        material_code: &str,
+        location: &Location,
+        since: DateTime<Utc>,
+    ) -> PricingResult<Vec<PricePoint>> {
+        let mut points: Vec<PricePoint> = self.points.read().await
+            .iter()
+            .filter(|p| p.observed_at >= since && p.matches(material_code, location))
+            .cloned()
+            .collect();
+        points.sort_by_key(|p| p.observed_at);
+        Ok(points)
+    }
+}
+
+/// Postgres-backed history (see migrations/003_price_history.sql)
+pub struct PgPriceHistory {
+    pool: PgPool,
+}
+
+impl PgPriceHistory {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn to_offset(at: DateTime<Utc>) -> PricingResult<time::OffsetDateTime> {
+    time::OffsetDateTime::from_unix_timestamp(at.timestamp())
+        .map(|t| t.replace_nanosecond(at.timestamp_subsec_nanos()).unwrap_or(t))
+        .map_err(|e| PricingError::ConfigError(e.to_string()))
+}
+
+fn from_offset(at: time::OffsetDateTime) -> DateTime<Utc> {
+    DateTime::from_timestamp(at.unix_timestamp(), at.nanosecond()).unwrap_or_default()
+}
+
+fn parse_currency(code: &str) -> Option<Currency> {
+    match code {
+        "USD" => Some(Currency::USD),
+        "BRL" => Some(Currency::BRL),
+        "EUR" => Some(Currency::EUR),
+        "GBP" => Some(Currency::GBP),
+        "CAD" => Some(Currency::CAD),
+        _ => None,
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PricePointRow {
+    material_code: String,
+    country_code: String,
+    region: Option<String>,
+    city: Option<String>,
+    store_name: String,
+    price: f64,
+    currency: String,
+    observed_at: time::OffsetDateTime,
+}
+
+#[async_trait]
+impl PriceHistoryStore for PgPriceHistory {
+    /// One statement per fetch, however many prices it returned
+    async fn record(&self, points: &[PricePoint]) -> PricingResult<()> {
+        if points.is_empty() {
+            return Ok(());
+        }
+        let materials: Vec<&str> = points.iter().map(|p| p.material_code.as_str()).collect();
+        let countries: Vec<&str> = points.iter().map(|p| p.country_code.as_str()).collect();
+        let regions: Vec<Option<&str>> = points.iter().map(|p| p.region.as_deref()).collect();
+        let cities: Vec<Option<&str>> = points.iter().map(|p| p.city.as_deref()).collect();
+        let stores: Vec<&str> = points.iter().map(|p| p.store_name.as_str()).collect();
+        let prices: Vec<f64> = points.iter().map(|p| p.price).collect();
+        let currencies: Vec<&str> = points.iter().map(|p| p.currency.code()).collect();
+        let observed: Vec<time::OffsetDateTime> = points
+            .iter()
+            .map(|p| to_offset(p.observed_at))
+            .collect::<PricingResult<_>>()?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO price_history
+                (material_code, country_code, region, city, store_name, price, currency, observed_at)
+            SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[], $4::text[], $5::text[],
+                                 $6::float8[], $7::text[], $8::timestamptz[])
+            "#,
+        )
+        .bind(&materials)
+        .bind(&countries)
+        .bind(&regions)
+        .bind(&cities)
+        .bind(&stores)
+        .bind(&prices)
+        .bind(&currencies)
+        .bind(&observed)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| PricingError::ApiError(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn query(
+        &self,
+        material_code: &str,
+        location: &Location,
+        since: DateTime<Utc>,
+    ) -> PricingResult<Vec<PricePoint>> {
+        let rows = sqlx::query_as::<_, PricePointRow>(
+            r#"
+            SELECT material_code, country_code, region, city, store_name, price, currency, observed_at
+            FROM price_history
+            WHERE material_code = $1
+              AND country_code = $2
+              AND ($3::TEXT IS NULL OR region = $3)
+              AND ($4::TEXT IS NULL OR city = $4)
+              AND observed_at >= $5
+            ORDER BY observed_at
+            "#,
+        )
+        .bind(material_code)
+        .bind(&location.country_code)
+        .bind(&location.region)
+        .bind(&location.city)
+        .bind(to_offset(since)?)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| PricingError::ApiError(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|r| {
+                Some(PricePoint {
+                    currency: parse_currency(&r.currency)?,
+                    material_code: r.material_code,
+                    country_code: r.country_code,
+                    region: r.region,
+                    city: r.city,
+                    store_name: r.store_name,
+                    price: r.price,
+                    observed_at: from_offset(r.observed_at),
+                })
+            })
+            .collect())
+    }
+}
+
+// ============================================================================
+// TREND ANALYSIS
+// ============================================================================
+
+/// Price trend summary for one material at one location
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceTrend {
+    pub material_code: String,
+    pub currency: Currency,
+    pub samples: usize,
+    pub days_observed: usize,
+    pub latest: f64,
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Coefficient of variation of daily mean prices, %
+    pub volatility_pct: f64,
+    pub change_30d_pct: Option<f64>,
+    pub change_90d_pct: Option<f64>,
+    /// Log-linear fit of daily means, expressed per year. Feed this into
+    /// `additional.material_escalation_rate` on the budget forecast.
+    pub annualized_escalation_pct: Option<f64>,
+}
+
+/// Summarise observed prices; `None` when there is nothing to summarise
+///
+/// Points in a currency other than the most recent one are ignored so
+/// that the trend never mixes units.
+pub fn analyze(material_code: &str, points: &[PricePoint], now: DateTime<Utc>) -> Option<PriceTrend> {
+    let currency = points.iter().max_by_key(|p| p.observed_at)?.currency;
+
+    // Average all stores per calendar day
+    let mut days: BTreeMap<chrono::NaiveDate, (f64, usize)> = BTreeMap::new();
+    for p in points.iter().filter(|p| p.currency == currency && p.price.is_finite()) {
+        let entry = days.entry(p.observed_at.date_naive()).or_insert((0.0, 0));
+        entry.0 += p.price;
+        entry.1 += 1;
+    }
+    let daily: Vec<(chrono::NaiveDate, f64)> = days
+        .into_iter()
+        .map(|(day, (sum, n))| (day, sum / n as f64))
+        .collect();
+
+    let samples = points.iter().filter(|p| p.currency == currency).count();
+    let latest = daily.last()?.1;
+    let n = daily.len() as f64;
+    let mean = daily.iter().map(|(_, v)| v).sum::<f64>() / n;
+    let min = daily.iter().map(|(_, v)| *v).fold(f64::INFINITY, f64::min);
+    let max = daily.iter().map(|(_, v)| *v).fold(f64::NEG_INFINITY, f64::max);
+    let variance = daily.iter().map(|(_, v)| (v - mean).powi(2)).sum::<f64>() / n;
+    let volatility_pct = if mean > 0.0 { variance.sqrt() / mean * 100.0 } else { 0.0 };
+
+    let change_since = |days_back: i64| {
+        let cutoff = (now - Duration::days(days_back)).date_naive();
+        daily
+            .iter()
+            .rev()
+            .find(|(day, _)| *day <= cutoff)
+            .filter(|(_, v)| *v > 0.0)
+            .map(|(_, v)| (latest / v - 1.0) * 100.0)
+    };
+
+    let annualized_escalation_pct = if daily.len() >= 2 && min > 0.0 {
+        let origin = daily[0].0;
+        let xs: Vec<f64> = daily.iter().map(|(d, _)| (*d - origin).num_days() as f64).collect();
+        let ys: Vec<f64> = daily.iter().map(|(_, v)| v.ln()).collect();
+        let x_mean = xs.iter().sum::<f64>() / n;
+        let y_mean = ys.iter().sum::<f64>() / n;
+        let sxx: f64 = xs.iter().map(|x| (x - x_mean).powi(2)).sum();
+        let sxy: f64 = xs.iter().zip(&ys).map(|(x, y)| (x - x_mean) * (y - y_mean)).sum();
+        (sxx > 0.0).then(|| ((sxy / sxx * 365.0).exp() - 1.0) * 100.0)
+    } else {
+        None
+    };
+
+    Some(PriceTrend {
+        material_code: material_code.to_string(),
+        currency,
+        samples,
+        days_observed: daily.len(),
+        latest,
+        mean,
+        min,
+        max,
+        volatility_pct,
+        change_30d_pct: change_since(30),
+        change_90d_pct: change_since(90),
+        annualized_escalation_pct,
+    })
+}
+
+// ============================================================================
+// API
+// ============================================================================
+
+/// API router for price history recorded by the application's engine
+pub fn create_history_router() -> Router<Arc<AppState>> {
+    Router::new().route("/history/{material_code}", get(price_trend))
+}
+
+#[derive(Debug, Deserialize)]
+struct TrendQuery {
+    country_code: String,
+    region: Option<String>,
+    city: Option<String>,
+    /// Look-back window (default: 365 days)
+    days: Option<i64>,
+}
+
+async fn price_trend(
+    State(state): State<Arc<AppState>>,
+    Path(material_code): Path<String>,
+    Query(query): Query<TrendQuery>,
+) -> Response {
+    let Some(store) = state.pricing.history() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({ "error": "Price history is not recorded on this deployment" })),
+        ).into_response();
+    };
+
+    let mut location = Location::new(query.country_code);
+    location.region = query.region;
+    location.city = query.city;
+
+    let now = Utc::now();
+    let since = now - Duration::days(query.days.unwrap_or(365).clamp(1, 3650));
+
+    match store.query(&material_code, &location, since).await {
+        Ok(points) => match analyze(&material_code, &points, now) {
+            Some(trend) => Json(trend).into_response(),
+            None => (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({ "error": format!("No price history for '{}'", material_code) })),
+            ).into_response(),
+        },
+        Err(e) => (
+            StatusCode::BAD_GATEWAY,
+            Json(serde_json::json!({ "error": e.to_string() })),
+        ).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod test_pricing_history {
+    use super::*;
+
+    fn point(days_ago: i64, price: f64, now: DateTime<Utc>) -> PricePoint {
+        PricePoint {
+            material_code: "lumber_2x4_8ft".to_string(),
+            country_code: "US".to_string(),
+            region: None,
+            city: None,
+            store_name: "The Home Depot".to_string(),
+            price,
+            currency: Currency::USD,
+            observed_at: now - Duration::days(days_ago),
+        }
+    }
+
+    #[test]
+    fn test_trend_changes_and_escalation() {
+        let now = Utc::now();
+        // Steady 1%/month growth over a year
+        let points: Vec<PricePoint> = (0..=12)
+            .map(|m| point(360 - m * 30, 8.0 * 1.01f64.powi(m as i32), now))
+            .collect();
+
+        let trend = analyze("lumber_2x4_8ft", &points, now).unwrap();
+        assert_eq!(trend.days_observed, 13);
+        assert!((trend.change_30d_pct.unwrap() - 1.0).abs() < 0.01);
+        assert!((trend.change_90d_pct.unwrap() - 3.0301).abs() < 0.01);
+        let escalation = trend.annualized_escalation_pct.unwrap();
+        assert!((escalation - 12.9).abs() < 0.5, "got {}", escalation);
+        assert!(trend.volatility_pct > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_filters_location() {
+        let now = Utc::now();
+        let store = InMemoryPriceHistory::new();
+        let mut elsewhere = point(1, 9.0, now);
+        elsewhere.country_code = "CA".to_string();
+        store.record(&[point(2, 8.0, now), elsewhere]).await.unwrap();
+
+        let points = store
+            .query("lumber_2x4_8ft", &Location::new("US"), now - Duration::days(30))
+            .await
+            .unwrap();
+        assert_eq!(points.len(), 1);
+    }
+}
@@ -0,0 +1,112 @@
+// "Yesterday's prices are today's cover story."
+
+use crate::pricing::{models::*, registry::PricingEngine};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Regional prices resolved for one calculation, in USD per material unit
+///
+/// Calculators read these through [`LivePrices::price_or`], so anything the
+/// engine couldn't price falls back to the calculator's bundled cost.
+#[derive(Debug, Clone, Default)]
+pub struct LivePrices {
+    prices: HashMap<String, f64>,
+    /// Materials left at their bundled cost, and why
+    pub warnings: Vec<String>,
+}
+
+impl LivePrices {
+    /// Live price for a material code, if one was found
+    pub fn get(&self, code: &str) -> Option<f64> {
+        self.prices.get(code).copied()
+    }
+
+    /// Live price for a material code, or the calculator's bundled cost
+    pub fn price_or(&self, code: &str, fallback: f64) -> f64 {
+        self.get(code).unwrap_or(fallback)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.prices.is_empty()
+    }
+}
+
+/// Price a calculator's materials at a location
+///
+/// Prices are requested in USD, the currency calculator costs are written
+/// in, and the cheapest store wins. Supplier quotes of `org_id`, when
+/// given, take precedence. Engine failures never fail the
+/// calculation; they leave warnings and the bundled costs in place.
+pub async fn resolve(
+    engine: &PricingEngine,
+    location: &Location,
+    org_id: Option<Uuid>,
+    materials: Vec<MaterialId>,
+) -> LivePrices {
+    let mut live = LivePrices::default();
+    if materials.is_empty() {
+        return live;
+    }
+
+    let mut request = PriceRequest::new(location.clone()).with_currency(Currency::USD);
+    request.org_id = org_id;
+    for material in &materials {
+        request = request.add_material(material.clone());
+    }
+
+    let response = match engine.fetch_prices(&request).await {
+        Ok(response) => response,
+        Err(e) => {
+            tracing::warn!(country = %location.country_code, error = %e, "live pricing failed");
+            live.warnings.push(format!("Live prices unavailable ({}); standard USD costs were used", e));
+            return live;
+        }
+    };
+
+    for material in &materials {
+        let best = response
+            .all_prices_for(material)
+            .into_iter()
+            .find(|p| p.currency == Currency::USD);
+        match best {
+            Some(price) => {
+                live.prices.insert(material.code.clone(), price.price);
+            }
+            None => live.warnings.push(format!(
+                "No live price for {} in {}; the standard cost was used",
+                material.description, location.country_code
+            )),
+        }
+    }
+    live.warnings.extend(response.warnings);
+
+    live
+}
+
+#[cfg(test)]
+mod test_pricing_live {
+    use super::*;
+    use crate::pricing::providers::StaticProvider;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_unpriced_materials_keep_their_fallback() {
+        let engine = PricingEngine::new();
+        engine.register_provider(Arc::new(StaticProvider::new())).await;
+
+        let boards = MaterialId::new(MaterialCategory::Decking, "deck_board_12ft", "unit", "Deck board 12ft");
+        let posts = MaterialId::new(MaterialCategory::Lumber, "post_4x4_treated", "unit", "Treated post 4x4");
+
+        let live = resolve(&engine, &Location::new("US"), None, vec![boards, posts]).await;
+        assert_eq!(live.get("deck_board_12ft"), Some(24.99));
+        assert_eq!(live.price_or("post_4x4_treated", 15.0), 15.0);
+        assert!(live.warnings.iter().any(|w| w.contains("Treated post 4x4")));
+
+        let nowhere = resolve(&engine, &Location::new("ZZ"), None, vec![MaterialId::new(
+            MaterialCategory::Gravel, "gravel", "m3", "Gravel",
+        )])
+        .await;
+        assert!(nowhere.is_empty());
+        assert!(nowhere.warnings[0].starts_with("Live prices unavailable"));
+    }
+}
@@ -4,12 +4,24 @@ pub mod traits;
 pub mod providers;
 pub mod converter;
 pub mod registry;
+pub mod quotes;
+pub mod geocoding;
+pub mod history;
+pub mod health;
+pub mod politeness;
+pub mod live;
 
 // Re-exports for tactical convenience
 pub use errors::{PricingError, PricingResult};
 pub use models::*;
 pub use traits::*;
-pub use registry::{PricingEngine, init_pricing_engine};
+pub use converter::{LiveCurrencyConverter, RateSource, SimpleCurrencyConverter};
+pub use registry::{PricingEngine, ProviderOptions, init_pricing_engine};
+pub use quotes::{QuoteBook, QuoteStore, SupplierQuote};
+pub use geocoding::{Geocoder, NominatimGeocoder, haversine_km};
+pub use history::{PriceHistoryStore, PriceTrend};
+pub use health::{FailoverPolicy, ProviderTier};
+pub use live::LivePrices;
 
 /// Quick price lookup helper
 pub fn quick_lookup(
@@ -32,7 +44,7 @@ mod test_pricing_module {
     
     #[tokio::test]
     async fn test_quick_lookup() {
-        let engine = init_pricing_engine().await.unwrap();
+        let engine = init_pricing_engine(None).await.unwrap();
         
         let request = quick_lookup(
             "BR",
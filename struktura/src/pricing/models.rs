@@ -1,5 +1,8 @@
-use serde::{de, Deserialize, Serialize};
+use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use std::collections::{hash_map::Entry, BTreeMap, HashMap};
+use uuid::Uuid;
+use crate::pricing::{health::ProviderTier, traits::CurrencyConverter};
 
 /// Location representation - where the operation is taking place
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -39,6 +42,11 @@ impl Location {
         self.longitude = Some(lon);
         self
     }
+    
+    /// (lat, lon) when both are known
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        self.latitude.zip(self.longitude)
+    }
 }
 
 /// Material categories for civil engineering
@@ -126,6 +134,13 @@ pub enum Currency {
 }
 
 impl Currency {
+    pub const ALL: [Currency; 5] = [Currency::USD, Currency::BRL, Currency::EUR, Currency::GBP, Currency::CAD];
+    
+    /// Parse an ISO 4217 code; `None` for currencies we don't price in
+    pub fn from_code(code: &str) -> Option<Currency> {
+        Currency::ALL.into_iter().find(|c| c.code().eq_ignore_ascii_case(code.trim()))
+    }
+    
     pub fn code(&self) -> &str {
         match self {
             Currency::USD => "USD",
@@ -152,12 +167,23 @@ impl Currency {
 pub struct StoreInfo {
     pub name: String,
     pub address: String,
-    pub distance_km: Option<f64>,
+    pub distance_km: Option<f64>,   // Computed from coordinates, never hard-coded
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    #[serde(default)]
+    pub longitude: Option<f64>,
     pub phone: Option<String>,
     pub website: Option<String>,
     pub maps_link: Option<String>,
 }
 
+impl StoreInfo {
+    /// (lat, lon) when both are known
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        self.latitude.zip(self.longitude)
+    }
+}
+
 /// Price information with store details
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceInfo {
@@ -177,6 +203,11 @@ pub struct PriceRequest {
     pub location: Location,
     pub max_distance_km: Option<f64>,  // Maximum store distance
     pub preferred_currency: Option<Currency>,
+    #[serde(default)]
+    pub project_start: Option<DateTime<Utc>>,  // Used to flag quotes that lapse first
+    /// Organization whose supplier quotes apply; set by the server, never the caller
+    #[serde(skip)]
+    pub org_id: Option<Uuid>,
 }
 
 impl PriceRequest {
@@ -186,6 +217,8 @@ impl PriceRequest {
             location,
             max_distance_km: Some(50.0), // Default: 50km radius
             preferred_currency: None,
+            project_start: None,
+            org_id: None,
         }
     }
     
@@ -203,6 +236,27 @@ impl PriceRequest {
         self.preferred_currency = Some(currency);
         self
     }
+    
+    pub fn with_project_start(mut self, start: DateTime<Utc>) -> Self {
+        self.project_start = Some(start);
+        self
+    }
+    
+    pub fn with_org(mut self, org_id: Uuid) -> Self {
+        self.org_id = Some(org_id);
+        self
+    }
+}
+
+/// Where a material's prices came from
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PriceProvenance {
+    /// Providers whose prices were kept; empty when nobody could price it
+    pub answered_by: Vec<String>,
+    /// Tier that answered; `None` for supplier quotes
+    pub tier: Option<ProviderTier>,
+    /// Providers asked earlier that failed, timed out or had no price
+    pub failed_over_from: Vec<String>,
 }
 
 /// Price response - the intelligence report
@@ -212,6 +266,9 @@ pub struct PriceResponse {
     pub unavailable: Vec<MaterialId>,
     pub warnings: Vec<String>,
     pub searched_at: DateTime<Utc>,
+    /// Provenance per material code, filled in by the engine
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub provenance: BTreeMap<String, PriceProvenance>,
 }
 
 impl PriceResponse {
@@ -221,6 +278,7 @@ impl PriceResponse {
             unavailable: Vec::new(),
             warnings: Vec::new(),
             searched_at: Utc::now(),
+            provenance: BTreeMap::new(),
         }
     }
     
@@ -242,6 +300,43 @@ impl PriceResponse {
         prices
     }
     
+    /// Convert every price into one currency so lookups across countries can
+    /// be compared directly
+    /// 
+    /// Each currency pair is rated once. Prices that can't be converted keep
+    /// their own currency and leave a warning.
+    pub async fn normalize_to(&mut self, currency: Currency, converter: &dyn CurrencyConverter) {
+        let mut rates: HashMap<Currency, Option<f64>> = HashMap::new();
+        
+        for price in &mut self.prices {
+            if price.currency == currency {
+                continue;
+            }
+            
+            let rate = match rates.entry(price.currency) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    let rate = match converter.get_rate(price.currency, currency).await {
+                        Ok(rate) => Some(rate),
+                        Err(e) => {
+                            self.warnings.push(format!(
+                                "Currency conversion from {} to {} failed: {}",
+                                price.currency.code(), currency.code(), e
+                            ));
+                            None
+                        }
+                    };
+                    *entry.insert(rate)
+                }
+            };
+            
+            if let Some(rate) = rate {
+                price.price *= rate;
+                price.currency = currency;
+            }
+        }
+    }
+    
     /// Get all stores that have all materials
     pub fn stores_with_all_materials(&self) -> Vec<String> {
         use std::collections::HashMap;
@@ -0,0 +1,379 @@
+// "A scout who gets spotted brings back nothing."
+
+use crate::pricing::errors::*;
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::resilience::Guard;
+use tokio::sync::{Mutex, RwLock};
+use tokio::time::Instant;
+
+/// Crawl etiquette for scraping providers
+#[derive(Debug, Clone)]
+pub struct PolitenessConfig {
+    /// Identifies us in robots.txt matching and request headers
+    pub user_agent: String,
+    /// Minimum spacing between requests to the same domain
+    pub min_interval: Duration,
+    /// Retries after a 429/503 before giving up
+    pub max_retries: u32,
+    /// First backoff delay; doubled on every retry
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Limit on reading a page body once the response has started
+    pub body_timeout: Duration,
+}
+
+impl Default for PolitenessConfig {
+    fn default() -> Self {
+        Self {
+            user_agent: "struktura-pricing/1.0".to_string(),
+            min_interval: Duration::from_secs(2),
+            max_retries: 3,
+            base_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            body_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl PolitenessConfig {
+    /// Delay before retry number `attempt` (0-based), honouring `Retry-After`
+    pub fn backoff(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let exponential = self.base_backoff.saturating_mul(2u32.saturating_pow(attempt));
+        retry_after.unwrap_or(exponential).min(self.max_backoff)
+    }
+}
+
+// ============================================================================
+// ROBOTS.TXT
+// ============================================================================
+
+/// The subset of robots.txt that applies to our user agent
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RobotsRules {
+    allow: Vec<String>,
+    disallow: Vec<String>,
+    pub crawl_delay: Option<Duration>,
+}
+
+impl RobotsRules {
+    /// Parse robots.txt, keeping the group for `user_agent` (or `*` when
+    /// no group names us)
+    pub fn parse(text: &str, user_agent: &str) -> Self {
+        let agent = user_agent.split('/').next().unwrap_or(user_agent).to_lowercase();
+
+        let mut specific = RobotsRules::default();
+        let mut wildcard = RobotsRules::default();
+        let mut found_specific = false;
+
+        // Agents named by the current group; a new group starts at the
+        // first User-agent line after any rule line
+        let mut group_agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let Some((field, value)) = line.split_once(':') else { continue };
+            let field = field.trim().to_lowercase();
+            let value = value.trim();
+
+            if field == "user-agent" {
+                if in_rules {
+                    group_agents.clear();
+                    in_rules = false;
+                }
+                group_agents.push(value.to_lowercase());
+                continue;
+            }
+            in_rules = true;
+
+            let mut targets: Vec<&mut RobotsRules> = Vec::new();
+            let names_us = group_agents.iter().any(|a| a != "*" && agent.contains(a.as_str()));
+            if names_us {
+                found_specific = true;
+                targets.push(&mut specific);
+            } else if group_agents.iter().any(|a| a == "*") {
+                targets.push(&mut wildcard);
+            }
+
+            for rules in targets {
+                match field.as_str() {
+                    "allow" if !value.is_empty() => rules.allow.push(value.to_string()),
+                    "disallow" if !value.is_empty() => rules.disallow.push(value.to_string()),
+                    "crawl-delay" => {
+                        rules.crawl_delay = value.parse::<f64>().ok()
+                            .filter(|d| d.is_finite() && *d >= 0.0)
+                            .map(Duration::from_secs_f64);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if found_specific { specific } else { wildcard }
+    }
+
+    /// Longest matching rule wins; ties go to Allow
+    pub fn is_allowed(&self, path: &str) -> bool {
+        let longest = |rules: &[String]| {
+            rules.iter().filter(|r| path.starts_with(r.as_str())).map(|r| r.len()).max()
+        };
+
+        match (longest(&self.allow), longest(&self.disallow)) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(a), Some(d)) => a >= d,
+        }
+    }
+}
+
+// ============================================================================
+// THROTTLED CLIENT
+// ============================================================================
+
+#[derive(Default)]
+struct DomainState {
+    robots: Option<RobotsRules>,
+    next_allowed: Option<Instant>,
+}
+
+/// HTTP client that respects robots.txt, spaces requests per domain and
+/// backs off when a site tells us to slow down
+///
+/// Each request goes through the provider's resilience guard, which bounds
+/// it in time and concurrency and stops calling a site that keeps failing.
+pub struct PoliteClient {
+    client: reqwest::Client,
+    config: PolitenessConfig,
+    guard: Arc<Guard>,
+    domains: Mutex<HashMap<String, DomainState>>,
+}
+
+impl PoliteClient {
+    pub fn new(config: PolitenessConfig, guard: Arc<Guard>) -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent(config.user_agent.clone())
+            .build()
+            .unwrap();
+
+        Self {
+            client,
+            config,
+            guard,
+            domains: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Fetch a page body politely
+    pub async fn get_text(&self, url: &str) -> PricingResult<String> {
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|e| PricingError::ConfigError(format!("Invalid URL '{}': {}", url, e)))?;
+        let domain = parsed.host_str().unwrap_or_default().to_string();
+        let origin = parsed.origin().ascii_serialization();
+
+        let rules = self.robots_for(&domain, &origin).await;
+        if !rules.is_allowed(parsed.path()) {
+            return Err(PricingError::DisallowedByRobots(url.to_string()));
+        }
+        let interval = rules.crawl_delay.unwrap_or_default().max(self.config.min_interval);
+
+        let mut attempt = 0;
+        loop {
+            self.wait_turn(&domain, interval).await;
+
+            let response = self.guard
+                .call(PricingError::is_retryable, || async {
+                    self.client
+                        .get(url)
+                        .send()
+                        .await
+                        .map_err(|e| PricingError::NetworkError(e.to_string()))
+                })
+                .await?;
+
+            let status = response.status();
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                || status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+            {
+                if attempt >= self.config.max_retries {
+                    return Err(PricingError::RateLimited(domain));
+                }
+                let retry_after = response.headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .map(Duration::from_secs);
+                tokio::time::sleep(self.config.backoff(attempt, retry_after)).await;
+                attempt += 1;
+                continue;
+            }
+
+            if !status.is_success() {
+                return Err(PricingError::ApiError(format!("{} returned {}", domain, status)));
+            }
+
+            // The body can stall after the headers arrived
+            return tokio::time::timeout(self.config.body_timeout, response.text())
+                .await
+                .map_err(|_| PricingError::NetworkError(format!("{} stalled sending the page", domain)))?
+                .map_err(|e| PricingError::NetworkError(e.to_string()));
+        }
+    }
+
+    /// Robots rules for a domain, fetched once; unreachable robots.txt means no rules
+    async fn robots_for(&self, domain: &str, origin: &str) -> RobotsRules {
+        if let Some(rules) = self.domains.lock().await.get(domain).and_then(|d| d.robots.clone()) {
+            return rules;
+        }
+
+        let robots = self.guard.call(|_| false, || self.client.get(format!("{}/robots.txt", origin)).send());
+        let rules = match robots.await {
+            Ok(resp) if resp.status().is_success() => resp
+                .text()
+                .await
+                .map(|text| RobotsRules::parse(&text, &self.config.user_agent))
+                .unwrap_or_default(),
+            _ => RobotsRules::default(),
+        };
+
+        self.domains.lock().await.entry(domain.to_string()).or_default().robots = Some(rules.clone());
+        rules
+    }
+
+    /// Reserve the next request slot for a domain and sleep until it opens
+    async fn wait_turn(&self, domain: &str, interval: Duration) {
+        let slot = {
+            let mut domains = self.domains.lock().await;
+            let state = domains.entry(domain.to_string()).or_default();
+            let now = Instant::now();
+            let slot = state.next_allowed.map_or(now, |next| next.max(now));
+            state.next_allowed = Some(slot + interval);
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+// ============================================================================
+// PERSISTENT CACHE
+// ============================================================================
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry<T> {
+    stored_at: DateTime<Utc>,
+    value: T,
+}
+
+/// Scrape results cache that survives restarts
+///
+/// Backed by a JSON file when a path is given, so a redeploy doesn't
+/// re-hit every search we already made.
+pub struct ScrapeCache<T> {
+    path: Option<PathBuf>,
+    ttl: chrono::Duration,
+    entries: RwLock<HashMap<String, CacheEntry<T>>>,
+}
+
+impl<T: Clone + Serialize + DeserializeOwned> ScrapeCache<T> {
+    pub fn in_memory(ttl: chrono::Duration) -> Self {
+        Self {
+            path: None,
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Load from `path`; a missing or unreadable file starts empty
+    pub fn persistent(path: impl Into<PathBuf>, ttl: chrono::Duration) -> Self {
+        let path = path.into();
+        let entries = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+
+        Self {
+            path: Some(path),
+            ttl,
+            entries: RwLock::new(entries),
+        }
+    }
+
+    pub async fn get(&self, key: &str) -> Option<T> {
+        let entries = self.entries.read().await;
+        entries
+            .get(key)
+            .filter(|e| Utc::now() - e.stored_at < self.ttl)
+            .map(|e| e.value.clone())
+    }
+
+    pub async fn insert(&self, key: String, value: T) {
+        let mut entries = self.entries.write().await;
+        let now = Utc::now();
+        entries.retain(|_, e| now - e.stored_at < self.ttl);
+        entries.insert(key, CacheEntry { stored_at: now, value });
+
+        if let Some(ref path) = self.path {
+            // Best effort - a failed write only costs a future cache miss
+            if let Ok(text) = serde_json::to_string(&*entries) {
+                let _ = tokio::fs::write(path, text).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_pricing_politeness {
+    use super::*;
+
+    const ROBOTS: &str = "\
+User-agent: *
+Disallow: /search
+Allow: /search/about
+Crawl-delay: 5
+
+User-agent: struktura-pricing
+Disallow: /private
+Crawl-delay: 1.5
+";
+
+    #[test]
+    fn test_robots_prefers_named_group() {
+        let ours = RobotsRules::parse(ROBOTS, "struktura-pricing/1.0");
+        assert!(ours.is_allowed("/search?q=lumber"));
+        assert!(!ours.is_allowed("/private/x"));
+        assert_eq!(ours.crawl_delay, Some(Duration::from_millis(1500)));
+
+        let anyone = RobotsRules::parse(ROBOTS, "somebot/2.0");
+        assert!(!anyone.is_allowed("/search?q=lumber"));
+        assert!(anyone.is_allowed("/search/about"));
+        assert_eq!(anyone.crawl_delay, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let config = PolitenessConfig::default();
+        assert_eq!(config.backoff(0, None), Duration::from_secs(1));
+        assert_eq!(config.backoff(3, None), Duration::from_secs(8));
+        assert_eq!(config.backoff(10, None), Duration::from_secs(30));
+        assert_eq!(config.backoff(0, Some(Duration::from_secs(12))), Duration::from_secs(12));
+    }
+
+    #[tokio::test]
+    async fn test_persistent_cache_round_trip() {
+        let path = std::env::temp_dir().join(format!("struktura-cache-{}.json", uuid::Uuid::new_v4()));
+        let cache: ScrapeCache<Vec<f64>> = ScrapeCache::persistent(&path, chrono::Duration::hours(1));
+        cache.insert("BR::cement".to_string(), vec![32.5]).await;
+
+        let reloaded: ScrapeCache<Vec<f64>> = ScrapeCache::persistent(&path, chrono::Duration::hours(1));
+        assert_eq!(reloaded.get("BR::cement").await, Some(vec![32.5]));
+
+        let expired: ScrapeCache<Vec<f64>> = ScrapeCache::persistent(&path, chrono::Duration::zero());
+        assert_eq!(expired.get("BR::cement").await, None);
+
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -1,22 +1,35 @@
 // "Why pay for intelligence when you can get it for free?" - Sun Tzu, probably
 
-use crate::pricing::{errors::*, models::*, traits::*};
+use crate::pricing::{
+    errors::*,
+    health::ProviderTier,
+    models::*,
+    politeness::{PoliteClient, PolitenessConfig, ScrapeCache},
+    traits::*,
+};
+use crate::resilience::{Guard, ResiliencePolicy};
 use async_trait::async_trait;
 use chrono::Utc;
 use regex::Regex;
 use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long scraped search results stay fresh
+const SCRAPE_CACHE_TTL_HOURS: i64 = 24;
 
 /// DuckDuckGo web reconnaissance provider
 /// 
 /// The people's intelligence agency. No API keys, no rate limits, no corporate surveillance.
-/// Just good old-fashioned web scraping with plausible deniability.
+/// Just good old-fashioned web scraping - politely, so our egress IP stays unblocked.
 pub struct DuckDuckGoProvider {
-    client: reqwest::Client,
-    cache: tokio::sync::RwLock<HashMap<String, Vec<StorePrice>>>,
+    client: PoliteClient,
+    cache: ScrapeCache<Vec<StorePrice>>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 struct StorePrice {
     store: StoreInfo,
     price: f64,
@@ -25,16 +38,22 @@ struct StorePrice {
 }
 
 impl DuckDuckGoProvider {
+    /// Default etiquette; results persist to `PRICING_CACHE_PATH` when set
     pub fn new() -> Self {
-        let client = reqwest::Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
-            
+        let cache_path = std::env::var("PRICING_CACHE_PATH").ok().map(PathBuf::from);
+        Self::with_config(PolitenessConfig::default(), cache_path)
+    }
+    
+    pub fn with_config(config: PolitenessConfig, cache_path: Option<PathBuf>) -> Self {
+        let ttl = chrono::Duration::hours(SCRAPE_CACHE_TTL_HOURS);
+        let cache = match cache_path {
+            Some(path) => ScrapeCache::persistent(path, ttl),
+            None => ScrapeCache::in_memory(ttl),
+        };
+        
         Self {
-            client,
-            cache: tokio::sync::RwLock::new(HashMap::new()),
+            client: PoliteClient::new(config, Guard::named("duckduckgo", ResiliencePolicy::scraper())),
+            cache,
         }
     }
     
@@ -55,11 +74,8 @@ impl DuckDuckGoProvider {
         );
         
         // Check intelligence archives first
-        {
-            let cache = self.cache.read().await;
-            if let Some(cached) = cache.get(&cache_key) {
-                return Ok(cached.clone());
-            }
+        if let Some(cached) = self.cache.get(&cache_key).await {
+            return Ok(cached);
         }
         
         // Deploy reconnaissance squad
@@ -68,25 +84,14 @@ impl DuckDuckGoProvider {
             urlencoding::encode(&query)
         );
         
-        let response = self.client
-            .get(&search_url)
-            .send()
-            .await
-            .map_err(|e| PricingError::NetworkError(e.to_string()))?;
-            
-        let html = response
-            .text()
-            .await
-            .map_err(|e| PricingError::NetworkError(e.to_string()))?;
+        // Throttled, robots-checked, and backed off on 429s
+        let html = self.client.get_text(&search_url).await?;
         
         // Parse the battlefield intelligence
         let stores = self.extract_store_info(&html, material, location).await?;
         
         // File report in archives
-        {
-            let mut cache = self.cache.write().await;
-            cache.insert(cache_key, stores.clone());
-        }
+        self.cache.insert(cache_key, stores.clone()).await;
         
         Ok(stores)
     }
@@ -126,54 +131,58 @@ impl DuckDuckGoProvider {
         material: &MaterialId,
         location: &Location,
     ) -> PricingResult<Vec<StorePrice>> {
-        let document = Html::parse_document(html);
-        let result_selector = Selector::parse(".result").unwrap();
-        let title_selector = Selector::parse(".result__title").unwrap();
-        let snippet_selector = Selector::parse(".result__snippet").unwrap();
-        let url_selector = Selector::parse(".result__url").unwrap();
-        
         let mut stores = Vec::new();
-        let price_regex = Regex::new(r"(?i)(?:R\$|USD|\$|€|£)\s*(\d+[,.]?\d*)").unwrap();
-        
-        for result in document.select(&result_selector).take(10) {
-            // Extract title (potential store name)
-            let title = result
-                .select(&title_selector)
-                .next()
-                .map(|e| e.text().collect::<String>())
-                .unwrap_or_default();
+        
+        // The parsed document isn't Send, so it must be gone before the fallback awaits
+        {
+            let document = Html::parse_document(html);
+            let result_selector = Selector::parse(".result").unwrap();
+            let title_selector = Selector::parse(".result__title").unwrap();
+            let snippet_selector = Selector::parse(".result__snippet").unwrap();
+            let url_selector = Selector::parse(".result__url").unwrap();
+        
+            for result in document.select(&result_selector).take(10) {
+                // Extract title (potential store name)
+                let title = result
+                    .select(&title_selector)
+                    .next()
+                    .map(|e| e.text().collect::<String>())
+                    .unwrap_or_default();
             
-            // Extract snippet (might contain price)
-            let snippet = result
-                .select(&snippet_selector)
-                .next()
-                .map(|e| e.text().collect::<String>())
-                .unwrap_or_default();
+                // Extract snippet (might contain price)
+                let snippet = result
+                    .select(&snippet_selector)
+                    .next()
+                    .map(|e| e.text().collect::<String>())
+                    .unwrap_or_default();
             
-            // Extract URL
-            let url = result
-                .select(&url_selector)
-                .next()
-                .map(|e| e.text().collect::<String>())
-                .unwrap_or_default();
+                // Extract URL
+                let url = result
+                    .select(&url_selector)
+                    .next()
+                    .map(|e| e.text().collect::<String>())
+                    .unwrap_or_default();
             
-            // Parse price from snippet if available
-            if let Some(price) = self.extract_price(&snippet, location) {
-                let store_info = StoreInfo {
-                    name: self.clean_store_name(&title),
-                    address: self.extract_address(&snippet, location),
-                    distance_km: None, // No GPS intel available
-                    phone: self.extract_phone(&snippet),
-                    website: if !url.is_empty() { Some(url) } else { None },
-                    maps_link: None,
-                };
+                // Parse price from snippet if available
+                if let Some(price) = self.extract_price(&snippet, location) {
+                    let store_info = StoreInfo {
+                        name: self.clean_store_name(&title),
+                        address: self.extract_address(&snippet, location),
+                        distance_km: None, // Resolved by the engine's geocoder
+                        latitude: None,
+                        longitude: None,
+                        phone: self.extract_phone(&snippet),
+                        website: if !url.is_empty() { Some(url) } else { None },
+                        maps_link: None,
+                    };
                 
-                stores.push(StorePrice {
-                    store: store_info,
-                    price: price.0,
-                    currency: price.1,
-                    material_code: material.code.clone(),
-                });
+                    stores.push(StorePrice {
+                        store: store_info,
+                        price: price.0,
+                        currency: price.1,
+                        material_code: material.code.clone(),
+                    });
+                }
             }
         }
         
@@ -295,7 +304,9 @@ impl DuckDuckGoProvider {
                         location.city.as_ref().unwrap_or(&"SP".to_string()),
                         location.region.as_ref().unwrap_or(&"Brazil".to_string())
                     ),
-                    distance_km: Some(10.0),
+                    distance_km: None, // Chain, not a specific branch
+                    latitude: None,
+                    longitude: None,
                     phone: None,
                     website: Some("https://www.leroymerlin.com.br".to_string()),
                     maps_link: None,
@@ -306,7 +317,9 @@ impl DuckDuckGoProvider {
                         location.city.as_ref().unwrap_or(&"SP".to_string()),
                         location.region.as_ref().unwrap_or(&"Brazil".to_string())
                     ),
-                    distance_km: Some(12.0),
+                    distance_km: None, // Chain, not a specific branch
+                    latitude: None,
+                    longitude: None,
                     phone: None,
                     website: Some("https://www.telhanorte.com.br".to_string()),
                     maps_link: None,
@@ -319,7 +332,9 @@ impl DuckDuckGoProvider {
                         location.city.as_ref().unwrap_or(&"USA".to_string()),
                         location.region.as_ref().unwrap_or(&"US".to_string())
                     ),
-                    distance_km: Some(5.0),
+                    distance_km: None, // Chain, not a specific branch
+                    latitude: None,
+                    longitude: None,
                     phone: None,
                     website: Some("https://www.homedepot.com".to_string()),
                     maps_link: None,
@@ -330,7 +345,9 @@ impl DuckDuckGoProvider {
                         location.city.as_ref().unwrap_or(&"USA".to_string()),
                         location.region.as_ref().unwrap_or(&"US".to_string())
                     ),
-                    distance_km: Some(7.0),
+                    distance_km: None, // Chain, not a specific branch
+                    latitude: None,
+                    longitude: None,
                     phone: None,
                     website: Some("https://www.lowes.com".to_string()),
                     maps_link: None,
@@ -402,6 +419,15 @@ impl PriceProvider for DuckDuckGoProvider {
         "duckduckgo"
     }
     
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::Scraper
+    }
+    
+    fn timeout(&self) -> Duration {
+        // Several searches per request, each throttled by the politeness policy
+        Duration::from_secs(30)
+    }
+    
     fn supports_location(&self, location: &Location) -> bool {
         // DuckDuckGo: The universal reconnaissance tool
         // Works anywhere there's an internet connection
@@ -414,15 +440,8 @@ impl PriceProvider for DuckDuckGoProvider {
         for material in &request.materials {
             match self.search_stores(material, &request.location).await {
                 Ok(stores) => {
+                    // Distance filtering happens in the engine once stores are geocoded
                     for store_price in stores {
-                        // Apply distance filter if specified
-                        if let (Some(max_dist), Some(store_dist)) = 
-                            (request.max_distance_km, store_price.store.distance_km) {
-                            if store_dist > max_dist {
-                                continue;
-                            }
-                        }
-                        
                         response.prices.push(PriceInfo {
                             material: material.clone(),
                             price: store_price.price,
@@ -456,14 +475,7 @@ impl PriceProvider for DuckDuckGoProvider {
     
     async fn health_check(&self) -> PricingResult<bool> {
         // Ping DuckDuckGo to verify operational status
-        match self.client
-            .get("https://duckduckgo.com")
-            .send()
-            .await 
-        {
-            Ok(resp) => Ok(resp.status().is_success()),
-            Err(_) => Ok(false),
-        }
+        Ok(self.client.get_text("https://duckduckgo.com").await.is_ok())
     }
 }
 
@@ -498,7 +510,9 @@ impl StaticProvider {
             store: StoreInfo {
                 name: "Leroy Merlin".to_string(),
                 address: "Av. das Nações, Campinas - SP".to_string(),
-                distance_km: Some(5.2),
+                distance_km: None,
+                latitude: Some(-22.8936),
+                longitude: Some(-47.0306),
                 phone: Some("(19) 3271-3000".to_string()),
                 website: Some("https://www.leroymerlin.com.br".to_string()),
                 maps_link: Some("https://maps.google.com/?q=Leroy+Merlin+Campinas".to_string()),
@@ -516,7 +530,9 @@ impl StaticProvider {
             store: StoreInfo {
                 name: "Telhanorte".to_string(),
                 address: "Av. Brasil, Campinas - SP".to_string(),
-                distance_km: Some(7.8),
+                distance_km: None,
+                latitude: Some(-22.8890),
+                longitude: Some(-47.0750),
                 phone: Some("(19) 3272-4000".to_string()),
                 website: Some("https://www.telhanorte.com.br".to_string()),
                 maps_link: Some("https://maps.google.com/?q=Telhanorte+Campinas".to_string()),
@@ -539,7 +555,9 @@ impl StaticProvider {
             store: StoreInfo {
                 name: "The Home Depot".to_string(),
                 address: "123 Main St, Anytown, CA".to_string(),
-                distance_km: Some(3.2),
+                distance_km: None,
+                latitude: None,
+                longitude: None,
                 phone: Some("(555) 123-4567".to_string()),
                 website: Some("https://www.homedepot.com".to_string()),
                 maps_link: Some("https://maps.google.com/?q=Home+Depot".to_string()),
@@ -575,6 +593,14 @@ impl PriceProvider for StaticProvider {
         "static"
     }
     
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::Static
+    }
+    
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(2)
+    }
+    
     fn supports_location(&self, location: &Location) -> bool {
         self.stores.contains_key(&location.country_code)
     }
@@ -597,13 +623,6 @@ impl PriceProvider for StaticProvider {
             let mut found = false;
             
             for store_mat in country_stores {
-                if let (Some(max_dist), Some(store_dist)) = 
-                    (request.max_distance_km, store_mat.store.distance_km) {
-                    if store_dist > max_dist {
-                        continue;
-                    }
-                }
-                
                 if let Some(&price) = store_mat.prices.get(&material.code) {
                     response.prices.push(PriceInfo {
                         material: material.clone(),
@@ -0,0 +1,483 @@
+use crate::pricing::{errors::*, models::*};
+use crate::state::AppState;
+use crate::tenancy::TenantScope;
+use async_trait::async_trait;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get},
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use sqlx::types::Json as SqlJson;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// A price a supplier has committed to for a limited window
+///
+/// Quotes beat anything scraped or static: they are what the contractor
+/// will actually pay, as long as the window is open.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierQuote {
+    #[serde(default = "Uuid::new_v4")]
+    pub id: Uuid,
+    pub material: MaterialId,
+    pub supplier: StoreInfo,
+    pub price: f64,
+    pub currency: Currency,
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: DateTime<Utc>,
+    pub notes: Option<String>,
+}
+
+impl SupplierQuote {
+    pub fn is_valid_at(&self, at: DateTime<Utc>) -> bool {
+        self.valid_from <= at && at <= self.valid_until
+    }
+
+    fn validate(&self) -> PricingResult<()> {
+        if !self.price.is_finite() || self.price < 0.0 {
+            return Err(PricingError::ConfigError("Quote price must be a non-negative number".to_string()));
+        }
+        if self.valid_until <= self.valid_from {
+            return Err(PricingError::ConfigError("Quote validity window is empty".to_string()));
+        }
+        Ok(())
+    }
+
+    fn to_price_info(&self) -> PriceInfo {
+        PriceInfo {
+            material: self.material.clone(),
+            price: self.price,
+            currency: self.currency,
+            store: self.supplier.clone(),
+            in_stock: true,
+            last_updated: Utc::now(),
+            notes: Some(format!(
+                "Supplier quote valid until {}",
+                self.valid_until.format("%Y-%m-%d")
+            )),
+        }
+    }
+}
+
+/// Storage for supplier quotes, kept per organization
+#[async_trait]
+pub trait QuoteStore: Send + Sync {
+    async fn add(&self, org_id: Uuid, created_by: Option<Uuid>, quote: &SupplierQuote) -> PricingResult<()>;
+
+    /// Whether a quote of this organization was removed
+    async fn remove(&self, org_id: Uuid, id: Uuid) -> PricingResult<bool>;
+
+    async fn list(&self, org_id: Uuid, material_code: Option<&str>) -> PricingResult<Vec<SupplierQuote>>;
+
+    /// Quotes for a material that are open at `at`
+    async fn valid_for(&self, org_id: Uuid, material_code: &str, at: DateTime<Utc>) -> PricingResult<Vec<SupplierQuote>>;
+}
+
+/// Process-local quotes - for tests and deployments without a database
+#[derive(Default)]
+pub struct InMemoryQuoteStore {
+    quotes: RwLock<Vec<(Uuid, SupplierQuote)>>,
+}
+
+impl InMemoryQuoteStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl QuoteStore for InMemoryQuoteStore {
+    async fn add(&self, org_id: Uuid, _created_by: Option<Uuid>, quote: &SupplierQuote) -> PricingResult<()> {
+        self.quotes.write().await.push((org_id, quote.clone()));
+        Ok(())
+    }
+
+    async fn remove(&self, org_id: Uuid, id: Uuid) -> PricingResult<bool> {
+        let mut quotes = self.quotes.write().await;
+        let before = quotes.len();
+        quotes.retain(|(org, q)| !(*org == org_id && q.id == id));
+        Ok(quotes.len() != before)
+    }
+
+    async fn list(&self, org_id: Uuid, material_code: Option<&str>) -> PricingResult<Vec<SupplierQuote>> {
+        let quotes = self.quotes.read().await;
+        Ok(quotes
+            .iter()
+            .filter(|(org, q)| *org == org_id && material_code.is_none_or(|code| q.material.code == code))
+            .map(|(_, q)| q.clone())
+            .collect())
+    }
+
+    async fn valid_for(&self, org_id: Uuid, material_code: &str, at: DateTime<Utc>) -> PricingResult<Vec<SupplierQuote>> {
+        let quotes = self.quotes.read().await;
+        Ok(quotes
+            .iter()
+            .filter(|(org, q)| *org == org_id && q.material.code == material_code && q.is_valid_at(at))
+            .map(|(_, q)| q.clone())
+            .collect())
+    }
+}
+
+/// Postgres-backed quotes (see migrations/025_supplier_quotes.sql)
+pub struct PgQuoteStore {
+    pool: PgPool,
+}
+
+impl PgQuoteStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn to_offset(at: DateTime<Utc>) -> PricingResult<time::OffsetDateTime> {
+    time::OffsetDateTime::from_unix_timestamp(at.timestamp())
+        .map(|t| t.replace_nanosecond(at.timestamp_subsec_nanos()).unwrap_or(t))
+        .map_err(|e| PricingError::ConfigError(e.to_string()))
+}
+
+fn from_offset(at: time::OffsetDateTime) -> DateTime<Utc> {
+    DateTime::from_timestamp(at.unix_timestamp(), at.nanosecond()).unwrap_or_default()
+}
+
+fn db_error(e: sqlx::Error) -> PricingError {
+    PricingError::ApiError(e.to_string())
+}
+
+#[derive(sqlx::FromRow)]
+struct QuoteRow {
+    id: Uuid,
+    material: SqlJson<MaterialId>,
+    supplier: SqlJson<StoreInfo>,
+    price: f64,
+    currency: String,
+    valid_from: time::OffsetDateTime,
+    valid_until: time::OffsetDateTime,
+    notes: Option<String>,
+}
+
+impl QuoteRow {
+    fn into_quote(self) -> Option<SupplierQuote> {
+        Some(SupplierQuote {
+            id: self.id,
+            material: self.material.0,
+            supplier: self.supplier.0,
+            price: self.price,
+            currency: Currency::from_code(&self.currency)?,
+            valid_from: from_offset(self.valid_from),
+            valid_until: from_offset(self.valid_until),
+            notes: self.notes,
+        })
+    }
+}
+
+const QUOTE_COLUMNS: &str = "id, material, supplier, price, currency, valid_from, valid_until, notes";
+
+#[async_trait]
+impl QuoteStore for PgQuoteStore {
+    async fn add(&self, org_id: Uuid, created_by: Option<Uuid>, quote: &SupplierQuote) -> PricingResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO supplier_quotes
+                (id, org_id, material_code, material, supplier, price, currency, valid_from, valid_until, notes, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+        )
+        .bind(quote.id)
+        .bind(org_id)
+        .bind(&quote.material.code)
+        .bind(SqlJson(&quote.material))
+        .bind(SqlJson(&quote.supplier))
+        .bind(quote.price)
+        .bind(quote.currency.code())
+        .bind(to_offset(quote.valid_from)?)
+        .bind(to_offset(quote.valid_until)?)
+        .bind(&quote.notes)
+        .bind(created_by)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn remove(&self, org_id: Uuid, id: Uuid) -> PricingResult<bool> {
+        let result = sqlx::query("DELETE FROM supplier_quotes WHERE id = $1 AND org_id = $2")
+            .bind(id)
+            .bind(org_id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list(&self, org_id: Uuid, material_code: Option<&str>) -> PricingResult<Vec<SupplierQuote>> {
+        let rows = sqlx::query_as::<_, QuoteRow>(&format!(
+            "SELECT {QUOTE_COLUMNS} FROM supplier_quotes \
+             WHERE org_id = $1 AND ($2::TEXT IS NULL OR material_code = $2) \
+             ORDER BY valid_until DESC"
+        ))
+        .bind(org_id)
+        .bind(material_code)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(rows.into_iter().filter_map(QuoteRow::into_quote).collect())
+    }
+
+    async fn valid_for(&self, org_id: Uuid, material_code: &str, at: DateTime<Utc>) -> PricingResult<Vec<SupplierQuote>> {
+        let rows = sqlx::query_as::<_, QuoteRow>(&format!(
+            "SELECT {QUOTE_COLUMNS} FROM supplier_quotes \
+             WHERE org_id = $1 AND material_code = $2 AND valid_from <= $3 AND valid_until >= $3"
+        ))
+        .bind(org_id)
+        .bind(material_code)
+        .bind(to_offset(at)?)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(rows.into_iter().filter_map(QuoteRow::into_quote).collect())
+    }
+}
+
+/// Supplier quotes consulted by the pricing engine
+///
+/// Each organization only ever sees and is priced by its own quotes;
+/// requests without an organization get none.
+pub struct QuoteBook {
+    store: Arc<dyn QuoteStore>,
+}
+
+impl Default for QuoteBook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QuoteBook {
+    /// Book kept in process memory
+    pub fn new() -> Self {
+        Self::with_store(Arc::new(InMemoryQuoteStore::new()))
+    }
+
+    pub fn with_store(store: Arc<dyn QuoteStore>) -> Self {
+        Self { store }
+    }
+
+    pub async fn add(&self, org_id: Uuid, created_by: Option<Uuid>, quote: SupplierQuote) -> PricingResult<SupplierQuote> {
+        quote.validate()?;
+        self.store.add(org_id, created_by, &quote).await?;
+        Ok(quote)
+    }
+
+    pub async fn remove(&self, org_id: Uuid, id: Uuid) -> PricingResult<bool> {
+        self.store.remove(org_id, id).await
+    }
+
+    pub async fn list(&self, org_id: Uuid, material_code: Option<&str>) -> PricingResult<Vec<SupplierQuote>> {
+        self.store.list(org_id, material_code).await
+    }
+
+    /// Replace scraped/static prices with the requesting organization's
+    /// open quotes where one exists, returning the codes of the quoted
+    /// materials
+    ///
+    /// Warns when a quote will lapse before the project starts, since the
+    /// price it locks in won't be available when materials are ordered.
+    pub async fn apply(&self, request: &PriceRequest, response: &mut PriceResponse) -> Vec<String> {
+        let now = Utc::now();
+        let mut quoted = Vec::new();
+        let Some(org_id) = request.org_id else { return quoted };
+
+        for material in &request.materials {
+            let quotes = match self.store.valid_for(org_id, &material.code, now).await {
+                Ok(quotes) => quotes,
+                Err(e) => {
+                    response.warnings.push(format!("Supplier quotes unavailable: {}", e));
+                    return quoted;
+                }
+            };
+            if quotes.is_empty() {
+                continue;
+            }
+
+            response.prices.retain(|p| p.material.code != material.code);
+            response.unavailable.retain(|m| m.code != material.code);
+            quoted.push(material.code.clone());
+
+            for quote in &quotes {
+                if let Some(start) = request.project_start
+                    && quote.valid_until < start
+                {
+                    response.warnings.push(format!(
+                        "Quote from {} for {} expires {} before project start {}",
+                        quote.supplier.name,
+                        material.description,
+                        quote.valid_until.format("%Y-%m-%d"),
+                        start.format("%Y-%m-%d"),
+                    ));
+                }
+                response.prices.push(quote.to_price_info());
+            }
+        }
+
+        quoted
+    }
+}
+
+// ============================================================================
+// API
+// ============================================================================
+
+/// API router for the caller's organization's supplier quotes
+///
+/// Mount behind authentication: every handler is scoped by [`TenantScope`].
+pub fn create_quotes_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/quotes", get(list_quotes).post(create_quote))
+        .route("/quotes/{id}", delete(delete_quote))
+}
+
+#[derive(Debug, Deserialize)]
+struct QuoteQuery {
+    material: Option<String>,
+}
+
+async fn list_quotes(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Query(query): Query<QuoteQuery>,
+) -> Result<Json<Vec<SupplierQuote>>, QuoteError> {
+    let quotes = state.pricing.quotes().list(scope.org_id, query.material.as_deref()).await?;
+    Ok(Json(quotes))
+}
+
+async fn create_quote(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(quote): Json<SupplierQuote>,
+) -> Result<(StatusCode, Json<SupplierQuote>), QuoteError> {
+    let quote = state.pricing.quotes().add(scope.org_id, Some(scope.user_id), quote).await?;
+    Ok((StatusCode::CREATED, Json(quote)))
+}
+
+async fn delete_quote(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, QuoteError> {
+    if state.pricing.quotes().remove(scope.org_id, id).await? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Ok(StatusCode::NOT_FOUND)
+    }
+}
+
+struct QuoteError(PricingError);
+
+impl From<PricingError> for QuoteError {
+    fn from(err: PricingError) -> Self {
+        QuoteError(err)
+    }
+}
+
+impl IntoResponse for QuoteError {
+    fn into_response(self) -> Response {
+        let status = match self.0 {
+            PricingError::ConfigError(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let body = Json(serde_json::json!({ "error": self.0.to_string() }));
+        (status, body).into_response()
+    }
+}
+
+#[cfg(test)]
+mod test_pricing_quotes {
+    use super::*;
+    use chrono::Duration;
+
+    fn lumber() -> MaterialId {
+        MaterialId::new(MaterialCategory::Lumber, "lumber_2x4_8ft", "unit", "2x4 Lumber 8ft")
+    }
+
+    fn quote(valid_for_days: i64) -> SupplierQuote {
+        SupplierQuote {
+            id: Uuid::new_v4(),
+            material: lumber(),
+            supplier: StoreInfo {
+                name: "Valley Lumber".to_string(),
+                address: "12 Mill Rd".to_string(),
+                distance_km: None,
+                latitude: None,
+                longitude: None,
+                phone: None,
+                website: None,
+                maps_link: None,
+            },
+            price: 6.10,
+            currency: Currency::USD,
+            valid_from: Utc::now() - Duration::days(1),
+            valid_until: Utc::now() + Duration::days(valid_for_days),
+            notes: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_valid_quote_replaces_scraped_price() {
+        let book = QuoteBook::new();
+        let org = Uuid::new_v4();
+        book.add(org, None, quote(30)).await.unwrap();
+
+        let request = PriceRequest::new(Location::new("US"))
+            .add_material(lumber())
+            .with_project_start(Utc::now() + Duration::days(60))
+            .with_org(org);
+
+        let mut response = PriceResponse::new();
+        response.prices.push(quote(30).to_price_info());
+        response.prices[0].price = 8.97;
+        response.prices[0].store.name = "The Home Depot".to_string();
+
+        book.apply(&request, &mut response).await;
+
+        assert_eq!(response.prices.len(), 1);
+        assert_eq!(response.prices[0].price, 6.10);
+        assert_eq!(response.warnings.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_quotes_stay_within_their_organization() {
+        let book = QuoteBook::new();
+        let (ours, theirs) = (Uuid::new_v4(), Uuid::new_v4());
+        let added = book.add(ours, None, quote(30)).await.unwrap();
+
+        assert_eq!(book.list(ours, None).await.unwrap().len(), 1);
+        assert!(book.list(theirs, None).await.unwrap().is_empty());
+        assert!(!book.remove(theirs, added.id).await.unwrap());
+
+        // Neither another organization's request nor an anonymous one is quoted
+        for request in [
+            PriceRequest::new(Location::new("US")).add_material(lumber()).with_org(theirs),
+            PriceRequest::new(Location::new("US")).add_material(lumber()),
+        ] {
+            let mut response = PriceResponse::new();
+            assert!(book.apply(&request, &mut response).await.is_empty());
+            assert!(response.prices.is_empty());
+        }
+
+        assert!(book.remove(ours, added.id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_empty_window() {
+        let book = QuoteBook::new();
+        let mut bad = quote(30);
+        bad.valid_until = bad.valid_from;
+        assert!(book.add(Uuid::new_v4(), None, bad).await.is_err());
+    }
+}
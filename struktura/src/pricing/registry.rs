@@ -1,14 +1,45 @@
-use crate::pricing::{errors::*, models::*, traits::*};
+use crate::pricing::{
+    errors::*,
+    geocoding::{self, Geocoder},
+    health::{FailoverPolicy, ProviderDashboard, ProviderHealthMonitor},
+    history::{PgPriceHistory, PricePoint, PriceHistoryStore},
+    models::*,
+    providers::{DuckDuckGoProvider, StaticProvider},
+    quotes::{PgQuoteStore, QuoteBook},
+    traits::*,
+};
+use sqlx::postgres::PgPool;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+/// Overrides for a provider's own priority and timeout at registration
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProviderOptions {
+    pub priority: Option<i32>,
+    pub timeout: Option<Duration>,
+}
+
+/// A provider as the engine runs it
+#[derive(Clone)]
+struct RegisteredProvider {
+    provider: Arc<dyn PriceProvider>,
+    priority: i32,
+    timeout: Duration,
+}
+
 /// Central coordination for price providers - Now with 100% more DuckDuckGo
 /// 
 /// Mission Control has been upgraded. No more API keys to lose, no more rate limits to hit.
 /// Just good old-fashioned web reconnaissance with a side of plausible deniability.
 pub struct PricingEngine {
-    providers: Arc<RwLock<Vec<Arc<dyn PriceProvider>>>>,
+    providers: Arc<RwLock<Vec<RegisteredProvider>>>,
     converter: Option<Arc<dyn CurrencyConverter>>,
+    quotes: Arc<QuoteBook>,
+    geocoder: Option<Arc<dyn Geocoder>>,
+    history: Option<Arc<dyn PriceHistoryStore>>,
+    failover: FailoverPolicy,
+    monitor: ProviderHealthMonitor,
 }
 
 impl PricingEngine {
@@ -16,12 +47,30 @@ impl PricingEngine {
         Self {
             providers: Arc::new(RwLock::new(Vec::new())),
             converter: None,
+            quotes: Arc::new(QuoteBook::new()),
+            geocoder: None,
+            history: None,
+            failover: FailoverPolicy::default(),
+            monitor: ProviderHealthMonitor::new(),
         }
     }
     
     pub async fn register_provider(&self, provider: Arc<dyn PriceProvider>) {
+        self.register_provider_with(provider, ProviderOptions::default()).await;
+    }
+    
+    /// Register a provider, overriding its priority and/or timeout
+    pub async fn register_provider_with(&self, provider: Arc<dyn PriceProvider>, options: ProviderOptions) {
+        let registered = RegisteredProvider {
+            priority: options.priority.unwrap_or_else(|| provider.priority()),
+            timeout: options.timeout.unwrap_or_else(|| provider.timeout()),
+            provider,
+        };
+        
         let mut providers = self.providers.write().await;
-        providers.push(provider);
+        providers.push(registered);
+        // Stable, so equal priorities keep registration order
+        providers.sort_by_key(|p| p.priority);
     }
     
     pub fn with_converter(mut self, converter: Arc<dyn CurrencyConverter>) -> Self {
@@ -29,15 +78,69 @@ impl PricingEngine {
         self
     }
     
-    async fn find_providers(&self, location: &Location) -> Vec<Arc<dyn PriceProvider>> {
+    pub fn with_geocoder(mut self, geocoder: Arc<dyn Geocoder>) -> Self {
+        self.geocoder = Some(geocoder);
+        self
+    }
+    
+    /// Address lookup, when one is configured
+    pub fn geocoder(&self) -> Option<&dyn Geocoder> {
+        self.geocoder.as_deref()
+    }
+    
+    /// Record every fetched price for trend analysis
+    pub fn with_history(mut self, history: Arc<dyn PriceHistoryStore>) -> Self {
+        self.history = Some(history);
+        self
+    }
+    
+    /// Where fetched prices are recorded, when anywhere
+    pub fn history(&self) -> Option<Arc<dyn PriceHistoryStore>> {
+        self.history.clone()
+    }
+    
+    /// Order in which provider tiers are tried
+    pub fn with_failover(mut self, failover: FailoverPolicy) -> Self {
+        self.failover = failover;
+        self
+    }
+    
+    /// Keep supplier quotes in `book` instead of process memory
+    pub fn with_quotes(mut self, book: QuoteBook) -> Self {
+        self.quotes = Arc::new(book);
+        self
+    }
+    
+    /// Supplier quotes preferred over provider prices while valid
+    pub fn quotes(&self) -> Arc<QuoteBook> {
+        self.quotes.clone()
+    }
+    
+    async fn find_providers(&self, location: &Location) -> Vec<RegisteredProvider> {
         let providers = self.providers.read().await;
         providers
             .iter()
-            .filter(|p| p.supports_location(location))
+            .filter(|p| p.provider.supports_location(location))
             .cloned()
             .collect()
     }
     
+    /// Ask one provider, giving up after its timeout
+    async fn ask(&self, registered: &RegisteredProvider, request: &PriceRequest) -> PricingResult<PriceResponse> {
+        let provider = &registered.provider;
+        let started = Instant::now();
+        let result = match tokio::time::timeout(registered.timeout, provider.fetch_prices(request)).await {
+            Ok(result) => result,
+            Err(_) => Err(PricingError::ProviderFailed(
+                provider.name().to_string(),
+                format!("no answer within {}ms", registered.timeout.as_millis()),
+            )),
+        };
+        let error = result.as_ref().err().map(|e| e.to_string());
+        self.monitor.record(provider.name(), started.elapsed(), error).await;
+        result
+    }
+    
     pub async fn fetch_prices(&self, request: &PriceRequest) -> PricingResult<PriceResponse> {
         let providers = self.find_providers(&request.location).await;
         
@@ -47,68 +150,150 @@ impl PricingEngine {
             ));
         }
         
-        let mut combined = PriceResponse::new();
-        
-        // Deploy all reconnaissance units in parallel
-        let mut tasks = Vec::new();
-        for provider in providers {
-            let req = request.clone();
-            tasks.push(async move {
-                (provider.name().to_string(), provider.fetch_prices(&req).await)
-            });
+        if let Some((lat, lon)) = request.location.coordinates() {
+            geocoding::validate_coordinates(lat, lon)?;
         }
         
-        let results = futures::future::join_all(tasks).await;
+        let mut combined = PriceResponse::new();
+        
+        let mut pending = request.clone();
         
-        // Compile intelligence reports
-        for (provider_name, result) in results {
-            match result {
-                Ok(response) => {
-                    combined.prices.extend(response.prices);
-                    combined.unavailable.extend(response.unavailable);
-                    combined.warnings.extend(response.warnings);
+        // Walk the failover chain; each step only sees what is still unpriced.
+        // Within a tier, providers go in priority order and share a step when
+        // their priorities are equal.
+        for tier in &self.failover.chain {
+            let in_tier: Vec<&RegisteredProvider> = providers.iter().filter(|p| p.provider.tier() == *tier).collect();
+            
+            for step in in_tier.chunk_by(|a, b| a.priority == b.priority) {
+                if pending.materials.is_empty() {
+                    break;
+                }
+                
+                // Deploy this step's reconnaissance units in parallel
+                let tasks = step.iter().map(|registered| {
+                    let req = &pending;
+                    async move { (registered.provider.name().to_string(), self.ask(registered, req).await) }
+                });
+                
+                let results = futures::future::join_all(tasks).await;
+                
+                // Compile intelligence reports
+                for (provider_name, result) in results {
+                    match result {
+                        Ok(response) => {
+                            for price in &response.prices {
+                                let provenance = combined.provenance.entry(price.material.code.clone()).or_default();
+                                if !provenance.answered_by.contains(&provider_name) {
+                                    provenance.answered_by.push(provider_name.clone());
+                                    provenance.tier = Some(*tier);
+                                }
+                            }
+                            combined.prices.extend(response.prices);
+                            combined.unavailable.extend(response.unavailable);
+                            combined.warnings.extend(response.warnings);
+                        }
+                        Err(e) => {
+                            combined.warnings.push(format!(
+                                "Provider '{}' encountered difficulties: {}", 
+                                provider_name, e
+                            ));
+                        }
+                    }
                 }
-                Err(e) => {
-                    combined.warnings.push(format!(
-                        "Provider '{}' encountered difficulties: {}", 
-                        provider_name, e
-                    ));
+                
+                pending.materials.retain(|m| !combined.prices.iter().any(|p| p.material.code == m.code));
+                
+                // Whoever was asked and came back empty-handed is failed over from
+                for material in &pending.materials {
+                    let provenance = combined.provenance.entry(material.code.clone()).or_default();
+                    provenance.failed_over_from.extend(step.iter().map(|p| p.provider.name().to_string()));
                 }
             }
         }
         
+        // Only what no tier could price counts as unavailable
+        combined.unavailable.retain(|m| pending.materials.iter().any(|p| p.code == m.code));
+        
         // Eliminate duplicate intelligence
         combined.unavailable.sort_by(|a, b| a.code.cmp(&b.code));
         combined.unavailable.dedup_by(|a, b| a.code == b.code);
         
-        // Currency conversion operations
-        if let Some(target_currency) = request.preferred_currency {
-            if let Some(ref converter) = self.converter {
-                for price in &mut combined.prices {
-                    if price.currency != target_currency {
-                        match converter.convert(price.price, price.currency, target_currency).await {
-                            Ok(converted) => {
-                                price.price = converted;
-                                price.currency = target_currency;
-                            }
-                            Err(e) => {
-                                combined.warnings.push(format!(
-                                    "Currency conversion failed for {}: {}",
-                                    price.material.code, e
-                                ));
-                            }
-                        }
-                    }
-                }
+        // Negotiated quotes outrank anything scouted
+        for code in self.quotes.apply(request, &mut combined).await {
+            let provenance = combined.provenance.entry(code).or_default();
+            provenance.answered_by = vec!["supplier_quote".to_string()];
+            provenance.tier = None;
+        }
+        
+        // Measure real distances, then enforce the radius
+        self.apply_distance_filter(request, &mut combined).await;
+        
+        // Log observations in their original currency
+        if let Some(ref history) = self.history {
+            let points: Vec<PricePoint> = combined.prices
+                .iter()
+                .map(|p| PricePoint::from_price(p, &request.location))
+                .collect();
+            if let Err(e) = history.record(&points).await {
+                combined.warnings.push(format!("Price history not recorded: {}", e));
             }
         }
         
+        // Currency conversion operations
+        if let (Some(target_currency), Some(converter)) = (request.preferred_currency, &self.converter) {
+            combined.normalize_to(target_currency, converter.as_ref()).await;
+        }
+        
         Ok(combined)
     }
     
+    async fn apply_distance_filter(&self, request: &PriceRequest, response: &mut PriceResponse) {
+        let origin = match &self.geocoder {
+            Some(geocoder) => geocoder.locate(&request.location).await.ok().flatten(),
+            None => request.location.coordinates(),
+        };
+        
+        let Some(origin) = origin else {
+            if request.max_distance_km.is_some() {
+                response.warnings.push(
+                    "Job site location could not be placed on the map; distance filter not applied".to_string()
+                );
+            }
+            return;
+        };
+        
+        geocoding::resolve_distances(origin, self.geocoder.as_deref(), &mut response.prices).await;
+        
+        let Some(max_km) = request.max_distance_km else { return };
+        
+        let unplaced = response.prices.iter().filter(|p| p.store.distance_km.is_none()).count();
+        response.prices.retain(|p| p.store.distance_km.is_none_or(|d| d <= max_km));
+        
+        if unplaced > 0 {
+            response.warnings.push(format!(
+                "{} price(s) from stores without a known location were kept despite the {}km radius",
+                unplaced, max_km
+            ));
+        }
+    }
+    
+    /// Health, success rate and latency for every registered provider
+    pub async fn dashboard(&self) -> ProviderDashboard {
+        let providers: Vec<Arc<dyn PriceProvider>> = self.providers
+            .read()
+            .await
+            .iter()
+            .map(|p| p.provider.clone())
+            .collect();
+        ProviderDashboard {
+            failover: self.failover.clone(),
+            providers: self.monitor.report(&providers).await,
+        }
+    }
+    
     pub async fn list_providers(&self) -> Vec<String> {
         let providers = self.providers.read().await;
-        providers.iter().map(|p| p.name().to_string()).collect()
+        providers.iter().map(|p| p.provider.name().to_string()).collect()
     }
 }
 
@@ -120,8 +305,12 @@ impl Default for PricingEngine {
 
 /// Initialize pricing engine with DuckDuckGo and static fallback
 /// 
+/// With a database, supplier quotes are persisted per organization and
+/// every fetched price is recorded for trends; without one (sandbox, tests)
+/// quotes live in process memory and no history is kept.
+/// 
 /// "The new standard operating procedure: Free, fast, and untraceable."
-pub async fn init_pricing_engine() -> PricingResult<PricingEngine> {
+pub async fn init_pricing_engine(pool: Option<PgPool>) -> PricingResult<PricingEngine> {
     let engine = PricingEngine::new();
     
     // Primary reconnaissance: DuckDuckGo
@@ -134,9 +323,124 @@ pub async fn init_pricing_engine() -> PricingResult<PricingEngine> {
     let static_provider = Arc::new(StaticProvider::new());
     engine.register_provider(static_provider).await;
     
-    // Currency converter for cross-border operations
-    let converter = Arc::new(crate::pricing::converter::SimpleCurrencyConverter::new());
+    // Live exchange rates for cross-border operations, static rates as backup
+    let converter = Arc::new(crate::pricing::converter::LiveCurrencyConverter::new());
     let engine = engine.with_converter(converter);
     
+    // Structured APIs first, then scraping, then bundled data
+    let engine = engine.with_failover(crate::pricing::health::FailoverPolicy::from_env());
+    
+    // Geocoding for real store distances
+    let engine = engine.with_geocoder(Arc::new(crate::pricing::geocoding::NominatimGeocoder::new()));
+    
+    // Negotiated quotes survive restarts, and observed prices feed the
+    // trend analysis, when there is somewhere to keep them
+    let engine = match pool {
+        Some(pool) => engine
+            .with_quotes(QuoteBook::with_store(Arc::new(PgQuoteStore::new(pool.clone()))))
+            .with_history(Arc::new(PgPriceHistory::new(pool))),
+        None => engine,
+    };
+    
     Ok(engine)
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod test_pricing_registry {
+    use super::*;
+    use crate::pricing::health::ProviderTier;
+    use async_trait::async_trait;
+    
+    /// Prices every material after a delay
+    struct DelayedProvider {
+        name: &'static str,
+        tier: ProviderTier,
+        delay: Duration,
+    }
+    
+    #[async_trait]
+    impl PriceProvider for DelayedProvider {
+        fn name(&self) -> &str {
+            self.name
+        }
+        
+        fn tier(&self) -> ProviderTier {
+            self.tier
+        }
+        
+        fn supports_location(&self, _location: &Location) -> bool {
+            true
+        }
+        
+        async fn fetch_prices(&self, request: &PriceRequest) -> PricingResult<PriceResponse> {
+            tokio::time::sleep(self.delay).await;
+            let mut response = PriceResponse::new();
+            for material in &request.materials {
+                response.prices.push(PriceInfo {
+                    material: material.clone(),
+                    price: 10.0,
+                    currency: Currency::BRL,
+                    store: StoreInfo {
+                        name: self.name.to_string(),
+                        address: String::new(),
+                        distance_km: None,
+                        latitude: None,
+                        longitude: None,
+                        phone: None,
+                        website: None,
+                        maps_link: None,
+                    },
+                    in_stock: true,
+                    last_updated: chrono::Utc::now(),
+                    notes: None,
+                });
+            }
+            Ok(response)
+        }
+    }
+    
+    fn provider(name: &'static str, tier: ProviderTier, delay_ms: u64) -> Arc<dyn PriceProvider> {
+        Arc::new(DelayedProvider { name, tier, delay: Duration::from_millis(delay_ms) })
+    }
+    
+    fn sand_request() -> PriceRequest {
+        PriceRequest::new(Location::new("BR"))
+            .add_material(MaterialId::new(MaterialCategory::Sand, "sand", "m3", "Sand"))
+    }
+    
+    #[tokio::test]
+    async fn test_timed_out_provider_fails_over() {
+        let engine = PricingEngine::new();
+        engine
+            .register_provider_with(
+                provider("scraper", ProviderTier::Scraper, 500),
+                ProviderOptions { timeout: Some(Duration::from_millis(20)), ..Default::default() },
+            )
+            .await;
+        engine.register_provider(provider("static", ProviderTier::Static, 0)).await;
+        
+        let response = engine.fetch_prices(&sand_request()).await.unwrap();
+        
+        let provenance = &response.provenance["sand"];
+        assert_eq!(provenance.answered_by, vec!["static"]);
+        assert_eq!(provenance.tier, Some(ProviderTier::Static));
+        assert_eq!(provenance.failed_over_from, vec!["scraper"]);
+        assert!(response.warnings.iter().any(|w| w.contains("no answer within 20ms")));
+    }
+    
+    #[tokio::test]
+    async fn test_priority_orders_providers_within_a_tier() {
+        let engine = PricingEngine::new();
+        engine
+            .register_provider_with(provider("backup", ProviderTier::Api, 0), ProviderOptions { priority: Some(10), ..Default::default() })
+            .await;
+        engine
+            .register_provider_with(provider("primary", ProviderTier::Api, 0), ProviderOptions { priority: Some(1), ..Default::default() })
+            .await;
+        
+        let response = engine.fetch_prices(&sand_request()).await.unwrap();
+        
+        assert_eq!(response.provenance["sand"].answered_by, vec!["primary"]);
+        assert!(response.provenance["sand"].failed_over_from.is_empty());
+        assert!(response.prices.iter().all(|p| p.store.name == "primary"));
+    }
+}
@@ -1,5 +1,6 @@
-use crate::pricing::{errors::PricingResult, models::*};
+use crate::pricing::{errors::PricingResult, health::ProviderTier, models::*};
 use async_trait::async_trait;
+use std::time::Duration;
 
 /// Price provider trait - the reconnaissance interface
 /// 
@@ -19,6 +20,22 @@ pub trait PriceProvider: Send + Sync {
     /// Returns prices with store information.
     async fn fetch_prices(&self, request: &PriceRequest) -> PricingResult<PriceResponse>;
     
+    /// Source kind, used to place the provider in the failover chain
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::Api
+    }
+    
+    /// Order among providers of the same tier - lower goes first, equal
+    /// priorities are asked in parallel
+    fn priority(&self) -> i32 {
+        0
+    }
+    
+    /// How long the engine waits before failing over to the next provider
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(15)
+    }
+    
     /// Check if provider is operational
     async fn health_check(&self) -> PricingResult<bool> {
         Ok(true)
@@ -0,0 +1,681 @@
+//! Project workspaces: saved calculations grouped by project
+//!
+//! Signed-in users keep named projects, each holding calculations from any
+//! tier. Saving a calculation runs it and stores the request parameters with
+//! the response and the calculator's formula version; re-running it later
+//! replaces the stored response and hands back the previous one, so a
+//! client can show what moved.
+//!
+//! Runs go through the same plan, quota and rollout checks as the
+//! calculator endpoints and count towards the caller's usage.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::types::Json as SqlJson;
+use std::sync::Arc;
+use std::time::Instant;
+use uuid::Uuid;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::billing;
+use crate::calculus::beginner::{models::BeginnerParameters, BeginnerError, BeginnerRegistry};
+use crate::calculus::changelog;
+use crate::calculus::contractor::{models::ContractingParameters, ContractingError, ContractingRegistry};
+use crate::calculus::engineer::{
+    models::EngineeringParameters, router::CalculateQuery, EngineeringError, EngineeringRegistry,
+};
+use crate::calculus::recommendation::Tier;
+use crate::flags::FlagContext;
+use crate::sec::{self, AppError};
+use crate::state::AppState;
+use crate::stats;
+use crate::tenancy::{self, TenantOwned, TenantScope};
+use crate::utils::conversions::UnitSystem;
+
+// =============================================================================
+// MODELS
+// =============================================================================
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct Project {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub org_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl TenantOwned for Project {
+    fn org_id(&self) -> Uuid {
+        self.org_id
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectDetail {
+    #[serde(flatten)]
+    pub project: Project,
+    pub calculations: Vec<SavedCalculation>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CalculationRow {
+    id: Uuid,
+    project_id: Uuid,
+    tier: String,
+    calculation_type: String,
+    label: Option<String>,
+    parameters: SqlJson<Value>,
+    response: SqlJson<Value>,
+    calculator_version: String,
+    created_at: OffsetDateTime,
+    last_run_at: OffsetDateTime,
+}
+
+/// A stored request with the response of its latest run
+#[derive(Debug, Clone, Serialize)]
+pub struct SavedCalculation {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub tier: Tier,
+    pub calculation_type: String,
+    pub label: Option<String>,
+    pub parameters: Value,
+    pub response: Value,
+    /// Formula version that produced `response`
+    pub calculator_version: String,
+    pub created_at: OffsetDateTime,
+    pub last_run_at: OffsetDateTime,
+}
+
+impl TryFrom<CalculationRow> for SavedCalculation {
+    type Error = AppError;
+
+    fn try_from(row: CalculationRow) -> Result<Self, AppError> {
+        let tier = row
+            .tier
+            .parse()
+            .map_err(|e| AppError::Internal(format!("Calculation {}: {}", row.id, e)))?;
+        Ok(Self {
+            id: row.id,
+            project_id: row.project_id,
+            tier,
+            calculation_type: row.calculation_type,
+            label: row.label,
+            parameters: row.parameters.0,
+            response: row.response.0,
+            calculator_version: row.calculator_version,
+            created_at: row.created_at,
+            last_run_at: row.last_run_at,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ProjectPayload {
+    #[validate(length(min = 1, max = 200))]
+    pub name: String,
+    #[validate(length(max = 2000))]
+    pub description: Option<String>,
+}
+
+/// A calculation request to run and keep, in the calculator endpoints' shape
+#[derive(Debug, Deserialize, Validate)]
+pub struct SaveCalculationPayload {
+    pub tier: Tier,
+    #[validate(length(min = 1, max = 100))]
+    pub calculation_type: String,
+    pub parameters: Value,
+    #[validate(length(max = 200))]
+    pub label: Option<String>,
+}
+
+/// A re-run calculation alongside what it returned before
+#[derive(Debug, Serialize)]
+pub struct RerunResult {
+    pub calculation: SavedCalculation,
+    pub previous_response: Value,
+    pub previous_calculator_version: String,
+}
+
+const PROJECT_COLUMNS: &str = "id, org_id, name, description, created_at, updated_at";
+
+const CALCULATION_COLUMNS: &str = "id, project_id, tier, calculation_type, label, parameters, response, \
+    calculator_version, created_at, last_run_at";
+
+/// Path recorded in calculation history for runs made through projects
+const HISTORY_PATH: &str = "/api/v1/user/projects";
+
+// =============================================================================
+// ERRORS
+// =============================================================================
+
+/// Project errors, or the refusal of the calculation being run
+#[derive(Debug)]
+pub enum ProjectError {
+    App(AppError),
+    /// Calculator, plan or quota error, already shaped for the client
+    Rejected(Box<Response>),
+}
+
+impl ProjectError {
+    pub fn rejected(response: Response) -> Self {
+        ProjectError::Rejected(Box::new(response))
+    }
+}
+
+impl IntoResponse for ProjectError {
+    fn into_response(self) -> Response {
+        match self {
+            ProjectError::App(e) => e.into_response(),
+            ProjectError::Rejected(response) => *response,
+        }
+    }
+}
+
+impl From<AppError> for ProjectError {
+    fn from(e: AppError) -> Self {
+        ProjectError::App(e)
+    }
+}
+
+impl From<sqlx::Error> for ProjectError {
+    fn from(e: sqlx::Error) -> Self {
+        ProjectError::App(AppError::DbError(e))
+    }
+}
+
+impl From<BeginnerError> for ProjectError {
+    fn from(e: BeginnerError) -> Self {
+        ProjectError::rejected(e.into_response())
+    }
+}
+
+impl From<EngineeringError> for ProjectError {
+    fn from(e: EngineeringError) -> Self {
+        ProjectError::rejected(e.into_response())
+    }
+}
+
+impl From<ContractingError> for ProjectError {
+    fn from(e: ContractingError) -> Self {
+        ProjectError::rejected(e.into_response())
+    }
+}
+
+// =============================================================================
+// EXECUTION
+// =============================================================================
+
+/// Parameters as the calculator read them, and its response
+#[derive(Debug)]
+pub struct Executed {
+    pub parameters: Value,
+    pub response: Value,
+    pub calculator_version: &'static str,
+}
+
+fn invalid_parameters(e: serde_json::Error) -> ProjectError {
+    let mut errors = ValidationErrors::new();
+    let mut error = ValidationError::new("parameters");
+    error.message = Some(e.to_string().into());
+    errors.add("parameters", error);
+    ProjectError::App(AppError::ValidationError(errors))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<Value, ProjectError> {
+    serde_json::to_value(value).map_err(|e| ProjectError::App(AppError::Internal(e.to_string())))
+}
+
+/// Stored parameters keep the caller's unit system; calculators run in metric
+fn to_metric<'a, P: Serialize + DeserializeOwned>(
+    system: UnitSystem,
+    parameters: P,
+    declared: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Result<P, ProjectError> {
+    system.parameters_to_metric(parameters, declared).map_err(invalid_parameters)
+}
+
+/// Run one calculation through its tier's registry; `query` shapes
+/// engineering responses the way the calculate endpoint's query does, and
+/// without one they are kept whole
+pub async fn execute(
+    beginner: &BeginnerRegistry,
+    engineer: &EngineeringRegistry,
+    contractor: &ContractingRegistry,
+    tier: Tier,
+    calculation_type: &str,
+    parameters: Value,
+    query: Option<&CalculateQuery>,
+) -> Result<Executed, ProjectError> {
+    let calculator_version = changelog::formula_version(tier, calculation_type);
+
+    let (parameters, response) = match tier {
+        Tier::Beginner => {
+            let calculator = beginner.find(calculation_type)?;
+            let params: BeginnerParameters = serde_json::from_value(parameters).map_err(invalid_parameters)?;
+            let stored = to_json(&params)?;
+            let system = params.unit_system;
+            let params = to_metric(system, params, calculator.metadata().parameters.iter().map(|p| (p.path.as_str(), p.unit.as_str())))?;
+            let mut response = beginner.execute(&calculator, params).await?;
+            response.express_in(system);
+            (stored, to_json(&response)?)
+        }
+        Tier::Engineer => {
+            let calculator = engineer.find(calculation_type)?;
+            let params: EngineeringParameters = serde_json::from_value(parameters).map_err(invalid_parameters)?;
+            let stored = to_json(&params)?;
+            let system = params.unit_system;
+            let params = to_metric(system, params, calculator.metadata().parameters.iter().map(|p| (p.path.as_str(), p.unit.as_str())))?;
+            let mut response = engineer.execute(&calculator, params).await?;
+            if let Some(metadata) = response.calculation_metadata.as_mut() {
+                metadata.calculator_version = calculator_version.to_string();
+            }
+            if let Some(query) = query {
+                query.shape(&mut response, false);
+            }
+            response.express_in(system);
+            (stored, to_json(&response)?)
+        }
+        Tier::Contractor => {
+            let calculator = contractor.find(calculation_type)?;
+            let params: ContractingParameters = serde_json::from_value(parameters).map_err(invalid_parameters)?;
+            let stored = to_json(&params)?;
+            let system = params.unit_system;
+            let params = to_metric(system, params, calculator.metadata().parameters.iter().map(|p| (p.path.as_str(), p.unit.as_str())))?;
+            let mut response = contractor.execute(&calculator, params).await?;
+            if let Some(metadata) = response.calculation_metadata.as_mut() {
+                metadata.calculator_version = calculator_version.to_string();
+            }
+            response.express_in(system);
+            (stored, to_json(&response)?)
+        }
+    };
+
+    Ok(Executed { parameters, response, calculator_version })
+}
+
+fn not_found(tier: Tier, calculation_type: &str) -> ProjectError {
+    let id = calculation_type.to_string();
+    match tier {
+        Tier::Beginner => BeginnerError::CalculatorNotFound(id).into(),
+        Tier::Engineer => EngineeringError::CalculatorNotFound(id).into(),
+        Tier::Contractor => ContractingError::CalculatorNotFound(id).into(),
+    }
+}
+
+/// Run a calculation for the caller and record it in their history under
+/// `path`
+pub(crate) async fn run_for_caller(
+    state: &AppState,
+    scope: &TenantScope,
+    path: &str,
+    tier: Tier,
+    calculation_type: &str,
+    parameters: Value,
+    query: Option<&CalculateQuery>,
+) -> Result<Executed, ProjectError> {
+    let started = Instant::now();
+    let parameters_hash = stats::parameters_hash(&parameters);
+    let result = check_and_execute(state, scope, tier, calculation_type, parameters, query).await;
+
+    let record = stats::CalculationRecord {
+        tier,
+        path: path.to_string(),
+        calculator_id: Some(calculation_type.to_string()),
+        category: stats::category_of(state, tier, calculation_type),
+        parameters_hash,
+        duration_ms: started.elapsed().as_millis() as i64,
+        status: match &result {
+            Ok(_) => StatusCode::OK,
+            Err(ProjectError::Rejected(response)) => response.status(),
+            Err(ProjectError::App(_)) => StatusCode::INTERNAL_SERVER_ERROR,
+        },
+    };
+    if let Err(e) = record.store(&state.pool, scope.user_id).await {
+        tracing::error!(error = %e, user_id = %scope.user_id, "failed to record calculation history");
+    }
+    result
+}
+
+/// Check the caller may run the calculation, run it and meter it
+async fn check_and_execute(
+    state: &AppState,
+    scope: &TenantScope,
+    tier: Tier,
+    calculation_type: &str,
+    parameters: Value,
+    query: Option<&CalculateQuery>,
+) -> Result<Executed, ProjectError> {
+    tenancy::check_monthly_quota(&state.pool, scope, 1).await.map_err(ProjectError::rejected)?;
+    billing::check_plan(state, Some(scope.user_id), tier, 1).await.map_err(ProjectError::rejected)?;
+
+    // Calculators behind a rollout flag look missing to callers outside it
+    let ctx = FlagContext::for_scope(scope);
+    if !state.flags.calculator_enabled(&state.pool, tier, calculation_type, &ctx).await {
+        return Err(not_found(tier, calculation_type));
+    }
+
+    let executed = execute(
+        &state.calculators_beginner,
+        &state.calculators_engineer,
+        &state.calculators_contractor,
+        tier,
+        calculation_type,
+        parameters,
+        query,
+    )
+    .await?;
+
+    if let Err(e) = billing::record_calculation(&state.pool, scope.user_id, tier).await {
+        tracing::error!(error = %e, user_id = %scope.user_id, "failed to record calculation");
+    }
+    Ok(executed)
+}
+
+// =============================================================================
+// QUERIES
+// =============================================================================
+
+pub(crate) async fn load_project(state: &AppState, scope: &TenantScope, id: Uuid) -> Result<Project, AppError> {
+    let project = sqlx::query_as::<_, Project>(&format!(
+        "SELECT {PROJECT_COLUMNS} FROM projects WHERE id = $1 AND user_id = $2 AND org_id = $3"
+    ))
+    .bind(id)
+    .bind(scope.user_id)
+    .bind(scope.org_id)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    scope.guard(project)
+}
+
+async fn load_calculation(state: &AppState, project_id: Uuid, id: Uuid) -> Result<SavedCalculation, AppError> {
+    sqlx::query_as::<_, CalculationRow>(&format!(
+        "SELECT {CALCULATION_COLUMNS} FROM project_calculations WHERE id = $1 AND project_id = $2"
+    ))
+    .bind(id)
+    .bind(project_id)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?
+    .try_into()
+}
+
+/// Every calculation of a project, oldest first
+pub(crate) async fn load_calculations(state: &AppState, project_id: Uuid) -> Result<Vec<SavedCalculation>, AppError> {
+    sqlx::query_as::<_, CalculationRow>(&format!(
+        "SELECT {CALCULATION_COLUMNS} FROM project_calculations WHERE project_id = $1 ORDER BY created_at"
+    ))
+    .bind(project_id)
+    .fetch_all(&state.pool)
+    .await?
+    .into_iter()
+    .map(SavedCalculation::try_from)
+    .collect()
+}
+
+// =============================================================================
+// HANDLERS
+// =============================================================================
+
+pub async fn list_projects_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+) -> Result<Json<Vec<Project>>, AppError> {
+    let projects = sqlx::query_as::<_, Project>(&format!(
+        "SELECT {PROJECT_COLUMNS} FROM projects WHERE user_id = $1 AND org_id = $2 ORDER BY updated_at DESC"
+    ))
+    .bind(scope.user_id)
+    .bind(scope.org_id)
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(scope.guard_all(projects)?))
+}
+
+pub async fn get_project_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ProjectDetail>, AppError> {
+    let project = load_project(&state, &scope, id).await?;
+    let calculations = load_calculations(&state, project.id).await?;
+
+    Ok(Json(ProjectDetail { project, calculations }))
+}
+
+pub async fn create_project_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Json(payload): Json<ProjectPayload>,
+) -> Result<(StatusCode, Json<Project>), AppError> {
+    payload.validate()?;
+
+    let project = sqlx::query_as::<_, Project>(&format!(
+        "INSERT INTO projects (org_id, user_id, name, description) VALUES ($1, $2, $3, $4) \
+         RETURNING {PROJECT_COLUMNS}"
+    ))
+    .bind(scope.org_id)
+    .bind(scope.user_id)
+    .bind(&payload.name)
+    .bind(&payload.description)
+    .fetch_one(&state.pool)
+    .await?;
+
+    sec::log_security_event("PROJECT_CREATE", Some(&scope.username), None, "Success");
+    Ok((StatusCode::CREATED, Json(project)))
+}
+
+pub async fn update_project_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ProjectPayload>,
+) -> Result<Json<Project>, AppError> {
+    payload.validate()?;
+
+    let project = sqlx::query_as::<_, Project>(&format!(
+        "UPDATE projects SET name = $4, description = $5, updated_at = CURRENT_TIMESTAMP \
+         WHERE id = $1 AND user_id = $2 AND org_id = $3 \
+         RETURNING {PROJECT_COLUMNS}"
+    ))
+    .bind(id)
+    .bind(scope.user_id)
+    .bind(scope.org_id)
+    .bind(&payload.name)
+    .bind(&payload.description)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    Ok(Json(project))
+}
+
+pub async fn delete_project_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let result = sqlx::query("DELETE FROM projects WHERE id = $1 AND user_id = $2 AND org_id = $3")
+        .bind(id)
+        .bind(scope.user_id)
+        .bind(scope.org_id)
+        .execute(&state.pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("PROJECT_DELETE", Some(&scope.username), None, "Success");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Run a calculation and keep it in the project
+pub async fn save_calculation_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<SaveCalculationPayload>,
+) -> Result<(StatusCode, Json<SavedCalculation>), ProjectError> {
+    payload.validate().map_err(AppError::from)?;
+    let project = load_project(&state, &scope, id).await?;
+
+    let executed = run_for_caller(
+        &state,
+        &scope,
+        HISTORY_PATH,
+        payload.tier,
+        &payload.calculation_type,
+        payload.parameters,
+        None,
+    )
+    .await?;
+
+    let row = sqlx::query_as::<_, CalculationRow>(&format!(
+        "INSERT INTO project_calculations \
+             (project_id, tier, calculation_type, label, parameters, response, calculator_version) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) \
+         RETURNING {CALCULATION_COLUMNS}"
+    ))
+    .bind(project.id)
+    .bind(payload.tier.as_str())
+    .bind(&payload.calculation_type)
+    .bind(&payload.label)
+    .bind(SqlJson(&executed.parameters))
+    .bind(SqlJson(&executed.response))
+    .bind(executed.calculator_version)
+    .fetch_one(&state.pool)
+    .await?;
+
+    sqlx::query("UPDATE projects SET updated_at = CURRENT_TIMESTAMP WHERE id = $1")
+        .bind(project.id)
+        .execute(&state.pool)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(row.try_into()?)))
+}
+
+/// Run a saved calculation again with today's calculators
+pub async fn rerun_calculation_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path((id, calculation_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<RerunResult>, ProjectError> {
+    let project = load_project(&state, &scope, id).await?;
+    let previous = load_calculation(&state, project.id, calculation_id).await?;
+
+    let executed = run_for_caller(
+        &state,
+        &scope,
+        HISTORY_PATH,
+        previous.tier,
+        &previous.calculation_type,
+        previous.parameters.clone(),
+        None,
+    )
+    .await?;
+
+    let row = sqlx::query_as::<_, CalculationRow>(&format!(
+        "UPDATE project_calculations \
+         SET response = $3, calculator_version = $4, last_run_at = CURRENT_TIMESTAMP \
+         WHERE id = $1 AND project_id = $2 \
+         RETURNING {CALCULATION_COLUMNS}"
+    ))
+    .bind(previous.id)
+    .bind(project.id)
+    .bind(SqlJson(&executed.response))
+    .bind(executed.calculator_version)
+    .fetch_one(&state.pool)
+    .await?;
+
+    Ok(Json(RerunResult {
+        calculation: row.try_into()?,
+        previous_response: previous.response,
+        previous_calculator_version: previous.calculator_version,
+    }))
+}
+
+pub async fn delete_calculation_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Path((id, calculation_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let project = load_project(&state, &scope, id).await?;
+    let result = sqlx::query("DELETE FROM project_calculations WHERE id = $1 AND project_id = $2")
+        .bind(calculation_id)
+        .bind(project.id)
+        .execute(&state.pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::UserNotFound);
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::{beginner, contractor, engineer};
+    use serde_json::json;
+
+    fn registries() -> (BeginnerRegistry, EngineeringRegistry, ContractingRegistry) {
+        (
+            beginner::create_default_registry(),
+            engineer::create_default_registry(),
+            contractor::create_default_registry(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_execute_stores_normalized_parameters_and_response() {
+        let (b, e, c) = registries();
+        let parameters = json!({ "width": 5.5, "length": 7.5, "height": 2.0 });
+
+        let executed = execute(&b, &e, &c, Tier::Beginner, "baseboard", parameters, None).await.unwrap();
+
+        assert_eq!(executed.parameters["width"], 5.5);
+        assert_eq!(executed.response["calculation_type"], "baseboard");
+        assert!(!executed.response["results"].as_array().unwrap().is_empty());
+        assert_eq!(executed.calculator_version, changelog::formula_version(Tier::Beginner, "baseboard"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_unknown_calculators_and_bad_parameters() {
+        let (b, e, c) = registries();
+
+        let missing = execute(&b, &e, &c, Tier::Engineer, "perpetual_motion", json!({}), None).await;
+        let Err(ProjectError::Rejected(response)) = missing else { panic!("expected a calculator error") };
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let malformed = execute(&b, &e, &c, Tier::Beginner, "baseboard", json!({ "width": "wide" }), None).await;
+        assert!(matches!(malformed, Err(ProjectError::App(AppError::ValidationError(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_execute_shapes_engineering_responses_for_a_query() {
+        let (b, e, c) = registries();
+        let parameters = json!({
+            "dimensions": { "height": 10.0, "width": 30.0, "length": 20.0 },
+            "additional": { "wind_speed": 51.0 },
+            "exposure_class": "C",
+        });
+
+        let whole = execute(&b, &e, &c, Tier::Engineer, "wind_load", parameters.clone(), None).await.unwrap();
+        assert!(!whole.response["calculation_trace"].is_null());
+
+        let query: CalculateQuery = serde_json::from_value(json!({ "audience": "operator" })).unwrap();
+        let shaped = execute(&b, &e, &c, Tier::Engineer, "wind_load", parameters, Some(&query)).await.unwrap();
+        assert!(shaped.response["calculation_trace"].is_null());
+        assert!(shaped.response["analysis"].is_null());
+    }
+}
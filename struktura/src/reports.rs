@@ -0,0 +1,520 @@
+//! Calculation reports: any calculator response as a downloadable PDF
+//!
+//! Clients post a response exactly as a calculate endpoint returned it (any
+//! tier, or the OEE API) together with project details and branding. The
+//! report lists the results, warnings, recommendations and compliance notes,
+//! the assumption ledger when the response carries one, and a version block
+//! naming the calculator's formula version and the report format, so a
+//! printed report can be traced back to the code that produced it.
+//!
+//! Nothing is recalculated: the report shows what the client received.
+
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::{ValidationError, ValidationErrors};
+
+use crate::billing;
+use crate::calculus::changelog;
+use crate::calculus::provenance::ledger::AssumptionLedger;
+use crate::calculus::recommendation::Tier;
+use crate::sec::{AppError, Claims};
+use crate::state::AppState;
+use crate::utils::format;
+use crate::utils::pdf::PdfDocument;
+
+/// Layout version of generated reports, bumped when sections change
+pub const REPORT_FORMAT_VERSION: &str = "1.0";
+
+/// Most ledger assumptions listed before the rest are summarized
+const MAX_LEDGER_ROWS: usize = 40;
+
+const GENERAL_DISCLAIMER: &str = "This report reproduces the output of Struktura calculators for the inputs \
+    supplied. Results depend on those inputs and on the assumptions listed; verify them against site conditions \
+    and the governing codes before relying on the figures.";
+
+const PE_DISCLAIMER: &str = "This calculation requires review by a licensed Professional Engineer. It must not \
+    be used for design, permitting or construction until it has been checked and sealed by a Professional \
+    Engineer licensed in the jurisdiction of the project.";
+
+// ============================================================================
+// Models
+// ============================================================================
+
+/// Project details printed in the report header
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProjectInfo {
+    pub name: Option<String>,
+    pub number: Option<String>,
+    pub client: Option<String>,
+    pub location: Option<String>,
+    pub prepared_by: Option<String>,
+    pub checked_by: Option<String>,
+}
+
+/// How the report presents its issuer
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Branding {
+    /// Company named at the top of the report
+    pub company_name: Option<String>,
+    /// Replaces the default "<calculator> Calculation Report" title
+    pub report_title: Option<String>,
+    /// Contact line under the company name
+    pub contact: Option<String>,
+    /// Closing line; defaults to "Prepared with Struktura"
+    pub footer: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReportRequest {
+    /// Tier of the endpoint that produced `response`
+    pub tier: Tier,
+    /// A calculation response, unchanged
+    pub response: Value,
+    #[serde(default)]
+    pub project: ProjectInfo,
+    #[serde(default)]
+    pub branding: Branding,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReportLine {
+    pub label: String,
+    pub value: String,
+}
+
+/// The content of a report, read out of a calculation response
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub title: String,
+    pub tier: Tier,
+    pub calculation_type: Option<String>,
+    pub generated_at: String,
+    pub calculator_version: Option<String>,
+    pub design_code: Option<String>,
+    pub requires_pe_review: bool,
+    pub results: Vec<ReportLine>,
+    pub warnings: Vec<String>,
+    pub recommendations: Vec<String>,
+    pub compliance_notes: Vec<String>,
+    /// Defaults merged into the request
+    pub applied_defaults: Vec<ReportLine>,
+    pub ledger: Option<AssumptionLedger>,
+    pub project: ProjectInfo,
+    pub branding: Branding,
+}
+
+// ============================================================================
+// Reading responses
+// ============================================================================
+
+fn invalid_response(message: &str) -> AppError {
+    let mut errors = ValidationErrors::new();
+    let mut error = ValidationError::new("response");
+    error.message = Some(message.to_string().into());
+    errors.add("response", error);
+    AppError::ValidationError(errors)
+}
+
+fn strings(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| items.iter().filter_map(Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// `label: value unit` rows from a `results` array
+fn result_lines(results: &[Value]) -> Vec<ReportLine> {
+    let policy = format::policy();
+    results
+        .iter()
+        .filter_map(|item| {
+            let label = item.get("label")?.as_str()?.to_string();
+            let value = match (item.get("formatted_value").and_then(Value::as_str), item.get("value")) {
+                (Some(formatted), _) => formatted.to_string(),
+                (None, Some(Value::Number(n))) => {
+                    let unit = item.get("unit").and_then(Value::as_str).unwrap_or_default();
+                    policy.format(n.as_f64()?, unit)
+                }
+                (None, Some(other)) => scalar(other),
+                (None, None) => return None,
+            };
+            Some(ReportLine { label, value })
+        })
+        .collect()
+}
+
+/// Scalar leaves of a nested object as dotted-path rows, for responses
+/// without a `results` list (OEE metrics)
+fn flatten_metrics(prefix: &str, value: &Value, out: &mut Vec<ReportLine>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten_metrics(&path, value, out);
+            }
+        }
+        Value::Array(_) | Value::Null => {}
+        Value::Number(n) => {
+            let value = match n.as_f64() {
+                Some(v) => format!("{:.4}", v).trim_end_matches('0').trim_end_matches('.').to_string(),
+                None => n.to_string(),
+            };
+            out.push(ReportLine { label: prefix.to_string(), value });
+        }
+        other => out.push(ReportLine { label: prefix.to_string(), value: scalar(other) }),
+    }
+}
+
+impl Report {
+    pub fn build(request: ReportRequest) -> Result<Self, AppError> {
+        let ReportRequest { tier, response, project, branding } = request;
+        if !response.is_object() {
+            return Err(invalid_response("Expected a calculation response object"));
+        }
+        // The OEE API nests its result one level down
+        let body = match response.get("result") {
+            Some(result) if result.is_object() && response.get("results").is_none() => result,
+            _ => &response,
+        };
+
+        let results = match body.get("results").and_then(Value::as_array) {
+            Some(items) => result_lines(items),
+            None => {
+                let mut lines = Vec::new();
+                if let Some(metrics) = body.get("core_metrics") {
+                    flatten_metrics("", metrics, &mut lines);
+                }
+                lines
+            }
+        };
+        if results.is_empty() {
+            return Err(invalid_response("The response has no results to report"));
+        }
+
+        let calculation_type = body.get("calculation_type").and_then(Value::as_str).map(str::to_string);
+        let metadata = body.get("calculation_metadata");
+        let metadata_str = |key: &str| metadata.and_then(|m| m.get(key)).and_then(Value::as_str).map(str::to_string);
+        let calculator_version = metadata_str("calculator_version")
+            .filter(|v| !v.is_empty())
+            .or_else(|| calculation_type.as_deref().map(|id| changelog::formula_version(tier, id).to_string()));
+        let requires_pe_review = metadata
+            .and_then(|m| m.get("requires_pe_review"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        let applied_defaults = body
+            .get("assumptions")
+            .and_then(Value::as_array)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|a| {
+                        let path = a.get("path")?.as_str()?;
+                        let source = a.get("source").and_then(Value::as_str).unwrap_or("default");
+                        Some(ReportLine {
+                            label: path.to_string(),
+                            value: format!("{} ({})", scalar(a.get("value").unwrap_or(&Value::Null)), source),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Engineer and contractor responses carry it as `provenance`, OEE as `ledger`
+        let ledger = ["provenance", "ledger"]
+            .iter()
+            .filter_map(|key| body.get(*key))
+            .find_map(|value| serde_json::from_value::<AssumptionLedger>(value.clone()).ok());
+
+        let name = calculation_type.as_deref().map_or("Calculation".to_string(), |id| {
+            id.split('_')
+                .map(|w| {
+                    let mut chars = w.chars();
+                    chars.next().map_or(String::new(), |c| c.to_uppercase().chain(chars).collect())
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+
+        Ok(Self {
+            title: branding.report_title.clone().unwrap_or_else(|| format!("{} Calculation Report", name)),
+            tier,
+            calculation_type,
+            generated_at: chrono::Utc::now().format("%Y-%m-%d %H:%M UTC").to_string(),
+            calculator_version,
+            design_code: metadata_str("design_code_used").filter(|c| !c.is_empty()),
+            requires_pe_review,
+            results,
+            warnings: strings(&body["warnings"]),
+            recommendations: strings(&body["recommendations"]),
+            compliance_notes: strings(&body["compliance_notes"]),
+            applied_defaults,
+            ledger,
+            project,
+            branding,
+        })
+    }
+
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let mut doc = PdfDocument::new();
+        if let Some(company) = &self.branding.company_name {
+            doc = doc.heading(company);
+        }
+        if let Some(contact) = &self.branding.contact {
+            doc = doc.text(contact);
+        }
+        doc = doc.title(&self.title).text(&format!("Generated: {}", self.generated_at));
+
+        let project = &self.project;
+        let details = [
+            ("Project", &project.name),
+            ("Project number", &project.number),
+            ("Client", &project.client),
+            ("Location", &project.location),
+            ("Prepared by", &project.prepared_by),
+            ("Checked by", &project.checked_by),
+        ];
+        if details.iter().any(|(_, v)| v.is_some()) {
+            doc = doc.spacer().heading("Project");
+            for (label, value) in details {
+                if let Some(value) = value {
+                    doc = doc.row(&[(0.0, label), (160.0, value)]);
+                }
+            }
+        }
+
+        doc = doc.spacer().heading("Results").bold_row(&[(0.0, "Item"), (300.0, "Value")]);
+        for line in &self.results {
+            doc = doc.row(&[(0.0, &line.label), (300.0, &line.value)]);
+        }
+
+        for (heading, notes) in [
+            ("Warnings", &self.warnings),
+            ("Recommendations", &self.recommendations),
+            ("Compliance notes", &self.compliance_notes),
+        ] {
+            if notes.is_empty() {
+                continue;
+            }
+            doc = doc.spacer().heading(heading);
+            for note in notes {
+                doc = doc.paragraph(&format!("- {}", note));
+            }
+        }
+
+        if !self.applied_defaults.is_empty() {
+            doc = doc.spacer().heading("Defaults applied").bold_row(&[(0.0, "Parameter"), (300.0, "Value (source)")]);
+            for line in &self.applied_defaults {
+                doc = doc.row(&[(0.0, &line.label), (300.0, &line.value)]);
+            }
+        }
+
+        if let Some(ledger) = &self.ledger {
+            doc = self.ledger_section(doc, ledger);
+        }
+
+        let version = |v: &Option<String>| v.clone().unwrap_or_else(|| "-".to_string());
+        doc = doc
+            .spacer()
+            .heading("Version")
+            .row(&[(0.0, "Calculator"), (160.0, self.calculation_type.as_deref().unwrap_or("-"))])
+            .row(&[(0.0, "Tier"), (160.0, self.tier.as_str())])
+            .row(&[(0.0, "Formula version"), (160.0, &version(&self.calculator_version))])
+            .row(&[(0.0, "Design code"), (160.0, &version(&self.design_code))])
+            .row(&[(0.0, "Struktura version"), (160.0, env!("CARGO_PKG_VERSION"))])
+            .row(&[(0.0, "Report format"), (160.0, REPORT_FORMAT_VERSION)]);
+
+        doc = doc.spacer().heading("Disclaimer");
+        if self.requires_pe_review {
+            doc = doc.paragraph(PE_DISCLAIMER);
+        }
+        doc = doc.paragraph(GENERAL_DISCLAIMER);
+
+        let footer = self.branding.footer.as_deref().unwrap_or("Prepared with Struktura");
+        doc.spacer().text(footer).to_bytes()
+    }
+
+    fn ledger_section(&self, mut doc: PdfDocument, ledger: &AssumptionLedger) -> PdfDocument {
+        let stats = &ledger.source_statistics;
+        doc = doc
+            .spacer()
+            .heading("Assumption ledger")
+            .text(&format!(
+                "Inputs: {} explicit, {} inferred, {} default",
+                stats.explicit_count, stats.inferred_count, stats.default_count
+            ));
+
+        if !ledger.assumptions.is_empty() {
+            doc = doc.bold_row(&[(0.0, "Assumption"), (250.0, "Value"), (380.0, "Source"), (460.0, "Impact")]);
+            for entry in ledger.assumptions.iter().take(MAX_LEDGER_ROWS) {
+                let source = match &entry.origin {
+                    Some(origin) => format!("{} ({})", entry.source, origin),
+                    None => entry.source.clone(),
+                };
+                doc = doc.row(&[
+                    (0.0, &entry.assumption_key),
+                    (250.0, &scalar(&entry.value)),
+                    (380.0, &source),
+                    (460.0, &format!("{:?}", entry.impact)),
+                ]);
+            }
+            if ledger.assumptions.len() > MAX_LEDGER_ROWS {
+                doc = doc.text(&format!("... and {} more", ledger.assumptions.len() - MAX_LEDGER_ROWS));
+            }
+        }
+
+        if !ledger.thresholds.is_empty() {
+            doc = doc.spacer().bold_row(&[(0.0, "Threshold"), (250.0, "Value"), (380.0, "Source")]);
+            for threshold in &ledger.thresholds {
+                doc = doc.row(&[
+                    (0.0, &threshold.threshold_key),
+                    (250.0, &format!("{} {}", threshold.value, threshold.unit_key)),
+                    (380.0, &threshold.source),
+                ]);
+            }
+        }
+
+        for warning in &ledger.warnings {
+            doc = doc.paragraph(&format!("- [{:?}] {}: {}", warning.severity, warning.code, warning.message_key));
+        }
+        doc
+    }
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// POST /api/v1/reports/pdf
+pub async fn render_pdf_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Json(payload): Json<ReportRequest>,
+) -> Result<Response, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub).ok();
+    if let Err(response) = billing::check_exports(&state, user_id).await {
+        return Ok(response);
+    }
+
+    let report = Report::build(payload)?;
+    let pdf = report.to_pdf();
+
+    if let Some(user_id) = user_id
+        && let Err(e) = crate::stats::record_usage(&state.pool, user_id, "export.pdf", billing::experience_level(report.tier)).await
+    {
+        tracing::error!(error = %e, %user_id, "failed to record report export");
+    }
+    crate::sec::log_security_event("REPORT_PDF", Some(&claims.username), None, "Success");
+
+    let filename = format!(
+        "attachment; filename=\"struktura-report-{}.pdf\"",
+        report.calculation_type.as_deref().unwrap_or("calculation")
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (header::CONTENT_DISPOSITION, filename),
+        ],
+        pdf,
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(tier: Tier, response: Value) -> ReportRequest {
+        ReportRequest { tier, response, project: ProjectInfo::default(), branding: Branding::default() }
+    }
+
+    #[test]
+    fn test_engineering_response_report() {
+        let response = json!({
+            "calculation_type": "beam_design",
+            "results": [
+                { "label": "Max Moment", "value": 12.5, "unit": "kN·m", "formatted_value": "12.5 kN·m" },
+                { "label": "Deflection", "value": 4.2, "unit": "mm" }
+            ],
+            "warnings": ["Deflection close to limit"],
+            "recommendations": [],
+            "compliance_notes": ["AISC 360-16 F2"],
+            "calculation_metadata": {
+                "timestamp": "2026-01-01T00:00:00Z",
+                "calculator_version": "2.1.0",
+                "design_code_used": "AISC 360-16",
+                "requires_pe_review": true
+            },
+            "assumptions": [{ "path": "material.yield_strength", "value": 345.0, "source": "organization" }]
+        });
+        let mut req = request(Tier::Engineer, response);
+        req.project.name = Some("Warehouse (Phase 2)".to_string());
+        req.branding.company_name = Some("Acme Structural".to_string());
+
+        let report = Report::build(req).unwrap();
+        assert_eq!(report.title, "Beam Design Calculation Report");
+        assert_eq!(report.calculator_version.as_deref(), Some("2.1.0"));
+        assert!(report.requires_pe_review);
+        assert_eq!(report.results[0].value, "12.5 kN·m");
+        assert_eq!(report.applied_defaults[0].value, "345.0 (organization)");
+
+        let pdf = String::from_utf8_lossy(&report.to_pdf()).to_string();
+        assert!(pdf.starts_with("%PDF"));
+        assert!(pdf.contains("Acme Structural"));
+        assert!(pdf.contains("Warehouse \\(Phase 2\\)"));
+        assert!(pdf.contains("Professional Engineer"));
+        assert!(pdf.contains(REPORT_FORMAT_VERSION));
+    }
+
+    #[test]
+    fn test_oee_response_uses_core_metrics_and_defaults_version() {
+        let response = json!({
+            "result": {
+                "core_metrics": { "oee": { "value": 0.6525 }, "availability": { "value": 0.9 } },
+                "loss_tree": {}
+            }
+        });
+        let report = Report::build(request(Tier::Engineer, response)).unwrap();
+        assert!(report.results.contains(&ReportLine { label: "oee.value".to_string(), value: "0.6525".to_string() }));
+        assert!(report.calculator_version.is_none());
+        assert!(!report.requires_pe_review);
+
+        let beginner = json!({
+            "calculation_type": "baseboard",
+            "results": [{ "label": "Baseboard Length", "value": 26.0, "unit": "m" }],
+            "warnings": []
+        });
+        let report = Report::build(request(Tier::Beginner, beginner)).unwrap();
+        assert_eq!(
+            report.calculator_version.as_deref(),
+            Some(changelog::formula_version(Tier::Beginner, "baseboard"))
+        );
+    }
+
+    #[test]
+    fn test_rejects_responses_without_results() {
+        for response in [json!([1, 2]), json!({ "calculation_type": "baseboard", "results": [] })] {
+            assert!(matches!(
+                Report::build(request(Tier::Beginner, response)),
+                Err(AppError::ValidationError(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,488 @@
+//! Resilience for outbound HTTP
+//!
+//! Every external provider (scraped pricing sources, geocoding, Stripe,
+//! notification relays) is called through a named `Guard`:
+//!
+//! - each attempt is bounded by the provider's timeout;
+//! - transient failures are retried with exponential backoff and full
+//!   jitter;
+//! - a bulkhead caps concurrent calls, rejecting the overflow instead of
+//!   queueing it, so one slow target cannot pile up tasks on the runtime;
+//! - a circuit breaker stops calling a provider after consecutive
+//!   failures and lets one probe through once the cool-down has passed.
+//!
+//! Guards are shared per provider name and count every outcome; admins
+//! read the counters at `/admin/outbound`. `OUTBOUND_<NAME>_TIMEOUT_MS`,
+//! `OUTBOUND_<NAME>_RETRIES` and `OUTBOUND_<NAME>_CONCURRENCY` override a
+//! provider's defaults.
+
+use axum::{extract::State, response::Json};
+use rand::Rng;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+
+// ============================================================================
+// Policy
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResiliencePolicy {
+    /// Limit on each attempt, not the whole call
+    pub timeout: Duration,
+    /// Retries after the first attempt for transient failures
+    pub max_retries: u32,
+    /// Backoff ceiling for the first retry; doubled on every retry
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Calls allowed in flight at once
+    pub max_concurrent: usize,
+    /// Consecutive failures that open the circuit
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects calls before probing again
+    pub open_for: Duration,
+}
+
+impl Default for ResiliencePolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            max_retries: 2,
+            base_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            max_concurrent: 16,
+            failure_threshold: 5,
+            open_for: Duration::from_secs(30),
+        }
+    }
+}
+
+impl ResiliencePolicy {
+    /// Scraped pricing sources: slow pages, few at a time
+    pub fn scraper() -> Self {
+        Self {
+            timeout: Duration::from_secs(15),
+            max_concurrent: 4,
+            open_for: Duration::from_secs(120),
+            ..Self::default()
+        }
+    }
+
+    /// Calls that must not be repeated, such as creating a Stripe session
+    pub fn no_retry() -> Self {
+        Self { max_retries: 0, ..Self::default() }
+    }
+
+    /// Apply `OUTBOUND_<NAME>_*` overrides
+    pub fn with_env(mut self, name: &str) -> Self {
+        let prefix = format!("OUTBOUND_{}", name.to_uppercase().replace(['-', '.', ':'], "_"));
+        let var = |suffix: &str| std::env::var(format!("{}_{}", prefix, suffix)).ok().and_then(|v| v.trim().parse::<u64>().ok());
+
+        if let Some(ms) = var("TIMEOUT_MS").filter(|ms| *ms > 0) {
+            self.timeout = Duration::from_millis(ms);
+        }
+        if let Some(retries) = var("RETRIES") {
+            self.max_retries = retries.min(10) as u32;
+        }
+        if let Some(n) = var("CONCURRENCY").filter(|n| *n > 0) {
+            self.max_concurrent = n as usize;
+        }
+        self
+    }
+
+    /// Delay before retry number `attempt` (0-based): uniform between zero
+    /// and the capped exponential step, so retries from many callers spread out
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let ceiling = self.base_backoff.saturating_mul(2u32.saturating_pow(attempt)).min(self.max_backoff);
+        ceiling.mul_f64(rand::rng().random_range(0.0..=1.0))
+    }
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Why a guarded call failed
+#[derive(Debug)]
+pub enum ResilienceError<E> {
+    /// The provider's circuit is open; it was not called
+    CircuitOpen(String),
+    /// Too many calls to the provider already in flight
+    BulkheadFull(String),
+    /// The last attempt ran out of time
+    Timeout(String, Duration),
+    /// The provider answered with an error
+    Failed(E),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ResilienceError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CircuitOpen(name) => write!(f, "{} is unavailable (circuit open)", name),
+            Self::BulkheadFull(name) => write!(f, "{} is saturated; too many calls in flight", name),
+            Self::Timeout(name, limit) => write!(f, "{} did not answer within {} ms", name, limit.as_millis()),
+            Self::Failed(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for ResilienceError<E> {}
+
+// ============================================================================
+// Circuit breaker
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    Closed,
+    Open,
+    /// Cool-down over; the next call is a probe
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct Breaker {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+impl Breaker {
+    fn state(&self, open_for: Duration, now: Instant) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(at) if now.duration_since(at) < open_for => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+}
+
+// ============================================================================
+// Guard
+// ============================================================================
+
+#[derive(Debug, Default)]
+struct Counters {
+    calls: AtomicU64,
+    successes: AtomicU64,
+    failures: AtomicU64,
+    timeouts: AtomicU64,
+    retries: AtomicU64,
+    rejected_open: AtomicU64,
+    rejected_bulkhead: AtomicU64,
+}
+
+/// Counters for one provider, as served to admins
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GuardStats {
+    pub name: String,
+    pub circuit: CircuitState,
+    pub in_flight: usize,
+    pub max_concurrent: usize,
+    pub timeout_ms: u64,
+    pub calls: u64,
+    pub successes: u64,
+    pub failures: u64,
+    pub timeouts: u64,
+    pub retries: u64,
+    pub rejected_open: u64,
+    pub rejected_bulkhead: u64,
+}
+
+/// Timeouts, retries, bulkhead and circuit breaker for one provider
+#[derive(Debug)]
+pub struct Guard {
+    name: String,
+    policy: ResiliencePolicy,
+    bulkhead: Semaphore,
+    breaker: Mutex<Breaker>,
+    counters: Counters,
+}
+
+fn guards() -> &'static Mutex<BTreeMap<String, Arc<Guard>>> {
+    static GUARDS: OnceLock<Mutex<BTreeMap<String, Arc<Guard>>>> = OnceLock::new();
+    GUARDS.get_or_init(Default::default)
+}
+
+impl Guard {
+    pub fn new(name: impl Into<String>, policy: ResiliencePolicy) -> Self {
+        Self {
+            name: name.into(),
+            bulkhead: Semaphore::new(policy.max_concurrent),
+            breaker: Mutex::new(Breaker { consecutive_failures: 0, opened_at: None, probing: false }),
+            counters: Counters::default(),
+            policy,
+        }
+    }
+
+    /// The shared guard for `name`, created with `policy` (plus environment
+    /// overrides) on first use
+    pub fn named(name: &str, policy: ResiliencePolicy) -> Arc<Guard> {
+        let mut guards = guards().lock().unwrap_or_else(|e| e.into_inner());
+        guards
+            .entry(name.to_string())
+            .or_insert_with(|| Arc::new(Guard::new(name, policy.with_env(name))))
+            .clone()
+    }
+
+    /// Stats for every guard created so far
+    pub fn all_stats() -> Vec<GuardStats> {
+        let guards = guards().lock().unwrap_or_else(|e| e.into_inner());
+        guards.values().map(|g| g.stats()).collect()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn stats(&self) -> GuardStats {
+        let load = |c: &AtomicU64| c.load(Ordering::Relaxed);
+        let circuit = self
+            .breaker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .state(self.policy.open_for, Instant::now());
+        GuardStats {
+            name: self.name.clone(),
+            circuit,
+            in_flight: self.policy.max_concurrent - self.bulkhead.available_permits(),
+            max_concurrent: self.policy.max_concurrent,
+            timeout_ms: self.policy.timeout.as_millis() as u64,
+            calls: load(&self.counters.calls),
+            successes: load(&self.counters.successes),
+            failures: load(&self.counters.failures),
+            timeouts: load(&self.counters.timeouts),
+            retries: load(&self.counters.retries),
+            rejected_open: load(&self.counters.rejected_open),
+            rejected_bulkhead: load(&self.counters.rejected_bulkhead),
+        }
+    }
+
+    /// Whether a call may go out now; claims the probe when half-open
+    fn admit(&self) -> bool {
+        let mut breaker = self.breaker.lock().unwrap_or_else(|e| e.into_inner());
+        match breaker.state(self.policy.open_for, Instant::now()) {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen if breaker.probing => false,
+            CircuitState::HalfOpen => {
+                breaker.probing = true;
+                true
+            }
+        }
+    }
+
+    fn record(&self, success: bool) {
+        let mut breaker = self.breaker.lock().unwrap_or_else(|e| e.into_inner());
+        breaker.probing = false;
+        if success {
+            breaker.consecutive_failures = 0;
+            breaker.opened_at = None;
+            return;
+        }
+        breaker.consecutive_failures += 1;
+        let was_probe = breaker.opened_at.is_some();
+        if was_probe || breaker.consecutive_failures >= self.policy.failure_threshold {
+            if !was_probe {
+                tracing::warn!(provider = %self.name, failures = breaker.consecutive_failures, "circuit opened");
+            }
+            breaker.opened_at = Some(Instant::now());
+        }
+    }
+
+    /// Run `op` under this provider's policy
+    ///
+    /// `retryable` decides which errors are worth another attempt; timeouts
+    /// always are. Only the final outcome of a call counts towards the
+    /// circuit breaker.
+    pub async fn call<T, E, F, Fut>(&self, retryable: impl Fn(&E) -> bool, mut op: F) -> Result<T, ResilienceError<E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        self.counters.calls.fetch_add(1, Ordering::Relaxed);
+
+        if !self.admit() {
+            self.counters.rejected_open.fetch_add(1, Ordering::Relaxed);
+            return Err(ResilienceError::CircuitOpen(self.name.clone()));
+        }
+        let Ok(_permit) = self.bulkhead.try_acquire() else {
+            // A rejected probe must not leave the breaker waiting on it
+            self.breaker.lock().unwrap_or_else(|e| e.into_inner()).probing = false;
+            self.counters.rejected_bulkhead.fetch_add(1, Ordering::Relaxed);
+            return Err(ResilienceError::BulkheadFull(self.name.clone()));
+        };
+
+        let mut attempt = 0;
+        let outcome = loop {
+            let error = match tokio::time::timeout(self.policy.timeout, op()).await {
+                Ok(Ok(value)) => break Ok(value),
+                Ok(Err(e)) if retryable(&e) => ResilienceError::Failed(e),
+                Ok(Err(e)) => break Err(ResilienceError::Failed(e)),
+                Err(_) => {
+                    self.counters.timeouts.fetch_add(1, Ordering::Relaxed);
+                    ResilienceError::Timeout(self.name.clone(), self.policy.timeout)
+                }
+            };
+            if attempt >= self.policy.max_retries {
+                break Err(error);
+            }
+            self.counters.retries.fetch_add(1, Ordering::Relaxed);
+            tokio::time::sleep(self.policy.backoff(attempt)).await;
+            attempt += 1;
+        };
+
+        match &outcome {
+            Ok(_) => self.counters.successes.fetch_add(1, Ordering::Relaxed),
+            Err(_) => self.counters.failures.fetch_add(1, Ordering::Relaxed),
+        };
+        self.record(outcome.is_ok());
+        outcome
+    }
+}
+
+/// Transport failures and 5xx/429 answers are worth retrying
+pub fn retryable_http(error: &reqwest::Error) -> bool {
+    error.is_timeout()
+        || error.is_connect()
+        || error
+            .status()
+            .is_some_and(|s| s.is_server_error() || s == reqwest::StatusCode::TOO_MANY_REQUESTS)
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Outbound provider counters and circuit states
+pub async fn outbound_stats_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<Vec<GuardStats>>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    Ok(Json(Guard::all_stats()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    fn fast_policy() -> ResiliencePolicy {
+        ResiliencePolicy {
+            timeout: Duration::from_millis(50),
+            max_retries: 2,
+            base_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+            max_concurrent: 1,
+            failure_threshold: 2,
+            open_for: Duration::from_millis(100),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_failures() {
+        let guard = Guard::new("flaky", fast_policy());
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<u32, _> = guard
+            .call(|_: &&str| true, || async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 | 1 => Err("reset"),
+                    n => Ok(n),
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 2);
+        let stats = guard.stats();
+        assert_eq!((stats.calls, stats.successes, stats.retries), (1, 1, 2));
+    }
+
+    #[tokio::test]
+    async fn test_permanent_errors_are_not_retried() {
+        let guard = Guard::new("strict", fast_policy());
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), _> = guard
+            .call(|_: &&str| false, || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err("bad request")
+            })
+            .await;
+
+        assert!(matches!(result, Err(ResilienceError::Failed("bad request"))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_slow_calls_time_out() {
+        let guard = Guard::new("slow", ResiliencePolicy { max_retries: 0, ..fast_policy() });
+
+        let result: Result<(), ResilienceError<&str>> = guard
+            .call(|_| true, || async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            })
+            .await;
+
+        assert!(matches!(result, Err(ResilienceError::Timeout(_, _))));
+        assert_eq!(guard.stats().timeouts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_opens_then_probes() {
+        let guard = Guard::new("down", ResiliencePolicy { max_retries: 0, ..fast_policy() });
+        let fail = || async { Err::<(), _>("down") };
+
+        for _ in 0..2 {
+            assert!(matches!(guard.call(|_| false, fail).await, Err(ResilienceError::Failed(_))));
+        }
+        assert_eq!(guard.stats().circuit, CircuitState::Open);
+        assert!(matches!(guard.call(|_| false, fail).await, Err(ResilienceError::CircuitOpen(_))));
+
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        assert_eq!(guard.stats().circuit, CircuitState::HalfOpen);
+        assert!(guard.call(|_: &&str| false, || async { Ok(()) }).await.is_ok());
+        assert_eq!(guard.stats().circuit, CircuitState::Closed);
+        assert_eq!(guard.stats().rejected_open, 1);
+    }
+
+    #[tokio::test]
+    async fn test_bulkhead_rejects_overflow() {
+        let guard = Arc::new(Guard::new("narrow", ResiliencePolicy { timeout: Duration::from_secs(1), ..fast_policy() }));
+        let busy = {
+            let guard = guard.clone();
+            tokio::spawn(async move {
+                guard
+                    .call(|_: &&str| false, || async {
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                        Ok(())
+                    })
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let overflow: Result<(), _> = guard.call(|_: &&str| false, || async { Ok(()) }).await;
+        assert!(matches!(overflow, Err(ResilienceError::BulkheadFull(_))));
+        assert!(busy.await.unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_backoff_is_jittered_within_cap() {
+        let policy = ResiliencePolicy::default();
+        for attempt in 0..10 {
+            assert!(policy.backoff(attempt) <= policy.max_backoff);
+        }
+        assert!(policy.backoff(0) <= policy.base_backoff);
+    }
+}
@@ -0,0 +1,1350 @@
+//! SCIM 2.0 provisioning (RFC 7643 / RFC 7644)
+//!
+//! Enterprise tenants point their identity provider at
+//! `/api/v1/scim/v2` so accounts and groups follow the directory instead of
+//! being managed by hand. Every request carries a bearer token an admin
+//! issued for one organization (`/api/v1/user/admin/orgs/{id}/scim-tokens`)
+//! and only ever sees that organization's members.
+//!
+//! - `Users`: creating one provisions an account in the organization that
+//!   signs in through the organization's SSO connection, linked by email or
+//!   user name on first sign-in. Setting `active` to false signs the user
+//!   out everywhere and refuses further logins. `DELETE` deprovisions: the
+//!   account is deactivated and hidden from SCIM, but kept, since its
+//!   projects and history belong to the organization.
+//! - `Groups`: organization groups and their members
+//!
+//! Platform admins are never exposed. Filters support `<attribute> eq
+//! "<value>"`; bulk operations, sorting and ETags are not supported.
+
+use axum::{
+    extract::{FromRequestParts, Path, Query, State},
+    http::{header, request::Parts, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use base64::{engine::general_purpose, Engine as _};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use sqlx::types::time::OffsetDateTime;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth;
+use crate::sec::hex;
+use crate::sec::{self, AppError, Claims};
+use crate::sso;
+use crate::state::AppState;
+
+const USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+const GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+const LIST_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+const PATCH_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:PatchOp";
+const ERROR_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:Error";
+const CONFIG_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:ServiceProviderConfig";
+
+const BASE_PATH: &str = "/api/v1/scim/v2";
+const TOKEN_PREFIX: &str = "scim_";
+const DEFAULT_PAGE_SIZE: i64 = 100;
+const MAX_PAGE_SIZE: i64 = 200;
+
+// ============================================================================
+// Responses and errors
+// ============================================================================
+
+/// JSON served as `application/scim+json`
+pub struct ScimJson<T>(pub T);
+
+impl<T: Serialize> IntoResponse for ScimJson<T> {
+    fn into_response(self) -> Response {
+        let mut response = Json(self.0).into_response();
+        response
+            .headers_mut()
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/scim+json"));
+        response
+    }
+}
+
+/// An error in the SCIM error schema
+#[derive(Debug)]
+pub struct ScimError {
+    status: StatusCode,
+    scim_type: Option<&'static str>,
+    detail: String,
+}
+
+impl ScimError {
+    fn new(status: StatusCode, scim_type: Option<&'static str>, detail: impl Into<String>) -> Self {
+        Self { status, scim_type, detail: detail.into() }
+    }
+
+    fn unauthorized() -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, None, "Authentication failed")
+    }
+
+    fn not_found(resource: &str, id: &str) -> Self {
+        Self::new(StatusCode::NOT_FOUND, None, format!("{} {} not found", resource, id))
+    }
+
+    fn invalid_value(detail: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, Some("invalidValue"), detail)
+    }
+
+    fn invalid_filter(detail: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, Some("invalidFilter"), detail)
+    }
+
+    fn invalid_path(path: &str) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, Some("invalidPath"), format!("Unsupported path '{}'", path))
+    }
+
+    fn internal() -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, None, "Internal error")
+    }
+}
+
+impl IntoResponse for ScimError {
+    fn into_response(self) -> Response {
+        let mut body = json!({
+            "schemas": [ERROR_SCHEMA],
+            "status": self.status.as_u16().to_string(),
+            "detail": self.detail,
+        });
+        if let Some(scim_type) = self.scim_type {
+            body["scimType"] = json!(scim_type);
+        }
+        (self.status, ScimJson(body)).into_response()
+    }
+}
+
+impl From<sqlx::Error> for ScimError {
+    fn from(e: sqlx::Error) -> Self {
+        if let Some(db) = e.as_database_error()
+            && db.is_unique_violation()
+        {
+            return Self::new(StatusCode::CONFLICT, Some("uniqueness"), "A resource with this name already exists");
+        }
+        tracing::error!(error = %e, "SCIM database error");
+        Self::internal()
+    }
+}
+
+impl From<AppError> for ScimError {
+    fn from(e: AppError) -> Self {
+        match e {
+            AppError::DbError(e) => e.into(),
+            other => {
+                tracing::error!(error = ?other, "SCIM request failed");
+                Self::internal()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Meta {
+    #[serde(rename = "resourceType")]
+    resource_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created: Option<String>,
+    location: String,
+}
+
+/// SCIM dates are RFC 3339 strings
+fn rfc3339(at: OffsetDateTime) -> Option<String> {
+    chrono::DateTime::from_timestamp(at.unix_timestamp(), at.nanosecond()).map(|at| at.to_rfc3339())
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListResponse<T> {
+    schemas: [&'static str; 1],
+    total_results: i64,
+    start_index: i64,
+    items_per_page: usize,
+    #[serde(rename = "Resources")]
+    resources: Vec<T>,
+}
+
+impl<T> ListResponse<T> {
+    fn new(total_results: i64, page: Page, resources: Vec<T>) -> Self {
+        Self {
+            schemas: [LIST_SCHEMA],
+            total_results,
+            start_index: page.start_index,
+            items_per_page: resources.len(),
+            resources,
+        }
+    }
+}
+
+fn parse_id(resource: &str, id: &str) -> Result<Uuid, ScimError> {
+    Uuid::parse_str(id).map_err(|_| ScimError::not_found(resource, id))
+}
+
+// ============================================================================
+// Authentication
+// ============================================================================
+
+/// The organization a SCIM request acts for, from its bearer token
+#[derive(Debug, Clone, Copy)]
+pub struct ScimClient {
+    pub org_id: Uuid,
+    pub token_id: Uuid,
+}
+
+fn hash_token(token: &str) -> String {
+    hex(&Sha256::digest(token.as_bytes()))
+}
+
+impl FromRequestParts<Arc<AppState>> for ScimClient {
+    type Rejection = ScimError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &Arc<AppState>) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|s| s.strip_prefix("Bearer "))
+            .filter(|t| t.starts_with(TOKEN_PREFIX))
+            .ok_or_else(ScimError::unauthorized)?;
+
+        let client = sqlx::query_as::<_, (Uuid, Uuid)>(
+            "UPDATE scim_tokens SET last_used_at = CURRENT_TIMESTAMP \
+             WHERE token_hash = $1 AND revoked_at IS NULL RETURNING org_id, id",
+        )
+        .bind(hash_token(token))
+        .fetch_optional(&state.pool)
+        .await?;
+
+        match client {
+            Some((org_id, token_id)) => Ok(Self { org_id, token_id }),
+            None => {
+                let (ip, _) = sec::extract_ip_and_ua(&parts.headers).unwrap_or_default();
+                sec::log_security_event("SCIM_AUTH_FAIL", None, ip.as_deref(), "Unknown or revoked token");
+                Err(ScimError::unauthorized())
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Listing and filtering
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ListQuery {
+    pub filter: Option<String>,
+    #[serde(rename = "startIndex")]
+    pub start_index: Option<i64>,
+    pub count: Option<i64>,
+}
+
+/// 1-based SCIM paging
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Page {
+    start_index: i64,
+    count: i64,
+}
+
+impl Page {
+    fn from_query(query: &ListQuery) -> Self {
+        Self {
+            start_index: query.start_index.unwrap_or(1).max(1),
+            count: query.count.unwrap_or(DEFAULT_PAGE_SIZE).clamp(0, MAX_PAGE_SIZE),
+        }
+    }
+
+    fn offset(&self) -> i64 {
+        self.start_index - 1
+    }
+}
+
+/// `<attribute> eq "<value>"`, the only filter identity providers need to
+/// find existing resources; the attribute is lowercased
+#[derive(Debug, PartialEq)]
+struct Filter {
+    attribute: String,
+    value: String,
+}
+
+fn parse_filter(filter: &str) -> Result<Filter, ScimError> {
+    let unsupported = || ScimError::invalid_filter(format!("Unsupported filter '{}'", filter));
+
+    let mut parts = filter.trim().splitn(3, char::is_whitespace);
+    let (Some(attribute), Some(operator), Some(operand)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(unsupported());
+    };
+    if !operator.eq_ignore_ascii_case("eq") {
+        return Err(unsupported());
+    }
+
+    let operand = operand.trim();
+    let value = if let Some(quoted) = operand.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        quoted.replace("\\\"", "\"").replace("\\\\", "\\")
+    } else if matches!(operand, "true" | "false") {
+        operand.to_string()
+    } else {
+        return Err(unsupported());
+    };
+
+    Ok(Filter { attribute: attribute.to_lowercase(), value })
+}
+
+/// SQL condition for a filter against `$2`; `$2 IS NULL` when unfiltered
+fn filter_condition(filter: Option<&Filter>, columns: &[(&str, &'static str)]) -> Result<&'static str, ScimError> {
+    let Some(filter) = filter else {
+        return Ok("$2::text IS NULL");
+    };
+    columns
+        .iter()
+        .find(|(attribute, _)| *attribute == filter.attribute)
+        .map(|(_, condition)| *condition)
+        .ok_or_else(|| ScimError::invalid_filter(format!("Filtering on '{}' is not supported", filter.attribute)))
+}
+
+// ============================================================================
+// Users
+// ============================================================================
+
+/// What SCIM manages of an account
+#[derive(Debug, Clone, PartialEq)]
+struct UserAttributes {
+    user_name: String,
+    external_id: Option<String>,
+    display_name: Option<String>,
+    email: Option<String>,
+    active: bool,
+}
+
+#[derive(sqlx::FromRow)]
+struct UserRow {
+    id: Uuid,
+    user_name: String,
+    external_id: Option<String>,
+    display_name: Option<String>,
+    email: Option<String>,
+    active: bool,
+    created_at: Option<OffsetDateTime>,
+}
+
+impl UserRow {
+    fn attributes(&self) -> UserAttributes {
+        UserAttributes {
+            user_name: self.user_name.clone(),
+            external_id: self.external_id.clone(),
+            display_name: self.display_name.clone(),
+            email: self.email.clone(),
+            active: self.active,
+        }
+    }
+}
+
+/// Accounts without a SCIM user name show their platform username
+const USER_COLUMNS: &str = "u.id, COALESCE(u.scim_user_name, u.username) AS user_name, u.external_id, \
+                            u.display_name, u.email, u.active, u.created_at";
+
+/// Members of the organization SCIM may see and change
+const USER_SCOPE: &str = "u.org_id = $1 AND NOT u.is_admin AND u.deprovisioned_at IS NULL";
+
+const USER_FILTERS: &[(&str, &str)] = &[
+    ("username", "lower(COALESCE(u.scim_user_name, u.username)) = lower($2)"),
+    ("externalid", "u.external_id = $2"),
+    ("emails", "lower(u.email) = lower($2)"),
+    ("emails.value", "lower(u.email) = lower($2)"),
+    ("active", "u.active = ($2 = 'true')"),
+];
+
+#[derive(Debug, Serialize)]
+struct Email {
+    value: String,
+    primary: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserResource {
+    schemas: [&'static str; 1],
+    id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+    user_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    emails: Vec<Email>,
+    active: bool,
+    meta: Meta,
+}
+
+impl From<UserRow> for UserResource {
+    fn from(row: UserRow) -> Self {
+        Self {
+            schemas: [USER_SCHEMA],
+            id: row.id,
+            external_id: row.external_id,
+            user_name: row.user_name,
+            display_name: row.display_name,
+            emails: row.email.map(|value| vec![Email { value, primary: true }]).unwrap_or_default(),
+            active: row.active,
+            meta: Meta {
+                resource_type: "User",
+                created: row.created_at.and_then(rfc3339),
+                location: format!("{}/Users/{}", BASE_PATH, row.id),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NameValue {
+    formatted: Option<String>,
+    given_name: Option<String>,
+    family_name: Option<String>,
+}
+
+impl NameValue {
+    fn display(&self) -> Option<String> {
+        self.formatted.clone().filter(|f| !f.trim().is_empty()).or_else(|| {
+            let joined = [self.given_name.as_deref(), self.family_name.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ");
+            (!joined.trim().is_empty()).then_some(joined)
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct EmailValue {
+    value: String,
+    #[serde(default)]
+    primary: bool,
+}
+
+/// The primary address, else the first
+fn primary_email(emails: Vec<EmailValue>) -> Option<String> {
+    let primary = emails.iter().position(|e| e.primary).unwrap_or(0);
+    emails.into_iter().nth(primary).map(|e| e.value)
+}
+
+/// Body of `POST` and `PUT /Users`; attributes we do not keep are ignored
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPayload {
+    user_name: String,
+    external_id: Option<String>,
+    display_name: Option<String>,
+    name: Option<NameValue>,
+    #[serde(default)]
+    emails: Vec<EmailValue>,
+    active: Option<bool>,
+}
+
+impl UserPayload {
+    fn into_attributes(self) -> Result<UserAttributes, ScimError> {
+        let attributes = UserAttributes {
+            display_name: self.display_name.or_else(|| self.name.as_ref().and_then(NameValue::display)),
+            email: primary_email(self.emails),
+            user_name: self.user_name,
+            external_id: self.external_id,
+            active: self.active.unwrap_or(true),
+        };
+        validate_user(&attributes)?;
+        Ok(attributes)
+    }
+}
+
+fn validate_user(attributes: &UserAttributes) -> Result<(), ScimError> {
+    let too_long = |value: &Option<String>| value.as_ref().is_some_and(|v| v.chars().count() > 255);
+    if attributes.user_name.trim().is_empty() || attributes.user_name.chars().count() > 255 {
+        return Err(ScimError::invalid_value("userName must be 1 to 255 characters"));
+    }
+    if too_long(&attributes.external_id) || too_long(&attributes.display_name) || too_long(&attributes.email) {
+        return Err(ScimError::invalid_value("Attributes are limited to 255 characters"));
+    }
+    Ok(())
+}
+
+fn string_value(path: &str, value: &Value) -> Result<String, ScimError> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| ScimError::invalid_value(format!("'{}' must be a string", path)))
+}
+
+/// Booleans arrive as JSON booleans or, from some providers, as strings
+fn bool_value(path: &str, value: &Value) -> Result<bool, ScimError> {
+    match value {
+        Value::Bool(b) => Ok(*b),
+        Value::String(s) if s.eq_ignore_ascii_case("true") => Ok(true),
+        Value::String(s) if s.eq_ignore_ascii_case("false") => Ok(false),
+        _ => Err(ScimError::invalid_value(format!("'{}' must be a boolean", path))),
+    }
+}
+
+/// Set one attribute from a PATCH `add` or `replace`; attributes we do not
+/// keep are accepted and ignored
+fn set_user_attribute(attributes: &mut UserAttributes, path: &str, value: &Value) -> Result<(), ScimError> {
+    let key = path.to_lowercase();
+    match key.as_str() {
+        "username" => attributes.user_name = string_value(path, value)?,
+        "externalid" => attributes.external_id = Some(string_value(path, value)?),
+        "displayname" | "name.formatted" => attributes.display_name = Some(string_value(path, value)?),
+        "active" => attributes.active = bool_value(path, value)?,
+        "name" => {
+            let name: NameValue = serde_json::from_value(value.clone())
+                .map_err(|_| ScimError::invalid_value("'name' must be an object"))?;
+            if let Some(display) = name.display() {
+                attributes.display_name = Some(display);
+            }
+        }
+        "emails" => {
+            let emails: Vec<EmailValue> = serde_json::from_value(value.clone())
+                .map_err(|_| ScimError::invalid_value("'emails' must be a list of addresses"))?;
+            attributes.email = primary_email(emails);
+        }
+        // e.g. emails[type eq "work"].value
+        _ if key.starts_with("emails[") && key.ends_with("].value") => {
+            attributes.email = Some(string_value(path, value)?)
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn remove_user_attribute(attributes: &mut UserAttributes, path: &str) -> Result<(), ScimError> {
+    let key = path.to_lowercase();
+    match key.as_str() {
+        "externalid" => attributes.external_id = None,
+        "displayname" | "name" | "name.formatted" => attributes.display_name = None,
+        "emails" => attributes.email = None,
+        _ if key.starts_with("emails[") => attributes.email = None,
+        "username" | "active" => {
+            return Err(ScimError::new(
+                StatusCode::BAD_REQUEST,
+                Some("mutability"),
+                format!("'{}' cannot be removed", path),
+            ))
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PatchRequest {
+    #[serde(default)]
+    schemas: Vec<String>,
+    #[serde(rename = "Operations")]
+    operations: Vec<PatchOperation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PatchOperation {
+    op: String,
+    path: Option<String>,
+    value: Option<Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PatchOp {
+    Add,
+    Replace,
+    Remove,
+}
+
+impl PatchOperation {
+    fn kind(&self) -> Result<PatchOp, ScimError> {
+        match self.op.to_lowercase().as_str() {
+            "add" => Ok(PatchOp::Add),
+            "replace" => Ok(PatchOp::Replace),
+            "remove" => Ok(PatchOp::Remove),
+            other => Err(ScimError::invalid_value(format!("Unknown operation '{}'", other))),
+        }
+    }
+
+    fn value(&self) -> Result<&Value, ScimError> {
+        self.value
+            .as_ref()
+            .ok_or_else(|| ScimError::invalid_value(format!("'{}' needs a value", self.op)))
+    }
+}
+
+impl PatchRequest {
+    fn check_schema(&self) -> Result<(), ScimError> {
+        if self.schemas.is_empty() || self.schemas.iter().any(|s| s == PATCH_SCHEMA) {
+            Ok(())
+        } else {
+            Err(ScimError::invalid_value("Expected a PatchOp message"))
+        }
+    }
+}
+
+fn apply_user_patch(attributes: &mut UserAttributes, request: &PatchRequest) -> Result<(), ScimError> {
+    request.check_schema()?;
+    for operation in &request.operations {
+        match (operation.kind()?, operation.path.as_deref()) {
+            (PatchOp::Remove, Some(path)) => remove_user_attribute(attributes, path)?,
+            (PatchOp::Remove, None) => return Err(ScimError::new(StatusCode::BAD_REQUEST, Some("noTarget"), "remove needs a path")),
+            (_, Some(path)) => set_user_attribute(attributes, path, operation.value()?)?,
+            (_, None) => {
+                let Value::Object(values) = operation.value()? else {
+                    return Err(ScimError::invalid_value("A patch without a path needs an object value"));
+                };
+                for (path, value) in values {
+                    set_user_attribute(attributes, path, value)?;
+                }
+            }
+        }
+    }
+    validate_user(attributes)
+}
+
+async fn load_user(state: &AppState, client: ScimClient, id: &str) -> Result<UserRow, ScimError> {
+    let user_id = parse_id("User", id)?;
+    sqlx::query_as::<_, UserRow>(&format!("SELECT {USER_COLUMNS} FROM users u WHERE {USER_SCOPE} AND u.id = $2"))
+        .bind(client.org_id)
+        .bind(user_id)
+        .fetch_optional(&state.pool)
+        .await?
+        .ok_or_else(|| ScimError::not_found("User", id))
+}
+
+/// Store `after` over `before`, signing the user out when deactivated
+async fn save_user(
+    state: &AppState,
+    client: ScimClient,
+    id: Uuid,
+    before: &UserAttributes,
+    after: &UserAttributes,
+) -> Result<UserRow, ScimError> {
+    let row = sqlx::query_as::<_, UserRow>(&format!(
+        "UPDATE users u SET scim_user_name = $3, external_id = $4, display_name = $5, email = $6, active = $7 \
+         WHERE {USER_SCOPE} AND u.id = $2 RETURNING {USER_COLUMNS}"
+    ))
+    .bind(client.org_id)
+    .bind(id)
+    .bind(&after.user_name)
+    .bind(&after.external_id)
+    .bind(&after.display_name)
+    .bind(&after.email)
+    .bind(after.active)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or_else(|| ScimError::not_found("User", &id.to_string()))?;
+
+    if before.active && !after.active {
+        auth::revoke_user_sessions(state, id).await?;
+        sec::log_security_event("SCIM_USER_DEACTIVATE", Some(&row.user_name), None, &client.org_id.to_string());
+    } else if !before.active && after.active {
+        sec::log_security_event("SCIM_USER_REACTIVATE", Some(&row.user_name), None, &client.org_id.to_string());
+    }
+    Ok(row)
+}
+
+/// GET /api/v1/scim/v2/Users
+pub async fn list_users_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Query(query): Query<ListQuery>,
+) -> Result<ScimJson<ListResponse<UserResource>>, ScimError> {
+    let filter = query.filter.as_deref().map(parse_filter).transpose()?;
+    let condition = filter_condition(filter.as_ref(), USER_FILTERS)?;
+    let value = filter.as_ref().map(|f| f.value.as_str());
+    let page = Page::from_query(&query);
+
+    let total: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM users u WHERE {USER_SCOPE} AND {condition}"))
+        .bind(client.org_id)
+        .bind(value)
+        .fetch_one(&state.pool)
+        .await?;
+    let rows = sqlx::query_as::<_, UserRow>(&format!(
+        "SELECT {USER_COLUMNS} FROM users u WHERE {USER_SCOPE} AND {condition} \
+         ORDER BY u.created_at, u.id LIMIT $3 OFFSET $4"
+    ))
+    .bind(client.org_id)
+    .bind(value)
+    .bind(page.count)
+    .bind(page.offset())
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(ScimJson(ListResponse::new(total, page, rows.into_iter().map(Into::into).collect())))
+}
+
+/// POST /api/v1/scim/v2/Users
+pub async fn create_user_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Json(payload): Json<UserPayload>,
+) -> Result<(StatusCode, ScimJson<UserResource>), ScimError> {
+    let attributes = payload.into_attributes()?;
+
+    let mut tx = state.pool.begin().await?;
+    let base = sso::username_candidate(Some(&attributes.user_name));
+    let (user_id, _) = sso::create_provisioned_user(&mut tx, &base, client.org_id).await?;
+    let row = sqlx::query_as::<_, UserRow>(&format!(
+        "UPDATE users u SET scim_user_name = $2, external_id = $3, display_name = $4, email = $5, active = $6 \
+         WHERE u.id = $1 RETURNING {USER_COLUMNS}"
+    ))
+    .bind(user_id)
+    .bind(&attributes.user_name)
+    .bind(&attributes.external_id)
+    .bind(&attributes.display_name)
+    .bind(&attributes.email)
+    .bind(attributes.active)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    sec::log_security_event("SCIM_USER_CREATE", Some(&row.user_name), None, &client.org_id.to_string());
+    Ok((StatusCode::CREATED, ScimJson(row.into())))
+}
+
+/// GET /api/v1/scim/v2/Users/{id}
+pub async fn get_user_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Path(id): Path<String>,
+) -> Result<ScimJson<UserResource>, ScimError> {
+    Ok(ScimJson(load_user(&state, client, &id).await?.into()))
+}
+
+/// PUT /api/v1/scim/v2/Users/{id}
+pub async fn replace_user_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Path(id): Path<String>,
+    Json(payload): Json<UserPayload>,
+) -> Result<ScimJson<UserResource>, ScimError> {
+    let current = load_user(&state, client, &id).await?;
+    let after = payload.into_attributes()?;
+    let row = save_user(&state, client, current.id, &current.attributes(), &after).await?;
+    Ok(ScimJson(row.into()))
+}
+
+/// PATCH /api/v1/scim/v2/Users/{id}
+pub async fn patch_user_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Path(id): Path<String>,
+    Json(request): Json<PatchRequest>,
+) -> Result<ScimJson<UserResource>, ScimError> {
+    let current = load_user(&state, client, &id).await?;
+    let before = current.attributes();
+    let mut after = before.clone();
+    apply_user_patch(&mut after, &request)?;
+
+    let row = save_user(&state, client, current.id, &before, &after).await?;
+    Ok(ScimJson(row.into()))
+}
+
+/// DELETE /api/v1/scim/v2/Users/{id}
+///
+/// Deprovisions: the account is deactivated, leaves its groups and
+/// disappears from SCIM, but its data stays with the organization.
+pub async fn delete_user_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ScimError> {
+    let current = load_user(&state, client, &id).await?;
+
+    let mut tx = state.pool.begin().await?;
+    sqlx::query("UPDATE users SET active = FALSE, deprovisioned_at = CURRENT_TIMESTAMP WHERE id = $1")
+        .bind(current.id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM org_group_members WHERE user_id = $1")
+        .bind(current.id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    auth::revoke_user_sessions(&state, current.id).await?;
+    sec::log_security_event("SCIM_USER_DEPROVISION", Some(&current.user_name), None, &client.org_id.to_string());
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Groups
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct GroupAttributes {
+    display_name: String,
+    external_id: Option<String>,
+    members: BTreeSet<Uuid>,
+}
+
+#[derive(sqlx::FromRow)]
+struct GroupRow {
+    id: Uuid,
+    display_name: String,
+    external_id: Option<String>,
+    created_at: OffsetDateTime,
+}
+
+const GROUP_COLUMNS: &str = "g.id, g.display_name, g.external_id, g.created_at";
+
+const GROUP_FILTERS: &[(&str, &str)] = &[
+    ("displayname", "lower(g.display_name) = lower($2)"),
+    ("externalid", "g.external_id = $2"),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct GroupMember {
+    value: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    display: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupResource {
+    schemas: [&'static str; 1],
+    id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+    display_name: String,
+    members: Vec<GroupMember>,
+    meta: Meta,
+}
+
+impl GroupResource {
+    fn new(row: GroupRow, members: Vec<GroupMember>) -> Self {
+        Self {
+            schemas: [GROUP_SCHEMA],
+            id: row.id,
+            external_id: row.external_id,
+            display_name: row.display_name,
+            members,
+            meta: Meta {
+                resource_type: "Group",
+                created: rfc3339(row.created_at),
+                location: format!("{}/Groups/{}", BASE_PATH, row.id),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPayload {
+    display_name: String,
+    external_id: Option<String>,
+    #[serde(default)]
+    members: Vec<GroupMember>,
+}
+
+impl GroupPayload {
+    fn into_attributes(self) -> Result<GroupAttributes, ScimError> {
+        let attributes = GroupAttributes {
+            display_name: self.display_name,
+            external_id: self.external_id,
+            members: self.members.into_iter().map(|m| m.value).collect(),
+        };
+        validate_group(&attributes)?;
+        Ok(attributes)
+    }
+}
+
+fn validate_group(attributes: &GroupAttributes) -> Result<(), ScimError> {
+    if attributes.display_name.trim().is_empty() || attributes.display_name.chars().count() > 255 {
+        return Err(ScimError::invalid_value("displayName must be 1 to 255 characters"));
+    }
+    Ok(())
+}
+
+fn member_ids(value: &Value) -> Result<Vec<Uuid>, ScimError> {
+    let members: Vec<GroupMember> = match value {
+        Value::Array(_) => serde_json::from_value(value.clone()),
+        _ => serde_json::from_value(Value::Array(vec![value.clone()])),
+    }
+    .map_err(|_| ScimError::invalid_value("'members' must be a list of {\"value\": <user id>}"))?;
+    Ok(members.into_iter().map(|m| m.value).collect())
+}
+
+/// The member a `members[value eq "<id>"]` path selects
+fn member_selector(path: &str) -> Result<Option<Uuid>, ScimError> {
+    let Some(selector) = path.strip_prefix("members[").and_then(|s| s.strip_suffix(']')) else {
+        return Ok(None);
+    };
+    let filter = parse_filter(selector)?;
+    if filter.attribute != "value" {
+        return Err(ScimError::invalid_path(path));
+    }
+    Uuid::parse_str(&filter.value)
+        .map(Some)
+        .map_err(|_| ScimError::invalid_value(format!("'{}' is not a member id", filter.value)))
+}
+
+fn set_group_attribute(attributes: &mut GroupAttributes, op: PatchOp, path: &str, value: &Value) -> Result<(), ScimError> {
+    match path.to_lowercase().as_str() {
+        "displayname" => attributes.display_name = string_value(path, value)?,
+        "externalid" => attributes.external_id = Some(string_value(path, value)?),
+        "members" => {
+            if op == PatchOp::Replace {
+                attributes.members.clear();
+            }
+            attributes.members.extend(member_ids(value)?);
+        }
+        _ => return Err(ScimError::invalid_path(path)),
+    }
+    Ok(())
+}
+
+fn apply_group_patch(attributes: &mut GroupAttributes, request: &PatchRequest) -> Result<(), ScimError> {
+    request.check_schema()?;
+    for operation in &request.operations {
+        let op = operation.kind()?;
+        match (op, operation.path.as_deref()) {
+            (PatchOp::Remove, Some(path)) if path.eq_ignore_ascii_case("members") => match &operation.value {
+                Some(value) => {
+                    for id in member_ids(value)? {
+                        attributes.members.remove(&id);
+                    }
+                }
+                None => attributes.members.clear(),
+            },
+            (PatchOp::Remove, Some(path)) if path.eq_ignore_ascii_case("externalid") => attributes.external_id = None,
+            (PatchOp::Remove, Some(path)) => match member_selector(path)? {
+                Some(id) => {
+                    attributes.members.remove(&id);
+                }
+                None => return Err(ScimError::invalid_path(path)),
+            },
+            (PatchOp::Remove, None) => return Err(ScimError::new(StatusCode::BAD_REQUEST, Some("noTarget"), "remove needs a path")),
+            (_, Some(path)) => set_group_attribute(attributes, op, path, operation.value()?)?,
+            (_, None) => {
+                let Value::Object(values) = operation.value()? else {
+                    return Err(ScimError::invalid_value("A patch without a path needs an object value"));
+                };
+                for (path, value) in values {
+                    set_group_attribute(attributes, op, path, value)?;
+                }
+            }
+        }
+    }
+    validate_group(attributes)
+}
+
+async fn load_group(state: &AppState, client: ScimClient, id: &str) -> Result<GroupRow, ScimError> {
+    let group_id = parse_id("Group", id)?;
+    sqlx::query_as::<_, GroupRow>(&format!("SELECT {GROUP_COLUMNS} FROM org_groups g WHERE g.org_id = $1 AND g.id = $2"))
+        .bind(client.org_id)
+        .bind(group_id)
+        .fetch_optional(&state.pool)
+        .await?
+        .ok_or_else(|| ScimError::not_found("Group", id))
+}
+
+async fn load_members(state: &AppState, group_id: Uuid) -> Result<Vec<GroupMember>, ScimError> {
+    Ok(sqlx::query_as::<_, GroupMember>(
+        "SELECT u.id AS value, COALESCE(u.display_name, u.scim_user_name, u.username) AS display \
+         FROM org_group_members m JOIN users u ON u.id = m.user_id WHERE m.group_id = $1 ORDER BY u.created_at, u.id",
+    )
+    .bind(group_id)
+    .fetch_all(&state.pool)
+    .await?)
+}
+
+/// Store a group's name and member list; members must be visible to SCIM
+/// in the same organization
+async fn save_group(state: &AppState, client: ScimClient, id: Option<Uuid>, attributes: &GroupAttributes) -> Result<GroupResource, ScimError> {
+    let members: Vec<Uuid> = attributes.members.iter().copied().collect();
+
+    let mut tx = state.pool.begin().await?;
+    let row = match id {
+        Some(id) => sqlx::query_as::<_, GroupRow>(&format!(
+            "UPDATE org_groups g SET display_name = $3, external_id = $4, updated_at = CURRENT_TIMESTAMP \
+             WHERE g.org_id = $1 AND g.id = $2 RETURNING {GROUP_COLUMNS}"
+        ))
+        .bind(client.org_id)
+        .bind(id)
+        .bind(&attributes.display_name)
+        .bind(&attributes.external_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| ScimError::not_found("Group", &id.to_string()))?,
+        None => sqlx::query_as::<_, GroupRow>(&format!(
+            "INSERT INTO org_groups AS g (org_id, display_name, external_id) VALUES ($1, $2, $3) RETURNING {GROUP_COLUMNS}"
+        ))
+        .bind(client.org_id)
+        .bind(&attributes.display_name)
+        .bind(&attributes.external_id)
+        .fetch_one(&mut *tx)
+        .await?,
+    };
+
+    sqlx::query("DELETE FROM org_group_members WHERE group_id = $1")
+        .bind(row.id)
+        .execute(&mut *tx)
+        .await?;
+    let added = sqlx::query(&format!(
+        "INSERT INTO org_group_members (group_id, user_id) \
+         SELECT $2, u.id FROM users u WHERE {USER_SCOPE} AND u.id = ANY($3)"
+    ))
+    .bind(client.org_id)
+    .bind(row.id)
+    .bind(&members)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    if added != members.len() as u64 {
+        return Err(ScimError::invalid_value("Members must be users of this organization"));
+    }
+    tx.commit().await?;
+
+    let members = load_members(state, row.id).await?;
+    Ok(GroupResource::new(row, members))
+}
+
+/// GET /api/v1/scim/v2/Groups
+pub async fn list_groups_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Query(query): Query<ListQuery>,
+) -> Result<ScimJson<ListResponse<GroupResource>>, ScimError> {
+    let filter = query.filter.as_deref().map(parse_filter).transpose()?;
+    let condition = filter_condition(filter.as_ref(), GROUP_FILTERS)?;
+    let value = filter.as_ref().map(|f| f.value.as_str());
+    let page = Page::from_query(&query);
+
+    let total: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM org_groups g WHERE g.org_id = $1 AND {condition}"))
+        .bind(client.org_id)
+        .bind(value)
+        .fetch_one(&state.pool)
+        .await?;
+    let rows = sqlx::query_as::<_, GroupRow>(&format!(
+        "SELECT {GROUP_COLUMNS} FROM org_groups g WHERE g.org_id = $1 AND {condition} \
+         ORDER BY g.display_name, g.id LIMIT $3 OFFSET $4"
+    ))
+    .bind(client.org_id)
+    .bind(value)
+    .bind(page.count)
+    .bind(page.offset())
+    .fetch_all(&state.pool)
+    .await?;
+
+    let mut groups = Vec::with_capacity(rows.len());
+    for row in rows {
+        let members = load_members(&state, row.id).await?;
+        groups.push(GroupResource::new(row, members));
+    }
+    Ok(ScimJson(ListResponse::new(total, page, groups)))
+}
+
+/// POST /api/v1/scim/v2/Groups
+pub async fn create_group_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Json(payload): Json<GroupPayload>,
+) -> Result<(StatusCode, ScimJson<GroupResource>), ScimError> {
+    let attributes = payload.into_attributes()?;
+    let group = save_group(&state, client, None, &attributes).await?;
+    sec::log_security_event("SCIM_GROUP_CREATE", None, None, &format!("{} ({})", group.display_name, client.org_id));
+    Ok((StatusCode::CREATED, ScimJson(group)))
+}
+
+/// GET /api/v1/scim/v2/Groups/{id}
+pub async fn get_group_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Path(id): Path<String>,
+) -> Result<ScimJson<GroupResource>, ScimError> {
+    let row = load_group(&state, client, &id).await?;
+    let members = load_members(&state, row.id).await?;
+    Ok(ScimJson(GroupResource::new(row, members)))
+}
+
+/// PUT /api/v1/scim/v2/Groups/{id}
+pub async fn replace_group_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Path(id): Path<String>,
+    Json(payload): Json<GroupPayload>,
+) -> Result<ScimJson<GroupResource>, ScimError> {
+    let group_id = parse_id("Group", &id)?;
+    let attributes = payload.into_attributes()?;
+    Ok(ScimJson(save_group(&state, client, Some(group_id), &attributes).await?))
+}
+
+/// PATCH /api/v1/scim/v2/Groups/{id}
+pub async fn patch_group_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Path(id): Path<String>,
+    Json(request): Json<PatchRequest>,
+) -> Result<ScimJson<GroupResource>, ScimError> {
+    let row = load_group(&state, client, &id).await?;
+    let mut attributes = GroupAttributes {
+        members: load_members(&state, row.id).await?.into_iter().map(|m| m.value).collect(),
+        display_name: row.display_name,
+        external_id: row.external_id,
+    };
+    apply_group_patch(&mut attributes, &request)?;
+    Ok(ScimJson(save_group(&state, client, Some(row.id), &attributes).await?))
+}
+
+/// DELETE /api/v1/scim/v2/Groups/{id}
+pub async fn delete_group_handler(
+    State(state): State<Arc<AppState>>,
+    client: ScimClient,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ScimError> {
+    let group_id = parse_id("Group", &id)?;
+    let deleted = sqlx::query("DELETE FROM org_groups WHERE org_id = $1 AND id = $2")
+        .bind(client.org_id)
+        .bind(group_id)
+        .execute(&state.pool)
+        .await?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(ScimError::not_found("Group", &id));
+    }
+    sec::log_security_event("SCIM_GROUP_DELETE", None, None, &format!("{} ({})", id, client.org_id));
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/v1/scim/v2/ServiceProviderConfig
+pub async fn service_provider_config_handler() -> ScimJson<Value> {
+    ScimJson(json!({
+        "schemas": [CONFIG_SCHEMA],
+        "patch": {"supported": true},
+        "bulk": {"supported": false, "maxOperations": 0, "maxPayloadSize": 0},
+        "filter": {"supported": true, "maxResults": MAX_PAGE_SIZE},
+        "changePassword": {"supported": false},
+        "sort": {"supported": false},
+        "etag": {"supported": false},
+        "authenticationSchemes": [{
+            "type": "oauthbearertoken",
+            "name": "OAuth Bearer Token",
+            "description": "Per-organization token issued by a platform admin",
+            "primary": true,
+        }],
+    }))
+}
+
+// ============================================================================
+// Token administration
+// ============================================================================
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct ScimToken {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub description: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub last_used_at: Option<OffsetDateTime>,
+    pub revoked_at: Option<OffsetDateTime>,
+}
+
+const TOKEN_COLUMNS: &str = "id, org_id, description, created_at, last_used_at, revoked_at";
+
+#[derive(Debug, Deserialize)]
+pub struct CreateTokenPayload {
+    pub description: Option<String>,
+}
+
+/// A new token; the secret is shown this once
+#[derive(Debug, Serialize)]
+pub struct IssuedToken {
+    #[serde(flatten)]
+    pub token: ScimToken,
+    pub secret: String,
+}
+
+fn new_token() -> String {
+    let bytes: [u8; 32] = rand::rng().random();
+    format!("{}{}", TOKEN_PREFIX, general_purpose::URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// GET /api/v1/user/admin/orgs/{id}/scim-tokens
+pub async fn list_tokens_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(org_id): Path<Uuid>,
+) -> Result<Json<Vec<ScimToken>>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+
+    let tokens = sqlx::query_as::<_, ScimToken>(&format!(
+        "SELECT {TOKEN_COLUMNS} FROM scim_tokens WHERE org_id = $1 ORDER BY created_at DESC"
+    ))
+    .bind(org_id)
+    .fetch_all(&state.pool)
+    .await?;
+    Ok(Json(tokens))
+}
+
+/// POST /api/v1/user/admin/orgs/{id}/scim-tokens
+pub async fn create_token_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(org_id): Path<Uuid>,
+    Json(payload): Json<CreateTokenPayload>,
+) -> Result<(StatusCode, Json<IssuedToken>), AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    let description = payload.description.map(|d| d.chars().take(100).collect::<String>());
+
+    let secret = new_token();
+    let token = sqlx::query_as::<_, ScimToken>(&format!(
+        "INSERT INTO scim_tokens (org_id, token_hash, description) \
+         SELECT id, $2, $3 FROM organizations WHERE id = $1 RETURNING {TOKEN_COLUMNS}"
+    ))
+    .bind(org_id)
+    .bind(hash_token(&secret))
+    .bind(&description)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("SCIM_TOKEN_CREATE", Some(&claims.username), None, &org_id.to_string());
+    Ok((StatusCode::CREATED, Json(IssuedToken { token, secret })))
+}
+
+/// DELETE /api/v1/user/admin/scim-tokens/{id}
+pub async fn revoke_token_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+
+    let revoked = sqlx::query("UPDATE scim_tokens SET revoked_at = CURRENT_TIMESTAMP WHERE id = $1 AND revoked_at IS NULL")
+        .bind(id)
+        .execute(&state.pool)
+        .await?
+        .rows_affected();
+    if revoked == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("SCIM_TOKEN_REVOKE", Some(&claims.username), None, &id.to_string());
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(operations: Value) -> PatchRequest {
+        serde_json::from_value(json!({"schemas": [PATCH_SCHEMA], "Operations": operations})).unwrap()
+    }
+
+    fn user() -> UserAttributes {
+        UserAttributes {
+            user_name: "jane@acme.com".to_string(),
+            external_id: None,
+            display_name: Some("Jane Doe".to_string()),
+            email: Some("jane@acme.com".to_string()),
+            active: true,
+        }
+    }
+
+    #[test]
+    fn test_filter_parsing() {
+        assert_eq!(
+            parse_filter(r#"userName eq "jane@acme.com""#).unwrap(),
+            Filter { attribute: "username".to_string(), value: "jane@acme.com".to_string() }
+        );
+        assert_eq!(parse_filter(r#"displayName EQ "R&D \"core\"""#).unwrap().value, r#"R&D "core""#);
+        assert_eq!(parse_filter("active eq true").unwrap().value, "true");
+        assert!(parse_filter(r#"userName co "jane""#).is_err());
+        assert!(parse_filter("userName eq jane").is_err());
+
+        assert_eq!(filter_condition(None, USER_FILTERS).unwrap(), "$2::text IS NULL");
+        assert!(filter_condition(Some(&parse_filter(r#"title eq "x""#).unwrap()), USER_FILTERS).is_err());
+    }
+
+    #[test]
+    fn test_user_payload() {
+        let payload: UserPayload = serde_json::from_value(json!({
+            "schemas": [USER_SCHEMA],
+            "userName": "r.smith@acme.com",
+            "name": {"givenName": "Rob", "familyName": "Smith"},
+            "emails": [{"value": "rob@home.net"}, {"value": "r.smith@acme.com", "primary": true}],
+            "title": "Site engineer",
+        }))
+        .unwrap();
+        let attributes = payload.into_attributes().unwrap();
+
+        assert_eq!(attributes.display_name.as_deref(), Some("Rob Smith"));
+        assert_eq!(attributes.email.as_deref(), Some("r.smith@acme.com"));
+        assert!(attributes.active);
+    }
+
+    #[test]
+    fn test_user_patch() {
+        // Azure AD style: capitalised op, string boolean, filtered email path
+        let mut attributes = user();
+        let request = patch(json!([
+            {"op": "Replace", "path": "active", "value": "False"},
+            {"op": "replace", "path": "emails[type eq \"work\"].value", "value": "jane.doe@acme.com"},
+        ]));
+        apply_user_patch(&mut attributes, &request).unwrap();
+        assert!(!attributes.active);
+        assert_eq!(attributes.email.as_deref(), Some("jane.doe@acme.com"));
+
+        // Okta style: no path, object value
+        let request = patch(json!([{"op": "replace", "value": {"active": true, "displayName": "Jane D."}}]));
+        apply_user_patch(&mut attributes, &request).unwrap();
+        assert!(attributes.active);
+        assert_eq!(attributes.display_name.as_deref(), Some("Jane D."));
+
+        let request = patch(json!([{"op": "remove", "path": "externalId"}, {"op": "remove", "path": "displayName"}]));
+        apply_user_patch(&mut attributes, &request).unwrap();
+        assert_eq!(attributes.display_name, None);
+
+        assert!(apply_user_patch(&mut attributes, &patch(json!([{"op": "remove", "path": "userName"}]))).is_err());
+        assert!(apply_user_patch(&mut attributes, &patch(json!([{"op": "move", "path": "active"}]))).is_err());
+    }
+
+    #[test]
+    fn test_group_patch() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut group = GroupAttributes {
+            display_name: "Estimators".to_string(),
+            external_id: None,
+            members: BTreeSet::from([a]),
+        };
+
+        let request = patch(json!([{"op": "add", "path": "members", "value": [{"value": b}, {"value": c}]}]));
+        apply_group_patch(&mut group, &request).unwrap();
+        assert_eq!(group.members, BTreeSet::from([a, b, c]));
+
+        let request = patch(json!([{"op": "remove", "path": format!("members[value eq \"{}\"]", a)}]));
+        apply_group_patch(&mut group, &request).unwrap();
+        assert_eq!(group.members, BTreeSet::from([b, c]));
+
+        let request = patch(json!([{"op": "replace", "value": {"displayName": "Estimating", "members": [{"value": a}]}}]));
+        apply_group_patch(&mut group, &request).unwrap();
+        assert_eq!(group.display_name, "Estimating");
+        assert_eq!(group.members, BTreeSet::from([a]));
+
+        apply_group_patch(&mut group, &patch(json!([{"op": "remove", "path": "members"}]))).unwrap();
+        assert!(group.members.is_empty());
+        assert!(apply_group_patch(&mut group, &patch(json!([{"op": "add", "path": "owners", "value": []}]))).is_err());
+    }
+
+    #[test]
+    fn test_error_body() {
+        let response = ScimError::invalid_filter("Unsupported filter").into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/scim+json");
+    }
+
+    #[test]
+    fn test_page_bounds() {
+        let query = ListQuery { filter: None, start_index: Some(0), count: Some(1000) };
+        assert_eq!(Page::from_query(&query), Page { start_index: 1, count: MAX_PAGE_SIZE });
+        let query = ListQuery { filter: None, start_index: Some(11), count: None };
+        assert_eq!(Page::from_query(&query).offset(), 10);
+    }
+}
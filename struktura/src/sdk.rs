@@ -0,0 +1,600 @@
+//! Client SDK generation
+//!
+//! Emits TypeScript and Python definitions for the calculus API. Model
+//! shapes are inferred from what serde actually produces for the demo
+//! catalogue's requests and responses and for each tier's catalogue, and
+//! calculator ids come from registry metadata - so the clients follow the
+//! Rust types instead of a hand-kept copy.
+//!
+//! `cargo run --bin generate_sdk` rewrites the checked-in files; the
+//! contract test below fails whenever they are stale.
+
+use crate::calculus::demo::DemoCatalogue;
+use crate::calculus::recommendation::Tier;
+use crate::calculus::{beginner, contractor, engineer};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// Generated TypeScript module, relative to the crate root
+pub const TYPESCRIPT_PATH: &str = "static/src/lib/generated/struktura-api.ts";
+/// Generated Python module, relative to the crate root
+pub const PYTHON_PATH: &str = "sdk/python/struktura_client.py";
+
+/// Object fields that are string-keyed maps rather than fixed structs
+const MAP_FIELDS: &[&str] = &[
+    "dimensions", "additional", "extended_parameters", "formula_params", "tags", "keywords", "by_category",
+    "expected_results",
+];
+
+const TIERS: [Tier; 3] = [Tier::Beginner, Tier::Engineer, Tier::Contractor];
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in",
+    "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+// =============================================================================
+// SHAPES
+// =============================================================================
+
+/// JSON shape observed in serialized values
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// Nothing observed yet (only nulls or empty arrays)
+    Unknown,
+    /// Conflicting observations
+    Any,
+    Bool,
+    Number,
+    String,
+    Array(Box<Shape>),
+    Map(Box<Shape>),
+    Object(BTreeMap<String, Field>),
+    Nullable(Box<Shape>),
+    /// Reference to a type emitted separately
+    Named(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub shape: Shape,
+    /// Absent from at least one observed value
+    pub optional: bool,
+}
+
+impl Shape {
+    pub fn infer(value: &Value) -> Shape {
+        Self::infer_as(value, false)
+    }
+
+    fn infer_as(value: &Value, as_map: bool) -> Shape {
+        match value {
+            Value::Null => Shape::Nullable(Box::new(Shape::Unknown)),
+            Value::Bool(_) => Shape::Bool,
+            Value::Number(_) => Shape::Number,
+            Value::String(_) => Shape::String,
+            Value::Array(items) => Shape::Array(Box::new(Self::infer_all(items.iter()))),
+            Value::Object(fields) if as_map => Shape::Map(Box::new(Self::infer_all(fields.values()))),
+            Value::Object(fields) => Shape::Object(
+                fields
+                    .iter()
+                    .map(|(key, v)| {
+                        let shape = Self::infer_as(v, MAP_FIELDS.contains(&key.as_str()));
+                        (key.clone(), Field { shape, optional: false })
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Merged shape of several values at the same position
+    pub fn infer_all<'a>(values: impl Iterator<Item = &'a Value>) -> Shape {
+        values.map(Shape::infer).fold(Shape::Unknown, Shape::merge)
+    }
+
+    /// Combine two observations of the same position
+    pub fn merge(self, other: Shape) -> Shape {
+        match (self, other) {
+            (Shape::Unknown, s) | (s, Shape::Unknown) => s,
+            (Shape::Nullable(a), Shape::Nullable(b)) => Shape::Nullable(Box::new(a.merge(*b))),
+            (Shape::Nullable(a), s) | (s, Shape::Nullable(a)) => Shape::Nullable(Box::new(a.merge(s))),
+            (Shape::Array(a), Shape::Array(b)) => Shape::Array(Box::new(a.merge(*b))),
+            (Shape::Map(a), Shape::Map(b)) => Shape::Map(Box::new(a.merge(*b))),
+            (Shape::Object(a), Shape::Object(b)) => Shape::Object(merge_fields(a, b)),
+            (a, b) if a == b => a,
+            _ => Shape::Any,
+        }
+    }
+}
+
+/// Fields missing on either side become optional
+fn merge_fields(mut a: BTreeMap<String, Field>, mut b: BTreeMap<String, Field>) -> BTreeMap<String, Field> {
+    let keys: BTreeSet<String> = a.keys().chain(b.keys()).cloned().collect();
+    keys.into_iter()
+        .map(|key| {
+            let field = match (a.remove(&key), b.remove(&key)) {
+                (Some(x), Some(y)) => Field { shape: x.shape.merge(y.shape), optional: x.optional || y.optional },
+                (Some(x), None) | (None, Some(x)) => Field { shape: x.shape, optional: true },
+                (None, None) => unreachable!("key comes from one of the maps"),
+            };
+            (key, field)
+        })
+        .collect()
+}
+
+// =============================================================================
+// SPEC
+// =============================================================================
+
+/// An object type to emit
+#[derive(Debug, Clone)]
+pub struct Model {
+    pub name: String,
+    pub fields: BTreeMap<String, Field>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParameterInfo {
+    pub path: String,
+    pub unit: String,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CalculatorInfo {
+    pub tier: Tier,
+    pub id: String,
+    pub name: String,
+    pub parameters: Vec<ParameterInfo>,
+}
+
+/// Everything the emitters need, in emission order
+#[derive(Debug, Clone)]
+pub struct SdkSpec {
+    pub calculators: Vec<CalculatorInfo>,
+    /// Nested models come before the models that use them
+    pub models: Vec<Model>,
+}
+
+fn tier_name(tier: Tier) -> &'static str {
+    match tier {
+        Tier::Beginner => "Beginner",
+        Tier::Engineer => "Engineer",
+        Tier::Contractor => "Contractor",
+    }
+}
+
+fn pascal_case(key: &str) -> String {
+    key.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map(|c| c.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+        })
+        .collect()
+}
+
+fn singular(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        return format!("{}y", stem);
+    }
+    match name.strip_suffix('s') {
+        Some(stem) if !stem.ends_with('s') => stem.to_string(),
+        _ => format!("{}Item", name),
+    }
+}
+
+/// Replace nested objects with references to named models
+fn hoist(name: &str, shape: Shape, models: &mut Vec<Model>) -> Shape {
+    match shape {
+        Shape::Object(fields) => {
+            let fields = fields
+                .into_iter()
+                .map(|(key, field)| {
+                    let child = format!("{}{}", name, pascal_case(&key));
+                    let shape = hoist(&child, field.shape, models);
+                    (key, Field { shape, optional: field.optional })
+                })
+                .collect();
+            models.push(Model { name: name.to_string(), fields });
+            Shape::Named(name.to_string())
+        }
+        Shape::Array(inner) => Shape::Array(Box::new(hoist(&singular(name), *inner, models))),
+        Shape::Map(inner) => Shape::Map(Box::new(hoist(&format!("{}Value", name), *inner, models))),
+        Shape::Nullable(inner) => Shape::Nullable(Box::new(hoist(name, *inner, models))),
+        other => other,
+    }
+}
+
+impl SdkSpec {
+    /// Build the spec from the default registries
+    pub async fn from_registries() -> Self {
+        let beginner = beginner::create_default_registry();
+        let engineer = engineer::create_default_registry();
+        let contractor = contractor::create_default_registry();
+        let demo = DemoCatalogue::build(&beginner, &engineer, &contractor).await;
+
+        let mut calculators = Vec::new();
+        for calc in beginner.all() {
+            let meta = calc.metadata();
+            calculators.push(CalculatorInfo {
+                tier: Tier::Beginner,
+                id: meta.id,
+                name: meta.name,
+                parameters: meta.parameters.into_iter()
+                    .map(|p| ParameterInfo { path: p.path, unit: p.unit, required: p.required })
+                    .collect(),
+            });
+        }
+        for calc in engineer.all() {
+            let meta = calc.metadata();
+            calculators.push(CalculatorInfo {
+                tier: Tier::Engineer,
+                id: meta.id,
+                name: meta.name,
+                parameters: meta.parameters.into_iter()
+                    .map(|p| ParameterInfo { path: p.path, unit: p.unit, required: p.required })
+                    .collect(),
+            });
+        }
+        for calc in contractor.all() {
+            let meta = calc.metadata();
+            calculators.push(CalculatorInfo {
+                tier: Tier::Contractor,
+                id: meta.id,
+                name: meta.name,
+                parameters: meta.parameters.into_iter()
+                    .map(|p| ParameterInfo { path: p.path, unit: p.unit, required: p.required })
+                    .collect(),
+            });
+        }
+        calculators.sort_by(|a, b| (a.tier as u8, &a.id).cmp(&(b.tier as u8, &b.id)));
+
+        let catalogues = [
+            serde_json::to_value(beginner.catalogue()),
+            serde_json::to_value(engineer.catalogue()),
+            serde_json::to_value(contractor.catalogue()),
+        ];
+
+        let mut models = Vec::new();
+        for (tier, catalogue) in TIERS.into_iter().zip(catalogues) {
+            let prefix = tier_name(tier);
+            let examples: Vec<_> = demo.examples().iter().filter(|e| e.tier == tier).collect();
+
+            let mut request = Shape::infer_all(examples.iter().map(|e| &e.request));
+            if let Shape::Object(ref mut fields) = request
+                && let Some(field) = fields.get_mut("calculation_type")
+            {
+                field.shape = Shape::Named(format!("{}CalculationType", prefix));
+            }
+            hoist(&format!("{}CalculationRequest", prefix), request, &mut models);
+
+            let response = Shape::infer_all(examples.iter().filter_map(|e| e.response.as_ref()));
+            hoist(&format!("{}CalculationResponse", prefix), response, &mut models);
+
+            if let Ok(catalogue) = catalogue {
+                hoist(&format!("{}Catalogue", prefix), Shape::infer(&catalogue), &mut models);
+            }
+        }
+
+        Self { calculators, models }
+    }
+
+    fn ids(&self, tier: Tier) -> impl Iterator<Item = &str> {
+        self.calculators.iter().filter(move |c| c.tier == tier).map(|c| c.id.as_str())
+    }
+}
+
+// =============================================================================
+// TYPESCRIPT
+// =============================================================================
+
+fn ts_type(shape: &Shape) -> String {
+    match shape {
+        Shape::Unknown | Shape::Any | Shape::Object(_) => "unknown".to_string(),
+        Shape::Bool => "boolean".to_string(),
+        Shape::Number => "number".to_string(),
+        Shape::String => "string".to_string(),
+        Shape::Array(inner) => match **inner {
+            Shape::Nullable(_) => format!("({})[]", ts_type(inner)),
+            _ => format!("{}[]", ts_type(inner)),
+        },
+        Shape::Map(inner) => format!("Record<string, {}>", ts_type(inner)),
+        Shape::Nullable(inner) => format!("{} | null", ts_type(inner)),
+        Shape::Named(name) => name.clone(),
+    }
+}
+
+fn ts_key(key: &str) -> String {
+    let identifier = key.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if identifier { key.to_string() } else { format!("{:?}", key) }
+}
+
+pub fn render_typescript(spec: &SdkSpec) -> String {
+    let mut out = String::new();
+    out.push_str("// Generated by `cargo run --bin generate_sdk` - do not edit by hand.\n");
+    out.push_str("// Source: calculator registries and serde output of the Rust API models.\n\n");
+    out.push_str("export type Tier = \"beginner\" | \"engineer\" | \"contractor\";\n");
+
+    for tier in TIERS {
+        let _ = writeln!(out, "\nexport type {}CalculationType =", tier_name(tier));
+        let ids: Vec<_> = spec.ids(tier).collect();
+        for (i, id) in ids.iter().enumerate() {
+            let end = if i + 1 == ids.len() { ";" } else { "" };
+            let _ = writeln!(out, "  | {:?}{}", id, end);
+        }
+    }
+
+    for model in &spec.models {
+        let _ = writeln!(out, "\nexport interface {} {{", model.name);
+        for (key, field) in &model.fields {
+            let optional = if field.optional { "?" } else { "" };
+            let _ = writeln!(out, "  {}{}: {};", ts_key(key), optional, ts_type(&field.shape));
+        }
+        out.push_str("}\n");
+    }
+
+    out.push_str("\nexport interface CalculatorParameterInfo {\n  path: string;\n  unit: string;\n  required: boolean;\n}\n");
+    out.push_str("\nexport interface CalculatorInfo {\n  tier: Tier;\n  id: string;\n  name: string;\n  parameters: readonly CalculatorParameterInfo[];\n}\n");
+    out.push_str("\nexport const CALCULATORS: readonly CalculatorInfo[] = [\n");
+    for calc in &spec.calculators {
+        let _ = writeln!(out, "  {{ tier: {:?}, id: {:?}, name: {:?}, parameters: [", calc.tier.as_str(), calc.id, calc.name);
+        for p in &calc.parameters {
+            let _ = writeln!(out, "    {{ path: {:?}, unit: {:?}, required: {} }},", p.path, p.unit, p.required);
+        }
+        out.push_str("  ] },\n");
+    }
+    out.push_str("];\n");
+
+    out.push_str(
+        "
+export class StrukturaApiError extends Error {
+  status: number;
+  body: unknown;
+
+  constructor(status: number, body: unknown) {
+    super(`HTTP ${status}`);
+    this.status = status;
+    this.body = body;
+  }
+}
+
+export class StrukturaApi {
+  baseUrl: string;
+  headers: Record<string, string>;
+
+  constructor(baseUrl: string = \"/api/v1\", headers: Record<string, string> = {}) {
+    this.baseUrl = baseUrl;
+    this.headers = headers;
+  }
+
+  private async request<T>(method: string, path: string, body?: unknown): Promise<T> {
+    const response = await fetch(`${this.baseUrl}${path}`, {
+      method,
+      credentials: \"same-origin\",
+      headers: {
+        Accept: \"application/json\",
+        ...(body === undefined ? {} : { \"Content-Type\": \"application/json\" }),
+        ...this.headers,
+      },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const data = await response.json().catch(() => null);
+    if (!response.ok) throw new StrukturaApiError(response.status, data);
+    return data as T;
+  }
+",
+    );
+    for tier in TIERS {
+        let (name, path) = (tier_name(tier), tier.as_str());
+        let _ = write!(
+            out,
+            "
+  calculate{name}(request: {name}CalculationRequest): Promise<{name}CalculationResponse> {{
+    return this.request(\"POST\", \"/calculus/{path}/calculate\", request);
+  }}
+
+  {path}Catalogue(): Promise<{name}Catalogue> {{
+    return this.request(\"GET\", \"/calculus/{path}/catalogue\");
+  }}
+"
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+// =============================================================================
+// PYTHON
+// =============================================================================
+
+fn py_type(shape: &Shape) -> String {
+    match shape {
+        Shape::Unknown | Shape::Any | Shape::Object(_) => "Any".to_string(),
+        Shape::Bool => "bool".to_string(),
+        Shape::Number => "float".to_string(),
+        Shape::String => "str".to_string(),
+        Shape::Array(inner) => format!("List[{}]", py_type(inner)),
+        Shape::Map(inner) => format!("Dict[str, {}]", py_type(inner)),
+        Shape::Nullable(inner) => format!("Optional[{}]", py_type(inner)),
+        Shape::Named(name) => name.clone(),
+    }
+}
+
+fn py_identifier(key: &str) -> bool {
+    key.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !PYTHON_KEYWORDS.contains(&key)
+}
+
+fn py_bool(value: bool) -> &'static str {
+    if value { "True" } else { "False" }
+}
+
+fn snake_case(name: &str) -> String {
+    name.to_lowercase()
+}
+
+pub fn render_python(spec: &SdkSpec) -> String {
+    let mut out = String::new();
+    out.push_str("\"\"\"Struktura API client.\n\nGenerated by `cargo run --bin generate_sdk` - do not edit by hand.\n");
+    out.push_str("Source: calculator registries and serde output of the Rust API models.\n\"\"\"\n\n");
+    out.push_str("import json\nimport urllib.error\nimport urllib.request\n");
+    out.push_str("from typing import Any, Dict, List, Literal, NotRequired, Optional, TypedDict\n\n");
+    out.push_str("Tier = Literal[\"beginner\", \"engineer\", \"contractor\"]\n");
+
+    for tier in TIERS {
+        let _ = writeln!(out, "\n{}CalculationType = Literal[", tier_name(tier));
+        for id in spec.ids(tier) {
+            let _ = writeln!(out, "    {:?},", id);
+        }
+        out.push_str("]\n");
+    }
+
+    for model in &spec.models {
+        let wrap = |field: &Field| {
+            let ty = py_type(&field.shape);
+            if field.optional { format!("NotRequired[{}]", ty) } else { ty }
+        };
+
+        if model.fields.keys().all(|k| py_identifier(k)) {
+            let _ = writeln!(out, "\n\nclass {}(TypedDict):", model.name);
+            if model.fields.is_empty() {
+                out.push_str("    pass\n");
+            }
+            for (key, field) in &model.fields {
+                let _ = writeln!(out, "    {}: {}", key, wrap(field));
+            }
+        } else {
+            let _ = writeln!(out, "\n\n{} = TypedDict(\"{}\", {{", model.name, model.name);
+            for (key, field) in &model.fields {
+                let _ = writeln!(out, "    {:?}: {},", key, wrap(field));
+            }
+            out.push_str("})\n");
+        }
+    }
+
+    out.push_str("\n\nCALCULATORS: List[Dict[str, Any]] = [\n");
+    for calc in &spec.calculators {
+        let _ = writeln!(out, "    {{\"tier\": {:?}, \"id\": {:?}, \"name\": {:?}, \"parameters\": [", calc.tier.as_str(), calc.id, calc.name);
+        for p in &calc.parameters {
+            let _ = writeln!(
+                out,
+                "        {{\"path\": {:?}, \"unit\": {:?}, \"required\": {}}},",
+                p.path, p.unit, py_bool(p.required)
+            );
+        }
+        out.push_str("    ]},\n");
+    }
+    out.push_str("]\n");
+
+    out.push_str(
+        "
+
+class StrukturaError(Exception):
+    def __init__(self, status: int, body: Any) -> None:
+        super().__init__(f\"HTTP {status}: {body}\")
+        self.status = status
+        self.body = body
+
+
+class StrukturaClient:
+    def __init__(
+        self,
+        base_url: str = \"http://localhost:8000/api/v1\",
+        token: Optional[str] = None,
+        timeout: float = 30.0,
+    ) -> None:
+        self.base_url = base_url.rstrip(\"/\")
+        self.token = token
+        self.timeout = timeout
+
+    def _request(self, method: str, path: str, body: Any = None) -> Any:
+        data = None if body is None else json.dumps(body).encode(\"utf-8\")
+        request = urllib.request.Request(self.base_url + path, data=data, method=method)
+        request.add_header(\"Accept\", \"application/json\")
+        if data is not None:
+            request.add_header(\"Content-Type\", \"application/json\")
+        if self.token:
+            request.add_header(\"Authorization\", f\"Bearer {self.token}\")
+        try:
+            with urllib.request.urlopen(request, timeout=self.timeout) as response:
+                return json.loads(response.read() or b\"null\")
+        except urllib.error.HTTPError as e:
+            raise StrukturaError(e.code, e.read().decode(\"utf-8\", \"replace\")) from None
+",
+    );
+    for tier in TIERS {
+        let (name, path) = (tier_name(tier), tier.as_str());
+        let method = snake_case(name);
+        let _ = write!(
+            out,
+            "
+    def calculate_{method}(self, request: {name}CalculationRequest) -> {name}CalculationResponse:
+        return self._request(\"POST\", \"/calculus/{path}/calculate\", request)
+
+    def {method}_catalogue(self) -> {name}Catalogue:
+        return self._request(\"GET\", \"/calculus/{path}/catalogue\")
+"
+        );
+    }
+    out
+}
+
+/// Generated files as (path relative to the crate root, contents)
+pub async fn generate() -> Vec<(&'static str, String)> {
+    let spec = SdkSpec::from_registries().await;
+    vec![
+        (TYPESCRIPT_PATH, render_typescript(&spec)),
+        (PYTHON_PATH, render_python(&spec)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_marks_missing_fields_optional() {
+        let shape = Shape::infer_all([json!({ "a": 1, "b": "x" }), json!({ "a": 2.5, "c": null })].iter());
+        let Shape::Object(fields) = shape else { panic!("expected an object") };
+
+        assert_eq!(fields["a"], Field { shape: Shape::Number, optional: false });
+        assert!(fields["b"].optional);
+        assert_eq!(fields["c"].shape, Shape::Nullable(Box::new(Shape::Unknown)));
+        assert_eq!(Shape::Number.merge(Shape::String), Shape::Any);
+    }
+
+    #[test]
+    fn test_map_fields_and_hoisting() {
+        let shape = Shape::infer(&json!({ "dimensions": { "span": 6.0 }, "results": [{ "label": "x" }] }));
+        let mut models = Vec::new();
+        hoist("Response", shape, &mut models);
+
+        let names: Vec<_> = models.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["ResponseResult", "Response"]);
+        assert_eq!(models[1].fields["dimensions"].shape, Shape::Map(Box::new(Shape::Number)));
+        assert_eq!(
+            models[1].fields["results"].shape,
+            Shape::Array(Box::new(Shape::Named("ResponseResult".to_string())))
+        );
+    }
+
+    /// The checked-in clients must match the current API models
+    #[tokio::test]
+    async fn test_generated_sdk_is_up_to_date() {
+        for (path, expected) in generate().await {
+            let full = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+            let current = std::fs::read_to_string(&full).unwrap_or_default();
+            assert!(
+                current == expected,
+                "{} is stale - run `cargo run --bin generate_sdk`",
+                path
+            );
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use axum::{
-    extract::{FromRequestParts, State},
+    extract::{FromRequestParts, OptionalFromRequestParts, State},
     http::{
         header::{AUTHORIZATION, HeaderMap, HeaderValue, HeaderName},
         request::Parts,
@@ -13,14 +13,17 @@ use serde::{Deserialize, Serialize};
 use time::{Duration, OffsetDateTime};
 use uuid::Uuid;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::HashMap,
     sync::{Arc, RwLock},
 };
 use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
 use argon2::password_hash::{rand_core::OsRng, SaltString, Error as ArgonError};
+use hmac::{Hmac, Mac};
 use sha2::{Sha256, Digest};
 use base64::{Engine as _, engine::general_purpose};
 use lazy_static::lazy_static;
+use governor::clock::{Clock, DefaultClock};
+use crate::password::PolicyViolation;
 use crate::state::AppState;
 use validator::ValidationErrors;
 use rand::Rng;
@@ -37,11 +40,18 @@ pub enum AppError {
     ExpiredToken,
     BlacklistedToken,
     UserNotFound,
+    Forbidden,
     MissingCsrf,
     InvalidCsrf,
     ValidationError(ValidationErrors),
     DbError(sqlx::Error),
     PasswordError(ArgonError),
+    /// A chosen password broke the policy; every failed rule is listed
+    PasswordPolicy(Vec<PolicyViolation>),
+    /// The organization's rotation rule requires a new password first
+    PasswordExpired,
+    /// Two-factor authentication is on and no code was sent
+    TwoFactorRequired,
     Internal(String),
 }
 
@@ -53,20 +63,42 @@ impl IntoResponse for AppError {
                 (StatusCode::UNAUTHORIZED, "Authentication failed")
             }
             AppError::UserNotFound => (StatusCode::NOT_FOUND, "Resource not found"),
+            AppError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient privileges"),
             AppError::MissingCsrf | AppError::InvalidCsrf => (StatusCode::FORBIDDEN, "CSRF validation failed"),
             AppError::ValidationError(ref e) => {
                 return (StatusCode::UNPROCESSABLE_ENTITY, Json(serde_json::json!({"error": e.to_string()}))).into_response();
             }
+            AppError::PasswordPolicy(ref violations) => {
+                return (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    Json(serde_json::json!({"error": "Password does not meet the policy", "violations": violations})),
+                )
+                    .into_response();
+            }
+            AppError::PasswordExpired => {
+                return (
+                    StatusCode::FORBIDDEN,
+                    Json(serde_json::json!({"error": "Password expired", "code": "password_expired"})),
+                )
+                    .into_response();
+            }
+            AppError::TwoFactorRequired => {
+                return (
+                    StatusCode::UNAUTHORIZED,
+                    Json(serde_json::json!({"error": "Two-factor code required", "code": "two_factor_required"})),
+                )
+                    .into_response();
+            }
             AppError::DbError(ref e) => {
-                eprintln!("[DB_ERROR] {}", e);
+                tracing::error!(error = %e, "database error");
                 (StatusCode::INTERNAL_SERVER_ERROR, "Database error")
             }
             AppError::PasswordError(ref e) => {
-                eprintln!("[PASS_ERROR] {}", e);
+                tracing::error!(error = %e, "password hashing error");
                 (StatusCode::INTERNAL_SERVER_ERROR, "Password error")
             }
             AppError::Internal(ref msg) => {
-                eprintln!("[INTERNAL] {}", msg);
+                tracing::error!(error = %msg, "internal error");
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal error")
             }
         };
@@ -92,9 +124,12 @@ pub struct Claims {
     pub session_fp: String,
 }
 
+/// Access tokens are short-lived; clients renew them with a refresh token
+pub const ACCESS_TOKEN_TTL: Duration = Duration::minutes(15);
+
 pub fn generate_jwt(user_id: String, username: String, session_fp: String, jwt_secret: &str) -> Result<(String, Claims), AppError> {
     let now = OffsetDateTime::now_utc();
-    let exp = (now + Duration::days(7)).unix_timestamp() as usize;
+    let exp = (now + ACCESS_TOKEN_TTL).unix_timestamp() as usize;
     let iat = now.unix_timestamp() as usize;
     let jti = Uuid::new_v4().to_string();
 
@@ -149,10 +184,23 @@ impl FromRequestParts<Arc<AppState>> for Claims {
             return Err(AppError::InvalidToken);
         }
 
+        crate::telemetry::record_user(&claims.sub);
         Ok(claims)
     }
 }
 
+/// Anonymous requests yield `None`; a present but invalid token is still rejected
+impl OptionalFromRequestParts<Arc<AppState>> for Claims {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &Arc<AppState>) -> Result<Option<Self>, Self::Rejection> {
+        if !parts.headers.contains_key(AUTHORIZATION) {
+            return Ok(None);
+        }
+        <Claims as FromRequestParts<Arc<AppState>>>::from_request_parts(parts, state).await.map(Some)
+    }
+}
+
 // =============================================================================
 // PASSWORD HASHING
 // =============================================================================
@@ -177,7 +225,8 @@ impl PasswordManager {
 // =============================================================================
 
 lazy_static! {
-    static ref BLACKLIST: RwLock<HashSet<String>> = RwLock::new(HashSet::new());
+    /// Revoked token ids, with when they can be forgotten
+    static ref BLACKLIST: RwLock<HashMap<String, OffsetDateTime>> = RwLock::new(HashMap::new());
 }
 
 #[derive(Clone)]
@@ -185,13 +234,26 @@ pub struct TokenBlacklist;
 
 impl TokenBlacklist {
     pub fn new() -> Self { Self }
+    /// Any access token revoked now has expired by `now + ACCESS_TOKEN_TTL`
     pub fn revoke(&self, jti: &str) {
         if let Ok(mut list) = BLACKLIST.write() {
-            list.insert(jti.to_string());
+            list.insert(jti.to_string(), OffsetDateTime::now_utc() + ACCESS_TOKEN_TTL);
         }
     }
     pub fn is_revoked(&self, jti: &str) -> bool {
-        BLACKLIST.read().map(|list| list.contains(jti)).unwrap_or(false)
+        BLACKLIST.read().map(|list| list.contains_key(jti)).unwrap_or(false)
+    }
+    /// Forget revocations of tokens that have expired anyway
+    pub fn purge_expired(&self) -> usize {
+        let now = OffsetDateTime::now_utc();
+        BLACKLIST
+            .write()
+            .map(|mut list| {
+                let before = list.len();
+                list.retain(|_, until| *until > now);
+                before - list.len()
+            })
+            .unwrap_or(0)
     }
 }
 
@@ -199,8 +261,12 @@ impl TokenBlacklist {
 // CSRF STORE
 // =============================================================================
 
+/// CSRF tokens are reissued on login and refresh; older ones expire
+pub const CSRF_TOKEN_TTL: Duration = Duration::hours(1);
+
 lazy_static! {
-    static ref CSRF_TOKENS: RwLock<HashMap<String, String>> = RwLock::new(HashMap::new());
+    /// Token hash and issue time, per user
+    static ref CSRF_TOKENS: RwLock<HashMap<String, (String, OffsetDateTime)>> = RwLock::new(HashMap::new());
 }
 
 #[derive(Clone)]
@@ -214,7 +280,7 @@ impl CsrfTokenStore {
         let hash = hash_csrf_token(&token);
         CSRF_TOKENS.write()
             .map_err(|_| AppError::Internal("CSRF write lock".into()))?
-            .insert(user_id.to_string(), hash);
+            .insert(user_id.to_string(), (hash, OffsetDateTime::now_utc()));
         Ok(token)
     }
 
@@ -231,7 +297,8 @@ impl CsrfTokenStore {
         };
         
         store.get(user_id)
-            .map(|stored| *stored == hash_csrf_token(token))
+            .filter(|(_, issued)| OffsetDateTime::now_utc() - *issued < CSRF_TOKEN_TTL)
+            .map(|(stored, _)| *stored == hash_csrf_token(token))
             .unwrap_or(false)
     }
     
@@ -240,6 +307,19 @@ impl CsrfTokenStore {
             store.remove(user_id);
         }
     }
+
+    /// Drop tokens past `CSRF_TOKEN_TTL`
+    pub fn purge_expired(&self) -> usize {
+        let now = OffsetDateTime::now_utc();
+        CSRF_TOKENS
+            .write()
+            .map(|mut store| {
+                let before = store.len();
+                store.retain(|_, (_, issued)| now - *issued < CSRF_TOKEN_TTL);
+                before - store.len()
+            })
+            .unwrap_or(0)
+    }
 }
 
 fn hash_csrf_token(token: &str) -> String {
@@ -287,17 +367,71 @@ pub fn compute_session_fingerprint(ip: Option<&str>, ua_hash: Option<&str>) -> S
 }
 
 pub fn log_security_event(event: &str, user: Option<&str>, ip: Option<&str>, details: &str) {
-    eprintln!("[AUDIT] {} | {} | User: {} | IP: {} | {}", 
-        OffsetDateTime::now_utc(), event, 
-        user.unwrap_or("anon"), ip.unwrap_or("unknown"), details
+    tracing::info!(
+        target: "audit",
+        event,
+        user = user.unwrap_or("anon"),
+        ip = ip.unwrap_or("unknown"),
+        details,
     );
 }
 
+/// Reject callers who are not administrators
+pub async fn require_admin(pool: &sqlx::PgPool, claims: &Claims) -> Result<Uuid, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+    let is_admin: Option<bool> = sqlx::query_scalar("SELECT is_admin FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+    if is_admin != Some(true) {
+        log_security_event("ADMIN_DENIED", Some(&claims.username), None, "Admin route without privileges");
+        return Err(AppError::Forbidden);
+    }
+    Ok(user_id)
+}
+
 pub async fn traffic_jitter(delay_ms: u64) {
     let jitter = rand::rng().random_range(0..10);
     tokio::time::sleep(std::time::Duration::from_millis(delay_ms + jitter)).await;
 }
 
+// =============================================================================
+// SIGNATURES
+// =============================================================================
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes keys of any length");
+    mac.update(message);
+    mac
+}
+
+/// Lowercase hex of `bytes`
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn unhex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| text.get(i..i + 2).and_then(|pair| u8::from_str_radix(pair, 16).ok()))
+        .collect()
+}
+
+/// Hex HMAC-SHA256 of `message`, as webhook signatures carry it
+pub fn sign_hmac_sha256(key: &[u8], message: &[u8]) -> String {
+    hex(&hmac_sha256(key, message).finalize().into_bytes())
+}
+
+/// Whether hex `signature` is the HMAC-SHA256 of `message`, compared in
+/// constant time
+pub fn verify_hmac_sha256(key: &[u8], message: &[u8], signature: &str) -> bool {
+    unhex(signature).is_some_and(|expected| hmac_sha256(key, message).verify_slice(&expected).is_ok())
+}
+
 // =============================================================================
 // MIDDLEWARE
 // =============================================================================
@@ -326,12 +460,62 @@ pub async fn csrf_protection_middleware(
     Ok(next.run(request).await)
 }
 
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// A client's rate-limit window as advertised in response headers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitStatus {
+    /// Requests allowed in a full window
+    pub limit: u32,
+    pub remaining: u32,
+    /// Until the quota is fully replenished
+    pub reset: std::time::Duration,
+    /// Until the next request is allowed, when this one was rejected
+    pub retry_after: Option<std::time::Duration>,
+}
+
+impl RateLimitStatus {
+    pub fn allowed(quota: governor::Quota, remaining: u32) -> Self {
+        let used = quota.burst_size().get().saturating_sub(remaining);
+        Self {
+            limit: quota.burst_size().get(),
+            remaining,
+            reset: quota.replenish_interval() * used,
+            retry_after: None,
+        }
+    }
+
+    pub fn rejected(quota: governor::Quota, wait: std::time::Duration) -> Self {
+        let burst = quota.burst_size().get();
+        Self {
+            limit: burst,
+            remaining: 0,
+            reset: wait + quota.replenish_interval() * (burst - 1),
+            retry_after: Some(wait),
+        }
+    }
+
+    /// `X-RateLimit-*` headers, plus `Retry-After` on rejections; times are
+    /// whole seconds, rounded up
+    pub fn apply(&self, headers: &mut HeaderMap) {
+        let secs = |d: std::time::Duration| d.as_secs() + u64::from(d.subsec_nanos() > 0);
+        headers.insert(HeaderName::from_static(RATE_LIMIT_LIMIT_HEADER), HeaderValue::from(self.limit));
+        headers.insert(HeaderName::from_static(RATE_LIMIT_REMAINING_HEADER), HeaderValue::from(self.remaining));
+        headers.insert(HeaderName::from_static(RATE_LIMIT_RESET_HEADER), HeaderValue::from(secs(self.reset)));
+        if let Some(wait) = self.retry_after {
+            headers.insert(axum::http::header::RETRY_AFTER, HeaderValue::from(secs(wait).max(1)));
+        }
+    }
+}
+
 pub async fn rate_limit_middleware(
     State(app_state): State<Arc<AppState>>,
     headers: HeaderMap,
     request: axum::extract::Request,
     next: Next,
-) -> Result<Response, StatusCode> {
+) -> Response {
     let ip = headers.get("x-forwarded-for")
         .or_else(|| headers.get("x-real-ip"))
         .and_then(|h| h.to_str().ok())
@@ -339,10 +523,18 @@ pub async fn rate_limit_middleware(
         .unwrap_or_else(|| std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1)));
     
     match app_state.rate_limiter.check_key(&ip) {
-        Ok(_) => Ok(next.run(request).await),
-        Err(_) => {
+        Ok(snapshot) => {
+            let status = RateLimitStatus::allowed(snapshot.quota(), snapshot.remaining_burst_capacity());
+            let mut response = next.run(request).await;
+            status.apply(response.headers_mut());
+            response
+        }
+        Err(not_until) => {
             log_security_event("RATE_LIMIT", None, Some(&ip.to_string()), "Rate limited");
-            Err(StatusCode::TOO_MANY_REQUESTS)
+            let wait = not_until.wait_time_from(DefaultClock::default().now());
+            let mut response = StatusCode::TOO_MANY_REQUESTS.into_response();
+            RateLimitStatus::rejected(not_until.quota(), wait).apply(response.headers_mut());
+            response
         }
     }
 }
@@ -406,4 +598,49 @@ pub async fn security_headers_middleware(
     );
 
     response
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use governor::Quota;
+    use std::num::NonZeroU32;
+
+    #[test]
+    fn test_hmac_matches_rfc4231() {
+        // RFC 4231 test case 2
+        let mac = sign_hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(mac, "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+        assert!(verify_hmac_sha256(b"Jefe", b"what do ya want for nothing?", &mac));
+        assert!(verify_hmac_sha256(b"Jefe", b"what do ya want for nothing?", &mac.to_uppercase()));
+        assert!(!verify_hmac_sha256(b"Jefe", b"what do ya want for nothing!", &mac));
+        assert!(!verify_hmac_sha256(b"Jefe", b"what do ya want for nothing?", &mac[..62]));
+        assert!(!verify_hmac_sha256(b"Jefe", b"what do ya want for nothing?", "zz"));
+
+        // Keys longer than the SHA-256 block are hashed first
+        let long_key = [0xaau8; 131];
+        assert_eq!(
+            sign_hmac_sha256(&long_key, b"Test Using Larger Than Block-Size Key - Hash Key First"),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_headers() {
+        let quota = Quota::per_minute(NonZeroU32::new(100).unwrap());
+
+        let mut headers = HeaderMap::new();
+        RateLimitStatus::allowed(quota, 97).apply(&mut headers);
+        assert_eq!(headers[RATE_LIMIT_LIMIT_HEADER], "100");
+        assert_eq!(headers[RATE_LIMIT_REMAINING_HEADER], "97");
+        // Three cells used, each replenished every 600ms
+        assert_eq!(headers[RATE_LIMIT_RESET_HEADER], "2");
+        assert!(headers.get(axum::http::header::RETRY_AFTER).is_none());
+
+        let mut headers = HeaderMap::new();
+        RateLimitStatus::rejected(quota, std::time::Duration::from_millis(250)).apply(&mut headers);
+        assert_eq!(headers[RATE_LIMIT_REMAINING_HEADER], "0");
+        assert_eq!(headers[axum::http::header::RETRY_AFTER], "1");
+        assert_eq!(headers[RATE_LIMIT_RESET_HEADER], "60");
+    }
+}
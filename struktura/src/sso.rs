@@ -0,0 +1,760 @@
+//! Enterprise single sign-on over OpenID Connect
+//!
+//! Each organization can register identity provider connections (Okta,
+//! Azure AD / Entra ID, Google Workspace, any OIDC issuer). Sign-in is the
+//! authorization code flow with PKCE:
+//!
+//! 1. `GET /api/v1/auth/sso/{slug}/authorize` returns the provider's
+//!    authorization URL; the client sends the user there
+//! 2. the provider redirects to the connection's `redirect_uri` (a frontend
+//!    page) with `code` and `state`
+//! 3. the page posts both to `POST /api/v1/auth/sso/{slug}/callback`, which
+//!    exchanges the code, verifies the ID token against the provider's
+//!    published keys and signs the user in like a password login
+//!
+//! Users are provisioned just in time: the first sign-in of an unknown
+//! subject links the account the organization provisioned over SCIM with
+//! the same email (only when the provider marks it `email_verified`) or user
+//! name, or else creates one inside the connection's organization.
+//! Created accounts get an unusable password, so they can only sign in
+//! through SSO. Deactivated accounts are refused.
+//!
+//! SAML 2.0 is not supported; every major enterprise IdP also speaks OIDC.
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use base64::{engine::general_purpose, Engine as _};
+use dashmap::DashMap;
+use jsonwebtoken::{decode, decode_header, jwk::JwkSet, Algorithm, DecodingKey, Validation};
+use lazy_static::lazy_static;
+use rand::Rng;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::types::time::OffsetDateTime;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::auth::{self, AuthResponse, UserProfile};
+use crate::resilience::{retryable_http, Guard, ResiliencePolicy};
+use crate::sec::{self, AppError, Claims, PasswordManager};
+use crate::state::AppState;
+
+/// How long a started sign-in may take to come back
+const PENDING_LOGIN_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Sign-ins waiting for their callback at once; `authorize` is public, so
+/// the map must not grow with whatever a client cares to send
+const MAX_PENDING_LOGINS: usize = 10_000;
+
+/// How long discovery documents and signing keys are reused
+const PROVIDER_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// ID token algorithms accepted from providers; never the HMAC family,
+/// whose key would be the client secret
+const ALLOWED_ALGORITHMS: &[Algorithm] = &[
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+];
+
+/// A sign-in started by `authorize`, waiting for its callback
+struct PendingLogin {
+    connection_id: Uuid,
+    nonce: String,
+    code_verifier: String,
+    started: Instant,
+}
+
+lazy_static! {
+    static ref PENDING_LOGINS: DashMap<String, PendingLogin> = DashMap::new();
+    static ref DISCOVERY: DashMap<String, (Instant, ProviderMetadata)> = DashMap::new();
+    static ref KEYS: DashMap<String, (Instant, JwkSet)> = DashMap::new();
+}
+
+/// Forget sign-ins that were never completed; returns how many
+pub fn purge_expired() -> usize {
+    let before = PENDING_LOGINS.len();
+    PENDING_LOGINS.retain(|_, pending| pending.started.elapsed() < PENDING_LOGIN_TTL);
+    before - PENDING_LOGINS.len()
+}
+
+/// Whether another sign-in may start, forgetting expired ones when full
+fn has_room_for_pending_login() -> bool {
+    if PENDING_LOGINS.len() < MAX_PENDING_LOGINS {
+        return true;
+    }
+    purge_expired();
+    PENDING_LOGINS.len() < MAX_PENDING_LOGINS
+}
+
+// ============================================================================
+// Connections
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct SsoConnection {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub slug: String,
+    pub issuer: String,
+    pub client_id: String,
+    #[serde(skip)]
+    pub client_secret: String,
+    pub redirect_uri: String,
+    pub enabled: bool,
+    pub created_at: OffsetDateTime,
+}
+
+const CONNECTION_COLUMNS: &str = "id, org_id, slug, issuer, client_id, client_secret, redirect_uri, enabled, created_at";
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SsoConnectionPayload {
+    pub org_id: Uuid,
+    /// Appears in sign-in URLs: lowercase letters, digits and dashes
+    #[validate(length(min = 2, max = 50), custom(function = "validate_slug"))]
+    pub slug: String,
+    #[validate(url, length(max = 255))]
+    pub issuer: String,
+    #[validate(length(min = 1, max = 255))]
+    pub client_id: String,
+    /// Kept when omitted on update
+    pub client_secret: Option<String>,
+    #[validate(url, length(max = 500))]
+    pub redirect_uri: String,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+fn validate_slug(slug: &str) -> Result<(), ValidationError> {
+    if slug.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+        Ok(())
+    } else {
+        Err(ValidationError::new("slug_format"))
+    }
+}
+
+async fn load_connection(state: &AppState, slug: &str) -> Result<SsoConnection, AppError> {
+    sqlx::query_as::<_, SsoConnection>(&format!(
+        "SELECT {CONNECTION_COLUMNS} FROM sso_connections WHERE slug = $1 AND enabled"
+    ))
+    .bind(slug)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)
+}
+
+// ============================================================================
+// Provider
+// ============================================================================
+
+/// The parts of an OpenID Provider's discovery document used here
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+async fn fetch_json<T: DeserializeOwned>(request: impl Fn() -> reqwest::RequestBuilder) -> Result<T, AppError> {
+    let body = Guard::named("sso", ResiliencePolicy::default())
+        .call(retryable_http, || async { request().send().await?.error_for_status()?.text().await })
+        .await
+        .map_err(|e| AppError::Internal(format!("Identity provider: {}", e)))?;
+    serde_json::from_str(&body).map_err(|e| AppError::Internal(format!("Identity provider: {}", e)))
+}
+
+/// Discovery document for `issuer`, cached
+async fn discover(issuer: &str) -> Result<ProviderMetadata, AppError> {
+    if let Some(entry) = DISCOVERY.get(issuer)
+        && entry.0.elapsed() < PROVIDER_CACHE_TTL
+    {
+        return Ok(entry.1.clone());
+    }
+
+    let url = format!("{}/.well-known/openid-configuration", issuer.trim_end_matches('/'));
+    let metadata: ProviderMetadata = fetch_json(|| reqwest::Client::new().get(&url)).await?;
+    if metadata.issuer.trim_end_matches('/') != issuer.trim_end_matches('/') {
+        return Err(AppError::Internal(format!(
+            "Identity provider at {} claims issuer {}",
+            issuer, metadata.issuer
+        )));
+    }
+    DISCOVERY.insert(issuer.to_string(), (Instant::now(), metadata.clone()));
+    Ok(metadata)
+}
+
+/// Signing keys published at `jwks_uri`; `refresh` skips the cache, for
+/// tokens signed with a key rotated in since
+async fn signing_keys(jwks_uri: &str, refresh: bool) -> Result<JwkSet, AppError> {
+    if !refresh
+        && let Some(entry) = KEYS.get(jwks_uri)
+        && entry.0.elapsed() < PROVIDER_CACHE_TTL
+    {
+        return Ok(entry.1.clone());
+    }
+
+    let keys: JwkSet = fetch_json(|| reqwest::Client::new().get(jwks_uri)).await?;
+    KEYS.insert(jwks_uri.to_string(), (Instant::now(), keys.clone()));
+    Ok(keys)
+}
+
+/// 256 random bits, URL-safe
+fn random_token() -> String {
+    let bytes: [u8; 32] = rand::rng().random();
+    general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// PKCE S256 challenge for a code verifier (RFC 7636)
+fn pkce_challenge(code_verifier: &str) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+fn authorization_url(
+    metadata: &ProviderMetadata,
+    connection: &SsoConnection,
+    state: &str,
+    nonce: &str,
+    code_verifier: &str,
+) -> Result<String, AppError> {
+    reqwest::Url::parse_with_params(
+        &metadata.authorization_endpoint,
+        &[
+            ("response_type", "code"),
+            ("client_id", connection.client_id.as_str()),
+            ("redirect_uri", connection.redirect_uri.as_str()),
+            ("scope", "openid email profile"),
+            ("state", state),
+            ("nonce", nonce),
+            ("code_challenge", &pkce_challenge(code_verifier)),
+            ("code_challenge_method", "S256"),
+        ],
+    )
+    .map(String::from)
+    .map_err(|e| AppError::Internal(format!("Authorization endpoint: {}", e)))
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+/// Claims read from a verified ID token
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+    email: Option<String>,
+    #[serde(default, deserialize_with = "lenient_bool")]
+    email_verified: bool,
+    preferred_username: Option<String>,
+}
+
+impl IdTokenClaims {
+    /// Email that may match an existing account: only one the provider
+    /// verified, or anyone able to set an unverified address could take
+    /// over that account
+    fn verified_email(&self) -> Option<&String> {
+        self.email.as_ref().filter(|_| self.email_verified)
+    }
+}
+
+/// A boolean claim, which some providers send as `"true"`
+fn lenient_bool<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flag {
+        Bool(bool),
+        Text(String),
+    }
+    Ok(match Flag::deserialize(deserializer)? {
+        Flag::Bool(value) => value,
+        Flag::Text(text) => text.eq_ignore_ascii_case("true"),
+    })
+}
+
+async fn exchange_code(
+    metadata: &ProviderMetadata,
+    connection: &SsoConnection,
+    code: &str,
+    code_verifier: &str,
+) -> Result<String, AppError> {
+    let form = [
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("redirect_uri", connection.redirect_uri.as_str()),
+        ("client_id", connection.client_id.as_str()),
+        ("client_secret", connection.client_secret.as_str()),
+        ("code_verifier", code_verifier),
+    ];
+    // Codes are single-use, so the exchange is never retried
+    let body = Guard::named("sso_token", ResiliencePolicy::no_retry())
+        .call(|_| false, || async {
+            reqwest::Client::new()
+                .post(&metadata.token_endpoint)
+                .form(&form)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await
+        })
+        .await
+        .map_err(|e| AppError::Internal(format!("Token exchange: {}", e)))?;
+    serde_json::from_str::<TokenResponse>(&body)
+        .map(|response| response.id_token)
+        .map_err(|e| AppError::Internal(format!("Token exchange: {}", e)))
+}
+
+/// Check the ID token's signature, issuer, audience, lifetime and nonce
+async fn verify_id_token(
+    metadata: &ProviderMetadata,
+    connection: &SsoConnection,
+    id_token: &str,
+    nonce: &str,
+) -> Result<IdTokenClaims, AppError> {
+    let header = decode_header(id_token).map_err(|_| AppError::InvalidToken)?;
+    if !ALLOWED_ALGORITHMS.contains(&header.alg) {
+        return Err(AppError::InvalidToken);
+    }
+    let kid = header.kid.ok_or(AppError::InvalidToken)?;
+
+    let jwk = match signing_keys(&metadata.jwks_uri, false).await?.find(&kid) {
+        Some(jwk) => jwk.clone(),
+        None => signing_keys(&metadata.jwks_uri, true)
+            .await?
+            .find(&kid)
+            .cloned()
+            .ok_or(AppError::InvalidToken)?,
+    };
+    let key = DecodingKey::from_jwk(&jwk).map_err(|_| AppError::InvalidToken)?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[&metadata.issuer]);
+    validation.set_audience(&[&connection.client_id]);
+    let claims = decode::<IdTokenClaims>(id_token, &key, &validation)
+        .map_err(|e| {
+            sec::log_security_event("SSO_TOKEN_FAIL", None, None, &e.to_string());
+            AppError::InvalidToken
+        })?
+        .claims;
+
+    if claims.nonce.as_deref() != Some(nonce) {
+        return Err(AppError::InvalidToken);
+    }
+    Ok(claims)
+}
+
+// ============================================================================
+// Provisioning
+// ============================================================================
+
+/// Username for a provisioned account, from a name the identity provider
+/// supplied (the local part when it is an email), reduced to the characters
+/// usernames allow
+pub(crate) fn username_candidate(name: Option<&str>) -> String {
+    let source = name.map(|name| name.split('@').next().unwrap_or(name)).unwrap_or("sso-user");
+    let cleaned: String = source
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .take(40)
+        .collect();
+    if cleaned.trim_matches('_').len() < 3 {
+        "sso-user".to_string()
+    } else {
+        cleaned
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct Account {
+    id: Uuid,
+    username: String,
+    is_pro: Option<bool>,
+    fav_experience_level: Option<String>,
+    created_at: Option<OffsetDateTime>,
+    active: bool,
+}
+
+impl Account {
+    fn into_profile(self) -> (Uuid, UserProfile) {
+        let profile = UserProfile {
+            username: self.username,
+            is_pro: self.is_pro,
+            fav_experience_level: self.fav_experience_level,
+            created_at: self.created_at,
+        };
+        (self.id, profile)
+    }
+}
+
+const ACCOUNT_COLUMNS: &str = "u.id, u.username, u.is_pro, u.fav_experience_level, u.created_at, u.active";
+
+/// Create an account for an identity provider in `org_id`, named `base`, or
+/// `base` with a random suffix when taken
+///
+/// Nobody knows its password; it signs in through SSO only.
+pub(crate) async fn create_provisioned_user(
+    conn: &mut sqlx::PgConnection,
+    base: &str,
+    org_id: Uuid,
+) -> Result<(Uuid, UserProfile), AppError> {
+    let unusable_hash = PasswordManager::hash_password(&random_token())?;
+    for attempt in 0..5 {
+        let username = if attempt == 0 {
+            base.to_string()
+        } else {
+            format!("{}-{:04x}", base, rand::rng().random::<u16>())
+        };
+        let created = sqlx::query_as::<_, Account>(&format!(
+            "INSERT INTO users AS u (username, hash, org_id) VALUES ($1, $2, $3) \
+             ON CONFLICT (username) DO NOTHING RETURNING {ACCOUNT_COLUMNS}"
+        ))
+        .bind(&username)
+        .bind(&unusable_hash)
+        .bind(org_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+        if let Some(account) = created {
+            return Ok(account.into_profile());
+        }
+    }
+    Err(AppError::Internal(format!("No free username for provisioned user {}", base)))
+}
+
+fn refuse_deactivated(account: &Account, connection: &SsoConnection) -> Result<(), AppError> {
+    if account.active {
+        return Ok(());
+    }
+    sec::log_security_event("SSO_LOGIN_FAIL", Some(&account.username), None, &format!("Deactivated ({})", connection.slug));
+    Err(AppError::Forbidden)
+}
+
+/// The account this subject signs in as: the one already linked, else an
+/// account the organization provisioned over SCIM with the same verified
+/// email or user name, else a new one in the connection's organization
+async fn provision(state: &AppState, connection: &SsoConnection, claims: &IdTokenClaims) -> Result<(Uuid, UserProfile), AppError> {
+    let linked = sqlx::query_as::<_, Account>(&format!(
+        "SELECT {ACCOUNT_COLUMNS} FROM sso_identities i JOIN users u ON u.id = i.user_id \
+         WHERE i.connection_id = $1 AND i.subject = $2"
+    ))
+    .bind(connection.id)
+    .bind(&claims.sub)
+    .fetch_optional(&state.pool)
+    .await?;
+    if let Some(account) = linked {
+        refuse_deactivated(&account, connection)?;
+        sqlx::query(
+            "UPDATE sso_identities SET last_login_at = CURRENT_TIMESTAMP, email = COALESCE($3, email) \
+             WHERE connection_id = $1 AND subject = $2",
+        )
+        .bind(connection.id)
+        .bind(&claims.sub)
+        .bind(&claims.email)
+        .execute(&state.pool)
+        .await?;
+        return Ok(account.into_profile());
+    }
+
+    let mut tx = state.pool.begin().await?;
+    let provisioned = sqlx::query_as::<_, Account>(&format!(
+        "SELECT {ACCOUNT_COLUMNS} FROM users u WHERE u.org_id = $1 \
+         AND (lower(u.email) = lower($2) OR lower(u.scim_user_name) = lower($3)) \
+         ORDER BY u.created_at LIMIT 1"
+    ))
+    .bind(connection.org_id)
+    .bind(claims.verified_email())
+    .bind(claims.preferred_username.as_ref().or(claims.verified_email()))
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let (user_id, profile) = match provisioned {
+        Some(account) => {
+            refuse_deactivated(&account, connection)?;
+            account.into_profile()
+        }
+        None => {
+            let base = username_candidate(claims.preferred_username.as_deref().or(claims.email.as_deref()));
+            let created = create_provisioned_user(&mut tx, &base, connection.org_id).await?;
+            sec::log_security_event("SSO_PROVISION", Some(&created.1.username), None, &connection.slug);
+            created
+        }
+    };
+
+    sqlx::query("INSERT INTO sso_identities (connection_id, subject, user_id, email) VALUES ($1, $2, $3, $4)")
+        .bind(connection.id)
+        .bind(&claims.sub)
+        .bind(user_id)
+        .bind(&claims.email)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    Ok((user_id, profile))
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct AuthorizeResponse {
+    pub authorization_url: String,
+    pub state: String,
+}
+
+/// GET /api/v1/auth/sso/{slug}/authorize
+pub async fn authorize_handler(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> Result<Response, AppError> {
+    let connection = load_connection(&state, &slug).await?;
+    if !has_room_for_pending_login() {
+        sec::log_security_event("SSO_LOGIN_FAIL", None, None, "Too many pending sign-ins");
+        let body = Json(serde_json::json!({"error": "Too many sign-ins in progress, try again shortly"}));
+        return Ok((StatusCode::SERVICE_UNAVAILABLE, body).into_response());
+    }
+    let metadata = discover(&connection.issuer).await?;
+
+    let login_state = random_token();
+    let pending = PendingLogin {
+        connection_id: connection.id,
+        nonce: random_token(),
+        code_verifier: random_token(),
+        started: Instant::now(),
+    };
+    let authorization_url = authorization_url(&metadata, &connection, &login_state, &pending.nonce, &pending.code_verifier)?;
+    PENDING_LOGINS.insert(login_state.clone(), pending);
+
+    Ok(Json(AuthorizeResponse { authorization_url, state: login_state }).into_response())
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CallbackPayload {
+    pub code: String,
+    pub state: String,
+}
+
+/// POST /api/v1/auth/sso/{slug}/callback
+pub async fn callback_handler(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<CallbackPayload>,
+) -> Result<Json<AuthResponse>, AppError> {
+    let (ip, ua_hash) = sec::extract_ip_and_ua(&headers)?;
+    let ip_str = ip.as_deref();
+    let connection = load_connection(&state, &slug).await?;
+
+    // A state is good for one callback, to the connection that issued it
+    let pending = PENDING_LOGINS
+        .remove(&payload.state)
+        .map(|(_, pending)| pending)
+        .filter(|p| p.connection_id == connection.id && p.started.elapsed() < PENDING_LOGIN_TTL);
+    let Some(pending) = pending else {
+        sec::log_security_event("SSO_LOGIN_FAIL", None, ip_str, "Unknown or expired state");
+        sec::traffic_jitter(100).await;
+        return Err(AppError::InvalidCredentials);
+    };
+
+    let metadata = discover(&connection.issuer).await?;
+    let id_token = exchange_code(&metadata, &connection, &payload.code, &pending.code_verifier).await?;
+    let claims = verify_id_token(&metadata, &connection, &id_token, &pending.nonce).await?;
+
+    let (user_id, profile) = provision(&state, &connection, &claims).await?;
+
+    let session_fp = sec::compute_session_fingerprint(ip.as_deref(), ua_hash.as_deref());
+    let session = auth::start_session(&state, user_id, &profile.username, session_fp).await?;
+    sec::log_security_event("SSO_LOGIN", Some(&profile.username), ip_str, &connection.slug);
+
+    Ok(Json(AuthResponse {
+        message: "Login successful".to_string(),
+        token: Some(session.token),
+        csrf_token: Some(session.csrf_token),
+        refresh_token: Some(session.refresh_token),
+        user_profile: Some(profile),
+    }))
+}
+
+/// GET /api/v1/user/admin/sso
+pub async fn list_connections_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<Vec<SsoConnection>>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+
+    let connections = sqlx::query_as::<_, SsoConnection>(&format!(
+        "SELECT {CONNECTION_COLUMNS} FROM sso_connections ORDER BY slug"
+    ))
+    .fetch_all(&state.pool)
+    .await?;
+    Ok(Json(connections))
+}
+
+/// POST /api/v1/user/admin/sso
+pub async fn create_connection_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Json(payload): Json<SsoConnectionPayload>,
+) -> Result<(StatusCode, Json<SsoConnection>), AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    payload.validate()?;
+    let Some(client_secret) = payload.client_secret.as_deref().filter(|s| !s.is_empty()) else {
+        let mut errors = ValidationErrors::new();
+        errors.add("client_secret", ValidationError::new("required"));
+        return Err(AppError::ValidationError(errors));
+    };
+
+    let connection = sqlx::query_as::<_, SsoConnection>(&format!(
+        "INSERT INTO sso_connections (org_id, slug, issuer, client_id, client_secret, redirect_uri, enabled) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING {CONNECTION_COLUMNS}"
+    ))
+    .bind(payload.org_id)
+    .bind(&payload.slug)
+    .bind(payload.issuer.trim_end_matches('/'))
+    .bind(&payload.client_id)
+    .bind(client_secret)
+    .bind(&payload.redirect_uri)
+    .bind(payload.enabled)
+    .fetch_one(&state.pool)
+    .await?;
+
+    sec::log_security_event("SSO_CONNECTION_CREATE", Some(&claims.username), None, &connection.slug);
+    Ok((StatusCode::CREATED, Json(connection)))
+}
+
+/// PUT /api/v1/user/admin/sso/{id}
+pub async fn update_connection_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<SsoConnectionPayload>,
+) -> Result<Json<SsoConnection>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    payload.validate()?;
+
+    let connection = sqlx::query_as::<_, SsoConnection>(&format!(
+        "UPDATE sso_connections SET org_id = $2, slug = $3, issuer = $4, client_id = $5, \
+         client_secret = COALESCE(NULLIF($6, ''), client_secret), redirect_uri = $7, enabled = $8 \
+         WHERE id = $1 RETURNING {CONNECTION_COLUMNS}"
+    ))
+    .bind(id)
+    .bind(payload.org_id)
+    .bind(&payload.slug)
+    .bind(payload.issuer.trim_end_matches('/'))
+    .bind(&payload.client_id)
+    .bind(payload.client_secret.as_deref())
+    .bind(&payload.redirect_uri)
+    .bind(payload.enabled)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("SSO_CONNECTION_UPDATE", Some(&claims.username), None, &connection.slug);
+    Ok(Json(connection))
+}
+
+/// DELETE /api/v1/user/admin/sso/{id}
+///
+/// Accounts provisioned through the connection stay, but can no longer sign in.
+pub async fn delete_connection_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+
+    let deleted = sqlx::query("DELETE FROM sso_connections WHERE id = $1")
+        .bind(id)
+        .execute(&state.pool)
+        .await?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(AppError::UserNotFound);
+    }
+
+    sec::log_security_event("SSO_CONNECTION_DELETE", Some(&claims.username), None, &id.to_string());
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pkce_challenge_matches_rfc_7636() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_username_candidates() {
+        assert_eq!(username_candidate(Some("jane.doe@acme.com")), "jane_doe");
+        assert_eq!(username_candidate(Some("r-smith")), "r-smith");
+        assert_eq!(username_candidate(Some("é")), "sso-user");
+        assert_eq!(username_candidate(None), "sso-user");
+        assert_eq!(username_candidate(Some(&"a".repeat(80))).len(), 40);
+    }
+
+    #[test]
+    fn test_only_verified_emails_link_accounts() {
+        let claims = |extra: serde_json::Value| -> IdTokenClaims {
+            let mut value = serde_json::json!({ "sub": "00u1", "email": "jane@acme.com" });
+            value.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+            serde_json::from_value(value).unwrap()
+        };
+
+        assert_eq!(claims(serde_json::json!({})).verified_email(), None);
+        assert_eq!(claims(serde_json::json!({ "email_verified": false })).verified_email(), None);
+        assert_eq!(claims(serde_json::json!({ "email_verified": "false" })).verified_email(), None);
+        assert_eq!(claims(serde_json::json!({ "email_verified": true })).verified_email().map(String::as_str), Some("jane@acme.com"));
+        assert_eq!(claims(serde_json::json!({ "email_verified": "true" })).verified_email().map(String::as_str), Some("jane@acme.com"));
+    }
+
+    #[test]
+    fn test_authorization_url() {
+        let metadata = ProviderMetadata {
+            issuer: "https://acme.okta.com".to_string(),
+            authorization_endpoint: "https://acme.okta.com/oauth2/v1/authorize".to_string(),
+            token_endpoint: "https://acme.okta.com/oauth2/v1/token".to_string(),
+            jwks_uri: "https://acme.okta.com/oauth2/v1/keys".to_string(),
+        };
+        let connection = SsoConnection {
+            id: Uuid::nil(),
+            org_id: Uuid::nil(),
+            slug: "acme".to_string(),
+            issuer: metadata.issuer.clone(),
+            client_id: "client-123".to_string(),
+            client_secret: "secret".to_string(),
+            redirect_uri: "https://app.struktura.dev/sso/acme".to_string(),
+            enabled: true,
+            created_at: OffsetDateTime::UNIX_EPOCH,
+        };
+
+        let url = authorization_url(&metadata, &connection, "st", "nn", "verifier").unwrap();
+        let parsed = reqwest::Url::parse(&url).unwrap();
+        let query: std::collections::HashMap<_, _> = parsed.query_pairs().into_owned().collect();
+        assert_eq!(query["client_id"], "client-123");
+        assert_eq!(query["redirect_uri"], "https://app.struktura.dev/sso/acme");
+        assert_eq!(query["code_challenge"], pkce_challenge("verifier"));
+        assert_eq!(query["code_challenge_method"], "S256");
+        assert!(!url.contains("secret"));
+    }
+}
@@ -1,15 +1,28 @@
 use sqlx::postgres::PgPool;
 use std::sync::Arc;
 use std::net::IpAddr;
-use governor::{RateLimiter, state::keyed::DashMapStateStore, clock::DefaultClock};
+use governor::{RateLimiter, state::keyed::DashMapStateStore, clock::DefaultClock, middleware::StateInformationMiddleware};
 
 use crate::sec::{SecurityConfig, TokenBlacklist, CsrfTokenStore};
 use crate::calculus::beginner::BeginnerRegistry;
 use crate::calculus::engineer::EngineeringRegistry;
 use crate::calculus::contractor::ContractingRegistry;
+use crate::calculus::beginner::sketch::VisionProvider;
+use crate::trial::TrialQuota;
+use crate::billing::BillingConfig;
+use crate::calculus::demo::DemoCatalogue;
+use crate::flags::FeatureFlags;
+use crate::tenancy::TenantDirectory;
+use crate::maintenance::Maintenance;
+use crate::notifications::Mailer;
+use crate::password::PasswordPolicy;
+use crate::jobs::JobQueue;
+use crate::pricing::PricingEngine;
 
-/// Type alias for IP-based rate limiter using DashMap state store
-pub type IpRateLimiter = Arc<RateLimiter<IpAddr, DashMapStateStore<IpAddr>, DefaultClock>>;
+/// Type alias for IP-based rate limiter using DashMap state store; checks
+/// report the remaining quota so responses can advertise it
+pub type IpRateLimiter =
+    Arc<RateLimiter<IpAddr, DashMapStateStore<IpAddr>, DefaultClock, StateInformationMiddleware>>;
 
 /// Application state shared across all handlers
 #[derive(Clone)]
@@ -29,4 +42,40 @@ pub struct AppState {
 
     /// Contractor calculator registry
     pub calculators_contractor: Arc<ContractingRegistry>,
+
+    /// Optional OCR/vision backend for sketch dimension extraction
+    pub sketch_vision: Option<Arc<dyn VisionProvider>>,
+
+    /// Anonymous beginner calculations allowed before signup
+    pub trial_quota: TrialQuota,
+
+    /// Stripe billing; plan entitlements are only enforced when configured
+    pub billing: Option<Arc<BillingConfig>>,
+
+    /// Feature flags, consulted before serving gated calculators
+    pub flags: FeatureFlags,
+
+    /// Canned example responses served by the demo routes
+    pub demo: Arc<DemoCatalogue>,
+
+    /// Caller organizations and per-organization request windows
+    pub tenants: TenantDirectory,
+
+    /// Read-only switch for database maintenance
+    pub maintenance: Maintenance,
+
+    /// Rules new passwords must satisfy
+    pub password_policy: PasswordPolicy,
+
+    /// Delivers verification and password-reset email
+    pub mailer: Arc<dyn Mailer>,
+
+    /// Calculations queued with `"async": true`, backed by Postgres
+    pub jobs: JobQueue,
+
+    /// Regional material prices for calculations with `use_live_pricing`
+    pub pricing: Arc<PricingEngine>,
+
+    /// Running without a database: no migrations, metering or quotas
+    pub sandbox: bool,
 }
\ No newline at end of file
@@ -1,11 +1,25 @@
-use sqlx::types::time::OffsetDateTime;
-use axum::{extract::State, response::Json};
-use serde::Serialize;
-use std::sync::Arc;
+use sqlx::{postgres::{PgArguments, PgPool}, query::QueryAs, types::time::OffsetDateTime, Postgres};
+use axum::{
+    body::{to_bytes, Body},
+    extract::{OptionalFromRequestParts, Query, Request, State},
+    http::{header, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, sync::Arc, time::Instant};
+use time::Month;
 use uuid::Uuid;
 
+use crate::billing::{self, Plan};
+use crate::calculus::contractor::inspections::{self, saved::{load_inspections, SavedInspection}, TradeQuality};
+use crate::calculus::recommendation::Tier;
 use crate::state::AppState;
-use crate::sec::{AppError, Claims};
+use crate::sec::{self, AppError, Claims};
+use crate::tenancy::TenantScope;
+use crate::utils::pdf::PdfDocument;
 
 #[derive(Serialize)]
 pub struct UsageStatsResponse {
@@ -74,4 +88,667 @@ pub async fn get_my_usage_stats_handler(
         features_accessed: feature_stats,
         last_activity: total.last_activity,
     }))
-}
\ No newline at end of file
+}
+/// Append one row to `usage_metrics`
+///
+/// Feature names are dotted by family: `calculus.<tier>`, `export.<format>`,
+/// `pricing.<action>`, `apikey.<route>`. Statements group on the prefix.
+pub async fn record_usage(
+    pool: &PgPool,
+    user_id: Uuid,
+    feature_name: &str,
+    experience_level: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO usage_metrics (user_id, feature_name, experience_level_used) VALUES ($1, $2, $3)")
+        .bind(user_id)
+        .bind(feature_name)
+        .bind(experience_level)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+// =============================================================================
+// USAGE STATEMENTS
+// =============================================================================
+
+/// Usage for one billing period, for invoice reconciliation
+///
+/// The account is the billing entity: one statement covers everything the
+/// subscription paid for in the period.
+#[derive(Debug, Serialize)]
+pub struct UsageStatement {
+    pub account: String,
+    pub plan: Plan,
+    pub period_start: OffsetDateTime,
+    pub period_end: OffsetDateTime,
+    pub calculations_by_tier: BTreeMap<String, i64>,
+    pub calculations: i64,
+    pub exports: i64,
+    pub pricing_lookups: i64,
+    pub api_key_requests: i64,
+    pub other: i64,
+}
+
+impl UsageStatement {
+    /// Fold `(feature_name, count)` rows into statement lines
+    fn tally(&mut self, rows: impl IntoIterator<Item = (String, i64)>) {
+        for (feature, count) in rows {
+            let (family, rest) = feature.split_once('.').unwrap_or((feature.as_str(), ""));
+            match family {
+                "calculus" => {
+                    *self.calculations_by_tier.entry(rest.to_string()).or_default() += count;
+                    self.calculations += count;
+                }
+                "export" => self.exports += count,
+                "pricing" => self.pricing_lookups += count,
+                "apikey" => self.api_key_requests += count,
+                _ => self.other += count,
+            }
+        }
+    }
+
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let date = |d: &OffsetDateTime| format!("{}-{:02}-{:02}", d.year(), d.month() as u8, d.day());
+        let amount = |n: i64| n.to_string();
+
+        let mut doc = PdfDocument::new()
+            .title("Struktura Usage Statement")
+            .text(&format!("Account: {}", self.account))
+            .text(&format!("Plan: {}", self.plan.as_str()))
+            .text(&format!("Billing period: {} to {}", date(&self.period_start), date(&self.period_end)))
+            .spacer()
+            .heading("Calculations by tier")
+            .bold_row(&[(0.0, "Tier"), (300.0, "Count")]);
+        for (tier, count) in &self.calculations_by_tier {
+            doc = doc.row(&[(0.0, tier), (300.0, &amount(*count))]);
+        }
+        doc.bold_row(&[(0.0, "Total calculations"), (300.0, &amount(self.calculations))])
+            .spacer()
+            .heading("Other metered usage")
+            .row(&[(0.0, "Exports"), (300.0, &amount(self.exports))])
+            .row(&[(0.0, "Pricing lookups"), (300.0, &amount(self.pricing_lookups))])
+            .row(&[(0.0, "API key requests"), (300.0, &amount(self.api_key_requests))])
+            .row(&[(0.0, "Other"), (300.0, &amount(self.other))])
+            .to_bytes()
+    }
+}
+
+/// Move a date by whole months, clamping the day to the target month
+fn shift_months(date: OffsetDateTime, months: i32) -> OffsetDateTime {
+    let total = date.year() * 12 + (date.month() as i32 - 1) + months;
+    let (year, month) = (total.div_euclid(12), Month::try_from((total.rem_euclid(12) + 1) as u8).unwrap_or(Month::January));
+    let day = date.day().min(month.length(year));
+    date.replace_day(1)
+        .and_then(|d| d.replace_year(year))
+        .and_then(|d| d.replace_month(month))
+        .and_then(|d| d.replace_day(day))
+        .unwrap_or(date)
+}
+
+#[derive(Deserialize)]
+pub struct StatementQuery {
+    /// Billing cycles back from the current one (0 = current)
+    #[serde(default)]
+    pub cycle: u32,
+}
+
+async fn build_statement(
+    app_state: &AppState,
+    claims: &Claims,
+    cycle: u32,
+) -> Result<UsageStatement, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+    let subscription = billing::subscription_for(&app_state.pool, user_id).await?;
+
+    // Cycles are anchored on the subscription's current period start
+    let anchor = billing::period_start(subscription.as_ref());
+    let period_start = shift_months(anchor, -(cycle as i32));
+    let period_end = shift_months(period_start, 1);
+
+    let rows = sqlx::query_as::<_, (String, i64)>(
+        r#"
+        SELECT feature_name, COUNT(*)
+        FROM usage_metrics
+        WHERE user_id = $1 AND accessed_at >= $2 AND accessed_at < $3
+        GROUP BY feature_name
+        "#,
+    )
+    .bind(user_id)
+    .bind(period_start)
+    .bind(period_end)
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    let mut statement = UsageStatement {
+        account: claims.username.clone(),
+        plan: subscription.as_ref().map_or(Plan::Free, |s| s.effective_plan()),
+        period_start,
+        period_end,
+        calculations_by_tier: BTreeMap::new(),
+        calculations: 0,
+        exports: 0,
+        pricing_lookups: 0,
+        api_key_requests: 0,
+        other: 0,
+    };
+    statement.tally(rows);
+    Ok(statement)
+}
+
+pub async fn get_usage_statement_handler(
+    State(app_state): State<Arc<AppState>>,
+    claims: Claims,
+    Query(query): Query<StatementQuery>,
+) -> Result<Json<UsageStatement>, AppError> {
+    let statement = build_statement(&app_state, &claims, query.cycle).await?;
+    crate::sec::log_security_event("STATEMENT_FETCH", Some(&claims.username), None, "Success");
+    Ok(Json(statement))
+}
+
+pub async fn get_usage_statement_pdf_handler(
+    State(app_state): State<Arc<AppState>>,
+    claims: Claims,
+    Query(query): Query<StatementQuery>,
+) -> Result<Response, AppError> {
+    if let Err(response) = billing::check_exports(&app_state, Uuid::parse_str(&claims.sub).ok()).await {
+        return Ok(response);
+    }
+    let statement = build_statement(&app_state, &claims, query.cycle).await?;
+    crate::sec::log_security_event("STATEMENT_PDF", Some(&claims.username), None, "Success");
+
+    let filename = format!(
+        "attachment; filename=\"struktura-usage-{}-{:02}.pdf\"",
+        statement.period_start.year(),
+        statement.period_start.month() as u8
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (header::CONTENT_DISPOSITION, filename),
+        ],
+        statement.to_pdf(),
+    )
+        .into_response())
+}
+
+// =============================================================================
+// CALCULATION HISTORY
+// =============================================================================
+
+/// Largest calculation request read for the history; matches the default
+/// body limit of the calculator routes
+const MAX_AUDITED_BYTES: usize = 2 * 1024 * 1024;
+
+const DEFAULT_HISTORY_PAGE_SIZE: u32 = 50;
+const MAX_HISTORY_PAGE_SIZE: u32 = 200;
+
+/// How a calculation ended, from its response status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Success,
+    /// Refused before or while computing: bad input, plan, quota
+    Rejected,
+    /// Server-side failure
+    Failed,
+}
+
+impl Outcome {
+    pub fn of(status: StatusCode) -> Self {
+        if status.is_success() {
+            Outcome::Success
+        } else if status.is_client_error() {
+            Outcome::Rejected
+        } else {
+            Outcome::Failed
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Success => "success",
+            Outcome::Rejected => "rejected",
+            Outcome::Failed => "failed",
+        }
+    }
+}
+
+/// One calculation a signed-in user ran
+///
+/// Inputs are kept only as a SHA-256 of their canonical JSON: enough to show
+/// two runs used the same parameters without storing the parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalculationRecord {
+    pub tier: Tier,
+    pub path: String,
+    pub calculator_id: Option<String>,
+    pub category: Option<String>,
+    pub parameters_hash: String,
+    pub duration_ms: i64,
+    pub status: StatusCode,
+}
+
+/// SHA-256 of the parameters, independent of key order and whitespace
+pub fn parameters_hash(parameters: &Value) -> String {
+    // serde_json maps are ordered by key, so serializing is canonical
+    let canonical = serde_json::to_vec(parameters).unwrap_or_default();
+    sec::hex(&Sha256::digest(&canonical))
+}
+
+/// Category of a calculator in its tier's registry
+pub fn category_of(state: &AppState, tier: Tier, calculator_id: &str) -> Option<String> {
+    match tier {
+        Tier::Beginner => state.calculators_beginner.find(calculator_id).ok().map(|c| c.category().as_str()),
+        Tier::Engineer => state.calculators_engineer.find(calculator_id).ok().map(|c| c.category().as_str()),
+        Tier::Contractor => state.calculators_contractor.find(calculator_id).ok().map(|c| c.category().as_str()),
+    }
+    .map(str::to_string)
+}
+
+impl CalculationRecord {
+    /// Describe a calculator request from its raw body; bodies that are not
+    /// JSON (schedule imports) are hashed as they are
+    pub fn from_request(state: &AppState, tier: Tier, path: &str, body: &[u8], started: Instant, status: StatusCode) -> Self {
+        let (calculator_id, parameters_hash) = match serde_json::from_slice::<Value>(body) {
+            Ok(value) => (
+                value.get("calculation_type").and_then(Value::as_str).map(str::to_string),
+                parameters_hash(value.get("parameters").unwrap_or(&value)),
+            ),
+            Err(_) => (None, sec::hex(&Sha256::digest(body))),
+        };
+        Self {
+            tier,
+            path: path.to_string(),
+            category: calculator_id.as_deref().and_then(|id| category_of(state, tier, id)),
+            calculator_id,
+            parameters_hash,
+            duration_ms: started.elapsed().as_millis() as i64,
+            status,
+        }
+    }
+
+    pub async fn store(&self, pool: &PgPool, user_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO calculation_history \
+                 (user_id, tier, path, calculator_id, category, parameters_hash, duration_ms, status, outcome) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+        )
+        .bind(user_id)
+        .bind(self.tier.as_str())
+        .bind(&self.path)
+        .bind(&self.calculator_id)
+        .bind(&self.category)
+        .bind(&self.parameters_hash)
+        .bind(self.duration_ms.min(i32::MAX as i64) as i32)
+        .bind(self.status.as_u16() as i16)
+        .bind(Outcome::of(self.status).as_str())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
+
+/// Middleware state: which calculator tier a router serves
+#[derive(Clone)]
+pub struct HistoryRecorder {
+    state: Arc<AppState>,
+    tier: Tier,
+}
+
+impl HistoryRecorder {
+    pub fn new(state: Arc<AppState>, tier: Tier) -> Self {
+        Self { state, tier }
+    }
+}
+
+/// Record every signed-in calculation request, whatever its outcome
+///
+/// Anonymous requests have no history to join. Nothing is written in
+/// sandbox mode or while maintenance keeps the database read-only.
+pub async fn history_middleware(
+    State(recorder): State<HistoryRecorder>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let state = &recorder.state;
+    if request.method() != Method::POST || state.sandbox || state.maintenance.is_active() {
+        return next.run(request).await;
+    }
+
+    let (mut parts, body) = request.into_parts();
+    let user_id = match <Claims as OptionalFromRequestParts<Arc<AppState>>>::from_request_parts(&mut parts, state).await {
+        Ok(Some(claims)) => Uuid::parse_str(&claims.sub).ok(),
+        Ok(None) => None,
+        Err(e) => return e.into_response(),
+    };
+    let Some(user_id) = user_id else {
+        return next.run(Request::from_parts(parts, body)).await;
+    };
+
+    let Ok(request_body) = to_bytes(body, MAX_AUDITED_BYTES).await else {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+    let path = parts.uri.path().to_string();
+
+    let started = Instant::now();
+    let response = next.run(Request::from_parts(parts, Body::from(request_body.clone()))).await;
+
+    let record = CalculationRecord::from_request(state, recorder.tier, &path, &request_body, started, response.status());
+    if let Err(e) = record.store(&state.pool, user_id).await {
+        tracing::error!(error = %e, %user_id, "failed to record calculation history");
+    }
+    response
+}
+
+/// A history entry as listed to its user
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct HistoryEntry {
+    pub id: i64,
+    pub tier: String,
+    pub path: String,
+    pub calculator_id: Option<String>,
+    pub category: Option<String>,
+    pub parameters_hash: String,
+    pub duration_ms: i32,
+    pub status: i16,
+    pub outcome: String,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct HistoryQuery {
+    /// 1-based (default 1)
+    pub page: Option<u32>,
+    /// Default 50, at most 200
+    pub per_page: Option<u32>,
+    pub tier: Option<Tier>,
+    pub category: Option<String>,
+    pub calculator: Option<String>,
+    pub outcome: Option<Outcome>,
+    /// First day included, `YYYY-MM-DD` (UTC)
+    pub from: Option<chrono::NaiveDate>,
+    /// Last day included, `YYYY-MM-DD` (UTC)
+    pub to: Option<chrono::NaiveDate>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HistoryPage {
+    pub entries: Vec<HistoryEntry>,
+    pub page: u32,
+    pub per_page: u32,
+    pub total: i64,
+}
+
+/// Midnight UTC starting `date`
+fn start_of(date: chrono::NaiveDate) -> OffsetDateTime {
+    let timestamp = date.and_time(chrono::NaiveTime::MIN).and_utc().timestamp();
+    OffsetDateTime::from_unix_timestamp(timestamp).unwrap_or(OffsetDateTime::UNIX_EPOCH)
+}
+
+impl HistoryQuery {
+    /// Page number and size, clamped
+    fn paging(&self) -> (u32, u32) {
+        let page = self.page.unwrap_or(1).max(1);
+        let per_page = self.per_page.unwrap_or(DEFAULT_HISTORY_PAGE_SIZE).clamp(1, MAX_HISTORY_PAGE_SIZE);
+        (page, per_page)
+    }
+
+    /// `[from, to)` bounds; `to` is inclusive of its whole day
+    fn range(&self) -> (Option<OffsetDateTime>, Option<OffsetDateTime>) {
+        (
+            self.from.map(start_of),
+            self.to.and_then(|d| d.succ_opt()).map(start_of),
+        )
+    }
+}
+
+const HISTORY_FILTER: &str = "user_id = $1 \
+    AND ($2::text IS NULL OR tier = $2) \
+    AND ($3::text IS NULL OR category = $3) \
+    AND ($4::text IS NULL OR calculator_id = $4) \
+    AND ($5::text IS NULL OR outcome = $5) \
+    AND ($6::timestamptz IS NULL OR created_at >= $6) \
+    AND ($7::timestamptz IS NULL OR created_at < $7)";
+
+/// Bind the user and filters of `HISTORY_FILTER` ($1 to $7)
+fn bind_history_filter<'q, O>(
+    statement: QueryAs<'q, Postgres, O, PgArguments>,
+    user_id: Uuid,
+    query: &'q HistoryQuery,
+) -> QueryAs<'q, Postgres, O, PgArguments> {
+    let (from, to) = query.range();
+    statement
+        .bind(user_id)
+        .bind(query.tier.map(|t| t.as_str()))
+        .bind(query.category.as_deref())
+        .bind(query.calculator.as_deref())
+        .bind(query.outcome.map(|o| o.as_str()))
+        .bind(from)
+        .bind(to)
+}
+
+/// GET /api/v1/user/history: the caller's calculations, newest first
+pub async fn get_history_handler(
+    State(app_state): State<Arc<AppState>>,
+    claims: Claims,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<HistoryPage>, AppError> {
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+    let (page, per_page) = query.paging();
+
+    let (total,) = bind_history_filter(
+        sqlx::query_as::<_, (i64,)>(&format!("SELECT COUNT(*) FROM calculation_history WHERE {HISTORY_FILTER}")),
+        user_id,
+        &query,
+    )
+    .fetch_one(&app_state.pool)
+    .await?;
+
+    let entries = bind_history_filter(
+        sqlx::query_as::<_, HistoryEntry>(&format!(
+            "SELECT id, tier, path, calculator_id, category, parameters_hash, duration_ms, status, outcome, created_at \
+             FROM calculation_history WHERE {HISTORY_FILTER} \
+             ORDER BY created_at DESC, id DESC LIMIT $8 OFFSET $9"
+        )),
+        user_id,
+        &query,
+    )
+    .bind(per_page as i64)
+    .bind((page as i64 - 1) * per_page as i64)
+    .fetch_all(&app_state.pool)
+    .await?;
+
+    crate::sec::log_security_event("HISTORY_FETCH", Some(&claims.username), None, "Success");
+    Ok(Json(HistoryPage { entries, page, per_page, total }))
+}
+
+// =============================================================================
+// QUALITY
+// =============================================================================
+
+/// First-time quality of an organization's inspections
+#[derive(Debug, Serialize)]
+pub struct QualityStats {
+    pub inspections: usize,
+    pub inspected_items: usize,
+    pub first_time_passes: usize,
+    pub first_time_quality: f64,
+    pub by_trade: Vec<TradeQuality>,
+    /// First-time quality per `YYYY-MM` of inspection date
+    pub by_month: BTreeMap<String, f64>,
+}
+
+#[derive(Deserialize)]
+pub struct QualityQuery {
+    pub project: Option<String>,
+}
+
+impl QualityStats {
+    fn from_inspections(saved: Vec<SavedInspection>) -> Self {
+        let rate = |passes: usize, inspected: usize| {
+            if inspected == 0 { 0.0 } else { passes as f64 / inspected as f64 * 100.0 }
+        };
+
+        let mut months: BTreeMap<String, Vec<inspections::Inspection>> = BTreeMap::new();
+        for s in &saved {
+            months
+                .entry(s.inspection_date.format("%Y-%m").to_string())
+                .or_default()
+                .push(s.inspection.clone());
+        }
+        let by_month = months
+            .into_iter()
+            .map(|(month, inspections)| {
+                let trades = inspections::first_time_quality(&inspections);
+                let passes = trades.iter().map(|t| t.first_time_passes).sum();
+                let inspected = trades.iter().map(|t| t.inspected).sum();
+                (month, rate(passes, inspected))
+            })
+            .collect();
+
+        let all: Vec<_> = saved.into_iter().map(|s| s.inspection).collect();
+        let by_trade = inspections::first_time_quality(&all);
+        let first_time_passes = by_trade.iter().map(|t| t.first_time_passes).sum();
+        let inspected_items = by_trade.iter().map(|t| t.inspected).sum();
+        QualityStats {
+            inspections: all.len(),
+            inspected_items,
+            first_time_passes,
+            first_time_quality: rate(first_time_passes, inspected_items),
+            by_trade,
+            by_month,
+        }
+    }
+}
+
+pub async fn get_quality_stats_handler(
+    State(app_state): State<Arc<AppState>>,
+    scope: TenantScope,
+    Query(query): Query<QualityQuery>,
+) -> Result<Json<QualityStats>, AppError> {
+    let saved = load_inspections(&app_state.pool, &scope, query.project.as_deref(), None).await?;
+    crate::sec::log_security_event("QUALITY_STATS_FETCH", Some(&scope.username), None, "Success");
+    Ok(Json(QualityStats::from_inspections(saved)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn empty_statement() -> UsageStatement {
+        UsageStatement {
+            account: "alice".to_string(),
+            plan: Plan::Pro,
+            period_start: datetime!(2025-01-15 0:00 UTC),
+            period_end: datetime!(2025-02-15 0:00 UTC),
+            calculations_by_tier: BTreeMap::new(),
+            calculations: 0,
+            exports: 0,
+            pricing_lookups: 0,
+            api_key_requests: 0,
+            other: 0,
+        }
+    }
+
+    #[test]
+    fn test_statement_groups_feature_families() {
+        let mut statement = empty_statement();
+        statement.tally(vec![
+            ("calculus.engineer".to_string(), 7),
+            ("calculus.beginner".to_string(), 3),
+            ("export.pdf".to_string(), 2),
+            ("pricing.lookup".to_string(), 5),
+            ("apikey.calculate".to_string(), 11),
+            ("profile".to_string(), 1),
+        ]);
+
+        assert_eq!(statement.calculations, 10);
+        assert_eq!(statement.calculations_by_tier["engineer"], 7);
+        assert_eq!(statement.exports, 2);
+        assert_eq!(statement.pricing_lookups, 5);
+        assert_eq!(statement.api_key_requests, 11);
+        assert_eq!(statement.other, 1);
+        assert!(statement.to_pdf().starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn test_quality_by_month() {
+        use crate::calculus::contractor::inspections::{Inspection, ItemResult, Outcome, ProjectScope, RoofingScope};
+
+        let items = ProjectScope { roofing: Some(RoofingScope { area_m2: 500.0, ..Default::default() }), ..Default::default() }
+            .checklists()
+            .remove(0)
+            .items;
+        let saved = |date: &str, outcomes: &[(usize, Outcome, u32)]| SavedInspection {
+            id: Uuid::nil(),
+            org_id: Uuid::nil(),
+            project_name: "Depot".to_string(),
+            inspection_date: date.parse().unwrap(),
+            inspector: None,
+            inspection: Inspection {
+                trade: inspections::Trade::Roofing,
+                items: items.clone(),
+                results: outcomes
+                    .iter()
+                    .map(|&(i, outcome, reinspections)| ItemResult {
+                        item_id: items[i].id.clone(),
+                        outcome,
+                        reinspections,
+                        notes: None,
+                    })
+                    .collect(),
+            },
+            created_at: datetime!(2025-01-01 0:00 UTC),
+            updated_at: datetime!(2025-01-01 0:00 UTC),
+        };
+
+        let stats = QualityStats::from_inspections(vec![
+            saved("2025-03-04", &[(0, Outcome::Pass, 0), (1, Outcome::Pass, 2)]),
+            saved("2025-04-10", &[(0, Outcome::Pass, 0), (1, Outcome::Pass, 0), (2, Outcome::NotApplicable, 0)]),
+        ]);
+        assert_eq!(stats.inspections, 2);
+        assert_eq!(stats.inspected_items, 4);
+        assert!((stats.first_time_quality - 75.0).abs() < 1e-9);
+        assert!((stats.by_month["2025-03"] - 50.0).abs() < 1e-9);
+        assert!((stats.by_month["2025-04"] - 100.0).abs() < 1e-9);
+        assert_eq!(stats.by_trade.len(), 1);
+    }
+
+    #[test]
+    fn test_parameters_hash_is_canonical() {
+        let a: Value = serde_json::from_str(r#"{"span": 6.0, "load": {"dead": 2.5, "live": 3.0}}"#).unwrap();
+        let b: Value = serde_json::from_str(r#"{ "load": {"live": 3.0, "dead": 2.5}, "span": 6.0 }"#).unwrap();
+        let c: Value = serde_json::from_str(r#"{"span": 6.5, "load": {"dead": 2.5, "live": 3.0}}"#).unwrap();
+
+        assert_eq!(parameters_hash(&a), parameters_hash(&b));
+        assert_ne!(parameters_hash(&a), parameters_hash(&c));
+        assert_eq!(parameters_hash(&a).len(), 64);
+    }
+
+    #[test]
+    fn test_history_query_paging_and_range() {
+        let query = HistoryQuery {
+            page: Some(0),
+            per_page: Some(1_000),
+            from: chrono::NaiveDate::from_ymd_opt(2025, 3, 1),
+            to: chrono::NaiveDate::from_ymd_opt(2025, 3, 31),
+            ..Default::default()
+        };
+        assert_eq!(query.paging(), (1, MAX_HISTORY_PAGE_SIZE));
+        assert_eq!(query.range(), (Some(datetime!(2025-03-01 0:00 UTC)), Some(datetime!(2025-04-01 0:00 UTC))));
+        assert_eq!(HistoryQuery::default().paging(), (1, DEFAULT_HISTORY_PAGE_SIZE));
+
+        assert_eq!(Outcome::of(StatusCode::OK), Outcome::Success);
+        assert_eq!(Outcome::of(StatusCode::PAYMENT_REQUIRED), Outcome::Rejected);
+        assert_eq!(Outcome::of(StatusCode::INTERNAL_SERVER_ERROR), Outcome::Failed);
+    }
+
+    #[test]
+    fn test_shift_months_clamps_day() {
+        assert_eq!(shift_months(datetime!(2025-01-31 0:00 UTC), 1), datetime!(2025-02-28 0:00 UTC));
+        assert_eq!(shift_months(datetime!(2025-01-15 0:00 UTC), -1), datetime!(2024-12-15 0:00 UTC));
+        assert_eq!(shift_months(datetime!(2025-03-31 0:00 UTC), -13), datetime!(2024-02-29 0:00 UTC));
+    }
+}
@@ -0,0 +1,753 @@
+//! Public status: component health, incidents and planned maintenance
+//!
+//! `GET /api/v1/status` is what the SPA and monitoring bots poll instead of
+//! the bare health check. It reports:
+//!
+//! - the live state of each component (API, database, calculators, job
+//!   queue, third-party integrations) and an overall status, the worst of
+//!   them
+//! - daily uptime per component over the last `history_days` (default 30,
+//!   at most 90), from probes every instance records every
+//!   `STATUS_PROBE_INTERVAL_SECONDS` (default 60)
+//! - open incidents and those resolved in the last week
+//! - maintenance in progress (the live switch or a planned window) and
+//!   windows announced ahead
+//!
+//! Incidents and planned windows are posted by admins under
+//! `/api/v1/user/admin/status`. Probes are kept for 90 days.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::time::OffsetDateTime;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::resilience::{CircuitState, Guard};
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+
+const DEFAULT_PROBE_SECONDS: u64 = 60;
+const DEFAULT_HISTORY_DAYS: u32 = 30;
+
+/// How long probes are kept, and the longest history served
+pub const HISTORY_RETENTION_DAYS: u32 = 90;
+
+/// Resolved incidents stay listed this long
+const RECENT_INCIDENT_DAYS: i64 = 7;
+
+const DATABASE_TIMEOUT: Duration = Duration::from_secs(2);
+const DATABASE_SLOW_MS: u64 = 500;
+
+/// Queued jobs waiting longer than this mean the workers are behind
+const JOB_BACKLOG_SECONDS: f64 = 300.0;
+
+/// Components reported, in display order
+pub const COMPONENTS: &[&str] = &["api", "database", "calculators", "jobs", "integrations"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentStatus {
+    Operational,
+    Maintenance,
+    Degraded,
+    Outage,
+}
+
+impl ComponentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComponentStatus::Operational => "operational",
+            ComponentStatus::Maintenance => "maintenance",
+            ComponentStatus::Degraded => "degraded",
+            ComponentStatus::Outage => "outage",
+        }
+    }
+}
+
+fn utc(at: OffsetDateTime) -> DateTime<Utc> {
+    DateTime::from_timestamp(at.unix_timestamp(), at.nanosecond()).unwrap_or_default()
+}
+
+fn offset(at: DateTime<Utc>) -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(at.timestamp()).unwrap_or(OffsetDateTime::UNIX_EPOCH)
+}
+
+// ============================================================================
+// Components
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentHealth {
+    pub name: &'static str,
+    pub status: ComponentStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+}
+
+impl ComponentHealth {
+    fn new(name: &'static str, status: ComponentStatus) -> Self {
+        Self { name, status, latency_ms: None }
+    }
+}
+
+/// Integrations are degraded while any outbound circuit is open
+fn integrations_status(guards: &[(String, CircuitState)]) -> ComponentStatus {
+    if guards.iter().any(|(_, circuit)| *circuit == CircuitState::Open) {
+        ComponentStatus::Degraded
+    } else {
+        ComponentStatus::Operational
+    }
+}
+
+async fn database_health(state: &AppState) -> ComponentHealth {
+    let started = Instant::now();
+    let ping = tokio::time::timeout(DATABASE_TIMEOUT, sqlx::query("SELECT 1").execute(&state.pool)).await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let status = match ping {
+        Ok(Ok(_)) if latency_ms > DATABASE_SLOW_MS => ComponentStatus::Degraded,
+        Ok(Ok(_)) => ComponentStatus::Operational,
+        _ => ComponentStatus::Outage,
+    };
+    ComponentHealth { name: "database", status, latency_ms: Some(latency_ms) }
+}
+
+async fn jobs_health(state: &AppState) -> ComponentHealth {
+    let oldest_wait = sqlx::query_scalar::<_, Option<f64>>(
+        "SELECT EXTRACT(EPOCH FROM NOW() - MIN(created_at))::float8 FROM calculation_jobs WHERE status = 'queued'",
+    )
+    .fetch_one(&state.pool)
+    .await;
+
+    let status = match oldest_wait {
+        Ok(Some(seconds)) if seconds > JOB_BACKLOG_SECONDS => ComponentStatus::Degraded,
+        Ok(_) => ComponentStatus::Operational,
+        Err(_) => ComponentStatus::Outage,
+    };
+    ComponentHealth::new("jobs", status)
+}
+
+/// Check every component now
+pub async fn check_components(state: &AppState) -> Vec<ComponentHealth> {
+    let calculators = state.calculators_beginner.stats().total_calculators
+        + state.calculators_engineer.stats().total_calculators
+        + state.calculators_contractor.stats().total_calculators;
+    let guards: Vec<(String, CircuitState)> = Guard::all_stats().into_iter().map(|g| (g.name, g.circuit)).collect();
+
+    let mut components = vec![
+        ComponentHealth::new("api", ComponentStatus::Operational),
+        ComponentHealth::new(
+            "calculators",
+            if calculators > 0 { ComponentStatus::Operational } else { ComponentStatus::Outage },
+        ),
+        ComponentHealth::new("integrations", integrations_status(&guards)),
+    ];
+
+    // Sandbox mode has no database, so nothing behind it to check
+    if !state.sandbox {
+        if state.maintenance.is_active() {
+            components.push(ComponentHealth::new("database", ComponentStatus::Maintenance));
+            components.push(ComponentHealth::new("jobs", ComponentStatus::Maintenance));
+        } else {
+            let database = database_health(state).await;
+            let jobs = if database.status == ComponentStatus::Outage {
+                ComponentHealth::new("jobs", ComponentStatus::Outage)
+            } else {
+                jobs_health(state).await
+            };
+            components.push(database);
+            components.push(jobs);
+        }
+    }
+
+    components.sort_by_key(|c| COMPONENTS.iter().position(|name| *name == c.name));
+    components
+}
+
+/// The worst component status
+pub fn overall(components: &[ComponentHealth]) -> ComponentStatus {
+    components.iter().map(|c| c.status).max().unwrap_or(ComponentStatus::Operational)
+}
+
+// ============================================================================
+// Probes
+// ============================================================================
+
+fn probe_interval() -> Duration {
+    std::env::var("STATUS_PROBE_INTERVAL_SECONDS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|s| *s > 0)
+        .map(Duration::from_secs)
+        .unwrap_or(Duration::from_secs(DEFAULT_PROBE_SECONDS))
+}
+
+async fn record_probe(state: &AppState) -> Result<(), sqlx::Error> {
+    let components = check_components(state).await;
+    let names: Vec<&str> = components.iter().map(|c| c.name).collect();
+    let statuses: Vec<&str> = components.iter().map(|c| c.status.as_str()).collect();
+    let latencies: Vec<Option<i32>> = components.iter().map(|c| c.latency_ms.map(|ms| ms as i32)).collect();
+
+    sqlx::query(
+        "INSERT INTO status_checks (component, status, latency_ms) \
+         SELECT * FROM UNNEST($1::text[], $2::text[], $3::int[])",
+    )
+    .bind(&names)
+    .bind(&statuses)
+    .bind(&latencies)
+    .execute(&state.pool)
+    .await?;
+    Ok(())
+}
+
+/// Probe on the configured cadence; nothing is written during maintenance
+pub fn spawn(state: Arc<AppState>) {
+    let period = probe_interval();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            if state.maintenance.is_active() {
+                continue;
+            }
+            if let Err(e) = record_probe(&state).await {
+                tracing::warn!(error = %e, "status probe not recorded");
+            }
+        }
+    });
+}
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct DailyUptime {
+    pub date: NaiveDate,
+    pub checks: i64,
+    /// Share of probes that found the component operational or in planned
+    /// maintenance, in percent
+    pub uptime_percent: f64,
+    /// Worst status seen that day
+    pub worst: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComponentHistory {
+    pub component: &'static str,
+    pub days: Vec<DailyUptime>,
+}
+
+#[derive(sqlx::FromRow)]
+struct DailyRow {
+    component: String,
+    day: String,
+    checks: i64,
+    up: i64,
+    worst: String,
+}
+
+/// Severity rank in SQL, matching [`ComponentStatus`]'s ordering
+const SEVERITY: &str = "CASE status WHEN 'outage' THEN 3 WHEN 'degraded' THEN 2 WHEN 'maintenance' THEN 1 ELSE 0 END";
+
+async fn history(state: &AppState, days: u32) -> Result<Vec<ComponentHistory>, AppError> {
+    let rows = sqlx::query_as::<_, DailyRow>(&format!(
+        "SELECT component, to_char(date_trunc('day', checked_at AT TIME ZONE 'UTC'), 'YYYY-MM-DD') AS day, \
+                COUNT(*) AS checks, \
+                COUNT(*) FILTER (WHERE status IN ('operational', 'maintenance')) AS up, \
+                (ARRAY['operational', 'maintenance', 'degraded', 'outage'])[MAX({SEVERITY}) + 1] AS worst \
+         FROM status_checks \
+         WHERE checked_at >= date_trunc('day', NOW() AT TIME ZONE 'UTC') AT TIME ZONE 'UTC' - make_interval(days => $1 - 1) \
+         GROUP BY component, day ORDER BY component, day"
+    ))
+    .bind(days as i32)
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(COMPONENTS
+        .iter()
+        .map(|component| ComponentHistory {
+            component,
+            days: rows
+                .iter()
+                .filter(|row| row.component == *component)
+                .filter_map(|row| {
+                    Some(DailyUptime {
+                        date: NaiveDate::parse_from_str(&row.day, "%Y-%m-%d").ok()?,
+                        checks: row.checks,
+                        uptime_percent: uptime_percent(row.up, row.checks),
+                        worst: row.worst.clone(),
+                    })
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+fn uptime_percent(up: i64, checks: i64) -> f64 {
+    if checks == 0 {
+        return 100.0;
+    }
+    (up as f64 / checks as f64 * 10_000.0).round() / 100.0
+}
+
+// ============================================================================
+// Incidents and maintenance windows
+// ============================================================================
+
+#[derive(sqlx::FromRow)]
+struct IncidentRow {
+    id: Uuid,
+    title: String,
+    message: String,
+    impact: String,
+    status: String,
+    components: Vec<String>,
+    started_at: OffsetDateTime,
+    resolved_at: Option<OffsetDateTime>,
+    updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Incident {
+    pub id: Uuid,
+    pub title: String,
+    pub message: String,
+    pub impact: String,
+    pub status: String,
+    pub components: Vec<String>,
+    pub started_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<IncidentRow> for Incident {
+    fn from(row: IncidentRow) -> Self {
+        Self {
+            id: row.id,
+            title: row.title,
+            message: row.message,
+            impact: row.impact,
+            status: row.status,
+            components: row.components,
+            started_at: utc(row.started_at),
+            resolved_at: row.resolved_at.map(utc),
+            updated_at: utc(row.updated_at),
+        }
+    }
+}
+
+const INCIDENT_COLUMNS: &str = "id, title, message, impact, status, components, started_at, resolved_at, updated_at";
+
+#[derive(sqlx::FromRow)]
+struct WindowRow {
+    id: Uuid,
+    title: String,
+    description: String,
+    components: Vec<String>,
+    starts_at: OffsetDateTime,
+    ends_at: OffsetDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlannedMaintenance {
+    /// `None` for the live maintenance switch
+    pub id: Option<Uuid>,
+    pub title: String,
+    pub description: String,
+    pub components: Vec<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+}
+
+impl From<WindowRow> for PlannedMaintenance {
+    fn from(row: WindowRow) -> Self {
+        Self {
+            id: Some(row.id),
+            title: row.title,
+            description: row.description,
+            components: row.components,
+            starts_at: utc(row.starts_at),
+            ends_at: Some(utc(row.ends_at)),
+        }
+    }
+}
+
+const WINDOW_COLUMNS: &str = "id, title, description, components, starts_at, ends_at";
+
+fn validate_components(components: &[String]) -> Result<(), ValidationError> {
+    if components.iter().all(|c| COMPONENTS.contains(&c.as_str())) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("unknown_component"))
+    }
+}
+
+fn validate_impact(impact: &str) -> Result<(), ValidationError> {
+    if matches!(impact, "minor" | "major" | "critical") {
+        Ok(())
+    } else {
+        Err(ValidationError::new("impact"))
+    }
+}
+
+fn validate_incident_status(status: &str) -> Result<(), ValidationError> {
+    if matches!(status, "investigating" | "identified" | "monitoring" | "resolved") {
+        Ok(())
+    } else {
+        Err(ValidationError::new("status"))
+    }
+}
+
+fn default_impact() -> String {
+    "minor".to_string()
+}
+
+fn default_incident_status() -> String {
+    "investigating".to_string()
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct IncidentPayload {
+    #[validate(length(min = 1, max = 200))]
+    pub title: String,
+    #[validate(length(min = 1, max = 5000))]
+    pub message: String,
+    #[serde(default = "default_impact")]
+    #[validate(custom(function = "validate_impact"))]
+    pub impact: String,
+    #[serde(default = "default_incident_status")]
+    #[validate(custom(function = "validate_incident_status"))]
+    pub status: String,
+    #[serde(default)]
+    #[validate(custom(function = "validate_components"))]
+    pub components: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct MaintenanceWindowPayload {
+    #[validate(length(min = 1, max = 200))]
+    pub title: String,
+    #[serde(default)]
+    #[validate(length(max = 5000))]
+    pub description: String,
+    #[serde(default)]
+    #[validate(custom(function = "validate_components"))]
+    pub components: Vec<String>,
+    /// RFC 3339
+    pub starts_at: DateTime<Utc>,
+    /// RFC 3339, after `starts_at`
+    pub ends_at: DateTime<Utc>,
+}
+
+impl MaintenanceWindowPayload {
+    fn check(&self) -> Result<(), AppError> {
+        self.validate()?;
+        if self.ends_at <= self.starts_at {
+            let mut errors = ValidationErrors::new();
+            errors.add("ends_at", ValidationError::new("before_start"));
+            return Err(AppError::ValidationError(errors));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct StatusQuery {
+    /// Days of uptime history (default 30, at most 90)
+    pub history_days: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatusPage {
+    pub status: ComponentStatus,
+    pub components: Vec<ComponentHealth>,
+    pub incidents: Vec<Incident>,
+    pub recent_incidents: Vec<Incident>,
+    pub maintenance_in_progress: Vec<PlannedMaintenance>,
+    pub upcoming_maintenance: Vec<PlannedMaintenance>,
+    pub history: Vec<ComponentHistory>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// GET /api/v1/status
+pub async fn status_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<StatusQuery>,
+) -> Result<Json<StatusPage>, AppError> {
+    let components = check_components(&state).await;
+    let mut page = StatusPage {
+        status: overall(&components),
+        components,
+        incidents: Vec::new(),
+        recent_incidents: Vec::new(),
+        maintenance_in_progress: Vec::new(),
+        upcoming_maintenance: Vec::new(),
+        history: Vec::new(),
+        generated_at: Utc::now(),
+    };
+
+    if let Some(window) = state.maintenance.current() {
+        page.maintenance_in_progress.push(PlannedMaintenance {
+            id: None,
+            title: "Scheduled maintenance".to_string(),
+            description: window.message,
+            components: vec!["database".to_string(), "jobs".to_string()],
+            starts_at: window.since,
+            ends_at: window.until,
+        });
+    }
+
+    // Records live in the database; report live checks alone without one
+    if state.sandbox || page.components.iter().any(|c| c.name == "database" && c.status == ComponentStatus::Outage) {
+        return Ok(Json(page));
+    }
+
+    let incidents = sqlx::query_as::<_, IncidentRow>(&format!(
+        "SELECT {INCIDENT_COLUMNS} FROM status_incidents \
+         WHERE resolved_at IS NULL OR resolved_at > NOW() - make_interval(days => $1) \
+         ORDER BY started_at DESC"
+    ))
+    .bind(RECENT_INCIDENT_DAYS as i32)
+    .fetch_all(&state.pool)
+    .await?;
+    for incident in incidents.into_iter().map(Incident::from) {
+        if incident.resolved_at.is_some() {
+            page.recent_incidents.push(incident);
+        } else {
+            page.incidents.push(incident);
+        }
+    }
+
+    let windows = sqlx::query_as::<_, WindowRow>(&format!(
+        "SELECT {WINDOW_COLUMNS} FROM status_maintenance_windows WHERE ends_at > NOW() ORDER BY starts_at"
+    ))
+    .fetch_all(&state.pool)
+    .await?;
+    let now = Utc::now();
+    for window in windows.into_iter().map(PlannedMaintenance::from) {
+        if window.starts_at <= now {
+            page.maintenance_in_progress.push(window);
+        } else {
+            page.upcoming_maintenance.push(window);
+        }
+    }
+
+    if !page.incidents.is_empty() && page.status == ComponentStatus::Operational {
+        page.status = ComponentStatus::Degraded;
+    }
+    if !page.maintenance_in_progress.is_empty() && page.status == ComponentStatus::Operational {
+        page.status = ComponentStatus::Maintenance;
+    }
+
+    let days = query.history_days.unwrap_or(DEFAULT_HISTORY_DAYS).clamp(1, HISTORY_RETENTION_DAYS);
+    page.history = history(&state, days).await?;
+    Ok(Json(page))
+}
+
+/// POST /api/v1/user/admin/status/incidents
+pub async fn create_incident_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Json(payload): Json<IncidentPayload>,
+) -> Result<(StatusCode, Json<Incident>), AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    payload.validate()?;
+
+    let incident = sqlx::query_as::<_, IncidentRow>(&format!(
+        "INSERT INTO status_incidents (title, message, impact, status, components, resolved_at) \
+         VALUES ($1, $2, $3, $4, $5, CASE WHEN $4 = 'resolved' THEN CURRENT_TIMESTAMP END) \
+         RETURNING {INCIDENT_COLUMNS}"
+    ))
+    .bind(&payload.title)
+    .bind(&payload.message)
+    .bind(&payload.impact)
+    .bind(&payload.status)
+    .bind(&payload.components)
+    .fetch_one(&state.pool)
+    .await?;
+
+    sec::log_security_event("STATUS_INCIDENT_CREATE", Some(&claims.username), None, &payload.title);
+    Ok((StatusCode::CREATED, Json(incident.into())))
+}
+
+/// PUT /api/v1/user/admin/status/incidents/{id}
+///
+/// Moving to `resolved` stamps the resolution time; moving away reopens it.
+pub async fn update_incident_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<IncidentPayload>,
+) -> Result<Json<Incident>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    payload.validate()?;
+
+    let incident = sqlx::query_as::<_, IncidentRow>(&format!(
+        "UPDATE status_incidents SET title = $2, message = $3, impact = $4, status = $5, components = $6, \
+         resolved_at = CASE WHEN $5 = 'resolved' THEN COALESCE(resolved_at, CURRENT_TIMESTAMP) END, \
+         updated_at = CURRENT_TIMESTAMP \
+         WHERE id = $1 RETURNING {INCIDENT_COLUMNS}"
+    ))
+    .bind(id)
+    .bind(&payload.title)
+    .bind(&payload.message)
+    .bind(&payload.impact)
+    .bind(&payload.status)
+    .bind(&payload.components)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("STATUS_INCIDENT_UPDATE", Some(&claims.username), None, &format!("{} {}", id, payload.status));
+    Ok(Json(incident.into()))
+}
+
+/// DELETE /api/v1/user/admin/status/incidents/{id}
+pub async fn delete_incident_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    delete_record(&state, "status_incidents", id).await?;
+    sec::log_security_event("STATUS_INCIDENT_DELETE", Some(&claims.username), None, &id.to_string());
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/user/admin/status/maintenance
+pub async fn create_window_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Json(payload): Json<MaintenanceWindowPayload>,
+) -> Result<(StatusCode, Json<PlannedMaintenance>), AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    payload.check()?;
+
+    let window = sqlx::query_as::<_, WindowRow>(&format!(
+        "INSERT INTO status_maintenance_windows (title, description, components, starts_at, ends_at) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING {WINDOW_COLUMNS}"
+    ))
+    .bind(&payload.title)
+    .bind(&payload.description)
+    .bind(&payload.components)
+    .bind(offset(payload.starts_at))
+    .bind(offset(payload.ends_at))
+    .fetch_one(&state.pool)
+    .await?;
+
+    sec::log_security_event("STATUS_MAINTENANCE_CREATE", Some(&claims.username), None, &payload.title);
+    Ok((StatusCode::CREATED, Json(window.into())))
+}
+
+/// PUT /api/v1/user/admin/status/maintenance/{id}
+pub async fn update_window_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<MaintenanceWindowPayload>,
+) -> Result<Json<PlannedMaintenance>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    payload.check()?;
+
+    let window = sqlx::query_as::<_, WindowRow>(&format!(
+        "UPDATE status_maintenance_windows SET title = $2, description = $3, components = $4, \
+         starts_at = $5, ends_at = $6 WHERE id = $1 RETURNING {WINDOW_COLUMNS}"
+    ))
+    .bind(id)
+    .bind(&payload.title)
+    .bind(&payload.description)
+    .bind(&payload.components)
+    .bind(offset(payload.starts_at))
+    .bind(offset(payload.ends_at))
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    sec::log_security_event("STATUS_MAINTENANCE_UPDATE", Some(&claims.username), None, &id.to_string());
+    Ok(Json(window.into()))
+}
+
+/// DELETE /api/v1/user/admin/status/maintenance/{id}
+pub async fn delete_window_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    delete_record(&state, "status_maintenance_windows", id).await?;
+    sec::log_security_event("STATUS_MAINTENANCE_DELETE", Some(&claims.username), None, &id.to_string());
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn delete_record(state: &AppState, table: &str, id: Uuid) -> Result<(), AppError> {
+    let deleted = sqlx::query(&format!("DELETE FROM {table} WHERE id = $1"))
+        .bind(id)
+        .execute(&state.pool)
+        .await?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(AppError::UserNotFound);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overall_is_the_worst_component() {
+        let components = vec![
+            ComponentHealth::new("api", ComponentStatus::Operational),
+            ComponentHealth::new("database", ComponentStatus::Maintenance),
+            ComponentHealth::new("integrations", ComponentStatus::Degraded),
+        ];
+        assert_eq!(overall(&components), ComponentStatus::Degraded);
+        assert_eq!(overall(&components[..2]), ComponentStatus::Maintenance);
+        assert_eq!(overall(&[]), ComponentStatus::Operational);
+    }
+
+    #[test]
+    fn test_open_circuits_degrade_integrations() {
+        let closed = vec![("stripe".to_string(), CircuitState::Closed), ("hibp".to_string(), CircuitState::HalfOpen)];
+        assert_eq!(integrations_status(&closed), ComponentStatus::Operational);
+
+        let open = vec![("stripe".to_string(), CircuitState::Open)];
+        assert_eq!(integrations_status(&open), ComponentStatus::Degraded);
+    }
+
+    #[test]
+    fn test_uptime_percent() {
+        assert_eq!(uptime_percent(1440, 1440), 100.0);
+        assert_eq!(uptime_percent(1439, 1440), 99.93);
+        assert_eq!(uptime_percent(0, 0), 100.0);
+    }
+
+    #[test]
+    fn test_window_payload_checks_order() {
+        let starts_at = Utc::now();
+        let payload = MaintenanceWindowPayload {
+            title: "Database upgrade".to_string(),
+            description: String::new(),
+            components: vec!["database".to_string()],
+            starts_at,
+            ends_at: starts_at - chrono::Duration::hours(1),
+        };
+        assert!(payload.check().is_err());
+
+        let payload = MaintenanceWindowPayload { ends_at: starts_at + chrono::Duration::hours(1), ..payload };
+        assert!(payload.check().is_ok());
+
+        let payload = MaintenanceWindowPayload { components: vec!["coffee".to_string()], ..payload };
+        assert!(payload.check().is_err());
+    }
+}
@@ -0,0 +1,451 @@
+//! Support bundles: debugging artifacts for failed calculations
+//!
+//! Clients opt in per request with `x-support-bundle: true`. When a
+//! calculation then fails, the request's input (with personal data and
+//! credentials redacted), the calculator and its formula version, the error
+//! response and the error chain are stored as a bundle, and its id comes
+//! back in `x-support-bundle-id`. Users quote the id in bug reports;
+//! support downloads the bundle and replays the recorded request. Only the
+//! user whose request failed, and admins, can download a bundle.
+//!
+//! Nothing is captured without the header, for successful responses, or in
+//! sandbox mode (there is no database to keep bundles in).
+
+use axum::{
+    body::{to_bytes, Body, Bytes},
+    extract::{OptionalFromRequestParts, Path, Request, State},
+    http::{header, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::postgres::PgPool;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::types::Json as SqlJson;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::calculus::changelog;
+use crate::calculus::recommendation::Tier;
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+use crate::tenancy::TenantScope;
+
+/// Request header opting a calculation into bundle capture
+pub const SUPPORT_BUNDLE_HEADER: &str = "x-support-bundle";
+
+/// Response header carrying the id of the captured bundle
+pub const SUPPORT_BUNDLE_ID_HEADER: &str = "x-support-bundle-id";
+
+/// Largest request or error body kept in a bundle
+const MAX_CAPTURED_BYTES: usize = 2 * 1024 * 1024;
+
+/// Input keys whose values never leave the request, matched as substrings
+/// of the lowercased key
+const REDACTED_KEYS: &[&str] = &[
+    "password", "token", "secret", "api_key", "authorization", "email", "phone", "address",
+];
+
+const REDACTED: &str = "[redacted]";
+
+// ============================================================================
+// Models
+// ============================================================================
+
+/// Display text of an error and each of its sources, outermost first
+///
+/// Calculator errors attach this to their responses so the capture
+/// middleware sees more than the client-facing message.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ErrorChain(pub Vec<String>);
+
+impl ErrorChain {
+    pub fn of(error: &(dyn std::error::Error + 'static)) -> Self {
+        let mut chain = vec![error.to_string()];
+        let mut source = error.source();
+        while let Some(e) = source {
+            chain.push(e.to_string());
+            source = e.source();
+        }
+        Self(chain)
+    }
+}
+
+/// Everything needed to reproduce one failed calculation
+#[derive(Debug, Clone, Serialize)]
+pub struct SupportBundle {
+    pub id: Uuid,
+    pub request_id: Option<String>,
+    pub tier: Tier,
+    pub path: String,
+    pub calculator_id: Option<String>,
+    /// Formula version of the calculator when the request failed
+    pub calculator_version: Option<String>,
+    pub app_version: String,
+    /// Request body with personal data and credentials redacted
+    pub input: Value,
+    pub status: u16,
+    /// Error body as returned to the client, including validation details
+    pub response: Value,
+    pub error_chain: Vec<String>,
+    pub created_at: OffsetDateTime,
+    /// Signed-in caller whose request failed; `None` for anonymous requests
+    pub user_id: Option<Uuid>,
+    pub org_id: Option<Uuid>,
+}
+
+#[derive(sqlx::FromRow)]
+struct BundleRow {
+    id: Uuid,
+    request_id: Option<String>,
+    tier: String,
+    path: String,
+    calculator_id: Option<String>,
+    calculator_version: Option<String>,
+    app_version: String,
+    input: SqlJson<Value>,
+    status: i16,
+    response: SqlJson<Value>,
+    error_chain: Vec<String>,
+    created_at: OffsetDateTime,
+    user_id: Option<Uuid>,
+    org_id: Option<Uuid>,
+}
+
+impl TryFrom<BundleRow> for SupportBundle {
+    type Error = AppError;
+
+    fn try_from(row: BundleRow) -> Result<Self, AppError> {
+        let tier = row
+            .tier
+            .parse()
+            .map_err(|e| AppError::Internal(format!("Bundle {}: {}", row.id, e)))?;
+        Ok(Self {
+            id: row.id,
+            request_id: row.request_id,
+            tier,
+            path: row.path,
+            calculator_id: row.calculator_id,
+            calculator_version: row.calculator_version,
+            app_version: row.app_version,
+            input: row.input.0,
+            status: row.status as u16,
+            response: row.response.0,
+            error_chain: row.error_chain,
+            created_at: row.created_at,
+            user_id: row.user_id,
+            org_id: row.org_id,
+        })
+    }
+}
+
+// ============================================================================
+// Capture
+// ============================================================================
+
+/// Replace the values of sensitive keys, at any depth
+pub fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let key = key.to_lowercase();
+                if REDACTED_KEYS.iter().any(|k| key.contains(k)) {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+fn parse_body(bytes: &Bytes) -> Value {
+    serde_json::from_slice(bytes).unwrap_or_else(|_| Value::String(String::from_utf8_lossy(bytes).into_owned()))
+}
+
+impl SupportBundle {
+    /// Assemble a bundle from the raw request and error bodies
+    pub fn capture(
+        tier: Tier,
+        path: &str,
+        request_id: Option<String>,
+        request_body: &Bytes,
+        status: StatusCode,
+        response_body: &Bytes,
+        error_chain: Option<ErrorChain>,
+    ) -> Self {
+        let mut input = parse_body(request_body);
+        redact(&mut input);
+
+        let calculator_id = input.get("calculation_type").and_then(Value::as_str).map(str::to_string);
+        let calculator_version = calculator_id
+            .as_deref()
+            .map(|id| changelog::formula_version(tier, id).to_string());
+        let response = parse_body(response_body);
+
+        Self {
+            id: Uuid::new_v4(),
+            request_id,
+            tier,
+            path: path.to_string(),
+            calculator_id,
+            calculator_version,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            input,
+            status: status.as_u16(),
+            error_chain: error_chain.map(|c| c.0).unwrap_or_default(),
+            response,
+            created_at: OffsetDateTime::now_utc(),
+            user_id: None,
+            org_id: None,
+        }
+    }
+
+    /// Whether the caller in `scope` is the one whose request failed
+    pub fn captured_by(&self, scope: &TenantScope) -> bool {
+        self.user_id == Some(scope.user_id) && self.org_id == Some(scope.org_id)
+    }
+
+    pub async fn store(&self, pool: &PgPool) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO support_bundles \
+                 (id, request_id, tier, path, calculator_id, calculator_version, app_version, \
+                  input, status, response, error_chain, created_at, user_id, org_id) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+        )
+        .bind(self.id)
+        .bind(&self.request_id)
+        .bind(self.tier.as_str())
+        .bind(&self.path)
+        .bind(&self.calculator_id)
+        .bind(&self.calculator_version)
+        .bind(&self.app_version)
+        .bind(SqlJson(&self.input))
+        .bind(self.status as i16)
+        .bind(SqlJson(&self.response))
+        .bind(&self.error_chain)
+        .bind(self.created_at)
+        .bind(self.user_id)
+        .bind(self.org_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
+
+/// Middleware state: which calculator tier a router serves
+#[derive(Clone)]
+pub struct SupportCapture {
+    state: Arc<AppState>,
+    tier: Tier,
+}
+
+impl SupportCapture {
+    pub fn new(state: Arc<AppState>, tier: Tier) -> Self {
+        Self { state, tier }
+    }
+}
+
+fn opted_in(request: &Request) -> bool {
+    request
+        .headers()
+        .get(SUPPORT_BUNDLE_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+}
+
+/// Capture a bundle for failed calculations that asked for one
+pub async fn capture_middleware(
+    State(capture): State<SupportCapture>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method() != Method::POST || capture.state.sandbox || !opted_in(&request) {
+        return next.run(request).await;
+    }
+
+    let (mut parts, body) = request.into_parts();
+    let claims = <Claims as OptionalFromRequestParts<Arc<AppState>>>::from_request_parts(&mut parts, &capture.state)
+        .await
+        .ok()
+        .flatten();
+    let Ok(request_body) = to_bytes(body, MAX_CAPTURED_BYTES).await else {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+    let path = parts.uri.path().to_string();
+    let request_id = parts
+        .headers
+        .get("x-request-id")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let response = next.run(Request::from_parts(parts, Body::from(request_body.clone()))).await;
+    if response.status().is_success() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let response_body = to_bytes(body, MAX_CAPTURED_BYTES).await.unwrap_or_default();
+    let mut bundle = SupportBundle::capture(
+        capture.tier,
+        &path,
+        request_id,
+        &request_body,
+        parts.status,
+        &response_body,
+        parts.extensions.remove::<ErrorChain>(),
+    );
+    // Owned bundles can be downloaded by their owner; the rest by admins only
+    if let Some(claims) = claims
+        && let Ok(scope) = capture.state.tenants.resolve(&capture.state.pool, &claims).await
+    {
+        bundle.user_id = Some(scope.user_id);
+        bundle.org_id = Some(scope.org_id);
+    }
+
+    match bundle.store(&capture.state.pool).await {
+        Ok(()) => {
+            if let Ok(value) = HeaderValue::from_str(&bundle.id.to_string()) {
+                parts.headers.insert(SUPPORT_BUNDLE_ID_HEADER, value);
+            }
+        }
+        Err(e) => tracing::error!(error = %e, "failed to store support bundle"),
+    }
+    Response::from_parts(parts, Body::from(response_body))
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Download a bundle as a JSON file
+///
+/// Only the user whose request failed, or an admin, gets the bundle;
+/// anyone else is told it doesn't exist.
+pub async fn download_bundle_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let row = sqlx::query_as::<_, BundleRow>(
+        "SELECT id, request_id, tier, path, calculator_id, calculator_version, app_version, \
+                input, status, response, error_chain, created_at, user_id, org_id \
+         FROM support_bundles WHERE id = $1",
+    )
+    .bind(id)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+    let bundle = SupportBundle::try_from(row)?;
+
+    let scope = state.tenants.resolve(&state.pool, &claims).await?;
+    if !bundle.captured_by(&scope) {
+        match sec::require_admin(&state.pool, &claims).await {
+            Ok(_) => {}
+            Err(AppError::Forbidden) => return Err(AppError::UserNotFound),
+            Err(e) => return Err(e),
+        }
+    }
+
+    let disposition = format!("attachment; filename=\"struktura-support-{}.json\"", bundle.id);
+    Ok(([(header::CONTENT_DISPOSITION, disposition)], Json(bundle)).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_redacts_sensitive_keys_at_any_depth() {
+        let mut input = json!({
+            "calculation_type": "beam_design",
+            "parameters": {
+                "dimensions": { "length": 6.0 },
+                "client": { "Email": "a@b.c", "site_address": "1 Main St" },
+                "items": [{ "api_key": "k", "qty": 2 }]
+            }
+        });
+        redact(&mut input);
+
+        assert_eq!(input["parameters"]["dimensions"]["length"], 6.0);
+        assert_eq!(input["parameters"]["client"]["Email"], REDACTED);
+        assert_eq!(input["parameters"]["client"]["site_address"], REDACTED);
+        assert_eq!(input["parameters"]["items"][0]["api_key"], REDACTED);
+        assert_eq!(input["parameters"]["items"][0]["qty"], 2);
+    }
+
+    #[test]
+    fn test_capture_records_calculator_and_error() {
+        let request = Bytes::from(r#"{"calculation_type":"beam_design","parameters":{"password":"x"}}"#);
+        let response = Bytes::from(r#"{"error_type":"missing_parameter"}"#);
+        let chain = ErrorChain(vec!["Required parameter 'span' missing".to_string()]);
+
+        let bundle = SupportBundle::capture(
+            Tier::Engineer,
+            "/api/v1/calculus/engineer/calculate",
+            Some("req-1".to_string()),
+            &request,
+            StatusCode::BAD_REQUEST,
+            &response,
+            Some(chain.clone()),
+        );
+
+        assert_eq!(bundle.calculator_id.as_deref(), Some("beam_design"));
+        assert_eq!(
+            bundle.calculator_version.as_deref(),
+            Some(changelog::formula_version(Tier::Engineer, "beam_design"))
+        );
+        assert_eq!(bundle.input["parameters"]["password"], REDACTED);
+        assert_eq!(bundle.response["error_type"], "missing_parameter");
+        assert_eq!(bundle.error_chain, chain.0);
+        assert_eq!(bundle.status, 400);
+    }
+
+    #[test]
+    fn test_non_json_bodies_are_kept_as_text() {
+        let bundle = SupportBundle::capture(
+            Tier::Beginner,
+            "/api/v1/calculus/beginner/calculate",
+            None,
+            &Bytes::from("not json"),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            &Bytes::new(),
+            None,
+        );
+        assert_eq!(bundle.input, Value::String("not json".to_string()));
+        assert!(bundle.calculator_id.is_none());
+    }
+
+    fn scope(org_id: Uuid, user_id: Uuid) -> TenantScope {
+        TenantScope { org_id, user_id, username: "user".to_string(), limits: Default::default() }
+    }
+
+    #[test]
+    fn test_other_users_get_not_found() {
+        let (org, owner, other) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut bundle = SupportBundle::capture(
+            Tier::Engineer,
+            "/api/v1/calculus/engineer/calculate",
+            None,
+            &Bytes::from("{}"),
+            StatusCode::BAD_REQUEST,
+            &Bytes::new(),
+            None,
+        );
+        assert!(!bundle.captured_by(&scope(org, owner)), "anonymous bundles are admin-only");
+
+        bundle.user_id = Some(owner);
+        bundle.org_id = Some(org);
+        assert!(bundle.captured_by(&scope(org, owner)));
+        assert!(!bundle.captured_by(&scope(org, other)));
+        assert!(!bundle.captured_by(&scope(Uuid::new_v4(), owner)));
+
+        // Non-owners who aren't admins are answered as if the bundle didn't exist
+        assert_eq!(AppError::UserNotFound.into_response().status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,204 @@
+//! Tracing setup
+//!
+//! Every HTTP request runs in a `request` span carrying its `x-request-id`
+//! and, once a token is verified, the caller's user id. Registries open a
+//! `calculator` span (tier and calculator id) inside it, with `preprocess`,
+//! `validate` and `calculate` spans around the calculator's own code, so a
+//! trace shows which calculator spent the time.
+//!
+//! Logs are written to stdout as one JSON object per line, with the
+//! enclosing spans' fields attached; `LOG_FORMAT=text` switches to the
+//! human-readable format for local runs.
+//!
+//! `RUST_LOG` sets the initial filter (default `info`); admins can replace
+//! it at runtime through `/admin/log-level`. Built with the `otlp`
+//! feature, spans are also exported when `OTEL_EXPORTER_OTLP_ENDPOINT` is
+//! set; `OTEL_SERVICE_NAME` names the service (default `struktura`).
+
+use axum::{extract::State, http::Request, response::Json};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, OnceLock};
+use tracing::Span;
+use tracing_subscriber::{layer::SubscriberExt, reload, util::SubscriberInitExt, EnvFilter, Layer, Registry};
+use validator::{ValidationError, ValidationErrors};
+
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+
+const DEFAULT_FILTER: &str = "info";
+
+/// Handle to the installed filter, set once by `init`
+static FILTER: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// Keeps the exporter alive; spans still buffered are flushed on drop
+pub struct Telemetry {
+    #[cfg(feature = "otlp")]
+    provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        #[cfg(feature = "otlp")]
+        if let Some(provider) = self.provider.take()
+            && let Err(e) = provider.shutdown()
+        {
+            eprintln!("[TELEMETRY] OTLP shutdown failed: {}", e);
+        }
+    }
+}
+
+/// Install the global subscriber; call once, inside the Tokio runtime
+pub fn init() -> Telemetry {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+    let (filter, handle) = reload::Layer::new(filter);
+    let _ = FILTER.set(handle);
+
+    let text = std::env::var("LOG_FORMAT").is_ok_and(|v| v.trim().eq_ignore_ascii_case("text"));
+    let output = if text {
+        tracing_subscriber::fmt::layer().boxed()
+    } else {
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_current_span(false)
+            .with_span_list(true)
+            .boxed()
+    };
+    let registry = tracing_subscriber::registry().with(filter).with(output);
+
+    #[cfg(feature = "otlp")]
+    {
+        let provider = otlp::provider();
+        let layer = provider.as_ref().map(otlp::layer);
+        registry.with(layer).init();
+        Telemetry { provider }
+    }
+
+    #[cfg(not(feature = "otlp"))]
+    {
+        registry.init();
+        Telemetry {}
+    }
+}
+
+#[cfg(feature = "otlp")]
+mod otlp {
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_otlp::SpanExporter;
+    use opentelemetry_sdk::{trace::SdkTracerProvider, Resource};
+
+    /// Tracer provider for the configured collector, if any
+    pub fn provider() -> Option<SdkTracerProvider> {
+        // The exporter reads the endpoint from the environment itself
+        std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok().filter(|v| !v.trim().is_empty())?;
+
+        let exporter = match SpanExporter::builder().with_tonic().build() {
+            Ok(exporter) => exporter,
+            Err(e) => {
+                eprintln!("[TELEMETRY] OTLP exporter disabled: {}", e);
+                return None;
+            }
+        };
+        let service = std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| "struktura".to_string());
+
+        Some(
+            SdkTracerProvider::builder()
+                .with_batch_exporter(exporter)
+                .with_resource(Resource::builder().with_service_name(service).build())
+                .build(),
+        )
+    }
+
+    pub fn layer<S>(provider: &SdkTracerProvider) -> impl tracing_subscriber::Layer<S>
+    where
+        S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+    {
+        tracing_opentelemetry::layer().with_tracer(provider.tracer("struktura"))
+    }
+}
+
+/// Span for one HTTP request, used by the trace layer
+///
+/// Runs after the request id layer, so the id is always set.
+pub fn request_span<B>(request: &Request<B>) -> Span {
+    let request_id = request
+        .headers()
+        .get("x-request-id")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        request_id,
+        user_id = tracing::field::Empty,
+    )
+}
+
+/// Attach the verified caller to the current request span
+pub fn record_user(user_id: &str) {
+    Span::current().record("user_id", user_id);
+}
+
+// =============================================================================
+// RUNTIME LOG LEVEL
+// =============================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogLevel {
+    /// `RUST_LOG` syntax, e.g. `info,struktura::billing=debug`
+    pub filter: String,
+}
+
+/// Replace the active filter; the previous one stays if `directives` is invalid
+pub fn set_filter(directives: &str) -> Result<(), AppError> {
+    let filter = EnvFilter::try_new(directives).map_err(|_| {
+        let mut errors = ValidationErrors::new();
+        errors.add("filter", ValidationError::new("directives"));
+        AppError::ValidationError(errors)
+    })?;
+    let handle = FILTER.get().ok_or_else(|| AppError::Internal("Tracing is not initialized".to_string()))?;
+    handle.reload(filter).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// The filter currently applied, in `RUST_LOG` syntax
+pub fn current_filter() -> Option<String> {
+    FILTER.get()?.with_current(|f| f.to_string()).ok()
+}
+
+pub async fn get_log_level_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+) -> Result<Json<LogLevel>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    let filter = current_filter().ok_or_else(|| AppError::Internal("Tracing is not initialized".to_string()))?;
+    Ok(Json(LogLevel { filter }))
+}
+
+pub async fn update_log_level_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Json(payload): Json<LogLevel>,
+) -> Result<Json<LogLevel>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    let previous = current_filter().unwrap_or_default();
+    set_filter(&payload.filter)?;
+
+    sec::log_security_event(
+        "LOG_LEVEL_CHANGED",
+        Some(&claims.username),
+        None,
+        &format!("{} -> {}", previous, payload.filter),
+    );
+    Ok(Json(LogLevel { filter: current_filter().unwrap_or(payload.filter) }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_filter_is_rejected() {
+        assert!(matches!(set_filter("info,=[bad"), Err(AppError::ValidationError(_))));
+    }
+}
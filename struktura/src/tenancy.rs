@@ -0,0 +1,531 @@
+use axum::{
+    extract::{FromRequestParts, OptionalFromRequestParts, Path, Request, State},
+    http::{header::RETRY_AFTER, request::Parts, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgPool, types::time::OffsetDateTime};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+
+/// How long a user's organization and limits are served from memory
+pub const DEFAULT_SCOPE_TTL: Duration = Duration::from_secs(60);
+
+// =============================================================================
+// SCOPE
+// =============================================================================
+
+/// Per-organization limits; `None` falls back to the platform default
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct TenantLimits {
+    pub rate_limit_per_minute: Option<u32>,
+    pub monthly_calculation_quota: Option<u32>,
+}
+
+/// The organization a signed-in request acts within
+///
+/// Every query touching user data binds `org_id` from here, and rows read
+/// back are passed through [`TenantScope::guard`] so a missing filter fails
+/// closed instead of leaking another tenant's data.
+#[derive(Debug, Clone)]
+pub struct TenantScope {
+    pub org_id: Uuid,
+    pub user_id: Uuid,
+    pub username: String,
+    pub limits: TenantLimits,
+}
+
+/// Rows that belong to a single organization
+pub trait TenantOwned {
+    fn org_id(&self) -> Uuid;
+}
+
+#[derive(sqlx::FromRow)]
+struct ScopeRow {
+    org_id: Uuid,
+    username: String,
+    rate_limit_per_minute: Option<i32>,
+    monthly_calculation_quota: Option<i32>,
+}
+
+impl TenantScope {
+    /// Look up the caller's organization
+    pub async fn load(pool: &PgPool, claims: &Claims) -> Result<Self, AppError> {
+        let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+        Self::for_user(pool, user_id).await
+    }
+
+    /// Look up a user's organization outside of a request, e.g. for work
+    /// queued on their behalf
+    pub async fn for_user(pool: &PgPool, user_id: Uuid) -> Result<Self, AppError> {
+        let row = sqlx::query_as::<_, ScopeRow>(
+            "SELECT u.org_id, u.username, o.rate_limit_per_minute, o.monthly_calculation_quota \
+             FROM users u JOIN organizations o ON o.id = u.org_id WHERE u.id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or(AppError::UserNotFound)?;
+
+        Ok(Self {
+            org_id: row.org_id,
+            user_id,
+            username: row.username,
+            limits: TenantLimits {
+                rate_limit_per_minute: row.rate_limit_per_minute.map(|v| v.max(0) as u32),
+                monthly_calculation_quota: row.monthly_calculation_quota.map(|v| v.max(0) as u32),
+            },
+        })
+    }
+
+    /// Reject a row owned by another organization
+    ///
+    /// Reported as not found, so probing ids reveals nothing about other tenants.
+    pub fn guard<T: TenantOwned>(&self, row: T) -> Result<T, AppError> {
+        if row.org_id() == self.org_id {
+            Ok(row)
+        } else {
+            sec::log_security_event(
+                "TENANT_VIOLATION",
+                Some(&self.username),
+                None,
+                &format!("Row of org {} read from org {}", row.org_id(), self.org_id),
+            );
+            Err(AppError::UserNotFound)
+        }
+    }
+
+    pub fn guard_all<T: TenantOwned>(&self, rows: Vec<T>) -> Result<Vec<T>, AppError> {
+        rows.into_iter().map(|row| self.guard(row)).collect()
+    }
+}
+
+/// Prefers the scope resolved by [`tenant_limit_middleware`] for this request
+impl FromRequestParts<Arc<AppState>> for TenantScope {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &Arc<AppState>) -> Result<Self, Self::Rejection> {
+        if let Some(scope) = parts.extensions.get::<TenantScope>() {
+            return Ok(scope.clone());
+        }
+        let claims = <Claims as FromRequestParts<Arc<AppState>>>::from_request_parts(parts, state).await?;
+        state.tenants.resolve(&state.pool, &claims).await
+    }
+}
+
+impl OptionalFromRequestParts<Arc<AppState>> for TenantScope {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &Arc<AppState>) -> Result<Option<Self>, Self::Rejection> {
+        if let Some(scope) = parts.extensions.get::<TenantScope>() {
+            return Ok(Some(scope.clone()));
+        }
+        match <Claims as OptionalFromRequestParts<Arc<AppState>>>::from_request_parts(parts, state).await? {
+            Some(claims) => state.tenants.resolve(&state.pool, &claims).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+// =============================================================================
+// DIRECTORY
+// =============================================================================
+
+/// Cached user → organization resolution and per-organization request windows
+#[derive(Clone)]
+pub struct TenantDirectory {
+    ttl: Duration,
+    default_rate_limit: Option<u32>,
+    scopes: Arc<DashMap<Uuid, (Instant, TenantScope)>>,
+    windows: Arc<DashMap<Uuid, (u64, u32)>>,
+}
+
+impl Default for TenantDirectory {
+    fn default() -> Self {
+        Self::new(DEFAULT_SCOPE_TTL, None)
+    }
+}
+
+impl TenantDirectory {
+    pub fn new(ttl: Duration, default_rate_limit: Option<u32>) -> Self {
+        Self {
+            ttl,
+            default_rate_limit,
+            scopes: Arc::new(DashMap::new()),
+            windows: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Reads `TENANT_RATE_LIMIT_PER_MINUTE` for organizations without their own limit
+    pub fn from_env() -> Self {
+        let default_rate_limit = std::env::var("TENANT_RATE_LIMIT_PER_MINUTE")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .filter(|v| *v > 0);
+        Self::new(DEFAULT_SCOPE_TTL, default_rate_limit)
+    }
+
+    pub async fn resolve(&self, pool: &PgPool, claims: &Claims) -> Result<TenantScope, AppError> {
+        let user_id = Uuid::parse_str(&claims.sub).map_err(|_| AppError::InvalidToken)?;
+        if let Some(entry) = self.scopes.get(&user_id)
+            && entry.0.elapsed() < self.ttl
+        {
+            return Ok(entry.1.clone());
+        }
+
+        let scope = TenantScope::load(pool, claims).await?;
+        self.scopes.insert(user_id, (Instant::now(), scope.clone()));
+        Ok(scope)
+    }
+
+    /// Organization of a recently seen user, without touching the database
+    pub fn cached_org(&self, user_id: Uuid) -> Option<Uuid> {
+        self.scopes.get(&user_id).map(|entry| entry.1.org_id)
+    }
+
+    /// Drop cached scopes after organizations or memberships change
+    pub fn invalidate(&self) {
+        self.scopes.clear();
+    }
+
+    pub fn rate_limit_for(&self, scope: &TenantScope) -> Option<u32> {
+        scope.limits.rate_limit_per_minute.or(self.default_rate_limit)
+    }
+
+    /// Count a request in the organization's current minute
+    ///
+    /// Returns the seconds until the window resets when the limit is reached.
+    pub fn check_rate(&self, org_id: Uuid, limit: u32, now_secs: u64) -> Result<u32, u64> {
+        let minute = now_secs / 60;
+        let mut window = self.windows.entry(org_id).or_insert((minute, 0));
+        if window.0 != minute {
+            *window = (minute, 0);
+        }
+        if window.1 >= limit {
+            return Err(60 - now_secs % 60);
+        }
+        window.1 += 1;
+        Ok(limit - window.1)
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+fn limit_response(message: String, code: &str, retry_after: Option<u64>) -> Response {
+    let body = Json(serde_json::json!({ "error": message, "code": code }));
+    let mut response = (StatusCode::TOO_MANY_REQUESTS, body).into_response();
+    if let Some(secs) = retry_after {
+        response.headers_mut().insert(RETRY_AFTER, HeaderValue::from(secs));
+    }
+    response
+}
+
+/// Resolve the caller's organization and apply its per-minute request limit
+///
+/// Anonymous requests pass through to the IP limiter; a bad token is left
+/// for the route itself to reject.
+pub async fn tenant_limit_middleware(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if state.sandbox {
+        return next.run(request).await;
+    }
+
+    let (mut parts, body) = request.into_parts();
+    let claims = <Claims as OptionalFromRequestParts<Arc<AppState>>>::from_request_parts(&mut parts, &state)
+        .await
+        .ok()
+        .flatten();
+    let Some(claims) = claims else {
+        return next.run(Request::from_parts(parts, body)).await;
+    };
+
+    let scope = match state.tenants.resolve(&state.pool, &claims).await {
+        Ok(scope) => scope,
+        Err(e) => return e.into_response(),
+    };
+
+    if let Some(limit) = state.tenants.rate_limit_for(&scope)
+        && let Err(retry_after) = state.tenants.check_rate(scope.org_id, limit, unix_now())
+    {
+        sec::log_security_event("TENANT_RATE_LIMIT", Some(&scope.username), None, &scope.org_id.to_string());
+        return limit_response(
+            format!("Organization request limit of {} per minute reached", limit),
+            "tenant_rate_limited",
+            Some(retry_after),
+        );
+    }
+
+    parts.extensions.insert(scope);
+    next.run(Request::from_parts(parts, body)).await
+}
+
+/// Why `requested` more calculations don't fit in a quota of `limit` with
+/// `used` already spent; `None` when they fit
+pub(crate) fn quota_shortfall(quota: &str, used: i64, requested: usize, limit: u32) -> Option<String> {
+    let remaining = (limit as i64 - used).max(0);
+    if requested as i64 <= remaining {
+        None
+    } else if remaining == 0 {
+        Some(format!("{} of {} reached", quota, limit))
+    } else {
+        Some(format!("{} calculations requested but only {} left of the {} of {}", requested, remaining, quota.to_lowercase(), limit))
+    }
+}
+
+/// Refuse `calculations` more calculations once they would overrun the
+/// organization's monthly quota
+pub async fn check_monthly_quota(pool: &PgPool, scope: &TenantScope, calculations: usize) -> Result<(), Response> {
+    let Some(quota) = scope.limits.monthly_calculation_quota else {
+        return Ok(());
+    };
+
+    let used = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM usage_metrics \
+         WHERE org_id = $1 AND feature_name LIKE 'calculus.%' \
+         AND accessed_at >= date_trunc('month', CURRENT_TIMESTAMP)",
+    )
+    .bind(scope.org_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| AppError::from(e).into_response())?;
+
+    if let Some(message) = quota_shortfall("Organization monthly calculation quota", used, calculations, quota) {
+        return Err(limit_response(message, "tenant_quota_exhausted", None));
+    }
+    Ok(())
+}
+
+// =============================================================================
+// ORGANIZATIONS
+// =============================================================================
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct Organization {
+    pub id: Uuid,
+    pub name: String,
+    pub personal: bool,
+    pub rate_limit_per_minute: Option<i32>,
+    pub monthly_calculation_quota: Option<i32>,
+    /// Members must replace passwords older than this
+    pub password_max_age_days: Option<i32>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Serialize)]
+pub struct OrganizationOverview {
+    #[serde(flatten)]
+    pub organization: Organization,
+    pub members: i64,
+    pub calculations_this_month: i64,
+}
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct OrganizationPayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(range(min = 1))]
+    pub rate_limit_per_minute: Option<i32>,
+    #[validate(range(min = 0))]
+    pub monthly_calculation_quota: Option<i32>,
+    #[validate(range(min = 1))]
+    pub password_max_age_days: Option<i32>,
+}
+
+const ORGANIZATION_COLUMNS: &str =
+    "id, name, personal, rate_limit_per_minute, monthly_calculation_quota, password_max_age_days, created_at";
+
+/// The caller's organization, its limits and this month's usage
+pub async fn my_org_handler(
+    State(state): State<Arc<AppState>>,
+    scope: TenantScope,
+) -> Result<Json<OrganizationOverview>, AppError> {
+    let organization = sqlx::query_as::<_, Organization>(&format!(
+        "SELECT {ORGANIZATION_COLUMNS} FROM organizations WHERE id = $1"
+    ))
+    .bind(scope.org_id)
+    .fetch_one(&state.pool)
+    .await?;
+
+    let (members, calculations_this_month) = sqlx::query_as::<_, (i64, i64)>(
+        "SELECT (SELECT COUNT(*) FROM users WHERE org_id = $1), \
+                (SELECT COUNT(*) FROM usage_metrics WHERE org_id = $1 \
+                 AND feature_name LIKE 'calculus.%' \
+                 AND accessed_at >= date_trunc('month', CURRENT_TIMESTAMP))",
+    )
+    .bind(scope.org_id)
+    .fetch_one(&state.pool)
+    .await?;
+
+    Ok(Json(OrganizationOverview { organization, members, calculations_this_month }))
+}
+
+/// Create a firm organization (admin only)
+pub async fn create_org_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Json(payload): Json<OrganizationPayload>,
+) -> Result<(StatusCode, Json<Organization>), AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    payload.validate()?;
+
+    let organization = sqlx::query_as::<_, Organization>(&format!(
+        "INSERT INTO organizations (name, rate_limit_per_minute, monthly_calculation_quota, password_max_age_days) \
+         VALUES ($1, $2, $3, $4) RETURNING {ORGANIZATION_COLUMNS}"
+    ))
+    .bind(&payload.name)
+    .bind(payload.rate_limit_per_minute)
+    .bind(payload.monthly_calculation_quota)
+    .bind(payload.password_max_age_days)
+    .fetch_one(&state.pool)
+    .await?;
+
+    sec::log_security_event("ORG_CREATE", Some(&claims.username), None, &organization.id.to_string());
+    Ok((StatusCode::CREATED, Json(organization)))
+}
+
+/// Rename an organization or change its limits (admin only)
+pub async fn update_org_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path(org_id): Path<Uuid>,
+    Json(payload): Json<OrganizationPayload>,
+) -> Result<Json<Organization>, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+    payload.validate()?;
+
+    let organization = sqlx::query_as::<_, Organization>(&format!(
+        "UPDATE organizations SET name = $2, rate_limit_per_minute = $3, monthly_calculation_quota = $4, \
+         password_max_age_days = $5 WHERE id = $1 RETURNING {ORGANIZATION_COLUMNS}"
+    ))
+    .bind(org_id)
+    .bind(&payload.name)
+    .bind(payload.rate_limit_per_minute)
+    .bind(payload.monthly_calculation_quota)
+    .bind(payload.password_max_age_days)
+    .fetch_optional(&state.pool)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    state.tenants.invalidate();
+    sec::log_security_event("ORG_UPDATE", Some(&claims.username), None, &org_id.to_string());
+    Ok(Json(organization))
+}
+
+/// Move a user, with their saved data, into an organization (admin only)
+///
+/// Past usage stays with the organization it was metered against.
+pub async fn add_member_handler(
+    State(state): State<Arc<AppState>>,
+    claims: Claims,
+    Path((org_id, username)): Path<(Uuid, String)>,
+) -> Result<StatusCode, AppError> {
+    sec::require_admin(&state.pool, &claims).await?;
+
+    let mut tx = state.pool.begin().await?;
+    let user_id = sqlx::query_scalar::<_, Uuid>(
+        "UPDATE users SET org_id = $1 WHERE username = $2 \
+         AND EXISTS (SELECT 1 FROM organizations WHERE id = $1) RETURNING id",
+    )
+    .bind(org_id)
+    .bind(&username)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(AppError::UserNotFound)?;
+
+    for table in ["custom_materials", "subscriptions"] {
+        sqlx::query(&format!("UPDATE {table} SET org_id = $1 WHERE user_id = $2"))
+            .bind(org_id)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+
+    state.tenants.invalidate();
+    sec::log_security_event(
+        "ORG_MEMBER_ADD",
+        Some(&claims.username),
+        None,
+        &format!("{} -> {}", username, org_id),
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Row(Uuid);
+
+    impl TenantOwned for Row {
+        fn org_id(&self) -> Uuid {
+            self.0
+        }
+    }
+
+    fn scope(org_id: Uuid) -> TenantScope {
+        TenantScope {
+            org_id,
+            user_id: Uuid::new_v4(),
+            username: "tester".to_string(),
+            limits: TenantLimits::default(),
+        }
+    }
+
+    #[test]
+    fn test_guard_rejects_other_tenants_rows() {
+        let org = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let scope = scope(org);
+
+        assert!(scope.guard(Row(org)).is_ok());
+        assert!(matches!(scope.guard(Row(other)), Err(AppError::UserNotFound)));
+        assert!(scope.guard_all(vec![Row(org), Row(other)]).is_err());
+        assert_eq!(scope.guard_all(vec![Row(org), Row(org)]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_rate_window_is_per_org_and_resets_each_minute() {
+        let directory = TenantDirectory::default();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        assert_eq!(directory.check_rate(a, 2, 600), Ok(1));
+        assert_eq!(directory.check_rate(a, 2, 610), Ok(0));
+        assert_eq!(directory.check_rate(a, 2, 615), Err(45));
+        assert_eq!(directory.check_rate(b, 2, 615), Ok(1));
+        assert_eq!(directory.check_rate(a, 2, 660), Ok(1));
+    }
+
+    #[test]
+    fn test_org_limit_overrides_platform_default() {
+        let directory = TenantDirectory::new(DEFAULT_SCOPE_TTL, Some(100));
+        let mut firm = scope(Uuid::new_v4());
+        assert_eq!(directory.rate_limit_for(&firm), Some(100));
+        assert_eq!(TenantDirectory::default().rate_limit_for(&firm), None);
+
+        firm.limits.rate_limit_per_minute = Some(500);
+        assert_eq!(directory.rate_limit_for(&firm), Some(500));
+    }
+
+    #[test]
+    fn test_quota_shortfall_counts_every_requested_calculation() {
+        assert_eq!(quota_shortfall("Quota", 90, 10, 100), None);
+        assert_eq!(quota_shortfall("Quota", 100, 1, 100).as_deref(), Some("Quota of 100 reached"));
+        assert_eq!(
+            quota_shortfall("Quota", 95, 10, 100).as_deref(),
+            Some("10 calculations requested but only 5 left of the quota of 100")
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Json, Response},
+};
+use dashmap::DashMap;
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::sec::{self, AppError, Claims};
+use crate::state::AppState;
+
+/// Request/response header carrying the signed trial token
+pub const TRIAL_TOKEN_HEADER: &str = "x-trial-token";
+/// Response header with the calculations left on the trial
+pub const TRIAL_REMAINING_HEADER: &str = "x-trial-remaining";
+
+/// Free beginner calculations before an account is required
+pub const DEFAULT_TRIAL_LIMIT: u32 = 5;
+
+/// Lifetime of a trial token
+pub const TRIAL_TOKEN_TTL: Duration = Duration::days(30);
+
+// =============================================================================
+// TOKEN
+// =============================================================================
+
+/// Signed anonymous identity for trial usage
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrialClaims {
+    pub tid: String,
+    pub device_fp: String,
+    pub exp: usize,
+    pub iat: usize,
+}
+
+fn issue_token(device_fp: &str, jwt_secret: &str) -> Result<(String, TrialClaims), AppError> {
+    let now = OffsetDateTime::now_utc();
+    let claims = TrialClaims {
+        tid: Uuid::new_v4().to_string(),
+        device_fp: device_fp.to_string(),
+        exp: (now + TRIAL_TOKEN_TTL).unix_timestamp() as usize,
+        iat: now.unix_timestamp() as usize,
+    };
+
+    let token = encode(
+        &Header::new(Algorithm::HS384),
+        &claims,
+        &EncodingKey::from_secret(jwt_secret.as_bytes()),
+    ).map_err(|e| AppError::Internal(format!("Trial token encode: {}", e)))?;
+
+    Ok((token, claims))
+}
+
+fn verify_token(token: &str, jwt_secret: &str) -> Option<TrialClaims> {
+    decode::<TrialClaims>(
+        token,
+        &DecodingKey::from_secret(jwt_secret.as_bytes()),
+        &Validation::new(Algorithm::HS384),
+    )
+    .ok()
+    .map(|data| data.claims)
+}
+
+// =============================================================================
+// QUOTA
+// =============================================================================
+
+/// Trial usage counters
+///
+/// Usage is counted against both the token and the device fingerprint, and
+/// the higher count wins: discarding the token or switching networks alone
+/// does not reset the trial. Counters idle for longer than a trial token
+/// lives are purged by the cleanup jobs.
+#[derive(Clone)]
+pub struct TrialQuota {
+    limit: u32,
+    used: Arc<DashMap<String, TrialUsage>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TrialUsage {
+    count: u32,
+    last_seen: OffsetDateTime,
+}
+
+impl TrialQuota {
+    pub fn new(limit: u32) -> Self {
+        Self { limit, used: Arc::new(DashMap::new()) }
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    fn keys(claims: &TrialClaims, device_fp: &str) -> [String; 2] {
+        [format!("tid:{}", claims.tid), format!("fp:{}", device_fp)]
+    }
+
+    pub fn used(&self, claims: &TrialClaims, device_fp: &str) -> u32 {
+        Self::keys(claims, device_fp)
+            .iter()
+            .map(|k| self.used.get(k).map(|v| v.count).unwrap_or(0))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Take one calculation from the trial; `Err(used)` once exhausted
+    pub fn reserve(&self, claims: &TrialClaims, device_fp: &str) -> Result<u32, u32> {
+        let used = self.used(claims, device_fp);
+        if used >= self.limit {
+            return Err(used);
+        }
+        let last_seen = OffsetDateTime::now_utc();
+        for key in Self::keys(claims, device_fp) {
+            self.used.insert(key, TrialUsage { count: used + 1, last_seen });
+        }
+        Ok(self.limit - used - 1)
+    }
+
+    /// Give back a reservation whose calculation failed
+    pub fn refund(&self, claims: &TrialClaims, device_fp: &str) {
+        for key in Self::keys(claims, device_fp) {
+            if let Some(mut used) = self.used.get_mut(&key) {
+                used.count = used.count.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Forget counters untouched for `max_idle`
+    pub fn purge_idle(&self, max_idle: Duration) -> usize {
+        let cutoff = OffsetDateTime::now_utc() - max_idle;
+        let before = self.used.len();
+        self.used.retain(|_, usage| usage.last_seen > cutoff);
+        before - self.used.len()
+    }
+}
+
+impl Default for TrialQuota {
+    fn default() -> Self {
+        Self::new(DEFAULT_TRIAL_LIMIT)
+    }
+}
+
+// =============================================================================
+// GATE
+// =============================================================================
+
+/// An anonymous calculation admitted against the trial quota
+pub struct TrialGrant {
+    pub token: String,
+    pub claims: TrialClaims,
+    pub device_fp: String,
+    pub remaining: u32,
+}
+
+impl TrialGrant {
+    /// Headers telling the client which token to keep and what's left
+    pub fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Ok(token) = HeaderValue::from_str(&self.token) {
+            headers.insert(TRIAL_TOKEN_HEADER, token);
+        }
+        headers.insert(TRIAL_REMAINING_HEADER, HeaderValue::from(self.remaining));
+        headers
+    }
+}
+
+/// Why an anonymous calculation was turned away
+#[derive(Debug)]
+pub enum TrialError {
+    Exhausted { limit: u32, used: u32 },
+    Internal(AppError),
+}
+
+impl IntoResponse for TrialError {
+    fn into_response(self) -> Response {
+        match self {
+            TrialError::Exhausted { limit, used } => {
+                let body = Json(serde_json::json!({
+                    "error": "Free trial exhausted",
+                    "code": "trial_quota_exhausted",
+                    "limit": limit,
+                    "used": used,
+                    "upgrade": {
+                        "message": "Create a free account to keep calculating and save your projects",
+                        "signup": "/api/v1/auth/signup",
+                        "login": "/api/v1/auth/login",
+                    },
+                }));
+                (StatusCode::FORBIDDEN, body).into_response()
+            }
+            TrialError::Internal(e) => e.into_response(),
+        }
+    }
+}
+
+fn device_fingerprint(headers: &HeaderMap) -> (String, Option<String>) {
+    let (ip, ua_hash) = sec::extract_ip_and_ua(headers).unwrap_or((None, None));
+    (sec::compute_session_fingerprint(ip.as_deref(), ua_hash.as_deref()), ip)
+}
+
+/// Current trial token for the request, minting one when absent or invalid
+fn resolve_token(state: &AppState, headers: &HeaderMap, device_fp: &str) -> Result<(String, TrialClaims), AppError> {
+    let presented = headers
+        .get(TRIAL_TOKEN_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|t| verify_token(t, &state.jwt_secret).map(|claims| (t.to_string(), claims)));
+
+    match presented {
+        Some(found) => Ok(found),
+        None => issue_token(device_fp, &state.jwt_secret),
+    }
+}
+
+/// Admit a calculation: signed-in users pass freely, anonymous callers
+/// spend one trial calculation
+pub fn admit(state: &AppState, claims: Option<&Claims>, headers: &HeaderMap) -> Result<Option<TrialGrant>, TrialError> {
+    if claims.is_some() {
+        return Ok(None);
+    }
+
+    let (device_fp, ip) = device_fingerprint(headers);
+    let (token, trial) = resolve_token(state, headers, &device_fp).map_err(TrialError::Internal)?;
+
+    match state.trial_quota.reserve(&trial, &device_fp) {
+        Ok(remaining) => Ok(Some(TrialGrant { token, claims: trial, device_fp, remaining })),
+        Err(used) => {
+            sec::log_security_event("TRIAL_EXHAUSTED", None, ip.as_deref(), &trial.tid);
+            Err(TrialError::Exhausted { limit: state.trial_quota.limit(), used })
+        }
+    }
+}
+
+// =============================================================================
+// HANDLERS
+// =============================================================================
+
+#[derive(Serialize)]
+pub struct TrialStatus {
+    pub token: String,
+    pub limit: u32,
+    pub used: u32,
+    pub remaining: u32,
+}
+
+/// Issue (or re-read) a trial token and report what's left on it
+pub async fn trial_status_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<TrialStatus>, AppError> {
+    let (device_fp, _) = device_fingerprint(&headers);
+    let (token, trial) = resolve_token(&state, &headers, &device_fp)?;
+    let limit = state.trial_quota.limit();
+    let used = state.trial_quota.used(&trial, &device_fp).min(limit);
+
+    Ok(Json(TrialStatus { token, limit, used, remaining: limit - used }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quota_survives_new_token_on_same_device() {
+        let quota = TrialQuota::new(2);
+        let (_, first) = issue_token("device-a", "secret").unwrap();
+        let (_, second) = issue_token("device-a", "secret").unwrap();
+
+        assert_eq!(quota.reserve(&first, "device-a"), Ok(1));
+        assert_eq!(quota.reserve(&second, "device-a"), Ok(0));
+        assert_eq!(quota.reserve(&second, "device-a"), Err(2));
+
+        quota.refund(&second, "device-a");
+        assert_eq!(quota.reserve(&first, "device-b"), Ok(0));
+    }
+
+    #[test]
+    fn test_idle_counters_purged() {
+        let quota = TrialQuota::new(2);
+        let (_, claims) = issue_token("device-a", "secret").unwrap();
+        quota.reserve(&claims, "device-a").unwrap();
+
+        assert_eq!(quota.purge_idle(Duration::hours(1)), 0);
+        assert_eq!(quota.purge_idle(Duration::seconds(-1)), 2);
+        assert_eq!(quota.used(&claims, "device-a"), 0);
+    }
+
+    #[test]
+    fn test_token_signature_checked() {
+        let (token, claims) = issue_token("device-a", "secret").unwrap();
+        assert_eq!(verify_token(&token, "secret").unwrap().tid, claims.tid);
+        assert!(verify_token(&token, "other-secret").is_none());
+    }
+}
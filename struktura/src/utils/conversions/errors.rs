@@ -0,0 +1,122 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use std::fmt;
+
+use super::units::Dimension;
+
+/// Unit conversion error types
+#[derive(Debug, Clone)]
+pub enum ConversionError {
+    /// Unit symbol not present in the registry
+    UnknownUnit(String),
+
+    /// Source and target units measure different physical quantities
+    DimensionMismatch {
+        from_unit: String,
+        from_dimension: Dimension,
+        to_unit: String,
+        to_dimension: Dimension,
+    },
+
+    /// Absolute temperature below 0 K
+    BelowAbsoluteZero {
+        value: f64,
+        unit: String,
+    },
+
+    /// NaN or infinite input
+    InvalidValue(String),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownUnit(unit) => write!(f, "Unknown unit '{}'", unit),
+            Self::DimensionMismatch { from_unit, from_dimension, to_unit, to_dimension } => {
+                write!(
+                    f,
+                    "Cannot convert '{}' ({}) to '{}' ({}): dimensions differ",
+                    from_unit,
+                    from_dimension.as_str(),
+                    to_unit,
+                    to_dimension.as_str()
+                )
+            }
+            Self::BelowAbsoluteZero { value, unit } => {
+                write!(f, "Temperature {} {} is below absolute zero", value, unit)
+            }
+            Self::InvalidValue(value) => write!(f, "Value '{}' is not a finite number", value),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// Structured error response for API
+#[derive(Debug, Serialize)]
+pub struct ConversionErrorResponse {
+    pub error_type: String,
+    pub message: String,
+    pub suggestions: Vec<String>,
+}
+
+impl ConversionError {
+    /// Convert error to HTTP response with appropriate status code and suggestions
+    pub fn to_response(&self) -> (StatusCode, ConversionErrorResponse) {
+        match self {
+            Self::UnknownUnit(_) => (
+                StatusCode::BAD_REQUEST,
+                ConversionErrorResponse {
+                    error_type: "unknown_unit".to_string(),
+                    message: self.to_string(),
+                    suggestions: vec![
+                        "List supported units at /api/v1/utils/units".to_string(),
+                        "Unit symbols are case-sensitive (e.g. 'K' for kelvin)".to_string(),
+                    ],
+                },
+            ),
+            Self::DimensionMismatch { from_dimension, .. } => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ConversionErrorResponse {
+                    error_type: "dimension_mismatch".to_string(),
+                    message: self.to_string(),
+                    suggestions: vec![format!(
+                        "Pick a target unit measuring {} (base unit: {})",
+                        from_dimension.as_str(),
+                        from_dimension.base_unit()
+                    )],
+                },
+            ),
+            Self::BelowAbsoluteZero { .. } => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ConversionErrorResponse {
+                    error_type: "below_absolute_zero".to_string(),
+                    message: self.to_string(),
+                    suggestions: vec!["Absolute temperatures must be >= 0 K".to_string()],
+                },
+            ),
+            Self::InvalidValue(_) => (
+                StatusCode::BAD_REQUEST,
+                ConversionErrorResponse {
+                    error_type: "invalid_value".to_string(),
+                    message: self.to_string(),
+                    suggestions: vec!["Provide a finite numeric value".to_string()],
+                },
+            ),
+        }
+    }
+}
+
+impl IntoResponse for ConversionError {
+    fn into_response(self) -> Response {
+        let (status, error_response) = self.to_response();
+        (status, Json(error_response)).into_response()
+    }
+}
+
+/// Result type alias for unit conversions
+pub type ConversionResult<T> = Result<T, ConversionError>;
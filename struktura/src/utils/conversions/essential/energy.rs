@@ -0,0 +1,178 @@
+// Energy Conversion Library
+// All conversions use precise, internationally standardized values
+// Primary references: NIST SP 811, CODATA 2022 (electron-volt)
+//
+// Base unit: joule (J = N·m = W·s)
+
+// ============================================================================
+// REFERENCE VALUES (in joules)
+// ============================================================================
+
+/// Watt-hour (exact)
+const WATT_HOUR: f64 = 3_600.0;
+
+/// Kilowatt-hour (exact)
+const KILOWATT_HOUR: f64 = 3_600_000.0;
+
+/// Thermochemical calorie (exact by definition)
+const CALORIE: f64 = 4.184;
+
+/// International Table BTU
+const BTU: f64 = 1_055.055_852_62;
+
+/// US therm (100,000 BTU_IT)
+const THERM: f64 = 100_000.0 * BTU;
+
+/// Foot-pound-force
+const FT_LBF: f64 = 1.355_817_948_331_400_4;
+
+/// Electron-volt (exact since 2019 SI redefinition)
+const ELECTRON_VOLT: f64 = 1.602_176_634e-19;
+
+/// Erg (CGS)
+const ERG: f64 = 1e-7;
+
+// ============================================================================
+// TO JOULE CONVERSIONS
+// ============================================================================
+
+/// Kilojoule to joule
+pub async fn kj_to_j(num: f64) -> f64 {
+    num * 1_000.0
+}
+
+/// Megajoule to joule
+pub async fn mj_to_j(num: f64) -> f64 {
+    num * 1_000_000.0
+}
+
+/// Gigajoule to joule
+pub async fn gj_to_j(num: f64) -> f64 {
+    num * 1_000_000_000.0
+}
+
+/// Watt-hour to joule
+pub async fn wh_to_j(num: f64) -> f64 {
+    num * WATT_HOUR
+}
+
+/// Kilowatt-hour to joule
+pub async fn kwh_to_j(num: f64) -> f64 {
+    num * KILOWATT_HOUR
+}
+
+/// Megawatt-hour to joule
+pub async fn mwh_to_j(num: f64) -> f64 {
+    num * KILOWATT_HOUR * 1_000.0
+}
+
+/// Calorie (thermochemical) to joule
+pub async fn cal_to_j(num: f64) -> f64 {
+    num * CALORIE
+}
+
+/// Kilocalorie (food Calorie) to joule
+pub async fn kcal_to_j(num: f64) -> f64 {
+    num * CALORIE * 1_000.0
+}
+
+/// BTU (International Table) to joule
+pub async fn btu_to_j(num: f64) -> f64 {
+    num * BTU
+}
+
+/// Therm to joule (natural gas billing)
+pub async fn therm_to_j(num: f64) -> f64 {
+    num * THERM
+}
+
+/// Foot-pound-force to joule
+pub async fn ft_lbf_to_j(num: f64) -> f64 {
+    num * FT_LBF
+}
+
+/// Electron-volt to joule
+pub async fn ev_to_j(num: f64) -> f64 {
+    num * ELECTRON_VOLT
+}
+
+/// Erg to joule
+pub async fn erg_to_j(num: f64) -> f64 {
+    num * ERG
+}
+
+// ============================================================================
+// FROM JOULE CONVERSIONS
+// ============================================================================
+
+pub async fn j_to_kj(num: f64) -> f64 {
+    num / 1_000.0
+}
+
+pub async fn j_to_mj(num: f64) -> f64 {
+    num / 1_000_000.0
+}
+
+pub async fn j_to_gj(num: f64) -> f64 {
+    num / 1_000_000_000.0
+}
+
+pub async fn j_to_wh(num: f64) -> f64 {
+    num / WATT_HOUR
+}
+
+pub async fn j_to_kwh(num: f64) -> f64 {
+    num / KILOWATT_HOUR
+}
+
+pub async fn j_to_mwh(num: f64) -> f64 {
+    num / (KILOWATT_HOUR * 1_000.0)
+}
+
+pub async fn j_to_cal(num: f64) -> f64 {
+    num / CALORIE
+}
+
+pub async fn j_to_kcal(num: f64) -> f64 {
+    num / (CALORIE * 1_000.0)
+}
+
+pub async fn j_to_btu(num: f64) -> f64 {
+    num / BTU
+}
+
+pub async fn j_to_therm(num: f64) -> f64 {
+    num / THERM
+}
+
+pub async fn j_to_ft_lbf(num: f64) -> f64 {
+    num / FT_LBF
+}
+
+pub async fn j_to_ev(num: f64) -> f64 {
+    num / ELECTRON_VOLT
+}
+
+pub async fn j_to_erg(num: f64) -> f64 {
+    num / ERG
+}
+
+// ============================================================================
+// COMMON DIRECT CONVERSIONS
+// ============================================================================
+
+pub async fn kwh_to_btu(num: f64) -> f64 {
+    num * KILOWATT_HOUR / BTU
+}
+
+pub async fn btu_to_kwh(num: f64) -> f64 {
+    num * BTU / KILOWATT_HOUR
+}
+
+pub async fn therm_to_kwh(num: f64) -> f64 {
+    num * THERM / KILOWATT_HOUR
+}
+
+pub async fn kwh_to_therm(num: f64) -> f64 {
+    num * KILOWATT_HOUR / THERM
+}
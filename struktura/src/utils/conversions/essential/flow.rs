@@ -0,0 +1,134 @@
+// Volumetric Flow Conversion Library
+// All conversions use precise, internationally standardized values
+// Primary references: NIST SP 811 (US customary definitions)
+//
+// Base unit: cubic meter per second (m³/s)
+
+// ============================================================================
+// REFERENCE VALUES
+// ============================================================================
+
+/// US liquid gallon (exact: 231 in³)
+const US_GALLON_M3: f64 = 0.003_785_411_784;
+
+/// Imperial gallon (exact)
+const IMPERIAL_GALLON_M3: f64 = 0.004_546_09;
+
+/// Cubic foot (exact: 0.3048³)
+const CUBIC_FOOT_M3: f64 = 0.028_316_846_592;
+
+/// Oil barrel (exact: 42 US gallons)
+const BARREL_M3: f64 = 42.0 * US_GALLON_M3;
+
+// ============================================================================
+// TO CUBIC METER PER SECOND CONVERSIONS
+// ============================================================================
+
+/// Cubic meter per hour to m³/s (pump and HVAC catalogues)
+pub async fn m3h_to_m3s(num: f64) -> f64 {
+    num / 3_600.0
+}
+
+/// Cubic meter per minute to m³/s
+pub async fn m3min_to_m3s(num: f64) -> f64 {
+    num / 60.0
+}
+
+/// Liter per second to m³/s
+pub async fn lps_to_m3s(num: f64) -> f64 {
+    num / 1_000.0
+}
+
+/// Liter per minute to m³/s
+pub async fn lpm_to_m3s(num: f64) -> f64 {
+    num / 60_000.0
+}
+
+/// US gallon per minute to m³/s
+pub async fn gpm_to_m3s(num: f64) -> f64 {
+    num * US_GALLON_M3 / 60.0
+}
+
+/// Imperial gallon per minute to m³/s
+pub async fn imp_gpm_to_m3s(num: f64) -> f64 {
+    num * IMPERIAL_GALLON_M3 / 60.0
+}
+
+/// Cubic foot per minute to m³/s (air flow)
+pub async fn cfm_to_m3s(num: f64) -> f64 {
+    num * CUBIC_FOOT_M3 / 60.0
+}
+
+/// Cubic foot per second to m³/s (open channel / stormwater)
+pub async fn cfs_to_m3s(num: f64) -> f64 {
+    num * CUBIC_FOOT_M3
+}
+
+/// Oil barrel per day to m³/s
+pub async fn bpd_to_m3s(num: f64) -> f64 {
+    num * BARREL_M3 / 86_400.0
+}
+
+// ============================================================================
+// FROM CUBIC METER PER SECOND CONVERSIONS
+// ============================================================================
+
+pub async fn m3s_to_m3h(num: f64) -> f64 {
+    num * 3_600.0
+}
+
+pub async fn m3s_to_m3min(num: f64) -> f64 {
+    num * 60.0
+}
+
+pub async fn m3s_to_lps(num: f64) -> f64 {
+    num * 1_000.0
+}
+
+pub async fn m3s_to_lpm(num: f64) -> f64 {
+    num * 60_000.0
+}
+
+pub async fn m3s_to_gpm(num: f64) -> f64 {
+    num * 60.0 / US_GALLON_M3
+}
+
+pub async fn m3s_to_imp_gpm(num: f64) -> f64 {
+    num * 60.0 / IMPERIAL_GALLON_M3
+}
+
+pub async fn m3s_to_cfm(num: f64) -> f64 {
+    num * 60.0 / CUBIC_FOOT_M3
+}
+
+pub async fn m3s_to_cfs(num: f64) -> f64 {
+    num / CUBIC_FOOT_M3
+}
+
+pub async fn m3s_to_bpd(num: f64) -> f64 {
+    num * 86_400.0 / BARREL_M3
+}
+
+// ============================================================================
+// COMMON DIRECT CONVERSIONS
+// ============================================================================
+
+pub async fn m3h_to_gpm(num: f64) -> f64 {
+    num / 60.0 / US_GALLON_M3
+}
+
+pub async fn gpm_to_m3h(num: f64) -> f64 {
+    num * US_GALLON_M3 * 60.0
+}
+
+pub async fn cfm_to_m3h(num: f64) -> f64 {
+    num * CUBIC_FOOT_M3 * 60.0
+}
+
+pub async fn m3h_to_cfm(num: f64) -> f64 {
+    num / 60.0 / CUBIC_FOOT_M3
+}
+
+pub async fn lps_to_gpm(num: f64) -> f64 {
+    num * 60.0 / 1_000.0 / US_GALLON_M3
+}
@@ -2,8 +2,18 @@ pub mod distances;
 pub mod mass;
 pub mod time;
 pub mod temperature;
+pub mod pressure;
+pub mod flow;
+pub mod power;
+pub mod torque;
+pub mod energy;
 
 pub use distances::*;
 pub use mass::*;
 pub use time::*;
-pub use temperature::*;
\ No newline at end of file
+pub use temperature::*;
+pub use pressure::*;
+pub use flow::*;
+pub use power::*;
+pub use torque::*;
+pub use energy::*;
@@ -0,0 +1,148 @@
+// Power Conversion Library
+// All conversions use precise, internationally standardized values
+// Primary references: NIST SP 811, ASHRAE Handbook (refrigeration ton)
+//
+// Base unit: watt (W = J/s)
+
+// ============================================================================
+// REFERENCE VALUES (in watts)
+// ============================================================================
+
+/// Mechanical horsepower (imperial: 550 ft·lbf/s)
+const HORSEPOWER_MECHANICAL: f64 = 745.699_871_582_270_2;
+
+/// Metric horsepower (PS, CV: 75 kgf·m/s)
+const HORSEPOWER_METRIC: f64 = 735.498_75;
+
+/// Electrical horsepower (exact by definition)
+const HORSEPOWER_ELECTRICAL: f64 = 746.0;
+
+/// International Table BTU per hour
+/// 1 BTU_IT = 1055.05585262 J → 1 BTU/h = 0.29307107017 W
+const BTU_PER_HOUR: f64 = 1_055.055_852_62 / 3_600.0;
+
+/// Ton of refrigeration (12,000 BTU/h)
+const TON_REFRIGERATION: f64 = 12_000.0 * BTU_PER_HOUR;
+
+/// Foot-pound-force per second
+const FT_LBF_PER_SECOND: f64 = 1.355_817_948_331_400_4;
+
+/// Kilocalorie (thermochemical) per hour
+const KCAL_PER_HOUR: f64 = 4_184.0 / 3_600.0;
+
+// ============================================================================
+// TO WATT CONVERSIONS
+// ============================================================================
+
+/// Kilowatt to watt
+pub async fn kw_to_w(num: f64) -> f64 {
+    num * 1_000.0
+}
+
+/// Megawatt to watt
+pub async fn mw_to_w(num: f64) -> f64 {
+    num * 1_000_000.0
+}
+
+/// Mechanical horsepower to watt
+pub async fn hp_to_w(num: f64) -> f64 {
+    num * HORSEPOWER_MECHANICAL
+}
+
+/// Metric horsepower to watt
+pub async fn hp_metric_to_w(num: f64) -> f64 {
+    num * HORSEPOWER_METRIC
+}
+
+/// Electrical horsepower to watt (motor nameplates)
+pub async fn hp_electrical_to_w(num: f64) -> f64 {
+    num * HORSEPOWER_ELECTRICAL
+}
+
+/// BTU per hour to watt
+pub async fn btu_h_to_w(num: f64) -> f64 {
+    num * BTU_PER_HOUR
+}
+
+/// Ton of refrigeration to watt
+pub async fn ton_refrigeration_to_w(num: f64) -> f64 {
+    num * TON_REFRIGERATION
+}
+
+/// Foot-pound-force per second to watt
+pub async fn ft_lbf_s_to_w(num: f64) -> f64 {
+    num * FT_LBF_PER_SECOND
+}
+
+/// Kilocalorie per hour to watt
+pub async fn kcal_h_to_w(num: f64) -> f64 {
+    num * KCAL_PER_HOUR
+}
+
+// ============================================================================
+// FROM WATT CONVERSIONS
+// ============================================================================
+
+pub async fn w_to_kw(num: f64) -> f64 {
+    num / 1_000.0
+}
+
+pub async fn w_to_mw(num: f64) -> f64 {
+    num / 1_000_000.0
+}
+
+pub async fn w_to_hp(num: f64) -> f64 {
+    num / HORSEPOWER_MECHANICAL
+}
+
+pub async fn w_to_hp_metric(num: f64) -> f64 {
+    num / HORSEPOWER_METRIC
+}
+
+pub async fn w_to_hp_electrical(num: f64) -> f64 {
+    num / HORSEPOWER_ELECTRICAL
+}
+
+pub async fn w_to_btu_h(num: f64) -> f64 {
+    num / BTU_PER_HOUR
+}
+
+pub async fn w_to_ton_refrigeration(num: f64) -> f64 {
+    num / TON_REFRIGERATION
+}
+
+pub async fn w_to_ft_lbf_s(num: f64) -> f64 {
+    num / FT_LBF_PER_SECOND
+}
+
+pub async fn w_to_kcal_h(num: f64) -> f64 {
+    num / KCAL_PER_HOUR
+}
+
+// ============================================================================
+// COMMON DIRECT CONVERSIONS
+// ============================================================================
+
+pub async fn kw_to_hp(num: f64) -> f64 {
+    num * 1_000.0 / HORSEPOWER_MECHANICAL
+}
+
+pub async fn hp_to_kw(num: f64) -> f64 {
+    num * HORSEPOWER_MECHANICAL / 1_000.0
+}
+
+pub async fn kw_to_btu_h(num: f64) -> f64 {
+    num * 1_000.0 / BTU_PER_HOUR
+}
+
+pub async fn btu_h_to_kw(num: f64) -> f64 {
+    num * BTU_PER_HOUR / 1_000.0
+}
+
+pub async fn kw_to_ton_refrigeration(num: f64) -> f64 {
+    num * 1_000.0 / TON_REFRIGERATION
+}
+
+pub async fn ton_refrigeration_to_kw(num: f64) -> f64 {
+    num * TON_REFRIGERATION / 1_000.0
+}
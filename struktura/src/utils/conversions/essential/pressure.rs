@@ -0,0 +1,203 @@
+// Pressure Conversion Library
+// All conversions use precise, internationally standardized values
+// Primary references: NIST SP 811, BIPM SI Brochure
+//
+// Base unit: pascal (Pa = N/m²)
+// Note: Gauge vs absolute pressure is NOT handled here - these are pure
+// unit conversions. Add/subtract atmospheric pressure explicitly when needed.
+
+// ============================================================================
+// REFERENCE VALUES (in pascals)
+// ============================================================================
+
+/// Standard atmosphere (exact by definition)
+const ATMOSPHERE: f64 = 101_325.0;
+
+/// Bar (exact by definition)
+const BAR: f64 = 100_000.0;
+
+/// Pound-force per square inch
+/// 1 psi = 4.4482216152605 N / (0.0254 m)² = 6894.757293168 Pa
+const PSI: f64 = 6_894.757293168;
+
+/// Conventional meter of water column (ρ = 1000 kg/m³, g = 9.80665 m/s²)
+const METER_WATER: f64 = 9_806.65;
+
+/// Conventional inch of water column (4 °C)
+const INCH_WATER: f64 = 249.08891;
+
+/// Conventional millimeter of mercury (0 °C)
+const MILLIMETER_MERCURY: f64 = 133.322387415;
+
+/// Conventional inch of mercury (0 °C)
+const INCH_MERCURY: f64 = 3_386.389;
+
+/// Torr (exact: 1/760 atm)
+const TORR: f64 = ATMOSPHERE / 760.0;
+
+/// Kilogram-force per square centimeter (technical atmosphere)
+const KGF_PER_CM2: f64 = 98_066.5;
+
+// ============================================================================
+// TO PASCAL CONVERSIONS
+// ============================================================================
+
+/// Kilopascal to pascal
+pub async fn kpa_to_pa(num: f64) -> f64 {
+    num * 1_000.0
+}
+
+/// Megapascal to pascal (1 MPa = 1 N/mm²)
+pub async fn mpa_to_pa(num: f64) -> f64 {
+    num * 1_000_000.0
+}
+
+/// Gigapascal to pascal
+pub async fn gpa_to_pa(num: f64) -> f64 {
+    num * 1_000_000_000.0
+}
+
+/// Bar to pascal (exact)
+pub async fn bar_to_pa(num: f64) -> f64 {
+    num * BAR
+}
+
+/// Millibar to pascal (exact, 1 mbar = 1 hPa)
+pub async fn mbar_to_pa(num: f64) -> f64 {
+    num * 100.0
+}
+
+/// Standard atmosphere to pascal (exact)
+pub async fn atm_to_pa(num: f64) -> f64 {
+    num * ATMOSPHERE
+}
+
+/// Pound-force per square inch to pascal
+pub async fn psi_to_pa(num: f64) -> f64 {
+    num * PSI
+}
+
+/// Kilopound-force per square inch to pascal
+pub async fn ksi_to_pa(num: f64) -> f64 {
+    num * PSI * 1_000.0
+}
+
+/// Meter of water column to pascal
+pub async fn mh2o_to_pa(num: f64) -> f64 {
+    num * METER_WATER
+}
+
+/// Inch of water column to pascal (common for duct static pressure)
+pub async fn inh2o_to_pa(num: f64) -> f64 {
+    num * INCH_WATER
+}
+
+/// Millimeter of mercury to pascal
+pub async fn mmhg_to_pa(num: f64) -> f64 {
+    num * MILLIMETER_MERCURY
+}
+
+/// Inch of mercury to pascal
+pub async fn inhg_to_pa(num: f64) -> f64 {
+    num * INCH_MERCURY
+}
+
+/// Torr to pascal
+pub async fn torr_to_pa(num: f64) -> f64 {
+    num * TORR
+}
+
+/// Kilogram-force per square centimeter to pascal
+pub async fn kgf_cm2_to_pa(num: f64) -> f64 {
+    num * KGF_PER_CM2
+}
+
+// ============================================================================
+// FROM PASCAL CONVERSIONS
+// ============================================================================
+
+pub async fn pa_to_kpa(num: f64) -> f64 {
+    num / 1_000.0
+}
+
+pub async fn pa_to_mpa(num: f64) -> f64 {
+    num / 1_000_000.0
+}
+
+pub async fn pa_to_gpa(num: f64) -> f64 {
+    num / 1_000_000_000.0
+}
+
+pub async fn pa_to_bar(num: f64) -> f64 {
+    num / BAR
+}
+
+pub async fn pa_to_mbar(num: f64) -> f64 {
+    num / 100.0
+}
+
+pub async fn pa_to_atm(num: f64) -> f64 {
+    num / ATMOSPHERE
+}
+
+pub async fn pa_to_psi(num: f64) -> f64 {
+    num / PSI
+}
+
+pub async fn pa_to_ksi(num: f64) -> f64 {
+    num / (PSI * 1_000.0)
+}
+
+pub async fn pa_to_mh2o(num: f64) -> f64 {
+    num / METER_WATER
+}
+
+pub async fn pa_to_inh2o(num: f64) -> f64 {
+    num / INCH_WATER
+}
+
+pub async fn pa_to_mmhg(num: f64) -> f64 {
+    num / MILLIMETER_MERCURY
+}
+
+pub async fn pa_to_inhg(num: f64) -> f64 {
+    num / INCH_MERCURY
+}
+
+pub async fn pa_to_torr(num: f64) -> f64 {
+    num / TORR
+}
+
+pub async fn pa_to_kgf_cm2(num: f64) -> f64 {
+    num / KGF_PER_CM2
+}
+
+// ============================================================================
+// COMMON DIRECT CONVERSIONS
+// ============================================================================
+
+pub async fn psi_to_bar(num: f64) -> f64 {
+    num * PSI / BAR
+}
+
+pub async fn bar_to_psi(num: f64) -> f64 {
+    num * BAR / PSI
+}
+
+pub async fn kpa_to_psi(num: f64) -> f64 {
+    num * 1_000.0 / PSI
+}
+
+pub async fn psi_to_kpa(num: f64) -> f64 {
+    num * PSI / 1_000.0
+}
+
+/// Head of water (m) to kPa - pump and piping calculations
+pub async fn mh2o_to_kpa(num: f64) -> f64 {
+    num * METER_WATER / 1_000.0
+}
+
+/// kPa to head of water (m)
+pub async fn kpa_to_mh2o(num: f64) -> f64 {
+    num * 1_000.0 / METER_WATER
+}
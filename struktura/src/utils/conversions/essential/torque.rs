@@ -0,0 +1,129 @@
+// Torque Conversion Library
+// All conversions use precise, internationally standardized values
+// Primary references: NIST SP 811
+//
+// Base unit: newton-meter (N·m)
+// Note: Torque shares dimensions with energy (N·m = J) but is a different
+// physical quantity - never mix them. Foot-pound torque is written lbf·ft here
+// to keep it distinct from the ft·lbf energy unit.
+
+// ============================================================================
+// REFERENCE VALUES (in newton-meters)
+// ============================================================================
+
+/// Pound-force foot (1 lbf × 0.3048 m)
+const LBF_FT: f64 = 1.355_817_948_331_400_4;
+
+/// Pound-force inch (1 lbf × 0.0254 m)
+const LBF_IN: f64 = 0.112_984_829_027_616_7;
+
+/// Ounce-force inch
+const OZF_IN: f64 = LBF_IN / 16.0;
+
+/// Kilogram-force meter (exact: g₀ = 9.80665 m/s²)
+const KGF_M: f64 = 9.806_65;
+
+/// Dyne-centimeter (CGS)
+const DYNE_CM: f64 = 1e-7;
+
+// ============================================================================
+// TO NEWTON-METER CONVERSIONS
+// ============================================================================
+
+/// Kilonewton-meter to newton-meter
+pub async fn kn_m_to_n_m(num: f64) -> f64 {
+    num * 1_000.0
+}
+
+/// Newton-centimeter to newton-meter
+pub async fn n_cm_to_n_m(num: f64) -> f64 {
+    num / 100.0
+}
+
+/// Newton-millimeter to newton-meter
+pub async fn n_mm_to_n_m(num: f64) -> f64 {
+    num / 1_000.0
+}
+
+/// Pound-force foot to newton-meter (bolt torque specs)
+pub async fn lbf_ft_to_n_m(num: f64) -> f64 {
+    num * LBF_FT
+}
+
+/// Pound-force inch to newton-meter
+pub async fn lbf_in_to_n_m(num: f64) -> f64 {
+    num * LBF_IN
+}
+
+/// Ounce-force inch to newton-meter (small motors)
+pub async fn ozf_in_to_n_m(num: f64) -> f64 {
+    num * OZF_IN
+}
+
+/// Kilogram-force meter to newton-meter
+pub async fn kgf_m_to_n_m(num: f64) -> f64 {
+    num * KGF_M
+}
+
+/// Dyne-centimeter to newton-meter
+pub async fn dyne_cm_to_n_m(num: f64) -> f64 {
+    num * DYNE_CM
+}
+
+// ============================================================================
+// FROM NEWTON-METER CONVERSIONS
+// ============================================================================
+
+pub async fn n_m_to_kn_m(num: f64) -> f64 {
+    num / 1_000.0
+}
+
+pub async fn n_m_to_n_cm(num: f64) -> f64 {
+    num * 100.0
+}
+
+pub async fn n_m_to_n_mm(num: f64) -> f64 {
+    num * 1_000.0
+}
+
+pub async fn n_m_to_lbf_ft(num: f64) -> f64 {
+    num / LBF_FT
+}
+
+pub async fn n_m_to_lbf_in(num: f64) -> f64 {
+    num / LBF_IN
+}
+
+pub async fn n_m_to_ozf_in(num: f64) -> f64 {
+    num / OZF_IN
+}
+
+pub async fn n_m_to_kgf_m(num: f64) -> f64 {
+    num / KGF_M
+}
+
+pub async fn n_m_to_dyne_cm(num: f64) -> f64 {
+    num / DYNE_CM
+}
+
+// ============================================================================
+// COMMON DIRECT CONVERSIONS
+// ============================================================================
+
+pub async fn lbf_ft_to_lbf_in(num: f64) -> f64 {
+    num * 12.0
+}
+
+pub async fn lbf_in_to_lbf_ft(num: f64) -> f64 {
+    num / 12.0
+}
+
+/// kip-foot to kN·m (structural moments in US units)
+pub async fn kip_ft_to_kn_m(num: f64) -> f64 {
+    num * LBF_FT
+}
+
+/// kN·m to kip-foot
+pub async fn kn_m_to_kip_ft(num: f64) -> f64 {
+    num / LBF_FT
+}
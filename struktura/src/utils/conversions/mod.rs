@@ -1,5 +1,13 @@
 pub mod essential;
 pub mod constants;
+pub mod errors;
+pub mod preferred;
+pub mod system;
+pub mod units;
 
 pub use essential::*;
-pub use constants::*;
\ No newline at end of file
+pub use constants::*;
+pub use errors::{ConversionError, ConversionResult};
+pub use preferred::{OutputUnits, QuantityKind};
+pub use system::UnitSystem;
+pub use units::{Dimension, UnitInfo, convert};
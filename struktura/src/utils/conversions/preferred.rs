@@ -0,0 +1,311 @@
+// Preferred Output Units
+// A request may name the unit it wants each kind of quantity reported in
+// (`{"length": "ft", "volume": "gal"}`) instead of switching its whole unit
+// system. Result items whose unit measures one of those kinds are converted
+// after the calculation and carry their metric value alongside as
+// `si_value` / `si_unit`: the calculator's own value, or the kind's base unit
+// when the request's unit system had already made it imperial. Everything
+// else is left as it was.
+//
+// Every kind is routed through one metric base unit:
+//   length → m, area → m², volume → m³, mass → kg, pressure → Pa,
+//   temperature → K, force → N, power → W, flow → m³/s, velocity → m/s
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Kind of quantity a unit measures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuantityKind {
+    Length,
+    Area,
+    Volume,
+    Mass,
+    Pressure,
+    Temperature,
+    Force,
+    Power,
+    Flow,
+    Velocity,
+}
+
+/// A unit as written in results or requests:
+/// `base = value × factor + offset`
+#[derive(Debug, PartialEq)]
+pub struct OutputUnit {
+    pub symbol: &'static str,
+    pub kind: QuantityKind,
+    factor: f64,
+    offset: f64,
+    metric: bool,
+    /// Other spellings accepted in requests
+    aliases: &'static [&'static str],
+}
+
+const fn si(symbol: &'static str, kind: QuantityKind, factor: f64, aliases: &'static [&'static str]) -> OutputUnit {
+    OutputUnit { symbol, kind, factor, offset: 0.0, metric: true, aliases }
+}
+
+const fn us(symbol: &'static str, kind: QuantityKind, factor: f64, aliases: &'static [&'static str]) -> OutputUnit {
+    OutputUnit { symbol, kind, factor, offset: 0.0, metric: false, aliases }
+}
+
+use QuantityKind::*;
+
+/// US gallon in m³ (exact)
+const GALLON: f64 = 0.003_785_411_784;
+/// Cubic foot in m³ (exact)
+const CUBIC_FOOT: f64 = 0.028_316_846_592;
+/// Pound-force in N
+const POUND_FORCE: f64 = 4.448_221_615_260_5;
+
+const OUTPUT_UNITS: &[OutputUnit] = &[
+    // Length
+    si("m", Length, 1.0, &[]),
+    si("mm", Length, 0.001, &[]),
+    si("cm", Length, 0.01, &[]),
+    si("km", Length, 1_000.0, &[]),
+    us("in", Length, 0.0254, &[]),
+    us("ft", Length, 0.3048, &[]),
+    us("yd", Length, 0.9144, &[]),
+    us("mi", Length, 1_609.344, &[]),
+    // Area
+    si("m²", Area, 1.0, &["m2", "m^2"]),
+    si("cm²", Area, 1e-4, &["cm2", "cm^2"]),
+    si("mm²", Area, 1e-6, &["mm2", "mm^2"]),
+    us("in²", Area, 0.000_645_16, &["in2", "in^2", "sq_in"]),
+    us("ft²", Area, 0.092_903_04, &["ft2", "ft^2", "sq_ft"]),
+    us("yd²", Area, 0.836_127_36, &["yd2", "yd^2", "sq_yd"]),
+    us("acre", Area, 4_046.856_422_4, &["acres"]),
+    // Volume
+    si("m³", Volume, 1.0, &["m3", "m^3"]),
+    si("liters", Volume, 0.001, &["L", "l", "liter"]),
+    us("in³", Volume, CUBIC_FOOT / 1_728.0, &["in3", "in^3", "cu_in"]),
+    us("ft³", Volume, CUBIC_FOOT, &["ft3", "ft^3", "cu_ft"]),
+    us("yd³", Volume, CUBIC_FOOT * 27.0, &["yd3", "yd^3", "cu_yd"]),
+    us("gal", Volume, GALLON, &["gallon", "gallons"]),
+    us("qt", Volume, GALLON / 4.0, &["quart", "quarts"]),
+    // Mass
+    si("kg", Mass, 1.0, &[]),
+    si("g", Mass, 0.001, &[]),
+    si("t", Mass, 1_000.0, &["tonne"]),
+    us("lb", Mass, 0.453_592_37, &["lbs"]),
+    us("oz", Mass, 0.028_349_523_125, &[]),
+    us("short_ton", Mass, 907.184_74, &["ton"]),
+    // Pressure
+    si("Pa", Pressure, 1.0, &[]),
+    si("kPa", Pressure, 1_000.0, &[]),
+    si("MPa", Pressure, 1_000_000.0, &[]),
+    si("bar", Pressure, 100_000.0, &[]),
+    us("psi", Pressure, POUND_FORCE / 0.000_645_16, &[]),
+    us("psf", Pressure, POUND_FORCE / 0.092_903_04, &[]),
+    us("ksi", Pressure, POUND_FORCE * 1_000.0 / 0.000_645_16, &[]),
+    // Temperature
+    si("K", Temperature, 1.0, &[]),
+    OutputUnit { symbol: "°C", kind: Temperature, factor: 1.0, offset: 273.15, metric: true, aliases: &["C", "degC"] },
+    OutputUnit { symbol: "°F", kind: Temperature, factor: 5.0 / 9.0, offset: 459.67 * 5.0 / 9.0, metric: false, aliases: &["F", "degF"] },
+    // Force
+    si("N", Force, 1.0, &[]),
+    si("kN", Force, 1_000.0, &[]),
+    us("lbf", Force, POUND_FORCE, &[]),
+    us("kip", Force, POUND_FORCE * 1_000.0, &[]),
+    // Power
+    si("W", Power, 1.0, &[]),
+    si("kW", Power, 1_000.0, &[]),
+    us("hp", Power, 745.699_871_582_270_2, &[]),
+    us("BTU/h", Power, 0.293_071_070_172_222, &["btu_h"]),
+    // Flow
+    si("m³/s", Flow, 1.0, &["m3/s"]),
+    si("m³/h", Flow, 1.0 / 3_600.0, &["m3/h"]),
+    si("L/s", Flow, 0.001, &[]),
+    si("L/min", Flow, 0.001 / 60.0, &[]),
+    us("gpm", Flow, GALLON / 60.0, &[]),
+    us("cfm", Flow, CUBIC_FOOT / 60.0, &[]),
+    // Velocity
+    si("m/s", Velocity, 1.0, &[]),
+    si("km/h", Velocity, 1.0 / 3.6, &[]),
+    us("ft/s", Velocity, 0.3048, &[]),
+    us("mph", Velocity, 0.447_04, &[]),
+];
+
+/// Metric base unit of a kind
+fn base_unit(kind: QuantityKind) -> &'static OutputUnit {
+    OUTPUT_UNITS
+        .iter()
+        .find(|u| u.kind == kind && u.factor == 1.0 && u.offset == 0.0)
+        .expect("every kind has a base unit")
+}
+
+/// Look up a unit by symbol or alias
+pub fn find_output_unit(symbol: &str) -> Option<&'static OutputUnit> {
+    OUTPUT_UNITS.iter().find(|u| u.symbol == symbol || u.aliases.contains(&symbol))
+}
+
+impl OutputUnit {
+    fn to_base(&self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+
+    fn convert_from_base(&self, value: f64) -> f64 {
+        (value - self.offset) / self.factor
+    }
+
+    /// Convert `value` in this unit into `target`, which must be the same kind
+    pub fn convert(&self, value: f64, target: &OutputUnit) -> f64 {
+        debug_assert_eq!(self.kind, target.kind);
+        target.convert_from_base(self.to_base(value))
+    }
+}
+
+/// A rejected `output_units` entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputUnitError {
+    pub kind: String,
+    pub unit: String,
+    pub reason: String,
+}
+
+/// Unit to report each quantity kind in
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutputUnits(BTreeMap<QuantityKind, &'static OutputUnit>);
+
+impl OutputUnits {
+    /// Read a request's `output_units` object (`{"length": "ft"}`)
+    pub fn parse(value: &Value) -> Result<Self, OutputUnitError> {
+        let Some(entries) = value.as_object() else {
+            return Err(OutputUnitError {
+                kind: String::new(),
+                unit: value.to_string(),
+                reason: "Must be an object of quantity kind to unit, e.g. {\"length\": \"ft\"}".to_string(),
+            });
+        };
+
+        let mut units = BTreeMap::new();
+        for (kind_name, unit_value) in entries {
+            let unit_name = unit_value.as_str().unwrap_or_default();
+            let error = |reason: String| OutputUnitError { kind: kind_name.clone(), unit: unit_value.to_string(), reason };
+
+            let kind: QuantityKind = serde_json::from_value(Value::String(kind_name.clone()))
+                .map_err(|_| error(format!("Unknown quantity kind '{}'", kind_name)))?;
+            let unit = find_output_unit(unit_name).ok_or_else(|| error(format!("Unknown unit '{}'", unit_name)))?;
+            if unit.kind != kind {
+                return Err(error(format!("'{}' is not a unit of {}", unit.symbol, kind_name)));
+            }
+            units.insert(kind, unit);
+        }
+        Ok(Self(units))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Convert one `value`/`unit` pair in place, adding its metric value as
+    /// `si_value`/`si_unit`; returns whether its kind had a preference
+    fn apply_to_item(&self, item: &mut serde_json::Map<String, Value>) -> bool {
+        let (Some(value), Some(unit)) = (item.get("value").and_then(Value::as_f64), item.get("unit").and_then(Value::as_str)) else {
+            return false;
+        };
+        let Some(from) = find_output_unit(unit) else {
+            return false;
+        };
+        let Some(to) = self.0.get(&from.kind) else {
+            return false;
+        };
+
+        let (si_value, si_unit) = if from.metric {
+            (value, unit.to_string())
+        } else {
+            let base = base_unit(from.kind);
+            (from.convert(value, base), base.symbol.to_string())
+        };
+        item.insert("value".to_string(), Value::from(from.convert(value, to)));
+        item.insert("unit".to_string(), Value::from(to.symbol));
+        item.insert("si_value".to_string(), Value::from(si_value));
+        item.insert("si_unit".to_string(), Value::from(si_unit));
+        true
+    }
+
+    /// Convert the items of every `results` array in a response; returns how
+    /// many were converted
+    pub fn apply(&self, response: &mut Value) -> usize {
+        match response {
+            Value::Array(items) => items.iter_mut().map(|item| self.apply(item)).sum(),
+            Value::Object(object) => object
+                .iter_mut()
+                .map(|(key, child)| match (key.as_str(), child) {
+                    ("results", Value::Array(items)) => items
+                        .iter_mut()
+                        .filter_map(Value::as_object_mut)
+                        .map(|item| self.apply_to_item(item) as usize)
+                        .sum(),
+                    (_, child) => self.apply(child),
+                })
+                .sum(),
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-6 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn test_output_units_round_trip() {
+        for unit in OUTPUT_UNITS {
+            let base = base_unit(unit.kind);
+            assert!(approx(unit.convert(base.convert(12.5, unit), base), 12.5), "{} drifted", unit.symbol);
+        }
+        let gal = find_output_unit("gal").unwrap();
+        assert!(approx(find_output_unit("m³").unwrap().convert(1.0, gal), 264.172_052));
+        assert!(approx(find_output_unit("°C").unwrap().convert(100.0, find_output_unit("degF").unwrap()), 212.0));
+        assert!(approx(find_output_unit("kPa").unwrap().convert(100.0, find_output_unit("psi").unwrap()), 14.503_774));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_and_mismatched_units() {
+        let units = OutputUnits::parse(&json!({ "length": "ft", "volume": "gal" })).unwrap();
+        assert!(!units.is_empty());
+
+        assert_eq!(OutputUnits::parse(&json!({ "lenght": "ft" })).unwrap_err().kind, "lenght");
+        assert!(OutputUnits::parse(&json!({ "length": "furlongs" })).unwrap_err().reason.contains("Unknown unit"));
+        assert!(OutputUnits::parse(&json!({ "length": "gal" })).unwrap_err().reason.contains("not a unit of length"));
+        assert!(OutputUnits::parse(&json!("ft")).is_err());
+    }
+
+    #[test]
+    fn test_apply_converts_results_and_keeps_si() {
+        let units = OutputUnits::parse(&json!({ "length": "ft", "volume": "gal", "area": "ft2" })).unwrap();
+        let mut response = json!({
+            "calculation_type": "planter_box",
+            "results": [
+                { "label": "Soil Volume", "value": 1.0, "unit": "m³" },
+                { "label": "Board Length", "value": 3.048, "unit": "m" },
+                { "label": "Total Cost", "value": 120.0, "unit": "USD" }
+            ]
+        });
+
+        assert_eq!(units.apply(&mut response), 2);
+        let soil = &response["results"][0];
+        assert!(approx(soil["value"].as_f64().unwrap(), 264.172_052));
+        assert_eq!(soil["unit"], "gal");
+        assert_eq!(soil["si_value"], 1.0);
+        assert_eq!(soil["si_unit"], "m³");
+        assert!(approx(response["results"][1]["value"].as_f64().unwrap(), 10.0));
+        assert_eq!(response["results"][2], json!({ "label": "Total Cost", "value": 120.0, "unit": "USD" }));
+
+        // Already imperial from `unit_system`: the metric value is recovered
+        let mut imperial = json!({ "results": [{ "label": "Soil Volume", "value": 10.0, "unit": "ft³" }] });
+        units.apply(&mut imperial);
+        assert_eq!(imperial["results"][0]["si_unit"], "m³");
+        assert!(approx(imperial["results"][0]["si_value"].as_f64().unwrap(), 0.283_168_466));
+    }
+}
@@ -0,0 +1,220 @@
+// Unit Systems
+// Calculators work in metric: SI with the usual engineering multiples (m, mm,
+// kN, MPa, m³, °C...). A request may instead declare `unit_system: imperial`;
+// its declared inputs are then read in the imperial counterpart of each
+// parameter's documented unit and converted before the calculator runs, and
+// result values come back converted the other way.
+//
+// Units without a counterpart (USD, %, pieces, hours, ratios...) pass through
+// untouched under either system.
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+/// System of units a request is written in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnitSystem {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+/// A metric unit and its imperial counterpart:
+/// `imperial = metric × factor + offset`
+struct Counterpart {
+    metric: &'static str,
+    imperial: &'static str,
+    factor: f64,
+    offset: f64,
+}
+
+const fn scale(metric: &'static str, imperial: &'static str, factor: f64) -> Counterpart {
+    Counterpart { metric, imperial, factor, offset: 0.0 }
+}
+
+/// Feet per meter (exact: 1 ft = 0.3048 m)
+const FT_PER_M: f64 = 1.0 / 0.3048;
+/// Pounds-force per newton (1 lbf = 4.4482216152605 N)
+const LBF_PER_N: f64 = 1.0 / 4.448_221_615_260_5;
+/// Pounds per kilogram (exact: 1 lb = 0.45359237 kg)
+const LB_PER_KG: f64 = 1.0 / 0.453_592_37;
+
+const COUNTERPARTS: &[Counterpart] = &[
+    // Length and geometry
+    scale("m", "ft", FT_PER_M),
+    scale("cm", "in", 1.0 / 2.54),
+    scale("mm", "in", 1.0 / 25.4),
+    scale("km", "mi", 1.0 / 1_609.344),
+    scale("m²", "ft²", FT_PER_M * FT_PER_M),
+    scale("mm²", "in²", 1.0 / 645.16),
+    scale("mm²/m", "in²/ft", 0.3048 / 645.16),
+    scale("m³", "ft³", FT_PER_M * FT_PER_M * FT_PER_M),
+    scale("liters", "gal", 1.0 / 3.785_411_784),
+    scale("L", "gal", 1.0 / 3.785_411_784),
+    // Mass and density
+    scale("kg", "lb", LB_PER_KG),
+    scale("kg/s", "lb/s", LB_PER_KG),
+    scale("kg/m³", "lb/ft³", LB_PER_KG / (FT_PER_M * FT_PER_M * FT_PER_M)),
+    // Forces and moments
+    scale("N", "lbf", LBF_PER_N),
+    scale("kN", "kip", LBF_PER_N),
+    scale("N/mm", "lbf/in", LBF_PER_N * 25.4),
+    scale("kN/m", "kip/ft", LBF_PER_N * 0.3048),
+    scale("kN/m³", "pcf", LBF_PER_N * 1_000.0 / (FT_PER_M * FT_PER_M * FT_PER_M)),
+    scale("kNm", "kip·ft", LBF_PER_N * FT_PER_M),
+    scale("kN·m", "kip·ft", LBF_PER_N * FT_PER_M),
+    scale("kNm/m", "kip·ft/ft", LBF_PER_N),
+    // Stresses and pressures
+    scale("kPa", "psf", 1_000.0 * LBF_PER_N / (FT_PER_M * FT_PER_M)),
+    scale("MPa", "psi", 1_000_000.0 * LBF_PER_N * 0.0254 * 0.0254),
+    // Flow and velocity
+    scale("m/s", "ft/s", FT_PER_M),
+    scale("m³/s", "cfs", FT_PER_M * FT_PER_M * FT_PER_M),
+    scale("m³/min", "cfm", FT_PER_M * FT_PER_M * FT_PER_M),
+    scale("m³/h", "cfm", FT_PER_M * FT_PER_M * FT_PER_M / 60.0),
+    // Thermal and power
+    Counterpart { metric: "°C", imperial: "°F", factor: 1.8, offset: 32.0 },
+    scale("kW", "hp", 1_000.0 / 745.699_871_582_270_2),
+    scale("W/(m²·K)", "BTU/(h·ft²·°F)", 1.0 / 5.678_263_341_6),
+];
+
+fn counterpart(metric_unit: &str) -> Option<&'static Counterpart> {
+    COUNTERPARTS.iter().find(|c| c.metric == metric_unit)
+}
+
+impl UnitSystem {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Metric => "metric",
+            Self::Imperial => "imperial",
+        }
+    }
+
+    pub fn is_metric(&self) -> bool {
+        *self == Self::Metric
+    }
+
+    /// Unit a quantity documented in `metric_unit` is written in under this system
+    pub fn unit_for<'a>(&self, metric_unit: &'a str) -> &'a str {
+        match (self, counterpart(metric_unit)) {
+            (Self::Imperial, Some(c)) => c.imperial,
+            _ => metric_unit,
+        }
+    }
+
+    /// Read a value written in this system into `metric_unit`
+    pub fn to_metric(&self, value: f64, metric_unit: &str) -> f64 {
+        match (self, counterpart(metric_unit)) {
+            (Self::Imperial, Some(c)) => (value - c.offset) / c.factor,
+            _ => value,
+        }
+    }
+
+    /// Express a value given in `metric_unit` in this system, with its new
+    /// unit; `None` when it stays as it is
+    pub fn from_metric(&self, value: f64, metric_unit: &str) -> Option<(f64, &'static str)> {
+        match (self, counterpart(metric_unit)) {
+            (Self::Imperial, Some(c)) => Some((value * c.factor + c.offset, c.imperial)),
+            _ => None,
+        }
+    }
+
+    /// Convert request parameters written in this system to metric
+    ///
+    /// `declared` lists each parameter's dotted path (`dimensions.span`)
+    /// with its metric unit; numbers found at those paths are converted and
+    /// the parameters come back marked metric. Undeclared fields are left
+    /// alone.
+    pub fn parameters_to_metric<'a, P>(
+        &self,
+        parameters: P,
+        declared: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<P, serde_json::Error>
+    where
+        P: Serialize + DeserializeOwned,
+    {
+        if self.is_metric() {
+            return Ok(parameters);
+        }
+
+        let mut value = serde_json::to_value(parameters)?;
+        for (path, unit) in declared {
+            let pointer = format!("/{}", path.replace('.', "/"));
+            if let Some(number) = value.pointer_mut(&pointer)
+                && let Some(n) = number.as_f64()
+            {
+                *number = Value::from(self.to_metric(n, unit));
+            }
+        }
+        if let Some(object) = value.as_object_mut() {
+            object.remove("unit_system");
+        }
+        serde_json::from_value(value)
+    }
+
+    /// Express metric result values in this system, in place; returns how
+    /// many were converted
+    pub fn results_from_metric<'a>(&self, results: impl IntoIterator<Item = (&'a mut f64, &'a mut String)>) -> usize {
+        if self.is_metric() {
+            return 0;
+        }
+
+        let mut converted = 0;
+        for (value, unit) in results {
+            if let Some((v, u)) = self.from_metric(*value, unit) {
+                *value = v;
+                *unit = u.to_string();
+                converted += 1;
+            }
+        }
+        converted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-6 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn test_counterparts_round_trip() {
+        for c in COUNTERPARTS {
+            let (imperial, unit) = UnitSystem::Imperial.from_metric(12.5, c.metric).unwrap();
+            assert_eq!(unit, c.imperial);
+            assert!(approx(UnitSystem::Imperial.to_metric(imperial, c.metric), 12.5), "{} drifted", c.metric);
+        }
+        assert!(approx(UnitSystem::Imperial.from_metric(1.0, "MPa").unwrap().0, 145.037_738));
+        assert!(approx(UnitSystem::Imperial.from_metric(100.0, "°C").unwrap().0, 212.0));
+        assert!(approx(UnitSystem::Imperial.to_metric(10.0, "m"), 3.048));
+    }
+
+    #[test]
+    fn test_metric_and_unknown_units_pass_through() {
+        assert_eq!(UnitSystem::Metric.from_metric(3.0, "m"), None);
+        assert_eq!(UnitSystem::Imperial.from_metric(3.0, "USD"), None);
+        assert_eq!(UnitSystem::Imperial.unit_for("pieces"), "pieces");
+        assert_eq!(UnitSystem::Imperial.unit_for("kN"), "kip");
+        assert_eq!(UnitSystem::Imperial.to_metric(3.0, "%"), 3.0);
+    }
+
+    #[test]
+    fn test_parameters_to_metric() {
+        let parameters = json!({
+            "dimensions": { "span": 20.0, "count": 4.0 },
+            "material": { "yield_strength": 50_000.0 },
+            "unit_system": "imperial"
+        });
+        let declared = [("dimensions.span", "m"), ("material.yield_strength", "MPa"), ("loads.dead_load", "kN/m")];
+        let metric: Value = UnitSystem::Imperial.parameters_to_metric(parameters, declared).unwrap();
+
+        assert!(approx(metric["dimensions"]["span"].as_f64().unwrap(), 6.096));
+        assert!(approx(metric["material"]["yield_strength"].as_f64().unwrap(), 344.737_865));
+        assert_eq!(metric["dimensions"]["count"], 4.0);
+        assert!(metric.get("unit_system").is_none());
+    }
+}
@@ -0,0 +1,549 @@
+// Unit Registry
+// Maps unit symbols onto the essential conversion libraries so callers can
+// convert between any two units of the same physical dimension.
+//
+// Every dimension is routed through a single SI base unit:
+//   length → m, mass → kg, time → s, temperature → K, pressure → Pa,
+//   flow → m³/s, power → W, torque → N·m, energy → J
+
+use serde::Serialize;
+
+use super::errors::{ConversionError, ConversionResult};
+use super::essential::*;
+
+// ============================================================================
+// DIMENSIONS
+// ============================================================================
+
+/// Physical dimension of a unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Dimension {
+    Length,
+    Mass,
+    Time,
+    Temperature,
+    Pressure,
+    Flow,
+    Power,
+    Torque,
+    Energy,
+}
+
+impl Dimension {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Length => "length",
+            Self::Mass => "mass",
+            Self::Time => "time",
+            Self::Temperature => "temperature",
+            Self::Pressure => "pressure",
+            Self::Flow => "flow",
+            Self::Power => "power",
+            Self::Torque => "torque",
+            Self::Energy => "energy",
+        }
+    }
+
+    /// SI base unit every conversion in this dimension passes through
+    pub fn base_unit(&self) -> &'static str {
+        match self {
+            Self::Length => "m",
+            Self::Mass => "kg",
+            Self::Time => "s",
+            Self::Temperature => "K",
+            Self::Pressure => "Pa",
+            Self::Flow => "m3/s",
+            Self::Power => "W",
+            Self::Torque => "N_m",
+            Self::Energy => "J",
+        }
+    }
+}
+
+/// Unit definition for API discovery
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct UnitInfo {
+    pub symbol: &'static str,
+    pub name: &'static str,
+    pub dimension: Dimension,
+}
+
+const fn unit(symbol: &'static str, name: &'static str, dimension: Dimension) -> UnitInfo {
+    UnitInfo { symbol, name, dimension }
+}
+
+/// All units accepted by the conversion API
+pub const UNITS: &[UnitInfo] = &[
+    // Length
+    unit("m", "meter", Dimension::Length),
+    unit("km", "kilometer", Dimension::Length),
+    unit("dm", "decimeter", Dimension::Length),
+    unit("cm", "centimeter", Dimension::Length),
+    unit("mm", "millimeter", Dimension::Length),
+    unit("um", "micrometer", Dimension::Length),
+    unit("nm", "nanometer", Dimension::Length),
+    unit("angstrom", "angstrom", Dimension::Length),
+    unit("in", "inch", Dimension::Length),
+    unit("ft", "foot", Dimension::Length),
+    unit("yd", "yard", Dimension::Length),
+    unit("mi", "mile", Dimension::Length),
+    unit("nmi", "nautical mile", Dimension::Length),
+    unit("fathom", "fathom", Dimension::Length),
+    unit("chain", "chain", Dimension::Length),
+    unit("furlong", "furlong", Dimension::Length),
+    // Mass
+    unit("kg", "kilogram", Dimension::Mass),
+    unit("g", "gram", Dimension::Mass),
+    unit("mg", "milligram", Dimension::Mass),
+    unit("ug", "microgram", Dimension::Mass),
+    unit("t", "tonne", Dimension::Mass),
+    unit("lb", "pound", Dimension::Mass),
+    unit("oz", "ounce", Dimension::Mass),
+    unit("short_ton", "short ton (US)", Dimension::Mass),
+    unit("long_ton", "long ton (UK)", Dimension::Mass),
+    unit("st", "stone", Dimension::Mass),
+    unit("gr", "grain", Dimension::Mass),
+    unit("slug", "slug", Dimension::Mass),
+    unit("ozt", "troy ounce", Dimension::Mass),
+    // Time
+    unit("s", "second", Dimension::Time),
+    unit("ms", "millisecond", Dimension::Time),
+    unit("us", "microsecond", Dimension::Time),
+    unit("ns", "nanosecond", Dimension::Time),
+    unit("min", "minute", Dimension::Time),
+    unit("h", "hour", Dimension::Time),
+    unit("day", "day", Dimension::Time),
+    unit("week", "week", Dimension::Time),
+    unit("year", "julian year", Dimension::Time),
+    // Temperature
+    unit("K", "kelvin", Dimension::Temperature),
+    unit("C", "degree Celsius", Dimension::Temperature),
+    unit("F", "degree Fahrenheit", Dimension::Temperature),
+    unit("R", "degree Rankine", Dimension::Temperature),
+    // Pressure
+    unit("Pa", "pascal", Dimension::Pressure),
+    unit("kPa", "kilopascal", Dimension::Pressure),
+    unit("MPa", "megapascal", Dimension::Pressure),
+    unit("GPa", "gigapascal", Dimension::Pressure),
+    unit("bar", "bar", Dimension::Pressure),
+    unit("mbar", "millibar", Dimension::Pressure),
+    unit("atm", "standard atmosphere", Dimension::Pressure),
+    unit("psi", "pound-force per square inch", Dimension::Pressure),
+    unit("ksi", "kip per square inch", Dimension::Pressure),
+    unit("mH2O", "meter of water column", Dimension::Pressure),
+    unit("inH2O", "inch of water column", Dimension::Pressure),
+    unit("mmHg", "millimeter of mercury", Dimension::Pressure),
+    unit("inHg", "inch of mercury", Dimension::Pressure),
+    unit("torr", "torr", Dimension::Pressure),
+    unit("kgf/cm2", "kilogram-force per square centimeter", Dimension::Pressure),
+    // Volumetric flow
+    unit("m3/s", "cubic meter per second", Dimension::Flow),
+    unit("m3/h", "cubic meter per hour", Dimension::Flow),
+    unit("m3/min", "cubic meter per minute", Dimension::Flow),
+    unit("L/s", "liter per second", Dimension::Flow),
+    unit("L/min", "liter per minute", Dimension::Flow),
+    unit("gpm", "US gallon per minute", Dimension::Flow),
+    unit("imp_gpm", "imperial gallon per minute", Dimension::Flow),
+    unit("cfm", "cubic foot per minute", Dimension::Flow),
+    unit("cfs", "cubic foot per second", Dimension::Flow),
+    unit("bpd", "oil barrel per day", Dimension::Flow),
+    // Power
+    unit("W", "watt", Dimension::Power),
+    unit("kW", "kilowatt", Dimension::Power),
+    unit("MW", "megawatt", Dimension::Power),
+    unit("hp", "mechanical horsepower", Dimension::Power),
+    unit("hp_metric", "metric horsepower", Dimension::Power),
+    unit("hp_electrical", "electrical horsepower", Dimension::Power),
+    unit("BTU/h", "BTU per hour", Dimension::Power),
+    unit("TR", "ton of refrigeration", Dimension::Power),
+    unit("ft_lbf/s", "foot-pound-force per second", Dimension::Power),
+    unit("kcal/h", "kilocalorie per hour", Dimension::Power),
+    // Torque
+    unit("N_m", "newton-meter", Dimension::Torque),
+    unit("kN_m", "kilonewton-meter", Dimension::Torque),
+    unit("N_cm", "newton-centimeter", Dimension::Torque),
+    unit("N_mm", "newton-millimeter", Dimension::Torque),
+    unit("lbf_ft", "pound-force foot", Dimension::Torque),
+    unit("lbf_in", "pound-force inch", Dimension::Torque),
+    unit("ozf_in", "ounce-force inch", Dimension::Torque),
+    unit("kgf_m", "kilogram-force meter", Dimension::Torque),
+    unit("dyne_cm", "dyne-centimeter", Dimension::Torque),
+    // Energy
+    unit("J", "joule", Dimension::Energy),
+    unit("kJ", "kilojoule", Dimension::Energy),
+    unit("MJ", "megajoule", Dimension::Energy),
+    unit("GJ", "gigajoule", Dimension::Energy),
+    unit("Wh", "watt-hour", Dimension::Energy),
+    unit("kWh", "kilowatt-hour", Dimension::Energy),
+    unit("MWh", "megawatt-hour", Dimension::Energy),
+    unit("cal", "calorie", Dimension::Energy),
+    unit("kcal", "kilocalorie", Dimension::Energy),
+    unit("BTU", "British thermal unit", Dimension::Energy),
+    unit("therm", "therm", Dimension::Energy),
+    unit("ft_lbf", "foot-pound-force", Dimension::Energy),
+    unit("eV", "electron-volt", Dimension::Energy),
+    unit("erg", "erg", Dimension::Energy),
+];
+
+/// Look up a unit by symbol (case-sensitive: "K" and "k" are not the same thing)
+pub fn find_unit(symbol: &str) -> ConversionResult<&'static UnitInfo> {
+    UNITS
+        .iter()
+        .find(|u| u.symbol == symbol)
+        .ok_or_else(|| ConversionError::UnknownUnit(symbol.to_string()))
+}
+
+// ============================================================================
+// CONVERSION
+// ============================================================================
+
+/// Convert `value` from one unit to another of the same dimension
+pub async fn convert(value: f64, from: &str, to: &str) -> ConversionResult<f64> {
+    if !value.is_finite() {
+        return Err(ConversionError::InvalidValue(value.to_string()));
+    }
+
+    let from_unit = find_unit(from)?;
+    let to_unit = find_unit(to)?;
+
+    if from_unit.dimension != to_unit.dimension {
+        return Err(ConversionError::DimensionMismatch {
+            from_unit: from.to_string(),
+            from_dimension: from_unit.dimension,
+            to_unit: to.to_string(),
+            to_dimension: to_unit.dimension,
+        });
+    }
+
+    let base = to_base(from_unit, value).await?;
+
+    if from_unit.dimension == Dimension::Temperature && base < 0.0 {
+        return Err(ConversionError::BelowAbsoluteZero {
+            value,
+            unit: from.to_string(),
+        });
+    }
+
+    from_base(to_unit, base).await
+}
+
+/// Convert a value expressed in `unit` into the dimension's SI base unit
+async fn to_base(unit: &UnitInfo, v: f64) -> ConversionResult<f64> {
+    let converted = match unit.symbol {
+        // Length → m
+        "m" => v,
+        "km" => km_to_m(v).await,
+        "dm" => decimeter_to_m(v).await,
+        "cm" => cm_to_m(v).await,
+        "mm" => mm_to_m(v).await,
+        "um" => micro_to_m(v).await,
+        "nm" => nano_to_m(v).await,
+        "angstrom" => angstrom_to_m(v).await,
+        "in" => inches_to_m(v).await,
+        "ft" => feet_to_m(v).await,
+        "yd" => yard_to_m(v).await,
+        "mi" => miles_to_m(v).await,
+        "nmi" => nautic_to_m(v).await,
+        "fathom" => fathom_to_m(v).await,
+        "chain" => chain_to_m(v).await,
+        "furlong" => furlong_to_m(v).await,
+
+        // Mass → kg
+        "kg" => v,
+        "g" => g_to_kg(v).await,
+        "mg" => mg_to_kg(v).await,
+        "ug" => micro_g_to_kg(v).await,
+        "t" => tonne_to_kg(v).await,
+        "lb" => lb_to_kg(v).await,
+        "oz" => oz_to_kg(v).await,
+        "short_ton" => short_ton_to_kg(v).await,
+        "long_ton" => long_ton_to_kg(v).await,
+        "st" => stone_to_kg(v).await,
+        "gr" => grain_to_kg(v).await,
+        "slug" => slug_to_kg(v).await,
+        "ozt" => troy_oz_to_kg(v).await,
+
+        // Time → s
+        "s" => v,
+        "ms" => ms_to_s(v).await,
+        "us" => us_to_s(v).await,
+        "ns" => ns_to_s(v).await,
+        "min" => min_to_s(v).await,
+        "h" => h_to_s(v).await,
+        "day" => day_to_s(v).await,
+        "week" => week_to_s(v).await,
+        "year" => julian_year_to_s(v).await,
+
+        // Temperature → K
+        "K" => v,
+        "C" => c_to_k(v).await,
+        "F" => f_to_k(v).await,
+        "R" => r_to_k(v).await,
+
+        // Pressure → Pa
+        "Pa" => v,
+        "kPa" => kpa_to_pa(v).await,
+        "MPa" => mpa_to_pa(v).await,
+        "GPa" => gpa_to_pa(v).await,
+        "bar" => bar_to_pa(v).await,
+        "mbar" => mbar_to_pa(v).await,
+        "atm" => atm_to_pa(v).await,
+        "psi" => psi_to_pa(v).await,
+        "ksi" => ksi_to_pa(v).await,
+        "mH2O" => mh2o_to_pa(v).await,
+        "inH2O" => inh2o_to_pa(v).await,
+        "mmHg" => mmhg_to_pa(v).await,
+        "inHg" => inhg_to_pa(v).await,
+        "torr" => torr_to_pa(v).await,
+        "kgf/cm2" => kgf_cm2_to_pa(v).await,
+
+        // Flow → m³/s
+        "m3/s" => v,
+        "m3/h" => m3h_to_m3s(v).await,
+        "m3/min" => m3min_to_m3s(v).await,
+        "L/s" => lps_to_m3s(v).await,
+        "L/min" => lpm_to_m3s(v).await,
+        "gpm" => gpm_to_m3s(v).await,
+        "imp_gpm" => imp_gpm_to_m3s(v).await,
+        "cfm" => cfm_to_m3s(v).await,
+        "cfs" => cfs_to_m3s(v).await,
+        "bpd" => bpd_to_m3s(v).await,
+
+        // Power → W
+        "W" => v,
+        "kW" => kw_to_w(v).await,
+        "MW" => mw_to_w(v).await,
+        "hp" => hp_to_w(v).await,
+        "hp_metric" => hp_metric_to_w(v).await,
+        "hp_electrical" => hp_electrical_to_w(v).await,
+        "BTU/h" => btu_h_to_w(v).await,
+        "TR" => ton_refrigeration_to_w(v).await,
+        "ft_lbf/s" => ft_lbf_s_to_w(v).await,
+        "kcal/h" => kcal_h_to_w(v).await,
+
+        // Torque → N·m
+        "N_m" => v,
+        "kN_m" => kn_m_to_n_m(v).await,
+        "N_cm" => n_cm_to_n_m(v).await,
+        "N_mm" => n_mm_to_n_m(v).await,
+        "lbf_ft" => lbf_ft_to_n_m(v).await,
+        "lbf_in" => lbf_in_to_n_m(v).await,
+        "ozf_in" => ozf_in_to_n_m(v).await,
+        "kgf_m" => kgf_m_to_n_m(v).await,
+        "dyne_cm" => dyne_cm_to_n_m(v).await,
+
+        // Energy → J
+        "J" => v,
+        "kJ" => kj_to_j(v).await,
+        "MJ" => mj_to_j(v).await,
+        "GJ" => gj_to_j(v).await,
+        "Wh" => wh_to_j(v).await,
+        "kWh" => kwh_to_j(v).await,
+        "MWh" => mwh_to_j(v).await,
+        "cal" => cal_to_j(v).await,
+        "kcal" => kcal_to_j(v).await,
+        "BTU" => btu_to_j(v).await,
+        "therm" => therm_to_j(v).await,
+        "ft_lbf" => ft_lbf_to_j(v).await,
+        "eV" => ev_to_j(v).await,
+        "erg" => erg_to_j(v).await,
+
+        _ => return Err(ConversionError::UnknownUnit(unit.symbol.to_string())),
+    };
+    Ok(converted)
+}
+
+/// Convert a value expressed in the dimension's SI base unit into `unit`
+async fn from_base(unit: &UnitInfo, v: f64) -> ConversionResult<f64> {
+    let converted = match unit.symbol {
+        // m → length
+        "m" => v,
+        "km" => m_to_km(v).await,
+        "dm" => m_to_decimeter(v).await,
+        "cm" => m_to_cm(v).await,
+        "mm" => m_to_mm(v).await,
+        "um" => m_to_micro(v).await,
+        "nm" => m_to_nano(v).await,
+        "angstrom" => m_to_angstrom(v).await,
+        "in" => m_to_inches(v).await,
+        "ft" => m_to_feet(v).await,
+        "yd" => m_to_yard(v).await,
+        "mi" => m_to_miles(v).await,
+        "nmi" => m_to_nautic(v).await,
+        "fathom" => m_to_fathom(v).await,
+        "chain" => m_to_chain(v).await,
+        "furlong" => m_to_furlong(v).await,
+
+        // kg → mass
+        "kg" => v,
+        "g" => kg_to_g(v).await,
+        "mg" => kg_to_mg(v).await,
+        "ug" => kg_to_micro_g(v).await,
+        "t" => kg_to_tonne(v).await,
+        "lb" => kg_to_lb(v).await,
+        "oz" => kg_to_oz(v).await,
+        "short_ton" => kg_to_short_ton(v).await,
+        "long_ton" => kg_to_long_ton(v).await,
+        "st" => kg_to_stone(v).await,
+        "gr" => kg_to_grain(v).await,
+        "slug" => kg_to_slug(v).await,
+        "ozt" => kg_to_troy_oz(v).await,
+
+        // s → time
+        "s" => v,
+        "ms" => s_to_ms(v).await,
+        "us" => s_to_us(v).await,
+        "ns" => s_to_ns(v).await,
+        "min" => s_to_min(v).await,
+        "h" => s_to_h(v).await,
+        "day" => s_to_day(v).await,
+        "week" => s_to_week(v).await,
+        "year" => s_to_julian_year(v).await,
+
+        // K → temperature
+        "K" => v,
+        "C" => k_to_c(v).await,
+        "F" => k_to_f(v).await,
+        "R" => k_to_r(v).await,
+
+        // Pa → pressure
+        "Pa" => v,
+        "kPa" => pa_to_kpa(v).await,
+        "MPa" => pa_to_mpa(v).await,
+        "GPa" => pa_to_gpa(v).await,
+        "bar" => pa_to_bar(v).await,
+        "mbar" => pa_to_mbar(v).await,
+        "atm" => pa_to_atm(v).await,
+        "psi" => pa_to_psi(v).await,
+        "ksi" => pa_to_ksi(v).await,
+        "mH2O" => pa_to_mh2o(v).await,
+        "inH2O" => pa_to_inh2o(v).await,
+        "mmHg" => pa_to_mmhg(v).await,
+        "inHg" => pa_to_inhg(v).await,
+        "torr" => pa_to_torr(v).await,
+        "kgf/cm2" => pa_to_kgf_cm2(v).await,
+
+        // m³/s → flow
+        "m3/s" => v,
+        "m3/h" => m3s_to_m3h(v).await,
+        "m3/min" => m3s_to_m3min(v).await,
+        "L/s" => m3s_to_lps(v).await,
+        "L/min" => m3s_to_lpm(v).await,
+        "gpm" => m3s_to_gpm(v).await,
+        "imp_gpm" => m3s_to_imp_gpm(v).await,
+        "cfm" => m3s_to_cfm(v).await,
+        "cfs" => m3s_to_cfs(v).await,
+        "bpd" => m3s_to_bpd(v).await,
+
+        // W → power
+        "W" => v,
+        "kW" => w_to_kw(v).await,
+        "MW" => w_to_mw(v).await,
+        "hp" => w_to_hp(v).await,
+        "hp_metric" => w_to_hp_metric(v).await,
+        "hp_electrical" => w_to_hp_electrical(v).await,
+        "BTU/h" => w_to_btu_h(v).await,
+        "TR" => w_to_ton_refrigeration(v).await,
+        "ft_lbf/s" => w_to_ft_lbf_s(v).await,
+        "kcal/h" => w_to_kcal_h(v).await,
+
+        // N·m → torque
+        "N_m" => v,
+        "kN_m" => n_m_to_kn_m(v).await,
+        "N_cm" => n_m_to_n_cm(v).await,
+        "N_mm" => n_m_to_n_mm(v).await,
+        "lbf_ft" => n_m_to_lbf_ft(v).await,
+        "lbf_in" => n_m_to_lbf_in(v).await,
+        "ozf_in" => n_m_to_ozf_in(v).await,
+        "kgf_m" => n_m_to_kgf_m(v).await,
+        "dyne_cm" => n_m_to_dyne_cm(v).await,
+
+        // J → energy
+        "J" => v,
+        "kJ" => j_to_kj(v).await,
+        "MJ" => j_to_mj(v).await,
+        "GJ" => j_to_gj(v).await,
+        "Wh" => j_to_wh(v).await,
+        "kWh" => j_to_kwh(v).await,
+        "MWh" => j_to_mwh(v).await,
+        "cal" => j_to_cal(v).await,
+        "kcal" => j_to_kcal(v).await,
+        "BTU" => j_to_btu(v).await,
+        "therm" => j_to_therm(v).await,
+        "ft_lbf" => j_to_ft_lbf(v).await,
+        "eV" => j_to_ev(v).await,
+        "erg" => j_to_erg(v).await,
+
+        _ => return Err(ConversionError::UnknownUnit(unit.symbol.to_string())),
+    };
+    Ok(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[tokio::test]
+    async fn test_length_round_trip() {
+        let ft = convert(1.0, "m", "ft").await.unwrap();
+        assert!(approx(ft, 3.280839895));
+        let m = convert(ft, "ft", "m").await.unwrap();
+        assert!(approx(m, 1.0));
+    }
+
+    #[tokio::test]
+    async fn test_temperature_offsets() {
+        assert!(approx(convert(100.0, "C", "F").await.unwrap(), 212.0));
+        assert!(approx(convert(0.0, "C", "K").await.unwrap(), 273.15));
+        assert!(convert(-300.0, "C", "K").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mechanical_units() {
+        assert!(approx(convert(1.0, "bar", "psi").await.unwrap(), 14.503773773));
+        assert!(approx(convert(100.0, "gpm", "m3/h").await.unwrap(), 22.712470704));
+        assert!(approx(convert(1.0, "TR", "BTU/h").await.unwrap(), 12_000.0));
+        assert!(approx(convert(1.0, "kWh", "MJ").await.unwrap(), 3.6));
+        assert!(approx(convert(100.0, "lbf_ft", "N_m").await.unwrap(), 135.58179483314));
+    }
+
+    #[tokio::test]
+    async fn test_torque_is_not_energy() {
+        let err = convert(1.0, "lbf_ft", "ft_lbf").await.unwrap_err();
+        assert!(matches!(err, ConversionError::DimensionMismatch { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_dimension_mismatch() {
+        let err = convert(1.0, "m", "kg").await.unwrap_err();
+        assert!(matches!(err, ConversionError::DimensionMismatch { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_unit() {
+        let err = convert(1.0, "parsec", "m").await.unwrap_err();
+        assert!(matches!(err, ConversionError::UnknownUnit(_)));
+    }
+
+    #[tokio::test]
+    async fn test_unit_without_base_conversion_is_an_error() {
+        let orphan = unit("league", "league", Dimension::Length);
+        let err = to_base(&orphan, 1.0).await.unwrap_err();
+        assert!(matches!(err, ConversionError::UnknownUnit(ref symbol) if symbol == "league"));
+        assert!(from_base(&orphan, 1.0).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_every_unit_converts_to_itself() {
+        for unit in UNITS {
+            let sample = if unit.dimension == Dimension::Temperature { 300.0 } else { 12.5 };
+            let value = convert(sample, unit.symbol, unit.symbol).await.unwrap();
+            assert!(approx(value, sample), "{} round trip drifted: {}", unit.symbol, value);
+        }
+    }
+}
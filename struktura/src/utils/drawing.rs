@@ -0,0 +1,286 @@
+//! Minimal 2D drawing writer for dimensioned sketches.
+//!
+//! Geometry is in meters with y pointing up, as on a site plan. The same
+//! drawing renders to SVG for the browser and to ASCII DXF (R12) for CAD,
+//! so no external crates are needed. Dimensions are drawn as plain lines
+//! and text rather than DXF DIMENSION entities, which need block tables.
+
+use std::fmt::Write;
+
+/// Longest side of the SVG viewport, in pixels
+const SVG_SIZE: f64 = 800.0;
+/// Blank border around the SVG drawing, in pixels
+const SVG_MARGIN: f64 = 60.0;
+/// DXF text height in meters
+const DXF_TEXT_HEIGHT: f64 = 0.1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Drawing layer, which also sets the SVG stroke
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layer {
+    Outline,
+    Framing,
+    Reinforcement,
+    Posts,
+    Dimensions,
+}
+
+impl Layer {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Layer::Outline => "OUTLINE",
+            Layer::Framing => "FRAMING",
+            Layer::Reinforcement => "REINFORCEMENT",
+            Layer::Posts => "POSTS",
+            Layer::Dimensions => "DIMENSIONS",
+        }
+    }
+
+    fn stroke(&self) -> (&'static str, f64) {
+        match self {
+            Layer::Outline => ("#222222", 2.0),
+            Layer::Framing => ("#8a5a2b", 1.0),
+            Layer::Reinforcement => ("#b03030", 0.75),
+            Layer::Posts => ("#222222", 1.5),
+            Layer::Dimensions => ("#1f5fa8", 0.75),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Entity {
+    Line { from: Point, to: Point, layer: Layer },
+    Circle { center: Point, radius: f64, layer: Layer },
+    Text { at: Point, text: String },
+}
+
+/// Line drawing built up from primitives, then rendered
+#[derive(Debug, Clone)]
+pub struct Drawing {
+    pub title: String,
+    entities: Vec<Entity>,
+}
+
+impl Drawing {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), entities: Vec::new() }
+    }
+
+    pub fn line(mut self, from: Point, to: Point, layer: Layer) -> Self {
+        self.entities.push(Entity::Line { from, to, layer });
+        self
+    }
+
+    /// Axis-aligned rectangle from its lower-left corner
+    pub fn rect(self, origin: Point, width: f64, height: f64, layer: Layer) -> Self {
+        let (x0, y0, x1, y1) = (origin.x, origin.y, origin.x + width, origin.y + height);
+        self.line(Point::new(x0, y0), Point::new(x1, y0), layer)
+            .line(Point::new(x1, y0), Point::new(x1, y1), layer)
+            .line(Point::new(x1, y1), Point::new(x0, y1), layer)
+            .line(Point::new(x0, y1), Point::new(x0, y0), layer)
+    }
+
+    pub fn circle(mut self, center: Point, radius: f64, layer: Layer) -> Self {
+        self.entities.push(Entity::Circle { center, radius, layer });
+        self
+    }
+
+    pub fn label(mut self, at: Point, text: impl Into<String>) -> Self {
+        self.entities.push(Entity::Text { at, text: text.into() });
+        self
+    }
+
+    /// Dimension line parallel to `from`-`to`, `offset` meters to its left,
+    /// labelled with the length unless `text` is given
+    pub fn dimension(self, from: Point, to: Point, offset: f64, text: Option<&str>) -> Self {
+        let (dx, dy) = (to.x - from.x, to.y - from.y);
+        let length = dx.hypot(dy);
+        if length == 0.0 {
+            return self;
+        }
+        let (nx, ny) = (-dy / length * offset, dx / length * offset);
+        let a = Point::new(from.x + nx, from.y + ny);
+        let b = Point::new(to.x + nx, to.y + ny);
+        let mid = Point::new((a.x + b.x) / 2.0 + nx * 0.3, (a.y + b.y) / 2.0 + ny * 0.3);
+        let text = text.map(str::to_string).unwrap_or_else(|| format!("{:.2} m", length));
+
+        self.line(from, a, Layer::Dimensions)
+            .line(to, b, Layer::Dimensions)
+            .line(a, b, Layer::Dimensions)
+            .label(mid, text)
+    }
+
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// (min, max) corners of everything drawn
+    fn bounds(&self) -> (Point, Point) {
+        let mut min = Point::new(f64::INFINITY, f64::INFINITY);
+        let mut max = Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        let mut include = |p: Point, r: f64| {
+            min = Point::new(min.x.min(p.x - r), min.y.min(p.y - r));
+            max = Point::new(max.x.max(p.x + r), max.y.max(p.y + r));
+        };
+        for entity in &self.entities {
+            match entity {
+                Entity::Line { from, to, .. } => {
+                    include(*from, 0.0);
+                    include(*to, 0.0);
+                }
+                Entity::Circle { center, radius, .. } => include(*center, *radius),
+                Entity::Text { at, .. } => include(*at, 0.0),
+            }
+        }
+        if min.x > max.x {
+            return (Point::new(0.0, 0.0), Point::new(1.0, 1.0));
+        }
+        (min, max)
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (min, max) = self.bounds();
+        let span = (max.x - min.x).max(max.y - min.y).max(f64::EPSILON);
+        let scale = SVG_SIZE / span;
+        let width = (max.x - min.x) * scale + 2.0 * SVG_MARGIN;
+        let height = (max.y - min.y) * scale + 2.0 * SVG_MARGIN;
+        let px = |p: &Point| (SVG_MARGIN + (p.x - min.x) * scale, SVG_MARGIN + (max.y - p.y) * scale);
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.0}" height="{h:.0}" viewBox="0 0 {w:.0} {h:.0}" font-family="Helvetica, Arial, sans-serif" font-size="13">"#,
+            w = width,
+            h = height
+        );
+        let _ = writeln!(out, "<title>{}</title>", escape_xml(&self.title));
+        let _ = writeln!(out, r##"<rect width="100%" height="100%" fill="#ffffff"/>"##);
+        for entity in &self.entities {
+            match entity {
+                Entity::Line { from, to, layer } => {
+                    let ((x1, y1), (x2, y2)) = (px(from), px(to));
+                    let (stroke, width) = layer.stroke();
+                    let _ = writeln!(
+                        out,
+                        r#"<line class="{}" x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="{}"/>"#,
+                        layer.name().to_lowercase(), x1, y1, x2, y2, stroke, width
+                    );
+                }
+                Entity::Circle { center, radius, layer } => {
+                    let (cx, cy) = px(center);
+                    let (stroke, width) = layer.stroke();
+                    let _ = writeln!(
+                        out,
+                        r##"<circle class="{}" cx="{:.1}" cy="{:.1}" r="{:.1}" fill="#ffffff" stroke="{}" stroke-width="{}"/>"##,
+                        layer.name().to_lowercase(), cx, cy, (radius * scale).max(3.0), stroke, width
+                    );
+                }
+                Entity::Text { at, text } => {
+                    let (x, y) = px(at);
+                    let _ = writeln!(
+                        out,
+                        r##"<text x="{:.1}" y="{:.1}" text-anchor="middle" dominant-baseline="middle" fill="#1f5fa8">{}</text>"##,
+                        x, y, escape_xml(text)
+                    );
+                }
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    pub fn to_dxf(&self) -> String {
+        let mut out = String::from("0\nSECTION\n2\nENTITIES\n");
+        for entity in &self.entities {
+            match entity {
+                Entity::Line { from, to, layer } => {
+                    let _ = write!(
+                        out,
+                        "0\nLINE\n8\n{}\n10\n{:.4}\n20\n{:.4}\n30\n0.0\n11\n{:.4}\n21\n{:.4}\n31\n0.0\n",
+                        layer.name(), from.x, from.y, to.x, to.y
+                    );
+                }
+                Entity::Circle { center, radius, layer } => {
+                    let _ = write!(
+                        out,
+                        "0\nCIRCLE\n8\n{}\n10\n{:.4}\n20\n{:.4}\n30\n0.0\n40\n{:.4}\n",
+                        layer.name(), center.x, center.y, radius
+                    );
+                }
+                Entity::Text { at, text } => {
+                    let _ = write!(
+                        out,
+                        "0\nTEXT\n8\n{}\n10\n{:.4}\n20\n{:.4}\n30\n0.0\n40\n{}\n1\n{}\n",
+                        Layer::Dimensions.name(), at.x, at.y, DXF_TEXT_HEIGHT, text.replace('\n', " ")
+                    );
+                }
+            }
+        }
+        out.push_str("0\nENDSEC\n0\nEOF\n");
+        out
+    }
+}
+
+/// `count` evenly spaced positions from `start` to `end` inclusive; one
+/// position sits in the middle
+pub fn spaced(start: f64, end: f64, count: usize) -> Vec<f64> {
+    match count {
+        0 => Vec::new(),
+        1 => vec![(start + end) / 2.0],
+        n => (0..n).map(|i| start + (end - start) * i as f64 / (n - 1) as f64).collect(),
+    }
+}
+
+pub fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Drawing {
+        Drawing::new("Slab <A>")
+            .rect(Point::new(0.0, 0.0), 4.0, 2.0, Layer::Outline)
+            .circle(Point::new(1.0, 1.0), 0.05, Layer::Posts)
+            .dimension(Point::new(0.0, 0.0), Point::new(4.0, 0.0), -0.5, None)
+    }
+
+    #[test]
+    fn test_svg_is_scaled_and_escaped() {
+        let svg = sample().to_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("<title>Slab &lt;A&gt;</title>"));
+        assert!(svg.contains(">4.00 m</text>"));
+        assert_eq!(svg.matches("<line").count(), 7);
+    }
+
+    #[test]
+    fn test_dxf_entities() {
+        let dxf = sample().to_dxf();
+        assert!(dxf.starts_with("0\nSECTION\n2\nENTITIES\n"));
+        assert!(dxf.ends_with("0\nEOF\n"));
+        assert_eq!(dxf.matches("0\nLINE\n").count(), 7);
+        assert_eq!(dxf.matches("0\nCIRCLE\n").count(), 1);
+        assert!(dxf.contains("1\n4.00 m\n"));
+    }
+
+    #[test]
+    fn test_spaced() {
+        assert_eq!(spaced(0.0, 4.0, 3), vec![0.0, 2.0, 4.0]);
+        assert_eq!(spaced(0.0, 4.0, 1), vec![2.0]);
+        assert!(spaced(0.0, 4.0, 0).is_empty());
+    }
+}
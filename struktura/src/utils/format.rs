@@ -0,0 +1,238 @@
+// Number Formatting Policy
+// One rule per kind of unit decides how a result value is rounded for
+// display, so every `formatted_value` the API serves follows the same
+// precision. Only the display string is rounded; raw values are untouched.
+//
+// Defaults can be overridden with NUMBER_FORMAT, a comma-separated list of
+// `kind=precision` pairs where precision is a count of significant figures
+// or, with a `dp` suffix, of decimal places:
+//   NUMBER_FORMAT="measure=3,percent=2,currency=0dp"
+
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// Kind of quantity a unit string describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    /// Money and money rates (USD, USD/hour, ...)
+    Currency,
+    Percent,
+    /// Whole quantities and identifiers (pieces, bags, timestamps, ...)
+    Integer,
+    /// Ratios, factors and other unitless numbers
+    Dimensionless,
+    /// Every physical measurement
+    Measure,
+}
+
+impl UnitKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Currency => "currency",
+            Self::Percent => "percent",
+            Self::Integer => "integer",
+            Self::Dimensionless => "dimensionless",
+            Self::Measure => "measure",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        [Self::Currency, Self::Percent, Self::Integer, Self::Dimensionless, Self::Measure]
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+    }
+
+    /// Classify a result unit
+    pub fn of(unit: &str) -> Self {
+        const CURRENCIES: &[&str] = &["$", "USD", "CAD", "EUR", "GBP", "AUD"];
+        const WHOLE: &[&str] = &[
+            "units", "pieces", "bags", "sheets", "stations", "counts", "vents", "bolts",
+            "workers", "unix timestamp",
+        ];
+
+        let unit = unit.trim();
+        let base = unit.split('/').next().unwrap_or(unit);
+        if CURRENCIES.contains(&base) {
+            Self::Currency
+        } else if unit == "%" {
+            Self::Percent
+        } else if WHOLE.contains(&unit) {
+            Self::Integer
+        } else if unit.is_empty() || unit == "dimensionless" || unit == "ratio" {
+            Self::Dimensionless
+        } else {
+            Self::Measure
+        }
+    }
+}
+
+/// How many digits a kind of value keeps for display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    SignificantFigures(u8),
+    DecimalPlaces(u8),
+}
+
+impl Precision {
+    fn parse(s: &str) -> Option<Self> {
+        match s.strip_suffix("dp") {
+            Some(dp) => dp.parse().ok().map(Self::DecimalPlaces),
+            None => s.parse().ok().filter(|n| *n > 0).map(Self::SignificantFigures),
+        }
+    }
+
+    /// Round for display; the same value always gives the same string
+    pub fn round(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        let text = match *self {
+            Self::DecimalPlaces(dp) => format!("{:.*}", dp as usize, value),
+            Self::SignificantFigures(sig) => round_significant(value, sig),
+        };
+        // Never show "-0" or "-0.00"
+        if text.starts_with('-') && text[1..].chars().all(|c| c == '0' || c == '.') {
+            text[1..].to_string()
+        } else {
+            text
+        }
+    }
+}
+
+/// Largest number of decimals shown for tiny values
+const MAX_DECIMALS: i32 = 12;
+
+fn round_significant(value: f64, sig: u8) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    let mut decimals = (sig as i32 - 1 - magnitude).min(MAX_DECIMALS);
+
+    // Rounding can carry into a new digit (999.96 → 1000.0); drop one decimal
+    let scale = 10f64.powi(decimals);
+    if decimals > 0 && ((value * scale).round() / scale).abs() >= 10f64.powi(magnitude + 1) {
+        decimals -= 1;
+    }
+
+    if decimals >= 0 {
+        format!("{:.*}", decimals as usize, value)
+    } else {
+        let step = 10f64.powi(-decimals);
+        format!("{:.0}", (value / step).round() * step)
+    }
+}
+
+/// Precision rules by unit kind
+#[derive(Debug, Clone)]
+pub struct FormatPolicy {
+    rules: HashMap<UnitKind, Precision>,
+}
+
+impl Default for FormatPolicy {
+    fn default() -> Self {
+        Self {
+            rules: HashMap::from([
+                (UnitKind::Currency, Precision::DecimalPlaces(2)),
+                (UnitKind::Percent, Precision::SignificantFigures(3)),
+                (UnitKind::Integer, Precision::DecimalPlaces(0)),
+                (UnitKind::Dimensionless, Precision::SignificantFigures(3)),
+                (UnitKind::Measure, Precision::SignificantFigures(4)),
+            ]),
+        }
+    }
+}
+
+impl FormatPolicy {
+    /// Defaults with any overrides from a NUMBER_FORMAT-style spec
+    pub fn parse(spec: &str) -> Self {
+        let mut policy = Self::default();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let parsed = entry
+                .split_once('=')
+                .and_then(|(kind, precision)| Some((UnitKind::parse(kind.trim())?, Precision::parse(precision.trim())?)));
+            match parsed {
+                Some((kind, precision)) => {
+                    policy.rules.insert(kind, precision);
+                }
+                None => tracing::warn!(%entry, "ignoring invalid NUMBER_FORMAT entry"),
+            }
+        }
+        policy
+    }
+
+    pub fn from_env() -> Self {
+        std::env::var("NUMBER_FORMAT").map(|spec| Self::parse(&spec)).unwrap_or_default()
+    }
+
+    pub fn precision(&self, kind: UnitKind) -> Precision {
+        self.rules.get(&kind).copied().unwrap_or(Precision::SignificantFigures(4))
+    }
+
+    /// Display string for a value in the given unit
+    pub fn format(&self, value: f64, unit: &str) -> String {
+        let kind = UnitKind::of(unit);
+        let number = self.precision(kind).round(value);
+        let unit = unit.trim();
+
+        match kind {
+            UnitKind::Currency => match unit.strip_prefix("USD").or_else(|| unit.strip_prefix('$')) {
+                Some(rate) => match number.strip_prefix('-') {
+                    Some(abs) => format!("-${}{}", abs, rate),
+                    None => format!("${}{}", number, rate),
+                },
+                None => format!("{} {}", number, unit),
+            },
+            UnitKind::Percent => format!("{}%", number),
+            UnitKind::Dimensionless => number,
+            UnitKind::Integer | UnitKind::Measure => format!("{} {}", number, unit),
+        }
+    }
+}
+
+lazy_static! {
+    static ref POLICY: FormatPolicy = FormatPolicy::from_env();
+}
+
+/// The process-wide policy, read from NUMBER_FORMAT on first use
+pub fn policy() -> &'static FormatPolicy {
+    &POLICY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_significant_figures() {
+        let p = Precision::SignificantFigures(4);
+        assert_eq!(p.round(1234.567), "1235");
+        assert_eq!(p.round(123456.0), "123500");
+        assert_eq!(p.round(0.00123456), "0.001235");
+        assert_eq!(p.round(999.96), "1000");
+        assert_eq!(p.round(-0.00001), "-0.00001000");
+        assert_eq!(p.round(0.0), "0");
+        assert_eq!(Precision::DecimalPlaces(2).round(-0.001), "0.00");
+    }
+
+    #[test]
+    fn test_formats_by_unit_kind() {
+        let policy = FormatPolicy::default();
+        assert_eq!(policy.format(30603390.034, "USD"), "$30603390.03");
+        assert_eq!(policy.format(45.5, "USD/hour"), "$45.50/hour");
+        assert_eq!(policy.format(-12.0, "CAD"), "-12.00 CAD");
+        assert_eq!(policy.format(85.123, "%"), "85.1%");
+        assert_eq!(policy.format(12.4, "pieces"), "12 pieces");
+        assert_eq!(policy.format(0.85291, "dimensionless"), "0.853");
+        assert_eq!(policy.format(4.56789, "kN"), "4.568 kN");
+    }
+
+    #[test]
+    fn test_overrides_from_spec() {
+        let policy = FormatPolicy::parse("measure=2, currency=0dp, bogus=3, percent=x");
+        assert_eq!(policy.precision(UnitKind::Measure), Precision::SignificantFigures(2));
+        assert_eq!(policy.precision(UnitKind::Currency), Precision::DecimalPlaces(0));
+        assert_eq!(policy.precision(UnitKind::Percent), Precision::SignificantFigures(3));
+        assert_eq!(policy.format(4.56789, "m"), "4.6 m");
+    }
+}
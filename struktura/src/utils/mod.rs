@@ -1,3 +1,10 @@
 pub mod conversions;
+pub mod drawing;
+pub mod format;
+pub mod negotiate;
+pub mod pdf;
+pub mod versioning;
+pub mod router;
 
-pub use conversions::*;
\ No newline at end of file
+pub use conversions::*;
+pub use router::create_router;
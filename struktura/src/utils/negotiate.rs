@@ -0,0 +1,181 @@
+//! Response content negotiation for calculation results.
+//!
+//! Calculation endpoints answer in JSON unless the client's `Accept` header
+//! prefers `application/xml` (or `text/xml`), in which case the same serde
+//! model is written as XML with quick-xml. Errors stay JSON either way.
+
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use std::convert::Infallible;
+
+/// Root element of XML calculation responses
+const XML_ROOT: &str = "calculation";
+
+/// Wire format picked from the request's `Accept` header
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResponseFormat {
+    #[default]
+    Json,
+    Xml,
+}
+
+impl ResponseFormat {
+    /// Pick the format with the highest quality value; JSON wins ties and
+    /// anything unrecognised
+    pub fn from_accept(accept: &str) -> Self {
+        let mut best = (Self::Json, 0.0_f32);
+        for range in accept.split(',') {
+            let mut parts = range.split(';').map(str::trim);
+            let media = parts.next().unwrap_or_default().to_ascii_lowercase();
+            let quality = parts
+                .find_map(|p| p.strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            let format = match media.as_str() {
+                "application/xml" | "text/xml" => Self::Xml,
+                "application/json" | "application/*" | "*/*" => Self::Json,
+                _ => continue,
+            };
+            if quality > best.1 || (quality == best.1 && format == Self::Json) {
+                best = (format, quality);
+            }
+        }
+        best.0
+    }
+
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        headers
+            .get(header::ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .map(Self::from_accept)
+            .unwrap_or_default()
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for ResponseFormat {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(&parts.headers))
+    }
+}
+
+/// Serialize a model as an XML document
+pub fn to_xml<T: Serialize>(value: &T) -> Result<String, quick_xml::SeError> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let mut serializer = quick_xml::se::Serializer::with_root(&mut xml, Some(XML_ROOT))?;
+    serializer.indent(' ', 2);
+    value.serialize(serializer)?;
+    Ok(xml)
+}
+
+/// A response body written in whichever format the client asked for
+pub struct Negotiated<T>(pub ResponseFormat, pub T);
+
+impl<T: Serialize> IntoResponse for Negotiated<T> {
+    fn into_response(self) -> Response {
+        let Negotiated(format, body) = self;
+        match format {
+            ResponseFormat::Json => Json(body).into_response(),
+            ResponseFormat::Xml => match to_xml(&body) {
+                Ok(xml) => (
+                    [(header::CONTENT_TYPE, HeaderValue::from_static("application/xml; charset=utf-8"))],
+                    xml,
+                )
+                    .into_response(),
+                Err(e) => {
+                    tracing::error!(error = %e, "failed to serialize response as XML");
+                    (
+                        StatusCode::NOT_ACCEPTABLE,
+                        Json(serde_json::json!({
+                            "error": "This response cannot be represented as XML; request application/json instead",
+                        })),
+                    )
+                        .into_response()
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculus::links::{self, Hal};
+    use crate::calculus::recommendation::Tier;
+    use serde::Serialize;
+
+    #[test]
+    fn test_accept_header_selection() {
+        assert_eq!(ResponseFormat::from_accept("application/xml"), ResponseFormat::Xml);
+        assert_eq!(ResponseFormat::from_accept("text/xml"), ResponseFormat::Xml);
+        assert_eq!(ResponseFormat::from_accept("application/json"), ResponseFormat::Json);
+        assert_eq!(ResponseFormat::from_accept("*/*"), ResponseFormat::Json);
+        assert_eq!(ResponseFormat::from_accept("text/html"), ResponseFormat::Json);
+        assert_eq!(
+            ResponseFormat::from_accept("application/json;q=0.5, application/xml"),
+            ResponseFormat::Xml
+        );
+        assert_eq!(
+            ResponseFormat::from_accept("application/xml, application/json"),
+            ResponseFormat::Json
+        );
+    }
+
+    #[derive(Serialize)]
+    struct Item {
+        label: String,
+        value: f64,
+    }
+
+    #[derive(Serialize)]
+    struct Body {
+        calculation_type: String,
+        results: Vec<Item>,
+        warnings: Vec<String>,
+        note: Option<String>,
+    }
+
+    #[test]
+    fn test_serializes_models_as_xml() {
+        let body = Body {
+            calculation_type: "beam_design".to_string(),
+            results: vec![
+                Item { label: "Moment".to_string(), value: 12.5 },
+                Item { label: "Shear & axial".to_string(), value: 3.0 },
+            ],
+            warnings: vec![],
+            note: None,
+        };
+        let xml = to_xml(&body).unwrap();
+
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<calculation>"));
+        assert!(xml.contains("<calculation_type>beam_design</calculation_type>"));
+        assert!(xml.contains("<label>Shear &amp; axial</label>"));
+        assert_eq!(xml.matches("<results>").count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_calculator_responses_serialize_as_xml() {
+        let registry = crate::calculus::beginner::create_default_registry();
+        let calculator = registry.find("baseboard").unwrap();
+        let parameters = serde_json::from_value(serde_json::json!({
+            "width": 5.5, "length": 7.5, "height": 2.0
+        }))
+        .unwrap();
+        let response = registry.execute(&calculator, parameters).await.unwrap();
+        let links = links::calculation(Tier::Beginner, "baseboard", [("crown_molding".to_string(), "Crown Molding".to_string())]);
+
+        let xml = to_xml(&Hal::new(response, links)).unwrap();
+        assert!(xml.contains("<calculation_type>baseboard</calculation_type>"));
+        assert!(xml.contains("<results>"));
+        assert!(xml.contains("<_links>"));
+    }
+}
@@ -0,0 +1,253 @@
+//! Minimal PDF writer for text documents (statements, reports, proposals).
+//!
+//! Produces PDF 1.4 with the built-in Helvetica fonts, so no font files or
+//! external crates are needed. Layout is top-to-bottom lines of text on
+//! A4 pages with automatic page breaks.
+
+/// A4 in points
+const PAGE_WIDTH: f64 = 595.0;
+const PAGE_HEIGHT: f64 = 842.0;
+const MARGIN: f64 = 50.0;
+/// Characters per line for wrapped 10pt text across the printable width
+const WRAP_CHARS: usize = 95;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+#[derive(Debug, Clone)]
+struct Line {
+    font: Font,
+    size: f64,
+    /// (x offset from the left margin, text)
+    cells: Vec<(f64, String)>,
+    spacing: f64,
+}
+
+/// Line-oriented PDF document builder
+#[derive(Debug, Clone, Default)]
+pub struct PdfDocument {
+    lines: Vec<Line>,
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, text: &str) -> Self {
+        self.push(Font::Bold, 18.0, vec![(0.0, text.to_string())], 10.0);
+        self
+    }
+
+    pub fn heading(mut self, text: &str) -> Self {
+        self.push(Font::Bold, 12.0, vec![(0.0, text.to_string())], 8.0);
+        self
+    }
+
+    pub fn text(mut self, text: &str) -> Self {
+        self.push(Font::Regular, 10.0, vec![(0.0, text.to_string())], 4.0);
+        self
+    }
+
+    /// Body text wrapped at word boundaries to fit the page
+    pub fn paragraph(mut self, text: &str) -> Self {
+        let mut line = String::new();
+        for word in text.split_whitespace() {
+            if !line.is_empty() && line.len() + 1 + word.len() > WRAP_CHARS {
+                self = self.text(&std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        if !line.is_empty() {
+            self = self.text(&line);
+        }
+        self
+    }
+
+    /// Columns at fixed offsets (points from the left margin)
+    pub fn row(mut self, columns: &[(f64, &str)]) -> Self {
+        let cells = columns.iter().map(|(x, t)| (*x, t.to_string())).collect();
+        self.push(Font::Regular, 10.0, cells, 4.0);
+        self
+    }
+
+    /// Like `row`, in bold - for table headers and totals
+    pub fn bold_row(mut self, columns: &[(f64, &str)]) -> Self {
+        let cells = columns.iter().map(|(x, t)| (*x, t.to_string())).collect();
+        self.push(Font::Bold, 10.0, cells, 4.0);
+        self
+    }
+
+    pub fn spacer(mut self) -> Self {
+        self.push(Font::Regular, 10.0, vec![], 4.0);
+        self
+    }
+
+    fn push(&mut self, font: Font, size: f64, cells: Vec<(f64, String)>, spacing: f64) {
+        self.lines.push(Line { font, size, cells, spacing });
+    }
+
+    /// Lay out pages and serialize the document
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let pages = self.paginate();
+
+        // Objects: 1 catalog, 2 page tree, 3 regular font, 4 bold font,
+        // then a (page, content stream) pair per page
+        let mut objects: Vec<String> = Vec::new();
+        let page_ids: Vec<usize> = (0..pages.len()).map(|i| 5 + i * 2).collect();
+
+        objects.push("<< /Type /Catalog /Pages 2 0 R >>".to_string());
+        objects.push(format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids.iter().map(|id| format!("{} 0 R", id)).collect::<Vec<_>>().join(" "),
+            pages.len()
+        ));
+        objects.push("<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_string());
+        objects.push("<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>".to_string());
+
+        for (i, stream) in pages.iter().enumerate() {
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                PAGE_WIDTH, PAGE_HEIGHT, page_ids[i] + 1
+            ));
+            objects.push(format!("<< /Length {} >>\nstream\n{}\nendstream", stream.len(), stream));
+        }
+
+        let mut out = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (i, body) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, body).as_bytes());
+        }
+
+        let xref_offset = out.len();
+        out.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+        for offset in offsets {
+            out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        out.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref_offset
+            )
+            .as_bytes(),
+        );
+        out
+    }
+
+    /// Content streams, one per page
+    fn paginate(&self) -> Vec<String> {
+        let mut pages = Vec::new();
+        let mut stream = String::new();
+        let mut y = PAGE_HEIGHT - MARGIN;
+
+        for line in &self.lines {
+            let advance = line.size + line.spacing;
+            if y - advance < MARGIN {
+                pages.push(std::mem::take(&mut stream));
+                y = PAGE_HEIGHT - MARGIN;
+            }
+            y -= advance;
+
+            let font = match line.font {
+                Font::Regular => "F1",
+                Font::Bold => "F2",
+            };
+            for (x, text) in &line.cells {
+                stream.push_str(&format!(
+                    "BT /{} {} Tf {:.1} {:.1} Td ({}) Tj ET\n",
+                    font,
+                    line.size,
+                    MARGIN + x,
+                    y,
+                    escape(text)
+                ));
+            }
+        }
+
+        pages.push(stream);
+        pages
+    }
+}
+
+/// Escape a string for a PDF literal; characters outside WinAnsi become '?'
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' '..='~' => out.push(c),
+            // Latin-1 supplement maps directly in WinAnsiEncoding
+            '\u{a0}'..='\u{ff}' => out.push_str(&format!("\\{:03o}", c as u32)),
+            _ => out.push('?'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_structure_and_xref() {
+        let bytes = PdfDocument::new()
+            .title("Usage Statement")
+            .row(&[(0.0, "Beginner"), (300.0, "12")])
+            .to_bytes();
+        let text = String::from_utf8(bytes.clone()).unwrap();
+
+        assert!(text.starts_with("%PDF-1.4"));
+        assert!(text.ends_with("%%EOF\n"));
+
+        // startxref must point at the xref table
+        let start: usize = text.rsplit("startxref\n").next().unwrap().lines().next().unwrap().parse().unwrap();
+        assert!(text[start..].starts_with("xref"));
+
+        // and every xref entry at its object
+        let entries: Vec<usize> = text[start..]
+            .lines()
+            .skip(3)
+            .take_while(|l| l.ends_with(" n "))
+            .map(|l| l[..10].parse().unwrap())
+            .collect();
+        for (i, offset) in entries.iter().enumerate() {
+            assert!(text[*offset..].starts_with(&format!("{} 0 obj", i + 1)));
+        }
+    }
+
+    #[test]
+    fn test_long_documents_paginate() {
+        let mut doc = PdfDocument::new();
+        for i in 0..200 {
+            doc = doc.text(&format!("Line {}", i));
+        }
+        let text = String::from_utf8(doc.to_bytes()).unwrap();
+        assert!(text.contains("/Count 4"));
+    }
+
+    #[test]
+    fn test_paragraph_wraps_at_words() {
+        let doc = PdfDocument::new().paragraph(&"word ".repeat(40));
+        assert_eq!(doc.lines.len(), 3);
+        assert!(doc.lines.iter().all(|l| l.cells[0].1.len() <= WRAP_CHARS));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a (b) \\ c"), "a \\(b\\) \\\\ c");
+        assert_eq!(escape("m²"), "m\\262");
+        assert_eq!(escape("≥"), "?");
+    }
+}
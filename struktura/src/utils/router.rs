@@ -0,0 +1,88 @@
+use crate::state::AppState;
+use crate::utils::conversions::{
+    errors::ConversionError,
+    units::{self, Dimension, UnitInfo},
+};
+use axum::{
+    extract::Query,
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Request body for unit conversion
+#[derive(Debug, Deserialize)]
+pub struct ConvertRequest {
+    pub value: f64,
+    pub from: String,
+    pub to: String,
+}
+
+/// Unit conversion result
+#[derive(Debug, Serialize)]
+pub struct ConvertResponse {
+    pub value: f64,
+    pub from: String,
+    pub to: String,
+    pub result: f64,
+    pub dimension: Dimension,
+}
+
+/// Query parameters for the unit listing
+#[derive(Debug, Deserialize)]
+pub struct UnitsQuery {
+    /// Filter by dimension ("length", "mass", ...)
+    dimension: Option<String>,
+}
+
+// ============================================================================
+// HANDLERS
+// ============================================================================
+
+/// POST /api/v1/utils/convert
+/// Convert a value between two units of the same dimension
+async fn convert_handler(
+    Json(payload): Json<ConvertRequest>,
+) -> Result<Json<ConvertResponse>, ConversionError> {
+    let result = units::convert(payload.value, &payload.from, &payload.to).await?;
+    let dimension = units::find_unit(&payload.from)?.dimension;
+
+    Ok(Json(ConvertResponse {
+        value: payload.value,
+        from: payload.from,
+        to: payload.to,
+        result,
+        dimension,
+    }))
+}
+
+/// GET /api/v1/utils/units
+/// List supported units, optionally filtered by dimension
+async fn units_handler(Query(query): Query<UnitsQuery>) -> Json<Vec<UnitInfo>> {
+    let units = units::UNITS
+        .iter()
+        .filter(|u| {
+            query
+                .dimension
+                .as_deref()
+                .is_none_or(|d| u.dimension.as_str() == d)
+        })
+        .copied()
+        .collect();
+
+    Json(units)
+}
+
+// ============================================================================
+// ROUTER CONSTRUCTION
+// ============================================================================
+
+/// Create the utilities router
+///
+/// This router should be nested under `/api/v1/utils`
+pub fn create_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/convert", post(convert_handler))
+        .route("/units", get(units_handler))
+}
@@ -0,0 +1,214 @@
+//! Request schema versioning for calculation endpoints.
+//!
+//! The URL prefix (`/api/v1`) only moves for wholesale breaks. Payload
+//! shapes evolve per endpoint instead: clients pin the schema they were
+//! written against with the `X-API-Version` header, and requests written
+//! against an older version are upconverted to the current internal models
+//! before any extractor, the job queue or the history recorder sees them.
+//!
+//! - No header means version 1, so existing integrations keep working
+//!   unchanged. New integrations should send the current version.
+//! - Every response echoes the version it was served under.
+//! - A version the server doesn't know is rejected with 400 rather than
+//!   guessed at.
+//!
+//! Each tier lists its [`Adapter`]s: the endpoint, the version that changed
+//! its shape, and the function rewriting the previous shape into it. A
+//! request runs every adapter newer than the version it was written against,
+//! oldest first.
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{FromRequestParts, Request, State},
+    http::{header, request::Parts, HeaderMap, HeaderName, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::convert::Infallible;
+
+/// Header clients pin their schema version with
+pub const VERSION_HEADER: &str = "x-api-version";
+
+/// Largest body upconverted; matches axum's default body limit
+const MAX_UPGRADED_BYTES: usize = 2 * 1024 * 1024;
+
+/// Request schema version
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum ApiVersion {
+    /// Shapes as first published; assumed when no version is sent
+    #[default]
+    V1,
+    /// Engineering parameters without `structured_data`
+    V2,
+}
+
+impl ApiVersion {
+    pub const CURRENT: ApiVersion = ApiVersion::V2;
+    pub const SUPPORTED: [ApiVersion; 2] = [ApiVersion::V1, ApiVersion::V2];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            ApiVersion::V1 => 1,
+            ApiVersion::V2 => 2,
+        }
+    }
+
+    /// Accepts `2` or `v2`
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let number = value.strip_prefix(['v', 'V']).unwrap_or(value).parse::<u8>().ok()?;
+        Self::SUPPORTED.into_iter().find(|v| v.number() == number)
+    }
+
+    /// The version a request was written against; `None` when it names one
+    /// this server doesn't support
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        match headers.get(VERSION_HEADER) {
+            None => Some(Self::default()),
+            Some(value) => value.to_str().ok().and_then(Self::parse),
+        }
+    }
+}
+
+/// The version the request was negotiated at, for handlers that care
+impl<S: Send + Sync> FromRequestParts<S> for ApiVersion {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts.extensions.get::<ApiVersion>().copied().unwrap_or_default())
+    }
+}
+
+/// Rewrites a request body from the shape before `since` into the shape at
+/// `since`; the error explains what in the payload couldn't be carried over
+pub type Upgrade = fn(&mut Value) -> Result<(), String>;
+
+/// One schema change on one endpoint
+pub struct Adapter {
+    /// Path suffix the change applies to, e.g. `/calculate`
+    pub path: &'static str,
+    /// Version that introduced the new shape
+    pub since: ApiVersion,
+    pub upgrade: Upgrade,
+}
+
+/// Apply every adapter for `path` newer than `version`, oldest first
+pub fn upgrade(adapters: &[Adapter], path: &str, version: ApiVersion, body: &mut Value) -> Result<bool, String> {
+    let mut pending: Vec<&Adapter> = adapters
+        .iter()
+        .filter(|a| a.since > version && path.ends_with(a.path))
+        .collect();
+    pending.sort_by_key(|a| a.since);
+
+    for adapter in &pending {
+        (adapter.upgrade)(body)?;
+    }
+    Ok(!pending.is_empty())
+}
+
+fn rejection(status: StatusCode, error: &str, message: String) -> Response {
+    let supported: Vec<u8> = ApiVersion::SUPPORTED.iter().map(ApiVersion::number).collect();
+    (
+        status,
+        Json(serde_json::json!({
+            "error": error,
+            "message": message,
+            "supported_versions": supported,
+            "current_version": ApiVersion::CURRENT.number(),
+        })),
+    )
+        .into_response()
+}
+
+/// Negotiate the request's schema version and upconvert older payloads
+pub async fn version_middleware(
+    State(adapters): State<&'static [Adapter]>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(version) = ApiVersion::from_headers(request.headers()) else {
+        let sent = request.headers().get(VERSION_HEADER).and_then(|v| v.to_str().ok()).unwrap_or_default();
+        return rejection(
+            StatusCode::BAD_REQUEST,
+            "unsupported_api_version",
+            format!("API version '{}' is not supported", sent),
+        );
+    };
+
+    let (mut parts, body) = request.into_parts();
+    parts.extensions.insert(version);
+
+    let needs_upgrade = parts.method == Method::POST
+        && adapters.iter().any(|a| a.since > version && parts.uri.path().ends_with(a.path));
+
+    let body = if needs_upgrade {
+        let Ok(bytes) = to_bytes(body, MAX_UPGRADED_BYTES).await else {
+            return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+        };
+        // Bodies that aren't JSON go through untouched for the handler to reject
+        match serde_json::from_slice::<Value>(&bytes) {
+            Ok(mut payload) => match upgrade(adapters, parts.uri.path(), version, &mut payload) {
+                Ok(_) => {
+                    parts.headers.remove(header::CONTENT_LENGTH);
+                    Body::from(serde_json::to_vec(&payload).unwrap_or_else(|_| bytes.to_vec()))
+                }
+                Err(message) => {
+                    return rejection(StatusCode::UNPROCESSABLE_ENTITY, "payload_not_upgradable", message);
+                }
+            },
+            Err(_) => Body::from(bytes),
+        }
+    } else {
+        body
+    };
+
+    let mut response = next.run(Request::from_parts(parts, body)).await;
+    response
+        .headers_mut()
+        .insert(HeaderName::from_static(VERSION_HEADER), HeaderValue::from(version.number() as u16));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rename_span(body: &mut Value) -> Result<(), String> {
+        let span = body.as_object_mut().and_then(|b| b.remove("span")).ok_or("missing span")?;
+        body["length"] = span;
+        Ok(())
+    }
+
+    const ADAPTERS: &[Adapter] = &[Adapter { path: "/calculate", since: ApiVersion::V2, upgrade: rename_span }];
+
+    #[test]
+    fn test_version_header_parsing() {
+        assert_eq!(ApiVersion::parse("2"), Some(ApiVersion::V2));
+        assert_eq!(ApiVersion::parse(" v1 "), Some(ApiVersion::V1));
+        assert_eq!(ApiVersion::parse("3"), None);
+        assert_eq!(ApiVersion::parse("latest"), None);
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(ApiVersion::from_headers(&headers), Some(ApiVersion::V1));
+        headers.insert(VERSION_HEADER, HeaderValue::from_static("9"));
+        assert_eq!(ApiVersion::from_headers(&headers), None);
+    }
+
+    #[test]
+    fn test_upgrade_runs_only_newer_adapters_for_the_endpoint() {
+        let mut body = json!({ "span": 4.0 });
+        assert_eq!(upgrade(ADAPTERS, "/api/v1/calculus/engineer/calculate", ApiVersion::V1, &mut body), Ok(true));
+        assert_eq!(body, json!({ "length": 4.0 }));
+
+        let mut current = json!({ "length": 4.0 });
+        assert_eq!(upgrade(ADAPTERS, "/api/v1/calculus/engineer/calculate", ApiVersion::V2, &mut current), Ok(false));
+
+        let mut other = json!({ "span": 4.0 });
+        assert_eq!(upgrade(ADAPTERS, "/api/v1/calculus/engineer/batch", ApiVersion::V1, &mut other), Ok(false));
+        assert_eq!(other, json!({ "span": 4.0 }));
+    }
+}
@@ -0,0 +1,1513 @@
+// Generated by `cargo run --bin generate_sdk` - do not edit by hand.
+// Source: calculator registries and serde output of the Rust API models.
+
+export type Tier = "beginner" | "engineer" | "contractor";
+
+export type BeginnerCalculationType =
+  | "acoustic_treatment"
+  | "baseboard"
+  | "compost_bin"
+  | "concrete_slab"
+  | "crown_molding"
+  | "deck"
+  | "drain_line"
+  | "drip_irrigation"
+  | "driveway"
+  | "drop_ceiling"
+  | "drywall_ceiling"
+  | "drywall_count"
+  | "electrical_service_load"
+  | "fence"
+  | "gravel_path"
+  | "hardwood_flooring"
+  | "hvac_sizing"
+  | "insulation"
+  | "laminate_flooring"
+  | "lawn_seed"
+  | "mulch_bed"
+  | "paint_coverage"
+  | "patio"
+  | "pergola"
+  | "pipe_run"
+  | "planter_box"
+  | "raised_garden_bed"
+  | "recessed_lighting"
+  | "retaining_wall"
+  | "shed_foundation"
+  | "sod"
+  | "sprinkler_coverage"
+  | "stepping_stone"
+  | "tile_count"
+  | "track_lighting"
+  | "wall_framing"
+  | "wallpaper";
+
+export type EngineerCalculationType =
+  | "beam_design"
+  | "capacity_planning"
+  | "column_design"
+  | "compressor_sizing"
+  | "condensation_risk"
+  | "connection_design"
+  | "conveyor_belt"
+  | "culvert_sizing"
+  | "elevator_traffic"
+  | "facility_layout"
+  | "foundation_design"
+  | "generator_ups_sizing"
+  | "grounding_lightning_protection"
+  | "heat_exchanger"
+  | "hvac_load_calculation"
+  | "inventory_optimization"
+  | "lateral_load_analysis"
+  | "moment_frame_design"
+  | "pavement_design"
+  | "pile_foundation"
+  | "piping_pressure_drop"
+  | "process_capability"
+  | "production_line_balancing"
+  | "pump_sizing"
+  | "rational_method_runoff"
+  | "refrigeration_cycle"
+  | "retaining_wall"
+  | "seismic_load"
+  | "settlement_analysis"
+  | "slab_design"
+  | "slope_stability"
+  | "soil_bearing_capacity"
+  | "soil_compaction"
+  | "sprinkler_hydraulic"
+  | "storm_sewer_sizing"
+  | "thermal_expansion"
+  | "tower_crane"
+  | "truss_analysis"
+  | "valve_sizing"
+  | "water_hammer"
+  | "wind_load"
+  | "work_sampling";
+
+export type ContractorCalculationType =
+  | "bid_bond"
+  | "bid_pricing"
+  | "budget_forecast"
+  | "cash_flow_analysis"
+  | "change_order"
+  | "contingency_planning"
+  | "contract_estimation"
+  | "cost_breakdown"
+  | "critical_path"
+  | "delay_analysis"
+  | "equipment_cost"
+  | "fuel_emissions"
+  | "gantt_chart"
+  | "labor_cost"
+  | "material_cost"
+  | "milestone_tracking"
+  | "overhead"
+  | "profit_margin"
+  | "progress_tracking"
+  | "project_closeout"
+  | "quality_control"
+  | "quantity_takeoff"
+  | "resource_allocation"
+  | "resource_leveling"
+  | "risk_assessment"
+  | "safety_planning"
+  | "schedule_optimization"
+  | "subcontractor_evaluation"
+  | "time_cost_tradeoff"
+  | "travel_cost"
+  | "value_engineering";
+
+export interface BeginnerCalculationRequestParameters {
+  additional?: Record<string, number>;
+  height: number;
+  length: number;
+  width: number;
+}
+
+export interface BeginnerCalculationRequest {
+  calculation_type: BeginnerCalculationType;
+  parameters: BeginnerCalculationRequestParameters;
+}
+
+export interface BeginnerCalculationResponseResult {
+  label: string;
+  unit: string;
+  value: number;
+}
+
+export interface BeginnerCalculationResponse {
+  calculation_type: string;
+  results: BeginnerCalculationResponseResult[];
+  warnings: string[];
+}
+
+export interface BeginnerCatalogueCalculatorExampleRequestParameters {
+  additional?: Record<string, number>;
+  height: number;
+  length: number;
+  width: number;
+}
+
+export interface BeginnerCatalogueCalculatorExampleRequest {
+  calculation_type: string;
+  parameters: BeginnerCatalogueCalculatorExampleRequestParameters;
+}
+
+export interface BeginnerCatalogueCalculatorExample {
+  expected_results: Record<string, number>;
+  name: string;
+  request: BeginnerCatalogueCalculatorExampleRequest;
+}
+
+export interface BeginnerCatalogueCalculatorParameter {
+  data_type: string;
+  description: string;
+  max_value: number;
+  min_value: number;
+  name: string;
+  path: string;
+  required: boolean;
+  typical_range: number[];
+  unit: string;
+}
+
+export interface BeginnerCatalogueCalculator {
+  category: string;
+  description: string;
+  examples: BeginnerCatalogueCalculatorExample[];
+  id: string;
+  name: string;
+  optional_parameters: string[];
+  parameters: BeginnerCatalogueCalculatorParameter[];
+  required_parameters: string[];
+}
+
+export interface BeginnerCatalogueCategory {
+  description: string;
+  icon: string;
+  id: string;
+  name: string;
+}
+
+export interface BeginnerCatalogueChangelogRecessedLightingItem {
+  date: string;
+  impact: string;
+  note: string;
+  summary: string;
+  version: string;
+}
+
+export interface BeginnerCatalogueChangelog {
+  recessed_lighting: BeginnerCatalogueChangelogRecessedLightingItem[];
+}
+
+export interface BeginnerCatalogue {
+  calculators: BeginnerCatalogueCalculator[];
+  categories: BeginnerCatalogueCategory[];
+  changelog: BeginnerCatalogueChangelog;
+  disclaimer: string;
+  version: string;
+}
+
+export interface EngineerCalculationRequestParametersLoads {
+  dead_load: number;
+  live_load: number;
+  load_combination: string;
+  seismic_load?: number;
+  shear_load?: number;
+  tension_load?: number;
+  wind_load?: number;
+}
+
+export interface EngineerCalculationRequestParametersMaterial {
+  compressive_strength?: number;
+  density?: number;
+  elastic_modulus?: number;
+  material_type: string;
+  thermal_expansion?: number;
+  yield_strength?: number;
+}
+
+export interface EngineerCalculationRequestParametersSafetyFactors {
+  bearing?: number;
+  dead_load_factor: number;
+  importance_factor: number;
+  live_load_factor: number;
+  material_reduction_factor: number;
+  overturning?: number;
+}
+
+export interface EngineerCalculationRequestParameters {
+  additional?: Record<string, number>;
+  dimensions: Record<string, number>;
+  exposure_class?: string;
+  loads?: EngineerCalculationRequestParametersLoads;
+  material?: EngineerCalculationRequestParametersMaterial;
+  safety_factors?: EngineerCalculationRequestParametersSafetyFactors;
+}
+
+export interface EngineerCalculationRequest {
+  calculation_type: EngineerCalculationType;
+  parameters: EngineerCalculationRequestParameters;
+}
+
+export interface EngineerCalculationResponseAnalysisStoryForce {
+  elevation: number;
+  force: number;
+  level: number;
+  shear: number;
+  weight: number;
+}
+
+export interface EngineerCalculationResponseAnalysisZonePressure {
+  elevation?: number;
+  external_coefficient: number;
+  pressure: number;
+  zone: string;
+}
+
+export interface EngineerCalculationResponseAnalysis {
+  governing_limit_state: string;
+  max_deflection: number;
+  max_moment: number;
+  max_shear: number;
+  story_forces?: EngineerCalculationResponseAnalysisStoryForce[];
+  utilization_ratio: number;
+  zone_pressures?: EngineerCalculationResponseAnalysisZonePressure[];
+}
+
+export interface EngineerCalculationResponseCalculationMetadata {
+  calculator_version: string;
+  design_code_used: string;
+  requires_pe_review: boolean;
+  timestamp: string;
+}
+
+export interface EngineerCalculationResponseDataQualityFactor {
+  code: string;
+  points: number;
+  subject: string;
+}
+
+export interface EngineerCalculationResponseDataQuality {
+  factors: EngineerCalculationResponseDataQualityFactor[];
+  score: number;
+}
+
+export interface EngineerCalculationResponseResult {
+  formatted_value: string;
+  is_critical: boolean;
+  label: string;
+  tolerance?: number;
+  unit: string;
+  value: number;
+}
+
+export interface EngineerCalculationResponseStructuredWarning {
+  affected_parameter?: string;
+  code: string;
+  message: string;
+  severity: string;
+}
+
+export interface EngineerCalculationResponse {
+  analysis?: EngineerCalculationResponseAnalysis;
+  calculation_metadata: EngineerCalculationResponseCalculationMetadata;
+  calculation_type: string;
+  compliance_notes: string[];
+  data_quality?: EngineerCalculationResponseDataQuality;
+  recommendations: string[];
+  results: EngineerCalculationResponseResult[];
+  structured_warnings: EngineerCalculationResponseStructuredWarning[];
+  warnings: string[];
+}
+
+export interface EngineerCatalogueCalculatorExampleRequestParametersLoads {
+  dead_load: number;
+  live_load: number;
+  load_combination: string;
+  seismic_load?: number;
+  shear_load?: number;
+  tension_load?: number;
+  wind_load?: number;
+}
+
+export interface EngineerCatalogueCalculatorExampleRequestParametersMaterial {
+  compressive_strength?: number;
+  density?: number;
+  elastic_modulus?: number;
+  material_type: string;
+  thermal_expansion?: number;
+  yield_strength?: number;
+}
+
+export interface EngineerCatalogueCalculatorExampleRequestParametersSafetyFactors {
+  bearing?: number;
+  dead_load_factor: number;
+  importance_factor: number;
+  live_load_factor: number;
+  material_reduction_factor: number;
+  overturning?: number;
+}
+
+export interface EngineerCatalogueCalculatorExampleRequestParameters {
+  additional?: Record<string, number>;
+  dimensions: Record<string, number>;
+  exposure_class?: string;
+  loads?: EngineerCatalogueCalculatorExampleRequestParametersLoads;
+  material?: EngineerCatalogueCalculatorExampleRequestParametersMaterial;
+  safety_factors?: EngineerCatalogueCalculatorExampleRequestParametersSafetyFactors;
+}
+
+export interface EngineerCatalogueCalculatorExampleRequest {
+  calculation_type: string;
+  parameters: EngineerCatalogueCalculatorExampleRequestParameters;
+}
+
+export interface EngineerCatalogueCalculatorExample {
+  expected_results: Record<string, number>;
+  name: string;
+  request: EngineerCatalogueCalculatorExampleRequest;
+}
+
+export interface EngineerCatalogueCalculatorParameter {
+  data_type: unknown;
+  default_value?: number;
+  description: string;
+  max_value?: number;
+  min_value?: number;
+  name: string;
+  path: string;
+  required: boolean;
+  typical_range?: number[];
+  unit: string;
+  validation_rules?: string[];
+}
+
+export interface EngineerCatalogueCalculatorWizardStepField {
+  path: string;
+}
+
+export interface EngineerCatalogueCalculatorWizardStep {
+  description?: string;
+  fields: EngineerCatalogueCalculatorWizardStepField[];
+  id: string;
+  title: string;
+}
+
+export interface EngineerCatalogueCalculatorWizard {
+  steps: EngineerCatalogueCalculatorWizardStep[];
+}
+
+export interface EngineerCatalogueCalculator {
+  category: string;
+  complexity_level: string;
+  description: string;
+  design_codes: string[];
+  examples?: EngineerCatalogueCalculatorExample[];
+  id: string;
+  name: string;
+  optional_parameters: string[];
+  parameters: EngineerCatalogueCalculatorParameter[];
+  required_parameters: string[];
+  requires_pe_review: boolean;
+  typical_applications: unknown[];
+  wizard?: EngineerCatalogueCalculatorWizard;
+}
+
+export interface EngineerCatalogueCategory {
+  description: string;
+  icon: string;
+  id: string;
+  name: string;
+  requires_pe: boolean;
+}
+
+export interface EngineerCatalogueChangelogBeamDesignItem {
+  date: string;
+  impact: string;
+  note: string;
+  summary: string;
+  version: string;
+}
+
+export interface EngineerCatalogueChangelogColumnDesignItem {
+  date: string;
+  impact: string;
+  note: string;
+  summary: string;
+  version: string;
+}
+
+export interface EngineerCatalogueChangelog {
+  beam_design: EngineerCatalogueChangelogBeamDesignItem[];
+  column_design: EngineerCatalogueChangelogColumnDesignItem[];
+}
+
+export interface EngineerCatalogueSearchIndex {
+  keywords: Record<string, string[]>;
+  tags: Record<string, string[]>;
+}
+
+export interface EngineerCatalogue {
+  calculators: EngineerCatalogueCalculator[];
+  categories: EngineerCatalogueCategory[];
+  changelog: EngineerCatalogueChangelog;
+  disclaimer: string;
+  search_index: EngineerCatalogueSearchIndex;
+  version: string;
+}
+
+export interface ContractorCalculationRequestParametersLocation {
+  city: unknown | null;
+  country_code: string;
+  latitude: number;
+  longitude: number;
+  postal_code: unknown | null;
+  region: unknown | null;
+}
+
+export interface ContractorCalculationRequestParametersMaterial {
+  material_type: string;
+  unit_cost?: number;
+  waste_factor?: number;
+}
+
+export interface ContractorCalculationRequestParametersOrigin {
+  city: unknown | null;
+  country_code: string;
+  latitude: number;
+  longitude: number;
+  postal_code: unknown | null;
+  region: unknown | null;
+}
+
+export interface ContractorCalculationRequestParametersResources {
+  equipment_hours: number;
+  labor_hours: number;
+  material_quantity?: number;
+  overhead?: number;
+}
+
+export interface ContractorCalculationRequestParametersSafetyFactors {
+  cost_factor: number;
+  importance_factor: number;
+  risk_reduction_factor: number;
+  time_factor: number;
+}
+
+export interface ContractorCalculationRequestParameters {
+  additional?: Record<string, number>;
+  dimensions: Record<string, number>;
+  location?: ContractorCalculationRequestParametersLocation;
+  material?: ContractorCalculationRequestParametersMaterial;
+  origin?: ContractorCalculationRequestParametersOrigin;
+  resources?: ContractorCalculationRequestParametersResources;
+  safety_factors?: ContractorCalculationRequestParametersSafetyFactors;
+}
+
+export interface ContractorCalculationRequest {
+  calculation_type: ContractorCalculationType;
+  parameters: ContractorCalculationRequestParameters;
+}
+
+export interface ContractorCalculationResponseAnalysis {
+  compliance_score: number;
+  risk_level: number;
+  total_cost: number;
+  total_duration: number;
+}
+
+export interface ContractorCalculationResponseCalculationMetadata {
+  calculator_version: string;
+  regulation_code_used: string;
+  requires_certification_review: boolean;
+  timestamp: string;
+}
+
+export interface ContractorCalculationResponseResult {
+  formatted_value: string;
+  is_critical: boolean;
+  label: string;
+  tolerance?: number;
+  unit: string;
+  value: number;
+}
+
+export interface ContractorCalculationResponseStructuredWarning {
+  affected_parameter: string;
+  code: string;
+  message: string;
+  severity: string;
+}
+
+export interface ContractorCalculationResponse {
+  analysis?: ContractorCalculationResponseAnalysis;
+  calculation_metadata: ContractorCalculationResponseCalculationMetadata;
+  calculation_type: string;
+  compliance_notes: string[];
+  recommendations: string[];
+  results: ContractorCalculationResponseResult[];
+  structured_warnings?: ContractorCalculationResponseStructuredWarning[];
+  warnings: string[];
+}
+
+export interface ContractorCatalogueCalculatorExampleRequestParametersLocation {
+  city: unknown | null;
+  country_code: string;
+  latitude: number;
+  longitude: number;
+  postal_code: unknown | null;
+  region: unknown | null;
+}
+
+export interface ContractorCatalogueCalculatorExampleRequestParametersMaterial {
+  material_type: string;
+  unit_cost?: number;
+  waste_factor?: number;
+}
+
+export interface ContractorCatalogueCalculatorExampleRequestParametersOrigin {
+  city: unknown | null;
+  country_code: string;
+  latitude: number;
+  longitude: number;
+  postal_code: unknown | null;
+  region: unknown | null;
+}
+
+export interface ContractorCatalogueCalculatorExampleRequestParametersResources {
+  equipment_hours: number;
+  labor_hours: number;
+  material_quantity?: number;
+  overhead?: number;
+}
+
+export interface ContractorCatalogueCalculatorExampleRequestParametersSafetyFactors {
+  cost_factor: number;
+  importance_factor: number;
+  risk_reduction_factor: number;
+  time_factor: number;
+}
+
+export interface ContractorCatalogueCalculatorExampleRequestParameters {
+  additional?: Record<string, number>;
+  dimensions: Record<string, number>;
+  location?: ContractorCatalogueCalculatorExampleRequestParametersLocation;
+  material?: ContractorCatalogueCalculatorExampleRequestParametersMaterial;
+  origin?: ContractorCatalogueCalculatorExampleRequestParametersOrigin;
+  resources?: ContractorCatalogueCalculatorExampleRequestParametersResources;
+  safety_factors?: ContractorCatalogueCalculatorExampleRequestParametersSafetyFactors;
+}
+
+export interface ContractorCatalogueCalculatorExampleRequest {
+  calculation_type: string;
+  parameters: ContractorCatalogueCalculatorExampleRequestParameters;
+}
+
+export interface ContractorCatalogueCalculatorExample {
+  expected_results: Record<string, number>;
+  name: string;
+  request: ContractorCatalogueCalculatorExampleRequest;
+}
+
+export interface ContractorCatalogueCalculatorParameter {
+  data_type: string;
+  default_value?: number;
+  description: string;
+  max_value?: number;
+  min_value?: number;
+  name: string;
+  path: string;
+  required: boolean;
+  typical_range?: number[];
+  unit: string;
+  validation_rules?: string[];
+}
+
+export interface ContractorCatalogueCalculator {
+  category: string;
+  complexity_level: string;
+  description: string;
+  examples: ContractorCatalogueCalculatorExample[];
+  id: string;
+  name: string;
+  optional_parameters: string[];
+  parameters: ContractorCatalogueCalculatorParameter[];
+  regulation_codes: string[];
+  required_parameters: string[];
+  requires_certification_review: boolean;
+  typical_applications: unknown[];
+}
+
+export interface ContractorCatalogueCategory {
+  description: string;
+  icon: string;
+  id: string;
+  name: string;
+  requires_certification: boolean;
+}
+
+export interface ContractorCatalogueSearchIndex {
+  keywords: Record<string, string[]>;
+  tags: Record<string, string[]>;
+}
+
+export interface ContractorCatalogue {
+  calculators: ContractorCatalogueCalculator[];
+  categories: ContractorCatalogueCategory[];
+  disclaimer: string;
+  search_index: ContractorCatalogueSearchIndex;
+  version: string;
+}
+
+export interface CalculatorParameterInfo {
+  path: string;
+  unit: string;
+  required: boolean;
+}
+
+export interface CalculatorInfo {
+  tier: Tier;
+  id: string;
+  name: string;
+  parameters: readonly CalculatorParameterInfo[];
+}
+
+export const CALCULATORS: readonly CalculatorInfo[] = [
+  { tier: "beginner", id: "acoustic_treatment", name: "Acoustic Treatment & STC Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+    { path: "additional.target_rt", unit: "s", required: false },
+    { path: "additional.carpet_fraction", unit: "", required: false },
+    { path: "additional.ceiling_tile_fraction", unit: "", required: false },
+    { path: "additional.window_area", unit: "m²", required: false },
+    { path: "additional.target_stc", unit: "STC", required: false },
+  ] },
+  { tier: "beginner", id: "baseboard", name: "Baseboard Trim Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "compost_bin", name: "Compost Bin Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "concrete_slab", name: "Concrete Slab Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "crown_molding", name: "Crown Molding Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "deck", name: "Deck Builder", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "drain_line", name: "Drain Line Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "mm", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "drip_irrigation", name: "Drip Irrigation Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "driveway", name: "Driveway Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "surface_type", required: true },
+  ] },
+  { tier: "beginner", id: "drop_ceiling", name: "Drop Ceiling Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "drywall_ceiling", name: "Drywall Ceiling Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "drywall_count", name: "Drywall Material Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "electrical_service_load", name: "Electrical Service Load & Panel Schedule", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "floors", required: true },
+    { path: "additional.range_kw", unit: "kW", required: false },
+    { path: "additional.dryer_kw", unit: "kW", required: false },
+    { path: "additional.water_heater_kw", unit: "kW", required: false },
+    { path: "additional.heating_kw", unit: "kW", required: false },
+    { path: "additional.cooling_kw", unit: "kW", required: false },
+    { path: "additional.ev_charger_kw", unit: "kW", required: false },
+  ] },
+  { tier: "beginner", id: "fence", name: "Fence Builder", parameters: [
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+    { path: "width", unit: "gates", required: true },
+  ] },
+  { tier: "beginner", id: "gravel_path", name: "Gravel Path Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "hardwood_flooring", name: "Hardwood Flooring Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "hvac_sizing", name: "HVAC Sizing & Ductwork Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "insulation", name: "Wall/Ceiling Insulation Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "laminate_flooring", name: "Laminate Flooring Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "lawn_seed", name: "Lawn Seed Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "mulch_bed", name: "Mulch Bed Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "paint_coverage", name: "Paint Coverage Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "patio", name: "Patio Paver Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "pergola", name: "Pergola Builder", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "pipe_run", name: "Water Supply Pipe Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "planter_box", name: "Planter Box Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "raised_garden_bed", name: "Raised Garden Bed Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "recessed_lighting", name: "Recessed Lighting Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+    { path: "additional.room_type", unit: "", required: false },
+    { path: "additional.target_lux", unit: "lux", required: false },
+    { path: "additional.fixture_lumens", unit: "lumens", required: false },
+    { path: "additional.maintenance_factor", unit: "", required: false },
+  ] },
+  { tier: "beginner", id: "retaining_wall", name: "Small Retaining Wall Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "shed_foundation", name: "Shed Foundation Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "foundation_type", required: true },
+  ] },
+  { tier: "beginner", id: "sod", name: "Sod Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "sprinkler_coverage", name: "Sprinkler Coverage Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "stepping_stone", name: "Stepping Stone Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "tile_count", name: "Tile Installation Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "track_lighting", name: "Track Lighting Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+    { path: "additional.room_type", unit: "", required: false },
+    { path: "additional.target_lux", unit: "lux", required: false },
+    { path: "additional.fixture_lumens", unit: "lumens", required: false },
+    { path: "additional.maintenance_factor", unit: "", required: false },
+  ] },
+  { tier: "beginner", id: "wall_framing", name: "Wall Framing Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "beginner", id: "wallpaper", name: "Wallpaper Calculator", parameters: [
+    { path: "width", unit: "m", required: true },
+    { path: "length", unit: "m", required: true },
+    { path: "height", unit: "m", required: true },
+  ] },
+  { tier: "engineer", id: "beam_design", name: "Beam Design", parameters: [
+    { path: "dimensions.length", unit: "m", required: true },
+    { path: "loads.dead_load", unit: "kN/m", required: true },
+    { path: "loads.live_load", unit: "kN/m", required: true },
+    { path: "material.yield_strength", unit: "MPa", required: false },
+    { path: "material.compressive_strength", unit: "MPa", required: false },
+    { path: "dimensions.width", unit: "m", required: false },
+    { path: "dimensions.depth", unit: "m", required: false },
+    { path: "additional.support_condition", unit: "", required: false },
+  ] },
+  { tier: "engineer", id: "capacity_planning", name: "Capacity Planning Analysis", parameters: [
+    { path: "additional.demand_units", unit: "units", required: true },
+    { path: "additional.period_days", unit: "days", required: true },
+    { path: "additional.shifts_per_day", unit: "shifts/day", required: true },
+    { path: "additional.hours_per_shift", unit: "hours/shift", required: true },
+    { path: "additional.cycle_time", unit: "minutes/cycle", required: true },
+    { path: "additional.output_per_cycle", unit: "units/cycle", required: true },
+    { path: "additional.quality_yield", unit: "%", required: false },
+    { path: "additional.target_utilization", unit: "%", required: false },
+  ] },
+  { tier: "engineer", id: "column_design", name: "Column Design", parameters: [
+    { path: "dimensions.height", unit: "m", required: true },
+    { path: "loads.dead_load", unit: "kN", required: true },
+    { path: "loads.live_load", unit: "kN", required: true },
+    { path: "material.yield_strength", unit: "MPa", required: false },
+    { path: "additional.k_factor", unit: "dimensionless", required: false },
+    { path: "additional.radius_of_gyration", unit: "mm", required: false },
+    { path: "material.compressive_strength", unit: "MPa", required: false },
+    { path: "additional.reinforcement_ratio", unit: "dimensionless", required: false },
+  ] },
+  { tier: "engineer", id: "compressor_sizing", name: "Compressor Sizing", parameters: [
+    { path: "additional.p_in", unit: "kPa", required: true },
+    { path: "additional.p_out", unit: "kPa", required: true },
+    { path: "additional.flow_rate", unit: "m³/min", required: true },
+    { path: "additional.gas_constant", unit: "J/(kg·K)", required: false },
+    { path: "additional.k", unit: "dimensionless", required: false },
+    { path: "additional.efficiency", unit: "%", required: false },
+  ] },
+  { tier: "engineer", id: "condensation_risk", name: "Condensation Risk (Glaser Method)", parameters: [
+    { path: "extended_parameters.layers", unit: "", required: false },
+    { path: "additional.insulation_thickness", unit: "mm", required: false },
+    { path: "additional.insulation_conductivity", unit: "W/(m·K)", required: false },
+    { path: "additional.vapour_control_sd", unit: "m", required: false },
+    { path: "additional.indoor_temp", unit: "°C", required: true },
+    { path: "additional.indoor_rh", unit: "%", required: true },
+    { path: "additional.outdoor_temp", unit: "°C", required: true },
+    { path: "additional.outdoor_rh", unit: "%", required: true },
+    { path: "additional.cold_days", unit: "days", required: false },
+    { path: "additional.accumulation_limit", unit: "g/m²", required: false },
+  ] },
+  { tier: "engineer", id: "connection_design", name: "Bolted Connection Design", parameters: [
+    { path: "loads.shear_load", unit: "kN", required: true },
+    { path: "loads.tension_load", unit: "kN", required: false },
+    { path: "additional.bolt_grade", unit: "", required: false },
+    { path: "dimensions.diameter", unit: "mm", required: false },
+    { path: "additional.eccentricity", unit: "mm", required: false },
+    { path: "additional.bolt_rows", unit: "", required: false },
+    { path: "additional.bolt_columns", unit: "", required: false },
+    { path: "additional.bolt_pitch", unit: "mm", required: false },
+    { path: "additional.bolt_gauge", unit: "mm", required: false },
+  ] },
+  { tier: "engineer", id: "conveyor_belt", name: "Belt Conveyor Design and Sizing", parameters: [
+    { path: "dimensions.length", unit: "m", required: true },
+    { path: "dimensions.width", unit: "m", required: true },
+    { path: "additional.belt_speed", unit: "m/s", required: true },
+    { path: "material.density", unit: "kg/m³", required: false },
+    { path: "additional.inclination_angle", unit: "degrees", required: false },
+    { path: "additional.surcharge_angle", unit: "degrees", required: false },
+  ] },
+  { tier: "engineer", id: "culvert_sizing", name: "Culvert Sizing (Inlet/Outlet Control)", parameters: [
+    { path: "additional.design_flow", unit: "m³/s", required: true },
+    { path: "additional.allowable_headwater", unit: "m", required: true },
+    { path: "dimensions.length", unit: "m", required: false },
+    { path: "dimensions.diameter", unit: "m", required: false },
+    { path: "additional.slope", unit: "m/m", required: false },
+    { path: "additional.tailwater", unit: "m", required: false },
+    { path: "additional.inlet_type", unit: "", required: false },
+    { path: "additional.manning_n", unit: "", required: false },
+  ] },
+  { tier: "engineer", id: "elevator_traffic", name: "Elevator Traffic Analysis", parameters: [
+    { path: "additional.floors", unit: "floors", required: true },
+    { path: "additional.population", unit: "persons", required: true },
+    { path: "dimensions.floor_height", unit: "m", required: true },
+    { path: "additional.rated_load", unit: "kg", required: false },
+    { path: "additional.car_speed", unit: "m/s", required: false },
+    { path: "additional.acceleration", unit: "m/s²", required: false },
+    { path: "additional.cars", unit: "cars", required: false },
+    { path: "additional.door_open_time", unit: "s", required: false },
+    { path: "additional.door_close_time", unit: "s", required: false },
+    { path: "additional.transfer_time", unit: "s", required: false },
+    { path: "additional.start_delay", unit: "s", required: false },
+    { path: "additional.target_interval", unit: "s", required: false },
+    { path: "additional.target_handling", unit: "%", required: false },
+  ] },
+  { tier: "engineer", id: "facility_layout", name: "Facility Layout Optimization", parameters: [
+    { path: "additional.total_flow_distance", unit: "m", required: true },
+    { path: "additional.num_departments", unit: "departments", required: true },
+    { path: "additional.facility_area", unit: "m²", required: true },
+    { path: "additional.target_efficiency", unit: "%", required: false },
+  ] },
+  { tier: "engineer", id: "foundation_design", name: "Shallow Foundation Design", parameters: [
+    { path: "loads.dead_load", unit: "kN", required: true },
+    { path: "additional.bearing_capacity", unit: "kPa", required: true },
+    { path: "dimensions.depth", unit: "m", required: false },
+    { path: "safety_factors.bearing", unit: "dimensionless", required: false },
+  ] },
+  { tier: "engineer", id: "generator_ups_sizing", name: "Standby Generator and UPS Runtime", parameters: [
+    { path: "extended_parameters.loads", unit: "", required: false },
+    { path: "additional.running_load", unit: "kW", required: false },
+    { path: "additional.largest_motor", unit: "kW", required: false },
+    { path: "additional.ups_load", unit: "kW", required: false },
+    { path: "additional.generator_rating", unit: "kW", required: false },
+    { path: "additional.loading_limit", unit: "fraction", required: false },
+    { path: "additional.max_voltage_dip", unit: "fraction", required: false },
+    { path: "additional.transient_reactance", unit: "per unit", required: false },
+    { path: "additional.battery_capacity", unit: "Ah", required: false },
+    { path: "additional.battery_voltage", unit: "V", required: false },
+    { path: "additional.inverter_efficiency", unit: "fraction", required: false },
+    { path: "additional.required_runtime", unit: "min", required: false },
+  ] },
+  { tier: "engineer", id: "grounding_lightning_protection", name: "Grounding and Lightning Protection", parameters: [
+    { path: "additional.soil_resistivity", unit: "Ω·m", required: true },
+    { path: "additional.rod_length", unit: "m", required: false },
+    { path: "additional.rod_diameter", unit: "m", required: false },
+    { path: "additional.rod_count", unit: "rods", required: false },
+    { path: "additional.target_resistance", unit: "Ω", required: false },
+    { path: "dimensions.grid_length", unit: "m", required: false },
+    { path: "dimensions.grid_width", unit: "m", required: false },
+    { path: "additional.grid_spacing", unit: "m", required: false },
+    { path: "additional.burial_depth", unit: "m", required: false },
+    { path: "dimensions.length", unit: "m", required: true },
+    { path: "dimensions.width", unit: "m", required: true },
+    { path: "dimensions.height", unit: "m", required: true },
+    { path: "additional.air_terminal_height", unit: "m", required: false },
+  ] },
+  { tier: "engineer", id: "heat_exchanger", name: "Heat Exchanger Design and Sizing", parameters: [
+    { path: "additional.t_hot_in", unit: "°C", required: true },
+    { path: "additional.t_hot_out", unit: "°C", required: true },
+    { path: "additional.t_cold_in", unit: "°C", required: true },
+    { path: "additional.t_cold_out", unit: "°C", required: true },
+    { path: "additional.mass_flow_hot", unit: "kg/s", required: true },
+    { path: "additional.mass_flow_cold", unit: "kg/s", required: true },
+    { path: "additional.u_value", unit: "W/(m²·K)", required: false },
+  ] },
+  { tier: "engineer", id: "hvac_load_calculation", name: "HVAC Cooling/Heating Load Calculation", parameters: [
+    { path: "dimensions.area", unit: "m²", required: true },
+    { path: "additional.outdoor_temp", unit: "°C", required: true },
+    { path: "additional.indoor_temp", unit: "°C", required: true },
+    { path: "additional.wall_u", unit: "W/(m²·K)", required: false },
+    { path: "additional.window_ratio", unit: "%", required: false },
+    { path: "additional.occupancy", unit: "persons/m²", required: false },
+    { path: "additional.outdoor_rh", unit: "%", required: false },
+    { path: "additional.indoor_rh", unit: "%", required: false },
+    { path: "additional.altitude", unit: "m", required: false },
+    { path: "additional.outdoor_airflow", unit: "m³/h", required: false },
+    { path: "additional.return_airflow", unit: "m³/h", required: false },
+  ] },
+  { tier: "engineer", id: "inventory_optimization", name: "Inventory Optimization (EOQ, ROP, Safety Stock)", parameters: [
+    { path: "additional.annual_demand", unit: "units/year", required: true },
+    { path: "additional.ordering_cost", unit: "USD/order", required: true },
+    { path: "additional.holding_cost_per_unit", unit: "USD/unit/year", required: true },
+    { path: "additional.daily_demand", unit: "units/day", required: true },
+    { path: "additional.lead_time_days", unit: "days", required: true },
+    { path: "additional.safety_stock", unit: "units", required: false },
+  ] },
+  { tier: "engineer", id: "lateral_load_analysis", name: "Lateral Load Analysis", parameters: [
+    { path: "dimensions.height", unit: "m", required: true },
+    { path: "dimensions.width", unit: "m", required: true },
+    { path: "additional.load_type", unit: "", required: true },
+    { path: "loads.wind_load", unit: "kPa", required: true },
+    { path: "additional.num_stories", unit: "", required: true },
+  ] },
+  { tier: "engineer", id: "moment_frame_design", name: "Moment Frame Design", parameters: [
+    { path: "dimensions.height", unit: "m", required: true },
+    { path: "additional.num_stories", unit: "", required: true },
+    { path: "loads.seismic_load", unit: "kN", required: true },
+    { path: "dimensions.width", unit: "m", required: true },
+  ] },
+  { tier: "engineer", id: "pavement_design", name: "Flexible Pavement Design", parameters: [
+    { path: "additional.esal", unit: "loadings", required: true },
+    { path: "additional.cbr", unit: "%", required: true },
+    { path: "additional.reliability", unit: "%", required: false },
+    { path: "additional.drainage_coeff", unit: "dimensionless", required: false },
+  ] },
+  { tier: "engineer", id: "pile_foundation", name: "Pile Foundation Design", parameters: [
+    { path: "loads.dead_load", unit: "kN", required: true },
+    { path: "loads.live_load", unit: "kN", required: false },
+    { path: "dimensions.diameter", unit: "m", required: false },
+    { path: "dimensions.length", unit: "m", required: false },
+    { path: "extended_parameters.layers", unit: "", required: false },
+    { path: "additional.undrained_shear_strength", unit: "kPa", required: false },
+    { path: "additional.friction_angle", unit: "degrees", required: false },
+    { path: "additional.unit_weight", unit: "kN/m³", required: false },
+    { path: "additional.water_table_depth", unit: "m", required: false },
+    { path: "additional.earth_pressure_coefficient", unit: "dimensionless", required: false },
+    { path: "additional.pile_spacing", unit: "m", required: false },
+    { path: "additional.pile_rows", unit: "", required: false },
+    { path: "additional.pile_columns", unit: "", required: false },
+    { path: "additional.allowable_settlement", unit: "mm", required: false },
+    { path: "safety_factors.bearing", unit: "dimensionless", required: false },
+  ] },
+  { tier: "engineer", id: "piping_pressure_drop", name: "Piping Pressure Drop Calculation", parameters: [
+    { path: "dimensions.length", unit: "m", required: true },
+    { path: "dimensions.diameter", unit: "m", required: true },
+    { path: "additional.flow_rate", unit: "m³/s", required: true },
+    { path: "material.density", unit: "kg/m³", required: false },
+    { path: "additional.viscosity", unit: "Pa·s", required: false },
+    { path: "additional.roughness", unit: "m", required: false },
+  ] },
+  { tier: "engineer", id: "process_capability", name: "Process Capability Analysis (Cp, Cpk)", parameters: [
+    { path: "additional.mean", unit: "", required: true },
+    { path: "additional.std_dev", unit: "", required: true },
+    { path: "additional.lower_spec", unit: "", required: true },
+    { path: "additional.upper_spec", unit: "", required: true },
+  ] },
+  { tier: "engineer", id: "production_line_balancing", name: "Production Line Balancing Analysis", parameters: [
+    { path: "additional.num_workstations", unit: "stations", required: false },
+    { path: "additional.desired_output_per_day", unit: "units/day", required: true },
+    { path: "additional.available_time_per_day", unit: "minutes", required: true },
+    { path: "additional.total_task_time", unit: "minutes", required: true },
+    { path: "additional.longest_task_time", unit: "minutes", required: false },
+  ] },
+  { tier: "engineer", id: "pump_sizing", name: "Pump Sizing and Selection", parameters: [
+    { path: "additional.flow_rate", unit: "m³/h", required: true },
+    { path: "additional.total_head", unit: "m", required: true },
+    { path: "material.density", unit: "kg/m³", required: false },
+    { path: "additional.viscosity", unit: "cP", required: false },
+    { path: "additional.pump_efficiency", unit: "%", required: false },
+    { path: "additional.npsh_available", unit: "m", required: false },
+  ] },
+  { tier: "engineer", id: "rational_method_runoff", name: "Rational Method Peak Runoff", parameters: [
+    { path: "additional.drainage_area", unit: "ha", required: true },
+    { path: "additional.runoff_coefficient", unit: "dimensionless", required: false },
+    { path: "extended_parameters.subareas", unit: "", required: false },
+    { path: "additional.time_of_concentration", unit: "min", required: false },
+    { path: "additional.flow_length", unit: "m", required: false },
+    { path: "additional.watershed_slope", unit: "m/m", required: false },
+    { path: "additional.rainfall_intensity", unit: "mm/h", required: false },
+    { path: "additional.idf_a", unit: "mm/h·min^n", required: false },
+    { path: "additional.idf_b", unit: "min", required: false },
+    { path: "additional.idf_n", unit: "dimensionless", required: false },
+    { path: "additional.return_period", unit: "years", required: false },
+  ] },
+  { tier: "engineer", id: "refrigeration_cycle", name: "Refrigeration Cycle Analysis", parameters: [
+    { path: "additional.t_evap", unit: "°C", required: true },
+    { path: "additional.t_cond", unit: "°C", required: true },
+    { path: "material.material_type", unit: "", required: false },
+    { path: "additional.cooling_capacity", unit: "kW", required: true },
+    { path: "additional.isentropic_eff", unit: "%", required: false },
+  ] },
+  { tier: "engineer", id: "retaining_wall", name: "Retaining Wall Design", parameters: [
+    { path: "dimensions.height", unit: "m", required: true },
+    { path: "additional.friction_angle", unit: "degrees", required: true },
+    { path: "additional.soil_unit_weight", unit: "kN/m³", required: false },
+    { path: "loads.live_load", unit: "kPa", required: false },
+    { path: "material.compressive_strength", unit: "MPa", required: false },
+    { path: "safety_factors.overturning", unit: "dimensionless", required: false },
+  ] },
+  { tier: "engineer", id: "seismic_load", name: "Seismic Base Shear (ASCE 7 ELF)", parameters: [
+    { path: "dimensions.height", unit: "m", required: true },
+    { path: "additional.ss", unit: "g", required: true },
+    { path: "additional.s1", unit: "g", required: true },
+    { path: "extended_parameters.site_class", unit: "", required: false },
+    { path: "additional.response_modification", unit: "dimensionless", required: true },
+    { path: "additional.importance_factor", unit: "dimensionless", required: false },
+    { path: "additional.seismic_weight", unit: "kN", required: true },
+    { path: "additional.period", unit: "s", required: false },
+    { path: "additional.long_period_transition", unit: "s", required: false },
+    { path: "additional.num_stories", unit: "", required: false },
+  ] },
+  { tier: "engineer", id: "settlement_analysis", name: "Consolidation Settlement Analysis", parameters: [
+    { path: "loads.dead_load", unit: "kPa", required: true },
+    { path: "dimensions.thickness", unit: "m", required: true },
+    { path: "additional.cc", unit: "dimensionless", required: true },
+    { path: "additional.e0", unit: "dimensionless", required: true },
+    { path: "additional.pc", unit: "kPa", required: false },
+  ] },
+  { tier: "engineer", id: "slab_design", name: "Concrete Slab Design", parameters: [
+    { path: "dimensions.length", unit: "m", required: true },
+    { path: "loads.dead_load", unit: "kPa", required: true },
+    { path: "loads.live_load", unit: "kPa", required: true },
+    { path: "material.compressive_strength", unit: "MPa", required: false },
+    { path: "material.yield_strength", unit: "MPa", required: false },
+  ] },
+  { tier: "engineer", id: "slope_stability", name: "Slope Stability Analysis", parameters: [
+    { path: "additional.slope_angle", unit: "degrees", required: true },
+    { path: "additional.friction_angle", unit: "degrees", required: true },
+    { path: "additional.cohesion", unit: "kPa", required: false },
+    { path: "additional.unit_weight", unit: "kN/m³", required: false },
+    { path: "dimensions.height", unit: "m", required: false },
+  ] },
+  { tier: "engineer", id: "soil_bearing_capacity", name: "Soil Bearing Capacity", parameters: [
+    { path: "additional.cohesion", unit: "kPa", required: false },
+    { path: "additional.friction_angle", unit: "degrees", required: true },
+    { path: "additional.unit_weight", unit: "kN/m³", required: false },
+    { path: "dimensions.width", unit: "m", required: true },
+    { path: "dimensions.depth", unit: "m", required: false },
+  ] },
+  { tier: "engineer", id: "soil_compaction", name: "Soil Compaction Control", parameters: [
+    { path: "extended_parameters.tests", unit: "", required: false },
+    { path: "additional.wet_density", unit: "kg/m³", required: false },
+    { path: "additional.field_moisture", unit: "%", required: false },
+    { path: "additional.max_dry_density", unit: "kg/m³", required: true },
+    { path: "additional.optimum_moisture", unit: "%", required: true },
+    { path: "additional.required_compaction", unit: "%", required: false },
+    { path: "additional.moisture_below", unit: "%", required: false },
+    { path: "additional.moisture_above", unit: "%", required: false },
+    { path: "additional.moisture_correction", unit: "%", required: false },
+    { path: "additional.specific_gravity", unit: "dimensionless", required: false },
+  ] },
+  { tier: "engineer", id: "sprinkler_hydraulic", name: "Fire Sprinkler Hydraulic Calculation", parameters: [
+    { path: "extended_parameters.hazard_class", unit: "", required: false },
+    { path: "additional.density", unit: "mm/min", required: false },
+    { path: "additional.design_area", unit: "m²", required: false },
+    { path: "dimensions.sprinkler_spacing", unit: "m", required: false },
+    { path: "dimensions.branch_spacing", unit: "m", required: false },
+    { path: "dimensions.branch_diameter", unit: "mm", required: false },
+    { path: "dimensions.main_diameter", unit: "mm", required: false },
+    { path: "dimensions.feed_length", unit: "m", required: false },
+    { path: "additional.elevation", unit: "m", required: false },
+    { path: "additional.k_factor", unit: "L/min/bar^0.5", required: false },
+    { path: "additional.min_pressure", unit: "kPa", required: false },
+    { path: "additional.hazen_williams_c", unit: "", required: false },
+    { path: "additional.supply_static_pressure", unit: "kPa", required: false },
+    { path: "additional.supply_residual_pressure", unit: "kPa", required: false },
+    { path: "additional.supply_flow", unit: "L/min", required: false },
+  ] },
+  { tier: "engineer", id: "storm_sewer_sizing", name: "Storm Sewer Sizing", parameters: [
+    { path: "additional.design_flow", unit: "m³/s", required: false },
+    { path: "additional.slope", unit: "m/m", required: false },
+    { path: "extended_parameters.reaches", unit: "", required: false },
+    { path: "additional.inlet_time", unit: "min", required: false },
+    { path: "additional.idf_a", unit: "mm/h·min^n", required: false },
+    { path: "additional.idf_b", unit: "min", required: false },
+    { path: "additional.idf_n", unit: "dimensionless", required: false },
+    { path: "additional.manning_n", unit: "", required: false },
+  ] },
+  { tier: "engineer", id: "thermal_expansion", name: "Thermal Expansion Calculation", parameters: [
+    { path: "dimensions.length", unit: "m", required: true },
+    { path: "additional.delta_t", unit: "°C", required: true },
+    { path: "material.thermal_expansion", unit: "1/°C", required: true },
+    { path: "additional.expansion_type", unit: "", required: false },
+  ] },
+  { tier: "engineer", id: "tower_crane", name: "Tower Crane Selection", parameters: [
+    { path: "extended_parameters.candidates", unit: "", required: false },
+    { path: "extended_parameters.picks", unit: "", required: false },
+    { path: "additional.max_load", unit: "t", required: false },
+    { path: "additional.max_radius", unit: "m", required: false },
+    { path: "additional.crane_x", unit: "m", required: false },
+    { path: "additional.crane_y", unit: "m", required: false },
+    { path: "additional.oversail_limit", unit: "m", required: false },
+    { path: "additional.mast_height", unit: "m", required: true },
+    { path: "additional.wind_pressure", unit: "kPa", required: false },
+    { path: "additional.footing_width", unit: "m", required: false },
+    { path: "additional.footing_depth", unit: "m", required: false },
+    { path: "additional.allowable_bearing", unit: "kPa", required: false },
+  ] },
+  { tier: "engineer", id: "truss_analysis", name: "Truss Analysis", parameters: [
+    { path: "additional.nodes", unit: "(x,y)", required: true },
+    { path: "additional.members", unit: "[node_i,node_j]", required: true },
+    { path: "additional.supports", unit: "[node, type]", required: true },
+    { path: "loads", unit: "kN", required: true },
+  ] },
+  { tier: "engineer", id: "valve_sizing", name: "Valve Sizing (Cv Calculation)", parameters: [
+    { path: "additional.flow_rate", unit: "gpm", required: true },
+    { path: "additional.dp", unit: "psi", required: true },
+    { path: "additional.sg", unit: "dimensionless", required: false },
+    { path: "additional.fluid_type", unit: "", required: false },
+  ] },
+  { tier: "engineer", id: "water_hammer", name: "Water Hammer / Surge Analysis", parameters: [
+    { path: "dimensions.length", unit: "m", required: true },
+    { path: "dimensions.diameter", unit: "m", required: true },
+    { path: "dimensions.wall_thickness", unit: "m", required: true },
+    { path: "material.material_type", unit: "", required: false },
+    { path: "material.elastic_modulus", unit: "GPa", required: false },
+    { path: "additional.velocity", unit: "m/s", required: true },
+    { path: "additional.closure_time", unit: "s", required: false },
+    { path: "additional.operating_pressure", unit: "kPa", required: false },
+    { path: "additional.pressure_rating", unit: "kPa", required: false },
+    { path: "additional.fluid_density", unit: "kg/m³", required: false },
+    { path: "additional.bulk_modulus", unit: "GPa", required: false },
+  ] },
+  { tier: "engineer", id: "wind_load", name: "Wind Load (ASCE 7 Directional)", parameters: [
+    { path: "dimensions.height", unit: "m", required: true },
+    { path: "dimensions.width", unit: "m", required: true },
+    { path: "dimensions.length", unit: "m", required: true },
+    { path: "additional.wind_speed", unit: "m/s", required: true },
+    { path: "exposure_class", unit: "", required: false },
+    { path: "additional.topographic_factor", unit: "dimensionless", required: false },
+    { path: "additional.ground_elevation", unit: "m", required: false },
+    { path: "additional.internal_pressure_coefficient", unit: "dimensionless", required: false },
+    { path: "additional.num_stories", unit: "", required: false },
+  ] },
+  { tier: "engineer", id: "work_sampling", name: "Work Sampling Analysis", parameters: [
+    { path: "additional.total_observations", unit: "counts", required: true },
+    { path: "additional.productive_observations", unit: "counts", required: true },
+    { path: "additional.confidence_level", unit: "%", required: false },
+  ] },
+  { tier: "contractor", id: "bid_bond", name: "Bid Bond", parameters: [
+    { path: "additional.bid_price", unit: "USD", required: true },
+    { path: "additional.bond_percentage", unit: "%", required: true },
+  ] },
+  { tier: "contractor", id: "bid_pricing", name: "Bid Pricing", parameters: [
+    { path: "resources.labor_hours", unit: "hours", required: true },
+    { path: "resources.equipment_hours", unit: "hours", required: true },
+    { path: "resources.material_quantity", unit: "units", required: true },
+    { path: "material.unit_cost", unit: "USD/unit", required: true },
+    { path: "additional.labor_rate", unit: "USD/hour", required: true },
+    { path: "additional.equipment_rate", unit: "USD/hour", required: true },
+    { path: "additional.markup_percentage", unit: "%", required: true },
+  ] },
+  { tier: "contractor", id: "budget_forecast", name: "Budget Forecast", parameters: [
+    { path: "additional.total_cost", unit: "USD", required: true },
+    { path: "additional.duration_months", unit: "months", required: true },
+    { path: "additional.inflation_rate", unit: "%", required: false },
+    { path: "additional.material_share", unit: "%", required: false },
+    { path: "additional.material_escalation_rate", unit: "%", required: false },
+  ] },
+  { tier: "contractor", id: "cash_flow_analysis", name: "Cash Flow Analysis", parameters: [
+    { path: "additional.inflows", unit: "USD", required: true },
+    { path: "additional.outflows", unit: "USD", required: true },
+  ] },
+  { tier: "contractor", id: "change_order", name: "Change Order", parameters: [
+    { path: "additional.original_cost", unit: "USD", required: true },
+    { path: "additional.change_cost", unit: "USD", required: true },
+    { path: "additional.change_duration", unit: "days", required: false },
+  ] },
+  { tier: "contractor", id: "contingency_planning", name: "Contingency Planning", parameters: [
+    { path: "additional.total_cost", unit: "USD", required: true },
+    { path: "additional.risk_factor", unit: "", required: true },
+  ] },
+  { tier: "contractor", id: "contract_estimation", name: "Contract Estimation", parameters: [
+    { path: "additional.total_cost", unit: "USD", required: true },
+    { path: "additional.contingency_percentage", unit: "%", required: true },
+  ] },
+  { tier: "contractor", id: "cost_breakdown", name: "Cost Breakdown", parameters: [
+    { path: "additional.material_cost", unit: "USD", required: true },
+    { path: "additional.labor_cost", unit: "USD", required: true },
+    { path: "additional.equipment_cost", unit: "USD", required: true },
+    { path: "resources.overhead", unit: "USD", required: false },
+  ] },
+  { tier: "contractor", id: "critical_path", name: "Critical Path", parameters: [
+    { path: "additional.total_tasks", unit: "", required: true },
+    { path: "additional.avg_duration", unit: "days", required: true },
+    { path: "additional.parallel_factor", unit: "", required: false },
+  ] },
+  { tier: "contractor", id: "delay_analysis", name: "Delay Analysis", parameters: [
+    { path: "additional.planned_duration", unit: "days", required: true },
+    { path: "additional.actual_duration", unit: "days", required: true },
+    { path: "additional.delay_cause_factor", unit: "", required: false },
+    { path: "additional.window_days", unit: "days", required: false },
+  ] },
+  { tier: "contractor", id: "equipment_cost", name: "Equipment Cost Estimator", parameters: [
+    { path: "resources.equipment_hours", unit: "hours", required: true },
+    { path: "additional.equipment_rate", unit: "USD/hour", required: true },
+    { path: "additional.maintenance_factor", unit: "", required: false },
+  ] },
+  { tier: "contractor", id: "fuel_emissions", name: "Fuel Consumption and Fleet Emissions", parameters: [
+    { path: "resources.equipment_hours", unit: "hours", required: false },
+    { path: "additional.fuel_price", unit: "USD/L", required: false },
+    { path: "additional.fuel_rate", unit: "L/h", required: false },
+    { path: "additional.hours_per_day", unit: "hours", required: false },
+    { path: "additional.emission_factor", unit: "kg CO2e/L", required: false },
+    { path: "additional.idle_fraction", unit: "", required: false },
+    { path: "additional.target_idle_fraction", unit: "", required: false },
+  ] },
+  { tier: "contractor", id: "gantt_chart", name: "Gantt Chart Generator", parameters: [
+    { path: "additional.start_date", unit: "unix timestamp", required: true },
+    { path: "additional.duration", unit: "days", required: true },
+    { path: "additional.milestones", unit: "", required: false },
+  ] },
+  { tier: "contractor", id: "labor_cost", name: "Labor Cost Estimator", parameters: [
+    { path: "resources.labor_hours", unit: "hours", required: true },
+    { path: "additional.labor_rate", unit: "USD/hour", required: true },
+    { path: "additional.productivity_factor", unit: "", required: false },
+  ] },
+  { tier: "contractor", id: "material_cost", name: "Material Cost Estimator", parameters: [
+    { path: "resources.material_quantity", unit: "units", required: true },
+    { path: "material.unit_cost", unit: "USD/unit", required: true },
+    { path: "material.waste_factor", unit: "", required: false },
+  ] },
+  { tier: "contractor", id: "milestone_tracking", name: "Milestone Tracking", parameters: [
+    { path: "additional.total_milestones", unit: "", required: true },
+    { path: "additional.completed_milestones", unit: "", required: true },
+  ] },
+  { tier: "contractor", id: "overhead", name: "Overhead Calculator", parameters: [
+    { path: "additional.direct_cost", unit: "USD", required: true },
+    { path: "additional.overhead_percentage", unit: "%", required: true },
+  ] },
+  { tier: "contractor", id: "profit_margin", name: "Profit Margin", parameters: [
+    { path: "additional.total_cost", unit: "USD", required: true },
+    { path: "additional.bid_price", unit: "USD", required: true },
+  ] },
+  { tier: "contractor", id: "progress_tracking", name: "Progress Tracking", parameters: [
+    { path: "additional.planned_progress", unit: "%", required: true },
+    { path: "additional.actual_progress", unit: "%", required: true },
+    { path: "additional.budget_at_completion", unit: "USD", required: false },
+  ] },
+  { tier: "contractor", id: "project_closeout", name: "Project Closeout", parameters: [
+    { path: "additional.completion_percentage", unit: "%", required: true },
+    { path: "additional.outstanding_issues", unit: "", required: true },
+  ] },
+  { tier: "contractor", id: "quality_control", name: "Quality Control", parameters: [
+    { path: "additional.total_items", unit: "", required: true },
+    { path: "additional.defective_items", unit: "", required: true },
+  ] },
+  { tier: "contractor", id: "quantity_takeoff", name: "Quantity Takeoff", parameters: [
+    { path: "dimensions.length", unit: "m", required: true },
+    { path: "dimensions.width", unit: "m", required: true },
+    { path: "dimensions.height", unit: "m", required: false },
+    { path: "material.waste_factor", unit: "", required: false },
+  ] },
+  { tier: "contractor", id: "resource_allocation", name: "Resource Allocation", parameters: [
+    { path: "resources.labor_hours", unit: "hours", required: true },
+    { path: "resources.equipment_hours", unit: "hours", required: true },
+    { path: "additional.available_labor", unit: "hours", required: true },
+    { path: "additional.available_equipment", unit: "hours", required: true },
+  ] },
+  { tier: "contractor", id: "resource_leveling", name: "Resource Leveling", parameters: [
+    { path: "additional.peak_demand", unit: "units", required: true },
+    { path: "additional.available_resources", unit: "units", required: true },
+    { path: "additional.project_duration", unit: "days", required: true },
+  ] },
+  { tier: "contractor", id: "risk_assessment", name: "Risk Assessment", parameters: [
+    { path: "safety_factors.risk_reduction_factor", unit: "", required: true },
+    { path: "safety_factors.importance_factor", unit: "", required: true },
+    { path: "additional.project_complexity", unit: "", required: true },
+  ] },
+  { tier: "contractor", id: "safety_planning", name: "Safety Planning", parameters: [
+    { path: "safety_factors.risk_reduction_factor", unit: "", required: true },
+    { path: "safety_factors.importance_factor", unit: "", required: true },
+    { path: "additional.hazard_level", unit: "", required: true },
+  ] },
+  { tier: "contractor", id: "schedule_optimization", name: "Schedule Optimization", parameters: [
+    { path: "additional.original_duration", unit: "days", required: true },
+    { path: "additional.optimization_factor", unit: "", required: true },
+  ] },
+  { tier: "contractor", id: "subcontractor_evaluation", name: "Subcontractor Evaluation", parameters: [
+    { path: "additional.performance_score", unit: "", required: true },
+    { path: "additional.reliability_score", unit: "", required: true },
+    { path: "additional.cost_score", unit: "", required: true },
+  ] },
+  { tier: "contractor", id: "time_cost_tradeoff", name: "Time-Cost Tradeoff", parameters: [
+    { path: "additional.normal_duration", unit: "days", required: true },
+    { path: "additional.normal_cost", unit: "USD", required: true },
+    { path: "additional.crash_duration", unit: "days", required: true },
+    { path: "additional.crash_cost", unit: "USD", required: true },
+  ] },
+  { tier: "contractor", id: "travel_cost", name: "Jobsite Travel Cost Estimator", parameters: [
+    { path: "origin.latitude", unit: "deg", required: true },
+    { path: "origin.longitude", unit: "deg", required: true },
+    { path: "location.latitude", unit: "deg", required: true },
+    { path: "location.longitude", unit: "deg", required: true },
+    { path: "additional.project_days", unit: "days", required: true },
+    { path: "additional.equipment_loads", unit: "loads", required: false },
+    { path: "additional.transport_rate", unit: "USD/mile", required: false },
+    { path: "additional.crew_vehicles", unit: "vehicles", required: false },
+    { path: "additional.vehicle_rate", unit: "USD/mile", required: false },
+    { path: "additional.crew_size", unit: "workers", required: false },
+    { path: "additional.travel_labor_rate", unit: "USD/hour", required: false },
+    { path: "additional.average_speed", unit: "mph", required: false },
+    { path: "additional.circuity_factor", unit: "", required: false },
+  ] },
+  { tier: "contractor", id: "value_engineering", name: "Value Engineering", parameters: [
+    { path: "additional.original_cost", unit: "USD", required: true },
+    { path: "additional.alternative_cost", unit: "USD", required: true },
+    { path: "additional.performance_factor", unit: "", required: false },
+  ] },
+];
+
+export class StrukturaApiError extends Error {
+  status: number;
+  body: unknown;
+
+  constructor(status: number, body: unknown) {
+    super(`HTTP ${status}`);
+    this.status = status;
+    this.body = body;
+  }
+}
+
+export class StrukturaApi {
+  baseUrl: string;
+  headers: Record<string, string>;
+
+  constructor(baseUrl: string = "/api/v1", headers: Record<string, string> = {}) {
+    this.baseUrl = baseUrl;
+    this.headers = headers;
+  }
+
+  private async request<T>(method: string, path: string, body?: unknown): Promise<T> {
+    const response = await fetch(`${this.baseUrl}${path}`, {
+      method,
+      credentials: "same-origin",
+      headers: {
+        Accept: "application/json",
+        ...(body === undefined ? {} : { "Content-Type": "application/json" }),
+        ...this.headers,
+      },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const data = await response.json().catch(() => null);
+    if (!response.ok) throw new StrukturaApiError(response.status, data);
+    return data as T;
+  }
+
+  calculateBeginner(request: BeginnerCalculationRequest): Promise<BeginnerCalculationResponse> {
+    return this.request("POST", "/calculus/beginner/calculate", request);
+  }
+
+  beginnerCatalogue(): Promise<BeginnerCatalogue> {
+    return this.request("GET", "/calculus/beginner/catalogue");
+  }
+
+  calculateEngineer(request: EngineerCalculationRequest): Promise<EngineerCalculationResponse> {
+    return this.request("POST", "/calculus/engineer/calculate", request);
+  }
+
+  engineerCatalogue(): Promise<EngineerCatalogue> {
+    return this.request("GET", "/calculus/engineer/catalogue");
+  }
+
+  calculateContractor(request: ContractorCalculationRequest): Promise<ContractorCalculationResponse> {
+    return this.request("POST", "/calculus/contractor/calculate", request);
+  }
+
+  contractorCatalogue(): Promise<ContractorCatalogue> {
+    return this.request("GET", "/calculus/contractor/catalogue");
+  }
+}